@@ -418,11 +418,16 @@ const fn catalog_note(feature: LpFeature) -> CatalogNote {
     match feature {
         LpFeature::NodeButton
         | LpFeature::NodeClock
+        | LpFeature::NodeFilter
+        | LpFeature::NodePalette
         | LpFeature::NodeFluid
+        | LpFeature::NodeImage
         | LpFeature::NodeFixture
+        | LpFeature::NodeParticle
         | LpFeature::NodePlaylist
         | LpFeature::NodeRadio
         | LpFeature::NodeShader
+        | LpFeature::NodeText
         | LpFeature::NodeTexture => CatalogNote::NodeRuntime,
         LpFeature::SvcButton => CatalogNote::Norm {
             absent: "no button input",
@@ -518,6 +523,11 @@ pub fn node_kind_label(kind: NodeKind) -> &'static str {
         NodeKind::Shader => "Shader",
         NodeKind::ComputeShader => "Compute shader",
         NodeKind::Fluid => "Fluid",
+        NodeKind::Image => "Image",
+        NodeKind::Text => "Text",
+        NodeKind::Particle => "Particle",
+        NodeKind::Filter => "Filter",
+        NodeKind::Palette => "Palette",
         NodeKind::Playlist => "Playlist",
         NodeKind::ControlRadio => "Radio",
         NodeKind::Output => "Output",
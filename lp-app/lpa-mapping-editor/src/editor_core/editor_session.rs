@@ -10,9 +10,9 @@
 use std::collections::BTreeSet;
 
 use lpc_mapping::{
-    Bounds2d, GridCorner, GridRouting, GridShape, Map2dDoc, Map2dObject, Map2dShape, PathShape,
-    RepeatShape, ResolvedMap2d, RingDir, RingOrder, RingShape, Rotation2d, bounds_of_points,
-    resolve,
+    ArcShape, Bounds2d, GridCorner, GridRouting, GridShape, HexShape, Map2dDoc, Map2dObject,
+    Map2dShape, PathShape, PolygonShape, RepeatShape, ResolvedMap2d, RingDir, RingOrder, RingShape,
+    Rotation2d, SpiralShape, TextShape, bounds_of_points, resolve,
 };
 
 use crate::editor_core::map_selection::MapSelection;
@@ -409,7 +409,8 @@ impl MapEditorSession {
 
     /// Illustrator-style expand: replace a parametric object with a plain
     /// path through its own resolved lamps, ready for hand-tweaking. The
-    /// lamp layout is identical before and after.
+    /// lamp layout is identical before and after. Text expands into the
+    /// gapped path it resolves through.
     ///
     /// A [`RepeatShape`] expands differently — into one independent object per
     /// instance, see [`Self::expand_repeat`] — because baking its whole wheel
@@ -422,6 +423,22 @@ impl MapEditorSession {
             self.expand_repeat(index);
             return;
         }
+        // Text already resolves through a gapped path: expanding hands over
+        // that path, so the letter strokes are the handles instead of one
+        // vertex per lamp.
+        if let Some(Map2dShape::Text(text)) =
+            self.doc.objects.get(index).map(|object| &object.shape)
+        {
+            let Ok(path) = text.to_path() else {
+                return;
+            };
+            self.edit(move |doc| {
+                if let Some(object) = doc.objects.get_mut(index) {
+                    object.shape = Map2dShape::Path(path);
+                }
+            });
+            return;
+        }
         let positions: Vec<[f32; 2]> = {
             let resolved = self.resolved();
             // The object's WHOLE lamp range, strands merged: `spans[index]` is
@@ -601,6 +618,95 @@ impl MapEditorSession {
         })
     }
 
+    /// Drop a default phyllotaxis disc centered on `at`; selects it, tool →
+    /// select.
+    pub fn create_default_spiral(&mut self, at: [f32; 2]) -> usize {
+        self.create_object(Map2dObject {
+            name: self.next_name("spiral"),
+            shape: Map2dShape::Spiral(SpiralShape {
+                center: at,
+                radius: 80.0,
+                count: 60,
+                divergence_deg: 137.507_77,
+                order: RingOrder::InnerFirst,
+                start_angle_deg: -90.0,
+                dir: RingDir::Cw,
+            }),
+        })
+    }
+
+    /// Drop a default half-circle arch centered on `at`; selects it, tool →
+    /// select.
+    pub fn create_default_arc(&mut self, at: [f32; 2]) -> usize {
+        self.create_object(Map2dObject {
+            name: self.next_name("arc"),
+            shape: Map2dShape::Arc(ArcShape {
+                center: at,
+                radius: 80.0,
+                count: 11,
+                start_angle_deg: -180.0,
+                sweep_deg: 180.0,
+                rings: 1,
+                counts: Vec::new(),
+                order: RingOrder::OuterFirst,
+                routing: GridRouting::Snake,
+                dir: RingDir::Cw,
+            }),
+        })
+    }
+
+    /// Drop a default 8×8 hex grid centered on `at`; selects it, tool →
+    /// select.
+    pub fn create_default_hex(&mut self, at: [f32; 2]) -> usize {
+        let row_pitch = DEFAULT_PITCH * (3.0_f32.sqrt() / 2.0);
+        let origin = [at[0] - 3.75 * DEFAULT_PITCH, at[1] - 3.5 * row_pitch];
+        self.create_object(Map2dObject {
+            name: self.next_name("hex"),
+            shape: Map2dShape::Hex(HexShape {
+                origin,
+                cols: 8,
+                rows: 8,
+                pitch: DEFAULT_PITCH,
+                routing: GridRouting::Snake,
+                start_corner: GridCorner::Tl,
+            }),
+        })
+    }
+
+    /// Drop a default hexagon outline centered on `at`; selects it, tool →
+    /// select.
+    pub fn create_default_polygon(&mut self, at: [f32; 2]) -> usize {
+        self.create_object(Map2dObject {
+            name: self.next_name("polygon"),
+            shape: Map2dShape::Polygon(PolygonShape {
+                center: at,
+                radius: 80.0,
+                sides: 6,
+                count: 18,
+                start_angle_deg: -90.0,
+                dir: RingDir::Cw,
+            }),
+        })
+    }
+
+    /// Drop default lettering centered on `at`; selects it, tool → select.
+    pub fn create_default_text(&mut self, at: [f32; 2]) -> usize {
+        // Font units are height / 6; "TEXT" spans three 6-unit advances and
+        // a final 4-unit cell.
+        let height = 60.0;
+        let width = height / 6.0 * (3.0 * 6.0 + 4.0);
+        self.create_object(Map2dObject {
+            name: self.next_name("text"),
+            shape: Map2dShape::Text(TextShape {
+                origin: [at[0] - width / 2.0, at[1] - height / 2.0],
+                text: "TEXT".into(),
+                height,
+                count: 40,
+                reversed: false,
+            }),
+        })
+    }
+
     // ---- path drafting ---------------------------------------------------
 
     pub fn path_add_point(&mut self, point: [f32; 2]) {
@@ -739,10 +845,11 @@ pub fn editable_path(shape: &Map2dShape) -> Option<&PathShape> {
 
 /// One instance of a shape under a repeat's turn, still parametric.
 ///
-/// `None` for a grid: it has no rotation of its own, so a turned grid is only
-/// representable as baked geometry. Everything else carries the turn in its
-/// own parameters — which is what keeps an expanded instance resolving to the
-/// lamps the repeat produced instead of a re-sampled approximation of them.
+/// `None` for a grid, hex grid or text: they have no rotation of their own,
+/// so a turned one is only representable as baked geometry. Everything else
+/// carries the turn in its own parameters — which is what keeps an expanded
+/// instance resolving to the lamps the repeat produced instead of a
+/// re-sampled approximation of them.
 fn rotate_shape(shape: &Map2dShape, rotation: Rotation2d, degrees: f32) -> Option<Map2dShape> {
     Some(match shape {
         Map2dShape::Path(path) => Map2dShape::Path(PathShape {
@@ -761,6 +868,22 @@ fn rotate_shape(shape: &Map2dShape, rotation: Rotation2d, degrees: f32) -> Optio
             start_angle_deg: ring.start_angle_deg + degrees,
             dir: ring.dir,
         }),
+        Map2dShape::Spiral(spiral) => Map2dShape::Spiral(SpiralShape {
+            center: rotation.apply(spiral.center),
+            start_angle_deg: spiral.start_angle_deg + degrees,
+            ..*spiral
+        }),
+        Map2dShape::Arc(arc) => Map2dShape::Arc(ArcShape {
+            center: rotation.apply(arc.center),
+            start_angle_deg: arc.start_angle_deg + degrees,
+            counts: arc.counts.clone(),
+            ..*arc
+        }),
+        Map2dShape::Polygon(polygon) => Map2dShape::Polygon(PolygonShape {
+            center: rotation.apply(polygon.center),
+            start_angle_deg: polygon.start_angle_deg + degrees,
+            ..*polygon
+        }),
         // Turning a wheel of wheels turns the hub and the spoke together: a
         // rotation conjugated by another rotation is the same rotation about
         // the moved center, so the inner instances land where they did.
@@ -769,7 +892,7 @@ fn rotate_shape(shape: &Map2dShape, rotation: Rotation2d, degrees: f32) -> Optio
             center: rotation.apply(repeat.center),
             count: repeat.count,
         }),
-        Map2dShape::Grid(_) => return None,
+        Map2dShape::Grid(_) | Map2dShape::Hex(_) | Map2dShape::Text(_) => return None,
     })
 }
 
@@ -793,6 +916,26 @@ fn translate_shape(shape: &mut Map2dShape, dx: f32, dy: f32) {
             ring.center[0] += dx;
             ring.center[1] += dy;
         }
+        Map2dShape::Spiral(spiral) => {
+            spiral.center[0] += dx;
+            spiral.center[1] += dy;
+        }
+        Map2dShape::Arc(arc) => {
+            arc.center[0] += dx;
+            arc.center[1] += dy;
+        }
+        Map2dShape::Hex(hex) => {
+            hex.origin[0] += dx;
+            hex.origin[1] += dy;
+        }
+        Map2dShape::Polygon(polygon) => {
+            polygon.center[0] += dx;
+            polygon.center[1] += dy;
+        }
+        Map2dShape::Text(text) => {
+            text.origin[0] += dx;
+            text.origin[1] += dy;
+        }
         Map2dShape::Path(path) => {
             for point in &mut path.points {
                 point[0] += dx;
@@ -826,6 +969,26 @@ fn scale_shape(shape: &mut Map2dShape, anchor: [f32; 2], factor: f32) {
             ring.center = scale_point(ring.center);
             ring.radius *= factor;
         }
+        Map2dShape::Spiral(spiral) => {
+            spiral.center = scale_point(spiral.center);
+            spiral.radius *= factor;
+        }
+        Map2dShape::Arc(arc) => {
+            arc.center = scale_point(arc.center);
+            arc.radius *= factor;
+        }
+        Map2dShape::Hex(hex) => {
+            hex.origin = scale_point(hex.origin);
+            hex.pitch *= factor;
+        }
+        Map2dShape::Polygon(polygon) => {
+            polygon.center = scale_point(polygon.center);
+            polygon.radius *= factor;
+        }
+        Map2dShape::Text(text) => {
+            text.origin = scale_point(text.origin);
+            text.height *= factor;
+        }
         Map2dShape::Path(path) => {
             for point in &mut path.points {
                 *point = scale_point(*point);
@@ -871,6 +1034,32 @@ fn sanitize_shape(shape: &mut Map2dShape) {
             ring.radius = ring.radius.max(1.0);
             ring.rings = ring.rings.max(1);
         }
+        Map2dShape::Spiral(spiral) => {
+            spiral.count = spiral.count.max(1);
+            spiral.radius = spiral.radius.max(1.0);
+        }
+        Map2dShape::Arc(arc) => {
+            arc.count = arc.count.max(1);
+            arc.radius = arc.radius.max(1.0);
+            arc.rings = arc.rings.max(1);
+            // A full turn is a ring; an arc stays strictly inside one.
+            arc.sweep_deg = arc.sweep_deg.clamp(1.0, 359.0);
+        }
+        Map2dShape::Hex(hex) => {
+            hex.cols = hex.cols.max(1);
+            hex.rows = hex.rows.max(1);
+            hex.pitch = hex.pitch.max(0.5);
+        }
+        Map2dShape::Polygon(polygon) => {
+            polygon.sides = polygon.sides.max(3);
+            polygon.count = polygon.count.max(1);
+            polygon.radius = polygon.radius.max(1.0);
+        }
+        Map2dShape::Text(text) => {
+            text.count = text.count.max(1);
+            text.height = text.height.max(1.0);
+            sanitize_text(text);
+        }
         Map2dShape::Path(path) => {
             path.count = path.count.max(1);
             sanitize_path_gaps(path);
@@ -886,6 +1075,19 @@ fn sanitize_shape(shape: &mut Map2dShape) {
     }
 }
 
+/// Drop characters the built-in font cannot draw, and fall back to the
+/// default lettering when nothing drawable is left — typing an accent or
+/// clearing the field should not leave the document unresolvable.
+fn sanitize_text(text: &mut TextShape) {
+    let unsupported = text.unsupported_chars();
+    if !unsupported.is_empty() {
+        text.text.retain(|c| !unsupported.contains(c));
+    }
+    if text.to_path().is_err() {
+        text.text = "TEXT".into();
+    }
+}
+
 /// Sort, dedupe and clamp inert segment indices.
 ///
 /// Sanitize's contract is "every emitted document resolves", so a gap set that
@@ -1596,6 +1798,108 @@ mod tests {
         }
     }
 
+    /// Every new-shape default resolves as dropped, and dropping one is what
+    /// lifts the document to the format that introduced it.
+    #[test]
+    fn new_shape_defaults_resolve_and_stamp_format_three() {
+        let mut session = MapEditorSession::new(Map2dDoc::new());
+        session.create_default_grid([100.0, 100.0]);
+        assert_eq!(session.doc().format, 1);
+        session.create_default_spiral([300.0, 100.0]);
+        assert_eq!(session.doc().format, 3);
+        session.create_default_arc([500.0, 100.0]);
+        session.create_default_hex([100.0, 300.0]);
+        session.create_default_polygon([300.0, 300.0]);
+        session.create_default_text([500.0, 300.0]);
+        assert_eq!(session.lamp_count(), 64 + 60 + 11 + 64 + 18 + 40);
+        assert!(session.resolve_error.is_none());
+        assert_eq!(session.selection.single_root(), Some(5));
+        assert!(session.tool.is_select());
+        for _ in 0..5 {
+            session.undo();
+        }
+        assert_eq!(session.doc().format, 1, "the format drops back");
+    }
+
+    /// Arcs carry a turn in their start angle, like rings, so a repeat of
+    /// arcs expands into arcs on the same lamps.
+    #[test]
+    fn expanding_a_repeat_of_arcs_keeps_them_arcs() {
+        let mut doc = Map2dDoc::new();
+        doc.objects.push(Map2dObject {
+            name: "petal".to_string(),
+            shape: Map2dShape::Repeat(RepeatShape {
+                shape: Box::new(Map2dShape::Arc(ArcShape {
+                    center: [100.0, 50.0],
+                    radius: 30.0,
+                    count: 7,
+                    start_angle_deg: -180.0,
+                    sweep_deg: 120.0,
+                    rings: 2,
+                    counts: Vec::new(),
+                    order: RingOrder::OuterFirst,
+                    routing: GridRouting::Snake,
+                    dir: RingDir::Cw,
+                })),
+                center: [100.0, 100.0],
+                count: 5,
+            }),
+        });
+        let mut session = MapEditorSession::new(doc);
+        let before: Vec<[f32; 2]> = session.resolved().lamps.iter().map(|l| l.pos).collect();
+        session.expand_object(0);
+        assert_eq!(session.doc().objects.len(), 5);
+        for object in &session.doc().objects {
+            assert!(matches!(object.shape, Map2dShape::Arc(_)), "still an arc");
+        }
+        let after: Vec<[f32; 2]> = session.resolved().lamps.iter().map(|l| l.pos).collect();
+        assert_eq!(after.len(), before.len());
+        for (index, (a, b)) in after.iter().zip(&before).enumerate() {
+            assert!(
+                (a[0] - b[0]).abs() < 1e-3 && (a[1] - b[1]).abs() < 1e-3,
+                "lamp {index} moved: {a:?} vs {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn expanding_text_hands_over_its_stroke_path() {
+        let mut session = MapEditorSession::new(Map2dDoc::new());
+        session.create_default_text([200.0, 200.0]);
+        let before: Vec<[f32; 2]> = session.resolved().lamps.iter().map(|l| l.pos).collect();
+        session.expand_object(0);
+        let Map2dShape::Path(path) = &session.doc().objects[0].shape else {
+            panic!("expected path after expand");
+        };
+        assert!(!path.gaps.is_empty(), "jumpers between strokes stay inert");
+        assert_eq!(path.count, 40);
+        let after: Vec<[f32; 2]> = session.resolved().lamps.iter().map(|l| l.pos).collect();
+        assert_eq!(after, before);
+    }
+
+    #[test]
+    fn sanitize_drops_glyphs_the_font_cannot_draw() {
+        let mut session = MapEditorSession::new(Map2dDoc::new());
+        session.create_default_text([200.0, 200.0]);
+        let set_text = |session: &mut MapEditorSession, value: &str| {
+            session.edit_object_shape(0, |shape| {
+                if let Map2dShape::Text(text) = shape {
+                    text.text = value.to_string();
+                }
+            });
+        };
+        let text_of = |session: &MapEditorSession| match &session.doc().objects[0].shape {
+            Map2dShape::Text(text) => text.text.clone(),
+            _ => panic!("expected text"),
+        };
+        set_text(&mut session, "Café~");
+        assert_eq!(text_of(&session), "Caf");
+        set_text(&mut session, " ");
+        assert_eq!(text_of(&session), "TEXT", "nothing drawable falls back");
+        assert!(session.lamp_count() > 0);
+        assert!(session.resolve_error.is_none());
+    }
+
     /// A wheel of wheels expands into wheels: the inner repeat's own center
     /// turns with it, so the strand structure survives instead of collapsing
     /// into one long baked run.
@@ -8,6 +8,11 @@ pub enum MapTool {
     Select,
     Grid,
     Ring,
+    Spiral,
+    Arc,
+    Hex,
+    Polygon,
+    Text,
     /// Path drawing carries its in-progress vertices; Escape backs vertices
     /// out one at a time (never wholesale — parent decision D6).
     Path {
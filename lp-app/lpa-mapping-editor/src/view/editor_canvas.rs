@@ -460,6 +460,26 @@ pub fn EditorCanvas(
                         session.write().create_default_ring(doc_point);
                         on_committed.call(());
                     }
+                    MapTool::Spiral => {
+                        session.write().create_default_spiral(doc_point);
+                        on_committed.call(());
+                    }
+                    MapTool::Arc => {
+                        session.write().create_default_arc(doc_point);
+                        on_committed.call(());
+                    }
+                    MapTool::Hex => {
+                        session.write().create_default_hex(doc_point);
+                        on_committed.call(());
+                    }
+                    MapTool::Polygon => {
+                        session.write().create_default_polygon(doc_point);
+                        on_committed.call(());
+                    }
+                    MapTool::Text => {
+                        session.write().create_default_text(doc_point);
+                        on_committed.call(());
+                    }
                     MapTool::Path { .. } => {
                         session.write().path_add_point(doc_point);
                     }
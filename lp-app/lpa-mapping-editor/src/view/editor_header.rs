@@ -6,7 +6,8 @@
 use base64::Engine as _;
 use dioxus::prelude::*;
 use dioxus_icons::lucide::{
-    CircleDashed, Grid3x3, Hash, Image, Layers, MousePointer, Route, Scan, Spline,
+    CircleDashed, Grid3x3, Hash, Hexagon, Image, Layers, MousePointer, Pentagon, Rainbow, Route,
+    Scan, Shell, Spline, Type,
 };
 use lpc_mapping::{Map2dDoc, corpus, resolve};
 
@@ -96,6 +97,36 @@ pub fn EditorHeader(
                 onclick: move |_| session.write().tool = MapTool::Ring,
                 CircleDashed { size: 13 }
             }
+            button {
+                class: toggle_class(matches!(session.read().tool, MapTool::Spiral)),
+                title: "spiral tool (S): click to drop a default phyllotaxis disc",
+                onclick: move |_| session.write().tool = MapTool::Spiral,
+                Shell { size: 13 }
+            }
+            button {
+                class: toggle_class(matches!(session.read().tool, MapTool::Arc)),
+                title: "arc tool (C): click to drop a default arc",
+                onclick: move |_| session.write().tool = MapTool::Arc,
+                Rainbow { size: 13 }
+            }
+            button {
+                class: toggle_class(matches!(session.read().tool, MapTool::Hex)),
+                title: "hex grid tool (H): click to drop a default hex grid",
+                onclick: move |_| session.write().tool = MapTool::Hex,
+                Hexagon { size: 13 }
+            }
+            button {
+                class: toggle_class(matches!(session.read().tool, MapTool::Polygon)),
+                title: "polygon tool (O): click to drop a default polygon outline",
+                onclick: move |_| session.write().tool = MapTool::Polygon,
+                Pentagon { size: 13 }
+            }
+            button {
+                class: toggle_class(matches!(session.read().tool, MapTool::Text)),
+                title: "text tool (T): click to drop default lettering",
+                onclick: move |_| session.write().tool = MapTool::Text,
+                Type { size: 13 }
+            }
             button {
                 class: toggle_class(matches!(session.read().tool, MapTool::Path { .. })),
                 title: "path tool (P): click vertices, Enter finishes",
@@ -254,6 +254,15 @@ pub fn MapEditor(
             }
             MapTool::Grid => "click to drop a default grid — size it in the properties popover",
             MapTool::Ring => "click to drop a default ring — tune it in the properties popover",
+            MapTool::Spiral => {
+                "click to drop a default spiral disc — tune it in the properties popover"
+            }
+            MapTool::Arc => "click to drop a default arc — sweep it in the properties popover",
+            MapTool::Hex => "click to drop a default hex grid — size it in the properties popover",
+            MapTool::Polygon => {
+                "click to drop a default polygon outline — set its sides in the properties popover"
+            }
+            MapTool::Text => "click to drop default lettering — type it in the properties popover",
             MapTool::Path { .. } => {
                 "click to place lamps · ⏎ or double-click finishes · esc backs out one point"
             }
@@ -499,6 +508,11 @@ fn handle_key(
                 "v" => session.write().tool = MapTool::Select,
                 "g" => session.write().tool = MapTool::Grid,
                 "r" => session.write().tool = MapTool::Ring,
+                "s" => session.write().tool = MapTool::Spiral,
+                "c" => session.write().tool = MapTool::Arc,
+                "h" => session.write().tool = MapTool::Hex,
+                "o" => session.write().tool = MapTool::Polygon,
+                "t" => session.write().tool = MapTool::Text,
                 "p" => session.write().tool = MapTool::path(),
                 "n" => {
                     let current = view_opts.peek().numbers;
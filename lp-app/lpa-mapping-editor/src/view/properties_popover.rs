@@ -347,6 +347,11 @@ pub(crate) fn shape_kind_label(shape: &Map2dShape) -> &'static str {
     match shape {
         Map2dShape::Grid(_) => "grid",
         Map2dShape::Ring(_) => "ring",
+        Map2dShape::Spiral(_) => "spiral",
+        Map2dShape::Arc(_) => "arc",
+        Map2dShape::Hex(_) => "hex grid",
+        Map2dShape::Polygon(_) => "polygon",
+        Map2dShape::Text(_) => "text",
         Map2dShape::Path(_) => "path",
         Map2dShape::Repeat(_) => "repeat",
     }
@@ -437,6 +442,161 @@ fn shape_fields(
                 }
             }
         }
+        Map2dShape::Spiral(spiral) => {
+            let order_current = if matches!(spiral.order, RingOrder::OuterFirst) {
+                "outer"
+            } else {
+                "inner"
+            };
+            let dir_current = if matches!(spiral.dir, RingDir::Cw) {
+                "cw"
+            } else {
+                "ccw"
+            };
+            rsx! {
+                NumberField { session, on_committed, index, depth, label: "count", value: spiral.count as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::SpiralCount }
+                NumberField { session, on_committed, index, depth, label: "radius", value: spiral.radius, min: 1.0, is_int: false,
+                    apply: FieldApply::SpiralRadius }
+                NumberField { session, on_committed, index, depth, label: "divergence", value: spiral.divergence_deg, min: -360.0, is_int: false,
+                    apply: FieldApply::SpiralDivergence }
+                SegField {
+                    session, on_committed, index, depth, label: "wiring",
+                    options: vec![("inner", "in→out"), ("outer", "out→in")],
+                    current: order_current,
+                    apply: FieldApply::SpiralOrder,
+                }
+                NumberField { session, on_committed, index, depth, label: "start angle", value: spiral.start_angle_deg, min: -360.0, is_int: false,
+                    apply: FieldApply::SpiralAngle }
+                SegField {
+                    session, on_committed, index, depth, label: "direction",
+                    options: vec![("cw", "cw ↻"), ("ccw", "ccw ↺")],
+                    current: dir_current,
+                    apply: FieldApply::SpiralDir,
+                }
+            }
+        }
+        Map2dShape::Arc(arc) => {
+            let order_current = if matches!(arc.order, RingOrder::OuterFirst) {
+                "outer"
+            } else {
+                "inner"
+            };
+            let routing_current = if matches!(arc.routing, GridRouting::Snake) {
+                "snake"
+            } else {
+                "raster"
+            };
+            let dir_current = if matches!(arc.dir, RingDir::Cw) {
+                "cw"
+            } else {
+                "ccw"
+            };
+            rsx! {
+                NumberField { session, on_committed, index, depth, label: "outer count", value: arc.count as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::ArcCount }
+                NumberField { session, on_committed, index, depth, label: "radius", value: arc.radius, min: 1.0, is_int: false,
+                    apply: FieldApply::ArcRadius }
+                NumberField { session, on_committed, index, depth, label: "start angle", value: arc.start_angle_deg, min: -360.0, is_int: false,
+                    apply: FieldApply::ArcAngle }
+                NumberField { session, on_committed, index, depth, label: "sweep", value: arc.sweep_deg, min: 1.0, is_int: false,
+                    apply: FieldApply::ArcSweep }
+                NumberField { session, on_committed, index, depth, label: "rings", value: arc.rings as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::ArcRings }
+                if arc.rings > 1 {
+                    SegField {
+                        session, on_committed, index, depth, label: "ring order",
+                        options: vec![("outer", "out→in"), ("inner", "in→out")],
+                        current: order_current,
+                        apply: FieldApply::ArcOrder,
+                    }
+                    SegField {
+                        session, on_committed, index, depth, label: "routing",
+                        options: vec![("snake", "snake"), ("raster", "raster")],
+                        current: routing_current,
+                        apply: FieldApply::ArcRouting,
+                    }
+                }
+                SegField {
+                    session, on_committed, index, depth, label: "direction",
+                    options: vec![("cw", "cw ↻"), ("ccw", "ccw ↺")],
+                    current: dir_current,
+                    apply: FieldApply::ArcDir,
+                }
+            }
+        }
+        Map2dShape::Hex(hex) => {
+            let routing_current = if matches!(hex.routing, GridRouting::Snake) {
+                "snake"
+            } else {
+                "raster"
+            };
+            let corner_current = match hex.start_corner {
+                GridCorner::Tl => "tl",
+                GridCorner::Tr => "tr",
+                GridCorner::Bl => "bl",
+                GridCorner::Br => "br",
+            };
+            rsx! {
+                NumberField { session, on_committed, index, depth, label: "cols", value: hex.cols as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::HexCols }
+                NumberField { session, on_committed, index, depth, label: "rows", value: hex.rows as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::HexRows }
+                NumberField { session, on_committed, index, depth, label: "pitch", value: hex.pitch, min: 0.5, is_int: false,
+                    apply: FieldApply::HexPitch }
+                SegField {
+                    session, on_committed, index, depth, label: "routing",
+                    options: vec![("snake", "snake"), ("raster", "raster")],
+                    current: routing_current,
+                    apply: FieldApply::HexRouting,
+                }
+                SegField {
+                    session, on_committed, index, depth, label: "start corner",
+                    options: vec![("tl", "↖"), ("tr", "↗"), ("bl", "↙"), ("br", "↘")],
+                    current: corner_current,
+                    apply: FieldApply::HexCorner,
+                }
+            }
+        }
+        Map2dShape::Polygon(polygon) => {
+            let dir_current = if matches!(polygon.dir, RingDir::Cw) {
+                "cw"
+            } else {
+                "ccw"
+            };
+            rsx! {
+                NumberField { session, on_committed, index, depth, label: "sides", value: polygon.sides as f32, min: 3.0, is_int: true,
+                    apply: FieldApply::PolygonSides }
+                NumberField { session, on_committed, index, depth, label: "count", value: polygon.count as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::PolygonCount }
+                NumberField { session, on_committed, index, depth, label: "radius", value: polygon.radius, min: 1.0, is_int: false,
+                    apply: FieldApply::PolygonRadius }
+                NumberField { session, on_committed, index, depth, label: "start angle", value: polygon.start_angle_deg, min: -360.0, is_int: false,
+                    apply: FieldApply::PolygonAngle }
+                SegField {
+                    session, on_committed, index, depth, label: "direction",
+                    options: vec![("cw", "cw ↻"), ("ccw", "ccw ↺")],
+                    current: dir_current,
+                    apply: FieldApply::PolygonDir,
+                }
+            }
+        }
+        Map2dShape::Text(text) => {
+            let dir_current = if text.reversed { "rev" } else { "fwd" };
+            rsx! {
+                TextStringField { session, on_committed, index, depth, text: text.text.clone() }
+                NumberField { session, on_committed, index, depth, label: "height", value: text.height, min: 1.0, is_int: false,
+                    apply: FieldApply::TextHeight }
+                NumberField { session, on_committed, index, depth, label: "count", value: text.count as f32, min: 1.0, is_int: true,
+                    apply: FieldApply::TextCount }
+                SegField {
+                    session, on_committed, index, depth, label: "direction",
+                    options: vec![("fwd", "forward"), ("rev", "reversed")],
+                    current: dir_current,
+                    apply: FieldApply::TextReversed,
+                }
+            }
+        }
         Map2dShape::Path(path) => {
             let dir_current = if path.reversed { "rev" } else { "fwd" };
             rsx! {
@@ -518,6 +678,33 @@ pub enum FieldApply {
     RingOrder,
     RingAngle,
     RingDir,
+    SpiralCount,
+    SpiralRadius,
+    SpiralDivergence,
+    SpiralOrder,
+    SpiralAngle,
+    SpiralDir,
+    ArcCount,
+    ArcRadius,
+    ArcAngle,
+    ArcSweep,
+    ArcRings,
+    ArcOrder,
+    ArcRouting,
+    ArcDir,
+    HexCols,
+    HexRows,
+    HexPitch,
+    HexRouting,
+    HexCorner,
+    PolygonSides,
+    PolygonCount,
+    PolygonRadius,
+    PolygonAngle,
+    PolygonDir,
+    TextHeight,
+    TextCount,
+    TextReversed,
     PathCount,
     PathReversed,
     RepeatCount,
@@ -534,6 +721,32 @@ fn apply_number(shape: &mut Map2dShape, apply: FieldApply, value: f32) {
         (FieldApply::RingRadius, Map2dShape::Ring(ring)) => ring.radius = value,
         (FieldApply::RingRings, Map2dShape::Ring(ring)) => ring.rings = value.max(1.0) as u32,
         (FieldApply::RingAngle, Map2dShape::Ring(ring)) => ring.start_angle_deg = value,
+        (FieldApply::SpiralCount, Map2dShape::Spiral(spiral)) => {
+            spiral.count = value.max(1.0) as u32;
+        }
+        (FieldApply::SpiralRadius, Map2dShape::Spiral(spiral)) => spiral.radius = value,
+        (FieldApply::SpiralDivergence, Map2dShape::Spiral(spiral)) => spiral.divergence_deg = value,
+        (FieldApply::SpiralAngle, Map2dShape::Spiral(spiral)) => spiral.start_angle_deg = value,
+        (FieldApply::ArcCount, Map2dShape::Arc(arc)) => arc.count = value.max(1.0) as u32,
+        (FieldApply::ArcRadius, Map2dShape::Arc(arc)) => arc.radius = value,
+        (FieldApply::ArcAngle, Map2dShape::Arc(arc)) => arc.start_angle_deg = value,
+        (FieldApply::ArcSweep, Map2dShape::Arc(arc)) => arc.sweep_deg = value,
+        (FieldApply::ArcRings, Map2dShape::Arc(arc)) => arc.rings = value.max(1.0) as u32,
+        (FieldApply::HexCols, Map2dShape::Hex(hex)) => hex.cols = value.max(1.0) as u32,
+        (FieldApply::HexRows, Map2dShape::Hex(hex)) => hex.rows = value.max(1.0) as u32,
+        (FieldApply::HexPitch, Map2dShape::Hex(hex)) => hex.pitch = value,
+        (FieldApply::PolygonSides, Map2dShape::Polygon(polygon)) => {
+            polygon.sides = value.max(3.0) as u32;
+        }
+        (FieldApply::PolygonCount, Map2dShape::Polygon(polygon)) => {
+            polygon.count = value.max(1.0) as u32;
+        }
+        (FieldApply::PolygonRadius, Map2dShape::Polygon(polygon)) => polygon.radius = value,
+        (FieldApply::PolygonAngle, Map2dShape::Polygon(polygon)) => {
+            polygon.start_angle_deg = value;
+        }
+        (FieldApply::TextHeight, Map2dShape::Text(text)) => text.height = value,
+        (FieldApply::TextCount, Map2dShape::Text(text)) => text.count = value.max(1.0) as u32,
         (FieldApply::PathCount, Map2dShape::Path(path)) => path.count = value.max(1.0) as u32,
         // Sanitize owns the upper bound (`MAX_REPEAT_COUNT`) so a typed digit
         // that overshoots is clamped on commit rather than refused mid-typing.
@@ -577,6 +790,66 @@ fn apply_choice(shape: &mut Map2dShape, apply: FieldApply, choice: &str) {
                 RingDir::Cw
             };
         }
+        (FieldApply::SpiralOrder, Map2dShape::Spiral(spiral)) => {
+            spiral.order = if choice == "outer" {
+                RingOrder::OuterFirst
+            } else {
+                RingOrder::InnerFirst
+            };
+        }
+        (FieldApply::SpiralDir, Map2dShape::Spiral(spiral)) => {
+            spiral.dir = if choice == "ccw" {
+                RingDir::Ccw
+            } else {
+                RingDir::Cw
+            };
+        }
+        (FieldApply::ArcOrder, Map2dShape::Arc(arc)) => {
+            arc.order = if choice == "inner" {
+                RingOrder::InnerFirst
+            } else {
+                RingOrder::OuterFirst
+            };
+        }
+        (FieldApply::ArcRouting, Map2dShape::Arc(arc)) => {
+            arc.routing = if choice == "snake" {
+                GridRouting::Snake
+            } else {
+                GridRouting::Raster
+            };
+        }
+        (FieldApply::ArcDir, Map2dShape::Arc(arc)) => {
+            arc.dir = if choice == "ccw" {
+                RingDir::Ccw
+            } else {
+                RingDir::Cw
+            };
+        }
+        (FieldApply::HexRouting, Map2dShape::Hex(hex)) => {
+            hex.routing = if choice == "snake" {
+                GridRouting::Snake
+            } else {
+                GridRouting::Raster
+            };
+        }
+        (FieldApply::HexCorner, Map2dShape::Hex(hex)) => {
+            hex.start_corner = match choice {
+                "tr" => GridCorner::Tr,
+                "bl" => GridCorner::Bl,
+                "br" => GridCorner::Br,
+                _ => GridCorner::Tl,
+            };
+        }
+        (FieldApply::PolygonDir, Map2dShape::Polygon(polygon)) => {
+            polygon.dir = if choice == "ccw" {
+                RingDir::Ccw
+            } else {
+                RingDir::Cw
+            };
+        }
+        (FieldApply::TextReversed, Map2dShape::Text(text)) => {
+            text.reversed = choice == "rev";
+        }
         (FieldApply::PathReversed, Map2dShape::Path(path)) => {
             path.reversed = choice == "rev";
         }
@@ -717,6 +990,43 @@ fn PathGapsField(
     }
 }
 
+/// The lettering itself, one line per row. The built-in font draws A–Z,
+/// 0–9, space and `-+/.'!?`; sanitize drops anything else as it is typed, so
+/// the label's tooltip is where an author learns why a glyph vanished.
+#[component]
+#[allow(non_snake_case, reason = "Dioxus components use PascalCase")]
+fn TextStringField(
+    session: Signal<MapEditorSession>,
+    on_committed: EventHandler<()>,
+    index: usize,
+    depth: usize,
+    text: String,
+) -> Element {
+    rsx! {
+        div { class: "lpme-field",
+            label { title: "single-stroke font: A–Z, 0–9, space and - + / . ' ! ? — other characters are dropped", "text" }
+            textarea {
+                rows: "2",
+                value: "{text}",
+                oninput: move |evt| {
+                    let typed = evt.value();
+                    session.write().edit_uncommitted(move |doc| {
+                        if let Some(object) = doc.objects.get_mut(index)
+                            && let Some(Map2dShape::Text(text)) = shape_at_depth(&mut object.shape, depth)
+                        {
+                            text.text = typed;
+                        }
+                    });
+                },
+                onchange: move |_| {
+                    session.write().commit_gesture();
+                    on_committed.call(());
+                },
+            }
+        }
+    }
+}
+
 /// Comma-separated per-ring counts (outer→inner); empty = derived. Typing
 /// previews through the gesture path; commit on change.
 #[component]
@@ -0,0 +1,158 @@
+//! Frame recording: `/.lp/recorder.json` turns it on, `/.lp/replay.bin` is
+//! what it leaves behind.
+//!
+//! Recording is opt-in per project. When the config file is present the
+//! engine's [`lpc_engine::FrameRecorder`] runs from project construction —
+//! before panel state is restored, so the restored writes are the first
+//! events in the log — and the ring is written out on a throttled schedule
+//! and on a clean shutdown. The file is the ring as it stood: the last
+//! `capacity_bytes` of frames, not the whole show.
+//!
+//! [`replay`] is the host half: it loads the same project afresh, feeds it
+//! the log and reports the first frame whose output checksum differs. Only
+//! a ring that has not wrapped can be replayed — once it drops its first
+//! frame the log no longer starts from a project load, [`replay`] refuses
+//! it, and [`write`] says so on every write. Size `capacity_bytes` for the
+//! session you mean to replay.
+//!
+//! Both files live in the framework-owned `/.lp/` tier, so neither touches
+//! the package hash nor triggers a project refresh when written. Posture is
+//! the `/.lp/panel.json` one — lenient load (missing, unparseable, or
+//! unknown-version config → no recording) and best-effort write.
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::cell::RefCell;
+
+use lpc_engine::{Engine, FrameLog, LpGraphics, ProjectLoader, ReplayReport, replay_frame_log};
+use lpc_model::AsLpPath;
+use lpc_shared::output::OutputProvider;
+use lpfs::LpFs;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ServerError;
+use crate::project::{build_engine_services, project_root_path};
+
+/// Path of the recorder config inside the project's own filesystem.
+pub const FRAME_RECORDER_CONFIG_PATH: &str = "/.lp/recorder.json";
+
+/// Path of the recorded log inside the project's own filesystem.
+pub const FRAME_RECORDING_PATH: &str = "/.lp/replay.bin";
+
+/// Format version of the config file. Bump-and-refuse, like panel state.
+pub const FRAME_RECORDER_CONFIG_VERSION: u32 = 1;
+
+/// The recorder config file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameRecorderConfig {
+    pub version: u32,
+    /// Ring size. An idle frame costs about seven bytes, so the default
+    /// holds a little over two minutes at 60 fps.
+    #[serde(default = "capacity_bytes_default")]
+    pub capacity_bytes: u32,
+    /// Minimum engine time between writes of the log. Every write rewrites
+    /// the whole ring, so this is the flash cost knob.
+    #[serde(default = "write_interval_ms_default")]
+    pub write_interval_ms: u32,
+}
+
+fn capacity_bytes_default() -> u32 {
+    64 * 1024
+}
+
+fn write_interval_ms_default() -> u32 {
+    60_000
+}
+
+/// Read the recorder config. Missing, unparseable, or wrong-version →
+/// `None` (do not record).
+pub fn read_config(fs: &dyn LpFs) -> Option<FrameRecorderConfig> {
+    let bytes = fs.read_file(FRAME_RECORDER_CONFIG_PATH.as_path()).ok()?;
+    let config = match lpc_wire::json::from_slice::<FrameRecorderConfig>(&bytes) {
+        Ok(config) => config,
+        Err(error) => {
+            log::warn!("frame recorder: ignoring unparseable /.lp/recorder.json: {error}");
+            return None;
+        }
+    };
+    if config.version != FRAME_RECORDER_CONFIG_VERSION {
+        log::warn!(
+            "frame recorder: ignoring /.lp/recorder.json with unknown version {} (expected {})",
+            config.version,
+            FRAME_RECORDER_CONFIG_VERSION
+        );
+        return None;
+    }
+    Some(config)
+}
+
+/// Start recording on `engine` if the project asks for it; returns the
+/// config in force. Call before the first tick and before panel restore.
+pub fn start(fs: &dyn LpFs, engine: &mut Engine) -> Option<FrameRecorderConfig> {
+    let config = read_config(fs)?;
+    engine.start_recording(config.capacity_bytes as usize);
+    log::info!(
+        "frame recorder: recording into a {} byte ring",
+        config.capacity_bytes
+    );
+    Some(config)
+}
+
+/// Write the running recording to `/.lp/replay.bin`. Best-effort: a write
+/// failure is logged and swallowed.
+pub fn write(fs: &dyn LpFs, engine: &Engine) {
+    let Some(recorder) = engine.recorder() else {
+        return;
+    };
+    if recorder.first_frame() > 0 {
+        log::warn!(
+            "frame recorder: the ring dropped its first {} frames; /.lp/replay.bin cannot be replayed",
+            recorder.first_frame()
+        );
+    }
+    if let Err(error) = fs.write_file(FRAME_RECORDING_PATH.as_path(), &recorder.encode_log()) {
+        log::warn!("frame recorder: failed to write /.lp/replay.bin: {error}");
+    }
+}
+
+/// Replay the project's `/.lp/replay.bin` into a freshly loaded copy of it.
+///
+/// `name` must be the name the project was recorded under — it fixes the
+/// root tree path every recorded scope and node is addressed by. The copy
+/// gets no hardware services (replay stands in for them) and no time
+/// provider; its outputs open on `output_provider`, so pass one nothing
+/// real is listening on.
+pub fn replay(
+    name: &str,
+    fs: Rc<RefCell<dyn LpFs>>,
+    output_provider: Rc<RefCell<dyn OutputProvider>>,
+    graphics: Arc<dyn LpGraphics>,
+) -> Result<ReplayReport, ServerError> {
+    let bytes = fs
+        .borrow()
+        .read_file(FRAME_RECORDING_PATH.as_path())
+        .map_err(|e| ServerError::Filesystem(format!("{FRAME_RECORDING_PATH}: {e}")))?;
+    let log = FrameLog::decode(&bytes)
+        .map_err(|e| ServerError::Serialization(format!("{FRAME_RECORDING_PATH}: {e}")))?;
+
+    let services = build_engine_services(
+        project_root_path(name)?,
+        fs.clone(),
+        output_provider,
+        None,
+        None,
+        None,
+    );
+    let (mut engine, registry) = {
+        let fs_ref = fs.borrow();
+        ProjectLoader::load_from_root(&*fs_ref, services)
+            .map_err(|e| ServerError::Core(format!("Failed to load core project: {e}")))?
+            .into_parts()
+    };
+    engine.set_graphics(Some(graphics));
+    replay_frame_log(&mut engine, &registry, &log)
+        .map_err(|e| ServerError::Core(format!("{FRAME_RECORDING_PATH}: {e}")))
+}
@@ -13,6 +13,7 @@
 pub mod device_identity;
 pub mod error;
 pub mod file_sync;
+pub mod frame_recording;
 pub mod handlers;
 pub mod panel_state;
 pub mod project;
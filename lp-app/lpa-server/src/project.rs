@@ -3,11 +3,15 @@
 extern crate alloc;
 
 use crate::error::ServerError;
+use crate::frame_recording::{self, FrameRecorderConfig};
 use crate::panel_state::{self, PANEL_STATE_WRITE_INTERVAL_MS};
 use crate::server::MemoryStatsFn;
 use alloc::{boxed::Box, format, rc::Rc, string::String, sync::Arc, vec::Vec};
-use core::cell::RefCell;
-use lpc_engine::{ButtonService, Engine, EngineServices, LpGraphics, ProjectLoader, RadioService};
+use core::cell::{Ref, RefCell};
+use lpc_engine::{
+    AssetStreamService, ButtonService, Engine, EngineServices, LpGraphics, ProjectLoader,
+    RadioService,
+};
 use lpc_hardware::HwEndpointSpec;
 use lpc_model::{LpPath, LpPathBuf, TreePath, current_revision};
 use lpc_registry::{ParseCtx, ProjectRegistry};
@@ -20,7 +24,7 @@ use lpc_wire::{
     WireOverlayMutationRequest, WireOverlayMutationResponse, WireOverlayReadResponse,
     WireProjectInventoryReadResponse, WireRemoveNodeRequest, WireRemoveNodeResponse,
 };
-use lpfs::{FsEvent, FsVersion, LpFs};
+use lpfs::{FsError, FsEvent, FsVersion, LpFs};
 
 /// A project instance wrapping one loaded engine.
 pub struct Project {
@@ -62,6 +66,10 @@ pub struct Project {
     /// Writer-store mutation count as of the last write, so an idle
     /// project writes nothing however long it runs.
     panel_state_saved_mutations: u64,
+    /// The frame recorder config, when `/.lp/recorder.json` turned it on.
+    recorder_config: Option<FrameRecorderConfig>,
+    /// Engine-time since `/.lp/replay.bin` was last written.
+    recording_age_ms: u32,
 }
 
 impl Project {
@@ -88,6 +96,7 @@ impl Project {
         backtrace::set_oom_context("project new: engine services");
         let services = build_engine_services(
             root_path,
+            fs.clone(),
             output_provider.clone(),
             time_provider.clone(),
             button_service.clone(),
@@ -107,6 +116,15 @@ impl Project {
         runtime.set_graphics(Some(graphics.clone()));
         log_memory(memory_stats, "project new after graphics");
 
+        // The recorder starts ahead of the panel restore so that the
+        // restored writes are the first events in the log — a replay of a
+        // fresh load then reaches the same state the device booted into.
+        backtrace::set_oom_context("project new: start frame recorder");
+        let recorder_config = {
+            let fs_ref = fs.borrow();
+            frame_recording::start(&*fs_ref, &mut runtime)
+        };
+
         // Panel state comes back BEFORE the first tick, therefore before
         // the first render (panel.md P10): the scarf that was dimmed from
         // a phone must not flash bright on replug, not for one frame.
@@ -137,6 +155,8 @@ impl Project {
             panel_auto_save,
             panel_state_age_ms: 0,
             panel_state_saved_mutations,
+            recorder_config,
+            recording_age_ms: 0,
         };
         log_memory(memory_stats, "project new after wrapper");
         backtrace::clear_oom_context();
@@ -199,6 +219,7 @@ impl Project {
         // gets its panel state written, since a crash loop is exactly
         // when losing the user's dim would hurt most.
         self.persist_panel_state_if_due(delta_ms);
+        self.persist_recording_if_due(delta_ms);
         result
     }
 
@@ -244,6 +265,33 @@ impl Project {
         self.panel_state_age_ms = 0;
     }
 
+    /// Write the frame recording once its write interval has elapsed.
+    fn persist_recording_if_due(&mut self, delta_ms: u32) {
+        let Some(config) = &self.recorder_config else {
+            return;
+        };
+        self.recording_age_ms = self.recording_age_ms.saturating_add(delta_ms);
+        if self.recording_age_ms < config.write_interval_ms {
+            return;
+        }
+        self.flush_recording();
+    }
+
+    /// Write the frame recording now, regardless of the throttle — the
+    /// clean-shutdown flush, alongside [`Self::flush_panel_state`].
+    pub fn flush_recording(&mut self) {
+        if self.recorder_config.is_none() {
+            return;
+        }
+        let runtime = self
+            .runtime
+            .as_ref()
+            .expect("project runtime is only absent while reloading");
+        let fs_ref = self.fs.borrow();
+        frame_recording::write(&*fs_ref, runtime);
+        self.recording_age_ms = 0;
+    }
+
     fn panel_state_mutations(&self) -> u64 {
         self.runtime
             .as_ref()
@@ -629,6 +677,7 @@ impl Project {
         backtrace::set_oom_context("project reload: engine services");
         let services = build_engine_services(
             root_path,
+            self.fs.clone(),
             self.output_provider.clone(),
             self.time_provider.clone(),
             self.button_service.clone(),
@@ -651,6 +700,14 @@ impl Project {
         // before-first-frame rule as `new()`. (`apply_project_changes`
         // does NOT rebuild the Engine, which is why an ordinary edit
         // leaves engaged writers alone and touches no file.)
+        // A rebuilt Engine records afresh: the old log ends at a project
+        // this one no longer is, and could not replay against it.
+        backtrace::set_oom_context("project reload: start frame recorder");
+        self.recorder_config = {
+            let fs_ref = self.fs.borrow();
+            frame_recording::start(&*fs_ref, &mut runtime)
+        };
+        self.recording_age_ms = 0;
         backtrace::set_oom_context("project reload: restore panel state");
         self.panel_auto_save = {
             let fs_ref = self.fs.borrow();
@@ -694,8 +751,9 @@ fn log_memory(memory_stats: Option<MemoryStatsFn>, label: &str) {
     }
 }
 
-fn build_engine_services(
+pub(crate) fn build_engine_services(
     root_path: TreePath,
+    fs: Rc<RefCell<dyn LpFs>>,
     output_provider: Rc<RefCell<dyn OutputProvider>>,
     time_provider: Option<Rc<dyn TimeProvider>>,
     button_service: Option<Rc<dyn ButtonService>>,
@@ -706,9 +764,41 @@ fn build_engine_services(
     services.set_time_provider(time_provider);
     services.set_button_service(button_service);
     services.set_radio_service(radio_service);
+    services.set_asset_stream(Some(Rc::new(SharedAssetStream(fs))));
     services
 }
 
+/// The project fs as the engine's [`AssetStreamService`], so image nodes
+/// decode straight off flash instead of holding whole files.
+///
+/// The borrow is taken per read and never across a frame. A read that finds
+/// the fs mutably borrowed fails rather than panics; the node reports it and
+/// keeps its last frame.
+struct SharedAssetStream(Rc<RefCell<dyn LpFs>>);
+
+impl SharedAssetStream {
+    fn fs(&self) -> Result<Ref<'_, dyn LpFs>, FsError> {
+        self.0
+            .try_borrow()
+            .map_err(|_| FsError::Filesystem(String::from("project fs is busy")))
+    }
+}
+
+impl AssetStreamService for SharedAssetStream {
+    fn file_size(&self, path: &LpPath) -> Result<u64, FsError> {
+        self.fs()?.file_size(path)
+    }
+
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        self.fs()?.read_file_range(path, offset, buf)
+    }
+}
+
 struct SharedOutputProvider(Rc<RefCell<dyn OutputProvider>>);
 
 impl OutputProvider for SharedOutputProvider {
@@ -744,6 +834,10 @@ impl OutputProvider for SharedOutputProvider {
         self.0.borrow().flush()
     }
 
+    fn refresh(&self, now_us: u64) -> Result<(), lpc_hardware::OutputError> {
+        self.0.borrow().refresh(now_us)
+    }
+
     fn hardware_generation(&self) -> u64 {
         self.0.borrow().hardware_generation()
     }
@@ -763,7 +857,7 @@ fn is_project_artifact_path(path: &LpPath) -> bool {
     path != lpc_history::hash::hash_rules::RESERVED_META_DIR && !path.starts_with("/.lp/")
 }
 
-fn project_root_path(name: &str) -> Result<TreePath, ServerError> {
+pub(crate) fn project_root_path(name: &str) -> Result<TreePath, ServerError> {
     let mut sanitized = String::new();
     for c in name.chars() {
         match c {
@@ -178,6 +178,7 @@ impl ProjectManager {
         // the one moment we can beat the ~10 s throttle, so the last few
         // seconds of a gesture are not lost.
         project.flush_panel_state();
+        project.flush_recording();
         let name = project.name();
         self.name_to_handle.remove(name);
 
@@ -192,6 +193,7 @@ impl ProjectManager {
     pub fn unload_all_projects(&mut self) -> Result<(), ServerError> {
         for project in self.projects.values_mut() {
             project.flush_panel_state();
+            project.flush_recording();
         }
         self.projects.clear();
         self.name_to_handle.clear();
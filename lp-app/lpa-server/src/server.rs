@@ -828,11 +828,16 @@ fn graphics_feature(backend_name: &str) -> Option<lpc_model::LpFeature> {
             LpFeature::GfxWgpu => Some("wgpu"),
             LpFeature::NodeButton
             | LpFeature::NodeClock
+            | LpFeature::NodeFilter
+            | LpFeature::NodePalette
             | LpFeature::NodeFluid
+            | LpFeature::NodeImage
             | LpFeature::NodeFixture
+            | LpFeature::NodeParticle
             | LpFeature::NodePlaylist
             | LpFeature::NodeRadio
             | LpFeature::NodeShader
+            | LpFeature::NodeText
             | LpFeature::NodeTexture
             | LpFeature::SvcButton
             | LpFeature::SvcRadioEspnow
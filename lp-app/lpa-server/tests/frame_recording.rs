@@ -0,0 +1,183 @@
+//! Frame recording (`/.lp/recorder.json` → `/.lp/replay.bin`) and host
+//! replay of what a device recorded.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use core::cell::RefCell;
+
+use lp_gfx_lpvm::TargetLpvmGraphics;
+use lpa_server::frame_recording::{self, FRAME_RECORDER_CONFIG_PATH, FRAME_RECORDING_PATH};
+use lpa_server::{LpGraphics, LpServer};
+use lpc_engine::{FrameEvent, FrameLog};
+use lpc_model::{AsLpPath, LpPath, LpPathBuf, LpValue};
+use lpc_shared::output::{MemoryOutputProvider, OutputProvider};
+use lpc_wire::{
+    BindingGraphProbeRequest, BindingGraphProbeResult, WirePanelWriteRequest, WireProjectHandle,
+};
+use lpfs::{LpFs, LpFsMemory, LpFsView};
+
+const NAME: &str = "frame-recording";
+
+#[test]
+fn a_recorded_session_replays_exactly_on_the_host() {
+    let base_fs = project_fs(Some(br#"{"version":1,"write_interval_ms":100}"#));
+    let mut server = build_server(base_fs.clone());
+    let handle = load(&mut server);
+    write_time(&mut server, handle, 4.5);
+    for _ in 0..10 {
+        server.advance_frame(16).expect("tick");
+    }
+    server
+        .project_manager_mut()
+        .unload_all_projects()
+        .expect("unload");
+
+    let bytes = base_fs
+        .borrow()
+        .read_file(project_path().join(".lp").join("replay.bin").as_path())
+        .expect("recording written");
+    let log = FrameLog::decode(&bytes).expect("recording decodes");
+    assert_eq!(log.first_frame, 0);
+    assert_eq!(log.frames.len(), 10);
+    assert!(matches!(
+        log.frames[0].events.as_slice(),
+        [FrameEvent::PanelWrite { channel, value: LpValue::F32(v), .. }]
+            if channel == "time" && *v == 4.5
+    ));
+
+    let project_fs: Rc<RefCell<dyn LpFs>> = Rc::new(RefCell::new(LpFsView::new(
+        base_fs,
+        project_path().as_path(),
+    )));
+    let output: Rc<RefCell<dyn OutputProvider>> =
+        Rc::new(RefCell::new(MemoryOutputProvider::new()));
+    let report = frame_recording::replay(NAME, project_fs, output, graphics()).expect("replay");
+    assert_eq!(report.frames, 10);
+    assert!(
+        report.skipped_events.is_empty(),
+        "{:?}",
+        report.skipped_events
+    );
+    assert!(report.is_exact(), "{:?}", report.divergences);
+}
+
+#[test]
+fn without_a_config_nothing_is_recorded() {
+    let base_fs = project_fs(None);
+    let mut server = build_server(base_fs.clone());
+    let handle = load(&mut server);
+    for _ in 0..10 {
+        server.advance_frame(1_000_000).expect("tick");
+    }
+    let project = server
+        .project_manager()
+        .get_project(handle)
+        .expect("loaded project");
+    assert!(project.engine().recorder().is_none());
+    server
+        .project_manager_mut()
+        .unload_all_projects()
+        .expect("unload");
+    assert!(
+        base_fs
+            .borrow()
+            .read_file(project_path().join(".lp").join("replay.bin").as_path())
+            .is_err()
+    );
+}
+
+#[test]
+fn recording_files_live_in_the_framework_tier() {
+    for path in [FRAME_RECORDER_CONFIG_PATH, FRAME_RECORDING_PATH] {
+        assert!(!lpc_history::hash::is_hashed_path(LpPath::new(path)));
+        assert!(path.starts_with("/.lp/"));
+    }
+}
+
+// ---------------------------------------------------------------------------
+
+fn project_path() -> LpPathBuf {
+    LpPathBuf::from("/projects").join(NAME)
+}
+
+fn project_fs(recorder_config: Option<&[u8]>) -> Rc<RefCell<dyn LpFs>> {
+    let base_fs: Rc<RefCell<dyn LpFs>> = Rc::new(RefCell::new(LpFsMemory::new()));
+    {
+        let fs = base_fs.borrow();
+        let root = project_path();
+        fs.write_file(
+            root.join("project.json").as_path(),
+            b"{\n  \"format\": 8\n}\n",
+        )
+        .expect("write container manifest");
+        fs.write_file(
+            root.join("module.json").as_path(),
+            br#"{"kind":"Module","nodes":{"clock":{"ref":"./clock.json"}}}"#,
+        )
+        .expect("write module");
+        fs.write_file(
+            root.join("clock.json").as_path(),
+            br#"{"kind":"Clock","transport":{"rate":1.0}}"#,
+        )
+        .expect("write clock");
+        if let Some(config) = recorder_config {
+            fs.write_file(root.join(".lp").join("recorder.json").as_path(), config)
+                .expect("write recorder config");
+        }
+    }
+    base_fs
+}
+
+fn load(server: &mut LpServer) -> WireProjectHandle {
+    server.load_project(project_path().as_path()).expect("load")
+}
+
+/// Latch the `time` channel from the panel, through the same request a
+/// phone sends.
+fn write_time(server: &mut LpServer, handle: WireProjectHandle, value: f32) {
+    let project = server
+        .project_manager_mut()
+        .get_project_mut(handle)
+        .expect("loaded project");
+    let (engine, registry) = project.runtime_read_parts();
+    let BindingGraphProbeResult::Graph(graph) = engine.read_project_binding_graph_probe(
+        registry,
+        BindingGraphProbeRequest {
+            include_values: false,
+        },
+    ) else {
+        panic!("expected graph result");
+    };
+    let scope = graph
+        .channels
+        .iter()
+        .find(|channel| channel.name == "time")
+        .expect("clock publishes bus:time")
+        .scope
+        .expect("channels are scoped");
+    project.panel_write(&WirePanelWriteRequest {
+        scope,
+        channel: "time".to_string(),
+        value: LpValue::F32(value),
+        ttl_ms: None,
+    });
+}
+
+fn graphics() -> Arc<dyn LpGraphics> {
+    Arc::new(TargetLpvmGraphics::new(lpa_server::DEVICE_SHADER_FRONTEND))
+}
+
+fn build_server(base_fs: Rc<RefCell<dyn LpFs>>) -> LpServer {
+    LpServer::new(
+        Rc::new(RefCell::new(MemoryOutputProvider::new())),
+        Box::new(LpFsView::new(base_fs, LpPath::new("/"))),
+        "projects".as_path(),
+        None,
+        None,
+        graphics(),
+    )
+}
@@ -975,11 +975,16 @@ mod tests {
                     features: vec![
                         LpFeature::NodeButton,
                         LpFeature::NodeClock,
+                        LpFeature::NodeFilter,
+                        LpFeature::NodePalette,
                         LpFeature::NodeFluid,
                         LpFeature::NodeFixture,
+                        LpFeature::NodeImage,
+                        LpFeature::NodeParticle,
                         LpFeature::NodePlaylist,
                         LpFeature::NodeRadio,
                         LpFeature::NodeShader,
+                        LpFeature::NodeText,
                         LpFeature::NodeTexture,
                         LpFeature::SvcButton,
                         LpFeature::SvcRadioEspnow,
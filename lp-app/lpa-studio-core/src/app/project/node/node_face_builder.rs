@@ -551,6 +551,9 @@ fn shader_panel_controls(sections: &[UiNodeSection]) -> Vec<UiPanelControl> {
             // `seconds` is unbounded time. There is nothing to set: no
             // period, no range, no default. It gets no knob at all.
             Some(SECONDS_SLOT_KIND) => None,
+            // A feedback uniform is a previous frame; its only authoring is
+            // the binding that names which one.
+            Some(FEEDBACK_SLOT_KIND) => None,
             // A palette has no `default` either — its value is the whole
             // `GradientConfig` on the slot's `gradient` option (M4 P3).
             Some(PALETTE_SLOT_KIND) => palette_swatch_control(entry, &rows),
@@ -586,6 +589,8 @@ const PHASOR_SLOT_KIND: &str = "phasor";
 const SECONDS_SLOT_KIND: &str = "seconds";
 /// `ShaderSlotKind::Palette`'s wire tag.
 const PALETTE_SLOT_KIND: &str = "palette";
+/// `ShaderSlotKind::Feedback`'s wire tag.
+const FEEDBACK_SLOT_KIND: &str = "feedback";
 
 /// Widest period a phasor knob reaches when the uniform authors no range:
 /// one hour. The knob sweeps LOG-period, so the slow decades cost no
@@ -22,6 +22,11 @@ pub fn node_kind_slug(kind: NodeKind) -> &'static str {
         NodeKind::Shader => "shader",
         NodeKind::ComputeShader => "compute_shader",
         NodeKind::Fluid => "fluid",
+        NodeKind::Image => "image",
+        NodeKind::Text => "text",
+        NodeKind::Particle => "particle",
+        NodeKind::Filter => "filter",
+        NodeKind::Palette => "palette",
         NodeKind::Playlist => "playlist",
         NodeKind::ControlRadio => "radio",
         NodeKind::Output => "output",
@@ -39,6 +44,11 @@ pub fn node_kind_label(kind: NodeKind) -> &'static str {
         NodeKind::Shader => "Shader",
         NodeKind::ComputeShader => "Compute shader",
         NodeKind::Fluid => "Fluid",
+        NodeKind::Image => "Image",
+        NodeKind::Text => "Text",
+        NodeKind::Particle => "Particle",
+        NodeKind::Filter => "Filter",
+        NodeKind::Palette => "Palette",
         NodeKind::Playlist => "Playlist",
         NodeKind::ControlRadio => "Radio",
         NodeKind::Output => "Output",
@@ -128,6 +138,11 @@ mod tests {
             NodeKind::Shader,
             NodeKind::ComputeShader,
             NodeKind::Fluid,
+            NodeKind::Image,
+            NodeKind::Text,
+            NodeKind::Particle,
+            NodeKind::Filter,
+            NodeKind::Palette,
             NodeKind::Playlist,
             NodeKind::ControlRadio,
             NodeKind::Output,
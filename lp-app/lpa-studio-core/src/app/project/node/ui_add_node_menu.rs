@@ -15,6 +15,11 @@ use super::node_naming::{node_kind_label, node_kind_slug};
 const PICKER_KINDS: &[NodeKind] = &[
     NodeKind::Shader,
     NodeKind::Texture,
+    NodeKind::Image,
+    NodeKind::Text,
+    NodeKind::Particle,
+    NodeKind::Filter,
+    NodeKind::Palette,
     NodeKind::Playlist,
     NodeKind::Module,
     NodeKind::Clock,
@@ -230,7 +235,7 @@ mod tests {
     fn menu_offers_every_kind_in_stable_order() {
         let menu = add_node_menu(&UiAttachTarget::ProjectRoot);
 
-        assert_eq!(menu.entries.len(), 11, "every instantiable kind");
+        assert_eq!(menu.entries.len(), 16, "every instantiable kind");
         assert!(menu.entries.iter().any(|e| e.kind == NodeKind::Module));
         assert_eq!(menu.entries[0].kind, NodeKind::Shader);
         assert_eq!(menu.entries[0].label, "Shader");
@@ -264,9 +269,14 @@ mod tests {
         let features = [
             LpFeature::NodeButton,
             LpFeature::NodeClock,
+            LpFeature::NodeFilter,
+            LpFeature::NodePalette,
             LpFeature::NodeFixture,
+            LpFeature::NodeImage,
+            LpFeature::NodeParticle,
             LpFeature::NodePlaylist,
             LpFeature::NodeShader,
+            LpFeature::NodeText,
             LpFeature::NodeTexture,
             LpFeature::GfxLpvm,
         ];
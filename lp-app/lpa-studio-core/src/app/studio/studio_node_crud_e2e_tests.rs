@@ -45,6 +45,11 @@ fn create_every_picker_kind_lands_in_tree_and_on_disk() {
     let cases: &[(NodeKind, &str, &str)] = &[
         (NodeKind::Shader, "shader", "shader"),
         (NodeKind::Texture, "texture", "texture"),
+        (NodeKind::Image, "image", "image"),
+        (NodeKind::Text, "text", "text"),
+        (NodeKind::Particle, "particle", "particle"),
+        (NodeKind::Filter, "filter", "filter"),
+        (NodeKind::Palette, "palette", "palette"),
         (NodeKind::Playlist, "playlist", "playlist"),
         // An embedded module (settled D-C): an empty child def whose node
         // introduces a scope, creatable like anything else.
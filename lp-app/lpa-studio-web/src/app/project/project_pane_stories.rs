@@ -359,9 +359,14 @@ fn gapped_device_features() -> Vec<LpFeature> {
     vec![
         LpFeature::NodeButton,
         LpFeature::NodeClock,
+        LpFeature::NodeFilter,
+        LpFeature::NodePalette,
         LpFeature::NodeFixture,
+        LpFeature::NodeImage,
+        LpFeature::NodeParticle,
         LpFeature::NodePlaylist,
         LpFeature::NodeShader,
+        LpFeature::NodeText,
         LpFeature::NodeTexture,
         LpFeature::GfxLpvm,
         LpFeature::SvcButton,
@@ -1140,11 +1140,16 @@ fn all_capable_features() -> Vec<LpFeature> {
     vec![
         LpFeature::NodeButton,
         LpFeature::NodeClock,
+        LpFeature::NodeFilter,
+        LpFeature::NodePalette,
         LpFeature::NodeFluid,
         LpFeature::NodeFixture,
+        LpFeature::NodeImage,
+        LpFeature::NodeParticle,
         LpFeature::NodePlaylist,
         LpFeature::NodeRadio,
         LpFeature::NodeShader,
+        LpFeature::NodeText,
         LpFeature::NodeTexture,
         LpFeature::SvcButton,
         LpFeature::SvcRadioEspnow,
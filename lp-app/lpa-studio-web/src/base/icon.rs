@@ -4,8 +4,9 @@ use dioxus_icons::lucide::{
     CircleAlert, CircleDot, CircleMinus, CircleQuestionMark, Clock, Copy, Cpu, Download, Droplet,
     Ellipsis, Eraser, Eye, Flag, FlaskConical, Folder, Funnel, Hash, Image, Info, Layers,
     Lightbulb, Link2, Link2Off, ListMusic, Locate, LocateFixed, Maximize2, Minimize2, MonitorPlay,
-    MousePointerClick, Pencil, Play, Plus, Radio, Route, Save, Settings, Sparkles,
-    SquareArrowRight, SquareTerminal, Trash2, TriangleAlert, Undo2, Upload, Usb, Waypoints, X, Zap,
+    MousePointerClick, Palette, Pencil, Play, Plus, Radio, Route, Save, Settings, Sparkles,
+    SquareArrowRight, SquareTerminal, Trash2, TriangleAlert, Type, Undo2, Upload, Usb, Waypoints,
+    X, Zap,
 };
 
 #[component]
@@ -51,6 +52,11 @@ pub fn StudioIcon(name: StudioIconName, size: u32) -> Element {
             NodeKindIcon::Playlist => rsx! { ListMusic { size } },
             NodeKindIcon::Module => rsx! { Folder { size } },
             NodeKindIcon::Texture => rsx! { Image { size } },
+            NodeKindIcon::Image => rsx! { Image { size } },
+            NodeKindIcon::Text => rsx! { Type { size } },
+            NodeKindIcon::Particle => rsx! { Sparkles { size } },
+            NodeKindIcon::Filter => rsx! { Layers { size } },
+            NodeKindIcon::Palette => rsx! { Palette { size } },
             NodeKindIcon::Radio => rsx! { Radio { size } },
             NodeKindIcon::Button => rsx! { MousePointerClick { size } },
             NodeKindIcon::Fluid => rsx! { Droplet { size } },
@@ -212,6 +218,11 @@ pub enum NodeKindIcon {
     Playlist,
     Module,
     Texture,
+    Image,
+    Text,
+    Particle,
+    Filter,
+    Palette,
     Radio,
     Button,
     Fluid,
@@ -234,6 +245,11 @@ pub fn node_kind_icon(kind_label: &str) -> StudioIconName {
         "Playlist" | "playlist" => NodeKindIcon::Playlist,
         "Module" | "module" => NodeKindIcon::Module,
         "Texture" | "texture" => NodeKindIcon::Texture,
+        "Image" | "image" => NodeKindIcon::Image,
+        "Text" | "text" => NodeKindIcon::Text,
+        "Particle" | "particle" => NodeKindIcon::Particle,
+        "Filter" | "filter" => NodeKindIcon::Filter,
+        "Palette" | "palette" => NodeKindIcon::Palette,
         "Control Radio" | "Radio" | "radio" => NodeKindIcon::Radio,
         "Button" | "button" => NodeKindIcon::Button,
         "Fluid" | "fluid" => NodeKindIcon::Fluid,
@@ -255,6 +271,11 @@ mod tests {
         for slug in [
             "shader",
             "texture",
+            "image",
+            "text",
+            "particle",
+            "filter",
+            "palette",
             "playlist",
             "clock",
             "fixture",
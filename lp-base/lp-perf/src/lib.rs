@@ -16,6 +16,8 @@ pub const EVENT_FRAME: &str = "frame";
 pub const EVENT_SHADER_COMPILE: &str = "shader-compile";
 pub const EVENT_SHADER_LINK: &str = "shader-link";
 pub const EVENT_PROJECT_LOAD: &str = "project-load";
+pub const EVENT_NODE_TICK: &str = "node-tick";
+pub const EVENT_NODE_RENDER: &str = "node-render";
 
 #[macro_export]
 macro_rules! emit_begin {
@@ -5,6 +5,7 @@ use crate::{
     LpFs,
     fs_event::FsEventKind,
     fs_event::{FsEvent, FsVersion},
+    lp_fs::copy_range,
     lp_fs_view::LpFsView,
 };
 use crate::{LpPath, LpPathBuf};
@@ -225,6 +226,21 @@ impl LpFs for LpFsMemory {
             .ok_or_else(|| FsError::NotFound(normalized.as_str().to_string()))
     }
 
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        let normalized = path.to_path_buf();
+        self.validate_path(normalized.as_path())?;
+        self.files
+            .borrow()
+            .get(&normalized)
+            .map(|bytes| copy_range(bytes, offset, buf))
+            .ok_or_else(|| FsError::NotFound(normalized.as_str().to_string()))
+    }
+
     fn append_file(&self, path: &LpPath, data: &[u8]) -> Result<(), FsError> {
         // Native append: extend the stored buffer in place (no whole-file copy)
         self.validate_path(path)?;
@@ -502,6 +518,41 @@ mod tests {
         assert_eq!(fs.read_file("/test.txt".as_path()).unwrap(), b"hello");
     }
 
+    #[test]
+    fn test_read_file_range_windows_the_file() {
+        let fs = LpFsMemory::new();
+        fs.write_file("/a.bin".as_path(), b"0123456789").unwrap();
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 3, &mut buf).unwrap(),
+            4
+        );
+        assert_eq!(&buf, b"3456");
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 8, &mut buf).unwrap(),
+            2
+        );
+        assert_eq!(&buf[..2], b"89");
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 99, &mut buf)
+                .unwrap(),
+            0
+        );
+        assert!(
+            fs.read_file_range("/missing".as_path(), 0, &mut buf)
+                .is_err()
+        );
+
+        // a chrooted view delegates to the parent's range read
+        fs.write_file("/sub/b.bin".as_path(), b"abcdef").unwrap();
+        let view = fs.chroot("/sub".as_path()).unwrap();
+        let n = view
+            .borrow()
+            .read_file_range("/b.bin".as_path(), 2, &mut buf)
+            .unwrap();
+        assert_eq!(&buf[..n], b"cdef");
+    }
+
     #[test]
     fn test_append_file_extends_and_creates() {
         let fs = LpFsMemory::new();
@@ -230,6 +230,30 @@ impl LpFs for LpFsStd {
             .map_err(|e| FsError::Filesystem(format!("Failed to read file {full_path:?}: {e}")))
     }
 
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let full_path = self.resolve_and_validate(path)?;
+        let read_error = |e: std::io::Error| {
+            FsError::Filesystem(format!("Failed to read file {full_path:?}: {e}"))
+        };
+        let mut file = fs::File::open(&full_path).map_err(read_error)?;
+        file.seek(SeekFrom::Start(offset)).map_err(read_error)?;
+        let mut filled = 0;
+        while filled < buf.len() {
+            match file.read(&mut buf[filled..]).map_err(read_error)? {
+                0 => break,
+                n => filled += n,
+            }
+        }
+        Ok(filled)
+    }
+
     fn write_file(&self, path: &LpPath, data: &[u8]) -> Result<(), FsError> {
         let full_path = self.get_path(path)?;
         // Create parent directory if it doesn't exist
@@ -502,6 +526,29 @@ mod tests {
         assert_eq!(fs.read_file("/log.txt".as_path()).unwrap(), b"one two");
     }
 
+    #[test]
+    fn test_read_file_range_seeks() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.bin"), b"0123456789").unwrap();
+        let fs = LpFsStd::new(temp_dir.path().to_path_buf());
+
+        let mut buf = [0u8; 4];
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 5, &mut buf).unwrap(),
+            4
+        );
+        assert_eq!(&buf, b"5678");
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 9, &mut buf).unwrap(),
+            1
+        );
+        assert_eq!(
+            fs.read_file_range("/a.bin".as_path(), 20, &mut buf)
+                .unwrap(),
+            0
+        );
+    }
+
     #[test]
     fn test_path_validation_prevents_escape() {
         let temp_dir = TempDir::new().unwrap();
@@ -61,6 +61,26 @@ pub trait LpFs {
         Ok(self.read_file(path)?.len() as u64)
     }
 
+    /// Read up to `buf.len()` bytes starting at byte `offset` of a file.
+    ///
+    /// Path is relative to project root.
+    ///
+    /// Returns the number of bytes read; fewer than `buf.len()` (zero at or
+    /// past the end) means the file ended. The default implementation reads
+    /// the whole file; backends that can seek (in-memory maps, `std::fs`,
+    /// LittleFS) should override it — streaming decoders (image playback)
+    /// read assets in small windows because whole files won't fit in device
+    /// RAM.
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        let data = self.read_file(path)?;
+        Ok(copy_range(&data, offset, buf))
+    }
+
     /// Check if a file exists in the filesystem
     ///
     /// Path is relative to project root.
@@ -153,3 +173,14 @@ pub trait LpFs {
     /// Each change is assigned the next version number.
     fn record_changes(&mut self, changes: alloc::vec::Vec<FsEvent>);
 }
+
+/// Copy the bytes of `data` starting at `offset` into `buf`; the shared
+/// slicing behind [`LpFs::read_file_range`] for whole-buffer backends.
+pub(crate) fn copy_range(data: &[u8], offset: u64, buf: &mut [u8]) -> usize {
+    let start = usize::try_from(offset)
+        .unwrap_or(usize::MAX)
+        .min(data.len());
+    let n = buf.len().min(data.len() - start);
+    buf[..n].copy_from_slice(&data[start..start + n]);
+    n
+}
@@ -141,6 +141,21 @@ impl LpFs for LpFsView {
         self.parent.borrow().file_size(parent_lp_path)
     }
 
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        self.validate_path(path)?;
+        let normalized = path.to_path_buf();
+        let parent_path = self.parent_path(normalized.as_str());
+        let parent_lp_path = LpPath::new(parent_path.as_str());
+        self.parent
+            .borrow()
+            .read_file_range(parent_lp_path, offset, buf)
+    }
+
     fn file_exists(&self, path: &LpPath) -> Result<bool, FsError> {
         // Validate input is absolute (contract: LpFs only accepts absolute paths)
         self.validate_path(path)?;
@@ -474,6 +474,11 @@ mod tests {
             "Shader",
             "ComputeShader",
             "Fluid",
+            "Image",
+            "Text",
+            "Particle",
+            "Filter",
+            "Palette",
             "Playlist",
             "ControlRadio",
             "Output",
@@ -48,7 +48,7 @@ pub struct Args {
     /// buffer. Inspecting its LPIR / asm is essential for debugging
     /// per-pixel iteration bugs.
     ///
-    /// Values: `none`, `r16`, `rgb16`, `rgba16`, `all`. Default: `rgba16`.
+    /// Values: `none`, `r8`, `r16`, `rgb8`, `rgb16`, `rgba16`, `all`. Default: `rgba16`.
     /// If the input has no `render` function the flag is silently ignored.
     #[arg(long, default_value = "rgba16", value_name = "FORMAT")]
     pub render_texture: String,
@@ -87,16 +87,20 @@ impl Args {
     pub fn render_texture_formats(&self) -> Result<Vec<TextureStorageFormat>, String> {
         match self.render_texture.as_str() {
             "none" => Ok(Vec::new()),
+            "r8" => Ok(vec![TextureStorageFormat::R8Unorm]),
             "r16" => Ok(vec![TextureStorageFormat::R16Unorm]),
+            "rgb8" => Ok(vec![TextureStorageFormat::Rgb8Unorm]),
             "rgb16" => Ok(vec![TextureStorageFormat::Rgb16Unorm]),
             "rgba16" => Ok(vec![TextureStorageFormat::Rgba16Unorm]),
             "all" => Ok(vec![
+                TextureStorageFormat::R8Unorm,
                 TextureStorageFormat::R16Unorm,
+                TextureStorageFormat::Rgb8Unorm,
                 TextureStorageFormat::Rgb16Unorm,
                 TextureStorageFormat::Rgba16Unorm,
             ]),
             other => Err(format!(
-                "invalid --render-texture value {other:?}; expected one of: none, r16, rgb16, rgba16, all"
+                "invalid --render-texture value {other:?}; expected one of: none, r8, r16, rgb8, rgb16, rgba16, all"
             )),
         }
     }
@@ -52,8 +52,9 @@ pub fn collect_fa_data(
         // Lower and compile
         let lower_opts = LowerOpts {
             float_mode,
-            // Match device codegen defaults (fuel checks on).
+            // Match device codegen defaults (fuel checks on, inline divide).
             fuel: true,
+            inline_q32_fdiv: true,
         };
         let lowered = lower_ops(func, ir, &module_abi, &lower_opts)
             .map_err(|e| anyhow::anyhow!("lower: {e:?}"))?;
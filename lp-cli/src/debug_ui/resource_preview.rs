@@ -160,6 +160,24 @@ pub(crate) fn render_texture_payload_preview(
                 .map(|chunk| [chunk[1], chunk[3], chunk[5]]),
             128,
         ),
+        WireTextureFormat::Rgb16 => render_rgb_swatches(
+            ui,
+            bytes
+                .chunks_exact(6)
+                .map(|chunk| [chunk[1], chunk[3], chunk[5]]),
+            128,
+        ),
+        WireTextureFormat::Rgb8 => render_rgb_swatches(
+            ui,
+            bytes
+                .chunks_exact(3)
+                .map(|chunk| [chunk[0], chunk[1], chunk[2]]),
+            128,
+        ),
+        WireTextureFormat::R16 => {
+            render_rgb_swatches(ui, bytes.chunks_exact(2).map(|chunk| [chunk[1]; 3]), 128)
+        }
+        WireTextureFormat::R8 => render_rgb_swatches(ui, bytes.iter().map(|&v| [v; 3]), 128),
     }
 }
 
@@ -170,21 +188,37 @@ fn texture_rgb8_for_display(
     bytes: &[u8],
 ) -> Option<Vec<u8>> {
     let pixels = width.checked_mul(height)? as usize;
-    match format {
-        WireTextureFormat::Srgb8 => (bytes.len() == pixels.checked_mul(3)?).then(|| bytes.to_vec()),
-        WireTextureFormat::Rgba16 => {
-            if bytes.len() != pixels.checked_mul(8)? {
-                return None;
-            }
-            let mut out = Vec::with_capacity(pixels * 3);
-            for px in bytes.chunks_exact(8) {
-                out.push(linear_unorm16_to_srgb8(u16::from_le_bytes([px[0], px[1]])));
-                out.push(linear_unorm16_to_srgb8(u16::from_le_bytes([px[2], px[3]])));
-                out.push(linear_unorm16_to_srgb8(u16::from_le_bytes([px[4], px[5]])));
-            }
-            Some(out)
+    if format == WireTextureFormat::Srgb8 {
+        return (bytes.len() == pixels.checked_mul(3)?).then(|| bytes.to_vec());
+    }
+    // Linear formats: (channels, bytes per channel); one channel is grey.
+    let (channels, channel_bytes) = match format {
+        WireTextureFormat::Rgba16 => (4, 2),
+        WireTextureFormat::Rgb16 => (3, 2),
+        WireTextureFormat::Rgb8 => (3, 1),
+        WireTextureFormat::R16 => (1, 2),
+        WireTextureFormat::R8 => (1, 1),
+        WireTextureFormat::Srgb8 => unreachable!("handled above"),
+    };
+    let pixel_bytes = channels * channel_bytes;
+    if bytes.len() != pixels.checked_mul(pixel_bytes)? {
+        return None;
+    }
+    let lane = |px: &[u8], channel: usize| -> u16 {
+        let channel = if channels == 1 { 0 } else { channel };
+        if channel_bytes == 2 {
+            u16::from_le_bytes([px[channel * 2], px[channel * 2 + 1]])
+        } else {
+            u16::from(px[channel]) * 257
+        }
+    };
+    let mut out = Vec::with_capacity(pixels * 3);
+    for px in bytes.chunks_exact(pixel_bytes) {
+        for channel in 0..3 {
+            out.push(linear_unorm16_to_srgb8(lane(px, channel)));
         }
     }
+    Some(out)
 }
 
 fn linear_unorm16_to_srgb8(value: u16) -> u8 {
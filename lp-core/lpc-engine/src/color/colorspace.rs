@@ -253,6 +253,31 @@ fn oklab_to_linear_srgb(lab: [f32; 3]) -> [f32; 3] {
     ]
 }
 
+/// Linear sRGB → Oklab, the inverse of [`to_linear_srgb`] for
+/// [`Colorspace::Oklab`].
+///
+/// For mixing palettes perceptually: two canonical colors converted here,
+/// lerped, and handed back as Oklab stops fade without the muddy midpoint a
+/// linear-light mix gives. Same published constants as the forward
+/// direction; the cube root is taken around the sign so out-of-gamut input
+/// survives the round trip.
+#[must_use]
+pub fn linear_srgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let l = 0.412_221_46 * rgb[0] + 0.536_332_55 * rgb[1] + 0.051_445_995 * rgb[2];
+    let m = 0.211_903_5 * rgb[0] + 0.680_699_5 * rgb[1] + 0.107_396_96 * rgb[2];
+    let s = 0.088_302_46 * rgb[0] + 0.281_718_85 * rgb[1] + 0.629_978_7 * rgb[2];
+
+    let l_ = libm::cbrtf(l);
+    let m_ = libm::cbrtf(m);
+    let s_ = libm::cbrtf(s);
+
+    [
+        0.210_454_26 * l_ + 0.793_617_8 * m_ - 0.004_072_047 * s_,
+        1.977_998_5 * l_ - 2.428_592_2 * m_ + 0.450_593_7 * s_,
+        0.025_904_037 * l_ + 0.782_771_77 * m_ - 0.808_675_77 * s_,
+    ]
+}
+
 fn abs(value: f32) -> f32 {
     if value < 0.0 { -value } else { value }
 }
@@ -359,6 +384,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn oklab_round_trips_through_linear_srgb() {
+        for rgb in [
+            [0.0, 0.0, 0.0],
+            [1.0, 1.0, 1.0],
+            [0.8, 0.1, 0.3],
+            [0.05, 0.6, 0.9],
+        ] {
+            close(
+                to_linear_srgb(Colorspace::Oklab, linear_srgb_to_oklab(rgb)),
+                rgb,
+                1e-4,
+            );
+        }
+        // White is L = 1 with no chroma.
+        close(linear_srgb_to_oklab([1.0, 1.0, 1.0]), [1.0, 0.0, 0.0], 1e-4);
+    }
+
     #[test]
     fn step_holds_the_segment_start_at_every_position() {
         let from = [1.0, 0.0, 0.0];
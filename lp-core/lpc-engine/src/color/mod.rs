@@ -14,8 +14,9 @@
 
 pub mod colorspace;
 pub mod gradient_bake;
+pub mod palette_eval;
 
-pub use colorspace::{interpolate_in_space, to_linear_srgb};
+pub use colorspace::{interpolate_in_space, linear_srgb_to_oklab, to_linear_srgb};
 pub use gradient_bake::{
     PALETTE_BAKE_BYTES, PALETTE_BAKE_FORMAT, PALETTE_BAKE_WIDTH, bake_gradient_into,
     bake_gradient_mix_into, sample_gradient,
@@ -1,7 +1,7 @@
 //! Turning a [`GradientConfig`] and one phasor position into the bake a
 //! palette uniform shows this tick.
 //!
-//! The counterpart of [`phasor_eval`](super::phasor_eval): the timebase store
+//! The counterpart of the shader node's `phasor_eval`: the timebase store
 //! answers the raw wrapped ramp, and everything about *where in the cycle*
 //! that puts the palette is decided here, as a pure function of φ. Nothing in
 //! this module reads a clock, keeps state, or allocates.
@@ -144,7 +144,7 @@ pub fn palette_cycle_gradients<'a>(
 /// The position a palette holds before any timebase has advanced it — frame
 /// 0, and the fallback whenever no time product resolves.
 ///
-/// Mirrors [`phasor_frame_zero`](super::phasor_eval::phasor_frame_zero):
+/// Mirrors the shader node's `phasor_frame_zero`:
 /// deterministic, never a panic, and the honest answer is the start of the
 /// first cycle.
 #[must_use]
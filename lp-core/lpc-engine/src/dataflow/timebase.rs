@@ -627,6 +627,24 @@ impl TimebaseStore {
         dropped
     }
 
+    /// Move `from`'s timebase, phasors and all, to `to`.
+    ///
+    /// For a clock rebuilt by a hot reload: the new runtime has a new
+    /// `NodeId`, and without this the next sweep would drop every phasor
+    /// riding on the old one. A no-op (returning `false`) when `from` has no
+    /// timebase or `to` already has one — the new clock produced first, and
+    /// its timebase is the truer one.
+    pub fn rekey(&mut self, from: NodeId, to: NodeId) -> bool {
+        if from == to || self.entries.contains_key(&to) {
+            return false;
+        }
+        let Some(entry) = self.entries.remove(&from) else {
+            return false;
+        };
+        self.entries.insert(to, entry);
+        true
+    }
+
     /// The store's own monotonic tick (advance-once and despawn clock).
     #[must_use]
     pub fn tick(&self) -> u32 {
@@ -6,12 +6,12 @@ use alloc::rc::Rc;
 use alloc::string::ToString;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
-use lp_collection::VecSet;
+use lp_collection::{VecMap, VecSet};
 use lpc_model::{
-    ChannelName, ControlProduct, NodeDef, NodeDefLocation, NodeDefState, NodeId, Revision,
-    SlotAccess, SlotAccessor, SlotData, SlotDirection, SlotMerge, SlotPath, SlotPathSegment,
-    SlotSemantics, SlotShapeLookup, SlotShapeRegistry, SlotShapeView, TreePath, WithRevision,
-    advance_revision, lookup_slot_data_and_shape,
+    ChannelName, ControlProduct, NodeDef, NodeDefLocation, NodeDefState, NodeId, NodeUseLocation,
+    Revision, SlotAccess, SlotAccessor, SlotData, SlotDirection, SlotMerge, SlotPath,
+    SlotPathSegment, SlotSemantics, SlotShapeLookup, SlotShapeRegistry, SlotShapeView, TreePath,
+    WithRevision, advance_revision, lookup_slot_data_and_shape,
 };
 use lpc_registry::ProjectRegistry;
 use lpc_shared::time::TimeProvider;
@@ -25,8 +25,8 @@ use crate::dataflow::resolver::{
 use crate::node::RuntimeNodeEntry;
 use crate::node::catch_node_panic::catch_node_panic_framed;
 use crate::node::{
-    ControlRenderContext, ControlRenderServices, NodeCall, NodeCallKey, NodeError,
-    NodeResourceInitContext, NodeRuntime, ProduceResult, RenderContext, TickContext,
+    ControlRenderContext, ControlRenderServices, DegradeMode, MigratedState, NodeCall, NodeCallKey,
+    NodeError, NodeResourceInitContext, NodeRuntime, ProduceResult, RenderContext, TickContext,
     VisualRenderServices,
 };
 use crate::node::{NodeEntryState, RuntimeNodeTree};
@@ -38,8 +38,10 @@ use crate::products::visual::{
 use crate::resource::{RuntimeBufferId, RuntimeBufferStore};
 use lp_gfx::{LpGraphics, TextureHandle};
 
+use super::frame_log::FrameEvent;
+use super::node_budget::{AuthoredBudget, NodeBudgetStore, budget_status, is_budget_status};
 use super::{ButtonService, EngineError, EngineServices, ProjectRuntimeIndex, RadioService};
-use super::{FrameNum, FrameTime};
+use super::{FrameNum, FrameRecorder, FrameTime};
 
 /// Conventional demand input used by the M2 engine slice.
 #[cfg(test)]
@@ -71,6 +73,10 @@ pub struct Engine {
     /// per-uniform phasor read through node dispatch would put the
     /// resolver's heaviest machinery on the hottest new path.
     timebases: crate::dataflow::timebase::TimebaseStore,
+    /// Per-node CPU meters and the degradation levels they drive
+    /// (`engine::node_budget`). A side store for the same reason: meters and
+    /// levels must not reset on every project edit.
+    node_budgets: NodeBudgetStore,
     /// Device-level safe-mode output ceiling, Q16 (`None` = no clamp).
     ///
     /// DEVICE state, not project data: set by the embedder (firmware, from a
@@ -79,6 +85,13 @@ pub struct Engine {
     /// not be editable by the thing that broke it. Composed into every
     /// fixture's power scale via `min` in the fixture render.
     safe_output_clamp_q16: Option<u32>,
+    /// The flight recorder, while one is running ([`Self::start_recording`]).
+    recorder: Option<FrameRecorder>,
+    /// Runtime state exported by a hot reload whose use location came back
+    /// without a live runtime (its def entered error). Held until the
+    /// location lives again or leaves the project; see
+    /// `apply_project_changes`.
+    parked_states: Vec<(NodeUseLocation, MigratedState)>,
     /// The tree shape and resolver epoch as of the last tick, so that a
     /// structural change that forgot to invalidate resolution is caught here
     /// rather than by someone noticing a stale value on a device.
@@ -108,7 +121,10 @@ impl Engine {
             graphics: None,
             panel_writers: crate::dataflow::panel_writers::PanelWriterStore::new(),
             timebases: crate::dataflow::timebase::TimebaseStore::new(),
+            node_budgets: NodeBudgetStore::new(),
             safe_output_clamp_q16: None,
+            recorder: None,
+            parked_states: Vec::new(),
             #[cfg(debug_assertions)]
             last_structural_check: None,
         }
@@ -300,6 +316,14 @@ impl Engine {
         // Momentary liveness (panel.md P14): the deadline is an engine-time
         // instant; renewal is just another write with a fresh TTL.
         let expires_at_ms = ttl_ms.map(|ttl| self.frame_time.total_ms as u64 + u64::from(ttl));
+        self.record_control(|tree| {
+            Some(FrameEvent::PanelWrite {
+                scope: tree.scope_persist_path(scope)?,
+                channel: channel.0.clone(),
+                value: value.clone(),
+                ttl_ms,
+            })
+        });
         self.panel_writers
             .set(scope, channel, value, self.revision, expires_at_ms);
         self.resolver.invalidate_structure();
@@ -308,6 +332,7 @@ impl Engine {
     /// Clear every engaged writer everywhere — sink scopes included
     /// (settled P-Q4). Returns the count.
     pub fn panel_clear_all(&mut self) -> usize {
+        self.record_control(|_| Some(FrameEvent::PanelClearAll));
         let cleared = self.panel_writers.clear_all();
         if cleared > 0 {
             self.resolver.invalidate_structure();
@@ -322,6 +347,12 @@ impl Engine {
         scope: crate::node::ScopeRef,
         channel: &lpc_model::ChannelName,
     ) -> bool {
+        self.record_control(|tree| {
+            Some(FrameEvent::PanelClear {
+                scope: tree.scope_persist_path(scope)?,
+                channel: channel.0.clone(),
+            })
+        });
         let cleared = self.panel_writers.clear(scope, channel);
         if cleared {
             self.resolver.invalidate_structure();
@@ -331,6 +362,11 @@ impl Engine {
 
     /// Clear every engaged writer in `scope`; returns the count.
     pub fn panel_clear_scope(&mut self, scope: crate::node::ScopeRef) -> usize {
+        self.record_control(|tree| {
+            Some(FrameEvent::PanelClearScope {
+                scope: tree.scope_persist_path(scope)?,
+            })
+        });
         let cleared = self.panel_writers.clear_scope(scope);
         if cleared > 0 {
             self.resolver.invalidate_structure();
@@ -349,6 +385,28 @@ impl Engine {
         &self.timebases
     }
 
+    /// Per-node costs, authored budgets and active degradations (probes,
+    /// tests).
+    pub fn node_budgets(&self) -> &NodeBudgetStore {
+        &self.node_budgets
+    }
+
+    /// Replace the authored budgets; the loader re-derives them from module
+    /// defs whenever it rebuilds bindings.
+    pub(crate) fn set_authored_budgets(&mut self, authored: VecMap<NodeId, AuthoredBudget>) {
+        self.node_budgets.set_authored(authored);
+    }
+
+    /// Carry a rebuilt clock's timebase over to its replacement's id.
+    pub(crate) fn rekey_timebase(&mut self, from: NodeId, to: NodeId) -> bool {
+        self.timebases.rekey(from, to)
+    }
+
+    /// Hot-reload state waiting for its use location to come back.
+    pub(crate) fn parked_states_mut(&mut self) -> &mut Vec<(NodeUseLocation, MigratedState)> {
+        &mut self.parked_states
+    }
+
     /// Mutable timebase access, so a test can stand in for the consumer that
     /// P3 will add. Not public: outside the engine, a timebase is read-only
     /// — only a producing node may publish one, and only a tick may advance
@@ -437,9 +495,17 @@ impl Engine {
         match entry.state.get_mut() {
             NodeEntryState::Alive(runtime) => runtime
                 .handle_command(command, time_s)
-                .map_err(|e| EngineError::node(node, e)),
-            _ => Err(EngineError::NotAlive(node)),
+                .map_err(|e| EngineError::node(node, e))?,
+            _ => return Err(EngineError::NotAlive(node)),
         }
+        // Only accepted commands are recorded: a refused one changed nothing.
+        self.record_control(|tree| {
+            Some(FrameEvent::NodeCommand {
+                node: alloc::format!("{}", tree.get(node)?.path),
+                command: command.clone(),
+            })
+        });
+        Ok(())
     }
 
     pub fn runtime_output_sink_buffer_id(&self, node_id: NodeId) -> Option<RuntimeBufferId> {
@@ -521,10 +587,93 @@ impl Engine {
                 })?;
             Ok(())
         })();
+        // A failed tick is still a frame: its inputs were consumed and its
+        // outputs are whatever the failure left, which replay must match.
+        if self.recorder.is_some() {
+            let checksum = self.output_checksum();
+            if let Some(recorder) = self.recorder.as_mut() {
+                recorder.finish_frame(delta_ms, checksum);
+            }
+        }
         lp_perf::emit_end!(lp_perf::EVENT_FRAME);
         result
     }
 
+    /// Start recording every frame into a ring of `capacity_bytes`
+    /// ([`FrameRecorder`]), replacing any recording already running.
+    ///
+    /// Start it before the first tick — and before restoring panel state, so
+    /// the restored writes are part of the log. Button and radio inputs
+    /// opened before this call are not recorded.
+    pub fn start_recording(&mut self, capacity_bytes: usize) {
+        self.stop_recording();
+        let mut recorder = FrameRecorder::new(capacity_bytes);
+        recorder.wrap_services(&mut self.services);
+        self.recorder = Some(recorder);
+    }
+
+    /// Stop recording and hand back the recorder with what it holds.
+    pub fn stop_recording(&mut self) -> Option<FrameRecorder> {
+        let mut recorder = self.recorder.take()?;
+        recorder.unwrap_services(&mut self.services);
+        Some(recorder)
+    }
+
+    /// The running recorder, if any.
+    pub fn recorder(&self) -> Option<&FrameRecorder> {
+        self.recorder.as_ref()
+    }
+
+    /// 32-bit FNV-1a over every published output buffer, in node order.
+    ///
+    /// This is the per-frame checksum the recorder stores and replay
+    /// compares: it covers exactly the bytes the outputs pushed, so two runs
+    /// agree on it precisely when they lit the same lamps.
+    pub fn output_checksum(&self) -> u32 {
+        const OFFSET_BASIS: u32 = 0x811c_9dc5;
+        const PRIME: u32 = 0x0100_0193;
+        let mut hash = OFFSET_BASIS;
+        let mut feed = |bytes: &[u8]| {
+            for &byte in bytes {
+                hash = (hash ^ u32::from(byte)).wrapping_mul(PRIME);
+            }
+        };
+        for entry in self.tree.entries() {
+            let NodeEntryState::Alive(node) = entry.state.value() else {
+                continue;
+            };
+            let Some(buffer) = node
+                .runtime_output_sink_buffer_id()
+                .and_then(|id| self.runtime_buffers.get(id))
+            else {
+                continue;
+            };
+            let bytes = &buffer.value().bytes;
+            feed(&entry.id.0.to_le_bytes());
+            feed(&(bytes.len() as u32).to_le_bytes());
+            feed(bytes);
+        }
+        hash
+    }
+
+    /// Append a control event to the running recording, if there is one.
+    ///
+    /// `event` only runs while recording, so an engine that is not recording
+    /// builds no strings for it. It returns `None` when the addressed scope or
+    /// node has no stable path, which cannot be replayed and is only logged.
+    fn record_control(
+        &mut self,
+        event: impl FnOnce(&RuntimeNodeTree<Box<dyn NodeRuntime>>) -> Option<FrameEvent>,
+    ) {
+        let Some(recorder) = self.recorder.as_mut() else {
+            return;
+        };
+        match event(&self.tree) {
+            Some(event) => recorder.record_control(event),
+            None => log::warn!("frame recorder: control has no stable address; not recorded"),
+        }
+    }
+
     /// Broadcast memory pressure to every alive node.
     ///
     /// ⚠️ Only call at a safe point — a moment where no render borrow into any
@@ -539,6 +688,11 @@ impl Engine {
         level: crate::node::PressureLevel,
     ) -> Result<(), EngineError> {
         let revision = self.revision;
+        if level >= crate::node::PressureLevel::Critical {
+            // Parked hot-reload state is resettable simulation state by
+            // definition: its node is not even running.
+            self.parked_states.clear();
+        }
         for entry in self.tree.entries_mut() {
             let node_id = entry.id;
             if let NodeEntryState::Alive(node) = entry.state.get_mut() {
@@ -632,6 +786,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -647,6 +802,12 @@ impl Engine {
         let walk = (|| {
             for &root in &self.demand_roots {
                 consume_tree_node(&mut session, &mut host, root)?;
+                // Between roots no render borrow is live: a provider with its
+                // own LED cadence re-presents its wires here. This only helps
+                // a multi-root project — a one-fixture project has no "between",
+                // and gets its refreshes from the output's own multi-frame
+                // posts instead (`Ws281xOutput::start_frames`).
+                self.services.refresh_outputs();
             }
             Ok(())
         })();
@@ -662,11 +823,48 @@ impl Engine {
                 Some(NodeEntryState::Alive(_))
             )
         });
+        // Same reasoning for the budget: a node erroring mid-walk was still
+        // metered, and the node that overran is the likeliest to error.
+        self.apply_budget_changes();
 
         self.resolver = resolver;
         walk
     }
 
+    /// Close the frame's budget meters and hand each node whose degradation
+    /// moved its new divisor. Levels only ever move here, between ticks, so
+    /// a node renders a whole frame at one quality.
+    fn apply_budget_changes(&mut self) {
+        let revision = self.revision;
+        let tree = &self.tree;
+        let changes = self.node_budgets.end_frame(|node| {
+            matches!(
+                tree.get(node).map(|entry| entry.state.value()),
+                Some(NodeEntryState::Alive(_))
+            )
+        });
+        for (node_id, degradation) in changes {
+            let Some(entry) = self.tree.get_mut(node_id) else {
+                continue;
+            };
+            let NodeEntryState::Alive(node) = entry.state.get_mut() else {
+                continue;
+            };
+            if matches!(
+                node.degrade_mode(),
+                DegradeMode::Resolution | DegradeMode::UpdateRate
+            ) {
+                node.set_degradation(degradation.map_or(1, |degradation| degradation.divisor()));
+            }
+            // Surface it now rather than on the node's next call: a node
+            // skipping ticks may not be called again for a while.
+            let status = budget_status(runtime_status_or_ok(&**node), degradation);
+            if is_budget_status(entry.status.value()) {
+                set_entry_status_if_changed(entry, status, revision);
+            }
+        }
+    }
+
     /// Fail loudly when the graph changed shape without anyone calling
     /// [`Resolver::invalidate_structure`].
     ///
@@ -716,6 +914,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -804,6 +1003,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -860,6 +1060,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -901,6 +1102,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -942,6 +1144,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -980,6 +1183,7 @@ impl Engine {
             registry,
             panel_writers: &self.panel_writers,
             timebases: &mut self.timebases,
+            budgets: &mut self.node_budgets,
             producers_ticked: &mut producers_ticked,
             runtime_buffers: &mut self.runtime_buffers,
             slot_shapes: &self.slot_shapes,
@@ -1029,6 +1233,7 @@ struct EngineResolveHost<'a> {
     registry: &'a ProjectRegistry,
     panel_writers: &'a crate::dataflow::panel_writers::PanelWriterStore,
     timebases: &'a mut crate::dataflow::timebase::TimebaseStore,
+    budgets: &'a mut NodeBudgetStore,
     producers_ticked: &'a mut VecSet<NodeId>,
     runtime_buffers: &'a mut RuntimeBufferStore,
     slot_shapes: &'a SlotShapeRegistry,
@@ -1135,6 +1340,47 @@ impl EngineResolveHost<'_> {
         Ok(Production::new(product, ProductionSource::Default))
     }
 
+    /// `node`'s runtime status with its budget degradation applied.
+    fn node_status(&self, node_id: NodeId, node: &dyn NodeRuntime) -> NodeRuntimeStatus {
+        budget_status(
+            runtime_status_or_ok(node),
+            self.budgets.degradation(node_id),
+        )
+    }
+
+    /// Open the budget span for one `produce`/`consume` call. Every call
+    /// pairs with [`Self::end_tick_span`] once the node returns, whatever it
+    /// returned; a node pulled from inside is charged to itself, not to this
+    /// one.
+    fn begin_tick_span(&mut self, node_id: NodeId, mode: DegradeMode) {
+        lp_perf::emit_begin!(lp_perf::EVENT_NODE_TICK);
+        if let Some(time) = &self.time_provider {
+            self.budgets.begin_tick(node_id, mode, time.now_us());
+        }
+    }
+
+    fn end_tick_span(&mut self) {
+        if let Some(time) = &self.time_provider {
+            self.budgets.end(time.now_us());
+        }
+        lp_perf::emit_end!(lp_perf::EVENT_NODE_TICK);
+    }
+
+    /// [`Self::begin_tick_span`] for one render or sample call.
+    fn begin_render_span(&mut self, node_id: NodeId, mode: DegradeMode) {
+        lp_perf::emit_begin!(lp_perf::EVENT_NODE_RENDER);
+        if let Some(time) = &self.time_provider {
+            self.budgets.begin_render(node_id, mode, time.now_us());
+        }
+    }
+
+    fn end_render_span(&mut self) {
+        if let Some(time) = &self.time_provider {
+            self.budgets.end(time.now_us());
+        }
+        lp_perf::emit_end!(lp_perf::EVENT_NODE_RENDER);
+    }
+
     fn produce_node_slot(
         &mut self,
         node_id: NodeId,
@@ -1144,6 +1390,12 @@ impl EngineResolveHost<'_> {
         if self.producers_ticked.contains(&node_id) {
             return Ok(());
         }
+        // An over-budget node sitting this frame out: consumers read what it
+        // produced last, exactly as on a repeat produce within one frame.
+        if self.budgets.skip_tick(node_id) {
+            self.producers_ticked.insert(node_id);
+            return Ok(());
+        }
 
         let revision = session.revision();
         let restore_frame = session.revision();
@@ -1188,6 +1440,7 @@ impl EngineResolveHost<'_> {
         let time_s = self.frame_time_seconds;
         let slot_shapes = self.slot_shapes;
         let recovery_name = recovery_frame_name(&self.tree, node_id);
+        self.begin_tick_span(node_id, node_runtime.degrade_mode());
         let produce_result = {
             let mut bridge = SessionHostResolver {
                 session,
@@ -1209,11 +1462,12 @@ impl EngineResolveHost<'_> {
                 node_runtime.produce(slot, &mut tick_ctx)
             })
         };
+        self.end_tick_span();
 
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("produce: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), restore_frame);
 
         match produce_result {
@@ -1539,11 +1793,13 @@ impl EngineResolveHost<'_> {
             .map(|slot| match slot.kind.value() {
                 lpc_model::ShaderSlotKind::Map => SlotMerge::ByKey,
                 // A timebase or palette uniform's binding, when it has one,
-                // names a single config channel — never an aggregate.
+                // names a single config channel — never an aggregate; a
+                // feedback uniform's names a single visual product.
                 lpc_model::ShaderSlotKind::Value
                 | lpc_model::ShaderSlotKind::Phasor
                 | lpc_model::ShaderSlotKind::Seconds
-                | lpc_model::ShaderSlotKind::Palette => SlotMerge::Latest,
+                | lpc_model::ShaderSlotKind::Palette
+                | lpc_model::ShaderSlotKind::Feedback => SlotMerge::Latest,
             }))
     }
 
@@ -1593,6 +1849,14 @@ impl EngineResolveHost<'_> {
                     self.frame_revision,
                     lpc_model::ToLpValue::to_lp_value(&slot.gradient_config()),
                 )),
+                // An unbound feedback uniform reads the shader's own last
+                // frame, so its default is the node's own output product.
+                lpc_model::ShaderSlotKind::Feedback => SlotData::Value(WithRevision::new(
+                    self.frame_revision,
+                    lpc_model::LpValue::Product(lpc_model::ProductRef::Visual(VisualProduct::new(
+                        node, 0,
+                    ))),
+                )),
                 lpc_model::ShaderSlotKind::Map => {
                     SlotData::Map(lpc_model::SlotMapDyn::with_revision(
                         self.frame_revision,
@@ -1762,6 +2026,7 @@ impl EngineResolveHost<'_> {
         };
 
         let recovery_name = recovery_frame_name(&self.tree, node_id);
+        let mode = node_runtime.degrade_mode();
         let result = {
             let Some(render_node) = node_runtime.render_node() else {
                 return restore_node_after_failed_render(
@@ -1775,6 +2040,7 @@ impl EngineResolveHost<'_> {
                     )),
                 );
             };
+            self.begin_render_span(node_id, mode);
             let mut ctx = RenderContext::with_services(
                 node_id,
                 revision,
@@ -1787,11 +2053,12 @@ impl EngineResolveHost<'_> {
                 render_node.render_texture(product, request, &mut ctx)
             })
         };
+        self.end_render_span();
 
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("render: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), revision);
 
         match result {
@@ -1853,6 +2120,7 @@ impl EngineResolveHost<'_> {
         };
 
         let recovery_name = recovery_frame_name(&self.tree, node_id);
+        let mode = node_runtime.degrade_mode();
         let result = {
             let Some(render_node) = node_runtime.render_node() else {
                 return restore_node_after_failed_render_unit(
@@ -1866,6 +2134,7 @@ impl EngineResolveHost<'_> {
                     )),
                 );
             };
+            self.begin_render_span(node_id, mode);
             let mut ctx = RenderContext::with_services(
                 node_id,
                 revision,
@@ -1878,11 +2147,12 @@ impl EngineResolveHost<'_> {
                 render_node.render_texture_into(product, request, target, &mut ctx)
             })
         };
+        self.end_render_span();
 
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("render: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), revision);
 
         match result {
@@ -1944,6 +2214,7 @@ impl EngineResolveHost<'_> {
         };
 
         let recovery_name = recovery_frame_name(&self.tree, node_id);
+        let mode = node_runtime.degrade_mode();
         let result = {
             let Some(render_node) = node_runtime.render_node() else {
                 return restore_node_after_failed_render_unit(
@@ -1957,6 +2228,7 @@ impl EngineResolveHost<'_> {
                     )),
                 );
             };
+            self.begin_render_span(node_id, mode);
             let mut ctx = RenderContext::with_services(
                 node_id,
                 revision,
@@ -1969,11 +2241,12 @@ impl EngineResolveHost<'_> {
                 render_node.sample_visual_into(product, request, target, &mut ctx)
             })
         };
+        self.end_render_span();
 
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("sample visual: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), revision);
 
         match result {
@@ -2037,6 +2310,7 @@ impl EngineResolveHost<'_> {
         };
 
         let recovery_name = recovery_frame_name(&self.tree, node_id);
+        let mode = node_runtime.degrade_mode();
         let result = {
             let Some(control_node) = node_runtime.control_node() else {
                 return restore_node_after_failed_control(
@@ -2050,6 +2324,7 @@ impl EngineResolveHost<'_> {
                     )),
                 );
             };
+            self.begin_render_span(node_id, mode);
             let mut ctx = ControlRenderContext::new(
                 node_id,
                 revision,
@@ -2062,11 +2337,12 @@ impl EngineResolveHost<'_> {
                 control_node.render_control(product, request, target, &mut ctx)
             })
         };
+        self.end_render_span();
 
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("control render: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), revision);
 
         match result {
@@ -2166,7 +2442,7 @@ impl EngineResolveHost<'_> {
         let entry = self.tree.get_mut(node_id).ok_or_else(|| {
             SessionResolveError::other(format!("control product probe: unknown node {node_id:?}"))
         })?;
-        let runtime_status = runtime_status_or_ok(&*node_runtime);
+        let runtime_status = self.node_status(node_id, &*node_runtime);
         entry.set_state(NodeEntryState::Alive(node_runtime), revision);
 
         match result {
@@ -2633,6 +2909,7 @@ fn consume_tree_node(
     let time_s = host.frame_time_seconds;
     let slot_shapes = host.slot_shapes;
     let recovery_name = recovery_frame_name(&host.tree, node_id);
+    host.begin_tick_span(node_id, node_runtime.degrade_mode());
     let consume_result = {
         let mut bridge = SessionHostResolver {
             session,
@@ -2654,12 +2931,13 @@ fn consume_tree_node(
             node_runtime.consume(&mut tick_ctx)
         })
     };
+    host.end_tick_span();
 
     let entry = host
         .tree
         .get_mut(node_id)
         .ok_or(EngineError::UnknownNode(node_id))?;
-    let runtime_status = runtime_status_or_ok(&*node_runtime);
+    let runtime_status = host.node_status(node_id, &*node_runtime);
     entry.set_state(NodeEntryState::Alive(node_runtime), restore_frame);
 
     match consume_result {
@@ -2715,6 +2993,7 @@ pub(crate) fn resolve_with_engine_host(
         registry,
         panel_writers: &eng.panel_writers,
         timebases: &mut eng.timebases,
+        budgets: &mut eng.node_budgets,
         producers_ticked: &mut producers_ticked,
         runtime_buffers: &mut eng.runtime_buffers,
         slot_shapes: &eng.slot_shapes,
@@ -2757,6 +3036,7 @@ pub(super) fn resolve_twice_same_frame_with_engine_host(
         registry,
         panel_writers: &eng.panel_writers,
         timebases: &mut eng.timebases,
+        budgets: &mut eng.node_budgets,
         producers_ticked: &mut producers_ticked,
         runtime_buffers: &mut eng.runtime_buffers,
         slot_shapes: &eng.slot_shapes,
@@ -19,6 +19,8 @@ use lpc_model::nodes::output::{OutputDef, OutputDriverOptionsConfig};
 use lpc_model::{HwEndpointSpec, NodeId, Revision, TreePath};
 use lpc_shared::output::{OutputChannelHandle, OutputDriverOptions, OutputFormat, OutputProvider};
 use lpc_shared::time::TimeProvider;
+use lpfs::FsError;
+use lpfs::lp_path::LpPath;
 
 use crate::resource::{RuntimeBufferId, RuntimeBufferStore};
 
@@ -178,6 +180,7 @@ pub struct EngineServices {
     time_provider: Option<Rc<dyn TimeProvider>>,
     button_service: Option<Rc<dyn ButtonService>>,
     radio_service: Option<Rc<dyn RadioService>>,
+    asset_stream: Option<Rc<dyn AssetStreamService>>,
     /// Fixture-written buffers paired with the wires their output node drives.
     output_sinks: HashMap<RuntimeBufferId, OutputSinkSet>,
     /// Scratch the flush decodes each node buffer into, once per frame.
@@ -207,6 +210,18 @@ impl ButtonService for HardwareSystem {
     }
 }
 
+/// Ranged reads of project files, for nodes that stream an asset (image
+/// frames) rather than hold it in memory.
+///
+/// Paths are the project-relative paths asset locations carry. Without this
+/// service, streaming nodes fall back to the registry's whole-file bytes.
+pub trait AssetStreamService {
+    fn file_size(&self, path: &LpPath) -> Result<u64, FsError>;
+
+    fn read_file_range(&self, path: &LpPath, offset: u64, buf: &mut [u8])
+    -> Result<usize, FsError>;
+}
+
 /// Hardware radio access used by runtime input/output bridge nodes.
 pub trait RadioService {
     fn open_radio_by_spec(
@@ -234,6 +249,7 @@ impl EngineServices {
             time_provider: None,
             button_service: None,
             radio_service: None,
+            asset_stream: None,
             output_sinks: HashMap::new(),
             flush_samples: Vec::new(),
         }
@@ -281,6 +297,14 @@ impl EngineServices {
         self.radio_service.clone()
     }
 
+    pub fn set_asset_stream(&mut self, service: Option<Rc<dyn AssetStreamService>>) {
+        self.asset_stream = service;
+    }
+
+    pub fn asset_stream(&self) -> Option<Rc<dyn AssetStreamService>> {
+        self.asset_stream.clone()
+    }
+
     /// Register an output sink: fixture pushes u16 RGB channel bytes into `buffer_id`; flush slices
     /// them across `config`'s channels and writes each slice through [`OutputProvider`].
     ///
@@ -370,6 +394,23 @@ impl EngineServices {
         result
     }
 
+    /// Give the output provider a chance to refresh its LEDs between engine
+    /// frames ([`OutputProvider::refresh`]). Needs a time provider for the
+    /// refresh's timestamp; without one, or without outputs, nothing happens.
+    /// A failure is logged and otherwise ignored — the next frame's flush
+    /// reports the wire's state.
+    pub fn refresh_outputs(&self) {
+        let (Some(provider), Some(time)) = (
+            self.output_provider.as_deref(),
+            self.time_provider.as_deref(),
+        ) else {
+            return;
+        };
+        if let Err(error) = provider.refresh(time.now_us()) {
+            log::warn!("EngineServices: output refresh failed: {error}");
+        }
+    }
+
     /// Rebuild a sink set's wires from its authored channels, keeping every
     /// wire the edit did not touch exactly as it was.
     ///
@@ -915,6 +956,7 @@ mod tests {
         MemoryOutputProvider, OutputChannelHandle, OutputDriverOptions, OutputFormat,
         OutputProvider,
     };
+    use lpc_shared::time::TimeProvider;
 
     use super::EngineServices;
     use crate::resource::{RuntimeBuffer, RuntimeBufferId, RuntimeBufferStore};
@@ -1067,6 +1109,38 @@ mod tests {
         assert_eq!(provider.flush_calls(), 2);
     }
 
+    /// A refresh is stamped from the engine's own clock, the one a refresh
+    /// provider stamps its writes against; with no clock there is nothing to
+    /// stamp it with, so there is no refresh.
+    #[test]
+    fn refresh_outputs_stamps_the_time_provider_clock() {
+        struct FixedClock;
+
+        impl TimeProvider for FixedClock {
+            fn now_ms(&self) -> u64 {
+                7
+            }
+
+            fn now_us(&self) -> u64 {
+                7_250
+            }
+        }
+
+        let provider = Rc::new(CountingOutputProvider::new(MemoryOutputProvider::new()));
+        let mut services = EngineServices::new(TreePath::parse("/p.show").expect("tree path"));
+        services.set_output_provider(Some(Box::new(SharedCountingProvider(Rc::clone(&provider)))));
+
+        services.refresh_outputs();
+        assert!(
+            provider.refreshes.borrow().is_empty(),
+            "no clock, no refresh"
+        );
+
+        services.set_time_provider(Some(Rc::new(FixedClock)));
+        services.refresh_outputs();
+        assert_eq!(*provider.refreshes.borrow(), vec![7_250]);
+    }
+
     #[test]
     fn unregister_output_sink_closes_open_channel() {
         let provider = Rc::new(MemoryOutputProvider::new());
@@ -1891,6 +1965,7 @@ mod tests {
         inner: MemoryOutputProvider,
         open_calls: core::cell::Cell<usize>,
         flush_calls: core::cell::Cell<usize>,
+        refreshes: core::cell::RefCell<Vec<u64>>,
     }
 
     impl CountingOutputProvider {
@@ -1899,6 +1974,7 @@ mod tests {
                 inner,
                 open_calls: core::cell::Cell::new(0),
                 flush_calls: core::cell::Cell::new(0),
+                refreshes: core::cell::RefCell::new(Vec::new()),
             }
         }
 
@@ -1946,6 +2022,11 @@ mod tests {
             OutputProvider::flush(&self.0.inner)
         }
 
+        fn refresh(&self, now_us: u64) -> Result<(), OutputError> {
+            self.0.refreshes.borrow_mut().push(now_us);
+            OutputProvider::refresh(&self.0.inner, now_us)
+        }
+
         fn hardware_generation(&self) -> u64 {
             self.0.inner.hardware_generation()
         }
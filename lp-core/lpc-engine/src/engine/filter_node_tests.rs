@@ -0,0 +1,343 @@
+//! Filter nodes end to end: a solid shader rendered through the chain.
+//!
+//! The input is one flat color, so every effect under test has an exact
+//! answer — a pass-through, a greyscale, a LUT that inverts — and the tests
+//! are about the node's plumbing: loader attach (with and without a `.cube`
+//! file), input resolution, the per-frame chain and scaling to a consumer.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use lpc_model::{NodeId, TreePath};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+use crate::node::PressureLevel;
+use crate::nodes::filter_output_path;
+use crate::nodes::shader::shader_node::shader_output_path;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+const WIDTH: u32 = 4;
+const HEIGHT: u32 = 2;
+/// Each pass round-trips the frame through unorm16, and the LUT samples a
+/// filtered strip; a couple of 8-bit steps covers both.
+const TOLERANCE: f32 = 2.0 / 255.0;
+
+const SOLID: [f32; 3] = [0.5, 0.25, 0.75];
+const SOLID_GLSL: &str = "vec4 render_2d(vec2 pos) { return vec4(0.5, 0.25, 0.75, 1.0); }";
+
+/// A 2³ table mapping every color to its complement.
+const INVERT_CUBE: &str = "TITLE \"invert\"\n\
+     LUT_3D_SIZE 2\n\
+     1 1 1\n0 1 1\n1 0 1\n0 0 1\n\
+     1 1 0\n0 1 0\n1 0 0\n0 0 0\n";
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+    solid: NodeId,
+    filter: NodeId,
+}
+
+impl Project {
+    /// Load a solid shader and a filter over it with `effects` (a JSON
+    /// object body) and `lut` (a JSON value, or `None` for no table).
+    fn load(input: Option<&str>, effects: &str, lut: Option<&str>) -> Self {
+        Self::load_over(SOLID_GLSL, "", input, effects, lut)
+    }
+
+    /// [`Self::load`] with the input shader's source and extra def fields
+    /// (`solid_fields`, a JSON fragment starting with a comma) spelled out.
+    fn load_over(
+        solid_glsl: &str,
+        solid_fields: &str,
+        input: Option<&str>,
+        effects: &str,
+        lut: Option<&str>,
+    ) -> Self {
+        let fs = LpFsMemory::new();
+        let write = |path: &str, body: &str| {
+            fs.write_file(path.as_path(), body.as_bytes())
+                .expect("write project file");
+        };
+        write("/project.json", "{ \"format\": 8 }\n");
+        write("/solid.glsl", solid_glsl);
+        write(
+            "/solid.json",
+            &alloc::format!(
+                r#"{{ "kind": "Shader", "source": {{ "path": "solid.glsl" }}{solid_fields} }}"#
+            ),
+        );
+        write("/luts/invert.cube", INVERT_CUBE);
+        let input = input.map_or(String::new(), |source| {
+            alloc::format!(r#""input": {{ "source": "{source}" }},"#)
+        });
+        let lut = lut.map_or(String::new(), |lut| alloc::format!(r#", "lut": {lut}"#));
+        write(
+            "/filter.json",
+            &alloc::format!(
+                r#"{{
+  "kind": "Filter",
+  "size": {{ "width": {WIDTH}, "height": {HEIGHT} }},
+  "bindings": {{ {input} "output": {{ "target": "bus:filtered" }} }},
+  "effects": {{ {effects} }}{lut}
+}}"#
+            ),
+        );
+        write(
+            "/module.json",
+            r#"{
+  "kind": "Module",
+  "nodes": {
+    "solid": { "ref": "./solid.json" },
+    "filter": { "ref": "./filter.json" }
+  }
+}"#,
+        );
+
+        let services = EngineServices::new(TreePath::parse("/filter.show").expect("root"));
+        let loaded = ProjectLoader::load_from_root(&fs, services).expect("load project");
+        let (mut engine, registry) = loaded.into_parts();
+        engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))));
+        let node = |suffix: &str| {
+            engine
+                .tree()
+                .entries()
+                .find(|entry| entry.path.to_string().ends_with(suffix))
+                .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+                .id
+        };
+        let (solid, filter) = (node("solid.shader"), node("filter.filter"));
+        Self {
+            engine,
+            registry,
+            solid,
+            filter,
+        }
+    }
+
+    /// One frame, with a demand on both outputs: nothing consumes them
+    /// here, and `produce` is where the filter resolves its input.
+    fn tick(&mut self) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        for (node, slot) in [
+            (self.solid, shader_output_path()),
+            (self.filter, filter_output_path()),
+        ] {
+            resolve_with_engine_host(
+                &mut self.engine,
+                &self.registry,
+                QueryKey::ProducedSlot { node, slot },
+                ResolveLogLevel::Off,
+            )
+            .expect("demand the output");
+        }
+    }
+
+    /// Render the filter at `width`×`height` and return RGB per pixel.
+    fn render(&mut self, width: u32, height: u32) -> Vec<[f32; 3]> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(self.filter, 0),
+                &RenderTextureRequest {
+                    width,
+                    height,
+                    format: lps_shared::TextureStorageFormat::Rgba16Unorm,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render filter");
+        let bytes = texture.try_raw_bytes().expect("host texture bytes");
+        bytes
+            .chunks_exact(8)
+            .map(|px| {
+                let lane = |i: usize| {
+                    u16::from_le_bytes([px[i * 2], px[i * 2 + 1]]) as f32 / u16::MAX as f32
+                };
+                [lane(0), lane(1), lane(2)]
+            })
+            .collect()
+    }
+
+    /// Run past the compile-window deferral of the shader and the passes.
+    fn warm_up(&mut self) {
+        for _ in 0..4 {
+            self.tick();
+            let _ = self.render(WIDTH, HEIGHT);
+        }
+    }
+}
+
+/// One effect entry, every field spelled out (slot values carry them all),
+/// with `overrides` replacing the defaults by name.
+fn effect(id: u32, name: &str, overrides: &[(&str, &str)]) -> String {
+    let fields = [
+        ("radius", "2.0"),
+        ("threshold", "0.7"),
+        ("amount", "1.0"),
+        ("segments", "6"),
+        ("angle", "0.0"),
+        ("center", "[0.5, 0.5]"),
+        ("cell", "4.0"),
+        ("hue", "0.0"),
+        ("contrast", "1.0"),
+        ("saturation", "1.0"),
+    ]
+    .map(|(field, default)| {
+        let value = overrides
+            .iter()
+            .find(|(key, _)| *key == field)
+            .map_or(default, |(_, value)| *value);
+        alloc::format!(r#""{field}": {value}"#)
+    });
+    alloc::format!(
+        r#""{id}": {{ "id": {id}, "effect": "{name}", {} }}"#,
+        fields.join(", ")
+    )
+}
+
+fn assert_all(pixels: &[[f32; 3]], expected: [f32; 3]) {
+    assert!(!pixels.is_empty());
+    for (index, pixel) in pixels.iter().enumerate() {
+        assert!(
+            pixel
+                .iter()
+                .zip(expected)
+                .all(|(lane, want)| (lane - want).abs() <= TOLERANCE),
+            "pixel {index}: {pixel:?} != {expected:?}"
+        );
+    }
+}
+
+const SOLID_SOURCE: Option<&str> = Some("node:../solid#output");
+
+// --- Tests -----------------------------------------------------------------
+
+/// An empty chain passes the frame through, at the canvas size and scaled
+/// to a larger consumer.
+#[test]
+fn an_empty_chain_passes_the_input_through() {
+    let mut project = Project::load(SOLID_SOURCE, "", None);
+    project.warm_up();
+
+    project.tick();
+    assert_all(&project.render(WIDTH, HEIGHT), SOLID);
+    assert_all(&project.render(WIDTH * 2, HEIGHT * 2), SOLID);
+}
+
+/// The chain asks its input for RGBA16. A shader that renders `R8` is
+/// widened to the grey a sampler would read, not refused.
+#[test]
+fn a_narrow_format_shader_feeds_the_chain() {
+    let mut project = Project::load_over(
+        "float render_2d(vec2 pos) { return 0.5; }",
+        r#", "output_format": "R8""#,
+        SOLID_SOURCE,
+        "",
+        None,
+    );
+    project.warm_up();
+
+    project.tick();
+    assert_all(&project.render(WIDTH, HEIGHT), [128.0 / 255.0; 3]);
+}
+
+/// Saturation 0 greys every pixel to its luma.
+#[test]
+fn color_adjust_with_no_saturation_is_grey() {
+    let effects = [effect(0, "color_adjust", &[("saturation", "0.0")])].join(", ");
+    let mut project = Project::load(SOLID_SOURCE, &effects, None);
+    project.warm_up();
+
+    project.tick();
+    let pixels = project.render(WIDTH, HEIGHT);
+    let luma = SOLID[0] * 0.2126 + SOLID[1] * 0.7152 + SOLID[2] * 0.0722;
+    assert_all(&pixels, [luma; 3]);
+}
+
+/// A `lut` effect grades through the node's `.cube` file; without a table
+/// it passes the frame through, and so does a name this build does not
+/// know.
+#[test]
+fn a_cube_lut_grades_the_frame() {
+    let effects = [effect(0, "lut", &[]), effect(1, "sharpen", &[])].join(", ");
+    let mut project = Project::load(
+        SOLID_SOURCE,
+        &effects,
+        Some(r#"{ "kind": "Cube", "source": "luts/invert.cube" }"#),
+    );
+    project.warm_up();
+    project.tick();
+    assert_all(
+        &project.render(WIDTH, HEIGHT),
+        [1.0 - SOLID[0], 1.0 - SOLID[1], 1.0 - SOLID[2]],
+    );
+
+    let mut project = Project::load(SOLID_SOURCE, &effects, None);
+    project.warm_up();
+    project.tick();
+    assert_all(&project.render(WIDTH, HEIGHT), SOLID);
+}
+
+/// Blur, bloom and the geometric effects leave a flat frame flat.
+#[test]
+fn spatial_effects_keep_a_flat_frame_flat() {
+    let effects = [
+        effect(0, "blur", &[]),
+        effect(1, "kaleidoscope", &[]),
+        effect(2, "mirror", &[]),
+        effect(3, "pixelate", &[]),
+        effect(4, "bloom", &[("threshold", "0.99")]),
+    ]
+    .join(", ");
+    let mut project = Project::load(SOLID_SOURCE, &effects, None);
+    project.warm_up();
+
+    project.tick();
+    assert_all(&project.render(WIDTH, HEIGHT), SOLID);
+}
+
+/// An unbound input renders black rather than failing the consumer.
+#[test]
+fn an_unbound_input_renders_black() {
+    let mut project = Project::load(None, "", None);
+    project.warm_up();
+
+    project.tick();
+    assert_all(&project.render(WIDTH, HEIGHT), [0.0; 3]);
+}
+
+/// `High` pressure drops the scratch targets; the next frame reallocates
+/// them and the chain runs as before.
+#[test]
+fn memory_pressure_drops_the_targets_and_keeps_rendering() {
+    let effects = [effect(0, "lut", &[])].join(", ");
+    let mut project = Project::load(
+        SOLID_SOURCE,
+        &effects,
+        Some(r#"{ "kind": "Cube", "source": "luts/invert.cube" }"#),
+    );
+    project.warm_up();
+
+    project
+        .engine
+        .broadcast_memory_pressure(PressureLevel::High)
+        .expect("high pressure");
+    project.tick();
+    assert_all(
+        &project.render(WIDTH, HEIGHT),
+        [1.0 - SOLID[0], 1.0 - SOLID[1], 1.0 - SOLID[2]],
+    );
+}
@@ -0,0 +1,399 @@
+//! The recorded-frame log: what [`super::FrameRecorder`] writes and
+//! [`super::replay_frame_log`] reads back.
+//!
+//! A frame is everything that reached the engine between two ticks plus the
+//! tick itself: the control events applied before it (panel writes and
+//! clears — the clock transport included, since it is a panel instrument —
+//! and node commands), the hardware inputs its nodes observed during it
+//! (button transitions, received radio packets), the tick's `delta_ms`, and
+//! a checksum of every published output buffer after it.
+//!
+//! The encoding is built for a device that records every frame of a show:
+//! a frame with no events costs a length byte, a varint delta and four
+//! checksum bytes. Events are rare and heterogeneous, so each one is a
+//! length-prefixed JSON document — the same serde shapes the wire already
+//! uses for values and node commands, with no second codec to keep in step.
+//!
+//! ```text
+//! file   := "LPFR" version:u16le first_frame:u64le frame*
+//! frame  := len:varint body            (len = byte length of body)
+//! body   := delta_ms:varint checksum:u32le count:varint event*
+//! event  := len:varint json
+//! ```
+//!
+//! `first_frame` is the number of frames the ring dropped before the first
+//! one kept. Only a log that starts at frame 0 starts from the state a
+//! freshly loaded project is in, so replay refuses any other.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use lpc_model::LpValue;
+use lpc_wire::WireNodeCommand;
+use serde::{Deserialize, Serialize};
+
+/// File magic: "LightPlayer Frame Record".
+pub const FRAME_LOG_MAGIC: [u8; 4] = *b"LPFR";
+
+/// Format version. Bump-and-refuse, like every other `/.lp/` file: a log of
+/// any other version is rejected rather than migrated.
+pub const FRAME_LOG_VERSION: u16 = 1;
+
+const HEADER_LEN: usize = 4 + 2 + 8;
+
+/// One thing that reached the engine during a recorded frame.
+///
+/// Scopes and nodes are named by their stable paths, never by runtime ids:
+/// the replaying engine loads the project afresh, and a path is what is
+/// guaranteed to mean the same node there.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameEvent {
+    /// [`super::Engine::panel_write`] — also how the clock transport moves
+    /// (`clock.*` channels).
+    PanelWrite {
+        scope: String,
+        channel: String,
+        value: LpValue,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ttl_ms: Option<u32>,
+    },
+    /// [`super::Engine::panel_clear`].
+    PanelClear { scope: String, channel: String },
+    /// [`super::Engine::panel_clear_scope`].
+    PanelClearScope { scope: String },
+    /// [`super::Engine::panel_clear_all`].
+    PanelClearAll,
+    /// [`super::Engine::handle_node_command`], addressed by tree path.
+    NodeCommand {
+        node: String,
+        command: WireNodeCommand,
+    },
+    /// A debounced transition one opened button input reported.
+    Button {
+        endpoint: String,
+        source: String,
+        sequence: u32,
+        pressed: bool,
+    },
+    /// A packet one opened radio delivered, as its on-air encoding.
+    Radio { endpoint: String, packet: Vec<u8> },
+}
+
+impl FrameEvent {
+    /// Whether this event was observed during the tick (a hardware input)
+    /// rather than applied before it (a control).
+    pub fn is_input(&self) -> bool {
+        matches!(self, Self::Button { .. } | Self::Radio { .. })
+    }
+}
+
+/// One recorded tick.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedFrame {
+    pub delta_ms: u32,
+    /// [`super::Engine::output_checksum`] after the tick.
+    pub checksum: u32,
+    /// Controls first, in the order they were applied, then inputs in the
+    /// order nodes observed them.
+    pub events: Vec<FrameEvent>,
+}
+
+/// A decoded log.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrameLog {
+    /// Frames the ring dropped before `frames[0]`.
+    pub first_frame: u64,
+    pub frames: Vec<RecordedFrame>,
+}
+
+/// Why a byte string is not a frame log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameLogError {
+    BadMagic,
+    UnsupportedVersion(u16),
+    /// The bytes end inside the header, a frame, or an event.
+    Truncated,
+    /// A frame's declared length disagrees with what its body decodes to.
+    FrameLength {
+        frame: u64,
+    },
+    /// An event's JSON did not decode.
+    Event {
+        frame: u64,
+        message: String,
+    },
+}
+
+impl fmt::Display for FrameLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "not a frame log (bad magic)"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "frame log version {version} is not supported (expected {FRAME_LOG_VERSION})"
+            ),
+            Self::Truncated => write!(f, "frame log is truncated"),
+            Self::FrameLength { frame } => {
+                write!(f, "frame {frame}: declared length does not match its body")
+            }
+            Self::Event { frame, message } => write!(f, "frame {frame}: bad event: {message}"),
+        }
+    }
+}
+
+impl core::error::Error for FrameLogError {}
+
+impl FrameLog {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_header(&mut out, self.first_frame);
+        let mut body = Vec::new();
+        for frame in &self.frames {
+            body.clear();
+            encode_frame_body(&mut body, frame.delta_ms, frame.checksum, &frame.events);
+            write_varint(&mut out, body.len() as u64);
+            out.extend_from_slice(&body);
+        }
+        out
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, FrameLogError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let header = reader.take(HEADER_LEN)?;
+        if header[..4] != FRAME_LOG_MAGIC {
+            return Err(FrameLogError::BadMagic);
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != FRAME_LOG_VERSION {
+            return Err(FrameLogError::UnsupportedVersion(version));
+        }
+        let first_frame = u64::from_le_bytes(header[6..14].try_into().expect("8 header bytes"));
+
+        let mut frames = Vec::new();
+        while !reader.is_empty() {
+            let frame = first_frame + frames.len() as u64;
+            let len = reader.varint()? as usize;
+            let mut body = Reader {
+                bytes: reader.take(len)?,
+                pos: 0,
+            };
+            frames.push(decode_frame_body(&mut body, frame)?);
+            if !body.is_empty() {
+                return Err(FrameLogError::FrameLength { frame });
+            }
+        }
+        Ok(Self {
+            first_frame,
+            frames,
+        })
+    }
+}
+
+pub(super) fn write_header(out: &mut Vec<u8>, first_frame: u64) {
+    out.extend_from_slice(&FRAME_LOG_MAGIC);
+    out.extend_from_slice(&FRAME_LOG_VERSION.to_le_bytes());
+    out.extend_from_slice(&first_frame.to_le_bytes());
+}
+
+/// Append one frame body (without its length prefix).
+pub(super) fn encode_frame_body(
+    out: &mut Vec<u8>,
+    delta_ms: u32,
+    checksum: u32,
+    events: &[FrameEvent],
+) {
+    write_varint(out, u64::from(delta_ms));
+    out.extend_from_slice(&checksum.to_le_bytes());
+    write_varint(out, events.len() as u64);
+    for event in events {
+        match lpc_wire::json::to_string(event) {
+            Ok(json) => {
+                write_varint(out, json.len() as u64);
+                out.extend_from_slice(json.as_bytes());
+            }
+            // Every field is a plain string, number or serde-derived wire
+            // type, so this cannot fail in practice; an empty document keeps
+            // the frame's event count honest if it ever does.
+            Err(error) => {
+                log::warn!("frame log: failed to encode {event:?}: {error:?}");
+                write_varint(out, 0);
+            }
+        }
+    }
+}
+
+fn decode_frame_body(body: &mut Reader<'_>, frame: u64) -> Result<RecordedFrame, FrameLogError> {
+    let delta_ms =
+        u32::try_from(body.varint()?).map_err(|_| FrameLogError::FrameLength { frame })?;
+    let checksum = u32::from_le_bytes(body.take(4)?.try_into().expect("4 checksum bytes"));
+    let count = body.varint()? as usize;
+    let mut events = Vec::with_capacity(count.min(64));
+    for _ in 0..count {
+        let len = body.varint()? as usize;
+        let json = body.take(len)?;
+        let event = lpc_wire::json::from_slice::<FrameEvent>(json).map_err(|error| {
+            FrameLogError::Event {
+                frame,
+                message: format!("{error}"),
+            }
+        })?;
+        events.push(event);
+    }
+    Ok(RecordedFrame {
+        delta_ms,
+        checksum,
+        events,
+    })
+}
+
+/// Unsigned LEB128.
+pub(super) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], FrameLogError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(FrameLogError::Truncated)?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn varint(&mut self) -> Result<u64, FrameLogError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(FrameLogError::Truncated)
+    }
+}
+
+/// Read the length prefix at the front of `bytes`: `(prefix_len, body_len)`.
+pub(super) fn read_frame_len(bytes: impl Iterator<Item = u8>) -> Option<(usize, usize)> {
+    let mut value = 0u64;
+    for (index, byte) in bytes.enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some((index + 1, usize::try_from(value).ok()?));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+
+    fn sample_log() -> FrameLog {
+        FrameLog {
+            first_frame: 0,
+            frames: vec![
+                RecordedFrame {
+                    delta_ms: 16,
+                    checksum: 0xdead_beef,
+                    events: vec![FrameEvent::PanelWrite {
+                        scope: "/show".to_string(),
+                        channel: "clock.rate".to_string(),
+                        value: LpValue::F32(2.0),
+                        ttl_ms: None,
+                    }],
+                },
+                RecordedFrame {
+                    delta_ms: 300,
+                    checksum: 7,
+                    events: vec![
+                        FrameEvent::NodeCommand {
+                            node: "/show/playlist".to_string(),
+                            command: WireNodeCommand::PlaylistActivateEntry { entry: 2 },
+                        },
+                        FrameEvent::Button {
+                            endpoint: "button:gpio18".to_string(),
+                            source: "/gpio/18".to_string(),
+                            sequence: 4,
+                            pressed: true,
+                        },
+                    ],
+                },
+                RecordedFrame {
+                    delta_ms: 17,
+                    checksum: 7,
+                    events: Vec::new(),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn frame_log_round_trips() {
+        let log = sample_log();
+        let bytes = log.encode();
+        assert_eq!(FrameLog::decode(&bytes), Ok(log));
+    }
+
+    #[test]
+    fn an_idle_frame_costs_seven_bytes() {
+        let empty = FrameLog::default().encode();
+        let mut log = FrameLog::default();
+        log.frames.push(RecordedFrame {
+            delta_ms: 16,
+            checksum: 1,
+            events: Vec::new(),
+        });
+        // Length prefix, one-byte delta, four checksum bytes, zero events.
+        assert_eq!(log.encode().len() - empty.len(), 7);
+    }
+
+    #[test]
+    fn frame_log_refuses_foreign_and_future_files() {
+        let mut bytes = sample_log().encode();
+        bytes[4] = 9;
+        assert_eq!(
+            FrameLog::decode(&bytes),
+            Err(FrameLogError::UnsupportedVersion(9))
+        );
+        assert_eq!(
+            FrameLog::decode(b"{\"version\":1}"),
+            Err(FrameLogError::BadMagic)
+        );
+    }
+
+    #[test]
+    fn frame_log_reports_a_cut_off_tail() {
+        let bytes = sample_log().encode();
+        assert_eq!(
+            FrameLog::decode(&bytes[..bytes.len() - 2]),
+            Err(FrameLogError::Truncated)
+        );
+    }
+}
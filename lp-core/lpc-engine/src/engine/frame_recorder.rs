@@ -0,0 +1,305 @@
+//! [`FrameRecorder`] — the engine's optional flight recorder.
+//!
+//! Recording is a ring: frames are encoded as they finish (see
+//! [`super::frame_log`] for the layout) into one byte ring of a fixed
+//! capacity, and the oldest frames fall off the front once it is full. The
+//! steady state allocates nothing — the ring, the frame scratch and the two
+//! event lists all keep their capacity — so a device can leave it running
+//! for a whole show and write [`FrameRecorder::encode_log`] out when asked.
+//!
+//! Hardware inputs are captured where nodes read them: while recording, the
+//! engine's button and radio services are wrapped so that every transition
+//! or packet a node observes is also appended to the current frame. Inputs
+//! opened before recording started are not wrapped, which is why hosts
+//! start the recorder before the first tick.
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use lpc_hardware::{
+    ButtonConfig, ButtonEvent, ButtonEventKind, ButtonInput, HardwareEndpointError, HwAddress,
+    HwEndpointSpec, RADIO_MAX_PACKET_LEN, RadioChannelId, RadioConfig, RadioDevice,
+    RadioDrainReport, RadioMessage, RadioMessageKind,
+};
+
+use super::frame_log::{self, FrameEvent};
+use super::{ButtonService, EngineServices, RadioService};
+
+/// Inputs observed during the current tick, shared with the wrapped services.
+type InputTap = Rc<RefCell<Vec<FrameEvent>>>;
+
+/// Bounded recording of the most recent engine frames.
+pub struct FrameRecorder {
+    capacity_bytes: usize,
+    ring: VecDeque<u8>,
+    /// Frames dropped off the front of the ring so far.
+    first_frame: u64,
+    frames: usize,
+    /// Controls applied since the last tick; they belong to the next frame.
+    controls: Vec<FrameEvent>,
+    inputs: InputTap,
+    scratch: Vec<u8>,
+    /// The services recording replaced, handed back by [`Self::unwrap_services`].
+    button_service: Option<Rc<dyn ButtonService>>,
+    radio_service: Option<Rc<dyn RadioService>>,
+}
+
+impl FrameRecorder {
+    /// A recorder keeping at most `capacity_bytes` of encoded frames (the
+    /// newest frame is always kept, whatever its size).
+    pub fn new(capacity_bytes: usize) -> Self {
+        Self {
+            capacity_bytes,
+            ring: VecDeque::with_capacity(capacity_bytes),
+            first_frame: 0,
+            frames: 0,
+            controls: Vec::new(),
+            inputs: Rc::new(RefCell::new(Vec::new())),
+            scratch: Vec::new(),
+            button_service: None,
+            radio_service: None,
+        }
+    }
+
+    pub fn capacity_bytes(&self) -> usize {
+        self.capacity_bytes
+    }
+
+    /// Encoded bytes currently held (excluding the file header).
+    pub fn len_bytes(&self) -> usize {
+        self.ring.len()
+    }
+
+    /// Frames currently held.
+    pub fn frame_count(&self) -> usize {
+        self.frames
+    }
+
+    /// Frames dropped off the front since recording started; `0` means the
+    /// log still begins at the first recorded tick.
+    pub fn first_frame(&self) -> u64 {
+        self.first_frame
+    }
+
+    /// The held frames as a complete log file (see [`super::FrameLog`]).
+    pub fn encode_log(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.ring.len() + 16);
+        frame_log::write_header(&mut out, self.first_frame);
+        let (front, back) = self.ring.as_slices();
+        out.extend_from_slice(front);
+        out.extend_from_slice(back);
+        out
+    }
+
+    pub(super) fn record_control(&mut self, event: FrameEvent) {
+        self.controls.push(event);
+    }
+
+    /// Close the current frame: encode it into the ring and evict whatever
+    /// no longer fits.
+    pub(super) fn finish_frame(&mut self, delta_ms: u32, checksum: u32) {
+        self.controls.append(&mut self.inputs.borrow_mut());
+
+        // Body after its length prefix, in one scratch buffer, so the ring
+        // takes the whole frame in a single copy.
+        self.scratch.clear();
+        frame_log::encode_frame_body(&mut self.scratch, delta_ms, checksum, &self.controls);
+        self.controls.clear();
+        let body_len = self.scratch.len();
+        frame_log::write_varint(&mut self.scratch, body_len as u64);
+        let prefix_len = self.scratch.len() - body_len;
+        self.scratch.rotate_right(prefix_len);
+        self.ring.extend(self.scratch.iter().copied());
+        self.frames += 1;
+
+        let newest = self.scratch.len();
+        while self.ring.len() > self.capacity_bytes && self.ring.len() > newest {
+            let Some((prefix_len, body_len)) = frame_log::read_frame_len(self.ring.iter().copied())
+            else {
+                break;
+            };
+            self.ring.drain(..prefix_len + body_len);
+            self.frames -= 1;
+            self.first_frame += 1;
+        }
+    }
+
+    /// Route the engine's hardware inputs through recording wrappers.
+    pub(super) fn wrap_services(&mut self, services: &mut EngineServices) {
+        self.button_service = services.button_service();
+        self.radio_service = services.radio_service();
+        if let Some(inner) = self.button_service.clone() {
+            services.set_button_service(Some(Rc::new(RecordingButtonService {
+                inner,
+                inputs: self.inputs.clone(),
+            })));
+        }
+        if let Some(inner) = self.radio_service.clone() {
+            services.set_radio_service(Some(Rc::new(RecordingRadioService {
+                inner,
+                inputs: self.inputs.clone(),
+            })));
+        }
+    }
+
+    /// Put back the services [`Self::wrap_services`] replaced.
+    pub(super) fn unwrap_services(&mut self, services: &mut EngineServices) {
+        services.set_button_service(self.button_service.take());
+        services.set_radio_service(self.radio_service.take());
+    }
+}
+
+struct RecordingButtonService {
+    inner: Rc<dyn ButtonService>,
+    inputs: InputTap,
+}
+
+impl ButtonService for RecordingButtonService {
+    fn open_button_by_spec(
+        &self,
+        spec: &HwEndpointSpec,
+        config: ButtonConfig,
+    ) -> Result<Box<dyn ButtonInput>, HardwareEndpointError> {
+        let inner = self.inner.open_button_by_spec(spec, config)?;
+        Ok(Box::new(RecordingButtonInput {
+            inner,
+            endpoint: spec.as_str().to_string(),
+            inputs: self.inputs.clone(),
+        }))
+    }
+}
+
+struct RecordingButtonInput {
+    inner: Box<dyn ButtonInput>,
+    endpoint: String,
+    inputs: InputTap,
+}
+
+impl ButtonInput for RecordingButtonInput {
+    fn source(&self) -> &HwAddress {
+        self.inner.source()
+    }
+
+    fn poll(&mut self, now_ms: u64) -> Option<ButtonEvent> {
+        let event = self.inner.poll(now_ms)?;
+        self.inputs.borrow_mut().push(FrameEvent::Button {
+            endpoint: self.endpoint.clone(),
+            source: event.source().as_str().to_string(),
+            sequence: event.sequence(),
+            pressed: event.kind() == ButtonEventKind::Pressed,
+        });
+        Some(event)
+    }
+}
+
+struct RecordingRadioService {
+    inner: Rc<dyn RadioService>,
+    inputs: InputTap,
+}
+
+impl RadioService for RecordingRadioService {
+    fn open_radio_by_spec(
+        &self,
+        spec: &HwEndpointSpec,
+        config: RadioConfig,
+    ) -> Result<Box<dyn RadioDevice>, HardwareEndpointError> {
+        let inner = self.inner.open_radio_by_spec(spec, config)?;
+        Ok(Box::new(RecordingRadioDevice {
+            inner,
+            endpoint: spec.as_str().to_string(),
+            inputs: self.inputs.clone(),
+        }))
+    }
+}
+
+struct RecordingRadioDevice {
+    inner: Box<dyn RadioDevice>,
+    endpoint: String,
+    inputs: InputTap,
+}
+
+impl RadioDevice for RecordingRadioDevice {
+    fn subscribe_channel(&mut self, channel: RadioChannelId) -> Result<(), HardwareEndpointError> {
+        self.inner.subscribe_channel(channel)
+    }
+
+    fn unsubscribe_channel(
+        &mut self,
+        channel: RadioChannelId,
+    ) -> Result<(), HardwareEndpointError> {
+        self.inner.unsubscribe_channel(channel)
+    }
+
+    fn send_channel(
+        &mut self,
+        channel: RadioChannelId,
+        kind: RadioMessageKind,
+        payload: &[u8],
+    ) -> Result<(), HardwareEndpointError> {
+        // Sends are outputs: replay re-derives them from the inputs.
+        self.inner.send_channel(channel, kind, payload)
+    }
+
+    fn drain_channel(
+        &mut self,
+        channel: RadioChannelId,
+        out: &mut Vec<RadioMessage>,
+    ) -> Result<RadioDrainReport, HardwareEndpointError> {
+        let before = out.len();
+        let report = self.inner.drain_channel(channel, out)?;
+        let mut inputs = self.inputs.borrow_mut();
+        let mut packet = [0u8; RADIO_MAX_PACKET_LEN];
+        for message in &out[before..] {
+            let len = message.encode(&mut packet);
+            inputs.push(FrameEvent::Radio {
+                endpoint: self.endpoint.clone(),
+                packet: packet[..len].to_vec(),
+            });
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::FrameLog;
+    use super::*;
+
+    #[test]
+    fn the_ring_drops_the_oldest_frames_first() {
+        // Idle frames are seven bytes each; room for three.
+        let mut recorder = FrameRecorder::new(21);
+        for frame in 0..5u32 {
+            recorder.finish_frame(16, frame);
+        }
+        assert_eq!(recorder.frame_count(), 3);
+        assert_eq!(recorder.first_frame(), 2);
+
+        let log = FrameLog::decode(&recorder.encode_log()).expect("decode");
+        assert_eq!(log.first_frame, 2);
+        let checksums: Vec<u32> = log.frames.iter().map(|frame| frame.checksum).collect();
+        assert_eq!(checksums, [2, 3, 4]);
+    }
+
+    #[test]
+    fn controls_and_inputs_land_in_the_frame_they_reached() {
+        let mut recorder = FrameRecorder::new(1024);
+        recorder.record_control(FrameEvent::PanelClearAll);
+        recorder.inputs.borrow_mut().push(FrameEvent::Radio {
+            endpoint: "radio:espnow".to_string(),
+            packet: alloc::vec![1, 2, 3],
+        });
+        recorder.finish_frame(16, 0);
+        recorder.finish_frame(16, 0);
+
+        let log = FrameLog::decode(&recorder.encode_log()).expect("decode");
+        assert_eq!(log.frames[0].events.len(), 2);
+        assert!(!log.frames[0].events[0].is_input());
+        assert!(log.frames[0].events[1].is_input());
+        assert!(log.frames[1].events.is_empty());
+    }
+}
@@ -0,0 +1,375 @@
+//! Host-side replay of a recorded [`FrameLog`].
+//!
+//! Replay feeds a log back into an [`Engine`] that has just loaded the same
+//! project: before each tick it applies the frame's controls exactly as the
+//! recording engine received them, it hands the frame's hardware inputs to
+//! stand-in button and radio services, and after the tick it compares
+//! [`Engine::output_checksum`] with the recorded one. The first frame whose
+//! checksum differs is where the two runs diverged — everything after it is
+//! downstream of that frame, so the report keeps only a short list.
+//!
+//! Output is reproduced bit for bit only when everything that is not an
+//! input matches too: the same project files, the same graphics backend
+//! (the recording device's Q32 CPU path, not a GPU), and a log that starts
+//! at frame 0. A ring that has dropped its head would replay from a freshly
+//! loaded state the device was no longer in, and every frame of it would
+//! diverge for a reason the report could not show, so such a log is
+//! refused ([`ReplayError::HeadDropped`]). Node runtime state has no
+//! serialized form to checkpoint into the ring, so there is nothing later
+//! to resume from.
+//!
+//! Engine time is the sum of the recorded deltas, so anything driven by it
+//! replays exactly. The wall clock (`TimeProvider::now_ms`) is the replay
+//! host's own, so a node that reads it directly can diverge.
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+
+use lpc_hardware::{
+    ButtonConfig, ButtonEvent, ButtonEventKind, ButtonInput, HardwareEndpointError, HwAddress,
+    HwEndpointSpec, RadioChannelId, RadioConfig, RadioDevice, RadioDrainReport, RadioMessage,
+    RadioMessageKind,
+};
+use lpc_model::{ChannelName, TreePath};
+use lpc_registry::ProjectRegistry;
+
+use super::frame_log::{FrameEvent, FrameLog};
+use super::{ButtonService, Engine, RadioService};
+use crate::node::ScopeRef;
+
+/// Divergences kept in a [`ReplayReport`]; later ones are counted only.
+const MAX_REPORTED_DIVERGENCES: usize = 16;
+
+/// One frame whose replayed output did not match the recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrameDivergence {
+    /// Frame number in the recording (`FrameLog::first_frame` based).
+    pub frame: u64,
+    pub recorded: u32,
+    pub replayed: u32,
+}
+
+/// Why a log cannot be replayed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The recording ring dropped `first_frame` frames before the first one
+    /// it kept, so the log does not start from the state a fresh project
+    /// load is in (see the module docs).
+    HeadDropped { first_frame: u64 },
+}
+
+impl core::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HeadDropped { first_frame } => write!(
+                f,
+                "frame log starts at frame {first_frame}: the recording ring dropped its head, \
+                 so it cannot be replayed from a project load"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ReplayError {}
+
+/// What a replay found.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    pub frames: u64,
+    /// The first few divergent frames, in order.
+    pub divergences: Vec<FrameDivergence>,
+    pub divergent_frames: u64,
+    /// Controls that could not be applied — a scope or node path this
+    /// project does not have, or a command the node refused.
+    pub skipped_events: Vec<String>,
+    /// Ticks that returned an error (the recording tolerated them too; they
+    /// are listed so a failing node is not mistaken for a divergence).
+    pub tick_errors: Vec<String>,
+}
+
+impl ReplayReport {
+    pub fn first_divergence(&self) -> Option<&FrameDivergence> {
+        self.divergences.first()
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.divergent_frames == 0
+    }
+}
+
+/// Replay `log` into `engine`, which should have just loaded the recorded
+/// project and not ticked yet. A log whose head was dropped is refused
+/// before `engine` is touched.
+///
+/// Any recording running on `engine` is stopped, and its button and radio
+/// services are replaced by the replay stand-ins for good: after a replay the
+/// engine belongs to the replay.
+pub fn replay_frame_log(
+    engine: &mut Engine,
+    registry: &ProjectRegistry,
+    log: &FrameLog,
+) -> Result<ReplayReport, ReplayError> {
+    if log.first_frame != 0 {
+        return Err(ReplayError::HeadDropped {
+            first_frame: log.first_frame,
+        });
+    }
+    engine.stop_recording();
+    let inputs = Rc::new(RefCell::new(ReplayInputs::default()));
+    engine
+        .services_mut()
+        .set_button_service(Some(Rc::new(ReplayButtonService {
+            inputs: inputs.clone(),
+        })));
+    engine
+        .services_mut()
+        .set_radio_service(Some(Rc::new(ReplayRadioService {
+            inputs: inputs.clone(),
+        })));
+
+    let mut report = ReplayReport::default();
+    for (index, recorded) in log.frames.iter().enumerate() {
+        let frame = index as u64;
+        for event in &recorded.events {
+            if event.is_input() {
+                inputs.borrow_mut().queue(event);
+            } else if let Err(reason) = apply_control(engine, event) {
+                report
+                    .skipped_events
+                    .push(format!("frame {frame}: {reason}"));
+            }
+        }
+        if let Err(error) = engine.tick(registry, recorded.delta_ms) {
+            report.tick_errors.push(format!("frame {frame}: {error}"));
+        }
+        // Inputs no node polled this frame were not observed by the
+        // recording's nodes either; carrying them over would invent them.
+        inputs.borrow_mut().clear();
+
+        let replayed = engine.output_checksum();
+        if replayed != recorded.checksum {
+            report.divergent_frames += 1;
+            if report.divergences.len() < MAX_REPORTED_DIVERGENCES {
+                report.divergences.push(FrameDivergence {
+                    frame,
+                    recorded: recorded.checksum,
+                    replayed,
+                });
+            }
+        }
+        report.frames += 1;
+    }
+    Ok(report)
+}
+
+fn apply_control(engine: &mut Engine, event: &FrameEvent) -> Result<(), String> {
+    match event {
+        FrameEvent::PanelWrite {
+            scope,
+            channel,
+            value,
+            ttl_ms,
+        } => {
+            let scope = scope_by_persist_path(engine, scope)?;
+            engine.panel_write(scope, ChannelName(channel.clone()), value.clone(), *ttl_ms);
+        }
+        FrameEvent::PanelClear { scope, channel } => {
+            let scope = scope_by_persist_path(engine, scope)?;
+            engine.panel_clear(scope, &ChannelName(channel.clone()));
+        }
+        FrameEvent::PanelClearScope { scope } => {
+            let scope = scope_by_persist_path(engine, scope)?;
+            engine.panel_clear_scope(scope);
+        }
+        FrameEvent::PanelClearAll => {
+            engine.panel_clear_all();
+        }
+        FrameEvent::NodeCommand { node, command } => {
+            let path = TreePath::parse(node).map_err(|e| format!("node path {node:?}: {e}"))?;
+            let id = engine
+                .tree()
+                .lookup_path(&path)
+                .ok_or_else(|| format!("no node at {node}"))?;
+            engine
+                .handle_node_command(id, command)
+                .map_err(|e| format!("{node}: {e}"))?;
+        }
+        FrameEvent::Button { .. } | FrameEvent::Radio { .. } => {}
+    }
+    Ok(())
+}
+
+fn scope_by_persist_path(engine: &Engine, path: &str) -> Result<ScopeRef, String> {
+    engine
+        .tree()
+        .scopes()
+        .into_iter()
+        .find(|scope| engine.tree().scope_persist_path(*scope).as_deref() == Some(path))
+        .ok_or_else(|| format!("no scope at {path}"))
+}
+
+/// This frame's recorded inputs, per endpoint, waiting for a node to poll.
+#[derive(Default)]
+struct ReplayInputs {
+    buttons: Vec<(String, VecDeque<ButtonEvent>)>,
+    radios: Vec<(String, VecDeque<RadioMessage>)>,
+}
+
+impl ReplayInputs {
+    fn queue(&mut self, event: &FrameEvent) {
+        match event {
+            FrameEvent::Button {
+                endpoint,
+                source,
+                sequence,
+                pressed,
+            } => {
+                let Ok(source) = HwAddress::new(source.clone()) else {
+                    return;
+                };
+                let kind = if *pressed {
+                    ButtonEventKind::Pressed
+                } else {
+                    ButtonEventKind::Released
+                };
+                queue_for(&mut self.buttons, endpoint)
+                    .push_back(ButtonEvent::new(source, *sequence, kind));
+            }
+            FrameEvent::Radio { endpoint, packet } => {
+                let Ok(message) = RadioMessage::decode(packet) else {
+                    return;
+                };
+                queue_for(&mut self.radios, endpoint).push_back(message);
+            }
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        for (_, queue) in &mut self.buttons {
+            queue.clear();
+        }
+        for (_, queue) in &mut self.radios {
+            queue.clear();
+        }
+    }
+}
+
+fn queue_for<'a, T>(
+    queues: &'a mut Vec<(String, VecDeque<T>)>,
+    endpoint: &str,
+) -> &'a mut VecDeque<T> {
+    let index = match queues.iter().position(|(key, _)| key == endpoint) {
+        Some(index) => index,
+        None => {
+            queues.push((String::from(endpoint), VecDeque::new()));
+            queues.len() - 1
+        }
+    };
+    &mut queues[index].1
+}
+
+struct ReplayButtonService {
+    inputs: Rc<RefCell<ReplayInputs>>,
+}
+
+impl ButtonService for ReplayButtonService {
+    fn open_button_by_spec(
+        &self,
+        spec: &HwEndpointSpec,
+        _config: ButtonConfig,
+    ) -> Result<Box<dyn ButtonInput>, HardwareEndpointError> {
+        Ok(Box::new(ReplayButtonInput {
+            endpoint: String::from(spec.as_str()),
+            // The address is only descriptive; recorded events carry their own.
+            source: HwAddress::new("/replay").expect("static replay address"),
+            inputs: self.inputs.clone(),
+        }))
+    }
+}
+
+struct ReplayButtonInput {
+    endpoint: String,
+    source: HwAddress,
+    inputs: Rc<RefCell<ReplayInputs>>,
+}
+
+impl ButtonInput for ReplayButtonInput {
+    fn source(&self) -> &HwAddress {
+        &self.source
+    }
+
+    fn poll(&mut self, _now_ms: u64) -> Option<ButtonEvent> {
+        queue_for(&mut self.inputs.borrow_mut().buttons, &self.endpoint).pop_front()
+    }
+}
+
+struct ReplayRadioService {
+    inputs: Rc<RefCell<ReplayInputs>>,
+}
+
+impl RadioService for ReplayRadioService {
+    fn open_radio_by_spec(
+        &self,
+        spec: &HwEndpointSpec,
+        _config: RadioConfig,
+    ) -> Result<Box<dyn RadioDevice>, HardwareEndpointError> {
+        Ok(Box::new(ReplayRadioDevice {
+            endpoint: String::from(spec.as_str()),
+            inputs: self.inputs.clone(),
+        }))
+    }
+}
+
+struct ReplayRadioDevice {
+    endpoint: String,
+    inputs: Rc<RefCell<ReplayInputs>>,
+}
+
+impl RadioDevice for ReplayRadioDevice {
+    fn subscribe_channel(&mut self, _channel: RadioChannelId) -> Result<(), HardwareEndpointError> {
+        Ok(())
+    }
+
+    fn unsubscribe_channel(
+        &mut self,
+        _channel: RadioChannelId,
+    ) -> Result<(), HardwareEndpointError> {
+        Ok(())
+    }
+
+    fn send_channel(
+        &mut self,
+        _channel: RadioChannelId,
+        _kind: RadioMessageKind,
+        _payload: &[u8],
+    ) -> Result<(), HardwareEndpointError> {
+        Ok(())
+    }
+
+    fn drain_channel(
+        &mut self,
+        channel: RadioChannelId,
+        out: &mut Vec<RadioMessage>,
+    ) -> Result<RadioDrainReport, HardwareEndpointError> {
+        let mut inputs = self.inputs.borrow_mut();
+        let queue = queue_for(&mut inputs.radios, &self.endpoint);
+        let before = out.len();
+        // A recording device drains per channel, so a recorded packet is
+        // only ever handed back to a drain of the channel it arrived on.
+        let mut index = 0;
+        while index < queue.len() {
+            if queue[index].channel_id() == channel {
+                out.extend(queue.remove(index));
+            } else {
+                index += 1;
+            }
+        }
+        Ok(RadioDrainReport::new(out.len() - before, 0, false))
+    }
+}
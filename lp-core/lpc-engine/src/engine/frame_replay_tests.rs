@@ -0,0 +1,285 @@
+//! Record a run, replay it into a second engine, compare the outputs.
+//!
+//! The probe node stands in for a whole show: it owns an output sink buffer
+//! and fills it from everything a recording has to carry — accumulated
+//! engine time (the tick deltas), a button it polls (a hardware input) and a
+//! runtime command it accepts (a control). If replay drops or misplaces any
+//! of them, the checksums say so on the frame it happened.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+use lpc_hardware::{
+    ButtonConfig, ButtonEvent, ButtonEventKind, ButtonInput, HardwareEndpointError, HwAddress,
+    HwEndpointSpec,
+};
+use lpc_model::{ChannelName, LpValue, NodeId, Revision, TreePath, WithRevision};
+use lpc_registry::ProjectRegistry;
+use lpc_wire::{WireChildKind, WireNodeCommand, WireSlotIndex};
+
+use crate::node::{
+    DestroyCtx, MemPressureCtx, NodeError, NodeResourceInitContext, NodeRuntime, PressureLevel,
+    ScopeRef, TickContext, test_placeholder_spine,
+};
+use crate::resource::{RuntimeBuffer, RuntimeBufferId};
+
+use super::{ButtonService, Engine, FrameEvent, FrameLog, FrameNum, ReplayError, replay_frame_log};
+
+const BUTTON: &str = "button:local:D2";
+
+/// Writes `[time_ms, presses, entry]` as u16 samples every tick.
+struct ProbeNode {
+    buffer: Option<RuntimeBufferId>,
+    button: Option<Box<dyn ButtonInput>>,
+    presses: u16,
+    entry: u16,
+}
+
+impl NodeRuntime for ProbeNode {
+    fn init_resources(&mut self, ctx: &mut NodeResourceInitContext<'_>) -> Result<(), NodeError> {
+        self.buffer = Some(ctx.insert_runtime_buffer(WithRevision::new(
+            Revision::default(),
+            RuntimeBuffer::output_channels_u16(1, Vec::new()),
+        )));
+        Ok(())
+    }
+
+    fn runtime_output_sink_buffer_id(&self) -> Option<RuntimeBufferId> {
+        self.buffer
+    }
+
+    fn consume(&mut self, ctx: &mut TickContext<'_>) -> Result<(), NodeError> {
+        if self.button.is_none()
+            && let Some(service) = ctx.button_service()
+        {
+            let input = service
+                .open_button_by_spec(&HwEndpointSpec::from_static(BUTTON), ButtonConfig::new(0))
+                .map_err(|e| NodeError::msg(e.to_string()))?;
+            self.button = Some(input);
+        }
+        if let Some(event) = self.button.as_mut().and_then(|button| button.poll(0))
+            && event.kind() == ButtonEventKind::Pressed
+        {
+            self.presses += 1;
+        }
+        let time_ms = (ctx.time_seconds() * 1000.0).round() as u16;
+        let samples = [time_ms, self.presses, self.entry];
+        let buffer = self.buffer.expect("buffer from init_resources");
+        ctx.with_runtime_buffer_mut(buffer, ctx.revision(), |buffer| {
+            buffer.bytes.clear();
+            for sample in samples {
+                buffer.bytes.extend_from_slice(&sample.to_le_bytes());
+            }
+            Ok(())
+        })
+    }
+
+    fn handle_command(&mut self, command: &WireNodeCommand, _time_s: f32) -> Result<(), NodeError> {
+        let WireNodeCommand::PlaylistActivateEntry { entry } = command;
+        self.entry = *entry as u16;
+        Ok(())
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        _level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        Ok(())
+    }
+}
+
+/// A button that reports one press the next time it is polled after
+/// [`PressOnce::arm`].
+#[derive(Clone, Default)]
+struct PressOnce {
+    armed: Rc<Cell<bool>>,
+    sequence: Rc<Cell<u32>>,
+}
+
+impl PressOnce {
+    fn arm(&self) {
+        self.armed.set(true);
+    }
+}
+
+impl ButtonService for PressOnce {
+    fn open_button_by_spec(
+        &self,
+        _spec: &HwEndpointSpec,
+        _config: ButtonConfig,
+    ) -> Result<Box<dyn ButtonInput>, HardwareEndpointError> {
+        Ok(Box::new(PressOnceInput {
+            button: self.clone(),
+            source: HwAddress::gpio(2),
+        }))
+    }
+}
+
+struct PressOnceInput {
+    button: PressOnce,
+    source: HwAddress,
+}
+
+impl ButtonInput for PressOnceInput {
+    fn source(&self) -> &HwAddress {
+        &self.source
+    }
+
+    fn poll(&mut self, _now_ms: u64) -> Option<ButtonEvent> {
+        if !self.button.armed.replace(false) {
+            return None;
+        }
+        let sequence = self.button.sequence.get() + 1;
+        self.button.sequence.set(sequence);
+        Some(ButtonEvent::new(
+            self.source.clone(),
+            sequence,
+            ButtonEventKind::Pressed,
+        ))
+    }
+}
+
+/// A root scope and one probe node demanding itself every tick.
+fn probe_engine(button: Option<Rc<dyn ButtonService>>) -> (Engine, NodeId) {
+    let mut engine = Engine::new(TreePath::parse("/show.t").expect("root path"));
+    engine.services_mut().set_button_service(button);
+    let frame = Revision::new(1);
+    let root = engine.tree().root();
+    engine
+        .tree_mut()
+        .get_mut(root)
+        .expect("root entry")
+        .introduces_scope = true;
+    let probe = engine
+        .tree_mut()
+        .add_child(
+            root,
+            lpc_model::NodeName::parse("probe").expect("probe name"),
+            lpc_model::NodeName::parse("output").expect("probe type"),
+            WireChildKind::Input {
+                source: WireSlotIndex(0),
+            },
+            test_placeholder_spine(),
+            frame,
+        )
+        .expect("add probe");
+    engine
+        .attach_runtime_node(
+            probe,
+            Box::new(ProbeNode {
+                buffer: None,
+                button: None,
+                presses: 0,
+                entry: 0,
+            }),
+            frame,
+        )
+        .expect("attach probe");
+    engine.add_demand_root(probe);
+    (engine, probe)
+}
+
+/// Four frames touching every kind of recorded event.
+fn record_session() -> FrameLog {
+    let button = PressOnce::default();
+    let (mut engine, probe) = probe_engine(Some(Rc::new(button.clone())));
+    let registry = ProjectRegistry::new();
+    engine.start_recording(4096);
+
+    let scope = ScopeRef::Module {
+        owner: engine.tree().root(),
+    };
+    engine.panel_write(
+        scope,
+        ChannelName("clock.rate".to_string()),
+        LpValue::F32(2.0),
+        None,
+    );
+    engine.tick(&registry, 16).expect("tick 0");
+    button.arm();
+    engine.tick(&registry, 16).expect("tick 1");
+    engine
+        .handle_node_command(probe, &WireNodeCommand::PlaylistActivateEntry { entry: 3 })
+        .expect("command");
+    engine.tick(&registry, 33).expect("tick 2");
+    engine.tick(&registry, 7).expect("tick 3");
+
+    let recorder = engine.stop_recording().expect("recording");
+    FrameLog::decode(&recorder.encode_log()).expect("decode log")
+}
+
+#[test]
+fn a_recording_replays_bit_exact_into_a_fresh_engine() {
+    let log = record_session();
+    assert_eq!(log.first_frame, 0);
+    assert_eq!(log.frames.len(), 4);
+    assert!(matches!(
+        log.frames[0].events.as_slice(),
+        [FrameEvent::PanelWrite { scope, .. }] if scope == "/show.t"
+    ));
+    assert!(matches!(
+        log.frames[1].events.as_slice(),
+        [FrameEvent::Button { pressed: true, .. }]
+    ));
+    assert!(matches!(
+        log.frames[2].events.as_slice(),
+        [FrameEvent::NodeCommand { node, .. }] if node == "/show.t/probe.output"
+    ));
+    // Every frame changed the output, so every checksum is distinct: a replay
+    // that lost any one event could not match by accident.
+    let mut checksums: Vec<u32> = log.frames.iter().map(|frame| frame.checksum).collect();
+    checksums.dedup();
+    assert_eq!(checksums.len(), 4);
+
+    // No button service at all: the replay stands in for the hardware.
+    let (mut replay, _) = probe_engine(None);
+    let report = replay_frame_log(&mut replay, &ProjectRegistry::new(), &log).expect("replays");
+    assert_eq!(report.frames, 4);
+    assert!(
+        report.skipped_events.is_empty(),
+        "{:?}",
+        report.skipped_events
+    );
+    assert!(report.is_exact(), "{:?}", report.divergences);
+    assert_eq!(replay.panel_writers().len(), 1);
+}
+
+#[test]
+fn replay_reports_the_first_divergent_frame() {
+    let mut log = record_session();
+    // A different delta on frame 2 is a different time from there on.
+    log.frames[2].delta_ms += 1;
+
+    let (mut replay, _) = probe_engine(None);
+    let report = replay_frame_log(&mut replay, &ProjectRegistry::new(), &log).expect("replays");
+    assert_eq!(report.divergent_frames, 2);
+    let first = report.first_divergence().expect("divergence");
+    assert_eq!(first.frame, 2);
+    assert_eq!(first.recorded, log.frames[2].checksum);
+    assert_ne!(first.replayed, first.recorded);
+}
+
+#[test]
+fn a_log_whose_head_was_dropped_is_refused() {
+    let mut log = record_session();
+    log.first_frame = 2;
+    log.frames.drain(..2);
+
+    let (mut replay, _) = probe_engine(None);
+    let error = replay_frame_log(&mut replay, &ProjectRegistry::new(), &log)
+        .expect_err("a truncated log cannot replay from a project load");
+    assert_eq!(error, ReplayError::HeadDropped { first_frame: 2 });
+    assert_eq!(
+        replay.frame_num(),
+        FrameNum::default(),
+        "the engine was not ticked"
+    );
+}
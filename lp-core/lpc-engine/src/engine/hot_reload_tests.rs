@@ -0,0 +1,380 @@
+//! Runtime state carried across hot-reload rebuilds (`NodeRuntime::
+//! export_state` / `import_state`).
+//!
+//! Each test drives a project the way live tuning does — an edit on disk,
+//! `refresh_artifacts`, `apply_project_changes` — and checks that the
+//! rebuilt node picks up where the old one stopped rather than at zero.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lpc_model::{
+    LpValue, NodeId, NodeName, NodeUseLocation, Revision, SlotPath, TreePath, VisualProduct,
+};
+use lpc_registry::{ParseCtx, ProjectRegistry};
+use lpc_wire::WireNodeCommand;
+use lpfs::{AsLpPath, FsEvent, FsEventKind, LpFs, LpFsMemory, LpPathBuf};
+use lps_shared::TextureStorageFormat;
+
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, RuntimeApplyResult};
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualSpace};
+
+const TICK_MS: u32 = 100;
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+    fs: LpFsMemory,
+    revision: i64,
+}
+
+impl Project {
+    fn load(fs: LpFsMemory) -> Self {
+        let services = EngineServices::new(TreePath::parse("/hot_reload.show").expect("path"));
+        let (engine, registry) = ProjectLoader::load_from_root(&fs, services)
+            .expect("load project")
+            .into_parts();
+        Self {
+            engine,
+            registry,
+            fs,
+            revision: 1,
+        }
+    }
+
+    fn tick(&mut self) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+    }
+
+    /// Write `body` to `path` and apply it, the way an authoring edit does.
+    fn edit(&mut self, path: &str, body: &str) -> RuntimeApplyResult {
+        write(&self.fs, path, body);
+        let shapes = self.engine.slot_shapes().clone();
+        self.revision += 1;
+        let changes = self.registry.refresh_artifacts(
+            &self.fs,
+            &[FsEvent {
+                path: LpPathBuf::from(path),
+                kind: FsEventKind::Modify,
+            }],
+            Revision::new(self.revision),
+            &ParseCtx { shapes: &shapes },
+        );
+        self.engine
+            .apply_project_changes(&self.fs, &mut self.registry, &changes)
+            .expect("apply project changes")
+    }
+
+    fn node(&self, name: &str) -> NodeId {
+        self.engine
+            .tree()
+            .lookup_sibling(
+                self.engine.tree().root(),
+                NodeName::parse(name).expect("name"),
+            )
+            .expect("node")
+    }
+
+    fn resolve(&mut self, node: NodeId, slot: &str) -> LpValue {
+        let (production, _) = super::resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node,
+                slot: SlotPath::parse(slot).expect("slot"),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("resolve");
+        production.value_leaf().expect("value").value().clone()
+    }
+
+    fn f32(&mut self, node: NodeId, slot: &str) -> f32 {
+        let LpValue::F32(value) = self.resolve(node, slot) else {
+            panic!("{slot} should be f32");
+        };
+        value
+    }
+
+    fn u32(&mut self, node: NodeId, slot: &str) -> u32 {
+        let LpValue::U32(value) = self.resolve(node, slot) else {
+            panic!("{slot} should be u32");
+        };
+        value
+    }
+
+    /// Tick and read the clock, so the clock produces once per frame.
+    fn clock_seconds_after_tick(&mut self) -> f32 {
+        self.tick();
+        let clock = self.node("clock");
+        self.f32(clock, "seconds")
+    }
+
+    fn fluid_pixels(&mut self, fluid: NodeId) -> Vec<u8> {
+        self.engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(fluid, 0),
+                &RenderTextureRequest {
+                    width: 8,
+                    height: 8,
+                    format: TextureStorageFormat::Rgba16Unorm,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render fluid")
+            .try_raw_bytes()
+            .expect("bytes")
+            .to_vec()
+    }
+}
+
+fn use_location(name: &str) -> NodeUseLocation {
+    NodeUseLocation::root().child(SlotPath::parse(&alloc::format!("nodes[{name}]")).unwrap())
+}
+
+fn write(fs: &LpFsMemory, path: &str, body: &str) {
+    let path = String::from(path);
+    fs.write_file(path.as_str().as_path(), body.as_bytes())
+        .expect("write project file");
+}
+
+fn module(nodes: &[(&str, &str)]) -> String {
+    let nodes = nodes
+        .iter()
+        .map(|(name, file)| alloc::format!("\"{name}\": {{ \"ref\": \"./{file}\" }}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    alloc::format!("{{ \"kind\": \"Module\", \"nodes\": {{ {nodes} }} }}")
+}
+
+fn project_fs(nodes: &[(&str, &str, &str)]) -> LpFsMemory {
+    let fs = LpFsMemory::new();
+    write(&fs, "/project.json", "{ \"format\": 8 }\n");
+    let uses = nodes
+        .iter()
+        .map(|(name, file, _)| (*name, *file))
+        .collect::<Vec<_>>();
+    write(&fs, "/module.json", &module(&uses));
+    for (_, file, body) in nodes {
+        write(&fs, &alloc::format!("/{file}"), body);
+    }
+    fs
+}
+
+const CLOCK: &str = r#"{ "kind": "Clock" }"#;
+
+fn fluid(viscosity: &str, emitters: bool) -> String {
+    let emitters = if emitters {
+        r#",
+  "emitters": {
+    "1": {
+      "id": 1, "pos": [0.5, 0.5], "dir": [1.0, 0.0], "radius": 0.2,
+      "color": [1.0, 0.0, 0.0], "velocity": 0.0, "intensity": 2.0
+    }
+  }"#
+    } else {
+        ""
+    };
+    alloc::format!(
+        r#"{{
+  "kind": "Fluid",
+  "size": {{ "width": 8, "height": 8 }},
+  "solver_iterations": 1,
+  "step_hz": 25.0,
+  "fade_speed": 0.0,
+  "viscosity": {viscosity}{emitters}
+}}"#
+    )
+}
+
+fn playlist(extra_entry: bool, active_entry: bool) -> String {
+    let mut entries = alloc::vec![String::from(
+        r#""1": { "name": "idle", "node": { "ref": "./idle.json" } }"#
+    )];
+    if active_entry {
+        entries.push(String::from(
+            r#""2": { "name": "active", "duration": 10.0, "node": { "ref": "./active.json" } }"#,
+        ));
+    }
+    if extra_entry {
+        entries.push(String::from(
+            r#""3": { "name": "encore", "duration": 10.0, "node": { "ref": "./active.json" } }"#,
+        ));
+    }
+    alloc::format!(
+        r#"{{
+  "kind": "Playlist",
+  "default_fade": 0.0,
+  "bindings": {{ "time": {{ "source": "bus:time" }} }},
+  "entries": {{ {} }}
+}}"#,
+        entries.join(", ")
+    )
+}
+
+fn playlist_project() -> Project {
+    let idle = fluid("3e-05", false);
+    let active = fluid("3e-05", true);
+    let list = playlist(false, true);
+    let fs = project_fs(&[
+        ("clock", "clock.json", CLOCK),
+        ("playlist", "playlist.json", &list),
+    ]);
+    // Entry children are used by the playlist only, not by the module.
+    write(&fs, "/idle.json", &idle);
+    write(&fs, "/active.json", &active);
+    Project::load(fs)
+}
+
+/// Switch to entry 2 through the command channel; the switch lands on the
+/// next produce.
+fn activate_second_entry(project: &mut Project, list: NodeId) {
+    project.tick();
+    assert_eq!(project.u32(list, "active_entry"), 1);
+    project
+        .engine
+        .handle_node_command(list, &WireNodeCommand::PlaylistActivateEntry { entry: 2 })
+        .expect("activate entry");
+    project.tick();
+    assert_eq!(project.u32(list, "active_entry"), 2);
+}
+
+#[test]
+fn a_clock_rebuilt_by_a_definition_change_keeps_its_time() {
+    let mut project = Project::load(project_fs(&[("clock", "clock.json", CLOCK)]));
+    let mut before = 0.0;
+    for _ in 0..5 {
+        before = project.clock_seconds_after_tick();
+    }
+    assert!(before > 0.3, "clock ran: {before}");
+    let old_clock = project.node("clock");
+
+    write(&project.fs, "/clock_b.json", CLOCK);
+    let apply = project.edit("/module.json", &module(&[("clock", "clock_b.json")]));
+
+    assert_eq!(apply.reattached_nodes, alloc::vec![use_location("clock")]);
+    assert_eq!(apply.migrated_nodes, alloc::vec![use_location("clock")]);
+    let new_clock = project.node("clock");
+    assert_ne!(new_clock, old_clock);
+    // The published timebase moved with it, phasors and all.
+    assert_eq!(project.engine.timebases().seconds(new_clock), Some(before));
+    assert!(project.engine.timebases().entry(old_clock).is_none());
+
+    let after = project.clock_seconds_after_tick();
+    assert!(
+        (after - (before + 0.1)).abs() < 1e-4,
+        "clock continued from {before}, got {after}"
+    );
+}
+
+#[test]
+fn a_clock_whose_definition_was_broken_resumes_when_it_is_fixed() {
+    let mut project = Project::load(project_fs(&[("clock", "clock.json", CLOCK)]));
+    let mut before = 0.0;
+    for _ in 0..5 {
+        before = project.clock_seconds_after_tick();
+    }
+
+    let broken = project.edit("/clock.json", "{ \"kind\": ");
+    assert!(broken.migrated_nodes.is_empty());
+    project.tick();
+
+    let fixed = project.edit("/clock.json", CLOCK);
+    assert_eq!(fixed.migrated_nodes, alloc::vec![use_location("clock")]);
+    // The clock kept its place, and caught up on the wall time it missed
+    // while its definition would not parse.
+    let after = project.clock_seconds_after_tick();
+    assert!(
+        after > before + 0.1,
+        "clock resumed from {before}, got {after}"
+    );
+}
+
+#[test]
+fn a_fluid_rebuilt_by_a_definition_change_keeps_its_simulation() {
+    let with_emitter = fluid("3e-05", true);
+    let mut project = Project::load(project_fs(&[
+        ("clock", "clock.json", CLOCK),
+        ("fluid", "fluid.json", &with_emitter),
+    ]));
+    let old_fluid = project.node("fluid");
+    for _ in 0..3 {
+        project.tick();
+        let _ = project.resolve(old_fluid, "output");
+    }
+    let before = project.fluid_pixels(old_fluid);
+    assert!(
+        before
+            .chunks_exact(8)
+            .any(|px| u16::from_le_bytes([px[0], px[1]]) > 0),
+        "the emitter stamped the field"
+    );
+
+    // New def, new viscosity, no emitter: nothing but the carried solver
+    // can put dye on the field from here on.
+    write(&project.fs, "/fluid_b.json", &fluid("1e-04", false));
+    let apply = project.edit(
+        "/module.json",
+        &module(&[("clock", "clock.json"), ("fluid", "fluid_b.json")]),
+    );
+    assert_eq!(apply.migrated_nodes, alloc::vec![use_location("fluid")]);
+    let new_fluid = project.node("fluid");
+    assert_ne!(new_fluid, old_fluid);
+    assert_eq!(project.fluid_pixels(new_fluid), before);
+
+    // The viscosity change is applied in place on the next step, not by
+    // reallocating the grid.
+    project.tick();
+    let _ = project.resolve(new_fluid, "output");
+    assert!(
+        project
+            .fluid_pixels(new_fluid)
+            .chunks_exact(8)
+            .any(|px| u16::from_le_bytes([px[0], px[1]]) > 0),
+        "the field survived the first step under the new config"
+    );
+}
+
+#[test]
+fn a_playlist_rebuilt_by_an_entry_edit_keeps_its_position() {
+    let mut project = playlist_project();
+    let list = project.node("playlist");
+    activate_second_entry(&mut project, list);
+    for _ in 0..3 {
+        project.tick();
+        assert_eq!(project.u32(list, "active_entry"), 2);
+    }
+    let before = project.f32(list, "entry_time");
+    assert!(before > 0.2, "entry ran: {before}");
+
+    // Adding an entry rebuilds the playlist and every entry under it.
+    let apply = project.edit("/playlist.json", &playlist(true, true));
+    assert!(apply.migrated_nodes.contains(&use_location("playlist")));
+    let list = project.node("playlist");
+
+    project.tick();
+    assert_eq!(project.u32(list, "active_entry"), 2);
+    let after = project.f32(list, "entry_time");
+    assert!(
+        (after - (before + 0.1)).abs() < 1e-4,
+        "entry clock continued from {before}, got {after}"
+    );
+}
+
+#[test]
+fn a_playlist_whose_active_entry_was_removed_starts_over() {
+    let mut project = playlist_project();
+    let list = project.node("playlist");
+    activate_second_entry(&mut project, list);
+
+    let apply = project.edit("/playlist.json", &playlist(true, false));
+    assert!(!apply.migrated_nodes.contains(&use_location("playlist")));
+    let list = project.node("playlist");
+
+    project.tick();
+    assert_eq!(project.u32(list, "active_entry"), 1);
+}
@@ -0,0 +1,326 @@
+//! Image nodes end to end: loader attach, playback off a timebase, and the
+//! streamed read path.
+//!
+//! Every fixture is a file from the `lpc-image` corpus, so the expected
+//! pixels come from the corpus's own `.rgba` decodes rather than from this
+//! crate. Renders are requested at the image's native size, where `Fit` is
+//! the identity and the comparison can be exact.
+
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+use lpc_image::corpus::{CorpusImage, NOISE_FULL_TABLE_GIF, RGB24_BMP, SHEET_4X2_BMP};
+use lpc_model::{ChannelName, Kind, LpValue, NodeId, ProductRef, TimeProduct, TreePath};
+use lpc_registry::ProjectRegistry;
+use lpfs::lp_path::LpPath;
+use lpfs::{AsLpPath, FsError, LpFs, LpFsMemory};
+use lps_shared::TextureStorageFormat;
+
+use crate::dataflow::binding::{BindingDraft, BindingPriority, BindingSource, BindingTarget};
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{
+    AssetStreamService, Engine, EngineServices, ProjectLoader, resolve_with_engine_host,
+};
+use crate::nodes::image_output_path;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+}
+
+impl Project {
+    fn node(&self, suffix: &str) -> NodeId {
+        self.engine
+            .tree()
+            .entries()
+            .find(|entry| entry.path.to_string().ends_with(suffix))
+            .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+            .id
+    }
+
+    /// One frame, then a demand on the image's output: nothing consumes it
+    /// here, and `produce` is where the player opens and picks its frame.
+    fn tick(&mut self, image: NodeId) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node: image,
+                slot: image_output_path(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("demand the image");
+    }
+
+    /// Render the image at `width`×`height` and return 8-bit RGBA.
+    fn render(&mut self, image: NodeId, width: u32, height: u32) -> Vec<u8> {
+        self.render_as(image, width, height, TextureStorageFormat::Rgba16Unorm)
+            .chunks_exact(2)
+            .map(|lane| (u16::from_le_bytes([lane[0], lane[1]]) / 257) as u8)
+            .collect()
+    }
+
+    /// Render the image at `width`×`height` and return the raw `format`
+    /// bytes.
+    fn render_as(
+        &mut self,
+        image: NodeId,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Vec<u8> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(image, 0),
+                &RenderTextureRequest {
+                    width,
+                    height,
+                    format,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render image");
+        texture
+            .try_raw_bytes()
+            .expect("host texture bytes")
+            .to_vec()
+    }
+
+    /// Publish `timebase`'s time product on `bus:time`, where the image's
+    /// `time` slot binds by default.
+    fn publish_time_product(&mut self, timebase: NodeId) {
+        let owner = self.engine.tree().root();
+        let revision = self.engine.revision();
+        self.engine
+            .add_binding(
+                BindingDraft {
+                    source: BindingSource::Literal(LpValue::Product(ProductRef::Time(
+                        TimeProduct::new(timebase, 0),
+                    ))),
+                    target: BindingTarget::BusChannel(ChannelName(String::from("time"))),
+                    priority: BindingPriority::authored(),
+                    kind: Kind::Instant,
+                    owner,
+                },
+                revision,
+            )
+            .expect("register time literal");
+    }
+
+    fn set_timebase(&mut self, timebase: NodeId, seconds: f32, delta: f32) {
+        let revision = self.engine.revision();
+        self.engine
+            .timebases_mut()
+            .set_timebase(timebase, seconds, delta, revision);
+    }
+}
+
+/// A file system whose ranged reads are counted, standing in for a host's
+/// flash-backed stream service.
+struct CountingStream {
+    fs: LpFsMemory,
+    reads: Cell<u32>,
+    largest_read: Cell<usize>,
+}
+
+impl AssetStreamService for CountingStream {
+    fn file_size(&self, path: &LpPath) -> Result<u64, FsError> {
+        self.fs.file_size(path)
+    }
+
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        self.reads.set(self.reads.get() + 1);
+        let read = self.fs.read_file_range(path, offset, buf)?;
+        self.largest_read.set(self.largest_read.get().max(read));
+        Ok(read)
+    }
+}
+
+// --- Project fixtures ------------------------------------------------------
+
+/// A clock plus one image node reading `image` from `file`.
+fn image_fs(image: &CorpusImage, file: &str, extra: &str) -> LpFsMemory {
+    let fs = LpFsMemory::new();
+    let write = |path: &str, body: &[u8]| {
+        fs.write_file(path.as_path(), body)
+            .expect("write project file");
+    };
+    write("/project.json", b"{ \"format\": 8 }\n");
+    write(&alloc::format!("/{file}"), image.bytes);
+    write(
+        "/clock.json",
+        br#"{ "kind": "Clock", "bindings": { "product": { "target": "bus:clock_product" } } }"#,
+    );
+    write(
+        "/image.json",
+        alloc::format!(r#"{{ "kind": "Image", "source": {{ "path": "{file}" }}{extra} }}"#)
+            .as_bytes(),
+    );
+    write(
+        "/module.json",
+        br#"{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "image": { "ref": "./image.json" }
+  }
+}
+"#,
+    );
+    fs
+}
+
+fn load(fs: &LpFsMemory, services: EngineServices) -> Project {
+    let loaded = ProjectLoader::load_from_root(fs, services).expect("load project");
+    let (mut engine, registry) = loaded.into_parts();
+    engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+        lp_shader::ShaderFrontend::LpsGlsl,
+    ))));
+    Project { engine, registry }
+}
+
+fn services() -> EngineServices {
+    EngineServices::new(TreePath::parse("/image.show").expect("root"))
+}
+
+/// Frame `index` of a corpus image's expected decode.
+fn expected_frame(image: &CorpusImage, width: u32, height: u32, index: usize) -> &'static [u8] {
+    let len = width as usize * height as usize * 4;
+    &image.expected[index * len..(index + 1) * len]
+}
+
+// --- Tests -----------------------------------------------------------------
+
+/// A still image renders its pixels with nothing on `bus:time`: only an
+/// animation resolves the time product.
+#[test]
+fn still_bmp_renders_without_a_time_product() {
+    let fs = image_fs(
+        &RGB24_BMP,
+        "still.bmp",
+        r#", "size": { "width": 5, "height": 4 }"#,
+    );
+    let mut project = load(&fs, services());
+    let image = project.node("image.image");
+
+    project.tick(image);
+
+    assert_eq!(
+        project.render(image, 5, 4),
+        expected_frame(&RGB24_BMP, 5, 4, 0)
+    );
+}
+
+/// A consumer asking for a narrower storage format gets the same pixels in
+/// that format: RGB8 drops alpha, R8 keeps red.
+#[test]
+fn still_bmp_renders_in_narrow_storage_formats() {
+    let fs = image_fs(
+        &RGB24_BMP,
+        "still.bmp",
+        r#", "size": { "width": 5, "height": 4 }"#,
+    );
+    let mut project = load(&fs, services());
+    let image = project.node("image.image");
+
+    project.tick(image);
+
+    let expected = expected_frame(&RGB24_BMP, 5, 4, 0);
+    let rgb: Vec<u8> = expected
+        .chunks_exact(4)
+        .flat_map(|texel| texel[..3].to_vec())
+        .collect();
+    let red: Vec<u8> = expected.chunks_exact(4).map(|texel| texel[0]).collect();
+    assert_eq!(
+        project.render_as(image, 5, 4, TextureStorageFormat::Rgb8Unorm),
+        rgb
+    );
+    assert_eq!(
+        project.render_as(image, 5, 4, TextureStorageFormat::R8Unorm),
+        red
+    );
+}
+
+/// A sprite sheet steps one cell per second at `fps: 1`, off the timebase
+/// the image's `time` slot reads.
+#[test]
+fn sprite_sheet_frames_follow_the_timebase() {
+    let fs = image_fs(
+        &SHEET_4X2_BMP,
+        "sheet.bmp",
+        r#", "size": { "width": 8, "height": 8 }, "fps": 1.0, "sheet_columns": 4, "sheet_rows": 2"#,
+    );
+    let mut project = load(&fs, services());
+    let clock = project.node("clock.clock");
+    let image = project.node("image.image");
+    project.publish_time_product(clock);
+
+    // Cell centers of the 32×16 sheet, in playback order.
+    let sheet = expected_frame(&SHEET_4X2_BMP, 32, 16, 0);
+    let center = |cell: usize| {
+        let (x, y) = ((cell % 4) * 8 + 4, (cell / 4) * 8 + 4);
+        let offset = (y * 32 + x) * 4;
+        &sheet[offset..offset + 4]
+    };
+    for (seconds, cell) in [(0.0, 0), (2.5, 2), (5.0, 5), (9.5, 1)] {
+        project.set_timebase(clock, seconds, 0.1);
+        project.tick(image);
+        let pixels = project.render(image, 8, 8);
+        let offset = (4 * 8 + 4) * 4;
+        assert_eq!(&pixels[offset..offset + 4], center(cell), "at {seconds}s");
+    }
+}
+
+/// With a stream service the node never holds the file: it decodes through
+/// ranged reads no larger than its read window, and still matches the
+/// reference decode.
+#[test]
+fn streamed_gif_decodes_through_ranged_reads() {
+    let stream = Rc::new(CountingStream {
+        fs: image_fs(
+            &NOISE_FULL_TABLE_GIF,
+            "noise.gif",
+            r#", "size": { "width": 64, "height": 64 }"#,
+        ),
+        reads: Cell::new(0),
+        largest_read: Cell::new(0),
+    });
+    let mut services = services();
+    services.set_asset_stream(Some(stream.clone()));
+    let mut project = load(&stream.fs, services);
+    let image = project.node("image.image");
+
+    project.tick(image);
+
+    assert_eq!(
+        project.render(image, 64, 64),
+        expected_frame(&NOISE_FULL_TABLE_GIF, 64, 64, 0)
+    );
+    assert!(
+        stream.reads.get() > 0,
+        "the file is read through the stream"
+    );
+    assert!(
+        stream.largest_read.get() < NOISE_FULL_TABLE_GIF.bytes.len(),
+        "no single read pulls the whole file"
+    );
+}
@@ -4,10 +4,34 @@ mod engine;
 mod engine_error;
 mod engine_services;
 pub mod error;
+#[cfg(all(test, feature = "node-filter", feature = "node-shader"))]
+mod filter_node_tests;
+mod frame_log;
 mod frame_num;
+mod frame_recorder;
+mod frame_replay;
+// Recording a shader-less output chain and replaying it into a second engine.
+#[cfg(test)]
+mod frame_replay_tests;
 mod frame_time;
+// Clock, fluid and playlist state surviving the rebuild an authoring edit
+// triggers.
+#[cfg(all(
+    test,
+    feature = "node-clock",
+    feature = "node-fluid",
+    feature = "node-playlist"
+))]
+mod hot_reload_tests;
+// Image nodes playing corpus files off a clock's timebase.
+#[cfg(all(test, feature = "node-clock", feature = "node-image"))]
+mod image_node_tests;
 mod loaded_project_runtime;
 pub mod memory_pressure;
+mod node_budget;
+// Budgets driven by dummy nodes that charge a fixed cost to a test clock.
+#[cfg(test)]
+mod node_budget_tests;
 // All three tests in this module exercise a Fixture node fed by a Shader
 // node's output slot, so the module needs both node kinds.
 #[cfg(all(test, feature = "node-fixture", feature = "node-shader"))]
@@ -17,6 +41,20 @@ mod output_flush_tests;
 // re-render would have pulled.
 #[cfg(all(test, feature = "node-fixture", feature = "node-shader"))]
 mod output_frame_probe_tests;
+// Palette nodes selecting and crossfading off a clock's timebase.
+#[cfg(all(test, feature = "node-clock", feature = "node-palette"))]
+mod palette_node_tests;
+// Particle nodes stepping off a clock's timebase.
+#[cfg(all(test, feature = "node-clock", feature = "node-particle"))]
+mod particle_node_tests;
+// Playlist nodes forwarding a consumer's request to a shader entry.
+#[cfg(all(
+    test,
+    feature = "node-clock",
+    feature = "node-playlist",
+    feature = "node-shader"
+))]
+mod playlist_node_tests;
 mod project_apply;
 mod project_loader;
 mod project_read_nodes;
@@ -31,6 +69,9 @@ mod resolution_persistence_tests;
 // Compute-shader nodes reading a clock's timebase through `bus:time`.
 #[cfg(test)]
 mod scoped_resolution_tests;
+// Visual shader nodes reading their own (or another product's) last frame.
+#[cfg(all(test, feature = "node-shader"))]
+mod shader_feedback_tests;
 // Visual shader nodes baking palette strips off a clock's timebase.
 #[cfg(all(test, feature = "node-clock", feature = "node-shader"))]
 mod shader_palette_tests;
@@ -39,6 +80,9 @@ mod shader_timebase_tests;
 mod srgb8_lut;
 #[cfg(test)]
 pub(crate) mod test_support;
+// Text nodes scrolling and cycling palettes off a clock's timebase.
+#[cfg(all(test, feature = "node-clock", feature = "node-text"))]
+mod text_node_tests;
 // Every project here drives a clock through an output → fixture → shader →
 // `bus:time` demand chain, so the module needs the clock, fixture and shader
 // node kinds.
@@ -56,10 +100,21 @@ pub use engine::Engine;
 #[cfg(all(test, feature = "node-fixture", feature = "node-shader"))]
 pub(crate) use engine::default_demand_input_path;
 pub use engine_error::EngineError;
-pub use engine_services::{ButtonService, EngineServices, OutputFlushError, RadioService};
+pub use engine_services::{
+    AssetStreamService, ButtonService, EngineServices, OutputFlushError, RadioService,
+};
+pub use frame_log::{
+    FRAME_LOG_MAGIC, FRAME_LOG_VERSION, FrameEvent, FrameLog, FrameLogError, RecordedFrame,
+};
 pub use frame_num::FrameNum;
+pub use frame_recorder::FrameRecorder;
+pub use frame_replay::{FrameDivergence, ReplayError, ReplayReport, replay_frame_log};
 pub use frame_time::FrameTime;
 pub use loaded_project_runtime::LoadedProjectRuntime;
+pub use node_budget::{
+    AuthoredBudget, DEGRADE_AFTER_FRAMES, Degradation, MAX_DEGRADE_LEVEL, NodeBudgetStore,
+    NodeCost, RECOVER_AFTER_FRAMES,
+};
 pub use project_apply::RuntimeApplyResult;
 pub use project_loader::{ProjectLoadError, ProjectLoader};
 pub use project_read_stream::{EngineProjectReadSource, ProjectReadEventStreamError};
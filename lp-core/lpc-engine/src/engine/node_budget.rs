@@ -0,0 +1,363 @@
+//! Per-node CPU time budgets and the degradation they drive.
+//!
+//! On a small board one heavy node starves the whole frame: everything
+//! downstream renders late and the lamps stutter. The engine therefore
+//! meters each node's own time — tick and render, with the time spent in
+//! nodes it pulled from subtracted — and when a node stays over its authored
+//! budget it is degraded one step at a time, in the way its kind allows
+//! ([`DegradeMode`]). A node that has stayed well under budget for a while
+//! steps back up.
+//!
+//! Store, not node state (the `timebases` precedent): this is Engine state
+//! and must survive `apply_project_changes`. Authored budgets are re-read
+//! from module defs with the bindings ([`NodeBudgetStore::set_authored`]),
+//! so a budget edit lands on the next frame without resetting any meter.
+//!
+//! Costs come from [`lpc_shared::time::TimeProvider::now_us`]. An engine
+//! without a time provider meters nothing and never degrades.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_collection::VecMap;
+use lpc_model::{NodeId, NodeRuntimeStatus};
+
+use crate::node::DegradeMode;
+
+/// Consecutive over-budget frames before a node steps down a level. One
+/// slow frame (a compile, a first allocation) is not a trend.
+pub const DEGRADE_AFTER_FRAMES: u8 = 8;
+
+/// Consecutive frames under half budget before a node steps back up.
+///
+/// Much longer than the way down, and "half" rather than "under": each
+/// level halves the work, so a node that only just fits one level down
+/// would be back over budget the moment it stepped up, and oscillate.
+pub const RECOVER_AFTER_FRAMES: u8 = 60;
+
+/// Deepest degradation level; the divisor is `1 << level`, so 8.
+pub const MAX_DEGRADE_LEVEL: u8 = 3;
+
+/// Prefix of every status message the budget sets, so the engine can tell
+/// its own warning from one the node raised.
+const OVER_BUDGET: &str = "over budget";
+
+/// A node's budget, as the module defs above it author it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AuthoredBudget {
+    /// Per-frame budget in microseconds; `None` meters without degrading.
+    pub budget_us: Option<u32>,
+    /// Never degrade this node, whatever its kind's [`DegradeMode`].
+    pub critical: bool,
+}
+
+/// The degradation a node is running under.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Degradation {
+    pub mode: DegradeMode,
+    /// `1..=MAX_DEGRADE_LEVEL`.
+    pub level: u8,
+    pub budget_us: u32,
+}
+
+impl Degradation {
+    /// How much work is shed: the resolution, tick or update-rate divisor.
+    pub fn divisor(self) -> u32 {
+        1 << self.level
+    }
+
+    /// The runtime status message, e.g. `over budget (4000 µs): rendering
+    /// at 1/2 resolution`. Deliberately free of the measured cost, which
+    /// changes every frame and would churn the status revision with it.
+    pub fn describe(self) -> String {
+        let divisor = self.divisor();
+        let action = match self.mode {
+            DegradeMode::SkipTick => format!("ticking 1 frame in {divisor}"),
+            DegradeMode::Resolution => format!("rendering at 1/{divisor} resolution"),
+            DegradeMode::UpdateRate => format!("simulating at 1/{divisor} rate"),
+            DegradeMode::Critical => String::from("not degraded"),
+        };
+        format!("{OVER_BUDGET} ({} µs): {action}", self.budget_us)
+    }
+}
+
+/// Smoothed per-frame cost of one node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NodeCost {
+    /// Own time in `produce`/`consume`, on the frames it ticked.
+    pub tick_us: u32,
+    /// Own time rendering and sampling, on the frames it rendered.
+    pub render_us: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpanKind {
+    Tick,
+    Render,
+}
+
+struct OpenSpan {
+    node: NodeId,
+    kind: SpanKind,
+    start_us: u64,
+    nested_us: u64,
+}
+
+struct NodeMeter {
+    mode: DegradeMode,
+    cost: NodeCost,
+    frame_tick_us: Option<u64>,
+    frame_render_us: Option<u64>,
+    /// Sat this frame out: still a frame the budget judged, just one that
+    /// cost nothing.
+    frame_skipped: bool,
+    ticked_once: bool,
+    level: u8,
+    over_frames: u8,
+    under_frames: u8,
+}
+
+impl NodeMeter {
+    fn new(mode: DegradeMode) -> Self {
+        Self {
+            mode,
+            cost: NodeCost::default(),
+            frame_tick_us: None,
+            frame_render_us: None,
+            frame_skipped: false,
+            ticked_once: false,
+            level: 0,
+            over_frames: 0,
+            under_frames: 0,
+        }
+    }
+
+    /// The cost compared against the budget. A skipped tick costs nothing,
+    /// so under [`DegradeMode::SkipTick`] the tick share is amortized over
+    /// the frames it skips; the other modes make the work itself cheaper,
+    /// which the measurement already shows.
+    fn budgeted_us(&self) -> u32 {
+        let tick_us = match self.mode {
+            DegradeMode::SkipTick => self.cost.tick_us >> self.level,
+            _ => self.cost.tick_us,
+        };
+        tick_us.saturating_add(self.cost.render_us)
+    }
+}
+
+/// Engine-owned meters and degradation levels, keyed by node.
+#[derive(Default)]
+pub struct NodeBudgetStore {
+    authored: VecMap<NodeId, AuthoredBudget>,
+    meters: VecMap<NodeId, NodeMeter>,
+    open: Vec<OpenSpan>,
+    frame: u32,
+}
+
+impl NodeBudgetStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace every authored budget. Meters and levels are kept; a node
+    /// whose budget went away steps back to full quality at frame end.
+    pub fn set_authored(&mut self, authored: VecMap<NodeId, AuthoredBudget>) {
+        self.authored = authored;
+    }
+
+    pub fn authored(&self, node: NodeId) -> AuthoredBudget {
+        self.authored.get(&node).copied().unwrap_or_default()
+    }
+
+    /// Smoothed cost of `node`, once it has been metered.
+    pub fn cost(&self, node: NodeId) -> Option<NodeCost> {
+        self.meters.get(&node).map(|meter| meter.cost)
+    }
+
+    /// The degradation `node` is under, if any.
+    pub fn degradation(&self, node: NodeId) -> Option<Degradation> {
+        let meter = self.meters.get(&node)?;
+        let budget_us = self.authored(node).budget_us?;
+        (meter.level > 0).then_some(Degradation {
+            mode: meter.mode,
+            level: meter.level,
+            budget_us,
+        })
+    }
+
+    /// Whether `node` sits this frame's tick out. Its consumers read what it
+    /// produced last; a node that never ticked always ticks. Nodes at the
+    /// same level are staggered so they do not all tick on the same frame.
+    pub fn skip_tick(&mut self, node: NodeId) -> bool {
+        let Some(degradation) = self.degradation(node) else {
+            return false;
+        };
+        let frame = self.frame;
+        let Some(meter) = self.meters.get_mut(&node) else {
+            return false;
+        };
+        let skip = degradation.mode == DegradeMode::SkipTick
+            && meter.ticked_once
+            && frame.wrapping_add(node.0) % degradation.divisor() != 0;
+        meter.frame_skipped |= skip;
+        skip
+    }
+
+    /// Open a tick span for `node` at `now_us`.
+    pub fn begin_tick(&mut self, node: NodeId, mode: DegradeMode, now_us: u64) {
+        self.begin(node, mode, SpanKind::Tick, now_us);
+    }
+
+    /// Open a render span for `node` at `now_us`.
+    pub fn begin_render(&mut self, node: NodeId, mode: DegradeMode, now_us: u64) {
+        self.begin(node, mode, SpanKind::Render, now_us);
+    }
+
+    fn begin(&mut self, node: NodeId, mode: DegradeMode, kind: SpanKind, now_us: u64) {
+        self.meters
+            .entry(node)
+            .or_insert_with(|| NodeMeter::new(mode))
+            .mode = mode;
+        self.open.push(OpenSpan {
+            node,
+            kind,
+            start_us: now_us,
+            nested_us: 0,
+        });
+    }
+
+    /// Close the innermost span at `now_us`, charging its node the elapsed
+    /// time minus what the nodes it pulled from spent inside it.
+    pub fn end(&mut self, now_us: u64) {
+        let Some(span) = self.open.pop() else {
+            return;
+        };
+        let elapsed = now_us.saturating_sub(span.start_us);
+        if let Some(parent) = self.open.last_mut() {
+            parent.nested_us = parent.nested_us.saturating_add(elapsed);
+        }
+        let own = elapsed.saturating_sub(span.nested_us);
+        let Some(meter) = self.meters.get_mut(&span.node) else {
+            return;
+        };
+        let slot = match span.kind {
+            SpanKind::Tick => {
+                meter.ticked_once = true;
+                &mut meter.frame_tick_us
+            }
+            SpanKind::Render => &mut meter.frame_render_us,
+        };
+        *slot = Some(slot.unwrap_or(0).saturating_add(own));
+    }
+
+    /// Fold this frame's measurements in and move levels; returns every node
+    /// whose degradation changed, with the new one (`None`: full quality).
+    /// Meters of nodes `alive` rejects are dropped.
+    pub fn end_frame(
+        &mut self,
+        mut alive: impl FnMut(NodeId) -> bool,
+    ) -> Vec<(NodeId, Option<Degradation>)> {
+        // A span left open is a node that unwound mid-call; its time is
+        // lost rather than charged to whoever opened the next one.
+        self.open.clear();
+        self.meters.retain(|node, _| alive(*node));
+        let mut changed = Vec::new();
+        for (node, meter) in self.meters.iter_mut() {
+            let ticked = meter.frame_tick_us.take();
+            let rendered = meter.frame_render_us.take();
+            let skipped = core::mem::take(&mut meter.frame_skipped);
+            if let Some(us) = ticked {
+                meter.cost.tick_us = smooth(meter.cost.tick_us, us);
+            }
+            if let Some(us) = rendered {
+                meter.cost.render_us = smooth(meter.cost.render_us, us);
+            }
+            let authored = self.authored.get(node).copied().unwrap_or_default();
+            let budget_us = match authored.budget_us {
+                Some(us) if !authored.critical && meter.mode != DegradeMode::Critical => us,
+                _ => {
+                    meter.over_frames = 0;
+                    meter.under_frames = 0;
+                    if meter.level > 0 {
+                        meter.level = 0;
+                        changed.push((*node, None));
+                    }
+                    continue;
+                }
+            };
+            if ticked.is_none() && rendered.is_none() && !skipped {
+                continue;
+            }
+            let before = meter.level;
+            let cost = meter.budgeted_us();
+            if cost > budget_us {
+                meter.under_frames = 0;
+                meter.over_frames = meter.over_frames.saturating_add(1);
+                if meter.over_frames >= DEGRADE_AFTER_FRAMES && meter.level < MAX_DEGRADE_LEVEL {
+                    meter.level += 1;
+                    meter.over_frames = 0;
+                }
+            } else if cost.saturating_mul(2) < budget_us {
+                meter.over_frames = 0;
+                meter.under_frames = meter.under_frames.saturating_add(1);
+                if meter.under_frames >= RECOVER_AFTER_FRAMES && meter.level > 0 {
+                    meter.level -= 1;
+                    meter.under_frames = 0;
+                }
+            } else {
+                meter.over_frames = 0;
+                meter.under_frames = 0;
+            }
+            if meter.level != before {
+                changed.push((
+                    *node,
+                    (meter.level > 0).then_some(Degradation {
+                        mode: meter.mode,
+                        level: meter.level,
+                        budget_us,
+                    }),
+                ));
+            }
+        }
+        self.frame = self.frame.wrapping_add(1);
+        changed
+    }
+}
+
+/// Exponential moving average with weight 1/4 on the new sample: a level
+/// change shows in the average within a handful of frames, one spike does
+/// not.
+fn smooth(average: u32, sample: u64) -> u32 {
+    let sample = sample.min(u64::from(u32::MAX)) as u32;
+    if average == 0 {
+        return sample;
+    }
+    let average = i64::from(average);
+    (average + (i64::from(sample) - average) / 4) as u32
+}
+
+/// `status` as reported with `degradation` applied: a degraded node that is
+/// otherwise healthy shows the degradation as a warning. A node's own
+/// warning or error wins — it says more than the budget does.
+pub(crate) fn budget_status(
+    status: NodeRuntimeStatus,
+    degradation: Option<Degradation>,
+) -> NodeRuntimeStatus {
+    match (status, degradation) {
+        (NodeRuntimeStatus::Ok, Some(degradation)) => {
+            NodeRuntimeStatus::Warn(degradation.describe())
+        }
+        (status, _) => status,
+    }
+}
+
+/// Whether `status` is one [`budget_status`] produced (or plain `Ok`), and so
+/// safe to replace when the degradation changes between ticks.
+pub(crate) fn is_budget_status(status: &NodeRuntimeStatus) -> bool {
+    match status {
+        NodeRuntimeStatus::Ok => true,
+        NodeRuntimeStatus::Warn(message) => message.starts_with(OVER_BUDGET),
+        _ => false,
+    }
+}
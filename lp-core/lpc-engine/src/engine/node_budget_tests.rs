@@ -0,0 +1,185 @@
+//! Per-node budgets end to end: a fixture pulling a shader that charges a
+//! fixed cost to a [`TestClock`](super::test_support::TestClock) on every
+//! `produce`.
+//!
+//! The clock only moves inside the shader, so every meter reads an exact
+//! cost and every level change lands on a known frame.
+
+use alloc::string::String;
+
+use lp_collection::VecMap;
+use lpc_model::NodeRuntimeStatus;
+
+use super::test_support::{EngineTestBuilder, EngineTestHarness, output, produced_slot};
+use super::{AuthoredBudget, DEGRADE_AFTER_FRAMES, MAX_DEGRADE_LEVEL, RECOVER_AFTER_FRAMES};
+use crate::node::DegradeMode;
+
+const TICK_MS: u32 = 16;
+const SHADER_COST_US: u32 = 5000;
+
+fn harness() -> EngineTestHarness {
+    EngineTestBuilder::new()
+        .costly_shader("shader", output("outputs[0]", 0.5), SHADER_COST_US)
+        .fixture("fixture")
+        .bind_demand_input("fixture", produced_slot("shader", "outputs[0]"))
+        .demand_root("fixture")
+        .build()
+}
+
+fn set_shader_budget(h: &mut EngineTestHarness, budget: AuthoredBudget) {
+    let mut authored = VecMap::new();
+    authored.insert(h.node("shader"), budget);
+    h.engine.set_authored_budgets(authored);
+}
+
+fn budget(us: u32) -> AuthoredBudget {
+    AuthoredBudget {
+        budget_us: Some(us),
+        critical: false,
+    }
+}
+
+fn tick_frames(h: &mut EngineTestHarness, frames: u32) {
+    for _ in 0..frames {
+        h.tick(TICK_MS).expect("tick");
+    }
+}
+
+fn shader_level(h: &EngineTestHarness) -> u8 {
+    h.engine
+        .node_budgets()
+        .degradation(h.node("shader"))
+        .map_or(0, |degradation| degradation.level)
+}
+
+fn shader_status(h: &EngineTestHarness) -> NodeRuntimeStatus {
+    h.engine
+        .tree()
+        .get(h.node("shader"))
+        .expect("shader entry")
+        .status
+        .value()
+        .clone()
+}
+
+#[test]
+fn pulled_node_is_charged_its_own_time_not_its_consumer() {
+    let mut h = harness();
+    tick_frames(&mut h, 3);
+
+    let budgets = h.engine.node_budgets();
+    let shader = budgets.cost(h.node("shader")).expect("shader metered");
+    let fixture = budgets.cost(h.node("fixture")).expect("fixture metered");
+    assert_eq!(shader.tick_us, SHADER_COST_US);
+    assert_eq!(
+        fixture.tick_us, 0,
+        "the shader ran inside the fixture's consume"
+    );
+}
+
+#[test]
+fn unbudgeted_node_is_metered_but_never_degraded() {
+    let mut h = harness();
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES) * 4);
+
+    assert_eq!(shader_level(&h), 0);
+    assert_eq!(
+        h.shader_ticks("shader"),
+        u32::from(DEGRADE_AFTER_FRAMES) * 4
+    );
+    assert_eq!(shader_status(&h), NodeRuntimeStatus::Ok);
+}
+
+#[test]
+fn over_budget_node_steps_down_to_skipping_ticks() {
+    let mut h = harness();
+    set_shader_budget(&mut h, budget(1000));
+
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES) - 1);
+    assert_eq!(shader_level(&h), 0, "one level per DEGRADE_AFTER_FRAMES");
+    tick_frames(&mut h, 1);
+    assert_eq!(shader_level(&h), 1);
+    assert_eq!(
+        shader_status(&h),
+        NodeRuntimeStatus::Warn(String::from("over budget (1000 µs): ticking 1 frame in 2"))
+    );
+
+    // 5000 µs amortized over 2 and 4 frames is still over; over 8 it is
+    // 625 µs, under budget but not under half of it, so the level holds.
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES) * 4);
+    assert_eq!(shader_level(&h), MAX_DEGRADE_LEVEL);
+    let degradation = h
+        .engine
+        .node_budgets()
+        .degradation(h.node("shader"))
+        .expect("degraded");
+    assert_eq!(degradation.mode, DegradeMode::SkipTick);
+    assert_eq!(degradation.divisor(), 8);
+
+    h.reset_shader_ticks("shader");
+    tick_frames(&mut h, 16);
+    assert_eq!(h.shader_ticks("shader"), 2);
+    assert_eq!(
+        h.fixture_f32("fixture"),
+        Some(0.5),
+        "the fixture keeps reading the last produced value"
+    );
+}
+
+#[test]
+fn degraded_node_recovers_once_well_under_budget() {
+    let mut h = harness();
+    set_shader_budget(&mut h, budget(1000));
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES));
+    assert_eq!(shader_level(&h), 1);
+
+    // 2500 µs amortized against 20000: well under half.
+    set_shader_budget(&mut h, budget(20_000));
+    tick_frames(&mut h, u32::from(RECOVER_AFTER_FRAMES) - 1);
+    assert_eq!(
+        shader_level(&h),
+        1,
+        "recovery waits out RECOVER_AFTER_FRAMES"
+    );
+    tick_frames(&mut h, 1);
+    assert_eq!(shader_level(&h), 0);
+    assert_eq!(shader_status(&h), NodeRuntimeStatus::Ok);
+
+    h.reset_shader_ticks("shader");
+    tick_frames(&mut h, 4);
+    assert_eq!(h.shader_ticks("shader"), 4);
+}
+
+#[test]
+fn removing_the_budget_restores_full_quality_at_once() {
+    let mut h = harness();
+    set_shader_budget(&mut h, budget(1000));
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES));
+    assert_eq!(shader_level(&h), 1);
+
+    h.engine.set_authored_budgets(VecMap::new());
+    tick_frames(&mut h, 1);
+    assert_eq!(shader_level(&h), 0);
+    assert_eq!(shader_status(&h), NodeRuntimeStatus::Ok);
+}
+
+#[test]
+fn critical_node_is_never_degraded() {
+    let mut h = harness();
+    set_shader_budget(
+        &mut h,
+        AuthoredBudget {
+            budget_us: Some(1000),
+            critical: true,
+        },
+    );
+    h.set_shader_cost("shader", SHADER_COST_US * 2);
+    tick_frames(&mut h, u32::from(DEGRADE_AFTER_FRAMES) * 4);
+
+    assert_eq!(shader_level(&h), 0);
+    assert_eq!(
+        h.shader_ticks("shader"),
+        u32::from(DEGRADE_AFTER_FRAMES) * 4
+    );
+    assert_eq!(shader_status(&h), NodeRuntimeStatus::Ok);
+}
@@ -112,9 +112,14 @@ impl LpGraphics for CountingGraphics {
         self.inner.glsl_frontend()
     }
 
-    fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError> {
+    fn create_render_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError> {
         self.output_alloc_count.fetch_add(1, Ordering::Relaxed);
-        self.inner.create_render_target(width, height)
+        self.inner.create_render_target(width, height, format)
     }
 
     fn create_texture(
@@ -145,6 +150,14 @@ impl LpGraphics for CountingGraphics {
         self.inner.blend_textures(previous, active, alpha, target)
     }
 
+    fn copy_texture(
+        &self,
+        source: &TextureHandle,
+        target: &mut TextureHandle,
+    ) -> Result<(), GfxError> {
+        self.inner.copy_texture(source, target)
+    }
+
     fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError> {
         self.inner.read_back(texture)
     }
@@ -266,6 +279,12 @@ fn solid_texture(
             lps_shared::TextureStorageFormat::R16Unorm => {
                 pixels.extend_from_slice(&color[0].to_le_bytes());
             }
+            lps_shared::TextureStorageFormat::Rgb8Unorm => {
+                for c in [color[0], color[1], color[2]] {
+                    pixels.push((c >> 8) as u8);
+                }
+            }
+            lps_shared::TextureStorageFormat::R8Unorm => pixels.push((color[0] >> 8) as u8),
         }
     }
     TextureRenderProduct::new(width, height, format, pixels)
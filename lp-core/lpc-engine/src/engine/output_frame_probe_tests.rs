@@ -441,6 +441,14 @@ impl RenderNode for CountingSolidProducer {
                 lps_shared::TextureStorageFormat::R16Unorm => {
                     pixels.extend_from_slice(&self.color[0].to_le_bytes());
                 }
+                lps_shared::TextureStorageFormat::Rgb8Unorm => {
+                    for channel in [self.color[0], self.color[1], self.color[2]] {
+                        pixels.push((channel >> 8) as u8);
+                    }
+                }
+                lps_shared::TextureStorageFormat::R8Unorm => {
+                    pixels.push((self.color[0] >> 8) as u8)
+                }
             }
         }
         TextureRenderProduct::new(request.width, request.height, request.format, pixels)
@@ -0,0 +1,250 @@
+//! Palette nodes end to end: loader attach, publishing on `bus:palette`,
+//! selection by a bound `index`, and a crossfade off the timebase.
+//!
+//! Every palette here is one flat color, so a frame's first stop says
+//! everything about it: which palette is showing, or how far a fade has got.
+
+use alloc::string::{String, ToString};
+
+use lpc_model::{
+    ChannelName, FromLpValue, Gradient, GradientConfig, Kind, LpValue, NodeId, ProductRef,
+    SlotData, SlotPath, TimeProduct, TreePath,
+};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+
+use crate::color::{linear_srgb_to_oklab, sample_gradient};
+use crate::dataflow::binding::{BindingDraft, BindingPriority, BindingSource, BindingTarget};
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+
+const TICK_MS: u32 = 100;
+
+const RED: [f32; 3] = [1.0, 0.0, 0.0];
+const BLUE: [f32; 3] = [0.0, 0.0, 1.0];
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+    clock: NodeId,
+    palette: NodeId,
+    /// Priority of the next `index` write, so each one outranks the last.
+    index_priority: i32,
+}
+
+impl Project {
+    /// A clock plus a red/blue palette node authored with `fields`.
+    fn load(fields: &str) -> Self {
+        let fs = LpFsMemory::new();
+        let write = |path: &str, body: &str| {
+            fs.write_file(path.as_path(), body.as_bytes())
+                .expect("write project file");
+        };
+        write("/project.json", "{ \"format\": 8 }\n");
+        write(
+            "/clock.json",
+            r#"{ "kind": "Clock", "bindings": { "product": { "target": "bus:clock_product" } } }"#,
+        );
+        write(
+            "/palette.json",
+            &alloc::format!(
+                r#"{{
+  "kind": "Palette",
+  "palettes": {{
+    "3": {{ "space": "linear_srgb", "method": "linear", "stops": "(1,0,0) (1,0,0)" }},
+    "8": {{ "space": "linear_srgb", "method": "linear", "stops": "(0,0,1) (0,0,1)" }}
+  }}{fields}
+}}"#
+            ),
+        );
+        write(
+            "/module.json",
+            r#"{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "palette": { "ref": "./palette.json" }
+  }
+}"#,
+        );
+
+        let services = EngineServices::new(TreePath::parse("/palette.show").expect("root"));
+        let loaded = ProjectLoader::load_from_root(&fs, services).expect("load project");
+        let (engine, registry) = loaded.into_parts();
+        let node = |suffix: &str| {
+            engine
+                .tree()
+                .entries()
+                .find(|entry| entry.path.to_string().ends_with(suffix))
+                .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+                .id
+        };
+        let (clock, palette) = (node("clock.clock"), node("palette.palette"));
+        Self {
+            engine,
+            registry,
+            clock,
+            palette,
+            index_priority: 0,
+        }
+    }
+
+    /// One frame at `seconds` on the clock's timebase, then what
+    /// `bus:palette` carries — the demand is what runs `produce`.
+    fn frame(&mut self, seconds: f32) -> Gradient {
+        let revision = self.engine.revision();
+        self.engine.timebases_mut().set_timebase(
+            self.clock,
+            seconds,
+            TICK_MS as f32 / 1000.0,
+            revision,
+        );
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        let (production, _) = resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::Bus {
+                scope: None,
+                channel: ChannelName(String::from("palette")),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("resolve bus:palette");
+        let SlotData::Value(value) = production.data() else {
+            panic!("bus:palette is not a value");
+        };
+        match GradientConfig::from_lp_value(value.value()).expect("gradient config") {
+            GradientConfig::Static(gradient) => gradient,
+            other => panic!("palette published a non-static config: {other:?}"),
+        }
+    }
+
+    /// The key of the palette the node reports as selected.
+    fn active(&mut self) -> u32 {
+        let (production, _) = resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node: self.palette,
+                slot: SlotPath::parse("active").expect("active path"),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("resolve active");
+        let SlotData::Value(value) = production.data() else {
+            panic!("active is not a value");
+        };
+        u32::from_lp_value(value.value()).expect("active key")
+    }
+
+    /// Publish the clock's time product on `bus:time`, where the palette's
+    /// `time` slot binds by default.
+    fn publish_time_product(&mut self) {
+        let product = LpValue::Product(ProductRef::Time(TimeProduct::new(self.clock, 0)));
+        self.add_literal(
+            product,
+            BindingTarget::BusChannel(ChannelName(String::from("time"))),
+            BindingPriority::authored(),
+            Kind::Instant,
+        );
+    }
+
+    /// Drive the palette's `index` input, outranking any earlier write.
+    fn select(&mut self, index: u32) {
+        self.index_priority += 1;
+        let target = BindingTarget::ConsumedSlot {
+            node: self.palette,
+            slot: SlotPath::parse("index").expect("index path"),
+        };
+        let priority = BindingPriority::new(self.index_priority);
+        self.add_literal(LpValue::U32(index), target, priority, Kind::Count);
+    }
+
+    fn add_literal(
+        &mut self,
+        value: LpValue,
+        target: BindingTarget,
+        priority: BindingPriority,
+        kind: Kind,
+    ) {
+        let owner = self.engine.tree().root();
+        let revision = self.engine.revision();
+        self.engine
+            .add_binding(
+                BindingDraft {
+                    source: BindingSource::Literal(value),
+                    target,
+                    priority,
+                    kind,
+                    owner,
+                },
+                revision,
+            )
+            .expect("register literal binding");
+    }
+}
+
+/// The color at the start of `gradient`, in linear sRGB.
+fn first_color(gradient: &Gradient) -> [f32; 3] {
+    sample_gradient(gradient, 0.0)
+}
+
+fn assert_close(actual: [f32; 3], expected: [f32; 3]) {
+    assert!(
+        actual
+            .iter()
+            .zip(expected)
+            .all(|(lane, want)| (lane - want).abs() <= 1e-3),
+        "{actual:?} != {expected:?}"
+    );
+}
+
+// --- Tests -----------------------------------------------------------------
+
+/// With no fade the bound `index` cuts between palettes in key order,
+/// wrapping past the end, and nothing reads the clock: `bus:time` is
+/// never published here.
+#[test]
+fn index_selects_in_key_order_and_wraps() {
+    let mut project = Project::load(r#", "fade_seconds": 0.0"#);
+
+    let first = project.frame(0.0);
+    assert_close(first_color(&first), RED);
+    assert_eq!(project.active(), 3);
+
+    project.select(1);
+    assert_close(first_color(&project.frame(0.1)), BLUE);
+    assert_eq!(project.active(), 8);
+
+    project.select(2);
+    assert_close(first_color(&project.frame(0.2)), RED);
+    assert_eq!(project.active(), 3);
+}
+
+/// A selection change fades in Oklab over `fade_seconds` of timebase time,
+/// then publishes the new palette exactly as authored.
+#[test]
+fn a_selection_change_crossfades_off_the_timebase() {
+    let mut project = Project::load(r#", "fade_seconds": 1.0"#);
+    project.publish_time_product();
+
+    assert_close(first_color(&project.frame(0.0)), RED);
+    project.select(1);
+    assert_close(first_color(&project.frame(1.0)), RED);
+
+    let (red, blue) = (linear_srgb_to_oklab(RED), linear_srgb_to_oklab(BLUE));
+    let halfway = project.frame(1.5);
+    let c = halfway.stops[0].c;
+    for lane in 0..3 {
+        assert!(
+            (c[lane] - (red[lane] + blue[lane]) / 2.0).abs() <= 1e-3,
+            "halfway is the Oklab midpoint: {c:?}"
+        );
+    }
+
+    let done = project.frame(2.5);
+    assert_close(first_color(&done), BLUE);
+    assert_eq!(done.stops.len(), 2, "published as authored");
+}
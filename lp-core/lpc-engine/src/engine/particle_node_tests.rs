@@ -0,0 +1,309 @@
+//! Particle nodes end to end: emission and freezing off a timebase, trigger
+//! bursts, palette coloring off `bus:palette`, and memory pressure.
+//!
+//! The simulation itself is covered by the pool, emitter and splat unit
+//! tests; these are about the node's plumbing, so they assert on what the
+//! render shows — lit or dark, same or different — rather than on exact
+//! particle positions.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use lpc_model::{
+    ChannelName, Colorspace, Gradient, GradientConfig, GradientStop, InterpMethod, Kind, LpValue,
+    NodeId, ProductRef, TimeProduct, ToLpValue, TreePath,
+};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+use lps_shared::TextureStorageFormat;
+
+use crate::dataflow::binding::{BindingDraft, BindingPriority, BindingSource, BindingTarget};
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+use crate::node::PressureLevel;
+use crate::nodes::particle_output_path;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+const SIZE: u32 = 16;
+
+/// A fountain at the bottom center, emitting steadily.
+const FOUNTAIN: &str = r#""emitters": { "1": { "id": 1, "pos": [0.5, 0.9], "spread": 0.0, "dir": [0.0, -1.0], "cone": 0.2, "speed": 0.5, "rate": 40.0, "burst": 0, "trigger": 0, "lifetime": 2.0, "jitter": 0.0 } }"#;
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+    clock: NodeId,
+    particle: NodeId,
+}
+
+impl Project {
+    /// Load a clock plus one particle node authored with `fields`, with the
+    /// clock's time product on `bus:time`.
+    fn load(fields: &str) -> Self {
+        let fs = LpFsMemory::new();
+        let write = |path: &str, body: &[u8]| {
+            fs.write_file(path.as_path(), body)
+                .expect("write project file");
+        };
+        write("/project.json", b"{ \"format\": 8 }\n");
+        write(
+            "/clock.json",
+            br#"{ "kind": "Clock", "bindings": { "product": { "target": "bus:clock_product" } } }"#,
+        );
+        write(
+            "/particle.json",
+            alloc::format!(
+                r#"{{ "kind": "Particle", "size": {{ "width": {SIZE}, "height": {SIZE} }}, {fields} }}"#
+            )
+            .as_bytes(),
+        );
+        write(
+            "/module.json",
+            br#"{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "particle": { "ref": "./particle.json" }
+  }
+}
+"#,
+        );
+
+        let services = EngineServices::new(TreePath::parse("/particle.show").expect("root"));
+        let loaded = ProjectLoader::load_from_root(&fs, services).expect("load project");
+        let (mut engine, registry) = loaded.into_parts();
+        engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))));
+        let node = |suffix: &str| {
+            engine
+                .tree()
+                .entries()
+                .find(|entry| entry.path.to_string().ends_with(suffix))
+                .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+                .id
+        };
+        let clock = node("clock.clock");
+        let particle = node("particle.particle");
+        let mut project = Self {
+            engine,
+            registry,
+            clock,
+            particle,
+        };
+        project.add_literal(
+            LpValue::Product(ProductRef::Time(TimeProduct::new(clock, 0))),
+            "time",
+            Kind::Instant,
+        );
+        project
+    }
+
+    /// One frame at `seconds` on the clock's timebase, then a demand on the
+    /// particle output: nothing consumes it here, and `produce` is where the
+    /// simulation steps.
+    fn tick_at(&mut self, seconds: f32) {
+        let revision = self.engine.revision();
+        self.engine
+            .timebases_mut()
+            .set_timebase(self.clock, seconds, 0.1, revision);
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node: self.particle,
+                slot: particle_output_path(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("demand the particles");
+    }
+
+    /// Render the particles at canvas size as 8-bit RGBA.
+    fn render(&mut self) -> Vec<u8> {
+        self.render_as(TextureStorageFormat::Rgba16Unorm)
+            .chunks_exact(2)
+            .map(|lane| (u16::from_le_bytes([lane[0], lane[1]]) / 257) as u8)
+            .collect()
+    }
+
+    /// Render the particles at canvas size as raw `format` bytes.
+    fn render_as(&mut self, format: TextureStorageFormat) -> Vec<u8> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(self.particle, 0),
+                &RenderTextureRequest {
+                    width: SIZE,
+                    height: SIZE,
+                    format,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render particles");
+        texture
+            .try_raw_bytes()
+            .expect("host texture bytes")
+            .to_vec()
+    }
+
+    fn add_literal(&mut self, value: LpValue, channel: &str, kind: Kind) {
+        let owner = self.engine.tree().root();
+        let revision = self.engine.revision();
+        self.engine
+            .add_binding(
+                BindingDraft {
+                    source: BindingSource::Literal(value),
+                    target: BindingTarget::BusChannel(ChannelName(String::from(channel))),
+                    priority: BindingPriority::authored(),
+                    kind,
+                    owner,
+                },
+                revision,
+            )
+            .expect("register literal binding");
+    }
+}
+
+/// Pixels with any color in them.
+fn lit(pixels: &[u8]) -> Vec<[u8; 4]> {
+    pixels
+        .chunks_exact(4)
+        .filter(|px| px[..3].iter().any(|channel| *channel > 0))
+        .map(|px| [px[0], px[1], px[2], px[3]])
+        .collect()
+}
+
+// --- Tests -----------------------------------------------------------------
+
+/// Nothing is alive before time moves; once it does the fountain fills in,
+/// and a paused clock holds the frame exactly.
+#[test]
+fn fountain_emits_as_time_advances_and_freezes_when_paused() {
+    let mut project = Project::load(FOUNTAIN);
+
+    project.tick_at(0.0);
+    assert!(lit(&project.render()).is_empty(), "no time, no particles");
+
+    for step in 1..=5 {
+        project.tick_at(step as f32 * 0.1);
+    }
+    let moving = project.render();
+    assert!(!lit(&moving).is_empty(), "the fountain is running");
+
+    project.tick_at(0.5);
+    assert_eq!(project.render(), moving, "a paused clock freezes the frame");
+}
+
+/// An RGB8 consumer gets the RGBA16 frame rounded to 8 bits, alpha
+/// dropped.
+#[test]
+fn particles_render_into_an_rgb8_request() {
+    let mut project = Project::load(FOUNTAIN);
+    for step in 0..=5 {
+        project.tick_at(step as f32 * 0.1);
+    }
+
+    let rgb: Vec<u8> = project
+        .render_as(TextureStorageFormat::Rgba16Unorm)
+        .chunks_exact(8)
+        .flat_map(|texel| {
+            texel[..6].chunks_exact(2).map(|lane| {
+                let c = u32::from(u16::from_le_bytes([lane[0], lane[1]]));
+                ((c * 255 + 32_767) / 65_535) as u8
+            })
+        })
+        .collect();
+    assert!(
+        rgb.iter().any(|channel| *channel > 0),
+        "the fountain is lit"
+    );
+    assert_eq!(project.render_as(TextureStorageFormat::Rgb8Unorm), rgb);
+}
+
+/// An emitter with no rate fires its burst when its trigger id shows up on
+/// the trigger map — and only then.
+#[test]
+fn trigger_fires_a_burst() {
+    let burst = r#""emitters": { "1": { "id": 1, "pos": [0.5, 0.5], "spread": 0.1, "dir": [0.0, -1.0], "cone": 3.0, "speed": 0.1, "rate": 0.0, "burst": 30, "trigger": 7, "lifetime": 5.0, "jitter": 0.0 } }"#;
+
+    let mut idle = Project::load(burst);
+    idle.tick_at(0.0);
+    idle.tick_at(0.1);
+    assert!(lit(&idle.render()).is_empty(), "no trigger, no burst");
+
+    let mut fired = Project::load(&alloc::format!(
+        r#"{burst}, "trigger": {{ "7": {{ "id": 7, "seq": 1 }} }}"#
+    ));
+    fired.tick_at(0.0);
+    assert!(!lit(&fired.render()).is_empty(), "the burst launched");
+}
+
+/// A palette on `bus:palette` colors the particles over their life.
+#[test]
+fn palette_on_the_bus_colors_the_particles() {
+    let mut project = Project::load(&alloc::format!(r#"{FOUNTAIN}, "fade": false"#));
+    let red = Gradient {
+        space: Colorspace::LinearSrgb,
+        method: InterpMethod::Linear,
+        stops: alloc::vec![
+            GradientStop {
+                at: 0.0,
+                c: [1.0, 0.0, 0.0],
+            },
+            GradientStop {
+                at: 1.0,
+                c: [1.0, 0.0, 0.0],
+            },
+        ],
+    };
+    project.add_literal(
+        GradientConfig::Static(red).to_lp_value(),
+        "palette",
+        Kind::Gradient,
+    );
+
+    for step in 0..=3 {
+        project.tick_at(step as f32 * 0.1);
+    }
+    let pixels = lit(&project.render());
+    assert!(!pixels.is_empty());
+    assert!(
+        pixels
+            .iter()
+            .all(|[r, g, b, _]| *r > 0 && *g == 0 && *b == 0),
+        "every particle is red"
+    );
+}
+
+/// `High` pressure drops only the canvas, which re-splats to the same
+/// frame; `Critical` drops the simulation, and the particles start over.
+#[test]
+fn memory_pressure_keeps_the_simulation_until_critical() {
+    let mut project = Project::load(FOUNTAIN);
+    for step in 0..=5 {
+        project.tick_at(step as f32 * 0.1);
+    }
+    let before = project.render();
+    assert!(!lit(&before).is_empty());
+
+    project
+        .engine
+        .broadcast_memory_pressure(PressureLevel::High)
+        .expect("high pressure");
+    assert_eq!(project.render(), before, "redrawn from the live pool");
+
+    project
+        .engine
+        .broadcast_memory_pressure(PressureLevel::Critical)
+        .expect("critical pressure");
+    assert!(lit(&project.render()).is_empty(), "the pool was dropped");
+}
@@ -0,0 +1,161 @@
+//! Playlist nodes end to end: a shader entry rendered through the playlist.
+//!
+//! A playlist forwards its consumer's request to the active entry
+//! unchanged, so these tests are about an entry whose own storage format
+//! differs from what the consumer asks for.
+
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use lpc_model::{NodeId, TreePath};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+use lps_shared::TextureStorageFormat;
+
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+use crate::nodes::playlist_output_path;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+const WIDTH: u32 = 4;
+const HEIGHT: u32 = 2;
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+    playlist: NodeId,
+}
+
+impl Project {
+    /// Load a clock and a playlist whose only entry is a shader running
+    /// `glsl`, authored with `fields` (a JSON fragment starting with a
+    /// comma).
+    fn load(glsl: &str, fields: &str) -> Self {
+        let fs = LpFsMemory::new();
+        let write = |path: &str, body: &str| {
+            fs.write_file(path.as_path(), body.as_bytes())
+                .expect("write project file");
+        };
+        write("/project.json", "{ \"format\": 8 }\n");
+        write("/clock.json", r#"{ "kind": "Clock" }"#);
+        write("/entry.glsl", glsl);
+        write(
+            "/entry.json",
+            &alloc::format!(
+                r#"{{ "kind": "Shader", "source": {{ "path": "entry.glsl" }}{fields} }}"#
+            ),
+        );
+        write(
+            "/playlist.json",
+            r#"{
+  "kind": "Playlist",
+  "default_fade": 0.0,
+  "bindings": { "time": { "source": "bus:time" } },
+  "entries": { "1": { "name": "idle", "node": { "ref": "./entry.json" } } }
+}"#,
+        );
+        write(
+            "/module.json",
+            r#"{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "playlist": { "ref": "./playlist.json" }
+  }
+}"#,
+        );
+
+        let services = EngineServices::new(TreePath::parse("/playlist.show").expect("root"));
+        let loaded = ProjectLoader::load_from_root(&fs, services).expect("load project");
+        let (mut engine, registry) = loaded.into_parts();
+        engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))));
+        let playlist = engine
+            .tree()
+            .entries()
+            .find(|entry| entry.path.to_string().ends_with("playlist.playlist"))
+            .expect("playlist node")
+            .id;
+        Self {
+            engine,
+            registry,
+            playlist,
+        }
+    }
+
+    /// One frame, then a demand on the playlist's output: nothing consumes
+    /// it here, and `produce` is where the active entry is resolved.
+    fn tick(&mut self) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node: self.playlist,
+                slot: playlist_output_path(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("demand the playlist");
+    }
+
+    /// Render the playlist as RGBA16 and return the lanes of every pixel.
+    fn render(&mut self) -> Vec<[u16; 4]> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(self.playlist, 0),
+                &RenderTextureRequest {
+                    width: WIDTH,
+                    height: HEIGHT,
+                    format: TextureStorageFormat::Rgba16Unorm,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render playlist");
+        let bytes = texture.try_raw_bytes().expect("host texture bytes");
+        bytes
+            .chunks_exact(8)
+            .map(|px| {
+                let lane = |i: usize| u16::from_le_bytes([px[i * 2], px[i * 2 + 1]]);
+                [lane(0), lane(1), lane(2), lane(3)]
+            })
+            .collect()
+    }
+
+    /// Run past the shader's compile-window deferral.
+    fn warm_up(&mut self) {
+        for _ in 0..4 {
+            self.tick();
+            let _ = self.render();
+        }
+    }
+}
+
+// --- Tests -----------------------------------------------------------------
+
+/// An `R8` entry behind a playlist asked for RGBA16 renders the grey a
+/// sampler would read, with opaque alpha.
+#[test]
+fn a_narrow_format_entry_renders_through_the_playlist() {
+    let mut project = Project::load(
+        "float render_2d(vec2 pos) { return 0.5; }",
+        r#", "output_format": "R8""#,
+    );
+    project.warm_up();
+
+    project.tick();
+    let pixels = project.render();
+    assert_eq!(pixels.len(), (WIDTH * HEIGHT) as usize);
+    for pixel in pixels {
+        assert_eq!(pixel, [128 * 257, 128 * 257, 128 * 257, u16::MAX]);
+    }
+}
@@ -12,7 +12,9 @@ use lpc_model::{
 use lpc_registry::ProjectRegistry;
 use lpfs::LpFs;
 
-use crate::node::{AssetRefreshContext, AssetRefreshResult, NodeEntryState};
+use crate::node::{
+    AssetRefreshContext, AssetRefreshResult, MigratedState, NodeEntryState, StateExport,
+};
 
 use super::{Engine, ProjectLoadError, ProjectLoader};
 
@@ -31,6 +33,11 @@ pub struct RuntimeApplyResult {
     pub refreshed_nodes: Vec<NodeUseLocation>,
     /// Node uses that could not be applied.
     pub failed_nodes: Vec<NodeUseLocation>,
+    /// Rebuilt node uses whose runtime state was carried into the new runtime.
+    pub migrated_nodes: Vec<NodeUseLocation>,
+    /// Rebuilt node uses whose runtime declared it starts over
+    /// ([`StateExport::Resets`]).
+    pub reset_nodes: Vec<NodeUseLocation>,
 }
 
 impl RuntimeApplyResult {
@@ -41,6 +48,8 @@ impl RuntimeApplyResult {
             && self.refreshed_assets.is_empty()
             && self.refreshed_nodes.is_empty()
             && self.failed_nodes.is_empty()
+            && self.migrated_nodes.is_empty()
+            && self.reset_nodes.is_empty()
     }
 }
 
@@ -50,6 +59,13 @@ impl Engine {
     /// This is intentionally a lifecycle/topology operation. Same-kind
     /// definition body changes and asset body changes are value changes owned by
     /// runtime nodes through resolver/revision-aware reads.
+    ///
+    /// A use location that is rebuilt rather than removed is a hot reload:
+    /// its outgoing runtimes export live state
+    /// ([`crate::node::NodeRuntime::export_state`]) and the replacements
+    /// import it, so an edit during live tuning does not reset simulations
+    /// and clocks. State whose location comes back as an error node is held
+    /// until the def is fixed.
     pub fn apply_project_changes(
         &mut self,
         fs: &dyn LpFs,
@@ -63,7 +79,11 @@ impl Engine {
             self.resolver_mut().invalidate_structure();
             self.project_runtime_index_mut()
                 .rebuild_asset_consumers(&registry.inventory().tree);
-            return Ok(RuntimeApplyResult::default());
+            // An edit to an `#include`d file alone summarizes to nothing:
+            // the registry only reports declared assets.
+            let mut result = RuntimeApplyResult::default();
+            self.refresh_file_dependents(fs, registry, lpc_model::current_revision(), &mut result)?;
+            return Ok(result);
         }
 
         let frame = lpc_model::current_revision();
@@ -129,6 +149,8 @@ impl Engine {
         }
 
         let mut result = RuntimeApplyResult::default();
+        let mut carried = self.export_reattached_states(&reattach_roots, &mut result);
+        carried.extend(self.take_parked_states(&add_targets));
         let mut removals = remove_roots.into_iter().collect::<Vec<_>>();
         removals.sort_by(|a, b| {
             b.segments
@@ -190,6 +212,7 @@ impl Engine {
                 }
             }
         }
+        self.import_carried_states(registry, carried, &mut result);
 
         for location in changed_effective_assets(changes) {
             let node_ids = self
@@ -206,6 +229,7 @@ impl Engine {
                 result.refreshed_nodes.extend(refreshed_nodes);
             }
         }
+        self.refresh_file_dependents(fs, registry, frame, &mut result)?;
 
         self.project_runtime_index_mut()
             .rebuild_asset_consumers(&registry.inventory().tree);
@@ -220,11 +244,141 @@ impl Engine {
         let projected_nodes = ProjectLoader::ensure_runtime_spine(registry, self, frame)?;
         self.clear_bindings(frame);
         ProjectLoader::register_projected_bindings(registry, self, &projected_nodes, frame)?;
+        ProjectLoader::register_projected_budgets(registry, self, &projected_nodes);
 
         self.resolver_mut().invalidate_structure();
         Ok(result)
     }
 
+    /// Ask every live runtime under the reattach roots for state worth
+    /// keeping across its rebuild. Removal for good never exports.
+    fn export_reattached_states(
+        &mut self,
+        reattach_roots: &VecSet<NodeUseLocation>,
+        result: &mut RuntimeApplyResult,
+    ) -> Vec<CarriedState> {
+        let mut carried = Vec::new();
+        let mut visited = VecSet::new();
+        for root in reattach_roots {
+            let root_id = if root.is_root() {
+                Some(self.tree().root())
+            } else {
+                self.project_runtime_index().node_id(root)
+            };
+            let Some(ids) = root_id.and_then(|id| self.tree().subtree_ids_depth_first(id).ok())
+            else {
+                continue;
+            };
+            for node_id in ids {
+                let Some(location) = self.project_runtime_index().use_location(node_id).cloned()
+                else {
+                    continue;
+                };
+                if !visited.insert(location.clone()) {
+                    continue;
+                }
+                let Some(entry) = self.tree_mut().get_mut(node_id) else {
+                    continue;
+                };
+                let NodeEntryState::Alive(runtime) = entry.state.get_mut() else {
+                    continue;
+                };
+                match runtime.export_state() {
+                    StateExport::Stateless => {}
+                    StateExport::Undeclared => {
+                        log::debug!(
+                            "hot reload: {} declares no state export; nothing is carried",
+                            format_node_use(&location)
+                        );
+                    }
+                    StateExport::Carry(state) => carried.push(CarriedState {
+                        location,
+                        from: Some(node_id),
+                        state,
+                    }),
+                    StateExport::Resets { reason } => {
+                        log::info!(
+                            "hot reload: {} starts over ({reason})",
+                            format_node_use(&location)
+                        );
+                        result.reset_nodes.push(location);
+                    }
+                }
+            }
+        }
+        carried
+    }
+
+    /// Parked states whose use location is about to be attached again.
+    fn take_parked_states(&mut self, add_targets: &VecSet<NodeUseLocation>) -> Vec<CarriedState> {
+        let (taken, kept): (Vec<_>, Vec<_>) = core::mem::take(self.parked_states_mut())
+            .into_iter()
+            .partition(|(location, _)| add_targets.contains(location));
+        *self.parked_states_mut() = kept;
+        taken
+            .into_iter()
+            .map(|(location, state)| CarriedState {
+                location,
+                from: None,
+                state,
+            })
+            .collect()
+    }
+
+    /// Hand carried states to the rebuilt runtimes. A location that came
+    /// back without a live runtime parks its state until it does; one that
+    /// left the project drops it.
+    fn import_carried_states(
+        &mut self,
+        registry: &ProjectRegistry,
+        carried: Vec<CarriedState>,
+        result: &mut RuntimeApplyResult,
+    ) {
+        for CarriedState {
+            location,
+            from,
+            state,
+        } in carried
+        {
+            let to = if location.is_root() {
+                Some(self.tree().root())
+            } else {
+                self.project_runtime_index().node_id(&location)
+            };
+            let Some(to) = to.filter(|&id| {
+                matches!(
+                    self.tree().get(id).map(|entry| entry.state.value()),
+                    Some(NodeEntryState::Alive(_))
+                )
+            }) else {
+                if registry.inventory().tree.nodes.contains_key(&location) {
+                    self.parked_states_mut().push((location, state));
+                }
+                continue;
+            };
+            let Some(entry) = self.tree_mut().get_mut(to) else {
+                continue;
+            };
+            let NodeEntryState::Alive(runtime) = entry.state.get_mut() else {
+                continue;
+            };
+            if runtime.import_state(state) {
+                if let Some(from) = from {
+                    self.rekey_timebase(from, to);
+                }
+                result.migrated_nodes.push(location);
+            } else {
+                log::debug!(
+                    "hot reload: {} did not accept its previous state",
+                    format_node_use(&location)
+                );
+            }
+        }
+        let nodes = &registry.inventory().tree.nodes;
+        self.parked_states_mut()
+            .retain(|(location, _)| nodes.contains_key(location));
+    }
+
     fn refresh_project_asset_consumers(
         &mut self,
         fs: &dyn LpFs,
@@ -273,6 +427,56 @@ impl Engine {
 
         Ok(refreshed)
     }
+
+    /// Let every live runtime notice a change to a file it read outside its
+    /// declared assets ([`crate::node::NodeRuntime::refresh_file_dependencies`]).
+    fn refresh_file_dependents(
+        &mut self,
+        fs: &dyn LpFs,
+        registry: &mut ProjectRegistry,
+        frame: lpc_model::Revision,
+        result: &mut RuntimeApplyResult,
+    ) -> Result<(), ProjectLoadError> {
+        let node_ids: Vec<NodeId> = self.tree().entries().map(|entry| entry.id).collect();
+        let slot_shapes = self.slot_shapes().clone();
+        for node_id in node_ids {
+            let Some(use_location) = self.project_runtime_index().use_location(node_id).cloned()
+            else {
+                continue;
+            };
+            let Some(entry) = self.tree_mut().get_mut(node_id) else {
+                continue;
+            };
+            let NodeEntryState::Alive(runtime) = entry.state.get_mut() else {
+                continue;
+            };
+            let mut ctx = AssetRefreshContext::new(fs, registry, &slot_shapes, frame);
+            let refresh_result = runtime.refresh_file_dependencies(&mut ctx).map_err(|e| {
+                ProjectLoadError::InvalidProjectReference {
+                    path: format_node_use(&use_location),
+                    reason: format!("refresh file dependencies: {e}"),
+                }
+            })?;
+            if refresh_result != AssetRefreshResult::Refreshed {
+                continue;
+            }
+            let runtime_status = runtime.runtime_status().unwrap_or(NodeRuntimeStatus::Ok);
+            entry.state.mark_updated(frame);
+            set_entry_status_if_changed(entry, runtime_status, frame);
+            if !result.refreshed_nodes.contains(&use_location) {
+                result.refreshed_nodes.push(use_location);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One runtime's exported state on its way to the rebuilt runtime.
+struct CarriedState {
+    location: NodeUseLocation,
+    /// The runtime it came from, when still known (not parked).
+    from: Option<NodeId>,
+    state: MigratedState,
 }
 
 fn set_entry_status_if_changed<N>(
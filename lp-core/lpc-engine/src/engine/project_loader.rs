@@ -4,7 +4,7 @@ use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use lp_collection::VecSet;
+use lp_collection::{VecMap, VecSet};
 
 use lpc_model::{ArtifactSpec, NodeInvocation, NodeKind};
 use lpc_model::{
@@ -24,9 +24,16 @@ use lpc_model::PlaylistDef;
 #[cfg(feature = "node-fixture")]
 use lpc_model::{FixtureDef, MappingConfig};
 // `AssetContentType`/`AssetLocation`/`AssetText` are used only by the
-// asset-backed node kinds (shader/compute-shader source, fixture map2d) via
+// asset-backed node kinds (shader/compute-shader source, fixture map2d,
+// image file, text font, filter LUT) via
 // `materialize_node_text_asset`/`asset_for_node_content_type` — same gate.
-#[cfg(any(feature = "node-shader", feature = "node-fixture"))]
+#[cfg(any(
+    feature = "node-shader",
+    feature = "node-fixture",
+    feature = "node-image",
+    feature = "node-text",
+    feature = "node-filter"
+))]
 use lpc_model::{AssetContentType, AssetLocation};
 #[cfg(any(feature = "node-shader", feature = "node-fixture"))]
 use lpc_registry::AssetText;
@@ -50,18 +57,28 @@ use crate::nodes::ControlRadioNode;
 #[cfg(feature = "node-fluid")]
 use crate::nodes::FluidNode;
 use crate::nodes::OutputNode;
+#[cfg(feature = "node-palette")]
+use crate::nodes::PaletteNode;
+#[cfg(feature = "node-particle")]
+use crate::nodes::ParticleNode;
 #[cfg(feature = "node-texture")]
 use crate::nodes::TextureNode;
 #[cfg(feature = "node-fixture")]
 use crate::nodes::fixture::mapping::mapping_from_map2d_doc;
 #[cfg(feature = "node-shader")]
 use crate::nodes::{ComputeShaderNode, ShaderNode};
+#[cfg(feature = "node-filter")]
+use crate::nodes::{FilterLutAsset, FilterNode};
 #[cfg(feature = "node-fixture")]
 use crate::nodes::{FixtureMap2dSource, FixtureMapping, FixtureNode};
+#[cfg(feature = "node-image")]
+use crate::nodes::{ImageAsset, ImageNode};
 #[cfg(feature = "node-playlist")]
 use crate::nodes::{PlaylistNode, PlaylistRuntimeEntry};
+#[cfg(feature = "node-text")]
+use crate::nodes::{TextFontAsset, TextNode};
 
-use super::{Engine, EngineServices, LoadedProjectRuntime};
+use super::{AuthoredBudget, Engine, EngineServices, LoadedProjectRuntime};
 
 /// Errors loading an authored project into [`Engine`].
 #[derive(Debug)]
@@ -147,6 +164,7 @@ impl ProjectLoader {
             Self::build_runtime_spine(&registry, &mut runtime, project_specifier.clone(), frame)?;
         Self::attach_projected_nodes(root, &mut registry, &mut runtime, &projected_nodes, frame)?;
         Self::register_projected_bindings(&mut registry, &mut runtime, &projected_nodes, frame)?;
+        Self::register_projected_budgets(&registry, &mut runtime, &projected_nodes);
 
         Ok(LoadedProjectRuntime::new(runtime, registry))
     }
@@ -416,12 +434,18 @@ impl ProjectLoader {
     }
 
     fn attach_projected_nodes_filtered(
-        // Only read when node-shader or node-fixture is on (the asset-backed
-        // kinds, via `materialize_node_text_asset`); the signature must stay
-        // stable across gate combinations, so this is a scoped allow rather
-        // than a `#[cfg]` on the parameter itself.
+        // Only read when node-shader, node-fixture, node-image, node-text or
+        // node-filter is on (the asset-backed kinds); the signature must stay stable
+        // across gate combinations, so this is a scoped allow rather than a
+        // `#[cfg]` on the parameter itself.
         #[cfg_attr(
-            not(any(feature = "node-shader", feature = "node-fixture")),
+            not(any(
+                feature = "node-shader",
+                feature = "node-fixture",
+                feature = "node-image",
+                feature = "node-text",
+                feature = "node-filter"
+            )),
             allow(unused_variables, reason = "read only by the asset-backed node kinds")
         )]
         fs: &dyn LpFs,
@@ -640,12 +664,15 @@ impl ProjectLoader {
                     AssetContentType::ShaderSource,
                     "shader source",
                 )?;
+                let mut shader = ShaderNode::new(
+                    node.id,
+                    config,
+                    glsl_source,
+                    &mut lp_shader::LpFsIncludes::new(fs),
+                );
+                shader.track_includes(registry, frame);
                 runtime
-                    .attach_runtime_node(
-                        node.id,
-                        Box::new(ShaderNode::new(node.id, config, glsl_source)),
-                        frame,
-                    )
+                    .attach_runtime_node(node.id, Box::new(shader), frame)
                     .map_err(|e| ProjectLoadError::InvalidProjectReference {
                         path: node_label(node),
                         reason: format!("attach shader runtime: {e}"),
@@ -764,6 +791,254 @@ impl ProjectLoader {
             }
         }
 
+        for node in projected_nodes {
+            if !should_attach_projected_node(node, targets) {
+                continue;
+            }
+            if node.kind != NodeKind::Image {
+                continue;
+            }
+            #[cfg(feature = "node-image")]
+            {
+                let NodeDef::Image(_) = projected_node_config(registry, node)? else {
+                    continue;
+                };
+                let location =
+                    asset_for_node_content_type(registry, node, AssetContentType::Image)?;
+                // Stream the file when the host can; otherwise hold it whole.
+                let asset = match (runtime.services().asset_stream(), &location) {
+                    (Some(stream), AssetLocation::Artifact { location: artifact }) => {
+                        ImageAsset::Streamed {
+                            stream,
+                            path: artifact.file_path().clone(),
+                        }
+                    }
+                    _ => {
+                        let asset = registry.materialize_asset(fs, &location).map_err(|e| {
+                            ProjectLoadError::InvalidProjectReference {
+                                path: node_label(node),
+                                reason: format!("materialize image: {e:?}"),
+                            }
+                        })?;
+                        ImageAsset::Resident {
+                            bytes: asset.bytes,
+                            revision: asset.revision,
+                        }
+                    }
+                };
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(ImageNode::new(node.id, location, asset)),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach image runtime: {e}"),
+                    })?;
+            }
+            #[cfg(not(feature = "node-image"))]
+            {
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(crate::nodes::CorePlaceholderNode::new_leaf(NodeKind::Image)),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach image placeholder runtime: {e}"),
+                    })?;
+            }
+        }
+
+        for node in projected_nodes {
+            if !should_attach_projected_node(node, targets) {
+                continue;
+            }
+            if node.kind != NodeKind::Text {
+                continue;
+            }
+            #[cfg(feature = "node-text")]
+            {
+                let NodeDef::Text(config) = projected_node_config(registry, node)? else {
+                    continue;
+                };
+                // Built-in fonts need no asset; only a BDF font is read.
+                let font_file = if matches!(config.font.value(), lpc_model::TextFont::Bdf { .. }) {
+                    let location =
+                        asset_for_node_content_type(registry, node, AssetContentType::Font)?;
+                    let asset = registry.materialize_asset(fs, &location).map_err(|e| {
+                        ProjectLoadError::InvalidProjectReference {
+                            path: node_label(node),
+                            reason: format!("materialize font: {e:?}"),
+                        }
+                    })?;
+                    Some(TextFontAsset {
+                        location,
+                        bytes: asset.bytes,
+                        revision: asset.revision,
+                    })
+                } else {
+                    None
+                };
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(TextNode::new(node.id, font_file)),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach text runtime: {e}"),
+                    })?;
+            }
+            #[cfg(not(feature = "node-text"))]
+            {
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(crate::nodes::CorePlaceholderNode::new_leaf(NodeKind::Text)),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach text placeholder runtime: {e}"),
+                    })?;
+            }
+        }
+
+        for node in projected_nodes {
+            if !should_attach_projected_node(node, targets) {
+                continue;
+            }
+            if node.kind != NodeKind::Particle {
+                continue;
+            }
+            #[cfg(feature = "node-particle")]
+            {
+                let NodeDef::Particle(_) = projected_node_config(registry, node)? else {
+                    continue;
+                };
+                runtime
+                    .attach_runtime_node(node.id, Box::new(ParticleNode::new(node.id)), frame)
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach particle runtime: {e}"),
+                    })?;
+            }
+            #[cfg(not(feature = "node-particle"))]
+            {
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(crate::nodes::CorePlaceholderNode::new_leaf(
+                            NodeKind::Particle,
+                        )),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach particle placeholder runtime: {e}"),
+                    })?;
+            }
+        }
+
+        for node in projected_nodes {
+            if !should_attach_projected_node(node, targets) {
+                continue;
+            }
+            if node.kind != NodeKind::Filter {
+                continue;
+            }
+            #[cfg(feature = "node-filter")]
+            {
+                let NodeDef::Filter(config) = projected_node_config(registry, node)? else {
+                    continue;
+                };
+                // Only a `.cube` table is read; without one, `lut` effects
+                // pass the frame through.
+                let lut_file = if matches!(config.lut.value(), lpc_model::FilterLut::Cube { .. }) {
+                    let location =
+                        asset_for_node_content_type(registry, node, AssetContentType::ColorLut)?;
+                    let asset = registry.materialize_asset(fs, &location).map_err(|e| {
+                        ProjectLoadError::InvalidProjectReference {
+                            path: node_label(node),
+                            reason: format!("materialize lut: {e:?}"),
+                        }
+                    })?;
+                    Some(FilterLutAsset {
+                        location,
+                        bytes: asset.bytes,
+                        revision: asset.revision,
+                    })
+                } else {
+                    None
+                };
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(FilterNode::new(node.id, lut_file)),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach filter runtime: {e}"),
+                    })?;
+            }
+            #[cfg(not(feature = "node-filter"))]
+            {
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(crate::nodes::CorePlaceholderNode::new_leaf(
+                            NodeKind::Filter,
+                        )),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach filter placeholder runtime: {e}"),
+                    })?;
+            }
+        }
+
+        for node in projected_nodes {
+            if !should_attach_projected_node(node, targets) {
+                continue;
+            }
+            if node.kind != NodeKind::Palette {
+                continue;
+            }
+            #[cfg(feature = "node-palette")]
+            {
+                let NodeDef::Palette(_) = projected_node_config(registry, node)? else {
+                    continue;
+                };
+                runtime
+                    .attach_runtime_node(node.id, Box::new(PaletteNode::new()), frame)
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach palette runtime: {e}"),
+                    })?;
+            }
+            #[cfg(not(feature = "node-palette"))]
+            {
+                runtime
+                    .attach_runtime_node(
+                        node.id,
+                        Box::new(crate::nodes::CorePlaceholderNode::new_leaf(
+                            NodeKind::Palette,
+                        )),
+                        frame,
+                    )
+                    .map_err(|e| ProjectLoadError::InvalidProjectReference {
+                        path: node_label(node),
+                        reason: format!("attach palette placeholder runtime: {e}"),
+                    })?;
+            }
+        }
+
         for node in projected_nodes {
             if !should_attach_projected_node(node, targets) {
                 continue;
@@ -891,6 +1166,62 @@ impl ProjectLoader {
         }
         Ok(())
     }
+
+    /// Derive every node's CPU budget from the module defs above it: an
+    /// entry under the parent module's `budget.nodes` wins, then the nearest
+    /// enclosing module's `budget.node_us` (the root module's is the project
+    /// default). Runs wherever the binding phase runs, so a budget edit is
+    /// an ordinary def body change. A module whose def did not load
+    /// contributes nothing; that is reported by its own node, not here.
+    pub(super) fn register_projected_budgets(
+        registry: &ProjectRegistry,
+        runtime: &mut Engine,
+        projected_nodes: &[ProjectedNode],
+    ) {
+        let module_budget = |id: NodeId| {
+            let node = projected_nodes.iter().find(|node| node.id == id)?;
+            if node.kind != NodeKind::Module {
+                return None;
+            }
+            match projected_node_config(registry, node) {
+                Ok(NodeDef::Module(config)) => config.budget.data.as_ref(),
+                _ => None,
+            }
+        };
+        let parent_of = |id: NodeId| {
+            projected_nodes
+                .iter()
+                .find(|node| node.id == id)
+                .and_then(|node| node.parent)
+        };
+        let mut authored = VecMap::new();
+        for node in projected_nodes {
+            let Some(parent) = node.parent else {
+                continue;
+            };
+            let own = module_budget(parent);
+            let mut budget_us = own.and_then(|budget| budget.child_us(node.name.as_str()));
+            let mut scope = Some(parent);
+            while budget_us.is_none() {
+                let Some(module) = scope else {
+                    break;
+                };
+                budget_us = module_budget(module).and_then(|budget| budget.default_us());
+                scope = parent_of(module);
+            }
+            let critical = own.is_some_and(|budget| budget.child_critical(node.name.as_str()));
+            if budget_us.is_some() || critical {
+                authored.insert(
+                    node.id,
+                    AuthoredBudget {
+                        budget_us,
+                        critical,
+                    },
+                );
+            }
+        }
+        runtime.set_authored_budgets(authored);
+    }
 }
 
 fn should_attach_projected_node(
@@ -1210,9 +1541,9 @@ fn projected_node_config<'a>(
 
 // Called from the Shader/ComputeShader loops (node-shader, GLSL/compute
 // source assets) and from `resolve_fixture_mapping` (node-fixture, the
-// map2d document) — the only two asset-backed node kinds. `Texture` reads
-// no text asset (image bytes go through a different path), so it does not
-// need this helper.
+// map2d document) — the only text-asset-backed node kinds. `Texture` reads
+// no text asset, and the Image loop reads its binary file directly or
+// through the asset stream, so neither needs this helper.
 #[cfg(any(feature = "node-shader", feature = "node-fixture"))]
 fn materialize_node_text_asset(
     fs: &dyn LpFs,
@@ -1230,7 +1561,13 @@ fn materialize_node_text_asset(
     })
 }
 
-#[cfg(any(feature = "node-shader", feature = "node-fixture"))]
+#[cfg(any(
+    feature = "node-shader",
+    feature = "node-fixture",
+    feature = "node-image",
+    feature = "node-text",
+    feature = "node-filter"
+))]
 fn asset_for_node_content_type(
     registry: &ProjectRegistry,
     node: &ProjectedNode,
@@ -1335,17 +1672,23 @@ fn kind_shapes(kind: NodeKind) -> (Option<SlotShape>, Option<SlotShape>) {
     use lpc_model::nodes::button::ButtonState;
     use lpc_model::nodes::clock::ClockDef;
     use lpc_model::nodes::clock::ClockState;
+    use lpc_model::nodes::filter::{FilterDef, FilterState};
     use lpc_model::nodes::fixture::FixtureDef;
     use lpc_model::nodes::fixture::FixtureState;
     use lpc_model::nodes::fluid::FluidDef;
     use lpc_model::nodes::fluid::FluidState;
+    use lpc_model::nodes::image::ImageDef;
+    use lpc_model::nodes::image::ImageState;
     use lpc_model::nodes::output::OutputDef;
+    use lpc_model::nodes::palette::{PaletteDef, PaletteState};
+    use lpc_model::nodes::particle::{ParticleDef, ParticleState};
     use lpc_model::nodes::playlist::PlaylistDef;
     use lpc_model::nodes::playlist::PlaylistState;
     use lpc_model::nodes::radio::ControlRadioDef;
     use lpc_model::nodes::radio::ControlRadioState;
     use lpc_model::nodes::shader::ShaderState;
     use lpc_model::nodes::shader::{ComputeShaderDef, ShaderDef};
+    use lpc_model::nodes::text::{TextDef, TextState};
     use lpc_model::nodes::texture::TextureDef;
     use lpc_model::nodes::texture::TextureState;
     let def_shape = match kind {
@@ -1353,6 +1696,11 @@ fn kind_shapes(kind: NodeKind) -> (Option<SlotShape>, Option<SlotShape>) {
         NodeKind::Clock => Some(ClockDef::slot_shape()),
         NodeKind::Fixture => Some(FixtureDef::slot_shape()),
         NodeKind::Fluid => Some(FluidDef::slot_shape()),
+        NodeKind::Image => Some(ImageDef::slot_shape()),
+        NodeKind::Text => Some(TextDef::slot_shape()),
+        NodeKind::Particle => Some(ParticleDef::slot_shape()),
+        NodeKind::Filter => Some(FilterDef::slot_shape()),
+        NodeKind::Palette => Some(PaletteDef::slot_shape()),
         NodeKind::Playlist => Some(PlaylistDef::slot_shape()),
         NodeKind::ControlRadio => Some(ControlRadioDef::slot_shape()),
         NodeKind::Shader => Some(ShaderDef::slot_shape()),
@@ -1366,6 +1714,11 @@ fn kind_shapes(kind: NodeKind) -> (Option<SlotShape>, Option<SlotShape>) {
         NodeKind::Clock => Some(ClockState::slot_shape()),
         NodeKind::Fixture => Some(FixtureState::slot_shape()),
         NodeKind::Fluid => Some(FluidState::slot_shape()),
+        NodeKind::Image => Some(ImageState::slot_shape()),
+        NodeKind::Text => Some(TextState::slot_shape()),
+        NodeKind::Particle => Some(ParticleState::slot_shape()),
+        NodeKind::Filter => Some(FilterState::slot_shape()),
+        NodeKind::Palette => Some(PaletteState::slot_shape()),
         NodeKind::Playlist => Some(PlaylistState::slot_shape()),
         NodeKind::ControlRadio => Some(ControlRadioState::slot_shape()),
         NodeKind::Shader => Some(ShaderState::slot_shape()),
@@ -1614,6 +1967,11 @@ fn node_def_bindings(config: &NodeDef) -> &BindingDefs {
         NodeDef::Shader(config) => &config.bindings,
         NodeDef::ComputeShader(config) => &config.bindings,
         NodeDef::Fluid(config) => &config.bindings,
+        NodeDef::Image(config) => &config.bindings,
+        NodeDef::Text(config) => &config.bindings,
+        NodeDef::Particle(config) => &config.bindings,
+        NodeDef::Filter(config) => &config.bindings,
+        NodeDef::Palette(config) => &config.bindings,
         NodeDef::Playlist(config) => &config.bindings,
         NodeDef::ControlRadio(config) => &config.bindings,
         NodeDef::Output(config) => &config.bindings,
@@ -2122,6 +2480,8 @@ fn binding_kind(source: &BindingSource, target: &BindingTarget, slot_name: &str)
         // A palette slot bound to a channel that is not the well-known
         // `palette` still carries a gradient, not a color.
         "palette" | "gradient" => Kind::Gradient,
+        // A text node's message.
+        "text" => Kind::Text,
         _ => Kind::Color,
     }
 }
@@ -2383,7 +2743,7 @@ mod tests {
         let rt = ProjectLoader::load_from_root(&fs, services).expect("load with bad fixture");
         assert_fixture_node_error(
             &rt,
-            "unsupported map2d format 99 (this build reads up to 2)",
+            "unsupported map2d format 99 (this build reads up to 3)",
         );
     }
 
@@ -3179,6 +3539,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn project_loader_applies_module_budgets() {
+        let fs = LpFsMemory::new();
+        fs.write_file("/project.json".as_path(), b"{\n  \"format\": 8\n}\n")
+            .expect("container manifest");
+        fs.write_file(
+            "/module.json".as_path(),
+            br#"
+{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "slow": { "ref": "./clock.json" },
+    "other": { "ref": "./clock.json" }
+  },
+  "budget": {
+    "node_us": 4000,
+    "nodes": {
+      "clock": { "critical": true },
+      "slow": { "us": 12000 }
+    }
+  }
+}
+"#,
+        )
+        .expect("module.json");
+        fs.write_file("/clock.json".as_path(), br#"{ "kind": "Clock" }"#)
+            .expect("clock.json");
+
+        let services = EngineServices::new(TreePath::parse("/budget.show").expect("path"));
+        let rt = ProjectLoader::load_from_root(&fs, services).expect("load");
+        let root = rt.tree().root();
+        let budget = |name: &str| {
+            let node = rt
+                .tree()
+                .lookup_sibling(root, NodeName::parse(name).unwrap())
+                .expect("child node");
+            rt.node_budgets().authored(node)
+        };
+
+        assert_eq!(
+            budget("clock"),
+            AuthoredBudget {
+                budget_us: Some(4000),
+                critical: true,
+            }
+        );
+        assert_eq!(
+            budget("slow"),
+            AuthoredBudget {
+                budget_us: Some(12000),
+                critical: false,
+            }
+        );
+        assert_eq!(
+            budget("other"),
+            AuthoredBudget {
+                budget_us: Some(4000),
+                critical: false,
+            }
+        );
+    }
+
     /// The happy path the break exists for: an ordinary clock, an ordinary
     /// phasor uniform, and no authored wiring anywhere. The clock's
     /// `product` default-publish carries `bus:time`, the shader's evaluator
@@ -5750,6 +6173,11 @@ mod tests {
                 NodeKind::Shader => "node-shader",
                 NodeKind::ComputeShader => "node-shader",
                 NodeKind::Fluid => "node-fluid",
+                NodeKind::Image => "node-image",
+                NodeKind::Text => "node-text",
+                NodeKind::Particle => "node-particle",
+                NodeKind::Filter => "node-filter",
+                NodeKind::Palette => "node-palette",
                 NodeKind::Playlist => "node-playlist",
                 NodeKind::ControlRadio => "node-radio",
                 NodeKind::Fixture => "node-fixture",
@@ -5764,6 +6192,11 @@ mod tests {
             NodeKind::Shader,
             NodeKind::ComputeShader,
             NodeKind::Fluid,
+            NodeKind::Image,
+            NodeKind::Text,
+            NodeKind::Particle,
+            NodeKind::Filter,
+            NodeKind::Palette,
             NodeKind::Playlist,
             NodeKind::ControlRadio,
             NodeKind::Fixture,
@@ -5780,7 +6213,7 @@ mod tests {
     /// status/reporting, which is deliberately absent by design.
     ///
     /// Gated to `node-button` off, so it only compiles when that feature is
-    /// disabled; under the crate's own `default` (all eight node gates on)
+    /// disabled; under the crate's own `default` (all thirteen node gates on)
     /// this cfg compiles the test out entirely, same as the disabled-path
     /// arm it exercises in `attach_projected_nodes_filtered` above. It does
     /// **not** run under `just test` — nothing there tests lpc-engine with a
@@ -5791,7 +6224,7 @@ mod tests {
     ///
     /// ```sh
     /// cargo test -p lpc-engine --no-default-features --features \
-    ///   "std,node-radio,node-fluid,node-fixture,node-texture,node-playlist,node-clock,node-shader" \
+    ///   "std,node-radio,node-fluid,node-fixture,node-texture,node-playlist,node-clock,node-shader,node-image,node-text,node-particle,node-filter,node-palette" \
     ///   disabled_node_kind_still_loads_project
     /// ```
     #[test]
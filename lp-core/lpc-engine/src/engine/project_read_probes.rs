@@ -46,6 +46,18 @@ impl Engine {
         registry: &ProjectRegistry,
         request: RenderProductProbeRequest,
     ) -> RenderProductProbeResult {
+        // Previews are the native linear format or its display encoding;
+        // the narrower storage formats describe buffers, not probe output.
+        let encode: fn(&[u8]) -> Vec<u8> = match request.format {
+            lpc_wire::WireTextureFormat::Rgba16 => <[u8]>::to_vec,
+            lpc_wire::WireTextureFormat::Srgb8 => rgba16_linear_to_srgb8,
+            other => {
+                return RenderProductProbeResult::Unsupported {
+                    product: request.product,
+                    reason: format!("probe preview format {other:?} is not supported"),
+                };
+            }
+        };
         let texture_request = RenderTextureRequest {
             width: request.width,
             height: request.height,
@@ -70,10 +82,7 @@ impl Engine {
                         height: texture.height(),
                     };
                 };
-                let bytes = match request.format {
-                    lpc_wire::WireTextureFormat::Rgba16 => bytes.to_vec(),
-                    lpc_wire::WireTextureFormat::Srgb8 => rgba16_linear_to_srgb8(bytes),
-                };
+                let bytes = encode(bytes);
                 RenderProductProbeResult::Texture {
                     product,
                     revision,
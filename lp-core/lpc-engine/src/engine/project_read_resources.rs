@@ -220,7 +220,10 @@ fn runtime_buffer_metadata_payload(
 fn texture_format(format: RuntimeTextureFormat) -> WireTextureFormat {
     match format {
         RuntimeTextureFormat::Rgba16 => WireTextureFormat::Rgba16,
-        RuntimeTextureFormat::Rgb8 => WireTextureFormat::Srgb8,
+        RuntimeTextureFormat::Rgb16 => WireTextureFormat::Rgb16,
+        RuntimeTextureFormat::Rgb8 => WireTextureFormat::Rgb8,
+        RuntimeTextureFormat::R16 => WireTextureFormat::R16,
+        RuntimeTextureFormat::R8 => WireTextureFormat::R8,
     }
 }
 
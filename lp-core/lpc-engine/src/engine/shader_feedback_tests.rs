@@ -0,0 +1,247 @@
+//! Feedback uniforms end to end: a `feedback` slot binds the previous frame
+//! of a visual product to a `sampler2D`.
+//!
+//! Unbound, that product is the shader's own output, so the accumulator here
+//! — `texture(trail, uv) + STEP` — brightens by one step per engine
+//! frame. That one fact pins most of the contract: the history is read one
+//! frame late, it swaps once per frame however often the shader renders, and
+//! the frame before the first is black. A bound slot reads another shader's
+//! frame instead; memory pressure keeps the history until `Critical`.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use lpc_model::{NodeId, TreePath};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+use crate::node::PressureLevel;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+const WIDTH: u32 = 4;
+const HEIGHT: u32 = 2;
+/// What the accumulator adds per frame.
+const STEP: f32 = 0.0625;
+/// Every frame round-trips the history through unorm16, so allow a few
+/// steps of that grid to build up across the frames a test compares.
+const TOLERANCE: f32 = 4.0 / u16::MAX as f32;
+
+const ACCUMULATE_GLSL: &str = "layout(binding = 0) uniform vec2 outputSize;\n\
+     layout(binding = 1) uniform sampler2D trail;\n\
+     vec4 render_2d(vec2 pos) {\n\
+       vec4 last = texture(trail, pos / outputSize);\n\
+       return vec4(min(last.rgb + vec3(0.0625), vec3(1.0)), 1.0);\n\
+     }";
+
+const SOLID_GLSL: &str = "vec4 render_2d(vec2 pos) { return vec4(0.5, 0.25, 0.75, 1.0); }";
+
+const ECHO_GLSL: &str = "layout(binding = 0) uniform vec2 outputSize;\n\
+     layout(binding = 1) uniform sampler2D upstream;\n\
+     vec4 render_2d(vec2 pos) { return texture(upstream, pos / outputSize); }";
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+}
+
+impl Project {
+    /// Load a module of `(name, glsl, json)` shaders.
+    fn load(shaders: &[(&str, &str, String)]) -> Self {
+        let fs = LpFsMemory::new();
+        let write = |path: &str, body: &str| {
+            fs.write_file(path.as_path(), body.as_bytes())
+                .expect("write project file");
+        };
+        write("/project.json", "{ \"format\": 8 }\n");
+        let mut nodes = Vec::new();
+        for (name, glsl, json) in shaders {
+            write(&alloc::format!("/{name}.glsl"), glsl);
+            write(&alloc::format!("/{name}.json"), json);
+            nodes.push(alloc::format!(r#""{name}": {{ "ref": "./{name}.json" }}"#));
+        }
+        write(
+            "/module.json",
+            &alloc::format!(
+                "{{\n  \"kind\": \"Module\",\n  \"nodes\": {{ {} }}\n}}\n",
+                nodes.join(", ")
+            ),
+        );
+
+        let services = EngineServices::new(TreePath::parse("/shader_feedback.show").expect("root"));
+        let loaded = ProjectLoader::load_from_root(&fs, services).expect("load project");
+        let (mut engine, registry) = loaded.into_parts();
+        engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))));
+        Self { engine, registry }
+    }
+
+    fn node(&self, suffix: &str) -> NodeId {
+        self.engine
+            .tree()
+            .entries()
+            .find(|entry| entry.path.to_string().ends_with(suffix))
+            .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+            .id
+    }
+
+    /// One engine frame, with a demand on each shader so `produce` resolves
+    /// its feedback bindings.
+    fn tick(&mut self, nodes: &[NodeId]) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        for node in nodes {
+            resolve_with_engine_host(
+                &mut self.engine,
+                &self.registry,
+                QueryKey::ProducedSlot {
+                    node: *node,
+                    slot: crate::nodes::shader::shader_node::shader_output_path(),
+                },
+                ResolveLogLevel::Off,
+            )
+            .expect("demand the shader");
+        }
+    }
+
+    /// Render one shader and return the red lane of every pixel.
+    fn render_red(&mut self, node: NodeId) -> Vec<f32> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(node, 0),
+                &RenderTextureRequest {
+                    width: WIDTH,
+                    height: HEIGHT,
+                    format: lps_shared::TextureStorageFormat::Rgba16Unorm,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render feedback shader");
+        let bytes = texture.try_raw_bytes().expect("host texture bytes");
+        bytes
+            .chunks_exact(8)
+            .map(|px| u16::from_le_bytes([px[0], px[1]]) as f32 / u16::MAX as f32)
+            .collect()
+    }
+
+    /// Run past the compile-window deferral so the next render has a program.
+    fn warm_up(&mut self, nodes: &[NodeId]) {
+        for _ in 0..3 {
+            self.tick(nodes);
+            for node in nodes {
+                let _ = self.render_red(*node);
+            }
+        }
+    }
+}
+
+fn shader_json(name: &str, consumed: &str, bindings: &str) -> String {
+    alloc::format!(
+        r#"{{
+  "kind": "Shader",
+  "source": {{ "path": "{name}.glsl" }},
+  "bindings": {{ {bindings} }},
+  "consumed": {{ {consumed} }}
+}}"#
+    )
+}
+
+fn accumulator() -> (&'static str, &'static str, String) {
+    (
+        "acc",
+        ACCUMULATE_GLSL,
+        shader_json(
+            "acc",
+            r#""trail": { "kind": "feedback", "value": "sampler2D", "label": "Trail", "description": "" }"#,
+            "",
+        ),
+    )
+}
+
+/// Every pixel of `row` is `expected`, within [`TOLERANCE`].
+fn uniform_at(row: &[f32], expected: f32) {
+    for (index, value) in row.iter().enumerate() {
+        assert!(
+            (value - expected).abs() <= TOLERANCE,
+            "pixel {index}: {value} != {expected}"
+        );
+    }
+}
+
+// --- Tests -----------------------------------------------------------------
+
+/// An unbound feedback slot reads the shader's own last frame: the
+/// accumulator climbs one step per engine frame, and rendering it twice in
+/// one frame does not climb twice.
+#[test]
+fn an_unbound_feedback_slot_accumulates_one_step_per_frame() {
+    let mut project = Project::load(&[accumulator()]);
+    let acc = project.node("acc.shader");
+    project.warm_up(&[acc]);
+
+    project.tick(&[acc]);
+    let first = project.render_red(acc);
+    assert!(first[0] > 0.0, "the shader compiled and rendered");
+    uniform_at(&first, first[0]);
+    uniform_at(&project.render_red(acc), first[0]);
+
+    project.tick(&[acc]);
+    uniform_at(&project.render_red(acc), first[0] + STEP);
+    project.tick(&[acc]);
+    uniform_at(&project.render_red(acc), first[0] + 2.0 * STEP);
+}
+
+/// A bound feedback slot reads another shader's frame, not its own.
+#[test]
+fn a_bound_feedback_slot_reads_the_named_product() {
+    let echo = shader_json(
+        "echo",
+        r#""upstream": { "kind": "feedback", "value": "sampler2D", "label": "Upstream", "description": "" }"#,
+        r#""upstream": { "source": "node:../solid#output" }"#,
+    );
+    let mut project = Project::load(&[
+        ("solid", SOLID_GLSL, shader_json("solid", "", "")),
+        ("echo", ECHO_GLSL, echo),
+    ]);
+    let solid = project.node("solid.shader");
+    let echo = project.node("echo.shader");
+    project.warm_up(&[solid, echo]);
+
+    project.tick(&[solid, echo]);
+    uniform_at(&project.render_red(echo), 0.5);
+}
+
+/// `High` pressure keeps the history — it is state, not a cache — while
+/// `Critical` drops it and the accumulator starts over from black.
+#[test]
+fn memory_pressure_keeps_the_history_until_critical() {
+    let mut project = Project::load(&[accumulator()]);
+    let acc = project.node("acc.shader");
+    project.warm_up(&[acc]);
+    project.tick(&[acc]);
+    let before = project.render_red(acc)[0];
+    assert!(before > STEP, "a few frames have accumulated");
+
+    project
+        .engine
+        .broadcast_memory_pressure(PressureLevel::High)
+        .expect("high pressure");
+    project.tick(&[acc]);
+    uniform_at(&project.render_red(acc), before + STEP);
+
+    project
+        .engine
+        .broadcast_memory_pressure(PressureLevel::Critical)
+        .expect("critical pressure");
+    project.tick(&[acc]);
+    uniform_at(&project.render_red(acc), STEP);
+}
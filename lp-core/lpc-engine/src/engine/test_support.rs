@@ -1,7 +1,9 @@
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::rc::Rc;
 use alloc::string::String;
 use alloc::sync::Arc;
+use core::cell::Cell;
 use core::sync::atomic::{AtomicU32, Ordering};
 use lp_collection::VecMap;
 
@@ -10,6 +12,7 @@ use lpc_model::{
     SlotPathSegment, SlotShapeRegistry, SlotShapeRegistryError, Slotted, TreePath, ValueSlot,
 };
 use lpc_registry::ProjectRegistry;
+use lpc_shared::time::TimeProvider;
 use lpc_wire::{WireChildKind, WireSlotIndex};
 use lps_shared::LpsValueF32;
 
@@ -37,6 +40,8 @@ pub(crate) struct EngineTestBuilder {
     fixture_records: VecMap<String, RecordedValue>,
     output_records: VecMap<String, RecordedValue>,
     selectors: VecMap<String, Arc<AtomicU32>>,
+    clock: Option<Rc<TestClock>>,
+    shader_costs: VecMap<String, Arc<AtomicU32>>,
 }
 
 pub(crate) struct EngineTestHarness {
@@ -47,6 +52,18 @@ pub(crate) struct EngineTestHarness {
     fixture_records: VecMap<String, RecordedValue>,
     output_records: VecMap<String, RecordedValue>,
     selectors: VecMap<String, Arc<AtomicU32>>,
+    shader_costs: VecMap<String, Arc<AtomicU32>>,
+}
+
+/// A microsecond clock that only moves when a test (or a costly dummy node)
+/// advances it, so budget meters read exact costs.
+pub(crate) struct TestClock {
+    now_us: Cell<u64>,
+}
+
+struct ShaderCost {
+    clock: Rc<TestClock>,
+    us: Arc<AtomicU32>,
 }
 
 pub(crate) struct OutputSpec {
@@ -76,6 +93,8 @@ impl EngineTestBuilder {
             fixture_records: VecMap::new(),
             output_records: VecMap::new(),
             selectors: VecMap::new(),
+            clock: None,
+            shader_costs: VecMap::new(),
         }
     }
 
@@ -87,6 +106,28 @@ impl EngineTestBuilder {
         self
     }
 
+    /// A shader whose every `produce` advances the engine clock by `cost_us`.
+    /// The first one installs a [`TestClock`] as the engine time provider.
+    pub(crate) fn costly_shader(mut self, label: &str, slot: OutputSpec, cost_us: u32) -> Self {
+        let clock = Rc::clone(self.clock.get_or_insert_with(|| {
+            let clock = Rc::new(TestClock::new());
+            let provider: Rc<dyn TimeProvider> = clock.clone();
+            self.engine.services_mut().set_time_provider(Some(provider));
+            clock
+        }));
+        let ticks = Arc::new(AtomicU32::new(0));
+        let us = Arc::new(AtomicU32::new(cost_us));
+        let mut node = DummyShaderNode::new(slot.path, slot.value, Arc::clone(&ticks));
+        node.cost = Some(ShaderCost {
+            clock,
+            us: Arc::clone(&us),
+        });
+        self.attach_node(label, "shader", Box::new(node));
+        self.shader_ticks.insert(String::from(label), ticks);
+        self.shader_costs.insert(String::from(label), us);
+        self
+    }
+
     pub(crate) fn fixture(mut self, label: &str) -> Self {
         let record = RecordedValue::new();
         let node = DummyFixtureNode::new(default_demand_input_path(), record.clone());
@@ -187,6 +228,7 @@ impl EngineTestBuilder {
             fixture_records: self.fixture_records,
             output_records: self.output_records,
             selectors: self.selectors,
+            shader_costs: self.shader_costs,
         }
     }
 
@@ -258,6 +300,15 @@ impl EngineTestHarness {
             .store(0, Ordering::Relaxed);
     }
 
+    /// Change what each `produce` of a [`EngineTestBuilder::costly_shader`]
+    /// costs from the next tick on.
+    pub(crate) fn set_shader_cost(&self, label: &str, cost_us: u32) {
+        self.shader_costs
+            .get(label)
+            .expect("costly shader label")
+            .store(cost_us, Ordering::Relaxed);
+    }
+
     pub(crate) fn fixture_f32(&self, label: &str) -> Option<f32> {
         self.fixture_records
             .get(label)
@@ -346,6 +397,28 @@ impl TestBindingSource {
     }
 }
 
+impl TestClock {
+    fn new() -> Self {
+        Self {
+            now_us: Cell::new(0),
+        }
+    }
+
+    fn advance(&self, delta_us: u64) {
+        self.now_us.set(self.now_us.get().saturating_add(delta_us));
+    }
+}
+
+impl TimeProvider for TestClock {
+    fn now_ms(&self) -> u64 {
+        self.now_us.get() / 1000
+    }
+
+    fn now_us(&self) -> u64 {
+        self.now_us.get()
+    }
+}
+
 impl RecordedValue {
     fn new() -> Self {
         Self {
@@ -430,6 +503,7 @@ pub(crate) fn trace_has_value_origin_path(
 pub(crate) struct DummyShaderNode {
     state: DummyShaderState,
     tick_count: Arc<AtomicU32>,
+    cost: Option<ShaderCost>,
 }
 
 #[derive(Default, Slotted)]
@@ -446,6 +520,7 @@ impl DummyShaderNode {
                 outputs: MapSlot::with_version(Revision::new(0), outputs),
             },
             tick_count,
+            cost: None,
         }
     }
 }
@@ -457,6 +532,10 @@ impl NodeRuntime for DummyShaderNode {
         ctx: &mut TickContext<'_>,
     ) -> Result<ProduceResult, NodeError> {
         self.tick_count.fetch_add(1, Ordering::Relaxed);
+        if let Some(cost) = &self.cost {
+            cost.clock
+                .advance(u64::from(cost.us.load(Ordering::Relaxed)));
+        }
         for output in self.state.outputs.entries.values_mut() {
             output.set_with_version(ctx.revision(), *output.value());
         }
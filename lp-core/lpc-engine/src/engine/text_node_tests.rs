@@ -0,0 +1,371 @@
+//! Text nodes end to end: loader attach (built-in and BDF fonts), scrolling
+//! off a timebase, and palette coloring off `bus:palette`.
+//!
+//! Expected pixels come from `lpc-font`'s own [`TextCanvas`] laid out with
+//! the same options, so these tests are about the node's plumbing rather
+//! than about glyph shapes. Renders are requested at the canvas size, where
+//! sampling is the identity and the comparison can be exact.
+
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use lpc_font::{
+    BdfFont, BuiltinFont, Font, TextAlign, TextCanvas, TextColoring, TextOptions, TextScroll,
+};
+use lpc_model::{
+    ChannelName, Colorspace, Gradient, GradientConfig, GradientStop, InterpMethod, Kind, LpValue,
+    NodeId, ProductRef, TimeProduct, ToLpValue, TreePath,
+};
+use lpc_registry::ProjectRegistry;
+use lpfs::{AsLpPath, LpFs, LpFsMemory};
+use lps_shared::TextureStorageFormat;
+
+use crate::dataflow::binding::{BindingDraft, BindingPriority, BindingSource, BindingTarget};
+use crate::dataflow::resolver::{QueryKey, ResolveLogLevel};
+use crate::engine::{Engine, EngineServices, ProjectLoader, resolve_with_engine_host};
+use crate::nodes::text_output_path;
+use crate::products::visual::{ConsumerPolicy, RenderTextureRequest, VisualProduct, VisualSpace};
+
+const TICK_MS: u32 = 100;
+
+const TINY_BDF: &[u8] = include_bytes!("../../../lpc-font/src/fonts/tiny-3x5.bdf");
+
+// --- Harness ---------------------------------------------------------------
+
+struct Project {
+    engine: Engine,
+    registry: ProjectRegistry,
+}
+
+impl Project {
+    fn node(&self, suffix: &str) -> NodeId {
+        self.engine
+            .tree()
+            .entries()
+            .find(|entry| entry.path.to_string().ends_with(suffix))
+            .unwrap_or_else(|| panic!("no node ending in {suffix}"))
+            .id
+    }
+
+    /// One frame, then a demand on the text's output: nothing consumes it
+    /// here, and `produce` is where the canvas is laid out.
+    fn tick(&mut self, text: NodeId) {
+        self.engine.tick(&self.registry, TICK_MS).expect("tick");
+        resolve_with_engine_host(
+            &mut self.engine,
+            &self.registry,
+            QueryKey::ProducedSlot {
+                node: text,
+                slot: text_output_path(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("demand the text");
+    }
+
+    /// Render the text at `width`×`height` and return 8-bit RGBA.
+    fn render(&mut self, text: NodeId, width: u32, height: u32) -> Vec<u8> {
+        self.render_as(text, width, height, TextureStorageFormat::Rgba16Unorm)
+            .chunks_exact(2)
+            .map(|lane| (u16::from_le_bytes([lane[0], lane[1]]) / 257) as u8)
+            .collect()
+    }
+
+    /// Render the text at `width`×`height` and return the raw `format`
+    /// bytes.
+    fn render_as(
+        &mut self,
+        text: NodeId,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Vec<u8> {
+        let texture = self
+            .engine
+            .render_texture_for_test(
+                &self.registry,
+                VisualProduct::new(text, 0),
+                &RenderTextureRequest {
+                    width,
+                    height,
+                    format,
+                    time_seconds: 0.0,
+                    space: VisualSpace::TwoD,
+                    policy: ConsumerPolicy::default(),
+                },
+            )
+            .expect("render text");
+        texture
+            .try_raw_bytes()
+            .expect("host texture bytes")
+            .to_vec()
+    }
+
+    /// Publish `timebase`'s time product on `bus:time`, where the text's
+    /// `time` slot binds by default.
+    fn publish_time_product(&mut self, timebase: NodeId) {
+        self.add_literal(
+            LpValue::Product(ProductRef::Time(TimeProduct::new(timebase, 0))),
+            "time",
+            Kind::Instant,
+        );
+    }
+
+    /// Write a gradient config onto `bus:palette`, where the text's
+    /// `palette` slot binds by default.
+    fn publish_palette(&mut self, config: &GradientConfig) {
+        self.add_literal(config.to_lp_value(), "palette", Kind::Gradient);
+    }
+
+    fn add_literal(&mut self, value: LpValue, channel: &str, kind: Kind) {
+        let owner = self.engine.tree().root();
+        let revision = self.engine.revision();
+        self.engine
+            .add_binding(
+                BindingDraft {
+                    source: BindingSource::Literal(value),
+                    target: BindingTarget::BusChannel(ChannelName(String::from(channel))),
+                    priority: BindingPriority::authored(),
+                    kind,
+                    owner,
+                },
+                revision,
+            )
+            .expect("register literal binding");
+    }
+
+    fn set_timebase(&mut self, timebase: NodeId, seconds: f32, delta: f32) {
+        let revision = self.engine.revision();
+        self.engine
+            .timebases_mut()
+            .set_timebase(timebase, seconds, delta, revision);
+    }
+}
+
+// --- Project fixtures ------------------------------------------------------
+
+/// A clock plus one text node authored with `fields`, and the tiny font as
+/// `fonts/tiny.bdf` for the ones that ask for a file.
+fn text_fs(fields: &str) -> LpFsMemory {
+    let fs = LpFsMemory::new();
+    let write = |path: &str, body: &[u8]| {
+        fs.write_file(path.as_path(), body)
+            .expect("write project file");
+    };
+    write("/project.json", b"{ \"format\": 8 }\n");
+    write("/fonts/tiny.bdf", TINY_BDF);
+    write(
+        "/clock.json",
+        br#"{ "kind": "Clock", "bindings": { "product": { "target": "bus:clock_product" } } }"#,
+    );
+    write(
+        "/text.json",
+        alloc::format!(r#"{{ "kind": "Text", {fields} }}"#).as_bytes(),
+    );
+    write(
+        "/module.json",
+        br#"{
+  "kind": "Module",
+  "nodes": {
+    "clock": { "ref": "./clock.json" },
+    "text": { "ref": "./text.json" }
+  }
+}
+"#,
+    );
+    fs
+}
+
+fn load(fs: &LpFsMemory) -> Project {
+    let services = EngineServices::new(TreePath::parse("/text.show").expect("root"));
+    let loaded = ProjectLoader::load_from_root(fs, services).expect("load project");
+    let (mut engine, registry) = loaded.into_parts();
+    engine.set_graphics(Some(Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+        lp_shader::ShaderFrontend::LpsGlsl,
+    ))));
+    Project { engine, registry }
+}
+
+fn options(width: u32, height: u32) -> TextOptions {
+    TextOptions {
+        width,
+        height,
+        ..TextOptions::default()
+    }
+}
+
+/// The canvas `lpc-font` lays out, as 8-bit RGBA with every inked pixel in
+/// `ink` and paper transparent.
+fn expected(font: &Font, text: &str, options: &TextOptions, seconds: f32, ink: [u8; 4]) -> Vec<u8> {
+    let mut canvas = TextCanvas::new();
+    canvas.render(font, text, options, seconds);
+    let mut pixels = Vec::new();
+    for y in 0..options.height {
+        for x in 0..options.width {
+            match canvas.palette_index(x, y) {
+                Some(_) => pixels.extend_from_slice(&ink),
+                None => pixels.extend_from_slice(&[0; 4]),
+            }
+        }
+    }
+    pixels
+}
+
+fn solid(c: [f32; 3]) -> Gradient {
+    Gradient {
+        space: Colorspace::LinearSrgb,
+        method: InterpMethod::Linear,
+        stops: alloc::vec![GradientStop { at: 0.0, c }, GradientStop { at: 1.0, c }],
+    }
+}
+
+const WHITE: [u8; 4] = [255; 4];
+
+// --- Tests -----------------------------------------------------------------
+
+/// Still text with nothing on `bus:palette` or `bus:time` draws the
+/// built-in font in white: only scrolling or a cycling palette reads the
+/// clock.
+#[test]
+fn still_text_renders_white_without_a_time_product() {
+    let fs = text_fs(r#""text": "HI", "size": { "width": 16, "height": 8 }"#);
+    let mut project = load(&fs);
+    let text = project.node("text.text");
+
+    project.tick(text);
+
+    let pixels = project.render(text, 16, 8);
+    assert_eq!(
+        pixels,
+        expected(
+            &Font::Builtin(BuiltinFont::Classic5x8),
+            "HI",
+            &options(16, 8),
+            0.0,
+            WHITE
+        )
+    );
+    assert!(pixels.contains(&255), "the glyphs are drawn");
+}
+
+/// A single-channel consumer gets the glyph coverage: ink is full red,
+/// paper is zero.
+#[test]
+fn still_text_renders_into_a_single_channel_request() {
+    let fs = text_fs(r#""text": "HI", "size": { "width": 16, "height": 8 }"#);
+    let mut project = load(&fs);
+    let text = project.node("text.text");
+
+    project.tick(text);
+
+    let red: Vec<u8> = expected(
+        &Font::Builtin(BuiltinFont::Classic5x8),
+        "HI",
+        &options(16, 8),
+        0.0,
+        WHITE,
+    )
+    .chunks_exact(4)
+    .map(|texel| texel[0])
+    .collect();
+    assert_eq!(
+        project.render_as(text, 16, 8, TextureStorageFormat::R8Unorm),
+        red
+    );
+}
+
+/// A left marquee sits where `lpc-font` puts it at each timebase second,
+/// so a paused clock would hold it in place.
+#[test]
+fn marquee_follows_the_timebase() {
+    let fs = text_fs(
+        r#""text": "GO", "size": { "width": 16, "height": 8 }, "scroll": { "kind": "Left" }, "speed": 8.0, "align": { "kind": "Left" }"#,
+    );
+    let mut project = load(&fs);
+    let clock = project.node("clock.clock");
+    let text = project.node("text.text");
+    project.publish_time_product(clock);
+
+    let options = TextOptions {
+        scroll: TextScroll::Left,
+        speed: 8.0,
+        align: TextAlign::Left,
+        ..options(16, 8)
+    };
+    let font = Font::Builtin(BuiltinFont::Classic5x8);
+    let mut frames = Vec::new();
+    for seconds in [0.0, 1.0, 2.5] {
+        project.set_timebase(clock, seconds, 0.1);
+        project.tick(text);
+        let pixels = project.render(text, 16, 8);
+        assert_eq!(
+            pixels,
+            expected(&font, "GO", &options, seconds, WHITE),
+            "at {seconds}s"
+        );
+        frames.push(pixels);
+    }
+    assert_ne!(frames[0], frames[1], "the text moved");
+}
+
+/// A `Bdf` font is read from the project at load and drawn in place of the
+/// built-in ones.
+#[test]
+fn bdf_font_asset_draws_its_glyphs() {
+    let fs = text_fs(
+        r#""text": "ABC", "font": { "kind": "Bdf", "source": "fonts/tiny.bdf" }, "size": { "width": 12, "height": 5 }"#,
+    );
+    let mut project = load(&fs);
+    let text = project.node("text.text");
+
+    project.tick(text);
+
+    let font = Font::Bdf(BdfFont::parse(TINY_BDF).expect("tiny font"));
+    let pixels = project.render(text, 12, 5);
+    assert_eq!(pixels, expected(&font, "ABC", &options(12, 5), 0.0, WHITE));
+    assert_ne!(
+        pixels,
+        expected(
+            &Font::Builtin(BuiltinFont::Classic5x8),
+            "ABC",
+            &options(12, 5),
+            0.0,
+            WHITE
+        ),
+        "not the default font"
+    );
+}
+
+/// A palette on `bus:palette` colors the glyphs; a cycling one steps with
+/// the timebase.
+#[test]
+fn palette_on_the_bus_colors_the_glyphs() {
+    let fs = text_fs(
+        r#""text": "HI", "size": { "width": 16, "height": 8 }, "coloring": { "kind": "Solid" }"#,
+    );
+    let mut project = load(&fs);
+    let clock = project.node("clock.clock");
+    let text = project.node("text.text");
+    project.publish_time_product(clock);
+    project.publish_palette(&GradientConfig::Cycle {
+        set: alloc::vec![solid([1.0, 0.0, 0.0]), solid([0.0, 1.0, 0.0])],
+        step_seconds: 1.0,
+        fade_seconds: 0.0,
+    });
+
+    let options = TextOptions {
+        coloring: TextColoring::Solid,
+        ..options(16, 8)
+    };
+    let font = Font::Builtin(BuiltinFont::Classic5x8);
+    for (seconds, ink) in [(0.5, [255, 0, 0, 255]), (1.5, [0, 255, 0, 255])] {
+        project.set_timebase(clock, seconds, 0.1);
+        project.tick(text);
+        assert_eq!(
+            project.render(text, 16, 8),
+            expected(&font, "HI", &options, seconds, ink),
+            "at {seconds}s"
+        );
+    }
+}
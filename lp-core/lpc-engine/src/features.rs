@@ -28,11 +28,16 @@ const fn origin(feature: LpFeature) -> FeatureOrigin {
     match feature {
         LpFeature::NodeButton => FeatureOrigin::Engine(cfg!(feature = "node-button")),
         LpFeature::NodeClock => FeatureOrigin::Engine(cfg!(feature = "node-clock")),
+        LpFeature::NodeFilter => FeatureOrigin::Engine(cfg!(feature = "node-filter")),
         LpFeature::NodeFluid => FeatureOrigin::Engine(cfg!(feature = "node-fluid")),
         LpFeature::NodeFixture => FeatureOrigin::Engine(cfg!(feature = "node-fixture")),
+        LpFeature::NodeImage => FeatureOrigin::Engine(cfg!(feature = "node-image")),
+        LpFeature::NodePalette => FeatureOrigin::Engine(cfg!(feature = "node-palette")),
+        LpFeature::NodeParticle => FeatureOrigin::Engine(cfg!(feature = "node-particle")),
         LpFeature::NodePlaylist => FeatureOrigin::Engine(cfg!(feature = "node-playlist")),
         LpFeature::NodeRadio => FeatureOrigin::Engine(cfg!(feature = "node-radio")),
         LpFeature::NodeShader => FeatureOrigin::Engine(cfg!(feature = "node-shader")),
+        LpFeature::NodeText => FeatureOrigin::Engine(cfg!(feature = "node-text")),
         LpFeature::NodeTexture => FeatureOrigin::Engine(cfg!(feature = "node-texture")),
         // Retained as wire vocabulary, reported by nothing. No target unwinds
         // (ADR 2026-08-02-rv32-firmwares-are-abort-tier); the variant keeps its
@@ -87,42 +92,58 @@ pub const ENGINE_FEATURE_FRAGMENT: &str = lpc_model::lp_const_concat!(
     engine_fragment(LpFeature::ALL[12]),
     engine_fragment(LpFeature::ALL[13]),
     engine_fragment(LpFeature::ALL[14]),
+    engine_fragment(LpFeature::ALL[15]),
+    engine_fragment(LpFeature::ALL[16]),
+    engine_fragment(LpFeature::ALL[17]),
+    engine_fragment(LpFeature::ALL[18]),
+    engine_fragment(LpFeature::ALL[19]),
 );
 
 // A new LpFeature variant grows ALL past this fragment list — fail the build
 // here until the list above covers it.
-const _: () = assert!(LpFeature::ALL.len() == 15);
+const _: () = assert!(LpFeature::ALL.len() == 20);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    /// Under the crate's default feature set (all eight node gates on) the
-    /// derivation yields exactly the eight `node.*` features. The expected list
-    /// is written out by hand — independent of the `cfg!` match — so a wrong
-    /// gate string or dropped arm in `origin` fails here instead of shipping.
+    /// Under the crate's default feature set (all thirteen node gates on) the
+    /// derivation yields exactly the thirteen `node.*` features. The expected
+    /// list is written out by hand — independent of the `cfg!` match — so a
+    /// wrong gate string or dropped arm in `origin` fails here instead of
+    /// shipping.
     #[test]
     #[cfg(all(
         feature = "node-button",
         feature = "node-clock",
+        feature = "node-filter",
         feature = "node-fluid",
         feature = "node-fixture",
+        feature = "node-image",
+        feature = "node-palette",
+        feature = "node-particle",
         feature = "node-playlist",
         feature = "node-radio",
         feature = "node-shader",
+        feature = "node-text",
         feature = "node-texture",
     ))]
-    fn default_build_yields_the_eight_node_features() {
+    fn default_build_yields_the_thirteen_node_features() {
         assert_eq!(
             supported_features(),
             alloc::vec![
                 LpFeature::NodeButton,
                 LpFeature::NodeClock,
+                LpFeature::NodeFilter,
                 LpFeature::NodeFluid,
                 LpFeature::NodeFixture,
+                LpFeature::NodeImage,
+                LpFeature::NodePalette,
+                LpFeature::NodeParticle,
                 LpFeature::NodePlaylist,
                 LpFeature::NodeRadio,
                 LpFeature::NodeShader,
+                LpFeature::NodeText,
                 LpFeature::NodeTexture,
             ]
         );
@@ -156,6 +177,11 @@ mod tests {
             NodeKind::Shader,
             NodeKind::ComputeShader,
             NodeKind::Fluid,
+            NodeKind::Image,
+            NodeKind::Text,
+            NodeKind::Particle,
+            NodeKind::Filter,
+            NodeKind::Palette,
             NodeKind::Playlist,
             NodeKind::ControlRadio,
             NodeKind::Fixture,
@@ -25,9 +25,11 @@ pub mod shader_abi;
 
 pub use engine::error::Error;
 pub use engine::{
-    ButtonService, Engine, EngineError, EngineProjectReadSource, EngineServices, FrameNum,
-    FrameTime, OutputFlushError, ProjectLoadError, ProjectLoader, ProjectReadEventStreamError,
-    RadioService, RuntimeApplyResult,
+    AssetStreamService, AuthoredBudget, ButtonService, Degradation, Engine, EngineError,
+    EngineProjectReadSource, EngineServices, FrameEvent, FrameLog, FrameNum, FrameRecorder,
+    FrameTime, NodeBudgetStore, NodeCost, OutputFlushError, ProjectLoadError, ProjectLoader,
+    ProjectReadEventStreamError, RadioService, ReplayError, ReplayReport, RuntimeApplyResult,
+    replay_frame_log,
 };
 pub use features::supported_features;
 // Graphics seam re-exports: the traits/handles live in `lp-gfx`; the
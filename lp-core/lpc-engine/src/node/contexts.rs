@@ -103,6 +103,13 @@ impl<'a> AssetRefreshContext<'a> {
             .read_asset_bytes_if_changed(self.fs, location, since)
     }
 
+    pub fn read_asset_text(
+        &mut self,
+        location: &AssetLocation,
+    ) -> Result<AssetText, AssetReadError> {
+        self.registry.materialize_asset_text(self.fs, location)
+    }
+
     pub fn read_asset_text_if_changed(
         &mut self,
         location: &AssetLocation,
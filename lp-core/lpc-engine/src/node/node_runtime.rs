@@ -1,5 +1,8 @@
 //! Engine spine [`NodeRuntime`] trait: produce, consume, destroy, memory pressure, and runtime state.
 
+use alloc::boxed::Box;
+use core::any::Any;
+
 use crate::products::control::{ControlLayout, ControlProduct};
 use crate::resource::RuntimeBufferId;
 use lpc_model::{
@@ -33,6 +36,50 @@ pub enum AssetRefreshResult {
     Refreshed,
 }
 
+/// State one runtime hands to its replacement across a rebuild.
+///
+/// Opaque to the engine: only the node kind that exported it knows its type,
+/// and [`NodeRuntime::import_state`] downcasts to find out whether it is its
+/// own. A replacement of a different kind simply fails the downcast.
+pub type MigratedState = Box<dyn Any>;
+
+/// What a runtime declares about its state when a project edit rebuilds it
+/// (hot reload): see [`NodeRuntime::export_state`].
+pub enum StateExport {
+    /// Nothing a rebuild would lose — the node is a pure function of its
+    /// config and inputs, or keeps only caches it re-derives.
+    Stateless,
+    /// Live state for the replacement's [`NodeRuntime::import_state`].
+    Carry(MigratedState),
+    /// Live state that does not survive a rebuild: the replacement starts
+    /// over. The reason is reported, so a reset during live tuning is a
+    /// documented behaviour rather than a surprise.
+    Resets { reason: &'static str },
+    /// The node kind never said. Nothing is carried — like `Resets`, but
+    /// without a reason to report, because nobody has checked whether the
+    /// node keeps state. Only the trait default returns this; a node kind
+    /// that ships should declare one of the others.
+    Undeclared,
+}
+
+/// How a node sheds work when it overruns its CPU budget; see
+/// [`NodeRuntime::degrade_mode`] and `engine::node_budget`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DegradeMode {
+    /// Never degraded. Outputs, fixtures and clocks: skipping them IS the
+    /// stutter the budget exists to prevent.
+    Critical,
+    /// The engine skips `produce` on some frames and consumers read the
+    /// last production. Needs nothing from the node.
+    SkipTick,
+    /// The node renders at a fraction of the requested resolution
+    /// ([`NodeRuntime::set_degradation`]).
+    Resolution,
+    /// The node advances its simulation less often
+    /// ([`NodeRuntime::set_degradation`]).
+    UpdateRate,
+}
+
 /// Runtime node instance for the demand-driven engine spine.
 pub trait NodeRuntime {
     /// Allocate [`RuntimeBufferId`] slots owned by this node before first use.
@@ -97,6 +144,58 @@ pub trait NodeRuntime {
         Ok(AssetRefreshResult::Unused)
     }
 
+    /// Refresh what this node read from the project filesystem outside its
+    /// declared assets — a shader's `#include`s — when any of it changed.
+    ///
+    /// The registry reports only declared assets, so the engine asks every
+    /// live node after each project apply; a node compares the revisions
+    /// [`lpc_registry::ProjectRegistry::track_file`] gave it with the current
+    /// ones. Default: nothing read, so nothing to refresh.
+    fn refresh_file_dependencies(
+        &mut self,
+        _ctx: &mut AssetRefreshContext<'_>,
+    ) -> Result<AssetRefreshResult, NodeError> {
+        Ok(AssetRefreshResult::Unused)
+    }
+
+    /// Hand over live state before a project edit rebuilds this node.
+    ///
+    /// Called on the outgoing runtime just before it is destroyed, when the
+    /// same use location is about to be re-attached — a definition change,
+    /// a playlist entry edit, a def leaving an error. Removal for good never
+    /// asks. Nodes with state they cannot carry must say so with
+    /// [`StateExport::Resets`], and nodes with none with
+    /// [`StateExport::Stateless`]; the default claims neither.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Undeclared
+    }
+
+    /// Adopt state a predecessor at the same use location exported.
+    ///
+    /// Runs once, after [`Self::init_resources`] and before the first tick.
+    /// Return `false` when the state is not this node's kind or no longer
+    /// fits its config (an entry that was removed); the node then starts
+    /// fresh, exactly as without migration. State that can only be checked
+    /// against config at tick time is the node's to drop there.
+    fn import_state(&mut self, _state: MigratedState) -> bool {
+        false
+    }
+
+    /// How this node may be degraded when it overruns its budget.
+    ///
+    /// Default: [`DegradeMode::SkipTick`], which any node that keeps its
+    /// production in runtime state supports for free. An author can still
+    /// mark a node critical in its module's `budget`.
+    fn degrade_mode(&self) -> DegradeMode {
+        DegradeMode::SkipTick
+    }
+
+    /// The engine moved this node's degradation: `divisor` is 1 at full
+    /// quality and 2, 4 or 8 as it degrades. Only called for
+    /// [`DegradeMode::Resolution`] and [`DegradeMode::UpdateRate`] nodes,
+    /// between ticks.
+    fn set_degradation(&mut self, _divisor: u32) {}
+
     fn destroy(&mut self, ctx: &mut DestroyCtx) -> Result<(), NodeError>;
 
     fn handle_memory_pressure(
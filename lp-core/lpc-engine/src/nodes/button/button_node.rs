@@ -11,8 +11,8 @@ use lpc_model::{
 };
 
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RuntimeStateShape, TickContext,
+    DegradeMode, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RuntimeStateShape, StateExport, TickContext,
 };
 
 /// Runtime node for `kind = "Button"` artifacts.
@@ -139,6 +139,17 @@ impl NodeRuntime for ButtonNode {
         Ok(ProduceResult::Produced)
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Resets {
+            reason: "the input is reopened and a held press is released",
+        }
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        // A skipped tick could drop a press.
+        DegradeMode::Critical
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         self.input = None;
         self.opened = None;
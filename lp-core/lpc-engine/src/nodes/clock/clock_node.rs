@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use alloc::format;
 
 use lpc_model::{
@@ -6,8 +7,8 @@ use lpc_model::{
 };
 
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RuntimeStateShape, TickContext,
+    DegradeMode, DestroyCtx, MemPressureCtx, MigratedState, NodeError, NodeRuntime, PressureLevel,
+    ProduceResult, RuntimeStateShape, StateExport, TickContext,
 };
 
 /// Runtime clock node producing project time as ordinary slot data.
@@ -102,6 +103,32 @@ impl NodeRuntime for ClockNode {
         Ok(ProduceResult::Produced)
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Carry(Box::new(ClockMigration {
+            accumulated_seconds: self.accumulated_seconds,
+            last_engine_seconds: self.last_engine_seconds,
+            last_effective_seconds: self.last_effective_seconds,
+        }))
+    }
+
+    fn import_state(&mut self, state: MigratedState) -> bool {
+        let Ok(state) = state.downcast::<ClockMigration>() else {
+            return false;
+        };
+        // Time carries on from where the old clock stopped; the engine moves
+        // the published timebase (and its phasors) to this node's id.
+        self.accumulated_seconds = state.accumulated_seconds;
+        self.last_engine_seconds = state.last_engine_seconds;
+        self.last_effective_seconds = state.last_effective_seconds;
+        true
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        // Every timebase hangs off the clock; skipping it would freeze them
+        // all, not just this node.
+        DegradeMode::Critical
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -126,6 +153,13 @@ impl NodeRuntime for ClockNode {
     }
 }
 
+/// A clock's running time, across a hot reload.
+struct ClockMigration {
+    accumulated_seconds: f32,
+    last_engine_seconds: Option<f32>,
+    last_effective_seconds: Option<f32>,
+}
+
 struct ClockAccessors {
     registry_revision: lpc_model::Revision,
     play_state: SlotAccessor,
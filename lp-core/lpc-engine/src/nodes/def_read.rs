@@ -0,0 +1,78 @@
+//! Tick-time reads of a node's own authored def slots, through the same
+//! overlay-aware resolver as the value syncs — for the slots a generated
+//! def view does not cover (enum variants, optional sub-slots).
+
+use alloc::format;
+use alloc::string::String;
+
+use lpc_model::{SlotData, SlotPath, SlotShapeId, SlotShapeRegistry};
+
+use crate::node::{NodeError, TickContext};
+
+/// The active variant of an authored enum slot, or `None` when it does not
+/// resolve (the caller then uses the default).
+pub(crate) fn authored_variant(ctx: &mut TickContext<'_>, path: &'static str) -> Option<String> {
+    let production = ctx.resolve_static_consumed(path).ok()?;
+    let SlotData::Enum(declaration) = production.data() else {
+        return None;
+    };
+    Some(String::from(declaration.variant.as_str()))
+}
+
+/// An authored value slot of the node's def (shape `def_shape`), or
+/// `Ok(None)` when it is absent.
+///
+/// "Absent" (no def loaded, inactive enum variant, option none) is expected;
+/// a path that cannot exist in the def's shape is a code bug and is an
+/// error rather than a silent `None`.
+#[cfg_attr(
+    not(feature = "node-fixture"),
+    allow(dead_code, reason = "only the fixture node reads def values so far")
+)]
+pub(crate) fn try_read_def_value<T: lpc_model::FromLpValue>(
+    ctx: &mut TickContext<'_>,
+    def_shape: SlotShapeId,
+    path: &'static str,
+) -> Result<Option<T>, NodeError> {
+    let production = match ctx.resolve_static_consumed(path) {
+        Ok(production) => production,
+        Err(e) => {
+            let slot = SlotPath::parse(path)
+                .map_err(|e| NodeError::msg(format!("invalid authored def path {path:?}: {e}")))?;
+            ensure_path_exists_in_def_shape(ctx.slot_shapes(), def_shape, &slot)?;
+            log::debug!("[def] path {path} unavailable: {}", e.message);
+            return Ok(None);
+        }
+    };
+    let value = production
+        .value_leaf()
+        .ok_or_else(|| NodeError::msg(format!("def path {path:?} is not a value")))?;
+    T::from_lp_value(value.value())
+        .map(Some)
+        .map_err(|e| NodeError::msg(format!("def path {path:?}: {e}")))
+}
+
+/// Shape-only check that `slot` addresses a declared slot of `def_shape`.
+/// The walk tolerates inactive enum variants and unpopulated map keys, so it
+/// only rejects paths that can never resolve (e.g. a misspelled variant
+/// segment).
+#[cfg_attr(
+    not(feature = "node-fixture"),
+    allow(dead_code, reason = "only the fixture node reads def values so far")
+)]
+pub(crate) fn ensure_path_exists_in_def_shape(
+    shapes: &SlotShapeRegistry,
+    def_shape: SlotShapeId,
+    slot: &SlotPath,
+) -> Result<(), NodeError> {
+    use lpc_model::SlotShapeLookup;
+    let shape = shapes
+        .get_shape(def_shape)
+        .ok_or_else(|| NodeError::msg("def slot shape is not registered"))?;
+    if lpc_model::resolve_slot_role(shape, shapes, slot).is_none() {
+        return Err(NodeError::msg(format!(
+            "def path {slot} does not exist in the def shape"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,725 @@
+//! Runtime filter node: renders one visual product at its own canvas size,
+//! runs it through a chain of post-processing passes, and produces the
+//! result as a visual product.
+//!
+//! The chain runs entirely behind [`LpGraphics`]: the input is rendered into
+//! a render target, every effect is a built-in GLSL pass from one target into
+//! another ([`super::filter_passes`]), and the finished frame is copied or
+//! scaled into whatever the consumer asked for. Nothing is read back, so on
+//! the GPU tier the frame never leaves the GPU.
+//!
+//! The chain runs once per frame, on the first render that frame; later
+//! renders — a preview and a fixture both demanding the node, say — reuse
+//! the finished target.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_gfx::{LpGraphics, TextureHandle};
+use lpc_image::CubeLut;
+use lpc_model::{
+    AssetLocation, Dim2u, FilterDefView, FilterEffect, FilterEffectKind, FilterState, FromLpValue,
+    NodeId, NodeRuntimeStatus, Revision, SlotAccess, SlotData, SlotMapKey, SlotPath,
+    SlotShapeRegistry, SlotShapeRegistryError, VisualProduct,
+};
+use lps_shared::{LpsValueF32, TextureStorageFormat};
+
+use crate::dataflow::resolver::QueryKey;
+use crate::node::{
+    AssetRefreshContext, AssetRefreshResult, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime,
+    PressureLevel, ProduceResult, RenderContext, RenderNode, RuntimeStateShape, StateExport,
+    TickContext, err_ctx,
+};
+use crate::nodes::def_read::authored_variant;
+use crate::products::visual::{
+    ConsumerPolicy, RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest,
+    VisualSampleTarget, VisualSpace,
+};
+use crate::shader_abi::uniforms::{VisualUniform, build_uniforms};
+
+use super::filter_passes::{FilterPrograms, Pass};
+
+/// A `.cube` file a filter node grades with, read whole at load.
+pub struct FilterLutAsset {
+    pub location: AssetLocation,
+    pub bytes: Vec<u8>,
+    pub revision: Revision,
+}
+
+/// The uploaded LUT strip and what the `lut` pass needs to address it.
+struct LutTexture {
+    texture: TextureHandle,
+    size: f32,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+}
+
+/// Runtime node for `kind = "Filter"` artifacts.
+pub struct FilterNode {
+    node_id: NodeId,
+    state: FilterState,
+    def_view: Option<FilterDefView>,
+    input: Option<VisualProduct>,
+    /// Canvas size the chain runs at.
+    size: (u32, u32),
+    /// The chain, in key order.
+    effects: Vec<FilterEffect>,
+    /// Location and revision of the `.cube` file, when the def names one.
+    lut_file: Option<(AssetLocation, Revision)>,
+    /// A parsed table waiting for the first render to upload it; the CPU
+    /// copy is dropped once it is on the backend.
+    lut_pending: Option<CubeLut>,
+    /// The uploaded table. Kept under memory pressure: rebuilding it would
+    /// need the file again.
+    lut: Option<LutTexture>,
+    /// Whether the def currently selects the table; with `lut` set to
+    /// `None`, `lut` effects pass the frame through.
+    lut_selected: bool,
+    programs: FilterPrograms,
+    /// Canvas-sized scratch targets: two to ping-pong between, two more
+    /// while a bloom is in the chain.
+    targets: Vec<TextureHandle>,
+    /// The frame whose chain has run, and which target holds its result.
+    finished: Option<(Revision, usize)>,
+    input_error: Option<String>,
+    lut_error: Option<String>,
+}
+
+impl FilterNode {
+    pub fn new(node_id: NodeId, lut_file: Option<FilterLutAsset>) -> Self {
+        let mut node = Self {
+            node_id,
+            state: FilterState::new(VisualProduct::new(node_id, 0)),
+            def_view: None,
+            input: None,
+            size: (1, 1),
+            effects: Vec::new(),
+            lut_file: None,
+            lut_pending: None,
+            lut: None,
+            lut_selected: false,
+            programs: FilterPrograms::new(node_id),
+            targets: Vec::new(),
+            finished: None,
+            input_error: None,
+            lut_error: None,
+        };
+        if let Some(asset) = lut_file {
+            node.lut_file = Some((asset.location, asset.revision));
+            node.parse_lut(&asset.bytes);
+        }
+        node
+    }
+
+    fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&FilterDefView, NodeError> {
+        FilterDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
+            .map_err(err_ctx("compile filter def view"))
+    }
+
+    fn parse_lut(&mut self, bytes: &[u8]) {
+        match CubeLut::parse(bytes) {
+            Ok(lut) => {
+                self.lut_pending = Some(lut);
+                self.lut_error = None;
+            }
+            Err(error) => self.lut_error = Some(format!("parse lut: {error}")),
+        }
+    }
+
+    /// Every pass this frame's chain runs, plus the one that presents it.
+    fn passes(&self) -> Vec<Pass> {
+        let mut passes = Vec::from([Pass::Present]);
+        for effect in &self.effects {
+            let needed: &[Pass] = match effect.kind() {
+                Some(FilterEffectKind::Blur) => &[Pass::Blur],
+                Some(FilterEffectKind::Bloom) => {
+                    &[Pass::BloomThreshold, Pass::Blur, Pass::BloomComposite]
+                }
+                Some(FilterEffectKind::Kaleidoscope) => &[Pass::Kaleidoscope],
+                Some(FilterEffectKind::Mirror) => &[Pass::Mirror],
+                Some(FilterEffectKind::Pixelate) => &[Pass::Pixelate],
+                Some(FilterEffectKind::HueShift) => &[Pass::HueShift],
+                Some(FilterEffectKind::ColorAdjust) => &[Pass::ColorAdjust],
+                Some(FilterEffectKind::Lut) => &[Pass::Lut],
+                None => &[],
+            };
+            for pass in needed {
+                if !passes.contains(pass) {
+                    passes.push(*pass);
+                }
+            }
+        }
+        passes
+    }
+
+    /// Upload a freshly parsed table, replacing the old one.
+    fn upload_lut(&mut self, graphics: &dyn LpGraphics) -> Result<(), NodeError> {
+        let Some(lut) = self.lut_pending.take() else {
+            return Ok(());
+        };
+        let texture = graphics
+            .create_texture(
+                lut.strip_width(),
+                lut.strip_height(),
+                TextureStorageFormat::Rgba16Unorm,
+                lut.strip_rgba16(),
+            )
+            .map_err(err_ctx("upload lut"))?;
+        self.lut = Some(LutTexture {
+            texture,
+            size: lut.size() as f32,
+            domain_min: lut.domain_min(),
+            domain_max: lut.domain_max(),
+        });
+        Ok(())
+    }
+
+    /// Run the chain for this frame, once, and return the target holding
+    /// the result — or `None` while there is nothing to show yet (no input,
+    /// passes still waiting for their compile window).
+    fn run_chain(&mut self, ctx: &mut RenderContext<'_>) -> Result<Option<usize>, NodeError> {
+        if let Some((revision, result)) = self.finished
+            && revision == ctx.revision()
+        {
+            return Ok(Some(result));
+        }
+        let Some(input) = self.input else {
+            return Ok(None);
+        };
+        let passes = self.passes();
+        if !self.programs.ensure(&passes, ctx)? {
+            return Ok(None);
+        }
+
+        let (width, height) = self.size;
+        let wanted = if passes.contains(&Pass::BloomComposite) {
+            4
+        } else {
+            2
+        };
+        if self
+            .targets
+            .first()
+            .is_some_and(|target| (target.width(), target.height()) != (width, height))
+        {
+            self.targets.clear();
+        }
+        {
+            let graphics = ctx
+                .graphics()
+                .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+            while self.targets.len() < wanted {
+                let target = graphics
+                    .create_render_target(width, height, TextureStorageFormat::Rgba16Unorm)
+                    .map_err(err_ctx("filter render target"))?;
+                self.targets.push(target);
+            }
+            self.upload_lut(graphics)?;
+        }
+
+        let request = RenderTextureRequest {
+            width,
+            height,
+            format: TextureStorageFormat::Rgba16Unorm,
+            time_seconds: ctx.time_seconds(),
+            space: VisualSpace::TwoD,
+            policy: ConsumerPolicy::default(),
+        };
+        ctx.render_texture_into(input, &request, &mut self.targets[0])?;
+
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let mut current = 0;
+        for index in 0..self.effects.len() {
+            current = self.apply(graphics, index, current)?;
+        }
+        self.finished = Some((ctx.revision(), current));
+        Ok(Some(current))
+    }
+
+    /// Apply effect `index` to the frame in target `current` and return the
+    /// target now holding the frame. An effect whose pass is unavailable —
+    /// an unknown name, a failed compile, a `lut` with no table — leaves
+    /// the frame where it is.
+    fn apply(
+        &mut self,
+        graphics: &dyn LpGraphics,
+        index: usize,
+        current: usize,
+    ) -> Result<usize, NodeError> {
+        let effect = self.effects[index].clone();
+        let (width, height) = self.size;
+        let next = 1 - current;
+        let f32_uniform = |name: &str, value: f32| (String::from(name), LpsValueF32::F32(value));
+        let vec2_uniform =
+            |name: &str, value: [f32; 2]| (String::from(name), LpsValueF32::Vec2(value));
+        let blur_stride = |axis: [f32; 2]| {
+            // Four taps each side: `radius` pixels is the outermost tap.
+            let pixels = effect.radius.max(0.0) / 4.0;
+            [
+                axis[0] * pixels / width as f32,
+                axis[1] * pixels / height as f32,
+            ]
+        };
+
+        let Some(kind) = effect.kind() else {
+            return Ok(current);
+        };
+        let done = match kind {
+            FilterEffectKind::Blur => {
+                // Across into `next`, then down back into `current`.
+                if self.pass(
+                    graphics,
+                    Pass::Blur,
+                    current,
+                    next,
+                    &[vec2_uniform("stride", blur_stride([1.0, 0.0]))],
+                )? {
+                    self.pass(
+                        graphics,
+                        Pass::Blur,
+                        next,
+                        current,
+                        &[vec2_uniform("stride", blur_stride([0.0, 1.0]))],
+                    )?;
+                }
+                return Ok(current);
+            }
+            FilterEffectKind::Bloom => {
+                // Bright parts into 2, blurred across into 3 and down back
+                // into 2, then added over the frame into `next`.
+                let glowing = self.pass(
+                    graphics,
+                    Pass::BloomThreshold,
+                    current,
+                    2,
+                    &[f32_uniform("threshold", effect.threshold)],
+                )? && self.pass(
+                    graphics,
+                    Pass::Blur,
+                    2,
+                    3,
+                    &[vec2_uniform("stride", blur_stride([1.0, 0.0]))],
+                )? && self.pass(
+                    graphics,
+                    Pass::Blur,
+                    3,
+                    2,
+                    &[vec2_uniform("stride", blur_stride([0.0, 1.0]))],
+                )?;
+                if !glowing {
+                    return Ok(current);
+                }
+                let glow = graphics
+                    .texture_uniform_value(&self.targets[2])
+                    .map_err(err_ctx("bind bloom glow"))?;
+                self.pass(
+                    graphics,
+                    Pass::BloomComposite,
+                    current,
+                    next,
+                    &[
+                        (String::from("glow"), glow),
+                        f32_uniform("intensity", effect.amount),
+                    ],
+                )?
+            }
+            FilterEffectKind::Kaleidoscope => self.pass(
+                graphics,
+                Pass::Kaleidoscope,
+                current,
+                next,
+                &[
+                    f32_uniform("segments", effect.segments.max(1) as f32),
+                    f32_uniform("angle", effect.angle),
+                    vec2_uniform("center", effect.center),
+                ],
+            )?,
+            FilterEffectKind::Mirror => self.pass(
+                graphics,
+                Pass::Mirror,
+                current,
+                next,
+                &[
+                    f32_uniform("angle", effect.angle),
+                    vec2_uniform("center", effect.center),
+                ],
+            )?,
+            FilterEffectKind::Pixelate => self.pass(
+                graphics,
+                Pass::Pixelate,
+                current,
+                next,
+                &[f32_uniform("cell", effect.cell)],
+            )?,
+            FilterEffectKind::HueShift => self.pass(
+                graphics,
+                Pass::HueShift,
+                current,
+                next,
+                &[f32_uniform("hue", effect.hue)],
+            )?,
+            FilterEffectKind::ColorAdjust => self.pass(
+                graphics,
+                Pass::ColorAdjust,
+                current,
+                next,
+                &[
+                    f32_uniform("contrast", effect.contrast),
+                    f32_uniform("saturation", effect.saturation),
+                ],
+            )?,
+            FilterEffectKind::Lut => {
+                let Some(lut) = self.lut.as_ref().filter(|_| self.lut_selected) else {
+                    return Ok(current);
+                };
+                let table = graphics
+                    .texture_uniform_value(&lut.texture)
+                    .map_err(err_ctx("bind lut"))?;
+                let uniforms = [
+                    (String::from("lut"), table),
+                    f32_uniform("lutSize", lut.size),
+                    (String::from("domainMin"), LpsValueF32::Vec3(lut.domain_min)),
+                    (String::from("domainMax"), LpsValueF32::Vec3(lut.domain_max)),
+                    f32_uniform("amount", effect.amount),
+                ];
+                self.pass(graphics, Pass::Lut, current, next, &uniforms)?
+            }
+        };
+        Ok(if done { next } else { current })
+    }
+
+    /// Run `pass` from target `from` into target `into`, with `source`
+    /// bound to `from` and `extra` after it. Returns `false` without
+    /// touching anything when the pass did not compile.
+    fn pass(
+        &mut self,
+        graphics: &dyn LpGraphics,
+        pass: Pass,
+        from: usize,
+        into: usize,
+        extra: &[VisualUniform],
+    ) -> Result<bool, NodeError> {
+        let Some(program) = self.programs.get(pass) else {
+            return Ok(false);
+        };
+        let source = graphics
+            .texture_uniform_value(&self.targets[from])
+            .map_err(err_ctx("bind filter source"))?;
+        let mut uniforms = Vec::with_capacity(extra.len() + 1);
+        uniforms.push((String::from("source"), source));
+        uniforms.extend(extra.iter().cloned());
+        let (width, height) = self.size;
+        program
+            .render(
+                &mut self.targets[into],
+                &build_uniforms(width, height, &uniforms),
+            )
+            .map_err(err_ctx("filter pass"))?;
+        Ok(true)
+    }
+
+    /// Bind the finished frame for the present pass at a consumer's size.
+    fn present_uniforms(
+        &self,
+        graphics: &dyn LpGraphics,
+        result: usize,
+        width: u32,
+        height: u32,
+    ) -> Result<LpsValueF32, NodeError> {
+        let source = graphics
+            .texture_uniform_value(&self.targets[result])
+            .map_err(err_ctx("bind filter result"))?;
+        Ok(build_uniforms(
+            width,
+            height,
+            &[(String::from("source"), source)],
+        ))
+    }
+}
+
+impl NodeRuntime for FilterNode {
+    fn produce(
+        &mut self,
+        _slot: &SlotPath,
+        ctx: &mut TickContext<'_>,
+    ) -> Result<ProduceResult, NodeError> {
+        let size: Dim2u = self.def_view(ctx)?.size().get(ctx)?;
+        self.size = (size.width.max(1), size.height.max(1));
+        self.effects = resolve_effects(ctx)?;
+        self.lut_selected = authored_variant(ctx, "lut").as_deref() == Some("Cube");
+
+        // An unbound input resolves to the slot's default product, which
+        // names the root module; the filter shows black until it is wired.
+        let own = VisualProduct::new(ctx.node_id(), 0);
+        match ctx.resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: filter_input_path(),
+        }) {
+            Ok(production) => match production
+                .value_leaf()
+                .map(|value| VisualProduct::from_lp_value(value.value()))
+            {
+                Some(Ok(product)) if product == VisualProduct::default() => {
+                    self.input = None;
+                    self.input_error = Some(String::from("filter input is not bound"));
+                }
+                Some(Ok(product)) if product == own => {
+                    self.input = None;
+                    self.input_error = Some(String::from("filter input is its own output"));
+                }
+                Some(Ok(product)) => {
+                    self.input = Some(product);
+                    self.input_error = None;
+                }
+                _ => {
+                    self.input = None;
+                    self.input_error =
+                        Some(String::from("filter input does not carry a visual product"));
+                }
+            },
+            Err(error) => {
+                self.input = None;
+                self.input_error = Some(format!("filter input not resolved: {}", error.message));
+            }
+        }
+
+        // A new tick is a new frame: the chain runs again on its first
+        // render.
+        self.finished = None;
+        self.state.output.set_with_version(ctx.revision(), own);
+        Ok(ProduceResult::Produced)
+    }
+
+    fn refresh_asset(
+        &mut self,
+        location: &AssetLocation,
+        ctx: &mut AssetRefreshContext<'_>,
+    ) -> Result<AssetRefreshResult, NodeError> {
+        let Some((lut_location, revision)) = &mut self.lut_file else {
+            return Ok(AssetRefreshResult::Unused);
+        };
+        if location != lut_location {
+            return Ok(AssetRefreshResult::Unused);
+        }
+        match ctx.read_asset_bytes_if_changed(location, *revision) {
+            Ok(Some(asset)) => {
+                *revision = asset.revision;
+                self.parse_lut(&asset.bytes);
+                self.finished = None;
+                Ok(AssetRefreshResult::Refreshed)
+            }
+            Ok(None) => Ok(AssetRefreshResult::Unchanged),
+            Err(err) => {
+                // Keep-last-good: the old table still grades.
+                self.lut_error = Some(format!("read lut: {err:?}"));
+                Ok(AssetRefreshResult::Refreshed)
+            }
+        }
+    }
+
+    /// The chain and its scratch targets are re-derived from the def and
+    /// the input each frame.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        // The scratch targets are pure cache: the next render reallocates
+        // them and reruns the chain. The compiled passes and the uploaded
+        // LUT stay, like a shader node's program.
+        if level >= PressureLevel::High {
+            self.targets.clear();
+            self.finished = None;
+        }
+        Ok(())
+    }
+
+    fn wants_compile_window(&self) -> bool {
+        self.programs.wants_compile_window()
+    }
+
+    fn open_compile_window(&mut self, revision: Revision) {
+        self.programs.open_compile_window(revision);
+    }
+
+    fn runtime_status(&self) -> Option<NodeRuntimeStatus> {
+        if let Some(error) = self.programs.error().or(self.lut_error.as_deref()) {
+            return Some(NodeRuntimeStatus::Error(String::from(error)));
+        }
+        self.input_error.clone().map(NodeRuntimeStatus::Warn)
+    }
+
+    fn runtime_state_slots(&self) -> Option<&dyn SlotAccess> {
+        Some(&self.state)
+    }
+
+    fn register_runtime_state_shapes(
+        &self,
+        registry: &mut SlotShapeRegistry,
+    ) -> Result<(), SlotShapeRegistryError> {
+        FilterState::register_runtime_state_shape(registry).map(|_| ())
+    }
+
+    fn render_node(&mut self) -> Option<&mut dyn RenderNode> {
+        Some(self)
+    }
+}
+
+impl RenderNode for FilterNode {
+    fn render_texture(
+        &mut self,
+        product: VisualProduct,
+        request: &RenderTextureRequest,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<TextureRenderProduct, NodeError> {
+        if request.format != TextureStorageFormat::Rgba16Unorm {
+            return Err(NodeError::msg("filter only renders RGBA16 unorm textures"));
+        }
+        let mut texture = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?
+            .create_render_target(request.width, request.height, request.format)
+            .map_err(err_ctx("filter output texture"))?;
+        self.render_texture_into(product, request, &mut texture, ctx)?;
+        let graphics = ctx.graphics().expect("graphics checked above");
+        if !graphics.supports_read_back() {
+            // GPU-resident tier: keep the rendered target on the GPU
+            // (fidelity-tiers ADR; see the shader node's render_texture).
+            return TextureRenderProduct::gpu_resident(texture)
+                .map_err(err_ctx("filter gpu texture product"));
+        }
+        let bytes = graphics
+            .read_back(&texture)
+            .map_err(err_ctx("filter read back"))?
+            .into_bytes();
+        TextureRenderProduct::rgba16_unorm(request.width, request.height, bytes)
+            .map_err(err_ctx("filter texture product"))
+    }
+
+    fn render_texture_into(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        if request.format != TextureStorageFormat::Rgba16Unorm
+            || target.format() != TextureStorageFormat::Rgba16Unorm
+            || target.width() != request.width
+            || target.height() != request.height
+        {
+            return Err(NodeError::msg("filter texture target shape mismatch"));
+        }
+        let result = self.run_chain(ctx)?;
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let Some(result) = result else {
+            return graphics
+                .clear_texture(target)
+                .map_err(err_ctx("filter clear target"));
+        };
+        if (request.width, request.height) == self.size {
+            return graphics
+                .copy_texture(&self.targets[result], target)
+                .map_err(err_ctx("filter copy result"));
+        }
+        let uniforms = self.present_uniforms(graphics, result, request.width, request.height)?;
+        let Some(present) = self.programs.get(Pass::Present) else {
+            return graphics
+                .clear_texture(target)
+                .map_err(err_ctx("filter clear target"));
+        };
+        present
+            .render(target, &uniforms)
+            .map_err(err_ctx("filter present"))
+    }
+
+    fn sample_visual_into(
+        &mut self,
+        _product: VisualProduct,
+        request: VisualSampleBufferRequest<'_>,
+        target: VisualSampleTarget<'_>,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        if target.samples.count() != request.points.count() {
+            return Err(NodeError::msg("filter sample target count mismatch"));
+        }
+        let result = self.run_chain(ctx)?;
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let Some(result) = result else {
+            return graphics
+                .clear_sample_out(target.samples)
+                .map_err(err_ctx("filter clear samples"));
+        };
+        let uniforms = self.present_uniforms(
+            graphics,
+            result,
+            request.output_width,
+            request.output_height,
+        )?;
+        let Some(present) = self.programs.get(Pass::Present) else {
+            return graphics
+                .clear_sample_out(target.samples)
+                .map_err(err_ctx("filter clear samples"));
+        };
+        present
+            .sample_rgba16(request.points, target.samples, &uniforms)
+            .map_err(err_ctx("filter sample"))
+    }
+}
+
+/// The effect chain in key order. An entry that is not a filter effect is
+/// an authoring error and fails the tick, like a malformed particle
+/// emitter.
+fn resolve_effects(ctx: &mut TickContext<'_>) -> Result<Vec<FilterEffect>, NodeError> {
+    let production = ctx
+        .resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: SlotPath::parse("effects").expect("filter effects path"),
+        })
+        .map_err(|e| NodeError::msg(format!("resolve filter effects: {}", e.message)))?;
+    let SlotData::Map(map) = production.data() else {
+        return Err(NodeError::msg(
+            "filter effects resolved to non-map slot data",
+        ));
+    };
+    let mut keyed = Vec::with_capacity(map.entries.len());
+    for (key, data) in &map.entries {
+        let SlotData::Value(value) = data else {
+            return Err(NodeError::msg(format!(
+                "filter effect {key:?} resolved to non-value slot data"
+            )));
+        };
+        let mut effect = FilterEffect::from_lp_value(value.value()).map_err(|e| {
+            NodeError::msg(format!("filter effect {key:?} has incompatible value: {e}"))
+        })?;
+        if let SlotMapKey::U32(id) = key {
+            effect.id = *id;
+        }
+        keyed.push((key.clone(), effect));
+    }
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(keyed.into_iter().map(|(_, effect)| effect).collect())
+}
+
+pub fn filter_input_path() -> SlotPath {
+    SlotPath::parse("input").expect("filter input path")
+}
+
+pub fn filter_output_path() -> SlotPath {
+    SlotPath::parse("output").expect("filter output path")
+}
@@ -0,0 +1,357 @@
+//! The filter node's built-in passes: one small GLSL program per effect
+//! step, compiled through the graphics backend like any shader node's
+//! source, so the chain runs on the CPU tier and the GPU tier alike.
+//!
+//! Every pass reads the frame so far through `source` and writes one
+//! canvas-sized render target. Only the passes a chain uses are compiled —
+//! all of them together in one compile window, so memory pressure precedes
+//! the lot once — and a compiled pass is kept for the node's lifetime: the
+//! sources never change.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+
+use lp_gfx::{LpShader, ShaderCompileOptions};
+use lpc_model::{NodeId, Revision};
+
+use crate::node::{NodeError, RenderContext};
+
+/// Caps how many diagnostics one failed pass reports.
+const PASS_COMPILE_MAX_ERRORS: usize = 4;
+
+const PRESENT_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+
+vec4 render_2d(vec2 pos) {
+    return texture(source, pos / outputSize);
+}
+"#;
+
+/// 9-tap binomial blur along `stride` (uv per tap); run once across and
+/// once down for a separable Gaussian.
+const BLUR_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform vec2 stride;
+
+vec4 render_2d(vec2 pos) {
+    vec2 uv = pos / outputSize;
+    vec4 sum = texture(source, uv) * 0.2734375;
+    sum += (texture(source, uv + stride) + texture(source, uv - stride)) * 0.21875;
+    sum += (texture(source, uv + stride * 2.0) + texture(source, uv - stride * 2.0)) * 0.109375;
+    sum += (texture(source, uv + stride * 3.0) + texture(source, uv - stride * 3.0)) * 0.03125;
+    sum += (texture(source, uv + stride * 4.0) + texture(source, uv - stride * 4.0)) * 0.00390625;
+    return sum;
+}
+"#;
+
+/// Keeps what is brighter than `threshold`, ramping in over the rest of
+/// the range so the glow has no hard edge.
+const BLOOM_THRESHOLD_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float threshold;
+
+vec4 render_2d(vec2 pos) {
+    vec4 color = texture(source, pos / outputSize);
+    float luma = dot(color.rgb, vec3(0.2126, 0.7152, 0.0722));
+    float keep = clamp((luma - threshold) / max(1.0 - threshold, 0.001), 0.0, 1.0);
+    return vec4(color.rgb * keep, color.a);
+}
+"#;
+
+const BLOOM_COMPOSITE_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform sampler2D glow;
+layout(binding = 3) uniform float intensity;
+
+vec4 render_2d(vec2 pos) {
+    vec2 uv = pos / outputSize;
+    vec4 base = texture(source, uv);
+    vec3 lit = base.rgb + texture(glow, uv).rgb * intensity;
+    return vec4(min(lit, vec3(1.0)), base.a);
+}
+"#;
+
+/// Worked in pixels rather than uv so wedges stay true on a non-square
+/// canvas.
+const KALEIDOSCOPE_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float segments;
+layout(binding = 3) uniform float angle;
+layout(binding = 4) uniform vec2 center;
+
+vec4 render_2d(vec2 pos) {
+    vec2 pivot = center * outputSize;
+    vec2 d = pos - pivot;
+    float wedge = 6.2831853 / max(segments, 1.0);
+    float a = mod(atan(d.y, d.x) - angle, wedge);
+    a = min(a, wedge - a) + angle;
+    vec2 p = pivot + vec2(cos(a), sin(a)) * length(d);
+    return texture(source, p / outputSize);
+}
+"#;
+
+/// Reflects every pixel behind the line's normal onto the side it faces.
+const MIRROR_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float angle;
+layout(binding = 3) uniform vec2 center;
+
+vec4 render_2d(vec2 pos) {
+    vec2 pivot = center * outputSize;
+    vec2 n = vec2(-sin(angle), cos(angle));
+    vec2 d = pos - pivot;
+    float side = dot(d, n);
+    if (side < 0.0) {
+        d = d - n * (2.0 * side);
+    }
+    return texture(source, (pivot + d) / outputSize);
+}
+"#;
+
+const PIXELATE_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float cell;
+
+vec4 render_2d(vec2 pos) {
+    float size = max(cell, 1.0);
+    vec2 p = (floor(pos / size) + 0.5) * size;
+    return texture(source, p / outputSize);
+}
+"#;
+
+/// Rotates each color about the grey axis (Rodrigues), which keeps its
+/// brightness.
+const HUE_SHIFT_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float hue;
+
+vec4 render_2d(vec2 pos) {
+    vec4 color = texture(source, pos / outputSize);
+    float a = hue * 6.2831853;
+    float c = cos(a);
+    float s = sin(a);
+    vec3 k = vec3(0.57735026);
+    vec3 rgb = color.rgb * c + cross(k, color.rgb) * s + k * (dot(k, color.rgb) * (1.0 - c));
+    return vec4(clamp(rgb, 0.0, 1.0), color.a);
+}
+"#;
+
+const COLOR_ADJUST_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform float contrast;
+layout(binding = 3) uniform float saturation;
+
+vec4 render_2d(vec2 pos) {
+    vec4 color = texture(source, pos / outputSize);
+    vec3 rgb = (color.rgb - 0.5) * contrast + 0.5;
+    float luma = dot(rgb, vec3(0.2126, 0.7152, 0.0722));
+    rgb = mix(vec3(luma), rgb, saturation);
+    return vec4(clamp(rgb, 0.0, 1.0), color.a);
+}
+"#;
+
+/// Samples the strip `lpc_image::CubeLut` lays out: red and green
+/// interpolate in the sampler, the two nearest blue slices are mixed here.
+const LUT_GLSL: &str = r#"layout(binding = 0) uniform vec2 outputSize;
+layout(binding = 1) uniform sampler2D source;
+layout(binding = 2) uniform sampler2D lut;
+layout(binding = 3) uniform float lutSize;
+layout(binding = 4) uniform vec3 domainMin;
+layout(binding = 5) uniform vec3 domainMax;
+layout(binding = 6) uniform float amount;
+
+vec4 render_2d(vec2 pos) {
+    vec4 color = texture(source, pos / outputSize);
+    vec3 t = clamp((color.rgb - domainMin) / (domainMax - domainMin), 0.0, 1.0) * (lutSize - 1.0);
+    float low = floor(t.b);
+    float high = min(low + 1.0, lutSize - 1.0);
+    float width = lutSize * lutSize;
+    float y = (t.g + 0.5) / lutSize;
+    vec3 a = texture(lut, vec2((t.r + 0.5 + low * lutSize) / width, y)).rgb;
+    vec3 b = texture(lut, vec2((t.r + 0.5 + high * lutSize) / width, y)).rgb;
+    vec3 graded = mix(a, b, t.b - low);
+    return vec4(mix(color.rgb, graded, amount), color.a);
+}
+"#;
+
+/// One built-in program.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Pass {
+    /// Scales the finished frame to a consumer's size.
+    Present,
+    Blur,
+    BloomThreshold,
+    BloomComposite,
+    Kaleidoscope,
+    Mirror,
+    Pixelate,
+    HueShift,
+    ColorAdjust,
+    Lut,
+}
+
+impl Pass {
+    const COUNT: usize = 10;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Present => "present",
+            Self::Blur => "blur",
+            Self::BloomThreshold => "bloom threshold",
+            Self::BloomComposite => "bloom composite",
+            Self::Kaleidoscope => "kaleidoscope",
+            Self::Mirror => "mirror",
+            Self::Pixelate => "pixelate",
+            Self::HueShift => "hue shift",
+            Self::ColorAdjust => "color adjust",
+            Self::Lut => "lut",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Self::Present => PRESENT_GLSL,
+            Self::Blur => BLUR_GLSL,
+            Self::BloomThreshold => BLOOM_THRESHOLD_GLSL,
+            Self::BloomComposite => BLOOM_COMPOSITE_GLSL,
+            Self::Kaleidoscope => KALEIDOSCOPE_GLSL,
+            Self::Mirror => MIRROR_GLSL,
+            Self::Pixelate => PIXELATE_GLSL,
+            Self::HueShift => HUE_SHIFT_GLSL,
+            Self::ColorAdjust => COLOR_ADJUST_GLSL,
+            Self::Lut => LUT_GLSL,
+        }
+    }
+
+    /// Every sampler the pass declares: linear and clamped, so a blur tap
+    /// or a mirrored read past the edge repeats the border rather than
+    /// wrapping the far side in.
+    fn textures(self) -> lp_shader::TextureBindingSpecs {
+        let mut specs = lp_shader::TextureBindingSpecs::new();
+        let names: &[&str] = match self {
+            Self::BloomComposite => &["source", "glow"],
+            Self::Lut => &["source", "lut"],
+            _ => &["source"],
+        };
+        for name in names {
+            specs.insert(
+                String::from(*name),
+                lp_shader::texture_binding::texture2d(
+                    lps_shared::TextureStorageFormat::Rgba16Unorm,
+                    lps_shared::TextureFilter::Linear,
+                    lps_shared::TextureWrap::ClampToEdge,
+                    lps_shared::TextureWrap::ClampToEdge,
+                ),
+            );
+        }
+        specs
+    }
+}
+
+/// The compiled passes of one filter node.
+pub(super) struct FilterPrograms {
+    node_id: NodeId,
+    programs: [Option<Box<dyn LpShader>>; Pass::COUNT],
+    /// Passes whose compile failed: never retried, and the effects that
+    /// need them pass the frame through.
+    failed: [bool; Pass::COUNT],
+    /// First compile error, for the node status.
+    error: Option<String>,
+    compile_window_requested: bool,
+    compile_window: Option<Revision>,
+}
+
+impl FilterPrograms {
+    pub(super) fn new(node_id: NodeId) -> Self {
+        Self {
+            node_id,
+            programs: Default::default(),
+            failed: [false; Pass::COUNT],
+            error: None,
+            compile_window_requested: false,
+            compile_window: None,
+        }
+    }
+
+    /// Compile whichever of `passes` are missing.
+    ///
+    /// The shader node's compile-window protocol: the first render that
+    /// needs a compile only requests a window, and the compile runs in the
+    /// window or — if none opened — on the next render. Returns whether
+    /// every pass in `passes` is settled (compiled or failed), i.e. whether
+    /// the chain can run this frame.
+    pub(super) fn ensure(
+        &mut self,
+        passes: &[Pass],
+        ctx: &RenderContext<'_>,
+    ) -> Result<bool, NodeError> {
+        let pending = |programs: &Self, pass: &Pass| {
+            programs.programs[pass.index()].is_none() && !programs.failed[pass.index()]
+        };
+        if !passes.iter().any(|pass| pending(self, pass)) {
+            return Ok(true);
+        }
+        if self.compile_window != Some(ctx.revision()) && !self.compile_window_requested {
+            self.compile_window_requested = true;
+            return Ok(false);
+        }
+        self.compile_window_requested = false;
+
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let semantics = graphics.native_semantics();
+        for pass in passes {
+            if !pending(self, pass) {
+                continue;
+            }
+            let options = ShaderCompileOptions {
+                semantics,
+                max_errors: Some(PASS_COMPILE_MAX_ERRORS),
+                textures: pass.textures(),
+                ..ShaderCompileOptions::new(semantics, graphics.glsl_frontend())
+            };
+            lpc_shared::backtrace::set_oom_context("filter node: compile");
+            let result = graphics.compile_shader(pass.source(), &options);
+            lpc_shared::backtrace::clear_oom_context();
+            match result {
+                Ok(program) => self.programs[pass.index()] = Some(program),
+                Err(error) => {
+                    log::warn!(
+                        "[filter-node] {} pass failed to compile (node={:?}): {error}",
+                        pass.label(),
+                        self.node_id
+                    );
+                    self.failed[pass.index()] = true;
+                    self.error
+                        .get_or_insert_with(|| format!("filter {} pass: {error}", pass.label()));
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// The compiled program for `pass`, or `None` if it failed.
+    pub(super) fn get(&mut self, pass: Pass) -> Option<&mut Box<dyn LpShader>> {
+        self.programs[pass.index()].as_mut()
+    }
+
+    pub(super) fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub(super) fn wants_compile_window(&self) -> bool {
+        self.compile_window_requested
+    }
+
+    pub(super) fn open_compile_window(&mut self, revision: Revision) {
+        // As on the shader node: an unused window expires with the frame.
+        self.compile_window_requested = false;
+        self.compile_window = Some(revision);
+    }
+}
@@ -0,0 +1,6 @@
+//! Post-processing filter node.
+
+mod filter_node;
+mod filter_passes;
+
+pub use filter_node::{FilterLutAsset, FilterNode, filter_output_path};
@@ -0,0 +1,165 @@
+//! Per-fixture dimmer: the brightness fader → the linear light scale that
+//! lands after the gamma encode.
+//!
+//! Brightness is a linear multiply on post-gamma duty
+//! (`docs/design/brightness-gamma-dithering.md`), so whatever this returns
+//! is, directly, the fraction of the wire's 256 codes full-white content
+//! gets. The [`DimmerCurve`] reshapes the fader first — the stage-lighting
+//! dimmer curve — and brightness gamma, when a fixture asks for it, raises
+//! the result to the gamma exponent, which is the old "brightness before
+//! gamma" behaviour (`(s·c)^γ = s^γ·c^γ`) made an explicit, per-fixture
+//! choice instead of the only one. WLED ships the same switch and advises
+//! leaving it off; so does the default here.
+
+use alloc::vec::Vec;
+
+use lpc_model::DimmerCurve;
+use lps_q32::q32::{Q32, ToQ32};
+
+/// A fixture's fader-to-light mapping, resolved from its def each tick.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dimmer {
+    pub curve: DimmerCurve,
+    /// Light levels for [`DimmerCurve::Custom`]: unorm16, spaced evenly over
+    /// the fader's travel. Fewer than one entry reads as linear.
+    pub lut: Vec<u32>,
+    /// Brightness gamma exponent; `None` keeps the fader linear in light.
+    pub brightness_gamma: Option<f32>,
+}
+
+impl Dimmer {
+    /// Linear light scale in `[0, 1]` for fader position `fader` (0–255).
+    ///
+    /// The default dimmer — linear, no brightness gamma — is exactly the
+    /// historical `fader / 255`, bit for bit.
+    pub fn scale(&self, fader: u8) -> Q32 {
+        let s = fader.to_q32() / 255.to_q32();
+        let shaped = match self.curve {
+            DimmerCurve::Linear => s,
+            DimmerCurve::Square => s * s,
+            // Smoothstep, 3s² − 2s³.
+            DimmerCurve::SCurve => s * s * (3.to_q32() - 2.to_q32() * s),
+            DimmerCurve::Custom => lut_scale(&self.lut, fader).unwrap_or(s),
+        };
+        match self.brightness_gamma {
+            // Endpoints stay exact rather than trusting `powf` at 0 and 1.
+            Some(exponent) if shaped > Q32::ZERO && shaped < Q32::ONE => {
+                Q32::from_f32_wrapping(libm::powf(shaped.to_f32(), exponent))
+            }
+            _ => shaped,
+        }
+    }
+}
+
+/// Interpolate a custom dimmer table at `fader`. `None` for an empty table.
+fn lut_scale(lut: &[u32], fader: u8) -> Option<Q32> {
+    let last = lut.len().checked_sub(1)?;
+    let level = |i: usize| i64::from(lut[i].min(0xFFFF));
+    let position = usize::from(fader) * last;
+    let index = position / 255;
+    let frac = (position % 255) as i64;
+    let value = if index >= last {
+        level(last)
+    } else {
+        level(index) + (level(index + 1) - level(index)) * frac / 255
+    };
+    // unorm16 → Q16.16, with 65535 landing exactly on one.
+    Some(Q32(((value * 65536 + 32767) / 65535) as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn dimmer(curve: DimmerCurve) -> Dimmer {
+        Dimmer {
+            curve,
+            ..Dimmer::default()
+        }
+    }
+
+    #[test]
+    fn the_default_dimmer_is_the_historical_fader_scale() {
+        let dimmer = Dimmer::default();
+        for fader in 0..=u8::MAX {
+            assert_eq!(dimmer.scale(fader), fader.to_q32() / 255.to_q32());
+        }
+    }
+
+    #[test]
+    fn every_built_in_curve_is_monotone_from_off_to_full() {
+        for curve in [
+            DimmerCurve::Linear,
+            DimmerCurve::Square,
+            DimmerCurve::SCurve,
+        ] {
+            for brightness_gamma in [None, Some(2.8)] {
+                let dimmer = Dimmer {
+                    curve,
+                    brightness_gamma,
+                    ..Dimmer::default()
+                };
+                assert_eq!(dimmer.scale(0), Q32::ZERO, "{curve:?}");
+                assert_eq!(dimmer.scale(u8::MAX), Q32::ONE, "{curve:?}");
+                let mut prev = Q32::ZERO;
+                for fader in 0..=u8::MAX {
+                    let scale = dimmer.scale(fader);
+                    assert!(scale >= prev, "{curve:?} decreased at {fader}");
+                    prev = scale;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn curves_shape_the_dim_end() {
+        let quarter = 64u8;
+        let linear = dimmer(DimmerCurve::Linear).scale(quarter);
+        let square = dimmer(DimmerCurve::Square).scale(quarter);
+        let s_curve = dimmer(DimmerCurve::SCurve).scale(quarter);
+        assert!(square < s_curve && s_curve < linear);
+        // And the S-curve overtakes linear past the midpoint.
+        assert!(dimmer(DimmerCurve::SCurve).scale(192) > dimmer(DimmerCurve::Linear).scale(192));
+    }
+
+    #[test]
+    fn a_custom_table_interpolates_evenly_over_the_fader() {
+        let dimmer = Dimmer {
+            curve: DimmerCurve::Custom,
+            lut: vec![0, 1000, 65535],
+            brightness_gamma: None,
+        };
+        assert_eq!(dimmer.scale(0), Q32::ZERO);
+        assert_eq!(dimmer.scale(u8::MAX), Q32::ONE);
+        // The middle entry sits at fader 127.5; 127 is just short of it.
+        let near_middle = dimmer.scale(127).to_u16_saturating();
+        assert!((990..=1000).contains(&near_middle), "{near_middle}");
+        // Out-of-range entries clamp to full.
+        let clamped = Dimmer {
+            lut: vec![0, 70000],
+            ..dimmer.clone()
+        };
+        assert_eq!(clamped.scale(u8::MAX), Q32::ONE);
+    }
+
+    #[test]
+    fn an_empty_custom_table_reads_as_linear() {
+        let dimmer = dimmer(DimmerCurve::Custom);
+        assert_eq!(dimmer.scale(38), Dimmer::default().scale(38));
+    }
+
+    /// Brightness gamma is the perceptual fader: slider `s` becomes `s^γ`
+    /// of the light. At 38/255 that is 0.48% — the 1.24-code figure the
+    /// design doc measured.
+    #[test]
+    fn brightness_gamma_raises_the_fader_to_the_exponent() {
+        let dimmer = Dimmer {
+            brightness_gamma: Some(2.8),
+            ..Dimmer::default()
+        };
+        let scale = dimmer.scale(38).to_f32();
+        let expected = libm::powf(38.0 / 255.0, 2.8);
+        assert!((scale - expected).abs() < 1e-4, "{scale} vs {expected}");
+    }
+}
@@ -13,9 +13,11 @@ use lpc_model::{
     ControlProduct, Dim2u, FixtureDefView, FixtureState, Revision, SlotAccess, SlotPath,
     SlotShapeRegistry, SlotShapeRegistryError,
 };
-use lps_q32::q32::{Q32, ToQ32};
+use lps_q32::q32::Q32;
 
-use crate::nodes::fixture::gamma::apply_gamma16;
+use crate::nodes::def_read::{authored_variant, try_read_def_value};
+use crate::nodes::fixture::dimmer::Dimmer;
+use crate::nodes::fixture::gamma::GammaCurve;
 use crate::nodes::fixture::mapping::{
     ChannelAccumulators, PixelMappingEntry, accumulate_from_mapping, compute_mapping,
     initialize_channel_accumulators, mapping_from_map2d_doc,
@@ -24,9 +26,9 @@ use lp_gfx::{SampleOutHandle, SamplePointsHandle, TextureData, TextureHandle};
 use lpc_model::nodes::texture::TextureFormat;
 
 use crate::node::{
-    AssetRefreshContext, AssetRefreshResult, ControlNode, ControlRenderContext, DestroyCtx,
-    MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult, RuntimeStateShape,
-    TickContext, err_ctx,
+    AssetRefreshContext, AssetRefreshResult, ControlNode, ControlRenderContext, DegradeMode,
+    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RuntimeStateShape, StateExport, TickContext, err_ctx,
 };
 use crate::nodes::fixture::power_limit::{self, PowerPass};
 use crate::products::control::{
@@ -39,7 +41,11 @@ use crate::products::visual::{
     normalized_q16_to_pixel_q16, texel_center_to_uv_q16,
 };
 use lpc_model::NodeRuntimeStatus;
-use lpc_model::nodes::fixture::{FixturePower, preset_for};
+use lpc_model::nodes::fixture::{FixturePower, GammaExponent, preset_for};
+
+/// Shape the authored def reads below are checked against.
+const FIXTURE_DEF: lpc_model::SlotShapeId =
+    <lpc_model::nodes::FixtureDef as lpc_model::StaticSlotShape>::SHAPE_ID;
 
 /// The map2d document a fixture's mapping was resolved from, kept so the
 /// node can re-resolve when the asset body changes (the in-place mapping
@@ -112,6 +118,9 @@ pub struct FixtureNode {
     def_view: Option<FixtureDefView>,
     last_visual_product: Option<VisualProduct>,
     last_settings: Option<FixtureRenderSettings>,
+    /// The gamma encode at the authored exponent. Regenerated only when the
+    /// exponent changes; the default shares the built-in table.
+    gamma: GammaCurve,
     render_target: Option<TextureHandle>,
     sample_points: Option<FixtureSamplePoints>,
     sample_target: Option<SampleOutHandle>,
@@ -161,6 +170,7 @@ impl FixtureNode {
             def_view: None,
             last_visual_product: None,
             last_settings: None,
+            gamma: GammaCurve::default(),
             render_target: None,
             sample_points: None,
             sample_target: None,
@@ -221,7 +231,7 @@ impl FixtureNode {
             height,
             diagnostic_mode: FixtureDiagnosticMode::Off,
             color_order,
-            brightness: lpc_model::Brightness::DEFAULT.as_u8(),
+            brightness: Dimmer::default().scale(lpc_model::Brightness::DEFAULT.as_u8()),
             gamma_correction: true,
             power: FixturePower::default(),
             strip_order_meaningful: self.strip_order_meaningful,
@@ -410,14 +420,32 @@ impl NodeRuntime for FixtureNode {
         // Absent falls back to the default guard rather than to unlimited — the
         // fixture most in need of a current limit is the one whose author has
         // never heard of the setting. Opting out is `budget_ma: 0`.
-        let power: FixturePower = try_read_def_value(ctx, "power.some")?.unwrap_or_default();
-        let diagnostic_mode =
-            try_read_def_value(ctx, "diagnostic_mode")?.unwrap_or(FixtureDiagnosticMode::Off);
+        let power: FixturePower =
+            try_read_def_value(ctx, FIXTURE_DEF, "power.some")?.unwrap_or_default();
+        // The dimmer and gamma exponent read the same way, for the same
+        // reason: absent from every project authored before they existed.
+        let gamma_exponent = try_read_def_value::<GammaExponent>(ctx, FIXTURE_DEF, "gamma.some")?
+            .unwrap_or_default()
+            .clamped();
+        let dimmer = Dimmer {
+            curve: try_read_def_value(ctx, FIXTURE_DEF, "dimmer_curve.some")?.unwrap_or_default(),
+            lut: try_read_def_value(ctx, FIXTURE_DEF, "dimmer_lut.some")?.unwrap_or_default(),
+            brightness_gamma: try_read_def_value(ctx, FIXTURE_DEF, "brightness_gamma.some")?
+                .unwrap_or(false)
+                .then_some(gamma_exponent),
+        };
+        if self.gamma.exponent() != gamma_exponent {
+            self.gamma = GammaCurve::new(gamma_exponent);
+        }
+        let diagnostic_mode = try_read_def_value(ctx, FIXTURE_DEF, "diagnostic_mode")?
+            .unwrap_or(FixtureDiagnosticMode::Off);
         // The two-sided space declaration (vision D3/D14). Absent reads —
         // a runtime fixture with no def behind it — leave the loaded
         // declaration standing rather than resetting it, the same rule the
         // shader node's `space` read follows.
-        if let Some(strip_order_meaningful) = try_read_def_value(ctx, "strip_order_meaningful")? {
+        if let Some(strip_order_meaningful) =
+            try_read_def_value(ctx, FIXTURE_DEF, "strip_order_meaningful")?
+        {
             self.strip_order_meaningful = strip_order_meaningful;
         }
         if let Some(policy) = try_read_consume_policy(ctx) {
@@ -483,7 +511,7 @@ impl NodeRuntime for FixtureNode {
             height,
             diagnostic_mode,
             color_order,
-            brightness,
+            brightness: dimmer.scale(brightness),
             gamma_correction,
             power,
             strip_order_meaningful: self.strip_order_meaningful,
@@ -517,6 +545,24 @@ impl NodeRuntime for FixtureNode {
         Ok(())
     }
 
+    fn degrade_mode(&self) -> DegradeMode {
+        // The fixture is where a frame reaches the lamps; it is never the
+        // node to shed. What it samples degrades instead.
+        DegradeMode::Critical
+    }
+
+    /// Mapping and sampling caches rebuild from the def. The current
+    /// limiter's scale is the only live state, and only while it is holding
+    /// the fixture below full brightness.
+    fn export_state(&mut self) -> StateExport {
+        if self.power_scale_q16 == power_limit::UNITY_SCALE_Q16 {
+            return StateExport::Stateless;
+        }
+        StateExport::Resets {
+            reason: "the current limiter restarts at full scale",
+        }
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -650,7 +696,7 @@ fn sync_mapping_config_from_def(
             ..
         } => {
             let Some(next_sample_diameter) =
-                try_read_def_value(ctx, MAPPING_SAMPLE_DIAMETER_DEF_PATH)?
+                try_read_def_value(ctx, FIXTURE_DEF, MAPPING_SAMPLE_DIAMETER_DEF_PATH)?
             else {
                 return Ok(false);
             };
@@ -673,12 +719,12 @@ fn sync_mapping_config_from_def(
 /// the runtime vocabulary. `None` when the declaration does not resolve at
 /// all (a runtime fixture with no def) — the loaded policy then stands.
 fn try_read_consume_policy(ctx: &mut TickContext<'_>) -> Option<ConsumerPolicy> {
-    match try_read_def_enum_variant(ctx, "consume")?.as_str() {
+    match authored_variant(ctx, "consume")?.as_str() {
         // `Auto` IS the defaults-only policy — the model says so in as many
         // words, so there is no third state to carry into the engine.
         "Auto" => Some(ConsumerPolicy::AUTO),
         "Policy" => {
-            let from_1d = match try_read_def_enum_variant(ctx, "consume.Policy.from_1d")
+            let from_1d = match authored_variant(ctx, "consume.Policy.from_1d")
                 .as_deref()
                 .unwrap_or("Extrude")
             {
@@ -687,7 +733,7 @@ fn try_read_consume_policy(ctx: &mut TickContext<'_>) -> Option<ConsumerPolicy>
                 "Mirror" => CellProjection::Mirror,
                 _ => CellProjection::Extrude,
             };
-            let force = try_read_def_value::<bool>(ctx, "consume.Policy.force")
+            let force = try_read_def_value::<bool>(ctx, FIXTURE_DEF, "consume.Policy.force")
                 .ok()
                 .flatten()
                 .unwrap_or(false);
@@ -700,73 +746,15 @@ fn try_read_consume_policy(ctx: &mut TickContext<'_>) -> Option<ConsumerPolicy>
     }
 }
 
-/// The active variant name of an authored enum slot, read through the same
-/// overlay-aware view as the value syncs.
-fn try_read_def_enum_variant(
-    ctx: &mut TickContext<'_>,
-    path: &'static str,
-) -> Option<alloc::string::String> {
-    let production = ctx.resolve_static_consumed(path).ok()?;
-    let lpc_model::SlotData::Enum(declaration) = production.data() else {
-        return None;
-    };
-    Some(alloc::string::String::from(declaration.variant.as_str()))
-}
-
-fn try_read_def_value<T: lpc_model::FromLpValue>(
-    ctx: &mut TickContext<'_>,
-    path: &'static str,
-) -> Result<Option<T>, NodeError> {
-    let production = match ctx.resolve_static_consumed(path) {
-        Ok(production) => production,
-        Err(e) => {
-            // "Absent" (no def loaded, inactive enum variant, option none) is
-            // expected and reads as None; a path that cannot exist in the
-            // FixtureDef shape is a code bug and must not be swallowed.
-            let slot = SlotPath::parse(path).map_err(|e| {
-                NodeError::msg(alloc::format!(
-                    "invalid authored fixture path {path:?}: {e}"
-                ))
-            })?;
-            ensure_path_exists_in_fixture_def_shape(ctx.slot_shapes(), &slot)?;
-            log::debug!("[fixture] def path {path} unavailable: {}", e.message);
-            return Ok(None);
-        }
-    };
-    let value = production
-        .value_leaf()
-        .ok_or_else(|| NodeError::msg("resolved fixture path is not a value"))?;
-    T::from_lp_value(value.value())
-        .map(Some)
-        .map_err(|e| NodeError::msg(alloc::format!("fixture path {path:?}: {e}")))
-}
-
-/// Shape-only check that `slot` addresses a declared `FixtureDef` slot. The
-/// walk tolerates inactive enum variants and unpopulated map keys, so it only
-/// rejects paths that can never resolve (e.g. a misspelled variant segment).
-fn ensure_path_exists_in_fixture_def_shape(
-    shapes: &SlotShapeRegistry,
-    slot: &SlotPath,
-) -> Result<(), NodeError> {
-    use lpc_model::{SlotShapeLookup, StaticSlotShape};
-    let shape = shapes
-        .get_shape(lpc_model::nodes::FixtureDef::SHAPE_ID)
-        .ok_or_else(|| NodeError::msg("FixtureDef slot shape is not registered"))?;
-    if lpc_model::resolve_slot_role(shape, shapes, slot).is_none() {
-        return Err(NodeError::msg(alloc::format!(
-            "fixture def path {slot} does not exist in the FixtureDef shape"
-        )));
-    }
-    Ok(())
-}
-
 #[derive(Clone, Copy)]
 struct FixtureRenderSettings {
     width: u32,
     height: u32,
     diagnostic_mode: FixtureDiagnosticMode,
     color_order: ColorOrder,
-    brightness: u8,
+    /// Linear light scale from the brightness fader, after the fixture's
+    /// dimmer curve (and brightness gamma, if authored).
+    brightness: Q32,
     gamma_correction: bool,
     /// Lamp type and the budget in force, after an unstated one has fallen
     /// back to the default. A zero budget means limiting was opted out of.
@@ -793,6 +781,7 @@ impl ControlNode for FixtureNode {
                 request,
                 target,
                 settings,
+                settings.gamma_correction.then_some(&self.gamma),
                 self.mapping.as_mapping_ref(),
                 ctx.time_seconds(),
             );
@@ -892,7 +881,7 @@ impl FixtureNode {
                 self.mapping.as_mapping_ref(),
                 settings.color_order,
                 settings.brightness,
-                settings.gamma_correction,
+                settings.gamma_correction.then_some(&self.gamma),
                 power,
             );
         };
@@ -923,6 +912,7 @@ impl FixtureNode {
                 request,
                 target,
                 settings,
+                settings.gamma_correction.then_some(&self.gamma),
                 request_space,
                 ctx,
                 power,
@@ -967,7 +957,7 @@ impl FixtureNode {
                 self.mapping.as_mapping_ref(),
                 settings.color_order,
                 settings.brightness,
-                settings.gamma_correction,
+                settings.gamma_correction.then_some(&self.gamma),
                 power,
             );
         }
@@ -977,10 +967,12 @@ impl FixtureNode {
             .ok_or_else(|| NodeError::msg("fixture control render missing cached mapping"))?
             .3;
 
+        // The area accumulator reads any storage format, so the target is
+        // the producer's own: an `R8` shader costs an `R8` frame.
         let texture_request = RenderTextureRequest {
             width: settings.width,
             height: settings.height,
-            format: lps_shared::TextureStorageFormat::Rgba16Unorm,
+            format: product_space.format,
             time_seconds: ctx.time_seconds(),
             space: VisualSpace::TwoD,
             policy: settings.consume_policy,
@@ -1006,7 +998,7 @@ impl FixtureNode {
             self.mapping.as_mapping_ref(),
             settings.color_order,
             settings.brightness,
-            settings.gamma_correction,
+            settings.gamma_correction.then_some(&self.gamma),
             power,
         )
     }
@@ -1080,7 +1072,7 @@ fn ensure_fixture_render_target<'a>(
         // the backend can reuse the memory.
         drop(current.take());
         let texture = graphics
-            .create_render_target(request.width, request.height)
+            .create_render_target(request.width, request.height, request.format)
             .map_err(err_ctx("fixture render target allocation"))?;
         if texture.format() != request.format {
             return Err(NodeError::msg(format!(
@@ -1337,6 +1329,7 @@ fn render_direct_fixture_control(
     request: &ControlRenderRequest,
     target: ControlRenderTarget<'_>,
     settings: FixtureRenderSettings,
+    gamma: Option<&GammaCurve>,
     space: VisualSpace,
     ctx: &mut ControlRenderContext<'_>,
     power: &mut PowerPass,
@@ -1399,31 +1392,15 @@ fn render_direct_fixture_control(
         .map_err(err_ctx("fixture sample read"))?;
 
     target.samples.fill(0);
-    let brightness = settings.brightness.to_q32() / 255.to_q32();
     let mut written_samples = 0usize;
     for (channel, rgba) in channels.iter().zip(sampled.chunks_exact(4)) {
         let base = (*channel as usize).saturating_mul(3);
         if base + 3 > expected_samples {
             continue;
         }
-        let r = encode_fixture_channel(
-            rgba[0],
-            settings.gamma_correction,
-            settings.brightness,
-            brightness,
-        );
-        let g = encode_fixture_channel(
-            rgba[1],
-            settings.gamma_correction,
-            settings.brightness,
-            brightness,
-        );
-        let b = encode_fixture_channel(
-            rgba[2],
-            settings.gamma_correction,
-            settings.brightness,
-            brightness,
-        );
+        let r = encode_fixture_channel(rgba[0], gamma, settings.brightness);
+        let g = encode_fixture_channel(rgba[1], gamma, settings.brightness);
+        let b = encode_fixture_channel(rgba[2], gamma, settings.brightness);
         // After gamma, never before. See `power_limit`.
         let r = power.channel(r);
         let g = power.channel(g);
@@ -1442,6 +1419,7 @@ fn render_fixture_diagnostic_control(
     request: &ControlRenderRequest,
     target: ControlRenderTarget<'_>,
     settings: FixtureRenderSettings,
+    gamma: Option<&GammaCurve>,
     mapping: MappingRef<'_>,
     time_seconds: f32,
 ) -> Result<ControlLayout, NodeError> {
@@ -1469,7 +1447,6 @@ fn render_fixture_diagnostic_control(
     let lamp_count = fixture_lamp_channel_count(mapping);
     let available_lamps = expected_samples / 3;
     let rendered_lamps = (lamp_count as usize).min(available_lamps);
-    let brightness = settings.brightness.to_q32() / 255.to_q32();
     let path_spans = if settings.diagnostic_mode == FixtureDiagnosticMode::PathColors {
         fixture_path_spans(mapping)
     } else {
@@ -1486,15 +1463,8 @@ fn render_fixture_diagnostic_control(
                 time_seconds,
             )
         };
-        let ordered = finalize_fixture_rgb(
-            settings.color_order,
-            r,
-            g,
-            b,
-            settings.brightness,
-            brightness,
-            settings.gamma_correction,
-        );
+        let ordered =
+            finalize_fixture_rgb(settings.color_order, r, g, b, settings.brightness, gamma);
         let base = lamp * 3;
         target.samples[base..base + 3].copy_from_slice(&ordered);
     }
@@ -1533,6 +1503,14 @@ fn diagnostic_rgb(
                 [0, 0, 0]
             }
         }
+        FixtureDiagnosticMode::Automap => {
+            let pattern = lpc_mapping::capture::CapturePattern::new(lamp_count);
+            if pattern.is_lit(pattern.frame_at_time(time_seconds), lamp) {
+                [u16::MAX, u16::MAX, u16::MAX]
+            } else {
+                [0, 0, 0]
+            }
+        }
     }
 }
 
@@ -1581,13 +1559,12 @@ fn finalize_fixture_rgb(
     r: u16,
     g: u16,
     b: u16,
-    brightness_u8: u8,
     brightness: Q32,
-    gamma_correction: bool,
+    gamma: Option<&GammaCurve>,
 ) -> [u16; 3] {
-    let r = encode_fixture_channel(r, gamma_correction, brightness_u8, brightness);
-    let g = encode_fixture_channel(g, gamma_correction, brightness_u8, brightness);
-    let b = encode_fixture_channel(b, gamma_correction, brightness_u8, brightness);
+    let r = encode_fixture_channel(r, gamma, brightness);
+    let g = encode_fixture_channel(g, gamma, brightness);
+    let b = encode_fixture_channel(b, gamma, brightness);
     ordered_rgb_u16(color_order, r, g, b)
 }
 
@@ -1596,26 +1573,19 @@ fn finalize_fixture_rgb(
 /// Gamma (when enabled) is the perceptual→linear encode; brightness is a
 /// linear light scale, so it must land after the encode — brightness `s`
 /// then emits `s` of the photons and keeps `s` of the wire's 256 codes.
-/// Applied before the encode it would be raised to the 2.8 power on its way
+/// Applied before the encode it would be raised to the γ power on its way
 /// to the wire (`(s·c)^γ = s^γ·c^γ`), starving the 8-bit output at dim
-/// settings. See `docs/design/brightness-gamma-dithering.md`.
-fn encode_fixture_channel(
-    value: u16,
-    gamma_correction: bool,
-    brightness_u8: u8,
-    brightness: Q32,
-) -> u16 {
-    let linear = if gamma_correction {
-        apply_gamma16(value)
-    } else {
-        value
-    };
-    apply_brightness_unorm16(linear, brightness_u8, brightness)
+/// settings; a fixture that wants that anyway says so with brightness
+/// gamma, which the [`Dimmer`] folds into `brightness`. See
+/// `docs/design/brightness-gamma-dithering.md`.
+fn encode_fixture_channel(value: u16, gamma: Option<&GammaCurve>, brightness: Q32) -> u16 {
+    let linear = gamma.map_or(value, |gamma| gamma.apply(value));
+    apply_brightness_unorm16(linear, brightness)
 }
 
 /// Linear-domain brightness multiply on a post-gamma u16 duty value.
-fn apply_brightness_unorm16(value: u16, brightness_u8: u8, brightness: Q32) -> u16 {
-    if brightness_u8 == u8::MAX {
+fn apply_brightness_unorm16(value: u16, brightness: Q32) -> u16 {
+    if brightness >= Q32::ONE {
         return value;
     }
     Q32((((i64::from(value)) * i64::from(brightness.0)) >> 16) as i32).to_u16_saturating()
@@ -1627,14 +1597,14 @@ fn accumulate_fixture_channels_from_texture_data(
     width: u32,
     height: u32,
 ) -> Result<ChannelAccumulators, NodeError> {
-    if texture.format() == lps_shared::TextureStorageFormat::Rgba16Unorm
+    if let Some(format) = area_sampler_format(texture.format())
         && texture.width() == width
         && texture.height() == height
     {
         return Ok(accumulate_from_mapping(
             mapping_entries,
             texture.bytes(),
-            TextureFormat::Rgba16,
+            format,
             width,
             height,
         ));
@@ -1655,6 +1625,18 @@ fn accumulate_fixture_channels_from_texture_data(
     )
 }
 
+/// The area accumulator's sampler for a render-target format; `None` for
+/// `Rgb16Unorm`, which only the sampled fallback reads.
+fn area_sampler_format(format: lps_shared::TextureStorageFormat) -> Option<TextureFormat> {
+    match format {
+        lps_shared::TextureStorageFormat::Rgba16Unorm => Some(TextureFormat::Rgba16),
+        lps_shared::TextureStorageFormat::R16Unorm => Some(TextureFormat::R16),
+        lps_shared::TextureStorageFormat::Rgb8Unorm => Some(TextureFormat::Rgb8),
+        lps_shared::TextureStorageFormat::R8Unorm => Some(TextureFormat::R8),
+        lps_shared::TextureStorageFormat::Rgb16Unorm => None,
+    }
+}
+
 fn accumulate_fixture_channels_from_texture_product(
     texture: &TextureRenderProduct,
     mapping_entries: &[PixelMappingEntry],
@@ -1783,8 +1765,8 @@ fn render_fixture_control_target(
     accumulators: &ChannelAccumulators,
     mapping: MappingRef<'_>,
     color_order: ColorOrder,
-    brightness_u8: u8,
-    gamma_correction: bool,
+    brightness: Q32,
+    gamma: Option<&GammaCurve>,
     power: &mut PowerPass,
 ) -> Result<ControlLayout, NodeError> {
     if request.sample_format != ControlSampleFormat::Unorm16
@@ -1810,7 +1792,6 @@ fn render_fixture_control_target(
     target.samples.fill(0);
 
     let max_channel = accumulators.max_channel as usize;
-    let brightness = brightness_u8.to_q32() / 255.to_q32();
     let mut written_samples = 0usize;
 
     for channel_idx in 0usize..=max_channel {
@@ -1819,23 +1800,20 @@ fn render_fixture_control_target(
             break;
         }
 
-        let (r, g, b) = if gamma_correction {
+        let (r, g, b) = if let Some(gamma) = gamma {
             // Encode first, then brightness as a linear multiply on the u16
             // duty values — see `encode_fixture_channel`.
             (
                 apply_brightness_unorm16(
-                    apply_gamma16(accumulators.r[channel_idx].to_u16_saturating()),
-                    brightness_u8,
+                    gamma.apply(accumulators.r[channel_idx].to_u16_saturating()),
                     brightness,
                 ),
                 apply_brightness_unorm16(
-                    apply_gamma16(accumulators.g[channel_idx].to_u16_saturating()),
-                    brightness_u8,
+                    gamma.apply(accumulators.g[channel_idx].to_u16_saturating()),
                     brightness,
                 ),
                 apply_brightness_unorm16(
-                    apply_gamma16(accumulators.b[channel_idx].to_u16_saturating()),
-                    brightness_u8,
+                    gamma.apply(accumulators.b[channel_idx].to_u16_saturating()),
                     brightness,
                 ),
             )
@@ -2017,9 +1995,12 @@ mod tests {
     #[cfg(feature = "node-shader")]
     use core::sync::atomic::{AtomicU32, Ordering};
 
-    use lpc_model::nodes::fixture::PathSpec;
+    use lpc_model::nodes::fixture::{DimmerCurve, PathSpec};
     use lpc_model::{Dim2u, Kind, LpValue, PositiveF32, ToLpValue, TreePath, WithRevision};
     use lpc_registry::ProjectRegistry;
+    use lps_q32::q32::ToQ32;
+
+    use crate::nodes::fixture::gamma::apply_gamma16;
     use lpc_wire::{WireChildKind, WireSlotIndex};
 
     use crate::dataflow::resolver::{
@@ -2260,6 +2241,12 @@ mod tests {
                 lps_shared::TextureStorageFormat::R16Unorm => {
                     pixels.extend_from_slice(&color[0].to_le_bytes());
                 }
+                lps_shared::TextureStorageFormat::Rgb8Unorm => {
+                    for c in [color[0], color[1], color[2]] {
+                        pixels.push((c >> 8) as u8);
+                    }
+                }
+                lps_shared::TextureStorageFormat::R8Unorm => pixels.push((color[0] >> 8) as u8),
             }
         }
         TextureRenderProduct::new(width, height, format, pixels).map_err(err_ctx("solid texture"))
@@ -2973,6 +2960,88 @@ mod tests {
         assert_eq!(layout.spans[0].len, 90);
     }
 
+    #[test]
+    fn fixture_diagnostic_automap_plays_the_capture_sequence() {
+        let mut engine = Engine::new(TreePath::parse("/show.t").unwrap());
+        let registry = ProjectRegistry::new();
+        let frame = Revision::new(1);
+        let root = engine.tree().root();
+        let spine = test_placeholder_spine();
+        let mapping =
+            MappingConfig::path_points_vec(vec![PathSpec::point_list(0, vec![[0.5, 0.5]; 4])], 2.0);
+
+        let fix_id = engine
+            .tree_mut()
+            .add_child(
+                root,
+                lpc_model::NodeName::parse("fx").unwrap(),
+                lpc_model::NodeName::parse("fixture").unwrap(),
+                WireChildKind::Input {
+                    source: WireSlotIndex(0),
+                },
+                spine,
+                frame,
+            )
+            .unwrap();
+
+        engine
+            .attach_runtime_node(
+                fix_id,
+                Box::new(FixtureNode::new(
+                    fix_id,
+                    mapping,
+                    FixtureSamplingConfig::TextureArea,
+                    frame,
+                )),
+                frame,
+            )
+            .unwrap();
+        bind_fixture_def_defaults(&mut engine, fix_id, frame);
+        bind_fixture_def_slot(
+            &mut engine,
+            fix_id,
+            frame,
+            "diagnostic_mode",
+            FixtureDiagnosticMode::Automap.to_lp_value(),
+        );
+        engine.add_demand_root(fix_id);
+
+        let extent = ControlExtent::new(1, 12);
+        let render_lit = |engine: &mut Engine| {
+            let request = ControlRenderRequest::unorm16(extent);
+            let mut samples = vec![0u16; extent.sample_count() as usize];
+            let target =
+                ControlRenderTarget::new(extent, ControlSampleFormat::Unorm16, &mut samples);
+            engine
+                .render_control_for_test(
+                    &registry,
+                    ControlProduct::new(fix_id, 0, extent),
+                    &request,
+                    target,
+                )
+                .expect("control render");
+            samples
+                .chunks_exact(3)
+                .map(|rgb| match rgb {
+                    [0, 0, 0] => false,
+                    [65535, 65535, 65535] => true,
+                    other => panic!("capture frames are black or white, got {other:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Frame 0 (all on) at t = 0.01 s.
+        engine.tick(&registry, 10).unwrap();
+        assert_eq!(render_lit(&mut engine), [true; 4]);
+        // Frame 1 (all off), then frame 2: Gray bit 0 of lamps 0..4 is 0,1,1,0.
+        engine.tick(&registry, 1000).unwrap();
+        assert_eq!(render_lit(&mut engine), [false; 4]);
+        engine.tick(&registry, 1000).unwrap();
+        let pattern = lpc_mapping::capture::CapturePattern::new(4);
+        assert_eq!(pattern.frame_at_time(2.01), 2);
+        assert_eq!(render_lit(&mut engine), [false, true, true, false]);
+    }
+
     #[test]
     #[cfg(all(feature = "node-shader", feature = "node-texture"))]
     fn fixture_demand_resolve_and_tick_share_one_shader_producer_tick_via_resolver_cache() {
@@ -4083,6 +4152,8 @@ mod tests {
         use lpc_model::nodes::FixtureDef;
         use lpc_model::{EnumSlot, lookup_slot_data};
 
+        use crate::nodes::def_read::ensure_path_exists_in_def_shape;
+
         let def = FixtureDef {
             mapping: EnumSlot::new(MappingConfig::path_points_vec(
                 vec![PathSpec::point_list(0, vec![[0.5, 0.5]; 12])],
@@ -4094,7 +4165,7 @@ mod tests {
 
         for path in fixture_sync_def_paths() {
             let slot = SlotPath::parse(&path).expect("parse path");
-            ensure_path_exists_in_fixture_def_shape(&shapes, &slot)
+            ensure_path_exists_in_def_shape(&shapes, FIXTURE_DEF, &slot)
                 .unwrap_or_else(|e| panic!("shape walk {path}: {e:?}"));
             lookup_slot_data(&def, &shapes, &slot)
                 .unwrap_or_else(|e| panic!("data walk {path}: {e}"));
@@ -4103,14 +4174,14 @@ mod tests {
         // Snake-cased variant segments (the original bug) must be rejected by
         // the shape check instead of silently reading as "absent".
         let wrong = SlotPath::parse("mapping.path_points.sample_diameter").unwrap();
-        assert!(ensure_path_exists_in_fixture_def_shape(&shapes, &wrong).is_err());
+        assert!(ensure_path_exists_in_def_shape(&shapes, FIXTURE_DEF, &wrong).is_err());
     }
 
     /// One lamp through `render_fixture_control_target`, returning the
     /// written RGB samples and the power pass that saw them.
     fn run_control_target(
         acc: Q32,
-        brightness_u8: u8,
+        brightness: Q32,
         gamma_correction: bool,
     ) -> ([u16; 3], PowerPass) {
         let accumulators = ChannelAccumulators {
@@ -4129,8 +4200,8 @@ mod tests {
             &accumulators,
             MappingRef::Slots(&MappingConfig::Unset),
             ColorOrder::Rgb,
-            brightness_u8,
-            gamma_correction,
+            brightness,
+            gamma_correction.then(GammaCurve::default).as_ref(),
             &mut power,
         )
         .unwrap();
@@ -4156,7 +4227,8 @@ mod tests {
         for brightness_u8 in [0u8, 1, 38, 64, 127, 128, 254, 255] {
             let brightness = brightness_u8.to_q32() / 255.to_q32();
             for acc in accs {
-                let ([r, g, b], _) = run_control_target(acc, brightness_u8, false);
+                let ([r, g, b], _) =
+                    run_control_target(acc, Dimmer::default().scale(brightness_u8), false);
                 let expected = (acc * brightness).to_u16_saturating();
                 assert_eq!(
                     [r, g, b],
@@ -4175,8 +4247,8 @@ mod tests {
         let brightness = 91u8.to_q32() / 255.to_q32();
         for v in [0u16, 1, 255, 9766, 32768, 65534, 65535] {
             assert_eq!(
-                encode_fixture_channel(v, false, 91, brightness),
-                apply_brightness_unorm16(v, 91, brightness)
+                encode_fixture_channel(v, None, brightness),
+                apply_brightness_unorm16(v, brightness)
             );
         }
     }
@@ -4188,7 +4260,7 @@ mod tests {
     /// pixels" bench symptom.
     #[test]
     fn gamma_on_brightness_scales_linear_light_after_the_encode() {
-        let ([r, g, b], _) = run_control_target(Q32::ONE, 38, true);
+        let ([r, g, b], _) = run_control_target(Q32::ONE, Dimmer::default().scale(38), true);
         assert_eq!([r >> 8, g >> 8, b >> 8], [38; 3]);
 
         // The old ordering, recomputed explicitly: brightness ahead of the
@@ -4202,7 +4274,7 @@ mod tests {
     /// brightness lands before demand accumulation.
     #[test]
     fn power_demand_sees_post_brightness_duty() {
-        let ([r, g, b], power) = run_control_target(Q32::ONE, 128, false);
+        let ([r, g, b], power) = run_control_target(Q32::ONE, Dimmer::default().scale(128), false);
         assert_eq!(
             power.demand8(),
             u32::from(r >> 8) + u32::from(g >> 8) + u32::from(b >> 8)
@@ -4212,6 +4284,73 @@ mod tests {
             "full white at half brightness must not demand full duty"
         );
     }
+
+    /// Wire code full-white content lands on at fader 38/255, gamma on,
+    /// for each dimmer.
+    fn full_white_code_at_fader_38(dimmer: Dimmer) -> u16 {
+        let ([r, g, b], _) = run_control_target(Q32::ONE, dimmer.scale(38), true);
+        assert!(r == g && g == b);
+        r >> 8
+    }
+
+    /// The dimmer curve decides how much of the wire a dim fader keeps.
+    /// Linear keeps 38 codes; square `38²/255`; the S-curve's slow start
+    /// `3s² − 2s³`; brightness gamma the design doc's 1.24 — the
+    /// pre-reorder pipeline, now opt-in.
+    #[test]
+    fn dimmer_curves_set_the_wire_codes_at_low_brightness() {
+        let curve = |curve| Dimmer {
+            curve,
+            ..Dimmer::default()
+        };
+        assert_eq!(full_white_code_at_fader_38(Dimmer::default()), 38);
+        assert_eq!(full_white_code_at_fader_38(curve(DimmerCurve::Square)), 5);
+        assert_eq!(full_white_code_at_fader_38(curve(DimmerCurve::SCurve)), 15);
+        let brightness_gamma = Dimmer {
+            brightness_gamma: Some(2.8),
+            ..Dimmer::default()
+        };
+        assert_eq!(full_white_code_at_fader_38(brightness_gamma), 1);
+    }
+
+    /// Distinct wire codes a full content ramp reaches at fader 38, gamma
+    /// on: the resolution the wire has left for the image itself.
+    fn distinct_codes_at_fader_38(dimmer: &Dimmer) -> usize {
+        let brightness = dimmer.scale(38);
+        let mut seen = [false; 256];
+        for v in (0..=u16::MAX).step_by(16) {
+            let ([r, _, _], _) = run_control_target(Q32(i32::from(v)), brightness, true);
+            seen[usize::from(r >> 8)] = true;
+        }
+        seen.iter().filter(|seen| **seen).count()
+    }
+
+    #[test]
+    fn brightness_gamma_collapses_the_dim_image_into_a_couple_of_codes() {
+        let linear = distinct_codes_at_fader_38(&Dimmer::default());
+        let brightness_gamma = distinct_codes_at_fader_38(&Dimmer {
+            brightness_gamma: Some(2.8),
+            ..Dimmer::default()
+        });
+        assert!(linear >= 38, "linear fader kept only {linear} codes");
+        assert!(
+            brightness_gamma <= 2,
+            "brightness gamma kept {brightness_gamma} codes"
+        );
+    }
+
+    /// A custom table is honoured end to end: an authored 10% floor keeps
+    /// full white on code 25 at fader 0.
+    #[test]
+    fn custom_dimmer_table_reaches_the_wire() {
+        let floor = Dimmer {
+            curve: DimmerCurve::Custom,
+            lut: vec![6554, 65535],
+            brightness_gamma: None,
+        };
+        let ([r, _, _], _) = run_control_target(Q32::ONE, floor.scale(0), true);
+        assert_eq!(r >> 8, 25);
+    }
 }
 
 /// The two-sided space negotiation, end to end through a real compiled
@@ -4238,8 +4377,8 @@ mod space_negotiation {
     use alloc::vec;
     use alloc::vec::Vec;
     use lpc_model::{
-        ArtifactLocation, AssetContentType, AssetLocation, EnumSlot, NodeId, ShaderDef,
-        ShaderSpace, SpaceAnswer2, VisualConsumerSpace,
+        ArtifactLocation, AssetContentType, AssetLocation, EnumSlot, NodeId, OptionSlot, ShaderDef,
+        ShaderSpace, SpaceAnswer2, TextureFormat, ValueSlot, VisualConsumerSpace,
     };
     use lpc_registry::AssetText;
 
@@ -4301,8 +4440,12 @@ vec4 render_2d(vec2 pos) { return vec4(pos.x / outputSize.x, pos.y / outputSize.
 
     impl ShaderProducer {
         fn new(space: ShaderSpace, source: &str) -> Self {
+            Self::with_def(shader_def(space), source)
+        }
+
+        fn with_def(def: ShaderDef, source: &str) -> Self {
             let node_id = NodeId::new(1);
-            let mut node = ShaderNode::new(node_id, shader_def(space), asset(source));
+            let mut node = ShaderNode::new(node_id, def, asset(source), &mut lp_shader::NoIncludes);
             // The engine opens compile windows during tick; a node-level
             // harness stands in for it.
             node.open_compile_window(Revision::new(1));
@@ -4407,7 +4550,7 @@ vec4 render_2d(vec2 pos) { return vec4(pos.x / outputSize.x, pos.y / outputSize.
         // coordinates the shader saw, not the output encode.
         if let Some(settings) = fixture.last_settings.as_mut() {
             settings.gamma_correction = false;
-            settings.brightness = u8::MAX;
+            settings.brightness = Q32::ONE;
             settings.power = FixturePower {
                 budget_ma: 0,
                 ..FixturePower::default()
@@ -4488,6 +4631,36 @@ vec4 render_2d(vec2 pos) { return vec4(pos.x / outputSize.x, pos.y / outputSize.
         }
     }
 
+    /// The strip texture path asks for RGBA16 whatever the product's own
+    /// format. A 1D effect authored as `R8` still fills the strip: the
+    /// shader widens its frame, and every lamp reads the grey ramp.
+    #[test]
+    fn a_narrow_format_1d_effect_fills_the_strip_texture() {
+        const COUNT: usize = 8;
+        let def = ShaderDef {
+            output_format: OptionSlot::some(ValueSlot::new(TextureFormat::R8)),
+            ..shader_def(ShaderSpace::OneD {
+                in_2d: EnumSlot::default(),
+            })
+        };
+        let mut producer = ShaderProducer::with_def(
+            def,
+            "layout(binding = 0) uniform vec2 outputSize; \
+float render_1d(float pos) { return pos / outputSize.x; }",
+        );
+        let product = producer.product();
+        let mut fixture = ring_fixture(COUNT, true, ConsumerPolicy::AUTO, product);
+        fixture.sampling = FixtureSamplingConfig::TextureArea;
+        let lamps = render_lamps(&mut fixture, &mut producer, COUNT);
+
+        for (index, lamp) in lamps.iter().enumerate() {
+            let strip_position = (index as f32 + 0.5) / COUNT as f32;
+            for channel in lamp {
+                assert_near(*channel, strip_position, &alloc::format!("lamp {index}"));
+            }
+        }
+    }
+
     /// The same scarf running a 2D effect samples the RING UVs — the map
     /// is exactly what a 2D effect is for.
     #[test]
@@ -4830,7 +5003,9 @@ vec4 render_2d(vec2 pos) { return vec4(pos.x / outputSize.x, pos.y / outputSize.
             space: VisualSpace::TwoD,
             policy: ConsumerPolicy::AUTO,
         };
-        let mut texture = graphics.create_render_target(4, 4).expect("target");
+        let mut texture = graphics
+            .create_render_target(4, 4, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         {
             let mut ctx = producer.ctx();
             producer
@@ -4890,6 +5065,7 @@ vec4 render_2d(vec2 pos) { return vec4(pos.x / outputSize.x, pos.y / outputSize.
                     in_2d: EnumSlot::new(SpaceAnswer2::Angular),
                 }),
                 asset(RAMP_1D),
+                &mut lp_shader::NoIncludes,
             );
             Box::new(node)
         };
@@ -8,8 +8,8 @@
 //! recover what gamma already destroyed (see
 //! docs/defects/2026-08-01-gamma-8bit-choke.md).
 //!
-//! The curve is x^2.8, matching the exponent of the legacy 8-bit Adafruit
-//! table this replaced. It is evaluated by linear interpolation over a
+//! The default curve is x^2.8, matching the exponent of the legacy 8-bit
+//! Adafruit table this replaced. It is evaluated by linear interpolation over a
 //! 513-entry lookup table (512 segments of 128 input counts), the same
 //! shape as the white-point LUT in `lpc_shared::display_pipeline::lut`.
 //! Entries carry 2 fractional bits so table rounding stays below the
@@ -19,6 +19,16 @@
 //! exactly on 65535 at the top without an edge clamp. Max absolute error
 //! versus the analytic curve is under one output count across the full
 //! domain (asserted by test).
+//!
+//! A fixture may author another exponent (`FixtureDef::gamma`). That curve
+//! is a [`GammaCurve`]: the same table shape, generated once from the same
+//! formula when the exponent changes and held by the fixture — 2 KB for the
+//! fixtures that ask, nothing for the ones on the default.
+
+use alloc::boxed::Box;
+
+/// The exponent [`GAMMA16`] is built for; see `lpc_model::GammaExponent`.
+pub const DEFAULT_GAMMA_EXPONENT: f32 = 2.8;
 
 /// Number of GAMMA16 entries (index 0..=512; segment width 128 counts).
 pub const GAMMA16_LEN: usize = 513;
@@ -80,13 +90,71 @@ pub const GAMMA16: [u32; GAMMA16_LEN] = [
 /// `apply_gamma16(0) == 0` and `apply_gamma16(65535) == 65535`.
 #[inline]
 pub fn apply_gamma16(value: u16) -> u16 {
+    apply_gamma16_table(&GAMMA16, value)
+}
+
+/// [`apply_gamma16`] over any table in the [`GAMMA16`] shape.
+#[inline]
+fn apply_gamma16_table(table: &[u32; GAMMA16_LEN], value: u16) -> u16 {
     let v = value as u32;
     let index = (v >> 7) as usize;
     let alpha = v & 0x7F;
-    let blended = GAMMA16[index] * (0x80 - alpha) + GAMMA16[index + 1] * alpha;
+    let blended = table[index] * (0x80 - alpha) + table[index + 1] * alpha;
     ((blended + 0x100) >> 9).min(0xFFFF) as u16
 }
 
+/// One [`GAMMA16`]-shaped entry for `exponent`:
+/// `round(4 · 65535 · ((128·i) / 65535)^exponent)`.
+fn gamma16_entry(i: usize, exponent: f64) -> u32 {
+    let x = (128 * i) as f64 / 65535.0;
+    libm::round(4.0 * 65535.0 * libm::pow(x, exponent)) as u32
+}
+
+/// A gamma encode at a fixture's authored exponent.
+///
+/// At [`DEFAULT_GAMMA_EXPONENT`] this is [`GAMMA16`] itself; any other
+/// exponent generates its own table once, here, so the per-sample cost is
+/// the same lookup either way. Exponents are expected in
+/// `lpc_model::GammaExponent`'s `[1, 4]`: convex curves keep the exact
+/// endpoints and monotonicity [`apply_gamma16`] promises.
+#[derive(Debug, Clone)]
+pub struct GammaCurve {
+    exponent: f32,
+    custom: Option<Box<[u32; GAMMA16_LEN]>>,
+}
+
+impl GammaCurve {
+    pub fn new(exponent: f32) -> Self {
+        let custom = (exponent != DEFAULT_GAMMA_EXPONENT).then(|| {
+            let mut table = Box::new([0u32; GAMMA16_LEN]);
+            for (i, entry) in table.iter_mut().enumerate() {
+                *entry = gamma16_entry(i, f64::from(exponent));
+            }
+            table
+        });
+        Self { exponent, custom }
+    }
+
+    pub fn exponent(&self) -> f32 {
+        self.exponent
+    }
+
+    /// Encode one unorm16 channel value; see [`apply_gamma16`].
+    #[inline]
+    pub fn apply(&self, value: u16) -> u16 {
+        match &self.custom {
+            Some(table) => apply_gamma16_table(table, value),
+            None => apply_gamma16(value),
+        }
+    }
+}
+
+impl Default for GammaCurve {
+    fn default() -> Self {
+        Self::new(DEFAULT_GAMMA_EXPONENT)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,4 +223,46 @@ mod tests {
         // Measured 318 distinct levels; the old path produced exactly 2.
         assert!(count >= 250, "only {count} distinct post-gamma levels");
     }
+
+    /// An authored exponent gets its own table with the same guarantees
+    /// as the built-in one: exact endpoints, monotone, within one count.
+    #[test]
+    fn authored_exponents_keep_the_table_guarantees() {
+        for exponent in [1.0f32, 1.8, 2.2, 3.2, 4.0] {
+            let curve = GammaCurve::new(exponent);
+            assert_eq!(curve.apply(0), 0, "γ {exponent}");
+            assert_eq!(curve.apply(u16::MAX), u16::MAX, "γ {exponent}");
+            let mut prev = 0u16;
+            let mut max_err = 0.0f64;
+            for v in 0..=u16::MAX {
+                let out = curve.apply(v);
+                assert!(out >= prev, "γ {exponent}: output decreased at {v}");
+                prev = out;
+                let oracle = 65535.0 * libm::pow(v as f64 / 65535.0, f64::from(exponent));
+                max_err = max_err.max(libm::fabs(out as f64 - oracle));
+            }
+            assert!(max_err <= 1.0, "γ {exponent}: max error {max_err}");
+        }
+    }
+
+    #[test]
+    fn the_default_exponent_is_the_built_in_table() {
+        let curve = GammaCurve::default();
+        assert!(curve.custom.is_none(), "the default must not allocate");
+        for v in (0..=u16::MAX).step_by(97) {
+            assert_eq!(curve.apply(v), apply_gamma16(v));
+        }
+    }
+
+    /// A lower exponent keeps more wire codes for dim content: the bottom
+    /// 15% of the input (the dim-content span above) spans ~1.3 of the 256
+    /// codes at γ 2.8 and several times that at γ 2.2.
+    #[test]
+    fn a_lower_exponent_lifts_dim_content_off_the_floor() {
+        let dim = 9766u16;
+        let at_28 = GammaCurve::default().apply(dim) >> 8;
+        let at_22 = GammaCurve::new(2.2).apply(dim) >> 8;
+        assert_eq!(at_28, 1);
+        assert!(at_22 >= 3, "γ 2.2 put 15% content at code {at_22}");
+    }
 }
@@ -1,5 +1,6 @@
 //! Format-specific texture sampling
 
+pub mod r16;
 pub mod r8;
 pub mod rgb8;
 pub mod rgba16;
@@ -39,6 +40,7 @@ pub fn create_sampler(format: TextureFormat) -> Box<dyn TextureSampler> {
         TextureFormat::Rgb8 => Box::new(rgb8::Rgb8Sampler),
         TextureFormat::Rgba8 => Box::new(rgba8::Rgba8Sampler),
         TextureFormat::R8 => Box::new(r8::R8Sampler),
+        TextureFormat::R16 => Box::new(r16::R16Sampler),
         TextureFormat::Rgba16 => Box::new(rgba16::Rgba16Sampler),
     }
 }
@@ -0,0 +1,62 @@
+//! R16 (grayscale, unorm16) format sampler
+
+use super::TextureSampler;
+
+pub struct R16Sampler;
+
+impl TextureSampler for R16Sampler {
+    fn sample_pixel(
+        &self,
+        data: &[u8],
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Option<[u8; 3]> {
+        if x >= width || y >= height {
+            return None;
+        }
+
+        let bytes_per_pixel = 2;
+        let offset = ((y * width + x) as usize) * bytes_per_pixel;
+
+        if offset + bytes_per_pixel > data.len() {
+            return None;
+        }
+
+        // Grayscale: R=G=B
+        let gray = (u16::from_le_bytes([data[offset], data[offset + 1]]) >> 8) as u8;
+        Some([gray, gray, gray])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_sample_pixel() {
+        let sampler = R16Sampler;
+        // Create a 2x1 R16 texture: 0x8040, 0xffff
+        let data = vec![0x40, 0x80, 0xff, 0xff];
+
+        assert_eq!(
+            sampler.sample_pixel(&data, 0, 0, 2, 1),
+            Some([128, 128, 128])
+        );
+        assert_eq!(
+            sampler.sample_pixel(&data, 1, 0, 2, 1),
+            Some([255, 255, 255])
+        );
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let sampler = R16Sampler;
+        let data = vec![0, 0, 0, 0];
+
+        assert_eq!(sampler.sample_pixel(&data, 2, 0, 2, 1), None);
+        assert_eq!(sampler.sample_pixel(&data, 0, 1, 2, 1), None);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod dimmer;
 pub mod fixture_node;
 pub mod gamma;
 pub mod mapping;
@@ -1,5 +1,6 @@
 //! Runtime fluid node: consumes emitter maps and produces a visual product.
 
+use alloc::boxed::Box;
 use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -14,8 +15,8 @@ use lps_shared::TextureStorageFormat;
 
 use crate::dataflow::resolver::QueryKey;
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RenderContext, RenderNode, RuntimeStateShape, TickContext, err_ctx,
+    DegradeMode, DestroyCtx, MemPressureCtx, MigratedState, NodeError, NodeRuntime, PressureLevel,
+    ProduceResult, RenderContext, RenderNode, RuntimeStateShape, StateExport, TickContext, err_ctx,
 };
 use crate::products::visual::{
     RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest, VisualSampleTarget,
@@ -31,6 +32,8 @@ pub struct FluidNode {
     solver: Option<MsaFluidSolver>,
     solver_config: Option<FluidSolverConfig>,
     last_step_time_seconds: Option<f32>,
+    /// Over budget, the solver steps at `step_hz / update_divisor`.
+    update_divisor: u32,
 }
 
 impl FluidNode {
@@ -41,6 +44,7 @@ impl FluidNode {
             solver: None,
             solver_config: None,
             last_step_time_seconds: None,
+            update_divisor: 1,
         }
     }
 
@@ -53,22 +57,40 @@ impl FluidNode {
         &mut self,
         config: FluidSolverConfig,
     ) -> Result<&mut MsaFluidSolver, NodeError> {
-        let stale = self.solver_config != Some(config);
-        if stale {
-            let mut solver = MsaFluidSolver::new(config.width as usize, config.height as usize);
-            solver.set_solver_iterations(config.solver_iterations as usize);
-            solver.set_fade_speed(Q32::from_f32_wrapping(config.fade_speed));
-            solver.set_viscosity(Q32::from_f32_wrapping(config.viscosity));
-            self.solver = Some(solver);
-            self.solver_config = Some(config);
+        // Only a new grid size reallocates (and so resets the fluid); the
+        // other parameters are retuned in place, so dragging viscosity while
+        // the sim runs does not wipe it.
+        let resized = self
+            .solver_config
+            .is_none_or(|old| (old.width, old.height) != (config.width, config.height));
+        if resized {
+            self.solver = Some(MsaFluidSolver::new(
+                config.width as usize,
+                config.height as usize,
+            ));
             self.last_step_time_seconds = None;
         }
+        if self.solver_config != Some(config) {
+            if let Some(solver) = self.solver.as_mut() {
+                solver.set_solver_iterations(config.solver_iterations as usize);
+                solver.set_fade_speed(Q32::from_f32_wrapping(config.fade_speed));
+                solver.set_viscosity(Q32::from_f32_wrapping(config.viscosity));
+            }
+            self.solver_config = Some(config);
+        }
         self.solver
             .as_mut()
             .ok_or_else(|| NodeError::msg("fluid solver missing after allocation"))
     }
 }
 
+/// A fluid's simulation, across a hot reload.
+struct FluidMigration {
+    solver: MsaFluidSolver,
+    solver_config: Option<FluidSolverConfig>,
+    last_step_time_seconds: Option<f32>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct FluidSolverConfig {
     width: u32,
@@ -109,7 +131,7 @@ impl NodeRuntime for FluidNode {
                 self.last_step_time_seconds = Some(now);
                 false
             }
-            Some(last) => now - last >= 1.0 / config.step_hz,
+            Some(last) => now - last >= self.update_divisor as f32 / config.step_hz,
         };
         if should_step {
             let solver = self.ensure_solver(config)?;
@@ -128,6 +150,45 @@ impl NodeRuntime for FluidNode {
         Ok(ProduceResult::Produced)
     }
 
+    fn export_state(&mut self) -> StateExport {
+        let Some(solver) = self.solver.take() else {
+            return StateExport::Stateless;
+        };
+        StateExport::Carry(Box::new(FluidMigration {
+            solver,
+            solver_config: self.solver_config.take(),
+            last_step_time_seconds: self.last_step_time_seconds.take(),
+        }))
+    }
+
+    fn import_state(&mut self, state: MigratedState) -> bool {
+        let Ok(state) = state.downcast::<FluidMigration>() else {
+            return false;
+        };
+        // The new def's size is only readable at tick time; a resized grid
+        // is dropped by the first `ensure_solver`, same as a live resize.
+        let FluidMigration {
+            solver,
+            solver_config,
+            last_step_time_seconds,
+        } = *state;
+        self.solver = Some(solver);
+        self.solver_config = solver_config;
+        self.last_step_time_seconds = last_step_time_seconds;
+        true
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        DegradeMode::UpdateRate
+    }
+
+    fn set_degradation(&mut self, divisor: u32) {
+        // The solver's step is fixed, not time-scaled: stepping less often
+        // slows the fluid down rather than making it coarser. That is the
+        // trade a budget asks for — it still moves, and the frame is on time.
+        self.update_divisor = divisor.max(1);
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
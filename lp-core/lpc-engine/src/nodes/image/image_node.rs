@@ -0,0 +1,358 @@
+//! Runtime image node: plays a GIF, BMP or sprite sheet onto a fixed canvas
+//! and produces it as a visual product.
+
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_gfx::TextureHandle;
+use lpc_image::palette_quantize::{MAX_PALETTE_COLORS, MIN_PALETTE_COLORS};
+use lpc_image::{
+    ImageError, ImageLoop, ImageOptions, ImagePlayer, ImageScale, ImageSource, SpriteSheet,
+};
+use lpc_model::{
+    AssetLocation, Dim2u, ImageDefView, ImageState, NodeId, NodeRuntimeStatus, Revision,
+    SlotAccess, SlotPath, SlotShapeRegistry, SlotShapeRegistryError, TimeProduct, VisualProduct,
+};
+use lpfs::lp_path::{LpPath, LpPathBuf};
+
+use crate::engine::AssetStreamService;
+use crate::node::{
+    AssetRefreshContext, AssetRefreshResult, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime,
+    PressureLevel, ProduceResult, RenderContext, RenderNode, RuntimeStateShape, StateExport,
+    TickContext, err_ctx,
+};
+use crate::nodes::def_read::authored_variant;
+use crate::products::visual::{
+    Canvas, RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest,
+    VisualSampleTarget, canvas_texture, sample_canvas, write_canvas_texture,
+};
+
+/// Where an image node reads its file from.
+pub enum ImageAsset {
+    /// Ranged reads through the engine's [`AssetStreamService`]: only the
+    /// frame index and the canvas stay resident, whatever the file's size.
+    /// Reads see the committed file, not uncommitted overlay edits.
+    Streamed {
+        stream: Rc<dyn AssetStreamService>,
+        path: LpPathBuf,
+    },
+    /// The whole file, for hosts without a stream service.
+    Resident { bytes: Vec<u8>, revision: Revision },
+}
+
+impl ImageAsset {
+    fn with_source<R>(
+        &self,
+        read: impl FnOnce(&dyn ImageSource) -> Result<R, ImageError>,
+    ) -> Result<R, ImageError> {
+        match self {
+            Self::Streamed { stream, path } => {
+                let size = stream.file_size(path).map_err(read_error)?;
+                read(&StreamSource {
+                    stream: stream.as_ref(),
+                    path,
+                    size,
+                })
+            }
+            Self::Resident { bytes, .. } => read(bytes),
+        }
+    }
+}
+
+/// An [`AssetStreamService`] file seen as an [`ImageSource`].
+struct StreamSource<'a> {
+    stream: &'a dyn AssetStreamService,
+    path: &'a LpPath,
+    size: u64,
+}
+
+impl ImageSource for StreamSource<'_> {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError> {
+        self.stream
+            .read_file_range(self.path, offset, buf)
+            .map_err(read_error)
+    }
+}
+
+fn read_error(error: lpfs::FsError) -> ImageError {
+    ImageError::Read(format!("{error}"))
+}
+
+/// Runtime node for `kind = "Image"` artifacts.
+pub struct ImageNode {
+    state: ImageState,
+    def_view: Option<ImageDefView>,
+    location: AssetLocation,
+    asset: ImageAsset,
+    player: Option<ImagePlayer>,
+    /// Options of the last open attempt, failed or not: a broken file is
+    /// reopened when its options or bytes change, not every frame.
+    opened_with: Option<ImageOptions>,
+    frame: u32,
+    error: Option<String>,
+}
+
+impl ImageNode {
+    pub fn new(node_id: NodeId, location: AssetLocation, asset: ImageAsset) -> Self {
+        Self {
+            state: ImageState::new(VisualProduct::new(node_id, 0)),
+            def_view: None,
+            location,
+            asset,
+            player: None,
+            opened_with: None,
+            frame: 0,
+            error: None,
+        }
+    }
+
+    fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&ImageDefView, NodeError> {
+        ImageDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
+            .map_err(err_ctx("compile image def view"))
+    }
+
+    fn open(&mut self, options: ImageOptions) {
+        self.opened_with = Some(options);
+        self.frame = 0;
+        match self
+            .asset
+            .with_source(|source| ImagePlayer::open(source, options))
+        {
+            Ok(player) => {
+                self.player = Some(player);
+                self.error = None;
+            }
+            Err(error) => {
+                self.player = None;
+                self.error = Some(format!("open image: {error}"));
+            }
+        }
+    }
+
+    /// Decode the current frame into the player's canvas unless it is
+    /// already there (it is dropped under memory pressure).
+    fn ensure_canvas(&mut self) {
+        let frame = self.frame;
+        let Some(player) = self
+            .player
+            .as_mut()
+            .filter(|player| !player.has_frame(frame))
+        else {
+            return;
+        };
+        let drawn = self
+            .asset
+            .with_source(|source| player.render_frame(source, frame).map(|_| ()));
+        self.error = drawn
+            .err()
+            .map(|error| format!("decode image frame {frame}: {error}"));
+    }
+}
+
+impl Canvas for ImageNode {
+    fn canvas_size(&self) -> Option<(u32, u32)> {
+        let player = self.player.as_ref()?;
+        let options = player.options();
+        (!player.pixels().is_empty()).then_some((options.width, options.height))
+    }
+
+    fn canvas_texel(&self, x: u32, y: u32) -> [u16; 4] {
+        let Some(player) = &self.player else {
+            return [0; 4];
+        };
+        let pixel = player.pixels()[(y * player.options().width + x) as usize];
+        pixel.map(|channel| u16::from(channel) * 257)
+    }
+}
+
+impl NodeRuntime for ImageNode {
+    fn produce(
+        &mut self,
+        _slot: &SlotPath,
+        ctx: &mut TickContext<'_>,
+    ) -> Result<ProduceResult, NodeError> {
+        let options = read_options(self.def_view(ctx)?, ctx)?;
+        if self.opened_with != Some(options) {
+            self.open(options);
+        }
+
+        // A still image needs no clock: only animations resolve the time
+        // product, so an unbound `bus:time` never blanks a logo.
+        let animated = self
+            .player
+            .as_ref()
+            .is_some_and(|player| player.frame_count() > 1);
+        if animated {
+            let product: TimeProduct = self.def_view(ctx)?.time().get(ctx)?;
+            let now = ctx.time_product_seconds(product)?;
+            if let Some(player) = &self.player {
+                self.frame = player.frame_at(now);
+            }
+        }
+        self.ensure_canvas();
+
+        self.state
+            .output
+            .set_with_version(ctx.revision(), VisualProduct::new(ctx.node_id(), 0));
+        Ok(ProduceResult::Produced)
+    }
+
+    fn refresh_asset(
+        &mut self,
+        location: &AssetLocation,
+        ctx: &mut AssetRefreshContext<'_>,
+    ) -> Result<AssetRefreshResult, NodeError> {
+        if location != &self.location {
+            return Ok(AssetRefreshResult::Unused);
+        }
+        if let ImageAsset::Resident { bytes, revision } = &mut self.asset {
+            match ctx.read_asset_bytes_if_changed(location, *revision) {
+                Ok(Some(asset)) => {
+                    *bytes = asset.bytes;
+                    *revision = asset.revision;
+                }
+                Ok(None) => return Ok(AssetRefreshResult::Unchanged),
+                Err(err) => {
+                    // Keep-last-good: the old bytes still decode.
+                    self.error = Some(format!("read image: {err:?}"));
+                    return Ok(AssetRefreshResult::Refreshed);
+                }
+            }
+        }
+        // A streamed file is re-indexed on the next produce.
+        self.player = None;
+        self.opened_with = None;
+        Ok(AssetRefreshResult::Refreshed)
+    }
+
+    /// The playing frame follows the time input, so a fresh player lands
+    /// on the same one.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        // The canvas is a pure cache: decoding the same frame again yields
+        // the same pixels, so even `High` may drop it. The frame index stays;
+        // rebuilding it would re-read the whole file.
+        if level >= PressureLevel::High
+            && let Some(player) = &mut self.player
+        {
+            player.release();
+        }
+        Ok(())
+    }
+
+    fn runtime_status(&self) -> Option<NodeRuntimeStatus> {
+        self.error.clone().map(NodeRuntimeStatus::Error)
+    }
+
+    fn runtime_state_slots(&self) -> Option<&dyn SlotAccess> {
+        Some(&self.state)
+    }
+
+    fn register_runtime_state_shapes(
+        &self,
+        registry: &mut SlotShapeRegistry,
+    ) -> Result<(), SlotShapeRegistryError> {
+        ImageState::register_runtime_state_shape(registry).map(|_| ())
+    }
+
+    fn render_node(&mut self) -> Option<&mut dyn RenderNode> {
+        Some(self)
+    }
+}
+
+impl RenderNode for ImageNode {
+    fn render_texture(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        _ctx: &mut RenderContext<'_>,
+    ) -> Result<TextureRenderProduct, NodeError> {
+        self.ensure_canvas();
+        canvas_texture(self, request).map_err(err_ctx("image texture product"))
+    }
+
+    fn render_texture_into(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        write_canvas_texture(self, graphics, request, target)
+            .map_err(err_ctx("image texture upload"))
+    }
+
+    fn sample_visual_into(
+        &mut self,
+        _product: VisualProduct,
+        request: VisualSampleBufferRequest<'_>,
+        target: VisualSampleTarget<'_>,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        sample_canvas(self, graphics, request, target).map_err(err_ctx("image sample"))
+    }
+}
+
+/// The player options the authored def asks for this frame.
+fn read_options(def: &ImageDefView, ctx: &mut TickContext<'_>) -> Result<ImageOptions, NodeError> {
+    let size: Dim2u = def.size().get(ctx)?;
+    let scale = match authored_variant(ctx, "scale").as_deref() {
+        Some("Stretch") => ImageScale::Stretch,
+        Some("Fill") => ImageScale::Fill,
+        Some("Center") => ImageScale::Center,
+        _ => ImageScale::Fit,
+    };
+    let looping = match authored_variant(ctx, "looping").as_deref() {
+        Some("Once") => ImageLoop::Once,
+        Some("PingPong") => ImageLoop::PingPong,
+        _ => ImageLoop::Loop,
+    };
+    let palette_colors = def
+        .palette_colors()
+        .get::<_, u32>(ctx)?
+        .map_or(0, |colors| {
+            colors.clamp(MIN_PALETTE_COLORS, MAX_PALETTE_COLORS)
+        });
+    Ok(ImageOptions {
+        width: size.width.max(1),
+        height: size.height.max(1),
+        scale,
+        looping,
+        fps: def.fps().get::<_, f32>(ctx)?.unwrap_or(0.0),
+        sheet: SpriteSheet {
+            columns: def.sheet_columns().get(ctx)?,
+            rows: def.sheet_rows().get(ctx)?,
+            frames: def.sheet_frames().get(ctx)?,
+        },
+        palette_colors,
+    })
+}
+
+pub fn image_output_path() -> SlotPath {
+    SlotPath::parse("output").expect("image output path")
+}
@@ -0,0 +1,5 @@
+//! Image, animated-GIF and sprite-sheet node.
+
+mod image_node;
+
+pub use image_node::{ImageAsset, ImageNode, image_output_path};
@@ -2,12 +2,29 @@
 pub mod button;
 #[cfg(feature = "node-clock")]
 pub mod clock;
+#[cfg(any(
+    feature = "node-filter",
+    feature = "node-fixture",
+    feature = "node-image",
+    feature = "node-text"
+))]
+mod def_read;
+#[cfg(feature = "node-filter")]
+pub mod filter;
 #[cfg(feature = "node-fixture")]
 pub mod fixture;
 #[cfg(feature = "node-fluid")]
 pub mod fluid;
+#[cfg(feature = "node-image")]
+pub mod image;
 pub mod module;
 pub mod output;
+#[cfg(feature = "node-palette")]
+pub mod palette;
+#[cfg(any(feature = "node-text", feature = "node-particle"))]
+mod palette_strip;
+#[cfg(feature = "node-particle")]
+pub mod particle;
 mod placeholder;
 // Always declared — `playlist::playlist_output_path` stays compiled even
 // when `node-playlist` is off (see `playlist/mod.rs`); the `PlaylistNode`
@@ -17,6 +34,8 @@ pub mod playlist;
 pub mod radio;
 #[cfg(feature = "node-shader")]
 pub mod shader;
+#[cfg(feature = "node-text")]
+pub mod text;
 #[cfg(feature = "node-texture")]
 pub mod texture;
 
@@ -24,14 +43,22 @@ pub mod texture;
 pub use button::{ButtonNode, button_down_path, button_held_path, button_up_path};
 #[cfg(feature = "node-clock")]
 pub use clock::{ClockNode, clock_product_path, clock_seconds_path};
+#[cfg(feature = "node-filter")]
+pub use filter::{FilterLutAsset, FilterNode, filter_output_path};
 #[cfg(feature = "node-fixture")]
 pub use fixture::fixture_node::{
     FixtureMap2dSource, FixtureMapping, FixtureNode, fixture_input_path,
 };
 #[cfg(feature = "node-fluid")]
 pub use fluid::{FluidNode, MsaFluidSolver, fluid_emitters_path, fluid_output_path};
+#[cfg(feature = "node-image")]
+pub use image::{ImageAsset, ImageNode, image_output_path};
 pub use module::ModuleNode;
 pub use output::output_node::{OutputNode, output_input_path};
+#[cfg(feature = "node-palette")]
+pub use palette::{PaletteNode, palette_output_path};
+#[cfg(feature = "node-particle")]
+pub use particle::{ParticleNode, particle_output_path};
 pub use placeholder::CorePlaceholderNode;
 pub use playlist::playlist_output_path;
 #[cfg(feature = "node-playlist")]
@@ -42,5 +69,7 @@ pub use radio::{ControlRadioNode, control_radio_input_path, control_radio_output
 pub use shader::compute_shader_node::ComputeShaderNode;
 #[cfg(feature = "node-shader")]
 pub use shader::shader_node::{ShaderNode, shader_output_path};
+#[cfg(feature = "node-text")]
+pub use text::{TextFontAsset, TextNode, text_output_path};
 #[cfg(feature = "node-texture")]
 pub use texture::texture_node::TextureNode;
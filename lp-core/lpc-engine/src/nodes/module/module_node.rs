@@ -19,8 +19,8 @@ use lps_shared::TextureStorageFormat;
 use crate::dataflow::resolver::QueryKey;
 use crate::node::RuntimeStateShape;
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RenderContext, RenderNode, ScopeRef, TickContext, err_ctx,
+    DegradeMode, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RenderContext, RenderNode, ScopeRef, StateExport, TickContext, err_ctx,
 };
 use crate::products::visual::{
     RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest, VisualSampleTarget,
@@ -101,6 +101,15 @@ impl NodeRuntime for ModuleNode {
         Ok(ProduceResult::Produced)
     }
 
+    fn degrade_mode(&self) -> DegradeMode {
+        // A module only mirrors its children; its cost is theirs.
+        DegradeMode::Critical
+    }
+
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -159,7 +168,7 @@ impl RenderNode for ModuleNode {
                 .graphics()
                 .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
             graphics
-                .create_render_target(request.width, request.height)
+                .create_render_target(request.width, request.height, request.format)
                 .map_err(err_ctx("module mirror texture"))?
         };
         self.render_texture_into(product, request, &mut texture, ctx)?;
@@ -7,8 +7,8 @@ use lpc_model::{OutputDefView, Revision, SlotPath, WithRevision};
 
 use crate::dataflow::resolver::QueryKey;
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeResourceInitContext, NodeRuntime, PressureLevel,
-    TickContext, err_ctx,
+    DegradeMode, DestroyCtx, MemPressureCtx, NodeError, NodeResourceInitContext, NodeRuntime,
+    PressureLevel, StateExport, TickContext, err_ctx,
 };
 use crate::products::control::{
     ControlLayout, ControlProduct, ControlRenderRequest, ControlRenderTarget, ControlSampleFormat,
@@ -211,6 +211,17 @@ impl NodeRuntime for OutputNode {
         self.publish_channel_buffer(ctx)
     }
 
+    fn degrade_mode(&self) -> DegradeMode {
+        // A skipped output tick is a frame the lamps never get: the stutter
+        // the budget exists to prevent.
+        DegradeMode::Critical
+    }
+
+    /// The channel buffer is rewritten by the next render.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
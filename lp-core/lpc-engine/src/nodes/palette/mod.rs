@@ -0,0 +1,6 @@
+//! Palette selector node.
+
+mod palette_blend;
+mod palette_node;
+
+pub use palette_node::{PaletteNode, palette_output_path};
@@ -0,0 +1,159 @@
+//! The palette a palette node publishes for one frame: the selection,
+//! crossfaded from the previous one in Oklab and rotated along its length.
+
+use alloc::vec::Vec;
+
+use lpc_model::{Colorspace, Gradient, GradientStop, InterpMethod, MAX_GRADIENT_STOPS};
+
+use crate::color::{linear_srgb_to_oklab, sample_gradient};
+
+/// Stops a blended or rotated palette is resampled to — every stop the
+/// storage allows, so a hard edge softens to a 1/23 ramp at worst.
+pub(crate) const BLEND_STOPS: usize = MAX_GRADIENT_STOPS as usize;
+
+/// `to`, faded in from `from` by `mix` (`0.0` is all `from`) and rotated by
+/// `turns` along its length.
+///
+/// A settled, unrotated palette is published exactly as authored. Anything
+/// else is resampled: each output stop reads both palettes at the rotated
+/// position, converts them to Oklab and lerps there, so a fade through
+/// complementary colors passes through a grey of matching lightness rather
+/// than a dark linear-light midpoint.
+pub(crate) fn palette_frame(
+    from: Option<&Gradient>,
+    to: &Gradient,
+    mix: f32,
+    turns: f32,
+) -> Gradient {
+    let offset = wrap_unit(turns);
+    let from = from.filter(|_| mix < 1.0);
+    if from.is_none() && offset == 0.0 {
+        return to.clone();
+    }
+    let mix = if mix.is_finite() {
+        mix.clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    let stops: Vec<GradientStop> = (0..BLEND_STOPS)
+        .map(|index| {
+            let at = index as f32 / (BLEND_STOPS - 1) as f32;
+            // Read `offset` back along the ramp; `offset` is below one, so a
+            // single lift wraps it, and an unrotated end stays at 1.0.
+            let mut t = at - offset;
+            if t < 0.0 {
+                t += 1.0;
+            }
+            let target = linear_srgb_to_oklab(sample_gradient(to, t));
+            let c = match from {
+                Some(from) => {
+                    let source = linear_srgb_to_oklab(sample_gradient(from, t));
+                    [
+                        source[0] + (target[0] - source[0]) * mix,
+                        source[1] + (target[1] - source[1]) * mix,
+                        source[2] + (target[2] - source[2]) * mix,
+                    ]
+                }
+                None => target,
+            };
+            GradientStop { at, c }
+        })
+        .collect();
+    Gradient {
+        space: Colorspace::Oklab,
+        method: InterpMethod::Linear,
+        stops,
+    }
+}
+
+/// Fold into `[0, 1)`; a non-finite rotation is no rotation.
+fn wrap_unit(value: f32) -> f32 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+    let mut frac = value - (value as i64) as f32;
+    if frac < 0.0 {
+        frac += 1.0;
+    }
+    if frac >= 1.0 {
+        frac = 0.0;
+    }
+    frac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(from: [f32; 3], to: [f32; 3]) -> Gradient {
+        Gradient {
+            space: Colorspace::LinearSrgb,
+            method: InterpMethod::Linear,
+            stops: alloc::vec![
+                GradientStop { at: 0.0, c: from },
+                GradientStop { at: 1.0, c: to },
+            ],
+        }
+    }
+
+    fn close(actual: [f32; 3], expected: [f32; 3]) {
+        for lane in 0..3 {
+            assert!(
+                (actual[lane] - expected[lane]).abs() <= 1e-3,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn a_settled_unrotated_palette_is_published_as_authored() {
+        let red = ramp([1.0, 0.0, 0.0], [0.0, 0.0, 0.0]);
+        let blue = ramp([0.0, 0.0, 1.0], [0.0, 0.0, 0.0]);
+
+        assert_eq!(palette_frame(None, &red, 1.0, 0.0), red);
+        assert_eq!(palette_frame(Some(&blue), &red, 1.0, 2.0), red);
+    }
+
+    #[test]
+    fn a_fade_lerps_in_oklab_between_the_ends() {
+        let red = ramp([1.0, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        let blue = ramp([0.0, 0.0, 1.0], [0.0, 0.0, 1.0]);
+
+        let start = palette_frame(Some(&red), &blue, 0.0, 0.0);
+        assert_eq!(start.space, Colorspace::Oklab);
+        assert_eq!(start.stops.len(), BLEND_STOPS);
+        close(start.stops[0].c, linear_srgb_to_oklab([1.0, 0.0, 0.0]));
+
+        let half = palette_frame(Some(&red), &blue, 0.5, 0.0);
+        let (r, b) = (
+            linear_srgb_to_oklab([1.0, 0.0, 0.0]),
+            linear_srgb_to_oklab([0.0, 0.0, 1.0]),
+        );
+        close(
+            half.stops[BLEND_STOPS / 2].c,
+            [
+                (r[0] + b[0]) / 2.0,
+                (r[1] + b[1]) / 2.0,
+                (r[2] + b[2]) / 2.0,
+            ],
+        );
+    }
+
+    #[test]
+    fn rotation_moves_colors_along_the_ramp_and_wraps() {
+        let ramp = ramp([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let grey = |level: f32| linear_srgb_to_oklab([level; 3]);
+
+        let quarter = palette_frame(None, &ramp, 1.0, 0.25);
+        // Each stop reads the authored ramp a quarter turn back, so black
+        // has moved a quarter along and the ends meet at 0.75.
+        for stop in [0, 6, BLEND_STOPS - 1] {
+            let at = quarter.stops[stop].at;
+            let source = if at >= 0.25 { at - 0.25 } else { at + 0.75 };
+            close(quarter.stops[stop].c, grey(source));
+        }
+        close(quarter.stops[0].c, quarter.stops[BLEND_STOPS - 1].c);
+        // Whole turns are no rotation.
+        assert_eq!(palette_frame(None, &ramp, 1.0, -3.0), ramp);
+    }
+}
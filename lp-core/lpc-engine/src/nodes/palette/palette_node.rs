@@ -0,0 +1,306 @@
+//! Runtime palette node: picks one gradient from its set by index or
+//! trigger, crossfades to it, rotates it, and publishes the result on its
+//! `palette` slot (default-bound to the scope's `palette` channel).
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_collection::VecMap;
+use lpc_model::{
+    ControlMessage, FromLpValue, Gradient, GradientConfig, NodeRuntimeStatus, PaletteDefView,
+    PaletteState, SlotAccess, SlotData, SlotMapKey, SlotPath, SlotShapeRegistry,
+    SlotShapeRegistryError, TimeProduct,
+};
+
+use crate::dataflow::resolver::QueryKey;
+use crate::node::{
+    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RuntimeStateShape, StateExport, TickContext, err_ctx,
+};
+
+use super::palette_blend::palette_frame;
+
+/// A crossfade in progress: what was showing when the selection changed,
+/// and when that was.
+struct Fade {
+    from: Gradient,
+    start_seconds: f32,
+}
+
+/// Runtime node for `kind = "Palette"` artifacts.
+#[derive(Default)]
+pub struct PaletteNode {
+    state: PaletteState,
+    def_view: Option<PaletteDefView>,
+    /// Position of the selection in key order.
+    selected: usize,
+    /// The `index` input as last read; selection follows it only when it
+    /// changes, so a trigger can step away from it.
+    last_index: Option<u32>,
+    /// Last trigger `seq` seen per message id.
+    last_seen: VecMap<u32, u32>,
+    /// The selected gradient, as authored.
+    target: Option<Gradient>,
+    fade: Option<Fade>,
+    /// A palette left out of the set for not being a valid gradient.
+    input_warning: Option<String>,
+}
+
+impl PaletteNode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&PaletteDefView, NodeError> {
+        PaletteDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
+            .map_err(err_ctx("compile palette def view"))
+    }
+
+    /// How far through the current fade `now` is; `1.0` once it is over (or
+    /// when there is none), which also ends it.
+    fn fade_mix(&mut self, now: f32, fade_seconds: f32) -> f32 {
+        let Some(fade) = &mut self.fade else {
+            return 1.0;
+        };
+        // A rewind restarts the fade from here rather than holding it.
+        if now < fade.start_seconds {
+            fade.start_seconds = now;
+        }
+        let mix = (now - fade.start_seconds) / fade_seconds;
+        // Also catches a fade shortened to zero (or a NaN) mid-way.
+        if mix.is_nan() || mix >= 1.0 {
+            self.fade = None;
+            return 1.0;
+        }
+        mix
+    }
+}
+
+impl NodeRuntime for PaletteNode {
+    fn produce(
+        &mut self,
+        _slot: &SlotPath,
+        ctx: &mut TickContext<'_>,
+    ) -> Result<ProduceResult, NodeError> {
+        let def = self.def_view(ctx)?;
+        let index: u32 = def.index().get(ctx)?;
+        let next_trigger: u32 = def.next_trigger().get(ctx)?;
+        let previous_trigger: u32 = def.previous_trigger().get(ctx)?;
+        let fade_seconds: f32 = def.fade_seconds().get(ctx)?;
+        let offset: f32 = def.offset().get(ctx)?;
+        let rotation: f32 = def.rotation().get(ctx)?;
+        let (palettes, skipped) = resolve_palettes(ctx)?;
+        let fired = fired_triggers(ctx, &mut self.last_seen)?;
+
+        self.input_warning = skipped.map(|key| format!("palette {key} is not a valid gradient"));
+        // An empty set keeps publishing whatever was last selected.
+        if palettes.is_empty() {
+            return Ok(ProduceResult::Produced);
+        }
+        let count = palettes.len();
+        if self.last_index != Some(index) {
+            self.last_index = Some(index);
+            self.selected = index as usize % count;
+        }
+        for id in fired {
+            if id == next_trigger {
+                self.selected = (self.selected + 1) % count;
+            } else if id == previous_trigger {
+                self.selected = (self.selected + count - 1) % count;
+            }
+        }
+        // The set may have shrunk under the selection.
+        self.selected %= count;
+        let (active, gradient) = &palettes[self.selected];
+
+        // Fades and rotation follow the scope's time product, so a paused
+        // clock holds both where they are. Only they need it, so an unbound
+        // `bus:time` never blanks a palette that just sits there.
+        let fading = fade_seconds.is_finite() && fade_seconds > 0.0;
+        let changing = self.target.is_some() && self.target.as_ref() != Some(gradient);
+        let now = if rotation != 0.0 || self.fade.is_some() || (fading && changing) {
+            let product: TimeProduct = self.def_view(ctx)?.time().get(ctx)?;
+            ctx.time_product_seconds(product)?
+        } else {
+            0.0
+        };
+
+        // A new selection — or an edit to the selected palette — fades from
+        // exactly what is showing now, mid-fade included.
+        if self.target.as_ref() != Some(gradient) {
+            if let Some(previous) = self.target.take() {
+                let mix = self.fade_mix(now, fade_seconds);
+                let showing = palette_frame(
+                    self.fade.as_ref().map(|fade| &fade.from),
+                    &previous,
+                    mix,
+                    0.0,
+                );
+                self.fade = fading.then_some(Fade {
+                    from: showing,
+                    start_seconds: now,
+                });
+            }
+            self.target = Some(gradient.clone());
+        }
+
+        let mix = self.fade_mix(now, fade_seconds);
+        let palette = GradientConfig::Static(palette_frame(
+            self.fade.as_ref().map(|fade| &fade.from),
+            gradient,
+            mix,
+            offset + rotation * now,
+        ));
+        // Republish only on change: consumers rebake their palette texture
+        // when the revision moves.
+        if *self.state.palette.value() != palette {
+            self.state.palette.set_with_version(ctx.revision(), palette);
+        }
+        if *self.state.active.value() != *active {
+            self.state.active.set_with_version(ctx.revision(), *active);
+        }
+        Ok(ProduceResult::Produced)
+    }
+
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Resets {
+            reason: "selection returns to the `index` input and a running fade is cut",
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        _level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        // Nothing cached: the fade snapshot is one gradient, and dropping
+        // it would jump the palette mid-fade.
+        Ok(())
+    }
+
+    fn runtime_status(&self) -> Option<NodeRuntimeStatus> {
+        self.input_warning.clone().map(NodeRuntimeStatus::Warn)
+    }
+
+    fn runtime_state_slots(&self) -> Option<&dyn SlotAccess> {
+        Some(&self.state)
+    }
+
+    fn register_runtime_state_shapes(
+        &self,
+        registry: &mut SlotShapeRegistry,
+    ) -> Result<(), SlotShapeRegistryError> {
+        PaletteState::register_runtime_state_shape(registry).map(|_| ())
+    }
+}
+
+/// The palette set in key order, and the key of a palette that was left
+/// out for not being a valid gradient (the last one, if several).
+fn resolve_palettes(
+    ctx: &mut TickContext<'_>,
+) -> Result<(Vec<(u32, Gradient)>, Option<u32>), NodeError> {
+    let production = ctx
+        .resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: SlotPath::parse("palettes").expect("palette set path"),
+        })
+        .map_err(|e| NodeError::msg(format!("resolve palette set: {}", e.message)))?;
+    palettes_from_slot_data(production.data())
+}
+
+fn palettes_from_slot_data(
+    data: &SlotData,
+) -> Result<(Vec<(u32, Gradient)>, Option<u32>), NodeError> {
+    let SlotData::Map(map) = data else {
+        return Err(NodeError::msg("palette set resolved to non-map slot data"));
+    };
+    let mut palettes = Vec::with_capacity(map.entries.len());
+    let mut skipped = None;
+    for (key, data) in &map.entries {
+        let SlotMapKey::U32(key) = key else {
+            continue;
+        };
+        let SlotData::Value(value) = data else {
+            return Err(NodeError::msg(format!(
+                "palette {key} resolved to non-value slot data"
+            )));
+        };
+        // A gradient with too few stops cannot be sampled; leave it out of
+        // the set rather than failing the node.
+        match Gradient::from_lp_value(value.value()) {
+            Ok(gradient) if gradient.validate().is_ok() => palettes.push((*key, gradient)),
+            _ => skipped = Some(*key),
+        }
+    }
+    palettes.sort_by_key(|(key, _)| *key);
+    Ok((palettes, skipped))
+}
+
+/// Ids of the trigger messages that are new since the last tick — a first
+/// sighting counts, as it does for the playlist.
+fn fired_triggers(
+    ctx: &mut TickContext<'_>,
+    last_seen: &mut VecMap<u32, u32>,
+) -> Result<Vec<u32>, NodeError> {
+    let production = ctx
+        .resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: SlotPath::parse("trigger").expect("palette trigger slot"),
+        })
+        .map_err(|e| NodeError::msg(format!("resolve palette trigger: {e:?}")))?;
+    let SlotData::Map(map) = production.data() else {
+        return Ok(Vec::new());
+    };
+    let mut fired = Vec::new();
+    for data in map.entries.values() {
+        let SlotData::Value(value) = data else {
+            continue;
+        };
+        let message = ControlMessage::from_lp_value(value.value())
+            .map_err(err_ctx("control message value"))?;
+        if last_seen.insert(message.id(), message.seq()) != Some(message.seq()) {
+            fired.push(message.id());
+        }
+    }
+    Ok(fired)
+}
+
+/// The published palette — the slot `bus:palette` is fed from.
+pub fn palette_output_path() -> SlotPath {
+    SlotPath::parse("palette").expect("palette output path")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lpc_model::{Revision, SlotMapDyn, ToLpValue, WithRevision};
+
+    #[test]
+    fn the_set_is_key_ordered_and_skips_invalid_gradients() {
+        let mut entries = VecMap::new();
+        let mut single_stop = Gradient::default();
+        single_stop.stops.truncate(1);
+        for (key, gradient) in [
+            (7, Gradient::default()),
+            (3, single_stop),
+            (2, Gradient::default()),
+        ] {
+            entries.insert(
+                SlotMapKey::U32(key),
+                SlotData::Value(WithRevision::new(Revision::new(1), gradient.to_lp_value())),
+            );
+        }
+        let data = SlotData::Map(SlotMapDyn::with_revision(Revision::new(1), entries));
+
+        let (palettes, skipped) = palettes_from_slot_data(&data).expect("palettes");
+
+        let keys: Vec<u32> = palettes.iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, [2, 7]);
+        assert_eq!(skipped, Some(3));
+    }
+}
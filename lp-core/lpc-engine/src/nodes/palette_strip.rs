@@ -0,0 +1,93 @@
+//! CPU-side palette for nodes that color their own pixels: the text and
+//! particle nodes bake their `palette` slot into a strip and look colors up
+//! by position, where a shader would sample the same bake as a texture.
+
+use alloc::vec::Vec;
+
+use lpc_model::{GradientConfig, SlotPath, TimeProduct};
+
+use crate::color::palette_eval::{
+    PaletteCyclePosition, palette_cycle_gradients, palette_cycle_position, palette_frame_zero,
+    palette_phasor_config,
+};
+use crate::color::{PALETTE_BAKE_BYTES, bake_gradient_into, bake_gradient_mix_into};
+use crate::dataflow::timebase::PhasorKey;
+use crate::node::{NodeError, TickContext};
+
+/// A baked palette strip, looked up by texel.
+///
+/// Re-baked only when the config or its cycle position changes, so a static
+/// palette bakes once and a cycle once per step (or per fade step).
+#[derive(Default)]
+pub(crate) struct PaletteStrip {
+    /// [`PALETTE_BAKE_BYTES`] of RGBA16 texels, or empty for plain white.
+    texels: Vec<u8>,
+    baked: Option<(GradientConfig, PaletteCyclePosition)>,
+}
+
+impl PaletteStrip {
+    pub(crate) fn bake(&mut self, config: &GradientConfig, position: PaletteCyclePosition) {
+        if self
+            .baked
+            .as_ref()
+            .is_some_and(|(baked, at)| baked == config && *at == position)
+        {
+            return;
+        }
+        let Some((from, to)) = palette_cycle_gradients(config, position) else {
+            self.clear();
+            return;
+        };
+        self.texels.resize(PALETTE_BAKE_BYTES, 0);
+        if position.is_single() {
+            bake_gradient_into(from, &mut self.texels);
+        } else {
+            bake_gradient_mix_into(from, to, position.mix(), &mut self.texels);
+        }
+        self.baked = Some((config.clone(), position));
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.texels = Vec::new();
+        self.baked = None;
+    }
+
+    /// The RGBA16 texel at `index`; white when nothing is baked.
+    pub(crate) fn color(&self, index: u8) -> [u16; 4] {
+        let offset = usize::from(index) * 8;
+        let Some(texel) = self.texels.get(offset..offset + 8) else {
+            return [u16::MAX; 4];
+        };
+        core::array::from_fn(|channel| {
+            u16::from_le_bytes([texel[channel * 2], texel[channel * 2 + 1]])
+        })
+    }
+}
+
+/// Where the node's `palette` config sits in its cycle this tick.
+///
+/// Follows the shader node's provenance rule: a config driven by a bus
+/// channel cycles on that channel's shared integrator, so every reader of
+/// `bus:palette` shows the same entry at the same instant; an authored
+/// config cycles privately.
+pub(crate) fn palette_position(
+    ctx: &mut TickContext<'_>,
+    config: &GradientConfig,
+    product: Option<TimeProduct>,
+) -> Result<PaletteCyclePosition, NodeError> {
+    let Some(product) = product.filter(|_| !matches!(config, GradientConfig::Static(_))) else {
+        return Ok(palette_frame_zero(config));
+    };
+    let node = ctx.node_id();
+    let slot = SlotPath::parse("palette.some").expect("palette path");
+    let key = match ctx.consumed_slot_bus_provenance(&slot) {
+        Some((scope, channel)) => PhasorKey::Shared { scope, channel },
+        None => PhasorKey::Private {
+            node,
+            slot: slot.clone(),
+        },
+    };
+    let (phase, _cycle) =
+        ctx.time_product_phasor(product, &key, &palette_phasor_config(config), (node, &slot))?;
+    Ok(palette_cycle_position(config, phase))
+}
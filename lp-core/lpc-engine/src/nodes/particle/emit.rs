@@ -0,0 +1,127 @@
+//! Launching particles from an emitter.
+
+use core::f32::consts::TAU;
+
+use lpc_model::ParticleEmitter;
+
+use super::Particle;
+
+/// Shortest life a launch may get, so a jittered lifetime never reaches
+/// zero.
+const MIN_LIFE_SECONDS: f32 = 0.01;
+
+/// Xorshift32: deterministic for a given seed on every target, and cheap
+/// enough to call several times per launch.
+#[derive(Clone, Debug)]
+pub struct Rng(u32);
+
+impl Rng {
+    pub fn new(seed: u32) -> Self {
+        // Xorshift sticks at zero; any other state is fine.
+        Self(seed.max(1))
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        x
+    }
+
+    /// Uniform in `0.0..1.0`.
+    pub fn unit(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Uniform in `-1.0..1.0`.
+    pub fn signed(&mut self) -> f32 {
+        self.unit() * 2.0 - 1.0
+    }
+}
+
+/// A new particle from `emitter`: somewhere in its spread disc, heading
+/// somewhere in its cone, with speed and lifetime jittered.
+pub fn launch(emitter: &ParticleEmitter, rng: &mut Rng) -> Particle {
+    let radius = emitter.spread.max(0.0) * libm::sqrtf(rng.unit());
+    let around = TAU * rng.unit();
+    let x = emitter.pos[0] + radius * libm::cosf(around);
+    let y = emitter.pos[1] + radius * libm::sinf(around);
+
+    let [dx, dy] = emitter.dir;
+    let heading = if dx == 0.0 && dy == 0.0 {
+        // No direction: straight up, like the emitter default.
+        -core::f32::consts::FRAC_PI_2
+    } else {
+        libm::atan2f(dy, dx)
+    } + emitter.cone * rng.signed();
+    let jitter = emitter.jitter.clamp(0.0, 1.0);
+    let speed = emitter.speed * (1.0 + jitter * rng.signed());
+    let life = (emitter.lifetime * (1.0 + jitter * rng.signed())).max(MIN_LIFE_SECONDS);
+
+    Particle {
+        x,
+        y,
+        vx: speed * libm::cosf(heading),
+        vy: speed * libm::sinf(heading),
+        age: 0.0,
+        life,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_deterministic_per_seed_and_in_range() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..1000 {
+            let value = a.unit();
+            assert_eq!(value, b.unit());
+            assert!((0.0..1.0).contains(&value));
+        }
+        assert_ne!(Rng::new(7).next_u32(), Rng::new(8).next_u32());
+        assert_ne!(Rng::new(0).next_u32(), 0, "a zero seed still runs");
+    }
+
+    #[test]
+    fn launch_without_cone_or_jitter_follows_the_emitter_exactly() {
+        let emitter = ParticleEmitter {
+            pos: [0.25, 0.75],
+            dir: [1.0, 0.0],
+            cone: 0.0,
+            speed: 2.0,
+            lifetime: 3.0,
+            jitter: 0.0,
+            ..ParticleEmitter::new(1)
+        };
+        let particle = launch(&emitter, &mut Rng::new(1));
+        assert_eq!((particle.x, particle.y), (0.25, 0.75));
+        assert!((particle.vx - 2.0).abs() < 1e-5 && particle.vy.abs() < 1e-5);
+        assert_eq!((particle.age, particle.life), (0.0, 3.0));
+    }
+
+    #[test]
+    fn launch_stays_inside_spread_and_cone() {
+        let emitter = ParticleEmitter {
+            pos: [0.5, 0.5],
+            spread: 0.1,
+            dir: [0.0, -1.0],
+            cone: 0.2,
+            speed: 1.0,
+            jitter: 0.0,
+            ..ParticleEmitter::new(1)
+        };
+        let mut rng = Rng::new(42);
+        for _ in 0..200 {
+            let particle = launch(&emitter, &mut rng);
+            let (dx, dy) = (particle.x - 0.5, particle.y - 0.5);
+            assert!(libm::sqrtf(dx * dx + dy * dy) <= 0.1 + 1e-5);
+            let off_axis = libm::atan2f(particle.vy, particle.vx) + core::f32::consts::FRAC_PI_2;
+            assert!(off_axis.abs() <= 0.2 + 1e-4, "heading {off_axis}");
+        }
+    }
+}
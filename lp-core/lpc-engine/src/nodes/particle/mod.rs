@@ -0,0 +1,12 @@
+//! Particle system node: a fixed-capacity pool, emitters, forces and
+//! palette-colored splatting.
+
+mod emit;
+mod particle_node;
+mod pool;
+mod splat;
+
+pub use emit::{Rng, launch};
+pub use particle_node::{ParticleNode, particle_output_path};
+pub use pool::{Forces, MAX_STEP_SECONDS, NoiseField, Particle, ParticlePool};
+pub use splat::{SplatCanvas, SplatLook};
@@ -0,0 +1,448 @@
+//! Runtime particle node: launches particles from emitters, moves them under
+//! gravity, drag, attractors and a noise field, and splats them onto a
+//! canvas it produces as a visual product.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_collection::VecMap;
+use lp_gfx::TextureHandle;
+use lpc_model::{
+    ControlMessage, Dim2u, FromLpValue, GradientConfig, MAX_PARTICLES, NodeId, NodeRuntimeStatus,
+    ParticleAttractor, ParticleDefView, ParticleEmitter, ParticleState, SlotAccess, SlotData,
+    SlotMapKey, SlotPath, SlotShapeRegistry, SlotShapeRegistryError, TimeProduct, VisualProduct,
+    Xy,
+};
+
+use crate::dataflow::resolver::QueryKey;
+use crate::node::{
+    DegradeMode, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RenderContext, RenderNode, RuntimeStateShape, StateExport, TickContext, err_ctx,
+};
+use crate::nodes::palette_strip::{PaletteStrip, palette_position};
+use crate::products::visual::{
+    Canvas, RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest,
+    VisualSampleTarget, canvas_texture, sample_canvas, write_canvas_texture,
+};
+
+use super::{Forces, NoiseField, ParticlePool, Rng, SplatCanvas, SplatLook, launch};
+
+/// The pool's shape: a change to either rebuilds it empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PoolConfig {
+    capacity: u32,
+    seed: u32,
+}
+
+/// Runtime node for `kind = "Particle"` artifacts.
+pub struct ParticleNode {
+    state: ParticleState,
+    def_view: Option<ParticleDefView>,
+    pool: Option<ParticlePool>,
+    pool_config: Option<PoolConfig>,
+    rng: Rng,
+    /// Fractional launches owed per emitter id, carried between ticks so a
+    /// rate below the frame rate still emits on average.
+    owed: VecMap<u32, f32>,
+    /// Last trigger `seq` seen per message id.
+    last_seen: VecMap<u32, u32>,
+    last_time_seconds: Option<f32>,
+    /// Over budget, the pool steps every `update_divisor`th tick, with the
+    /// time of the ticks it sat out.
+    update_divisor: u32,
+    skipped_updates: u32,
+    input_error: Option<String>,
+    /// How the particles were last splatted: kept so a canvas dropped under
+    /// memory pressure can be redrawn at render time without a tick.
+    look: Option<SplatLook>,
+    canvas: SplatCanvas,
+    palette: PaletteStrip,
+}
+
+impl ParticleNode {
+    pub fn new(node_id: NodeId) -> Self {
+        Self {
+            state: ParticleState::new(VisualProduct::new(node_id, 0)),
+            def_view: None,
+            pool: None,
+            pool_config: None,
+            rng: Rng::new(0),
+            owed: VecMap::new(),
+            last_seen: VecMap::new(),
+            last_time_seconds: None,
+            update_divisor: 1,
+            skipped_updates: 0,
+            input_error: None,
+            look: None,
+            canvas: SplatCanvas::new(),
+            palette: PaletteStrip::default(),
+        }
+    }
+
+    fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&ParticleDefView, NodeError> {
+        ParticleDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
+            .map_err(err_ctx("compile particle def view"))
+    }
+
+    /// Splat the live particles unless the canvas already shows them.
+    fn ensure_canvas(&mut self) {
+        let Some(look) = &self.look else {
+            return;
+        };
+        if self.canvas.is_drawn() {
+            return;
+        }
+        let particles = self.pool.as_ref().map_or(&[][..], ParticlePool::particles);
+        let palette = &self.palette;
+        self.canvas.draw(particles, look, |t| {
+            let [r, g, b, _] = palette.color((t * 255.0) as u8);
+            [r, g, b]
+        });
+    }
+}
+
+impl NodeRuntime for ParticleNode {
+    fn produce(
+        &mut self,
+        _slot: &SlotPath,
+        ctx: &mut TickContext<'_>,
+    ) -> Result<ProduceResult, NodeError> {
+        let def = self.def_view(ctx)?;
+        let size: Dim2u = def.size().get(ctx)?;
+        let pool_config = PoolConfig {
+            capacity: def.capacity().get::<_, u32>(ctx)?.min(MAX_PARTICLES),
+            seed: def.seed().get(ctx)?,
+        };
+        let gravity: Xy = def.gravity().get(ctx)?;
+        let drag: f32 = def.drag().get(ctx)?;
+        let noise_strength: f32 = def.noise_strength().get(ctx)?;
+        let noise_scale: f32 = def.noise_scale().get(ctx)?;
+        let noise_speed: f32 = def.noise_speed().get(ctx)?;
+        let look = SplatLook {
+            width: size.width.max(1),
+            height: size.height.max(1),
+            size_start: def.size_start().get(ctx)?,
+            size_end: def.size_end().get(ctx)?,
+            fade: def.fade().get(ctx)?,
+        };
+        let palette = def.palette().get::<_, GradientConfig>(ctx);
+        // `bus:time` carries a queryable product: the step is the change in
+        // its effective seconds, so a paused clock freezes the particles.
+        let product: TimeProduct = def.time().get(ctx)?;
+        let now = ctx.time_product_seconds(product)?;
+        // Sitting a tick out leaves `last_time_seconds` alone, so the next
+        // step covers the skipped time and the particles keep their pace.
+        // Triggers carry a sequence number and are picked up then too.
+        if self.skipped_updates + 1 < self.update_divisor {
+            self.skipped_updates += 1;
+            self.state
+                .output
+                .set_with_version(ctx.revision(), VisualProduct::new(ctx.node_id(), 0));
+            return Ok(ProduceResult::Produced);
+        }
+        self.skipped_updates = 0;
+        // A palette channel carrying something that is not a palette is
+        // reported and drawn white, like an unbound one.
+        let palette = match palette {
+            Ok(palette) => {
+                self.input_error = None;
+                palette
+            }
+            Err(error) => {
+                self.input_error = Some(format!("palette: {error}"));
+                None
+            }
+        };
+
+        let emitters = resolve_map::<ParticleEmitter>(ctx, "emitters", |emitter, id| {
+            emitter.id = id;
+        })?;
+        let attractors = resolve_map::<ParticleAttractor>(ctx, "attractors", |attractor, id| {
+            attractor.id = id;
+        })?;
+        let fired = fired_triggers(ctx, &mut self.last_seen)?;
+        let position = match &palette {
+            Some(config) => Some((config, palette_position(ctx, config, Some(product))?)),
+            None => None,
+        };
+
+        // A rewind (scrub back, clock reset) holds the particles where they
+        // are rather than replaying them: the simulation is not reversible.
+        let dt = match self.last_time_seconds {
+            Some(last) if now >= last => now - last,
+            _ => 0.0,
+        };
+        self.last_time_seconds = Some(now);
+
+        if self.pool_config != Some(pool_config) {
+            self.pool = None;
+            self.pool_config = Some(pool_config);
+            self.rng = Rng::new(pool_config.seed);
+            self.owed.clear();
+        }
+        // Capacity is allocated here and nowhere else.
+        let pool = self
+            .pool
+            .get_or_insert_with(|| ParticlePool::new(pool_config.capacity as usize));
+        let forces = Forces {
+            gravity: gravity.0,
+            drag,
+            attractors: &attractors,
+            noise: (noise_strength != 0.0).then_some(NoiseField {
+                strength: noise_strength,
+                scale: noise_scale,
+                drift: now * noise_speed,
+                seed: pool_config.seed,
+            }),
+        };
+        pool.step(dt, &forces);
+
+        let mut owed = VecMap::new();
+        for emitter in &emitters {
+            let carried = self.owed.get(&emitter.id).copied().unwrap_or(0.0);
+            let due = carried + emitter.rate.max(0.0) * dt;
+            let mut launches = libm::floorf(due) as u32;
+            owed.insert(emitter.id, due - launches as f32);
+            if fired.contains(&emitter.trigger) {
+                launches = launches.saturating_add(emitter.burst);
+            }
+            for _ in 0..launches {
+                if !pool.spawn(launch(emitter, &mut self.rng)) {
+                    break;
+                }
+            }
+        }
+        self.owed = owed;
+
+        match position {
+            Some((config, position)) => self.palette.bake(config, position),
+            None => self.palette.clear(),
+        }
+        self.look = Some(look);
+        self.canvas.release();
+        self.ensure_canvas();
+
+        self.state
+            .output
+            .set_with_version(ctx.revision(), VisualProduct::new(ctx.node_id(), 0));
+        Ok(ProduceResult::Produced)
+    }
+
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Resets {
+            reason: "live particles and the emitter rng are not carried",
+        }
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        DegradeMode::UpdateRate
+    }
+
+    fn set_degradation(&mut self, divisor: u32) {
+        self.update_divisor = divisor.max(1);
+        self.skipped_updates = 0;
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        // The canvas is a cache, re-splatted from the pool on the next
+        // render. The pool is SIMULATION state: like the fluid solver, only
+        // `Critical` may drop it, and the particles then start over.
+        if level >= PressureLevel::High {
+            self.canvas.release();
+        }
+        if level >= PressureLevel::Critical {
+            self.pool = None;
+            self.pool_config = None;
+            self.owed.clear();
+            self.last_time_seconds = None;
+        }
+        Ok(())
+    }
+
+    fn runtime_status(&self) -> Option<NodeRuntimeStatus> {
+        self.input_error.clone().map(NodeRuntimeStatus::Error)
+    }
+
+    fn runtime_state_slots(&self) -> Option<&dyn SlotAccess> {
+        Some(&self.state)
+    }
+
+    fn register_runtime_state_shapes(
+        &self,
+        registry: &mut SlotShapeRegistry,
+    ) -> Result<(), SlotShapeRegistryError> {
+        ParticleState::register_runtime_state_shape(registry).map(|_| ())
+    }
+
+    fn render_node(&mut self) -> Option<&mut dyn RenderNode> {
+        Some(self)
+    }
+}
+
+impl RenderNode for ParticleNode {
+    fn render_texture(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        _ctx: &mut RenderContext<'_>,
+    ) -> Result<TextureRenderProduct, NodeError> {
+        self.ensure_canvas();
+        canvas_texture(self, request).map_err(err_ctx("particle texture product"))
+    }
+
+    fn render_texture_into(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        write_canvas_texture(self, graphics, request, target)
+            .map_err(err_ctx("particle texture upload"))
+    }
+
+    fn sample_visual_into(
+        &mut self,
+        _product: VisualProduct,
+        request: VisualSampleBufferRequest<'_>,
+        target: VisualSampleTarget<'_>,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        sample_canvas(self, graphics, request, target).map_err(err_ctx("particle sample"))
+    }
+}
+
+impl Canvas for ParticleNode {
+    fn canvas_size(&self) -> Option<(u32, u32)> {
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        (self.canvas.is_drawn() && width > 0 && height > 0).then_some((width, height))
+    }
+
+    fn canvas_texel(&self, x: u32, y: u32) -> [u16; 4] {
+        self.canvas.texel(x, y)
+    }
+}
+
+/// A consumed `u32`-keyed map of native values, with each map key
+/// overriding the value's own `id` (as fluid emitters do).
+fn resolve_map<T: FromLpValue>(
+    ctx: &mut TickContext<'_>,
+    slot: &str,
+    set_id: impl Fn(&mut T, u32),
+) -> Result<Vec<T>, NodeError> {
+    let production = ctx
+        .resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: SlotPath::parse(slot).expect("particle map path"),
+        })
+        .map_err(|e| NodeError::msg(format!("resolve particle {slot}: {}", e.message)))?;
+    values_from_slot_data(production.data(), slot, set_id)
+}
+
+fn values_from_slot_data<T: FromLpValue>(
+    data: &SlotData,
+    slot: &str,
+    set_id: impl Fn(&mut T, u32),
+) -> Result<Vec<T>, NodeError> {
+    let SlotData::Map(map) = data else {
+        return Err(NodeError::msg(format!(
+            "particle {slot} resolved to non-map slot data"
+        )));
+    };
+    let mut values = Vec::with_capacity(map.entries.len());
+    for (key, data) in &map.entries {
+        let SlotData::Value(value) = data else {
+            return Err(NodeError::msg(format!(
+                "particle {slot} {key:?} resolved to non-value slot data"
+            )));
+        };
+        let mut item = T::from_lp_value(value.value()).map_err(|e| {
+            NodeError::msg(format!(
+                "particle {slot} {key:?} has incompatible value: {e}"
+            ))
+        })?;
+        if let SlotMapKey::U32(id) = key {
+            set_id(&mut item, *id);
+        }
+        values.push(item);
+    }
+    Ok(values)
+}
+
+/// Ids of the trigger messages that are new since the last tick — a first
+/// sighting counts, as it does for the playlist.
+fn fired_triggers(
+    ctx: &mut TickContext<'_>,
+    last_seen: &mut VecMap<u32, u32>,
+) -> Result<Vec<u32>, NodeError> {
+    let production = ctx
+        .resolve(&QueryKey::ConsumedSlot {
+            node: ctx.node_id(),
+            slot: SlotPath::parse("trigger").expect("particle trigger slot"),
+        })
+        .map_err(|e| NodeError::msg(format!("resolve particle trigger: {e:?}")))?;
+    let SlotData::Map(map) = production.data() else {
+        return Ok(Vec::new());
+    };
+    let mut fired = Vec::new();
+    for data in map.entries.values() {
+        let SlotData::Value(value) = data else {
+            continue;
+        };
+        let message = ControlMessage::from_lp_value(value.value())
+            .map_err(err_ctx("control message value"))?;
+        if last_seen.insert(message.id(), message.seq()) != Some(message.seq()) {
+            fired.push(message.id());
+        }
+    }
+    Ok(fired)
+}
+
+pub fn particle_output_path() -> SlotPath {
+    SlotPath::parse("output").expect("particle output path")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lpc_model::{Revision, SlotMapDyn, ToLpValue, WithRevision};
+
+    #[test]
+    fn map_keys_override_value_ids() {
+        let mut entries = VecMap::new();
+        entries.insert(
+            SlotMapKey::U32(4),
+            SlotData::Value(WithRevision::new(
+                Revision::new(1),
+                ParticleEmitter::new(9).to_lp_value(),
+            )),
+        );
+        let data = SlotData::Map(SlotMapDyn::with_revision(Revision::new(1), entries));
+
+        let emitters =
+            values_from_slot_data::<ParticleEmitter>(&data, "emitters", |emitter, id| {
+                emitter.id = id;
+            })
+            .expect("emitters");
+
+        assert_eq!(emitters.len(), 1);
+        assert_eq!(emitters[0].id, 4);
+    }
+}
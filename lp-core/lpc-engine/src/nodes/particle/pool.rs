@@ -0,0 +1,280 @@
+//! Fixed-capacity particle pool and the forces that move it.
+
+use alloc::vec::Vec;
+
+use lpc_model::ParticleAttractor;
+use lps_builtins::builtins::lpfn::generative::snoise::snoise3_q32::lpfn_snoise3;
+use lps_q32::{Q32, Vec3Q32};
+
+/// Longest step one tick may take. A stalled frame (or a clock jump) would
+/// otherwise launch everything through the attractors in one leap.
+pub const MAX_STEP_SECONDS: f32 = 0.1;
+
+/// Seed offset for the noise field's `y` channel, so the two axes are
+/// independent fields rather than the same one twice.
+const NOISE_Y_SEED: u32 = 0x9e37_79b9;
+
+/// One live particle, in canvas units (`0.0..=1.0`, `y` down).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    /// Seconds since launch.
+    pub age: f32,
+    /// Seconds the particle lives.
+    pub life: f32,
+}
+
+impl Particle {
+    /// How far through its life the particle is, `0.0..=1.0`.
+    pub fn life_fraction(&self) -> f32 {
+        (self.age / self.life).clamp(0.0, 1.0)
+    }
+}
+
+/// A simplex-noise flow field sampled at each particle.
+#[derive(Clone, Copy, Debug)]
+pub struct NoiseField {
+    /// Peak acceleration.
+    pub strength: f32,
+    /// Noise features across the canvas.
+    pub scale: f32,
+    /// Position along the field's third axis: advancing it drifts the field.
+    pub drift: f32,
+    pub seed: u32,
+}
+
+impl NoiseField {
+    fn acceleration(&self, x: f32, y: f32) -> [f32; 2] {
+        let point = Vec3Q32::new(
+            Q32::from_f32_wrapping(x * self.scale),
+            Q32::from_f32_wrapping(y * self.scale),
+            Q32::from_f32_wrapping(self.drift),
+        );
+        [
+            lpfn_snoise3(point, self.seed).to_f32() * self.strength,
+            lpfn_snoise3(point, self.seed ^ NOISE_Y_SEED).to_f32() * self.strength,
+        ]
+    }
+}
+
+/// Everything acting on the particles this step.
+pub struct Forces<'a> {
+    /// Constant acceleration.
+    pub gravity: [f32; 2],
+    /// Velocity lost per second, as a rate.
+    pub drag: f32,
+    pub attractors: &'a [ParticleAttractor],
+    pub noise: Option<NoiseField>,
+}
+
+impl Forces<'_> {
+    fn acceleration(&self, particle: &Particle) -> [f32; 2] {
+        let [mut ax, mut ay] = self.gravity;
+        for attractor in self.attractors {
+            let dx = attractor.pos[0] - particle.x;
+            let dy = attractor.pos[1] - particle.y;
+            let distance = libm::sqrtf(dx * dx + dy * dy);
+            if distance <= f32::EPSILON || distance >= attractor.radius {
+                continue;
+            }
+            let pull = attractor.strength * (1.0 - distance / attractor.radius) / distance;
+            ax += dx * pull;
+            ay += dy * pull;
+        }
+        if let Some(noise) = &self.noise {
+            let [nx, ny] = noise.acceleration(particle.x, particle.y);
+            ax += nx;
+            ay += ny;
+        }
+        [ax, ay]
+    }
+}
+
+/// The live particles, in a buffer sized once for `capacity`.
+///
+/// Launches past capacity are dropped, never grown into: the pool's memory
+/// is fixed from the moment it is built, which is what lets a device budget
+/// for it.
+pub struct ParticlePool {
+    particles: Vec<Particle>,
+    capacity: usize,
+}
+
+impl ParticlePool {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            particles: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Add `particle` if there is room; `false` when the pool is full.
+    pub fn spawn(&mut self, particle: Particle) -> bool {
+        if self.particles.len() >= self.capacity {
+            return false;
+        }
+        self.particles.push(particle);
+        true
+    }
+
+    /// Age every particle by `dt` seconds, retire the expired ones and move
+    /// the rest under `forces` (semi-implicit Euler).
+    pub fn step(&mut self, dt: f32, forces: &Forces<'_>) {
+        let dt = dt.clamp(0.0, MAX_STEP_SECONDS);
+        let damping = libm::expf(-forces.drag.max(0.0) * dt);
+        let mut index = 0;
+        while index < self.particles.len() {
+            let particle = &mut self.particles[index];
+            particle.age += dt;
+            if particle.age >= particle.life {
+                self.particles.swap_remove(index);
+                continue;
+            }
+            let [ax, ay] = forces.acceleration(particle);
+            particle.vx = (particle.vx + ax * dt) * damping;
+            particle.vy = (particle.vy + ay * dt) * damping;
+            particle.x += particle.vx * dt;
+            particle.y += particle.vy * dt;
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(x: f32, y: f32) -> Particle {
+        Particle {
+            x,
+            y,
+            vx: 0.0,
+            vy: 0.0,
+            age: 0.0,
+            life: 10.0,
+        }
+    }
+
+    fn still() -> Forces<'static> {
+        Forces {
+            gravity: [0.0, 0.0],
+            drag: 0.0,
+            attractors: &[],
+            noise: None,
+        }
+    }
+
+    #[test]
+    fn spawns_past_capacity_are_dropped() {
+        let mut pool = ParticlePool::new(2);
+        assert!(pool.spawn(at(0.0, 0.0)));
+        assert!(pool.spawn(at(0.0, 0.0)));
+        assert!(!pool.spawn(at(0.0, 0.0)));
+        assert_eq!(pool.particles().len(), 2);
+        assert_eq!(pool.capacity(), 2);
+    }
+
+    #[test]
+    fn expired_particles_free_their_slot() {
+        let mut pool = ParticlePool::new(2);
+        pool.spawn(Particle {
+            life: 0.05,
+            ..at(0.0, 0.0)
+        });
+        pool.spawn(at(0.5, 0.5));
+        pool.step(0.06, &still());
+        assert_eq!(pool.particles().len(), 1);
+        assert_eq!(pool.particles()[0].x, 0.5);
+        assert!(pool.spawn(at(0.0, 0.0)));
+    }
+
+    #[test]
+    fn gravity_accelerates_and_drag_slows() {
+        let mut pool = ParticlePool::new(1);
+        pool.spawn(at(0.5, 0.0));
+        let falling = Forces {
+            gravity: [0.0, 1.0],
+            ..still()
+        };
+        pool.step(0.1, &falling);
+        let first = pool.particles()[0];
+        assert!((first.vy - 0.1).abs() < 1e-6);
+        assert!((first.y - 0.01).abs() < 1e-6);
+
+        let mut dragged = ParticlePool::new(1);
+        dragged.spawn(Particle {
+            vx: 1.0,
+            ..at(0.0, 0.0)
+        });
+        dragged.step(
+            0.1,
+            &Forces {
+                drag: 1.0,
+                ..still()
+            },
+        );
+        assert!((dragged.particles()[0].vx - libm::expf(-0.1)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn attractors_pull_within_their_radius_only() {
+        let attractors = [ParticleAttractor {
+            id: 1,
+            pos: [0.5, 0.5],
+            strength: 1.0,
+            radius: 0.25,
+        }];
+        let forces = Forces {
+            attractors: &attractors,
+            ..still()
+        };
+        let mut pool = ParticlePool::new(2);
+        pool.spawn(at(0.3, 0.5));
+        pool.spawn(at(0.0, 0.5));
+        pool.step(0.1, &forces);
+        let near = pool.particles()[0];
+        let far = pool.particles()[1];
+        assert!(near.vx > 0.0 && near.vy == 0.0, "pulled toward the center");
+        assert_eq!(far.vx, 0.0, "out of reach");
+    }
+
+    #[test]
+    fn long_frames_are_capped() {
+        let mut pool = ParticlePool::new(1);
+        pool.spawn(at(0.0, 0.0));
+        pool.step(
+            5.0,
+            &Forces {
+                gravity: [0.0, 1.0],
+                ..still()
+            },
+        );
+        assert_eq!(pool.particles()[0].age, MAX_STEP_SECONDS);
+    }
+
+    #[test]
+    fn noise_field_is_seeded_and_moves_particles() {
+        let field = |seed| NoiseField {
+            strength: 1.0,
+            scale: 3.0,
+            drift: 0.5,
+            seed,
+        };
+        let a = field(1).acceleration(0.37, 0.61);
+        assert_eq!(a, field(1).acceleration(0.37, 0.61));
+        assert_ne!(a, field(2).acceleration(0.37, 0.61));
+        assert_ne!(a[0], a[1], "independent axes");
+        assert!(a.iter().all(|value| value.abs() <= 1.5));
+    }
+}
@@ -0,0 +1,191 @@
+//! Splatting particles onto the node's canvas.
+
+use alloc::vec::Vec;
+
+use super::Particle;
+
+/// How particles look: size and brightness over life.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SplatLook {
+    pub width: u32,
+    pub height: u32,
+    /// Radius in canvas pixels at birth.
+    pub size_start: f32,
+    /// Radius in canvas pixels at death.
+    pub size_end: f32,
+    /// Dim to black over life.
+    pub fade: bool,
+}
+
+/// An additive RGB16 canvas: overlapping particles brighten each other and
+/// clip at full scale.
+#[derive(Default)]
+pub struct SplatCanvas {
+    width: u32,
+    height: u32,
+    /// Three channels per pixel, or empty when released.
+    rgb: Vec<u16>,
+    drawn: bool,
+}
+
+impl SplatCanvas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn is_drawn(&self) -> bool {
+        self.drawn
+    }
+
+    /// Free the pixels; the next [`Self::draw`] allocates them again.
+    pub fn release(&mut self) {
+        self.rgb = Vec::new();
+        self.drawn = false;
+    }
+
+    /// Clear the canvas to `look`'s size and splat every particle, each
+    /// colored by `color` from its life fraction.
+    pub fn draw(
+        &mut self,
+        particles: &[Particle],
+        look: &SplatLook,
+        color: impl Fn(f32) -> [u16; 3],
+    ) {
+        self.width = look.width;
+        self.height = look.height;
+        self.rgb.clear();
+        self.rgb
+            .resize(look.width as usize * look.height as usize * 3, 0);
+        for particle in particles {
+            let t = particle.life_fraction();
+            let radius = look.size_start + (look.size_end - look.size_start) * t;
+            let mut rgb = color(t);
+            if look.fade {
+                let dim = 1.0 - t;
+                rgb = rgb.map(|channel| (f32::from(channel) * dim) as u16);
+            }
+            self.splat(
+                particle.x * look.width as f32,
+                particle.y * look.height as f32,
+                radius,
+                rgb,
+            );
+        }
+        self.drawn = true;
+    }
+
+    /// Opaque RGBA16 at canvas pixel (`x`, `y`).
+    pub fn texel(&self, x: u32, y: u32) -> [u16; 4] {
+        let offset = (y as usize * self.width as usize + x as usize) * 3;
+        match self.rgb.get(offset..offset + 3) {
+            Some(rgb) => [rgb[0], rgb[1], rgb[2], u16::MAX],
+            None => [0, 0, 0, u16::MAX],
+        }
+    }
+
+    /// Add an anti-aliased disc centered at (`cx`, `cy`) in pixels: full
+    /// `rgb` inside `radius`, ramping to nothing over the pixel at its edge.
+    fn splat(&mut self, cx: f32, cy: f32, radius: f32, rgb: [u16; 3]) {
+        let reach = radius.max(0.0) + 0.5;
+        let left = libm::floorf(cx - reach).max(0.0) as u32;
+        let top = libm::floorf(cy - reach).max(0.0) as u32;
+        let right = (libm::ceilf(cx + reach).max(0.0) as u32).min(self.width);
+        let bottom = (libm::ceilf(cy + reach).max(0.0) as u32).min(self.height);
+        for y in top..bottom {
+            let dy = y as f32 + 0.5 - cy;
+            for x in left..right {
+                let dx = x as f32 + 0.5 - cx;
+                let coverage = (reach - libm::sqrtf(dx * dx + dy * dy)).clamp(0.0, 1.0);
+                if coverage <= 0.0 {
+                    continue;
+                }
+                let offset = (y as usize * self.width as usize + x as usize) * 3;
+                for (channel, value) in self.rgb[offset..offset + 3].iter_mut().zip(rgb) {
+                    let add = (f32::from(value) * coverage) as u16;
+                    *channel = channel.saturating_add(add);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: [u16; 3] = [u16::MAX; 3];
+    /// The center of pixel 4 on an 8-pixel axis.
+    const CENTER: f32 = 4.5 / 8.0;
+
+    fn look(fade: bool) -> SplatLook {
+        SplatLook {
+            width: 8,
+            height: 8,
+            size_start: 1.0,
+            size_end: 1.0,
+            fade,
+        }
+    }
+
+    fn particle(x: f32, y: f32, age: f32) -> Particle {
+        Particle {
+            x,
+            y,
+            vx: 0.0,
+            vy: 0.0,
+            age,
+            life: 1.0,
+        }
+    }
+
+    #[test]
+    fn splat_is_bright_at_the_center_and_dark_far_away() {
+        let mut canvas = SplatCanvas::new();
+        canvas.draw(&[particle(CENTER, CENTER, 0.0)], &look(false), |_| WHITE);
+        assert!(canvas.is_drawn());
+        assert_eq!(canvas.texel(4, 4)[0], u16::MAX);
+        assert_eq!(canvas.texel(0, 0), [0, 0, 0, u16::MAX]);
+    }
+
+    #[test]
+    fn overlapping_particles_add_and_clip() {
+        let mut canvas = SplatCanvas::new();
+        let half = [u16::MAX / 2; 3];
+        let one = particle(CENTER, CENTER, 0.0);
+        canvas.draw(&[one], &look(false), |_| half);
+        let single = canvas.texel(4, 4)[0];
+        canvas.draw(&[one, one], &look(false), |_| half);
+        assert_eq!(canvas.texel(4, 4)[0], single * 2);
+        canvas.draw(&[one, one, one], &look(false), |_| half);
+        assert_eq!(canvas.texel(4, 4)[0], u16::MAX);
+    }
+
+    #[test]
+    fn fade_dims_over_life_and_color_follows_life() {
+        let mut canvas = SplatCanvas::new();
+        canvas.draw(&[particle(CENTER, CENTER, 0.5)], &look(true), |t| {
+            [(t * 65535.0) as u16, u16::MAX, 0]
+        });
+        let [r, g, b, _] = canvas.texel(4, 4);
+        assert!((r as i32 - 16383).abs() <= 1, "half-way color, half faded");
+        assert!((g as i32 - 32767).abs() <= 1);
+        assert_eq!(b, 0);
+    }
+
+    #[test]
+    fn released_canvas_reads_black_until_redrawn() {
+        let mut canvas = SplatCanvas::new();
+        canvas.draw(&[particle(CENTER, CENTER, 0.0)], &look(false), |_| WHITE);
+        canvas.release();
+        assert!(!canvas.is_drawn());
+        assert_eq!(canvas.texel(4, 4), [0, 0, 0, u16::MAX]);
+    }
+}
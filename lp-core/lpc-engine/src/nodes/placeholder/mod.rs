@@ -3,7 +3,7 @@
 use alloc::format;
 use lpc_model::{LpFeature, NodeKind, NodeRuntimeStatus};
 
-use crate::node::{DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel};
+use crate::node::{DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, StateExport};
 
 /// Runtime placeholder for synthetic projection nodes and load-error entries.
 ///
@@ -47,6 +47,10 @@ impl NodeRuntime for CorePlaceholderNode {
             .map(|_| NodeRuntimeStatus::Unsupported(Self::unsupported_kind_message(kind)))
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -1,5 +1,6 @@
 //! Runtime playlist node: selects and blends owned visual child entries.
 
+use alloc::boxed::Box;
 use alloc::format;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -14,8 +15,8 @@ use lps_shared::TextureStorageFormat;
 
 use crate::dataflow::resolver::QueryKey;
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RenderContext, RenderNode, RuntimeStateShape, TickContext, err_ctx,
+    DegradeMode, DestroyCtx, MemPressureCtx, MigratedState, NodeError, NodeRuntime, PressureLevel,
+    ProduceResult, RenderContext, RenderNode, RuntimeStateShape, StateExport, TickContext, err_ctx,
 };
 use crate::products::visual::{
     RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest, VisualSampleTarget,
@@ -130,6 +131,17 @@ impl PlaylistNode {
     }
 }
 
+/// A playlist's schedule position, across a hot reload.
+struct PlaylistMigration {
+    current_entry: u32,
+    previous_entry: Option<u32>,
+    switch_time: f32,
+    transition_start_time: f32,
+    transition_duration: f32,
+    last_seen_triggers: VecMap<u32, u32>,
+    pending_activate: Option<u32>,
+}
+
 impl NodeRuntime for PlaylistNode {
     fn produce(
         &mut self,
@@ -232,6 +244,49 @@ impl NodeRuntime for PlaylistNode {
         }
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Carry(Box::new(PlaylistMigration {
+            current_entry: self.current_entry,
+            previous_entry: self.previous_entry,
+            switch_time: self.switch_time,
+            transition_start_time: self.transition_start_time,
+            transition_duration: self.transition_duration,
+            last_seen_triggers: core::mem::take(&mut self.last_seen_triggers),
+            pending_activate: self.pending_activate,
+        }))
+    }
+
+    /// Keep the schedule position across an entry edit. Products are not
+    /// carried — every child was rebuilt under a new id — and are
+    /// re-resolved on the next `produce`, outgoing crossfade side included.
+    /// An active entry the edit removed starts the playlist over at idle.
+    fn import_state(&mut self, state: MigratedState) -> bool {
+        let Ok(state) = state.downcast::<PlaylistMigration>() else {
+            return false;
+        };
+        if self.runtime_entry(state.current_entry).is_none() {
+            return false;
+        }
+        self.current_entry = state.current_entry;
+        self.previous_entry = state
+            .previous_entry
+            .filter(|entry| self.runtime_entry(*entry).is_some());
+        self.switch_time = state.switch_time;
+        self.transition_start_time = state.transition_start_time;
+        self.transition_duration = state.transition_duration;
+        self.last_seen_triggers = state.last_seen_triggers;
+        self.pending_activate = state
+            .pending_activate
+            .filter(|entry| self.runtime_entry(*entry).is_some());
+        true
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        // Entry switching and transitions are timed; a skipped tick would
+        // land a switch late.
+        DegradeMode::Critical
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -293,7 +348,7 @@ impl RenderNode for PlaylistNode {
                 .graphics()
                 .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
             graphics
-                .create_render_target(request.width, request.height)
+                .create_render_target(request.width, request.height, request.format)
                 .map_err(err_ctx("playlist scratch texture"))?
         };
         self.render_texture_into(product, request, &mut texture, ctx)?;
@@ -344,7 +399,7 @@ impl RenderNode for PlaylistNode {
                 .graphics()
                 .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
             graphics
-                .create_render_target(request.width, request.height)
+                .create_render_target(request.width, request.height, request.format)
                 .map_err(err_ctx("playlist previous texture"))?
         };
         let mut active_texture = {
@@ -352,7 +407,7 @@ impl RenderNode for PlaylistNode {
                 .graphics()
                 .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
             graphics
-                .create_render_target(request.width, request.height)
+                .create_render_target(request.width, request.height, request.format)
                 .map_err(err_ctx("playlist active texture"))?
         };
         ctx.render_texture_into(previous, request, &mut previous_texture)?;
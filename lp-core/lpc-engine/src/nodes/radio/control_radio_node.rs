@@ -13,8 +13,8 @@ use lpc_model::{
 
 use crate::dataflow::resolver::QueryKey;
 use crate::node::{
-    DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
-    RuntimeStateShape, TickContext,
+    DegradeMode, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime, PressureLevel, ProduceResult,
+    RuntimeStateShape, StateExport, TickContext,
 };
 
 const CONTROL_MESSAGE_PAYLOAD_LEN: usize = 8;
@@ -274,6 +274,17 @@ impl NodeRuntime for ControlRadioNode {
         Ok(())
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Resets {
+            reason: "the radio is reopened; unsent messages and duplicate history are dropped",
+        }
+    }
+
+    fn degrade_mode(&self) -> DegradeMode {
+        // A skipped tick could drop an incoming control message.
+        DegradeMode::Critical
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         self.device = None;
         self.opened = None;
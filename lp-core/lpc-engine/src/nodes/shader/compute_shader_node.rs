@@ -20,7 +20,7 @@ use lps_shared::LpsValueF32;
 
 use crate::node::{
     AssetRefreshContext, AssetRefreshResult, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime,
-    PressureLevel, ProduceResult, TickContext,
+    PressureLevel, ProduceResult, StateExport, TickContext,
 };
 use crate::shader_abi::compute_desc_from_model_def;
 use lp_gfx::LpComputeShader;
@@ -346,6 +346,12 @@ impl NodeRuntime for ComputeShaderNode {
         Ok(AssetRefreshResult::Refreshed)
     }
 
+    /// The program recompiles from the def; its outputs are rewritten
+    /// every dispatch.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
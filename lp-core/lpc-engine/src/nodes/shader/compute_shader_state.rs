@@ -172,6 +172,9 @@ pub fn shape_for_shader_slot(
         ShaderSlotKind::Palette => Err(ComputeStateError::Unsupported(String::from(
             "a palette slot cannot be produced",
         ))),
+        ShaderSlotKind::Feedback => Err(ComputeStateError::Unsupported(String::from(
+            "a feedback slot cannot be produced",
+        ))),
         ShaderSlotKind::Map => {
             let key = slot.key.data.as_ref().ok_or_else(|| {
                 ComputeStateError::Unsupported(String::from("map slot missing key"))
@@ -237,11 +240,13 @@ fn value_shape_for_ref(
 
 fn empty_data_for_slot(slot: &ShaderSlotDef, revision: Revision) -> SlotData {
     match slot.kind.value() {
-        // `Palette` rides with the scalar kinds only because this function is
-        // infallible and runs before shaping: a produced palette is refused
-        // with a diagnosable error by `shape_for_shader_slot`, which is the
-        // one place that decision belongs.
+        // The texture kinds ride with the scalar kinds only because this
+        // function is infallible and runs before shaping: a produced palette
+        // or feedback slot is refused with a diagnosable error by
+        // `shape_for_shader_slot`, which is the one place that decision
+        // belongs.
         ShaderSlotKind::Palette
+        | ShaderSlotKind::Feedback
         | ShaderSlotKind::Value
         | ShaderSlotKind::Phasor
         | ShaderSlotKind::Seconds => SlotData::Value(WithRevision::new(
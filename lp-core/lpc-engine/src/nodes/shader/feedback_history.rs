@@ -0,0 +1,348 @@
+//! Feedback history: the previous frame a `feedback` uniform samples.
+//!
+//! Every feedback slot owns two render targets of the frame's size. The
+//! shader reads `previous` through its `sampler2D` while `next` receives this
+//! frame's capture — either a GPU-resident copy of the node's own output
+//! ([`LpGraphics::copy_texture`]) or a render of the visual product the slot
+//! is bound to. On the first render of the next frame the two swap, so a
+//! shader reading its own output never samples the texture it is writing.
+//!
+//! # Frames, not renders
+//!
+//! A shader can be rendered more than once per frame — a preview and a
+//! fixture both demanding it, say. Only the **first** render of a frame
+//! swaps and captures; the rest bind the same `previous` and leave `next`
+//! alone, so "last frame" means the last *engine* frame however many
+//! consumers there are. That first render also fixes the history's size: a
+//! different size than last frame reallocates both targets and starts the
+//! history over from black, because there is no honest way to carry a trail
+//! across a resize.
+//!
+//! # Memory
+//!
+//! The history is state, not a cache: dropping it visibly resets every trail.
+//! So it goes only under `Critical` pressure, like a particle pool or a fluid
+//! grid, and costs two frame-sized textures per feedback slot until then.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_gfx::{GfxError, LpGraphics, TextureHandle};
+use lpc_model::Revision;
+use lps_shared::LpsValueF32;
+
+use crate::products::visual::VisualProduct;
+
+/// What a feedback slot captures each frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedbackSource {
+    /// This shader's own output — the unbound default.
+    Own,
+    /// Another node's visual product, rendered at the history's size.
+    Product(VisualProduct),
+}
+
+/// One node's feedback slots and their double buffers.
+pub struct FeedbackHistory {
+    slots: Vec<FeedbackSlot>,
+    /// Size of every buffer, fixed by the first render of the newest frame.
+    size: Option<(u32, u32)>,
+    /// The frame whose first render already swapped and captured.
+    frame: Option<Revision>,
+}
+
+struct FeedbackSlot {
+    name: String,
+    source: FeedbackSource,
+    buffers: Option<FeedbackBuffers>,
+}
+
+struct FeedbackBuffers {
+    previous: TextureHandle,
+    next: TextureHandle,
+    /// Binds `previous`; re-read after every swap.
+    uniform: LpsValueF32,
+    /// Whether `next` holds a finished capture to swap in.
+    captured: bool,
+}
+
+impl FeedbackHistory {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            size: None,
+            frame: None,
+        }
+    }
+
+    /// Reconcile the slot set with what this tick resolved.
+    ///
+    /// A slot whose source changed starts over: another product's history
+    /// is not this one's previous frame.
+    pub fn sync_sources(&mut self, sources: Vec<(String, FeedbackSource)>) {
+        self.slots
+            .retain(|slot| sources.iter().any(|(name, _)| *name == slot.name));
+        for (name, source) in sources {
+            match self.slots.iter_mut().find(|slot| slot.name == name) {
+                Some(slot) if slot.source == source => {}
+                Some(slot) => {
+                    slot.source = source;
+                    slot.buffers = None;
+                }
+                None => self.slots.push(FeedbackSlot {
+                    name,
+                    source,
+                    buffers: None,
+                }),
+            }
+        }
+    }
+
+    /// Whether this node has any feedback slots at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Ready the buffers for a render at `width`×`height` in frame
+    /// `revision`, and say whether this render should capture.
+    ///
+    /// The first call of a frame swaps every captured `next` into `previous`
+    /// and returns `true`; later calls of the same frame change nothing and
+    /// return `false`. See the module docs for why.
+    pub fn begin_frame(
+        &mut self,
+        graphics: &dyn LpGraphics,
+        revision: Revision,
+        width: u32,
+        height: u32,
+    ) -> Result<bool, GfxError> {
+        if self.frame == Some(revision) {
+            return Ok(false);
+        }
+        self.frame = Some(revision);
+        if self.size != Some((width, height)) {
+            self.size = Some((width, height));
+            for slot in &mut self.slots {
+                slot.buffers = None;
+            }
+        }
+        for slot in &mut self.slots {
+            match slot.buffers.as_mut() {
+                Some(buffers) => {
+                    if buffers.captured {
+                        core::mem::swap(&mut buffers.previous, &mut buffers.next);
+                        buffers.uniform = graphics.texture_uniform_value(&buffers.previous)?;
+                        buffers.captured = false;
+                    }
+                }
+                None => slot.buffers = Some(FeedbackBuffers::black(graphics, width, height)?),
+            }
+        }
+        Ok(true)
+    }
+
+    /// The `(name, uniform)` of every slot that has buffers.
+    pub fn uniforms(&self) -> impl Iterator<Item = (&str, &LpsValueF32)> {
+        self.slots.iter().filter_map(|slot| {
+            slot.buffers
+                .as_ref()
+                .map(|buffers| (slot.name.as_str(), &buffers.uniform))
+        })
+    }
+
+    /// How many slots [`Self::capture_target`] can be asked about.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// The source and capture target of slot `index`, when it has buffers.
+    pub fn capture_target(&mut self, index: usize) -> Option<(FeedbackSource, &mut TextureHandle)> {
+        let slot = self.slots.get_mut(index)?;
+        let buffers = slot.buffers.as_mut()?;
+        Some((slot.source, &mut buffers.next))
+    }
+
+    /// Record that slot `index`'s capture finished, so the next frame swaps
+    /// it in. A capture that failed is never marked, and the slot keeps
+    /// showing the frame it already had.
+    pub fn mark_captured(&mut self, index: usize) {
+        if let Some(buffers) = self
+            .slots
+            .get_mut(index)
+            .and_then(|slot| slot.buffers.as_mut())
+        {
+            buffers.captured = true;
+        }
+    }
+
+    /// Drop every buffer, keeping the slot set. The next render starts the
+    /// history over from black.
+    pub fn clear(&mut self) {
+        for slot in &mut self.slots {
+            slot.buffers = None;
+        }
+        self.size = None;
+        self.frame = None;
+    }
+}
+
+impl Default for FeedbackHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FeedbackBuffers {
+    /// Two black targets: the frame before the first one is black.
+    fn black(graphics: &dyn LpGraphics, width: u32, height: u32) -> Result<Self, GfxError> {
+        let mut previous = graphics.create_render_target(
+            width,
+            height,
+            lps_shared::TextureStorageFormat::Rgba16Unorm,
+        )?;
+        graphics.clear_texture(&mut previous)?;
+        let mut next = graphics.create_render_target(
+            width,
+            height,
+            lps_shared::TextureStorageFormat::Rgba16Unorm,
+        )?;
+        graphics.clear_texture(&mut next)?;
+        let uniform = graphics.texture_uniform_value(&previous)?;
+        Ok(Self {
+            previous,
+            next,
+            uniform,
+            captured: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::sync::Arc;
+    use alloc::vec;
+
+    use lpc_model::NodeId;
+
+    use super::*;
+
+    /// A capture shows up one frame later, not in the frame that made it —
+    /// and a second render of the same frame neither swaps nor captures.
+    #[test]
+    fn a_capture_is_read_back_on_the_next_frame_only() {
+        let graphics = graphics();
+        let mut history = FeedbackHistory::new();
+        history.sync_sources(vec![("trail".to_string(), FeedbackSource::Own)]);
+
+        assert!(
+            history
+                .begin_frame(graphics.as_ref(), Revision::new(1), 2, 1)
+                .expect("frame 1")
+        );
+        assert_eq!(previous_texels(&history, graphics.as_ref()), [0u8; 16]);
+        capture(&mut history, graphics.as_ref(), 0x11);
+
+        assert!(
+            !history
+                .begin_frame(graphics.as_ref(), Revision::new(1), 2, 1)
+                .expect("again")
+        );
+        assert_eq!(
+            previous_texels(&history, graphics.as_ref()),
+            [0u8; 16],
+            "the frame that captured still reads the frame before it"
+        );
+
+        assert!(
+            history
+                .begin_frame(graphics.as_ref(), Revision::new(2), 2, 1)
+                .expect("frame 2")
+        );
+        assert_eq!(previous_texels(&history, graphics.as_ref()), [0x11u8; 16]);
+    }
+
+    /// Resizing, switching source, and clearing all start over from black.
+    #[test]
+    fn resize_source_change_and_clear_reset_to_black() {
+        let graphics = graphics();
+        let mut history = FeedbackHistory::new();
+        history.sync_sources(vec![("trail".to_string(), FeedbackSource::Own)]);
+        let lit_frame = |history: &mut FeedbackHistory, revision: i64, width: u32| {
+            history
+                .begin_frame(graphics.as_ref(), Revision::new(revision), width, 1)
+                .expect("frame");
+            capture(history, graphics.as_ref(), 0x22);
+            history
+                .begin_frame(graphics.as_ref(), Revision::new(revision + 1), width, 1)
+                .expect("next frame");
+        };
+
+        lit_frame(&mut history, 1, 2);
+        assert_eq!(previous_texels(&history, graphics.as_ref()), [0x22u8; 16]);
+        history
+            .begin_frame(graphics.as_ref(), Revision::new(3), 4, 1)
+            .expect("resize");
+        assert!(
+            is_black(&history, graphics.as_ref()),
+            "a resize starts over"
+        );
+
+        lit_frame(&mut history, 4, 4);
+        history.sync_sources(vec![(
+            "trail".to_string(),
+            FeedbackSource::Product(VisualProduct::new(NodeId::new(9), 0)),
+        )]);
+        assert_eq!(history.uniforms().count(), 0, "the old history is gone");
+        history
+            .begin_frame(graphics.as_ref(), Revision::new(6), 4, 1)
+            .expect("new source");
+        assert!(is_black(&history, graphics.as_ref()));
+
+        lit_frame(&mut history, 7, 4);
+        history.clear();
+        assert_eq!(history.uniforms().count(), 0);
+        history
+            .begin_frame(graphics.as_ref(), Revision::new(8), 4, 1)
+            .expect("after clear");
+        assert!(is_black(&history, graphics.as_ref()));
+    }
+
+    fn capture(history: &mut FeedbackHistory, graphics: &dyn LpGraphics, byte: u8) {
+        let (_, next) = history.capture_target(0).expect("buffers");
+        let len = next.width() as usize * next.height() as usize * 8;
+        graphics
+            .write_texture(next, &vec![byte; len])
+            .expect("write capture");
+        history.mark_captured(0);
+    }
+
+    fn previous_bytes(history: &FeedbackHistory, graphics: &dyn LpGraphics) -> Vec<u8> {
+        let slot = &history.slots[0];
+        graphics
+            .read_back(&slot.buffers.as_ref().expect("buffers").previous)
+            .expect("read back")
+            .into_bytes()
+    }
+
+    fn previous_texels(history: &FeedbackHistory, graphics: &dyn LpGraphics) -> [u8; 16] {
+        previous_bytes(history, graphics)
+            .try_into()
+            .expect("a 2x1 RGBA16 frame")
+    }
+
+    fn is_black(history: &FeedbackHistory, graphics: &dyn LpGraphics) -> bool {
+        previous_bytes(history, graphics)
+            .iter()
+            .all(|byte| *byte == 0)
+    }
+
+    fn graphics() -> Arc<dyn LpGraphics> {
+        Arc::new(lp_gfx_lpvm::TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))
+    }
+}
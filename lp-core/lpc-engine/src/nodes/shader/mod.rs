@@ -1,8 +1,8 @@
 pub mod compute_materialize;
 pub mod compute_shader_node;
 pub mod compute_shader_state;
+pub mod feedback_history;
 pub mod palette_bake_cache;
-pub mod palette_eval;
 pub mod phasor_eval;
 pub mod shader_input_materialize;
 pub mod shader_node;
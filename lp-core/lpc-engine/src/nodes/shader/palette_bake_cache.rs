@@ -42,13 +42,12 @@ use lp_gfx::{GfxError, LpGraphics, TextureHandle};
 use lpc_model::Gradient;
 use lps_shared::LpsValueF32;
 
+use crate::color::palette_eval::PALETTE_MIX_STEPS;
 use crate::color::{
     PALETTE_BAKE_BYTES, PALETTE_BAKE_FORMAT, PALETTE_BAKE_WIDTH, bake_gradient_into,
     bake_gradient_mix_into,
 };
 
-use super::palette_eval::PALETTE_MIX_STEPS;
-
 /// Strips kept beyond the number of live palette uniforms. See the module
 /// docs — one, deliberately.
 const PALETTE_BAKE_SPARE_STRIPS: usize = 1;
@@ -281,7 +280,7 @@ mod tests {
     use lpc_model::{Colorspace, GradientStop, InterpMethod};
 
     use super::*;
-    use crate::nodes::shader::palette_eval::palette_cycle_position;
+    use crate::color::palette_eval::palette_cycle_position;
     use lpc_model::GradientConfig;
 
     #[test]
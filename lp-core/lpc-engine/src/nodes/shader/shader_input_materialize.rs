@@ -31,10 +31,11 @@ pub fn materialize_shader_input(
         // A palette's uniform is a texture handle, not a value: there is no
         // `LpValue` shape a sampler could be materialized from, and the
         // shader node's bake path answers before this is reached. Saying so
-        // is better than inventing a black texture nobody asked for.
-        ShaderSlotKind::Palette => Err(ShaderInputMaterializeError::ExpectedTexture(String::from(
-            slot_name,
-        ))),
+        // is better than inventing a black texture nobody asked for. A
+        // feedback uniform is a texture the node's own history owns.
+        ShaderSlotKind::Palette | ShaderSlotKind::Feedback => Err(
+            ShaderInputMaterializeError::ExpectedTexture(String::from(slot_name)),
+        ),
     }
 }
 
@@ -42,8 +43,8 @@ pub fn materialize_shader_input(
 pub enum ShaderInputMaterializeError {
     ExpectedValue(String),
     ExpectedMap(String),
-    /// A palette slot reached value materialization. Its uniform is a baked
-    /// texture supplied by the shader node, never an `LpValue`.
+    /// A palette or feedback slot reached value materialization. Its uniform
+    /// is a texture supplied by the shader node, never an `LpValue`.
     ExpectedTexture(String),
     MissingMapping(String),
     MissingKey(String),
@@ -74,7 +75,7 @@ impl core::fmt::Display for ShaderInputMaterializeError {
             Self::ExpectedMap(slot) => write!(f, "shader input {slot:?} expected map data"),
             Self::ExpectedTexture(slot) => write!(
                 f,
-                "shader input {slot:?} is a palette: its uniform is a baked texture, not a value"
+                "shader input {slot:?} is a texture: its uniform is supplied by the shader node, not a value"
             ),
             Self::MissingMapping(slot) => write!(f, "shader map input {slot:?} missing mapping"),
             Self::MissingKey(slot) => write!(f, "shader map input {slot:?} missing key"),
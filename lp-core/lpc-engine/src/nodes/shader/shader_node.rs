@@ -13,39 +13,44 @@
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
 
 use lp_gfx::{
     GfxError, LpShader, ShaderCompileOptions, ShaderCompileStats, ShaderEntrySpace, TextureHandle,
 };
+use lp_shader::{IncludeResolver, LpFsIncludes, Preprocessed, preprocess};
 use lpc_model::{
     AssetLocation, FloatMode, FromLpValue, GradientConfig, MapSlot, NodeId, NodeRuntimeStatus,
     OptionSlot, PhasorConfig, Revision, ShaderDef, ShaderMapKeyDef, ShaderSlotDef, ShaderSlotKind,
     ShaderSlotMappingDef, ShaderSlotMappingKind, ShaderState, ShaderValueShapeRef, SlotAccess,
-    SlotPath, SlotShapeRegistry, SlotShapeRegistryError, TimeProduct, ValueSlot,
+    SlotPath, SlotShapeRegistry, SlotShapeRegistryError, TextureFormat, TimeProduct, ValueSlot,
 };
-use lpc_registry::AssetText;
-use lps_shared::LpsValueF32;
+use lpc_registry::{AssetText, ProjectRegistry};
+use lpfs::LpPath;
+use lps_shared::{LpsValueF32, TextureStorageFormat};
 
+use crate::color::palette_eval::{
+    PaletteCyclePosition, palette_cycle_gradients, palette_cycle_position, palette_frame_zero,
+    palette_phasor_config,
+};
 use crate::dataflow::resolver::{QueryKey, resolver::model_value_to_lps_value_f32};
 use crate::dataflow::timebase::PhasorKey;
 use crate::node::{
-    AssetRefreshContext, AssetRefreshResult, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime,
-    PressureLevel, ProduceResult, RenderContext, RenderNode, RuntimeStateShape, TickContext,
-    err_ctx,
+    AssetRefreshContext, AssetRefreshResult, DegradeMode, DestroyCtx, MemPressureCtx, NodeError,
+    NodeRuntime, PressureLevel, ProduceResult, RenderContext, RenderNode, RuntimeStateShape,
+    StateExport, TickContext, err_ctx,
 };
 use crate::products::visual::{
-    CellProjection, ProductSpaceInfo, RenderTextureRequest, TextureRenderProduct, VisualProduct,
-    VisualSpace, coordinates, resolve_1d_to_2d,
+    CellProjection, ConsumerPolicy, ProductSpaceInfo, RenderTextureRequest, TextureRenderProduct,
+    VisualProduct, VisualSpace, convert_texels, coordinates, resolve_1d_to_2d,
+    texture_storage_format, write_texel,
 };
 use crate::products::visual::{VisualSampleBufferRequest, VisualSampleTarget};
 use crate::shader_abi::uniforms::{VisualUniform, build_uniforms};
 
+use super::feedback_history::{FeedbackHistory, FeedbackSource};
 use super::palette_bake_cache::{PaletteBake, PaletteBakeCache};
-use super::palette_eval::{
-    PaletteCyclePosition, palette_cycle_gradients, palette_cycle_position, palette_frame_zero,
-    palette_phasor_config,
-};
 use super::phasor_eval::{phasor_frame_zero, shape_phasor};
 use super::shader_input_materialize::materialize_shader_input;
 
@@ -72,7 +77,15 @@ pub struct ShaderNode {
     node_id: NodeId,
     source_location: AssetLocation,
     source_revision: Revision,
-    glsl_source: String,
+    /// The source as the compiler sees it: run through the preprocessor,
+    /// with `#include`s loaded from the project when the source was loaded
+    /// or refreshed. A preprocessing failure is kept here and reported by
+    /// the next compile attempt in place of a compiler error.
+    source: Result<Preprocessed, String>,
+    /// Every file `source` pulled in by `#include`, with its registry
+    /// revision when it was read ([`Self::track_includes`]). An edit to any
+    /// of them re-reads and recompiles the source.
+    include_revisions: Vec<(String, Revision)>,
     consumed_slots: MapSlot<String, ShaderSlotDef>,
     /// Authored representation pin, and the compile request it produces.
     /// `None` is Auto — the target's native representation, the state of
@@ -86,6 +99,12 @@ pub struct ShaderNode {
     /// `float_mode`; a change flips `needs_compile`, because the entry a
     /// source must define changes with it.
     space: ShaderEntrySpace,
+    /// Authored output texture format (`ShaderDef::output_format`, absent
+    /// = `Rgba16Unorm`). Part of the entry contract like `space`, so a
+    /// change flips `needs_compile`; consumers learn it from
+    /// [`ProductSpaceInfo::format`]. A request in another format is served
+    /// converted (`render_converted_into`).
+    output_format: TextureStorageFormat,
     /// This shader's authored answer for a 2D consumer, when it is 1D
     /// (`ShaderSpace::OneD { in_2d }`). `None` = `Default` — no opinion,
     /// defer to the consumer's policy. Read from the declaration, never
@@ -136,20 +155,59 @@ pub struct ShaderNode {
     /// ([`PaletteBakeCache`]). Rebuildable: dropped under memory pressure
     /// and re-baked on the next tick.
     palette_cache: PaletteBakeCache,
+    /// Previous-frame buffers for this node's `feedback` uniforms
+    /// ([`FeedbackHistory`]). State, not a cache: only `Critical` pressure
+    /// drops it, because doing so restarts every trail from black.
+    feedback: FeedbackHistory,
+    /// Over budget, texture renders run at `1 / render_divisor` of the
+    /// requested size and are scaled up ([`NodeRuntime::set_degradation`]).
+    render_divisor: u32,
+    /// The small frame a reduced render draws into. Pure cache, kept only so
+    /// a degraded shader does not allocate every frame.
+    reduced_target: Option<TextureHandle>,
+    /// The frame a render at `output_format` draws into before it is
+    /// re-encoded for a consumer that asked for another format. Pure cache,
+    /// like `reduced_target`.
+    format_target: Option<TextureHandle>,
     state: ShaderState,
 }
 
 impl ShaderNode {
-    pub fn new(node_id: NodeId, def: ShaderDef, source: AssetText) -> Self {
+    /// `includes` loads the files named by `#include` in `source`; the
+    /// project loader passes one reading the project filesystem.
+    pub fn new(
+        node_id: NodeId,
+        def: ShaderDef,
+        source: AssetText,
+        includes: &mut dyn IncludeResolver,
+    ) -> Self {
         let visual_uniforms = default_uniforms(&def.consumed_slots);
+        // Until a tick resolves their bindings, feedback slots read this
+        // shader's own output — the unbound default.
+        let mut feedback = FeedbackHistory::new();
+        feedback.sync_sources(
+            def.consumed_slots
+                .entries
+                .iter()
+                .filter(|(_, slot)| matches!(slot.kind.value(), ShaderSlotKind::Feedback))
+                .map(|(name, _)| (name.clone(), FeedbackSource::Own))
+                .collect(),
+        );
         Self {
             node_id,
             source_location: source.location,
             source_revision: source.revision,
-            glsl_source: source.text,
+            source: preprocess_source(&source, includes),
+            include_revisions: Vec::new(),
             consumed_slots: def.consumed_slots,
             float_mode: def.float_mode.data.as_ref().map(|slot| *slot.value()),
             space: entry_space_for(def.space.value()),
+            output_format: def
+                .output_format
+                .data
+                .as_ref()
+                .and_then(|slot| texture_storage_format(*slot.value()))
+                .unwrap_or(TextureStorageFormat::Rgba16Unorm),
             space_answer_2: space_answer_2_for(def.space.value()),
             projected_points: None,
             projected_samples: None,
@@ -162,6 +220,10 @@ impl ShaderNode {
             compile_window_requested: false,
             compile_window: None,
             palette_cache: PaletteBakeCache::new(),
+            feedback,
+            render_divisor: 1,
+            reduced_target: None,
+            format_target: None,
             state: ShaderState::new(VisualProduct::new(node_id, 0)),
         }
     }
@@ -170,6 +232,151 @@ impl ShaderNode {
         self.node_id
     }
 
+    /// Record the registry revision of every file the source `#include`s,
+    /// registering each so filesystem events advance it. The project loader
+    /// calls this once the node is built. A source that failed to
+    /// preprocess keeps the previous list: fixing the include that broke it
+    /// must still be noticed.
+    pub fn track_includes(&mut self, registry: &mut ProjectRegistry, frame: Revision) {
+        let Ok(source) = &self.source else {
+            return;
+        };
+        self.include_revisions = source
+            .included_files()
+            .iter()
+            .map(|path| (path.clone(), registry.track_file(LpPath::new(path), frame)))
+            .collect();
+    }
+
+    /// Render `request` into `target` with the compiled program.
+    fn render_compiled_into(
+        &mut self,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_palette_uniforms(ctx)?;
+        let capture = self.ensure_feedback_uniforms(ctx, request.width, request.height)?;
+        let uniforms = build_uniforms(request.width, request.height, &self.visual_uniforms);
+        if self.declared_space() != request.space {
+            self.render_projected_texture(request, target, &uniforms, ctx)?;
+        } else {
+            let shader = self
+                .shader
+                .as_mut()
+                .ok_or_else(|| NodeError::msg("shader missing after compile"))?;
+            match shader.render(target, &uniforms) {
+                Ok(()) => {}
+                Err(GfxError::FuelExhausted(trap)) => return fuel_exhausted_failure(&trap),
+                Err(error) => return Err(err_ctx("shader render")(error)),
+            }
+        }
+        if capture {
+            self.capture_feedback(Some(&*target), request.time_seconds, &uniforms, ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Over-budget render: draw at `1 / render_divisor` of `request`, then
+    /// scale up into `target` texel by texel. The program sees the small
+    /// size as its `outputSize`, so the picture is the same, only coarser;
+    /// feedback history follows the small size while degraded.
+    fn render_reduced_into(
+        &mut self,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        let reduced = RenderTextureRequest {
+            width: request.width.div_ceil(self.render_divisor),
+            height: request.height.div_ceil(self.render_divisor),
+            ..*request
+        };
+        let mut small = match self.reduced_target.take() {
+            Some(texture)
+                if texture.width() == reduced.width
+                    && texture.height() == reduced.height
+                    && texture.format() == reduced.format =>
+            {
+                texture
+            }
+            _ => ctx
+                .graphics()
+                .ok_or_else(|| NodeError::msg("missing graphics backend"))?
+                .create_render_target(reduced.width, reduced.height, reduced.format)
+                .map_err(err_ctx("create reduced render target"))?,
+        };
+        self.render_compiled_into(&reduced, &mut small, ctx)?;
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let frame = graphics
+            .read_back(&small)
+            .map_err(err_ctx("read back reduced render"))?;
+        let texels = upscale_nearest(
+            frame.bytes(),
+            (reduced.width, reduced.height),
+            (request.width, request.height),
+            request.format.bytes_per_pixel(),
+        );
+        graphics
+            .write_texture(target, &texels)
+            .map_err(err_ctx("write upscaled render"))?;
+        self.reduced_target = Some(small);
+        Ok(())
+    }
+
+    /// Serve a consumer that asks for another storage format than this
+    /// shader's `output_format`: render at the shader's own format, then
+    /// re-encode the frame ([`convert_texels`]). A fixture strip, a filter
+    /// or a playlist asks for RGBA16 whatever the shader under it renders.
+    fn render_converted_into(
+        &mut self,
+        product: VisualProduct,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        let native = RenderTextureRequest {
+            format: self.output_format,
+            ..*request
+        };
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        if !graphics.supports_read_back() {
+            return Err(NodeError::msg(format!(
+                "shader renders {:?} textures, and this backend cannot convert them to {:?}",
+                native.format, request.format
+            )));
+        }
+        let mut frame = match self.format_target.take() {
+            Some(texture)
+                if texture.width() == native.width
+                    && texture.height() == native.height
+                    && texture.format() == native.format =>
+            {
+                texture
+            }
+            _ => graphics
+                .create_render_target(native.width, native.height, native.format)
+                .map_err(err_ctx("create format render target"))?,
+        };
+        self.render_texture_into(product, &native, &mut frame, ctx)?;
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let rendered = graphics
+            .read_back(&frame)
+            .map_err(err_ctx("read back format render"))?;
+        let texels = convert_texels(rendered.bytes(), native.format, request.format);
+        graphics
+            .write_texture(target, &texels)
+            .map_err(err_ctx("write converted render"))?;
+        self.format_target = Some(frame);
+        Ok(())
+    }
+
     pub fn visual_product(&self) -> VisualProduct {
         *self.state.output.value()
     }
@@ -193,9 +400,9 @@ impl ShaderNode {
         self.compilation_error.as_deref()
     }
 
-    fn refresh_source(&mut self, source: AssetText) {
+    fn refresh_source(&mut self, source: AssetText, includes: &mut dyn IncludeResolver) {
         self.source_revision = source.revision;
-        self.glsl_source = source.text;
+        self.source = preprocess_source(&source, includes);
         // Keep-last-good: the old program keeps rendering until the new
         // source compiles; only the stale error is cleared.
         self.needs_compile = true;
@@ -209,6 +416,16 @@ impl ShaderNode {
         if !self.needs_compile {
             return Ok(self.shader.is_some());
         }
+        // A source that failed to preprocess has nothing to compile; its
+        // error takes the compile error's place (keep-last-good).
+        let source = match &self.source {
+            Ok(source) => source,
+            Err(error) => {
+                self.needs_compile = false;
+                self.compilation_error = Some(error.clone());
+                return Ok(self.shader.is_some());
+            }
+        };
 
         // Compile-window deferral (memory-pressure seam). The first render
         // that wants a compile only REQUESTS a window and renders
@@ -236,7 +453,7 @@ impl ShaderNode {
         log::info!(
             "[shader-node] compilation starting (node={:?}, {} bytes)",
             self.node_id,
-            self.glsl_source.len()
+            source.source.len()
         );
         // Recovery frame around the compile: crashes/hangs here are blamed
         // on shader compilation for this node (nested under its NodeRender
@@ -271,11 +488,15 @@ impl ShaderNode {
         let compile_opts = ShaderCompileOptions {
             semantics,
             max_errors: Some(SHADER_COMPILE_MAX_ERRORS),
-            textures: palette_texture_specs(&self.consumed_slots),
+            textures: texture_binding_specs(&self.consumed_slots),
             // The authored space *is* the entry contract: the backend
             // validates (CPU) or splices (GPU) `render_2d` / `render_1d`
             // against it rather than sniffing the source.
             space: self.space,
+            output_format: self.output_format,
+            // Diagnostics name the authored file and line, including ones
+            // inside an `#include`d file.
+            source_map: Some(source.map.clone()),
             ..ShaderCompileOptions::new(semantics, graphics.glsl_frontend())
         };
 
@@ -287,7 +508,7 @@ impl ShaderNode {
         // The `set_oom_context` above is what carries compile attribution into
         // the crash report instead.
         let compile_result = graphics
-            .compile_shader(self.glsl_source.as_str(), &compile_opts)
+            .compile_shader(source.source.as_str(), &compile_opts)
             .map_err(|error| format!("{error}"));
         lpc_shared::backtrace::clear_oom_context();
         let compile_elapsed_ms = compile_start_ms.and_then(|start| ctx.elapsed_ms(start));
@@ -351,6 +572,20 @@ impl ShaderNode {
             self.needs_compile = true;
             self.compilation_error = None;
         }
+        let next_output_format =
+            match try_read_authored_value::<TextureFormat>(ctx, "output_format.some")? {
+                None => TextureStorageFormat::Rgba16Unorm,
+                Some(format) => texture_storage_format(format).ok_or_else(|| {
+                    NodeError::msg(format!(
+                        "shader output_format {format:?} cannot be rendered"
+                    ))
+                })?,
+            };
+        if self.output_format != next_output_format {
+            self.output_format = next_output_format;
+            self.needs_compile = true;
+            self.compilation_error = None;
+        }
         // The answer cell selects a coordinate map at the sampling
         // boundary, so unlike the space variant it never forces a recompile.
         if let Some(next_answer) = try_read_authored_space_answer_2(ctx) {
@@ -417,8 +652,20 @@ impl ShaderNode {
         let mut uniforms = Vec::new();
         let mut failures = Vec::new();
         let mut timebase = TimeProductCache::new();
+        let mut feedback_sources = Vec::new();
         let palette_slots = palette_slot_count(&self.consumed_slots);
         for (name, slot) in &self.consumed_slots.entries {
+            // A feedback uniform's texture belongs to the render path, which
+            // owns the history; the tick only decides what it captures.
+            if matches!(slot.kind.value(), ShaderSlotKind::Feedback) {
+                let own = VisualProduct::new(self.node_id, 0);
+                let (source, failure) = resolve_feedback_source(ctx, name, own);
+                if let Some(failure) = failure {
+                    failures.push((name.clone(), failure));
+                }
+                feedback_sources.push((name.clone(), source));
+                continue;
+            }
             // Palette uniforms are textures, not values: they resolve to a
             // gradient config, bake to a strip, and bind as a sampler. The
             // value path below has no shape that could carry one.
@@ -444,6 +691,7 @@ impl ShaderNode {
             uniforms.push((name.clone(), value));
         }
         self.visual_uniforms = uniforms;
+        self.feedback.sync_sources(feedback_sources);
         note_input_resolve_failures(
             &mut self.input_resolve_failures,
             failures,
@@ -473,7 +721,7 @@ impl ShaderNode {
             .graphics()
             .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
         for (name, slot) in &self.consumed_slots.entries {
-            if !slot.kind.value().is_texture()
+            if !matches!(slot.kind.value(), ShaderSlotKind::Palette)
                 || self
                     .visual_uniforms
                     .iter()
@@ -500,6 +748,104 @@ impl ShaderNode {
         Ok(())
     }
 
+    /// Bind every feedback uniform to its previous frame for a render at
+    /// `width`×`height`, and say whether this render should capture.
+    ///
+    /// Upserted rather than appended: the tick rebuilds `visual_uniforms`
+    /// without them, but a second render of the same frame finds them
+    /// already there, and a swap moves them onto the other buffer.
+    fn ensure_feedback_uniforms(
+        &mut self,
+        ctx: &RenderContext<'_>,
+        width: u32,
+        height: u32,
+    ) -> Result<bool, NodeError> {
+        if self.feedback.is_empty() {
+            return Ok(false);
+        }
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        let capture = self
+            .feedback
+            .begin_frame(graphics, ctx.revision(), width, height)
+            .map_err(err_ctx("allocate feedback history"))?;
+        for (name, value) in self.feedback.uniforms() {
+            match self
+                .visual_uniforms
+                .iter_mut()
+                .find(|(uniform, _)| uniform == name)
+            {
+                Some((_, bound)) => *bound = value.clone(),
+                None => self
+                    .visual_uniforms
+                    .push((String::from(name), value.clone())),
+            }
+        }
+        Ok(capture)
+    }
+
+    /// Capture this frame into every feedback slot's next buffer.
+    ///
+    /// The shader's own output is copied from `rendered` on the GPU-resident
+    /// path when the render produced a texture of the history's shape; the
+    /// sampling path has no texture, so it renders the frame into the buffer
+    /// with the same `uniforms` instead. Another visual product is rendered
+    /// into the buffer through the engine, like any upstream input.
+    fn capture_feedback(
+        &mut self,
+        rendered: Option<&TextureHandle>,
+        time_seconds: f32,
+        uniforms: &LpsValueF32,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        for index in 0..self.feedback.len() {
+            let Some((source, next)) = self.feedback.capture_target(index) else {
+                continue;
+            };
+            match source {
+                FeedbackSource::Own => match rendered {
+                    Some(rendered)
+                        if rendered.width() == next.width()
+                            && rendered.height() == next.height()
+                            && rendered.format() == next.format() =>
+                    {
+                        ctx.graphics()
+                            .ok_or_else(|| NodeError::msg("missing graphics backend"))?
+                            .copy_texture(rendered, next)
+                            .map_err(err_ctx("capture feedback frame"))?;
+                    }
+                    _ => {
+                        let shader = self
+                            .shader
+                            .as_mut()
+                            .ok_or_else(|| NodeError::msg("shader missing after compile"))?;
+                        match shader.render(next, uniforms) {
+                            Ok(()) => {}
+                            Err(GfxError::FuelExhausted(trap)) => {
+                                return fuel_exhausted_failure(&trap);
+                            }
+                            Err(error) => return Err(err_ctx("render feedback frame")(error)),
+                        }
+                    }
+                },
+                FeedbackSource::Product(product) => {
+                    let request = RenderTextureRequest {
+                        width: next.width(),
+                        height: next.height(),
+                        format: next.format(),
+                        time_seconds,
+                        space: VisualSpace::TwoD,
+                        policy: ConsumerPolicy::default(),
+                    };
+                    ctx.render_texture_into(product, &request, next)?;
+                }
+            }
+            self.feedback.mark_captured(index);
+        }
+        Ok(())
+    }
+
     /// The space this shader natively renders in.
     fn declared_space(&self) -> VisualSpace {
         match self.space {
@@ -513,6 +859,7 @@ impl ShaderNode {
         ProductSpaceInfo {
             primary: self.declared_space(),
             in_2d: self.space_answer_2,
+            format: self.output_format,
         }
     }
 
@@ -628,12 +975,6 @@ impl ShaderNode {
                 request.space.label()
             )));
         }
-        if request.format != lps_shared::TextureStorageFormat::Rgba16Unorm {
-            return Err(NodeError::msg(format!(
-                "projected texture fill needs an Rgba16Unorm target, got {:?}",
-                request.format
-            )));
-        }
         let graphics = ctx
             .graphics()
             .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
@@ -686,9 +1027,10 @@ impl ShaderNode {
         let channels = graphics
             .read_sample_out(samples)
             .map_err(err_ctx("read projected texture samples"))?;
-        let mut texels = Vec::with_capacity(channels.len() * 2);
-        for channel in &channels {
-            texels.extend_from_slice(&channel.to_le_bytes());
+        let bpp = request.format.bytes_per_pixel();
+        let mut texels = vec![0u8; channels.len() / 4 * bpp];
+        for (rgba, texel) in channels.chunks_exact(4).zip(texels.chunks_exact_mut(bpp)) {
+            write_texel([rgba[0], rgba[1], rgba[2], rgba[3]], request.format, texel);
         }
         graphics
             .write_texture(target, &texels)
@@ -791,17 +1133,60 @@ impl NodeRuntime for ShaderNode {
             }
         };
 
-        self.refresh_source(source);
+        self.refresh_source(source, &mut LpFsIncludes::new(ctx.fs()));
+        let frame = ctx.revision();
+        self.track_includes(ctx.registry(), frame);
         Ok(AssetRefreshResult::Refreshed)
     }
 
+    fn refresh_file_dependencies(
+        &mut self,
+        ctx: &mut AssetRefreshContext<'_>,
+    ) -> Result<AssetRefreshResult, NodeError> {
+        if self.include_revisions.is_empty() {
+            return Ok(AssetRefreshResult::Unused);
+        }
+        let frame = ctx.revision();
+        let changed = self.include_revisions.iter().any(|(path, revision)| {
+            ctx.registry().track_file(LpPath::new(path), frame) != *revision
+        });
+        if !changed {
+            return Ok(AssetRefreshResult::Unchanged);
+        }
+
+        // The source itself is unchanged, but it is preprocessed again as a
+        // whole: the included text is spliced into it.
+        let source = match ctx.read_asset_text(&self.source_location) {
+            Ok(source) => source,
+            Err(err) => {
+                self.needs_compile = false;
+                self.compilation_error = Some(format!("read shader source: {err:?}"));
+                return Ok(AssetRefreshResult::Refreshed);
+            }
+        };
+        self.refresh_source(source, &mut LpFsIncludes::new(ctx.fs()));
+        self.track_includes(ctx.registry(), frame);
+        Ok(AssetRefreshResult::Refreshed)
+    }
+
+    /// A source edit keeps feedback history (`refresh_asset`); a rebuild
+    /// does not.
+    fn export_state(&mut self) -> StateExport {
+        if self.feedback.is_empty() {
+            return StateExport::Stateless;
+        }
+        StateExport::Resets {
+            reason: "feedback history restarts from a cleared frame",
+        }
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
 
     fn handle_memory_pressure(
         &mut self,
-        _level: PressureLevel,
+        level: PressureLevel,
         _ctx: &mut MemPressureCtx,
     ) -> Result<(), NodeError> {
         // `shader` is the compiled product (keep-last-good contract, not a
@@ -817,6 +1202,14 @@ impl NodeRuntime for ShaderNode {
         // reallocates and rewrites it from the request it is answering.
         drop(self.projected_points.take());
         drop(self.projected_samples.take());
+        drop(self.reduced_target.take());
+        drop(self.format_target.take());
+        // The feedback history is state: dropping it restarts every trail
+        // from black, so only `Critical` may. Its uniforms went with the
+        // palette ones above and are re-bound on the next render either way.
+        if level >= PressureLevel::Critical {
+            self.feedback.clear();
+        }
         Ok(())
     }
 
@@ -824,6 +1217,19 @@ impl NodeRuntime for ShaderNode {
         self.compile_window_requested
     }
 
+    fn degrade_mode(&self) -> DegradeMode {
+        DegradeMode::Resolution
+    }
+
+    /// Applies to texture renders only. A sampled shader already evaluates
+    /// exactly one point per lamp; there is no resolution to give up.
+    fn set_degradation(&mut self, divisor: u32) {
+        self.render_divisor = divisor.max(1);
+        if self.render_divisor == 1 {
+            drop(self.reduced_target.take());
+        }
+    }
+
     fn open_compile_window(&mut self, revision: Revision) {
         // Cleared even if this node is not demanded this frame: an unused
         // window expires, and the node simply re-requests on its next
@@ -858,6 +1264,16 @@ impl NodeRuntime for ShaderNode {
     }
 }
 
+/// Run `source` through the preprocessor under its project path, so
+/// `#include`s resolve relative to it and diagnostics name it.
+fn preprocess_source(
+    source: &AssetText,
+    includes: &mut dyn IncludeResolver,
+) -> Result<Preprocessed, String> {
+    preprocess(&source.text, &source.diagnostic_name, includes)
+        .map_err(|err| format!("shader preprocess: {}", err.render()))
+}
+
 /// The semantics tier to request for a shader's `float_mode` pin.
 ///
 /// Both answers come from the backend rather than from a table here: which
@@ -983,7 +1399,7 @@ pub(super) fn sync_shader_slot_def_from_authored(
     // live palette edit hot-applies — but its result is deliberately NOT
     // folded into `changed`. `changed` means *compile*-affecting, and a
     // palette's compile contract is its `TextureBindingSpec`, which depends
-    // on the slot's KIND alone (`palette_texture_specs`). Or-ing it in would
+    // on the slot's KIND alone (`texture_binding_specs`). Or-ing it in would
     // JIT-recompile the shader on every frame of a color-picker drag, for a
     // spec that is byte-identical before and after.
     if matches!(slot.kind.value(), ShaderSlotKind::Palette) {
@@ -1267,6 +1683,20 @@ where
     true
 }
 
+/// Nearest-texel upscale of a tightly packed `from` frame to `to`.
+fn upscale_nearest(src: &[u8], from: (u32, u32), to: (u32, u32), texel: usize) -> Vec<u8> {
+    let (src_width, src_height) = (from.0 as usize, from.1 as usize);
+    let (dst_width, dst_height) = (to.0 as usize, to.1 as usize);
+    let mut out = Vec::with_capacity(dst_width * dst_height * texel);
+    for y in 0..dst_height {
+        let row = &src[(y * src_height / dst_height) * src_width * texel..][..src_width * texel];
+        for x in 0..dst_width {
+            out.extend_from_slice(&row[(x * src_width / dst_width) * texel..][..texel]);
+        }
+    }
+    out
+}
+
 pub fn shader_output_path() -> SlotPath {
     SlotPath::parse("output").expect("shader output path")
 }
@@ -1283,7 +1713,7 @@ impl RenderNode for ShaderNode {
                 .graphics()
                 .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
             let texture = graphics
-                .create_render_target(request.width, request.height)
+                .create_render_target(request.width, request.height, request.format)
                 .map_err(err_ctx("create_render_target"))?;
             if texture.format() != request.format {
                 return Err(NodeError::msg(format!(
@@ -1340,6 +1770,9 @@ impl RenderNode for ShaderNode {
                 request.height
             )));
         }
+        if request.format != self.output_format {
+            return self.render_converted_into(product, request, target, ctx);
+        }
 
         if !self.ensure_compiled(ctx)? {
             if self.note_black_fallback() {
@@ -1358,20 +1791,15 @@ impl RenderNode for ShaderNode {
                 .map_err(err_ctx("clear render target"))?;
             return Ok(());
         }
-        self.ensure_palette_uniforms(ctx)?;
-        let uniforms = build_uniforms(request.width, request.height, &self.visual_uniforms);
-        if self.declared_space() != request.space {
-            return self.render_projected_texture(request, target, &uniforms, ctx);
-        }
-        let shader = self
-            .shader
-            .as_mut()
-            .ok_or_else(|| NodeError::msg("shader missing after compile"))?;
-        match shader.render(target, &uniforms) {
-            Ok(()) => Ok(()),
-            Err(GfxError::FuelExhausted(trap)) => fuel_exhausted_failure(&trap),
-            Err(error) => Err(err_ctx("shader render")(error)),
+        // The upscale reads the small frame back, which the GPU tier cannot;
+        // a GPU is not where a CPU budget bites anyway.
+        let can_reduce = ctx
+            .graphics()
+            .is_some_and(|graphics| graphics.supports_read_back());
+        if self.render_divisor > 1 && can_reduce {
+            return self.render_reduced_into(request, target, ctx);
         }
+        self.render_compiled_into(request, target, ctx)
     }
 
     fn sample_visual_into(
@@ -1408,23 +1836,34 @@ impl RenderNode for ShaderNode {
             return Ok(());
         }
         self.ensure_palette_uniforms(ctx)?;
+        let capture =
+            self.ensure_feedback_uniforms(ctx, request.output_width, request.output_height)?;
         let uniforms = build_uniforms(
             request.output_width,
             request.output_height,
             &self.visual_uniforms,
         );
         if self.declared_space() != request.space {
-            return self.sample_projected(request, target, &uniforms, ctx);
+            self.sample_projected(request, target, &uniforms, ctx)?;
+        } else {
+            let shader = self
+                .shader
+                .as_mut()
+                .ok_or_else(|| NodeError::msg("shader missing after compile"))?;
+            match shader.sample_rgba16(request.points, target.samples, &uniforms) {
+                Ok(()) => {}
+                Err(GfxError::FuelExhausted(trap)) => return fuel_exhausted_failure(&trap),
+                Err(error) => return Err(err_ctx("shader sample")(error)),
+            }
         }
-        let shader = self
-            .shader
-            .as_mut()
-            .ok_or_else(|| NodeError::msg("shader missing after compile"))?;
-        match shader.sample_rgba16(request.points, target.samples, &uniforms) {
-            Ok(()) => Ok(()),
-            Err(GfxError::FuelExhausted(trap)) => fuel_exhausted_failure(&trap),
-            Err(error) => Err(err_ctx("shader sample")(error)),
+        // Sampling leaves no frame behind to copy, so a feedback shader that
+        // is only ever sampled pays one full render per frame for its
+        // history.
+        if capture {
+            let time_seconds = ctx.time_seconds();
+            self.capture_feedback(None, time_seconds, &uniforms, ctx)?;
         }
+        Ok(())
     }
 
     fn visual_space(
@@ -1487,11 +1926,11 @@ fn default_uniforms(slots: &MapSlot<String, ShaderSlotDef>) -> Vec<VisualUniform
             )),
             ShaderSlotKind::Seconds => Some((name.clone(), LpsValueF32::F32(0.0))),
             // A map declares an array the backend fills from slot data, and a
-            // palette declares a sampler whose strip cannot be allocated
-            // without a graphics backend. The palette's frame-zero answer is
-            // baked on the first render instead
-            // ([`ShaderNode::ensure_palette_uniforms`]).
-            ShaderSlotKind::Map | ShaderSlotKind::Palette => None,
+            // palette or feedback slot declares a sampler whose texture cannot
+            // be allocated without a graphics backend. Both are answered on
+            // the first render instead ([`ShaderNode::ensure_palette_uniforms`],
+            // [`ShaderNode::ensure_feedback_uniforms`]).
+            ShaderSlotKind::Map | ShaderSlotKind::Palette | ShaderSlotKind::Feedback => None,
         })
         .collect()
 }
@@ -1504,11 +1943,12 @@ fn palette_slot_count(slots: &MapSlot<String, ShaderSlotDef>) -> usize {
     slots
         .entries
         .values()
-        .filter(|slot| slot.kind.value().is_texture())
+        .filter(|slot| matches!(slot.kind.value(), ShaderSlotKind::Palette))
         .count()
 }
 
-/// The compile-time texture binding contract for this node's palette slots.
+/// The compile-time texture binding contract for this node's palette and
+/// feedback slots.
 ///
 /// One [`lps_shared::TextureBindingSpec`] per `sampler2D` uniform leaf, keyed
 /// by uniform name — the map `lp-shader` validates the shader's declared
@@ -1532,18 +1972,40 @@ fn palette_slot_count(slots: &MapSlot<String, ShaderSlotDef>) -> usize {
 /// That would be a model change (a new authored field on `ShaderSlotDef`),
 /// and nothing has yet wanted a palette sampled any other way; the one spec
 /// is what makes every baked strip interchangeable across shaders.
-fn palette_texture_specs(slots: &MapSlot<String, ShaderSlotDef>) -> lp_shader::TextureBindingSpecs {
+///
+/// A feedback slot is a whole frame: a general 2D `Rgba16Unorm` texture —
+/// the render-target format the history copies — sampled `Linear` so a
+/// warp or zoom of the previous frame stays smooth, and clamped at the
+/// edges, where wrapping would smear the opposite border into a trail.
+fn texture_binding_specs(slots: &MapSlot<String, ShaderSlotDef>) -> lp_shader::TextureBindingSpecs {
     let mut specs = lp_shader::TextureBindingSpecs::new();
     for (name, slot) in &slots.entries {
-        if slot.kind.value().is_texture() {
-            specs.insert(
-                name.clone(),
-                lp_shader::texture_binding::height_one(
-                    crate::color::PALETTE_BAKE_FORMAT,
-                    lps_shared::TextureFilter::Linear,
-                    lps_shared::TextureWrap::Repeat,
-                ),
-            );
+        match slot.kind.value() {
+            ShaderSlotKind::Palette => {
+                specs.insert(
+                    name.clone(),
+                    lp_shader::texture_binding::height_one(
+                        crate::color::PALETTE_BAKE_FORMAT,
+                        lps_shared::TextureFilter::Linear,
+                        lps_shared::TextureWrap::Repeat,
+                    ),
+                );
+            }
+            ShaderSlotKind::Feedback => {
+                specs.insert(
+                    name.clone(),
+                    lp_shader::texture_binding::texture2d(
+                        lps_shared::TextureStorageFormat::Rgba16Unorm,
+                        lps_shared::TextureFilter::Linear,
+                        lps_shared::TextureWrap::ClampToEdge,
+                        lps_shared::TextureWrap::ClampToEdge,
+                    ),
+                );
+            }
+            ShaderSlotKind::Value
+            | ShaderSlotKind::Map
+            | ShaderSlotKind::Phasor
+            | ShaderSlotKind::Seconds => {}
         }
     }
     specs
@@ -1749,12 +2211,58 @@ fn resolve_palette_input(
     }
 }
 
+/// What a `feedback` uniform captures this tick.
+///
+/// The binding names a visual product; the unbound default is the node's
+/// own output (`read_shader_consumed_slot_default`), and a binding that names
+/// it explicitly means the same thing. Anything that does not resolve to a
+/// visual product falls back to the node's own output, with the reason — a
+/// trail of this shader is the least surprising thing to show for a broken
+/// feedback wire, and the warning says why it is not the wired one.
+fn resolve_feedback_source(
+    ctx: &mut TickContext<'_>,
+    name: &str,
+    own: VisualProduct,
+) -> (FeedbackSource, Option<String>) {
+    let slot = match SlotPath::parse(name) {
+        Ok(path) => path,
+        Err(e) => {
+            return (
+                FeedbackSource::Own,
+                Some(format!("invalid feedback slot {name:?}: {e}")),
+            );
+        }
+    };
+    let production = match ctx.resolve(&QueryKey::ConsumedSlot {
+        node: ctx.node_id(),
+        slot,
+    }) {
+        Ok(production) => production,
+        Err(e) => return (FeedbackSource::Own, Some(e.message)),
+    };
+    match production.value_leaf().map(|value| value.value()) {
+        Some(lpc_model::LpValue::Product(lpc_model::ProductRef::Visual(product))) => {
+            if *product == own {
+                (FeedbackSource::Own, None)
+            } else {
+                (FeedbackSource::Product(*product), None)
+            }
+        }
+        _ => (
+            FeedbackSource::Own,
+            Some(String::from(
+                "feedback binding does not carry a visual product",
+            )),
+        ),
+    }
+}
+
 /// Where a palette config sits in its cycle this tick.
 ///
 /// A static config never queries the timebase — there is one gradient and no
 /// phase to read, so a shader whose palette does not cycle costs no timebase
 /// work at all. A cycle makes exactly **one** query, for the whole set's
-/// pass; see [`palette_eval`](super::palette_eval).
+/// pass; see [`palette_eval`](crate::color::palette_eval).
 fn palette_cycle_position_for(
     ctx: &mut TickContext<'_>,
     config: &GradientConfig,
@@ -1893,11 +2401,15 @@ pub(super) fn resolve_or_default_input(
     match *slot.kind.value() {
         ShaderSlotKind::Seconds => return Ok(resolve_seconds_input(ctx, timebase)),
         ShaderSlotKind::Phasor => return resolve_phasor_input(ctx, name, slot, timebase),
-        // A palette never reaches here from the visual shader node — it takes
-        // the bake path before this function is called, and the compute node
-        // has no palette support yet. The materialize helper below refuses it
-        // by name rather than by silence.
-        ShaderSlotKind::Value | ShaderSlotKind::Map | ShaderSlotKind::Palette => {}
+        // A palette or feedback slot never reaches here from the visual
+        // shader node — each takes its texture path before this function is
+        // called, and the compute node has no texture support yet. The
+        // materialize helper below refuses them by name rather than by
+        // silence.
+        ShaderSlotKind::Value
+        | ShaderSlotKind::Map
+        | ShaderSlotKind::Palette
+        | ShaderSlotKind::Feedback => {}
     }
     let slot_path = SlotPath::parse(name)
         .map_err(|e| NodeError::msg(format!("invalid {context} consumed slot {name:?}: {e}")))?;
@@ -2146,6 +2658,7 @@ mod tests {
     use alloc::vec;
     use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use lp_collection::VecMap;
+    use lp_shader::NoIncludes;
 
     use super::*;
     use crate::dataflow::resolver::ResolveLogLevel;
@@ -2253,7 +2766,12 @@ mod tests {
                 frame,
             )
             .expect("load test defs");
-        let sh = ShaderNode::new(sh_id, shader_def, shader_asset_text(DEMO_GLSL, frame));
+        let sh = ShaderNode::new(
+            sh_id,
+            shader_def,
+            shader_asset_text(DEMO_GLSL, frame),
+            &mut NoIncludes,
+        );
         engine
             .attach_runtime_node(sh_id, Box::new(sh), frame)
             .expect("attach shader");
@@ -2269,6 +2787,7 @@ mod tests {
             NodeId::new(1),
             ShaderDef::default(),
             shader_asset_text("", Revision::new(1)),
+            &mut NoIncludes,
         );
 
         let state = node.runtime_state_slots().expect("shader state slots");
@@ -2349,6 +2868,7 @@ mod tests {
                     sh_id,
                     shader_def_with_time(),
                     shader_asset_text(source, frame),
+                    &mut NoIncludes,
                 )),
                 frame,
             )
@@ -2426,6 +2946,7 @@ mod tests {
             sh_id,
             shader_def_with_time(),
             shader_asset_text(source, frame),
+            &mut NoIncludes,
         );
         engine
             .attach_runtime_node(sh_id, Box::new(sh), frame)
@@ -2500,7 +3021,12 @@ mod tests {
             .consumed_slots
             .entries
             .insert(String::from("speed"), speed);
-        let sh = ShaderNode::new(sh_id, stale, shader_asset_text(source, frame));
+        let sh = ShaderNode::new(
+            sh_id,
+            stale,
+            shader_asset_text(source, frame),
+            &mut NoIncludes,
+        );
         engine
             .attach_runtime_node(sh_id, Box::new(sh), frame)
             .expect("attach shader");
@@ -2549,6 +3075,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn authored_output_format_renders_a_narrower_product() {
+        let source = "float render_2d(vec2 pos) { return 0.5; }";
+        let mut engine = Engine::new(TreePath::parse("/show.t").expect("path"));
+        let mut registry = ProjectRegistry::new();
+        engine.set_graphics(Some(Arc::new(TargetLpvmGraphics::new(
+            lp_shader::ShaderFrontend::LpsGlsl,
+        ))));
+        let frame = Revision::new(1);
+        let root = engine.tree().root();
+        let sh_id = engine
+            .tree_mut()
+            .add_child(
+                root,
+                lpc_model::NodeName::parse("sh").expect("name"),
+                lpc_model::NodeName::parse("shader").expect("ty"),
+                WireChildKind::Input {
+                    source: WireSlotIndex(0),
+                },
+                NodeInvocation::new(ArtifactSpec::path("shader.toml")),
+                frame,
+            )
+            .expect("shader");
+        let def = ShaderDef {
+            output_format: OptionSlot::some(ValueSlot::new(TextureFormat::R8)),
+            ..ShaderDef::default()
+        };
+        engine
+            .load_test_node_defs(
+                &mut registry,
+                &[(sh_id, NodeDef::Shader(def.clone()))],
+                frame,
+            )
+            .expect("load test defs");
+        let sh = ShaderNode::new(
+            sh_id,
+            def,
+            shader_asset_text(source, frame),
+            &mut NoIncludes,
+        );
+        assert_eq!(sh.space_info().format, TextureStorageFormat::R8Unorm);
+        engine
+            .attach_runtime_node(sh_id, Box::new(sh), frame)
+            .expect("attach shader");
+
+        engine.tick(&registry, 500).expect("tick");
+        let q = QueryKey::ProducedSlot {
+            node: sh_id,
+            slot: shader_output_path(),
+        };
+        resolve_with_engine_host(&mut engine, &registry, q, ResolveLogLevel::Off).expect("resolve");
+
+        let mut request = crate::products::visual::RenderTextureRequest {
+            width: 4,
+            height: 4,
+            format: TextureStorageFormat::R8Unorm,
+            time_seconds: 0.5,
+            space: VisualSpace::TwoD,
+            policy: ConsumerPolicy::default(),
+        };
+        // First render requests a compile window (deferral); the second
+        // compiles under the at-most-once progress guarantee.
+        engine
+            .render_texture_for_test(&registry, VisualProduct::new(sh_id, 0), &request)
+            .expect("warm-up render");
+        let texture = engine
+            .render_texture_for_test(&registry, VisualProduct::new(sh_id, 0), &request)
+            .expect("render texture");
+        assert_eq!(texture.storage_format(), TextureStorageFormat::R8Unorm);
+        assert_eq!(texture.try_raw_bytes(), Some(&[128u8; 16][..]));
+
+        // A consumer that still asks for RGBA16 gets the R8 frame widened,
+        // as a sampler would read it.
+        request.format = TextureStorageFormat::Rgba16Unorm;
+        let texture = engine
+            .render_texture_for_test(&registry, VisualProduct::new(sh_id, 0), &request)
+            .expect("an RGBA16 request is served converted");
+        assert_eq!(texture.storage_format(), TextureStorageFormat::Rgba16Unorm);
+        let grey = 32_896u16.to_le_bytes();
+        let white = u16::MAX.to_le_bytes();
+        let texel = [grey, grey, grey, white].concat();
+        let bytes = texture.try_raw_bytes().expect("host texture");
+        assert!(bytes.chunks_exact(8).all(|t| t == texel), "{bytes:?}");
+    }
+
     #[test]
     #[cfg(feature = "node-texture")]
     fn shader_core_visual_product_is_sampleable_red_channel() {
@@ -2614,6 +3225,7 @@ mod tests {
             NodeId::new(1),
             ShaderDef::default(),
             shader_asset_text(source, Revision::new(1)),
+            &mut NoIncludes,
         );
         // The engine opens compile windows during tick; these node-level
         // tests stand in for it so the single render below compiles.
@@ -2667,6 +3279,7 @@ mod tests {
             NodeId::new(1),
             ShaderDef::default(),
             shader_asset_text(source, Revision::new(1)),
+            &mut NoIncludes,
         );
         // The engine opens compile windows during tick; these node-level
         // tests stand in for it so the single render below compiles.
@@ -2875,6 +3488,7 @@ mod tests {
             NodeId::new(1),
             ShaderDef::default(),
             shader_asset_text(DEMO_GLSL, Revision::new(1)),
+            &mut NoIncludes,
         );
         // The engine opens compile windows during tick; these node-level
         // tests stand in for it so the single render below compiles.
@@ -2895,7 +3509,9 @@ mod tests {
             space: VisualSpace::TwoD,
             policy: ConsumerPolicy::default(),
         };
-        let mut texture = graphics.create_render_target(4, 4).expect("texture");
+        let mut texture = graphics
+            .create_render_target(4, 4, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .expect("texture");
 
         node.render_texture_into(product, &request, &mut texture, &mut ctx)
             .expect("initial render");
@@ -2913,7 +3529,10 @@ mod tests {
         // A new revision arrives while the compiler rejects it: the old
         // program keeps rendering and the failure rides the status.
         graphics.set_fail(true);
-        node.refresh_source(shader_asset_text("broken {", Revision::new(2)));
+        node.refresh_source(
+            shader_asset_text("broken {", Revision::new(2)),
+            &mut NoIncludes,
+        );
         node.render_texture_into(product, &request, &mut texture, &mut ctx)
             .expect("render after failed recompile");
         assert_eq!(graphics.compile_count(), 2);
@@ -2943,7 +3562,10 @@ mod tests {
 
         // A fixed revision compiles and swaps in.
         graphics.set_fail(false);
-        node.refresh_source(shader_asset_text(DEMO_GLSL, Revision::new(3)));
+        node.refresh_source(
+            shader_asset_text(DEMO_GLSL, Revision::new(3)),
+            &mut NoIncludes,
+        );
         node.render_texture_into(product, &request, &mut texture, &mut ctx)
             .expect("render after fix");
         assert_eq!(graphics.compile_count(), 3);
@@ -2959,6 +3581,109 @@ mod tests {
         );
     }
 
+    fn sample_center(node: &mut ShaderNode, graphics: &Arc<TargetLpvmGraphics>) -> Vec<u16> {
+        node.open_compile_window(Revision::new(1));
+        let mut ctx = crate::node::RenderContext::new(
+            NodeId::new(1),
+            Revision::new(1),
+            Some(graphics.clone()),
+            None,
+            0.0,
+        );
+        let mut points = graphics.create_sample_points(1).expect("points");
+        graphics
+            .write_sample_points(&mut points, &[5 * 65536, 8 * 65536])
+            .expect("write points");
+        let mut samples = graphics.create_sample_out(1).expect("samples");
+        node.sample_visual_into(
+            VisualProduct::new(NodeId::new(1), 0),
+            VisualSampleBufferRequest {
+                points: &mut points,
+                output_width: 10,
+                output_height: 16,
+                time_seconds: 0.0,
+                space: VisualSpace::TwoD,
+                policy: ConsumerPolicy::default(),
+            },
+            VisualSampleTarget {
+                samples: &mut samples,
+            },
+            &mut ctx,
+        )
+        .expect("sample visual");
+        graphics.read_sample_out(&samples).expect("read samples")
+    }
+
+    #[test]
+    fn shader_source_is_preprocessed_with_project_includes() {
+        let graphics = Arc::new(TargetLpvmGraphics::new(lp_shader::ShaderFrontend::LpsGlsl));
+        let fs = LpFsMemory::new();
+        fs.write_file(
+            "/lib/color.glsl".as_path(),
+            b"#pragma once\nvec4 half_red() { return vec4(0.5, 0.0, 0.0, 1.0); }\n",
+        )
+        .expect("write include");
+        let source = "#include \"lib/color.glsl\"\n\
+                      #define GREEN 0.25\n\
+                      vec4 render_2d(vec2 pos) {\n\
+                      #if defined(GREEN)\n\
+                          return half_red() + vec4(0.0, GREEN, 0.0, 0.0);\n\
+                      #else\n\
+                          return vec4(0.0);\n\
+                      #endif\n\
+                      }\n";
+        let mut node = ShaderNode::new(
+            NodeId::new(1),
+            ShaderDef::default(),
+            shader_asset_text(source, Revision::new(1)),
+            &mut LpFsIncludes::new(&fs),
+        );
+
+        let got = sample_center(&mut node, &graphics);
+        assert!(
+            node.compilation_error().is_none(),
+            "{:?}",
+            node.compilation_error()
+        );
+        assert!((i32::from(got[0]) - 32768).abs() <= 16, "{got:?}");
+        assert!((i32::from(got[1]) - 16384).abs() <= 16, "{got:?}");
+        assert_eq!(got[2], 0);
+    }
+
+    #[test]
+    fn shader_include_errors_name_the_authored_file() {
+        let graphics = Arc::new(TargetLpvmGraphics::new(lp_shader::ShaderFrontend::LpsGlsl));
+        let fs = LpFsMemory::new();
+        fs.write_file(
+            "/lib/broken.glsl".as_path(),
+            b"\nfloat broken() { return missing_name; }\n",
+        )
+        .expect("write include");
+        let source = "#include \"lib/broken.glsl\"\n\
+                      vec4 render_2d(vec2 pos) { return vec4(broken()); }\n";
+        let mut node = ShaderNode::new(
+            NodeId::new(1),
+            ShaderDef::default(),
+            shader_asset_text(source, Revision::new(1)),
+            &mut LpFsIncludes::new(&fs),
+        );
+        sample_center(&mut node, &graphics);
+        let error = node.compilation_error().expect("compile error");
+        assert!(error.contains("/lib/broken.glsl:2:"), "{error}");
+
+        // A missing include fails before the compiler, at the `#include`.
+        let mut node = ShaderNode::new(
+            NodeId::new(1),
+            ShaderDef::default(),
+            shader_asset_text("#include \"lib/absent.glsl\"\n", Revision::new(1)),
+            &mut LpFsIncludes::new(&fs),
+        );
+        sample_center(&mut node, &graphics);
+        let error = node.compilation_error().expect("preprocess error");
+        assert!(error.starts_with("shader preprocess:"), "{error}");
+        assert!(error.contains("/shader.glsl:1:"), "{error}");
+    }
+
     #[test]
     fn shader_compile_failure_is_cached_and_renders_fallback() {
         let graphics = Arc::new(CountingGraphics::failing());
@@ -2966,6 +3691,7 @@ mod tests {
             NodeId::new(1),
             ShaderDef::default(),
             shader_asset_text(DEMO_GLSL, Revision::new(1)),
+            &mut NoIncludes,
         );
         // The engine opens compile windows during tick; these node-level
         // tests stand in for it so the single render below compiles.
@@ -2987,7 +3713,9 @@ mod tests {
             policy: ConsumerPolicy::default(),
         };
 
-        let mut texture = graphics.create_render_target(4, 4).expect("texture");
+        let mut texture = graphics
+            .create_render_target(4, 4, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .expect("texture");
         for _ in 0..3 {
             node.render_texture_into(product, &request, &mut texture, &mut ctx)
                 .expect("fallback render");
@@ -3065,6 +3793,7 @@ mod tests {
                 NodeId::new(1),
                 def,
                 shader_asset_text(DEMO_GLSL, Revision::new(1)),
+                &mut NoIncludes,
             );
             // The engine opens compile windows during tick; these node-level
             // tests stand in for it so the single render below compiles.
@@ -3084,7 +3813,9 @@ mod tests {
                 space: VisualSpace::TwoD,
                 policy: ConsumerPolicy::default(),
             };
-            let mut texture = graphics.create_render_target(4, 4).expect("texture");
+            let mut texture = graphics
+                .create_render_target(4, 4, lps_shared::TextureStorageFormat::Rgba16Unorm)
+                .expect("texture");
             node.render_texture_into(
                 VisualProduct::new(NodeId::new(1), 0),
                 &request,
@@ -3167,6 +3898,7 @@ mod tests {
             NodeId::new(1),
             def,
             shader_asset_text(DEMO_GLSL, Revision::new(1)),
+            &mut NoIncludes,
         )
     }
 
@@ -3266,6 +3998,7 @@ mod tests {
             NodeId::new(1),
             def,
             shader_asset_text(DEMO_GLSL, Revision::new(1)),
+            &mut NoIncludes,
         );
         // The engine opens compile windows during tick; these node-level
         // tests stand in for it so the single render below compiles.
@@ -3285,7 +4018,9 @@ mod tests {
             space: VisualSpace::TwoD,
             policy: ConsumerPolicy::default(),
         };
-        let mut texture = graphics.create_render_target(4, 4).expect("texture");
+        let mut texture = graphics
+            .create_render_target(4, 4, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .expect("texture");
         node.render_texture_into(
             VisualProduct::new(NodeId::new(1), 0),
             &request,
@@ -3424,8 +4159,13 @@ mod tests {
             self.inner.float_semantics()
         }
 
-        fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError> {
-            self.inner.create_render_target(width, height)
+        fn create_render_target(
+            &self,
+            width: u32,
+            height: u32,
+            format: TextureStorageFormat,
+        ) -> Result<TextureHandle, GfxError> {
+            self.inner.create_render_target(width, height, format)
         }
 
         fn create_texture(
@@ -3460,6 +4200,14 @@ mod tests {
             self.inner.blend_textures(previous, active, alpha, target)
         }
 
+        fn copy_texture(
+            &self,
+            source: &TextureHandle,
+            target: &mut TextureHandle,
+        ) -> Result<(), GfxError> {
+            self.inner.copy_texture(source, target)
+        }
+
         fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError> {
             self.inner.read_back(texture)
         }
@@ -0,0 +1,5 @@
+//! Bitmap-font text node.
+
+mod text_node;
+
+pub use text_node::{TextFontAsset, TextNode, text_output_path};
@@ -0,0 +1,365 @@
+//! Runtime text node: draws a string in a bitmap font onto a fixed canvas,
+//! colored from a palette, and produces it as a visual product.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use lp_gfx::TextureHandle;
+use lpc_font::{
+    BdfFont, BuiltinFont, Font, PALETTE_STEPS, TextAlign, TextCanvas, TextColoring, TextOptions,
+    TextScroll,
+};
+use lpc_model::{
+    AssetLocation, Dim2u, GradientConfig, NodeId, NodeRuntimeStatus, Revision, SlotAccess,
+    SlotPath, SlotShapeRegistry, SlotShapeRegistryError, TextDefView, TextState, TimeProduct,
+    VisualProduct,
+};
+
+use crate::color::PALETTE_BAKE_WIDTH;
+use crate::node::{
+    AssetRefreshContext, AssetRefreshResult, DestroyCtx, MemPressureCtx, NodeError, NodeRuntime,
+    PressureLevel, ProduceResult, RenderContext, RenderNode, RuntimeStateShape, StateExport,
+    TickContext, err_ctx,
+};
+use crate::nodes::def_read::authored_variant;
+use crate::nodes::palette_strip::{PaletteStrip, palette_position};
+use crate::products::visual::{
+    Canvas, RenderTextureRequest, TextureRenderProduct, VisualSampleBufferRequest,
+    VisualSampleTarget, canvas_texture, sample_canvas, write_canvas_texture,
+};
+
+// A canvas pixel names a palette position by texel of the baked strip.
+const _: () = assert!(PALETTE_BAKE_WIDTH == PALETTE_STEPS);
+
+/// A BDF file a text node draws with, read whole at load.
+pub struct TextFontAsset {
+    pub location: AssetLocation,
+    pub bytes: Vec<u8>,
+    pub revision: Revision,
+}
+
+/// Which font the def asks for this frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FontChoice {
+    Builtin(BuiltinFont),
+    Bdf,
+}
+
+/// What the canvas shows: kept so a canvas dropped under memory pressure
+/// can be redrawn at render time without a tick.
+struct Frame {
+    text: String,
+    options: TextOptions,
+    seconds: f32,
+    font: FontChoice,
+}
+
+/// Runtime node for `kind = "Text"` artifacts.
+pub struct TextNode {
+    state: TextState,
+    def_view: Option<TextDefView>,
+    /// Location and revision of the BDF file, when the def names one. The
+    /// bytes themselves are dropped once parsed.
+    font_file: Option<(AssetLocation, Revision)>,
+    /// The parsed BDF file; kept across a failed re-parse (keep-last-good).
+    bdf: Option<Font>,
+    font_error: Option<String>,
+    input_error: Option<String>,
+    frame: Option<Frame>,
+    /// Font the canvas was last drawn in: the canvas cannot tell two fonts
+    /// apart by itself.
+    drawn_font: Option<FontChoice>,
+    canvas: TextCanvas,
+    palette: PaletteStrip,
+}
+
+impl TextNode {
+    pub fn new(node_id: NodeId, font_file: Option<TextFontAsset>) -> Self {
+        let mut node = Self {
+            state: TextState::new(VisualProduct::new(node_id, 0)),
+            def_view: None,
+            font_file: None,
+            bdf: None,
+            font_error: None,
+            input_error: None,
+            frame: None,
+            drawn_font: None,
+            canvas: TextCanvas::new(),
+            palette: PaletteStrip::default(),
+        };
+        if let Some(asset) = font_file {
+            node.font_file = Some((asset.location, asset.revision));
+            node.parse_font(&asset.bytes);
+        }
+        node
+    }
+
+    fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&TextDefView, NodeError> {
+        TextDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
+            .map_err(err_ctx("compile text def view"))
+    }
+
+    fn parse_font(&mut self, bytes: &[u8]) {
+        match BdfFont::parse(bytes) {
+            Ok(font) => {
+                self.bdf = Some(Font::Bdf(font));
+                self.font_error = None;
+            }
+            Err(error) => self.font_error = Some(format!("parse font: {error}")),
+        }
+        self.drawn_font = None;
+    }
+
+    /// Draw the current frame into the canvas unless it is already there.
+    fn ensure_canvas(&mut self) {
+        let Some(frame) = &self.frame else {
+            return;
+        };
+        if self.drawn_font != Some(frame.font) {
+            self.canvas.invalidate();
+            self.drawn_font = Some(frame.font);
+        }
+        let builtin;
+        let font = match (frame.font, &self.bdf) {
+            (FontChoice::Bdf, Some(bdf)) => bdf,
+            (FontChoice::Builtin(font), _) => {
+                builtin = Font::Builtin(font);
+                &builtin
+            }
+            // A BDF file that never parsed: the error is on the status,
+            // and the default font keeps the message readable meanwhile.
+            (FontChoice::Bdf, None) => {
+                builtin = Font::default();
+                &builtin
+            }
+        };
+        self.canvas
+            .render(font, &frame.text, &frame.options, frame.seconds);
+    }
+}
+
+impl Canvas for TextNode {
+    fn canvas_size(&self) -> Option<(u32, u32)> {
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        (self.canvas.is_drawn() && width > 0 && height > 0).then_some((width, height))
+    }
+
+    /// Paper is transparent.
+    fn canvas_texel(&self, x: u32, y: u32) -> [u16; 4] {
+        self.canvas
+            .palette_index(x, y)
+            .map_or([0; 4], |index| self.palette.color(index))
+    }
+}
+
+impl NodeRuntime for TextNode {
+    fn produce(
+        &mut self,
+        _slot: &SlotPath,
+        ctx: &mut TickContext<'_>,
+    ) -> Result<ProduceResult, NodeError> {
+        let options = read_options(self.def_view(ctx)?, ctx)?;
+        let text: String = self.def_view(ctx)?.text().get(ctx)?;
+        let font = match authored_variant(ctx, "font").as_deref() {
+            Some("Tiny3x5") => FontChoice::Builtin(BuiltinFont::Tiny3x5),
+            Some("Bdf") => FontChoice::Bdf,
+            _ => FontChoice::Builtin(BuiltinFont::Classic5x8),
+        };
+        // A palette channel carrying something that is not a palette is
+        // reported and drawn white, like an unbound one.
+        let palette = match self.def_view(ctx)?.palette().get::<_, GradientConfig>(ctx) {
+            Ok(palette) => {
+                self.input_error = None;
+                palette
+            }
+            Err(error) => {
+                self.input_error = Some(format!("palette: {error}"));
+                None
+            }
+        };
+
+        // Only a moving marquee or a cycling palette needs the clock, so an
+        // unbound `bus:time` never blanks a still sign.
+        let scrolling =
+            options.scroll != TextScroll::None && options.speed.is_finite() && options.speed > 0.0;
+        let cycling = palette
+            .as_ref()
+            .is_some_and(|config| !matches!(config, GradientConfig::Static(_)));
+        let product: Option<TimeProduct> = if scrolling || cycling {
+            Some(self.def_view(ctx)?.time().get(ctx)?)
+        } else {
+            None
+        };
+        let seconds = match product {
+            Some(product) if scrolling => ctx.time_product_seconds(product)?,
+            _ => 0.0,
+        };
+        match &palette {
+            Some(config) => {
+                let position = palette_position(ctx, config, product)?;
+                self.palette.bake(config, position);
+            }
+            None => self.palette.clear(),
+        }
+
+        self.frame = Some(Frame {
+            text,
+            options,
+            seconds,
+            font,
+        });
+        self.ensure_canvas();
+
+        self.state
+            .output
+            .set_with_version(ctx.revision(), VisualProduct::new(ctx.node_id(), 0));
+        Ok(ProduceResult::Produced)
+    }
+
+    fn refresh_asset(
+        &mut self,
+        location: &AssetLocation,
+        ctx: &mut AssetRefreshContext<'_>,
+    ) -> Result<AssetRefreshResult, NodeError> {
+        let Some((font_location, revision)) = &mut self.font_file else {
+            return Ok(AssetRefreshResult::Unused);
+        };
+        if location != font_location {
+            return Ok(AssetRefreshResult::Unused);
+        }
+        match ctx.read_asset_bytes_if_changed(location, *revision) {
+            Ok(Some(asset)) => {
+                *revision = asset.revision;
+                self.parse_font(&asset.bytes);
+                Ok(AssetRefreshResult::Refreshed)
+            }
+            Ok(None) => Ok(AssetRefreshResult::Unchanged),
+            Err(err) => {
+                // Keep-last-good: the old font still draws.
+                self.font_error = Some(format!("read font: {err:?}"));
+                Ok(AssetRefreshResult::Refreshed)
+            }
+        }
+    }
+
+    /// Scroll and palette cycling follow the time input; the canvas is a
+    /// cache of the drawn frame.
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
+    fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
+        Ok(())
+    }
+
+    fn handle_memory_pressure(
+        &mut self,
+        level: PressureLevel,
+        _ctx: &mut MemPressureCtx,
+    ) -> Result<(), NodeError> {
+        // The canvas is redrawn from the kept frame on the next render. The
+        // parsed font stays: re-reading it would need the file again.
+        if level >= PressureLevel::High {
+            self.canvas.release();
+        }
+        Ok(())
+    }
+
+    fn runtime_status(&self) -> Option<NodeRuntimeStatus> {
+        self.font_error
+            .clone()
+            .or_else(|| self.input_error.clone())
+            .map(NodeRuntimeStatus::Error)
+    }
+
+    fn runtime_state_slots(&self) -> Option<&dyn SlotAccess> {
+        Some(&self.state)
+    }
+
+    fn register_runtime_state_shapes(
+        &self,
+        registry: &mut SlotShapeRegistry,
+    ) -> Result<(), SlotShapeRegistryError> {
+        TextState::register_runtime_state_shape(registry).map(|_| ())
+    }
+
+    fn render_node(&mut self) -> Option<&mut dyn RenderNode> {
+        Some(self)
+    }
+}
+
+impl RenderNode for TextNode {
+    fn render_texture(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        _ctx: &mut RenderContext<'_>,
+    ) -> Result<TextureRenderProduct, NodeError> {
+        self.ensure_canvas();
+        canvas_texture(self, request).map_err(err_ctx("text texture product"))
+    }
+
+    fn render_texture_into(
+        &mut self,
+        _product: VisualProduct,
+        request: &RenderTextureRequest,
+        target: &mut TextureHandle,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        write_canvas_texture(self, graphics, request, target)
+            .map_err(err_ctx("text texture upload"))
+    }
+
+    fn sample_visual_into(
+        &mut self,
+        _product: VisualProduct,
+        request: VisualSampleBufferRequest<'_>,
+        target: VisualSampleTarget<'_>,
+        ctx: &mut RenderContext<'_>,
+    ) -> Result<(), NodeError> {
+        self.ensure_canvas();
+        let graphics = ctx
+            .graphics()
+            .ok_or_else(|| NodeError::msg("missing graphics backend"))?;
+        sample_canvas(self, graphics, request, target).map_err(err_ctx("text sample"))
+    }
+}
+
+/// The layout options the authored def asks for this frame.
+fn read_options(def: &TextDefView, ctx: &mut TickContext<'_>) -> Result<TextOptions, NodeError> {
+    let size: Dim2u = def.size().get(ctx)?;
+    let scroll = match authored_variant(ctx, "scroll").as_deref() {
+        Some("Left") => TextScroll::Left,
+        Some("Right") => TextScroll::Right,
+        Some("Up") => TextScroll::Up,
+        Some("Down") => TextScroll::Down,
+        _ => TextScroll::None,
+    };
+    let align = match authored_variant(ctx, "align").as_deref() {
+        Some("Left") => TextAlign::Left,
+        Some("Right") => TextAlign::Right,
+        _ => TextAlign::Center,
+    };
+    let coloring = match authored_variant(ctx, "coloring").as_deref() {
+        Some("PerCharacter") => TextColoring::PerCharacter,
+        Some("Solid") => TextColoring::Solid,
+        _ => TextColoring::Across,
+    };
+    Ok(TextOptions {
+        width: size.width.max(1),
+        height: size.height.max(1),
+        scroll,
+        speed: def.speed().get(ctx)?,
+        align,
+        coloring,
+    })
+}
+
+pub fn text_output_path() -> SlotPath {
+    SlotPath::parse("output").expect("text output path")
+}
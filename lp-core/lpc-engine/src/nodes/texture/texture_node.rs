@@ -10,12 +10,14 @@ use lpc_model::TextureDefView;
 use lpc_model::WithRevision;
 use lpc_model::nodes::texture::TextureFormat;
 use lpc_model::nodes::texture::TextureState;
+use lps_shared::TextureStorageFormat;
 
 use crate::node::{
     DestroyCtx, MemPressureCtx, NodeError, NodeResourceInitContext, NodeRuntime, PressureLevel,
-    ProduceResult, RuntimeStateShape, TickContext,
+    ProduceResult, RuntimeStateShape, StateExport, TickContext,
 };
-use crate::resource::{RuntimeBuffer, RuntimeBufferId};
+use crate::products::visual::texture_storage_format;
+use crate::resource::{RuntimeBuffer, RuntimeBufferId, RuntimeTextureFormat};
 
 #[cfg(test)]
 fn size_path() -> SlotPath {
@@ -28,6 +30,7 @@ fn texture_format_tag(f: TextureFormat) -> u32 {
         TextureFormat::Rgba8 => 1,
         TextureFormat::R8 => 2,
         TextureFormat::Rgba16 => 3,
+        TextureFormat::R16 => 4,
     }
 }
 
@@ -38,6 +41,9 @@ pub struct TextureNode {
     state: TextureState,
     def_view: Option<TextureDefView>,
     texture_buffer_id: Option<RuntimeBufferId>,
+    /// Shape of the current texture buffer allocation, so a steady def
+    /// does not reallocate (or bump the buffer revision) every produce.
+    allocated: Option<(lpc_model::Dim2u, TextureStorageFormat)>,
 }
 
 impl TextureNode {
@@ -49,6 +55,7 @@ impl TextureNode {
             state: TextureState::new(0, 0, texture_format_tag(pixel_format)),
             def_view: None,
             texture_buffer_id: None,
+            allocated: None,
         }
     }
 
@@ -60,6 +67,12 @@ impl TextureNode {
         self.pixel_format
     }
 
+    /// The lp-gfx storage format for the authored format, as passed to
+    /// texture allocation. `None` for legacy `RGBA8`, which nothing renders.
+    pub fn storage_format(&self) -> Option<TextureStorageFormat> {
+        texture_storage_format(self.pixel_format)
+    }
+
     fn def_view(&mut self, ctx: &TickContext<'_>) -> Result<&TextureDefView, NodeError> {
         TextureDefView::get_or_compile(&mut self.def_view, ctx.slot_shapes())
             .map_err(|e| NodeError::msg(alloc::format!("compile texture def view: {e}")))
@@ -84,23 +97,46 @@ impl NodeRuntime for TextureNode {
         _slot: &SlotPath,
         ctx: &mut TickContext<'_>,
     ) -> Result<ProduceResult, NodeError> {
-        let size: lpc_model::Dim2u = self.def_view(ctx)?.size().get(ctx)?;
+        let def = self.def_view(ctx)?;
+        let size: lpc_model::Dim2u = def.size().get(ctx)?;
+        self.pixel_format = def.format().get(ctx)?;
         self.state.sync_with_revision(
             ctx.revision(),
             i32::try_from(size.width).unwrap_or(i32::MAX),
             i32::try_from(size.height).unwrap_or(i32::MAX),
             texture_format_tag(self.pixel_format),
         );
-        if let Some(buffer_id) = self.texture_buffer_id {
+        let format = self.storage_format().ok_or_else(|| {
+            NodeError::msg(alloc::format!(
+                "texture format {:?} has no storage format",
+                self.pixel_format
+            ))
+        })?;
+        if let Some(buffer_id) = self.texture_buffer_id
+            && self.allocated != Some((size, format))
+        {
+            let buffer_format = RuntimeTextureFormat::from_storage(format);
+            let len = (size.width as usize)
+                .saturating_mul(size.height as usize)
+                .saturating_mul(buffer_format.bytes_per_pixel());
             ctx.with_runtime_buffer_mut(buffer_id, ctx.revision(), |buffer| {
-                *buffer =
-                    RuntimeBuffer::texture_rgba16(size.width, size.height, alloc::vec::Vec::new());
+                *buffer = RuntimeBuffer::texture(
+                    size.width,
+                    size.height,
+                    buffer_format,
+                    alloc::vec![0; len],
+                );
                 Ok(())
             })?;
+            self.allocated = Some((size, format));
         }
         Ok(ProduceResult::Produced)
     }
 
+    fn export_state(&mut self) -> StateExport {
+        StateExport::Stateless
+    }
+
     fn destroy(&mut self, _ctx: &mut DestroyCtx) -> Result<(), NodeError> {
         Ok(())
     }
@@ -207,6 +243,30 @@ mod tests {
         assert!(matches!(pv.as_value().expect("value"), LpsValueF32::I32(9)));
     }
 
+    #[test]
+    fn texture_tick_reads_the_authored_format() {
+        let mut def = TextureDef::new(8, 8);
+        def.format = lpc_model::ValueSlot::new(TextureFormat::R8);
+        let (mut engine, registry, tid) = texture_engine_with(def);
+
+        let pv = resolve_with_engine_host(
+            &mut engine,
+            &registry,
+            QueryKey::ProducedSlot {
+                node: tid,
+                slot: SlotPath::parse("format").unwrap(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("resolve")
+        .0;
+        assert!(matches!(pv.as_value().expect("value"), LpsValueF32::U32(2)));
+        assert_eq!(
+            texture_storage_format(TextureFormat::R8),
+            Some(TextureStorageFormat::R8Unorm)
+        );
+    }
+
     #[test]
     fn texture_node_exposes_owned_texture_resource_summary() {
         let (mut engine, registry, tid) = texture_engine(64, 48);
@@ -244,7 +304,58 @@ mod tests {
         assert_eq!(texture.owner, Some(tid));
     }
 
+    #[test]
+    fn texture_buffer_is_allocated_at_the_authored_format() {
+        let rgba16 = texture_buffer_summary(TextureFormat::Rgba16);
+        let rgb8 = texture_buffer_summary(TextureFormat::Rgb8);
+        let r8 = texture_buffer_summary(TextureFormat::R8);
+
+        assert_eq!(
+            rgba16,
+            (lpc_wire::WireTextureFormat::Rgba16, Some(8 * 4 * 8))
+        );
+        assert_eq!(rgb8, (lpc_wire::WireTextureFormat::Rgb8, Some(8 * 4 * 3)));
+        assert_eq!(r8, (lpc_wire::WireTextureFormat::R8, Some(8 * 4)));
+    }
+
+    /// Wire format and byte length of the texture buffer an 8×4 texture of
+    /// `format` allocates.
+    fn texture_buffer_summary(format: TextureFormat) -> (lpc_wire::WireTextureFormat, Option<u64>) {
+        let mut def = TextureDef::new(8, 4);
+        def.format = lpc_model::ValueSlot::new(format);
+        let (mut engine, registry, tid) = texture_engine_with(def);
+        resolve_with_engine_host(
+            &mut engine,
+            &registry,
+            QueryKey::ProducedSlot {
+                node: tid,
+                slot: SlotPath::parse("width").unwrap(),
+            },
+            ResolveLogLevel::Off,
+        )
+        .expect("resolve texture width");
+        let (view, _) = crate::engine::test_support::read_into_view(
+            &mut engine,
+            &registry,
+            lpc_wire::ProjectReadRequest::default_debug(None),
+        );
+        let summary = view
+            .resource_cache
+            .summaries()
+            .find(|summary| summary.owner == Some(tid))
+            .expect("texture summary");
+        let lpc_wire::WireResourceMetadataSummary::Texture { format, .. } = &summary.metadata
+        else {
+            panic!("expected texture metadata, got {:?}", summary.metadata);
+        };
+        (*format, summary.byte_length_hint)
+    }
+
     fn texture_engine(width: u32, height: u32) -> (Engine, ProjectRegistry, NodeId) {
+        texture_engine_with(TextureDef::new(width, height))
+    }
+
+    fn texture_engine_with(def: TextureDef) -> (Engine, ProjectRegistry, NodeId) {
         let mut engine = Engine::new(TreePath::parse("/t.show").expect("path"));
         let mut registry = ProjectRegistry::new();
         let frame = Revision::new(1);
@@ -264,11 +375,7 @@ mod tests {
             )
             .expect("add");
         engine
-            .load_test_node_defs(
-                &mut registry,
-                &[(tid, NodeDef::Texture(TextureDef::new(width, height)))],
-                frame,
-            )
+            .load_test_node_defs(&mut registry, &[(tid, NodeDef::Texture(def))], frame)
             .expect("load test defs");
         let tex = TextureNode::new(tid);
         engine
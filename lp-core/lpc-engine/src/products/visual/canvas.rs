@@ -0,0 +1,188 @@
+//! CPU-drawn canvases served as visual products.
+//!
+//! The image, text and particle nodes each draw a fixed-size grid of RGBA16
+//! colours. Consumers ask for it at their own size and storage format, as a
+//! whole texture or at sample points; every answer is a nearest sample of
+//! the canvas, and pixels outside a drawn canvas are transparent black.
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use lp_gfx::{GfxError, LpGraphics, TextureHandle};
+
+use super::{
+    RenderTextureRequest, TextureRenderProduct, TextureRenderProductError,
+    VisualSampleBufferRequest, VisualSampleTarget, pixel_q16_to_normalized_q16,
+    texture_uv_q16_to_texel, write_texel,
+};
+
+/// A node's CPU-drawn picture.
+pub trait Canvas {
+    /// Width and height in pixels, or `None` while nothing is drawn.
+    fn canvas_size(&self) -> Option<(u32, u32)>;
+
+    /// The RGBA16 colour at (`x`, `y`), inside [`Self::canvas_size`].
+    fn canvas_texel(&self, x: u32, y: u32) -> [u16; 4];
+}
+
+/// The canvas as a host texture in the requested size and format.
+pub fn canvas_texture(
+    canvas: &dyn Canvas,
+    request: &RenderTextureRequest,
+) -> Result<TextureRenderProduct, TextureRenderProductError> {
+    TextureRenderProduct::new(
+        request.width,
+        request.height,
+        request.format,
+        canvas_pixels(canvas, request),
+    )
+}
+
+/// Upload the canvas into a `target` allocated for `request`.
+pub fn write_canvas_texture(
+    canvas: &dyn Canvas,
+    graphics: &dyn LpGraphics,
+    request: &RenderTextureRequest,
+    target: &mut TextureHandle,
+) -> Result<(), GfxError> {
+    if target.width() != request.width
+        || target.height() != request.height
+        || target.format() != request.format
+    {
+        return Err(GfxError::Render(format!(
+            "canvas target {:?} {}x{} does not match request {:?} {}x{}",
+            target.format(),
+            target.width(),
+            target.height(),
+            request.format,
+            request.width,
+            request.height
+        )));
+    }
+    graphics.write_texture(target, &canvas_pixels(canvas, request))
+}
+
+/// Sample the canvas at the consumer's points, which are pixel positions in
+/// its `output_width`×`output_height` frame.
+pub fn sample_canvas(
+    canvas: &dyn Canvas,
+    graphics: &dyn LpGraphics,
+    request: VisualSampleBufferRequest<'_>,
+    target: VisualSampleTarget<'_>,
+) -> Result<(), GfxError> {
+    let point_count = request.points.count();
+    if target.samples.count() != point_count {
+        return Err(GfxError::Render(format!(
+            "canvas sample target count {} does not match request count {point_count}",
+            target.samples.count()
+        )));
+    }
+    let Some((width, height)) = canvas.canvas_size() else {
+        return graphics.clear_sample_out(target.samples);
+    };
+    let points = graphics.read_sample_points(request.points)?;
+    let mut channels = vec![0u16; point_count as usize * 4];
+    for (point, sample) in points.chunks_exact(2).zip(channels.chunks_exact_mut(4)) {
+        let u = pixel_q16_to_normalized_q16(point[0], request.output_width);
+        let v = pixel_q16_to_normalized_q16(point[1], request.output_height);
+        let x = texture_uv_q16_to_texel(u, width).min(width - 1);
+        let y = texture_uv_q16_to_texel(v, height).min(height - 1);
+        sample.copy_from_slice(&canvas.canvas_texel(x, y));
+    }
+    graphics.write_sample_out(target.samples, &channels)
+}
+
+/// Nearest-sample the canvas onto a `request.width`×`request.height`
+/// texture in `request.format`.
+fn canvas_pixels(canvas: &dyn Canvas, request: &RenderTextureRequest) -> Vec<u8> {
+    let (width, height) = (request.width, request.height);
+    let bpp = request.format.bytes_per_pixel();
+    let mut pixels = vec![0u8; width as usize * height as usize * bpp];
+    let Some((canvas_width, canvas_height)) = canvas.canvas_size() else {
+        return pixels;
+    };
+    if pixels.is_empty() {
+        return pixels;
+    }
+    for (y, row) in (0..height).zip(pixels.chunks_exact_mut(width as usize * bpp)) {
+        let source_y = (u64::from(y) * u64::from(canvas_height) / u64::from(height)) as u32;
+        for (x, texel) in (0..width).zip(row.chunks_exact_mut(bpp)) {
+            let source_x = (u64::from(x) * u64::from(canvas_width) / u64::from(width)) as u32;
+            write_texel(
+                canvas.canvas_texel(source_x, source_y),
+                request.format,
+                texel,
+            );
+        }
+    }
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use lps_shared::TextureStorageFormat;
+
+    use super::*;
+    use crate::products::visual::{ConsumerPolicy, VisualSpace};
+
+    /// A 2×1 canvas: red, then half-grey.
+    struct TwoPixels;
+
+    impl Canvas for TwoPixels {
+        fn canvas_size(&self) -> Option<(u32, u32)> {
+            Some((2, 1))
+        }
+
+        fn canvas_texel(&self, x: u32, _y: u32) -> [u16; 4] {
+            if x == 0 {
+                [u16::MAX, 0, 0, u16::MAX]
+            } else {
+                [32_896; 4]
+            }
+        }
+    }
+
+    struct Blank;
+
+    impl Canvas for Blank {
+        fn canvas_size(&self) -> Option<(u32, u32)> {
+            None
+        }
+
+        fn canvas_texel(&self, _x: u32, _y: u32) -> [u16; 4] {
+            unreachable!("a blank canvas has no texels")
+        }
+    }
+
+    fn request(width: u32, height: u32, format: TextureStorageFormat) -> RenderTextureRequest {
+        RenderTextureRequest {
+            width,
+            height,
+            format,
+            time_seconds: 0.0,
+            space: VisualSpace::TwoD,
+            policy: ConsumerPolicy::default(),
+        }
+    }
+
+    #[test]
+    fn canvas_textures_nearest_sample_in_the_requested_format() {
+        let rgb8 = canvas_texture(&TwoPixels, &request(4, 1, TextureStorageFormat::Rgb8Unorm))
+            .expect("rgb8 texture");
+        assert_eq!(
+            rgb8.try_raw_bytes(),
+            Some(&[255, 0, 0, 255, 0, 0, 128, 128, 128, 128, 128, 128][..])
+        );
+        let r8 = canvas_texture(&TwoPixels, &request(2, 2, TextureStorageFormat::R8Unorm))
+            .expect("r8 texture");
+        assert_eq!(r8.try_raw_bytes(), Some(&[255, 128, 255, 128][..]));
+    }
+
+    #[test]
+    fn a_blank_canvas_is_transparent_black() {
+        let texture = canvas_texture(&Blank, &request(2, 2, TextureStorageFormat::Rgba16Unorm))
+            .expect("texture");
+        assert_eq!(texture.try_raw_bytes(), Some(&[0u8; 32][..]));
+    }
+}
@@ -1,5 +1,6 @@
 //! Visual-product handle and minimal sample request/result shapes.
 
+mod canvas;
 pub mod coordinates;
 mod render_texture_request;
 mod sample_request;
@@ -7,6 +8,7 @@ mod sample_result;
 mod space;
 mod texture_product;
 
+pub use canvas::{Canvas, canvas_texture, sample_canvas, write_canvas_texture};
 pub use coordinates::{
     RADIAL_CORNER_REACH, angular, centre_scanline, extrude, mirror, normalized_f32_to_q16,
     normalized_q16_to_pixel_q16, pixel_q16_to_normalized_q16, project_2d_to_1d, radial,
@@ -19,7 +21,10 @@ pub use sample_request::{
 };
 pub use sample_result::{VisualSample, VisualSampleBatchResult};
 pub use space::{CellProjection, ConsumerPolicy, ProductSpaceInfo, VisualSpace, resolve_1d_to_2d};
-pub use texture_product::{TextureRenderProduct, TextureRenderProductError};
+pub use texture_product::{
+    TextureRenderProduct, TextureRenderProductError, convert_texels, texture_storage_format,
+    write_texel,
+};
 #[cfg(test)]
 mod tests {
     use alloc::vec;
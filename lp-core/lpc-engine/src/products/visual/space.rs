@@ -20,6 +20,8 @@
 //!    ([`resolve_1d_to_2d`]) using the shared coordinate-map library in
 //!    [`super::coordinates`].
 
+use lps_shared::TextureStorageFormat;
+
 /// Which coordinate space a visual product renders in, or a request asks in.
 ///
 /// One enum for both ends deliberately: "the space" is the same vocabulary
@@ -101,9 +103,9 @@ impl ConsumerPolicy {
 /// What a producer answers when asked what space its product lives in.
 ///
 /// Runtime info, not authored model: a producer that has never declared a
-/// space answers [`Self::default`] (2D primary, no opinion), which is what
-/// keeps every pre-plan project meaning-identical.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// space answers [`Self::default`] (2D primary, no opinion, RGBA16), which
+/// is what keeps every pre-plan project meaning-identical.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ProductSpaceInfo {
     /// The space this product natively renders in.
     pub primary: VisualSpace,
@@ -114,6 +116,16 @@ pub struct ProductSpaceInfo {
     // in_1d: only the centre scanline exists today (vision D8), so a 2D
     // producer has nothing to say that the map library does not already
     // know. The field arrives with an authorable scanline choice.
+    /// The storage format the producer renders textures in. A consumer
+    /// that can read any format asks in this one rather than forcing an
+    /// RGBA16 target on a narrower producer.
+    pub format: TextureStorageFormat,
+}
+
+impl Default for ProductSpaceInfo {
+    fn default() -> Self {
+        Self::two_d()
+    }
 }
 
 impl ProductSpaceInfo {
@@ -123,6 +135,7 @@ impl ProductSpaceInfo {
         Self {
             primary: VisualSpace::OneD,
             in_2d,
+            format: TextureStorageFormat::Rgba16Unorm,
         }
     }
 
@@ -132,6 +145,7 @@ impl ProductSpaceInfo {
         Self {
             primary: VisualSpace::TwoD,
             in_2d: None,
+            format: TextureStorageFormat::Rgba16Unorm,
         }
     }
 }
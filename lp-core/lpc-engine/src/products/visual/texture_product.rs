@@ -4,10 +4,23 @@
 use core::fmt;
 
 use lp_gfx::TextureHandle;
+use lpc_model::nodes::texture::TextureFormat;
 use lps_shared::TextureStorageFormat;
 
 use super::{TextureSampleBatch, VisualSample, VisualSampleBatchResult, texture_uv_q16_to_texel};
 
+/// The lp-gfx storage format an authored texture format renders and
+/// allocates as. `None` for legacy `RGBA8`, which nothing renders.
+pub fn texture_storage_format(format: TextureFormat) -> Option<TextureStorageFormat> {
+    match format {
+        TextureFormat::Rgba16 => Some(TextureStorageFormat::Rgba16Unorm),
+        TextureFormat::Rgb8 => Some(TextureStorageFormat::Rgb8Unorm),
+        TextureFormat::R16 => Some(TextureStorageFormat::R16Unorm),
+        TextureFormat::R8 => Some(TextureStorageFormat::R8Unorm),
+        TextureFormat::Rgba8 => None,
+    }
+}
+
 /// Invalid [`TextureRenderProduct`] construction input or an operation that
 /// requires host-resident bytes on a GPU-resident product.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -217,7 +230,59 @@ fn sample_texel(
     let bpp = format.bytes_per_pixel();
     let stride = width as usize * bpp;
     let offset = y as usize * stride + x as usize * bpp;
-    let slice = pixels.get(offset..offset + bpp).unwrap_or(&[]);
+    read_texel(pixels.get(offset..offset + bpp).unwrap_or(&[]), format)
+}
+
+/// Re-encode a tightly packed `from` frame as `to`, texel by texel, through
+/// the RGBA16 every format widens to. A copy when the formats match.
+pub fn convert_texels(
+    pixels: &[u8],
+    from: TextureStorageFormat,
+    to: TextureStorageFormat,
+) -> alloc::vec::Vec<u8> {
+    if from == to {
+        return pixels.to_vec();
+    }
+    let mut out = alloc::vec![0u8; pixels.len() / from.bytes_per_pixel() * to.bytes_per_pixel()];
+    for (texel, stored) in pixels
+        .chunks_exact(from.bytes_per_pixel())
+        .zip(out.chunks_exact_mut(to.bytes_per_pixel()))
+    {
+        write_texel(read_texel(texel, from), to, stored);
+    }
+    out
+}
+
+/// Store an RGBA16 colour as one `format` texel in `out`
+/// (`format.bytes_per_pixel()` bytes). The inverse of the sampling widen:
+/// byte formats round to the nearest step, and the single-channel formats
+/// keep red, as a shader's `float` result lands in red.
+pub fn write_texel(rgba: [u16; 4], format: TextureStorageFormat, out: &mut [u8]) {
+    let narrow = |channel: u16| ((u32::from(channel) * 255 + 32_767) / 65_535) as u8;
+    match format {
+        TextureStorageFormat::Rgba16Unorm => {
+            for (bytes, channel) in out.chunks_exact_mut(2).zip(rgba) {
+                bytes.copy_from_slice(&channel.to_le_bytes());
+            }
+        }
+        TextureStorageFormat::Rgb16Unorm => {
+            for (bytes, channel) in out.chunks_exact_mut(2).zip(&rgba[..3]) {
+                bytes.copy_from_slice(&channel.to_le_bytes());
+            }
+        }
+        TextureStorageFormat::R16Unorm => out[..2].copy_from_slice(&rgba[0].to_le_bytes()),
+        TextureStorageFormat::Rgb8Unorm => {
+            for (byte, channel) in out.iter_mut().zip(&rgba[..3]) {
+                *byte = narrow(*channel);
+            }
+        }
+        TextureStorageFormat::R8Unorm => out[0] = narrow(rgba[0]),
+    }
+}
+
+/// One stored `format` texel widened to RGBA16; transparent black when
+/// `slice` is short.
+fn read_texel(slice: &[u8], format: TextureStorageFormat) -> [u16; 4] {
     match format {
         TextureStorageFormat::Rgba16Unorm => {
             if slice.len() < 8 {
@@ -245,6 +310,21 @@ fn sample_texel(
             let r = u16::from_le_bytes([slice[0], slice[1]]);
             [r, r, r, u16::MAX]
         }
+        // Bytes widen as the shader samplers widen them: `255` is `u16::MAX`.
+        TextureStorageFormat::Rgb8Unorm => {
+            if slice.len() < 3 {
+                return [0; 4];
+            }
+            let [r, g, b] = [slice[0], slice[1], slice[2]].map(|c| u16::from(c) * 257);
+            [r, g, b, u16::MAX]
+        }
+        TextureStorageFormat::R8Unorm => {
+            let Some(&r) = slice.first() else {
+                return [0; 4];
+            };
+            let r = u16::from(r) * 257;
+            [r, r, r, u16::MAX]
+        }
     }
 }
 
@@ -252,7 +332,7 @@ fn sample_texel(
 mod tests {
     use alloc::vec;
 
-    use super::{TextureRenderProduct, TextureRenderProductError};
+    use super::{TextureRenderProduct, TextureRenderProductError, convert_texels};
     use crate::products::visual::{TextureSampleBatch, TextureUvSamplePoint};
 
     fn pixel_rgba16(r: u16, g: u16, b: u16, a: u16) -> [u8; 8] {
@@ -308,6 +388,63 @@ mod tests {
         assert_eq!(out.samples[3].rgba_unorm16, [65535, 65535, 65535, 65535]);
     }
 
+    #[test]
+    fn byte_formats_sample_widened_to_unorm16() {
+        use lps_shared::TextureStorageFormat;
+
+        let batch = TextureSampleBatch {
+            points: vec![TextureUvSamplePoint { u_q16: 0, v_q16: 0 }],
+            time_seconds: 0.0,
+        };
+        let rgb8 =
+            TextureRenderProduct::new(1, 1, TextureStorageFormat::Rgb8Unorm, vec![255, 0, 1])
+                .expect("rgb8 texture");
+        assert_eq!(
+            rgb8.sample_batch(&batch).expect("samples").samples[0].rgba_unorm16,
+            [65535, 0, 257, 65535]
+        );
+        let r8 = TextureRenderProduct::new(1, 1, TextureStorageFormat::R8Unorm, vec![128])
+            .expect("r8 texture");
+        assert_eq!(
+            r8.sample_batch(&batch).expect("samples").samples[0].rgba_unorm16,
+            [32896, 32896, 32896, 65535]
+        );
+    }
+
+    #[test]
+    fn converted_texels_round_trip_through_the_sampling_widen() {
+        use lps_shared::TextureStorageFormat;
+
+        let rgb8 = [255u8, 0, 1, 128, 64, 32];
+        let wide = convert_texels(
+            &rgb8,
+            TextureStorageFormat::Rgb8Unorm,
+            TextureStorageFormat::Rgba16Unorm,
+        );
+        assert_eq!(
+            &wide[..8],
+            &[0xff, 0xff, 0, 0, 0x01, 0x01, 0xff, 0xff],
+            "255 widens to u16::MAX and alpha is opaque"
+        );
+        assert_eq!(
+            convert_texels(
+                &wide,
+                TextureStorageFormat::Rgba16Unorm,
+                TextureStorageFormat::Rgb8Unorm
+            ),
+            rgb8
+        );
+        // The single-channel formats keep red.
+        assert_eq!(
+            convert_texels(
+                &wide,
+                TextureStorageFormat::Rgba16Unorm,
+                TextureStorageFormat::R8Unorm
+            ),
+            [255, 128]
+        );
+    }
+
     #[test]
     fn rejects_bad_byte_length() {
         let err = TextureRenderProduct::rgba16_unorm(2, 2, vec![0u8; 31]).expect_err("short buf");
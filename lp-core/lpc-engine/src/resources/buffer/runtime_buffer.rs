@@ -2,6 +2,8 @@
 
 use alloc::vec::Vec;
 
+use lps_shared::TextureStorageFormat;
+
 /// High-level classification of buffer payloads in [`RuntimeBuffer`].
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RuntimeBufferKind {
@@ -15,7 +17,35 @@ pub enum RuntimeBufferKind {
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RuntimeTextureFormat {
     Rgba16,
+    Rgb16,
     Rgb8,
+    R16,
+    R8,
+}
+
+impl RuntimeTextureFormat {
+    /// The buffer format for an lp-gfx storage format.
+    #[must_use]
+    pub fn from_storage(format: TextureStorageFormat) -> Self {
+        match format {
+            TextureStorageFormat::Rgba16Unorm => Self::Rgba16,
+            TextureStorageFormat::Rgb16Unorm => Self::Rgb16,
+            TextureStorageFormat::Rgb8Unorm => Self::Rgb8,
+            TextureStorageFormat::R16Unorm => Self::R16,
+            TextureStorageFormat::R8Unorm => Self::R8,
+        }
+    }
+
+    #[must_use]
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Rgba16 => 8,
+            Self::Rgb16 => 6,
+            Self::Rgb8 => 3,
+            Self::R16 => 2,
+            Self::R8 => 1,
+        }
+    }
 }
 
 /// Memory layout for fixture color bytes.
@@ -61,12 +91,17 @@ pub struct RuntimeBuffer {
 impl RuntimeBuffer {
     #[must_use]
     pub fn texture_rgba16(width: u32, height: u32, bytes: Vec<u8>) -> Self {
+        Self::texture(width, height, RuntimeTextureFormat::Rgba16, bytes)
+    }
+
+    #[must_use]
+    pub fn texture(width: u32, height: u32, format: RuntimeTextureFormat, bytes: Vec<u8>) -> Self {
         Self {
             kind: RuntimeBufferKind::Texture,
             metadata: RuntimeBufferMetadata::Texture {
                 width,
                 height,
-                format: RuntimeTextureFormat::Rgba16,
+                format,
             },
             bytes,
         }
@@ -42,11 +42,13 @@ pub fn compute_desc_from_model_def<'a>(
     );
 
     for (name, slot) in &def.consumed_slots.entries {
-        // A palette declares a sampler, which is not an `LpsType` the value
-        // lookup can answer — so that lookup must not run for it.
+        // A palette or feedback slot declares a sampler, which is not an
+        // `LpsType` the value lookup can answer — so that lookup must not run
+        // for it.
         if slot.kind.value().is_texture() {
             return Err(ComputeDescError::Unsupported(alloc::format!(
-                "compute shader slot {name:?} is a palette; texture uniforms are supported on visual shaders only"
+                "compute shader slot {name:?} is a {}; texture uniforms are supported on visual shaders only",
+                slot.kind.value().as_str()
             )));
         }
         let ty = lps_type_for_slot_value(slot.value.value(), registry)?;
@@ -57,7 +59,7 @@ pub fn compute_desc_from_model_def<'a>(
                 desc = desc.with_consumed(name.clone(), ty);
             }
             // Refused above, before the type lookup.
-            ShaderSlotKind::Palette => continue,
+            ShaderSlotKind::Palette | ShaderSlotKind::Feedback => continue,
             ShaderSlotKind::Map => {
                 ensure_u32_map_key(slot)?;
                 let mapping = slot
@@ -83,7 +85,8 @@ pub fn compute_desc_from_model_def<'a>(
     for (name, slot) in &def.produced_slots.entries {
         if slot.kind.value().is_texture() {
             return Err(ComputeDescError::Unsupported(alloc::format!(
-                "compute shader slot {name:?} is a palette, which cannot be produced"
+                "compute shader slot {name:?} is a {}, which cannot be produced",
+                slot.kind.value().as_str()
             )));
         }
         let ty = lps_type_for_slot_value(slot.value.value(), registry)?;
@@ -92,7 +95,7 @@ pub fn compute_desc_from_model_def<'a>(
                 desc = desc.with_produced(name.clone(), ty);
             }
             // Refused above, before the type lookup.
-            ShaderSlotKind::Palette => continue,
+            ShaderSlotKind::Palette | ShaderSlotKind::Feedback => continue,
             ShaderSlotKind::Map => {
                 ensure_u32_map_key(slot)?;
                 let mapping = slot
@@ -294,6 +294,76 @@ fn project_apply_asset_body_change_refreshes_existing_shader_node() {
     );
 }
 
+#[test]
+fn project_apply_included_file_change_refreshes_existing_shader_node() {
+    let mut fs = shader_project_fs();
+    fs.write_file_mut(
+        LpPath::new("/shader.glsl"),
+        b"#include \"lib/tint.glsl\"\nvec4 render_2d(vec2 pos) { return tint(pos); }",
+    )
+    .expect("write shader source");
+    fs.write_file_mut(
+        LpPath::new("/lib/tint.glsl"),
+        b"vec4 tint(vec2 pos) { return vec4(0.0, pos.y, 0.0, 1.0); }",
+    )
+    .expect("write included file");
+    let services = EngineServices::new(TreePath::parse("/shader_include_change.show").unwrap());
+    let loaded = ProjectLoader::load_from_root(&fs, services).expect("load");
+    let (mut engine, mut registry) = loaded.into_parts();
+    let shader_use = NodeUseLocation::root().child(SlotPath::parse("nodes[shader]").unwrap());
+    let shader_before = engine
+        .project_runtime_index()
+        .node_id(&shader_use)
+        .expect("shader runtime node");
+    let shapes = engine.slot_shapes().clone();
+
+    fs.write_file_mut(
+        LpPath::new("/lib/tint.glsl"),
+        b"vec4 tint(vec2 pos) { return vec4(pos.x, 0.0, 0.0, 1.0); }",
+    )
+    .expect("edit included file");
+    let changes = registry.refresh_artifacts(
+        &fs,
+        &[FsEvent {
+            path: LpPathBuf::from("/lib/tint.glsl"),
+            kind: FsEventKind::Modify,
+        }],
+        Revision::new(2),
+        &ParseCtx { shapes: &shapes },
+    );
+    // The included file is no declared asset: only the shader notices it.
+    assert!(changes.is_empty());
+    let apply = engine
+        .apply_project_changes(&fs, &mut registry, &changes)
+        .expect("apply changes");
+
+    assert_eq!(apply.refreshed_nodes, vec![shader_use.clone()]);
+    assert!(apply.refreshed_assets.is_empty());
+    assert!(apply.added_nodes.is_empty());
+    assert!(apply.removed_nodes.is_empty());
+    assert_eq!(
+        engine.project_runtime_index().node_id(&shader_use),
+        Some(shader_before)
+    );
+
+    // An unrelated file leaves the shader alone.
+    fs.write_file_mut(LpPath::new("/notes.txt"), b"unrelated")
+        .expect("write unrelated file");
+    let changes = registry.refresh_artifacts(
+        &fs,
+        &[FsEvent {
+            path: LpPathBuf::from("/notes.txt"),
+            kind: FsEventKind::Create,
+        }],
+        Revision::new(3),
+        &ParseCtx { shapes: &shapes },
+    );
+    let apply = engine
+        .apply_project_changes(&fs, &mut registry, &changes)
+        .expect("apply changes");
+    assert!(apply.refreshed_nodes.is_empty());
+}
+
 #[test]
 fn project_apply_map2d_body_change_refreshes_existing_fixture_node() {
     let mut fs = fixture_map2d_project_fs();
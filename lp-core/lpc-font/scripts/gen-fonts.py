@@ -0,0 +1,329 @@
+#!/usr/bin/env python3
+"""Generate lpc-font's built-in fonts from the glyph art below.
+
+Each font is drawn here as ASCII art ('#' ink, '.' paper) and written twice:
+as a BDF file under src/fonts/ (what a project would add as an asset, and
+what the BDF parser's tests read back) and as the packed row tables in
+src/builtin_glyphs.rs that the crate embeds. Rows are one byte per glyph
+row, most significant bit leftmost — BDF's own bitmap order.
+
+Usage:
+    python3 lp-core/lpc-font/scripts/gen-fonts.py           # dry-run: list files
+    python3 lp-core/lpc-font/scripts/gen-fonts.py --write   # write the outputs
+    python3 lp-core/lpc-font/scripts/gen-fonts.py --check   # diff against disk; exit 1 on drift
+"""
+
+from __future__ import annotations
+
+import os
+import sys
+
+ROOT = os.path.normpath(os.path.join(os.path.dirname(os.path.abspath(__file__)), ".."))
+
+FIRST = 0x20
+LAST = 0x7E
+
+# ------------------------------------------------------------ classic 5x8
+#
+# Seven rows above the baseline, one below for descenders (g j p q y , ;).
+# Glyphs with fewer than eight rows are padded with paper at the bottom.
+
+CLASSIC = {
+    " ": [],
+    "!": ["..#..", "..#..", "..#..", "..#..", "..#..", ".....", "..#.."],
+    '"': [".#.#.", ".#.#.", ".#.#."],
+    "#": [".#.#.", ".#.#.", "#####", ".#.#.", "#####", ".#.#.", ".#.#."],
+    "$": ["..#..", ".####", "#.#..", ".###.", "..#.#", "####.", "..#.."],
+    "%": ["##...", "##..#", "...#.", "..#..", ".#...", "#..##", "...##"],
+    "&": [".##..", "#..#.", "#.#..", ".#...", "#.#.#", "#..#.", ".##.#"],
+    "'": ["..#..", "..#..", ".#..."],
+    "(": ["...#.", "..#..", ".#...", ".#...", ".#...", "..#..", "...#."],
+    ")": [".#...", "..#..", "...#.", "...#.", "...#.", "..#..", ".#..."],
+    "*": [".....", "..#..", "#.#.#", ".###.", "#.#.#", "..#..", "....."],
+    "+": [".....", "..#..", "..#..", "#####", "..#..", "..#..", "....."],
+    ",": [".....", ".....", ".....", ".....", ".....", ".##..", "..#..", ".#..."],
+    "-": [".....", ".....", ".....", "#####", ".....", ".....", "....."],
+    ".": [".....", ".....", ".....", ".....", ".....", ".##..", ".##.."],
+    "/": [".....", "....#", "...#.", "..#..", ".#...", "#....", "....."],
+    "0": [".###.", "#...#", "#..##", "#.#.#", "##..#", "#...#", ".###."],
+    "1": ["..#..", ".##..", "..#..", "..#..", "..#..", "..#..", ".###."],
+    "2": [".###.", "#...#", "....#", "...#.", "..#..", ".#...", "#####"],
+    "3": ["#####", "...#.", "..#..", "...#.", "....#", "#...#", ".###."],
+    "4": ["...#.", "..##.", ".#.#.", "#..#.", "#####", "...#.", "...#."],
+    "5": ["#####", "#....", "####.", "....#", "....#", "#...#", ".###."],
+    "6": ["..##.", ".#...", "#....", "####.", "#...#", "#...#", ".###."],
+    "7": ["#####", "....#", "...#.", "..#..", ".#...", ".#...", ".#..."],
+    "8": [".###.", "#...#", "#...#", ".###.", "#...#", "#...#", ".###."],
+    "9": [".###.", "#...#", "#...#", ".####", "....#", "...#.", ".##.."],
+    ":": [".....", ".##..", ".##..", ".....", ".##..", ".##..", "....."],
+    ";": [".....", ".##..", ".##..", ".....", ".##..", "..#..", ".#..."],
+    "<": ["...#.", "..#..", ".#...", "#....", ".#...", "..#..", "...#."],
+    "=": [".....", ".....", "#####", ".....", "#####", ".....", "....."],
+    ">": [".#...", "..#..", "...#.", "....#", "...#.", "..#..", ".#..."],
+    "?": [".###.", "#...#", "....#", "...#.", "..#..", ".....", "..#.."],
+    "@": [".###.", "#...#", "....#", ".##.#", "#.#.#", "#.#.#", ".###."],
+    "A": [".###.", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"],
+    "B": ["####.", "#...#", "#...#", "####.", "#...#", "#...#", "####."],
+    "C": [".###.", "#...#", "#....", "#....", "#....", "#...#", ".###."],
+    "D": ["###..", "#..#.", "#...#", "#...#", "#...#", "#..#.", "###.."],
+    "E": ["#####", "#....", "#....", "####.", "#....", "#....", "#####"],
+    "F": ["#####", "#....", "#....", "####.", "#....", "#....", "#...."],
+    "G": [".###.", "#...#", "#....", "#.###", "#...#", "#...#", ".####"],
+    "H": ["#...#", "#...#", "#...#", "#####", "#...#", "#...#", "#...#"],
+    "I": [".###.", "..#..", "..#..", "..#..", "..#..", "..#..", ".###."],
+    "J": ["..###", "...#.", "...#.", "...#.", "...#.", "#..#.", ".##.."],
+    "K": ["#...#", "#..#.", "#.#..", "##...", "#.#..", "#..#.", "#...#"],
+    "L": ["#....", "#....", "#....", "#....", "#....", "#....", "#####"],
+    "M": ["#...#", "##.##", "#.#.#", "#.#.#", "#...#", "#...#", "#...#"],
+    "N": ["#...#", "#...#", "##..#", "#.#.#", "#..##", "#...#", "#...#"],
+    "O": [".###.", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."],
+    "P": ["####.", "#...#", "#...#", "####.", "#....", "#....", "#...."],
+    "Q": [".###.", "#...#", "#...#", "#...#", "#.#.#", "#..#.", ".##.#"],
+    "R": ["####.", "#...#", "#...#", "####.", "#.#..", "#..#.", "#...#"],
+    "S": [".####", "#....", "#....", ".###.", "....#", "....#", "####."],
+    "T": ["#####", "..#..", "..#..", "..#..", "..#..", "..#..", "..#.."],
+    "U": ["#...#", "#...#", "#...#", "#...#", "#...#", "#...#", ".###."],
+    "V": ["#...#", "#...#", "#...#", "#...#", "#...#", ".#.#.", "..#.."],
+    "W": ["#...#", "#...#", "#...#", "#.#.#", "#.#.#", "#.#.#", ".#.#."],
+    "X": ["#...#", "#...#", ".#.#.", "..#..", ".#.#.", "#...#", "#...#"],
+    "Y": ["#...#", "#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."],
+    "Z": ["#####", "....#", "...#.", "..#..", ".#...", "#....", "#####"],
+    "[": [".###.", ".#...", ".#...", ".#...", ".#...", ".#...", ".###."],
+    "\\": [".....", "#....", ".#...", "..#..", "...#.", "....#", "....."],
+    "]": [".###.", "...#.", "...#.", "...#.", "...#.", "...#.", ".###."],
+    "^": ["..#..", ".#.#.", "#...#"],
+    "_": [".....", ".....", ".....", ".....", ".....", ".....", "#####"],
+    "`": [".#...", "..#..", "...#."],
+    "a": [".....", ".....", ".###.", "....#", ".####", "#...#", ".####"],
+    "b": ["#....", "#....", "#.##.", "##..#", "#...#", "#...#", "####."],
+    "c": [".....", ".....", ".###.", "#....", "#....", "#...#", ".###."],
+    "d": ["....#", "....#", ".##.#", "#..##", "#...#", "#...#", ".####"],
+    "e": [".....", ".....", ".###.", "#...#", "#####", "#....", ".###."],
+    "f": ["..##.", ".#..#", ".#...", "###..", ".#...", ".#...", ".#..."],
+    "g": [".....", ".....", ".####", "#...#", "#...#", ".####", "....#", ".###."],
+    "h": ["#....", "#....", "#.##.", "##..#", "#...#", "#...#", "#...#"],
+    "i": ["..#..", ".....", ".##..", "..#..", "..#..", "..#..", ".###."],
+    "j": ["...#.", ".....", "..##.", "...#.", "...#.", "...#.", "#..#.", ".##.."],
+    "k": ["#....", "#....", "#..#.", "#.#..", "##...", "#.#..", "#..#."],
+    "l": [".##..", "..#..", "..#..", "..#..", "..#..", "..#..", ".###."],
+    "m": [".....", ".....", "##.#.", "#.#.#", "#.#.#", "#...#", "#...#"],
+    "n": [".....", ".....", "#.##.", "##..#", "#...#", "#...#", "#...#"],
+    "o": [".....", ".....", ".###.", "#...#", "#...#", "#...#", ".###."],
+    "p": [".....", ".....", "####.", "#...#", "#...#", "####.", "#....", "#...."],
+    "q": [".....", ".....", ".####", "#...#", "#...#", ".####", "....#", "....#"],
+    "r": [".....", ".....", "#.##.", "##..#", "#....", "#....", "#...."],
+    "s": [".....", ".....", ".####", "#....", ".###.", "....#", "####."],
+    "t": [".#...", ".#...", "###..", ".#...", ".#...", ".#..#", "..##."],
+    "u": [".....", ".....", "#...#", "#...#", "#...#", "#..##", ".##.#"],
+    "v": [".....", ".....", "#...#", "#...#", "#...#", ".#.#.", "..#.."],
+    "w": [".....", ".....", "#...#", "#...#", "#.#.#", "#.#.#", ".#.#."],
+    "x": [".....", ".....", "#...#", ".#.#.", "..#..", ".#.#.", "#...#"],
+    "y": [".....", ".....", "#...#", "#...#", "#...#", ".####", "....#", ".###."],
+    "z": [".....", ".....", "#####", "...#.", "..#..", ".#...", "#####"],
+    "{": ["...#.", "..#..", "..#..", ".#...", "..#..", "..#..", "...#."],
+    "|": ["..#..", "..#..", "..#..", "..#..", "..#..", "..#..", "..#.."],
+    "}": [".#...", "..#..", "..#..", "...#.", "..#..", "..#..", ".#..."],
+    "~": [".....", ".....", ".#...", "#.#.#", "...#.", ".....", "....."],
+}
+
+# --------------------------------------------------------------- tiny 3x5
+#
+# Capitals only: lowercase letters reuse the capital's bitmap, which at
+# three pixels wide is what every 3x5 font does anyway.
+
+TINY = {
+    " ": [],
+    "!": [".#.", ".#.", ".#.", "...", ".#."],
+    '"': ["#.#", "#.#"],
+    "#": ["#.#", "###", "#.#", "###", "#.#"],
+    "$": [".##", "##.", ".#.", ".##", "##."],
+    "%": ["#.#", "..#", ".#.", "#..", "#.#"],
+    "&": [".#.", "#.#", ".#.", "#.#", ".##"],
+    "'": [".#.", ".#."],
+    "(": ["..#", ".#.", ".#.", ".#.", "..#"],
+    ")": ["#..", ".#.", ".#.", ".#.", "#.."],
+    "*": ["#.#", ".#.", "#.#"],
+    "+": ["...", ".#.", "###", ".#.", "..."],
+    ",": ["...", "...", "...", ".#.", "#.."],
+    "-": ["...", "...", "###", "...", "..."],
+    ".": ["...", "...", "...", "...", ".#."],
+    "/": ["..#", "..#", ".#.", "#..", "#.."],
+    "0": ["###", "#.#", "#.#", "#.#", "###"],
+    "1": [".#.", "##.", ".#.", ".#.", "###"],
+    "2": ["###", "..#", "###", "#..", "###"],
+    "3": ["###", "..#", ".##", "..#", "###"],
+    "4": ["#.#", "#.#", "###", "..#", "..#"],
+    "5": ["###", "#..", "###", "..#", "###"],
+    "6": ["###", "#..", "###", "#.#", "###"],
+    "7": ["###", "..#", ".#.", ".#.", ".#."],
+    "8": ["###", "#.#", "###", "#.#", "###"],
+    "9": ["###", "#.#", "###", "..#", "###"],
+    ":": ["...", ".#.", "...", ".#.", "..."],
+    ";": ["...", ".#.", "...", ".#.", "#.."],
+    "<": ["..#", ".#.", "#..", ".#.", "..#"],
+    "=": ["...", "###", "...", "###", "..."],
+    ">": ["#..", ".#.", "..#", ".#.", "#.."],
+    "?": ["###", "..#", ".##", "...", ".#."],
+    "@": ["###", "#.#", "###", "#..", ".##"],
+    "A": [".#.", "#.#", "###", "#.#", "#.#"],
+    "B": ["##.", "#.#", "##.", "#.#", "##."],
+    "C": [".##", "#..", "#..", "#..", ".##"],
+    "D": ["##.", "#.#", "#.#", "#.#", "##."],
+    "E": ["###", "#..", "##.", "#..", "###"],
+    "F": ["###", "#..", "##.", "#..", "#.."],
+    "G": [".##", "#..", "#.#", "#.#", ".##"],
+    "H": ["#.#", "#.#", "###", "#.#", "#.#"],
+    "I": ["###", ".#.", ".#.", ".#.", "###"],
+    "J": ["..#", "..#", "..#", "#.#", ".#."],
+    "K": ["#.#", "#.#", "##.", "#.#", "#.#"],
+    "L": ["#..", "#..", "#..", "#..", "###"],
+    "M": ["#.#", "###", "###", "#.#", "#.#"],
+    "N": ["##.", "#.#", "#.#", "#.#", "#.#"],
+    "O": [".#.", "#.#", "#.#", "#.#", ".#."],
+    "P": ["##.", "#.#", "##.", "#..", "#.."],
+    "Q": [".#.", "#.#", "#.#", "##.", ".##"],
+    "R": ["##.", "#.#", "##.", "#.#", "#.#"],
+    "S": [".##", "#..", ".#.", "..#", "##."],
+    "T": ["###", ".#.", ".#.", ".#.", ".#."],
+    "U": ["#.#", "#.#", "#.#", "#.#", "###"],
+    "V": ["#.#", "#.#", "#.#", "#.#", ".#."],
+    "W": ["#.#", "#.#", "###", "###", "#.#"],
+    "X": ["#.#", "#.#", ".#.", "#.#", "#.#"],
+    "Y": ["#.#", "#.#", ".#.", ".#.", ".#."],
+    "Z": ["###", "..#", ".#.", "#..", "###"],
+    "[": ["##.", "#..", "#..", "#..", "##."],
+    "\\": ["#..", "#..", ".#.", "..#", "..#"],
+    "]": [".##", "..#", "..#", "..#", ".##"],
+    "^": [".#.", "#.#"],
+    "_": ["...", "...", "...", "...", "###"],
+    "`": ["#..", ".#."],
+    "{": [".##", ".#.", "#..", ".#.", ".##"],
+    "|": [".#.", ".#.", ".#.", ".#.", ".#."],
+    "}": ["##.", ".#.", "..#", ".#.", "##."],
+    "~": ["...", ".##", "##.", "...", "..."],
+}
+for code in range(ord("a"), ord("z") + 1):
+    TINY[chr(code)] = TINY[chr(code).upper()]
+
+
+class Font:
+    def __init__(self, name, const, art, width, ascent, descent, advance):
+        self.name = name
+        self.const = const
+        self.art = art
+        self.width = width
+        self.ascent = ascent
+        self.descent = descent
+        self.advance = advance
+
+    @property
+    def height(self) -> int:
+        return self.ascent + self.descent
+
+    def rows(self, char: str) -> list[int]:
+        """The glyph's rows as bytes, MSB leftmost, padded to the full cell."""
+        art = self.art[char]
+        assert len(art) <= self.height, f"{self.name} {char!r}: too many rows"
+        out = []
+        for row in art + ["." * self.width] * (self.height - len(art)):
+            assert len(row) == self.width, f"{self.name} {char!r}: row {row!r}"
+            assert set(row) <= {"#", "."}, f"{self.name} {char!r}: row {row!r}"
+            byte = 0
+            for i, pixel in enumerate(row):
+                if pixel == "#":
+                    byte |= 0x80 >> i
+            out.append(byte)
+        return out
+
+
+FONTS = [
+    Font("classic-5x8", "CLASSIC_ROWS", CLASSIC, width=5, ascent=7, descent=1, advance=6),
+    Font("tiny-3x5", "TINY_ROWS", TINY, width=3, ascent=5, descent=0, advance=4),
+]
+
+
+def bdf(font: Font) -> str:
+    chars = [chr(code) for code in range(FIRST, LAST + 1)]
+    lines = [
+        "STARTFONT 2.1",
+        f"FONT -lightplayer-{font.name}-medium-r-normal--{font.height}-{font.height * 10}-75-75-c-{font.advance * 10}-iso10646-1",
+        f"SIZE {font.height} 75 75",
+        f"FONTBOUNDINGBOX {font.width} {font.height} 0 {-font.descent}",
+        "STARTPROPERTIES 2",
+        f"FONT_ASCENT {font.ascent}",
+        f"FONT_DESCENT {font.descent}",
+        "ENDPROPERTIES",
+        f"CHARS {len(chars)}",
+    ]
+    for char in chars:
+        code = ord(char)
+        lines += [
+            f"STARTCHAR U+{code:04X}",
+            f"ENCODING {code}",
+            f"SWIDTH {font.advance * 1000 // font.height} 0",
+            f"DWIDTH {font.advance} 0",
+            f"BBX {font.width} {font.height} 0 {-font.descent}",
+            "BITMAP",
+        ]
+        lines += [f"{row:02X}" for row in font.rows(char)]
+        lines.append("ENDCHAR")
+    lines.append("ENDFONT")
+    return "\n".join(lines) + "\n"
+
+
+def rust_tables() -> str:
+    out = [
+        "//! Packed glyph rows for the built-in fonts, ASCII `' '..='~'`.",
+        "//!",
+        "//! Generated by `scripts/gen-fonts.py` from the same art as",
+        "//! `src/fonts/*.bdf`; do not edit by hand.",
+        "",
+    ]
+    for font in FONTS:
+        rows = [row for code in range(FIRST, LAST + 1) for row in font.rows(chr(code))]
+        out.append(f"/// `{font.name}`: {font.height} rows per glyph.")
+        out.append(f"pub(crate) const {font.const}: [u8; {len(rows)}] = [")
+        for code in range(FIRST, LAST + 1):
+            glyph = font.rows(chr(code))
+            body = ", ".join(f"0x{row:02X}" for row in glyph)
+            out.append(f"    {body}, // {chr(code)!r}" if chr(code) != "\\" else f"    {body}, // '\\\\'")
+        out.append("];")
+        out.append("")
+    return "\n".join(out)
+
+
+def outputs() -> dict[str, str]:
+    files = {os.path.join("src", "fonts", f"{font.name}.bdf"): bdf(font) for font in FONTS}
+    files[os.path.join("src", "builtin_glyphs.rs")] = rust_tables()
+    return files
+
+
+def main() -> int:
+    mode = sys.argv[1] if len(sys.argv) > 1 else ""
+    for font in FONTS:
+        missing = [chr(c) for c in range(FIRST, LAST + 1) if chr(c) not in font.art]
+        assert not missing, f"{font.name} is missing {missing}"
+    drift = False
+    for rel, text in outputs().items():
+        path = os.path.join(ROOT, rel)
+        if mode == "--write":
+            os.makedirs(os.path.dirname(path), exist_ok=True)
+            with open(path, "w", encoding="ascii") as f:
+                f.write(text)
+            print(f"wrote {rel}")
+        elif mode == "--check":
+            try:
+                with open(path, encoding="ascii") as f:
+                    current = f.read()
+            except FileNotFoundError:
+                current = None
+            if current != text:
+                print(f"drift: {rel}")
+                drift = True
+        else:
+            print(f"{rel} ({len(text)} bytes)")
+    return 1 if drift else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,377 @@
+//! BDF (Glyph Bitmap Distribution Format 2.1) parsing.
+//!
+//! Only what drawing needs survives the parse: each encoded glyph's bitmap,
+//! bounding box and advance, and the font's ascent and descent. Properties,
+//! comments and scalable widths are skipped, so a font costs its bitmaps
+//! plus 20 bytes per glyph, not its text.
+
+use alloc::vec::Vec;
+
+use crate::{FontError, Glyph, MAX_BDF_BITMAP_BYTES, MAX_GLYPH_SIZE};
+
+/// A parsed BDF font.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BdfFont {
+    /// Sorted by code point, one entry per code point.
+    glyphs: Vec<BdfGlyph>,
+    bitmap: Vec<u8>,
+    ascent: i32,
+    descent: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BdfGlyph {
+    codepoint: u32,
+    width: u16,
+    height: u16,
+    x_offset: i16,
+    y_offset: i16,
+    advance: i16,
+    /// Offset of the first row in `BdfFont::bitmap`.
+    start: u32,
+}
+
+/// Bounding box as BDF writes it: size, then the bottom-left corner
+/// relative to the pen.
+#[derive(Clone, Copy, Default)]
+struct BoundingBox {
+    width: u32,
+    height: u32,
+    x_offset: i32,
+    y_offset: i32,
+}
+
+/// The glyph between `STARTCHAR` and `ENDCHAR`.
+struct PendingGlyph {
+    codepoint: Option<u32>,
+    advance: Option<i32>,
+    bbx: BoundingBox,
+    start: usize,
+}
+
+impl BdfFont {
+    /// Parse a BDF file. Glyphs without a usable encoding (`ENCODING -1`
+    /// with no alternative, or a value that is not a Unicode scalar) are
+    /// skipped; a repeated code point keeps its first glyph.
+    pub fn parse(text: &[u8]) -> Result<Self, FontError> {
+        let mut lines = text
+            .split(|&byte| byte == b'\n')
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+            .zip(1u32..);
+
+        let first = lines.by_ref().find(|(line, _)| !trim(line).is_empty());
+        if !first.is_some_and(|(line, _)| keyword(line).0 == b"STARTFONT") {
+            return Err(FontError::NotBdf);
+        }
+
+        let mut font_bbx = BoundingBox::default();
+        let mut font_advance = None;
+        let mut ascent = None;
+        let mut descent = None;
+        let mut glyphs = Vec::new();
+        let mut bitmap = Vec::new();
+        let mut pending: Option<PendingGlyph> = None;
+
+        while let Some((line, number)) = lines.next() {
+            let malformed = |what| FontError::Malformed { line: number, what };
+            let (word, rest) = keyword(line);
+            match word {
+                b"FONTBOUNDINGBOX" => {
+                    font_bbx = bounding_box(rest).ok_or(malformed("FONTBOUNDINGBOX"))?
+                }
+                b"FONT_ASCENT" => ascent = Some(int(rest, 0).ok_or(malformed("FONT_ASCENT"))?),
+                b"FONT_DESCENT" => descent = Some(int(rest, 0).ok_or(malformed("FONT_DESCENT"))?),
+                b"DWIDTH" => {
+                    let advance = int(rest, 0).ok_or(malformed("DWIDTH"))?;
+                    match &mut pending {
+                        Some(glyph) => glyph.advance = Some(advance),
+                        None => font_advance = Some(advance),
+                    }
+                }
+                b"STARTCHAR" => {
+                    if pending.is_some() {
+                        return Err(malformed("STARTCHAR inside a glyph"));
+                    }
+                    pending = Some(PendingGlyph {
+                        codepoint: None,
+                        advance: font_advance,
+                        bbx: font_bbx,
+                        start: bitmap.len(),
+                    });
+                }
+                b"ENCODING" => {
+                    let glyph = pending
+                        .as_mut()
+                        .ok_or(malformed("ENCODING outside a glyph"))?;
+                    let standard = int(rest, 0).ok_or(malformed("ENCODING"))?;
+                    // `ENCODING -1 n` names a non-standard encoding `n`,
+                    // which for Unicode fonts is still the code point.
+                    let code = if standard >= 0 {
+                        Some(standard)
+                    } else {
+                        int(rest, 1)
+                    };
+                    glyph.codepoint = code
+                        .and_then(|code| u32::try_from(code).ok())
+                        .filter(|&code| char::from_u32(code).is_some());
+                }
+                b"BBX" => {
+                    let glyph = pending.as_mut().ok_or(malformed("BBX outside a glyph"))?;
+                    glyph.bbx = bounding_box(rest).ok_or(malformed("BBX"))?;
+                }
+                b"BITMAP" => {
+                    let glyph = pending
+                        .as_ref()
+                        .ok_or(malformed("BITMAP outside a glyph"))?;
+                    let BoundingBox { width, height, .. } = glyph.bbx;
+                    if width > MAX_GLYPH_SIZE || height > MAX_GLYPH_SIZE {
+                        return Err(FontError::TooLarge);
+                    }
+                    let stride = width.div_ceil(8) as usize;
+                    if bitmap.len() + stride * height as usize > MAX_BDF_BITMAP_BYTES {
+                        return Err(FontError::TooLarge);
+                    }
+                    for _ in 0..height {
+                        let (row, number) = lines.next().ok_or(malformed("bitmap ends early"))?;
+                        let malformed = FontError::Malformed {
+                            line: number,
+                            what: "bitmap row",
+                        };
+                        let row = trim(row);
+                        // Rows may be padded past the glyph's width (some
+                        // tools pad to 16 or 32 bits); the extra is paper.
+                        if row.len() < stride * 2 {
+                            return Err(malformed);
+                        }
+                        for pair in row[..stride * 2].chunks_exact(2) {
+                            let byte = hex(pair[0])
+                                .zip(hex(pair[1]))
+                                .map(|(high, low)| high << 4 | low)
+                                .ok_or(malformed.clone())?;
+                            bitmap.push(byte);
+                        }
+                    }
+                }
+                b"ENDCHAR" => {
+                    let glyph = pending.take().ok_or(malformed("ENDCHAR outside a glyph"))?;
+                    let rows = glyph.bbx.width.div_ceil(8) as usize * glyph.bbx.height as usize;
+                    match glyph.codepoint {
+                        Some(codepoint) if bitmap.len() - glyph.start == rows => {
+                            glyphs.push(BdfGlyph {
+                                codepoint,
+                                width: glyph.bbx.width as u16,
+                                height: glyph.bbx.height as u16,
+                                x_offset: clamp_i16(glyph.bbx.x_offset),
+                                y_offset: clamp_i16(glyph.bbx.y_offset),
+                                advance: clamp_i16(
+                                    glyph
+                                        .advance
+                                        .unwrap_or(glyph.bbx.x_offset + glyph.bbx.width as i32),
+                                ),
+                                start: glyph.start as u32,
+                            });
+                        }
+                        Some(_) => return Err(malformed("glyph without a BITMAP")),
+                        // Unencoded: drop whatever bitmap it read.
+                        None => bitmap.truncate(glyph.start),
+                    }
+                }
+                b"ENDFONT" => break,
+                _ => {}
+            }
+        }
+        if pending.is_some() {
+            return Err(FontError::Malformed {
+                line: text.iter().filter(|&&byte| byte == b'\n').count() as u32 + 1,
+                what: "file ends inside a glyph",
+            });
+        }
+
+        // Stable sort, so dedup keeps the first glyph for each code point.
+        glyphs.sort_by_key(|glyph| glyph.codepoint);
+        glyphs.dedup_by_key(|glyph| glyph.codepoint);
+        Ok(Self {
+            glyphs,
+            bitmap,
+            ascent: ascent.unwrap_or(font_bbx.height as i32 + font_bbx.y_offset),
+            descent: descent.unwrap_or(-font_bbx.y_offset),
+        })
+    }
+
+    /// The glyph for `ch`, if the font has one.
+    pub fn glyph(&self, ch: char) -> Option<Glyph<'_>> {
+        let index = self
+            .glyphs
+            .binary_search_by_key(&u32::from(ch), |glyph| glyph.codepoint)
+            .ok()?;
+        let glyph = &self.glyphs[index];
+        let start = glyph.start as usize;
+        let len = usize::from(glyph.width).div_ceil(8) * usize::from(glyph.height);
+        Some(Glyph::new(
+            u32::from(glyph.width),
+            u32::from(glyph.height),
+            i32::from(glyph.x_offset),
+            i32::from(glyph.y_offset),
+            i32::from(glyph.advance),
+            &self.bitmap[start..start + len],
+        ))
+    }
+
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Pixels from the baseline to the top of a line.
+    pub fn ascent(&self) -> i32 {
+        self.ascent
+    }
+
+    /// Pixels from the baseline to the bottom of a line.
+    pub fn descent(&self) -> i32 {
+        self.descent
+    }
+}
+
+fn trim(line: &[u8]) -> &[u8] {
+    line.trim_ascii()
+}
+
+/// The line's first word and the rest.
+fn keyword(line: &[u8]) -> (&[u8], &[u8]) {
+    let line = trim(line);
+    match line.iter().position(u8::is_ascii_whitespace) {
+        Some(end) => (&line[..end], &line[end..]),
+        None => (line, &[]),
+    }
+}
+
+/// The `index`th whitespace-separated integer in `rest`.
+fn int(rest: &[u8], index: usize) -> Option<i32> {
+    let word = rest
+        .split(u8::is_ascii_whitespace)
+        .filter(|word| !word.is_empty())
+        .nth(index)?;
+    core::str::from_utf8(word).ok()?.parse().ok()
+}
+
+fn bounding_box(rest: &[u8]) -> Option<BoundingBox> {
+    Some(BoundingBox {
+        width: u32::try_from(int(rest, 0)?).ok()?,
+        height: u32::try_from(int(rest, 1)?).ok()?,
+        x_offset: int(rest, 2)?,
+        y_offset: int(rest, 3)?,
+    })
+}
+
+fn hex(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|value| value as u8)
+}
+
+fn clamp_i16(value: i32) -> i16 {
+    value.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuiltinFont;
+
+    const TWO_GLYPHS: &[u8] = b"STARTFONT 2.1\r
+COMMENT padded rows, an alternate encoding and an unencoded glyph\r
+FONTBOUNDINGBOX 9 10 -1 -2\r
+STARTPROPERTIES 1\r
+COPYRIGHT \"Public domain\"\r
+ENDPROPERTIES\r
+CHARS 3\r
+STARTCHAR A\r
+ENCODING 65\r
+SWIDTH 500 0\r
+DWIDTH 10 0\r
+BBX 9 2 -1 0\r
+BITMAP\r
+FF800000\r
+80800000\r
+ENDCHAR\r
+STARTCHAR private\r
+ENCODING -1\r
+BBX 1 1 0 0\r
+BITMAP\r
+80\r
+ENDCHAR\r
+STARTCHAR euro\r
+ENCODING -1 8364\r
+BBX 2 1 0 3\r
+BITMAP\r
+C0\r
+ENDCHAR\r
+ENDFONT\r
+";
+
+    #[test]
+    fn parses_metrics_padding_and_encodings() {
+        let font = BdfFont::parse(TWO_GLYPHS).expect("parse");
+        assert_eq!(font.glyph_count(), 2);
+        assert_eq!((font.ascent(), font.descent()), (8, 2));
+
+        let a = font.glyph('A').expect("A");
+        assert_eq!((a.width, a.height, a.x_offset, a.advance), (9, 2, -1, 10));
+        assert!((0..9).all(|x| a.ink(x, 0)));
+        assert!(a.ink(0, 1) && a.ink(8, 1) && !a.ink(4, 1));
+
+        let euro = font.glyph('€').expect("alternate encoding");
+        assert_eq!((euro.y_offset, euro.advance), (3, 2));
+        assert!(font.glyph('B').is_none());
+    }
+
+    /// The shipped BDF files and the embedded tables come from the same art,
+    /// so parsing one must reproduce the other pixel for pixel.
+    #[test]
+    fn shipped_bdf_files_match_the_builtin_tables() {
+        for (builtin, text) in [
+            (
+                BuiltinFont::Classic5x8,
+                &include_bytes!("fonts/classic-5x8.bdf")[..],
+            ),
+            (
+                BuiltinFont::Tiny3x5,
+                &include_bytes!("fonts/tiny-3x5.bdf")[..],
+            ),
+        ] {
+            let font = BdfFont::parse(text).expect("parse shipped font");
+            assert_eq!(font.glyph_count(), 95, "{}", builtin.name());
+            assert_eq!(font.ascent(), builtin.ascent());
+            assert_eq!(font.descent(), builtin.descent());
+            for ch in ' '..='~' {
+                assert_eq!(
+                    font.glyph(ch),
+                    builtin.glyph(ch),
+                    "{} {ch:?}",
+                    builtin.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_broken_files() {
+        assert_eq!(BdfFont::parse(b"hello"), Err(FontError::NotBdf));
+        assert_eq!(
+            BdfFont::parse(b"STARTFONT 2.1\nSTARTCHAR a\nENCODING 97\nBBX 8 2 0 0\nBITMAP\nFF"),
+            Err(FontError::Malformed {
+                line: 5,
+                what: "bitmap ends early"
+            })
+        );
+        assert_eq!(
+            BdfFont::parse(b"STARTFONT 2.1\nSTARTCHAR a\nENCODING 97\nBBX 8 1 0 0\nBITMAP\nZZ\n"),
+            Err(FontError::Malformed {
+                line: 6,
+                what: "bitmap row"
+            })
+        );
+        assert_eq!(
+            BdfFont::parse(b"STARTFONT 2.1\nSTARTCHAR a\nENCODING 97\nBBX 200 1 0 0\nBITMAP\n"),
+            Err(FontError::TooLarge)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Fonts compiled into the crate, so a text node draws without any asset.
+
+use crate::Glyph;
+use crate::builtin_glyphs::{CLASSIC_ROWS, TINY_ROWS};
+
+const FIRST: u32 = 0x20;
+const LAST: u32 = 0x7E;
+
+/// A built-in bitmap font covering printable ASCII.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BuiltinFont {
+    /// 5×7 capitals with one descender row; 6 px advance, 8 px lines.
+    #[default]
+    Classic5x8,
+    /// 3×5 capitals (lowercase draws as capitals); 4 px advance, 5 px
+    /// lines. Two lines fit an 11-pixel-tall panel.
+    Tiny3x5,
+}
+
+impl BuiltinFont {
+    pub const ALL: [BuiltinFont; 2] = [BuiltinFont::Classic5x8, BuiltinFont::Tiny3x5];
+
+    /// The file stem of the same font as a BDF under `src/fonts/`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Classic5x8 => "classic-5x8",
+            Self::Tiny3x5 => "tiny-3x5",
+        }
+    }
+
+    /// Pixels from the baseline to the top of the tallest glyph.
+    pub const fn ascent(self) -> i32 {
+        match self {
+            Self::Classic5x8 => 7,
+            Self::Tiny3x5 => 5,
+        }
+    }
+
+    /// Pixels below the baseline.
+    pub const fn descent(self) -> i32 {
+        match self {
+            Self::Classic5x8 => 1,
+            Self::Tiny3x5 => 0,
+        }
+    }
+
+    /// The glyph for `ch`, or `None` outside printable ASCII.
+    pub fn glyph(self, ch: char) -> Option<Glyph<'static>> {
+        let code = u32::from(ch);
+        if !(FIRST..=LAST).contains(&code) {
+            return None;
+        }
+        let (rows, width, advance): (&'static [u8], u32, i32) = match self {
+            Self::Classic5x8 => (&CLASSIC_ROWS, 5, 6),
+            Self::Tiny3x5 => (&TINY_ROWS, 3, 4),
+        };
+        let height = (self.ascent() + self.descent()) as u32;
+        let start = (code - FIRST) as usize * height as usize;
+        Some(Glyph::new(
+            width,
+            height,
+            0,
+            -self.descent(),
+            advance,
+            &rows[start..start + height as usize],
+        ))
+    }
+}
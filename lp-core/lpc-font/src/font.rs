@@ -0,0 +1,52 @@
+//! A font a text node draws with: built in, or parsed from a BDF asset.
+
+use crate::{BdfFont, BuiltinFont, Glyph};
+
+/// Character drawn in place of one the font lacks.
+pub const REPLACEMENT: char = '?';
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Font {
+    Builtin(BuiltinFont),
+    Bdf(BdfFont),
+}
+
+impl Default for Font {
+    fn default() -> Self {
+        Self::Builtin(BuiltinFont::default())
+    }
+}
+
+impl Font {
+    pub fn glyph(&self, ch: char) -> Option<Glyph<'_>> {
+        match self {
+            Self::Builtin(font) => font.glyph(ch),
+            Self::Bdf(font) => font.glyph(ch),
+        }
+    }
+
+    /// The glyph for `ch`, else the font's [`REPLACEMENT`], else nothing
+    /// (the character then takes no space).
+    pub fn glyph_or_replacement(&self, ch: char) -> Option<Glyph<'_>> {
+        self.glyph(ch).or_else(|| self.glyph(REPLACEMENT))
+    }
+
+    pub fn ascent(&self) -> i32 {
+        match self {
+            Self::Builtin(font) => font.ascent(),
+            Self::Bdf(font) => font.ascent(),
+        }
+    }
+
+    pub fn descent(&self) -> i32 {
+        match self {
+            Self::Builtin(font) => font.descent(),
+            Self::Bdf(font) => font.descent(),
+        }
+    }
+
+    /// Baseline-to-baseline distance, before [`crate::LINE_GAP`].
+    pub fn line_height(&self) -> i32 {
+        (self.ascent() + self.descent()).max(1)
+    }
+}
@@ -0,0 +1,29 @@
+//! Errors for font parsing.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontError {
+    /// The file does not start with `STARTFONT`.
+    NotBdf,
+    /// A line breaks the BDF format; `line` counts from 1.
+    Malformed { line: u32, what: &'static str },
+    /// Glyph bitmaps would take more than [`crate::MAX_BDF_BITMAP_BYTES`],
+    /// or one glyph is larger than [`crate::MAX_GLYPH_SIZE`].
+    TooLarge,
+}
+
+impl core::fmt::Display for FontError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotBdf => write!(f, "not a BDF font"),
+            Self::Malformed { line, what } => write!(f, "malformed BDF at line {line}: {what}"),
+            Self::TooLarge => write!(
+                f,
+                "font is too large (glyphs over {0}×{0} px or bitmaps over {1} bytes)",
+                crate::MAX_GLYPH_SIZE,
+                crate::MAX_BDF_BITMAP_BYTES
+            ),
+        }
+    }
+}
+
+impl core::error::Error for FontError {}
@@ -0,0 +1,52 @@
+//! One glyph's bitmap, borrowed from its font.
+
+/// A glyph bitmap plus the metrics that place it against the pen.
+///
+/// Coordinates follow BDF: the pen sits on the baseline, `x_offset` is the
+/// bitmap's left edge right of the pen and `y_offset` its bottom edge above
+/// the baseline (negative for descenders).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph<'a> {
+    pub width: u32,
+    pub height: u32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+    /// How far the pen moves after this glyph.
+    pub advance: i32,
+    /// `height` rows of `stride` bytes, most significant bit leftmost.
+    rows: &'a [u8],
+    stride: usize,
+}
+
+impl<'a> Glyph<'a> {
+    pub(crate) fn new(
+        width: u32,
+        height: u32,
+        x_offset: i32,
+        y_offset: i32,
+        advance: i32,
+        rows: &'a [u8],
+    ) -> Self {
+        let stride = width.div_ceil(8) as usize;
+        debug_assert!(rows.len() >= stride * height as usize);
+        Self {
+            width,
+            height,
+            x_offset,
+            y_offset,
+            advance,
+            rows,
+            stride,
+        }
+    }
+
+    /// Whether pixel (`x`, `y`) is inked, `y` counting down from the
+    /// bitmap's top row. Outside the bitmap is paper.
+    pub fn ink(&self, x: u32, y: u32) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let byte = self.rows[y as usize * self.stride + (x / 8) as usize];
+        byte & (0x80 >> (x % 8)) != 0
+    }
+}
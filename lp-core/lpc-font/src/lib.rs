@@ -0,0 +1,42 @@
+//! Bitmap fonts and text layout for text nodes.
+//!
+//! A [`Font`] is one of the [`BuiltinFont`]s compiled into the crate or a
+//! [`BdfFont`] parsed from a project asset. A [`TextCanvas`] lays a string
+//! out in it on a fixed-size canvas — aligned ([`TextAlign`]), scrolled by
+//! time ([`TextScroll`]) — and records, per inked pixel, where in a palette
+//! its color comes from ([`TextColoring`]). Turning palette positions into
+//! colors is the caller's job.
+//!
+//! The built-in fonts are generated by `scripts/gen-fonts.py`, which also
+//! writes them out as BDF files under `src/fonts/`: a project can copy one
+//! as the starting point for its own.
+//!
+//! Boundary: no filesystem, no engine types.
+
+#![no_std]
+
+extern crate alloc;
+
+mod bdf;
+mod builtin;
+mod builtin_glyphs;
+mod font;
+mod font_error;
+mod glyph;
+mod text_canvas;
+
+pub use bdf::BdfFont;
+pub use builtin::BuiltinFont;
+pub use font::{Font, REPLACEMENT};
+pub use font_error::FontError;
+pub use glyph::Glyph;
+pub use text_canvas::{
+    LINE_GAP, PALETTE_STEPS, TextAlign, TextCanvas, TextColoring, TextOptions, TextScroll,
+};
+
+/// Largest glyph width or height a BDF font may declare.
+pub const MAX_GLYPH_SIZE: u32 = 64;
+
+/// Most bitmap bytes a parsed BDF font may hold: a full Latin-1 set at
+/// 16×16 is under 8 KiB.
+pub const MAX_BDF_BITMAP_BYTES: usize = 64 * 1024;
@@ -0,0 +1,467 @@
+//! Laying a string out on a fixed canvas, scrolled and colored.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Font;
+
+/// Blank pixel rows between two lines of text.
+pub const LINE_GAP: i32 = 1;
+
+/// Palette entries a pixel can name: one per texel of the engine's baked
+/// palette strip.
+pub const PALETTE_STEPS: u32 = 256;
+
+/// Which way the text moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextScroll {
+    /// Held in place by [`TextAlign`], centered vertically.
+    #[default]
+    None,
+    /// Marquee: enters at the right edge, leaves at the left, repeats.
+    Left,
+    /// Enters at the left edge, leaves at the right.
+    Right,
+    /// Credits: enters at the bottom, leaves at the top.
+    Up,
+    /// Enters at the top, leaves at the bottom.
+    Down,
+}
+
+/// Where lines sit across the canvas, and against each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// Where in the palette each inked pixel takes its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextColoring {
+    /// The palette spans the text's width and travels with it.
+    #[default]
+    Across,
+    /// Each character takes one color, stepping through the palette.
+    PerCharacter,
+    /// Every pixel takes the palette's first color.
+    Solid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextOptions {
+    pub width: u32,
+    pub height: u32,
+    pub scroll: TextScroll,
+    /// Scroll speed in canvas pixels per second. Zero, negative or
+    /// non-finite holds the text still, as [`TextScroll::None`].
+    pub speed: f32,
+    pub align: TextAlign,
+    pub coloring: TextColoring,
+}
+
+impl Default for TextOptions {
+    fn default() -> Self {
+        Self {
+            width: 32,
+            height: 8,
+            scroll: TextScroll::None,
+            speed: 8.0,
+            align: TextAlign::Center,
+            coloring: TextColoring::Across,
+        }
+    }
+}
+
+/// What the canvas last drew; drawing it again would change nothing.
+#[derive(Debug, Clone, PartialEq)]
+struct Drawn {
+    text: String,
+    options: TextOptions,
+    origin: (i32, i32),
+}
+
+/// A text render target: one palette index (or paper) per pixel.
+///
+/// Scrolling moves whole pixels, so between steps [`TextCanvas::render`]
+/// finds the same origin and keeps what it drew.
+#[derive(Debug, Clone, Default)]
+pub struct TextCanvas {
+    width: u32,
+    height: u32,
+    /// Row-major; 0 is paper, `1 + i` is palette index `i`.
+    ink: Vec<u16>,
+    drawn: Option<Drawn>,
+}
+
+impl TextCanvas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Draw `text` as it stands `seconds` into its scroll. Returns whether
+    /// any pixel may have changed.
+    pub fn render(&mut self, font: &Font, text: &str, options: &TextOptions, seconds: f32) -> bool {
+        let layout = Layout::measure(font, text);
+        let origin = layout.origin(options, seconds);
+        let drawn = Drawn {
+            text: String::from(text),
+            options: *options,
+            origin,
+        };
+        if !self.ink.is_empty() && self.drawn.as_ref() == Some(&drawn) {
+            return false;
+        }
+
+        self.width = options.width;
+        self.height = options.height;
+        let len = options.width as usize * options.height as usize;
+        if self.ink.len() == len {
+            self.ink.fill(0);
+        } else {
+            self.ink = vec![0; len];
+        }
+        self.draw(font, text, &layout, options, origin);
+        self.drawn = Some(drawn);
+        true
+    }
+
+    /// Forget what was drawn, e.g. because the font changed underneath it.
+    pub fn invalidate(&mut self) {
+        self.drawn = None;
+    }
+
+    /// Drop the pixel buffer; the next [`TextCanvas::render`] redraws.
+    pub fn release(&mut self) {
+        self.ink = Vec::new();
+        self.drawn = None;
+    }
+
+    /// The palette index inked at (`x`, `y`), or `None` for paper and for
+    /// anything outside the canvas.
+    pub fn palette_index(&self, x: u32, y: u32) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let ink = *self.ink.get((y * self.width + x) as usize)?;
+        (ink != 0).then(|| (ink - 1) as u8)
+    }
+
+    /// Whether the canvas holds a drawing at all (it does not before the
+    /// first render or after [`TextCanvas::release`]).
+    pub fn is_drawn(&self) -> bool {
+        !self.ink.is_empty()
+    }
+
+    fn draw(
+        &mut self,
+        font: &Font,
+        text: &str,
+        layout: &Layout,
+        options: &TextOptions,
+        (origin_x, origin_y): (i32, i32),
+    ) {
+        let (width, height) = (options.width as i32, options.height as i32);
+        let line_step = font.line_height() + LINE_GAP;
+        let mut char_index = 0u32;
+        for (line_number, (line, line_width)) in lines(text).zip(&layout.line_widths).enumerate() {
+            let baseline = origin_y + line_number as i32 * line_step + font.ascent();
+            let mut pen = layout.line_offset(*line_width, options.align);
+            for ch in line.chars() {
+                let Some(glyph) = font.glyph_or_replacement(ch) else {
+                    char_index += 1;
+                    continue;
+                };
+                let left = origin_x + pen + glyph.x_offset;
+                let top = baseline - glyph.y_offset - glyph.height as i32;
+                let visible = left < width
+                    && left + glyph.width as i32 > 0
+                    && top < height
+                    && top + glyph.height as i32 > 0;
+                if visible {
+                    for gy in 0..glyph.height {
+                        let y = top + gy as i32;
+                        if !(0..height).contains(&y) {
+                            continue;
+                        }
+                        for gx in 0..glyph.width {
+                            let x = left + gx as i32;
+                            if !(0..width).contains(&x) || !glyph.ink(gx, gy) {
+                                continue;
+                            }
+                            let position = match options.coloring {
+                                TextColoring::Across => {
+                                    ((pen + glyph.x_offset + gx as i32) as f32 + 0.5)
+                                        / layout.block_width.max(1) as f32
+                                }
+                                TextColoring::PerCharacter => {
+                                    (char_index as f32 + 0.5) / layout.char_count.max(1) as f32
+                                }
+                                TextColoring::Solid => 0.0,
+                            };
+                            self.ink[(y * width + x) as usize] = 1 + palette_index(position);
+                        }
+                    }
+                }
+                pen += glyph.advance;
+                char_index += 1;
+            }
+        }
+    }
+}
+
+/// The lines of `text`, split on `\n` with any `\r` dropped.
+fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+/// `position` in `[0,1]` as a palette index.
+fn palette_index(position: f32) -> u16 {
+    let index = (position.clamp(0.0, 1.0) * PALETTE_STEPS as f32) as u32;
+    index.min(PALETTE_STEPS - 1) as u16
+}
+
+/// Line widths and the block they make.
+struct Layout {
+    line_widths: Vec<i32>,
+    block_width: i32,
+    block_height: i32,
+    char_count: u32,
+}
+
+impl Layout {
+    fn measure(font: &Font, text: &str) -> Self {
+        let mut char_count = 0;
+        let line_widths: Vec<i32> = lines(text)
+            .map(|line| {
+                line.chars()
+                    .map(|ch| {
+                        char_count += 1;
+                        font.glyph_or_replacement(ch)
+                            .map_or(0, |glyph| glyph.advance)
+                    })
+                    .sum()
+            })
+            .collect();
+        let count = line_widths.len() as i32;
+        Self {
+            block_width: line_widths.iter().copied().max().unwrap_or(0).max(0),
+            block_height: count * font.line_height() + (count - 1) * LINE_GAP,
+            line_widths,
+            char_count,
+        }
+    }
+
+    /// Where a line of `width` starts inside the block.
+    fn line_offset(&self, width: i32, align: TextAlign) -> i32 {
+        match align {
+            TextAlign::Left => 0,
+            TextAlign::Center => (self.block_width - width) / 2,
+            TextAlign::Right => self.block_width - width,
+        }
+    }
+
+    /// The block's top-left corner on the canvas `seconds` into the scroll.
+    fn origin(&self, options: &TextOptions, seconds: f32) -> (i32, i32) {
+        let (width, height) = (options.width as i32, options.height as i32);
+        let aligned_x = match options.align {
+            TextAlign::Left => 0,
+            TextAlign::Center => (width - self.block_width) / 2,
+            TextAlign::Right => width - self.block_width,
+        };
+        let centered_y = (height - self.block_height) / 2;
+        let moving = options.speed.is_finite() && options.speed > 0.0;
+        let scroll = if moving {
+            options.scroll
+        } else {
+            TextScroll::None
+        };
+        let horizontal = |seconds| travelled(seconds, options.speed, self.block_width + width);
+        let vertical = |seconds| travelled(seconds, options.speed, self.block_height + height);
+        match scroll {
+            TextScroll::None => (aligned_x, centered_y),
+            TextScroll::Left => (width - horizontal(seconds), centered_y),
+            TextScroll::Right => (horizontal(seconds) - self.block_width, centered_y),
+            TextScroll::Up => (aligned_x, height - vertical(seconds)),
+            TextScroll::Down => (aligned_x, vertical(seconds) - self.block_height),
+        }
+    }
+}
+
+/// Whole pixels covered after `seconds` at `speed`, wrapped to `period`.
+/// Negative times have not started.
+fn travelled(seconds: f32, speed: f32, period: i32) -> i32 {
+    let distance = seconds * speed;
+    if period <= 0 || !distance.is_finite() || distance <= 0.0 {
+        return 0;
+    }
+    // `as` floors a positive float and saturates past u64::MAX.
+    ((distance as u64) % period as u64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuiltinFont;
+
+    const CLASSIC: Font = Font::Builtin(BuiltinFont::Classic5x8);
+
+    fn options(width: u32, height: u32) -> TextOptions {
+        TextOptions {
+            width,
+            height,
+            coloring: TextColoring::Solid,
+            ..TextOptions::default()
+        }
+    }
+
+    /// The canvas as rows of '#' and '.', for comparing against glyph art.
+    fn art(canvas: &TextCanvas) -> Vec<String> {
+        (0..canvas.height())
+            .map(|y| {
+                (0..canvas.width())
+                    .map(|x| match canvas.palette_index(x, y) {
+                        Some(_) => '#',
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn still_text_is_aligned_and_centered_vertically() {
+        let mut canvas = TextCanvas::new();
+        // "HI" is 12 px wide; 16 px leaves 2 either side when centered.
+        canvas.render(&CLASSIC, "HI", &options(16, 8), 0.0);
+        assert_eq!(
+            art(&canvas),
+            [
+                "..#...#..###....",
+                "..#...#...#.....",
+                "..#...#...#.....",
+                "..#####...#.....",
+                "..#...#...#.....",
+                "..#...#...#.....",
+                "..#...#..###....",
+                "................",
+            ]
+        );
+
+        let right = TextOptions {
+            align: TextAlign::Right,
+            ..options(16, 8)
+        };
+        canvas.render(&CLASSIC, "HI", &right, 0.0);
+        assert_eq!(art(&canvas)[3], "....#####...#...");
+    }
+
+    #[test]
+    fn marquee_enters_right_and_wraps() {
+        let marquee = TextOptions {
+            scroll: TextScroll::Left,
+            speed: 4.0,
+            ..options(8, 8)
+        };
+        let mut canvas = TextCanvas::new();
+        // Period: text width 6 + canvas width 8 = 14 px, 3.5 s at 4 px/s.
+        canvas.render(&CLASSIC, "I", &marquee, 0.0);
+        assert!(art(&canvas).iter().all(|row| row == "........"));
+        canvas.render(&CLASSIC, "I", &marquee, 0.5);
+        assert_eq!(art(&canvas)[0], ".......#");
+        canvas.render(&CLASSIC, "I", &marquee, 2.0);
+        assert_eq!(art(&canvas)[0], ".###....");
+        canvas.render(&CLASSIC, "I", &marquee, 3.5 + 0.5);
+        assert_eq!(art(&canvas)[0], ".......#");
+    }
+
+    #[test]
+    fn credits_scroll_up_line_by_line() {
+        let credits = TextOptions {
+            scroll: TextScroll::Up,
+            speed: 1.0,
+            align: TextAlign::Left,
+            ..options(6, 8)
+        };
+        let mut canvas = TextCanvas::new();
+        // After 8 s the block's top row reaches the canvas top.
+        canvas.render(&CLASSIC, "L\nT", &credits, 8.0);
+        assert_eq!(art(&canvas)[6], "#####.");
+        // 9 more rows: the second line (8 px + 1 gap below the first) is up.
+        canvas.render(&CLASSIC, "L\nT", &credits, 17.0);
+        assert_eq!(art(&canvas)[0], "#####.");
+        assert_eq!(art(&canvas)[1], "..#...");
+    }
+
+    #[test]
+    fn zero_speed_holds_the_text_still() {
+        let held = TextOptions {
+            scroll: TextScroll::Left,
+            speed: 0.0,
+            ..options(16, 8)
+        };
+        let mut canvas = TextCanvas::new();
+        canvas.render(&CLASSIC, "HI", &held, 100.0);
+        let mut still = TextCanvas::new();
+        still.render(&CLASSIC, "HI", &options(16, 8), 0.0);
+        assert_eq!(art(&canvas), art(&still));
+    }
+
+    #[test]
+    fn coloring_picks_palette_positions() {
+        let mut canvas = TextCanvas::new();
+        let per_char = TextOptions {
+            coloring: TextColoring::PerCharacter,
+            align: TextAlign::Left,
+            ..options(12, 8)
+        };
+        canvas.render(&CLASSIC, "II", &per_char, 0.0);
+        // Two characters: centers at 1/4 and 3/4 of the palette.
+        assert_eq!(canvas.palette_index(2, 1), Some(64));
+        assert_eq!(canvas.palette_index(8, 1), Some(192));
+
+        let across = TextOptions {
+            coloring: TextColoring::Across,
+            ..per_char
+        };
+        canvas.render(&CLASSIC, "II", &across, 0.0);
+        let first = canvas.palette_index(1, 0).expect("inked");
+        let last = canvas.palette_index(9, 0).expect("inked");
+        assert!(first < last, "{first} then {last}");
+    }
+
+    #[test]
+    fn missing_characters_draw_the_replacement() {
+        let mut canvas = TextCanvas::new();
+        canvas.render(&CLASSIC, "é", &options(6, 8), 0.0);
+        let mut question = TextCanvas::new();
+        question.render(&CLASSIC, "?", &options(6, 8), 0.0);
+        assert_eq!(art(&canvas), art(&question));
+    }
+
+    #[test]
+    fn unchanged_frames_are_not_redrawn() {
+        let marquee = TextOptions {
+            scroll: TextScroll::Left,
+            speed: 1.0,
+            ..options(8, 8)
+        };
+        let mut canvas = TextCanvas::new();
+        assert!(canvas.render(&CLASSIC, "A", &marquee, 1.0));
+        assert!(!canvas.render(&CLASSIC, "A", &marquee, 1.5));
+        assert!(canvas.render(&CLASSIC, "A", &marquee, 2.0));
+        canvas.release();
+        assert!(canvas.render(&CLASSIC, "A", &marquee, 2.0));
+    }
+}
@@ -102,6 +102,49 @@ pub trait Ws281xOutput {
         Ok(())
     }
 
+    /// Has the frame begun by [`Ws281xOutput::start`] finished? Never blocks.
+    ///
+    /// `true` means [`Ws281xOutput::wait_complete`] would return without
+    /// waiting — it must still be called, to collect the frame's outcome
+    /// before the bytes are touched. The default is `true`, right for an
+    /// implementation whose `start` is the blocking default.
+    ///
+    /// ⚠️ Like [`Ws281xOutput::background_tx_safe`], a delegating wrapper must
+    /// forward this explicitly.
+    fn poll_complete(&mut self) -> bool {
+        true
+    }
+
+    /// How many frames one [`Ws281xOutput::start_frames`] may carry.
+    ///
+    /// Above 1, the output presents the frames after the first on its own,
+    /// back to back as the wire frees — refreshes between the caller's
+    /// renders that cost the caller's core nothing. The default is 1.
+    ///
+    /// ⚠️ Like [`Ws281xOutput::background_tx_safe`], a delegating wrapper must
+    /// forward this explicitly.
+    fn max_frames_per_start(&self) -> usize {
+        1
+    }
+
+    /// [`Ws281xOutput::start`] for `count` frames of equal length, back to
+    /// back in `frames`, transmitted in order without the caller.
+    ///
+    /// [`Ws281xOutput::wait_complete`] returns once the output is done with
+    /// them: after the last, or earlier when the output cut the run short to
+    /// serve another wire — the frames after the first are refreshes, never
+    /// owed. `count` must not exceed [`Ws281xOutput::max_frames_per_start`].
+    /// The default starts the first frame alone.
+    ///
+    /// # Safety
+    ///
+    /// [`Ws281xOutput::start`]'s contract, over all of `frames`.
+    unsafe fn start_frames(&mut self, frames: &[u8], count: usize) -> Result<(), OutputError> {
+        let len = frames.len() / count.max(1);
+        // SAFETY: the caller's contract covers all of `frames`.
+        unsafe { self.start(&frames[..len]) }
+    }
+
     /// May a frame this output started keep transmitting while the render
     /// core does arbitrary work?
     ///
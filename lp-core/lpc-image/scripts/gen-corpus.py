@@ -0,0 +1,403 @@
+#!/usr/bin/env python3
+"""Generate the lpc-image reference corpus under lpc-image/src/corpus/.
+
+Every image is built from explicit pixel data by the encoders in this script
+(a GIF writer with its own LZW encoder, a BMP writer covering each supported
+bit depth), and its expected decode is computed here too: `<name>.rgba` holds
+every frame at the file's native size, row-major RGBA, frames back to back,
+after GIF compositing (transparency, disposal) as a browser shows it. The
+Rust decoder shares no code with this script, so a corpus pass means two
+independent readings of the formats agree.
+
+Usage:
+    python3 lp-core/lpc-image/scripts/gen-corpus.py           # dry-run: list files
+    python3 lp-core/lpc-image/scripts/gen-corpus.py --write   # write the corpus
+    python3 lp-core/lpc-image/scripts/gen-corpus.py --check   # diff against disk; exit 1 on drift
+"""
+
+from __future__ import annotations
+
+import os
+import random
+import struct
+import sys
+
+CORPUS_DIR = os.path.normpath(
+    os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "src", "corpus")
+)
+
+# ---------------------------------------------------------------- GIF writer
+
+
+def lzw_encode(indices: list[int], min_code_size: int, clear_when_full: bool) -> bytes:
+    """GIF-flavoured LZW: variable code width from min_code_size+1 to 12."""
+    clear = 1 << min_code_size
+    end = clear + 1
+    out = bytearray()
+    acc = 0
+    acc_bits = 0
+
+    def put(code: int, width: int) -> None:
+        nonlocal acc, acc_bits
+        acc |= code << acc_bits
+        acc_bits += width
+        while acc_bits >= 8:
+            out.append(acc & 0xFF)
+            acc >>= 8
+            acc_bits -= 8
+
+    def fresh() -> dict[tuple[int, ...], int]:
+        return {(i,): i for i in range(clear)}
+
+    table = fresh()
+    next_code = end + 1
+    width = min_code_size + 1
+    put(clear, width)
+    current: tuple[int, ...] = ()
+    for index in indices:
+        extended = current + (index,)
+        if extended in table:
+            current = extended
+            continue
+        put(table[current], width)
+        if next_code < 4096:
+            table[extended] = next_code
+            next_code += 1
+            if next_code > (1 << width) and width < 12:
+                width += 1
+        elif clear_when_full:
+            put(clear, width)
+            table = fresh()
+            next_code = end + 1
+            width = min_code_size + 1
+        current = (index,)
+    if current:
+        put(table[current], width)
+        # the decoder defines one more code on reading that last one
+        if next_code < 4096 and next_code + 1 > (1 << width) and width < 12:
+            width += 1
+    put(end, width)
+    if acc_bits:
+        out.append(acc & 0xFF)
+    return bytes(out)
+
+
+def sub_blocks(data: bytes) -> bytes:
+    out = bytearray()
+    for i in range(0, len(data), 255):
+        chunk = data[i : i + 255]
+        out.append(len(chunk))
+        out += chunk
+    out.append(0)
+    return bytes(out)
+
+
+def palette_bits(count: int) -> int:
+    bits = 1
+    while (1 << bits) < count:
+        bits += 1
+    return bits
+
+
+def palette_bytes(palette: list[tuple[int, int, int]]) -> bytes:
+    size = 1 << palette_bits(len(palette))
+    padded = palette + [(0, 0, 0)] * (size - len(palette))
+    return b"".join(bytes(color) for color in padded)
+
+
+class GifFrame:
+    def __init__(self, x, y, width, height, indices, delay=10, disposal=0,
+                 transparent=None, palette=None, interlaced=False):
+        self.x, self.y, self.width, self.height = x, y, width, height
+        self.indices = indices  # row-major, top to bottom
+        self.delay = delay
+        self.disposal = disposal
+        self.transparent = transparent
+        self.palette = palette  # local palette, or None for the global one
+        self.interlaced = interlaced
+
+
+def interlace_order(height: int) -> list[int]:
+    rows = []
+    for start, step in ((0, 8), (4, 8), (2, 4), (1, 2)):
+        rows.extend(range(start, height, step))
+    return rows
+
+
+def write_gif(width, height, palette, frames, loop=True, clear_when_full=False,
+              trailer=True) -> bytes:
+    out = bytearray(b"GIF89a")
+    bits = palette_bits(len(palette))
+    out += struct.pack("<HHBBB", width, height, 0x80 | (bits - 1), 0, 0)
+    out += palette_bytes(palette)
+    if loop:
+        out += b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00"
+    for frame in frames:
+        flags = (frame.disposal << 2) | (1 if frame.transparent is not None else 0)
+        out += struct.pack("<BBBBHBB", 0x21, 0xF9, 4, flags, frame.delay,
+                           frame.transparent or 0, 0)
+        table = frame.palette or palette
+        descriptor_flags = 0x40 if frame.interlaced else 0
+        if frame.palette:
+            descriptor_flags |= 0x80 | (palette_bits(len(frame.palette)) - 1)
+        out += struct.pack("<BHHHHB", 0x2C, frame.x, frame.y, frame.width,
+                           frame.height, descriptor_flags)
+        if frame.palette:
+            out += palette_bytes(frame.palette)
+        min_code_size = max(2, palette_bits(len(table)))
+        rows = interlace_order(frame.height) if frame.interlaced else range(frame.height)
+        stored = []
+        for row in rows:
+            stored += frame.indices[row * frame.width : (row + 1) * frame.width]
+        out.append(min_code_size)
+        out += sub_blocks(lzw_encode(stored, min_code_size, clear_when_full))
+    if trailer:
+        out.append(0x3B)
+    return bytes(out)
+
+
+def composite_gif(width, height, palette, frames) -> bytes:
+    """Reference compositing: the canvas starts transparent, background
+    disposal clears to transparent (as browsers do), previous restores."""
+    canvas = [(0, 0, 0, 0)] * (width * height)
+    shown = bytearray()
+    pending = None
+    for frame in frames:
+        if pending is not None:
+            disposal, rect, saved = pending
+            if disposal == 2:
+                x0, y0, w, h = rect
+                for y in range(y0, min(y0 + h, height)):
+                    for x in range(x0, min(x0 + w, width)):
+                        canvas[y * width + x] = (0, 0, 0, 0)
+            elif disposal == 3:
+                canvas = saved
+        saved = list(canvas) if frame.disposal == 3 else None
+        table = frame.palette or palette
+        for fy in range(frame.height):
+            for fx in range(frame.width):
+                x, y = frame.x + fx, frame.y + fy
+                if x >= width or y >= height:
+                    continue
+                index = frame.indices[fy * frame.width + fx]
+                if index == frame.transparent:
+                    continue
+                canvas[y * width + x] = table[index] + (255,)
+        for pixel in canvas:
+            shown += bytes(pixel)
+        pending = (frame.disposal, (frame.x, frame.y, frame.width, frame.height), saved)
+    return bytes(shown)
+
+
+# ---------------------------------------------------------------- BMP writer
+
+
+def write_bmp(width, height, bits, pixels, palette=None, masks=None,
+              top_down=False, header_size=40) -> bytes:
+    """pixels: row-major top-to-bottom; palette indices for bits <= 8, else
+    (r, g, b, a) tuples packed through `masks` (or plain BGR for 24-bit)."""
+    stride = (bits * width + 31) // 32 * 4
+    rows = []
+    for y in range(height):
+        row = bytearray()
+        line = pixels[y * width : (y + 1) * width]
+        if bits <= 8:
+            acc, acc_bits = 0, 0
+            for index in line:
+                acc = (acc << bits) | index
+                acc_bits += bits
+                if acc_bits == 8:
+                    row.append(acc)
+                    acc, acc_bits = 0, 0
+            if acc_bits:
+                row.append(acc << (8 - acc_bits))
+        elif bits == 24:
+            for r, g, b, _ in line:
+                row += bytes((b, g, r))
+        else:
+            for pixel in line:
+                value = 0
+                for channel, mask in zip(pixel, masks):
+                    if mask:
+                        shift = (mask & -mask).bit_length() - 1
+                        top = mask >> shift
+                        value |= (round(channel * top / 255) << shift) & mask
+                row += value.to_bytes(bits // 8, "little")
+        row += b"\x00" * (stride - len(row))
+        rows.append(bytes(row))
+    if not top_down:
+        rows.reverse()
+    compression = 0
+    extra = b""
+    if masks is not None:
+        compression = 3
+        if header_size == 40:
+            extra = struct.pack("<III", *masks[:3])
+    table = b""
+    if bits <= 8:
+        table = b"".join(bytes((b, g, r, 0)) for r, g, b in palette)
+    info = struct.pack("<IiiHHIIiiII", header_size, width, -height if top_down else height,
+                       1, bits, compression, stride * height, 2835, 2835,
+                       len(palette) if bits <= 8 else 0, 0)
+    if header_size > 40:
+        info += struct.pack("<IIII", *masks) if masks else b"\x00" * 16
+        info += b"\x00" * (header_size - len(info))
+    offset = 14 + len(info) + len(extra) + len(table)
+    body = info + extra + table + b"".join(rows)
+    return b"BM" + struct.pack("<IHHI", 14 + len(body), 0, 0, offset) + body
+
+
+def bmp_expected(width, height, bits, pixels, palette=None, masks=None) -> bytes:
+    out = bytearray()
+    for pixel in pixels:
+        if bits <= 8:
+            out += bytes(palette[pixel]) + b"\xff"
+        elif bits == 24:
+            out += bytes(pixel[:3]) + b"\xff"
+        else:
+            channels = []
+            for channel, mask in zip(pixel, masks):
+                if not mask:
+                    channels.append(255 if len(channels) == 3 else 0)
+                    continue
+                top = mask >> ((mask & -mask).bit_length() - 1)
+                stored = round(channel * top / 255)
+                channels.append((stored * 255 + top // 2) // top)
+            out += bytes(channels)
+    return bytes(out)
+
+
+# ---------------------------------------------------------------- the corpus
+
+
+def rng(seed: int) -> random.Random:
+    return random.Random(seed)
+
+
+def corpus() -> dict[str, bytes]:
+    files: dict[str, bytes] = {}
+
+    # -- BMP: one of each depth, odd widths to exercise row padding
+    r = rng(1)
+    pal8 = [(r.randrange(256), r.randrange(256), r.randrange(256)) for _ in range(40)]
+    px = [r.randrange(40) for _ in range(13 * 7)]
+    files["pal8_13x7.bmp"] = write_bmp(13, 7, 8, px, palette=pal8)
+    files["pal8_13x7.rgba"] = bmp_expected(13, 7, 8, px, palette=pal8)
+
+    pal4 = [(i * 17, 255 - i * 17, (i * 53) % 256) for i in range(16)]
+    px = [r.randrange(16) for _ in range(7 * 5)]
+    files["pal4_7x5.bmp"] = write_bmp(7, 5, 4, px, palette=pal4)
+    files["pal4_7x5.rgba"] = bmp_expected(7, 5, 4, px, palette=pal4)
+
+    mono = [(10, 20, 30), (250, 240, 230)]
+    px = [(x * y + x) % 3 == 0 for y in range(3) for x in range(10)]
+    px = [int(p) for p in px]
+    files["mono1_10x3.bmp"] = write_bmp(10, 3, 1, px, palette=mono)
+    files["mono1_10x3.rgba"] = bmp_expected(10, 3, 1, px, palette=mono)
+
+    px = [(r.randrange(256), r.randrange(256), r.randrange(256), 255) for _ in range(5 * 4)]
+    files["rgb24_5x4.bmp"] = write_bmp(5, 4, 24, px)
+    files["rgb24_5x4.rgba"] = bmp_expected(5, 4, 24, px)
+
+    masks565 = (0xF800, 0x07E0, 0x001F, 0)
+    px = [(r.randrange(256), r.randrange(256), r.randrange(256), 255) for _ in range(6 * 4)]
+    files["rgb565_6x4.bmp"] = write_bmp(6, 4, 16, px, masks=masks565)
+    files["rgb565_6x4.rgba"] = bmp_expected(6, 4, 16, px, masks=masks565)
+
+    masks8888 = (0x00FF0000, 0x0000FF00, 0x000000FF, 0xFF000000)
+    px = [(x * 40, y * 60, 200, (x + y) * 30) for y in range(4) for x in range(5)]
+    files["argb32_topdown.bmp"] = write_bmp(5, 4, 32, px, masks=masks8888,
+                                            top_down=True, header_size=108)
+    files["argb32_topdown.rgba"] = bmp_expected(5, 4, 32, px, masks=masks8888)
+
+    # a 4x2 sheet of 8x8 cells, each a solid color with a 1px dark border
+    colors = [(255, 0, 0), (0, 255, 0), (0, 0, 255), (255, 255, 0),
+              (0, 255, 255), (255, 0, 255), (255, 128, 0), (128, 128, 128)]
+    px = []
+    for y in range(16):
+        for x in range(32):
+            cell = (y // 8) * 4 + x // 8
+            edge = x % 8 in (0, 7) or y % 8 in (0, 7)
+            px.append((16, 16, 16, 255) if edge else colors[cell] + (255,))
+    files["sheet_4x2.bmp"] = write_bmp(32, 16, 24, px)
+    files["sheet_4x2.rgba"] = bmp_expected(32, 16, 24, px)
+
+    # -- GIF: compositing, disposal, transparency, local palettes, timing
+    palette = [(0, 0, 0), (255, 255, 255), (255, 0, 0), (0, 255, 0),
+               (0, 0, 255), (255, 255, 0)]
+    frames = [
+        # full background
+        GifFrame(0, 0, 16, 12, [(x // 4 + y // 3) % 2 for y in range(12) for x in range(16)],
+                 delay=10, disposal=1),
+        # a red box, cleared to transparent afterwards
+        GifFrame(2, 2, 5, 4, [2] * 20, delay=20, disposal=2),
+        # a green ring with a transparent hole, restored afterwards
+        GifFrame(8, 3, 6, 6, [0 if 1 <= x <= 4 and 1 <= y <= 4 else 3
+                              for y in range(6) for x in range(6)],
+                 delay=5, disposal=3, transparent=0),
+        # local palette, partly off-screen to the right
+        GifFrame(12, 8, 6, 4, [x % 3 for y in range(4) for x in range(6)],
+                 delay=0, palette=[(9, 9, 9), (100, 150, 200), (200, 150, 100)]),
+    ]
+    files["disposal_anim.gif"] = write_gif(16, 12, palette, frames)
+    files["disposal_anim.rgba"] = composite_gif(16, 12, palette, frames)
+
+    r = rng(2)
+    gray = [(i * 8, i * 8, i * 8) for i in range(32)]
+    px = [(x + 2 * y + r.randrange(3)) % 32 for y in range(15) for x in range(20)]
+    frames = [GifFrame(0, 0, 20, 15, px, interlaced=True)]
+    files["interlaced.gif"] = write_gif(20, 15, gray, frames, loop=False)
+    files["interlaced.rgba"] = composite_gif(20, 15, gray, frames)
+
+    # 256-color noise overflows the 4096-entry table: once kept full (the
+    # common encoder choice), once reset with clear codes
+    r = rng(3)
+    full = [(r.randrange(256), r.randrange(256), r.randrange(256)) for _ in range(256)]
+    px = [r.randrange(256) for _ in range(64 * 64)]
+    frames = [GifFrame(0, 0, 64, 64, px)]
+    files["noise_full_table.gif"] = write_gif(64, 64, full, frames, loop=False)
+    files["noise_full_table.rgba"] = composite_gif(64, 64, full, frames)
+    files["noise_clears.gif"] = write_gif(64, 64, full, frames, loop=False,
+                                          clear_when_full=True)
+    files["noise_clears.rgba"] = composite_gif(64, 64, full, frames)
+
+    # two colors: the smallest legal code size
+    two = [(0, 0, 0), (255, 255, 255)]
+    frames = [GifFrame(0, 0, 9, 9, [(x ^ y) & 1 for y in range(9) for x in range(9)])]
+    files["two_color.gif"] = write_gif(9, 9, two, frames, loop=False)
+    files["two_color.rgba"] = composite_gif(9, 9, two, frames)
+
+    # three frames and no trailer, as cut-short downloads end
+    frames = [GifFrame(0, 0, 4, 4, [i % 6] * 16, delay=7) for i in range(3)]
+    files["no_trailer.gif"] = write_gif(4, 4, palette, frames, trailer=False)
+    files["no_trailer.rgba"] = composite_gif(4, 4, palette, frames)
+
+    return files
+
+
+def main() -> int:
+    files = corpus()
+    mode = sys.argv[1] if len(sys.argv) > 1 else ""
+    drift = False
+    for name, data in sorted(files.items()):
+        path = os.path.join(CORPUS_DIR, name)
+        if mode == "--write":
+            os.makedirs(CORPUS_DIR, exist_ok=True)
+            with open(path, "wb") as f:
+                f.write(data)
+        elif mode == "--check":
+            try:
+                with open(path, "rb") as f:
+                    same = f.read() == data
+            except FileNotFoundError:
+                same = False
+            if not same:
+                print(f"drift: {name}")
+                drift = True
+        else:
+            print(f"{name} ({len(data)} bytes)")
+    return 1 if drift else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
@@ -0,0 +1,207 @@
+//! Uncompressed BMP: 1/4/8-bit paletted, 16-bit, 24-bit and 32-bit rows,
+//! with `BI_BITFIELDS` masks. Rows are fixed-stride, so any row is one
+//! windowed read away and a scaled-down draw reads only the rows it samples.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ImageError;
+use crate::image_source::{ImageSource, SourceReader, read_exact_at};
+
+const BI_RGB: u32 = 0;
+const BI_BITFIELDS: u32 = 3;
+const BI_ALPHABITFIELDS: u32 = 6;
+
+#[derive(Debug, Clone)]
+pub(crate) struct BmpImage {
+    pub width: u32,
+    pub height: u32,
+    bits: u16,
+    /// Rows stored bottom row first (a positive height in the header).
+    bottom_up: bool,
+    pixel_offset: u64,
+    stride: u64,
+    palette: Vec<[u8; 3]>,
+    /// Red, green, blue and alpha masks for 16- and 32-bit pixels; an alpha
+    /// mask of zero means opaque.
+    masks: [u32; 4],
+}
+
+impl BmpImage {
+    pub fn sniff(header: &[u8]) -> bool {
+        header.starts_with(b"BM")
+    }
+
+    pub fn open(source: &dyn ImageSource) -> Result<Self, ImageError> {
+        let mut reader = SourceReader::new(source, 0);
+        let mut signature = [0u8; 2];
+        reader.read_exact(&mut signature)?;
+        if !Self::sniff(&signature) {
+            return Err(ImageError::UnknownFormat);
+        }
+        reader.seek(10);
+        let pixel_offset = u64::from(reader.u32_le()?);
+        let header_size = reader.u32_le()?;
+        if header_size < 40 {
+            return Err(ImageError::Unsupported("OS/2 BMP header"));
+        }
+        let width = reader.u32_le()? as i32;
+        let height = reader.u32_le()? as i32;
+        reader.skip(2); // planes
+        let bits = reader.u16_le()?;
+        let compression = reader.u32_le()?;
+        reader.skip(12); // image size, resolution
+        let colors_used = reader.u32_le()?;
+        if width <= 0 || height == 0 || height == i32::MIN {
+            return Err(ImageError::Malformed("BMP has no pixels"));
+        }
+        let (width, bottom_up) = (width as u32, height > 0);
+        let height = height.unsigned_abs();
+        crate::check_dimensions(width, height)?;
+
+        let masks = match (compression, bits) {
+            (BI_RGB, 16) => [0x7c00, 0x03e0, 0x001f, 0],
+            (BI_RGB, 32) => [0x00ff_0000, 0x0000_ff00, 0x0000_00ff, 0],
+            (BI_RGB, 1 | 4 | 8 | 24) => [0; 4],
+            (BI_BITFIELDS | BI_ALPHABITFIELDS, 16 | 32) => {
+                // masks follow a 40-byte header, and sit inside larger ones
+                reader.seek(14 + 40);
+                let mut masks = [0u32; 4];
+                let with_alpha = compression == BI_ALPHABITFIELDS || header_size >= 56;
+                let count = if with_alpha { 4 } else { 3 };
+                for mask in &mut masks[..count] {
+                    *mask = reader.u32_le()?;
+                }
+                masks
+            }
+            (BI_RGB, _) => return Err(ImageError::Unsupported("BMP bit depth")),
+            _ => return Err(ImageError::Unsupported("compressed BMP")),
+        };
+
+        let palette = if bits <= 8 {
+            let count = match colors_used {
+                0 => 1usize << bits,
+                n => (n as usize).min(1 << bits),
+            };
+            let table = 14 + u64::from(header_size);
+            let mut raw = vec![0u8; count * 4];
+            read_exact_at(source, table, &mut raw)?;
+            raw.chunks_exact(4)
+                .map(|bgrx| [bgrx[2], bgrx[1], bgrx[0]])
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            width,
+            height,
+            bits,
+            bottom_up,
+            pixel_offset,
+            stride: (u64::from(bits) * u64::from(width)).div_ceil(32) * 4,
+            palette,
+            masks,
+        })
+    }
+
+    /// Bytes one stored row needs in the scratch buffer.
+    pub fn stride(&self) -> usize {
+        self.stride as usize
+    }
+
+    /// Read row `y` (0 is the top) into `scratch`, which holds
+    /// [`Self::stride`] bytes.
+    pub fn read_row(
+        &self,
+        source: &dyn ImageSource,
+        y: u32,
+        scratch: &mut [u8],
+    ) -> Result<(), ImageError> {
+        let stored = if self.bottom_up {
+            self.height - 1 - y
+        } else {
+            y
+        };
+        let offset = self.pixel_offset + u64::from(stored) * self.stride;
+        read_exact_at(source, offset, &mut scratch[..self.stride as usize])
+    }
+
+    /// Pixel `x` of a row read by [`Self::read_row`], as RGBA.
+    pub fn pixel(&self, row: &[u8], x: u32) -> [u8; 4] {
+        let x = x as usize;
+        let index = match self.bits {
+            1 => (row[x / 8] >> (7 - x % 8)) & 1,
+            4 => (row[x / 2] >> (4 * (1 - x % 2))) & 0xf,
+            8 => row[x],
+            16 => {
+                let value = u32::from(u16::from_le_bytes([row[2 * x], row[2 * x + 1]]));
+                return self.masked(value);
+            }
+            24 => return [row[3 * x + 2], row[3 * x + 1], row[3 * x], 255],
+            _ => {
+                let bytes = [row[4 * x], row[4 * x + 1], row[4 * x + 2], row[4 * x + 3]];
+                return self.masked(u32::from_le_bytes(bytes));
+            }
+        };
+        match self.palette.get(usize::from(index)) {
+            Some([r, g, b]) => [*r, *g, *b, 255],
+            None => [0, 0, 0, 255],
+        }
+    }
+
+    fn masked(&self, value: u32) -> [u8; 4] {
+        let [r, g, b, a] = self.masks;
+        let alpha = if a == 0 { 255 } else { channel(value, a) };
+        [
+            channel(value, r),
+            channel(value, g),
+            channel(value, b),
+            alpha,
+        ]
+    }
+}
+
+/// The field of `value` under `mask`, widened to eight bits.
+fn channel(value: u32, mask: u32) -> u8 {
+    if mask == 0 {
+        return 0;
+    }
+    let field = (value & mask) >> mask.trailing_zeros();
+    let max = mask >> mask.trailing_zeros();
+    ((u64::from(field) * 255 + u64::from(max) / 2) / u64::from(max)) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_fields_widen_to_full_range() {
+        assert_eq!(channel(0x7c00, 0x7c00), 255);
+        assert_eq!(channel(0x0400, 0x7c00), 8);
+        assert_eq!(channel(0xf800, 0xf800), 255);
+        assert_eq!(channel(0x07e0, 0x07e0), 255);
+        assert_eq!(channel(0x1234, 0), 0);
+    }
+
+    #[test]
+    fn refuses_compressed_and_core_headers() {
+        let mut header = vec![0u8; 64];
+        header[..2].copy_from_slice(b"BM");
+        header[14] = 12;
+        assert_eq!(
+            BmpImage::open(&header).unwrap_err(),
+            ImageError::Unsupported("OS/2 BMP header")
+        );
+        header[14] = 40;
+        header[18] = 4; // width
+        header[22] = 4; // height
+        header[28] = 8; // bits
+        header[30] = 1; // BI_RLE8
+        assert_eq!(
+            BmpImage::open(&header).unwrap_err(),
+            ImageError::Unsupported("compressed BMP")
+        );
+    }
+}
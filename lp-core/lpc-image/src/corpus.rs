@@ -0,0 +1,307 @@
+//! The image reference corpus, shared by decoder tests, engine tests and
+//! example projects.
+//!
+//! Every file comes from `scripts/gen-corpus.py`, which writes the image and
+//! its expected decode (`.rgba`: every frame at native size, composited,
+//! back to back) with encoders that share no code with this crate. The bytes
+//! are only linked into binaries that reference them.
+
+/// One corpus image and its expected decode.
+#[derive(Debug, Clone, Copy)]
+pub struct CorpusImage {
+    pub name: &'static str,
+    pub bytes: &'static [u8],
+    pub expected: &'static [u8],
+}
+
+macro_rules! corpus_image {
+    ($name:literal, $stem:literal) => {
+        CorpusImage {
+            name: $name,
+            bytes: include_bytes!(concat!("corpus/", $name)),
+            expected: include_bytes!(concat!("corpus/", $stem, ".rgba")),
+        }
+    };
+}
+
+/// 16×12, four frames: a full background, a box disposed to transparency,
+/// a ring with a transparent hole restored to the previous canvas, and a
+/// local-palette frame hanging off the right edge.
+pub const DISPOSAL_ANIM_GIF: CorpusImage = corpus_image!("disposal_anim.gif", "disposal_anim");
+
+/// 20×15 grayscale, interlaced.
+pub const INTERLACED_GIF: CorpusImage = corpus_image!("interlaced.gif", "interlaced");
+
+/// 64×64 of 256-color noise that fills the LZW table and keeps it full.
+pub const NOISE_FULL_TABLE_GIF: CorpusImage =
+    corpus_image!("noise_full_table.gif", "noise_full_table");
+
+/// The same noise, encoded with a clear code whenever the table fills.
+pub const NOISE_CLEARS_GIF: CorpusImage = corpus_image!("noise_clears.gif", "noise_clears");
+
+/// 9×9 checkerboard at the smallest code size.
+pub const TWO_COLOR_GIF: CorpusImage = corpus_image!("two_color.gif", "two_color");
+
+/// Three 4×4 frames and no trailer.
+pub const NO_TRAILER_GIF: CorpusImage = corpus_image!("no_trailer.gif", "no_trailer");
+
+/// 13×7, 8-bit paletted (40 colors), bottom-up.
+pub const PAL8_BMP: CorpusImage = corpus_image!("pal8_13x7.bmp", "pal8_13x7");
+
+/// 7×5, 4-bit paletted.
+pub const PAL4_BMP: CorpusImage = corpus_image!("pal4_7x5.bmp", "pal4_7x5");
+
+/// 10×3, 1-bit.
+pub const MONO1_BMP: CorpusImage = corpus_image!("mono1_10x3.bmp", "mono1_10x3");
+
+/// 5×4, 24-bit.
+pub const RGB24_BMP: CorpusImage = corpus_image!("rgb24_5x4.bmp", "rgb24_5x4");
+
+/// 6×4, 16-bit 5-6-5 bit fields.
+pub const RGB565_BMP: CorpusImage = corpus_image!("rgb565_6x4.bmp", "rgb565_6x4");
+
+/// 5×4, 32-bit with an alpha mask in a V4 header, top-down.
+pub const ARGB32_BMP: CorpusImage = corpus_image!("argb32_topdown.bmp", "argb32_topdown");
+
+/// 32×16 sprite sheet: 4×2 cells of 8×8, each a solid color inside a dark
+/// one-pixel border (red, green, blue, yellow / cyan, magenta, orange, gray).
+pub const SHEET_4X2_BMP: CorpusImage = corpus_image!("sheet_4x2.bmp", "sheet_4x2");
+
+pub const ALL: &[CorpusImage] = &[
+    DISPOSAL_ANIM_GIF,
+    INTERLACED_GIF,
+    NOISE_FULL_TABLE_GIF,
+    NOISE_CLEARS_GIF,
+    TWO_COLOR_GIF,
+    NO_TRAILER_GIF,
+    PAL8_BMP,
+    PAL4_BMP,
+    MONO1_BMP,
+    RGB24_BMP,
+    RGB565_BMP,
+    ARGB32_BMP,
+    SHEET_4X2_BMP,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ImageError, ImageFormat, ImageLoop, ImageOptions, ImagePlayer, ImageScale, ImageSource,
+        SpriteSheet, palette_quantize,
+    };
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+
+    /// A source that counts bytes handed out and reads at most 32 at a
+    /// time, like a flash-backed range read.
+    struct Metered<'a> {
+        bytes: &'a [u8],
+        served: Cell<u64>,
+    }
+
+    impl<'a> Metered<'a> {
+        fn new(bytes: &'a [u8]) -> Self {
+            Self {
+                bytes,
+                served: Cell::new(0),
+            }
+        }
+    }
+
+    impl ImageSource for Metered<'_> {
+        fn size(&self) -> u64 {
+            self.bytes.len() as u64
+        }
+
+        fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError> {
+            let n = buf.len().min(32);
+            let n = self.bytes.read_at(offset, &mut buf[..n])?;
+            self.served.set(self.served.get() + n as u64);
+            Ok(n)
+        }
+    }
+
+    fn native(image: &CorpusImage) -> ImagePlayer {
+        let probe = ImagePlayer::open(&image.bytes, ImageOptions::default()).unwrap();
+        let info = probe.info();
+        let options = ImageOptions {
+            width: info.width,
+            height: info.height,
+            scale: ImageScale::Stretch,
+            ..ImageOptions::default()
+        };
+        ImagePlayer::open(&image.bytes, options).unwrap()
+    }
+
+    fn flat(pixels: &[[u8; 4]]) -> Vec<u8> {
+        pixels.iter().flatten().copied().collect()
+    }
+
+    #[test]
+    fn every_corpus_frame_decodes_to_the_reference() {
+        for image in ALL {
+            let mut player = native(image);
+            let info = player.info();
+            let frame_len = (info.width * info.height * 4) as usize;
+            assert_eq!(
+                image.expected.len(),
+                frame_len * info.frames as usize,
+                "{}: frame count",
+                image.name
+            );
+            for (frame, expected) in image.expected.chunks(frame_len).enumerate() {
+                let pixels = player.render_frame(&image.bytes, frame as u32).unwrap();
+                assert_eq!(flat(pixels), expected, "{} frame {frame}", image.name);
+            }
+        }
+    }
+
+    #[test]
+    fn jumping_back_replays_the_composite() {
+        let image = DISPOSAL_ANIM_GIF;
+        let mut player = native(&image);
+        let frame_len = 16 * 12 * 4;
+        for frame in [3u32, 1, 2, 0, 3, 2] {
+            let pixels = player.render_frame(&image.bytes, frame).unwrap();
+            let at = frame as usize * frame_len;
+            assert_eq!(flat(pixels), &image.expected[at..at + frame_len]);
+        }
+    }
+
+    #[test]
+    fn gif_timing_follows_frame_delays() {
+        let player = native(&DISPOSAL_ANIM_GIF);
+        assert_eq!(player.info().format, ImageFormat::Gif);
+        // delays 0.1, 0.2, 0.05, then 0 (plays as 0.1): 0.45s per loop
+        let frames: Vec<u32> = [0.05, 0.15, 0.32, 0.36, 0.44, 0.5]
+            .iter()
+            .map(|time| player.frame_at(*time))
+            .collect();
+        assert_eq!(frames, [0, 1, 2, 3, 3, 0]);
+    }
+
+    #[test]
+    fn a_sprite_sheet_plays_its_cells() {
+        let options = ImageOptions {
+            width: 4,
+            height: 4,
+            scale: ImageScale::Stretch,
+            fps: 2.0,
+            looping: ImageLoop::Once,
+            sheet: SpriteSheet {
+                columns: 4,
+                rows: 2,
+                frames: 7,
+            },
+            ..ImageOptions::default()
+        };
+        let source = SHEET_4X2_BMP.bytes;
+        let mut player = ImagePlayer::open(&source, options).unwrap();
+        assert_eq!(player.frame_count(), 7);
+        assert_eq!(player.frame_at(1.1), 2);
+        assert_eq!(player.frame_at(99.0), 6);
+        let pixels = player.render_at(&source, 2.6).unwrap();
+        // cell 5 (magenta): canvas column 3 samples the cell's right border
+        assert_eq!(pixels[0], [255, 0, 255, 255]);
+        assert_eq!(pixels[3], [16, 16, 16, 255]);
+    }
+
+    #[test]
+    fn scaling_samples_pixel_centers_and_skips_unsampled_rows() {
+        let image = NOISE_FULL_TABLE_GIF;
+        let options = ImageOptions {
+            width: 8,
+            height: 8,
+            scale: ImageScale::Stretch,
+            ..ImageOptions::default()
+        };
+        let metered = Metered::new(image.bytes);
+        let mut player = ImagePlayer::open(&metered, options).unwrap();
+        let pixels = player.render_frame(&metered, 0).unwrap().to_vec();
+        // canvas pixel (x, y) samples native pixel (8x + 4, 8y + 4)
+        let native_pixel = |x: usize, y: usize| {
+            let at = ((8 * y + 4) * 64 + 8 * x + 4) * 4;
+            &image.expected[at..at + 4]
+        };
+        for y in 0..8 {
+            for x in 0..8 {
+                assert_eq!(&pixels[y * 8 + x][..], native_pixel(x, y));
+            }
+        }
+
+        // a scaled BMP reads a row per canvas row, not the file
+        let bmp = SHEET_4X2_BMP;
+        let metered = Metered::new(bmp.bytes);
+        let options = ImageOptions {
+            width: 4,
+            height: 2,
+            ..options
+        };
+        let mut player = ImagePlayer::open(&metered, options).unwrap();
+        let opened = metered.served.get();
+        player.render_frame(&metered, 0).unwrap();
+        assert_eq!(metered.served.get() - opened, 2 * 96);
+    }
+
+    #[test]
+    fn fit_letterboxes_with_transparency() {
+        let image = RGB24_BMP;
+        let options = ImageOptions {
+            width: 5,
+            height: 8,
+            scale: ImageScale::Fit,
+            ..ImageOptions::default()
+        };
+        let mut player = ImagePlayer::open(&image.bytes, options).unwrap();
+        let pixels = player.render_frame(&image.bytes, 0).unwrap();
+        assert_eq!(pixels[0], [0, 0, 0, 0]);
+        assert_eq!(&pixels[2 * 5][..], &image.expected[..4]);
+        assert_eq!(pixels[7 * 5 + 4], [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn palette_quantization_and_release() {
+        let image = NOISE_CLEARS_GIF;
+        let options = ImageOptions {
+            width: 16,
+            height: 16,
+            palette_colors: 4,
+            ..ImageOptions::default()
+        };
+        let mut player = ImagePlayer::open(&image.bytes, options).unwrap();
+        let first = player.render_frame(&image.bytes, 0).unwrap().to_vec();
+        let mut colors = first.clone();
+        colors.sort_unstable();
+        colors.dedup();
+        assert!(colors.len() <= 4);
+
+        let mut direct = ImagePlayer::open(
+            &image.bytes,
+            ImageOptions {
+                palette_colors: 0,
+                ..options
+            },
+        )
+        .unwrap()
+        .render_frame(&image.bytes, 0)
+        .unwrap()
+        .to_vec();
+        palette_quantize::quantize(&mut direct, 4);
+        assert_eq!(first, direct);
+
+        assert!(player.has_frame(0));
+        player.release();
+        assert!(player.pixels().is_empty());
+        assert!(!player.has_frame(0));
+        assert_eq!(player.render_frame(&image.bytes, 0).unwrap(), &first[..]);
+    }
+
+    #[test]
+    fn unknown_bytes_are_refused() {
+        assert_eq!(
+            ImagePlayer::open(&b"\x89PNG\r\n\x1a\n".as_slice(), ImageOptions::default()).err(),
+            Some(ImageError::UnknownFormat)
+        );
+    }
+}
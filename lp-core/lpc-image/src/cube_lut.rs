@@ -0,0 +1,391 @@
+//! 3D color lookup tables in the Adobe/Resolve `.cube` format.
+//!
+//! A `.cube` file is text: a few keyword lines (`TITLE`, `LUT_3D_SIZE`,
+//! `DOMAIN_MIN`, `DOMAIN_MAX`) and then `N³` lines of three floats, red
+//! varying fastest, then green, then blue. Graders export them from
+//! Resolve, Photoshop and most camera tools, which makes them the easy way to
+//! bring a "look" onto an LED wall.
+//!
+//! The table is kept the way a shader samples it: a strip texture `N·N`
+//! texels wide and `N` tall in RGBA16, with blue slice `b` occupying columns
+//! `b·N..(b+1)·N`, red across each slice and green down it. Red and green
+//! then interpolate in the sampler; the shader mixes the two nearest blue
+//! slices itself. [`CubeLut::lookup`] is the same trilinear lookup on the
+//! CPU.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Largest `LUT_3D_SIZE` accepted. 65 is the largest size graders export;
+/// its strip is 2.2 MB, so a device wants 17 or 33.
+pub const MAX_LUT_SIZE: u32 = 65;
+
+/// Bytes per strip texel (RGBA16).
+const TEXEL_BYTES: usize = 8;
+
+/// A parsed 3D LUT.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubeLut {
+    size: u32,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    /// RGBA16 little-endian strip; see the module docs for the layout.
+    texels: Vec<u8>,
+}
+
+impl CubeLut {
+    /// Parse a `.cube` file.
+    ///
+    /// Output colors are clamped to `0.0..=1.0`. Unknown keywords are
+    /// skipped; a 1D LUT (`LUT_1D_SIZE`) is rejected.
+    pub fn parse(bytes: &[u8]) -> Result<Self, CubeLutError> {
+        let text = core::str::from_utf8(bytes).map_err(|_| CubeLutError::NotText)?;
+        let mut size: Option<u32> = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut texels = Vec::new();
+        let mut entries = 0usize;
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut words = line.split_ascii_whitespace();
+            let first = words.next().unwrap_or_default();
+            if first.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                match first {
+                    "LUT_3D_SIZE" => {
+                        if size.is_some() {
+                            return Err(CubeLutError::malformed(
+                                line_number,
+                                "LUT_3D_SIZE given twice",
+                            ));
+                        }
+                        let n = words
+                            .next()
+                            .and_then(|word| word.parse::<u32>().ok())
+                            .ok_or(CubeLutError::malformed(line_number, "bad LUT_3D_SIZE"))?;
+                        if !(2..=MAX_LUT_SIZE).contains(&n) {
+                            return Err(CubeLutError::BadSize(n));
+                        }
+                        size = Some(n);
+                        let n = n as usize;
+                        texels = vec![0; n * n * n * TEXEL_BYTES];
+                    }
+                    "LUT_1D_SIZE" => return Err(CubeLutError::OneDimensional),
+                    "DOMAIN_MIN" => domain_min = parse_triple(words, line_number)?,
+                    "DOMAIN_MAX" => domain_max = parse_triple(words, line_number)?,
+                    // TITLE, LUT_3D_INPUT_RANGE and vendor extensions.
+                    _ => {}
+                }
+                continue;
+            }
+
+            let Some(n) = size else {
+                return Err(CubeLutError::malformed(
+                    line_number,
+                    "table data before LUT_3D_SIZE",
+                ));
+            };
+            let n = n as usize;
+            if entries == n * n * n {
+                return Err(CubeLutError::WrongEntryCount {
+                    expected: entries,
+                    found: entries + 1,
+                });
+            }
+            let rgb = parse_triple(line.split_ascii_whitespace(), line_number)?;
+            let (r, g, b) = (entries % n, entries / n % n, entries / (n * n));
+            let offset = (g * n * n + b * n + r) * TEXEL_BYTES;
+            for (lane, value) in rgb.iter().chain(&[1.0]).enumerate() {
+                let unorm = (value.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16;
+                texels[offset + lane * 2..offset + lane * 2 + 2]
+                    .copy_from_slice(&unorm.to_le_bytes());
+            }
+            entries += 1;
+        }
+
+        let size = size.ok_or(CubeLutError::MissingSize)?;
+        let expected = (size as usize).pow(3);
+        if entries != expected {
+            return Err(CubeLutError::WrongEntryCount {
+                expected,
+                found: entries,
+            });
+        }
+        if (0..3).any(|lane| domain_max[lane] <= domain_min[lane]) {
+            return Err(CubeLutError::EmptyDomain);
+        }
+        Ok(Self {
+            size,
+            domain_min,
+            domain_max,
+            texels,
+        })
+    }
+
+    /// The table that maps every color to itself.
+    #[must_use]
+    pub fn identity(size: u32) -> Self {
+        let size = size.clamp(2, MAX_LUT_SIZE);
+        let n = size as usize;
+        let step = 1.0 / (n - 1) as f32;
+        let mut texels = vec![0; n * n * n * TEXEL_BYTES];
+        for b in 0..n {
+            for g in 0..n {
+                for r in 0..n {
+                    let offset = (g * n * n + b * n + r) * TEXEL_BYTES;
+                    let rgba = [r as f32 * step, g as f32 * step, b as f32 * step, 1.0];
+                    for (lane, value) in rgba.iter().enumerate() {
+                        let unorm = (value * 65535.0 + 0.5) as u16;
+                        texels[offset + lane * 2..offset + lane * 2 + 2]
+                            .copy_from_slice(&unorm.to_le_bytes());
+                    }
+                }
+            }
+        }
+        Self {
+            size,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+            texels,
+        }
+    }
+
+    /// Entries along each axis (`LUT_3D_SIZE`).
+    #[must_use]
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Input color that maps to the first entry on each axis.
+    #[must_use]
+    pub fn domain_min(&self) -> [f32; 3] {
+        self.domain_min
+    }
+
+    /// Input color that maps to the last entry on each axis.
+    #[must_use]
+    pub fn domain_max(&self) -> [f32; 3] {
+        self.domain_max
+    }
+
+    /// Strip texture width: `size²`.
+    #[must_use]
+    pub fn strip_width(&self) -> u32 {
+        self.size * self.size
+    }
+
+    /// Strip texture height: `size`.
+    #[must_use]
+    pub fn strip_height(&self) -> u32 {
+        self.size
+    }
+
+    /// The strip texture, RGBA16 little-endian, row-major.
+    #[must_use]
+    pub fn strip_rgba16(&self) -> &[u8] {
+        &self.texels
+    }
+
+    /// Grade one color: trilinear interpolation between the eight nearest
+    /// entries, with inputs outside the domain clamped to its edge.
+    #[must_use]
+    pub fn lookup(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let last = (self.size - 1) as f32;
+        let mut cell = [0usize; 3];
+        let mut frac = [0.0f32; 3];
+        for lane in 0..3 {
+            let span = self.domain_max[lane] - self.domain_min[lane];
+            let t = ((rgb[lane] - self.domain_min[lane]) / span).clamp(0.0, 1.0) * last;
+            let base = (t as usize).min(self.size as usize - 2);
+            cell[lane] = base;
+            frac[lane] = t - base as f32;
+        }
+
+        let mut out = [0.0f32; 3];
+        for corner in 0..8 {
+            let mut weight = 1.0;
+            let mut at = [0usize; 3];
+            for lane in 0..3 {
+                let high = corner >> lane & 1 == 1;
+                at[lane] = cell[lane] + usize::from(high);
+                weight *= if high { frac[lane] } else { 1.0 - frac[lane] };
+            }
+            let entry = self.entry(at[0], at[1], at[2]);
+            for lane in 0..3 {
+                out[lane] += entry[lane] * weight;
+            }
+        }
+        out
+    }
+
+    fn entry(&self, r: usize, g: usize, b: usize) -> [f32; 3] {
+        let n = self.size as usize;
+        let offset = (g * n * n + b * n + r) * TEXEL_BYTES;
+        core::array::from_fn(|lane| {
+            let at = offset + lane * 2;
+            f32::from(u16::from_le_bytes([self.texels[at], self.texels[at + 1]])) / 65535.0
+        })
+    }
+}
+
+fn parse_triple<'a>(
+    mut words: impl Iterator<Item = &'a str>,
+    line: usize,
+) -> Result<[f32; 3], CubeLutError> {
+    let mut out = [0.0; 3];
+    for value in &mut out {
+        *value = words
+            .next()
+            .and_then(|word| word.parse::<f32>().ok())
+            .ok_or(CubeLutError::malformed(line, "expected three numbers"))?;
+    }
+    if words.next().is_some() {
+        return Err(CubeLutError::malformed(line, "expected three numbers"));
+    }
+    Ok(out)
+}
+
+/// Why a `.cube` file did not parse.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CubeLutError {
+    /// The file is not UTF-8 text.
+    NotText,
+    /// A line breaks the format; `line` counts from 1.
+    Malformed { line: usize, what: &'static str },
+    /// No `LUT_3D_SIZE` line.
+    MissingSize,
+    /// `LUT_3D_SIZE` outside `2..=`[`MAX_LUT_SIZE`].
+    BadSize(u32),
+    /// A 1D LUT: only 3D tables are supported.
+    OneDimensional,
+    /// The table does not hold `size³` entries.
+    WrongEntryCount { expected: usize, found: usize },
+    /// `DOMAIN_MAX` is not above `DOMAIN_MIN` on every channel.
+    EmptyDomain,
+}
+
+impl CubeLutError {
+    fn malformed(line: usize, what: &'static str) -> Self {
+        Self::Malformed { line, what }
+    }
+}
+
+impl core::fmt::Display for CubeLutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotText => write!(f, "cube LUT is not UTF-8 text"),
+            Self::Malformed { line, what } => write!(f, "cube LUT line {line}: {what}"),
+            Self::MissingSize => write!(f, "cube LUT has no LUT_3D_SIZE"),
+            Self::BadSize(size) => write!(f, "cube LUT size {size} is outside 2..={MAX_LUT_SIZE}"),
+            Self::OneDimensional => write!(f, "1D cube LUTs are not supported"),
+            Self::WrongEntryCount { expected, found } => {
+                write!(f, "cube LUT has {found} entries, expected {expected}")
+            }
+            Self::EmptyDomain => write!(f, "cube LUT DOMAIN_MAX must exceed DOMAIN_MIN"),
+        }
+    }
+}
+
+impl core::error::Error for CubeLutError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use super::*;
+
+    /// A 2³ table, red fastest, that inverts every color.
+    const INVERT_2: &str = "# inverts\n\
+        TITLE \"invert\"\n\
+        LUT_3D_SIZE 2\n\
+        \n\
+        1 1 1\n0 1 1\n1 0 1\n0 0 1\n\
+        1 1 0\n0 1 0\n1 0 0\n0 0 0\n";
+
+    #[test]
+    fn parses_a_table_in_red_fastest_order() {
+        let lut = CubeLut::parse(INVERT_2.as_bytes()).expect("parse");
+        assert_eq!(lut.size(), 2);
+        assert_eq!(lut.domain_min(), [0.0; 3]);
+        assert_eq!(lut.domain_max(), [1.0; 3]);
+        assert_eq!(lut.lookup([0.0, 0.0, 0.0]), [1.0, 1.0, 1.0]);
+        assert_eq!(lut.lookup([1.0, 0.0, 0.0]), [0.0, 1.0, 1.0]);
+        assert_eq!(lut.lookup([0.0, 0.0, 1.0]), [1.0, 1.0, 0.0]);
+        close(lut.lookup([0.25, 0.5, 0.75]), [0.75, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn strip_places_blue_slices_side_by_side() {
+        let lut = CubeLut::parse(INVERT_2.as_bytes()).expect("parse");
+        assert_eq!((lut.strip_width(), lut.strip_height()), (4, 2));
+        let texel = |x: usize, y: usize| {
+            let at = (y * 4 + x) * TEXEL_BYTES;
+            core::array::from_fn::<u16, 4, _>(|lane| {
+                u16::from_le_bytes([
+                    lut.strip_rgba16()[at + lane * 2],
+                    lut.strip_rgba16()[at + lane * 2 + 1],
+                ])
+            })
+        };
+        // (r=1, g=0, b=0) is column 1 of the first slice, row 0
+        assert_eq!(texel(1, 0), [0, u16::MAX, u16::MAX, u16::MAX]);
+        // (r=0, g=1, b=1) is column 0 of the second slice, row 1
+        assert_eq!(texel(2, 1), [u16::MAX, 0, 0, u16::MAX]);
+    }
+
+    #[test]
+    fn domain_rescales_inputs() {
+        let text = String::from("LUT_3D_SIZE 2\nDOMAIN_MIN 0 0 0\nDOMAIN_MAX 2 2 2\n")
+            + "0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+        let lut = CubeLut::parse(text.as_bytes()).expect("parse");
+        close(lut.lookup([1.0, 2.0, 0.0]), [0.5, 1.0, 0.0]);
+        close(lut.lookup([4.0, -1.0, 0.0]), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn identity_maps_colors_to_themselves() {
+        let lut = CubeLut::identity(17);
+        close(lut.lookup([0.2, 0.4, 0.9]), [0.2, 0.4, 0.9]);
+    }
+
+    #[test]
+    fn rejects_broken_files() {
+        let cases: [(&str, CubeLutError); 6] = [
+            (
+                "0 0 0\n",
+                CubeLutError::malformed(1, "table data before LUT_3D_SIZE"),
+            ),
+            ("TITLE \"x\"\n", CubeLutError::MissingSize),
+            ("LUT_3D_SIZE 1\n", CubeLutError::BadSize(1)),
+            ("LUT_1D_SIZE 16\n", CubeLutError::OneDimensional),
+            (
+                "LUT_3D_SIZE 2\n0 0 0\n",
+                CubeLutError::WrongEntryCount {
+                    expected: 8,
+                    found: 1,
+                },
+            ),
+            (
+                "LUT_3D_SIZE 2\n0 0\n",
+                CubeLutError::malformed(2, "expected three numbers"),
+            ),
+        ];
+        for (text, expected) in cases {
+            assert_eq!(CubeLut::parse(text.as_bytes()), Err(expected), "{text:?}");
+        }
+        assert_eq!(CubeLut::parse(&[0xff, 0xfe]), Err(CubeLutError::NotText));
+    }
+
+    fn close(actual: [f32; 3], expected: [f32; 3]) {
+        for lane in 0..3 {
+            assert!(
+                (actual[lane] - expected[lane]).abs() < 1e-3,
+                "{actual:?} != {expected:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! GIF container parsing: the logical screen, the global palette and an
+//! index of every frame, built by one pass over the file that skips the
+//! image data. Frames decode later, one at a time, straight from the source.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ImageError;
+use crate::gif_lzw::{LzwDecoder, SubBlocks};
+use crate::image_source::{ImageSource, SourceReader};
+
+/// Delays at or below this many centiseconds play at [`SLOW_DELAY_CS`]:
+/// encoders write 0 meaning "as fast as possible", and browsers have long
+/// slowed such frames down rather than spin.
+const FAST_DELAY_CS: u16 = 1;
+const SLOW_DELAY_CS: u16 = 10;
+
+/// A rectangle in image pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PixelRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PixelRect {
+    pub fn contains(&self, x: u32, y: u32) -> bool {
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+}
+
+/// What happens to a frame's rectangle before the next frame draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Disposal {
+    /// Leave the frame in place.
+    Keep,
+    /// Clear the rectangle to transparent.
+    Background,
+    /// Put back what was under the frame.
+    Previous,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GifFrame {
+    pub rect: PixelRect,
+    pub delay_cs: u16,
+    pub disposal: Disposal,
+    pub transparent: Option<u8>,
+    interlaced: bool,
+    /// Offset and entry count of the frame's own palette.
+    local_palette: Option<(u64, u16)>,
+    /// Offset of the LZW minimum-code-size byte.
+    data_offset: u64,
+}
+
+impl GifFrame {
+    /// How long the frame shows, in seconds.
+    pub fn seconds(&self) -> f32 {
+        let delay = if self.delay_cs <= FAST_DELAY_CS {
+            SLOW_DELAY_CS
+        } else {
+            self.delay_cs
+        };
+        f32::from(delay) / 100.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct GifImage {
+    pub width: u32,
+    pub height: u32,
+    global_palette: Vec<[u8; 3]>,
+    pub frames: Vec<GifFrame>,
+}
+
+impl GifImage {
+    pub fn sniff(header: &[u8]) -> bool {
+        header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a")
+    }
+
+    pub fn open(source: &dyn ImageSource) -> Result<Self, ImageError> {
+        let mut reader = SourceReader::new(source, 0);
+        let mut signature = [0u8; 6];
+        reader.read_exact(&mut signature)?;
+        if !Self::sniff(&signature) {
+            return Err(ImageError::UnknownFormat);
+        }
+        let width = u32::from(reader.u16_le()?);
+        let height = u32::from(reader.u16_le()?);
+        let packed = reader.u8()?;
+        reader.skip(2); // background index, pixel aspect ratio
+        if width == 0 || height == 0 {
+            return Err(ImageError::Malformed("zero-sized logical screen"));
+        }
+        crate::check_dimensions(width, height)?;
+        let global_palette = if packed & 0x80 != 0 {
+            read_palette(&mut reader, 2 << (packed & 7))?
+        } else {
+            Vec::new()
+        };
+
+        let mut frames = Vec::new();
+        let mut control: Option<(Disposal, Option<u8>, u16)> = None;
+        loop {
+            let introducer = match reader.next_byte()? {
+                Some(byte) => byte,
+                // a missing trailer is common; keep whatever frames arrived
+                None => break,
+            };
+            match introducer {
+                0x21 => {
+                    let label = reader.u8()?;
+                    if label == 0xf9 {
+                        let size = reader.u8()?;
+                        let flags = reader.u8()?;
+                        let delay = reader.u16_le()?;
+                        let transparent = reader.u8()?;
+                        reader.skip(u64::from(size.saturating_sub(4)));
+                        let disposal = match (flags >> 2) & 7 {
+                            2 => Disposal::Background,
+                            3 => Disposal::Previous,
+                            _ => Disposal::Keep,
+                        };
+                        let transparent = (flags & 1 != 0).then_some(transparent);
+                        control = Some((disposal, transparent, delay));
+                    }
+                    skip_sub_blocks(&mut reader)?;
+                }
+                0x2c => {
+                    let x = u32::from(reader.u16_le()?);
+                    let y = u32::from(reader.u16_le()?);
+                    let frame_width = u32::from(reader.u16_le()?);
+                    let frame_height = u32::from(reader.u16_le()?);
+                    let flags = reader.u8()?;
+                    let local_palette = if flags & 0x80 != 0 {
+                        let count = 2u16 << (flags & 7);
+                        let offset = reader.position();
+                        reader.skip(u64::from(count) * 3);
+                        Some((offset, count))
+                    } else {
+                        None
+                    };
+                    if local_palette.is_none() && global_palette.is_empty() {
+                        return Err(ImageError::Malformed("frame has no palette"));
+                    }
+                    let data_offset = reader.position();
+                    reader.skip(1);
+                    let complete = skip_sub_blocks(&mut reader).is_ok();
+                    let (disposal, transparent, delay_cs) =
+                        control.take().unwrap_or((Disposal::Keep, None, 0));
+                    frames.push(GifFrame {
+                        rect: PixelRect {
+                            x,
+                            y,
+                            width: frame_width,
+                            height: frame_height,
+                        },
+                        delay_cs,
+                        disposal,
+                        transparent,
+                        interlaced: flags & 0x40 != 0,
+                        local_palette,
+                        data_offset,
+                    });
+                    if !complete {
+                        break;
+                    }
+                }
+                0x3b => break,
+                _ => return Err(ImageError::Malformed("unknown GIF block")),
+            }
+        }
+        if frames.is_empty() {
+            return Err(ImageError::Malformed("GIF has no frames"));
+        }
+        Ok(Self {
+            width,
+            height,
+            global_palette,
+            frames,
+        })
+    }
+
+    /// Decode frame `index`, handing each row's palette indices to `row`
+    /// with the row's y on the logical screen, and the frame's palette.
+    /// Rows arrive in file order (interlaced frames arrive interlaced); a
+    /// truncated frame hands over the rows it has.
+    pub fn decode_frame(
+        &self,
+        source: &dyn ImageSource,
+        lzw: &mut LzwDecoder,
+        index: usize,
+        row: &mut dyn FnMut(u32, &[u8], &[[u8; 3]]),
+    ) -> Result<(), ImageError> {
+        let frame = &self.frames[index];
+        let local;
+        let palette = match frame.local_palette {
+            Some((offset, count)) => {
+                let mut reader = SourceReader::new(source, offset);
+                local = read_palette(&mut reader, usize::from(count))?;
+                &local
+            }
+            None => &self.global_palette,
+        };
+        let width = frame.rect.width as usize;
+        if width == 0 || frame.rect.height == 0 {
+            return Ok(());
+        }
+        let mut reader = SourceReader::new(source, frame.data_offset);
+        let min_code_size = reader.u8()?;
+        let mut blocks = SubBlocks::new(&mut reader);
+        let mut rows = RowOrder::new(frame.rect.height, frame.interlaced);
+        let mut line = vec![0u8; width];
+        let mut filled = 0;
+        lzw.decode(min_code_size, &mut blocks, &mut |value| {
+            line[filled] = value;
+            filled += 1;
+            if filled < width {
+                return true;
+            }
+            filled = 0;
+            match rows.next() {
+                Some(y) => {
+                    row(frame.rect.y + y, &line, palette);
+                    rows.remaining()
+                }
+                None => false,
+            }
+        })
+    }
+}
+
+fn read_palette(reader: &mut SourceReader<'_>, count: usize) -> Result<Vec<[u8; 3]>, ImageError> {
+    let mut palette = vec![[0u8; 3]; count];
+    for entry in &mut palette {
+        reader.read_exact(entry)?;
+    }
+    Ok(palette)
+}
+
+fn skip_sub_blocks(reader: &mut SourceReader<'_>) -> Result<(), ImageError> {
+    loop {
+        match reader.u8()? {
+            0 => return Ok(()),
+            len => reader.skip(u64::from(len)),
+        }
+    }
+}
+
+/// Frame rows in stored order: top to bottom, or the four interlace passes
+/// (every 8th row from 0, every 8th from 4, every 4th from 2, every 2nd
+/// from 1).
+struct RowOrder {
+    height: u32,
+    interlaced: bool,
+    pass: usize,
+    next: u32,
+}
+
+const PASSES: [(u32, u32); 4] = [(0, 8), (4, 8), (2, 4), (1, 2)];
+
+impl RowOrder {
+    fn new(height: u32, interlaced: bool) -> Self {
+        Self {
+            height,
+            interlaced,
+            pass: 0,
+            next: 0,
+        }
+    }
+
+    fn remaining(&self) -> bool {
+        if self.interlaced {
+            self.pass < PASSES.len()
+        } else {
+            self.next < self.height
+        }
+    }
+}
+
+impl Iterator for RowOrder {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if !self.interlaced {
+            let y = self.next;
+            self.next += 1;
+            return (y < self.height).then_some(y);
+        }
+        while self.pass < PASSES.len() {
+            let (start, step) = PASSES[self.pass];
+            let y = start + self.next * step;
+            if y < self.height {
+                self.next += 1;
+                // step past exhausted passes so `remaining` sees the end
+                while self.pass < PASSES.len() {
+                    let (start, step) = PASSES[self.pass];
+                    if start + self.next * step < self.height {
+                        break;
+                    }
+                    self.pass += 1;
+                    self.next = 0;
+                }
+                return Some(y);
+            }
+            self.pass += 1;
+            self.next = 0;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interlaced_rows_cover_every_row_once() {
+        for height in 1..20 {
+            let mut rows: Vec<u32> = RowOrder::new(height, true).collect();
+            assert_eq!(rows.len() as u32, height);
+            if height >= 5 {
+                assert_eq!(&rows[..2], &[0, if height > 8 { 8 } else { 4 }]);
+            }
+            rows.sort_unstable();
+            assert_eq!(rows, (0..height).collect::<Vec<_>>());
+        }
+        let mut order = RowOrder::new(3, true);
+        assert_eq!(order.next(), Some(0));
+        assert_eq!(order.next(), Some(2));
+        assert!(order.remaining());
+        assert_eq!(order.next(), Some(1));
+        assert!(!order.remaining());
+    }
+
+    #[test]
+    fn tiny_delays_play_at_a_tenth_of_a_second() {
+        let frame = |delay_cs| GifFrame {
+            rect: PixelRect::default(),
+            delay_cs,
+            disposal: Disposal::Keep,
+            transparent: None,
+            interlaced: false,
+            local_palette: None,
+            data_offset: 0,
+        };
+        assert_eq!(frame(0).seconds(), 0.1);
+        assert_eq!(frame(1).seconds(), 0.1);
+        assert_eq!(frame(4).seconds(), 0.04);
+    }
+
+    #[test]
+    fn rejects_what_is_not_a_gif() {
+        assert_eq!(
+            GifImage::open(&b"GIF90a".as_slice()).unwrap_err(),
+            ImageError::UnknownFormat
+        );
+        assert_eq!(
+            GifImage::open(&b"GIF89a\x01\x00".as_slice()).unwrap_err(),
+            ImageError::Truncated
+        );
+    }
+}
@@ -0,0 +1,282 @@
+//! Streaming GIF LZW decoding.
+//!
+//! The code table is the classic prefix/suffix pair (12-bit codes, 4096
+//! entries, 16 KiB with the output stack) and the input is pulled one byte
+//! at a time out of the data sub-blocks, so decoding a frame costs the table
+//! plus one row of indices no matter how large the file is.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ImageError;
+use crate::image_source::SourceReader;
+
+const MAX_CODES: usize = 4096;
+
+/// The bytes of a GIF image-data sub-block chain, in order.
+pub(crate) struct SubBlocks<'r, 'a> {
+    reader: &'r mut SourceReader<'a>,
+    remaining: u8,
+    ended: bool,
+}
+
+impl<'r, 'a> SubBlocks<'r, 'a> {
+    pub fn new(reader: &'r mut SourceReader<'a>) -> Self {
+        Self {
+            reader,
+            remaining: 0,
+            ended: false,
+        }
+    }
+
+    /// The next data byte; `None` at the zero-length terminator or at the
+    /// end of a truncated file.
+    fn next_byte(&mut self) -> Result<Option<u8>, ImageError> {
+        while self.remaining == 0 {
+            if self.ended {
+                return Ok(None);
+            }
+            match self.reader.next_byte()? {
+                Some(0) | None => {
+                    self.ended = true;
+                    return Ok(None);
+                }
+                Some(len) => self.remaining = len,
+            }
+        }
+        self.remaining -= 1;
+        match self.reader.next_byte()? {
+            Some(byte) => Ok(Some(byte)),
+            None => {
+                self.ended = true;
+                self.remaining = 0;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Decodes one frame's LZW stream into palette indices.
+pub(crate) struct LzwDecoder {
+    prefix: Vec<u16>,
+    suffix: Vec<u8>,
+    stack: Vec<u8>,
+}
+
+impl LzwDecoder {
+    pub fn new() -> Self {
+        Self {
+            prefix: vec![0; MAX_CODES],
+            suffix: vec![0; MAX_CODES],
+            stack: Vec::with_capacity(MAX_CODES + 1),
+        }
+    }
+
+    /// Decode `blocks` with the frame's minimum code size, handing every
+    /// index to `emit` in stream order. `emit` returns `false` once it has
+    /// every pixel it wants, which ends decoding early. A stream that stops
+    /// short (no end code, truncated file) just ends; the caller keeps what
+    /// arrived, as browsers do.
+    pub fn decode(
+        &mut self,
+        min_code_size: u8,
+        blocks: &mut SubBlocks<'_, '_>,
+        emit: &mut dyn FnMut(u8) -> bool,
+    ) -> Result<(), ImageError> {
+        if !(1..=11).contains(&min_code_size) {
+            return Err(ImageError::Malformed("LZW minimum code size out of range"));
+        }
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+        let mut code_size = u32::from(min_code_size) + 1;
+        let mut next = end + 1;
+        let mut old: Option<u16> = None;
+        let mut first = 0u8;
+        let mut bits = 0u32;
+        let mut bit_count = 0u32;
+
+        loop {
+            while bit_count < code_size {
+                let Some(byte) = blocks.next_byte()? else {
+                    return Ok(());
+                };
+                bits |= u32::from(byte) << bit_count;
+                bit_count += 8;
+            }
+            let code = (bits & ((1 << code_size) - 1)) as u16;
+            bits >>= code_size;
+            bit_count -= code_size;
+
+            if code == clear {
+                code_size = u32::from(min_code_size) + 1;
+                next = end + 1;
+                old = None;
+                continue;
+            }
+            if code == end {
+                return Ok(());
+            }
+            let Some(previous) = old else {
+                if code > clear {
+                    return Err(ImageError::Malformed("LZW stream starts with a table code"));
+                }
+                first = code as u8;
+                old = Some(code);
+                if !emit(first) {
+                    return Ok(());
+                }
+                continue;
+            };
+
+            self.stack.clear();
+            let mut walk = if code < next {
+                code
+            } else if code == next {
+                self.stack.push(first);
+                previous
+            } else {
+                return Err(ImageError::Malformed("LZW code past the table"));
+            };
+            while walk > end {
+                self.stack.push(self.suffix[usize::from(walk)]);
+                walk = self.prefix[usize::from(walk)];
+            }
+            first = walk as u8;
+            self.stack.push(first);
+
+            if usize::from(next) < MAX_CODES {
+                self.prefix[usize::from(next)] = previous;
+                self.suffix[usize::from(next)] = first;
+                next += 1;
+                if u32::from(next) == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            old = Some(code);
+
+            while let Some(index) = self.stack.pop() {
+                if !emit(index) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    /// LZW-encode `indices` the way common encoders do (no clear code when
+    /// the table fills) and wrap the codes in sub-blocks of `block_len`
+    /// bytes.
+    fn encode(min_code_size: u8, indices: &[u8], block_len: usize) -> Vec<u8> {
+        let clear = 1u32 << min_code_size;
+        let mut dict: BTreeMap<Vec<u8>, u32> = (0..clear).map(|i| (vec![i as u8], i)).collect();
+        let mut next = clear + 2;
+        let mut code_size = u32::from(min_code_size) + 1;
+        let mut out = Vec::new();
+        let (mut acc, mut acc_bits) = (0u32, 0u32);
+        let mut put = |code: u32, size: u32| {
+            acc |= code << acc_bits;
+            acc_bits += size;
+            while acc_bits >= 8 {
+                out.push(acc as u8);
+                acc >>= 8;
+                acc_bits -= 8;
+            }
+        };
+        put(clear, code_size);
+        let mut current: Vec<u8> = Vec::new();
+        for &index in indices {
+            let mut extended = current.clone();
+            extended.push(index);
+            if dict.contains_key(&extended) {
+                current = extended;
+                continue;
+            }
+            put(dict[&current], code_size);
+            if (next as usize) < MAX_CODES {
+                dict.insert(extended, next);
+                next += 1;
+                if next > 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            current = vec![index];
+        }
+        put(dict[&current], code_size);
+        // the decoder adds one more entry on reading that last code
+        if (next as usize) < MAX_CODES && next + 1 > 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+        put(clear + 1, code_size);
+        put(0, 7);
+        let mut blocks = Vec::new();
+        for chunk in out.chunks(block_len) {
+            blocks.push(chunk.len() as u8);
+            blocks.extend_from_slice(chunk);
+        }
+        blocks.push(0);
+        blocks
+    }
+
+    fn decode(min_code_size: u8, data: &[u8], limit: usize) -> Result<Vec<u8>, ImageError> {
+        let mut reader = SourceReader::new(&data, 0);
+        let mut blocks = SubBlocks::new(&mut reader);
+        let mut out = Vec::new();
+        LzwDecoder::new().decode(min_code_size, &mut blocks, &mut |index| {
+            out.push(index);
+            out.len() < limit
+        })?;
+        Ok(out)
+    }
+
+    #[test]
+    fn round_trips_repetitive_and_noisy_runs() {
+        let mut indices: Vec<u8> = (0..600).map(|i| ((i / 7) % 4) as u8).collect();
+        let mut seed = 7u32;
+        indices.extend((0..400).map(|_| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as u8 & 3
+        }));
+        let data = encode(2, &indices, 255);
+        assert_eq!(decode(2, &data, usize::MAX).unwrap(), indices);
+        // one-byte sub-blocks exercise the chain walk between every byte
+        let data = encode(2, &indices, 1);
+        assert_eq!(decode(2, &data, usize::MAX).unwrap(), indices);
+    }
+
+    #[test]
+    fn a_full_table_stops_growing_without_a_clear() {
+        let mut seed = 99u32;
+        let indices: Vec<u8> = (0..20_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                (seed >> 16) as u8
+            })
+            .collect();
+        let data = encode(8, &indices, 255);
+        assert_eq!(decode(8, &data, usize::MAX).unwrap(), indices);
+    }
+
+    #[test]
+    fn emit_can_stop_early_and_short_streams_end_quietly() {
+        let indices = [1u8, 2, 3, 1, 2, 3, 1, 2, 3];
+        let data = encode(2, &indices, 255);
+        assert_eq!(decode(2, &data, 4).unwrap(), [1, 2, 3, 1]);
+        let cut = &data[..3];
+        assert!(decode(2, cut, usize::MAX).unwrap().len() < indices.len());
+    }
+
+    #[test]
+    fn rejects_codes_past_the_table() {
+        // clear (4), then code 7 with only 6 defined
+        let data = [1u8, 0b0011_1100, 0];
+        assert!(matches!(
+            decode(2, &data, usize::MAX),
+            Err(ImageError::Malformed(_))
+        ));
+    }
+}
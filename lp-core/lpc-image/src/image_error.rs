@@ -0,0 +1,38 @@
+//! Errors for image decoding and playback.
+
+use alloc::string::String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImageError {
+    /// The source failed to read; the string is the backend's message.
+    Read(String),
+    /// The file ends inside a structure the decoder needs.
+    Truncated,
+    /// Neither a GIF nor a BMP.
+    UnknownFormat,
+    /// A real variant of the format that this decoder does not handle.
+    Unsupported(&'static str),
+    /// The file breaks its own format.
+    Malformed(&'static str),
+    /// The image is wider or taller than [`crate::MAX_DIMENSION`].
+    TooLarge { width: u32, height: u32 },
+}
+
+impl core::fmt::Display for ImageError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Read(message) => write!(f, "image read failed: {message}"),
+            Self::Truncated => write!(f, "image file is truncated"),
+            Self::UnknownFormat => write!(f, "not a GIF or BMP image"),
+            Self::Unsupported(what) => write!(f, "unsupported image: {what}"),
+            Self::Malformed(what) => write!(f, "malformed image: {what}"),
+            Self::TooLarge { width, height } => write!(
+                f,
+                "image is {width}×{height}, larger than {0}×{0}",
+                crate::MAX_DIMENSION
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ImageError {}
@@ -0,0 +1,372 @@
+//! Plays one image file onto a fixed-size canvas.
+//!
+//! The player owns only canvas-sized state: the composited GIF canvas, the
+//! copy a "restore to previous" frame needs, and the presented pixels. The
+//! file stays in its [`ImageSource`], which every render is handed again, so
+//! the same player runs over a slice on the host and over flash on a device.
+//!
+//! GIF frames build on each other, so showing frame `n` composites every
+//! frame up to it. Playing forward costs one frame per step; jumping back
+//! (a loop wrap, ping-pong's return) replays from the first frame.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ImageError;
+use crate::bmp::BmpImage;
+use crate::gif::{Disposal, GifImage, PixelRect};
+use crate::gif_lzw::LzwDecoder;
+use crate::image_source::ImageSource;
+use crate::palette_quantize::quantize;
+use crate::playback::{DEFAULT_SHEET_FPS, ImageLoop, frame_at};
+use crate::scale::ImageScale;
+use crate::sprite_sheet::SpriteSheet;
+
+const TRANSPARENT: [u8; 4] = [0; 4];
+
+/// How a player renders its file.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImageOptions {
+    /// Canvas size in pixels; frames are scaled onto it.
+    pub width: u32,
+    pub height: u32,
+    pub scale: ImageScale,
+    pub looping: ImageLoop,
+    /// Frames per second. `0` plays a GIF at its own frame delays, and a
+    /// sprite sheet at [`DEFAULT_SHEET_FPS`].
+    pub fps: f32,
+    /// Cuts the image into frames. Sheets use a GIF's first frame.
+    pub sheet: SpriteSheet,
+    /// Quantize every rendered frame to this many colors; `0` keeps full
+    /// color.
+    pub palette_colors: u32,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        Self {
+            width: 32,
+            height: 32,
+            scale: ImageScale::default(),
+            looping: ImageLoop::default(),
+            fps: 0.0,
+            sheet: SpriteSheet::default(),
+            palette_colors: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Gif,
+    Bmp,
+}
+
+/// What a file holds, independent of how it plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageInfo {
+    pub format: ImageFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Frames in the file (animation frames, not sheet cells).
+    pub frames: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Decoder {
+    Gif(GifImage),
+    Bmp(BmpImage),
+}
+
+pub struct ImagePlayer {
+    decoder: Decoder,
+    options: ImageOptions,
+    /// Canvas column / row → source column / row inside the current cell.
+    columns: Vec<Option<u32>>,
+    rows: Vec<Option<u32>>,
+    canvas: Vec<[u8; 4]>,
+    saved: Vec<[u8; 4]>,
+    /// The last GIF frame composited into `canvas`, and the disposal it
+    /// leaves for the next.
+    composited: Option<u32>,
+    pending: Option<(Disposal, PixelRect)>,
+    pixels: Vec<[u8; 4]>,
+    shown: Option<u32>,
+}
+
+impl ImagePlayer {
+    /// Index `source` (a pass over its headers, not its pixels) for playback
+    /// with `options`.
+    pub fn open(source: &dyn ImageSource, options: ImageOptions) -> Result<Self, ImageError> {
+        let mut header = [0u8; 6];
+        let read = source.read_at(0, &mut header)?;
+        let header = &header[..read];
+        let decoder = if GifImage::sniff(header) {
+            Decoder::Gif(GifImage::open(source)?)
+        } else if BmpImage::sniff(header) {
+            Decoder::Bmp(BmpImage::open(source)?)
+        } else {
+            return Err(ImageError::UnknownFormat);
+        };
+        let options = ImageOptions {
+            width: options.width.max(1),
+            height: options.height.max(1),
+            ..options
+        };
+        let mut player = Self {
+            decoder,
+            options,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            canvas: Vec::new(),
+            saved: Vec::new(),
+            composited: None,
+            pending: None,
+            pixels: Vec::new(),
+            shown: None,
+        };
+        let info = player.info();
+        let cell = options.sheet.cell(0, info.width, info.height);
+        [player.columns, player.rows] = options
+            .scale
+            .axis_maps([cell.width, cell.height], [options.width, options.height]);
+        Ok(player)
+    }
+
+    pub fn info(&self) -> ImageInfo {
+        match &self.decoder {
+            Decoder::Gif(gif) => ImageInfo {
+                format: ImageFormat::Gif,
+                width: gif.width,
+                height: gif.height,
+                frames: gif.frames.len() as u32,
+            },
+            Decoder::Bmp(bmp) => ImageInfo {
+                format: ImageFormat::Bmp,
+                width: bmp.width,
+                height: bmp.height,
+                frames: 1,
+            },
+        }
+    }
+
+    pub fn options(&self) -> &ImageOptions {
+        &self.options
+    }
+
+    /// Frames playback steps through: sheet cells, or the file's frames.
+    pub fn frame_count(&self) -> u32 {
+        if self.options.sheet.is_sheet() {
+            self.options.sheet.frame_count()
+        } else {
+            self.info().frames
+        }
+    }
+
+    /// The frame showing `time_seconds` into playback.
+    pub fn frame_at(&self, time_seconds: f32) -> u32 {
+        let fixed = |fps: f32| move |_| 1.0 / fps;
+        let count = self.frame_count();
+        let looping = self.options.looping;
+        match (&self.decoder, self.options.fps) {
+            (_, fps) if fps > 0.0 => frame_at(time_seconds, count, looping, &fixed(fps)),
+            (Decoder::Gif(gif), _) if !self.options.sheet.is_sheet() => {
+                frame_at(time_seconds, count, looping, &|frame| {
+                    gif.frames[frame as usize].seconds()
+                })
+            }
+            _ => frame_at(time_seconds, count, looping, &fixed(DEFAULT_SHEET_FPS)),
+        }
+    }
+
+    /// Render the frame showing at `time_seconds`.
+    pub fn render_at(
+        &mut self,
+        source: &dyn ImageSource,
+        time_seconds: f32,
+    ) -> Result<&[[u8; 4]], ImageError> {
+        let frame = self.frame_at(time_seconds);
+        self.render_frame(source, frame)
+    }
+
+    /// Render `frame` (clamped to the last), row-major RGBA with straight
+    /// alpha, `width × height` of the options. Rendering the frame already
+    /// shown costs nothing.
+    pub fn render_frame(
+        &mut self,
+        source: &dyn ImageSource,
+        frame: u32,
+    ) -> Result<&[[u8; 4]], ImageError> {
+        let frame = frame.min(self.frame_count().saturating_sub(1));
+        if self.shown == Some(frame) && !self.pixels.is_empty() {
+            return Ok(&self.pixels);
+        }
+        self.shown = None;
+        let size = (self.options.width * self.options.height) as usize;
+        let info = self.info();
+        let map = CanvasMap {
+            width: self.options.width as usize,
+            columns: &self.columns,
+            rows: &self.rows,
+            cell: self.options.sheet.cell(frame, info.width, info.height),
+        };
+
+        match &self.decoder {
+            Decoder::Gif(gif) if !self.options.sheet.is_sheet() => {
+                if self.canvas.len() != size || self.composited.is_none_or(|last| last > frame) {
+                    self.canvas.clear();
+                    self.canvas.resize(size, TRANSPARENT);
+                    self.composited = None;
+                    self.pending = None;
+                }
+                let start = self.composited.map_or(0, |last| last + 1);
+                let mut lzw = LzwDecoder::new();
+                for index in start..=frame {
+                    // a failed frame leaves the canvas half drawn: start over next time
+                    self.composited = None;
+                    if let Some((disposal, rect)) = self.pending.take() {
+                        match disposal {
+                            Disposal::Keep => {}
+                            Disposal::Background => map.clear_rect(&mut self.canvas, rect),
+                            Disposal::Previous => self.canvas.copy_from_slice(&self.saved),
+                        }
+                    }
+                    let gif_frame = &gif.frames[index as usize];
+                    if gif_frame.disposal == Disposal::Previous {
+                        self.saved.clone_from(&self.canvas);
+                    }
+                    draw_gif_frame(gif, source, &mut lzw, index, &map, &mut self.canvas)?;
+                    self.pending = Some((gif_frame.disposal, gif_frame.rect));
+                    self.composited = Some(index);
+                }
+                self.pixels.clone_from(&self.canvas);
+            }
+            Decoder::Gif(gif) => {
+                self.pixels.clear();
+                self.pixels.resize(size, TRANSPARENT);
+                let mut lzw = LzwDecoder::new();
+                draw_gif_frame(gif, source, &mut lzw, 0, &map, &mut self.pixels)?;
+            }
+            Decoder::Bmp(bmp) => {
+                self.pixels.clear();
+                self.pixels.resize(size, TRANSPARENT);
+                let mut row = vec![0u8; bmp.stride()];
+                for (canvas_y, source_y) in map.rows.iter().enumerate() {
+                    let Some(source_y) = source_y else {
+                        continue;
+                    };
+                    bmp.read_row(source, map.cell.y + source_y, &mut row)?;
+                    let line = &mut self.pixels[canvas_y * map.width..][..map.width];
+                    for (pixel, source_x) in line.iter_mut().zip(map.columns) {
+                        if let Some(source_x) = source_x {
+                            *pixel = bmp.pixel(&row, map.cell.x + source_x);
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.options.palette_colors > 0 {
+            quantize(&mut self.pixels, self.options.palette_colors);
+        }
+        self.shown = Some(frame);
+        Ok(&self.pixels)
+    }
+
+    /// Whether [`Self::pixels`] already holds `frame` (clamped like
+    /// [`Self::render_frame`]), so a render would read nothing.
+    pub fn has_frame(&self, frame: u32) -> bool {
+        let frame = frame.min(self.frame_count().saturating_sub(1));
+        self.shown == Some(frame) && !self.pixels.is_empty()
+    }
+
+    /// The last rendered frame; empty before the first render and after
+    /// [`Self::release`].
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.pixels
+    }
+
+    /// Drop every canvas-sized buffer. The next render rebuilds them from
+    /// the file and draws the same pixels.
+    pub fn release(&mut self) {
+        self.canvas = Vec::new();
+        self.saved = Vec::new();
+        self.pixels = Vec::new();
+        self.composited = None;
+        self.pending = None;
+        self.shown = None;
+    }
+}
+
+/// Where canvas pixels sample the image: per-axis maps into the current
+/// cell.
+struct CanvasMap<'a> {
+    width: usize,
+    columns: &'a [Option<u32>],
+    rows: &'a [Option<u32>],
+    cell: PixelRect,
+}
+
+impl CanvasMap<'_> {
+    /// Canvas rows sampling image row `y`.
+    fn rows_sampling(&self, y: u32) -> impl Iterator<Item = usize> + '_ {
+        let cell = self.cell;
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(move |(_, row)| row.is_some_and(|row| cell.y + row == y))
+            .map(|(canvas_y, _)| canvas_y)
+    }
+
+    /// Canvas columns with the image column each samples.
+    fn columns(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        let cell = self.cell;
+        self.columns
+            .iter()
+            .enumerate()
+            .filter_map(move |(canvas_x, column)| column.map(|column| (canvas_x, cell.x + column)))
+    }
+
+    fn clear_rect(&self, canvas: &mut [[u8; 4]], rect: PixelRect) {
+        for (canvas_y, row) in self.rows.iter().enumerate() {
+            let Some(row) = row else {
+                continue;
+            };
+            for (canvas_x, x) in self.columns() {
+                if rect.contains(x, self.cell.y + row) {
+                    canvas[canvas_y * self.width + canvas_x] = TRANSPARENT;
+                }
+            }
+        }
+    }
+}
+
+fn draw_gif_frame(
+    gif: &GifImage,
+    source: &dyn ImageSource,
+    lzw: &mut LzwDecoder,
+    index: u32,
+    map: &CanvasMap<'_>,
+    canvas: &mut [[u8; 4]],
+) -> Result<(), ImageError> {
+    let frame = &gif.frames[index as usize];
+    let rect = frame.rect;
+    let transparent = frame.transparent;
+    gif.decode_frame(source, lzw, index as usize, &mut |y, indices, palette| {
+        for canvas_y in map.rows_sampling(y) {
+            let line = &mut canvas[canvas_y * map.width..][..map.width];
+            for (canvas_x, x) in map.columns() {
+                if x < rect.x || x - rect.x >= rect.width {
+                    continue;
+                }
+                let index = indices[(x - rect.x) as usize];
+                if transparent == Some(index) {
+                    continue;
+                }
+                if let Some([r, g, b]) = palette.get(usize::from(index)) {
+                    line[canvas_x] = [*r, *g, *b, 255];
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,178 @@
+//! Windowed byte access to an image file.
+//!
+//! Decoders never hold a whole file: they pull small windows through
+//! [`ImageSource::read_at`], so the same code decodes a slice in a host test
+//! and a GIF on device flash that is larger than the heap.
+
+use alloc::vec::Vec;
+
+use crate::ImageError;
+
+/// Random-access bytes of one image file.
+pub trait ImageSource {
+    /// Total size of the file in bytes.
+    fn size(&self) -> u64;
+
+    /// Read up to `buf.len()` bytes at `offset`; fewer only at the end of the
+    /// file.
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError>;
+}
+
+impl ImageSource for &[u8] {
+    fn size(&self) -> u64 {
+        self.len() as u64
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError> {
+        let start = usize::try_from(offset)
+            .unwrap_or(usize::MAX)
+            .min(self.len());
+        let n = buf.len().min(self.len() - start);
+        buf[..n].copy_from_slice(&self[start..start + n]);
+        Ok(n)
+    }
+}
+
+impl ImageSource for Vec<u8> {
+    fn size(&self) -> u64 {
+        self.as_slice().size()
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError> {
+        self.as_slice().read_at(offset, buf)
+    }
+}
+
+/// Bytes per refill of a [`SourceReader`]; GIF data sub-blocks are at most
+/// 255 bytes, so one refill covers any of them.
+const WINDOW: usize = 256;
+
+/// Sequential little-endian reads over an [`ImageSource`] through one
+/// fixed window.
+pub(crate) struct SourceReader<'a> {
+    source: &'a dyn ImageSource,
+    window: [u8; WINDOW],
+    window_start: u64,
+    window_len: usize,
+    position: u64,
+}
+
+impl<'a> SourceReader<'a> {
+    pub fn new(source: &'a dyn ImageSource, position: u64) -> Self {
+        Self {
+            source,
+            window: [0; WINDOW],
+            window_start: 0,
+            window_len: 0,
+            position,
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    /// The next byte, or `None` at the end of the file.
+    pub fn next_byte(&mut self) -> Result<Option<u8>, ImageError> {
+        let in_window = self.position.wrapping_sub(self.window_start);
+        if self.position < self.window_start || in_window >= self.window_len as u64 {
+            self.window_start = self.position;
+            self.window_len = self.source.read_at(self.position, &mut self.window)?;
+            if self.window_len == 0 {
+                return Ok(None);
+            }
+        }
+        let byte = self.window[(self.position - self.window_start) as usize];
+        self.position += 1;
+        Ok(Some(byte))
+    }
+
+    pub fn u8(&mut self) -> Result<u8, ImageError> {
+        self.next_byte()?.ok_or(ImageError::Truncated)
+    }
+
+    pub fn u16_le(&mut self) -> Result<u16, ImageError> {
+        Ok(u16::from(self.u8()?) | (u16::from(self.u8()?) << 8))
+    }
+
+    pub fn u32_le(&mut self) -> Result<u32, ImageError> {
+        Ok(u32::from(self.u16_le()?) | (u32::from(self.u16_le()?) << 16))
+    }
+
+    pub fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), ImageError> {
+        for byte in buf {
+            *byte = self.u8()?;
+        }
+        Ok(())
+    }
+
+    pub fn skip(&mut self, count: u64) {
+        self.position += count;
+    }
+}
+
+/// Fill `buf` from `offset`, failing if the file ends first.
+pub(crate) fn read_exact_at(
+    source: &dyn ImageSource,
+    offset: u64,
+    buf: &mut [u8],
+) -> Result<(), ImageError> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match source.read_at(offset + filled as u64, &mut buf[filled..])? {
+            0 => return Err(ImageError::Truncated),
+            n => filled += n,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A source that hands out at most three bytes per read.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl ImageSource for Trickle<'_> {
+        fn size(&self) -> u64 {
+            self.0.len() as u64
+        }
+
+        fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<usize, ImageError> {
+            let n = buf.len().min(3);
+            self.0.read_at(offset, &mut buf[..n])
+        }
+    }
+
+    #[test]
+    fn reader_walks_little_endian_fields_across_windows() {
+        let bytes: Vec<u8> = (0..=255u8).chain(0..=255u8).collect();
+        let mut reader = SourceReader::new(&bytes, 254);
+        assert_eq!(reader.u16_le().unwrap(), 0xfffe);
+        assert_eq!(reader.u32_le().unwrap(), 0x0302_0100);
+        reader.seek(510);
+        assert_eq!(reader.u16_le().unwrap(), 0xfffe);
+        assert_eq!(reader.next_byte().unwrap(), None);
+        assert_eq!(reader.u8(), Err(ImageError::Truncated));
+    }
+
+    #[test]
+    fn short_reads_are_refilled() {
+        let bytes = [1u8, 2, 3, 4, 5, 6, 7];
+        let source = Trickle(&bytes);
+        let mut reader = SourceReader::new(&source, 2);
+        assert_eq!(reader.u32_le().unwrap(), 0x0605_0403);
+        let mut buf = [0u8; 5];
+        read_exact_at(&source, 1, &mut buf).unwrap();
+        assert_eq!(buf, [2, 3, 4, 5, 6]);
+        assert_eq!(
+            read_exact_at(&source, 4, &mut buf),
+            Err(ImageError::Truncated)
+        );
+    }
+}
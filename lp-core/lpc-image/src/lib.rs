@@ -0,0 +1,62 @@
+//! Image, animated-GIF and sprite-sheet playback for image nodes.
+//!
+//! An [`ImagePlayer`] indexes one file through an [`ImageSource`] and renders
+//! any of its frames onto a fixed-size RGBA canvas: scaled
+//! ([`ImageScale`]), timed ([`ImageLoop`], per-frame GIF delays or a fixed
+//! rate), cut from a [`SpriteSheet`], and optionally reduced to a small
+//! palette ([`palette_quantize::quantize`]).
+//!
+//! Memory: decoding streams. The source is read in small windows, a GIF frame
+//! decodes through one row of indices and a 16 KiB LZW table, and a BMP
+//! reads only the rows the canvas samples. What stays resident is the frame
+//! index (a few dozen bytes per frame) and up to three canvas-sized buffers,
+//! so a 64×64 canvas plays a GIF far larger than an ESP32-C6's heap.
+//!
+//! Formats: GIF (87a/89a: transparency, disposal, interlace, local
+//! palettes) and uncompressed BMP (1/4/8/16/24/32-bit, bit fields). PNG is
+//! out of scope — inflate needs a 32 KiB window and its rows are filtered
+//! against their neighbours, which breaks row-at-a-time sampling.
+//!
+//! Also here: [`CubeLut`], the `.cube` 3D color lookup table a filter node
+//! grades through, parsed straight into the strip texture it is sampled
+//! from.
+//!
+//! Boundary: no filesystem, no engine types. Callers adapt their storage to
+//! [`ImageSource`] (the engine wraps `lpfs` range reads).
+
+#![no_std]
+
+extern crate alloc;
+
+mod bmp;
+pub mod corpus;
+mod cube_lut;
+mod gif;
+mod gif_lzw;
+mod image_error;
+mod image_player;
+mod image_source;
+pub mod palette_quantize;
+mod playback;
+mod scale;
+mod sprite_sheet;
+
+pub use cube_lut::{CubeLut, CubeLutError, MAX_LUT_SIZE};
+pub use gif::PixelRect;
+pub use image_error::ImageError;
+pub use image_player::{ImageFormat, ImageInfo, ImageOptions, ImagePlayer};
+pub use image_source::ImageSource;
+pub use playback::{DEFAULT_SHEET_FPS, ImageLoop};
+pub use scale::ImageScale;
+pub use sprite_sheet::SpriteSheet;
+
+/// Largest width or height a file may declare. BMP rows are read whole, so
+/// this bounds the one row buffer a BMP needs.
+pub const MAX_DIMENSION: u32 = 8192;
+
+fn check_dimensions(width: u32, height: u32) -> Result<(), ImageError> {
+    if width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Err(ImageError::TooLarge { width, height });
+    }
+    Ok(())
+}
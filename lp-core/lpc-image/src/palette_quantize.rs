@@ -0,0 +1,139 @@
+//! Median-cut palette quantization of a rendered frame.
+//!
+//! LED installs often want a flat, posterized look (or a palette small enough
+//! to read at a distance), so the player can reduce each rendered frame to a
+//! few colors. The cut is deterministic — stable sorts, ties to the lower
+//! box — so a frame quantizes identically on every platform and every tick.
+
+use alloc::vec::Vec;
+
+/// Fewest and most colors a quantized frame can have.
+pub const MIN_PALETTE_COLORS: u32 = 2;
+pub const MAX_PALETTE_COLORS: u32 = 256;
+
+/// Reduce the opaque pixels of `pixels` to at most `colors` colors (clamped
+/// to [`MIN_PALETTE_COLORS`]..=[`MAX_PALETTE_COLORS`]): median-cut the color
+/// cloud into boxes, then snap every pixel to its nearest box average.
+/// Transparent pixels are left alone and do not vote.
+pub fn quantize(pixels: &mut [[u8; 4]], colors: u32) {
+    let colors = colors.clamp(MIN_PALETTE_COLORS, MAX_PALETTE_COLORS) as usize;
+    let mut cloud: Vec<[u8; 3]> = pixels
+        .iter()
+        .filter(|pixel| pixel[3] != 0)
+        .map(|[r, g, b, _]| [*r, *g, *b])
+        .collect();
+    if cloud.is_empty() {
+        return;
+    }
+
+    let mut boxes: Vec<(usize, usize)> = Vec::with_capacity(colors);
+    boxes.push((0, cloud.len()));
+    while boxes.len() < colors {
+        // split the box with the widest channel range
+        let widest = boxes
+            .iter()
+            .enumerate()
+            .map(|(index, (start, end))| {
+                let (channel, range) = widest_channel(&cloud[*start..*end]);
+                (range, index, channel)
+            })
+            .filter(|(range, _, _)| *range > 0)
+            .max_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+        let Some((_, index, channel)) = widest else {
+            break;
+        };
+        let (start, end) = boxes[index];
+        cloud[start..end].sort_by_key(|color| color[channel]);
+        let middle = start + (end - start) / 2;
+        boxes[index] = (start, middle);
+        boxes.push((middle, end));
+    }
+
+    let palette: Vec<[u8; 3]> = boxes
+        .iter()
+        .map(|(start, end)| {
+            let mut sum = [0u32; 3];
+            for color in &cloud[*start..*end] {
+                for (total, value) in sum.iter_mut().zip(color) {
+                    *total += u32::from(*value);
+                }
+            }
+            let count = (end - start) as u32;
+            sum.map(|total| ((total + count / 2) / count) as u8)
+        })
+        .collect();
+
+    for pixel in pixels.iter_mut().filter(|pixel| pixel[3] != 0) {
+        let nearest = palette
+            .iter()
+            .min_by_key(|entry| {
+                (0..3)
+                    .map(|channel| {
+                        let delta = i32::from(entry[channel]) - i32::from(pixel[channel]);
+                        (delta * delta) as u32
+                    })
+                    .sum::<u32>()
+            })
+            .copied()
+            .unwrap_or([0; 3]);
+        pixel[..3].copy_from_slice(&nearest);
+    }
+}
+
+fn widest_channel(colors: &[[u8; 3]]) -> (usize, u8) {
+    let mut low = [u8::MAX; 3];
+    let mut high = [0u8; 3];
+    for color in colors {
+        for ((low, high), value) in low.iter_mut().zip(&mut high).zip(color) {
+            *low = (*low).min(*value);
+            *high = (*high).max(*value);
+        }
+    }
+    (0..3)
+        .map(|channel| (channel, high[channel].saturating_sub(low[channel])))
+        .fold((0, 0), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeSet;
+
+    fn distinct(pixels: &[[u8; 4]]) -> usize {
+        pixels
+            .iter()
+            .filter(|pixel| pixel[3] != 0)
+            .collect::<BTreeSet<_>>()
+            .len()
+    }
+
+    #[test]
+    fn a_gradient_collapses_to_the_requested_count() {
+        let mut pixels: Vec<[u8; 4]> = (0..=255u8).map(|v| [v, v / 2, 255 - v, 255]).collect();
+        quantize(&mut pixels, 4);
+        assert_eq!(distinct(&pixels), 4);
+        // ordering along the ramp survives
+        assert!(pixels[0][0] < pixels[255][0]);
+    }
+
+    #[test]
+    fn few_colors_stay_exact_and_transparency_is_untouched() {
+        let mut pixels = [
+            [255, 0, 0, 255],
+            [0, 0, 255, 255],
+            [9, 9, 9, 0],
+            [255, 0, 0, 255],
+        ];
+        let before = pixels;
+        quantize(&mut pixels, 16);
+        assert_eq!(pixels, before);
+        quantize(&mut pixels, 1);
+        assert_eq!(distinct(&pixels), 2, "clamped to the minimum");
+    }
+}
@@ -0,0 +1,97 @@
+//! Which frame shows at a given time.
+
+/// How playback continues past the last frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageLoop {
+    /// Start over from the first frame.
+    #[default]
+    Loop,
+    /// Play through once and hold the last frame.
+    Once,
+    /// Play forward then backward, without repeating the end frames.
+    PingPong,
+}
+
+/// Frame rate for sprite sheets played with file timing, which they do not
+/// have.
+pub const DEFAULT_SHEET_FPS: f32 = 12.0;
+
+/// The frame showing `time_seconds` into playback of `count` frames, where
+/// frame `i` lasts `seconds(i)`. Negative times show the first frame.
+pub(crate) fn frame_at(
+    time_seconds: f32,
+    count: u32,
+    looping: ImageLoop,
+    seconds: &dyn Fn(u32) -> f32,
+) -> u32 {
+    if count <= 1 {
+        return 0;
+    }
+    let steps = match looping {
+        ImageLoop::PingPong => 2 * count - 2,
+        ImageLoop::Loop | ImageLoop::Once => count,
+    };
+    let frame_of_step = |step: u32| {
+        if step < count {
+            step
+        } else {
+            2 * count - 2 - step
+        }
+    };
+    let total: f32 = (0..steps).map(|step| seconds(frame_of_step(step))).sum();
+    if total <= 0.0 || time_seconds.is_nan() {
+        return 0;
+    }
+    let mut time = time_seconds.max(0.0);
+    if time >= total {
+        if looping == ImageLoop::Once {
+            return count - 1;
+        }
+        time %= total;
+    }
+    let mut elapsed = 0.0;
+    for step in 0..steps {
+        elapsed += seconds(frame_of_step(step));
+        if time < elapsed {
+            return frame_of_step(step);
+        }
+    }
+    frame_of_step(steps - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn frames(times: &[f32], count: u32, looping: ImageLoop) -> Vec<u32> {
+        times
+            .iter()
+            .map(|time| frame_at(*time, count, looping, &|_| 0.5))
+            .collect()
+    }
+
+    #[test]
+    fn loop_wraps_and_once_holds() {
+        let times = [-1.0, 0.0, 0.49, 0.5, 1.4, 1.5, 3.2];
+        assert_eq!(frames(&times, 3, ImageLoop::Loop), [0, 0, 0, 1, 2, 0, 0]);
+        assert_eq!(frames(&times, 3, ImageLoop::Once), [0, 0, 0, 1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn ping_pong_turns_around_without_doubling_the_ends() {
+        let times: Vec<f32> = (0..10).map(|step| step as f32 * 0.5 + 0.1).collect();
+        assert_eq!(
+            frames(&times, 4, ImageLoop::PingPong),
+            [0, 1, 2, 3, 2, 1, 0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn per_frame_durations_are_honoured() {
+        let delays = [0.1f32, 1.0, 0.1];
+        let at = |time| frame_at(time, 3, ImageLoop::Loop, &|frame| delays[frame as usize]);
+        assert_eq!([at(0.05), at(0.5), at(1.15), at(1.25)], [0, 1, 2, 0]);
+        assert_eq!(frame_at(3.0, 1, ImageLoop::Loop, &|_| 0.0), 0);
+    }
+}
@@ -0,0 +1,91 @@
+//! How an image's frame sits on the player's canvas.
+//!
+//! Scaling is nearest-neighbour and decided per axis once: each canvas
+//! column (row) maps to one source column (row) or to none, so drawing a
+//! decoded source row is a table lookup per canvas pixel and the decoder
+//! never needs more than a row of source pixels.
+
+use alloc::vec::Vec;
+
+/// How a frame fits a canvas of a different shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageScale {
+    /// Fill the canvas, distorting the aspect ratio.
+    Stretch,
+    /// Show the whole frame, letterboxed with transparency.
+    #[default]
+    Fit,
+    /// Cover the whole canvas, cropping the frame's overflow.
+    Fill,
+    /// One image pixel per canvas pixel, centered (cropped or padded).
+    Center,
+}
+
+impl ImageScale {
+    /// Canvas index → source index for both axes, for a `source` frame on a
+    /// `canvas`, both `[width, height]`.
+    pub(crate) fn axis_maps(self, source: [u32; 2], canvas: [u32; 2]) -> [Vec<Option<u32>>; 2] {
+        let ratio = [
+            canvas[0] as f32 / source[0] as f32,
+            canvas[1] as f32 / source[1] as f32,
+        ];
+        let scale = match self {
+            Self::Stretch => None,
+            Self::Fit => Some(ratio[0].min(ratio[1])),
+            Self::Fill => Some(ratio[0].max(ratio[1])),
+            Self::Center => Some(1.0),
+        };
+        [0, 1].map(|axis| {
+            let span = match scale {
+                Some(scale) => source[axis] as f32 * scale,
+                None => canvas[axis] as f32,
+            };
+            let offset = (canvas[axis] as f32 - span) / 2.0;
+            axis_map(source[axis], canvas[axis], span, offset)
+        })
+    }
+}
+
+/// Each of `canvas` positions sampled at its center into a frame of
+/// `source` pixels drawn `span` wide starting at `offset`.
+fn axis_map(source: u32, canvas: u32, span: f32, offset: f32) -> Vec<Option<u32>> {
+    (0..canvas)
+        .map(|index| {
+            let at = (index as f32 + 0.5 - offset) * source as f32 / span;
+            (at >= 0.0 && at < source as f32).then_some(at as u32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn stretch_samples_pixel_centers() {
+        let [columns, rows] = ImageScale::Stretch.axis_maps([4, 2], [8, 1]);
+        assert_eq!(columns, (0..8).map(|x| Some(x / 2)).collect::<Vec<_>>());
+        assert_eq!(rows, vec![Some(1)]);
+        let [same, _] = ImageScale::Stretch.axis_maps([5, 5], [5, 5]);
+        assert_eq!(same, (0..5).map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fit_letterboxes_and_fill_crops() {
+        // a 4x2 frame on an 4x4 canvas
+        let [columns, rows] = ImageScale::Fit.axis_maps([4, 2], [4, 4]);
+        assert_eq!(columns, vec![Some(0), Some(1), Some(2), Some(3)]);
+        assert_eq!(rows, vec![None, Some(0), Some(1), None]);
+        let [columns, rows] = ImageScale::Fill.axis_maps([4, 2], [4, 4]);
+        assert_eq!(columns, vec![Some(1), Some(1), Some(2), Some(2)]);
+        assert_eq!(rows, vec![Some(0), Some(0), Some(1), Some(1)]);
+    }
+
+    #[test]
+    fn center_keeps_pixels_one_to_one() {
+        let [columns, rows] = ImageScale::Center.axis_maps([2, 6], [4, 2]);
+        assert_eq!(columns, vec![None, Some(0), Some(1), None]);
+        assert_eq!(rows, vec![Some(2), Some(3)]);
+    }
+}
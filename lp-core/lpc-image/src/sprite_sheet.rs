@@ -0,0 +1,100 @@
+//! Sprite sheets: a still image cut into a grid of equal cells that play as
+//! frames, left to right then top to bottom.
+
+use crate::gif::PixelRect;
+
+/// The grid a sheet is cut into. The default — one column, one row — is an
+/// ordinary image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpriteSheet {
+    pub columns: u32,
+    pub rows: u32,
+    /// Cells that hold frames; `0` means all of them. A last row that is
+    /// only partly filled sets this below `columns × rows`.
+    pub frames: u32,
+}
+
+impl Default for SpriteSheet {
+    fn default() -> Self {
+        Self {
+            columns: 1,
+            rows: 1,
+            frames: 0,
+        }
+    }
+}
+
+impl SpriteSheet {
+    /// Whether the image is cut at all.
+    pub fn is_sheet(&self) -> bool {
+        self.cells() > 1
+    }
+
+    pub fn frame_count(&self) -> u32 {
+        match self.frames {
+            0 => self.cells(),
+            frames => frames.min(self.cells()),
+        }
+    }
+
+    /// Cell `frame` of an image `width × height`; cells drop the remainder
+    /// when the image does not divide evenly.
+    pub fn cell(&self, frame: u32, width: u32, height: u32) -> PixelRect {
+        let columns = self.columns.max(1);
+        let cell_width = (width / columns).max(1);
+        let cell_height = (height / self.rows.max(1)).max(1);
+        let frame = frame.min(self.frame_count().saturating_sub(1));
+        PixelRect {
+            x: (frame % columns) * cell_width,
+            y: (frame / columns) * cell_height,
+            width: cell_width,
+            height: cell_height,
+        }
+    }
+
+    fn cells(&self) -> u32 {
+        self.columns.max(1).saturating_mul(self.rows.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cells_run_across_then_down() {
+        let sheet = SpriteSheet {
+            columns: 4,
+            rows: 2,
+            frames: 7,
+        };
+        assert!(sheet.is_sheet());
+        assert_eq!(sheet.frame_count(), 7);
+        assert_eq!(
+            sheet.cell(5, 34, 17),
+            PixelRect {
+                x: 8,
+                y: 8,
+                width: 8,
+                height: 8
+            }
+        );
+        assert_eq!(sheet.cell(99, 34, 17), sheet.cell(6, 34, 17));
+    }
+
+    #[test]
+    fn the_default_is_the_whole_image() {
+        let sheet = SpriteSheet::default();
+        assert!(!sheet.is_sheet());
+        assert_eq!(sheet.frame_count(), 1);
+        assert_eq!(
+            sheet.cell(0, 5, 3),
+            PixelRect {
+                x: 0,
+                y: 0,
+                width: 5,
+                height: 3
+            }
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Errors for the photo auto-mapping solver.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaptureError {
+    /// The capture does not hold one image per pattern frame.
+    FrameCount { expected: u32, found: u32 },
+    /// An image's dimensions differ from the first image's.
+    FrameSize { frame: u32, width: u32, height: u32 },
+    /// An image's pixel buffer is not `width × height` bytes.
+    BufferSize {
+        frame: u32,
+        expected: usize,
+        found: usize,
+    },
+    /// The pattern has no lamps.
+    NoLamps,
+    /// No lamp decoded anywhere in the images, so there is nothing to place
+    /// the missing ones relative to.
+    NoLampsFound,
+}
+
+impl core::fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FrameCount { expected, found } => write!(
+                f,
+                "capture has {found} images, the pattern needs {expected}"
+            ),
+            Self::FrameSize {
+                frame,
+                width,
+                height,
+            } => write!(
+                f,
+                "image {frame} is {width}×{height}, unlike the first image"
+            ),
+            Self::BufferSize {
+                frame,
+                expected,
+                found,
+            } => write!(
+                f,
+                "image {frame} holds {found} bytes, its size needs {expected}"
+            ),
+            Self::NoLamps => write!(f, "capture pattern has no lamps"),
+            Self::NoLampsFound => write!(f, "no lamp was found in the capture"),
+        }
+    }
+}
+
+impl core::error::Error for CaptureError {}
@@ -0,0 +1,171 @@
+//! The coded lamp sequence a capture session photographs.
+//!
+//! Every lamp gets a Gray-coded identity (`index ^ (index >> 1)`) spread over
+//! `bits` frames. Each bit frame is followed by its inverse, so the solver
+//! decides a bit by comparing two photos of the same pixel instead of a fixed
+//! threshold — ambient light, lens falloff and per-lamp brightness cancel.
+//! Two reference frames lead the sequence: everything lit (where lamps are
+//! visible at all) and everything dark (the ambient floor). Gray coding keeps
+//! a pixel straddling two neighbouring lamps one bit away from either, so a
+//! blurred spot edge is rejected as ambiguous rather than decoded as a distant
+//! third lamp.
+//!
+//! Frame order: `0` all on, `1` all off, then `2 + 2b` bit `b` and `3 + 2b`
+//! its inverse, lowest bit first.
+
+/// Seconds each frame holds when the engine plays the sequence
+/// (`FixtureDiagnosticMode::Automap`): long enough for a phone camera to
+/// settle exposure between shots.
+pub const CAPTURE_FRAME_SECONDS: f32 = 1.0;
+
+/// One frame of the sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureFrame {
+    /// Every lamp lit: the per-pixel white reference.
+    AllOn,
+    /// Every lamp dark: the per-pixel ambient reference.
+    AllOff,
+    /// Lamps whose code has bit `bit` set (or clear, when `inverted`) are lit.
+    Bit { bit: u32, inverted: bool },
+}
+
+/// The coded sequence for a fixture of `lamp_count` lamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapturePattern {
+    lamp_count: u32,
+    bits: u32,
+}
+
+impl CapturePattern {
+    pub fn new(lamp_count: u32) -> Self {
+        let highest = lamp_count.saturating_sub(1);
+        let bits = (u32::BITS - highest.leading_zeros()).max(1);
+        Self { lamp_count, bits }
+    }
+
+    pub fn lamp_count(&self) -> u32 {
+        self.lamp_count
+    }
+
+    /// Code bits per lamp: `ceil(log2(lamp_count))`, at least one.
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    /// Frames in one pass of the sequence (two references plus a pair per
+    /// bit) — also the number of images [`super::solve_capture`] expects.
+    pub fn frame_count(&self) -> u32 {
+        2 + 2 * self.bits
+    }
+
+    pub fn frame(&self, index: u32) -> Option<CaptureFrame> {
+        match index {
+            0 => Some(CaptureFrame::AllOn),
+            1 => Some(CaptureFrame::AllOff),
+            _ if index < self.frame_count() => Some(CaptureFrame::Bit {
+                bit: (index - 2) / 2,
+                inverted: index % 2 == 1,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether `lamp` is lit in frame `index`. Lamps past the count and
+    /// frames past the sequence are dark.
+    pub fn is_lit(&self, index: u32, lamp: u32) -> bool {
+        if lamp >= self.lamp_count {
+            return false;
+        }
+        match self.frame(index) {
+            Some(CaptureFrame::AllOn) => true,
+            Some(CaptureFrame::AllOff) | None => false,
+            Some(CaptureFrame::Bit { bit, inverted }) => {
+                ((gray_encode(lamp) >> bit) & 1 == 1) != inverted
+            }
+        }
+    }
+
+    /// The frame showing at `time_seconds` when the sequence loops with
+    /// [`CAPTURE_FRAME_SECONDS`] per frame from time zero.
+    pub fn frame_at_time(&self, time_seconds: f32) -> u32 {
+        let time = if time_seconds.is_sign_negative() {
+            0.0
+        } else {
+            time_seconds
+        };
+        ((time / CAPTURE_FRAME_SECONDS) as u32) % self.frame_count()
+    }
+}
+
+pub fn gray_encode(index: u32) -> u32 {
+    index ^ (index >> 1)
+}
+
+pub fn gray_decode(code: u32) -> u32 {
+    let mut index = code;
+    let mut shift = code >> 1;
+    while shift != 0 {
+        index ^= shift;
+        shift >>= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn bit_count_covers_every_lamp() {
+        assert_eq!(CapturePattern::new(0).bits(), 1);
+        assert_eq!(CapturePattern::new(1).bits(), 1);
+        assert_eq!(CapturePattern::new(2).bits(), 1);
+        assert_eq!(CapturePattern::new(3).bits(), 2);
+        assert_eq!(CapturePattern::new(256).bits(), 8);
+        assert_eq!(CapturePattern::new(257).bits(), 9);
+        assert_eq!(CapturePattern::new(257).frame_count(), 20);
+    }
+
+    #[test]
+    fn each_lamp_reads_back_its_own_index() {
+        let pattern = CapturePattern::new(37);
+        for lamp in 0..37 {
+            let mut code = 0;
+            for bit in 0..pattern.bits() {
+                let on = pattern.is_lit(2 + 2 * bit, lamp);
+                assert_ne!(on, pattern.is_lit(3 + 2 * bit, lamp), "pair is inverse");
+                code |= u32::from(on) << bit;
+            }
+            assert_eq!(gray_decode(code), lamp);
+            assert!(pattern.is_lit(0, lamp));
+            assert!(!pattern.is_lit(1, lamp));
+        }
+        assert!(!pattern.is_lit(0, 37), "lamps past the count stay dark");
+    }
+
+    #[test]
+    fn neighbouring_lamps_differ_in_one_bit() {
+        for lamp in 0..1000 {
+            assert_eq!((gray_encode(lamp) ^ gray_encode(lamp + 1)).count_ones(), 1);
+        }
+    }
+
+    #[test]
+    fn the_sequence_loops_over_time() {
+        let pattern = CapturePattern::new(4);
+        let frames = [0.0, 0.5, 1.0, 5.9, 6.0, -3.0]
+            .iter()
+            .map(|time| pattern.frame_at_time(*time))
+            .collect::<Vec<_>>();
+        assert_eq!(frames, [0, 0, 1, 5, 0, 0]);
+        assert_eq!(pattern.frame(6), None);
+        assert_eq!(
+            pattern.frame(5),
+            Some(CaptureFrame::Bit {
+                bit: 1,
+                inverted: true
+            })
+        );
+    }
+}
@@ -0,0 +1,493 @@
+//! Captured images → lamp positions with per-lamp confidence.
+//!
+//! Each pixel is decoded on its own: the all-on and all-off frames give its
+//! contrast, every bit pair gives one code bit (`on − off`, normalized by the
+//! contrast), and the weakest bit is the pixel's margin. Pixels too dim to see
+//! a lamp, or with any bit too close to call, are dropped; the rest vote for
+//! the lamp their Gray code names. A lamp's position is the centroid of its
+//! pixels weighted by `contrast × margin`, so the bright, unambiguous core of
+//! its spot dominates the blurred rim.
+//!
+//! Confidence multiplies three scores in `0..=1`: how decisively its pixels
+//! decoded (mean margin), whether enough pixels saw it at all, and how compact
+//! they are — a lamp whose pixels scatter (a reflection in a window, a spot
+//! merged with a neighbour's) is placed at an average of several places and
+//! deserves review.
+
+use alloc::vec::Vec;
+
+use crate::import::cells_to_path;
+use crate::map2d_doc::{Map2dDoc, Map2dObject, Map2dShape};
+
+use super::capture_error::CaptureError;
+use super::capture_pattern::{CapturePattern, gray_decode};
+
+/// One photo of the sequence: 8-bit luma, row-major, `width × height` bytes.
+/// Convert from the camera's format (and register the shots to each other —
+/// the solver assumes a fixed camera) before solving.
+#[derive(Debug, Clone, Copy)]
+pub struct CaptureImage<'a> {
+    pub width: u32,
+    pub height: u32,
+    pub luma: &'a [u8],
+}
+
+/// Solver thresholds. The defaults suit a phone photo of a dark room.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaptureConfig {
+    /// Smallest all-on − all-off brightness step that counts as a lamp being
+    /// visible at a pixel.
+    pub min_contrast: u8,
+    /// Smallest normalized `|on − off| / contrast` every bit of a pixel must
+    /// clear for the pixel to decode.
+    pub min_margin: f32,
+    /// Pixels a lamp needs for full confidence.
+    pub min_pixels: u32,
+    /// RMS pixel spread (pixels) beyond which confidence falls off.
+    pub spot_radius: f32,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            min_contrast: 24,
+            min_margin: 0.3,
+            min_pixels: 4,
+            spot_radius: 6.0,
+        }
+    }
+}
+
+/// One lamp of a solved capture, in wiring order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CapturedLamp {
+    /// Image-space position of the lamp (pixel centers sit on `x + 0.5`), or
+    /// `None` when no pixel decoded to it.
+    pub position: Option<[f32; 2]>,
+    /// `0..=1`; `0` when the lamp was not found.
+    pub confidence: f32,
+    /// Pixels that decoded to this lamp.
+    pub pixels: u32,
+}
+
+/// The solver's output: every lamp of the pattern, found or not.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureSolution {
+    pub width: u32,
+    pub height: u32,
+    pub lamps: Vec<CapturedLamp>,
+}
+
+impl CaptureSolution {
+    /// Position of every lamp in wiring order. A lamp that was not found is
+    /// placed along the wiring between its nearest found neighbours (or at
+    /// the nearest one, past either end) so the strand stays whole and its
+    /// index stays put; its confidence stays `0` to flag it.
+    pub fn points(&self) -> Result<Vec<[f32; 2]>, CaptureError> {
+        let found = self
+            .lamps
+            .iter()
+            .enumerate()
+            .filter_map(|(index, lamp)| lamp.position.map(|position| (index, position)))
+            .collect::<Vec<_>>();
+        if found.is_empty() {
+            return Err(CaptureError::NoLampsFound);
+        }
+        let mut points = Vec::with_capacity(self.lamps.len());
+        let mut next = 0;
+        for index in 0..self.lamps.len() {
+            while next < found.len() && found[next].0 < index {
+                next += 1;
+            }
+            let point = match (next.checked_sub(1).map(|i| found[i]), found.get(next)) {
+                (_, Some(&(at, position))) if at == index => position,
+                (Some((before, from)), Some(&(after, to))) => {
+                    let t = (index - before) as f32 / (after - before) as f32;
+                    [
+                        from[0] + (to[0] - from[0]) * t,
+                        from[1] + (to[1] - from[1]) * t,
+                    ]
+                }
+                (Some((_, position)), None) | (None, Some(&(_, position))) => position,
+                (None, None) => unreachable!("found is not empty"),
+            };
+            points.push(point);
+        }
+        Ok(points)
+    }
+
+    /// A one-object document placing every lamp at [`Self::points`], framed by
+    /// the image. The object is a gapped path through the points (see
+    /// [`crate::import`]'s grid importers), so the result edits like any other
+    /// imported layout.
+    pub fn to_doc(&self, name: &str, sample_diameter: f32) -> Result<Map2dDoc, CaptureError> {
+        let points = self.points()?;
+        let mut doc = Map2dDoc {
+            sample_diameter,
+            canvas: Some([0.0, 0.0, self.width as f32, self.height as f32]),
+            objects: alloc::vec![Map2dObject {
+                name: name.into(),
+                shape: Map2dShape::Path(cells_to_path(&points)),
+            }],
+            ..Map2dDoc::new()
+        };
+        doc.normalize_format();
+        Ok(doc)
+    }
+}
+
+/// Solve a capture: `images[i]` is the photo of `pattern` frame `i`.
+pub fn solve_capture(
+    pattern: &CapturePattern,
+    images: &[CaptureImage<'_>],
+    config: &CaptureConfig,
+) -> Result<CaptureSolution, CaptureError> {
+    if pattern.lamp_count() == 0 {
+        return Err(CaptureError::NoLamps);
+    }
+    let (width, height) = check_images(pattern, images)?;
+
+    let mut sums = alloc::vec![LampSums::default(); pattern.lamp_count() as usize];
+    for y in 0..height {
+        for x in 0..width {
+            let pixel = (y * width + x) as usize;
+            let Some((lamp, contrast, margin)) = decode_pixel(pattern, images, pixel, config)
+            else {
+                continue;
+            };
+            sums[lamp as usize].add(
+                f64::from(x) + 0.5,
+                f64::from(y) + 0.5,
+                contrast * margin,
+                margin,
+            );
+        }
+    }
+
+    Ok(CaptureSolution {
+        width,
+        height,
+        lamps: sums.iter().map(|sums| sums.lamp(config)).collect(),
+    })
+}
+
+fn check_images(
+    pattern: &CapturePattern,
+    images: &[CaptureImage<'_>],
+) -> Result<(u32, u32), CaptureError> {
+    let expected = pattern.frame_count();
+    if images.len() != expected as usize {
+        return Err(CaptureError::FrameCount {
+            expected,
+            found: images.len() as u32,
+        });
+    }
+    let (width, height) = (images[0].width, images[0].height);
+    for (frame, image) in images.iter().enumerate() {
+        let frame = frame as u32;
+        if (image.width, image.height) != (width, height) {
+            return Err(CaptureError::FrameSize {
+                frame,
+                width: image.width,
+                height: image.height,
+            });
+        }
+        let expected = width as usize * height as usize;
+        if image.luma.len() != expected {
+            return Err(CaptureError::BufferSize {
+                frame,
+                expected,
+                found: image.luma.len(),
+            });
+        }
+    }
+    Ok((width, height))
+}
+
+/// `(lamp, contrast, margin)` for a pixel that decodes cleanly.
+fn decode_pixel(
+    pattern: &CapturePattern,
+    images: &[CaptureImage<'_>],
+    pixel: usize,
+    config: &CaptureConfig,
+) -> Option<(u32, f64, f64)> {
+    let white = i32::from(images[0].luma[pixel]);
+    let dark = i32::from(images[1].luma[pixel]);
+    let contrast = white - dark;
+    if contrast < i32::from(config.min_contrast.max(1)) {
+        return None;
+    }
+    let mut code = 0;
+    let mut margin = f64::INFINITY;
+    for bit in 0..pattern.bits() {
+        let on = i32::from(images[(2 + 2 * bit) as usize].luma[pixel]);
+        let off = i32::from(images[(3 + 2 * bit) as usize].luma[pixel]);
+        let delta = f64::from(on - off) / f64::from(contrast);
+        if delta > 0.0 {
+            code |= 1 << bit;
+        }
+        margin = margin.min(delta.abs());
+    }
+    if margin < f64::from(config.min_margin) {
+        return None;
+    }
+    let lamp = gray_decode(code);
+    (lamp < pattern.lamp_count()).then_some((lamp, f64::from(contrast), margin.min(1.0)))
+}
+
+/// Running weighted moments of one lamp's pixels. `f64` because a photo's
+/// squared pixel coordinates times 8-bit weights outrun `f32` precision.
+#[derive(Debug, Clone, Copy, Default)]
+struct LampSums {
+    pixels: u32,
+    weight: f64,
+    x: f64,
+    y: f64,
+    squares: f64,
+    margin: f64,
+}
+
+impl LampSums {
+    fn add(&mut self, x: f64, y: f64, weight: f64, margin: f64) {
+        self.pixels += 1;
+        self.weight += weight;
+        self.x += x * weight;
+        self.y += y * weight;
+        self.squares += (x * x + y * y) * weight;
+        self.margin += margin;
+    }
+
+    fn lamp(&self, config: &CaptureConfig) -> CapturedLamp {
+        if self.pixels == 0 || self.weight <= 0.0 {
+            return CapturedLamp {
+                position: None,
+                confidence: 0.0,
+                pixels: 0,
+            };
+        }
+        let cx = self.x / self.weight;
+        let cy = self.y / self.weight;
+        let spread = libm::sqrt((self.squares / self.weight - cx * cx - cy * cy).max(0.0));
+
+        let margin = self.margin / f64::from(self.pixels);
+        let support = (f64::from(self.pixels) / f64::from(config.min_pixels.max(1))).min(1.0);
+        let radius = f64::from(config.spot_radius);
+        let compactness = if spread > radius {
+            radius / spread
+        } else {
+            1.0
+        };
+        CapturedLamp {
+            position: Some([cx as f32, cy as f32]),
+            confidence: (margin * support * compactness) as f32,
+            pixels: self.pixels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map2d_resolve::resolve;
+    use alloc::vec;
+
+    const WIDTH: u32 = 96;
+    const HEIGHT: u32 = 64;
+
+    /// A lamp rendered as a Gaussian spot at `center`, `peak` luma above the
+    /// ambient floor.
+    #[derive(Clone, Copy)]
+    struct Spot {
+        lamp: u32,
+        center: [f32; 2],
+        peak: f32,
+    }
+
+    /// Photograph every frame of `pattern`: a lit spot per visible lamp over a
+    /// sloped ambient floor, plus deterministic sensor noise.
+    fn render(pattern: &CapturePattern, spots: &[Spot]) -> Vec<Vec<u8>> {
+        let mut noise = 0x1234_5678u32;
+        (0..pattern.frame_count())
+            .map(|frame| {
+                let mut luma = Vec::with_capacity((WIDTH * HEIGHT) as usize);
+                for y in 0..HEIGHT {
+                    for x in 0..WIDTH {
+                        let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+                        let mut value = 10.0 + px * 0.2 + py * 0.1;
+                        for spot in spots {
+                            if pattern.is_lit(frame, spot.lamp) {
+                                let dx = px - spot.center[0];
+                                let dy = py - spot.center[1];
+                                value += spot.peak * libm::expf(-(dx * dx + dy * dy) / 4.5);
+                            }
+                        }
+                        noise = noise.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                        value += (noise >> 29) as f32 - 3.5;
+                        luma.push(value.clamp(0.0, 255.0) as u8);
+                    }
+                }
+                luma
+            })
+            .collect()
+    }
+
+    fn images(frames: &[Vec<u8>]) -> Vec<CaptureImage<'_>> {
+        frames
+            .iter()
+            .map(|luma| CaptureImage {
+                width: WIDTH,
+                height: HEIGHT,
+                luma,
+            })
+            .collect()
+    }
+
+    /// An irregular 20-lamp string wandering across the frame.
+    fn wandering_string() -> Vec<Spot> {
+        (0..20)
+            .map(|lamp| {
+                let t = lamp as f32;
+                Spot {
+                    lamp,
+                    center: [8.0 + t * 4.1, 32.0 + 18.0 * libm::sinf(t * 0.45)],
+                    peak: 200.0,
+                }
+            })
+            .collect()
+    }
+
+    fn assert_near(actual: [f32; 2], expected: [f32; 2], tolerance: f32) {
+        assert!(
+            (actual[0] - expected[0]).abs() < tolerance
+                && (actual[1] - expected[1]).abs() < tolerance,
+            "{actual:?} vs {expected:?}"
+        );
+    }
+
+    #[test]
+    fn locates_every_visible_lamp() {
+        let spots = wandering_string();
+        let pattern = CapturePattern::new(spots.len() as u32);
+        let frames = render(&pattern, &spots);
+        let solution =
+            solve_capture(&pattern, &images(&frames), &CaptureConfig::default()).unwrap();
+        assert_eq!((solution.width, solution.height), (WIDTH, HEIGHT));
+        for spot in &spots {
+            let lamp = solution.lamps[spot.lamp as usize];
+            assert_near(lamp.position.unwrap(), spot.center, 0.5);
+            assert!(lamp.confidence > 0.6, "lamp {}: {lamp:?}", spot.lamp);
+        }
+    }
+
+    #[test]
+    fn a_hidden_lamp_is_flagged_and_bridged_along_the_wiring() {
+        let mut spots = wandering_string();
+        let hidden = spots.remove(7);
+        let pattern = CapturePattern::new(20);
+        let frames = render(&pattern, &spots);
+        let solution =
+            solve_capture(&pattern, &images(&frames), &CaptureConfig::default()).unwrap();
+        assert_eq!(solution.lamps[7].position, None);
+        assert_eq!(solution.lamps[7].confidence, 0.0);
+
+        let points = solution.points().unwrap();
+        let [before, after] = [spots[6].center, spots[7].center];
+        let midpoint = [(before[0] + after[0]) / 2.0, (before[1] + after[1]) / 2.0];
+        assert_near(points[7], midpoint, 0.5);
+        assert_near(points[7], hidden.center, 2.0);
+    }
+
+    #[test]
+    fn a_reflection_lowers_confidence() {
+        let mut spots = wandering_string();
+        spots.push(Spot {
+            lamp: 3,
+            center: [60.0, 6.0],
+            peak: 120.0,
+        });
+        let pattern = CapturePattern::new(20);
+        let frames = render(&pattern, &spots);
+        let solution =
+            solve_capture(&pattern, &images(&frames), &CaptureConfig::default()).unwrap();
+        assert!(
+            solution.lamps[3].confidence < 0.5,
+            "{:?}",
+            solution.lamps[3]
+        );
+        assert!(solution.lamps[4].confidence > 0.6);
+    }
+
+    #[test]
+    fn the_document_places_lamps_where_they_were_seen() {
+        let spots = wandering_string();
+        let pattern = CapturePattern::new(spots.len() as u32);
+        let frames = render(&pattern, &spots);
+        let solution =
+            solve_capture(&pattern, &images(&frames), &CaptureConfig::default()).unwrap();
+        let doc = solution.to_doc("string", 3.0).unwrap();
+        assert_eq!(doc.canvas, Some([0.0, 0.0, WIDTH as f32, HEIGHT as f32]));
+        assert_eq!(doc.objects[0].name, "string");
+        let resolved = resolve(&doc).unwrap().positions();
+        for (position, lamp) in resolved.iter().zip(&solution.lamps) {
+            assert_near(*position, lamp.position.unwrap(), 1e-2);
+        }
+    }
+
+    #[test]
+    fn a_dark_capture_finds_nothing() {
+        let pattern = CapturePattern::new(5);
+        let frames = render(&pattern, &[]);
+        let solution =
+            solve_capture(&pattern, &images(&frames), &CaptureConfig::default()).unwrap();
+        assert!(solution.lamps.iter().all(|lamp| lamp.position.is_none()));
+        assert_eq!(solution.points(), Err(CaptureError::NoLampsFound));
+    }
+
+    #[test]
+    fn rejects_mismatched_images() {
+        let pattern = CapturePattern::new(2);
+        let luma = vec![0u8; 4];
+        let image = CaptureImage {
+            width: 2,
+            height: 2,
+            luma: &luma,
+        };
+        let config = CaptureConfig::default();
+        assert_eq!(
+            solve_capture(&pattern, &[image; 3], &config),
+            Err(CaptureError::FrameCount {
+                expected: 4,
+                found: 3
+            })
+        );
+        let wide = CaptureImage {
+            width: 4,
+            height: 1,
+            ..image
+        };
+        assert_eq!(
+            solve_capture(&pattern, &[image, image, wide, image], &config),
+            Err(CaptureError::FrameSize {
+                frame: 2,
+                width: 4,
+                height: 1
+            })
+        );
+        let short = CaptureImage {
+            luma: &luma[..3],
+            ..image
+        };
+        assert_eq!(
+            solve_capture(&pattern, &[image, short, image, image], &config),
+            Err(CaptureError::BufferSize {
+                frame: 1,
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            solve_capture(&CapturePattern::new(0), &[image; 4], &config),
+            Err(CaptureError::NoLamps)
+        );
+    }
+}
@@ -0,0 +1,22 @@
+//! Photo auto-mapping: locate a fixture's lamps from photos of a coded
+//! lighting sequence.
+//!
+//! The engine plays [`CapturePattern`]'s frames (`FixtureDiagnosticMode::Automap`);
+//! the user photographs each one from a fixed camera; [`solve_capture`]
+//! decodes which lamp lights every pixel and returns each lamp's image
+//! position with a confidence. [`CaptureSolution::to_doc`] turns the result
+//! into an ordinary mapping document — like import, a one-time conversion the
+//! user owns and corrects from then on. Low-confidence lamps are the ones to
+//! check by eye.
+//!
+//! Offline and sans-IO: images arrive as decoded 8-bit luma buffers.
+
+mod capture_error;
+mod capture_pattern;
+mod capture_solve;
+
+pub use capture_error::CaptureError;
+pub use capture_pattern::{CAPTURE_FRAME_SECONDS, CaptureFrame, CapturePattern};
+pub use capture_solve::{
+    CaptureConfig, CaptureImage, CaptureSolution, CapturedLamp, solve_capture,
+};
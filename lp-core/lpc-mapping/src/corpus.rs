@@ -3,10 +3,11 @@
 //!
 //! The JSON documents are the canonical corpus (they exercise serde on every
 //! use); fyeah derives from its real mapping SVG through the importer so the
-//! conversion path is exercised too. The strings are tiny and only linked
+//! conversion path is exercised too, and the WLED ledmap and xLights model
+//! sources do the same for the grid importers. The strings are tiny and only linked
 //! into binaries that reference them.
 
-use crate::import::svg_to_doc;
+use crate::import::{ledmap_to_doc, svg_to_doc, xmodel_to_doc};
 use crate::map2d_doc::{DEFAULT_SAMPLE_DIAMETER, Map2dDoc};
 
 /// One multi-ring button: 16-lamp outer ring + derived 8-lamp inner ring.
@@ -29,6 +30,14 @@ pub const REPEATED_SECTOR_JSON: &str = include_str!("corpus/repeated_sector.map2
 /// The real fyeah sign mapping SVG (10 labeled paths, 219 lamps).
 pub const FYEAH_SVG: &str = include_str!("corpus/fyeah_mapping.svg");
 
+/// A WLED 8×8 snake-wired panel with a 2×2 hole for a mounting post: 60
+/// lamps, the hole crossed by inert jumpers on rows 3 and 4.
+pub const POST_PANEL_LEDMAP: &str = include_str!("corpus/post_panel.ledmap.json");
+
+/// An xLights 7×7 custom model: a 12-node star outline, every step between
+/// nodes a jump across empty cells.
+pub const WINDOW_STAR_XMODEL: &str = include_str!("corpus/window_star.xmodel");
+
 pub fn basic_button() -> Map2dDoc {
     Map2dDoc::from_json(BASIC_BUTTON_JSON).expect("corpus basic_button parses")
 }
@@ -53,9 +62,19 @@ pub fn fyeah() -> Map2dDoc {
     svg_to_doc(FYEAH_SVG, DEFAULT_SAMPLE_DIAMETER).expect("corpus fyeah imports")
 }
 
+pub fn post_panel() -> Map2dDoc {
+    ledmap_to_doc(POST_PANEL_LEDMAP, DEFAULT_SAMPLE_DIAMETER).expect("corpus post_panel imports")
+}
+
+pub fn window_star() -> Map2dDoc {
+    xmodel_to_doc(WINDOW_STAR_XMODEL, DEFAULT_SAMPLE_DIAMETER).expect("corpus window_star imports")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::export::doc_to_ledmap;
+    use crate::import::LedmapJson;
     use crate::map2d_resolve::{LAMPS_PER_UNIVERSE, resolve};
 
     /// Expected `path:N,count:N` labels from the fyeah mapping SVG.
@@ -191,6 +210,68 @@ mod tests {
         assert_eq!(boundary.object, 6);
     }
 
+    /// Wiring order survives the ledmap import: the snake alternates rows,
+    /// the post's cells stay dark, and the one channel is still one object.
+    #[test]
+    fn post_panel_keeps_wiring_order_around_the_hole() {
+        let doc = post_panel();
+        assert_eq!(doc.format, 2, "the jumpers are inert path segments");
+        assert_eq!(doc.objects.len(), 1);
+        assert_eq!(doc.objects[0].name, "post panel");
+
+        let resolved = resolve(&doc).unwrap();
+        assert_eq!(resolved.lamps.len(), 60);
+        assert_eq!(resolved.spans.len(), 1);
+        assert_near(resolved.lamps[8].pos, [7.0, 1.0]);
+        assert_near(resolved.lamps[26].pos, [5.0, 3.0]);
+        assert_near(resolved.lamps[27].pos, [2.0, 3.0]);
+        for lamp in &resolved.lamps {
+            let [col, row] = [libm::roundf(lamp.pos[0]), libm::roundf(lamp.pos[1])];
+            assert!(
+                !((3.0..=4.0).contains(&col) && (3.0..=4.0).contains(&row)),
+                "lamp {} landed on the post at {:?}",
+                lamp.index,
+                lamp.pos
+            );
+        }
+    }
+
+    #[test]
+    fn post_panel_round_trips_back_to_its_ledmap() {
+        let exported = doc_to_ledmap(&post_panel(), 8, 8).unwrap();
+        let exported: LedmapJson = serde_json::from_str(&exported).unwrap();
+        let source: LedmapJson = serde_json::from_str(POST_PANEL_LEDMAP).unwrap();
+        assert_eq!(exported, source);
+    }
+
+    /// The xLights star crosses empty cells between every pair of nodes, so
+    /// every lamp is placed through a jumper — and each still lands on its
+    /// own cell, which the ledmap export then reads back in node order.
+    #[test]
+    fn window_star_places_every_node_on_its_cell() {
+        let doc = window_star();
+        assert_eq!(doc.objects[0].name, "Window Star");
+        assert_eq!(doc.canvas, Some([-0.5, -0.5, 7.0, 7.0]));
+        let resolved = resolve(&doc).unwrap();
+        assert_eq!(resolved.lamps.len(), 12);
+        assert_near(resolved.lamps[0].pos, [3.0, 0.0]);
+        assert_near(resolved.lamps[5].pos, [6.0, 6.0]);
+        assert_near(resolved.lamps[11].pos, [2.0, 1.0]);
+
+        let exported = doc_to_ledmap(&doc, 7, 7).unwrap();
+        let exported: LedmapJson = serde_json::from_str(&exported).unwrap();
+        assert_eq!(exported.map[3], 0, "node 1 is wiring index 0");
+        assert_eq!(exported.map[6 * 7 + 6], 5);
+        assert_eq!(exported.map.iter().filter(|cell| **cell >= 0).count(), 12);
+    }
+
+    fn assert_near(actual: [f32; 2], expected: [f32; 2]) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 1e-3 && (actual[1] - expected[1]).abs() < 1e-3,
+            "{actual:?} vs {expected:?}"
+        );
+    }
+
     #[test]
     fn corpus_documents_fit_the_asset_body_budget() {
         // Studio applies mapping docs as whole asset bodies with a 10 KiB
@@ -204,6 +285,8 @@ mod tests {
             ("gapped_path", gapped_path()),
             ("repeated_sector", repeated_sector()),
             ("fyeah", fyeah()),
+            ("post_panel", post_panel()),
+            ("window_star", window_star()),
         ] {
             let bytes = doc.to_json().len();
             assert!(
@@ -222,6 +305,8 @@ mod tests {
             gapped_path(),
             repeated_sector(),
             fyeah(),
+            post_panel(),
+            window_star(),
         ] {
             let round_tripped = Map2dDoc::from_json(&doc.to_json()).unwrap();
             assert_eq!(round_tripped, doc);
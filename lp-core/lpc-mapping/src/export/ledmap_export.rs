@@ -0,0 +1,179 @@
+//! Mapping document → WLED `ledmap.json`.
+//!
+//! The resolved lamps are binned into a `width × height` cell grid over the
+//! document's canvas (or, without one, its lamp bounds); each cell holds the
+//! wiring index of the lamp that lands there, `-1` when none does. A document
+//! imported with [`crate::import::ledmap_to_doc`] and exported at its own size
+//! reproduces the source map.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::map2d_doc::Map2dDoc;
+use crate::map2d_error::Map2dError;
+use crate::map2d_fit::bounds_of_points;
+use crate::map2d_resolve::resolve;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LedmapExportError {
+    /// The document does not resolve, or has nothing to frame.
+    Map2d(Map2dError),
+    /// The requested grid has no cells.
+    EmptyGrid,
+    /// Two lamps land in the same cell; a ledmap cell names one LED. Export
+    /// at a finer grid.
+    CellCollision {
+        col: u32,
+        row: u32,
+        first: u32,
+        second: u32,
+    },
+}
+
+impl core::fmt::Display for LedmapExportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Map2d(error) => write!(f, "{error}"),
+            Self::EmptyGrid => write!(f, "ledmap grid needs at least one column and one row"),
+            Self::CellCollision {
+                col,
+                row,
+                first,
+                second,
+            } => write!(
+                f,
+                "lamps {first} and {second} both land in cell ({col}, {row}); export at a finer grid"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for LedmapExportError {}
+
+impl From<Map2dError> for LedmapExportError {
+    fn from(error: Map2dError) -> Self {
+        Self::Map2d(error)
+    }
+}
+
+/// Export a document as a `width × height` WLED ledmap (JSON text, one grid
+/// row per line). A single-object document names the map after its object.
+pub fn doc_to_ledmap(doc: &Map2dDoc, width: u32, height: u32) -> Result<String, LedmapExportError> {
+    if width == 0 || height == 0 {
+        return Err(LedmapExportError::EmptyGrid);
+    }
+    let resolved = resolve(doc)?;
+    let positions = resolved.positions();
+    let frame = doc
+        .canvas_bounds()
+        .or_else(|| bounds_of_points(&positions))
+        .ok_or(Map2dError::EmptyBounds)?;
+
+    let mut map: Vec<i64> = alloc::vec![-1; width as usize * height as usize];
+    for lamp in &resolved.lamps {
+        let col = axis_cell(lamp.pos[0], frame.min_x, frame.width, width);
+        let row = axis_cell(lamp.pos[1], frame.min_y, frame.height, height);
+        let cell = &mut map[(row * width + col) as usize];
+        if *cell >= 0 {
+            return Err(LedmapExportError::CellCollision {
+                col,
+                row,
+                first: *cell as u32,
+                second: lamp.index,
+            });
+        }
+        *cell = i64::from(lamp.index);
+    }
+
+    let name = match doc.objects.as_slice() {
+        [only] if !only.name.is_empty() => Some(only.name.as_str()),
+        _ => None,
+    };
+    Ok(format_ledmap(name, width, &map))
+}
+
+/// The cell along one axis a coordinate falls in. Lamps outside the frame
+/// clamp to the edge cells, as [`crate::fit_points`] clamps to `[0, 1]`.
+fn axis_cell(value: f32, min: f32, extent: f32, cells: u32) -> u32 {
+    if extent <= f32::EPSILON {
+        return 0;
+    }
+    let scaled = libm::floorf((value - min) * cells as f32 / extent);
+    (scaled.max(0.0) as u32).min(cells - 1)
+}
+
+/// WLED's hand-edited layout: header fields first, then the map one grid row
+/// per line so the file reads as the panel it describes.
+fn format_ledmap(name: Option<&str>, width: u32, map: &[i64]) -> String {
+    let mut out = String::from("{");
+    if let Some(name) = name {
+        out.push_str("\"n\":");
+        out.push_str(&serde_json::to_string(name).expect("string serializes"));
+        out.push(',');
+    }
+    let height = map.len() as u32 / width;
+    out.push_str(&alloc::format!(
+        "\"width\":{width},\"height\":{height},\"map\":[\n"
+    ));
+    let rows = map.chunks(width as usize).collect::<Vec<_>>();
+    for (row_index, row) in rows.iter().enumerate() {
+        let cells = row.iter().map(|cell| cell.to_string()).collect::<Vec<_>>();
+        out.push_str(&cells.join(","));
+        if row_index + 1 < rows.len() {
+            out.push(',');
+        }
+        out.push('\n');
+    }
+    out.push_str("]}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corpus;
+    use crate::import::{LedmapJson, ledmap_to_doc};
+
+    #[test]
+    fn round_trips_a_ledmap_through_a_document() {
+        let source = r#"{"n":"panel","width":3,"height":2,"map":[0,1,2,-1,4,3]}"#;
+        let doc = ledmap_to_doc(source, 2.0).unwrap();
+        let exported = doc_to_ledmap(&doc, 3, 2).unwrap();
+        let parsed: LedmapJson = serde_json::from_str(&exported).unwrap();
+        let original: LedmapJson = serde_json::from_str(source).unwrap();
+        assert_eq!(parsed, original);
+        assert_eq!(
+            exported,
+            "{\"n\":\"panel\",\"width\":3,\"height\":2,\"map\":[\n0,1,2,\n-1,4,3\n]}\n"
+        );
+    }
+
+    #[test]
+    fn bins_a_parametric_document_over_its_lamp_bounds() {
+        // 16×16 snake panel; the canvas-less frame is the lamp bounds.
+        let exported = doc_to_ledmap(&corpus::panel_16x16(), 16, 16).unwrap();
+        let parsed: LedmapJson = serde_json::from_str(&exported).unwrap();
+        assert_eq!(parsed.n.as_deref(), Some("panel"));
+        // Snake: row 1 runs right to left.
+        assert_eq!(&parsed.map[..3], &[0, 1, 2]);
+        assert_eq!(parsed.map[16], 31);
+        assert_eq!(parsed.map[31], 16);
+    }
+
+    #[test]
+    fn refuses_two_lamps_in_one_cell() {
+        assert_eq!(
+            doc_to_ledmap(&corpus::panel_16x16(), 8, 8),
+            Err(LedmapExportError::CellCollision {
+                col: 0,
+                row: 0,
+                first: 0,
+                second: 1,
+            })
+        );
+        assert_eq!(
+            doc_to_ledmap(&corpus::panel_16x16(), 0, 8),
+            Err(LedmapExportError::EmptyGrid)
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! Export mapping documents to other tools' formats.
+//!
+//! Export is lossy by nature — the targets are lamp grids, not parametric
+//! shapes — so it works from the *resolved* lamp list: wiring order and
+//! positions, nothing else.
+
+mod ledmap_export;
+
+pub use ledmap_export::{LedmapExportError, doc_to_ledmap};
@@ -0,0 +1,283 @@
+//! Wiring-ordered grid cells → one gapped [`PathShape`].
+//!
+//! Grid-cell sources (WLED ledmaps, xLights custom models) name a cell for
+//! every lamp in wiring order. A path places its lamps evenly by *active* arc
+//! length, so the builder lays exactly one pitch of active length between each
+//! pair of consecutive lamps: neighbouring cells are joined directly, and any
+//! other step — a row change in raster wiring, a hole in the grid, a diagonal —
+//! becomes half a pitch of active tail, an inert jumper, and half a pitch of
+//! active lead-in. Every lamp then lands on its own cell, and no lamp ever sits
+//! on the boundary of a jumper, so float slop in the resolver's distance walk
+//! cannot carry one across it.
+
+use alloc::vec::Vec;
+
+use crate::map2d_doc::{Map2dDoc, Map2dObject, Map2dShape, PathShape};
+
+use super::grid_error::GridImportError;
+
+/// Doc-space distance between neighbouring cells. Cell `(col, row)` sits at
+/// `[col, row]`; the canvas frames each cell's full square around it.
+pub const CELL_PITCH: f32 = 1.0;
+
+/// Wiring index → the cells that name it, gathered in any order.
+///
+/// A lamp may be named by several cells (WLED maps one physical LED to
+/// multiple logical cells; an xLights node may cover several); it sits at
+/// their centroid.
+#[derive(Default)]
+pub struct CellLamps {
+    entries: Vec<(u32, [u32; 2])>,
+}
+
+impl CellLamps {
+    pub fn push(&mut self, index: u32, col: u32, row: u32) {
+        self.entries.push((index, [col, row]));
+    }
+
+    /// Every `(index, [col, row])` pushed so far, in push order.
+    pub fn cells(&self) -> impl Iterator<Item = (u32, [u32; 2])> + '_ {
+        self.entries.iter().copied()
+    }
+
+    /// Lamp positions in wiring order. Indices must run `0..n` without holes:
+    /// an unnamed index is a physical lamp with no position, and dropping it
+    /// would shift every later lamp's wiring index.
+    pub fn into_positions(mut self) -> Result<Vec<[f32; 2]>, GridImportError> {
+        if self.entries.is_empty() {
+            return Err(GridImportError::NoLamps);
+        }
+        self.entries.sort_by_key(|(index, _)| *index);
+        let mut positions: Vec<[f32; 2]> = Vec::new();
+        let mut cells_at_lamp = 0u32;
+        for (index, [col, row]) in self.entries {
+            let cell = [col as f32 * CELL_PITCH, row as f32 * CELL_PITCH];
+            let next = positions.len() as u32;
+            if next.checked_sub(1) == Some(index) {
+                // Another cell for the lamp just started: running centroid.
+                cells_at_lamp += 1;
+                let lamp = positions.last_mut().expect("lamp started");
+                let weight = 1.0 / cells_at_lamp as f32;
+                lamp[0] += (cell[0] - lamp[0]) * weight;
+                lamp[1] += (cell[1] - lamp[1]) * weight;
+            } else if index == next {
+                positions.push(cell);
+                cells_at_lamp = 1;
+            } else {
+                return Err(GridImportError::MissingIndex(next));
+            }
+        }
+        Ok(positions)
+    }
+}
+
+/// A one-object document over a `width × height` cell grid.
+///
+/// The canvas is the whole grid — empty cells included — so fitting frames the
+/// source layout as authored, and [`crate::export::doc_to_ledmap`] at the same
+/// size puts every lamp back on its cell.
+pub fn cells_to_doc(
+    name: &str,
+    width: u32,
+    height: u32,
+    positions: &[[f32; 2]],
+    sample_diameter: f32,
+) -> Map2dDoc {
+    let half = CELL_PITCH * 0.5;
+    let mut doc = Map2dDoc {
+        sample_diameter,
+        canvas: Some([
+            -half,
+            -half,
+            width as f32 * CELL_PITCH,
+            height as f32 * CELL_PITCH,
+        ]),
+        objects: alloc::vec![Map2dObject {
+            name: name.into(),
+            shape: Map2dShape::Path(cells_to_path(positions)),
+        }],
+        ..Map2dDoc::new()
+    };
+    doc.normalize_format();
+    doc
+}
+
+/// The gapped path through `positions` (non-empty), in order.
+pub fn cells_to_path(positions: &[[f32; 2]]) -> PathShape {
+    let mut builder = PathBuilder::new(positions[0]);
+    if positions.len() == 1 {
+        // A lone lamp sits at the start of a one-pitch run.
+        builder.active_to(add(positions[0], [CELL_PITCH, 0.0]));
+        return builder.finish(1);
+    }
+    let mut incoming: Option<[f32; 2]> = None;
+    for (step, pair) in positions.windows(2).enumerate() {
+        let [from, to] = [pair[0], pair[1]];
+        if let Some(dir) = neighbour_step(from, to) {
+            builder.active_to(to);
+            incoming = Some(dir);
+            continue;
+        }
+        let tail = incoming.unwrap_or([1.0, 0.0]);
+        let lead = positions
+            .get(step + 2)
+            .and_then(|after| neighbour_step(to, *after))
+            .unwrap_or([1.0, 0.0]);
+        let half = CELL_PITCH * 0.5;
+        builder.active_to(add(from, scale(tail, half)));
+        builder.inert_to(add(to, scale(lead, -half)));
+        builder.active_to(to);
+        incoming = None;
+    }
+    builder.finish(positions.len() as u32)
+}
+
+/// Unit direction from `from` to `to` when they are axis neighbours one pitch
+/// apart.
+fn neighbour_step(from: [f32; 2], to: [f32; 2]) -> Option<[f32; 2]> {
+    let dx = to[0] - from[0];
+    let dy = to[1] - from[1];
+    let is_step = (dx.abs() == CELL_PITCH && dy == 0.0) || (dx == 0.0 && dy.abs() == CELL_PITCH);
+    is_step.then(|| [dx / CELL_PITCH, dy / CELL_PITCH])
+}
+
+/// Accumulates points and inert segment indices, merging collinear active
+/// runs so a straight strip stays two points however many lamps it carries.
+struct PathBuilder {
+    points: Vec<[f32; 2]>,
+    gaps: Vec<u32>,
+    /// Direction of the last segment when it is active.
+    active_dir: Option<[f32; 2]>,
+}
+
+impl PathBuilder {
+    fn new(start: [f32; 2]) -> Self {
+        Self {
+            points: alloc::vec![start],
+            gaps: Vec::new(),
+            active_dir: None,
+        }
+    }
+
+    fn last(&self) -> [f32; 2] {
+        *self.points.last().expect("path has a start point")
+    }
+
+    fn active_to(&mut self, point: [f32; 2]) {
+        let last = self.last();
+        let dir = [point[0] - last[0], point[1] - last[1]];
+        let continues = self.active_dir.is_some_and(|previous| {
+            previous[0] * dir[1] == previous[1] * dir[0]
+                && previous[0] * dir[0] + previous[1] * dir[1] > 0.0
+        });
+        if continues {
+            *self.points.last_mut().expect("path has a start point") = point;
+        } else {
+            self.points.push(point);
+        }
+        self.active_dir = Some(dir);
+    }
+
+    fn inert_to(&mut self, point: [f32; 2]) {
+        self.gaps.push((self.points.len() - 1) as u32);
+        self.points.push(point);
+        self.active_dir = None;
+    }
+
+    fn finish(self, count: u32) -> PathShape {
+        PathShape {
+            points: self.points,
+            count,
+            reversed: false,
+            gaps: self.gaps,
+        }
+    }
+}
+
+fn add(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    [a[0] + b[0], a[1] + b[1]]
+}
+
+fn scale(v: [f32; 2], by: f32) -> [f32; 2] {
+    [v[0] * by, v[1] * by]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map2d_resolve::resolve;
+    use alloc::vec;
+
+    fn resolved_positions(positions: &[[f32; 2]]) -> Vec<[f32; 2]> {
+        let doc = cells_to_doc("cells", 8, 8, positions, 2.0);
+        resolve(&doc).unwrap().positions()
+    }
+
+    fn assert_on_cells(actual: &[[f32; 2]], expected: &[[f32; 2]]) {
+        assert_eq!(actual.len(), expected.len());
+        for (index, (got, want)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                (got[0] - want[0]).abs() < 1e-3 && (got[1] - want[1]).abs() < 1e-3,
+                "lamp {index}: {got:?} vs {want:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn a_straight_run_is_two_points_and_no_gaps() {
+        let cells = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0]];
+        let path = cells_to_path(&cells);
+        assert_eq!(path.points, vec![[0.0, 0.0], [3.0, 0.0]]);
+        assert!(path.gaps.is_empty());
+        assert_on_cells(&resolved_positions(&cells), &cells);
+    }
+
+    #[test]
+    fn raster_rows_jumper_back_and_keep_every_lamp_on_its_cell() {
+        let cells = [
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+            [2.0, 1.0],
+        ];
+        let path = cells_to_path(&cells);
+        assert_eq!(path.gaps.len(), 1);
+        assert_on_cells(&resolved_positions(&cells), &cells);
+    }
+
+    #[test]
+    fn holes_and_diagonals_become_jumpers() {
+        let cells = [[0.0, 0.0], [3.0, 0.0], [4.0, 1.0], [4.0, 2.0], [7.0, 7.0]];
+        assert_on_cells(&resolved_positions(&cells), &cells);
+    }
+
+    #[test]
+    fn a_single_lamp_resolves_to_its_cell() {
+        assert_on_cells(&resolved_positions(&[[2.0, 3.0]]), &[[2.0, 3.0]]);
+    }
+
+    #[test]
+    fn centroid_of_a_lamp_named_by_several_cells() {
+        let mut lamps = CellLamps::default();
+        lamps.push(1, 4, 0);
+        lamps.push(0, 0, 0);
+        lamps.push(0, 2, 0);
+        assert_eq!(
+            lamps.into_positions().unwrap(),
+            vec![[1.0, 0.0], [4.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn an_unnamed_wiring_index_is_refused() {
+        let mut lamps = CellLamps::default();
+        lamps.push(0, 0, 0);
+        lamps.push(2, 1, 0);
+        assert_eq!(
+            lamps.into_positions(),
+            Err(GridImportError::MissingIndex(1))
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Errors for the grid-cell importers (WLED `ledmap.json`, xLights
+//! `.xmodel`).
+
+use alloc::string::String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GridImportError {
+    /// The source is not valid JSON for the ledmap schema.
+    Parse(String),
+    InvalidAttribute {
+        name: &'static str,
+    },
+    InvalidNumber(String),
+    /// `map` does not hold `width × height` cells.
+    MapSize {
+        expected: u32,
+        found: u32,
+    },
+    /// A wiring index below the highest one is named by no cell. The lamp
+    /// exists on the wire but has no position, and dropping it would shift
+    /// every later lamp's wiring index.
+    MissingIndex(u32),
+    /// No cell names a lamp.
+    NoLamps,
+    /// A cell lies outside the declared grid.
+    CellOutOfRange {
+        col: u32,
+        row: u32,
+    },
+    /// A multi-layer (3D) custom model; mapping documents are 2D.
+    UnsupportedDepth(u32),
+}
+
+impl core::fmt::Display for GridImportError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Parse(reason) => write!(f, "invalid ledmap: {reason}"),
+            Self::InvalidAttribute { name } => {
+                write!(f, "invalid or missing xmodel attribute {name}")
+            }
+            Self::InvalidNumber(value) => write!(f, "invalid grid cell number {value:?}"),
+            Self::MapSize { expected, found } => write!(
+                f,
+                "ledmap map has {found} cells, width × height needs {expected}"
+            ),
+            Self::MissingIndex(index) => {
+                write!(f, "lamp {index} is on the wire but no cell places it")
+            }
+            Self::NoLamps => write!(f, "grid names no lamps"),
+            Self::CellOutOfRange { col, row } => {
+                write!(f, "cell ({col}, {row}) lies outside the declared grid")
+            }
+            Self::UnsupportedDepth(depth) => {
+                write!(f, "custom model has {depth} layers; only 2D models import")
+            }
+        }
+    }
+}
+
+impl core::error::Error for GridImportError {}
+
+pub fn invalid_number(value: &str) -> GridImportError {
+    GridImportError::InvalidNumber(String::from(value))
+}
@@ -0,0 +1,151 @@
+//! WLED `ledmap.json` → mapping-document conversion.
+//!
+//! A ledmap is a row-major grid of `width × height` cells, each holding the
+//! physical LED index shown there or `-1` for an empty cell:
+//!
+//! ```json
+//! { "n": "panel", "width": 3, "height": 2, "map": [0, 1, 2, -1, 4, 3] }
+//! ```
+//!
+//! The physical index is the wiring order, so the document is one
+//! [`crate::PathShape`] through the cells in index order; empty cells and
+//! non-adjacent steps become inert jumper segments (see [`super::cell_path`]).
+//! A map without `width`/`height` is a single row.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use serde::{Deserialize, Serialize};
+
+use crate::map2d_doc::Map2dDoc;
+
+use super::cell_path::{CellLamps, cells_to_doc};
+use super::grid_error::GridImportError;
+
+/// Object name used when the ledmap carries no `n`.
+pub const LEDMAP_DEFAULT_NAME: &str = "ledmap";
+
+/// The `ledmap.json` schema (shared with [`crate::export::doc_to_ledmap`]).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct LedmapJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    pub map: Vec<i64>,
+}
+
+/// Convert a WLED ledmap into a document. `sample_diameter` seeds the
+/// doc-level default (the ledmap itself does not carry one).
+pub fn ledmap_to_doc(json: &str, sample_diameter: f32) -> Result<Map2dDoc, GridImportError> {
+    let ledmap: LedmapJson =
+        serde_json::from_str(json).map_err(|e| GridImportError::Parse(e.to_string()))?;
+    let cells = ledmap.map.len() as u32;
+    let (width, height) = match (ledmap.width, ledmap.height) {
+        (Some(width), Some(height)) => (width, height),
+        _ => (cells, 1),
+    };
+    let expected = width.saturating_mul(height);
+    if expected != cells {
+        return Err(GridImportError::MapSize {
+            expected,
+            found: cells,
+        });
+    }
+
+    let mut lamps = CellLamps::default();
+    for (cell, value) in ledmap.map.iter().enumerate() {
+        // WLED marks empty cells with -1; any negative index is empty.
+        let Ok(index) = u32::try_from(*value) else {
+            continue;
+        };
+        let cell = cell as u32;
+        lamps.push(index, cell % width, cell / width);
+    }
+    let positions = lamps.into_positions()?;
+    let name = ledmap.n.as_deref().unwrap_or(LEDMAP_DEFAULT_NAME);
+    Ok(cells_to_doc(
+        name,
+        width,
+        height,
+        &positions,
+        sample_diameter,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map2d_doc::Map2dShape;
+    use crate::map2d_resolve::resolve;
+    use alloc::vec;
+
+    #[test]
+    fn places_lamps_in_physical_index_order() {
+        let doc = ledmap_to_doc(
+            r#"{"n":"panel","width":3,"height":2,"map":[0,1,2,-1,4,3]}"#,
+            2.0,
+        )
+        .unwrap();
+        assert_eq!(doc.objects.len(), 1);
+        assert_eq!(doc.objects[0].name, "panel");
+        assert_eq!(doc.canvas, Some([-0.5, -0.5, 3.0, 2.0]));
+        let positions = resolve(&doc).unwrap().positions();
+        assert_eq!(
+            positions,
+            vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn a_map_without_dimensions_is_one_row() {
+        let doc = ledmap_to_doc(r#"{"map":[2,1,0]}"#, 2.0).unwrap();
+        assert_eq!(doc.objects[0].name, LEDMAP_DEFAULT_NAME);
+        assert_eq!(doc.canvas, Some([-0.5, -0.5, 3.0, 1.0]));
+        let Map2dShape::Path(path) = &doc.objects[0].shape else {
+            panic!("expected path");
+        };
+        assert_eq!(path.points, vec![[2.0, 0.0], [0.0, 0.0]]);
+        assert_eq!(doc.format, 1, "a gap-free import needs no newer format");
+    }
+
+    #[test]
+    fn a_hole_in_the_wiring_becomes_an_inert_jumper() {
+        // Row 0 runs right, row 1 starts again at the left (raster).
+        let doc = ledmap_to_doc(r#"{"width":2,"height":2,"map":[0,1,2,3]}"#, 2.0).unwrap();
+        assert_eq!(doc.format, 2);
+        let Map2dShape::Path(path) = &doc.objects[0].shape else {
+            panic!("expected path");
+        };
+        assert_eq!(path.gaps.len(), 1);
+    }
+
+    #[test]
+    fn rejects_a_map_that_does_not_fill_the_grid() {
+        assert_eq!(
+            ledmap_to_doc(r#"{"width":2,"height":2,"map":[0,1,2]}"#, 2.0),
+            Err(GridImportError::MapSize {
+                expected: 4,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unplaced_physical_lamps_and_empty_maps() {
+        assert_eq!(
+            ledmap_to_doc(r#"{"map":[0,2]}"#, 2.0),
+            Err(GridImportError::MissingIndex(1))
+        );
+        assert_eq!(
+            ledmap_to_doc(r#"{"map":[-1,-1]}"#, 2.0),
+            Err(GridImportError::NoLamps)
+        );
+        assert!(matches!(
+            ledmap_to_doc(r#"{"width":2}"#, 2.0),
+            Err(GridImportError::Parse(_))
+        ));
+    }
+}
@@ -2,12 +2,27 @@
 //!
 //! Import is an explicit *conversion*, not a runtime source of truth: the
 //! output is a [`crate::Map2dDoc`] the user owns and edits from then on.
+//!
+//! - [`svg_to_doc`]: the strict Illustrator-friendly SVG subset.
+//! - [`ledmap_to_doc`]: a WLED `ledmap.json` grid.
+//! - [`xmodel_to_doc`]: an xLights custom model (`.xmodel`).
 
+mod cell_path;
+mod grid_error;
+mod ledmap_import;
 mod svg_data;
 mod svg_error;
 mod svg_group;
 mod svg_import;
 mod svg_parser;
+mod xml_scan;
+mod xmodel_import;
 
+pub(crate) use cell_path::cells_to_path;
+pub use grid_error::GridImportError;
+#[cfg(test)]
+pub(crate) use ledmap_import::LedmapJson;
+pub use ledmap_import::{LEDMAP_DEFAULT_NAME, ledmap_to_doc};
 pub use svg_error::SvgImportError;
 pub use svg_import::svg_to_doc;
+pub use xmodel_import::{XMODEL_DEFAULT_NAME, xmodel_to_doc};
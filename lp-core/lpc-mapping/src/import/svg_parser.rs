@@ -9,6 +9,7 @@ use crate::map2d_fit::Bounds2d;
 use super::svg_data::{parse_path_data, parse_polyline};
 use super::svg_error::{SvgImportError, invalid_label, invalid_number};
 use super::svg_group::{ParsedSvgPathGroups, SvgPathGroup};
+use super::xml_scan::{attr_value, find_tag};
 
 pub fn parse_svg_path_groups(svg: &str) -> Result<ParsedSvgPathGroups, SvgImportError> {
     let view_box = parse_view_box(svg)?;
@@ -233,67 +234,6 @@ fn normalize_text_payload(raw: &str) -> String {
     output.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn attr_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
-    let mut search_start = 0usize;
-    while let Some(found) = tag[search_start..].find(name) {
-        let start = search_start + found;
-        let after_name = start + name.len();
-        if !is_attr_boundary(tag, start, after_name) {
-            search_start = after_name;
-            continue;
-        }
-        let rest = tag[after_name..].trim_start();
-        let rest_offset = tag[after_name..].len() - rest.len();
-        if !rest.starts_with('=') {
-            search_start = after_name;
-            continue;
-        }
-        let after_equals = after_name + rest_offset + 1;
-        let value = tag[after_equals..].trim_start();
-        let value_offset = tag[after_equals..].len() - value.len();
-        let quote = value.chars().next()?;
-        if quote != '"' && quote != '\'' {
-            return None;
-        }
-        let value_start = after_equals + value_offset + 1;
-        let value_end = tag[value_start..]
-            .find(quote)
-            .map(|offset| value_start + offset)?;
-        return Some(&tag[value_start..value_end]);
-    }
-    None
-}
-
-fn is_attr_boundary(tag: &str, start: usize, end: usize) -> bool {
-    let before = tag[..start]
-        .chars()
-        .next_back()
-        .is_none_or(|c| c.is_ascii_whitespace() || c == '<');
-    let after = tag[end..]
-        .chars()
-        .next()
-        .is_none_or(|c| c.is_ascii_whitespace() || c == '=');
-    before && after
-}
-
-fn find_tag(svg: &str, name: &str, from: usize) -> Option<usize> {
-    let needle = alloc::format!("<{name}");
-    let mut cursor = from;
-    while let Some(offset) = svg[cursor..].find(&needle) {
-        let start = cursor + offset;
-        let after_name = start + needle.len();
-        if svg[after_name..]
-            .chars()
-            .next()
-            .is_some_and(|c| c.is_ascii_whitespace() || c == '>' || c == '/')
-        {
-            return Some(start);
-        }
-        cursor = after_name;
-    }
-    None
-}
-
 fn parse_f32(value: &str) -> Result<f32, SvgImportError> {
     value.parse().map_err(|_| invalid_number(value))
 }
@@ -0,0 +1,75 @@
+//! Minimal tag/attribute scanning for the XML-shaped import sources (SVG,
+//! xLights `.xmodel`). Not an XML parser: it finds `<name` tags and quoted
+//! attribute values, which is all the strict import subsets need.
+
+pub fn attr_value<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut search_start = 0usize;
+    while let Some(found) = tag[search_start..].find(name) {
+        let start = search_start + found;
+        let after_name = start + name.len();
+        if !is_attr_boundary(tag, start, after_name) {
+            search_start = after_name;
+            continue;
+        }
+        let rest = tag[after_name..].trim_start();
+        let rest_offset = tag[after_name..].len() - rest.len();
+        if !rest.starts_with('=') {
+            search_start = after_name;
+            continue;
+        }
+        let after_equals = after_name + rest_offset + 1;
+        let value = tag[after_equals..].trim_start();
+        let value_offset = tag[after_equals..].len() - value.len();
+        let quote = value.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value_start = after_equals + value_offset + 1;
+        let value_end = tag[value_start..]
+            .find(quote)
+            .map(|offset| value_start + offset)?;
+        return Some(&tag[value_start..value_end]);
+    }
+    None
+}
+
+fn is_attr_boundary(tag: &str, start: usize, end: usize) -> bool {
+    let before = tag[..start]
+        .chars()
+        .next_back()
+        .is_none_or(|c| c.is_ascii_whitespace() || c == '<');
+    let after = tag[end..]
+        .chars()
+        .next()
+        .is_none_or(|c| c.is_ascii_whitespace() || c == '=');
+    before && after
+}
+
+pub fn find_tag(source: &str, name: &str, from: usize) -> Option<usize> {
+    let needle = alloc::format!("<{name}");
+    let mut cursor = from;
+    while let Some(offset) = source[cursor..].find(&needle) {
+        let start = cursor + offset;
+        let after_name = start + needle.len();
+        if source[after_name..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_whitespace() || c == '>' || c == '/')
+        {
+            return Some(start);
+        }
+        cursor = after_name;
+    }
+    None
+}
+
+/// Decode the five predefined XML entities in an attribute value. Numeric
+/// character references are left as-is — the import subsets never need them.
+pub fn decode_entities(value: &str) -> alloc::string::String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
@@ -0,0 +1,226 @@
+//! xLights custom model (`.xmodel`) → mapping-document conversion.
+//!
+//! A custom model is a `<custommodel>` element whose `parm1 × parm2` grid
+//! (columns × rows) holds 1-based node numbers; the node number is the wiring
+//! order. The grid arrives in one of two encodings:
+//!
+//! - `CustomModel`: rows separated by `;`, cells by `,`, empty cells blank
+//!   (`",1,;2,,3"`), depth layers separated by `|`.
+//! - `CustomModelCompressed` (newer xLights): `node,row,col[,layer]` entries
+//!   separated by `;`, rows and columns 0-based.
+//!
+//! Only single-layer models import — a mapping document is 2D. Like the
+//! ledmap importer, the result is one [`crate::PathShape`] through the cells
+//! in node order (see [`super::cell_path`]).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::map2d_doc::Map2dDoc;
+
+use super::cell_path::{CellLamps, cells_to_doc};
+use super::grid_error::{GridImportError, invalid_number};
+use super::xml_scan::{attr_value, decode_entities, find_tag};
+
+/// Object name used when the model carries no `name`.
+pub const XMODEL_DEFAULT_NAME: &str = "custom model";
+
+/// Convert an xLights custom model into a document. `sample_diameter` seeds
+/// the doc-level default.
+pub fn xmodel_to_doc(xml: &str, sample_diameter: f32) -> Result<Map2dDoc, GridImportError> {
+    let start = find_tag(xml, "custommodel", 0).ok_or(GridImportError::InvalidAttribute {
+        name: "custommodel",
+    })?;
+    let tag_end = xml[start..].find('>').map(|offset| start + offset).ok_or(
+        GridImportError::InvalidAttribute {
+            name: "custommodel",
+        },
+    )?;
+    let tag = &xml[start..=tag_end];
+
+    let width = required_u32(tag, "parm1")?;
+    let height = required_u32(tag, "parm2")?;
+    let depth = match attr_value(tag, "Depth") {
+        Some(raw) => parse_u32(raw.trim())?,
+        None => 1,
+    };
+    if depth > 1 {
+        return Err(GridImportError::UnsupportedDepth(depth));
+    }
+
+    let mut lamps = CellLamps::default();
+    if let Some(grid) = attr_value(tag, "CustomModel") {
+        parse_custom_model(grid, &mut lamps)?;
+    } else if let Some(entries) = attr_value(tag, "CustomModelCompressed") {
+        parse_compressed(entries, &mut lamps)?;
+    } else {
+        return Err(GridImportError::InvalidAttribute {
+            name: "CustomModel",
+        });
+    }
+    for (_, [col, row]) in lamps.cells() {
+        if col >= width || row >= height {
+            return Err(GridImportError::CellOutOfRange { col, row });
+        }
+    }
+    let positions = lamps.into_positions()?;
+
+    let name = attr_value(tag, "name")
+        .map(decode_entities)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| String::from(XMODEL_DEFAULT_NAME));
+    Ok(cells_to_doc(
+        &name,
+        width,
+        height,
+        &positions,
+        sample_diameter,
+    ))
+}
+
+fn parse_custom_model(grid: &str, lamps: &mut CellLamps) -> Result<(), GridImportError> {
+    let layers = grid.split('|').collect::<Vec<_>>();
+    if layers.len() > 1 {
+        return Err(GridImportError::UnsupportedDepth(layers.len() as u32));
+    }
+    for (row, cells) in layers[0].split(';').enumerate() {
+        for (col, cell) in cells.split(',').enumerate() {
+            let cell = cell.trim();
+            if cell.is_empty() {
+                continue;
+            }
+            let index = node_index(cell)?;
+            lamps.push(index, col as u32, row as u32);
+        }
+    }
+    Ok(())
+}
+
+fn parse_compressed(entries: &str, lamps: &mut CellLamps) -> Result<(), GridImportError> {
+    for entry in entries.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+        let fields = entry.split(',').map(str::trim).collect::<Vec<_>>();
+        match fields.as_slice() {
+            [node, row, col] => {
+                lamps.push(node_index(node)?, parse_u32(col)?, parse_u32(row)?);
+            }
+            [node, row, col, layer] => {
+                let layer = parse_u32(layer)?;
+                if layer != 0 {
+                    return Err(GridImportError::UnsupportedDepth(layer + 1));
+                }
+                lamps.push(node_index(node)?, parse_u32(col)?, parse_u32(row)?);
+            }
+            _ => {
+                return Err(GridImportError::InvalidAttribute {
+                    name: "CustomModelCompressed",
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// xLights node numbers are 1-based; wiring indices are 0-based.
+fn node_index(raw: &str) -> Result<u32, GridImportError> {
+    parse_u32(raw)?
+        .checked_sub(1)
+        .ok_or_else(|| invalid_number(raw))
+}
+
+fn required_u32(tag: &str, name: &'static str) -> Result<u32, GridImportError> {
+    let raw = attr_value(tag, name).ok_or(GridImportError::InvalidAttribute { name })?;
+    parse_u32(raw.trim())
+}
+
+fn parse_u32(value: &str) -> Result<u32, GridImportError> {
+    value.parse().map_err(|_| invalid_number(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map2d_resolve::resolve;
+    use alloc::vec;
+
+    #[test]
+    fn reads_the_classic_grid_encoding() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<custommodel name="Arch &amp; Star" parm1="3" parm2="2" StringType="RGB Nodes"
+  CustomModel="1,2,3;,,4" SourceVersion="2024.01" >
+</custommodel>"#;
+        let doc = xmodel_to_doc(xml, 2.0).unwrap();
+        assert_eq!(doc.objects[0].name, "Arch & Star");
+        assert_eq!(doc.canvas, Some([-0.5, -0.5, 3.0, 2.0]));
+        assert_eq!(
+            resolve(&doc).unwrap().positions(),
+            vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [2.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn reads_the_compressed_encoding() {
+        let xml = r#"<custommodel name="m" parm1="2" parm2="2"
+  CustomModelCompressed="2,0,1;1,0,0;3,1,1" />"#;
+        let doc = xmodel_to_doc(xml, 2.0).unwrap();
+        assert_eq!(
+            resolve(&doc).unwrap().positions(),
+            vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn a_node_spanning_cells_sits_at_their_centroid() {
+        let xml = r#"<custommodel parm1="3" parm2="1" CustomModel="1,1,1" />"#;
+        let doc = xmodel_to_doc(xml, 2.0).unwrap();
+        assert_eq!(doc.objects[0].name, XMODEL_DEFAULT_NAME);
+        assert_eq!(resolve(&doc).unwrap().positions(), vec![[1.0, 0.0]]);
+    }
+
+    #[test]
+    fn rejects_three_dimensional_models() {
+        assert_eq!(
+            xmodel_to_doc(
+                r#"<custommodel parm1="1" parm2="1" Depth="2" CustomModel="1|2" />"#,
+                2.0
+            ),
+            Err(GridImportError::UnsupportedDepth(2))
+        );
+        assert_eq!(
+            xmodel_to_doc(
+                r#"<custommodel parm1="1" parm2="1" CustomModel="1|2" />"#,
+                2.0
+            ),
+            Err(GridImportError::UnsupportedDepth(2))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_models() {
+        assert_eq!(
+            xmodel_to_doc(r#"<model parm1="1" parm2="1" />"#, 2.0),
+            Err(GridImportError::InvalidAttribute {
+                name: "custommodel"
+            })
+        );
+        assert_eq!(
+            xmodel_to_doc(r#"<custommodel parm1="1" parm2="1" />"#, 2.0),
+            Err(GridImportError::InvalidAttribute {
+                name: "CustomModel"
+            })
+        );
+        assert_eq!(
+            xmodel_to_doc(
+                r#"<custommodel parm1="1" parm2="1" CustomModel="0" />"#,
+                2.0
+            ),
+            Err(GridImportError::InvalidNumber("0".into()))
+        );
+        assert_eq!(
+            xmodel_to_doc(
+                r#"<custommodel parm1="1" parm2="1" CustomModel="1,2" />"#,
+                2.0
+            ),
+            Err(GridImportError::CellOutOfRange { col: 1, row: 0 })
+        );
+    }
+}
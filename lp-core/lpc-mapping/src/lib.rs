@@ -5,10 +5,14 @@
 //! asset authored per fixture (e.g. `fixture.map2d.json`). It contains
 //! parametric objects — grids, multi-ring circles, sampled paths, and
 //! rotational repeats of any of those — whose vec order **is** the wiring
-//! order. [`resolve`] turns a document into the
-//! ordered lamp list (positions in doc space plus derived DMX-style
-//! `{universe, channel}` addresses); [`fit_points`] maps doc-space positions
-//! into a fixture render target without stretching.
+//! order; spirals, arcs, hex grids, polygon outlines and single-stroke
+//! lettering cover the common installs beyond those. [`resolve`] turns a
+//! document into the ordered lamp list (positions in doc space plus derived
+//! DMX-style `{universe, channel}` addresses); [`fit_points`] maps doc-space
+//! positions into a fixture render target without stretching. [`import`] converts SVG,
+//! WLED ledmap and xLights custom-model sources into documents, and
+//! [`export`] writes a document back out as a WLED ledmap. [`capture`]
+//! solves a document from photos of the fixture playing a coded sequence.
 //!
 //! Boundary: schema + pure geometry only. No filesystem access, no engine
 //! types, no UI. The crate is `no_std + alloc` and dependency-light because
@@ -22,16 +26,20 @@
 
 extern crate alloc;
 
+pub mod capture;
 pub mod corpus;
+pub mod export;
 pub mod import;
 mod map2d_doc;
 mod map2d_error;
 mod map2d_fit;
 mod map2d_resolve;
+mod map2d_text;
 
 pub use map2d_doc::{
-    DEFAULT_SAMPLE_DIAMETER, GridCorner, GridRouting, GridShape, MAP2D_FORMAT, MAX_REPEAT_COUNT,
-    Map2dDoc, Map2dObject, Map2dShape, PathShape, RepeatShape, RingDir, RingOrder, RingShape,
+    ArcShape, DEFAULT_SAMPLE_DIAMETER, GridCorner, GridRouting, GridShape, HexShape, MAP2D_FORMAT,
+    MAX_REPEAT_COUNT, Map2dDoc, Map2dObject, Map2dShape, PathShape, PolygonShape, RepeatShape,
+    RingDir, RingOrder, RingShape, SpiralShape, TextShape,
 };
 pub use map2d_error::Map2dError;
 pub use map2d_fit::{Bounds2d, bounds_of_points, fit_points};
@@ -39,3 +47,4 @@ pub use map2d_resolve::{
     CHANNELS_PER_LAMP, LAMPS_PER_UNIVERSE, LampAddress, ObjectSpan, ResolvedLamp, ResolvedMap2d,
     Rotation2d, resolve,
 };
+pub use map2d_text::TextPathError;
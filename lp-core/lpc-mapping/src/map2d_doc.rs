@@ -34,7 +34,7 @@ use crate::map2d_error::Map2dError;
 use crate::map2d_fit::Bounds2d;
 
 /// Newest document format this crate can read.
-pub const MAP2D_FORMAT: u32 = 2;
+pub const MAP2D_FORMAT: u32 = 3;
 
 /// The format every document using only the original constructs declares.
 /// [`Map2dDoc::required_format`] returns this unless the content needs more.
@@ -52,6 +52,12 @@ const MAP2D_FORMAT_PATH_GAPS: u32 = 2;
 /// this and old builds refuse them whole.
 const MAP2D_FORMAT_REPEAT: u32 = 2;
 
+/// The format a document needs once any object is a [`SpiralShape`],
+/// [`ArcShape`], [`HexShape`], [`PolygonShape`] or [`TextShape`]: variants a
+/// format-2 build has never heard of, refused whole for the same reason as
+/// [`RepeatShape`].
+const MAP2D_FORMAT_SHAPES_V3: u32 = 3;
+
 /// Largest [`RepeatShape::count`] an editor may author. The resolver itself
 /// has no ceiling — a hand-authored document is the author's business — but a
 /// slider or a typo should not be able to multiply a 300-lamp strand into a
@@ -100,6 +106,11 @@ pub enum Map2dShape {
     Ring(RingShape),
     Path(PathShape),
     Repeat(RepeatShape),
+    Spiral(SpiralShape),
+    Arc(ArcShape),
+    Hex(HexShape),
+    Polygon(PolygonShape),
+    Text(TextShape),
 }
 
 /// A rectilinear lamp grid with snake or raster routing.
@@ -231,6 +242,117 @@ pub struct RepeatShape {
     pub count: u32,
 }
 
+/// A Fermat spiral of lamps: seed `k` of `count` sits at radius
+/// `radius * sqrt(k / (count - 1))`, turned `k * divergence_deg` from the
+/// start angle. Equal areas hold equal lamps, so with the default golden
+/// angle the seeds pack a disc evenly (phyllotaxis — the sunflower layout of
+/// spiral LED discs). Seed 0 is the center.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpiralShape {
+    pub center: [f32; 2],
+    /// Radius of the outermost seed.
+    pub radius: f32,
+    pub count: u32,
+    /// Turn between consecutive seeds, degrees.
+    #[serde(default = "default_divergence")]
+    pub divergence_deg: f32,
+    /// Which end of the spiral holds lamp 1; center-out by default.
+    #[serde(default = "default_spiral_order")]
+    pub order: RingOrder,
+    /// Angle of seed 0's turn in degrees; -90 is 12 o'clock.
+    #[serde(default = "default_start_angle")]
+    pub start_angle_deg: f32,
+    #[serde(default)]
+    pub dir: RingDir,
+}
+
+/// One or more concentric arcs — an arch over a doorway, a rainbow. Each arc
+/// runs `sweep_deg` from `start_angle_deg`, with lamps on both ends. Like
+/// [`RingShape`], arcs auto-space from `radius` inward and inner counts
+/// derive from the outer one by circumference ratio unless `counts`
+/// overrides them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArcShape {
+    pub center: [f32; 2],
+    /// Outer arc radius.
+    pub radius: f32,
+    /// Lamp count on the outer arc.
+    pub count: u32,
+    /// Angle of the first end in degrees; -180 is 9 o'clock.
+    #[serde(default = "default_arc_start_angle")]
+    pub start_angle_deg: f32,
+    /// Angle the arc covers, strictly between 0 and 360 degrees (a full
+    /// turn is a [`RingShape`]).
+    #[serde(default = "default_arc_sweep")]
+    pub sweep_deg: f32,
+    #[serde(default = "default_rings")]
+    pub rings: u32,
+    /// Optional per-arc lamp counts, listed outer→inner; missing or zero
+    /// entries fall back to the derived count.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub counts: Vec<u32>,
+    #[serde(default)]
+    pub order: RingOrder,
+    /// Snake turns back at the end of each arc (one continuous strip up and
+    /// down a multi-row arch); raster starts every arc at the same end.
+    #[serde(default)]
+    pub routing: GridRouting,
+    #[serde(default)]
+    pub dir: RingDir,
+}
+
+/// A hexagonally packed lamp grid: odd rows shift half a pitch right and
+/// rows sit `pitch * sqrt(3) / 2` apart, so every lamp is `pitch` from its
+/// six neighbours. Routing and start corner read as on [`GridShape`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HexShape {
+    /// First lamp of the first (unshifted) row, before corner-based ordering.
+    pub origin: [f32; 2],
+    pub cols: u32,
+    pub rows: u32,
+    /// Neighbour-to-neighbour spacing.
+    pub pitch: f32,
+    #[serde(default)]
+    pub routing: GridRouting,
+    #[serde(default)]
+    pub start_corner: GridCorner,
+}
+
+/// `count` lamps evenly around the outline of a regular polygon, lamp 1 on
+/// the vertex at `start_angle_deg`. The outline is closed, so the last lamp
+/// stops one step short of the first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PolygonShape {
+    pub center: [f32; 2],
+    /// Circumradius (center to vertex).
+    pub radius: f32,
+    /// Vertex count, at least 3.
+    pub sides: u32,
+    pub count: u32,
+    /// Angle of the first vertex in degrees; -90 is 12 o'clock.
+    #[serde(default = "default_start_angle")]
+    pub start_angle_deg: f32,
+    #[serde(default)]
+    pub dir: RingDir,
+}
+
+/// Lettering in the built-in single-stroke font: `count` lamps spread evenly
+/// over every stroke of `text`, glyph by glyph and stroke by stroke, one
+/// strand jumpering between strokes exactly like an inert
+/// [`PathShape::gaps`] segment. Letters are case-insensitive; `\n` starts a
+/// new line. [`TextShape::to_path`] is the equivalent gapped path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TextShape {
+    /// Top-left corner of the first glyph.
+    pub origin: [f32; 2],
+    pub text: String,
+    /// Cap height; glyphs are two thirds as wide.
+    pub height: f32,
+    pub count: u32,
+    #[serde(default)]
+    pub reversed: bool,
+}
+
 impl RepeatShape {
     /// Clamp `count` into the range an editor may author
     /// (`1..=`[`MAX_REPEAT_COUNT`]). Pure so the editor's sanitize pass and
@@ -353,6 +475,12 @@ fn shape_required_format(shape: &Map2dShape) -> u32 {
         // The rotational repeat (format 2): an unknown variant cannot be
         // ignored — the whole object's lamps would vanish.
         Map2dShape::Repeat(repeat) => MAP2D_FORMAT_REPEAT.max(shape_required_format(&repeat.shape)),
+        // The format-3 shape variants, unknown to a format-2 build.
+        Map2dShape::Spiral(_)
+        | Map2dShape::Arc(_)
+        | Map2dShape::Hex(_)
+        | Map2dShape::Polygon(_)
+        | Map2dShape::Text(_) => MAP2D_FORMAT_SHAPES_V3,
         _ => MAP2D_FORMAT_BASE,
     }
 }
@@ -377,6 +505,23 @@ fn default_start_angle() -> f32 {
     -90.0
 }
 
+/// The golden angle, `360 * (2 - φ)`.
+fn default_divergence() -> f32 {
+    137.507_77
+}
+
+fn default_spiral_order() -> RingOrder {
+    RingOrder::InnerFirst
+}
+
+fn default_arc_start_angle() -> f32 {
+    -180.0
+}
+
+fn default_arc_sweep() -> f32 {
+    180.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -424,9 +569,9 @@ mod tests {
     #[test]
     fn rejects_newer_and_zero_formats() {
         assert!(matches!(
-            Map2dDoc::from_json(r#"{"format":3}"#),
+            Map2dDoc::from_json(r#"{"format":4}"#),
             Err(Map2dError::UnsupportedFormat {
-                found: 3,
+                found: 4,
                 supported: MAP2D_FORMAT
             })
         ));
@@ -451,7 +596,7 @@ mod tests {
             Map2dDoc::from_json(newer),
             Err(Map2dError::UnsupportedFormat {
                 found: 99,
-                supported: 2
+                supported: 3
             })
         );
     }
@@ -607,6 +752,50 @@ mod tests {
         assert!(Map2dDoc::from_json(&doc.to_json()).is_ok());
     }
 
+    /// The spiral, arc, hex, polygon and text variants arrived together as
+    /// format 3: unknown to a format-2 build, so refused whole there.
+    #[test]
+    fn the_format_three_shapes_stamp_three() {
+        let spiral = Map2dDoc::from_json(
+            r#"{"format":3,"objects":[
+                {"shape":{"spiral":{"center":[0,0],"radius":50,"count":100}}}
+            ]}"#,
+        )
+        .unwrap();
+        let Map2dShape::Spiral(shape) = &spiral.objects[0].shape else {
+            panic!("expected spiral");
+        };
+        assert_eq!(shape.order, RingOrder::InnerFirst);
+        assert_eq!(shape.divergence_deg, 137.507_77);
+        assert_eq!(spiral.required_format(), 3);
+
+        let mut doc = Map2dDoc::new();
+        doc.objects.push(Map2dObject {
+            name: "sign".to_string(),
+            shape: Map2dShape::Repeat(RepeatShape {
+                shape: Box::new(Map2dShape::Text(TextShape {
+                    origin: [0.0, 0.0],
+                    text: "OPEN".to_string(),
+                    height: 40.0,
+                    count: 80,
+                    reversed: false,
+                })),
+                center: [0.0, 0.0],
+                count: 2,
+            }),
+        });
+        doc.normalize_format();
+        assert_eq!(doc.format, 3);
+        assert_eq!(Map2dDoc::from_json(&doc.to_json()).unwrap(), doc);
+        assert_eq!(
+            format_gate(doc.format, 2),
+            Err(Map2dError::UnsupportedFormat {
+                found: 3,
+                supported: 2
+            })
+        );
+    }
+
     #[test]
     fn ignores_unknown_fields_for_additive_evolution() {
         let doc = Map2dDoc::from_json(
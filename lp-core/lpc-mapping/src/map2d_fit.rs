@@ -88,6 +88,8 @@ pub fn fit_points(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::map2d_doc::{Map2dDoc, Map2dObject, Map2dShape, RingDir, RingOrder, SpiralShape};
+    use crate::map2d_resolve::resolve;
 
     #[test]
     fn fits_wide_bounds_into_square_with_vertical_padding() {
@@ -117,6 +119,33 @@ mod tests {
         assert_eq!(fitted[1], [0.5, 0.5]);
     }
 
+    /// Fit works off resolved lamps, so every shape fits the same way — a
+    /// spiral disc lands centered in a wide target with its aspect intact.
+    #[test]
+    fn fits_any_resolved_shape() {
+        let doc = Map2dDoc {
+            objects: alloc::vec![Map2dObject {
+                name: "disc".into(),
+                shape: Map2dShape::Spiral(SpiralShape {
+                    center: [500.0, 500.0],
+                    radius: 100.0,
+                    count: 64,
+                    divergence_deg: 137.507_77,
+                    order: RingOrder::InnerFirst,
+                    start_angle_deg: -90.0,
+                    dir: RingDir::Cw,
+                }),
+            }],
+            ..Map2dDoc::new()
+        };
+        let positions = resolve(&doc).unwrap().positions();
+        let fitted = fit_points(&positions, None, 20, 10).unwrap();
+        let bounds = bounds_of_points(&fitted).unwrap();
+        assert!((bounds.height - 1.0).abs() < 1e-5);
+        assert!((bounds.min_x + bounds.width / 2.0 - 0.5).abs() < 0.02);
+        assert!(bounds.width < 0.55, "{bounds:?}");
+    }
+
     #[test]
     fn rejects_degenerate_input() {
         assert!(matches!(
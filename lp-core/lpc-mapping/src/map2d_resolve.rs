@@ -9,8 +9,8 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use crate::map2d_doc::{
-    GridCorner, GridRouting, GridShape, Map2dDoc, Map2dShape, PathShape, RepeatShape, RingDir,
-    RingOrder, RingShape,
+    ArcShape, GridCorner, GridRouting, GridShape, HexShape, Map2dDoc, Map2dShape, PathShape,
+    PolygonShape, RepeatShape, RingDir, RingOrder, RingShape, SpiralShape, TextShape,
 };
 use crate::map2d_error::Map2dError;
 
@@ -156,6 +156,11 @@ fn resolve_shape(
         Map2dShape::Ring(ring) => resolve_ring(ring, invalid)?,
         Map2dShape::Path(path) => resolve_path(path, invalid)?,
         Map2dShape::Repeat(repeat) => return resolve_repeat(repeat, invalid),
+        Map2dShape::Spiral(spiral) => resolve_spiral(spiral, invalid)?,
+        Map2dShape::Arc(arc) => resolve_arc(arc, invalid)?,
+        Map2dShape::Hex(hex) => resolve_hex(hex, invalid)?,
+        Map2dShape::Polygon(polygon) => resolve_polygon(polygon, invalid)?,
+        Map2dShape::Text(text) => resolve_text(text, invalid)?,
     };
     let strands = alloc::vec![positions.len() as u32];
     Ok(ShapeLamps { positions, strands })
@@ -242,30 +247,45 @@ fn resolve_grid(
         return Err(invalid("grid pitch must be positive"));
     }
 
-    let flip_rows = matches!(grid.start_corner, GridCorner::Bl | GridCorner::Br);
-    let flip_cols = matches!(grid.start_corner, GridCorner::Tr | GridCorner::Br);
-    let mut positions = Vec::with_capacity((grid.cols * grid.rows) as usize);
-    for row_step in 0..grid.rows {
+    Ok(
+        grid_cells(grid.cols, grid.rows, grid.routing, grid.start_corner)
+            .map(|(col, row)| {
+                [
+                    grid.origin[0] + col as f32 * grid.pitch,
+                    grid.origin[1] + row as f32 * grid.pitch,
+                ]
+            })
+            .collect(),
+    )
+}
+
+/// `(col, row)` of every cell in wiring order: rows from the start corner's
+/// edge, each row from its side (or alternating, for snake routing).
+fn grid_cells(
+    cols: u32,
+    rows: u32,
+    routing: GridRouting,
+    start_corner: GridCorner,
+) -> impl Iterator<Item = (u32, u32)> {
+    let flip_rows = matches!(start_corner, GridCorner::Bl | GridCorner::Br);
+    let flip_cols = matches!(start_corner, GridCorner::Tr | GridCorner::Br);
+    (0..rows).flat_map(move |row_step| {
         let row = if flip_rows {
-            grid.rows - 1 - row_step
+            rows - 1 - row_step
         } else {
             row_step
         };
-        let odd_row = grid.routing == GridRouting::Snake && row_step % 2 == 1;
-        for col_step in 0..grid.cols {
+        let odd_row = routing == GridRouting::Snake && row_step % 2 == 1;
+        (0..cols).map(move |col_step| {
             let forward = flip_cols == odd_row;
             let col = if forward {
                 col_step
             } else {
-                grid.cols - 1 - col_step
+                cols - 1 - col_step
             };
-            positions.push([
-                grid.origin[0] + col as f32 * grid.pitch,
-                grid.origin[1] + row as f32 * grid.pitch,
-            ]);
-        }
-    }
-    Ok(positions)
+            (col, row)
+        })
+    })
 }
 
 fn resolve_ring(
@@ -318,6 +338,166 @@ fn resolve_ring(
     Ok(positions)
 }
 
+fn resolve_spiral(
+    spiral: &SpiralShape,
+    invalid: &impl Fn(&str) -> Map2dError,
+) -> Result<Vec<[f32; 2]>, Map2dError> {
+    if spiral.count == 0 {
+        return Err(invalid("spiral count must be at least 1"));
+    }
+    if spiral.radius <= 0.0 {
+        return Err(invalid("spiral radius must be positive"));
+    }
+    let sign = dir_sign(spiral.dir);
+    let last = (spiral.count - 1).max(1) as f32;
+    let mut positions = Vec::with_capacity(spiral.count as usize);
+    for lamp in 0..spiral.count {
+        let seed = match spiral.order {
+            RingOrder::InnerFirst => lamp,
+            RingOrder::OuterFirst => spiral.count - 1 - lamp,
+        };
+        let radius = spiral.radius * libm::sqrtf(seed as f32 / last);
+        let degrees = spiral.start_angle_deg + sign * seed as f32 * spiral.divergence_deg;
+        positions.push(polar(spiral.center, radius, degrees));
+    }
+    Ok(positions)
+}
+
+fn resolve_arc(
+    arc: &ArcShape,
+    invalid: &impl Fn(&str) -> Map2dError,
+) -> Result<Vec<[f32; 2]>, Map2dError> {
+    if arc.count == 0 {
+        return Err(invalid("arc count must be at least 1"));
+    }
+    if arc.radius <= 0.0 {
+        return Err(invalid("arc radius must be positive"));
+    }
+    if arc.rings == 0 {
+        return Err(invalid("arc needs at least one ring"));
+    }
+    if !(arc.sweep_deg > 0.0 && arc.sweep_deg < 360.0) {
+        return Err(invalid(
+            "arc sweep must be between 0 and 360 degrees (a full turn is a ring)",
+        ));
+    }
+    // Same auto-spacing and count derivation as concentric rings.
+    let mut arcs: Vec<(f32, u32)> = (0..arc.rings)
+        .map(|ring_index| {
+            let radius = arc.radius * (arc.rings - ring_index) as f32 / arc.rings as f32;
+            let count = arc
+                .counts
+                .get(ring_index as usize)
+                .copied()
+                .filter(|count| *count > 0)
+                .unwrap_or_else(|| derived_ring_count(arc.count, radius, arc.radius));
+            (radius, count)
+        })
+        .collect();
+    if arc.order == RingOrder::InnerFirst {
+        arcs.reverse();
+    }
+
+    let sign = dir_sign(arc.dir);
+    let mut positions = Vec::new();
+    for (wired, (radius, count)) in arcs.into_iter().enumerate() {
+        let backward = arc.routing == GridRouting::Snake && wired % 2 == 1;
+        let step = arc.sweep_deg / (count - 1).max(1) as f32;
+        for lamp in 0..count {
+            let along = if backward { count - 1 - lamp } else { lamp };
+            let degrees = arc.start_angle_deg + sign * along as f32 * step;
+            positions.push(polar(arc.center, radius, degrees));
+        }
+    }
+    Ok(positions)
+}
+
+fn resolve_hex(
+    hex: &HexShape,
+    invalid: &impl Fn(&str) -> Map2dError,
+) -> Result<Vec<[f32; 2]>, Map2dError> {
+    if hex.cols == 0 || hex.rows == 0 {
+        return Err(invalid("hex grid needs at least one column and one row"));
+    }
+    if hex.pitch <= 0.0 {
+        return Err(invalid("hex grid pitch must be positive"));
+    }
+    let row_pitch = hex.pitch * (libm::sqrtf(3.0) / 2.0);
+    Ok(
+        grid_cells(hex.cols, hex.rows, hex.routing, hex.start_corner)
+            .map(|(col, row)| {
+                let shift = if row % 2 == 1 { hex.pitch / 2.0 } else { 0.0 };
+                [
+                    hex.origin[0] + col as f32 * hex.pitch + shift,
+                    hex.origin[1] + row as f32 * row_pitch,
+                ]
+            })
+            .collect(),
+    )
+}
+
+fn resolve_polygon(
+    polygon: &PolygonShape,
+    invalid: &impl Fn(&str) -> Map2dError,
+) -> Result<Vec<[f32; 2]>, Map2dError> {
+    if polygon.sides < 3 {
+        return Err(invalid("polygon needs at least 3 sides"));
+    }
+    if polygon.count == 0 {
+        return Err(invalid("polygon count must be at least 1"));
+    }
+    if polygon.radius <= 0.0 {
+        return Err(invalid("polygon radius must be positive"));
+    }
+    // The closed outline as a polyline from vertex 0 back to itself; lamps
+    // step a whole `perimeter / count` so the loop never doubles vertex 0.
+    let sign = dir_sign(polygon.dir);
+    let step = 360.0 / polygon.sides as f32;
+    let vertices: Vec<[f32; 2]> = (0..=polygon.sides)
+        .map(|vertex| {
+            let degrees = polygon.start_angle_deg + sign * (vertex % polygon.sides) as f32 * step;
+            polar(polygon.center, polygon.radius, degrees)
+        })
+        .collect();
+    let perimeter = active_length(&vertices, &[]);
+    Ok((0..polygon.count)
+        .map(|lamp| {
+            let distance = perimeter * (lamp as f32 / polygon.count as f32);
+            point_at_active_distance(&vertices, &[], distance)
+        })
+        .collect())
+}
+
+fn resolve_text(
+    text: &TextShape,
+    invalid: &impl Fn(&str) -> Map2dError,
+) -> Result<Vec<[f32; 2]>, Map2dError> {
+    if text.height <= 0.0 {
+        return Err(invalid("text height must be positive"));
+    }
+    let path = text
+        .to_path()
+        .map_err(|error| invalid(&error.to_string()))?;
+    resolve_path(&path, invalid)
+}
+
+/// The point `radius` from `center` at `degrees` (screen coordinates, y-down:
+/// -90 is 12 o'clock, increasing runs clockwise).
+fn polar(center: [f32; 2], radius: f32, degrees: f32) -> [f32; 2] {
+    let radians = degrees * (core::f32::consts::PI / 180.0);
+    [
+        center[0] + radius * libm::cosf(radians),
+        center[1] + radius * libm::sinf(radians),
+    ]
+}
+
+fn dir_sign(dir: RingDir) -> f32 {
+    match dir {
+        RingDir::Cw => 1.0,
+        RingDir::Ccw => -1.0,
+    }
+}
+
 /// Inner ring counts scale with circumference: `max(1, round(outer * r / R))`.
 fn derived_ring_count(outer_count: u32, radius: f32, outer_radius: f32) -> u32 {
     let scaled = libm::roundf(outer_count as f32 * radius / outer_radius);
@@ -863,6 +1043,159 @@ mod tests {
         ));
     }
 
+    // ---- spiral, arc, hex, polygon, text ---------------------------------
+
+    #[test]
+    fn spiral_seeds_grow_from_the_center_by_the_divergence_angle() {
+        let resolved = resolve_shape(Map2dShape::Spiral(sunflower(RingOrder::InnerFirst)));
+        let positions = resolved.positions();
+        assert_eq!(positions.len(), 50);
+        assert_eq!(positions[0], [0.0, 0.0]);
+        assert_near(
+            positions[49],
+            [0.0, 0.0],
+            100.0,
+            "outermost seed on the rim",
+        );
+        // Seed 1 sits one golden-angle turn on from 12 o'clock.
+        let expected = polar(
+            [0.0, 0.0],
+            100.0 * libm::sqrtf(1.0 / 49.0),
+            -90.0 + 137.50777,
+        );
+        assert_close(positions[1], expected);
+        // Equal areas, equal seeds: half the seeds inside r / sqrt(2).
+        let inside = positions
+            .iter()
+            .filter(|p| radius_from([0.0, 0.0], **p) < 100.0 / libm::sqrtf(2.0))
+            .count();
+        assert!((24..=26).contains(&inside), "{inside}");
+    }
+
+    #[test]
+    fn spiral_outer_first_is_the_same_seeds_backwards() {
+        let inner = resolve_shape(Map2dShape::Spiral(sunflower(RingOrder::InnerFirst)));
+        let outer = resolve_shape(Map2dShape::Spiral(sunflower(RingOrder::OuterFirst)));
+        let mut expected = inner.positions();
+        expected.reverse();
+        assert_eq!(outer.positions(), expected);
+    }
+
+    #[test]
+    fn arc_puts_lamps_on_both_ends() {
+        let resolved = resolve_shape(Map2dShape::Arc(doorway(1, GridRouting::Snake)));
+        let positions = resolved.positions();
+        assert_eq!(positions.len(), 9);
+        // -180° (9 o'clock) clockwise over the top to 0° (3 o'clock).
+        assert_close(positions[0], [-100.0, 0.0]);
+        assert_close(positions[4], [0.0, -100.0]);
+        assert_close(positions[8], [100.0, 0.0]);
+    }
+
+    #[test]
+    fn snake_arcs_turn_back_and_raster_arcs_restart() {
+        let snake = resolve_shape(Map2dShape::Arc(doorway(2, GridRouting::Snake)));
+        // Outer arc 9 lamps, inner arc derived at half radius: 5 lamps.
+        assert_eq!(snake.lamps.len(), 14);
+        assert_close(snake.lamps[8].pos, [100.0, 0.0]);
+        assert_close(snake.lamps[9].pos, [50.0, 0.0]);
+        assert_close(snake.lamps[13].pos, [-50.0, 0.0]);
+
+        let raster = resolve_shape(Map2dShape::Arc(doorway(2, GridRouting::Raster)));
+        assert_close(raster.lamps[9].pos, [-50.0, 0.0]);
+    }
+
+    #[test]
+    fn a_full_turn_arc_is_invalid() {
+        let mut arc = doorway(1, GridRouting::Snake);
+        arc.sweep_deg = 360.0;
+        let doc = Map2dDoc {
+            objects: vec![object(Map2dShape::Arc(arc))],
+            ..Map2dDoc::new()
+        };
+        assert!(matches!(
+            resolve(&doc).unwrap_err(),
+            Map2dError::InvalidObject { reason, .. } if reason.contains("arc sweep")
+        ));
+    }
+
+    #[test]
+    fn hex_grid_neighbours_are_all_one_pitch_apart() {
+        let resolved = resolve_shape(Map2dShape::Hex(HexShape {
+            origin: [0.0, 0.0],
+            cols: 3,
+            rows: 2,
+            pitch: 10.0,
+            routing: GridRouting::Snake,
+            start_corner: GridCorner::Tl,
+        }));
+        let positions = resolved.positions();
+        let row_pitch = 10.0 * libm::sqrtf(3.0) / 2.0;
+        // Row 0 left to right; row 1 shifted half a pitch, snaking back.
+        assert_eq!(&positions[..3], &[[0.0, 0.0], [10.0, 0.0], [20.0, 0.0]]);
+        assert_eq!(
+            &positions[3..],
+            &[[25.0, row_pitch], [15.0, row_pitch], [5.0, row_pitch]]
+        );
+        assert!((radius_from(positions[0], positions[5]) - 10.0).abs() < 1e-4);
+        assert!((radius_from(positions[1], positions[4]) - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn polygon_lamps_walk_the_outline_from_the_first_vertex() {
+        let resolved = resolve_shape(Map2dShape::Polygon(PolygonShape {
+            center: [0.0, 0.0],
+            radius: 10.0,
+            sides: 4,
+            count: 8,
+            start_angle_deg: -90.0,
+            dir: RingDir::Cw,
+        }));
+        let positions = resolved.positions();
+        assert_eq!(positions.len(), 8);
+        // A diamond: vertices on the axes, a lamp at every edge midpoint.
+        assert_close(positions[0], [0.0, -10.0]);
+        assert_close(positions[1], [5.0, -5.0]);
+        assert_close(positions[2], [10.0, 0.0]);
+        assert_close(positions[6], [-10.0, 0.0]);
+        assert_close(positions[7], [-5.0, -5.0]);
+    }
+
+    #[test]
+    fn text_resolves_through_its_gapped_path() {
+        let text = TextShape {
+            origin: [0.0, 0.0],
+            text: "Hi".into(),
+            height: 60.0,
+            count: 24,
+            reversed: false,
+        };
+        let path = text.to_path().unwrap();
+        assert!(!path.gaps.is_empty(), "H's strokes are jumpered");
+        let from_text = resolve_shape(Map2dShape::Text(text));
+        let from_path = resolve_shape(Map2dShape::Path(path));
+        assert_eq!(from_text.positions(), from_path.positions());
+        assert_eq!(from_text.spans.len(), 1, "a sign is one strand");
+    }
+
+    #[test]
+    fn text_the_font_cannot_draw_names_the_object() {
+        let doc = Map2dDoc {
+            objects: vec![object(Map2dShape::Text(TextShape {
+                origin: [0.0, 0.0],
+                text: "OK~".into(),
+                height: 60.0,
+                count: 24,
+                reversed: false,
+            }))],
+            ..Map2dDoc::new()
+        };
+        assert!(matches!(
+            resolve(&doc).unwrap_err(),
+            Map2dError::InvalidObject { object: 0, reason, .. } if reason.contains("'~'")
+        ));
+    }
+
     #[test]
     fn addresses_flow_across_universe_boundaries() {
         let resolved = resolve_shape(Map2dShape::Grid(GridShape {
@@ -1010,6 +1343,53 @@ mod tests {
         degrees
     }
 
+    fn sunflower(order: RingOrder) -> SpiralShape {
+        SpiralShape {
+            center: [0.0, 0.0],
+            radius: 100.0,
+            count: 50,
+            divergence_deg: 137.50777,
+            order,
+            start_angle_deg: -90.0,
+            dir: RingDir::Cw,
+        }
+    }
+
+    fn doorway(rings: u32, routing: GridRouting) -> ArcShape {
+        ArcShape {
+            center: [0.0, 0.0],
+            radius: 100.0,
+            count: 9,
+            start_angle_deg: -180.0,
+            sweep_deg: 180.0,
+            rings,
+            counts: Vec::new(),
+            order: RingOrder::OuterFirst,
+            routing,
+            dir: RingDir::Cw,
+        }
+    }
+
+    fn radius_from(center: [f32; 2], pos: [f32; 2]) -> f32 {
+        libm::sqrtf((pos[0] - center[0]).powi(2) + (pos[1] - center[1]).powi(2))
+    }
+
+    /// `pos` lies `radius` from `center`.
+    fn assert_near(pos: [f32; 2], center: [f32; 2], radius: f32, what: &str) {
+        let actual = radius_from(center, pos);
+        assert!(
+            (actual - radius).abs() < 1e-3,
+            "{what}: {actual} != {radius}"
+        );
+    }
+
+    fn assert_close(actual: [f32; 2], want: [f32; 2]) {
+        assert!(
+            (actual[0] - want[0]).abs() < 1e-3 && (actual[1] - want[1]).abs() < 1e-3,
+            "{actual:?} != {want:?}"
+        );
+    }
+
     fn button_rings() -> RingShape {
         RingShape {
             center: [240.0, 200.0],
@@ -0,0 +1,346 @@
+//! The built-in single-stroke font behind [`TextShape`].
+//!
+//! LED lettering is strip glued along strokes, so glyphs are centerlines, not
+//! outlines: each is a few polylines on a 4 × 6 unit cell (y down, cap height
+//! 6), drawn in the order a builder would lay strip. Consecutive strokes that
+//! meet end to start stay one run; any other move between strokes is an inert
+//! jumper, so a whole sign is one gapped path and one strand.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::map2d_doc::{PathShape, TextShape};
+
+/// Font cell height in font units; [`TextShape::height`] scales this.
+const CAP_HEIGHT: f32 = 6.0;
+/// Horizontal distance from one glyph's left edge to the next.
+const ADVANCE: f32 = 6.0;
+/// Vertical distance from one line's top to the next.
+const LINE_HEIGHT: f32 = 9.0;
+
+type Stroke = &'static [(u8, u8)];
+
+/// Why a [`TextShape`] has no path.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextPathError {
+    /// The font has no glyph for this character.
+    UnsupportedChar(char),
+    /// The text is empty or all whitespace.
+    NoStrokes,
+}
+
+impl core::fmt::Display for TextPathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedChar(c) => write!(f, "text font has no glyph for {c:?}"),
+            Self::NoStrokes => write!(f, "text has no strokes to light"),
+        }
+    }
+}
+
+impl core::error::Error for TextPathError {}
+
+impl TextShape {
+    /// The gapped path this text resolves through: every stroke in order,
+    /// joined by inert jumpers. Resolving the path with the text's `count`
+    /// and `reversed` gives the text's lamps, which is also how an editor
+    /// expands lettering into hand-editable geometry.
+    pub fn to_path(&self) -> Result<PathShape, TextPathError> {
+        let scale = self.height / CAP_HEIGHT;
+        let mut points: Vec<[f32; 2]> = Vec::new();
+        let mut gaps = Vec::new();
+        let (mut column, mut line) = (0u32, 0u32);
+        for c in self.text.chars() {
+            if c == '\n' {
+                column = 0;
+                line += 1;
+                continue;
+            }
+            let strokes = glyph(c).ok_or(TextPathError::UnsupportedChar(c))?;
+            let left = self.origin[0] + column as f32 * ADVANCE * scale;
+            let top = self.origin[1] + line as f32 * LINE_HEIGHT * scale;
+            for stroke in strokes {
+                let mut stroke_points = stroke
+                    .iter()
+                    .map(|(x, y)| [left + f32::from(*x) * scale, top + f32::from(*y) * scale]);
+                let Some(start) = stroke_points.next() else {
+                    continue;
+                };
+                match points.last() {
+                    Some(last) if *last == start => {}
+                    Some(_) => {
+                        gaps.push((points.len() - 1) as u32);
+                        points.push(start);
+                    }
+                    None => points.push(start),
+                }
+                points.extend(stroke_points);
+            }
+            column += 1;
+        }
+        if points.len() < 2 {
+            return Err(TextPathError::NoStrokes);
+        }
+        Ok(PathShape {
+            points,
+            count: self.count,
+            reversed: self.reversed,
+            gaps,
+        })
+    }
+
+    /// Every character of `text` the font cannot draw, in order of first
+    /// appearance — what an editor flags next to the text field.
+    pub fn unsupported_chars(&self) -> String {
+        let mut unsupported = String::new();
+        for c in self.text.chars() {
+            if c != '\n' && glyph(c).is_none() && !unsupported.contains(c) {
+                unsupported.push(c);
+            }
+        }
+        unsupported
+    }
+}
+
+/// Strokes of one glyph; `None` for a character the font lacks. Space has
+/// none but still advances.
+fn glyph(c: char) -> Option<&'static [Stroke]> {
+    Some(match c.to_ascii_uppercase() {
+        ' ' => &[],
+        'A' => &[&[(0, 6), (0, 2), (2, 0), (4, 2), (4, 6)], &[(0, 3), (4, 3)]],
+        'B' => &[&[
+            (0, 3),
+            (3, 3),
+            (4, 4),
+            (4, 5),
+            (3, 6),
+            (0, 6),
+            (0, 0),
+            (3, 0),
+            (4, 1),
+            (4, 2),
+            (3, 3),
+        ]],
+        'C' => &[&[
+            (4, 1),
+            (3, 0),
+            (1, 0),
+            (0, 1),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+        ]],
+        'D' => &[&[(0, 0), (0, 6), (3, 6), (4, 5), (4, 1), (3, 0), (0, 0)]],
+        'E' => &[&[(4, 0), (0, 0), (0, 6), (4, 6)], &[(0, 3), (3, 3)]],
+        'F' => &[&[(4, 0), (0, 0), (0, 6)], &[(0, 3), (3, 3)]],
+        'G' => &[&[
+            (4, 1),
+            (3, 0),
+            (1, 0),
+            (0, 1),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+            (4, 3),
+            (2, 3),
+        ]],
+        'H' => &[&[(0, 0), (0, 6)], &[(4, 0), (4, 6)], &[(0, 3), (4, 3)]],
+        'I' => &[&[(1, 0), (3, 0)], &[(2, 0), (2, 6)], &[(1, 6), (3, 6)]],
+        'J' => &[&[(4, 0), (4, 5), (3, 6), (1, 6), (0, 5)]],
+        'K' => &[&[(0, 0), (0, 6)], &[(4, 0), (0, 4)], &[(1, 3), (4, 6)]],
+        'L' => &[&[(0, 0), (0, 6), (4, 6)]],
+        'M' => &[&[(0, 6), (0, 0), (2, 3), (4, 0), (4, 6)]],
+        'N' => &[&[(0, 6), (0, 0), (4, 6), (4, 0)]],
+        'O' => &[O_LOOP],
+        'P' => &[P_BOWL],
+        'Q' => &[O_LOOP, &[(2, 4), (4, 6)]],
+        'R' => &[P_BOWL, &[(2, 3), (4, 6)]],
+        'S' => &[&[
+            (4, 1),
+            (3, 0),
+            (1, 0),
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (3, 3),
+            (4, 4),
+            (4, 5),
+            (3, 6),
+            (1, 6),
+            (0, 5),
+        ]],
+        'T' => &[&[(0, 0), (4, 0)], &[(2, 0), (2, 6)]],
+        'U' => &[&[(0, 0), (0, 5), (1, 6), (3, 6), (4, 5), (4, 0)]],
+        'V' => &[&[(0, 0), (2, 6), (4, 0)]],
+        'W' => &[&[(0, 0), (1, 6), (2, 3), (3, 6), (4, 0)]],
+        'X' => &[&[(0, 0), (4, 6)], &[(4, 0), (0, 6)]],
+        'Y' => &[&[(0, 0), (2, 3), (4, 0)], &[(2, 3), (2, 6)]],
+        'Z' => &[&[(0, 0), (4, 0), (0, 6), (4, 6)]],
+        '0' => &[O_LOOP, &[(4, 1), (0, 5)]],
+        '1' => &[&[(1, 1), (2, 0), (2, 6)], &[(1, 6), (3, 6)]],
+        '2' => &[&[(0, 1), (1, 0), (3, 0), (4, 1), (4, 2), (0, 6), (4, 6)]],
+        '3' => &[
+            &[
+                (0, 1),
+                (1, 0),
+                (3, 0),
+                (4, 1),
+                (4, 2),
+                (3, 3),
+                (4, 4),
+                (4, 5),
+                (3, 6),
+                (1, 6),
+                (0, 5),
+            ],
+            &[(1, 3), (3, 3)],
+        ],
+        '4' => &[&[(3, 6), (3, 0), (0, 4), (4, 4)]],
+        '5' => &[&[
+            (4, 0),
+            (0, 0),
+            (0, 3),
+            (3, 3),
+            (4, 4),
+            (4, 5),
+            (3, 6),
+            (0, 6),
+        ]],
+        '6' => &[&[
+            (3, 0),
+            (1, 0),
+            (0, 1),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+            (4, 4),
+            (3, 3),
+            (0, 3),
+        ]],
+        '7' => &[&[(0, 0), (4, 0), (1, 6)]],
+        '8' => &[&[
+            (1, 3),
+            (0, 2),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+            (4, 2),
+            (3, 3),
+            (1, 3),
+            (0, 4),
+            (0, 5),
+            (1, 6),
+            (3, 6),
+            (4, 5),
+            (4, 4),
+            (3, 3),
+        ]],
+        '9' => &[&[
+            (4, 3),
+            (1, 3),
+            (0, 2),
+            (0, 1),
+            (1, 0),
+            (3, 0),
+            (4, 1),
+            (4, 5),
+            (3, 6),
+            (1, 6),
+        ]],
+        '-' => &[&[(1, 3), (3, 3)]],
+        '+' => &[&[(2, 1), (2, 5)], &[(0, 3), (4, 3)]],
+        '/' => &[&[(0, 6), (4, 0)]],
+        '.' => &[&[(2, 5), (2, 6)]],
+        '\'' => &[&[(2, 0), (2, 1)]],
+        '!' => &[&[(2, 0), (2, 4)], &[(2, 5), (2, 6)]],
+        '?' => &[
+            &[(0, 1), (1, 0), (3, 0), (4, 1), (4, 2), (2, 3), (2, 4)],
+            &[(2, 5), (2, 6)],
+        ],
+        _ => return None,
+    })
+}
+
+const O_LOOP: Stroke = &[
+    (1, 0),
+    (3, 0),
+    (4, 1),
+    (4, 5),
+    (3, 6),
+    (1, 6),
+    (0, 5),
+    (0, 1),
+    (1, 0),
+];
+
+const P_BOWL: Stroke = &[(0, 6), (0, 0), (3, 0), (4, 1), (4, 2), (3, 3), (0, 3)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn text(text: &str) -> TextShape {
+        TextShape {
+            origin: [10.0, 20.0],
+            text: text.into(),
+            height: 12.0,
+            count: 10,
+            reversed: false,
+        }
+    }
+
+    #[test]
+    fn a_glyph_is_its_strokes_scaled_to_the_cap_height() {
+        let path = text("L").to_path().unwrap();
+        assert_eq!(path.points, vec![[10.0, 20.0], [10.0, 32.0], [18.0, 32.0]]);
+        assert!(path.gaps.is_empty());
+    }
+
+    #[test]
+    fn strokes_that_do_not_meet_are_joined_by_jumpers() {
+        // T: the bar, a jumper back to its middle, the stem.
+        let path = text("T").to_path().unwrap();
+        assert_eq!(
+            path.points,
+            vec![[10.0, 20.0], [18.0, 20.0], [14.0, 20.0], [14.0, 32.0]]
+        );
+        assert_eq!(path.gaps, vec![1]);
+    }
+
+    #[test]
+    fn glyphs_advance_and_lines_break() {
+        let path = text("I\nI").to_path().unwrap();
+        let second_line = path.points[path.points.len() - 2];
+        assert_eq!(second_line, [12.0, 20.0 + 18.0 + 12.0]);
+        let spaced = text("L L").to_path().unwrap();
+        assert_eq!(spaced.points.last(), Some(&[10.0 + 24.0 + 8.0, 32.0]));
+    }
+
+    #[test]
+    fn every_glyph_is_on_the_cell() {
+        for c in "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789-+/.'!?".chars() {
+            let strokes = glyph(c).unwrap();
+            assert!(!strokes.is_empty(), "{c}");
+            for stroke in strokes {
+                assert!(stroke.len() >= 2, "{c}");
+                assert!(stroke.iter().all(|(x, y)| *x <= 4 && *y <= 6), "{c}");
+            }
+        }
+        assert_eq!(glyph('a'), glyph('A'));
+    }
+
+    #[test]
+    fn refuses_what_it_cannot_draw() {
+        assert_eq!(
+            text("HI~").to_path(),
+            Err(TextPathError::UnsupportedChar('~'))
+        );
+        assert_eq!(text("é~é").unsupported_chars(), "é~");
+        assert_eq!(text(" \n ").to_path(), Err(TextPathError::NoStrokes));
+    }
+}
@@ -33,12 +33,24 @@ pub enum LpFeature {
     /// Clock node runtime.
     #[serde(rename = "node.clock")]
     NodeClock,
+    /// Post-processing filter node runtime.
+    #[serde(rename = "node.filter")]
+    NodeFilter,
     /// Fluid simulation node runtime.
     #[serde(rename = "node.fluid")]
     NodeFluid,
     /// Fixture (mapped-LED) node runtime.
     #[serde(rename = "node.fixture")]
     NodeFixture,
+    /// Image, animated-GIF and sprite-sheet node runtime.
+    #[serde(rename = "node.image")]
+    NodeImage,
+    /// Palette selector node runtime.
+    #[serde(rename = "node.palette")]
+    NodePalette,
+    /// Particle system node runtime.
+    #[serde(rename = "node.particle")]
+    NodeParticle,
     /// Playlist node runtime.
     #[serde(rename = "node.playlist")]
     NodePlaylist,
@@ -48,6 +60,9 @@ pub enum LpFeature {
     /// Shader and compute-shader node runtimes.
     #[serde(rename = "node.shader")]
     NodeShader,
+    /// Bitmap-font text node runtime.
+    #[serde(rename = "node.text")]
+    NodeText,
     /// Texture node runtime.
     #[serde(rename = "node.texture")]
     NodeTexture,
@@ -78,14 +93,19 @@ impl LpFeature {
     /// Every feature, in declaration order. Iteration over the registry goes
     /// through this const so call sites stay wildcard-free: adding a variant
     /// without extending it is caught by [`tests::all_is_total_and_unique`].
-    pub const ALL: [LpFeature; 15] = [
+    pub const ALL: [LpFeature; 20] = [
         LpFeature::NodeButton,
         LpFeature::NodeClock,
+        LpFeature::NodeFilter,
         LpFeature::NodeFluid,
         LpFeature::NodeFixture,
+        LpFeature::NodeImage,
+        LpFeature::NodePalette,
+        LpFeature::NodeParticle,
         LpFeature::NodePlaylist,
         LpFeature::NodeRadio,
         LpFeature::NodeShader,
+        LpFeature::NodeText,
         LpFeature::NodeTexture,
         LpFeature::SvcButton,
         LpFeature::SvcRadioEspnow,
@@ -101,11 +121,16 @@ impl LpFeature {
         match self {
             LpFeature::NodeButton => "node.button",
             LpFeature::NodeClock => "node.clock",
+            LpFeature::NodeFilter => "node.filter",
             LpFeature::NodeFluid => "node.fluid",
             LpFeature::NodeFixture => "node.fixture",
+            LpFeature::NodeImage => "node.image",
+            LpFeature::NodePalette => "node.palette",
+            LpFeature::NodeParticle => "node.particle",
             LpFeature::NodePlaylist => "node.playlist",
             LpFeature::NodeRadio => "node.radio",
             LpFeature::NodeShader => "node.shader",
+            LpFeature::NodeText => "node.text",
             LpFeature::NodeTexture => "node.texture",
             LpFeature::SvcButton => "svc.button",
             LpFeature::SvcRadioEspnow => "svc.radio-espnow",
@@ -130,6 +155,11 @@ impl LpFeature {
             NodeKind::Shader => Some(LpFeature::NodeShader),
             NodeKind::ComputeShader => Some(LpFeature::NodeShader),
             NodeKind::Fluid => Some(LpFeature::NodeFluid),
+            NodeKind::Image => Some(LpFeature::NodeImage),
+            NodeKind::Text => Some(LpFeature::NodeText),
+            NodeKind::Particle => Some(LpFeature::NodeParticle),
+            NodeKind::Filter => Some(LpFeature::NodeFilter),
+            NodeKind::Palette => Some(LpFeature::NodePalette),
             NodeKind::Playlist => Some(LpFeature::NodePlaylist),
             NodeKind::ControlRadio => Some(LpFeature::NodeRadio),
             NodeKind::Fixture => Some(LpFeature::NodeFixture),
@@ -173,19 +203,24 @@ mod tests {
             match feature {
                 LpFeature::NodeButton => 0,
                 LpFeature::NodeClock => 1,
-                LpFeature::NodeFluid => 2,
-                LpFeature::NodeFixture => 3,
-                LpFeature::NodePlaylist => 4,
-                LpFeature::NodeRadio => 5,
-                LpFeature::NodeShader => 6,
-                LpFeature::NodeTexture => 7,
-                LpFeature::SvcButton => 8,
-                LpFeature::SvcRadioEspnow => 9,
-                LpFeature::GfxLpvm => 10,
-                LpFeature::GfxNull => 11,
-                LpFeature::GfxWgpu => 12,
-                LpFeature::DiagUnwind => 13,
-                LpFeature::ShaderF32 => 14,
+                LpFeature::NodeFilter => 2,
+                LpFeature::NodeFluid => 3,
+                LpFeature::NodeFixture => 4,
+                LpFeature::NodeImage => 5,
+                LpFeature::NodePalette => 6,
+                LpFeature::NodeParticle => 7,
+                LpFeature::NodePlaylist => 8,
+                LpFeature::NodeRadio => 9,
+                LpFeature::NodeShader => 10,
+                LpFeature::NodeText => 11,
+                LpFeature::NodeTexture => 12,
+                LpFeature::SvcButton => 13,
+                LpFeature::SvcRadioEspnow => 14,
+                LpFeature::GfxLpvm => 15,
+                LpFeature::GfxNull => 16,
+                LpFeature::GfxWgpu => 17,
+                LpFeature::DiagUnwind => 18,
+                LpFeature::ShaderF32 => 19,
             }
         }
         for (i, feature) in LpFeature::ALL.iter().enumerate() {
@@ -200,11 +235,16 @@ mod tests {
         let expected = [
             "node.button",
             "node.clock",
+            "node.filter",
             "node.fluid",
             "node.fixture",
+            "node.image",
+            "node.palette",
+            "node.particle",
             "node.playlist",
             "node.radio",
             "node.shader",
+            "node.text",
             "node.texture",
             "svc.button",
             "svc.radio-espnow",
@@ -223,7 +263,7 @@ mod tests {
         }
     }
 
-    /// Node-kind mapping: gated kinds map onto the eight `node.*` features,
+    /// Node-kind mapping: gated kinds map onto the thirteen `node.*` features,
     /// ungated kinds map to `None`, and Shader/ComputeShader share a gate —
     /// mirrors `every_node_kind_is_explicitly_gated_or_always_on` in
     /// lpc-engine.
@@ -238,6 +278,11 @@ mod tests {
             (NodeKind::Shader, Some(LpFeature::NodeShader)),
             (NodeKind::ComputeShader, Some(LpFeature::NodeShader)),
             (NodeKind::Fluid, Some(LpFeature::NodeFluid)),
+            (NodeKind::Image, Some(LpFeature::NodeImage)),
+            (NodeKind::Text, Some(LpFeature::NodeText)),
+            (NodeKind::Particle, Some(LpFeature::NodeParticle)),
+            (NodeKind::Filter, Some(LpFeature::NodeFilter)),
+            (NodeKind::Palette, Some(LpFeature::NodePalette)),
             (NodeKind::Playlist, Some(LpFeature::NodePlaylist)),
             (NodeKind::ControlRadio, Some(LpFeature::NodeRadio)),
             (NodeKind::Fixture, Some(LpFeature::NodeFixture)),
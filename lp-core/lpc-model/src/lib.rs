@@ -117,27 +117,31 @@ pub use node::{
     RelativeNodeRefError, RelativeNodeRefSrc,
 };
 pub use nodes::{
-    ArtifactPathResolutionError, Brightness, ButtonDef, ButtonDefView, ButtonState,
-    ButtonStateView, CLOCK_PLAY_STATE_DEFAULT_BIND, CLOCK_PLAY_STATE_SHAPE_NAME,
+    ArtifactPathResolutionError, Brightness, BudgetDef, BudgetDefView, ButtonDef, ButtonDefView,
+    ButtonState, ButtonStateView, CLOCK_PLAY_STATE_DEFAULT_BIND, CLOCK_PLAY_STATE_SHAPE_NAME,
     CLOCK_RATE_DEFAULT_BIND, CLOCK_SCRUB_DEFAULT_BIND, CLOCK_TRANSPORT_SHAPE_NAME, ChannelMetaDef,
     ChannelMetaDefView, ClockDef, ClockDefView, ClockState, ClockTransport, ColorOrder,
     ComputeShaderDef, ComputeShaderDefView, ConsumerCell2, ControlRadioDef, ControlRadioDefView,
-    ControlRadioState, ControlRadioStateView, FixtureDef, FixtureDefView, FixtureDiagnosticMode,
+    ControlRadioState, ControlRadioStateView, DimmerCurve, FilterDef, FilterDefView, FilterEffect,
+    FilterEffectKind, FilterLut, FilterState, FixtureDef, FixtureDefView, FixtureDiagnosticMode,
     FixturePower, FixtureSamplingConfig, FixtureState, FixtureStateView, FloatMode, FluidDef,
-    FluidDefView, FluidEmitter, FluidState, InvocationSite, LampType, MappingConfig, ModuleDef,
-    ModuleDefView, NodeDefParseError, NodeStarter, OutputChannelDef, OutputChannelDefView,
-    OutputDef, OutputDefView, OutputDriverOptionsConfig, OutputDriverOptionsConfigView,
-    PATTERN_EXPORT_FOLDER, PathSpec, PlayState, PlaylistDef, PlaylistDefView, PlaylistEntry,
-    PlaylistEntryView, PlaylistState, PlaylistStateView, ProvenanceDef, STARTER_SHADER_GLSL,
-    STARTER_STEM_PLACEHOLDER, ScalarHint, ScalarHintView, ShaderDef, ShaderDefView,
-    ShaderHeaderGenError, ShaderMapKeyDef, ShaderParamDef, ShaderParamDefView, ShaderSlotDef,
-    ShaderSlotKind, ShaderSlotMappingDef, ShaderSlotMappingKind, ShaderSpace, ShaderState,
-    ShaderStateView, ShaderValueShapeRef, SpaceAnswer1, SpaceAnswer2, TextureDef, TextureDefView,
-    TextureFormat, TextureState, TextureStateView, VisualConsumerSpace,
-    generate_compute_shader_header, glsl_type_for_lp_type, node_def_asset_ref,
-    pattern_project_files_1d, pattern_project_files_2d, resolve_artifact_specifier,
-    set_node_def_asset_ref, shader_panel_step, starter_def_for_kind, starter_for_kind,
-    starter_project_files,
+    FluidDefView, FluidEmitter, FluidState, GammaExponent, ImageDef, ImageDefView, ImageLoopMode,
+    ImageScaleMode, ImageState, InvocationSite, LampType, MAX_PARTICLES, MappingConfig, ModuleDef,
+    ModuleDefView, NodeBudgetDef, NodeBudgetDefView, NodeDefParseError, NodeStarter,
+    OutputChannelDef, OutputChannelDefView, OutputDef, OutputDefView, OutputDriverOptionsConfig,
+    OutputDriverOptionsConfigView, PATTERN_EXPORT_FOLDER, PaletteDef, PaletteDefView, PaletteState,
+    ParticleAttractor, ParticleDef, ParticleDefView, ParticleEmitter, ParticleState, PathSpec,
+    PlayState, PlaylistDef, PlaylistDefView, PlaylistEntry, PlaylistEntryView, PlaylistState,
+    PlaylistStateView, ProvenanceDef, STARTER_SHADER_GLSL, STARTER_STEM_PLACEHOLDER, ScalarHint,
+    ScalarHintView, ShaderDef, ShaderDefView, ShaderHeaderGenError, ShaderMapKeyDef,
+    ShaderParamDef, ShaderParamDefView, ShaderSlotDef, ShaderSlotKind, ShaderSlotMappingDef,
+    ShaderSlotMappingKind, ShaderSpace, ShaderState, ShaderStateView, ShaderValueShapeRef,
+    SpaceAnswer1, SpaceAnswer2, TextAlignMode, TextColorMode, TextDef, TextDefView, TextFont,
+    TextScrollMode, TextState, TextureDef, TextureDefView, TextureFormat, TextureState,
+    TextureStateView, VisualConsumerSpace, generate_compute_shader_header, glsl_type_for_lp_type,
+    node_def_asset_ref, pattern_project_files_1d, pattern_project_files_2d,
+    resolve_artifact_specifier, set_node_def_asset_ref, shader_panel_step, starter_def_for_kind,
+    starter_for_kind, starter_project_files,
 };
 pub use product::{
     ControlDisplayLayout, ControlExtent, ControlLamp2d, ControlLayout2d, ControlPathSpan2d,
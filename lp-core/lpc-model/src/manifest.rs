@@ -119,11 +119,16 @@ pub const fn feature_fragment(enabled: bool, feature: LpFeature) -> &'static str
         match feature {
             LpFeature::NodeButton => "\"node.button\",",
             LpFeature::NodeClock => "\"node.clock\",",
+            LpFeature::NodeFilter => "\"node.filter\",",
             LpFeature::NodeFluid => "\"node.fluid\",",
             LpFeature::NodeFixture => "\"node.fixture\",",
+            LpFeature::NodeImage => "\"node.image\",",
+            LpFeature::NodePalette => "\"node.palette\",",
+            LpFeature::NodeParticle => "\"node.particle\",",
             LpFeature::NodePlaylist => "\"node.playlist\",",
             LpFeature::NodeRadio => "\"node.radio\",",
             LpFeature::NodeShader => "\"node.shader\",",
+            LpFeature::NodeText => "\"node.text\",",
             LpFeature::NodeTexture => "\"node.texture\",",
             LpFeature::SvcButton => "\"svc.button\",",
             LpFeature::SvcRadioEspnow => "\"svc.radio-espnow\",",
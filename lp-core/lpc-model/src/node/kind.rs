@@ -12,6 +12,11 @@ pub enum NodeKind {
     Shader,
     ComputeShader,
     Fluid,
+    Image,
+    Text,
+    Particle,
+    Filter,
+    Palette,
     Playlist,
     ControlRadio,
     Output,
@@ -23,7 +28,7 @@ impl NodeKind {
     /// through this const so call sites stay wildcard-free: adding a
     /// variant without extending it is caught by
     /// [`tests::all_is_total_and_in_declaration_order`].
-    pub const ALL: [NodeKind; 11] = [
+    pub const ALL: [NodeKind; 16] = [
         NodeKind::Module,
         NodeKind::Button,
         NodeKind::Clock,
@@ -31,6 +36,11 @@ impl NodeKind {
         NodeKind::Shader,
         NodeKind::ComputeShader,
         NodeKind::Fluid,
+        NodeKind::Image,
+        NodeKind::Text,
+        NodeKind::Particle,
+        NodeKind::Filter,
+        NodeKind::Palette,
         NodeKind::Playlist,
         NodeKind::ControlRadio,
         NodeKind::Output,
@@ -56,10 +66,15 @@ mod tests {
                 NodeKind::Shader => 4,
                 NodeKind::ComputeShader => 5,
                 NodeKind::Fluid => 6,
-                NodeKind::Playlist => 7,
-                NodeKind::ControlRadio => 8,
-                NodeKind::Output => 9,
-                NodeKind::Fixture => 10,
+                NodeKind::Image => 7,
+                NodeKind::Text => 8,
+                NodeKind::Particle => 9,
+                NodeKind::Filter => 10,
+                NodeKind::Palette => 11,
+                NodeKind::Playlist => 12,
+                NodeKind::ControlRadio => 13,
+                NodeKind::Output => 14,
+                NodeKind::Fixture => 15,
             }
         }
         for (i, kind) in NodeKind::ALL.iter().enumerate() {
@@ -0,0 +1,119 @@
+use crate::nodes::filter::FilterLut;
+use crate::{
+    BindingDefs, Dim2u, Dim2uSlot, EnumSlot, FilterEffect, MapSlot, Slotted, VisualProductSlot,
+};
+
+/// Authored filter node definition: one visual product run through a chain
+/// of post-processing effects.
+///
+/// The chain renders at the node's own `size`, whatever the consumer asks
+/// for, so blur radii and pixelate cells keep their look when the output is
+/// previewed larger; the result is scaled to each consumer.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub struct FilterDef {
+    /// Authored slot bindings for the input, effects and visual output.
+    pub bindings: BindingDefs,
+
+    /// The visual product to filter. Never default-bound: the filter's own
+    /// output goes to `visual.out`, so the input must name its source.
+    #[slot(consumed)]
+    pub input: VisualProductSlot,
+
+    /// Canvas size the chain runs at.
+    pub size: Dim2uSlot,
+
+    /// Stable-key effect chain, applied in key order. A name this build
+    /// does not know passes the frame through.
+    #[slot(
+        consumed,
+        merge = "by_key",
+        map(key = "u32", value_ref = "lp::filter::Effect")
+    )]
+    pub effects: MapSlot<u32, FilterEffect>,
+
+    /// Color table the chain's `lut` effects use.
+    pub lut: EnumSlot<FilterLut>,
+}
+
+impl Default for FilterDef {
+    fn default() -> Self {
+        Self {
+            bindings: BindingDefs::default(),
+            input: VisualProductSlot::default(),
+            size: default_size(),
+            effects: MapSlot::default(),
+            lut: EnumSlot::default(),
+        }
+    }
+}
+
+impl FilterDef {
+    pub const KIND: &'static str = "filter";
+
+    pub fn kind(&self) -> crate::NodeKind {
+        crate::NodeKind::Filter
+    }
+}
+
+fn default_size() -> Dim2uSlot {
+    Dim2uSlot::new(Dim2u {
+        width: 32,
+        height: 32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FilterEffectKind, NodeDef, SlotDirection, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn filter_def_parses_effect_chain_and_lut() {
+        let def = NodeDef::from_json_str(
+            r#"{
+  "kind": "Filter",
+  "size": { "width": 16, "height": 8 },
+  "effects": {
+    "0": {
+      "id": 0,
+      "effect": "bloom",
+      "radius": 3.0,
+      "threshold": 0.5,
+      "amount": 1.0,
+      "segments": 6,
+      "angle": 0.0,
+      "center": [0.5, 0.5],
+      "cell": 4.0,
+      "hue": 0.0,
+      "contrast": 1.0,
+      "saturation": 1.0
+    }
+  },
+  "lut": { "kind": "Cube", "source": "luts/warm.cube" }
+}"#,
+        )
+        .expect("filter");
+
+        let NodeDef::Filter(def) = def else {
+            panic!("filter def");
+        };
+        assert_eq!(def.size.value().width, 16);
+        let bloom = def.effects.entries.get(&0).expect("bloom");
+        assert_eq!(bloom.kind(), Some(FilterEffectKind::Bloom));
+        assert_eq!((bloom.radius, bloom.threshold), (3.0, 0.5));
+        assert!(matches!(def.lut.value(), FilterLut::Cube { .. }));
+    }
+
+    #[test]
+    fn filter_input_is_consumed_but_not_default_bound() {
+        let SlotShape::Record { fields, .. } = FilterDef::slot_shape() else {
+            panic!("record shape");
+        };
+        let input = fields
+            .iter()
+            .find(|field| field.name.as_str() == "input")
+            .expect("input field");
+        assert_eq!(input.semantics.direction, SlotDirection::Consumed);
+        assert_eq!(input.default_bind, None);
+    }
+}
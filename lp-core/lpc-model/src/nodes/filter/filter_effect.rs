@@ -0,0 +1,176 @@
+//! Native filter effect value shape.
+//!
+//! Like `ParticleEmitter`, a complete slot value leaf: a chain lives in a
+//! `MapSlot<u32, FilterEffect>`, applied in key order, so a compute node can
+//! rewrite the whole chain and each effect keeps its place.
+
+use alloc::string::String;
+
+use crate::SlotValue;
+use serde::{Deserialize, Serialize};
+
+/// Native shape name used by authored shader slot defs.
+pub const FILTER_EFFECT_SHAPE_NAME: &str = "lp::filter::Effect";
+
+/// One step of a filter chain.
+///
+/// `effect` names the step; the other fields are its parameters, and each
+/// effect reads only the ones listed against it in [`FilterEffectKind`]
+/// and ignores the rest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SlotValue)]
+#[slot_value(shape_id = "lp::filter::Effect")]
+pub struct FilterEffect {
+    pub id: u32,
+    /// Which effect this is: a [`FilterEffectKind`] name.
+    pub effect: String,
+    /// Blur reach, in canvas pixels.
+    pub radius: f32,
+    /// Brightness (0–1) a pixel must pass to bloom.
+    pub threshold: f32,
+    /// Bloom glow gain, or how much of the graded color a LUT mixes in.
+    pub amount: f32,
+    /// Kaleidoscope wedges.
+    pub segments: u32,
+    /// Rotation of the kaleidoscope or mirror line, in radians.
+    pub angle: f32,
+    /// Kaleidoscope or mirror pivot, in canvas units (`0.0..=1.0`, `y`
+    /// down).
+    pub center: [f32; 2],
+    /// Pixelate block size, in canvas pixels.
+    pub cell: f32,
+    /// Hue rotation, in turns.
+    pub hue: f32,
+    /// Contrast around mid-grey; `1.0` leaves it alone.
+    pub contrast: f32,
+    /// Saturation; `0.0` is greyscale, `1.0` leaves it alone.
+    pub saturation: f32,
+}
+
+impl FilterEffect {
+    pub fn new(id: u32, effect: FilterEffectKind) -> Self {
+        Self {
+            id,
+            effect: String::from(effect.as_str()),
+            ..Self::default()
+        }
+    }
+
+    /// The effect named by `effect`, or `None` for a name this build does
+    /// not know.
+    #[must_use]
+    pub fn kind(&self) -> Option<FilterEffectKind> {
+        FilterEffectKind::parse(&self.effect)
+    }
+}
+
+impl Default for FilterEffect {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            effect: String::from(FilterEffectKind::Blur.as_str()),
+            radius: 2.0,
+            threshold: 0.7,
+            amount: 1.0,
+            segments: 6,
+            angle: 0.0,
+            center: [0.5, 0.5],
+            cell: 4.0,
+            hue: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+        }
+    }
+}
+
+/// The built-in effects a filter chain is made of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterEffectKind {
+    /// Separable Gaussian blur. Reads `radius`.
+    Blur,
+    /// Pixels brighter than `threshold` glow: they are blurred by `radius`
+    /// and added back, scaled by `amount`.
+    Bloom,
+    /// Folds the frame into `segments` mirrored wedges around `center`,
+    /// turned by `angle`.
+    Kaleidoscope,
+    /// Reflects one side of the line through `center` onto the other. At
+    /// `angle` 0 the bottom half shows on the top; a quarter turn puts the
+    /// left half on the right.
+    Mirror,
+    /// Flat blocks `cell` pixels wide.
+    Pixelate,
+    /// Rotates every color's hue by `hue` turns.
+    HueShift,
+    /// Applies `contrast`, then `saturation`.
+    ColorAdjust,
+    /// Grades through the node's 3D LUT, mixed in by `amount`.
+    Lut,
+}
+
+impl FilterEffectKind {
+    /// Every effect, in declaration order (pickers, tests).
+    pub const ALL: [FilterEffectKind; 8] = [
+        FilterEffectKind::Blur,
+        FilterEffectKind::Bloom,
+        FilterEffectKind::Kaleidoscope,
+        FilterEffectKind::Mirror,
+        FilterEffectKind::Pixelate,
+        FilterEffectKind::HueShift,
+        FilterEffectKind::ColorAdjust,
+        FilterEffectKind::Lut,
+    ];
+
+    /// Snake-case wire name.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Blur => "blur",
+            Self::Bloom => "bloom",
+            Self::Kaleidoscope => "kaleidoscope",
+            Self::Mirror => "mirror",
+            Self::Pixelate => "pixelate",
+            Self::HueShift => "hue_shift",
+            Self::ColorAdjust => "color_adjust",
+            Self::Lut => "lut",
+        }
+    }
+
+    /// Parse a snake-case wire name.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromLpValue, ToLpValue};
+
+    #[test]
+    fn filter_effect_round_trips_through_lp_value() {
+        let effect = FilterEffect {
+            segments: 8,
+            center: [0.25, 0.75],
+            ..FilterEffect::new(3, FilterEffectKind::Kaleidoscope)
+        };
+
+        assert_eq!(
+            FilterEffect::from_lp_value(&effect.to_lp_value()).unwrap(),
+            effect
+        );
+        assert_eq!(effect.kind(), Some(FilterEffectKind::Kaleidoscope));
+        assert_eq!(
+            crate::slot_shapes::static_slot_shape_name(<FilterEffect as SlotValue>::SHAPE_ID),
+            Some(FILTER_EFFECT_SHAPE_NAME)
+        );
+    }
+
+    #[test]
+    fn effect_names_round_trip_and_unknown_names_parse_to_none() {
+        for kind in FilterEffectKind::ALL {
+            assert_eq!(FilterEffectKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(FilterEffectKind::parse("sharpen"), None);
+    }
+}
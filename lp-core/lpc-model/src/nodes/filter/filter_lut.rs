@@ -0,0 +1,19 @@
+use crate::{AssetSlot, LpPathBuf, Slotted};
+
+/// The 3D color lookup table a filter's `lut` effects grade through.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum FilterLut {
+    /// No table: `lut` effects pass the frame through.
+    #[default]
+    None,
+    /// An Adobe/Resolve `.cube` file asset, parsed when the node loads.
+    Cube { source: AssetSlot },
+}
+
+impl FilterLut {
+    pub fn cube(source: impl Into<LpPathBuf>) -> Self {
+        Self::Cube {
+            source: AssetSlot::path(source),
+        }
+    }
+}
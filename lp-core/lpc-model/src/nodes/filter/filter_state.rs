@@ -0,0 +1,20 @@
+//! Public runtime state shape for filter nodes.
+
+use crate::{Slotted, VisualProduct, VisualProductSlot};
+
+/// Runtime state exposed by a filter node.
+#[derive(Default, Slotted)]
+#[slot(default_role = "state")]
+pub struct FilterState {
+    /// The filtered frame.
+    #[slot(produced, default_bind = "bus:visual.out")]
+    pub output: VisualProductSlot,
+}
+
+impl FilterState {
+    pub fn new(output: VisualProduct) -> Self {
+        Self {
+            output: VisualProductSlot::new(output),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod filter_def;
+pub mod filter_effect;
+pub mod filter_lut;
+pub mod filter_state;
+
+pub use crate::slot_views::FilterDefView;
+pub use filter_def::FilterDef;
+pub use filter_effect::{FilterEffect, FilterEffectKind};
+pub use filter_lut::FilterLut;
+pub use filter_state::FilterState;
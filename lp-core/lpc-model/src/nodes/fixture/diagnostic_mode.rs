@@ -24,6 +24,9 @@ pub enum FixtureDiagnosticMode {
     PathColors,
     /// Animate a single bright index marker through the fixture.
     Chase,
+    /// Loop the photo auto-mapping sequence (`lpc_mapping::capture`): one
+    /// coded lamp pattern per second, for a fixed camera to photograph.
+    Automap,
 }
 
 impl FixtureDiagnosticMode {
@@ -34,6 +37,7 @@ impl FixtureDiagnosticMode {
             Self::Groups10 => "groups_10",
             Self::PathColors => "path_colors",
             Self::Chase => "chase",
+            Self::Automap => "automap",
         }
     }
 
@@ -44,6 +48,7 @@ impl FixtureDiagnosticMode {
             "groups_10" => Some(Self::Groups10),
             "path_colors" => Some(Self::PathColors),
             "chase" => Some(Self::Chase),
+            "automap" => Some(Self::Automap),
             _ => None,
         }
     }
@@ -96,6 +101,10 @@ impl SlotValue for FixtureDiagnosticMode {
                         value: "chase",
                         label: "Chase",
                     },
+                    StaticSlotEnumOption {
+                        value: "automap",
+                        label: "Auto-map capture",
+                    },
                 ],
             },
         });
@@ -112,6 +121,7 @@ impl SlotValue for FixtureDiagnosticMode {
                     SlotEnumOption::new("groups_10", "RGB groups of 10"),
                     SlotEnumOption::new("path_colors", "Path colors"),
                     SlotEnumOption::new("chase", "Chase"),
+                    SlotEnumOption::new("automap", "Auto-map capture"),
                 ],
             },
         }
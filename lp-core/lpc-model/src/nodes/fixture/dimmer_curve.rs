@@ -0,0 +1,157 @@
+//! Fixture dimmer curves: how the brightness fader maps to light.
+//!
+//! Brightness is a linear light scale applied after the gamma encode
+//! (`docs/design/brightness-gamma-dithering.md`), so fader `s` emits `s` of
+//! the photons and keeps `s` of the wire's 256 codes. Stage lighting makes
+//! the fader-to-light mapping an explicit per-fixture choice, and so does
+//! this: the curve reshapes the fader *before* it becomes that linear scale.
+//! Every curve passes through `0 → 0` and `1 → 1` except a custom table,
+//! which means exactly what it says.
+
+use alloc::string::ToString;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    FromLpValue, LpType, LpValue, SlotEnumOption, SlotMeta, SlotShapeId, SlotValue, SlotValueShape,
+    StaticLpType, StaticSlotEnumOption, StaticSlotMeta, StaticSlotValueShape,
+    StaticValueEditorHint, ToLpValue, ValueEditorHint, ValueRootError,
+};
+
+/// Fader-to-light mapping for a fixture's brightness.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum DimmerCurve {
+    /// Light follows the fader: half the fader is half the photons.
+    #[default]
+    Linear,
+    /// Light follows the fader squared — more travel at the dim end.
+    Square,
+    /// Smoothstep: slow at both ends, fastest through the middle.
+    SCurve,
+    /// The fixture's authored `dimmer_lut` table.
+    Custom,
+}
+
+impl DimmerCurve {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Square => "square",
+            Self::SCurve => "s_curve",
+            Self::Custom => "custom",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "linear" => Some(Self::Linear),
+            "square" => Some(Self::Square),
+            "s_curve" => Some(Self::SCurve),
+            "custom" => Some(Self::Custom),
+            _ => None,
+        }
+    }
+}
+
+impl ToLpValue for DimmerCurve {
+    fn to_lp_value(&self) -> LpValue {
+        LpValue::String(self.as_str().to_string())
+    }
+}
+
+impl FromLpValue for DimmerCurve {
+    fn from_lp_value(value: &LpValue) -> Result<Self, ValueRootError> {
+        match value {
+            LpValue::String(value) => {
+                Self::parse(value).ok_or_else(|| ValueRootError::new("expected dimmer curve"))
+            }
+            other => Err(ValueRootError::new(alloc::format!(
+                "expected String, got {other:?}"
+            ))),
+        }
+    }
+}
+
+impl SlotValue for DimmerCurve {
+    const SHAPE_ID: SlotShapeId = SlotShapeId::from_static_name("lp::fixture::DimmerCurve");
+    const STATIC_VALUE_SHAPE_DESCRIPTOR: Option<StaticSlotValueShape> =
+        Some(StaticSlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: StaticLpType::String,
+            meta: StaticSlotMeta {
+                label: Some("Dimmer curve"),
+                description: Some("How the brightness fader maps to light output."),
+                unit: None,
+            },
+            editor: StaticValueEditorHint::Dropdown {
+                options: &[
+                    StaticSlotEnumOption {
+                        value: "linear",
+                        label: "Linear",
+                    },
+                    StaticSlotEnumOption {
+                        value: "square",
+                        label: "Square",
+                    },
+                    StaticSlotEnumOption {
+                        value: "s_curve",
+                        label: "S-curve",
+                    },
+                    StaticSlotEnumOption {
+                        value: "custom",
+                        label: "Custom table",
+                    },
+                ],
+            },
+        });
+
+    fn value_shape() -> SlotValueShape {
+        SlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: LpType::String,
+            meta: SlotMeta {
+                label: Some("Dimmer curve".to_string()),
+                description: Some("How the brightness fader maps to light output.".to_string()),
+                unit: None,
+            },
+            editor: ValueEditorHint::Dropdown {
+                options: alloc::vec![
+                    SlotEnumOption::new("linear", "Linear"),
+                    SlotEnumOption::new("square", "Square"),
+                    SlotEnumOption::new("s_curve", "S-curve"),
+                    SlotEnumOption::new("custom", "Custom table"),
+                ],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_lp_value_and_json() {
+        for curve in [
+            DimmerCurve::Linear,
+            DimmerCurve::Square,
+            DimmerCurve::SCurve,
+            DimmerCurve::Custom,
+        ] {
+            let value = curve.to_lp_value();
+            assert_eq!(DimmerCurve::from_lp_value(&value).expect("decodes"), curve);
+            let json = serde_json::to_string(&curve).expect("encodes");
+            assert_eq!(json, alloc::format!("\"{}\"", curve.as_str()));
+        }
+        assert!(DimmerCurve::from_lp_value(&LpValue::String("cubic".to_string())).is_err());
+    }
+
+    #[test]
+    fn static_and_owned_shapes_agree() {
+        let shape = DimmerCurve::value_shape();
+        let static_shape =
+            DimmerCurve::STATIC_VALUE_SHAPE_DESCRIPTOR.expect("static descriptor exists");
+        assert_eq!(static_shape.to_owned_value_shape(), shape);
+    }
+}
@@ -2,14 +2,14 @@ use alloc::string::ToString;
 use serde::{Deserialize, Serialize};
 
 use crate::nodes::fixture::{
-    Brightness, FixtureDiagnosticMode, FixturePower, FixtureSamplingConfig, MappingConfig,
-    VisualConsumerSpace,
+    Brightness, DimmerCurve, FixtureDiagnosticMode, FixturePower, FixtureSamplingConfig,
+    GammaExponent, MappingConfig, VisualConsumerSpace,
 };
 use crate::{
     Affine2dSlot, BindingDefs, Dim2u, Dim2uSlot, EnumSlot, FromLpValue, LpType, LpValue,
     OptionSlot, SlotEnumOption, SlotMeta, SlotShapeId, SlotValue, SlotValueShape, Slotted,
     StaticLpType, StaticSlotEnumOption, StaticSlotMeta, StaticSlotValueShape,
-    StaticValueEditorHint, ToLpValue, ValueEditorHint, ValueRootError, ValueSlot,
+    StaticValueEditorHint, ToLpValue, U32ListSlot, ValueEditorHint, ValueRootError, ValueSlot,
     VisualProductSlot,
 };
 
@@ -59,6 +59,20 @@ pub struct FixtureDef {
     pub brightness: OptionSlot<ValueSlot<Brightness>>,
     /// Enable gamma correction.
     pub gamma_correction: OptionSlot<ValueSlot<bool>>,
+    /// Exponent of the gamma encode. Absent means [`GammaExponent::DEFAULT`]
+    /// (2.8); also the exponent brightness gamma uses.
+    pub gamma: OptionSlot<ValueSlot<GammaExponent>>,
+    /// How the brightness fader maps to light. Absent means linear.
+    pub dimmer_curve: OptionSlot<ValueSlot<DimmerCurve>>,
+    /// Light levels for [`DimmerCurve::Custom`]: unorm16 values (0–65535)
+    /// spaced evenly over the fader's travel, interpolated between. Ignored
+    /// by the other curves; an empty table reads as linear.
+    pub dimmer_lut: OptionSlot<U32ListSlot>,
+    /// Also run the brightness fader through the gamma curve — WLED's
+    /// "brightness gamma". Off by default: it hands the fader back to the
+    /// perceptual side, which spends the wire's codes at dim settings (see
+    /// `docs/design/brightness-gamma-dithering.md`).
+    pub brightness_gamma: OptionSlot<ValueSlot<bool>>,
     /// Lamp type and supply budget. Absent means the default guard applies
     /// ([`FixturePower::default`], 1000 mA); a stated budget of zero is the
     /// explicit unlimited opt-out.
@@ -80,6 +94,10 @@ impl Default for FixtureDef {
             transform: Affine2dSlot::default(),
             brightness: default_brightness(),
             gamma_correction: default_gamma_correction(),
+            gamma: OptionSlot::none(),
+            dimmer_curve: OptionSlot::none(),
+            dimmer_lut: OptionSlot::none(),
+            brightness_gamma: OptionSlot::none(),
             power: OptionSlot::none(),
         }
     }
@@ -373,6 +391,10 @@ mod tests {
             transform: Affine2dSlot::new(Affine2d::identity()),
             brightness: OptionSlot::none(),
             gamma_correction: OptionSlot::none(),
+            gamma: OptionSlot::none(),
+            dimmer_curve: OptionSlot::none(),
+            dimmer_lut: OptionSlot::none(),
+            brightness_gamma: OptionSlot::none(),
             power: OptionSlot::none(),
         };
         assert_eq!(def.kind(), NodeKind::Fixture);
@@ -0,0 +1,134 @@
+//! Fixture gamma exponent — the `γ` in the perceptual→linear encode
+//! `linear = perceptual^γ`.
+//!
+//! An `f32` newtype like [`super::Brightness`], so the slot carries a slider
+//! over the useful range instead of a bare number field. Absent means
+//! [`GammaExponent::DEFAULT`], the 2.8 of the legacy Adafruit table every
+//! project so far was tuned against.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    FromLpValue, LpType, LpValue, OrderedF32, SlotMeta, SlotShapeId, SlotValue, SlotValueShape,
+    StaticLpType, StaticSlotMeta, StaticSlotValueShape, StaticValueEditorHint, ToLpValue,
+    ValueEditorHint, ValueRootError,
+};
+
+/// Gamma exponent for a fixture's colour encode (and brightness gamma).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct GammaExponent(pub f32);
+
+impl GammaExponent {
+    /// The exponent of the legacy 8-bit table and of the built-in 16-bit one.
+    pub const DEFAULT: Self = Self(2.8);
+    /// Smallest accepted exponent: the identity curve. Below 1 the curve
+    /// turns concave, which brightens rather than encodes.
+    pub const MIN: f32 = 1.0;
+    /// Largest accepted exponent.
+    pub const MAX: f32 = 4.0;
+
+    /// The exponent clamped to `[MIN, MAX]`; a non-finite value reads as
+    /// the default.
+    pub fn clamped(self) -> f32 {
+        if self.0.is_finite() {
+            self.0.clamp(Self::MIN, Self::MAX)
+        } else {
+            Self::DEFAULT.0
+        }
+    }
+}
+
+impl Default for GammaExponent {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl ToLpValue for GammaExponent {
+    fn to_lp_value(&self) -> LpValue {
+        LpValue::F32(self.0)
+    }
+}
+
+impl FromLpValue for GammaExponent {
+    fn from_lp_value(value: &LpValue) -> Result<Self, ValueRootError> {
+        match value {
+            LpValue::F32(value) => Ok(Self(*value)),
+            LpValue::U32(value) => Ok(Self(*value as f32)),
+            LpValue::I32(value) => Ok(Self(*value as f32)),
+            other => Err(ValueRootError::new(alloc::format!(
+                "expected F32, got {other:?}"
+            ))),
+        }
+    }
+}
+
+impl SlotValue for GammaExponent {
+    const SHAPE_ID: SlotShapeId = SlotShapeId::from_static_name("lp::fixture::GammaExponent");
+    const STATIC_VALUE_SHAPE_DESCRIPTOR: Option<StaticSlotValueShape> =
+        Some(StaticSlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: StaticLpType::F32,
+            meta: StaticSlotMeta {
+                label: Some("Gamma"),
+                description: Some("Gamma exponent of the colour encode (1 is linear)."),
+                unit: None,
+            },
+            editor: StaticValueEditorHint::Slider {
+                min: OrderedF32(GammaExponent::MIN),
+                max: OrderedF32(GammaExponent::MAX),
+                step: None,
+            },
+        });
+
+    fn value_shape() -> SlotValueShape {
+        SlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: LpType::F32,
+            meta: SlotMeta {
+                label: Some("Gamma".into()),
+                description: Some("Gamma exponent of the colour encode (1 is linear).".into()),
+                unit: None,
+            },
+            editor: ValueEditorHint::Slider {
+                min: OrderedF32(Self::MIN),
+                max: OrderedF32(Self::MAX),
+                step: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_as_plain_number() {
+        let decoded: GammaExponent = serde_json::from_str("2.2").expect("plain number decodes");
+        assert_eq!(decoded, GammaExponent(2.2));
+        assert_eq!(serde_json::to_string(&decoded).expect("encodes"), "2.2");
+        assert_eq!(
+            GammaExponent::from_lp_value(&LpValue::U32(2)).expect("integer converts"),
+            GammaExponent(2.0)
+        );
+    }
+
+    #[test]
+    fn clamps_to_the_encoding_range() {
+        assert_eq!(GammaExponent(0.5).clamped(), 1.0);
+        assert_eq!(GammaExponent(9.0).clamped(), 4.0);
+        assert_eq!(GammaExponent(2.2).clamped(), 2.2);
+        assert_eq!(GammaExponent(f32::NAN).clamped(), 2.8);
+    }
+
+    #[test]
+    fn static_and_owned_shapes_agree() {
+        let shape = GammaExponent::value_shape();
+        let static_shape =
+            GammaExponent::STATIC_VALUE_SHAPE_DESCRIPTOR.expect("static descriptor exists");
+        assert_eq!(static_shape.to_owned_value_shape(), shape);
+    }
+}
@@ -1,7 +1,9 @@
 pub mod brightness;
 pub mod diagnostic_mode;
+pub mod dimmer_curve;
 pub mod fixture_def;
 pub mod fixture_state;
+pub mod gamma_exponent;
 pub mod lamp_presets;
 pub mod lamp_type;
 pub mod mapping;
@@ -15,8 +17,10 @@ pub mod visual_consumer_space;
 pub use crate::slot_views::{FixtureDefView, FixtureStateView};
 pub use brightness::Brightness;
 pub use diagnostic_mode::FixtureDiagnosticMode;
+pub use dimmer_curve::DimmerCurve;
 pub use fixture_def::{ColorOrder, FixtureDef};
 pub use fixture_state::FixtureState;
+pub use gamma_exponent::GammaExponent;
 pub use lamp_presets::{LampPreset, PowerProvenance, preset_for};
 pub use lamp_type::LampType;
 pub use mapping::{MappingConfig, PathSpec};
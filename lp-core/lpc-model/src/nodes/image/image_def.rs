@@ -0,0 +1,135 @@
+use crate::nodes::image::{ImageLoopMode, ImageScaleMode};
+use crate::{
+    AssetSlot, BindingDefs, Dim2u, Dim2uSlot, EnumSlot, OptionSlot, PositiveF32Slot, Slotted,
+    TimeProductSlot, ValueSlot,
+};
+
+/// Authored image node definition: a GIF or BMP file played onto a fixed
+/// canvas, optionally cut from a sprite sheet.
+///
+/// The file is streamed, never loaded whole, so `size` (not the file's
+/// dimensions) decides the node's memory.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub struct ImageDef {
+    /// Authored slot bindings for image inputs and visual output.
+    pub bindings: BindingDefs,
+
+    /// GIF or uncompressed BMP file.
+    pub source: AssetSlot,
+
+    /// Canvas size frames are scaled onto.
+    pub size: Dim2uSlot,
+
+    /// How frames fit the canvas.
+    pub scale: EnumSlot<ImageScaleMode>,
+
+    /// What the animation does after its last frame.
+    pub looping: EnumSlot<ImageLoopMode>,
+
+    /// Playback rate in frames per second. Absent plays a GIF at its own
+    /// frame delays and a sprite sheet at 12 fps.
+    pub fps: OptionSlot<PositiveF32Slot>,
+
+    /// Sprite-sheet columns; with `sheet_rows`, more than one cell makes
+    /// each cell a frame (read left to right, top to bottom).
+    pub sheet_columns: ValueSlot<u32>,
+
+    /// Sprite-sheet rows.
+    pub sheet_rows: ValueSlot<u32>,
+
+    /// Cells actually used, for a partly filled last row; 0 uses them all.
+    pub sheet_frames: ValueSlot<u32>,
+
+    /// Reduce each frame to this many colors (2–256) for a flat,
+    /// posterized look. Absent keeps full color.
+    pub palette_colors: OptionSlot<ValueSlot<u32>>,
+
+    /// Timebase frames are chosen from — the scope's time product, queried
+    /// for effective seconds, so a paused or scrubbed clock freezes or
+    /// seeks the animation.
+    #[slot(consumed, default_bind = "bus:time")]
+    pub time: TimeProductSlot,
+}
+
+impl Default for ImageDef {
+    fn default() -> Self {
+        Self {
+            bindings: BindingDefs::default(),
+            source: AssetSlot::path("image.gif"),
+            size: default_size(),
+            scale: EnumSlot::default(),
+            looping: EnumSlot::default(),
+            fps: OptionSlot::none(),
+            sheet_columns: ValueSlot::new(1),
+            sheet_rows: ValueSlot::new(1),
+            sheet_frames: ValueSlot::new(0),
+            palette_colors: OptionSlot::none(),
+            time: TimeProductSlot::default(),
+        }
+    }
+}
+
+impl ImageDef {
+    pub const KIND: &'static str = "image";
+
+    pub fn kind(&self) -> crate::NodeKind {
+        crate::NodeKind::Image
+    }
+
+    pub fn image_source(&self) -> &AssetSlot {
+        &self.source
+    }
+}
+
+fn default_size() -> Dim2uSlot {
+    Dim2uSlot::new(Dim2u {
+        width: 32,
+        height: 32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NodeDef, SlotDirection, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn image_def_parses_sheet_and_modes() {
+        let def = NodeDef::from_json_str(
+            r#"{
+  "kind": "Image",
+  "source": "walk.bmp",
+  "scale": { "kind": "Fill" },
+  "looping": { "kind": "PingPong" },
+  "fps": 8.0,
+  "sheet_columns": 4,
+  "sheet_rows": 2,
+  "sheet_frames": 7
+}"#,
+        )
+        .expect("image");
+
+        let NodeDef::Image(def) = def else {
+            panic!("image def");
+        };
+        assert_eq!(def.scale.value(), &ImageScaleMode::Fill);
+        assert_eq!(def.looping.value(), &ImageLoopMode::PingPong);
+        assert_eq!(def.fps.data.as_ref().map(|fps| fps.value().0), Some(8.0));
+        assert_eq!(*def.sheet_columns.value(), 4);
+        assert_eq!(*def.sheet_frames.value(), 7);
+        assert!(def.palette_colors.data.is_none());
+        assert_eq!(def.size.value().width, 32);
+    }
+
+    #[test]
+    fn image_time_is_consumed() {
+        let SlotShape::Record { fields, .. } = ImageDef::slot_shape() else {
+            panic!("record shape");
+        };
+        let time = fields
+            .iter()
+            .find(|field| field.name.as_str() == "time")
+            .expect("time field");
+        assert_eq!(time.semantics.direction, SlotDirection::Consumed);
+    }
+}
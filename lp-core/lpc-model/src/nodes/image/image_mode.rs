@@ -0,0 +1,27 @@
+use crate::Slotted;
+
+/// How an image's frames fit the node's canvas.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum ImageScaleMode {
+    /// Fill the canvas, distorting the aspect ratio.
+    Stretch,
+    /// Show the whole frame, letterboxed with transparency.
+    #[default]
+    Fit,
+    /// Cover the whole canvas, cropping the frame's overflow.
+    Fill,
+    /// One image pixel per canvas pixel, centered.
+    Center,
+}
+
+/// What an animation does after its last frame.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum ImageLoopMode {
+    /// Start over from the first frame.
+    #[default]
+    Loop,
+    /// Hold the last frame.
+    Once,
+    /// Play backwards to the first frame, then forwards again.
+    PingPong,
+}
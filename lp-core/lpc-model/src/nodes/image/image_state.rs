@@ -0,0 +1,20 @@
+//! Public runtime state shape for image nodes.
+
+use crate::{Slotted, VisualProduct, VisualProductSlot};
+
+/// Runtime state exposed by an image node.
+#[derive(Default, Slotted)]
+#[slot(default_role = "state")]
+pub struct ImageState {
+    /// Renderable visual output produced by this image node.
+    #[slot(produced, default_bind = "bus:visual.out")]
+    pub output: VisualProductSlot,
+}
+
+impl ImageState {
+    pub fn new(output: VisualProduct) -> Self {
+        Self {
+            output: VisualProductSlot::new(output),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod image_def;
+pub mod image_mode;
+pub mod image_state;
+
+pub use crate::slot_views::ImageDefView;
+pub use image_def::ImageDef;
+pub use image_mode::{ImageLoopMode, ImageScaleMode};
+pub use image_state::ImageState;
@@ -1,10 +1,14 @@
 pub mod button;
 pub mod clock;
+pub mod filter;
 pub mod fixture;
 pub mod fluid;
+pub mod image;
 pub mod module;
 pub mod node_def;
 pub mod output;
+pub mod palette;
+pub mod particle;
 pub mod pattern_project;
 pub mod playlist;
 pub mod provenance_def;
@@ -12,6 +16,7 @@ pub mod radio;
 pub mod shader;
 pub mod starter;
 pub mod starter_project;
+pub mod text;
 pub mod texture;
 
 pub use button::{ButtonDef, ButtonDefView, ButtonState, ButtonStateView};
@@ -20,13 +25,20 @@ pub use clock::{
     CLOCK_SCRUB_DEFAULT_BIND, CLOCK_TRANSPORT_SHAPE_NAME, ClockDef, ClockDefView, ClockState,
     ClockTransport, PlayState,
 };
+pub use filter::{
+    FilterDef, FilterDefView, FilterEffect, FilterEffectKind, FilterLut, FilterState,
+};
 pub use fixture::{
-    Brightness, ColorOrder, ConsumerCell2, FixtureDef, FixtureDefView, FixtureDiagnosticMode,
-    FixturePower, FixtureSamplingConfig, FixtureState, FixtureStateView, LampType, MappingConfig,
-    PathSpec, VisualConsumerSpace,
+    Brightness, ColorOrder, ConsumerCell2, DimmerCurve, FixtureDef, FixtureDefView,
+    FixtureDiagnosticMode, FixturePower, FixtureSamplingConfig, FixtureState, FixtureStateView,
+    GammaExponent, LampType, MappingConfig, PathSpec, VisualConsumerSpace,
 };
 pub use fluid::{FluidDef, FluidDefView, FluidEmitter, FluidState};
-pub use module::{ChannelMetaDef, ChannelMetaDefView, ModuleDef, ModuleDefView};
+pub use image::{ImageDef, ImageDefView, ImageLoopMode, ImageScaleMode, ImageState};
+pub use module::{
+    BudgetDef, BudgetDefView, ChannelMetaDef, ChannelMetaDefView, ModuleDef, ModuleDefView,
+    NodeBudgetDef, NodeBudgetDefView,
+};
 pub use node_def::{
     ArtifactPathResolutionError, InvocationSite, NodeArtifact, NodeDef, NodeDefParseError,
     NodeDefWriteError, resolve_artifact_specifier,
@@ -35,6 +47,10 @@ pub use output::{
     OutputChannelDef, OutputChannelDefView, OutputDef, OutputDefView, OutputDriverOptionsConfig,
     OutputDriverOptionsConfigView,
 };
+pub use palette::{PaletteDef, PaletteDefView, PaletteState};
+pub use particle::{
+    MAX_PARTICLES, ParticleAttractor, ParticleDef, ParticleDefView, ParticleEmitter, ParticleState,
+};
 pub use pattern_project::{
     PATTERN_EXPORT_FOLDER, pattern_project_files_1d, pattern_project_files_2d,
 };
@@ -56,4 +72,7 @@ pub use starter::{
     set_node_def_asset_ref, starter_def_for_kind, starter_for_kind,
 };
 pub use starter_project::starter_project_files;
+pub use text::{
+    TextAlignMode, TextColorMode, TextDef, TextDefView, TextFont, TextScrollMode, TextState,
+};
 pub use texture::{TextureDef, TextureDefView, TextureFormat, TextureState, TextureStateView};
@@ -0,0 +1,58 @@
+//! Authored CPU time budgets for a module's children.
+//!
+//! A budget is the per-frame time, in microseconds, one node may spend in
+//! its tick and render before the engine starts degrading it. The root
+//! module's `node_us` is the project-wide default; a nested module's
+//! `node_us` replaces it for that subtree, and a `nodes` entry overrides it
+//! for one named child. A node with no budget anywhere up its chain is
+//! measured but never degraded.
+
+use alloc::string::String;
+
+use crate::{MapSlot, OptionSlot, Slotted, ValueSlot};
+
+/// Budget settings a module applies to its own children.
+#[derive(Clone, Debug, Default, PartialEq, Slotted)]
+#[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
+pub struct BudgetDef {
+    /// Default per-node budget in microseconds for this module's subtree;
+    /// the enclosing module's default applies when absent.
+    pub node_us: OptionSlot<ValueSlot<u32>>,
+    /// Per-child overrides, keyed by the child's name in `nodes`.
+    pub nodes: MapSlot<String, NodeBudgetDef>,
+}
+
+/// Budget override for one child node.
+#[derive(Clone, Debug, Default, PartialEq, Slotted)]
+#[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
+pub struct NodeBudgetDef {
+    /// Budget in microseconds; the module default applies when absent.
+    pub us: OptionSlot<ValueSlot<u32>>,
+    /// A critical node is measured but never degraded, whatever its kind.
+    pub critical: OptionSlot<ValueSlot<bool>>,
+}
+
+impl BudgetDef {
+    /// This module's own subtree default, if authored.
+    pub fn default_us(&self) -> Option<u32> {
+        self.node_us.data.as_ref().map(|slot| *slot.value())
+    }
+
+    /// The override authored for child `name`, if any.
+    pub fn child_us(&self, name: &str) -> Option<u32> {
+        self.nodes
+            .entries
+            .get(name)
+            .and_then(|child| child.us.data.as_ref())
+            .map(|slot| *slot.value())
+    }
+
+    /// Whether child `name` is marked critical.
+    pub fn child_critical(&self, name: &str) -> bool {
+        self.nodes
+            .entries
+            .get(name)
+            .and_then(|child| child.critical.data.as_ref())
+            .is_some_and(|slot| *slot.value())
+    }
+}
@@ -1,6 +1,8 @@
+pub mod budget_def;
 pub mod channel_meta_def;
 pub mod module_def;
 
-pub use crate::slot_views::{ChannelMetaDefView, ModuleDefView};
+pub use crate::slot_views::{BudgetDefView, ChannelMetaDefView, ModuleDefView, NodeBudgetDefView};
+pub use budget_def::{BudgetDef, NodeBudgetDef};
 pub use channel_meta_def::ChannelMetaDef;
 pub use module_def::ModuleDef;
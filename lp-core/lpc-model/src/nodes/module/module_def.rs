@@ -3,7 +3,7 @@ use alloc::string::String;
 use crate::nodes::ProvenanceDef;
 use crate::{BindingDefs, BindingRef, MapSlot, NodeInvocationSlot, OptionSlot, Slotted, ValueSlot};
 
-use super::ChannelMetaDef;
+use super::{BudgetDef, ChannelMetaDef};
 
 /// Authored root module node definition.
 ///
@@ -37,6 +37,8 @@ pub struct ModuleDef {
     /// Per-channel authored meta overrides for this module's scope
     /// (R9 / Q1 curation escape hatch).
     pub meta: MapSlot<String, ChannelMetaDef>,
+    /// CPU time budgets for this module's children; see [`BudgetDef`].
+    pub budget: OptionSlot<BudgetDef>,
     /// Authorship metadata (R14); normally carried by modules.
     pub provenance: OptionSlot<ProvenanceDef>,
 }
@@ -209,7 +211,7 @@ mod tests {
         let bare = NodeDef::read_json(&registry, r#"{ "kind": "Module", "nodes": {} }"#)
             .expect("bare module");
         let text = bare.write_json(&registry).expect("write bare");
-        for key in ["bindings", "exports", "meta", "provenance"] {
+        for key in ["bindings", "exports", "meta", "provenance", "budget"] {
             assert!(
                 !text.contains(key),
                 "{key} must not serialize when absent: {text}"
@@ -217,6 +219,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn module_def_reads_budgets() {
+        let json = r#"{
+            "kind": "Module",
+            "nodes": {
+                "sky": { "ref": "./sky.json" },
+                "clock": { "ref": "./clock.json" }
+            },
+            "budget": {
+                "node_us": 4000,
+                "nodes": {
+                    "sky": { "us": 12000 },
+                    "clock": { "critical": true }
+                }
+            }
+        }"#;
+        let registry = registry();
+        let def = NodeDef::read_json(&registry, json).unwrap();
+        let NodeDef::Module(module) = &def else {
+            panic!("expected module def");
+        };
+        let budget = module.budget.data.as_ref().expect("budget");
+        assert_eq!(budget.default_us(), Some(4000));
+        assert_eq!(budget.child_us("sky"), Some(12000));
+        assert_eq!(budget.child_us("clock"), None);
+        assert!(budget.child_critical("clock"));
+        assert!(!budget.child_critical("sky"));
+        assert!(!budget.child_critical("missing"));
+
+        let text = def.write_json(&registry).unwrap();
+        let reread = NodeDef::read_json(&registry, &text).unwrap();
+        assert_eq!(reread, def);
+    }
+
     fn registry() -> SlotShapeRegistry {
         SlotShapeRegistry::default()
     }
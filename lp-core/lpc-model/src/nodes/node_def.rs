@@ -14,13 +14,18 @@ use crate::artifact::artifact_spec::ArtifactSpec;
 use crate::node::kind::NodeKind;
 use crate::nodes::button::ButtonDef;
 use crate::nodes::clock::ClockDef;
+use crate::nodes::filter::{FilterDef, FilterLut};
 use crate::nodes::fixture::{FixtureDef, MappingConfig};
 use crate::nodes::fluid::FluidDef;
+use crate::nodes::image::ImageDef;
 use crate::nodes::module::ModuleDef;
 use crate::nodes::output::OutputDef;
+use crate::nodes::palette::PaletteDef;
+use crate::nodes::particle::ParticleDef;
 use crate::nodes::playlist::PlaylistDef;
 use crate::nodes::radio::ControlRadioDef;
 use crate::nodes::shader::{ComputeShaderDef, ShaderDef};
+use crate::nodes::text::{TextDef, TextFont};
 use crate::nodes::texture::TextureDef;
 use crate::{
     ArtifactLocation, AssetContentType, AssetLocation, AssetSlot, AssetSlotValue, EnumSlot, LpPath,
@@ -36,6 +41,11 @@ const TEXTURE_VARIANT: &str = "Texture";
 const SHADER_VARIANT: &str = "Shader";
 const COMPUTE_SHADER_VARIANT: &str = "ComputeShader";
 const FLUID_VARIANT: &str = "Fluid";
+const IMAGE_VARIANT: &str = "Image";
+const TEXT_VARIANT: &str = "Text";
+const PARTICLE_VARIANT: &str = "Particle";
+const FILTER_VARIANT: &str = "Filter";
+const PALETTE_VARIANT: &str = "Palette";
 const PLAYLIST_VARIANT: &str = "Playlist";
 const CONTROL_RADIO_VARIANT: &str = "ControlRadio";
 const OUTPUT_VARIANT: &str = "Output";
@@ -48,6 +58,11 @@ const NODE_DEF_VARIANT_NAMES: &[&str] = &[
     SHADER_VARIANT,
     COMPUTE_SHADER_VARIANT,
     FLUID_VARIANT,
+    IMAGE_VARIANT,
+    TEXT_VARIANT,
+    PARTICLE_VARIANT,
+    FILTER_VARIANT,
+    PALETTE_VARIANT,
     PLAYLIST_VARIANT,
     CONTROL_RADIO_VARIANT,
     OUTPUT_VARIANT,
@@ -69,6 +84,11 @@ pub enum NodeDef {
     Shader(ShaderDef),
     ComputeShader(ComputeShaderDef),
     Fluid(FluidDef),
+    Image(ImageDef),
+    Text(TextDef),
+    Particle(ParticleDef),
+    Filter(FilterDef),
+    Palette(PaletteDef),
     Playlist(PlaylistDef),
     ControlRadio(ControlRadioDef),
     Output(OutputDef),
@@ -172,6 +192,11 @@ impl NodeDef {
             NodeKind::Shader => Self::Shader(ShaderDef::default()),
             NodeKind::ComputeShader => Self::ComputeShader(ComputeShaderDef::default()),
             NodeKind::Fluid => Self::Fluid(FluidDef::default()),
+            NodeKind::Image => Self::Image(ImageDef::default()),
+            NodeKind::Text => Self::Text(TextDef::default()),
+            NodeKind::Particle => Self::Particle(ParticleDef::default()),
+            NodeKind::Filter => Self::Filter(FilterDef::default()),
+            NodeKind::Palette => Self::Palette(PaletteDef::default()),
             NodeKind::Playlist => Self::Playlist(PlaylistDef::default()),
             NodeKind::ControlRadio => Self::ControlRadio(ControlRadioDef::default()),
             NodeKind::Output => Self::Output(OutputDef::default()),
@@ -189,6 +214,11 @@ impl NodeDef {
             Self::Shader(_) => NodeKind::Shader,
             Self::ComputeShader(_) => NodeKind::ComputeShader,
             Self::Fluid(_) => NodeKind::Fluid,
+            Self::Image(_) => NodeKind::Image,
+            Self::Text(_) => NodeKind::Text,
+            Self::Particle(_) => NodeKind::Particle,
+            Self::Filter(_) => NodeKind::Filter,
+            Self::Palette(_) => NodeKind::Palette,
             Self::Playlist(_) => NodeKind::Playlist,
             Self::ControlRadio(_) => NodeKind::ControlRadio,
             Self::Output(_) => NodeKind::Output,
@@ -206,6 +236,11 @@ impl NodeDef {
             Self::Shader(_) => ShaderDef::KIND,
             Self::ComputeShader(_) => ComputeShaderDef::KIND,
             Self::Fluid(_) => FluidDef::KIND,
+            Self::Image(_) => ImageDef::KIND,
+            Self::Text(_) => TextDef::KIND,
+            Self::Particle(_) => ParticleDef::KIND,
+            Self::Filter(_) => FilterDef::KIND,
+            Self::Palette(_) => PaletteDef::KIND,
             Self::Playlist(_) => PlaylistDef::KIND,
             Self::ControlRadio(_) => ControlRadioDef::KIND,
             Self::Output(_) => OutputDef::KIND,
@@ -223,6 +258,11 @@ impl NodeDef {
             Self::Shader(_) => SHADER_VARIANT,
             Self::ComputeShader(_) => COMPUTE_SHADER_VARIANT,
             Self::Fluid(_) => FLUID_VARIANT,
+            Self::Image(_) => IMAGE_VARIANT,
+            Self::Text(_) => TEXT_VARIANT,
+            Self::Particle(_) => PARTICLE_VARIANT,
+            Self::Filter(_) => FILTER_VARIANT,
+            Self::Palette(_) => PALETTE_VARIANT,
             Self::Playlist(_) => PLAYLIST_VARIANT,
             Self::ControlRadio(_) => CONTROL_RADIO_VARIANT,
             Self::Output(_) => OUTPUT_VARIANT,
@@ -302,6 +342,23 @@ impl NodeDef {
                 containing_file,
                 AssetContentType::ComputeShaderSource,
             ),
+            Self::Image(image) => assets_for_slot(
+                image.image_source(),
+                containing_file,
+                AssetContentType::Image,
+            ),
+            Self::Text(text) => match text.font.value() {
+                TextFont::Bdf { source } => {
+                    assets_for_slot(source, containing_file, AssetContentType::Font)
+                }
+                TextFont::Classic5x8 | TextFont::Tiny3x5 => Ok(Vec::new()),
+            },
+            Self::Filter(filter) => match filter.lut.value() {
+                FilterLut::Cube { source } => {
+                    assets_for_slot(source, containing_file, AssetContentType::ColorLut)
+                }
+                FilterLut::None => Ok(Vec::new()),
+            },
             Self::Fixture(fixture) => assets_for_fixture(fixture, containing_file),
             _ => Ok(Vec::new()),
         }
@@ -370,6 +427,41 @@ impl NodeDef {
         }
     }
 
+    pub fn as_image(&self) -> Option<&ImageDef> {
+        match self {
+            Self::Image(def) => Some(def),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&TextDef> {
+        match self {
+            Self::Text(def) => Some(def),
+            _ => None,
+        }
+    }
+
+    pub fn as_particle(&self) -> Option<&ParticleDef> {
+        match self {
+            Self::Particle(def) => Some(def),
+            _ => None,
+        }
+    }
+
+    pub fn as_filter(&self) -> Option<&FilterDef> {
+        match self {
+            Self::Filter(def) => Some(def),
+            _ => None,
+        }
+    }
+
+    pub fn as_palette(&self) -> Option<&PaletteDef> {
+        match self {
+            Self::Palette(def) => Some(def),
+            _ => None,
+        }
+    }
+
     pub fn as_playlist(&self) -> Option<&PlaylistDef> {
         match self {
             Self::Playlist(def) => Some(def),
@@ -495,6 +587,11 @@ impl SlotAccess for NodeDef {
             Self::Shader(def) => def.shape_id(),
             Self::ComputeShader(def) => def.shape_id(),
             Self::Fluid(def) => def.shape_id(),
+            Self::Image(def) => def.shape_id(),
+            Self::Text(def) => def.shape_id(),
+            Self::Particle(def) => def.shape_id(),
+            Self::Filter(def) => def.shape_id(),
+            Self::Palette(def) => def.shape_id(),
             Self::Playlist(def) => def.shape_id(),
             Self::ControlRadio(def) => def.shape_id(),
             Self::Output(def) => def.shape_id(),
@@ -511,6 +608,11 @@ impl SlotAccess for NodeDef {
             Self::Shader(def) => def.data(),
             Self::ComputeShader(def) => def.data(),
             Self::Fluid(def) => def.data(),
+            Self::Image(def) => def.data(),
+            Self::Text(def) => def.data(),
+            Self::Particle(def) => def.data(),
+            Self::Filter(def) => def.data(),
+            Self::Palette(def) => def.data(),
             Self::Playlist(def) => def.data(),
             Self::ControlRadio(def) => def.data(),
             Self::Output(def) => def.data(),
@@ -537,6 +639,11 @@ impl SlotMutAccess for NodeDef {
             Self::Shader(def) => def.data_mut(),
             Self::ComputeShader(def) => def.data_mut(),
             Self::Fluid(def) => def.data_mut(),
+            Self::Image(def) => def.data_mut(),
+            Self::Text(def) => def.data_mut(),
+            Self::Particle(def) => def.data_mut(),
+            Self::Filter(def) => def.data_mut(),
+            Self::Palette(def) => def.data_mut(),
             Self::Playlist(def) => def.data_mut(),
             Self::ControlRadio(def) => def.data_mut(),
             Self::Output(def) => def.data_mut(),
@@ -1267,6 +1374,11 @@ mod tests {
             NodeKind::Shader,
             NodeKind::ComputeShader,
             NodeKind::Fluid,
+            NodeKind::Image,
+            NodeKind::Text,
+            NodeKind::Particle,
+            NodeKind::Filter,
+            NodeKind::Palette,
             NodeKind::Playlist,
             NodeKind::ControlRadio,
             NodeKind::Output,
@@ -0,0 +1,6 @@
+pub mod palette_def;
+pub mod palette_state;
+
+pub use crate::slot_views::PaletteDefView;
+pub use palette_def::PaletteDef;
+pub use palette_state::PaletteState;
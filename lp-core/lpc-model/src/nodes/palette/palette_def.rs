@@ -0,0 +1,133 @@
+use crate::{BindingDefs, ControlMessage, Gradient, MapSlot, Slotted, TimeProductSlot, ValueSlot};
+
+/// Authored palette node definition: a set of gradients, one of them
+/// selected, published on the scope's `palette` channel.
+///
+/// The set is plain gradients. A catalog palette is copied in by the
+/// palette chooser like any other gradient value, so the device never
+/// needs the catalog. Selection moves by `index` (a position in key order,
+/// wrapping) or by trigger messages stepping through the set, and each
+/// change crossfades in Oklab over `fade_seconds`.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub struct PaletteDef {
+    /// Authored slot bindings for palette inputs and the published palette.
+    pub bindings: BindingDefs,
+
+    /// Stable-key palette set, stepped through in key order.
+    #[slot(consumed, merge = "by_key")]
+    pub palettes: MapSlot<u32, ValueSlot<Gradient>>,
+
+    /// Position of the selected palette in key order, wrapping past the
+    /// end. Read when it changes, so a trigger can step away from it.
+    #[slot(consumed)]
+    pub index: ValueSlot<u32>,
+
+    /// Trigger messages that step the selection (routed by `next_trigger`
+    /// and `previous_trigger`).
+    #[slot(
+        consumed,
+        merge = "by_key",
+        map(key = "u32", value_ref = "lp::control::Message")
+    )]
+    pub trigger: MapSlot<u32, ControlMessage>,
+
+    /// Trigger id that steps to the next palette.
+    pub next_trigger: ValueSlot<u32>,
+
+    /// Trigger id that steps to the previous palette.
+    pub previous_trigger: ValueSlot<u32>,
+
+    /// Crossfade length in seconds; `0.0` cuts.
+    pub fade_seconds: ValueSlot<f32>,
+
+    /// Rotation of the gradient along its length, in turns: `0.25` moves
+    /// every color a quarter of the way along, wrapping at the end.
+    #[slot(consumed)]
+    pub offset: ValueSlot<f32>,
+
+    /// Continuous rotation in turns per second, added to `offset`.
+    #[slot(consumed)]
+    pub rotation: ValueSlot<f32>,
+
+    /// Timebase fades and rotation follow — the scope's time product, so a
+    /// paused clock holds a fade where it is.
+    #[slot(consumed, default_bind = "bus:time")]
+    pub time: TimeProductSlot,
+}
+
+impl Default for PaletteDef {
+    fn default() -> Self {
+        Self {
+            bindings: BindingDefs::default(),
+            palettes: MapSlot::default(),
+            index: ValueSlot::new(0),
+            trigger: MapSlot::default(),
+            next_trigger: ValueSlot::new(1),
+            previous_trigger: ValueSlot::new(2),
+            fade_seconds: ValueSlot::new(1.0),
+            offset: ValueSlot::new(0.0),
+            rotation: ValueSlot::new(0.0),
+            time: TimeProductSlot::default(),
+        }
+    }
+}
+
+impl PaletteDef {
+    pub const KIND: &'static str = "palette";
+
+    pub fn kind(&self) -> crate::NodeKind {
+        crate::NodeKind::Palette
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Colorspace, NodeDef, SlotDirection, SlotMerge, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn palette_def_parses_a_gradient_set() {
+        let def = NodeDef::from_json_str(
+            r##"{
+  "kind": "Palette",
+  "palettes": {
+    "1": { "space": "srgb", "method": "linear", "stops": "#000 #f80" },
+    "4": { "space": "oklab", "method": "step", "stops": "(0.5,0,0) (0.9,0.1,0)" }
+  },
+  "fade_seconds": 2.5,
+  "rotation": 0.1
+}"##,
+        )
+        .expect("palette");
+
+        let NodeDef::Palette(def) = def else {
+            panic!("palette def");
+        };
+        assert_eq!(def.palettes.entries.len(), 2);
+        let oklab = def.palettes.entries.get(&4).expect("second palette").value();
+        assert_eq!(oklab.space, Colorspace::Oklab);
+        assert_eq!(oklab.stops.len(), 2);
+        assert_eq!(*def.fade_seconds.value(), 2.5);
+        assert_eq!(*def.rotation.value(), 0.1);
+        assert_eq!(*def.next_trigger.value(), 1);
+    }
+
+    #[test]
+    fn palette_inputs_are_consumed_and_time_is_default_bound() {
+        let SlotShape::Record { fields, .. } = PaletteDef::slot_shape() else {
+            panic!("record shape");
+        };
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|field| field.name.as_str() == name)
+                .expect("field")
+        };
+        for name in ["palettes", "index", "trigger", "offset", "rotation", "time"] {
+            assert_eq!(field(name).semantics.direction, SlotDirection::Consumed);
+        }
+        assert_eq!(field("palettes").semantics.merge, SlotMerge::ByKey);
+        assert_eq!(field("time").default_bind.as_deref(), Some("bus:time"));
+        assert_eq!(field("index").default_bind, None);
+    }
+}
@@ -0,0 +1,46 @@
+//! Public runtime state shape for palette nodes.
+
+use crate::{GradientConfig, Slotted, ValueSlot};
+
+/// Runtime state exposed by a palette node.
+#[derive(Default, Slotted)]
+#[slot(default_role = "state")]
+pub struct PaletteState {
+    /// The palette as it stands this frame: the selection, mid-fade or
+    /// rotated as authored, always a static gradient.
+    #[slot(produced, default_bind = "bus:palette")]
+    pub palette: ValueSlot<GradientConfig>,
+
+    /// Key of the selected palette (the one being faded to, mid-fade).
+    #[slot(produced)]
+    pub active: ValueSlot<u32>,
+}
+
+impl PaletteState {
+    pub fn new(palette: GradientConfig, active: u32) -> Self {
+        Self {
+            palette: ValueSlot::new(palette),
+            active: ValueSlot::new(active),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SlotDirection, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn palette_state_publishes_on_the_palette_channel() {
+        let SlotShape::Record { fields, .. } = PaletteState::slot_shape() else {
+            panic!("record shape");
+        };
+        let palette = fields
+            .iter()
+            .find(|field| field.name.as_str() == "palette")
+            .expect("palette field");
+
+        assert_eq!(palette.semantics.direction, SlotDirection::Produced);
+        assert_eq!(palette.default_bind.as_deref(), Some("bus:palette"));
+    }
+}
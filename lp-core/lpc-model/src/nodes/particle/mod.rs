@@ -0,0 +1,10 @@
+pub mod particle_attractor;
+pub mod particle_def;
+pub mod particle_emitter;
+pub mod particle_state;
+
+pub use crate::slot_views::ParticleDefView;
+pub use particle_attractor::ParticleAttractor;
+pub use particle_def::{MAX_PARTICLES, ParticleDef};
+pub use particle_emitter::ParticleEmitter;
+pub use particle_state::ParticleState;
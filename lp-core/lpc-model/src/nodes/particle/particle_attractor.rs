@@ -0,0 +1,63 @@
+//! Native particle attractor value shape.
+
+use crate::SlotValue;
+use serde::{Deserialize, Serialize};
+
+/// Native shape name used by authored shader slot defs.
+pub const PARTICLE_ATTRACTOR_SHAPE_NAME: &str = "lp::particle::Attractor";
+
+/// A point that pulls particles toward it (or, with a negative strength,
+/// pushes them away). Same canvas units as
+/// [`ParticleEmitter`](crate::ParticleEmitter).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SlotValue)]
+#[slot_value(shape_id = "lp::particle::Attractor")]
+pub struct ParticleAttractor {
+    pub id: u32,
+    pub pos: [f32; 2],
+    /// Acceleration at the center, in canvas units per second squared.
+    pub strength: f32,
+    /// Reach: the pull falls off linearly to nothing at this distance.
+    pub radius: f32,
+}
+
+impl ParticleAttractor {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            pos: [0.5, 0.5],
+            strength: 1.0,
+            radius: 0.5,
+        }
+    }
+}
+
+impl Default for ParticleAttractor {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FromLpValue, ToLpValue};
+
+    #[test]
+    fn particle_attractor_round_trips_through_lp_value() {
+        let attractor = ParticleAttractor {
+            id: 1,
+            pos: [0.2, 0.4],
+            strength: -2.0,
+            radius: 0.3,
+        };
+
+        assert_eq!(
+            ParticleAttractor::from_lp_value(&attractor.to_lp_value()).unwrap(),
+            attractor
+        );
+        assert_eq!(
+            crate::slot_shapes::static_slot_shape_name(<ParticleAttractor as SlotValue>::SHAPE_ID),
+            Some(PARTICLE_ATTRACTOR_SHAPE_NAME)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{
+    BindingDefs, ControlMessage, Dim2u, Dim2uSlot, GradientConfig, MapSlot, OptionSlot,
+    ParticleAttractor, ParticleEmitter, PositiveF32, PositiveF32Slot, Slotted, TimeProductSlot,
+    ValueSlot, Xy, XySlot,
+};
+
+/// Most particles a node can hold at once, whatever `capacity` asks for.
+pub const MAX_PARTICLES: u32 = 4096;
+
+/// Authored particle system node definition.
+///
+/// Particles live in a pool of fixed `capacity`, allocated once: a spawn
+/// with the pool full is dropped rather than growing it. Emitters, the
+/// trigger map, attractors and the global forces are consumed slots, so any
+/// of them can be bound from the bus or a compute node; inline maps are
+/// useful for simple scenes and tests.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub struct ParticleDef {
+    /// Authored slot bindings for particle inputs and visual output.
+    pub bindings: BindingDefs,
+
+    /// Canvas size particles are splatted onto.
+    pub size: Dim2uSlot,
+
+    /// Pool size: the most particles alive at once. Clamped to
+    /// [`MAX_PARTICLES`].
+    pub capacity: ValueSlot<u32>,
+
+    /// Seed for spawn positions, launch angles and jitter.
+    pub seed: ValueSlot<u32>,
+
+    /// Timebase the simulation steps against — the scope's time product, so
+    /// a paused clock freezes the particles.
+    #[slot(consumed, default_bind = "bus:time")]
+    pub time: TimeProductSlot,
+
+    /// Trigger messages that fire emitter bursts (routed by emitter
+    /// `trigger`).
+    #[slot(
+        consumed,
+        merge = "by_key",
+        map(key = "u32", value_ref = "lp::control::Message")
+    )]
+    pub trigger: MapSlot<u32, ControlMessage>,
+
+    /// Stable-key emitter map.
+    #[slot(
+        consumed,
+        merge = "by_key",
+        map(key = "u32", value_ref = "lp::particle::Emitter")
+    )]
+    pub emitters: MapSlot<u32, ParticleEmitter>,
+
+    /// Constant acceleration in canvas units per second squared (`y` down).
+    #[slot(consumed)]
+    pub gravity: XySlot,
+
+    /// Velocity lost per second, as a rate: `1.0` sheds about 63% a second.
+    #[slot(consumed)]
+    pub drag: ValueSlot<f32>,
+
+    /// Stable-key attractor map.
+    #[slot(
+        consumed,
+        merge = "by_key",
+        map(key = "u32", value_ref = "lp::particle::Attractor")
+    )]
+    pub attractors: MapSlot<u32, ParticleAttractor>,
+
+    /// Peak acceleration of the simplex-noise flow field; `0.0` turns it
+    /// off.
+    #[slot(consumed)]
+    pub noise_strength: ValueSlot<f32>,
+
+    /// Noise features across the canvas.
+    pub noise_scale: PositiveF32Slot,
+
+    /// How fast the noise field drifts, in field units per second.
+    pub noise_speed: ValueSlot<f32>,
+
+    /// Particle radius in canvas pixels at birth.
+    pub size_start: PositiveF32Slot,
+
+    /// Particle radius in canvas pixels at death.
+    pub size_end: PositiveF32Slot,
+
+    /// Dim each particle to black over its life.
+    pub fade: ValueSlot<bool>,
+
+    /// Color over life: a particle walks the palette from its start at birth
+    /// to its end at death. Default-bound to the scope's `palette` channel;
+    /// with no writer and nothing authored, particles are white.
+    #[slot(consumed, default_bind = "bus:palette")]
+    pub palette: OptionSlot<ValueSlot<GradientConfig>>,
+}
+
+impl Default for ParticleDef {
+    fn default() -> Self {
+        Self {
+            bindings: BindingDefs::default(),
+            size: default_size(),
+            capacity: ValueSlot::new(256),
+            seed: ValueSlot::new(1),
+            time: TimeProductSlot::default(),
+            trigger: MapSlot::default(),
+            emitters: MapSlot::default(),
+            gravity: XySlot::new(Xy([0.0, 0.5])),
+            drag: ValueSlot::new(0.5),
+            attractors: MapSlot::default(),
+            noise_strength: ValueSlot::new(0.0),
+            noise_scale: PositiveF32Slot::new(PositiveF32(3.0)),
+            noise_speed: ValueSlot::new(0.25),
+            size_start: PositiveF32Slot::new(PositiveF32(1.5)),
+            size_end: PositiveF32Slot::new(PositiveF32(0.5)),
+            fade: ValueSlot::new(true),
+            palette: OptionSlot::none(),
+        }
+    }
+}
+
+impl ParticleDef {
+    pub const KIND: &'static str = "particle";
+
+    pub fn kind(&self) -> crate::NodeKind {
+        crate::NodeKind::Particle
+    }
+}
+
+fn default_size() -> Dim2uSlot {
+    Dim2uSlot::new(Dim2u {
+        width: 32,
+        height: 32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NodeDef, SlotDirection, SlotMerge, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn particle_def_parses_inline_emitters_and_attractors() {
+        let def = NodeDef::from_json_str(
+            r#"{
+  "kind": "Particle",
+  "capacity": 128,
+  "gravity": [0.0, 1.0],
+  "trigger": {
+    "7": { "id": 7, "seq": 1 }
+  },
+  "emitters": {
+    "1": {
+      "id": 1,
+      "pos": [0.5, 1.0],
+      "spread": 0.0,
+      "dir": [0.0, -1.0],
+      "cone": 0.4,
+      "speed": 1.0,
+      "rate": 0.0,
+      "burst": 30,
+      "trigger": 7,
+      "lifetime": 2.0,
+      "jitter": 0.1
+    }
+  },
+  "attractors": {
+    "1": { "id": 1, "pos": [0.5, 0.5], "strength": 2.0, "radius": 0.25 }
+  }
+}"#,
+        )
+        .expect("particle");
+
+        let NodeDef::Particle(def) = def else {
+            panic!("particle def");
+        };
+        assert_eq!(*def.capacity.value(), 128);
+        assert_eq!(def.gravity.value().0, [0.0, 1.0]);
+        let emitter = def.emitters.entries.get(&1).expect("emitter");
+        assert_eq!((emitter.burst, emitter.trigger), (30, 7));
+        assert_eq!(
+            def.trigger.entries.get(&7).map(|message| message.seq()),
+            Some(1)
+        );
+        assert_eq!(def.attractors.entries.len(), 1);
+        assert!(def.palette.data.is_none());
+    }
+
+    #[test]
+    fn particle_inputs_are_consumed_and_maps_merge_by_key() {
+        let SlotShape::Record { fields, .. } = ParticleDef::slot_shape() else {
+            panic!("record shape");
+        };
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|field| field.name.as_str() == name)
+                .expect("field")
+        };
+        for name in ["trigger", "emitters", "attractors"] {
+            assert_eq!(field(name).semantics.direction, SlotDirection::Consumed);
+            assert_eq!(field(name).semantics.merge, SlotMerge::ByKey, "{name}");
+        }
+        for (name, endpoint) in [("palette", "bus:palette"), ("time", "bus:time")] {
+            assert_eq!(field(name).semantics.direction, SlotDirection::Consumed);
+            assert_eq!(field(name).default_bind.as_deref(), Some(endpoint));
+        }
+    }
+}
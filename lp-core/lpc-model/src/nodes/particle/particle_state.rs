@@ -0,0 +1,20 @@
+//! Public runtime state shape for particle nodes.
+
+use crate::{Slotted, VisualProduct, VisualProductSlot};
+
+/// Runtime state exposed by a particle node.
+#[derive(Default, Slotted)]
+#[slot(default_role = "state")]
+pub struct ParticleState {
+    /// Renderable visual output produced by this particle node.
+    #[slot(produced, default_bind = "bus:visual.out")]
+    pub output: VisualProductSlot,
+}
+
+impl ParticleState {
+    pub fn new(output: VisualProduct) -> Self {
+        Self {
+            output: VisualProductSlot::new(output),
+        }
+    }
+}
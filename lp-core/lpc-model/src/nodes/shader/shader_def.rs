@@ -1,6 +1,7 @@
 use alloc::string::String;
 
 use crate::nodes::shader::{FloatMode, ShaderParamDef, ShaderSlotDef, ShaderSpace};
+use crate::nodes::texture::TextureFormat;
 use crate::{
     AssetSlot, BindingDefs, EnumSlot, MapSlot, OptionSlot, RenderOrderSlot, Slotted, ValueSlot,
 };
@@ -18,6 +19,11 @@ pub struct ShaderDef {
     /// float arithmetic. Absent (the normal state) is Auto: the target's
     /// native representation. See [`FloatMode`].
     pub float_mode: OptionSlot<ValueSlot<FloatMode>>,
+    /// Optional texture format the shader renders. Absent is `RGBA16`, the
+    /// native visual format; a narrower format makes the entry return
+    /// `float` (`R8`/`R16`) or `vec3` (`RGB8`) and shrinks the render
+    /// target. `RGBA8` has no storage format and fails the node.
+    pub output_format: OptionSlot<ValueSlot<TextureFormat>>,
     pub param_defs: MapSlot<String, ShaderParamDef>,
     /// Shader-consumed slots exposed to the resolver and GLSL uniform block.
     #[slot(name = "consumed")]
@@ -37,6 +43,7 @@ impl Default for ShaderDef {
             render_order: RenderOrderSlot::default(),
             bindings: BindingDefs::default(),
             float_mode: OptionSlot::none(),
+            output_format: OptionSlot::none(),
             param_defs: MapSlot::default(),
             consumed_slots: MapSlot::default(),
             space: EnumSlot::default(),
@@ -76,6 +83,7 @@ mod tests {
             render_order: RenderOrderSlot::new(RenderOrder(0)),
             bindings: BindingDefs::default(),
             float_mode: OptionSlot::none(),
+            output_format: OptionSlot::none(),
             param_defs: MapSlot::default(),
             consumed_slots: MapSlot::default(),
             space: EnumSlot::default(),
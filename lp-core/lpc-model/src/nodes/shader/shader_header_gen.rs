@@ -45,7 +45,7 @@ pub fn generate_compute_shader_header(
             // the palette itself rides the def's `gradient` field. The engine
             // supplies the matching `TextureBindingSpec` at compile
             // (`docs/design/lp-shader-texture-access.md`).
-            ShaderSlotKind::Palette => {
+            ShaderSlotKind::Palette | ShaderSlotKind::Feedback => {
                 writeln!(&mut out, "// consumed: {name}").expect("write string");
                 writeln!(
                     &mut out,
@@ -94,6 +94,11 @@ pub fn generate_compute_shader_header(
                     "a palette slot cannot be produced",
                 ));
             }
+            ShaderSlotKind::Feedback => {
+                return Err(ShaderHeaderGenError::Unsupported(
+                    "a feedback slot cannot be produced",
+                ));
+            }
             ShaderSlotKind::Map => {
                 let ty = glsl_type_for_ref(slot.value.value(), registry)?;
                 let mapping = slot
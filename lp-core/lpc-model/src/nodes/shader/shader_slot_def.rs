@@ -155,6 +155,21 @@ impl ShaderSlotDef {
         .with_panel_show()
     }
 
+    /// A `feedback` slot: a `sampler2D` uniform holding the previous frame of
+    /// a visual product — this shader's own output unless the slot is bound
+    /// to another one.
+    ///
+    /// No default bind: an unbound feedback slot reads the shader's own last
+    /// frame, which is the case trails and cellular automata want.
+    pub fn feedback(label: &str, description: &str) -> Self {
+        Self {
+            kind: ValueSlot::new(ShaderSlotKind::Feedback),
+            value: ValueSlot::new(ShaderValueShapeRef::builtin(SAMPLER_2D_SHAPE)),
+            default: OptionSlot::none(),
+            ..Self::value_f32(label, description, 0.0, None)
+        }
+    }
+
     /// The phasor config this slot evaluates against when nothing drives it
     /// from a channel — the authored one, or the default shaping.
     pub fn phasor_config(&self) -> PhasorConfig {
@@ -242,6 +257,12 @@ impl Default for ShaderSlotDef {
 /// never a `float`, so [`ShaderSlotKind::is_timebase`] deliberately stays
 /// false for it: that predicate answers "is this a float uniform the time
 /// product fills", and every caller of it assumes exactly that.
+///
+/// `Feedback` is the other texture kind: a `sampler2D` holding the previous
+/// frame of a visual product. Unbound, that product is the shader's own
+/// output; bound, it is whatever visual product the binding resolves to.
+/// The engine double-buffers the frame, so a shader reading its own output
+/// never reads the texture it is writing.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum ShaderSlotKind {
     #[default]
@@ -250,6 +271,7 @@ pub enum ShaderSlotKind {
     Phasor,
     Seconds,
     Palette,
+    Feedback,
 }
 
 impl ShaderSlotKind {
@@ -260,6 +282,7 @@ impl ShaderSlotKind {
             Self::Phasor => "phasor",
             Self::Seconds => "seconds",
             Self::Palette => "palette",
+            Self::Feedback => "feedback",
         }
     }
 
@@ -270,6 +293,7 @@ impl ShaderSlotKind {
             "phasor" => Some(Self::Phasor),
             "seconds" => Some(Self::Seconds),
             "palette" => Some(Self::Palette),
+            "feedback" => Some(Self::Feedback),
             _ => None,
         }
     }
@@ -283,15 +307,15 @@ impl ShaderSlotKind {
 
     /// Whether this kind's uniform is a texture the engine must supply a
     /// compile-time `TextureBindingSpec` for
-    /// (`docs/design/lp-shader-texture-access.md`) — today exactly
-    /// [`ShaderSlotKind::Palette`].
+    /// (`docs/design/lp-shader-texture-access.md`) — a
+    /// [`ShaderSlotKind::Palette`] or a [`ShaderSlotKind::Feedback`].
     ///
     /// Separate from [`Self::is_timebase`] on purpose: a texture uniform is
     /// not a `float`, does not go through value materialization, and needs a
     /// compile-time spec that no other kind has.
     #[must_use]
     pub fn is_texture(self) -> bool {
-        matches!(self, Self::Palette)
+        matches!(self, Self::Palette | Self::Feedback)
     }
 }
 
@@ -615,6 +639,7 @@ mod tests {
             ShaderSlotKind::Phasor,
             ShaderSlotKind::Seconds,
             ShaderSlotKind::Palette,
+            ShaderSlotKind::Feedback,
         ] {
             assert_eq!(ShaderSlotKind::parse(kind.as_str()), Some(kind));
         }
@@ -633,6 +658,8 @@ mod tests {
         // the texture kind, never a timebase one.
         assert!(!ShaderSlotKind::Palette.is_timebase());
         assert!(ShaderSlotKind::Palette.is_texture());
+        assert!(ShaderSlotKind::Feedback.is_texture());
+        assert!(!ShaderSlotKind::Feedback.is_timebase());
         assert!(!ShaderSlotKind::Phasor.is_texture());
     }
 
@@ -677,6 +704,31 @@ mod tests {
         assert!(slot.default.data.is_none());
     }
 
+    #[test]
+    fn feedback_shader_slot_declares_a_sampler_with_no_default_bind() {
+        let slot = read_slot_def(
+            r#"{
+  "kind": "feedback",
+  "value": "sampler2D",
+  "label": "Previous frame",
+  "description": "Last frame of this shader"
+}"#,
+        );
+
+        assert_eq!(*slot.kind.value(), ShaderSlotKind::Feedback);
+        assert!(slot.kind.value().is_texture());
+        assert_eq!(slot.value.value().as_str(), SAMPLER_2D_SHAPE);
+        assert_eq!(slot.value_lp_type(), None);
+        // Unbound means "my own output"; nothing materializes a channel.
+        assert!(slot.default_bind.data.is_none());
+
+        let built = ShaderSlotDef::feedback("Previous frame", "Last frame of this shader");
+        assert_eq!(*built.kind.value(), ShaderSlotKind::Feedback);
+        assert_eq!(built.value.value().as_str(), SAMPLER_2D_SHAPE);
+        assert!(built.default_bind.data.is_none());
+        assert!(built.default.data.is_none());
+    }
+
     /// The `gradient` field survives the [`crate::LpValue`] round trip the
     /// project loader reads slot defs through — the fixed-shape recipe
     /// (`docs/design/color.md` §5), not the authored-JSON serde surface.
@@ -4,7 +4,7 @@
 //! fields, so its `Default` impls must stay untouched. This table layers
 //! authored starter content on top for the kinds whose bare default is not a
 //! usable authoring target (0×0 textures, dangling shader sources, unset
-//! fixture mappings). Kinds without an entry are created from the bare
+//! fixture mappings, particle systems with nothing emitting). Kinds without an entry are created from the bare
 //! default with no assets.
 //!
 //! The table is pure data: serialization stays the caller's job via
@@ -16,7 +16,10 @@ use alloc::vec::Vec;
 
 use crate::node::kind::NodeKind;
 use crate::nodes::fixture::{FixtureDef, MappingConfig};
+use crate::nodes::image::ImageDef;
+use crate::nodes::particle::{ParticleDef, ParticleEmitter};
 use crate::nodes::shader::{ComputeShaderDef, ShaderDef, ShaderSlotDef};
+use crate::nodes::text::TextFont;
 use crate::nodes::texture::TextureDef;
 use crate::{AssetSlot, EnumSlot, MapSlot, NodeDef, PhasorConfig, Waveform};
 
@@ -55,6 +58,11 @@ pub const STARTER_FIXTURE_MAP2D: &str = r#"{
 }
 "#;
 
+/// Canonical scaffold image: an 8×8, four-frame GIF whose quadrants rotate
+/// once a second, so a new image node visibly animates before the author
+/// drops in a real file.
+pub const STARTER_IMAGE_GIF: &[u8] = include_bytes!("image/starter.gif");
+
 /// A starter node artifact: the authored definition plus any sibling assets
 /// it references (paths relative to the def file's directory, named with
 /// [`STARTER_STEM_PLACEHOLDER`]).
@@ -92,6 +100,12 @@ impl NodeStarter {
                     compute.source = AssetSlot::path(substituted);
                 }
             }
+            NodeDef::Image(image) => {
+                if let Some(spec) = image.source.artifact_value() {
+                    let substituted = spec.to_string().replace(STARTER_STEM_PLACEHOLDER, stem);
+                    image.source = AssetSlot::path(substituted);
+                }
+            }
             NodeDef::Fixture(fixture) => {
                 if let MappingConfig::Map2d { source } = fixture.mapping.value()
                     && let Some(spec) = source.artifact_value()
@@ -118,6 +132,11 @@ pub fn node_def_asset_ref(def: &NodeDef) -> Option<String> {
         NodeDef::ComputeShader(compute) => {
             compute.source.artifact_value().map(|spec| spec.to_string())
         }
+        NodeDef::Image(image) => image.source.artifact_value().map(|spec| spec.to_string()),
+        NodeDef::Text(text) => match text.font.value() {
+            TextFont::Bdf { source } => source.artifact_value().map(|spec| spec.to_string()),
+            TextFont::Classic5x8 | TextFont::Tiny3x5 => None,
+        },
         _ => None,
     }
 }
@@ -140,6 +159,19 @@ pub fn set_node_def_asset_ref(def: &mut NodeDef, path: &str) {
                 compute.source = AssetSlot::path(path);
             }
         }
+        NodeDef::Image(image) => {
+            if image.source.artifact_value().is_some() {
+                image.source = AssetSlot::path(path);
+            }
+        }
+        NodeDef::Text(text) => {
+            if matches!(
+                text.font.value(),
+                TextFont::Bdf { source } if source.artifact_value().is_some()
+            ) {
+                text.font = EnumSlot::new(TextFont::bdf(path));
+            }
+        }
         _ => {}
     }
 }
@@ -166,6 +198,16 @@ pub fn starter_for_kind(kind: NodeKind) -> Option<NodeStarter> {
                 STARTER_COMPUTE_GLSL.as_bytes().to_vec(),
             )],
         }),
+        NodeKind::Image => Some(NodeStarter {
+            def: NodeDef::Image(starter_image_def()),
+            assets: vec![(
+                alloc::format!("{STARTER_STEM_PLACEHOLDER}.gif"),
+                STARTER_IMAGE_GIF.to_vec(),
+            )],
+        }),
+        NodeKind::Particle => Some(NodeStarter::def_only(NodeDef::Particle(
+            starter_particle_def(),
+        ))),
         NodeKind::Fixture => Some(NodeStarter {
             def: NodeDef::Fixture(starter_fixture_def()),
             assets: vec![(
@@ -232,6 +274,36 @@ fn starter_compute_shader_def() -> ComputeShaderDef {
     }
 }
 
+fn starter_image_def() -> ImageDef {
+    ImageDef {
+        source: AssetSlot::path(alloc::format!("{STARTER_STEM_PLACEHOLDER}.gif")),
+        ..ImageDef::default()
+    }
+}
+
+/// A fountain: one emitter at the bottom center spraying upward against
+/// the default gravity, so a new particle node moves on first render.
+fn starter_particle_def() -> ParticleDef {
+    let mut emitters = lp_collection::VecMap::new();
+    emitters.insert(
+        1,
+        ParticleEmitter {
+            pos: [0.5, 0.95],
+            spread: 0.02,
+            cone: 0.35,
+            speed: 0.9,
+            rate: 40.0,
+            lifetime: 1.5,
+            jitter: 0.3,
+            ..ParticleEmitter::new(1)
+        },
+    );
+    ParticleDef {
+        emitters: MapSlot::new(emitters),
+        ..ParticleDef::default()
+    }
+}
+
 fn starter_fixture_def() -> FixtureDef {
     FixtureDef {
         mapping: EnumSlot::new(MappingConfig::Map2d {
@@ -255,6 +327,11 @@ mod tests {
         NodeKind::Shader,
         NodeKind::ComputeShader,
         NodeKind::Fluid,
+        NodeKind::Image,
+        NodeKind::Text,
+        NodeKind::Particle,
+        NodeKind::Filter,
+        NodeKind::Palette,
         NodeKind::Playlist,
         NodeKind::ControlRadio,
         NodeKind::Output,
@@ -279,7 +356,12 @@ mod tests {
         for kind in ALL_KINDS {
             let expected = matches!(
                 kind,
-                NodeKind::Texture | NodeKind::Shader | NodeKind::ComputeShader | NodeKind::Fixture
+                NodeKind::Texture
+                    | NodeKind::Shader
+                    | NodeKind::ComputeShader
+                    | NodeKind::Image
+                    | NodeKind::Particle
+                    | NodeKind::Fixture
             );
             assert_eq!(
                 starter_for_kind(*kind).is_some(),
@@ -418,6 +500,39 @@ mod tests {
         assert_eq!(read.write_json(&registry).expect("re-write"), text);
     }
 
+    #[test]
+    fn image_starter_ships_a_stem_named_gif() {
+        let starter = starter_for_kind(NodeKind::Image)
+            .expect("image starter")
+            .for_stem("logo");
+        let NodeDef::Image(image) = &starter.def else {
+            panic!("expected image");
+        };
+        assert_eq!(
+            image.image_source().artifact_value().unwrap().to_string(),
+            "logo.gif"
+        );
+        assert_eq!(starter.assets[0].0, "logo.gif");
+        assert!(starter.assets[0].1.starts_with(b"GIF89a"));
+        assert_eq!(
+            node_def_asset_ref(&starter.def).as_deref(),
+            Some("logo.gif")
+        );
+    }
+
+    #[test]
+    fn particle_starter_emits_without_wiring() {
+        let NodeDef::Particle(particle) = starter_def_for_kind(NodeKind::Particle) else {
+            panic!("expected particle");
+        };
+        let emitter = particle.emitters.entries.get(&1).expect("fountain");
+        assert!(emitter.rate > 0.0 && emitter.trigger == 0);
+        assert_eq!(
+            NodeDef::default_for_kind(NodeKind::Particle),
+            NodeDef::Particle(ParticleDef::default())
+        );
+    }
+
     #[test]
     fn bare_defaults_are_untouched_by_the_starter_table() {
         // Guard: the table must never leak into parse-time defaults.
@@ -0,0 +1,8 @@
+pub mod text_def;
+pub mod text_mode;
+pub mod text_state;
+
+pub use crate::slot_views::TextDefView;
+pub use text_def::TextDef;
+pub use text_mode::{TextAlignMode, TextColorMode, TextFont, TextScrollMode};
+pub use text_state::TextState;
@@ -0,0 +1,133 @@
+use alloc::string::String;
+
+use crate::nodes::text::{TextAlignMode, TextColorMode, TextFont, TextScrollMode};
+use crate::{
+    BindingDefs, Dim2u, Dim2uSlot, EnumSlot, GradientConfig, OptionSlot, Slotted, TimeProductSlot,
+    ValueSlot,
+};
+
+/// Authored text node definition: a string drawn onto a fixed canvas in a
+/// bitmap font, optionally scrolling, colored from a palette.
+///
+/// The message is an ordinary consumed slot, so a binding can feed it from a
+/// bus channel or another node's produced text; `\n` starts a new line.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub struct TextDef {
+    /// Authored slot bindings for text inputs and visual output.
+    pub bindings: BindingDefs,
+
+    /// The message to draw.
+    #[slot(consumed)]
+    pub text: ValueSlot<String>,
+
+    /// Built-in font, or a BDF file.
+    pub font: EnumSlot<TextFont>,
+
+    /// Canvas size the text is drawn onto.
+    pub size: Dim2uSlot,
+
+    /// Which way the text moves. A scrolling message wraps around once it
+    /// has fully left the canvas.
+    pub scroll: EnumSlot<TextScrollMode>,
+
+    /// Scroll speed in canvas pixels per second.
+    pub speed: ValueSlot<f32>,
+
+    /// Horizontal placement of each line inside the text block.
+    pub align: EnumSlot<TextAlignMode>,
+
+    /// How the palette colors the glyphs.
+    pub coloring: EnumSlot<TextColorMode>,
+
+    /// Glyph colors. Default-bound to the scope's `palette` channel; with
+    /// no writer and nothing authored, text draws white.
+    #[slot(consumed, default_bind = "bus:palette")]
+    pub palette: OptionSlot<ValueSlot<GradientConfig>>,
+
+    /// Timebase scrolling and palette cycles follow — the scope's time
+    /// product, so a paused clock freezes the marquee.
+    #[slot(consumed, default_bind = "bus:time")]
+    pub time: TimeProductSlot,
+}
+
+impl Default for TextDef {
+    fn default() -> Self {
+        Self {
+            bindings: BindingDefs::default(),
+            text: ValueSlot::new(String::from("HELLO")),
+            font: EnumSlot::default(),
+            size: default_size(),
+            scroll: EnumSlot::default(),
+            speed: ValueSlot::new(8.0),
+            align: EnumSlot::default(),
+            coloring: EnumSlot::default(),
+            palette: OptionSlot::none(),
+            time: TimeProductSlot::default(),
+        }
+    }
+}
+
+impl TextDef {
+    pub const KIND: &'static str = "text";
+
+    pub fn kind(&self) -> crate::NodeKind {
+        crate::NodeKind::Text
+    }
+}
+
+fn default_size() -> Dim2uSlot {
+    Dim2uSlot::new(Dim2u {
+        width: 32,
+        height: 8,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NodeDef, SlotDirection, SlotShape, StaticSlotShape};
+
+    #[test]
+    fn text_def_parses_font_file_and_modes() {
+        let def = NodeDef::from_json_str(
+            r#"{
+  "kind": "Text",
+  "text": "Go team",
+  "font": { "kind": "Bdf", "source": "fonts/spleen-6x12.bdf" },
+  "size": { "width": 64, "height": 12 },
+  "scroll": { "kind": "Left" },
+  "speed": 20.0,
+  "align": { "kind": "Left" },
+  "coloring": { "kind": "PerCharacter" }
+}"#,
+        )
+        .expect("text");
+
+        let NodeDef::Text(def) = def else {
+            panic!("text def");
+        };
+        assert_eq!(def.text.value(), "Go team");
+        assert!(matches!(def.font.value(), TextFont::Bdf { .. }));
+        assert_eq!(def.scroll.value(), &TextScrollMode::Left);
+        assert_eq!(*def.speed.value(), 20.0);
+        assert_eq!(def.align.value(), &TextAlignMode::Left);
+        assert_eq!(def.coloring.value(), &TextColorMode::PerCharacter);
+        assert!(def.palette.data.is_none());
+        assert_eq!(def.size.value().height, 12);
+    }
+
+    #[test]
+    fn text_palette_and_time_are_default_bound() {
+        let SlotShape::Record { fields, .. } = TextDef::slot_shape() else {
+            panic!("record shape");
+        };
+        for (name, endpoint) in [("palette", "bus:palette"), ("time", "bus:time")] {
+            let field = fields
+                .iter()
+                .find(|field| field.name.as_str() == name)
+                .expect("field");
+            assert_eq!(field.semantics.direction, SlotDirection::Consumed);
+            assert_eq!(field.default_bind.as_deref(), Some(endpoint));
+        }
+    }
+}
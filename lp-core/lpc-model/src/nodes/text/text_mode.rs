@@ -0,0 +1,60 @@
+use crate::{AssetSlot, LpPathBuf, Slotted};
+
+/// The bitmap font a text node draws with: one of the two built in, or a
+/// BDF file shipped with the project.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum TextFont {
+    /// 5×8 pixels, full ASCII with lowercase.
+    #[default]
+    Classic5x8,
+    /// 3×5 pixels, capitals only: fits two lines on an 8×8 panel.
+    Tiny3x5,
+    /// A BDF bitmap font asset (`*.bdf`), parsed when the node loads.
+    /// Characters the font lacks draw as `?`.
+    Bdf { source: AssetSlot },
+}
+
+impl TextFont {
+    pub fn bdf(source: impl Into<LpPathBuf>) -> Self {
+        Self::Bdf {
+            source: AssetSlot::path(source),
+        }
+    }
+}
+
+/// Which way text moves across the canvas.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum TextScrollMode {
+    /// Hold still, placed by the alignment.
+    #[default]
+    None,
+    /// Marquee: enter at the right edge, leave at the left.
+    Left,
+    /// Enter at the left edge, leave at the right.
+    Right,
+    /// Credits: enter at the bottom, leave at the top.
+    Up,
+    /// Enter at the top, leave at the bottom.
+    Down,
+}
+
+/// Horizontal placement of each line inside the text block.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum TextAlignMode {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// How the palette colors the glyphs.
+#[derive(Debug, Clone, PartialEq, Slotted)]
+pub enum TextColorMode {
+    /// Spread the palette across the text block, left to right.
+    #[default]
+    Across,
+    /// One palette step per character, so each letter is its own color.
+    PerCharacter,
+    /// The palette's first color for every glyph.
+    Solid,
+}
@@ -0,0 +1,20 @@
+//! Public runtime state shape for text nodes.
+
+use crate::{Slotted, VisualProduct, VisualProductSlot};
+
+/// Runtime state exposed by a text node.
+#[derive(Default, Slotted)]
+#[slot(default_role = "state")]
+pub struct TextState {
+    /// Renderable visual output produced by this text node.
+    #[slot(produced, default_bind = "bus:visual.out")]
+    pub output: VisualProductSlot,
+}
+
+impl TextState {
+    pub fn new(output: VisualProduct) -> Self {
+        Self {
+            output: VisualProductSlot::new(output),
+        }
+    }
+}
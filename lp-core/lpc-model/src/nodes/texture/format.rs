@@ -1,13 +1,23 @@
 //! Texture format definitions
 //!
 //! Rgba16 is the default for new textures in the 16-bit pipeline. Rgba8 is retained
-//! for backwards compatibility with legacy project state.
+//! for backwards compatibility with legacy project state. R8, Rgb8 and R16 are the
+//! authored low-memory formats: a mask or heightmap at R8 is an eighth of its
+//! RGBA16 size.
+
+use alloc::string::ToString;
+
+use crate::{
+    FromLpValue, LpType, LpValue, SlotEnumOption, SlotMeta, SlotShapeId, SlotValue,
+    SlotValueShape, StaticLpType, StaticSlotEnumOption, StaticSlotMeta, StaticSlotValueShape,
+    StaticValueEditorHint, ToLpValue, ValueEditorHint, ValueRootError,
+};
 
 /// Texture pixel format
 #[derive(
     Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
 )]
-#[serde(rename_all = "UPPERCASE")] // Serializes as "RGB8", "RGBA8", "R8", "RGBA16"
+#[serde(rename_all = "UPPERCASE")] // Serializes as "RGB8", "RGBA8", "R8", "R16", "RGBA16"
 pub enum TextureFormat {
     /// RGB 8-bit per channel (3 bytes per pixel)
     Rgb8,
@@ -15,6 +25,8 @@ pub enum TextureFormat {
     Rgba8,
     /// Single channel 8-bit (1 byte per pixel)
     R8,
+    /// Single channel 16-bit (2 bytes per pixel), unsigned normalized
+    R16,
     /// RGBA 16-bit per channel (8 bytes per pixel), unsigned normalized
     #[default]
     Rgba16,
@@ -27,6 +39,7 @@ impl TextureFormat {
             TextureFormat::Rgb8 => 3,
             TextureFormat::Rgba8 => 4,
             TextureFormat::R8 => 1,
+            TextureFormat::R16 => 2,
             TextureFormat::Rgba16 => 8,
         }
     }
@@ -37,6 +50,7 @@ impl TextureFormat {
             TextureFormat::Rgb8 => "RGB8",
             TextureFormat::Rgba8 => "RGBA8",
             TextureFormat::R8 => "R8",
+            TextureFormat::R16 => "R16",
             TextureFormat::Rgba16 => "RGBA16",
         }
     }
@@ -47,6 +61,7 @@ impl TextureFormat {
             "RGB8" => Some(TextureFormat::Rgb8),
             "RGBA8" => Some(TextureFormat::Rgba8),
             "R8" => Some(TextureFormat::R8),
+            "R16" => Some(TextureFormat::R16),
             "RGBA16" => Some(TextureFormat::Rgba16),
             _ => None,
         }
@@ -58,3 +73,70 @@ impl core::fmt::Display for TextureFormat {
         f.write_str(self.as_str())
     }
 }
+
+impl ToLpValue for TextureFormat {
+    fn to_lp_value(&self) -> LpValue {
+        LpValue::String(self.as_str().to_string())
+    }
+}
+
+impl FromLpValue for TextureFormat {
+    fn from_lp_value(value: &LpValue) -> Result<Self, ValueRootError> {
+        match value {
+            LpValue::String(value) => Self::from_str(value).ok_or_else(|| {
+                ValueRootError::new(alloc::format!("unknown texture format {value:?}"))
+            }),
+            other => Err(ValueRootError::new(alloc::format!(
+                "expected String, got {other:?}"
+            ))),
+        }
+    }
+}
+
+/// The authored formats, largest first. `RGBA8` parses for legacy state but
+/// is not offered: nothing renders it.
+impl SlotValue for TextureFormat {
+    const SHAPE_ID: SlotShapeId = SlotShapeId::from_static_name("TextureFormat");
+    const STATIC_VALUE_SHAPE_DESCRIPTOR: Option<StaticSlotValueShape> =
+        Some(StaticSlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: StaticLpType::String,
+            meta: StaticSlotMeta::EMPTY,
+            editor: StaticValueEditorHint::Dropdown {
+                options: &[
+                    StaticSlotEnumOption {
+                        value: "RGBA16",
+                        label: "RGBA16",
+                    },
+                    StaticSlotEnumOption {
+                        value: "RGB8",
+                        label: "RGB8",
+                    },
+                    StaticSlotEnumOption {
+                        value: "R16",
+                        label: "R16",
+                    },
+                    StaticSlotEnumOption {
+                        value: "R8",
+                        label: "R8",
+                    },
+                ],
+            },
+        });
+
+    fn value_shape() -> SlotValueShape {
+        SlotValueShape {
+            id: Self::SHAPE_ID,
+            ty: LpType::String,
+            meta: SlotMeta::empty(),
+            editor: ValueEditorHint::Dropdown {
+                options: alloc::vec![
+                    SlotEnumOption::new("RGBA16", "RGBA16"),
+                    SlotEnumOption::new("RGB8", "RGB8"),
+                    SlotEnumOption::new("R16", "R16"),
+                    SlotEnumOption::new("R8", "R8"),
+                ],
+            },
+        }
+    }
+}
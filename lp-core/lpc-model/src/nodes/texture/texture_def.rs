@@ -1,4 +1,4 @@
-use crate::{BindingDefs, Dim2u, Dim2uSlot, Slotted};
+use crate::{BindingDefs, Dim2u, Dim2uSlot, Slotted, TextureFormat, ValueSlot};
 
 /// Authored texture node definition.
 #[derive(Debug, Clone, Default, PartialEq, Slotted)]
@@ -6,7 +6,9 @@ pub struct TextureDef {
     pub size: Dim2uSlot,
     /// Authored slot bindings for texture materialization.
     pub bindings: BindingDefs,
-    // Format selection will be added when texture output semantics are revisited.
+    /// Storage format. RGBA16 by default; a mask or heightmap fits in `R8`
+    /// at an eighth of the memory.
+    pub format: ValueSlot<TextureFormat>,
 }
 
 impl TextureDef {
@@ -16,9 +18,14 @@ impl TextureDef {
         Self {
             size: Dim2uSlot::new(Dim2u { width, height }),
             bindings: BindingDefs::default(),
+            format: ValueSlot::new(TextureFormat::Rgba16),
         }
     }
 
+    pub fn format(&self) -> TextureFormat {
+        *self.format.value()
+    }
+
     pub fn width(&self) -> u32 {
         self.size.value().width
     }
@@ -35,7 +42,7 @@ impl TextureDef {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{NodeKind, SlotPath, SlotShapeRegistry, TextureDefView};
+    use crate::{NodeDef, NodeKind, SlotPath, SlotShapeRegistry, TextureDefView};
 
     #[test]
     fn test_texture_def_kind() {
@@ -45,6 +52,25 @@ mod tests {
         assert_eq!(def.height(), 200);
     }
 
+    #[test]
+    fn texture_def_parses_an_authored_format() {
+        let def = NodeDef::from_json_str(
+            r#"{ "kind": "Texture", "size": { "width": 16, "height": 8 }, "format": "R8" }"#,
+        )
+        .expect("texture");
+        let NodeDef::Texture(def) = def else {
+            panic!("texture def");
+        };
+        assert_eq!(def.format(), TextureFormat::R8);
+        assert_eq!(def.format().bytes_per_pixel(), 1);
+
+        assert_eq!(TextureDef::new(4, 4).format(), TextureFormat::Rgba16);
+        assert!(
+            NodeDef::from_json_str(r#"{ "kind": "Texture", "format": "R12" }"#).is_err(),
+            "unknown formats are rejected"
+        );
+    }
+
     #[test]
     fn generated_texture_def_view_compiles() {
         let registry = SlotShapeRegistry::default();
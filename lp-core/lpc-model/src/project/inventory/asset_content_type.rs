@@ -14,8 +14,12 @@ pub enum AssetContentType {
     ComputeShaderSource,
     /// 2D mapping document (`*.map2d.json`) consumed by a fixture node.
     FixtureMap2d,
-    /// Image data; decoding details are future work.
+    /// GIF or BMP image consumed by an image node.
     Image,
+    /// BDF bitmap font consumed by a text node.
+    Font,
+    /// 3D color lookup table (`*.cube`) consumed by a filter node.
+    ColorLut,
     /// Generic UTF-8 text.
     Text,
     /// Generic binary data.
@@ -42,7 +42,7 @@ pub const AUDIO_LEVEL_BANDS: usize = 3;
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
 pub enum Dimension {
-    /// Kinds with no physical dimension: [`Kind::Amplitude`], [`Kind::Ratio`], [`Kind::Phase`], [`Kind::Count`], [`Kind::Bool`], [`Kind::Choice`], color-family and spatial-struct Kinds, and [`Kind::Texture`].
+    /// Kinds with no physical dimension: [`Kind::Amplitude`], [`Kind::Ratio`], [`Kind::Phase`], [`Kind::Count`], [`Kind::Bool`], [`Kind::Choice`], [`Kind::Text`], color-family and spatial-struct Kinds, and [`Kind::Texture`].
     Dimensionless,
     /// [`Kind::Instant`] and [`Kind::Duration`] (stored in seconds as F32, `quantity.md` §4).
     Time,
@@ -77,6 +77,7 @@ pub enum Unit {
 /// Grouping (per `quantity.md` §3 sketch):
 ///
 /// - **Dimensionless value scalars:** `Kind::Amplitude`, `Kind::Ratio`, `Kind::Phase`, `Kind::Count`, `Kind::Bool`, `Kind::Choice`
+/// - **Text:** `Kind::Text`
 /// - **Scalars with a [`Dimension`]:** `Kind::Instant`, `Kind::Duration`, `Kind::Frequency`, `Kind::Angle`
 /// - **Structured *value* kinds (GPU-friendly structs):** `Kind::Color`, `Kind::Gradient`, `Kind::Position2d`, `Kind::Position3d`, `Kind::AudioLevel`
 /// - **Opaque handle (texture today):** `Kind::Texture`
@@ -96,6 +97,9 @@ pub enum Kind {
     Bool,
     /// Discrete choice; storage `LpType::I32` in v0 (`quantity.md` §3).
     Choice,
+    /// UTF-8 text, e.g. the message a text node draws; storage
+    /// `LpType::String`. Never reaches a shader.
+    Text,
 
     /// Time **instant** as F32 seconds since an epoch; [`Dimension::Time`] (`quantity.md` §3). Default bus is [`Binding::Bus`] with channel `"time"` when no explicit bind (`quantity.md` §8).
     Instant,
@@ -156,6 +160,7 @@ impl Kind {
             | Self::Angle => LpType::F32,
             Self::Count | Self::Choice => LpType::I32,
             Self::Bool => LpType::Bool,
+            Self::Text => LpType::String,
             Self::Position2d => LpType::Vec2,
             Self::Position3d => LpType::Vec3,
             Self::Color => color_struct(),
@@ -275,6 +280,7 @@ mod tests {
             Kind::Count,
             Kind::Bool,
             Kind::Choice,
+            Kind::Text,
             Kind::Instant,
             Kind::Duration,
             Kind::Frequency,
@@ -302,6 +308,12 @@ mod tests {
         assert_eq!(Kind::Choice.storage(), LpType::I32);
     }
 
+    #[test]
+    fn text_storage_is_a_string() {
+        assert_eq!(Kind::Text.storage(), LpType::String);
+        assert_eq!(Kind::Text.dimension(), Dimension::Dimensionless);
+    }
+
     #[test]
     fn position_storages() {
         assert_eq!(Kind::Position2d.storage(), LpType::Vec2);
@@ -660,6 +660,15 @@ impl ProjectRegistry {
         &self.artifacts
     }
 
+    /// The revision of a project file read outside the declared assets — a
+    /// shader's `#include` — registering it first, so filesystem events
+    /// advance it from then on. Two answers that differ mean the file
+    /// changed in between.
+    pub fn track_file(&mut self, path: &LpPath, frame: Revision) -> Revision {
+        let location = self.artifacts.register_file(path.to_path_buf(), frame);
+        self.artifacts.revision(&location).unwrap_or(frame)
+    }
+
     pub fn inventory(&self) -> &ProjectInventory {
         &self.inventory
     }
@@ -1729,6 +1738,27 @@ mod tests {
         assert!(!def.nodes.entries.contains_key("strip"));
     }
 
+    #[test]
+    fn a_tracked_file_advances_with_its_filesystem_events() {
+        let shapes = SlotShapeRegistry::default();
+        let (fs, mut registry) = clock_project(&shapes);
+        let include = LpPath::new("/lib/noise.glsl");
+
+        let tracked = registry.track_file(include, Revision::new(3));
+        assert_eq!(tracked, Revision::new(3));
+        assert_eq!(registry.track_file(include, Revision::new(4)), tracked);
+
+        let edit = |path: &str| FsEvent {
+            path: LpPath::new(path).to_path_buf(),
+            kind: FsEventKind::Modify,
+        };
+        let ctx = ParseCtx { shapes: &shapes };
+        registry.refresh_artifacts(&fs, &[edit("/lib/other.glsl")], Revision::new(5), &ctx);
+        assert_eq!(registry.track_file(include, Revision::new(5)), tracked);
+        registry.refresh_artifacts(&fs, &[edit("/lib/noise.glsl")], Revision::new(6), &ctx);
+        assert_eq!(registry.track_file(include, Revision::new(6)), Revision::new(6));
+    }
+
     #[test]
     fn type_mismatched_assign_value_is_rejected_and_matching_value_accepted() {
         let shapes = SlotShapeRegistry::default();
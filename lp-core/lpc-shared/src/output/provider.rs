@@ -92,6 +92,20 @@ pub trait OutputProvider {
         Ok(())
     }
 
+    /// Re-transmit the latest frames between engine frames.
+    ///
+    /// A provider with its own refresh cadence re-renders every output whose
+    /// wire is free at `now_us` — interpolating between the last two frames
+    /// [`write`](OutputProvider::write) delivered and dithering afresh — and
+    /// starts it again, so the LEDs refresh faster than the engine renders.
+    /// It must never block on a wire still transmitting: the caller invokes
+    /// this opportunistically, mid-frame, as often as it can afford.
+    ///
+    /// The default is a no-op: the LEDs refresh once per `write`.
+    fn refresh(&self, _now_us: u64) -> Result<(), OutputError> {
+        Ok(())
+    }
+
     /// Change signal for endpoint availability.
     ///
     /// The value changes whenever an endpoint that refused to [`open`] might
@@ -7,7 +7,7 @@ use lpc_model::nodes::clock::ClockDef;
 use lpc_model::nodes::fixture::{ColorOrder, FixtureDef, MappingConfig, PathSpec};
 use lpc_model::nodes::output::{OutputDef, OutputDriverOptionsConfig};
 use lpc_model::nodes::shader::{ShaderDef, ShaderSlotDef};
-use lpc_model::nodes::texture::TextureDef;
+use lpc_model::nodes::texture::{TextureDef, TextureFormat};
 use lpc_model::{
     Affine2d, Affine2dSlot, ArtifactSpec, AsLpPath, AssetSlot, BindingDef, BindingDefs, BindingRef,
     BusSlotRef, ChannelName, Dim2u, Dim2uSlot, EnumSlot, FixtureDiagnosticMode,
@@ -263,6 +263,7 @@ impl TextureBuilder {
             // TextureDef declares no `input` slot — a bus binding here would
             // be the dead entry the loader now refuses (the silent-drop fix).
             bindings: BindingDefs::default(),
+            format: ValueSlot::new(TextureFormat::Rgba16),
         };
 
         let json = authored_node_json(&slot_shape_registry(), &NodeDef::Texture(config));
@@ -313,6 +314,7 @@ impl ShaderBuilder {
             render_order: RenderOrderSlot::new(RenderOrder(self.render_order)),
             bindings: bus_output_binding_defs(&self.visual_bus),
             float_mode: OptionSlot::none(),
+            output_format: OptionSlot::none(),
             param_defs: MapSlot::default(),
             consumed_slots: default_visual_consumed_slots(),
             space: EnumSlot::default(),
@@ -435,6 +437,10 @@ impl FixtureBuilder {
                 .map_or_else(OptionSlot::none, |enabled| {
                     OptionSlot::some(ValueSlot::new(enabled))
                 }),
+            gamma: OptionSlot::none(),
+            dimmer_curve: OptionSlot::none(),
+            dimmer_lut: OptionSlot::none(),
+            brightness_gamma: OptionSlot::none(),
             power: OptionSlot::none(),
         };
 
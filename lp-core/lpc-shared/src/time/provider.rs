@@ -35,6 +35,16 @@ pub trait TimeProvider {
             0
         }
     }
+
+    /// Get the current time in microseconds since boot/start
+    ///
+    /// Same epoch as [`Self::now_ms`]. Used for per-node CPU budgets, where a
+    /// millisecond tick is too coarse to tell a cheap node from a free one.
+    /// The default scales `now_ms`; providers with a finer hardware timer
+    /// should override it.
+    fn now_us(&self) -> u64 {
+        self.now_ms().saturating_mul(1000)
+    }
 }
 
 #[cfg(test)]
@@ -76,6 +86,13 @@ mod tests {
         assert_eq!(provider.elapsed_ms(start), 100);
     }
 
+    #[test]
+    fn test_now_us_defaults_to_scaled_ms() {
+        let mut provider = MockTimeProvider::new();
+        provider.advance(7);
+        assert_eq!(provider.now_us(), 7000);
+    }
+
     #[test]
     fn test_elapsed_ms_wraparound() {
         let provider = MockTimeProvider::new();
@@ -101,6 +101,14 @@ impl Texture {
                 result[2] = self.data[offset];
                 result[3] = 255; // Alpha defaults to 255
             }
+            TextureFormat::R16 => {
+                let gray =
+                    (u16::from_le_bytes([self.data[offset], self.data[offset + 1]]) >> 8) as u8;
+                result[0] = gray;
+                result[1] = gray;
+                result[2] = gray;
+                result[3] = 255;
+            }
             TextureFormat::Rgba16 => {
                 let r = u16::from_le_bytes([self.data[offset], self.data[offset + 1]]);
                 let g = u16::from_le_bytes([self.data[offset + 2], self.data[offset + 3]]);
@@ -168,6 +176,10 @@ impl Texture {
             TextureFormat::R8 => {
                 self.data[offset] = color[0];
             }
+            TextureFormat::R16 => {
+                let gray = (color[0] as u16) * 257;
+                self.data[offset..offset + 2].copy_from_slice(&gray.to_le_bytes());
+            }
             TextureFormat::Rgba16 => {
                 let r = (color[0] as u16) * 257;
                 let g = (color[1] as u16) * 257;
@@ -275,38 +287,20 @@ impl Texture {
     pub fn data_mut(&mut self) -> &mut [u8] {
         &mut self.data
     }
-}
-
-#[allow(
-    deprecated,
-    reason = "deprecated Texture retained until callers migrate to LpsTextureBuf"
-)]
-impl lps_shared::TextureBuffer for Texture {
-    fn width(&self) -> u32 {
-        self.width
-    }
 
-    fn height(&self) -> u32 {
-        self.height
-    }
-
-    fn format(&self) -> lps_shared::TextureStorageFormat {
+    /// The shader storage format of this texture's pixels, or `None` for
+    /// [`TextureFormat::Rgba8`], which nothing samples. This is why `Texture`
+    /// does not implement [`lps_shared::TextureBuffer`]: its `format()` cannot
+    /// refuse a format, so callers check here and reject the texture instead.
+    pub fn storage_format(&self) -> Option<lps_shared::TextureStorageFormat> {
         match self.format {
-            TextureFormat::Rgba16 => lps_shared::TextureStorageFormat::Rgba16Unorm,
-            other => unimplemented!(
-                "TextureFormat {:?} has no TextureStorageFormat mapping yet (only Rgba16 → Rgba16Unorm is wired during M4a)",
-                other,
-            ),
+            TextureFormat::Rgba16 => Some(lps_shared::TextureStorageFormat::Rgba16Unorm),
+            TextureFormat::Rgb8 => Some(lps_shared::TextureStorageFormat::Rgb8Unorm),
+            TextureFormat::R16 => Some(lps_shared::TextureStorageFormat::R16Unorm),
+            TextureFormat::R8 => Some(lps_shared::TextureStorageFormat::R8Unorm),
+            TextureFormat::Rgba8 => None,
         }
     }
-
-    fn data(&self) -> &[u8] {
-        &self.data
-    }
-
-    fn data_mut(&mut self) -> &mut [u8] {
-        &mut self.data
-    }
 }
 
 #[cfg(test)]
@@ -416,4 +410,15 @@ mod tests {
         assert_eq!(pixel[1], 30);
         assert_eq!(pixel[2], 128);
     }
+
+    #[test]
+    fn test_storage_format_rejects_rgba8() {
+        let texture = Texture::new(2, 2, TextureFormat::R16).unwrap();
+        assert_eq!(
+            texture.storage_format(),
+            Some(lps_shared::TextureStorageFormat::R16Unorm)
+        );
+        let texture = Texture::new(2, 2, TextureFormat::Rgba8).unwrap();
+        assert_eq!(texture.storage_format(), None);
+    }
 }
@@ -31,7 +31,8 @@ pub enum WireResourceKindSummary {
 ///
 /// `Rgba16` is LightPlayer's native linear visual format. `Srgb8` is a
 /// display-oriented preview format used when exact linear sample fidelity would
-/// waste bandwidth.
+/// waste bandwidth. The remaining variants mirror the narrower authored
+/// texture storage formats, linear like `Rgba16`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "schema-gen", derive(schemars::JsonSchema))]
 #[serde(rename_all = "snake_case")]
@@ -40,6 +41,14 @@ pub enum WireTextureFormat {
     Rgba16,
     /// Display-encoded RGB8 preview data.
     Srgb8,
+    /// Linear RGB, little-endian `u16` channels.
+    Rgb16,
+    /// Linear RGB, `u8` channels.
+    Rgb8,
+    /// Linear single channel, little-endian `u16`.
+    R16,
+    /// Linear single channel, `u8`.
+    R8,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
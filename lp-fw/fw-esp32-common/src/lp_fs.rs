@@ -13,9 +13,17 @@ use lpfs::lp_path::{LpPath, LpPathBuf};
 use lpfs::{FsError, FsEvent, FsEventKind, FsVersion, LpFs, LpFsMemory, LpFsView};
 
 use littlefs_rust::{
-    Config, Error as LfsError, FileType as LfsFileType, Filesystem, OpenFlags, Storage,
+    Config, Error as LfsError, FileType as LfsFileType, Filesystem, OpenFlags, SeekFrom, Storage,
 };
 
+/// Directory of the native JIT code cache on the flash filesystem.
+pub const JIT_CACHE_DIR: &str = "/.lp/jit-cache";
+
+/// Flash budget of the JIT code cache. A module is a few KiB of machine code,
+/// so this holds the shaders of several projects and leaves the rest of the
+/// smallest (960 KiB) `lpfs` partition to projects.
+pub const JIT_CACHE_MAX_BYTES: u64 = 128 * 1024;
+
 /// Flash-backed filesystem implementing LpFs.
 ///
 /// Uses littlefs-rust over the lpfs partition. Supports chroot via LpFsView.
@@ -59,6 +67,15 @@ impl<S: Storage> LpFsFlash<S> {
         Ok(Self::from_fs(fs))
     }
 
+    /// A handle on this mount for the native JIT code cache (see
+    /// [`JitCacheFs`]). It shares the mount and its change log with `self`.
+    #[must_use]
+    pub fn jit_cache_fs(&self) -> JitCacheFs<S> {
+        JitCacheFs(LpFsFlash {
+            inner: Rc::clone(&self.inner),
+        })
+    }
+
     fn from_fs(fs: Filesystem<S>) -> Self {
         LpFsFlash {
             inner: Rc::new(RefCell::new(LpFsFlashInner {
@@ -202,6 +219,53 @@ impl<S: Storage + 'static> LpFs for LpFsFlash<S> {
         Ok(buf)
     }
 
+    /// Native range read: seek to `offset` and read into `buf` — O(window)
+    /// RAM and no pass over the bytes before it, instead of the trait
+    /// default's whole-file read, which an animated GIF would not survive.
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        if !path.is_absolute() {
+            return Err(FsError::InvalidPath(format!(
+                "Path must be absolute: {}",
+                path.as_str()
+            )));
+        }
+        let lfs_path = Self::to_lfs_path(path);
+        let inner = self.inner.borrow();
+        let file = inner
+            .fs
+            .open(lfs_path, OpenFlags::READ)
+            .map_err(|e| map_lfs_read_error(path, e))?;
+        // littlefs file offsets are 32-bit: nothing lies past that.
+        let Ok(offset) = u32::try_from(offset) else {
+            file.close()
+                .map_err(|e| FsError::Filesystem(format!("close {}: {e}", path.as_str())))?;
+            return Ok(0);
+        };
+        let read_error = |e| FsError::Filesystem(format!("read {}: {e}", path.as_str()));
+        let mut filled = 0usize;
+        let result = (|| {
+            // Seeking past the end is allowed; the first read returns 0.
+            file.seek(SeekFrom::Start(offset))
+                .map_err(|e| FsError::Filesystem(format!("seek {}: {e}", path.as_str())))?;
+            while filled < buf.len() {
+                let n = file.read(&mut buf[filled..]).map_err(read_error)? as usize;
+                if n == 0 {
+                    break;
+                }
+                filled += n;
+            }
+            Ok(())
+        })();
+        file.close()
+            .map_err(|e| FsError::Filesystem(format!("close {}: {e}", path.as_str())))?;
+        result.map(|()| filled)
+    }
+
     fn write_file(&self, path: &LpPath, data: &[u8]) -> Result<(), FsError> {
         if !path.is_absolute() {
             return Err(FsError::InvalidPath(format!(
@@ -500,3 +564,81 @@ impl<S: Storage + 'static> LpFs for LpFsFlash<S> {
         }
     }
 }
+
+/// [`LpFsFlash`] handle that the JIT code cache can hold.
+///
+/// `lpvm_native::code_cache::JitCodeCache` is `Send + Sync` because the
+/// engine that owns it is, but the flash filesystem is `Rc`-shared with the
+/// server. This wrapper is only sound because the firmware runs the server
+/// and every shader compile on one executor on one core — the second core of
+/// the ESP32 (`fw-esp32v3`) only services RMT interrupts and never touches the
+/// filesystem. Hand it to nothing that runs elsewhere.
+pub struct JitCacheFs<S: Storage>(LpFsFlash<S>);
+
+// SAFETY: see the type docs — every access happens on the server's executor.
+unsafe impl<S: Storage> Send for JitCacheFs<S> {}
+// SAFETY: as above; there is never a second thread to share with.
+unsafe impl<S: Storage> Sync for JitCacheFs<S> {}
+
+impl<S: Storage + 'static> LpFs for JitCacheFs<S> {
+    fn read_file(&self, path: &LpPath) -> Result<Vec<u8>, FsError> {
+        self.0.read_file(path)
+    }
+
+    fn write_file(&self, path: &LpPath, data: &[u8]) -> Result<(), FsError> {
+        self.0.write_file(path, data)
+    }
+
+    fn file_size(&self, path: &LpPath) -> Result<u64, FsError> {
+        self.0.file_size(path)
+    }
+
+    fn read_file_range(
+        &self,
+        path: &LpPath,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, FsError> {
+        self.0.read_file_range(path, offset, buf)
+    }
+
+    fn file_exists(&self, path: &LpPath) -> Result<bool, FsError> {
+        self.0.file_exists(path)
+    }
+
+    fn is_dir(&self, path: &LpPath) -> Result<bool, FsError> {
+        self.0.is_dir(path)
+    }
+
+    fn list_dir(&self, path: &LpPath, recursive: bool) -> Result<Vec<LpPathBuf>, FsError> {
+        self.0.list_dir(path, recursive)
+    }
+
+    fn delete_file(&self, path: &LpPath) -> Result<(), FsError> {
+        self.0.delete_file(path)
+    }
+
+    fn delete_dir(&self, path: &LpPath) -> Result<(), FsError> {
+        self.0.delete_dir(path)
+    }
+
+    fn chroot(&self, subdir: &LpPath) -> Result<Rc<RefCell<dyn LpFs>>, FsError> {
+        self.0.chroot(subdir)
+    }
+
+    fn current_version(&self) -> FsVersion {
+        self.0.current_version()
+    }
+
+    fn get_changes_since(&self, since_version: FsVersion) -> Vec<FsEvent> {
+        self.0.get_changes_since(since_version)
+    }
+
+    fn clear_changes_before(&mut self, before_version: FsVersion) {
+        self.0.clear_changes_before(before_version);
+    }
+
+    fn record_changes(&mut self, changes: Vec<FsEvent>) {
+        self.0.record_changes(changes);
+    }
+}
@@ -2,6 +2,38 @@
 //!
 //! The provider is the compatibility layer used by the engine. Hardware-specific
 //! details live in capability drivers registered on the root `HardwareSystem`.
+//!
+//! # Refresh cadence
+//!
+//! By default the LEDs refresh once per engine frame: each `write` renders
+//! one frame through the channel's [`DisplayPipeline`] and transmits it. That
+//! locks refresh to the frame rate — ~20 fps on the classic ESP32 — which is
+//! too slow for temporal dithering to fuse.
+//!
+//! [`Esp32OutputProvider::with_refresh`] loosens the lock. Each `write` is
+//! stamped one engine-frame interval into the future, so the pipeline can
+//! interpolate between the last two engine frames, dithering afresh on every
+//! pass, and the refreshes come from two places:
+//!
+//! * **The output itself.** An output that takes several frames per start
+//!   ([`Ws281xOutput::max_frames_per_start`] — the classic's APP-core pusher)
+//!   is handed, with each write, the rendered frame plus as many refreshes as
+//!   fit in the last engine frame interval, each sampled one frame of wire
+//!   time after the one before it. It presents them back to back on its own
+//!   core, so a project with one fixture refreshes as often as one with
+//!   ten. The next write cuts whatever is left.
+//! * **[`OutputProvider::refresh`]**, which re-renders every idle
+//!   background-safe wire at the current time. The engine calls it between
+//!   demand roots; it fills whatever the output-side refreshes leave idle.
+//!
+//! The wire is the cadence either way: nothing restarts a wire still on the
+//! air.
+//!
+//! This is not a refresh cadence of its own. Refresh frames are rendered
+//! ahead, inside `write`, at most `max_frames_per_start` of them (3, on the
+//! classic only; 1 on every barrier wire), and the rate the classic reaches
+//! has not been measured on silicon. `docs/design/brightness-gamma-dithering.md`
+//! ("Scope") records what a live, RMT-driven cadence would still need.
 
 use alloc::boxed::Box;
 use alloc::format;
@@ -18,6 +50,7 @@ use lpc_hardware::{
 };
 use lpc_shared::DisplayPipeline;
 use lpc_shared::output::{OutputChannelHandle, OutputDriverOptions, OutputFormat, OutputProvider};
+use lpc_shared::time::TimeProvider;
 const FRAME_INTERVAL_US: u64 = 16_667;
 const MID_FRAME_US: u64 = 8_333;
 
@@ -50,7 +83,9 @@ struct ChannelState {
     output: Box<dyn Ws281xOutput>,
     byte_count: u32,
     pipeline: DisplayPipeline,
-    /// The channel's own rendered frame, alive between writes.
+    /// The channel's own rendered frame, alive between writes — followed, in
+    /// refresh mode, by the refreshes handed to the output with it (see the
+    /// module docs), so its length is a whole number of frames.
     ///
     /// It used to be a `Vec` allocated inside every `write`, which is fine
     /// while one handle is written at a time. It is not fine for a concurrent
@@ -60,6 +95,10 @@ struct ChannelState {
     /// `byte_count`, so this is the same memory the per-write allocation held,
     /// just held for longer.
     frame: Vec<u8>,
+    /// When the last `write` arrived, in refresh mode; `None` until then, and
+    /// always in per-frame mode. Its spacing is the engine frame interval the
+    /// next write is stamped ahead by.
+    last_write_us: Option<u64>,
 }
 
 /// ESP32 OutputProvider implementation.
@@ -67,6 +106,8 @@ pub struct Esp32OutputProvider {
     hardware_system: Rc<HardwareSystem>,
     channels: RefCell<VecMap<i32, ChannelState>>,
     next_handle: RefCell<i32>,
+    /// Present in refresh mode — see the module docs.
+    clock: Option<Rc<dyn TimeProvider>>,
 }
 
 impl Esp32OutputProvider {
@@ -75,6 +116,16 @@ impl Esp32OutputProvider {
             hardware_system,
             channels: RefCell::new(VecMap::with_capacity(RESERVED_CHANNELS)),
             next_handle: RefCell::new(1),
+            clock: None,
+        }
+    }
+
+    /// A provider whose LEDs refresh on their own cadence, between engine
+    /// frames, timed by `clock` — see the module docs.
+    pub fn with_refresh(hardware_system: Rc<HardwareSystem>, clock: Rc<dyn TimeProvider>) -> Self {
+        Self {
+            clock: Some(clock),
+            ..Self::new(hardware_system)
         }
     }
 }
@@ -140,6 +191,7 @@ impl OutputProvider for Esp32OutputProvider {
                 byte_count,
                 pipeline,
                 frame,
+                last_write_us: None,
             },
         );
 
@@ -226,19 +278,46 @@ impl OutputProvider for Esp32OutputProvider {
             });
         }
 
+        let frame_len = num_leds * 3;
+        let now_us = self.clock.as_ref().map(|clock| clock.now_us());
+        let interval_us = match (now_us, channel.last_write_us) {
+            (Some(now_us), Some(last)) => now_us.saturating_sub(last),
+            _ => 0,
+        };
+        let wire_us = ws281x_frame_us(num_leds);
+        let frames = frames_per_write(interval_us, wire_us, channel.output.max_frames_per_start());
+
         // The channel owns its frame storage, so a resize is the only time
         // this allocates and a steady-state write allocates nothing at all.
-        channel.frame.resize(num_leds * 3, 0);
+        channel.frame.resize(frame_len * frames, 0);
 
-        channel.pipeline.write_frame(0, data);
-        channel.pipeline.write_frame(FRAME_INTERVAL_US, data);
         let ChannelState {
             output,
             pipeline,
             frame,
+            last_write_us,
             ..
         } = channel;
-        pipeline.tick(MID_FRAME_US, frame);
+        match now_us {
+            // Stamped one frame interval ahead, so the refreshes until the
+            // next write land between this frame and the one before it. The
+            // frames after the first go out back to back, so each is sampled
+            // one frame of wire time after the one before it.
+            Some(now_us) => {
+                *last_write_us = Some(now_us);
+                pipeline.write_frame(now_us.saturating_add(interval_us), data);
+                for (index, out) in frame.chunks_exact_mut(frame_len).enumerate() {
+                    pipeline.tick(now_us + index as u64 * wire_us, out);
+                }
+            }
+            // One frame, rendered once: written twice so the pipeline has a
+            // pair to interpolate, and sampled between them.
+            None => {
+                pipeline.write_frame(0, data);
+                pipeline.write_frame(FRAME_INTERVAL_US, data);
+                pipeline.tick(MID_FRAME_US, frame);
+            }
+        }
 
         // Start the transmission and return without waiting for it. Handles
         // written back to back in one flush therefore transmit concurrently —
@@ -253,8 +332,8 @@ impl OutputProvider for Esp32OutputProvider {
         // `wait_complete`, and dropping the entry drops `output` (which stops
         // the transmission) before `frame` (see the field-order note on
         // `ChannelState`). That is exactly the lifetime `start`'s contract
-        // asks for.
-        unsafe { output.start(frame) }
+        // asks for — over every frame, for `start_frames`.
+        unsafe { output.start_frames(frame, frames) }
     }
 
     fn close(&self, handle: OutputChannelHandle) -> Result<(), OutputError> {
@@ -309,11 +388,75 @@ impl OutputProvider for Esp32OutputProvider {
         }
     }
 
+    /// One refresh pass over every wire that may take one: written at least
+    /// once, background-safe, and done with its previous frame.
+    ///
+    /// Barrier wires are skipped. Their transmissions must not overlap the
+    /// render (see [`Self::flush`]), and the engine calls this mid-frame, so
+    /// they keep the once-per-write cadence. An idle wire costs one
+    /// non-blocking poll; a wire still on the air is left alone. A no-op
+    /// unless the provider was built [`Esp32OutputProvider::with_refresh`].
+    fn refresh(&self, now_us: u64) -> Result<(), OutputError> {
+        if self.clock.is_none() {
+            return Ok(());
+        }
+        let mut first_error: Option<OutputError> = None;
+        for (handle_id, channel) in self.channels.borrow_mut().iter_mut() {
+            if channel.last_write_us.is_none()
+                || !channel.output.background_tx_safe()
+                || !channel.output.poll_complete()
+            {
+                continue;
+            }
+            let frame_len = (channel.byte_count / 3 * 3) as usize;
+            let ChannelState {
+                output,
+                pipeline,
+                frame,
+                ..
+            } = channel;
+            // Only the first frame: the refreshes a write handed over were
+            // for the time since, and are spent.
+            let frame = &mut frame[..frame_len];
+            // Returns at once — the poll said so — but collects the previous
+            // frame's outcome before its bytes are overwritten.
+            let result = output.wait_complete().and_then(|()| {
+                pipeline.tick(now_us, frame);
+                // SAFETY: as in `write` — `frame` is this channel's own
+                // storage, the previous transmission was waited out above, and
+                // every later touch of these bytes waits first.
+                unsafe { output.start(frame) }
+            });
+            if let Err(error) = result {
+                log::warn!("Esp32OutputProvider::refresh: handle={handle_id}: {error}");
+                first_error.get_or_insert(error);
+            }
+        }
+        match first_error {
+            None => Ok(()),
+            Some(error) => Err(error),
+        }
+    }
+
     fn hardware_generation(&self) -> u64 {
         self.hardware_system.registry().generation()
     }
 }
 
+/// Wire time of one WS281x frame: 24 bits of 1.25 µs per LED, then the
+/// 300 µs latch.
+fn ws281x_frame_us(num_leds: usize) -> u64 {
+    num_leds as u64 * 30 + 300
+}
+
+/// Frames a write hands its output: the rendered one, plus the refreshes
+/// that fit in the last engine frame interval, up to what the output takes.
+/// One before there is an interval to go by.
+fn frames_per_write(interval_us: u64, wire_us: u64, max_frames: usize) -> usize {
+    let fit = (interval_us / wire_us.max(1)).min(usize::MAX as u64) as usize;
+    fit.clamp(1, max_frames.max(1))
+}
+
 fn capped_byte_count_for_len(data_len: usize) -> (u32, bool) {
     ws281x_capped_byte_count(((data_len / 3) * 3) as u32)
 }
@@ -463,13 +606,15 @@ mod concurrent_flush_tests {
     //! The deferred-wait transmission contract, proven against a probe output:
     //! `write` waits out the channel's *previous* frame before touching its
     //! storage, starts the new one, and returns without waiting for it — so
-    //! handles written back to back transmit concurrently.
+    //! handles written back to back transmit concurrently. `refresh` rides the
+    //! same contract: it restarts a wire only once the probe reports its
+    //! frame complete.
 
     use alloc::boxed::Box;
     use alloc::rc::Rc;
     use alloc::vec;
     use alloc::vec::Vec;
-    use core::cell::RefCell;
+    use core::cell::{Cell, RefCell};
 
     use lpc_hardware::{
         HardwareEndpointError, HardwareSystem, HwAddress, HwDriver, HwEndpoint, HwEndpointId,
@@ -477,6 +622,7 @@ mod concurrent_flush_tests {
         Ws281xDriver, Ws281xOutput,
     };
     use lpc_shared::output::{OutputChannelHandle, OutputFormat, OutputProvider};
+    use lpc_shared::time::TimeProvider;
 
     use super::Esp32OutputProvider;
 
@@ -497,6 +643,13 @@ mod concurrent_flush_tests {
         /// Outputs with a started, un-waited frame — across ALL probe outputs,
         /// which is what makes concurrency observable.
         in_flight: usize,
+        /// Whether the wires have finished what they carry: what
+        /// `poll_complete` reports for an output with a frame in flight.
+        wires_done: bool,
+        /// The bytes of every frame started, in order.
+        frames: Vec<Vec<u8>>,
+        /// How many frames each start carried, in order.
+        frames_per_start: Vec<usize>,
     }
 
     struct ProbeOutput {
@@ -505,6 +658,8 @@ mod concurrent_flush_tests {
         /// What this output claims via `background_tx_safe` — the provider's
         /// flush must skip waiting exactly the outputs that claim `true`.
         background_safe: bool,
+        /// What this output claims via `max_frames_per_start`.
+        max_frames: usize,
     }
 
     impl Ws281xOutput for ProbeOutput {
@@ -521,18 +676,41 @@ mod concurrent_flush_tests {
             Ok(())
         }
 
-        unsafe fn start(&mut self, _data: &[u8]) -> Result<(), OutputError> {
+        unsafe fn start(&mut self, data: &[u8]) -> Result<(), OutputError> {
+            // SAFETY: forwarded.
+            unsafe { self.start_frames(data, 1) }
+        }
+
+        fn max_frames_per_start(&self) -> usize {
+            self.max_frames
+        }
+
+        unsafe fn start_frames(&mut self, frames: &[u8], count: usize) -> Result<(), OutputError> {
             assert!(
                 !self.in_flight,
                 "start while a frame is in flight violates the wait-first contract"
             );
+            assert!(
+                count <= self.max_frames,
+                "more frames than the output takes"
+            );
             let mut log = self.log.borrow_mut();
             log.events.push(ProbeEvent::Start);
+            log.frames.extend(
+                frames
+                    .chunks_exact(frames.len() / count)
+                    .map(<[u8]>::to_vec),
+            );
+            log.frames_per_start.push(count);
             log.in_flight += 1;
             self.in_flight = true;
             Ok(())
         }
 
+        fn poll_complete(&mut self) -> bool {
+            !self.in_flight || self.log.borrow().wires_done
+        }
+
         fn wait_complete(&mut self) -> Result<(), OutputError> {
             let mut log = self.log.borrow_mut();
             log.events.push(ProbeEvent::Wait {
@@ -553,6 +731,8 @@ mod concurrent_flush_tests {
         log: Rc<RefCell<ProbeLog>>,
         /// Bit `n` set = endpoint `Pn`'s outputs report `background_tx_safe`.
         background_safe: u32,
+        /// Every output's `max_frames_per_start`.
+        max_frames: usize,
     }
 
     const PROBE_ENDPOINTS: usize = 2;
@@ -601,6 +781,7 @@ mod concurrent_flush_tests {
                 log: Rc::clone(&self.log),
                 in_flight: false,
                 background_safe,
+                max_frames: self.max_frames,
             }))
         }
     }
@@ -613,6 +794,15 @@ mod concurrent_flush_tests {
     /// `Pn`'s outputs claim `background_tx_safe`.
     fn probe_provider_with_background(
         background_safe: u32,
+    ) -> (Esp32OutputProvider, Rc<RefCell<ProbeLog>>) {
+        probe_provider_with(background_safe, 1)
+    }
+
+    /// [`probe_provider_with_background`] whose outputs take up to
+    /// `max_frames` frames per start.
+    fn probe_provider_with(
+        background_safe: u32,
+        max_frames: usize,
     ) -> (Esp32OutputProvider, Rc<RefCell<ProbeLog>>) {
         let log = Rc::new(RefCell::new(ProbeLog::default()));
         let registry = Rc::new(HwRegistry::new(HwManifest::virtual_single_rmt_gpio_board()));
@@ -620,10 +810,38 @@ mod concurrent_flush_tests {
         system.add_ws281x_driver(Box::new(ProbeDriver {
             log: Rc::clone(&log),
             background_safe,
+            max_frames,
         }));
         (Esp32OutputProvider::new(Rc::new(system)), log)
     }
 
+    /// A microsecond clock the test sets by hand.
+    #[derive(Default)]
+    struct ProbeClock {
+        now_us: Cell<u64>,
+    }
+
+    impl TimeProvider for ProbeClock {
+        fn now_ms(&self) -> u64 {
+            self.now_us.get() / 1000
+        }
+
+        fn now_us(&self) -> u64 {
+            self.now_us.get()
+        }
+    }
+
+    /// [`probe_provider_with_background`] in refresh mode, on a [`ProbeClock`].
+    fn probe_refresh_provider(
+        background_safe: u32,
+    ) -> (Esp32OutputProvider, Rc<RefCell<ProbeLog>>, Rc<ProbeClock>) {
+        let (provider, log) = probe_provider_with_background(background_safe);
+        let clock = Rc::new(ProbeClock::default());
+        let provider =
+            Esp32OutputProvider::with_refresh(Rc::clone(&provider.hardware_system), clock.clone());
+        (provider, log, clock)
+    }
+
     fn open_probe(provider: &Esp32OutputProvider, n: usize) -> OutputChannelHandle {
         provider
             .open(
@@ -762,6 +980,137 @@ mod concurrent_flush_tests {
         );
     }
 
+    /// Without a clock the provider refreshes once per write, as it always
+    /// has: `refresh` starts nothing, however idle the wire.
+    #[test]
+    fn refresh_is_a_no_op_without_a_clock() {
+        let (provider, log) = probe_provider_with_background(0b1);
+        let handle = open_probe(&provider, 0);
+        provider.write(handle, &vec![0u16; 3]).expect("write");
+        log.borrow_mut().wires_done = true;
+
+        let starts = start_count(&log);
+        provider.refresh(10_000).expect("refresh");
+        assert_eq!(start_count(&log), starts);
+    }
+
+    /// The wire is the cadence: a refresh restarts a background-safe wire
+    /// once its frame completed, never while it is still on the air, and
+    /// never a barrier wire or one nothing was written to yet.
+    #[test]
+    fn refresh_restarts_only_idle_background_safe_wires() {
+        let (provider, log, _clock) = probe_refresh_provider(0b01);
+        let background = open_probe(&provider, 0);
+        let barrier = open_probe(&provider, 1);
+        provider
+            .write(background, &vec![0u16; 3])
+            .expect("background wire");
+        provider
+            .write(barrier, &vec![0u16; 3])
+            .expect("barrier wire");
+        provider.flush().expect("flush");
+        let starts = start_count(&log);
+
+        provider.refresh(1_000).expect("refresh while on the air");
+        assert_eq!(start_count(&log), starts, "a busy wire is left alone");
+
+        log.borrow_mut().wires_done = true;
+        provider.refresh(2_000).expect("refresh once idle");
+        assert_eq!(
+            start_count(&log),
+            starts + 1,
+            "only the background-safe wire is restarted"
+        );
+        assert_eq!(
+            log.borrow().events[log.borrow().events.len() - 2..],
+            [
+                ProbeEvent::Wait {
+                    had_in_flight: true
+                },
+                ProbeEvent::Start,
+            ],
+            "the finished frame is collected before its bytes are restaged"
+        );
+    }
+
+    #[test]
+    fn refresh_skips_a_wire_never_written() {
+        let (provider, log, _clock) = probe_refresh_provider(0b1);
+        open_probe(&provider, 0);
+        provider.refresh(1_000).expect("refresh");
+        assert_eq!(start_count(&log), 0, "no frame to show yet");
+    }
+
+    /// Refreshes between two engine frames show the blend of the last two:
+    /// each write is stamped one frame interval ahead, so the refresh halfway
+    /// to the next write lands halfway between the frames.
+    #[test]
+    fn refreshes_interpolate_between_the_last_two_writes() {
+        let (provider, log, clock) = probe_refresh_provider(0b1);
+        let handle = open_probe(&provider, 0);
+        log.borrow_mut().wires_done = true;
+
+        for (at_us, level) in [(0, 0u16), (50_000, 0), (100_000, u16::MAX)] {
+            clock.now_us.set(at_us);
+            provider.write(handle, &[level; 3]).expect("write");
+        }
+        let green = |log: &Rc<RefCell<ProbeLog>>| log.borrow().frames.last().expect("frame")[1];
+        assert_eq!(green(&log), 0, "a write shows the frame before it");
+
+        provider.refresh(125_000).expect("halfway refresh");
+        let halfway = green(&log);
+        assert!(
+            (126..=129).contains(&halfway),
+            "halfway between black and white, got {halfway}"
+        );
+
+        provider
+            .refresh(150_000)
+            .expect("refresh at the next frame");
+        assert_eq!(green(&log), 255);
+    }
+
+    /// An output that takes several frames per start gets, with each write,
+    /// the refreshes that fit in the last frame interval — sampled one frame
+    /// of wire time apart, so they carry the blend forward without the
+    /// engine calling `refresh` at all.
+    #[test]
+    fn a_write_hands_an_output_its_refreshes() {
+        let (provider, log) = probe_provider_with(0b1, 3);
+        let clock = Rc::new(ProbeClock::default());
+        let provider =
+            Esp32OutputProvider::with_refresh(Rc::clone(&provider.hardware_system), clock.clone());
+        let handle = open_probe(&provider, 0);
+        log.borrow_mut().wires_done = true;
+
+        // One LED: 330 µs of wire per frame, so a 1 ms interval fits three.
+        for (at_us, level) in [(0, 0u16), (1_000, 0), (2_000, u16::MAX)] {
+            clock.now_us.set(at_us);
+            provider.write(handle, &[level; 3]).expect("write");
+        }
+        assert_eq!(
+            log.borrow().frames_per_start,
+            vec![1, 3, 3],
+            "no interval to go by before the second write"
+        );
+
+        let greens: Vec<u8> = log.borrow().frames[4..].iter().map(|f| f[1]).collect();
+        assert_eq!(greens[0], 0, "the write's own frame");
+        assert!(
+            (82..=87).contains(&greens[1]),
+            "a third of the way: {greens:?}"
+        );
+        assert!((166..=171).contains(&greens[2]), "two thirds: {greens:?}");
+    }
+
+    fn start_count(log: &Rc<RefCell<ProbeLog>>) -> usize {
+        log.borrow()
+            .events
+            .iter()
+            .filter(|e| matches!(e, ProbeEvent::Start))
+            .count()
+    }
+
     fn wait_count(log: &Rc<RefCell<ProbeLog>>) -> usize {
         log.borrow()
             .events
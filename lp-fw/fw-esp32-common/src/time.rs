@@ -1,6 +1,7 @@
 //! ESP32 TimeProvider implementation
 //!
-//! Uses embassy-time for millisecond-precision timing.
+//! Uses embassy-time for millisecond-precision timing, and microseconds
+//! for node budget measurement.
 
 use embassy_time::Instant;
 use lpc_shared::time::TimeProvider;
@@ -31,6 +32,12 @@ impl TimeProvider for Esp32TimeProvider {
         let current_ms = self.now_ms();
         current_ms.saturating_sub(start_ms)
     }
+
+    fn now_us(&self) -> u64 {
+        Instant::now()
+            .saturating_duration_since(self.start_time)
+            .as_micros()
+    }
 }
 
 impl Default for Esp32TimeProvider {
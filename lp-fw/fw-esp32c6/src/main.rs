@@ -169,6 +169,34 @@ fn esp32_memory_stats() -> Option<(u32, u32)> {
     ))
 }
 
+/// The on-device JIT, with emitted machine code cached on flash under
+/// [`lp_fs::JIT_CACHE_DIR`] so a project loads without recompiling on the
+/// next boot.
+///
+/// Cache entries are keyed by the build commit, so a dirty build — whose
+/// commit does not identify its compiler — gets no cache. Neither does safe
+/// mode: the cache is one more thing the crash being recovered from may
+/// have touched.
+#[cfg(all(not(feature = "memory_fs"), not(fw_harness),))]
+fn target_graphics(
+    frontend: lpa_server::ShaderFrontend,
+    jit_cache_fs: Option<lp_fs::JitCacheFs<flash_storage::LpFlashStorage>>,
+    safe_mode: bool,
+) -> TargetLpvmGraphics {
+    match jit_cache_fs {
+        Some(fs) if !safe_mode && env!("LP_BUILD_DIRTY") != "true" => {
+            let cache = lp_gfx_lpvm::CodeCache::new(
+                fs,
+                lpfs::LpPathBuf::from(lp_fs::JIT_CACHE_DIR),
+                lp_fs::JIT_CACHE_MAX_BYTES,
+                env!("LP_BUILD_COMMIT"),
+            );
+            TargetLpvmGraphics::with_code_cache(frontend, Arc::new(cache))
+        }
+        _ => TargetLpvmGraphics::new(frontend),
+    }
+}
+
 #[cfg(not(fw_harness))]
 struct FirmwareApp {
     server: LpServer,
@@ -253,30 +281,32 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     let boot_control = lp_bootctl::DecodeOutcome::Blank;
 
     // Create filesystem before hardware providers so /hardware.json can override board policy.
-    let base_fs: Box<dyn lpfs::LpFs> = {
-        #[cfg(not(feature = "memory_fs"))]
-        {
-            let flash_storage = flash;
-            match lp_fs::LpFsFlash::init(
-                crate::flash_storage::LpFlashStorage::new(flash_storage),
-                crate::flash_storage::lpfs_config,
-            ) {
-                Ok(fs) => {
-                    esp_println::println!("[INIT] Flash filesystem mounted");
-                    Box::new(fs)
-                }
-                Err(e) => {
-                    esp_println::println!("[WARN] Flash FS failed: {e}, falling back to memory");
-                    Box::new(LpFsMemory::new())
-                }
+    // The flash mount also yields a handle for the JIT code cache (see
+    // `target_graphics`); the RAM fallback does not, as a cache there would
+    // not outlive the boot.
+    #[cfg(not(feature = "memory_fs"))]
+    let (base_fs, jit_cache_fs): (Box<dyn lpfs::LpFs>, _) = {
+        let flash_storage = flash;
+        match lp_fs::LpFsFlash::init(
+            crate::flash_storage::LpFlashStorage::new(flash_storage),
+            crate::flash_storage::lpfs_config,
+        ) {
+            Ok(fs) => {
+                esp_println::println!("[INIT] Flash filesystem mounted");
+                let jit_cache_fs = fs.jit_cache_fs();
+                (Box::new(fs), Some(jit_cache_fs))
+            }
+            Err(e) => {
+                esp_println::println!("[WARN] Flash FS failed: {e}, falling back to memory");
+                (Box::new(LpFsMemory::new()), None)
             }
         }
-        #[cfg(feature = "memory_fs")]
-        {
-            let _ = flash;
-            esp_println::println!("[INIT] Creating in-memory filesystem...");
-            Box::new(LpFsMemory::new())
-        }
+    };
+    #[cfg(feature = "memory_fs")]
+    let base_fs: Box<dyn lpfs::LpFs> = {
+        let _ = flash;
+        esp_println::println!("[INIT] Creating in-memory filesystem...");
+        Box::new(LpFsMemory::new())
     };
     #[cfg(feature = "memory_fs")]
     esp_println::println!("[INIT] In-memory filesystem created");
@@ -357,7 +387,11 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     } else {
         lpa_server::DEVICE_SHADER_FRONTEND
     };
-    let graphics: Arc<dyn LpGraphics> = Arc::new(TargetLpvmGraphics::new(shader_frontend));
+    #[cfg(not(feature = "memory_fs"))]
+    let jit_graphics = target_graphics(shader_frontend, jit_cache_fs, boot_assessment.safe_mode);
+    #[cfg(feature = "memory_fs")]
+    let jit_graphics = TargetLpvmGraphics::new(shader_frontend);
+    let graphics: Arc<dyn LpGraphics> = Arc::new(jit_graphics);
     let button_service: Rc<dyn ButtonService> = hardware_system.clone();
     let radio_service: Rc<dyn lpa_server::RadioService> = hardware_system.clone();
     let mut server = LpServer::new_with_hardware_services(
@@ -116,7 +116,7 @@ use {
     fw_esp32_common::time::Esp32TimeProvider,
     fw_esp32_common::{boot, logger, lp_fs, transport},
     hardware::button::Esp32GpioButtonDriver,
-    lp_gfx_lpvm::TargetLpvmGraphics,
+    lp_gfx_lpvm::{CodeCache, TargetLpvmGraphics},
     lpa_server::{ButtonService, LpGraphics, LpServer},
     lpc_hardware::{HardwareSystem, HwRegistry},
     lpc_shared::output::OutputProvider,
@@ -299,7 +299,7 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     let transport =
         transport::StreamingMessageRouterTransport::new(incoming, write_request, write_result);
 
-    let base_fs = mount_filesystem(flash);
+    let (base_fs, jit_cache_fs) = mount_filesystem(flash);
 
     // The compiled-in fallback is the XIAO ESP32-S3 Plus profile — the desk
     // board. It is deliberately partial (no user LED, no castellated pads); see
@@ -359,8 +359,11 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     // deliberately has no default impl so that every host states its choice;
     // the device ships `LpsGlsl`, and silently taking Naga would change what
     // the shader means without changing a line of it.
-    let graphics: Arc<dyn LpGraphics> =
-        Arc::new(TargetLpvmGraphics::new(lpa_server::DEVICE_SHADER_FRONTEND));
+    let graphics: Arc<dyn LpGraphics> = Arc::new(target_graphics(
+        lpa_server::DEVICE_SHADER_FRONTEND,
+        jit_cache_fs,
+        boot_assessment.safe_mode,
+    ));
 
     let time_provider_rc = Rc::new(Esp32TimeProvider::new());
     let button_service: Rc<dyn ButtonService> = hardware_system.clone();
@@ -425,8 +428,17 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
 
 /// Mount the `lpfs` partition, falling back to RAM so an unformattable or
 /// mis-flashed board still comes up reachable and can say so over the wire.
+///
+/// The second value is a handle on the same flash mount for the JIT code
+/// cache; there is none on the RAM fallback, where a cache would not outlive
+/// the boot.
 #[cfg(not(fw_harness))]
-fn mount_filesystem(flash: esp_hal::peripherals::FLASH<'static>) -> Box<dyn lpfs::LpFs> {
+fn mount_filesystem(
+    flash: esp_hal::peripherals::FLASH<'static>,
+) -> (
+    Box<dyn lpfs::LpFs>,
+    Option<lp_fs::JitCacheFs<LpFlashStorage>>,
+) {
     let mut flash_storage = esp_storage::FlashStorage::new(flash);
     let Some(partition) = LpfsPartition::locate(&mut flash_storage) else {
         // Not a runtime condition: it means the image was flashed without
@@ -437,17 +449,46 @@ fn mount_filesystem(flash: esp_hal::peripherals::FLASH<'static>) -> Box<dyn lpfs
             "[ERROR] no `lpfs` partition in the flashed table — reflash with \
              --partition-table lp-fw/fw-esp32s3/partitions.csv; using memory FS"
         );
-        return Box::new(LpFsMemory::new());
+        return (Box::new(LpFsMemory::new()), None);
     };
     match lp_fs::LpFsFlash::init(LpFlashStorage::new(flash_storage, partition), lpfs_config) {
         Ok(fs) => {
             esp_println::println!("[INIT] flash filesystem mounted");
-            Box::new(fs)
+            let jit_cache_fs = fs.jit_cache_fs();
+            (Box::new(fs), Some(jit_cache_fs))
         }
         Err(e) => {
             esp_println::println!("[WARN] flash FS failed: {e}, falling back to memory");
-            Box::new(LpFsMemory::new())
+            (Box::new(LpFsMemory::new()), None)
+        }
+    }
+}
+
+/// The on-device JIT, with emitted machine code cached on flash under
+/// [`lp_fs::JIT_CACHE_DIR`] so a project loads without recompiling on the
+/// next boot.
+///
+/// Cache entries are keyed by the build commit, so a dirty build — whose
+/// commit does not identify its compiler — gets no cache. Neither does safe
+/// mode: the cache is one more thing the crash being recovered from may
+/// have touched.
+#[cfg(not(fw_harness))]
+fn target_graphics(
+    frontend: lpa_server::ShaderFrontend,
+    jit_cache_fs: Option<lp_fs::JitCacheFs<LpFlashStorage>>,
+    safe_mode: bool,
+) -> TargetLpvmGraphics {
+    match jit_cache_fs {
+        Some(fs) if !safe_mode && env!("LP_BUILD_DIRTY") != "true" => {
+            let cache = CodeCache::new(
+                fs,
+                lpfs::LpPathBuf::from(lp_fs::JIT_CACHE_DIR),
+                lp_fs::JIT_CACHE_MAX_BYTES,
+                env!("LP_BUILD_COMMIT"),
+            );
+            TargetLpvmGraphics::with_code_cache(frontend, Arc::new(cache))
         }
+        _ => TargetLpvmGraphics::new(frontend),
     }
 }
 
@@ -281,6 +281,7 @@ fn run_per_compile_float_mode(tally: &mut Tally) {
     let params = LpvmCompileParams {
         config: Default::default(),
         float_mode: FloatMode::F32,
+        source_hash: None,
     };
 
     for case in F32_CASES {
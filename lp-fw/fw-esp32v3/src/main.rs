@@ -123,7 +123,7 @@ use {
     fw_esp32_common::server_loop::run_server_loop,
     fw_esp32_common::time::Esp32TimeProvider,
     fw_esp32_common::{boot, logger, lp_fs, transport},
-    lp_gfx_lpvm::TargetLpvmGraphics,
+    lp_gfx_lpvm::{CodeCache, TargetLpvmGraphics},
     lpa_server::{LpGraphics, LpServer},
     lpc_hardware::{HardwareSystem, HwRegistry},
     lpc_shared::output::OutputProvider,
@@ -494,7 +494,7 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     let transport =
         transport::StreamingMessageRouterTransport::new(incoming, write_request, write_result);
 
-    let base_fs = mount_filesystem(flash);
+    let (base_fs, jit_cache_fs) = mount_filesystem(flash);
 
     // The compiled-in fallback is the DOM-Z-102 profile — the desk board and
     // the roadmap's WLED-class exemplar. An `/hardware.json` on the device
@@ -563,9 +563,15 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
     let hardware_system = Rc::new(hardware_system);
 
     // The provider itself is chip-agnostic and comes from fw-esp32-common
-    // untouched; only the driver registered above is chip-side.
-    let output_provider: Rc<RefCell<dyn OutputProvider>> =
-        Rc::new(RefCell::new(Esp32OutputProvider::new(hardware_system)));
+    // untouched; only the driver registered above is chip-side. Refresh mode:
+    // with the refill ISR on the APP core the wires are background-safe, so
+    // they re-present interpolated, freshly dithered frames between engine
+    // frames instead of refreshing at the ~20 fps the engine manages. In the
+    // single-core fallback no wire is background-safe and refresh is inert.
+    let time_provider_rc = Rc::new(Esp32TimeProvider::new());
+    let output_provider: Rc<RefCell<dyn OutputProvider>> = Rc::new(RefCell::new(
+        Esp32OutputProvider::with_refresh(hardware_system, time_provider_rc.clone()),
+    ));
 
     // Stamped device identity: read the fs-root `/.lp/device.json` once at boot
     // for the hello (missing file → unstamped, `None`).
@@ -610,10 +616,12 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
             .reclaimable_heap_span()
             .1,
     );
-    let graphics: Arc<dyn LpGraphics> =
-        Arc::new(TargetLpvmGraphics::new(lpa_server::DEVICE_SHADER_FRONTEND));
+    let graphics: Arc<dyn LpGraphics> = Arc::new(target_graphics(
+        lpa_server::DEVICE_SHADER_FRONTEND,
+        jit_cache_fs,
+        boot_assessment.safe_mode,
+    ));
 
-    let time_provider_rc = Rc::new(Esp32TimeProvider::new());
     let mut server = LpServer::new_with_hardware_services(
         output_provider,
         base_fs,
@@ -682,8 +690,17 @@ fn boot_firmware(spawner: embassy_executor::Spawner) -> FirmwareApp {
 
 /// Mount the `lpfs` partition, falling back to RAM so an unformattable or
 /// mis-flashed board still comes up reachable and can say so over the wire.
+///
+/// The second value is a handle on the same flash mount for the JIT code
+/// cache; there is none on the RAM fallback, where a cache would not outlive
+/// the boot.
 #[cfg(all(feature = "server", not(feature = "radio_ram_probe"), not(fw_harness)))]
-fn mount_filesystem(flash: esp_hal::peripherals::FLASH<'static>) -> Box<dyn lpfs::LpFs> {
+fn mount_filesystem(
+    flash: esp_hal::peripherals::FLASH<'static>,
+) -> (
+    Box<dyn lpfs::LpFs>,
+    Option<lp_fs::JitCacheFs<LpFlashStorage>>,
+) {
     let mut flash_storage = esp_storage::FlashStorage::new(flash);
     let Some(partition) = LpfsPartition::locate(&mut flash_storage) else {
         // Not a runtime condition: it means the image was flashed without
@@ -694,17 +711,41 @@ fn mount_filesystem(flash: esp_hal::peripherals::FLASH<'static>) -> Box<dyn lpfs
             "[ERROR] no `lpfs` partition in the flashed table — reflash with \
              --partition-table lp-fw/fw-esp32v3/partitions.csv; using memory FS"
         );
-        return Box::new(LpFsMemory::new());
+        return (Box::new(LpFsMemory::new()), None);
     };
     match lp_fs::LpFsFlash::init(LpFlashStorage::new(flash_storage, partition), lpfs_config) {
         Ok(fs) => {
             esp_println::println!("[INIT] flash filesystem mounted");
-            Box::new(fs)
+            let jit_cache_fs = fs.jit_cache_fs();
+            (Box::new(fs), Some(jit_cache_fs))
         }
         Err(e) => {
             esp_println::println!("[WARN] flash FS failed: {e}, falling back to memory");
-            Box::new(LpFsMemory::new())
+            (Box::new(LpFsMemory::new()), None)
+        }
+    }
+}
+
+/// The on-device JIT, with emitted machine code cached on flash under
+/// [`lp_fs::JIT_CACHE_DIR`]; same policy as `fw-esp32s3`: no cache for a
+/// dirty build (its commit does not identify the compiler) or in safe mode.
+#[cfg(all(feature = "server", not(feature = "radio_ram_probe"), not(fw_harness)))]
+fn target_graphics(
+    frontend: lpa_server::ShaderFrontend,
+    jit_cache_fs: Option<lp_fs::JitCacheFs<LpFlashStorage>>,
+    safe_mode: bool,
+) -> TargetLpvmGraphics {
+    match jit_cache_fs {
+        Some(fs) if !safe_mode && env!("LP_BUILD_DIRTY") != "true" => {
+            let cache = CodeCache::new(
+                fs,
+                lpfs::LpPathBuf::from(lp_fs::JIT_CACHE_DIR),
+                lp_fs::JIT_CACHE_MAX_BYTES,
+                env!("LP_BUILD_COMMIT"),
+            );
+            TargetLpvmGraphics::with_code_cache(frontend, Arc::new(cache))
         }
+        _ => TargetLpvmGraphics::new(frontend),
     }
 }
 
@@ -80,6 +80,11 @@ use crate::output::rmt::wire_pusher;
 use lp_ws281x::WireOutcome;
 
 const DRIVER_ID: &str = "esp32v3-rmt-ws281x";
+
+/// Most frames one post hands the APP-core pusher: the rendered frame plus
+/// two refreshes. The provider keeps that many copies of each channel's
+/// frame alive, so this is a heap cost on the chip with the least of it.
+const MAX_FRAMES_PER_POST: usize = 3;
 const DISPLAY_LABEL: &str = "ESP32 RMT WS281x";
 
 /// How many RMT channels this driver lets transmit **at the same time**.
@@ -578,6 +583,9 @@ struct Esp32V3RmtWs281xOutput {
 struct PostedFrame {
     seq: u32,
     at: Instant,
+    /// Frames the post carries; above 1 the rest are refreshes, which the
+    /// wait cuts short (see [`Esp32V3RmtWs281xOutput::wait_posted`]).
+    frames: usize,
     #[cfg(feature = "frame-dump")]
     ptr: *const u8,
     #[cfg(feature = "frame-dump")]
@@ -617,15 +625,26 @@ impl Esp32V3RmtWs281xOutput {
     /// legal frame (~31 ms of wire) plus a whole wave of queueing. The
     /// deadline check reads a timer over APB and is throttled so the common
     /// case is a pure SRAM-atomic spin.
+    ///
+    /// A post still refreshing is cut first: the caller has a new frame, so
+    /// the refreshes it no longer needs are dropped and the wait owes only
+    /// the frame on the wire. The deadline then runs from the cut, since the
+    /// refreshes legitimately kept the post open past it.
     fn wait_posted(&mut self, posted: PostedFrame) -> Result<(), OutputError> {
         let mailbox = &wire_pusher::MAILBOXES[self.index];
+        let mut deadline_from = posted.at;
+        if posted.frames > 1 && mailbox.completed_outcome(posted.seq).is_none() {
+            mailbox.request_cut(posted.seq);
+            wire_pusher::ring_doorbell();
+            deadline_from = Instant::now();
+        }
         let mut iterations = 0u32;
         let outcome = loop {
             if let Some(outcome) = mailbox.completed_outcome(posted.seq) {
                 break Some(outcome);
             }
             iterations = iterations.wrapping_add(1);
-            if iterations % 1024 == 0 && posted.at.elapsed() > FRAME_TIMEOUT {
+            if iterations % 1024 == 0 && deadline_from.elapsed() > FRAME_TIMEOUT {
                 break None;
             }
         };
@@ -739,13 +758,30 @@ impl Ws281xOutput for Esp32V3RmtWs281xOutput {
     }
 
     unsafe fn start(&mut self, data: &[u8]) -> Result<(), OutputError> {
+        // SAFETY: forwarding this method's own contract.
+        unsafe { self.start_frames(data, 1) }
+    }
+
+    /// Refresh frames ride the APP-core pusher, so only the dual-core shape
+    /// takes them; the single-core fallback transmits one frame per start.
+    fn max_frames_per_start(&self) -> usize {
+        if isr_on_app_core() {
+            MAX_FRAMES_PER_POST
+        } else {
+            1
+        }
+    }
+
+    unsafe fn start_frames(&mut self, frames: &[u8], count: usize) -> Result<(), OutputError> {
         let expected_len = byte_len_for_byte_count(self.byte_count);
-        if data.len() != expected_len {
+        let count = count.clamp(1, self.max_frames_per_start());
+        if frames.len() != expected_len * count {
             return Err(OutputError::DataLengthMismatch {
-                expected: expected_len as u32,
-                actual: data.len(),
+                expected: (expected_len * count) as u32,
+                actual: frames.len(),
             });
         }
+        let data = &frames[..expected_len];
 
         // Dual-core: post to the APP-core pusher and return WITHOUT waiting
         // for a slot — the whole point of the overlap deployment. Admission,
@@ -756,17 +792,26 @@ impl Ws281xOutput for Esp32V3RmtWs281xOutput {
         if isr_on_app_core() {
             let mailbox = &wire_pusher::MAILBOXES[self.index];
             // SAFETY: forwarding this method's own contract through the
-            // mailbox — the caller keeps `data` alive, in place, and
+            // mailbox — the caller keeps `frames` alive, in place, and
             // unmodified until `wait_complete` returns (or drops this
             // output, whose `Drop` quiesces the wire through the pusher's
             // close ack first).
             let now = Instant::now();
             let now_us = now.duration_since_epoch().as_micros() as u32;
-            let seq = unsafe { mailbox.post(self.gpio, data.as_ptr(), data.len(), now_us) };
+            let seq = unsafe {
+                mailbox.post_frames(
+                    self.gpio,
+                    frames.as_ptr(),
+                    expected_len,
+                    count as u8,
+                    now_us,
+                )
+            };
             wire_pusher::ring_doorbell();
             self.posted = Some(PostedFrame {
                 seq,
                 at: now,
+                frames: count,
                 #[cfg(feature = "frame-dump")]
                 ptr: data.as_ptr(),
                 #[cfg(feature = "frame-dump")]
@@ -820,6 +865,22 @@ impl Ws281xOutput for Esp32V3RmtWs281xOutput {
         Ok(())
     }
 
+    /// The same two completion signals `wait_complete` spins on, read once:
+    /// the pusher's completed sequence for a posted frame, the slot's own
+    /// completion (or its takeover) for one started here.
+    fn poll_complete(&mut self) -> bool {
+        if let Some(posted) = &self.posted {
+            return wire_pusher::MAILBOXES[self.index]
+                .completed_outcome(posted.seq)
+                .is_some();
+        }
+        let Some(in_flight) = &self.in_flight else {
+            return true;
+        };
+        self.pool.borrow()[in_flight.pool_idx].generation != in_flight.generation
+            || DRIVER.is_complete(in_flight.rmt_channel)
+    }
+
     fn wait_complete(&mut self) -> Result<(), OutputError> {
         if let Some(posted) = self.posted.take() {
             return self.wait_posted(posted);
@@ -77,4 +77,8 @@ impl TimeProvider for HostLoopTimeProvider {
     fn now_ms(&self) -> u64 {
         self.start.elapsed().as_millis().min(u64::MAX as u128) as u64
     }
+
+    fn now_us(&self) -> u64 {
+        self.start.elapsed().as_micros().min(u64::MAX as u128) as u64
+    }
 }
@@ -33,6 +33,16 @@
 //! with GPIO-matrix writes; a host test wraps it in a plain loop against
 //! [`MockRmt`](crate::MockRmt). Neither changes the scheduler.
 //!
+//! # Refresh frames
+//!
+//! A post may carry several frames ([`WireMailbox::post_frames`]). The
+//! pusher starts each on the same slot the moment the one before it
+//! completes, so the wire keeps refreshing — a poster that renders at 20 fps
+//! can hand over interpolated, freshly dithered frames for the gaps between
+//! its renders, and the strip sees them at wire rate without the poster's
+//! core doing anything further. The post still completes once, after its
+//! last frame, so the one-outstanding-post invariant is unchanged.
+//!
 //! # Timeouts live with the poster
 //!
 //! This crate has no clock, so the pusher never times anything out. A poster
@@ -98,7 +108,12 @@ fn seq_after(a: u32, b: u32) -> bool {
 #[derive(Debug)]
 pub struct WireMailbox {
     frame_ptr: AtomicPtr<u8>,
+    /// Length of one frame. A post may carry several back to back.
     frame_len: AtomicUsize,
+    /// Frames at `frame_ptr`, each `frame_len` bytes: the first is the
+    /// post's own, the rest refresh the wire after it (see
+    /// [`Self::post_frames`]).
+    frame_count: AtomicU8,
     gpio: AtomicU8,
     /// Last frame the poster published. Store is `Release`: it is the
     /// publish edge for the descriptor fields above.
@@ -117,6 +132,9 @@ pub struct WireMailbox {
     /// this sequence, then ack. The teardown edge of the wire lifecycle.
     close_req_seq: AtomicU32,
     close_ack_seq: AtomicU32,
+    /// Poster: end the post through this sequence after the frame on the
+    /// wire — no more refresh frames. Completes as transmitted.
+    cut_req_seq: AtomicU32,
     /// The slot channel currently carrying this wire, [`NO_CHANNEL`] when
     /// idle. Advisory (`Relaxed`): exists solely for the poster's defensive
     /// abort on the pusher-wedged path, which is already a defect state.
@@ -138,6 +156,7 @@ pub struct WireMailbox {
     aborted: AtomicU32,
     cancelled: AtomicU32,
     start_failed: AtomicU32,
+    refreshed: AtomicU32,
 }
 
 /// A read-only snapshot of one wire's attribution counters.
@@ -171,6 +190,9 @@ pub struct WireStats {
     pub cancelled: u32,
     /// Frames the driver refused to start (a defect).
     pub start_failed: u32,
+    /// Refresh frames transmitted: every frame of a post after its first,
+    /// started by the pusher with no poster in between.
+    pub refreshed: u32,
 }
 
 impl WireMailbox {
@@ -179,6 +201,7 @@ impl WireMailbox {
         Self {
             frame_ptr: AtomicPtr::new(core::ptr::null_mut()),
             frame_len: AtomicUsize::new(0),
+            frame_count: AtomicU8::new(1),
             gpio: AtomicU8::new(0),
             posted_seq: AtomicU32::new(0),
             completed_seq: AtomicU32::new(0),
@@ -186,6 +209,7 @@ impl WireMailbox {
             abort_req_seq: AtomicU32::new(0),
             close_req_seq: AtomicU32::new(0),
             close_ack_seq: AtomicU32::new(0),
+            cut_req_seq: AtomicU32::new(0),
             active_channel: AtomicU8::new(NO_CHANNEL),
             posted_at_us: AtomicU32::new(0),
             transmitted: AtomicU32::new(0),
@@ -196,6 +220,7 @@ impl WireMailbox {
             aborted: AtomicU32::new(0),
             cancelled: AtomicU32::new(0),
             start_failed: AtomicU32::new(0),
+            refreshed: AtomicU32::new(0),
         }
     }
 
@@ -212,6 +237,7 @@ impl WireMailbox {
             aborted: self.aborted.load(Relaxed),
             cancelled: self.cancelled.load(Relaxed),
             start_failed: self.start_failed.load(Relaxed),
+            refreshed: self.refreshed.load(Relaxed),
         }
     }
 
@@ -229,9 +255,38 @@ impl WireMailbox {
     /// [`Self::request_abort`] covering it completes. This is
     /// `start_frame`'s byte contract, transferred through the mailbox.
     pub unsafe fn post(&self, gpio: u8, ptr: *const u8, len: usize, now_us: u32) -> u32 {
+        // SAFETY: the caller's contract is this one's with one frame.
+        unsafe { self.post_frames(gpio, ptr, len, 1, now_us) }
+    }
+
+    /// Publish `count` frames of `len` bytes each, back to back at `ptr`, as
+    /// one post: the pusher transmits them in order on the slot it started
+    /// the first on, each the moment the one before it completes, and
+    /// reports the post complete after the last.
+    ///
+    /// This is how a wire refreshes faster than its poster renders — the
+    /// frames after the first are the poster's refreshes, presented without
+    /// it. The pusher ends a post early (completing it as transmitted) when
+    /// another wire is waiting for its slot, or when a [`Self::request_cut`]
+    /// covers it, so a refresh never delays another wire's frame or the
+    /// poster's next one by more than a frame. An abort or close disposes
+    /// of it as usual. A `count` of 0 posts one frame.
+    ///
+    /// # Safety
+    ///
+    /// [`Self::post`]'s contract over all `count * len` bytes.
+    pub unsafe fn post_frames(
+        &self,
+        gpio: u8,
+        ptr: *const u8,
+        len: usize,
+        count: u8,
+        now_us: u32,
+    ) -> u32 {
         let seq = self.posted_seq.load(Relaxed).wrapping_add(1);
         self.frame_ptr.store(ptr.cast_mut(), Relaxed);
         self.frame_len.store(len, Relaxed);
+        self.frame_count.store(count.max(1), Relaxed);
         self.gpio.store(gpio, Relaxed);
         self.posted_at_us.store(now_us, Relaxed);
         // The publish edge: everything above must be visible before the
@@ -259,6 +314,15 @@ impl WireMailbox {
         self.abort_req_seq.store(seq, Release);
     }
 
+    /// Ask the pusher to end post `seq` after the frame it is transmitting:
+    /// its remaining refresh frames are dropped and it completes as
+    /// [`WireOutcome::Transmitted`]. The poster's way to stop refreshing
+    /// when a new render is ready, so a refresh never delays it by more
+    /// than one frame of wire time. A no-op for a one-frame post.
+    pub fn request_cut(&self, seq: u32) {
+        self.cut_req_seq.store(seq, Release);
+    }
+
     /// Ask the pusher to quiesce the wire entirely. Returns the sequence the
     /// ack must reach; poll [`Self::close_acked`] with it. After the ack the
     /// wire may be reused (sequences continue, nothing resets).
@@ -296,6 +360,20 @@ impl WireMailbox {
             self.gpio.load(Relaxed),
         ))
     }
+
+    /// Frame `index` of the post the pusher is carrying, if the post has
+    /// one. Pusher-side helper: the descriptor was published before the
+    /// post's sequence, which the pusher `Acquire`d when it started the
+    /// first frame, and the poster cannot rewrite it until the post
+    /// completes.
+    fn frame_for_pusher(&self, index: u8) -> Option<(*const u8, usize)> {
+        if index >= self.frame_count.load(Relaxed) {
+            return None;
+        }
+        let len = self.frame_len.load(Relaxed);
+        let ptr = self.frame_ptr.load(Relaxed);
+        Some((ptr.wrapping_add(index as usize * len).cast_const(), len))
+    }
 }
 
 impl Default for WireMailbox {
@@ -354,6 +432,9 @@ pub struct Pusher<'d, H: RmtHw, P: PadOps, F: Fn() -> u32, const N: usize, const
     /// The wire's pending frame failed at least one acquire — when it
     /// finally starts, it counts as `waved`.
     waited: [bool; W],
+    /// Which frame of its post the wire is transmitting (0 for the post's
+    /// own, then its refreshes — see [`WireMailbox::post_frames`]).
+    frame_index: [u8; W],
     /// Round-robin scan origin for starts, so no wire starves behind
     /// lower-indexed siblings at two-wave occupancy.
     rr_next: usize,
@@ -397,6 +478,7 @@ impl<'d, H: RmtHw, P: PadOps, F: Fn() -> u32, const N: usize, const W: usize>
             wire_channel: [NO_CHANNEL; W],
             trips_at_start: [0; W],
             waited: [false; W],
+            frame_index: [0; W],
             rr_next: 0,
             transmitting: 0,
         }
@@ -444,14 +526,70 @@ impl<'d, H: RmtHw, P: PadOps, F: Fn() -> u32, const N: usize, const W: usize>
                         .torn
                         .fetch_add(torn.min(u32::MAX as usize) as u32, Relaxed);
                 }
+                progress = true;
+                if self.start_next_frame(wire, ch) {
+                    continue;
+                }
                 self.release_wire_slot(wire);
                 self.finish_wire(wire, self.started[wire], WireOutcome::Transmitted);
-                progress = true;
             }
         }
         progress
     }
 
+    /// Start the next frame of `wire`'s post on `ch`, the slot that just
+    /// completed the one before it; `false` ends the post instead. It ends
+    /// when it has no frames left, when a cut, abort or close covers it, or
+    /// when another wire is waiting for a slot — a refresh is a bonus frame
+    /// and never costs another wire its turn.
+    fn start_next_frame(&mut self, wire: usize, ch: u8) -> bool {
+        let mailbox = &self.mailboxes[wire];
+        let seq = self.started[wire];
+        if !seq_after(seq, mailbox.cut_req_seq.load(Acquire))
+            || !seq_after(seq, mailbox.close_req_seq.load(Acquire))
+            || !seq_after(seq, mailbox.abort_req_seq.load(Acquire))
+            || self.sibling_waiting(wire)
+        {
+            return false;
+        }
+        let next = self.frame_index[wire].wrapping_add(1);
+        let Some((ptr, len)) = mailbox.frame_for_pusher(next) else {
+            return false;
+        };
+        // SAFETY: forwarding `WireMailbox::post_frames`' contract — every
+        // frame of the post stays alive until its sequence completes, and it
+        // has not: the pusher publishes that only once it stops here.
+        let started = unsafe {
+            self.driver
+                .start_frame(ch, core::slice::from_raw_parts(ptr, len))
+        };
+        if started.is_err() {
+            // The post's own frame reached the strip; a refresh the slot
+            // refused just ends the post early.
+            return false;
+        }
+        self.frame_index[wire] = next;
+        self.trips_at_start[wire] = self.driver.stats(ch).guard_trips;
+        mailbox.refreshed.fetch_add(1, Relaxed);
+        true
+    }
+
+    /// Would another wire's posted frame start if `wire` gave up its slot?
+    /// Only a full house counts: with a free slot under the cap, the other
+    /// wire starts regardless.
+    fn sibling_waiting(&self, wire: usize) -> bool {
+        let slot_free = self.transmitting < self.cap
+            && self.slots[..self.slot_count].iter().any(|slot| !slot.busy);
+        !slot_free
+            && (0..W).any(|other| {
+                other != wire
+                    && self.wire_channel[other] == NO_CHANNEL
+                    && self.mailboxes[other]
+                        .pending_for_pusher(self.started[other])
+                        .is_some()
+            })
+    }
+
     fn service_requests(&mut self) -> bool {
         let mut progress = false;
         // Copy the `'d` reference out of `self` so the mailbox borrows do
@@ -563,6 +701,7 @@ impl<'d, H: RmtHw, P: PadOps, F: Fn() -> u32, const N: usize, const W: usize>
                     self.slots[slot_idx].busy = true;
                     self.wire_channel[wire] = ch;
                     self.started[wire] = seq;
+                    self.frame_index[wire] = 0;
                     self.trips_at_start[wire] = self.driver.stats(ch).guard_trips;
                     self.transmitting += 1;
                     mailbox.active_channel.store(ch, Relaxed);
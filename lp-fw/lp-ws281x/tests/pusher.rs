@@ -15,7 +15,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use std::vec::Vec;
 
-use common::ramp_frame;
+use common::{expected_words, ramp_frame};
 use lp_ws281x::{ChannelTiming, MockRmt, PadOps, Pusher, WireMailbox, WireOutcome, Ws281xDriver};
 
 /// Slot channels used by most tests: all four MockRmt channels.
@@ -503,3 +503,129 @@ fn unconfigured_slot_reports_start_failed() {
     );
     drop(frame);
 }
+
+/// `count` distinct frames of `pixels` pixels, back to back — one post's
+/// worth for [`WireMailbox::post_frames`].
+fn refresh_frames(pixels: usize, count: u8) -> Vec<Vec<u8>> {
+    (0..count)
+        .map(|k| {
+            ramp_frame(pixels)
+                .into_iter()
+                .map(|byte| byte.wrapping_add(k * 29))
+                .collect()
+        })
+        .collect()
+}
+
+/// One post of three frames on a single wire: the pusher presents all three
+/// back to back with nothing posted in between — two refreshes for one
+/// render — and reports the post complete only after the last.
+#[test]
+fn a_post_refreshes_the_wire_with_each_of_its_frames() {
+    let driver = driver();
+    let mailboxes: [WireMailbox; 1] = core::array::from_fn(|_| WireMailbox::new());
+    let mut pusher = Pusher::new(
+        &driver,
+        &mailboxes,
+        RecorderPads::default(),
+        || 0,
+        &SLOTS,
+        4,
+    );
+
+    let frames = refresh_frames(4, 3);
+    let bytes: Box<[u8]> = frames.concat().into_boxed_slice();
+    // SAFETY: `bytes` outlives the post — drained before the drop.
+    let seq = unsafe { mailboxes[0].post_frames(10, bytes.as_ptr(), frames[0].len(), 3, 0) };
+    pusher.service();
+    for _ in 0..10_000 {
+        if mailboxes[0].completed_outcome(seq).is_some() {
+            break;
+        }
+        tick(&driver, &mut pusher);
+    }
+
+    assert_eq!(
+        mailboxes[0].completed_outcome(seq),
+        Some(WireOutcome::Transmitted)
+    );
+    assert_eq!(driver.stats(0).frames, 3, "every frame reached tx_end");
+    let expected: Vec<u32> = frames
+        .iter()
+        .flat_map(|frame| expected_words(frame, &ChannelTiming::WS2812))
+        .collect();
+    assert_eq!(driver.hw().emitted(0), expected, "in order, each whole");
+    let stats = mailboxes[0].wire_stats();
+    assert_eq!((stats.transmitted, stats.refreshed), (1, 2));
+    assert_eq!(pusher.transmitting(), 0);
+    drop(bytes);
+}
+
+/// Refreshes never cost another wire its turn: with one slot, a wire
+/// waiting behind a multi-frame post ends that post after its own frame.
+#[test]
+fn refresh_frames_yield_the_slot_to_a_waiting_wire() {
+    let driver = driver();
+    let mailboxes: [WireMailbox; 2] = core::array::from_fn(|_| WireMailbox::new());
+    let mut pusher = Pusher::new(
+        &driver,
+        &mailboxes,
+        RecorderPads::default(),
+        || 0,
+        &SLOTS[..1],
+        1,
+    );
+
+    let frames = refresh_frames(4, 3);
+    let bytes: Box<[u8]> = frames.concat().into_boxed_slice();
+    let other: Box<[u8]> = ramp_frame(4).into_boxed_slice();
+    // SAFETY: both buffers outlive their posts — drained before the drops.
+    let seq_a = unsafe { mailboxes[0].post_frames(10, bytes.as_ptr(), frames[0].len(), 3, 0) };
+    pusher.service();
+    let seq_b = unsafe { mailboxes[1].post(11, other.as_ptr(), other.len(), 0) };
+    drain(&driver, &mut pusher, &mailboxes, &[seq_a, seq_b]);
+
+    assert_eq!(
+        mailboxes[0].completed_outcome(seq_a),
+        Some(WireOutcome::Transmitted)
+    );
+    assert_eq!(
+        mailboxes[1].completed_outcome(seq_b),
+        Some(WireOutcome::Transmitted)
+    );
+    assert_eq!(mailboxes[0].wire_stats().refreshed, 0);
+    assert_eq!(driver.stats(0).frames, 2);
+    drop((bytes, other));
+}
+
+/// A cut ends the run after the frame on the wire: the poster's next render
+/// waits for at most one frame, not for the refreshes it no longer needs.
+#[test]
+fn a_cut_ends_the_refresh_run_after_the_frame_on_the_wire() {
+    let driver = driver();
+    let mailboxes: [WireMailbox; 1] = core::array::from_fn(|_| WireMailbox::new());
+    let mut pusher = Pusher::new(
+        &driver,
+        &mailboxes,
+        RecorderPads::default(),
+        || 0,
+        &SLOTS,
+        4,
+    );
+
+    let frames = refresh_frames(4, 3);
+    let bytes: Box<[u8]> = frames.concat().into_boxed_slice();
+    // SAFETY: `bytes` outlives the post — drained before the drop.
+    let seq = unsafe { mailboxes[0].post_frames(10, bytes.as_ptr(), frames[0].len(), 3, 0) };
+    pusher.service();
+    mailboxes[0].request_cut(seq);
+    drain(&driver, &mut pusher, &mailboxes, &[seq]);
+
+    assert_eq!(
+        mailboxes[0].completed_outcome(seq),
+        Some(WireOutcome::Transmitted)
+    );
+    assert_eq!(driver.stats(0).frames, 1, "the frame on the wire finished");
+    assert_eq!(mailboxes[0].wire_stats().refreshed, 0);
+    drop(bytes);
+}
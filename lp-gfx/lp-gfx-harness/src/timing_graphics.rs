@@ -65,8 +65,13 @@ impl LpGraphics for TimingGraphics {
         self.inner.native_semantics()
     }
 
-    fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError> {
-        self.inner.create_render_target(width, height)
+    fn create_render_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError> {
+        self.inner.create_render_target(width, height, format)
     }
 
     fn create_texture(
@@ -101,6 +106,14 @@ impl LpGraphics for TimingGraphics {
         self.inner.blend_textures(previous, active, alpha, target)
     }
 
+    fn copy_texture(
+        &self,
+        source: &TextureHandle,
+        target: &mut TextureHandle,
+    ) -> Result<(), GfxError> {
+        self.inner.copy_texture(source, target)
+    }
+
     fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError> {
         self.inner.read_back(texture)
     }
@@ -29,3 +29,8 @@ pub mod target_backend;
 
 pub use lpvm_graphics::LpvmGraphics;
 pub use target_backend::{TargetLpvmEngine, TargetLpvmGraphics};
+
+/// The native JIT's persistent code cache, for
+/// [`TargetLpvmGraphics::with_code_cache`].
+#[cfg(any(target_arch = "riscv32", target_arch = "xtensa"))]
+pub use lpvm_native::code_cache::{CodeCache, JitCodeCache};
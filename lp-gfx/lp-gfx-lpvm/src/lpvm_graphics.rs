@@ -97,15 +97,11 @@ where
             )));
         }
         let cfg = options.to_compiler_config();
-        let mut desc = CompilePxDesc::new(
-            source,
-            lps_shared::TextureStorageFormat::Rgba16Unorm,
-            cfg,
-            options.frontend,
-        )
-        .with_float_mode(float_mode)
-        .with_space(options.space);
+        let mut desc = CompilePxDesc::new(source, options.output_format, cfg, options.frontend)
+            .with_float_mode(float_mode)
+            .with_space(options.space);
         desc.textures = options.textures.clone();
+        desc.source_map = options.source_map.as_ref();
         let px = self
             .shared
             .engine
@@ -144,11 +140,16 @@ where
         ShaderSemantics::F32Cpu
     }
 
-    fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError> {
+    fn create_render_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError> {
         let buffer = self
             .shared
             .engine
-            .alloc_texture(width, height, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .alloc_texture(width, height, format)
             .map_err(|e| GfxError::Alloc(format!("alloc texture: {e:?}")))?;
         Ok(self.texture_handle(buffer))
     }
@@ -231,6 +232,24 @@ where
         Ok(())
     }
 
+    fn copy_texture(
+        &self,
+        source: &TextureHandle,
+        target: &mut TextureHandle,
+    ) -> Result<(), GfxError> {
+        if source.width() != target.width()
+            || source.height() != target.height()
+            || source.format() != target.format()
+        {
+            return Err(GfxError::Backend(String::from(
+                "copy_texture: texture shape mismatch",
+            )));
+        }
+        let source = texture_buf(source)?.data();
+        texture_buf_mut(target)?.data_mut().copy_from_slice(source);
+        Ok(())
+    }
+
     fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError> {
         let buffer = texture_buf(texture)?;
         Ok(TextureData::new(
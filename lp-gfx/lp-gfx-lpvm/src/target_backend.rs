@@ -43,17 +43,33 @@ impl TargetLpvmGraphics {
     /// [`lp_gfx::LpGraphics::glsl_frontend`]).
     #[must_use]
     pub fn new(frontend: ShaderFrontend) -> Self {
-        lps_builtins::ensure_builtins_referenced();
-        let mut table = lpvm_native::BuiltinTable::new();
-        table.populate();
-        let backend = lpvm_native::NativeJitEngine::new(
-            alloc::sync::Arc::new(table),
-            lpvm_native::NativeCompileOptions::default(),
-        );
+        Self::from_engine(native_jit_engine(), "lpvm-native::rt_jit", frontend)
+    }
+
+    /// [`Self::new`] with emitted machine code kept in `cache` and reused
+    /// across boots (see [`lpvm_native::code_cache`]). The embedder owns the
+    /// cache's build identity, and builds none for a dirty tree.
+    #[must_use]
+    pub fn with_code_cache(
+        frontend: ShaderFrontend,
+        cache: alloc::sync::Arc<dyn lpvm_native::code_cache::JitCodeCache>,
+    ) -> Self {
+        let backend = native_jit_engine().with_code_cache(cache);
         Self::from_engine(backend, "lpvm-native::rt_jit", frontend)
     }
 }
 
+#[cfg(any(target_arch = "riscv32", target_arch = "xtensa"))]
+fn native_jit_engine() -> lpvm_native::NativeJitEngine {
+    lps_builtins::ensure_builtins_referenced();
+    let mut table = lpvm_native::BuiltinTable::new();
+    table.populate();
+    lpvm_native::NativeJitEngine::new(
+        alloc::sync::Arc::new(table),
+        lpvm_native::NativeCompileOptions::default(),
+    )
+}
+
 /// Wasm32 guest (`lpvm-wasm` `rt_browser`): runs emitted shader WASM via the
 /// host JS `WebAssembly.Module` / `Instance` API.
 #[cfg(target_arch = "wasm32")]
@@ -46,6 +46,7 @@ use std::fmt::Write as _;
 use lp_gfx::GfxError;
 use lp_shader::{ShaderEntrySpace, TextureBindingSpecs};
 use lps_builtins::canonical_glsl::{CANONICAL_GLSL, CanonicalGlsl};
+use lps_shared::TextureStorageFormat;
 
 use crate::texture_lowering::lower_texture_calls;
 
@@ -56,7 +57,7 @@ const FRAG_OUT: &str = "lp_gfx_frag_color";
 const SAMPLE_POS_IN: &str = "lp_gfx_sample_pos";
 
 /// Assemble the full fragment-stage GLSL for an authored pixel shader
-/// declaring `space`.
+/// declaring `space` and rendering `output_format`.
 ///
 /// `textures` is the compile-time [`lps_shared::TextureBindingSpec`] map
 /// keyed by sampler uniform leaf path; sampling call sites are lowered
@@ -65,6 +66,7 @@ pub fn assemble_fragment_glsl(
     authored: &str,
     textures: &TextureBindingSpecs,
     space: ShaderEntrySpace,
+    output_format: TextureStorageFormat,
 ) -> Result<String, GfxError> {
     let mut out = assembled_unit_prefix(authored, textures)?;
     // A 1D target is one row, so the raster x coordinate is the whole
@@ -73,6 +75,7 @@ pub fn assemble_fragment_glsl(
         ShaderEntrySpace::TwoD => "render_2d(floor(gl_FragCoord.xy))",
         ShaderEntrySpace::OneD => "render_1d(floor(gl_FragCoord.x))",
     };
+    let call = widen_entry_result(call, output_format);
     let _ = write!(
         out,
         "\nlayout(location = 0) out vec4 {FRAG_OUT};\n\
@@ -81,6 +84,19 @@ pub fn assemble_fragment_glsl(
     Ok(out)
 }
 
+/// The entry call widened to the `vec4` fragment output. The entry returns
+/// as many channels as `output_format` stores (`float`, `vec3` or `vec4`);
+/// a single channel is splatted to grey, which is how the CPU samplers
+/// read an `R8Unorm` / `R16Unorm` texel, and the attachment keeps only the
+/// channels its backing has.
+fn widen_entry_result(call: &str, output_format: TextureStorageFormat) -> String {
+    match output_format.channel_count() {
+        1 => format!("vec4(vec3({call}), 1.0)"),
+        3 => format!("vec4({call}, 1.0)"),
+        _ => String::from(call),
+    }
+}
+
 /// Everything of the compilation unit before the generated `main`: version,
 /// lpfn prelude, hoisted struct/const declarations, texture-lowering
 /// helpers, generated prototypes, the (rewritten) authored text, and helper
@@ -124,17 +140,19 @@ pub fn assemble_sample_fragment_glsl(
     authored: &str,
     textures: &TextureBindingSpecs,
     space: ShaderEntrySpace,
+    output_format: TextureStorageFormat,
 ) -> Result<String, GfxError> {
     let mut out = assembled_unit_prefix(authored, textures)?;
     let (varying_ty, entry) = match space {
         ShaderEntrySpace::TwoD => ("vec2", "render_2d"),
         ShaderEntrySpace::OneD => ("float", "render_1d"),
     };
+    let call = widen_entry_result(&format!("{entry}({SAMPLE_POS_IN})"), output_format);
     let _ = write!(
         out,
         "\nlayout(location = 0) in {varying_ty} {SAMPLE_POS_IN};\n\
          layout(location = 0) out vec4 {FRAG_OUT};\n\
-         void main() {{\n    {FRAG_OUT} = {entry}({SAMPLE_POS_IN});\n}}\n"
+         void main() {{\n    {FRAG_OUT} = {call};\n}}\n"
     );
     Ok(out)
 }
@@ -623,6 +641,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         let struct_at = unit.find("struct Point").expect("struct hoisted");
@@ -645,6 +664,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         // Only the generated prototype survives (naga rejects duplicates),
@@ -687,6 +707,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         assert!(unit.starts_with("#version 450 core\n"));
@@ -708,6 +729,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::OneD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         assert!(unit.contains("render_1d(floor(gl_FragCoord.x))"), "{unit}");
@@ -729,6 +751,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::OneD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         assert!(
@@ -746,6 +769,7 @@ float sum(float arr[N]) { return arr[0] + arr[1]; }
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("assembles");
         assert!(unit.starts_with("#version 450 core\n"));
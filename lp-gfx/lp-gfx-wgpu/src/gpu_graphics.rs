@@ -164,6 +164,7 @@ impl GpuGraphics {
             source,
             textures,
             lp_shader::ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         );
         let scope_err = pollster::block_on(scope.pop());
         match (result, scope_err) {
@@ -228,6 +229,7 @@ impl LpGraphics for GpuGraphics {
             source,
             &options.textures,
             options.space,
+            options.output_format,
         )?))
     }
 
@@ -253,8 +255,12 @@ impl LpGraphics for GpuGraphics {
         lp_shader::ShaderFrontend::Naga
     }
 
-    fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError> {
-        let format = TextureStorageFormat::Rgba16Unorm;
+    fn create_render_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError> {
         let backing = GpuTexture::new(
             &self.shared.device,
             width,
@@ -325,6 +331,41 @@ impl LpGraphics for GpuGraphics {
         blend_textures_gpu(&self.shared, previous, active, alpha, target)
     }
 
+    /// A plain `copy_texture_to_texture`: same-shape handles share a backing
+    /// format, so there is no pass to run.
+    fn copy_texture(
+        &self,
+        source: &TextureHandle,
+        target: &mut TextureHandle,
+    ) -> Result<(), GfxError> {
+        if source.width() != target.width()
+            || source.height() != target.height()
+            || source.format() != target.format()
+        {
+            return Err(GfxError::Backend(String::from(
+                "copy_texture: texture shape mismatch",
+            )));
+        }
+        let (width, height) = (source.width(), source.height());
+        let source = gpu_texture(source)?;
+        let target = gpu_texture(target)?;
+        let mut encoder = self
+            .shared
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_texture(
+            source.texture.as_image_copy(),
+            target.texture.as_image_copy(),
+            wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+        );
+        self.shared.queue.submit([encoder.finish()]);
+        Ok(())
+    }
+
     fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError> {
         read_back_texture(
             &self.shared.device,
@@ -552,7 +593,9 @@ void tick() {
                 &options,
             )
             .expect("compiles");
-        let mut target = graphics.create_render_target(4, 4).expect("target");
+        let mut target = graphics
+            .create_render_target(4, 4, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         let uniforms = LpsValueF32::Struct {
             name: None,
             fields: vec![(String::from("outputSize"), LpsValueF32::Vec2([4.0, 4.0]))],
@@ -575,6 +618,72 @@ void tick() {
         assert_eq!(channels[px + 1], 16384);
     }
 
+    #[test]
+    fn narrow_output_formats_render_at_their_texel_size() {
+        let Some(graphics) = test_graphics() else {
+            eprintln!("SKIP: no GPU adapter available");
+            return;
+        };
+        // The entry returns the format's channel count; readback packs
+        // bytes with the CPU rule, so 4×2 pixels cost 8 (R8) / 24 (Rgb8)
+        // bytes rather than RGBA16's 64.
+        let cases: [(TextureStorageFormat, &str, &[u8]); 2] = [
+            (
+                TextureStorageFormat::R8Unorm,
+                "float render_2d(vec2 pos) { return 0.5; }\n",
+                &[128],
+            ),
+            (
+                TextureStorageFormat::Rgb8Unorm,
+                "vec3 render_2d(vec2 pos) { return vec3(0.25, 0.5, 0.75); }\n",
+                &[64, 128, 192],
+            ),
+        ];
+        let uniforms = LpsValueF32::Struct {
+            name: None,
+            fields: vec![],
+        };
+        for (format, source, pixel) in cases {
+            let options =
+                ShaderCompileOptions::new(ShaderSemantics::F32Gpu, lp_shader::ShaderFrontend::Naga)
+                    .with_output_format(format);
+            let mut shader = graphics
+                .compile_shader(source, &options)
+                .unwrap_or_else(|e| panic!("{format:?} compiles: {e}"));
+            let mut target = graphics.create_render_target(4, 2, format).expect("target");
+            shader.render(&mut target, &uniforms).expect("renders");
+            let data = graphics.read_back(&target).expect("read back");
+            assert_eq!(data.bytes(), &pixel.repeat(4 * 2)[..], "{format:?}");
+        }
+    }
+
+    #[test]
+    fn render_rejects_a_target_of_another_format() {
+        let Some(graphics) = test_graphics() else {
+            eprintln!("SKIP: no GPU adapter available");
+            return;
+        };
+        let options =
+            ShaderCompileOptions::new(ShaderSemantics::F32Gpu, lp_shader::ShaderFrontend::Naga)
+                .with_output_format(TextureStorageFormat::R8Unorm);
+        let mut shader = graphics
+            .compile_shader("float render_2d(vec2 pos) { return 0.5; }\n", &options)
+            .expect("compiles");
+        let mut target = graphics
+            .create_render_target(2, 2, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
+        let uniforms = LpsValueF32::Struct {
+            name: None,
+            fields: vec![],
+        };
+        match shader.render(&mut target, &uniforms) {
+            Err(GfxError::Render(message)) => {
+                assert!(message.contains("R8Unorm"), "{message}");
+            }
+            other => panic!("expected format-mismatch render error, got {other:?}"),
+        }
+    }
+
     #[test]
     fn missing_uniform_errors_at_render_time() {
         let Some(graphics) = test_graphics() else {
@@ -590,7 +699,9 @@ void tick() {
                 &options,
             )
             .expect("compiles");
-        let mut target = graphics.create_render_target(2, 2).expect("target");
+        let mut target = graphics
+            .create_render_target(2, 2, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         let uniforms = LpsValueF32::Struct {
             name: None,
             fields: vec![],
@@ -637,7 +748,9 @@ void tick() {
                         &rgba16_bytes(&active),
                     )
                     .expect("active");
-                let mut target = graphics.create_render_target(2, 2).expect("target");
+                let mut target = graphics
+                    .create_render_target(2, 2, TextureStorageFormat::Rgba16Unorm)
+                    .expect("target");
                 graphics
                     .blend_textures(&previous_tex, &active_tex, alpha, &mut target)
                     .expect("blend");
@@ -660,6 +773,49 @@ void tick() {
         }
     }
 
+    #[test]
+    fn copy_texture_matches_the_cpu_tier_exactly() {
+        let Some(graphics) = test_graphics() else {
+            eprintln!("SKIP: no GPU adapter available");
+            return;
+        };
+        let cpu = TargetLpvmGraphics::new(lp_shader::ShaderFrontend::Naga);
+        let texels: Vec<u16> = (0..16u32)
+            .map(|i| (i.wrapping_mul(40503) % 65536) as u16)
+            .collect();
+
+        let copy_on = |graphics: &dyn LpGraphics| -> Vec<u8> {
+            let source = graphics
+                .create_texture(
+                    2,
+                    2,
+                    TextureStorageFormat::Rgba16Unorm,
+                    &rgba16_bytes(&texels),
+                )
+                .expect("source");
+            let mut target = graphics
+                .create_render_target(2, 2, TextureStorageFormat::Rgba16Unorm)
+                .expect("target");
+            graphics.copy_texture(&source, &mut target).expect("copy");
+            graphics
+                .read_back(&target)
+                .expect("read back")
+                .bytes()
+                .to_vec()
+        };
+        let gpu_result = copy_on(&graphics);
+        assert_eq!(gpu_result, rgba16_bytes(&texels));
+        assert_eq!(gpu_result, copy_on(&cpu));
+
+        let small = graphics
+            .create_render_target(1, 1, TextureStorageFormat::Rgba16Unorm)
+            .expect("small");
+        let mut large = graphics
+            .create_render_target(2, 2, TextureStorageFormat::Rgba16Unorm)
+            .expect("large");
+        assert!(graphics.copy_texture(&small, &mut large).is_err());
+    }
+
     #[test]
     fn texture_uniform_renders_fetched_texels_exactly() {
         let Some(graphics) = test_graphics() else {
@@ -693,7 +849,9 @@ void tick() {
             )
             .expect("compiles");
 
-        let mut target = graphics.create_render_target(2, 1).expect("target");
+        let mut target = graphics
+            .create_render_target(2, 1, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         let uniforms = LpsValueF32::Struct {
             name: None,
             fields: vec![(
@@ -732,7 +890,9 @@ void tick() {
         let source = "uniform sampler2D t;\n\
                       vec4 render_2d(vec2 pos) { return texture(t, pos); }\n";
         let mut shader = graphics.compile_shader(source, &options).expect("compiles");
-        let mut target = graphics.create_render_target(1, 1).expect("target");
+        let mut target = graphics
+            .create_render_target(1, 1, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
 
         // Missing texture uniform field.
         let missing = LpsValueF32::Struct {
@@ -923,7 +1083,9 @@ void tick() {
                 &options,
             )
             .expect("compiles");
-        let mut target = graphics.create_render_target(4, 1).expect("target");
+        let mut target = graphics
+            .create_render_target(4, 1, TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         let uniforms = LpsValueF32::Struct {
             name: None,
             fields: vec![(String::from("outputSize"), LpsValueF32::Vec2([4.0, 1.0]))],
@@ -23,7 +23,8 @@
 //! # GPU residency
 //!
 //! Render products stay GPU-resident: transforms run behind trait ops
-//! (`blend_textures` — a small fixed pipeline); `read_back` is for sinks
+//! (`blend_textures` — a small fixed pipeline; `copy_texture` — a
+//! texture-to-texture copy); `read_back` is for sinks
 //! that inherently need bytes and is native-only (explicit error on the
 //! browser tier). See the crate README for the full policy.
 
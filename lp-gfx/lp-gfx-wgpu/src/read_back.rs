@@ -2,7 +2,7 @@
 //!
 //! `read_back` exists for sinks that inherently need bytes (fixture
 //! sampling, wire probes) — never for transforms, which belong behind
-//! GPU-resident trait ops like `blend_textures`.
+//! GPU-resident trait ops like `blend_textures` and `copy_texture`.
 //!
 //! - **native**: copy to a mapped buffer and block on
 //!   `device.poll(wait)` — bounded and synchronous; the native server host
@@ -46,6 +46,9 @@ pub struct GpuShader {
     /// Declared space of the authored shader: which entry the wrappers
     /// call, and how many lanes a packed sample point carries.
     space: ShaderEntrySpace,
+    /// Storage format of the targets this pipeline renders into; its
+    /// colour attachment is that format's backing (`gpu_format`).
+    output_format: TextureStorageFormat,
     /// Sample-point pass (the LED-output path), built on the first
     /// `sample_rgba16` call — render-only consumers never pay for it.
     sample_pass: Option<SamplePass>,
@@ -82,13 +85,17 @@ impl GpuShader {
     /// Compile authored GLSL into a render pipeline on `shared`'s device.
     /// `textures` is the compile-time `TextureBindingSpec` map (shared
     /// contract with the CPU tier; mismatches fail compilation).
+    /// `output_format` fixes the pipeline's colour attachment: every format
+    /// has a renderable float backing, and the byte and single-channel
+    /// formats are quantized on readback like the CPU tier's writes.
     pub(crate) fn new(
         shared: Arc<GpuShared>,
         authored: &str,
         textures: &TextureBindingSpecs,
         space: ShaderEntrySpace,
+        output_format: TextureStorageFormat,
     ) -> Result<Self, GfxError> {
-        let compiled = compile_wgsl(authored, textures, space)?;
+        let compiled = compile_wgsl(authored, textures, space, output_format)?;
         let table = reflect_uniforms(&compiled.module)?;
         let texture_globals = reflect_textures(&compiled.module, textures)?;
         let device = &shared.device;
@@ -208,7 +215,7 @@ impl GpuShader {
                 entry_point: Some("main"),
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: gpu_format(TextureStorageFormat::Rgba16Unorm),
+                    format: gpu_format(output_format),
                     blend: None,
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
@@ -227,6 +234,7 @@ impl GpuShader {
             bindings,
             texture_specs: textures.clone(),
             space,
+            output_format,
             sample_pass: None,
         })
     }
@@ -241,6 +249,7 @@ impl GpuShader {
                 &self.authored,
                 &self.texture_specs,
                 self.space,
+                self.output_format,
             )?;
             let sample_table = reflect_uniforms(&compiled.module)?;
             let interface = |table: &UniformTable| -> Vec<(String, u32, u32)> {
@@ -440,9 +449,10 @@ impl LpShader for GpuShader {
         target: &mut TextureHandle,
         uniforms: &LpsValueF32,
     ) -> Result<(), GfxError> {
-        if target.format() != TextureStorageFormat::Rgba16Unorm {
+        if target.format() != self.output_format {
             return Err(GfxError::Render(format!(
-                "GPU shader renders RGBA16 targets; got {:?}",
+                "GPU shader renders {:?} targets; got {:?}",
+                self.output_format,
                 target.format()
             )));
         }
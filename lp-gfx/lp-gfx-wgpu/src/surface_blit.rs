@@ -328,7 +328,9 @@ mod tests {
                 lp_shader::ShaderFrontend::Naga,
             )),
         );
-        let product = graphics.create_render_target(1, 1).expect("target");
+        let product = graphics
+            .create_render_target(1, 1, lps_shared::TextureStorageFormat::Rgba16Unorm)
+            .expect("target");
         let stand_in = device.create_texture(&wgpu::TextureDescriptor {
             label: Some("srgb stand-in"),
             size: wgpu::Extent3d {
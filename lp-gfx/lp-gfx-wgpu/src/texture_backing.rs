@@ -120,31 +120,40 @@ impl GpuTexture {
 /// wgpu backing format for a logical texture format.
 pub(crate) fn gpu_format(format: TextureStorageFormat) -> wgpu::TextureFormat {
     match format {
-        TextureStorageFormat::Rgba16Unorm | TextureStorageFormat::Rgb16Unorm => {
-            wgpu::TextureFormat::Rgba32Float
+        TextureStorageFormat::Rgba16Unorm
+        | TextureStorageFormat::Rgb16Unorm
+        | TextureStorageFormat::Rgb8Unorm => wgpu::TextureFormat::Rgba32Float,
+        TextureStorageFormat::R16Unorm | TextureStorageFormat::R8Unorm => {
+            wgpu::TextureFormat::R32Float
         }
-        TextureStorageFormat::R16Unorm => wgpu::TextureFormat::R32Float,
     }
 }
 
 /// Channels stored per pixel in the GPU backing (≥ the logical channel
-/// count; `Rgb16Unorm` pads an alpha lane).
+/// count; the RGB formats pad an alpha lane).
 pub(crate) fn gpu_channels(format: TextureStorageFormat) -> usize {
     match format {
-        TextureStorageFormat::Rgba16Unorm | TextureStorageFormat::Rgb16Unorm => 4,
-        TextureStorageFormat::R16Unorm => 1,
+        TextureStorageFormat::Rgba16Unorm
+        | TextureStorageFormat::Rgb16Unorm
+        | TextureStorageFormat::Rgb8Unorm => 4,
+        TextureStorageFormat::R16Unorm | TextureStorageFormat::R8Unorm => 1,
     }
 }
 
-/// Convert logical unorm16 texel bytes to backing floats (`v / 65536`,
-/// exact; `Rgb16Unorm` pads alpha with 1.0).
+/// Convert logical texel bytes to backing floats (`v / 65536`, exact; the
+/// RGB formats pad alpha with 1.0). Byte channels widen to unorm16 as
+/// `raw * 257` first, as the CPU samplers do.
 pub(crate) fn texels_to_f32(format: TextureStorageFormat, texels: &[u8]) -> Vec<f32> {
     let logical = format.channel_count();
     let backing = gpu_channels(format);
-    let mut out = Vec::with_capacity(texels.len() / 2 / logical * backing);
-    for pixel in texels.chunks_exact(2 * logical) {
-        for channel in pixel.chunks_exact(2) {
-            let v = u16::from_le_bytes([channel[0], channel[1]]);
+    let channel_bytes = format.bytes_per_channel();
+    let mut out = Vec::with_capacity(texels.len() / channel_bytes / logical * backing);
+    for pixel in texels.chunks_exact(channel_bytes * logical) {
+        for channel in pixel.chunks_exact(channel_bytes) {
+            let v = match channel {
+                [byte] => u16::from(*byte) * 257,
+                _ => u16::from_le_bytes([channel[0], channel[1]]),
+            };
             out.push(f32::from(v) / 65536.0);
         }
         for _ in logical..backing {
@@ -154,17 +163,23 @@ pub(crate) fn texels_to_f32(format: TextureStorageFormat, texels: &[u8]) -> Vec<
     out
 }
 
-/// Quantize backing floats to logical unorm16 texel bytes with the CPU
-/// path's exact packing rule: `trunc(v · 65536)` saturated to `[0, 65535]`
-/// (1.0 maps to 65535; non-finite lanes map to 0, matching the spike).
+/// Quantize backing floats to logical texel bytes with the CPU path's exact
+/// packing rule: `trunc(v · 65536)` saturated to `[0, 65535]` (1.0 maps to
+/// 65535; non-finite lanes map to 0, matching the spike), and for byte
+/// channels `trunc(v · 256)` saturated to `[0, 255]`.
 #[cfg(not(target_arch = "wasm32"))]
 pub(crate) fn f32_to_texels(format: TextureStorageFormat, pixels: &[f32]) -> Vec<u8> {
     let logical = format.channel_count();
     let backing = gpu_channels(format);
-    let mut out = Vec::with_capacity(pixels.len() / backing * logical * 2);
+    let channel_bytes = format.bytes_per_channel();
+    let mut out = Vec::with_capacity(pixels.len() / backing * logical * channel_bytes);
     for pixel in pixels.chunks_exact(backing) {
         for &v in &pixel[..logical] {
-            out.extend_from_slice(&quantize_unorm16(v).to_le_bytes());
+            if channel_bytes == 1 {
+                out.push(quantize_unorm8(v));
+            } else {
+                out.extend_from_slice(&quantize_unorm16(v).to_le_bytes());
+            }
         }
     }
     out
@@ -176,6 +191,13 @@ pub(crate) fn quantize_unorm16(v: f32) -> u16 {
     raw.clamp(0.0, 65535.0) as u16
 }
 
+/// The byte-channel packing rule: the Q32 `FtoUnorm8` shift, `q >> 8`.
+#[cfg(not(target_arch = "wasm32"))]
+fn quantize_unorm8(v: f32) -> u8 {
+    let raw = (f64::from(v) * 256.0).floor();
+    raw.clamp(0.0, 255.0) as u8
+}
+
 /// Downcast a texture handle to its GPU backing.
 pub(crate) fn gpu_texture(handle: &TextureHandle) -> Result<&GpuTexture, GfxError> {
     handle
@@ -231,4 +253,24 @@ mod tests {
             assert_eq!(back, bytes, "{format:?} round trip");
         }
     }
+
+    #[test]
+    fn byte_texels_widen_like_the_cpu_samplers_and_round_trip() {
+        for format in [
+            TextureStorageFormat::Rgb8Unorm,
+            TextureStorageFormat::R8Unorm,
+        ] {
+            let bytes: Vec<u8> = (0..format.channel_count() * 3)
+                .map(|i| [0u8, 1, 127, 128, 254, 255][i % 6])
+                .collect();
+            let floats = texels_to_f32(format, &bytes);
+            assert_eq!(floats.len(), 3 * gpu_channels(format), "{format:?}");
+            assert_eq!(floats[1], f32::from(257u16) / 65536.0, "{format:?}");
+            assert_eq!(
+                f32_to_texels(format, &floats),
+                bytes,
+                "{format:?} round trip"
+            );
+        }
+    }
 }
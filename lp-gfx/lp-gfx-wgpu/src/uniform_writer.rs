@@ -272,6 +272,7 @@ mod tests {
     use super::*;
     use crate::uniform_layout::reflect_uniforms;
     use crate::wgsl_compile::compile_wgsl;
+    use lps_shared::TextureStorageFormat;
 
     fn encode_shader(
         authored: &str,
@@ -281,6 +282,7 @@ mod tests {
             authored,
             &lp_shader::TextureBindingSpecs::new(),
             lp_shader::ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("shader compiles");
         let table = reflect_uniforms(&shader.module).expect("uniforms reflect");
@@ -334,6 +336,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return vec4(blk.a + vec3(blk.b, 0.0), 1.0); }\n",
             &lp_shader::TextureBindingSpecs::new(),
             lp_shader::ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("compiles");
         let table = reflect_uniforms(&shader.module).expect("reflects");
@@ -371,6 +374,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return vec4(blk.m * vec3(pos, blk.tail), 1.0); }\n",
             &lp_shader::TextureBindingSpecs::new(),
             lp_shader::ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("compiles");
         let table = reflect_uniforms(&shader.module).expect("reflects");
@@ -406,6 +410,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return vec4(blk.inner.v * blk.inner.x, blk.after); }\n",
             &lp_shader::TextureBindingSpecs::new(),
             lp_shader::ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("compiles");
         let table = reflect_uniforms(&shader.module).expect("reflects");
@@ -6,6 +6,7 @@
 
 use lp_gfx::GfxError;
 use lp_shader::{ShaderEntrySpace, TextureBindingSpecs};
+use lps_shared::TextureStorageFormat;
 
 use crate::assembly::{assemble_fragment_glsl, assemble_sample_fragment_glsl};
 use crate::tanh_pass::bound_tanh;
@@ -36,8 +37,14 @@ pub fn compile_wgsl(
     authored: &str,
     textures: &TextureBindingSpecs,
     space: ShaderEntrySpace,
+    output_format: TextureStorageFormat,
 ) -> Result<WgslShader, GfxError> {
-    translate_assembled_glsl(assemble_fragment_glsl(authored, textures, space)?)
+    translate_assembled_glsl(assemble_fragment_glsl(
+        authored,
+        textures,
+        space,
+        output_format,
+    )?)
 }
 
 /// Translate the sample-point variant of an authored pixel shader: the same
@@ -47,8 +54,14 @@ pub fn compile_sample_wgsl(
     authored: &str,
     textures: &TextureBindingSpecs,
     space: ShaderEntrySpace,
+    output_format: TextureStorageFormat,
 ) -> Result<WgslShader, GfxError> {
-    translate_assembled_glsl(assemble_sample_fragment_glsl(authored, textures, space)?)
+    translate_assembled_glsl(assemble_sample_fragment_glsl(
+        authored,
+        textures,
+        space,
+        output_format,
+    )?)
 }
 
 /// naga `glsl-in` → bounded-tanh pass → validation → `wgsl-out` on an
@@ -100,6 +113,7 @@ mod tests {
             authored,
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
     }
 
@@ -121,6 +135,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return vec4(pos / outputSize, 0.0, 1.0); }\n",
             &TextureBindingSpecs::new(),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("translates");
         assert!(shader.wgsl.contains("fn main"), "entry point present");
@@ -193,6 +208,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return texelFetch(inputColor, ivec2(pos), 0); }\n",
             &textures,
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("translates");
         assert!(
@@ -229,6 +245,7 @@ mod tests {
              vec4 render_2d(vec2 pos) { return texture(t, pos / 8.0); }\n",
             &textures,
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .expect("translates");
         assert!(shader.wgsl.contains("textureLoad"), "{}", shader.wgsl);
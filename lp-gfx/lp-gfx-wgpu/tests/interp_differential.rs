@@ -170,7 +170,9 @@ fn gpu_probe_frame(graphics: &lp_gfx_wgpu::GpuGraphics, glsl: &str) -> Vec<u16>
     let mut shader = graphics
         .compile_shader(&authored, &options)
         .expect("gpu probe compiles");
-    let mut target = graphics.create_render_target(N, 1).expect("target");
+    let mut target = graphics
+        .create_render_target(N, 1, lps_shared::TextureStorageFormat::Rgba16Unorm)
+        .expect("target");
     let uniforms = LpsValueF32::Struct {
         name: None,
         fields: vec![],
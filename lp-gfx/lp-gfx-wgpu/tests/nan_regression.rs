@@ -34,7 +34,7 @@ fn rocaille_renders_zero_non_finite_lanes() {
     for (width, height) in [(32u32, 32u32), (128, 128)] {
         for t in [0.0f32, 2.5, 5.0] {
             let mut target = graphics
-                .create_render_target(width, height)
+                .create_render_target(width, height, lps_shared::TextureStorageFormat::Rgba16Unorm)
                 .expect("render target");
             shader
                 .render(&mut target, &corpus_uniforms(rocaille, width, height, t))
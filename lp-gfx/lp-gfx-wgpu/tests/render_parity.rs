@@ -99,7 +99,7 @@ fn corpus_parity_holds_or_beats_m3() {
                 .expect(shader.name);
 
             let mut target = graphics
-                .create_render_target(width, height)
+                .create_render_target(width, height, lps_shared::TextureStorageFormat::Rgba16Unorm)
                 .expect("render target");
             compiled_gpu
                 .render(
@@ -261,7 +261,9 @@ fn gpu_sample_matches_gpu_render_at_integer_points() {
         .expect("gpu compile");
     let uniforms = corpus_uniforms(shader_src, width, height, 2.5);
 
-    let mut target = gpu.create_render_target(width, height).expect("target");
+    let mut target = gpu
+        .create_render_target(width, height, lps_shared::TextureStorageFormat::Rgba16Unorm)
+        .expect("target");
     shader.render(&mut target, &uniforms).expect("render");
     let frame: Vec<u16> = gpu
         .read_back(&target)
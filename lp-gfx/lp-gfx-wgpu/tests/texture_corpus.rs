@@ -247,7 +247,7 @@ fn gpu_channels(
     let uniforms = LpsValueF32::Struct { name: None, fields };
 
     let mut target = graphics
-        .create_render_target(1, 1)
+        .create_render_target(1, 1, TextureStorageFormat::Rgba16Unorm)
         .map_err(|e| format!("target: {e}"))?;
     shader
         .render(&mut target, &uniforms)
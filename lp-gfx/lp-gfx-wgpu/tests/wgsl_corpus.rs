@@ -5,6 +5,7 @@ mod util;
 
 use lp_gfx_wgpu::wgsl_compile::compile_wgsl;
 use lp_shader::{ShaderEntrySpace, TextureBindingSpecs};
+use lps_shared::TextureStorageFormat;
 use util::corpus::CORPUS;
 
 #[test]
@@ -16,6 +17,7 @@ fn whole_corpus_translates_to_wgsl() {
             shader.source,
             &util::palette::texture_specs(shader),
             ShaderEntrySpace::TwoD,
+            TextureStorageFormat::Rgba16Unorm,
         )
         .unwrap_or_else(|e| panic!("{}: {e}", shader.name));
         assert!(
@@ -36,6 +38,7 @@ fn rocaille_tanh_is_bounded() {
         rocaille.source,
         &TextureBindingSpecs::new(),
         ShaderEntrySpace::TwoD,
+        TextureStorageFormat::Rgba16Unorm,
     )
     .expect("rocaille translates");
     assert!(
@@ -99,9 +99,16 @@ pub trait LpGraphics: Send + Sync {
     /// has no default implementation: every backend must state its choice.
     fn glsl_frontend(&self) -> lp_shader::ShaderFrontend;
 
-    /// Allocate a zeroed RGBA16 render-target texture for
-    /// [`LpShader::render`].
-    fn create_render_target(&self, width: u32, height: u32) -> Result<TextureHandle, GfxError>;
+    /// Allocate a zeroed render-target texture in `format` for
+    /// [`LpShader::render`]. A shader renders only into the format it was
+    /// compiled for ([`ShaderCompileOptions::output_format`]); other targets
+    /// serve as blend, copy and feedback scratch.
+    fn create_render_target(
+        &self,
+        width: u32,
+        height: u32,
+        format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError>;
 
     /// Allocate a texture and upload `texels` into it (the texel-upload path
     /// for CPU-produced content such as fluid frames and baked palettes).
@@ -164,11 +171,23 @@ pub trait LpGraphics: Send + Sync {
         target: &mut TextureHandle,
     ) -> Result<(), GfxError>;
 
+    /// Copy every texel of `source` into `target`; both must share a shape
+    /// (width, height and storage format).
+    ///
+    /// The second member of the GPU-resident texture-op family: this is how
+    /// a shader's feedback history keeps last frame's output without a
+    /// [`Self::read_back`] round trip.
+    fn copy_texture(
+        &self,
+        source: &TextureHandle,
+        target: &mut TextureHandle,
+    ) -> Result<(), GfxError>;
+
     /// Read a texture back as owned CPU bytes.
     ///
     /// For sinks that inherently need bytes (fixture sampling, wire probes).
     /// Transforms on render products belong behind GPU-resident ops like
-    /// [`Self::blend_textures`] instead — see the crate README doctrine.
+    /// [`Self::blend_textures`] and [`Self::copy_texture`] instead — see the crate README doctrine.
     fn read_back(&self, texture: &TextureHandle) -> Result<TextureData, GfxError>;
 
     /// Whether [`Self::read_back`] can service requests on this backend.
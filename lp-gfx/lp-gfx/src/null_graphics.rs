@@ -122,7 +122,12 @@ impl LpGraphics for NullGraphics {
         lp_shader::ShaderFrontend::LpsGlsl
     }
 
-    fn create_render_target(&self, _width: u32, _height: u32) -> Result<TextureHandle, GfxError> {
+    fn create_render_target(
+        &self,
+        _width: u32,
+        _height: u32,
+        _format: TextureStorageFormat,
+    ) -> Result<TextureHandle, GfxError> {
         Err(unsupported("allocate a render target"))
     }
 
@@ -154,6 +159,14 @@ impl LpGraphics for NullGraphics {
         Err(unsupported("blend textures"))
     }
 
+    fn copy_texture(
+        &self,
+        _source: &TextureHandle,
+        _target: &mut TextureHandle,
+    ) -> Result<(), GfxError> {
+        Err(unsupported("copy a texture"))
+    }
+
     fn read_back(&self, _texture: &TextureHandle) -> Result<TextureData, GfxError> {
         Err(unsupported("read a texture back"))
     }
@@ -257,7 +270,11 @@ mod tests {
     fn every_allocating_method_errors() {
         let graphics = NullGraphics::new();
 
-        assert!(graphics.create_render_target(8, 8).is_err());
+        assert!(
+            graphics
+                .create_render_target(8, 8, TextureStorageFormat::Rgba16Unorm)
+                .is_err()
+        );
         assert!(
             graphics
                 .create_texture(1, 1, TextureStorageFormat::Rgba16Unorm, &[0; 8])
@@ -307,6 +324,7 @@ mod tests {
                 .blend_textures(&other, &other, 0.5, &mut texture)
                 .is_err()
         );
+        assert!(graphics.copy_texture(&other, &mut texture).is_err());
     }
 
     #[test]
@@ -1,5 +1,7 @@
 //! Backend-agnostic shader compile options.
 
+use lps_shared::TextureStorageFormat;
+
 use crate::shader_semantics::ShaderSemantics;
 
 /// Backend-agnostic compile options understood by every [`crate::LpGraphics`].
@@ -31,12 +33,23 @@ pub struct ShaderCompileOptions {
     /// the GLSL (the GPU tier) splice the matching entry call; the CPU tier
     /// validates and synthesises against it.
     pub space: lp_shader::ShaderEntrySpace,
+    /// Storage format of the targets this shader renders into. The entry's
+    /// return type must match its channel count (`float` for `R8Unorm` /
+    /// `R16Unorm`, `vec3` for the RGB formats, `vec4` for `Rgba16Unorm`).
+    /// Backends that render only RGBA16 reject anything else at compile time.
+    pub output_format: TextureStorageFormat,
+    /// Authored locations for a source that went through
+    /// [`lp_shader::preprocess`]. Backends that render frontend diagnostics
+    /// map them through it back to the authored file and line; `None` means
+    /// the source is compiled as authored.
+    pub source_map: Option<lp_shader::SourceMap>,
 }
 
 impl ShaderCompileOptions {
     /// Build options from the two per-backend product decisions — semantics
     /// tier and GLSL frontend — with neutral defaults for the rest (20 max
-    /// errors, no texture bindings, the default 2D declared space).
+    /// errors, no texture bindings, the default 2D declared space, RGBA16
+    /// targets, no source map).
     ///
     /// There is deliberately no `Default`: `frontend` used to fall back to a
     /// `cfg!(feature = "naga")` default, which let Cargo feature unification
@@ -52,6 +65,8 @@ impl ShaderCompileOptions {
             frontend,
             textures: lp_shader::TextureBindingSpecs::new(),
             space: lp_shader::ShaderEntrySpace::TwoD,
+            output_format: TextureStorageFormat::Rgba16Unorm,
+            source_map: None,
         }
     }
 
@@ -62,6 +77,13 @@ impl ShaderCompileOptions {
         self
     }
 
+    /// Same options, for a shader rendering `format` targets.
+    #[must_use]
+    pub fn with_output_format(mut self, format: TextureStorageFormat) -> Self {
+        self.output_format = format;
+        self
+    }
+
     /// LPIR compiler configuration for this compile.
     ///
     /// The Q32 arithmetic configuration is no longer selectable per shader —
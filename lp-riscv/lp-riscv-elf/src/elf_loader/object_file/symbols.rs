@@ -140,10 +140,10 @@ pub fn build_object_symbol_map(
                             .wrapping_add(symbol_addr as u32)
                     }
                     Some(".rodata") => {
-                        // .rodata section symbol: placed in code buffer after .text
-                        // For now, place after .text (we'd need to track .rodata placement)
-                        // This is a simplification - in practice .rodata might be placed differently
-                        text_placement.wrapping_add(symbol_addr as u32)
+                        // .rodata section symbol: `load_object_sections` places the
+                        // section in the code buffer right after the combined .text
+                        align_4(text_placement.wrapping_add(combined_text_size(obj)))
+                            .wrapping_add(symbol_addr as u32)
                     }
                     Some(".bss") => {
                         // .bss section symbol: placed in RAM buffer after .data
@@ -190,6 +190,29 @@ pub fn build_object_symbol_map(
     symbol_map
 }
 
+/// Size of the .text sections as `load_object_sections` lays them out: each
+/// non-empty section starts on a 4-byte boundary.
+fn combined_text_size(obj: &::object::File) -> u32 {
+    let mut size = 0u32;
+    for section in obj.sections() {
+        let Ok(name) = section.name() else {
+            continue;
+        };
+        if (name == ".text" || name.starts_with(".text."))
+            && section.kind() == ::object::SectionKind::Text
+            && section.size() > 0
+        {
+            size = align_4(size) + section.size() as u32;
+        }
+    }
+    size
+}
+
+#[inline]
+fn align_4(addr: u32) -> u32 {
+    (addr + 3) & !3
+}
+
 /// Merge base and object symbol maps.
 ///
 /// Combines symbol maps, with base symbols taking precedence over object symbols.
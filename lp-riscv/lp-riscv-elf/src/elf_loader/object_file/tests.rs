@@ -616,4 +616,42 @@ mod tests {
             println!("Note: a0 is still 0, but __lp_lpir_fsqrt_q32 was called");
         }
     }
+
+    #[test]
+    fn test_rodata_symbol_follows_combined_text() {
+        use hashbrown::HashMap;
+        use object::write::{Object, Symbol, SymbolSection};
+        use object::{
+            Architecture, BinaryFormat, Endianness, SectionKind, SymbolFlags, SymbolKind,
+            SymbolScope,
+        };
+
+        let mut obj = Object::new(BinaryFormat::Elf, Architecture::Riscv32, Endianness::Little);
+        let text = obj.add_section(Vec::new(), b".text".to_vec(), SectionKind::Text);
+        obj.append_section_data(text, &[0x13, 0x00, 0x00, 0x00, 0x01, 0x00], 4);
+        let rodata = obj.add_section(Vec::new(), b".rodata".to_vec(), SectionKind::ReadOnlyData);
+        let table: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let offset = obj.append_section_data(rodata, &table, 4);
+        obj.add_symbol(Symbol {
+            name: b"table".to_vec(),
+            value: offset,
+            size: table.len() as u64,
+            kind: SymbolKind::Data,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: SymbolSection::Section(rodata),
+            flags: SymbolFlags::None,
+        });
+        let bytes = obj.write().expect("write object");
+
+        // A 10-byte base image puts .text at 12; its 6 bytes end at 18.
+        let mut code = vec![0u8; 10];
+        let mut ram = Vec::new();
+        let mut symbol_map: HashMap<String, u32> = HashMap::new();
+        load_object_file(&bytes, &mut code, &mut ram, &mut symbol_map).expect("load object");
+
+        let addr = symbol_map["table"] as usize;
+        assert_eq!(addr, 20);
+        assert_eq!(&code[addr..addr + table.len()], &table);
+    }
 }
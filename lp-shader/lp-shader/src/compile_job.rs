@@ -9,6 +9,7 @@ use crate::compile_px_desc::{CompilePxDesc, ShaderFrontend, TextureBindingSpecs}
 use crate::entry_space::ShaderEntrySpace;
 use crate::error::LpsError;
 use crate::px_shader::LpsPxShader;
+use crate::source_hash::px_source_hash;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ShaderCompileBudget {
@@ -95,6 +96,8 @@ pub struct ShaderCompileJob<'src, 'engine, E: LpvmEngine> {
     /// validation, both synth passes, and the host-side target-shape check
     /// all read it (`CompilePxDesc::space`).
     space: ShaderEntrySpace,
+    /// Authored locations for `glsl` (`CompilePxDesc::source_map`).
+    source_map: Option<&'src lps_glsl::SourceMap>,
     state: ShaderCompileState<'src, 'engine, E>,
 }
 
@@ -129,9 +132,17 @@ where
             params: LpvmCompileParams {
                 config: desc.compiler_config,
                 float_mode: desc.float_mode,
+                source_hash: Some(px_source_hash(
+                    desc.glsl,
+                    desc.output_format,
+                    &desc.textures,
+                    desc.space,
+                    desc.frontend,
+                )),
             },
             textures: desc.textures,
             space: desc.space,
+            source_map: desc.source_map,
             state,
         }
     }
@@ -175,7 +186,11 @@ where
                             ShaderCompileStepResult::Pending
                         }
                         lps_glsl::CompileStepResult::Failed(err) => {
-                            ShaderCompileStepResult::Failed(LpsError::Parse(err.render(self.glsl)))
+                            let rendered = match self.source_map {
+                                Some(map) => err.render_mapped(self.glsl, map),
+                                None => err.render(self.glsl),
+                            };
+                            ShaderCompileStepResult::Failed(LpsError::Parse(rendered))
                         }
                         lps_glsl::CompileStepResult::Finished(output) => {
                             self.state = ShaderCompileState::Prepare {
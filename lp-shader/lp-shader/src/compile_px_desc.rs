@@ -4,6 +4,7 @@ use alloc::string::String;
 use lp_collection::VecMap;
 
 use lpir::{CompilerConfig, FloatMode};
+use lps_glsl::SourceMap;
 use lps_shared::{TextureBindingSpec, TextureStorageFormat};
 
 use crate::entry_space::ShaderEntrySpace;
@@ -75,6 +76,11 @@ pub struct CompilePxDesc<'a> {
     /// the compiler never infers it from the source. Defaults to
     /// [`ShaderEntrySpace::TwoD`].
     pub space: ShaderEntrySpace,
+    /// Where the lines of [`Self::glsl`] were authored, when it is
+    /// [`crate::preprocess`] output. Frontend diagnostics are rendered
+    /// against it, so they name the included file and authored line instead
+    /// of a line in the expanded text. `None` renders against `glsl` itself.
+    pub source_map: Option<&'a SourceMap>,
 }
 
 impl<'a> CompilePxDesc<'a> {
@@ -97,6 +103,7 @@ impl<'a> CompilePxDesc<'a> {
             frontend,
             float_mode: FloatMode::Q32,
             space: ShaderEntrySpace::TwoD,
+            source_map: None,
         }
     }
 
@@ -114,6 +121,13 @@ impl<'a> CompilePxDesc<'a> {
         self
     }
 
+    /// Same descriptor, with `glsl` expanded by the preprocessor into `map`.
+    #[must_use]
+    pub fn with_source_map(mut self, map: &'a SourceMap) -> Self {
+        self.source_map = Some(map);
+        self
+    }
+
     /// Adds or replaces the compile-time [`TextureBindingSpec`] for uniform `name`.
     ///
     /// Callers supply specs that match how they populate buffers at runtime (format, dimensions via
@@ -16,6 +16,7 @@ use crate::entry_space::{RenderEntry, ShaderEntrySpace};
 use crate::error::LpsError;
 use crate::px_shader::LpsPxShader;
 use crate::sample_buf::{LpsSamplePointBuf, LpsSampleRgba16Buf};
+use crate::source_hash::compute_source_hash;
 use crate::texture_buf::LpsTextureBuf;
 
 /// Shader compilation and shared-memory texture allocation.
@@ -124,6 +125,7 @@ impl<E: LpvmEngine> LpsEngine<E> {
                 &lpvm::LpvmCompileParams {
                     config: compiler_config,
                     float_mode,
+                    source_hash: Some(compute_source_hash(glsl)),
                 },
             )
             .map_err(|e| LpsError::Compile(format!("{e}")))?;
@@ -339,8 +341,8 @@ pub(crate) fn validate_render_sig(
 /// Map output format to expected return type.
 fn expected_return_type(format: TextureStorageFormat) -> LpsType {
     match format {
-        TextureStorageFormat::R16Unorm => LpsType::Float,
-        TextureStorageFormat::Rgb16Unorm => LpsType::Vec3,
+        TextureStorageFormat::R8Unorm | TextureStorageFormat::R16Unorm => LpsType::Float,
+        TextureStorageFormat::Rgb8Unorm | TextureStorageFormat::Rgb16Unorm => LpsType::Vec3,
         TextureStorageFormat::Rgba16Unorm => LpsType::Vec4,
     }
 }
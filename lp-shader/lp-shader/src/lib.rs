@@ -8,6 +8,10 @@
 //! runtime uniform structs for [`LpsPxShader::render_frame`]. Higher layers (engine/domain code)
 //! own baking palette or gradient texels into a buffer (typically height `== 1`) and must pass a
 //! matching spec—for example [`texture_binding::height_one`] when sampling ignores the vertical axis.
+//!
+//! Project shaders run through [`preprocess`] first: pass the expanded source
+//! as [`CompilePxDesc::glsl`] and its map as [`CompilePxDesc::source_map`] so
+//! diagnostics point at the authored file and line.
 
 #![no_std]
 
@@ -25,6 +29,7 @@ mod error;
 mod px_shader;
 mod runtime_texture_validation;
 mod sample_buf;
+mod source_hash;
 pub mod synth;
 mod texture_buf;
 mod texture_interface;
@@ -47,6 +52,10 @@ pub use px_shader::LpsPxShader;
 pub use sample_buf::{LpsSamplePointBuf, LpsSampleRgba16Buf};
 pub use texture_buf::LpsTextureBuf;
 
+pub use lps_glsl::{
+    IncludeResolver, IncludedSource, LpFsIncludes, NoIncludes, PreprocessError, Preprocessed,
+    SourceMap, preprocess,
+};
 pub use lps_shared::{
     LpsModuleSig, LpsValueF32, TextureBindingSpec, TextureBuffer, TextureStorageFormat,
 };
@@ -0,0 +1,66 @@
+//! [`lpvm::LpvmCompileParams::source_hash`] for the shaders this crate
+//! compiles: the GLSL plus every descriptor field that changes the LPIR it
+//! lowers to.
+
+use core::fmt::{self, Debug, Write};
+
+use lps_shared::TextureStorageFormat;
+
+use crate::compile_px_desc::{ShaderFrontend, TextureBindingSpecs};
+use crate::entry_space::ShaderEntrySpace;
+
+/// Hash of a pixel shader's source and the options its frontend and synth
+/// passes read.
+pub(crate) fn px_source_hash(
+    glsl: &str,
+    output_format: TextureStorageFormat,
+    textures: &TextureBindingSpecs,
+    space: ShaderEntrySpace,
+    frontend: ShaderFrontend,
+) -> u64 {
+    let mut h = Fnv64::new();
+    h.write_str(glsl).ok();
+    h.field(&output_format);
+    // `VecMap` iterates in key order, so equal maps render equally.
+    h.field(textures);
+    h.field(&space);
+    h.field(&frontend);
+    h.0
+}
+
+/// Hash of a compute shader's source. Its frontend options all come from the
+/// compiler config, which the cache keys separately.
+pub(crate) fn compute_source_hash(glsl: &str) -> u64 {
+    let mut h = Fnv64::new();
+    h.write_str(glsl).ok();
+    h.0
+}
+
+/// FNV-1a 64; fields are rendered with `Debug` behind a separator byte so
+/// adjacent ones cannot run together.
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn field(&mut self, value: &impl Debug) {
+        self.byte(0xff);
+        write!(self, "{value:?}").ok();
+    }
+
+    fn byte(&mut self, b: u8) {
+        self.0 ^= u64::from(b);
+        self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+    }
+}
+
+impl Write for Fnv64 {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for &b in s.as_bytes() {
+            self.byte(b);
+        }
+        Ok(())
+    }
+}
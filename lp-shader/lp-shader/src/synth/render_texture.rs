@@ -1,4 +1,5 @@
-//! Synthesise `__render_texture_<format>`: nested y/x loops, incremental offsets (Shape B), F32 → unorm16.
+//! Synthesise `__render_texture_<format>`: nested y/x loops, incremental offsets (Shape B), F32 → unorm16
+//! (or unorm8 for the byte formats).
 //!
 //! # Declared space
 //!
@@ -41,7 +42,9 @@ pub enum SynthError {
 /// Name suffix for the synthesised entry (e.g. `"__render_texture_rgba16"`).
 pub fn render_texture_fn_name(format: TextureStorageFormat) -> &'static str {
     match format {
+        TextureStorageFormat::R8Unorm => "__render_texture_r8",
         TextureStorageFormat::R16Unorm => "__render_texture_r16",
+        TextureStorageFormat::Rgb8Unorm => "__render_texture_rgb8",
         TextureStorageFormat::Rgb16Unorm => "__render_texture_rgb16",
         TextureStorageFormat::Rgba16Unorm => "__render_texture_rgba16",
     }
@@ -140,6 +143,7 @@ pub fn synthesise_render_texture(
 
     let channels = format.channel_count();
     let bytes_per_px = format.bytes_per_pixel() as i32;
+    let bytes_per_ch = format.bytes_per_channel() as u32;
     if render_fn.return_types.len() != channels {
         return Err(SynthError::RenderFunctionMissing);
     }
@@ -271,15 +275,28 @@ pub fn synthesise_render_texture(
 
             for ch in 0..channels {
                 let unorm = fb.alloc_vreg(IrType::I32);
-                fb.push(LpirOp::FtoUnorm16 {
-                    dst: unorm,
-                    src: color[ch],
-                });
-                fb.push(LpirOp::Store16 {
-                    base,
-                    offset: (ch as u32) * 2,
-                    value: unorm,
-                });
+                let offset = (ch as u32) * bytes_per_ch;
+                if bytes_per_ch == 1 {
+                    fb.push(LpirOp::FtoUnorm8 {
+                        dst: unorm,
+                        src: color[ch],
+                    });
+                    fb.push(LpirOp::Store8 {
+                        base,
+                        offset,
+                        value: unorm,
+                    });
+                } else {
+                    fb.push(LpirOp::FtoUnorm16 {
+                        dst: unorm,
+                        src: color[ch],
+                    });
+                    fb.push(LpirOp::Store16 {
+                        base,
+                        offset,
+                        value: unorm,
+                    });
+                }
             }
 
             fb.push(LpirOp::IaddImm {
@@ -481,6 +498,42 @@ mod tests {
         assert_eq!(fto, 1, "expected one FtoUnorm16 for R16");
     }
 
+    /// Byte formats store each channel as one `Store8` at its byte offset.
+    #[test]
+    fn synth_rgb8_stores_one_byte_per_channel() {
+        let (mut ir, mut meta) = make_stub_render_module(LpsType::Vec3);
+        let name = synthesise_render_texture(
+            &mut ir,
+            &mut meta,
+            0,
+            TextureStorageFormat::Rgb8Unorm,
+            FloatMode::Q32,
+            ShaderEntrySpace::TwoD,
+        )
+        .expect("synth");
+        assert_eq!(name, "__render_texture_rgb8");
+        let synth_fn = ir
+            .functions
+            .values()
+            .find(|f| f.name == name)
+            .expect("synth fn");
+        let offsets: Vec<u32> = synth_fn
+            .body
+            .iter()
+            .filter_map(|op| match op {
+                LpirOp::Store8 { offset, .. } => Some(*offset),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(offsets, [0, 1, 2]);
+        assert!(
+            !synth_fn
+                .body
+                .iter()
+                .any(|op| matches!(op, LpirOp::Store16 { .. } | LpirOp::FtoUnorm16 { .. }))
+        );
+    }
+
     /// Inliner regression sanity (Q7).
     ///
     /// Today, on this branch (no inliner integrated), `__render_texture`
@@ -993,6 +993,48 @@ fn render_frame_rgb16_constant_writes_expected_bytes() {
     }
 }
 
+/// Byte formats pack one byte per channel. The channels are endpoints so
+/// the expected bytes do not depend on the float mode's rounding.
+#[test]
+fn render_frame_rgb8_and_r8_write_one_byte_per_channel() {
+    let engine = test_engine();
+    let uniforms = LpsValueF32::Struct {
+        name: None,
+        fields: vec![],
+    };
+    for (glsl, format, expected_pixel) in [
+        (
+            "vec3 render_2d(vec2 pos) { return vec3(1.0, 0.0, 1.0); }",
+            TextureStorageFormat::Rgb8Unorm,
+            &[255u8, 0, 255][..],
+        ),
+        (
+            "float render_2d(vec2 pos) { return 1.0; }",
+            TextureStorageFormat::R8Unorm,
+            &[255u8][..],
+        ),
+    ] {
+        let shader = engine
+            .compile_px(
+                glsl,
+                format,
+                &lpir::CompilerConfig::default(),
+                ShaderFrontend::LpsGlsl,
+            )
+            .expect("compile_px");
+        let mut tex = engine.alloc_texture(2, 2, format).expect("alloc_texture");
+        shader
+            .render_frame(&uniforms, &mut tex)
+            .expect("render_frame");
+
+        let bytes = tex.data();
+        assert_eq!(bytes.len(), 2 * 2 * expected_pixel.len());
+        for (i, chunk) in bytes.chunks_exact(expected_pixel.len()).enumerate() {
+            assert_eq!(chunk, expected_pixel, "{format:?} pixel {i}");
+        }
+    }
+}
+
 #[test]
 fn render_frame_rgba16_constant_writes_expected_bytes() {
     let engine = test_engine();
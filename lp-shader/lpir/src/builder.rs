@@ -4,7 +4,9 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use lp_collection::VecMap;
 
-use crate::lpir_module::{ImportDecl, IrFunction, LpirBody, LpirModule, SlotDecl, VMCTX_VREG};
+use crate::lpir_module::{
+    ImportDecl, IrFunction, LpirBody, LpirModule, RodataWord, SlotDecl, VMCTX_VREG,
+};
 use crate::lpir_op::LpirOp;
 use crate::types::{CalleeRef, FuncId, ImportId, IrType, SlotId, VReg, VRegRange};
 
@@ -486,6 +488,7 @@ impl FunctionBuilder {
 pub struct ModuleBuilder {
     imports: Vec<ImportDecl>,
     functions: VecMap<FuncId, IrFunction>,
+    rodata: Vec<RodataWord>,
     next_func_id: u16,
 }
 
@@ -494,6 +497,7 @@ impl Default for ModuleBuilder {
         Self {
             imports: Vec::new(),
             functions: VecMap::new(),
+            rodata: Vec::new(),
             next_func_id: 0,
         }
     }
@@ -534,10 +538,19 @@ impl ModuleBuilder {
         CalleeRef::Local(id)
     }
 
+    /// Append `words` to the module's read-only data; returns their byte
+    /// offset from [`LpirOp::RodataAddr`].
+    pub fn add_rodata(&mut self, words: &[RodataWord]) -> u32 {
+        let offset = (self.rodata.len() * 4) as u32;
+        self.rodata.extend_from_slice(words);
+        offset
+    }
+
     pub fn finish(self) -> LpirModule {
         LpirModule {
             imports: self.imports,
             functions: self.functions,
+            rodata: self.rodata,
         }
     }
 }
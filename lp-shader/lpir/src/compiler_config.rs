@@ -1,4 +1,5 @@
-//! Middle-end options for LPIR optimization passes (inlining, future passes).
+//! Middle-end options for LPIR optimization passes (inlining, the per-function
+//! pass pipeline).
 //!
 //! String keys are applied via [`CompilerConfig::apply`]; the full key namespace
 //! lives here so typos surface as parse errors.
@@ -11,6 +12,7 @@ use core::str::FromStr;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CompilerConfig {
     pub inline: InlineConfig,
+    pub opt: OptConfig,
     pub texture: TextureConfig,
 }
 
@@ -18,12 +20,13 @@ impl Default for CompilerConfig {
     fn default() -> Self {
         Self {
             inline: InlineConfig::default(),
+            opt: OptConfig::default(),
             texture: TextureConfig::default(),
         }
     }
 }
 
-/// Controls the inliner when it exists.
+/// Controls the inliner ([`crate::inline`]).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum InlineMode {
     /// Heuristic-based inlining (default).
@@ -58,13 +61,18 @@ impl FromStr for InlineMode {
     }
 }
 
-/// Tunables for the inline pass.
+/// Tunables for the inline pass. Sizes are LPIR op counts.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct InlineConfig {
     pub mode: InlineMode,
+    /// [`InlineMode::Auto`]: inline a callee with one call site regardless of size.
     pub always_inline_single_site: bool,
+    /// [`InlineMode::Auto`]: inline callees of at most this many ops at every site.
     pub small_func_threshold: usize,
+    /// Most ops any one function may grow by; `None` is unbounded.
     pub max_growth_budget: Option<usize>,
+    /// Most ops the whole module may hold after inlining; `None` leaves it
+    /// to the backend's per-target default (unbounded on hosts).
     pub module_op_budget: Option<usize>,
 }
 
@@ -80,6 +88,50 @@ impl Default for InlineConfig {
     }
 }
 
+/// Per-function passes of the optimization pipeline ([`crate::pipeline`]);
+/// each runs only when its flag is set. All on by default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OptConfig {
+    /// Unroll loops with a small known trip count ([`crate::unroll`]).
+    pub unroll: bool,
+    /// Longest trip count a loop is unrolled for.
+    pub unroll_max_trip: u32,
+    /// Most ops one unrolled loop may expand to.
+    pub unroll_max_ops: u32,
+    /// Fold integer ops on known constants ([`crate::const_fold`]).
+    pub const_fold: bool,
+    /// Keep small stack slots in vregs ([`crate::scalarize`]).
+    pub scalarize: bool,
+    /// Largest slot, in bytes, that scalarization promotes.
+    pub scalarize_max_bytes: u32,
+    /// Replace multiplies and divides by powers of two with shifts, and drop
+    /// identity arithmetic ([`crate::strength_reduce`]).
+    pub strength_reduce: bool,
+    /// Reuse an earlier identical computation ([`crate::cse`]).
+    pub cse: bool,
+    /// Hoist loop-invariant computations out of loops ([`crate::licm`]).
+    pub licm: bool,
+    /// Remove computations whose results are never read ([`crate::dce`]).
+    pub dce: bool,
+}
+
+impl Default for OptConfig {
+    fn default() -> Self {
+        Self {
+            unroll: true,
+            unroll_max_trip: 8,
+            unroll_max_ops: 256,
+            const_fold: true,
+            scalarize: true,
+            scalarize_max_bytes: 64,
+            strength_reduce: true,
+            cse: true,
+            licm: true,
+            dce: true,
+        }
+    }
+}
+
 /// Texture-related lowering options (filetest [`CompilerConfig::apply`] keys).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TextureConfig {
@@ -181,6 +233,37 @@ impl CompilerConfig {
                 self.inline.module_op_budget =
                     Some(value.trim().parse().map_err(|_| invalid(key, value))?);
             }
+            "opt.unroll" => {
+                self.opt.unroll = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.unroll_max_trip" => {
+                self.opt.unroll_max_trip = value.trim().parse().map_err(|_| invalid(key, value))?;
+            }
+            "opt.unroll_max_ops" => {
+                self.opt.unroll_max_ops = value.trim().parse().map_err(|_| invalid(key, value))?;
+            }
+            "opt.scalarize" => {
+                self.opt.scalarize = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.scalarize_max_bytes" => {
+                self.opt.scalarize_max_bytes =
+                    value.trim().parse().map_err(|_| invalid(key, value))?;
+            }
+            "opt.const_fold" => {
+                self.opt.const_fold = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.strength_reduce" => {
+                self.opt.strength_reduce = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.cse" => {
+                self.opt.cse = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.licm" => {
+                self.opt.licm = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
+            "opt.dce" => {
+                self.opt.dce = parse_bool(value).ok_or_else(|| invalid(key, value))?;
+            }
             "texture.texel_fetch_bounds" => {
                 self.texture.texel_fetch_bounds =
                     value.trim().parse().map_err(|_| invalid(key, value))?;
@@ -239,6 +322,42 @@ mod tests {
         assert!(c.inline.always_inline_single_site);
     }
 
+    #[test]
+    fn apply_opt_pass_toggles() {
+        let mut c = CompilerConfig::default();
+        for key in [
+            "opt.unroll",
+            "opt.const_fold",
+            "opt.scalarize",
+            "opt.strength_reduce",
+            "opt.cse",
+            "opt.licm",
+            "opt.dce",
+        ] {
+            c.apply(key, "false").unwrap();
+        }
+        assert_eq!(
+            c.opt,
+            OptConfig {
+                unroll: false,
+                const_fold: false,
+                scalarize: false,
+                strength_reduce: false,
+                cse: false,
+                licm: false,
+                dce: false,
+                ..OptConfig::default()
+            }
+        );
+        c.apply("opt.licm", "1").unwrap();
+        assert!(c.opt.licm);
+        assert!(c.apply("opt.cse", "maybe").is_err());
+        c.apply("opt.unroll_max_trip", "4").unwrap();
+        c.apply("opt.scalarize_max_bytes", " 16 ").unwrap();
+        assert_eq!((c.opt.unroll_max_trip, c.opt.scalarize_max_bytes), (4, 16));
+        assert!(c.apply("opt.unroll_max_ops", "-1").is_err());
+    }
+
     #[test]
     fn apply_unknown_key_errors() {
         let mut c = CompilerConfig::default();
@@ -3,11 +3,13 @@
 //! Single forward pass over `IrFunction::body` that folds operations on
 //! compile-time-known integer constants. Replaces in place without changing
 //! the body length (control-flow offsets remain valid).
+//!
+//! Known values follow structured control flow: a constant
+//! assigned before an `if` or a loop is still known inside it unless the
+//! construct reassigns it, so the induction variable of an unrolled loop
+//! ([`crate::unroll`]) folds through every copy of the body.
 
-extern crate alloc;
-
-use alloc::vec::Vec;
-
+use crate::rewrite::{FlowFacts, for_each_def};
 use crate::{IrFunction, LpirOp, VReg};
 
 /// Fold constant integer operations in `func.body` in place.
@@ -19,11 +21,11 @@ pub fn fold_constants(func: &mut IrFunction) -> usize {
         return 0;
     }
 
-    let mut vreg_val: Vec<Option<i32>> = Vec::new();
-    vreg_val.resize(max_vreg, None);
+    let mut vreg_val = FlowFacts::new(func);
     let mut folded = 0;
 
     for i in 0..func.body.len() {
+        vreg_val.enter(func, i);
         // Take ownership of the op temporarily to avoid borrow issues
         let op = core::mem::replace(
             &mut func.body[i],
@@ -35,12 +37,22 @@ pub fn fold_constants(func: &mut IrFunction) -> usize {
 
         let new_op = match &op {
             LpirOp::IconstI32 { dst, value } => {
-                let idx = dst.0 as usize;
-                if idx < vreg_val.len() {
-                    vreg_val[idx] = Some(*value);
-                }
+                set(&mut vreg_val, *dst, *value);
                 op
             }
+            LpirOp::Copy { dst, src } => {
+                if let Some(v) = get(&vreg_val, *src) {
+                    set(&mut vreg_val, *dst, v);
+                    folded += 1;
+                    LpirOp::IconstI32 {
+                        dst: *dst,
+                        value: v,
+                    }
+                } else {
+                    clear_vreg(&mut vreg_val, *dst);
+                    op
+                }
+            }
 
             LpirOp::Iadd { dst, lhs, rhs } => {
                 if let (Some(l), Some(r)) = (get(&vreg_val, *lhs), get(&vreg_val, *rhs)) {
@@ -197,6 +209,61 @@ pub fn fold_constants(func: &mut IrFunction) -> usize {
                 }
             }
 
+            LpirOp::IaddImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                i32::wrapping_add,
+                &mut folded,
+                op,
+            ),
+            LpirOp::IsubImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                i32::wrapping_sub,
+                &mut folded,
+                op,
+            ),
+            LpirOp::ImulImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                i32::wrapping_mul,
+                &mut folded,
+                op,
+            ),
+            LpirOp::IshlImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                |v, s| v.wrapping_shl(s as u32),
+                &mut folded,
+                op,
+            ),
+            LpirOp::IshrSImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                |v, s| v.wrapping_shr(s as u32),
+                &mut folded,
+                op,
+            ),
+            LpirOp::IshrUImm { dst, src, imm } => fold_imm(
+                &mut vreg_val,
+                *dst,
+                *src,
+                *imm,
+                |v, s| (v as u32).wrapping_shr(s as u32) as i32,
+                &mut folded,
+                op,
+            ),
+
             LpirOp::IeqImm { dst, src, imm } => {
                 if let Some(v) = get(&vreg_val, *src) {
                     let result = if v == *imm { 1 } else { 0 };
@@ -324,23 +391,8 @@ pub fn fold_constants(func: &mut IrFunction) -> usize {
                 }
             }
 
-            LpirOp::IfStart { .. }
-            | LpirOp::Else
-            | LpirOp::End
-            | LpirOp::LoopStart { .. }
-            | LpirOp::Block { .. }
-            | LpirOp::Break
-            | LpirOp::Continue
-            | LpirOp::BrIfNot { .. }
-            | LpirOp::ExitBlock => {
-                vreg_val.iter_mut().for_each(|v| *v = None);
-                op
-            }
-
             _ => {
-                if let Some(dst) = op.def_vreg() {
-                    clear_vreg(&mut vreg_val, dst);
-                }
+                for_each_def(func, &op, |dst| clear_vreg(&mut vreg_val, dst));
                 op
             }
         };
@@ -351,26 +403,40 @@ pub fn fold_constants(func: &mut IrFunction) -> usize {
     folded
 }
 
-fn get(vreg_val: &[Option<i32>], vreg: VReg) -> Option<i32> {
-    vreg_val.get(vreg.0 as usize).copied().flatten()
+fn get(vreg_val: &FlowFacts<i32>, vreg: VReg) -> Option<i32> {
+    vreg_val.get(vreg).copied()
 }
 
-fn set(vreg_val: &mut [Option<i32>], vreg: VReg, val: i32) {
-    let idx = vreg.0 as usize;
-    if idx < vreg_val.len() {
-        vreg_val[idx] = Some(val);
-    }
+fn set(vreg_val: &mut FlowFacts<i32>, vreg: VReg, val: i32) {
+    vreg_val.set(vreg, Some(val));
+}
+
+fn clear_vreg(vreg_val: &mut FlowFacts<i32>, vreg: VReg) {
+    vreg_val.set(vreg, None);
 }
 
-fn clear_vreg(vreg_val: &mut [Option<i32>], vreg: VReg) {
-    let idx = vreg.0 as usize;
-    if idx < vreg_val.len() {
-        vreg_val[idx] = None;
+fn fold_imm(
+    vreg_val: &mut FlowFacts<i32>,
+    dst: VReg,
+    src: VReg,
+    imm: i32,
+    eval: fn(i32, i32) -> i32,
+    folded: &mut usize,
+    original_op: LpirOp,
+) -> LpirOp {
+    if let Some(v) = get(vreg_val, src) {
+        let result = eval(v, imm);
+        set(vreg_val, dst, result);
+        *folded += 1;
+        LpirOp::IconstI32 { dst, value: result }
+    } else {
+        clear_vreg(vreg_val, dst);
+        original_op
     }
 }
 
 fn fold_icmp(
-    vreg_val: &mut [Option<i32>],
+    vreg_val: &mut FlowFacts<i32>,
     dst: VReg,
     lhs: VReg,
     rhs: VReg,
@@ -390,7 +456,7 @@ fn fold_icmp(
 }
 
 fn fold_icmp_u(
-    vreg_val: &mut [Option<i32>],
+    vreg_val: &mut FlowFacts<i32>,
     dst: VReg,
     lhs: VReg,
     rhs: VReg,
@@ -0,0 +1,250 @@
+//! LPIR common-subexpression elimination.
+//!
+//! Walks a function once, remembering which vreg holds each pure computation
+//! seen so far. A later op computing the same thing from the same operands
+//! becomes a copy of that vreg. Each rewrite replaces one op with one op, so
+//! control-flow offsets stay valid.
+//!
+//! LPIR vregs can be reassigned, so an entry is forgotten as soon as its
+//! result or one of its operands is written again. Availability follows the
+//! structured control flow: an entry made inside an `if` arm, loop, switch
+//! arm or block is dropped when that construct closes, the else arm starts
+//! from what was available before the `if`, and a loop body starts without
+//! anything the loop writes (a back-edge may have changed it). Loads, calls
+//! and constants are never reused; constants are cheaper to rematerialize
+//! than to keep live.
+
+use alloc::vec::Vec;
+use core::mem::Discriminant;
+
+use crate::lpir_module::IrFunction;
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{end_index, for_each_def};
+use crate::types::VReg;
+
+/// Most computations remembered at once. Bounds the per-op lookup on long
+/// straight-line bodies; the oldest entry is forgotten first.
+const MAX_AVAILABLE: usize = 64;
+
+/// What an op computes, independent of where it puts the result.
+#[derive(Clone, Copy, PartialEq)]
+struct Key {
+    opcode: Discriminant<LpirOp>,
+    operands: [VReg; 3],
+    /// Immediate operand bits (`f32` via `to_bits`), `0` when there is none.
+    imm: u32,
+}
+
+impl Key {
+    fn reads(&self, v: VReg) -> bool {
+        self.operands.contains(&v)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Available {
+    key: Key,
+    holder: VReg,
+}
+
+/// One open construct.
+struct Frame {
+    /// Availability on entry (for a loop: minus everything the loop writes).
+    entry: Vec<Available>,
+    /// Vregs written inside, so far.
+    written: Vec<VReg>,
+    /// For a loop, where `continue` lands.
+    continuing: Option<usize>,
+}
+
+/// Replace recomputations in `func.body` with copies. Returns the number of
+/// ops replaced.
+pub fn eliminate_common_subexpressions(func: &mut IrFunction) -> usize {
+    let mut available: Vec<Available> = Vec::new();
+    let mut frames: Vec<Frame> = Vec::new();
+    let mut replaced = 0;
+
+    for i in 0..func.body.len() {
+        if let Some(frame) = frames.last()
+            && frame.continuing == Some(i)
+        {
+            available.clone_from(&frame.entry);
+        }
+
+        let op = &func.body[i];
+        match op {
+            LpirOp::IfStart { .. }
+            | LpirOp::SwitchStart { .. }
+            | LpirOp::CaseStart { .. }
+            | LpirOp::DefaultStart { .. }
+            | LpirOp::Block { .. } => {
+                frames.push(Frame {
+                    entry: available.clone(),
+                    written: Vec::new(),
+                    continuing: None,
+                });
+                continue;
+            }
+            LpirOp::LoopStart {
+                continuing_offset, ..
+            } => {
+                let continuing = *continuing_offset as usize;
+                if let Some(end) = end_index(op) {
+                    for j in i + 1..end {
+                        for_each_def(func, &func.body[j], |v| forget(&mut available, v));
+                    }
+                }
+                frames.push(Frame {
+                    entry: available.clone(),
+                    written: Vec::new(),
+                    continuing: Some(continuing),
+                });
+                continue;
+            }
+            LpirOp::Else => {
+                if let Some(frame) = frames.last() {
+                    available.clone_from(&frame.entry);
+                }
+                continue;
+            }
+            LpirOp::End => {
+                if let Some(frame) = frames.pop() {
+                    available = frame.entry;
+                    for &v in &frame.written {
+                        forget(&mut available, v);
+                    }
+                    if let Some(parent) = frames.last_mut() {
+                        parent.written.extend_from_slice(&frame.written);
+                    }
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        let key = key(op);
+        let mut written = None;
+        for_each_def(func, op, |v| {
+            forget(&mut available, v);
+            if let Some(frame) = frames.last_mut() {
+                frame.written.push(v);
+            }
+            written = Some(v);
+        });
+        let (Some(key), Some(dst)) = (key, written) else {
+            continue;
+        };
+        // Forgetting `dst` above also dropped any entry reading it, so a hit
+        // never refers to a stale operand.
+        if let Some(hit) = available.iter().find(|a| a.key == key) {
+            func.body[i] = LpirOp::Copy {
+                dst,
+                src: hit.holder,
+            };
+            replaced += 1;
+            continue;
+        }
+        if !key.reads(dst) {
+            if available.len() == MAX_AVAILABLE {
+                available.remove(0);
+            }
+            available.push(Available { key, holder: dst });
+        }
+    }
+    replaced
+}
+
+/// Drop every entry held in or reading `v`.
+fn forget(available: &mut Vec<Available>, v: VReg) {
+    available.retain(|a| a.holder != v && !a.key.reads(v));
+}
+
+/// The reusable computation `op` performs, if any: pure, not a load, call,
+/// constant or copy.
+fn key(op: &LpirOp) -> Option<Key> {
+    let opcode = core::mem::discriminant(op);
+    let none = VReg(u32::MAX);
+    let (operands, imm) = match *op {
+        // Commutative: order the operands so `a + b` and `b + a` match.
+        LpirOp::Fadd { lhs, rhs, .. }
+        | LpirOp::Fmul { lhs, rhs, .. }
+        | LpirOp::Feq { lhs, rhs, .. }
+        | LpirOp::Fne { lhs, rhs, .. }
+        | LpirOp::Iadd { lhs, rhs, .. }
+        | LpirOp::Imul { lhs, rhs, .. }
+        | LpirOp::Ieq { lhs, rhs, .. }
+        | LpirOp::Ine { lhs, rhs, .. }
+        | LpirOp::Iand { lhs, rhs, .. }
+        | LpirOp::Ior { lhs, rhs, .. }
+        | LpirOp::Ixor { lhs, rhs, .. } => {
+            let (a, b) = if lhs.0 <= rhs.0 {
+                (lhs, rhs)
+            } else {
+                (rhs, lhs)
+            };
+            ([a, b, none], 0)
+        }
+        LpirOp::Fsub { lhs, rhs, .. }
+        | LpirOp::Fdiv { lhs, rhs, .. }
+        | LpirOp::Fmin { lhs, rhs, .. }
+        | LpirOp::Fmax { lhs, rhs, .. }
+        | LpirOp::Isub { lhs, rhs, .. }
+        | LpirOp::IdivS { lhs, rhs, .. }
+        | LpirOp::IdivU { lhs, rhs, .. }
+        | LpirOp::IremS { lhs, rhs, .. }
+        | LpirOp::IremU { lhs, rhs, .. }
+        | LpirOp::Flt { lhs, rhs, .. }
+        | LpirOp::Fle { lhs, rhs, .. }
+        | LpirOp::Fgt { lhs, rhs, .. }
+        | LpirOp::Fge { lhs, rhs, .. }
+        | LpirOp::IltS { lhs, rhs, .. }
+        | LpirOp::IleS { lhs, rhs, .. }
+        | LpirOp::IgtS { lhs, rhs, .. }
+        | LpirOp::IgeS { lhs, rhs, .. }
+        | LpirOp::IltU { lhs, rhs, .. }
+        | LpirOp::IleU { lhs, rhs, .. }
+        | LpirOp::IgtU { lhs, rhs, .. }
+        | LpirOp::IgeU { lhs, rhs, .. }
+        | LpirOp::Ishl { lhs, rhs, .. }
+        | LpirOp::IshrS { lhs, rhs, .. }
+        | LpirOp::IshrU { lhs, rhs, .. } => ([lhs, rhs, none], 0),
+        LpirOp::FdivConstF32 { lhs, rhs, .. } => ([lhs, none, none], rhs.to_bits()),
+        LpirOp::Fneg { src, .. }
+        | LpirOp::Fabs { src, .. }
+        | LpirOp::Fsqrt { src, .. }
+        | LpirOp::Ffloor { src, .. }
+        | LpirOp::Fceil { src, .. }
+        | LpirOp::Ftrunc { src, .. }
+        | LpirOp::Fnearest { src, .. }
+        | LpirOp::Ineg { src, .. }
+        | LpirOp::Ibnot { src, .. }
+        | LpirOp::FtoiSatS { src, .. }
+        | LpirOp::FtoiSatU { src, .. }
+        | LpirOp::ItofS { src, .. }
+        | LpirOp::ItofU { src, .. }
+        | LpirOp::FfromI32Bits { src, .. }
+        | LpirOp::FtoUnorm16 { src, .. }
+        | LpirOp::FtoUnorm8 { src, .. }
+        | LpirOp::Unorm16toF { src, .. }
+        | LpirOp::Unorm8toF { src, .. } => ([src, none, none], 0),
+        LpirOp::IaddImm { src, imm, .. }
+        | LpirOp::IsubImm { src, imm, .. }
+        | LpirOp::ImulImm { src, imm, .. }
+        | LpirOp::IshlImm { src, imm, .. }
+        | LpirOp::IshrSImm { src, imm, .. }
+        | LpirOp::IshrUImm { src, imm, .. }
+        | LpirOp::IeqImm { src, imm, .. } => ([src, none, none], imm as u32),
+        LpirOp::Select {
+            cond,
+            if_true,
+            if_false,
+            ..
+        } => ([cond, if_true, if_false], 0),
+        _ => return None,
+    };
+    Some(Key {
+        opcode,
+        operands,
+        imm,
+    })
+}
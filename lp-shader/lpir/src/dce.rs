@@ -0,0 +1,91 @@
+//! LPIR dead-code elimination.
+//!
+//! Removes pure computations whose result is never read, and copies of a
+//! vreg into itself. A vreg is dead when nothing in the function reads it;
+//! removing its writes can leave their operands dead in turn, so the pass
+//! repeats until nothing changes. Integer division and remainder (which trap
+//! on some targets), loads, stores and calls are always kept.
+
+use alloc::vec::Vec;
+
+use crate::lpir_module::IrFunction;
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{for_each_use, rebuild_body};
+
+/// Remove dead ops from `func.body`. Returns the number of ops removed.
+pub fn eliminate_dead_code(func: &mut IrFunction) -> usize {
+    let mut reads = Vec::new();
+    reads.resize(func.vreg_types.len(), 0u32);
+    for op in func.body.iter() {
+        if !is_self_copy(op) {
+            for_each_use(func, op, |v| {
+                if let Some(n) = reads.get_mut(v.0 as usize) {
+                    *n += 1;
+                }
+            });
+        }
+    }
+
+    let mut dead = Vec::new();
+    dead.resize(func.body.len(), false);
+    let mut removed = 0;
+    loop {
+        let before = removed;
+        // Backwards, so a chain of dead ops goes in one sweep.
+        for i in (0..func.body.len()).rev() {
+            if dead[i] {
+                continue;
+            }
+            let op = &func.body[i];
+            let removable = is_self_copy(op)
+                || (is_removable(op)
+                    && op
+                        .def_vreg()
+                        .is_some_and(|dst| reads.get(dst.0 as usize) == Some(&0)));
+            if !removable {
+                continue;
+            }
+            dead[i] = true;
+            removed += 1;
+            if !is_self_copy(op) {
+                for_each_use(func, op, |v| {
+                    if let Some(n) = reads.get_mut(v.0 as usize) {
+                        *n -= 1;
+                    }
+                });
+            }
+        }
+        if removed == before {
+            break;
+        }
+    }
+
+    if removed > 0 {
+        rebuild_body(func, |i, op, out| {
+            if !dead[i] {
+                out.push(op.clone());
+            }
+        });
+    }
+    removed
+}
+
+fn is_self_copy(op: &LpirOp) -> bool {
+    matches!(op, LpirOp::Copy { dst, src } if dst == src)
+}
+
+/// Pure and unable to trap, so dropping it changes nothing but its result.
+fn is_removable(op: &LpirOp) -> bool {
+    !matches!(
+        op,
+        LpirOp::IdivS { .. }
+            | LpirOp::IdivU { .. }
+            | LpirOp::IremS { .. }
+            | LpirOp::IremU { .. }
+            | LpirOp::Load { .. }
+            | LpirOp::Load8U { .. }
+            | LpirOp::Load8S { .. }
+            | LpirOp::Load16U { .. }
+            | LpirOp::Load16S { .. }
+    )
+}
@@ -0,0 +1,625 @@
+//! LPIR function inliner.
+//!
+//! Module-level pass that splices local callee bodies into their call sites.
+//! An inlined call binds its arguments to the callee's parameters, and every
+//! `return` becomes copies into the call's result vregs. A callee that can
+//! return from anywhere but its last op is wrapped in a [`LpirOp::Block`] so
+//! those returns become [`LpirOp::ExitBlock`]; the block's `End` is where the
+//! call used to return to. A callee that only returns at the end needs no
+//! block at all.
+//!
+//! Callees keep their own definitions: every function is still an export
+//! (the runtime and filetests call any of them by name), so inlining always
+//! costs code size, and the cost model is about how much a target can spend.
+//! Under [`InlineMode::Auto`]:
+//!
+//! - a callee of at most `small_func_threshold` ops is inlined at every site;
+//! - a callee with exactly one call site in the module is inlined there when
+//!   `always_inline_single_site` is set;
+//! - `max_growth_budget` caps how many ops any one function may grow by;
+//! - `module_op_budget` caps the module's total op count after inlining. This
+//!   is the per-target JIT memory budget: a backend with a fixed code region
+//!   fills it in when the config leaves it unset.
+//!
+//! [`InlineMode::Always`] inlines every site the pass can splice, ignoring
+//! size and budgets alike. Functions are processed callees-first, so a caller sees its
+//! callees' already-inlined size. Recursive functions are never inlined (GLSL
+//! forbids recursion; the IR does not). Only bodies change — function ids,
+//! names and signatures stay as they were, so module metadata stays valid.
+//!
+//! Inlining moves ops, it never changes them: results are bit-identical to
+//! the call on every backend.
+
+use alloc::vec::Vec;
+
+use lp_collection::{VecMap, VecSet};
+
+use crate::compiler_config::{InlineConfig, InlineMode};
+use crate::lpir_module::{IrFunction, LpirBody, LpirModule, SlotDecl};
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{has_offsets, relocate};
+use crate::types::{CalleeRef, FuncId, IrType, SlotId, VReg, VRegRange};
+
+/// What [`inline_module`] did.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InlineStats {
+    /// Call sites replaced by the callee's body.
+    pub sites_inlined: usize,
+    /// Call sites the cost model wanted but a budget kept as calls.
+    pub sites_over_budget: usize,
+    /// Ops added across the module.
+    pub ops_added: usize,
+}
+
+/// Whether [`inline_module`] can change `module` under `config`: inlining is
+/// enabled and some function calls a local one. Lets a backend that borrows
+/// its module skip the copy when there is nothing to do.
+pub fn wants_inlining(module: &LpirModule, config: &InlineConfig) -> bool {
+    config.mode != InlineMode::Never
+        && module
+            .functions
+            .values()
+            .any(|func| func.body.iter().any(is_local_call))
+}
+
+/// Inline local calls in `module` in place, as `config` allows.
+pub fn inline_module(module: &mut LpirModule, config: &InlineConfig) -> InlineStats {
+    let mut stats = InlineStats::default();
+    if !wants_inlining(module, config) {
+        return stats;
+    }
+    let graph = CallGraph::build(module);
+    let mut module_ops: usize = module.functions.values().map(|f| f.body.len()).sum();
+    for &caller_id in &graph.bottom_up {
+        let Some(rewritten) = inline_into(
+            module,
+            caller_id,
+            &graph,
+            config,
+            &mut module_ops,
+            &mut stats,
+        ) else {
+            continue;
+        };
+        if let Some(caller) = module.functions.get_mut(&caller_id) {
+            caller.body = rewritten.body;
+            caller.vreg_types = rewritten.vreg_types;
+            caller.slots = rewritten.slots;
+            caller.vreg_pool = rewritten.vreg_pool;
+        }
+    }
+    stats
+}
+
+fn is_local_call(op: &LpirOp) -> bool {
+    matches!(
+        op,
+        LpirOp::Call {
+            callee: CalleeRef::Local(_),
+            ..
+        }
+    )
+}
+
+/// Local call structure of a module.
+struct CallGraph {
+    /// Every local function, callees before their callers.
+    bottom_up: Vec<FuncId>,
+    /// Local call sites per callee, across the module.
+    sites: VecMap<FuncId, usize>,
+    /// Functions on a call cycle.
+    recursive: VecSet<FuncId>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    OnStack,
+    Done,
+}
+
+impl CallGraph {
+    fn build(module: &LpirModule) -> Self {
+        let mut callees: VecMap<FuncId, Vec<FuncId>> = VecMap::new();
+        let mut sites: VecMap<FuncId, usize> = VecMap::new();
+        for (&id, func) in module.functions.iter() {
+            let mut out = Vec::new();
+            for op in func.body.iter() {
+                if let LpirOp::Call {
+                    callee: CalleeRef::Local(callee),
+                    ..
+                } = op
+                {
+                    if module.functions.contains_key(callee) {
+                        out.push(*callee);
+                        *sites.entry(*callee).or_insert(0) += 1;
+                    }
+                }
+            }
+            callees.insert(id, out);
+        }
+
+        let mut graph = Self {
+            bottom_up: Vec::with_capacity(module.functions.len()),
+            sites,
+            recursive: VecSet::new(),
+        };
+        let mut visits: VecMap<FuncId, Visit> = VecMap::new();
+        let mut stack = Vec::new();
+        for &id in module.functions.keys() {
+            if !visits.contains_key(&id) {
+                graph.visit(id, &callees, &mut visits, &mut stack);
+            }
+        }
+        graph
+    }
+
+    fn visit(
+        &mut self,
+        id: FuncId,
+        callees: &VecMap<FuncId, Vec<FuncId>>,
+        visits: &mut VecMap<FuncId, Visit>,
+        stack: &mut Vec<FuncId>,
+    ) {
+        visits.insert(id, Visit::OnStack);
+        stack.push(id);
+        for &callee in callees.get(&id).into_iter().flatten() {
+            match visits.get(&callee) {
+                None => self.visit(callee, callees, visits, stack),
+                Some(Visit::OnStack) => {
+                    let from = stack.iter().rposition(|&f| f == callee).unwrap_or(0);
+                    for &f in &stack[from..] {
+                        self.recursive.insert(f);
+                    }
+                }
+                Some(Visit::Done) => {}
+            }
+        }
+        stack.pop();
+        visits.insert(id, Visit::Done);
+        self.bottom_up.push(id);
+    }
+
+    fn site_count(&self, id: FuncId) -> usize {
+        self.sites.get(&id).copied().unwrap_or(0)
+    }
+}
+
+/// The parts of a caller an inline rewrites.
+struct Rewritten {
+    body: LpirBody,
+    vreg_types: Vec<IrType>,
+    slots: Vec<SlotDecl>,
+    vreg_pool: Vec<VReg>,
+}
+
+impl Rewritten {
+    fn alloc_vreg(&mut self, ty: IrType) -> VReg {
+        let v = VReg(self.vreg_types.len() as u32);
+        self.vreg_types.push(ty);
+        v
+    }
+
+    fn push_pool(&mut self, vregs: impl Iterator<Item = VReg>) -> VRegRange {
+        let start = self.vreg_pool.len() as u32;
+        self.vreg_pool.extend(vregs);
+        VRegRange {
+            start,
+            count: (self.vreg_pool.len() as u32 - start) as u16,
+        }
+    }
+}
+
+/// Rebuild `caller_id` with its chosen call sites inlined; `None` when no site
+/// was.
+fn inline_into(
+    module: &LpirModule,
+    caller_id: FuncId,
+    graph: &CallGraph,
+    config: &InlineConfig,
+    module_ops: &mut usize,
+    stats: &mut InlineStats,
+) -> Option<Rewritten> {
+    let caller = module.functions.get(&caller_id)?;
+    let candidate = |op: &LpirOp| -> Option<&IrFunction> {
+        let LpirOp::Call {
+            callee: CalleeRef::Local(id),
+            ..
+        } = op
+        else {
+            return None;
+        };
+        if *id == caller_id || graph.recursive.contains(id) {
+            return None;
+        }
+        let callee = module.functions.get(id)?;
+        let wanted = match config.mode {
+            InlineMode::Never => false,
+            InlineMode::Always => true,
+            InlineMode::Auto => {
+                callee.body.len() <= config.small_func_threshold
+                    || (config.always_inline_single_site && graph.site_count(*id) == 1)
+            }
+        };
+        wanted.then_some(callee)
+    };
+    if !caller.body.iter().any(|op| candidate(op).is_some()) {
+        return None;
+    }
+
+    let mut out = Rewritten {
+        body: LpirBody::new(),
+        vreg_types: caller.vreg_types.clone(),
+        slots: caller.slots.clone(),
+        vreg_pool: caller.vreg_pool.clone(),
+    };
+    let mut old_to_new = Vec::with_capacity(caller.body.len() + 1);
+    let mut relocate_at = Vec::new();
+    let mut growth = 0usize;
+    let mut inlined = false;
+
+    for op in caller.body.iter() {
+        old_to_new.push(out.body.len() as u32);
+        if let (Some(callee), LpirOp::Call { args, results, .. }) = (candidate(op), op) {
+            let args = caller.pool_slice(*args);
+            let results = caller.pool_slice(*results);
+            if let Some(plan) = SplicePlan::new(callee, args, results, &caller.vreg_types) {
+                let added = plan.len.saturating_sub(1);
+                let affordable = config.mode == InlineMode::Always
+                    || (config
+                        .max_growth_budget
+                        .is_none_or(|budget| growth + added <= budget)
+                        && config
+                            .module_op_budget
+                            .is_none_or(|budget| *module_ops + added <= budget));
+                if affordable {
+                    plan.emit(&mut out, callee, args, results);
+                    growth += added;
+                    *module_ops += added;
+                    stats.sites_inlined += 1;
+                    stats.ops_added += added;
+                    inlined = true;
+                    continue;
+                }
+                stats.sites_over_budget += 1;
+            }
+        }
+        if has_offsets(op) {
+            relocate_at.push(out.body.len());
+        }
+        out.body.push(op.clone());
+    }
+    if !inlined {
+        return None;
+    }
+    old_to_new.push(out.body.len() as u32);
+    for at in relocate_at {
+        relocate(&mut out.body[at], &old_to_new);
+    }
+    Some(out)
+}
+
+/// How one call site splices, decided before anything is emitted so the
+/// budget check sees the exact cost.
+struct SplicePlan {
+    /// Per callee parameter (vmctx and sret included): the caller vreg it
+    /// reads directly, or `None` to copy the argument into a fresh vreg.
+    params: Vec<Option<VReg>>,
+    /// Whether some `return` is not the last op and needs a block to exit.
+    needs_block: bool,
+    /// Ops the splice emits.
+    len: usize,
+}
+
+impl SplicePlan {
+    /// `None` when the site cannot be spliced: a signature mismatch, a hidden
+    /// parameter the callee writes, or a `return` inside one of the callee's
+    /// own blocks (its exit would bind to that block instead).
+    fn new(
+        callee: &IrFunction,
+        args: &[VReg],
+        results: &[VReg],
+        caller_types: &[IrType],
+    ) -> Option<Self> {
+        let param_slots = usize::from(callee.total_param_slots());
+        if args.len() != param_slots
+            || results.len() != callee.return_types.len()
+            || results
+                .iter()
+                .zip(&callee.return_types)
+                .any(|(r, ty)| caller_types.get(r.0 as usize) != Some(ty))
+        {
+            return None;
+        }
+
+        let mut written = alloc::vec![false; callee.vreg_types.len()];
+        let mut mark = |v: VReg| {
+            if let Some(w) = written.get_mut(v.0 as usize) {
+                *w = true;
+            }
+        };
+        for op in callee.body.iter() {
+            if let Some(dst) = op.def_vreg() {
+                mark(dst);
+            }
+            if let LpirOp::Call { results, .. } = op {
+                callee
+                    .pool_slice(*results)
+                    .iter()
+                    .copied()
+                    .for_each(&mut mark);
+            }
+        }
+
+        // A parameter the callee only reads can alias the caller's argument,
+        // unless the call writes that vreg as a result.
+        let hidden = callee.hidden_param_slots() as usize;
+        let mut params = Vec::with_capacity(param_slots);
+        let mut len = 0;
+        for (j, &arg) in args.iter().enumerate() {
+            let read_only = !written.get(j).copied().unwrap_or(true);
+            let same_type = caller_types.get(arg.0 as usize) == callee.vreg_types.get(j);
+            if read_only && same_type && !results.contains(&arg) {
+                params.push(Some(arg));
+            } else if j < hidden || !same_type {
+                return None;
+            } else {
+                params.push(None);
+                len += 1;
+            }
+        }
+
+        let last = callee.body.len().checked_sub(1);
+        let mut frames: Vec<bool> = Vec::new();
+        let mut needs_block = false;
+        for (k, op) in callee.body.iter().enumerate() {
+            match op {
+                LpirOp::IfStart { .. }
+                | LpirOp::LoopStart { .. }
+                | LpirOp::SwitchStart { .. }
+                | LpirOp::CaseStart { .. }
+                | LpirOp::DefaultStart { .. } => frames.push(false),
+                LpirOp::Block { .. } => frames.push(true),
+                LpirOp::End => {
+                    frames.pop();
+                }
+                _ => {}
+            }
+            match op {
+                LpirOp::Return { values } => {
+                    if frames.contains(&true) {
+                        return None;
+                    }
+                    len += usize::from(values.count);
+                    if Some(k) != last {
+                        needs_block = true;
+                        len += 1;
+                    }
+                }
+                _ => len += 1,
+            }
+        }
+        if needs_block {
+            len += 2;
+        }
+        Some(Self {
+            params,
+            needs_block,
+            len,
+        })
+    }
+
+    fn emit(&self, out: &mut Rewritten, callee: &IrFunction, args: &[VReg], results: &[VReg]) {
+        let mut map = Vec::with_capacity(callee.vreg_types.len());
+        for (v, &ty) in callee.vreg_types.iter().enumerate() {
+            match self.params.get(v).copied().flatten() {
+                Some(direct) => map.push(direct),
+                None => map.push(out.alloc_vreg(ty)),
+            }
+        }
+        let slot_base = out.slots.len() as u32;
+        out.slots.extend(callee.slots.iter().cloned());
+
+        let block_at = out.body.len();
+        if self.needs_block {
+            out.body.push(LpirOp::Block { end_offset: 0 });
+        }
+        for (j, direct) in self.params.iter().enumerate() {
+            if direct.is_none() {
+                out.body.push(LpirOp::Copy {
+                    dst: map[j],
+                    src: args[j],
+                });
+            }
+        }
+
+        let last = callee.body.len().checked_sub(1);
+        let mut callee_to_new = Vec::with_capacity(callee.body.len() + 1);
+        let mut relocate_at = Vec::new();
+        for (k, op) in callee.body.iter().enumerate() {
+            callee_to_new.push(out.body.len() as u32);
+            match op {
+                LpirOp::Return { values } => {
+                    for (&dst, &v) in results.iter().zip(callee.pool_slice(*values)) {
+                        out.body.push(LpirOp::Copy {
+                            dst,
+                            src: map[v.0 as usize],
+                        });
+                    }
+                    if Some(k) != last {
+                        out.body.push(LpirOp::ExitBlock);
+                    }
+                }
+                LpirOp::Call {
+                    callee: target,
+                    args,
+                    results,
+                } => {
+                    let args =
+                        out.push_pool(callee.pool_slice(*args).iter().map(|v| map[v.0 as usize]));
+                    let results = out.push_pool(
+                        callee
+                            .pool_slice(*results)
+                            .iter()
+                            .map(|v| map[v.0 as usize]),
+                    );
+                    out.body.push(LpirOp::Call {
+                        callee: *target,
+                        args,
+                        results,
+                    });
+                }
+                _ => {
+                    if has_offsets(op) {
+                        relocate_at.push(out.body.len());
+                    }
+                    out.body
+                        .push(remap_op(op, |v| map[v.0 as usize], slot_base));
+                }
+            }
+        }
+        callee_to_new.push(out.body.len() as u32);
+        for at in relocate_at {
+            relocate(&mut out.body[at], &callee_to_new);
+        }
+
+        if self.needs_block {
+            out.body.push(LpirOp::End);
+            out.body[block_at] = LpirOp::Block {
+                end_offset: out.body.len() as u32,
+            };
+        }
+    }
+}
+
+/// `op` with every vreg operand passed through `map` and slots shifted by
+/// `slot_base`. Calls and returns carry pool ranges and are handled by the
+/// caller.
+fn remap_op(op: &LpirOp, map: impl Fn(VReg) -> VReg, slot_base: u32) -> LpirOp {
+    let mut op = op.clone();
+    match &mut op {
+        LpirOp::Fadd { dst, lhs, rhs }
+        | LpirOp::Fsub { dst, lhs, rhs }
+        | LpirOp::Fmul { dst, lhs, rhs }
+        | LpirOp::Fdiv { dst, lhs, rhs }
+        | LpirOp::Fmin { dst, lhs, rhs }
+        | LpirOp::Fmax { dst, lhs, rhs }
+        | LpirOp::Iadd { dst, lhs, rhs }
+        | LpirOp::Isub { dst, lhs, rhs }
+        | LpirOp::Imul { dst, lhs, rhs }
+        | LpirOp::IdivS { dst, lhs, rhs }
+        | LpirOp::IdivU { dst, lhs, rhs }
+        | LpirOp::IremS { dst, lhs, rhs }
+        | LpirOp::IremU { dst, lhs, rhs }
+        | LpirOp::Feq { dst, lhs, rhs }
+        | LpirOp::Fne { dst, lhs, rhs }
+        | LpirOp::Flt { dst, lhs, rhs }
+        | LpirOp::Fle { dst, lhs, rhs }
+        | LpirOp::Fgt { dst, lhs, rhs }
+        | LpirOp::Fge { dst, lhs, rhs }
+        | LpirOp::Ieq { dst, lhs, rhs }
+        | LpirOp::Ine { dst, lhs, rhs }
+        | LpirOp::IltS { dst, lhs, rhs }
+        | LpirOp::IleS { dst, lhs, rhs }
+        | LpirOp::IgtS { dst, lhs, rhs }
+        | LpirOp::IgeS { dst, lhs, rhs }
+        | LpirOp::IltU { dst, lhs, rhs }
+        | LpirOp::IleU { dst, lhs, rhs }
+        | LpirOp::IgtU { dst, lhs, rhs }
+        | LpirOp::IgeU { dst, lhs, rhs }
+        | LpirOp::Iand { dst, lhs, rhs }
+        | LpirOp::Ior { dst, lhs, rhs }
+        | LpirOp::Ixor { dst, lhs, rhs }
+        | LpirOp::Ishl { dst, lhs, rhs }
+        | LpirOp::IshrS { dst, lhs, rhs }
+        | LpirOp::IshrU { dst, lhs, rhs } => {
+            *dst = map(*dst);
+            *lhs = map(*lhs);
+            *rhs = map(*rhs);
+        }
+        LpirOp::FdivConstF32 { dst, lhs, .. } => {
+            *dst = map(*dst);
+            *lhs = map(*lhs);
+        }
+        LpirOp::Fneg { dst, src }
+        | LpirOp::Fabs { dst, src }
+        | LpirOp::Fsqrt { dst, src }
+        | LpirOp::Ffloor { dst, src }
+        | LpirOp::Fceil { dst, src }
+        | LpirOp::Ftrunc { dst, src }
+        | LpirOp::Fnearest { dst, src }
+        | LpirOp::Ineg { dst, src }
+        | LpirOp::Ibnot { dst, src }
+        | LpirOp::FtoiSatS { dst, src }
+        | LpirOp::FtoiSatU { dst, src }
+        | LpirOp::ItofS { dst, src }
+        | LpirOp::ItofU { dst, src }
+        | LpirOp::FfromI32Bits { dst, src }
+        | LpirOp::FtoUnorm16 { dst, src }
+        | LpirOp::FtoUnorm8 { dst, src }
+        | LpirOp::Unorm16toF { dst, src }
+        | LpirOp::Unorm8toF { dst, src }
+        | LpirOp::Copy { dst, src }
+        | LpirOp::IaddImm { dst, src, .. }
+        | LpirOp::IsubImm { dst, src, .. }
+        | LpirOp::ImulImm { dst, src, .. }
+        | LpirOp::IshlImm { dst, src, .. }
+        | LpirOp::IshrSImm { dst, src, .. }
+        | LpirOp::IshrUImm { dst, src, .. }
+        | LpirOp::IeqImm { dst, src, .. } => {
+            *dst = map(*dst);
+            *src = map(*src);
+        }
+        LpirOp::FconstF32 { dst, .. }
+        | LpirOp::IconstI32 { dst, .. }
+        | LpirOp::RodataAddr { dst } => *dst = map(*dst),
+        LpirOp::Select {
+            dst,
+            cond,
+            if_true,
+            if_false,
+        } => {
+            *dst = map(*dst);
+            *cond = map(*cond);
+            *if_true = map(*if_true);
+            *if_false = map(*if_false);
+        }
+        LpirOp::SlotAddr { dst, slot } => {
+            *dst = map(*dst);
+            *slot = SlotId(slot.0 + slot_base);
+        }
+        LpirOp::Load { dst, base, .. }
+        | LpirOp::Load8U { dst, base, .. }
+        | LpirOp::Load8S { dst, base, .. }
+        | LpirOp::Load16U { dst, base, .. }
+        | LpirOp::Load16S { dst, base, .. } => {
+            *dst = map(*dst);
+            *base = map(*base);
+        }
+        LpirOp::Store { base, value, .. }
+        | LpirOp::Store8 { base, value, .. }
+        | LpirOp::Store16 { base, value, .. } => {
+            *base = map(*base);
+            *value = map(*value);
+        }
+        LpirOp::Memcpy {
+            dst_addr, src_addr, ..
+        } => {
+            *dst_addr = map(*dst_addr);
+            *src_addr = map(*src_addr);
+        }
+        LpirOp::IfStart { cond, .. } | LpirOp::BrIfNot { cond } => *cond = map(*cond),
+        LpirOp::SwitchStart { selector, .. } => *selector = map(*selector),
+        LpirOp::Else
+        | LpirOp::LoopStart { .. }
+        | LpirOp::CaseStart { .. }
+        | LpirOp::DefaultStart { .. }
+        | LpirOp::End
+        | LpirOp::Block { .. }
+        | LpirOp::Break
+        | LpirOp::Continue
+        | LpirOp::ExitBlock
+        | LpirOp::Call { .. }
+        | LpirOp::Return { .. } => {}
+    }
+    op
+}
@@ -159,11 +159,12 @@ pub fn interpret_with_limits(
     }
     full.extend_from_slice(args);
     // One linear stack shared by all frames so slot addresses stay valid
-    // across calls (out-parameters, sret pointers).
-    let mut stack: Vec<u8> = Vec::new();
+    // across calls (out-parameters, sret pointers). The module's read-only
+    // data sits at its base.
+    let mut stack = module.rodata_bytes(f32::to_bits);
     let mut fuel = limits.fuel.unwrap_or(0);
     exec_func(
-        module, func, &full, imports, 0, limits, &mut fuel, &mut stack,
+        module, func, &full, imports, 0, limits, &mut fuel, &mut stack, 0,
     )
 }
 
@@ -185,7 +186,7 @@ pub struct EntryOutput {
 /// image and (for aggregate-returning functions) a real sret destination.
 ///
 /// The shared interpreter stack is laid out `[vmctx image][sret buffer]
-/// [call frames…]`:
+/// [rodata][call frames…]`:
 /// - `vmctx_image` is copied to the stack base and the VMContext pointer
 ///   (hidden arg 0) is `0`, so vmctx-relative uniform/global loads and
 ///   stores (offsets baked in by the frontend, region sized by
@@ -229,12 +230,23 @@ pub fn interpret_entry(
         full.push(Value::I32(sret_base as i32));
     }
     full.extend_from_slice(args);
-    let mut stack: Vec<u8> = Vec::with_capacity(sret_base + sret_size);
+    let rodata = module.rodata_bytes(f32::to_bits);
+    let rodata_base = sret_base + sret_size;
+    let mut stack: Vec<u8> = Vec::with_capacity(rodata_base + rodata.len());
     stack.extend_from_slice(vmctx_image);
-    stack.resize(sret_base + sret_size, 0);
+    stack.resize(rodata_base, 0);
+    stack.extend_from_slice(&rodata);
     let mut fuel = limits.fuel.unwrap_or(0);
     let values = exec_func(
-        module, func, &full, imports, 0, limits, &mut fuel, &mut stack,
+        module,
+        func,
+        &full,
+        imports,
+        0,
+        limits,
+        &mut fuel,
+        &mut stack,
+        rodata_base,
     )?;
     let sret_bytes = stack[sret_base..sret_base + sret_size].to_vec();
     let vmctx_bytes = stack[..vmctx_image.len()].to_vec();
@@ -258,6 +270,7 @@ fn exec_func(
     limits: InterpLimits,
     fuel: &mut u64,
     stack: &mut Vec<u8>,
+    rodata_base: usize,
 ) -> Result<Vec<Value>, InterpError> {
     if depth > limits.max_depth {
         return Err(InterpError::StackOverflow);
@@ -507,6 +520,7 @@ fn exec_func(
                         limits,
                         fuel,
                         stack,
+                        rodata_base,
                     )?
                 } else {
                     return Err(InterpError::Internal("bad callee".into()));
@@ -520,6 +534,10 @@ fn exec_func(
                 }
                 pc += 1;
             }
+            LpirOp::RodataAddr { dst } => {
+                set_reg(&mut regs, *dst, Value::I32(rodata_base as i32))?;
+                pc += 1;
+            }
             op => {
                 eval_op(func, op, &mut regs, &slot_off, stack)?;
                 pc += 1;
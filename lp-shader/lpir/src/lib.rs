@@ -9,12 +9,21 @@ extern crate alloc;
 pub mod builder;
 pub mod compiler_config;
 pub mod const_fold;
+pub mod cse;
+pub mod dce;
+pub mod inline;
 pub mod interp;
+pub mod licm;
 pub mod lpir_module;
 pub mod lpir_op;
 pub mod parse;
+pub mod pipeline;
 pub mod print;
+mod rewrite;
+pub mod scalarize;
+pub mod strength_reduce;
 pub mod types;
+pub mod unroll;
 pub mod validate;
 
 #[cfg(test)]
@@ -22,15 +31,18 @@ mod tests;
 
 pub use builder::{FunctionBuilder, ModuleBuilder};
 pub use compiler_config::{
-    CompilerConfig, ConfigError, InlineConfig, InlineMode, TexelFetchBoundsMode, TextureConfig,
+    CompilerConfig, ConfigError, InlineConfig, InlineMode, OptConfig, TexelFetchBoundsMode,
+    TextureConfig,
 };
+pub use inline::{InlineStats, inline_module, wants_inlining};
 pub use interp::{
     DEFAULT_MAX_DEPTH, EntryOutput, ImportHandler, InterpError, InterpLimits, Value, interpret,
     interpret_entry, interpret_with_depth, interpret_with_limits,
 };
-pub use lpir_module::{ImportDecl, IrFunction, LpirModule, SlotDecl, VMCTX_VREG};
+pub use lpir_module::{ImportDecl, IrFunction, LpirModule, RodataWord, SlotDecl, VMCTX_VREG};
 pub use lpir_op::LpirOp;
 pub use parse::{ParseError, parse_module};
+pub use pipeline::{Pass, PassPipeline};
 pub use print::print_module;
 pub use types::{CalleeRef, FloatMode, FuncId, ImportId, IrType, SlotId, VReg, VRegRange};
 pub use validate::{ValidationError, validate_function, validate_module};
@@ -0,0 +1,204 @@
+//! LPIR loop-invariant code motion.
+//!
+//! Moves a computation out of a loop when every iteration would compute the
+//! same value: it is pure, cannot trap, and none of its operands is written
+//! inside the loop. The op is placed just before the outermost loop it is
+//! invariant in, so a computation that only depends on uniforms leaves a
+//! nested per-pixel loop entirely. Ops moved earlier feed the same decision
+//! for the ops that read them.
+//!
+//! LPIR vregs can be reassigned, so only an op whose result has no other
+//! write anywhere in the function moves; it then holds the same value
+//! wherever it used to be read. A moved op may run once where the loop would
+//! have left before reaching it — harmless for a pure, non-trapping op.
+//! Integer division and remainder (which trap on some targets), loads and
+//! calls stay where they are.
+//!
+//! A constant does not move on its own account: hoisting it saves nothing a
+//! backend does not already get by rematerializing it, and it would hold a
+//! register across the whole loop. It moves only as the operand of an op that
+//! is hoisted, which needs it defined first.
+
+use alloc::vec::Vec;
+
+use crate::lpir_module::IrFunction;
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{def_counts, end_index, for_each_def, for_each_use, rebuild_body};
+use crate::types::VReg;
+
+/// A loop enclosing the op being visited.
+struct OpenLoop {
+    /// Index of its `LoopStart`.
+    start: usize,
+    /// Index of its `End`.
+    end: usize,
+    /// Vregs written inside it, sorted.
+    written: Vec<VReg>,
+}
+
+impl OpenLoop {
+    fn contains(&self, at: usize) -> bool {
+        self.start < at && at < self.end
+    }
+}
+
+/// Hoist loop-invariant ops in `func.body` out of their loops. Returns the
+/// number of ops moved.
+pub fn hoist_loop_invariants(func: &mut IrFunction) -> usize {
+    if !func
+        .body
+        .iter()
+        .any(|op| matches!(op, LpirOp::LoopStart { .. }))
+    {
+        return 0;
+    }
+    let counts = def_counts(func);
+    let single_def = |v: VReg| counts.get(v.0 as usize) == Some(&1);
+    let param_slots = func.total_param_slots() as usize;
+
+    // Where each single-write vreg is finally written: its own index, or the
+    // `LoopStart` it now sits in front of.
+    let mut placed: Vec<Option<usize>> = Vec::new();
+    placed.resize(func.vreg_types.len(), None);
+    let mut loops: Vec<OpenLoop> = Vec::new();
+    // (loop start, op index), in body order.
+    let mut moves: Vec<(usize, usize)> = Vec::new();
+    // Constants still inside a loop, by vreg: free to go along with an op
+    // that reads them.
+    let mut loop_consts: Vec<bool> = Vec::new();
+    loop_consts.resize(func.vreg_types.len(), false);
+    let mut carried: Vec<VReg> = Vec::new();
+
+    for i in 0..func.body.len() {
+        while loops.last().is_some_and(|l| i >= l.end) {
+            loops.pop();
+        }
+        let op = &func.body[i];
+        if let LpirOp::LoopStart { .. } = op {
+            let end = end_index(op).unwrap_or(i);
+            let mut written = Vec::new();
+            for j in i + 1..end {
+                for_each_def(func, &func.body[j], |v| written.push(v));
+            }
+            written.sort_unstable_by_key(|v| v.0);
+            written.dedup();
+            loops.push(OpenLoop {
+                start: i,
+                end,
+                written,
+            });
+            continue;
+        }
+        let Some(dst) = op.def_vreg() else {
+            continue;
+        };
+        if !single_def(dst) || (dst.0 as usize) < param_slots {
+            continue;
+        }
+        placed[dst.0 as usize] = Some(i);
+        if loops.is_empty() {
+            continue;
+        }
+        if is_constant(op) {
+            loop_consts[dst.0 as usize] = true;
+            continue;
+        }
+        if !is_hoistable(op) {
+            continue;
+        }
+
+        // Loops (outermost first) this op must stay inside: the deepest one
+        // any operand is written in.
+        let mut depth = 0;
+        carried.clear();
+        for_each_use(func, op, |v| {
+            // Wherever it sits, it can be moved ahead of this op.
+            let needed = if loop_consts.get(v.0 as usize) == Some(&true) {
+                carried.push(v);
+                0
+            } else if single_def(v) {
+                match placed.get(v.0 as usize).copied().flatten() {
+                    Some(at) => loops
+                        .iter()
+                        .rposition(|l| l.contains(at))
+                        .map_or(0, |d| d + 1),
+                    // A parameter, written before every loop.
+                    None if (v.0 as usize) < param_slots => 0,
+                    None => loops.len(),
+                }
+            } else {
+                loops
+                    .iter()
+                    .rposition(|l| l.written.binary_search_by_key(&v.0, |w| w.0).is_ok())
+                    .map_or(0, |d| d + 1)
+            };
+            depth = depth.max(needed);
+        });
+        if depth < loops.len() {
+            let target = loops[depth].start;
+            carried.sort_unstable_by_key(|v| v.0);
+            carried.dedup();
+            for &c in &carried {
+                if let Some(at) = placed[c.0 as usize]
+                    && loops[depth].contains(at)
+                {
+                    loop_consts[c.0 as usize] = false;
+                    placed[c.0 as usize] = Some(target);
+                    moves.push((target, at));
+                }
+            }
+            placed[dst.0 as usize] = Some(target);
+            moves.push((target, i));
+        }
+    }
+    if moves.is_empty() {
+        return 0;
+    }
+
+    let moved = moves.len();
+    let mut leaving = Vec::new();
+    leaving.resize(func.body.len(), false);
+    for &(_, at) in &moves {
+        leaving[at] = true;
+    }
+    moves.sort_by_key(|&(target, _)| target);
+    let hoisted: Vec<(usize, LpirOp)> = moves
+        .iter()
+        .map(|&(target, at)| (target, func.body[at].clone()))
+        .collect();
+    let mut next = 0;
+    rebuild_body(func, |i, op, out| {
+        while let Some((target, hoisted_op)) = hoisted.get(next)
+            && *target == i
+        {
+            out.push(hoisted_op.clone());
+            next += 1;
+        }
+        if !leaving[i] {
+            out.push(op.clone());
+        }
+    });
+    moved
+}
+
+/// A constant, which moves only with an op that reads it.
+fn is_constant(op: &LpirOp) -> bool {
+    matches!(op, LpirOp::IconstI32 { .. } | LpirOp::FconstF32 { .. })
+}
+
+/// Pure and unable to trap, so running it earlier, or on a path that would
+/// have skipped it, changes nothing.
+fn is_hoistable(op: &LpirOp) -> bool {
+    !matches!(
+        op,
+        LpirOp::IdivS { .. }
+            | LpirOp::IdivU { .. }
+            | LpirOp::IremS { .. }
+            | LpirOp::IremU { .. }
+            | LpirOp::Load { .. }
+            | LpirOp::Load8U { .. }
+            | LpirOp::Load8S { .. }
+            | LpirOp::Load16U { .. }
+            | LpirOp::Load16S { .. }
+    ) && op.def_vreg().is_some()
+}
@@ -179,7 +179,8 @@ impl IrFunction {
     }
 
     /// Whether this function's body contains any memory-accessing ops
-    /// (word/narrow load/store, [`LpirOp::SlotAddr`], [`LpirOp::Memcpy`]).
+    /// (word/narrow load/store, [`LpirOp::SlotAddr`], [`LpirOp::RodataAddr`],
+    /// [`LpirOp::Memcpy`]).
     pub fn uses_memory(&self) -> bool {
         !self.slots.is_empty()
             || self.body.iter().any(|op| {
@@ -194,17 +195,31 @@ impl IrFunction {
                         | LpirOp::Store8 { .. }
                         | LpirOp::Store16 { .. }
                         | LpirOp::SlotAddr { .. }
+                        | LpirOp::RodataAddr { .. }
                         | LpirOp::Memcpy { .. }
                 )
             })
     }
 }
 
+/// One 32-bit word of a module's read-only data (`rodata i32 7, f32 0.5`).
+///
+/// Float words stay `f32` here: LPIR does not fix a float mode, so each
+/// backend encodes them the way it encodes [`LpirOp::FconstF32`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RodataWord {
+    I32(i32),
+    F32(f32),
+}
+
 /// Full LPIR module: imports and local functions (keyed by stable [`FuncId`]).
 #[derive(Clone, Debug, Default)]
 pub struct LpirModule {
     pub imports: Vec<ImportDecl>,
     pub functions: VecMap<FuncId, IrFunction>,
+    /// Read-only data shared by every function, addressed through
+    /// [`LpirOp::RodataAddr`]. Word `i` sits at byte offset `4 * i`.
+    pub rodata: Vec<RodataWord>,
 }
 
 impl LpirModule {
@@ -245,6 +260,26 @@ impl LpirModule {
         }
     }
 
+    /// [`Self::rodata`] as 32-bit words, with `f32_bits` encoding the float
+    /// words for the backend's float mode.
+    pub fn rodata_words(&self, f32_bits: impl Fn(f32) -> u32) -> Vec<u32> {
+        self.rodata
+            .iter()
+            .map(|word| match *word {
+                RodataWord::I32(value) => value as u32,
+                RodataWord::F32(value) => f32_bits(value),
+            })
+            .collect()
+    }
+
+    /// [`Self::rodata_words`] as little-endian bytes.
+    pub fn rodata_bytes(&self, f32_bits: impl Fn(f32) -> u32) -> Vec<u8> {
+        self.rodata_words(f32_bits)
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
     /// Resolve local function from `CalleeRef`, or `None` if import or unknown id.
     pub fn callee_as_function(&self, callee: CalleeRef) -> Option<&IrFunction> {
         match callee {
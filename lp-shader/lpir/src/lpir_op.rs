@@ -348,6 +348,11 @@ pub enum LpirOp {
         dst: VReg,
         slot: SlotId,
     },
+    /// Address of the module's [`crate::LpirModule::rodata`]. Memory there is
+    /// read-only: nothing may store to it.
+    RodataAddr {
+        dst: VReg,
+    },
     /// 32-bit load: `dst = i32[base + offset]`.
     /// Precondition: `base + offset` is 4-byte aligned.
     Load {
@@ -533,6 +538,7 @@ impl LpirOp {
             | LpirOp::Select { dst, .. }
             | LpirOp::Copy { dst, .. }
             | LpirOp::SlotAddr { dst, .. }
+            | LpirOp::RodataAddr { dst }
             | LpirOp::Load { dst, .. }
             | LpirOp::Load8U { dst, .. }
             | LpirOp::Load8S { dst, .. }
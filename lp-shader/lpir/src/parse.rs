@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use crate::builder::{FunctionBuilder, ModuleBuilder};
-use crate::lpir_module::{ImportDecl, IrFunction, LpirModule, VMCTX_VREG};
+use crate::lpir_module::{ImportDecl, IrFunction, LpirModule, RodataWord, VMCTX_VREG};
 use crate::lpir_op::LpirOp;
 use crate::types::{CalleeRef, FuncId, ImportId, IrType, SlotId, VReg};
 
@@ -55,6 +55,14 @@ pub fn parse_module(input: &str) -> Result<LpirModule, ParseError> {
             names.push((key, r));
             continue;
         }
+        if s.starts_with("rodata ") {
+            let words = parse_rodata(&mut s).map_err(|e| {
+                let (line, col) = line_col(input, line_start);
+                err(line, col, e.message)
+            })?;
+            mb.add_rodata(&words);
+            continue;
+        }
         if s.starts_with("entry ") {
             let self_id = mb.next_local_func_id();
             let func = parse_func_decl(
@@ -86,7 +94,7 @@ pub fn parse_module(input: &str) -> Result<LpirModule, ParseError> {
             line,
             col,
             format!(
-                "expected import, func, or entry func at {:?}",
+                "expected import, rodata, func, or entry func at {:?}",
                 line_start.chars().take(20).collect::<String>()
             ),
         ));
@@ -156,6 +164,30 @@ fn parse_import(s: &mut &str) -> Result<(ImportDecl, String), ParseError> {
     Ok((decl, key))
 }
 
+/// One `rodata i32 7, f32 0.5` line.
+fn parse_rodata(s: &mut &str) -> Result<Vec<RodataWord>, ParseError> {
+    let raw = *s;
+    let line_end = raw.find('\n').unwrap_or(raw.len());
+    let line = raw[..line_end].trim();
+    *s = &raw[line_end.saturating_add(1).min(raw.len())..];
+    let rest = line
+        .strip_prefix("rodata")
+        .ok_or_else(|| err(1, 1, "rodata"))?;
+    let mut words = Vec::new();
+    for word in rest.split(',') {
+        let (ty, value) = word
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| err(1, 1, "expected `i32 <int>` or `f32 <float>` rodata word"))?;
+        words.push(match ty {
+            "i32" => RodataWord::I32(parse_int_literal(value.trim())?),
+            "f32" => RodataWord::F32(parse_f32_literal(value.trim())?),
+            _ => return Err(err(1, 1, format!("unknown rodata word type {ty}"))),
+        });
+    }
+    Ok(words)
+}
+
 fn ir_type_from_str(s: &str) -> Result<IrType, ParseError> {
     match s.trim() {
         "f32" => Ok(IrType::F32),
@@ -1142,6 +1174,7 @@ fn parse_rhs_op(dst: VReg, rhs: &str) -> Result<LpirOp, ParseError> {
                 slot: SlotId(n),
             })
         }
+        "rodata_addr" => Ok(LpirOp::RodataAddr { dst }),
         "load" => Ok(LpirOp::Load {
             dst,
             base: parse_vreg_token(parts[1].trim_end_matches(','))?,
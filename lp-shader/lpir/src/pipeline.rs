@@ -0,0 +1,152 @@
+//! LPIR per-function optimization pipeline.
+//!
+//! Runs the middle-end passes enabled in [`OptConfig`] over a function, in a
+//! fixed order: unrolling turns loop counters into per-copy constants,
+//! constant folding resolves them and exposes constant operands to
+//! scalarization and strength reduction, all of these make more computations
+//! look alike to CSE, CSE leaves fewer ops for LICM to consider, and DCE last
+//! removes whatever the others left unread.
+//!
+//! A pipeline is a list of steps, one pass each, so a compile job that must
+//! yield between steps (the on-device JIT) can run one [`Pass`] at a time and
+//! resume at the next; [`PassPipeline::run_function`] runs them all at once.
+
+use alloc::vec::Vec;
+
+use crate::compiler_config::OptConfig;
+use crate::const_fold::fold_constants;
+use crate::cse::eliminate_common_subexpressions;
+use crate::dce::eliminate_dead_code;
+use crate::licm::hoist_loop_invariants;
+use crate::lpir_module::{IrFunction, LpirModule};
+use crate::scalarize::scalarize_slots;
+use crate::strength_reduce::strength_reduce;
+use crate::types::FloatMode;
+use crate::unroll::unroll_loops;
+
+/// One middle-end pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pass {
+    Unroll,
+    ConstFold,
+    Scalarize,
+    StrengthReduce,
+    Cse,
+    Licm,
+    Dce,
+}
+
+impl Pass {
+    /// Every pass, in pipeline order.
+    pub const ALL: [Pass; 7] = [
+        Pass::Unroll,
+        Pass::ConstFold,
+        Pass::Scalarize,
+        Pass::StrengthReduce,
+        Pass::Cse,
+        Pass::Licm,
+        Pass::Dce,
+    ];
+
+    /// Short stable name, matching its `opt.*` config key.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Pass::Unroll => "unroll",
+            Pass::ConstFold => "const_fold",
+            Pass::Scalarize => "scalarize",
+            Pass::StrengthReduce => "strength_reduce",
+            Pass::Cse => "cse",
+            Pass::Licm => "licm",
+            Pass::Dce => "dce",
+        }
+    }
+
+    /// Whether `config` turns this pass on.
+    #[must_use]
+    pub fn enabled(self, config: &OptConfig) -> bool {
+        match self {
+            Pass::Unroll => config.unroll,
+            Pass::ConstFold => config.const_fold,
+            Pass::Scalarize => config.scalarize,
+            Pass::StrengthReduce => config.strength_reduce,
+            Pass::Cse => config.cse,
+            Pass::Licm => config.licm,
+            Pass::Dce => config.dce,
+        }
+    }
+
+    /// Run this pass over `func`, within the size limits in `config`.
+    /// Returns how many ops it changed, moved or removed (loops unrolled and
+    /// slots promoted, for those two passes).
+    pub fn run(self, func: &mut IrFunction, config: &OptConfig, float_mode: FloatMode) -> usize {
+        match self {
+            Pass::Unroll => {
+                unroll_loops(func, config.unroll_max_trip, config.unroll_max_ops as usize)
+            }
+            Pass::ConstFold => fold_constants(func),
+            Pass::Scalarize => scalarize_slots(func, config.scalarize_max_bytes),
+            Pass::StrengthReduce => strength_reduce(func, float_mode),
+            Pass::Cse => eliminate_common_subexpressions(func),
+            Pass::Licm => hoist_loop_invariants(func),
+            Pass::Dce => eliminate_dead_code(func),
+        }
+    }
+}
+
+/// The passes [`OptConfig`] enables, for one float mode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PassPipeline {
+    passes: Vec<Pass>,
+    config: OptConfig,
+    float_mode: FloatMode,
+}
+
+impl PassPipeline {
+    pub fn new(config: &OptConfig, float_mode: FloatMode) -> Self {
+        Self {
+            passes: Pass::ALL
+                .into_iter()
+                .filter(|pass| pass.enabled(config))
+                .collect(),
+            config: config.clone(),
+            float_mode,
+        }
+    }
+
+    /// Enabled passes, in the order they run.
+    pub fn passes(&self) -> &[Pass] {
+        &self.passes
+    }
+
+    pub fn float_mode(&self) -> FloatMode {
+        self.float_mode
+    }
+
+    /// Run one pass over `func` with this pipeline's limits and float mode.
+    pub fn run_pass(&self, pass: Pass, func: &mut IrFunction) -> usize {
+        pass.run(func, &self.config, self.float_mode)
+    }
+
+    /// Whether no pass is enabled, so running the pipeline changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.passes.is_empty()
+    }
+
+    /// Run every enabled pass over `func`. Returns how many ops they changed.
+    pub fn run_function(&self, func: &mut IrFunction) -> usize {
+        self.passes
+            .iter()
+            .map(|&pass| self.run_pass(pass, func))
+            .sum()
+    }
+
+    /// Run every enabled pass over each function in `module`.
+    pub fn run_module(&self, module: &mut LpirModule) -> usize {
+        module
+            .functions
+            .values_mut()
+            .map(|func| self.run_function(func))
+            .sum()
+    }
+}
@@ -6,7 +6,7 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt::Write as _;
 
-use crate::lpir_module::{ImportDecl, IrFunction, LpirBody, LpirModule, VMCTX_VREG};
+use crate::lpir_module::{ImportDecl, IrFunction, LpirBody, LpirModule, RodataWord, VMCTX_VREG};
 use crate::lpir_op::LpirOp;
 use crate::types::ImportId;
 use crate::types::{CalleeRef, IrType, VReg};
@@ -52,7 +52,11 @@ pub fn print_module(module: &LpirModule) -> String {
         print_import(&mut out, imp);
         let _ = writeln!(out);
     }
-    if !module.imports.is_empty() && !module.functions.is_empty() {
+    if !module.imports.is_empty() && !module.rodata.is_empty() {
+        let _ = writeln!(out);
+    }
+    print_rodata(&mut out, &module.rodata);
+    if (!module.imports.is_empty() || !module.rodata.is_empty()) && !module.functions.is_empty() {
         let _ = writeln!(out);
     }
     for (i, (_, f)) in module.functions.iter().enumerate() {
@@ -64,6 +68,27 @@ pub fn print_module(module: &LpirModule) -> String {
     out
 }
 
+/// Read-only data, eight words to a `rodata` line.
+fn print_rodata(out: &mut String, rodata: &[RodataWord]) {
+    for line in rodata.chunks(8) {
+        let _ = write!(out, "rodata ");
+        for (i, word) in line.iter().enumerate() {
+            if i > 0 {
+                let _ = write!(out, ", ");
+            }
+            match *word {
+                RodataWord::I32(value) => {
+                    let _ = write!(out, "i32 {value}");
+                }
+                RodataWord::F32(value) => {
+                    let _ = write!(out, "f32 {}", fmt_f32(value));
+                }
+            }
+        }
+        let _ = writeln!(out);
+    }
+}
+
 fn print_import(out: &mut String, imp: &ImportDecl) {
     let _ = write!(out, "import @{}::{}", imp.module_name, imp.func_name);
     print_import_param_types(out, &imp.param_types, imp.sret);
@@ -622,6 +647,11 @@ fn print_simple_op(out: &mut String, st: &mut PrintState<'_>, ind: &str, op: &Lp
             fmt_vreg(st, out, *dst);
             let _ = writeln!(out, " = slot_addr {slot}");
         }
+        LpirOp::RodataAddr { dst } => {
+            let _ = write!(out, "{ind}");
+            fmt_vreg(st, out, *dst);
+            let _ = writeln!(out, " = rodata_addr");
+        }
         LpirOp::Load { dst, base, offset } => {
             let _ = write!(out, "{ind}");
             fmt_vreg(st, out, *dst);
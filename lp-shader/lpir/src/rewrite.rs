@@ -0,0 +1,352 @@
+//! Shared plumbing for passes that edit a function body: operand visitors and
+//! rebuilding a body with its control-flow offsets relocated.
+
+use alloc::vec::Vec;
+
+use crate::lpir_module::{IrFunction, LpirBody};
+use crate::lpir_op::LpirOp;
+use crate::types::VReg;
+
+/// Call `f` with every vreg `op` reads, call arguments and returned values
+/// included.
+pub(crate) fn for_each_use(func: &IrFunction, op: &LpirOp, mut f: impl FnMut(VReg)) {
+    match op {
+        LpirOp::Fadd { lhs, rhs, .. }
+        | LpirOp::Fsub { lhs, rhs, .. }
+        | LpirOp::Fmul { lhs, rhs, .. }
+        | LpirOp::Fdiv { lhs, rhs, .. }
+        | LpirOp::Fmin { lhs, rhs, .. }
+        | LpirOp::Fmax { lhs, rhs, .. }
+        | LpirOp::Iadd { lhs, rhs, .. }
+        | LpirOp::Isub { lhs, rhs, .. }
+        | LpirOp::Imul { lhs, rhs, .. }
+        | LpirOp::IdivS { lhs, rhs, .. }
+        | LpirOp::IdivU { lhs, rhs, .. }
+        | LpirOp::IremS { lhs, rhs, .. }
+        | LpirOp::IremU { lhs, rhs, .. }
+        | LpirOp::Feq { lhs, rhs, .. }
+        | LpirOp::Fne { lhs, rhs, .. }
+        | LpirOp::Flt { lhs, rhs, .. }
+        | LpirOp::Fle { lhs, rhs, .. }
+        | LpirOp::Fgt { lhs, rhs, .. }
+        | LpirOp::Fge { lhs, rhs, .. }
+        | LpirOp::Ieq { lhs, rhs, .. }
+        | LpirOp::Ine { lhs, rhs, .. }
+        | LpirOp::IltS { lhs, rhs, .. }
+        | LpirOp::IleS { lhs, rhs, .. }
+        | LpirOp::IgtS { lhs, rhs, .. }
+        | LpirOp::IgeS { lhs, rhs, .. }
+        | LpirOp::IltU { lhs, rhs, .. }
+        | LpirOp::IleU { lhs, rhs, .. }
+        | LpirOp::IgtU { lhs, rhs, .. }
+        | LpirOp::IgeU { lhs, rhs, .. }
+        | LpirOp::Iand { lhs, rhs, .. }
+        | LpirOp::Ior { lhs, rhs, .. }
+        | LpirOp::Ixor { lhs, rhs, .. }
+        | LpirOp::Ishl { lhs, rhs, .. }
+        | LpirOp::IshrS { lhs, rhs, .. }
+        | LpirOp::IshrU { lhs, rhs, .. } => {
+            f(*lhs);
+            f(*rhs);
+        }
+        LpirOp::FdivConstF32 { lhs, .. } => f(*lhs),
+        LpirOp::Fneg { src, .. }
+        | LpirOp::Fabs { src, .. }
+        | LpirOp::Fsqrt { src, .. }
+        | LpirOp::Ffloor { src, .. }
+        | LpirOp::Fceil { src, .. }
+        | LpirOp::Ftrunc { src, .. }
+        | LpirOp::Fnearest { src, .. }
+        | LpirOp::Ineg { src, .. }
+        | LpirOp::Ibnot { src, .. }
+        | LpirOp::FtoiSatS { src, .. }
+        | LpirOp::FtoiSatU { src, .. }
+        | LpirOp::ItofS { src, .. }
+        | LpirOp::ItofU { src, .. }
+        | LpirOp::FfromI32Bits { src, .. }
+        | LpirOp::FtoUnorm16 { src, .. }
+        | LpirOp::FtoUnorm8 { src, .. }
+        | LpirOp::Unorm16toF { src, .. }
+        | LpirOp::Unorm8toF { src, .. }
+        | LpirOp::Copy { src, .. }
+        | LpirOp::IaddImm { src, .. }
+        | LpirOp::IsubImm { src, .. }
+        | LpirOp::ImulImm { src, .. }
+        | LpirOp::IshlImm { src, .. }
+        | LpirOp::IshrSImm { src, .. }
+        | LpirOp::IshrUImm { src, .. }
+        | LpirOp::IeqImm { src, .. } => f(*src),
+        LpirOp::Select {
+            cond,
+            if_true,
+            if_false,
+            ..
+        } => {
+            f(*cond);
+            f(*if_true);
+            f(*if_false);
+        }
+        LpirOp::Load { base, .. }
+        | LpirOp::Load8U { base, .. }
+        | LpirOp::Load8S { base, .. }
+        | LpirOp::Load16U { base, .. }
+        | LpirOp::Load16S { base, .. } => f(*base),
+        LpirOp::Store { base, value, .. }
+        | LpirOp::Store8 { base, value, .. }
+        | LpirOp::Store16 { base, value, .. } => {
+            f(*base);
+            f(*value);
+        }
+        LpirOp::Memcpy {
+            dst_addr, src_addr, ..
+        } => {
+            f(*dst_addr);
+            f(*src_addr);
+        }
+        LpirOp::IfStart { cond, .. } | LpirOp::BrIfNot { cond } => f(*cond),
+        LpirOp::SwitchStart { selector, .. } => f(*selector),
+        LpirOp::Call { args, .. } => func.pool_slice(*args).iter().copied().for_each(f),
+        LpirOp::Return { values } => func.pool_slice(*values).iter().copied().for_each(f),
+        LpirOp::FconstF32 { .. }
+        | LpirOp::IconstI32 { .. }
+        | LpirOp::SlotAddr { .. }
+        | LpirOp::RodataAddr { .. }
+        | LpirOp::Else
+        | LpirOp::LoopStart { .. }
+        | LpirOp::CaseStart { .. }
+        | LpirOp::DefaultStart { .. }
+        | LpirOp::End
+        | LpirOp::Block { .. }
+        | LpirOp::Break
+        | LpirOp::Continue
+        | LpirOp::ExitBlock => {}
+    }
+}
+
+/// Call `f` with every vreg `op` writes: its destination, or a call's results.
+pub(crate) fn for_each_def(func: &IrFunction, op: &LpirOp, mut f: impl FnMut(VReg)) {
+    match op {
+        LpirOp::Call { results, .. } => func.pool_slice(*results).iter().copied().for_each(f),
+        _ => {
+            if let Some(dst) = op.def_vreg() {
+                f(dst);
+            }
+        }
+    }
+}
+
+/// How many times each vreg is written, parameters counting once for the
+/// write at entry.
+pub(crate) fn def_counts(func: &IrFunction) -> Vec<u32> {
+    let mut counts = Vec::new();
+    counts.resize(func.vreg_types.len(), 0u32);
+    for param in counts.iter_mut().take(func.total_param_slots() as usize) {
+        *param = 1;
+    }
+    for op in func.body.iter() {
+        for_each_def(func, op, |v| {
+            if let Some(count) = counts.get_mut(v.0 as usize) {
+                *count += 1;
+            }
+        });
+    }
+    counts
+}
+
+/// Whether `op` is a control-flow op carrying absolute body offsets.
+pub(crate) fn has_offsets(op: &LpirOp) -> bool {
+    matches!(
+        op,
+        LpirOp::IfStart { .. }
+            | LpirOp::LoopStart { .. }
+            | LpirOp::SwitchStart { .. }
+            | LpirOp::CaseStart { .. }
+            | LpirOp::DefaultStart { .. }
+            | LpirOp::Block { .. }
+    )
+}
+
+/// Move a control-flow op's absolute offsets through `to_new` (old index →
+/// new index, with one trailing entry for the end of the body).
+pub(crate) fn relocate(op: &mut LpirOp, to_new: &[u32]) {
+    relocate_with(op, |offset| {
+        to_new.get(offset as usize).copied().unwrap_or(offset)
+    });
+}
+
+/// Move a control-flow op's absolute offsets through `f`.
+pub(crate) fn relocate_with(op: &mut LpirOp, f: impl Fn(u32) -> u32) {
+    let at = |offset: &mut u32| *offset = f(*offset);
+    match op {
+        LpirOp::IfStart {
+            else_offset,
+            end_offset,
+            ..
+        } => {
+            at(else_offset);
+            at(end_offset);
+        }
+        LpirOp::LoopStart {
+            continuing_offset,
+            end_offset,
+        } => {
+            at(continuing_offset);
+            at(end_offset);
+        }
+        LpirOp::SwitchStart { end_offset, .. }
+        | LpirOp::CaseStart { end_offset, .. }
+        | LpirOp::DefaultStart { end_offset }
+        | LpirOp::Block { end_offset } => at(end_offset),
+        _ => {}
+    }
+}
+
+/// Rebuild `func.body`: `emit` sees each old op with its index and pushes
+/// whatever replaces it — nothing, the op itself, or several ops. A jump to
+/// an old index lands on the first op emitted for it, so ops pushed ahead of
+/// an op run on every path that reaches it. Emitted control ops must come
+/// from the old body; their offsets are relocated here.
+pub(crate) fn rebuild_body(
+    func: &mut IrFunction,
+    mut emit: impl FnMut(usize, &LpirOp, &mut LpirBody),
+) {
+    let old = core::mem::take(&mut func.body);
+    let mut body = LpirBody::new();
+    let mut to_new = Vec::with_capacity(old.len() + 1);
+    for (i, op) in old.iter().enumerate() {
+        to_new.push(body.len() as u32);
+        emit(i, op, &mut body);
+    }
+    to_new.push(body.len() as u32);
+    drop(old);
+    for op in body.iter_mut() {
+        if has_offsets(op) {
+            relocate(op, &to_new);
+        }
+    }
+    func.body = body;
+}
+
+/// Index of the `End` closing the if, loop, switch or block `op` opens.
+pub(crate) fn end_index(op: &LpirOp) -> Option<usize> {
+    match op {
+        LpirOp::IfStart { end_offset, .. }
+        | LpirOp::LoopStart { end_offset, .. }
+        | LpirOp::SwitchStart { end_offset, .. }
+        | LpirOp::Block { end_offset } => Some(*end_offset as usize - 1),
+        _ => None,
+    }
+}
+
+/// Per-vreg facts for a forward walk over a body, kept sound across
+/// structured control flow. Call [`FlowFacts::enter`] with each op before
+/// looking at it: a fact learned before an `if`, `switch` or block still
+/// holds inside it, each arm starts from the facts at its head, and after the
+/// construct (or at a loop head or `continuing:` label, which back edges and
+/// `continue` also reach) only the facts about vregs it never writes survive.
+pub(crate) struct FlowFacts<T> {
+    facts: Vec<Option<T>>,
+    scopes: Vec<Scope<T>>,
+}
+
+/// An open if, loop, switch, switch arm or block.
+struct Scope<T> {
+    /// Index of its `continuing:` section, for a loop that has one.
+    continuing: Option<usize>,
+    /// Facts on entry.
+    entry: Vec<Option<T>>,
+    /// Vregs written inside it, sorted.
+    written: Vec<VReg>,
+}
+
+impl<T: Clone> FlowFacts<T> {
+    pub(crate) fn new(func: &IrFunction) -> Self {
+        let mut facts = Vec::new();
+        facts.resize(func.vreg_types.len(), None);
+        Self {
+            facts,
+            scopes: Vec::new(),
+        }
+    }
+
+    pub(crate) fn get(&self, v: VReg) -> Option<&T> {
+        self.facts.get(v.0 as usize).and_then(Option::as_ref)
+    }
+
+    pub(crate) fn set(&mut self, v: VReg, fact: Option<T>) {
+        if let Some(slot) = self.facts.get_mut(v.0 as usize) {
+            *slot = fact;
+        }
+    }
+
+    /// Update the facts for control flow reaching op `i`.
+    pub(crate) fn enter(&mut self, func: &IrFunction, i: usize) {
+        if let Some(scope) = self.scopes.last() {
+            if scope.continuing == Some(i) {
+                self.facts = scope.surviving();
+            }
+        }
+        let op = &func.body[i];
+        match op {
+            LpirOp::IfStart { .. } | LpirOp::SwitchStart { .. } | LpirOp::Block { .. } => {
+                let end = end_index(op).unwrap_or(i);
+                self.open(func, i, end, None);
+            }
+            LpirOp::LoopStart {
+                continuing_offset, ..
+            } => {
+                let end = end_index(op).unwrap_or(i);
+                let continuing =
+                    Some(*continuing_offset as usize).filter(|&c| c != i + 1 && c < end);
+                self.open(func, i, end, continuing);
+                if let Some(scope) = self.scopes.last() {
+                    self.facts = scope.surviving();
+                }
+            }
+            LpirOp::CaseStart { end_offset, .. } | LpirOp::DefaultStart { end_offset } => {
+                if let Some(switch) = self.scopes.last() {
+                    self.facts = switch.entry.clone();
+                }
+                self.open(func, i, (*end_offset as usize).saturating_sub(1), None);
+            }
+            LpirOp::Else => {
+                if let Some(scope) = self.scopes.last() {
+                    self.facts = scope.entry.clone();
+                }
+            }
+            LpirOp::End => {
+                if let Some(scope) = self.scopes.pop() {
+                    self.facts = scope.surviving();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn open(&mut self, func: &IrFunction, start: usize, end: usize, continuing: Option<usize>) {
+        let mut written = Vec::new();
+        for j in start + 1..end.min(func.body.len()) {
+            for_each_def(func, &func.body[j], |v| written.push(v));
+        }
+        written.sort_unstable_by_key(|v| v.0);
+        written.dedup();
+        self.scopes.push(Scope {
+            continuing,
+            entry: self.facts.clone(),
+            written,
+        });
+    }
+}
+
+impl<T: Clone> Scope<T> {
+    /// Entry facts minus those about vregs written inside.
+    fn surviving(&self) -> Vec<Option<T>> {
+        let mut facts = self.entry.clone();
+        for v in &self.written {
+            if let Some(slot) = facts.get_mut(v.0 as usize) {
+                *slot = None;
+            }
+        }
+        facts
+    }
+}
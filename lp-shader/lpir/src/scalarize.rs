@@ -0,0 +1,421 @@
+//! LPIR scalarization of small stack slots.
+//!
+//! The frontends keep arrays, structs and call temporaries in stack slots,
+//! read and written by `load` / `store` at `slot_addr` plus an offset. When
+//! every access to a small slot lands on a known 4-byte lane, the slot can
+//! live in vregs instead, one per lane, which the native backend keeps in
+//! registers rather than in the frame. Once loop unrolling and const folding
+//! have turned `a[i]` in a fixed loop into constant offsets, this covers the
+//! small temporaries of noise and fbm code.
+//!
+//! A slot of at most `max_bytes` is promoted when its address never escapes:
+//! every vreg that may hold it is only copied, offset (`iadd` / `iadd_imm`),
+//! used as the base of a 32-bit `load` or `store`, or passed to `memcpy`.
+//! At each use the address must resolve to the slot plus a known constant,
+//! on an aligned lane inside the slot, and each lane must be read and
+//! written with one type. Loads and stores become copies from and to the
+//! lane's vreg, a `memcpy` touching the slot becomes per-lane loads, stores
+//! or copies, and the address arithmetic goes away. Lanes start at zero, so
+//! a read before any write stays defined.
+//!
+//! Offsets are resolved from constants only; run const folding first so
+//! computed indices have become `iconst`s.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::lpir_module::IrFunction;
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{FlowFacts, for_each_def, for_each_use, rebuild_body};
+use crate::types::{IrType, SlotId, VReg};
+
+/// Which slot a vreg may hold the address of, over all its writes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Taint {
+    Slot(u32),
+    Many,
+}
+
+/// What a vreg holds at one point in the body.
+#[derive(Clone, Copy)]
+enum Fact {
+    Const(i32),
+    /// Address of a slot plus a byte offset.
+    Addr(u32, i64),
+}
+
+/// How an op touches a candidate slot.
+#[derive(Clone, Copy)]
+enum Access {
+    /// Computes an address in the slot; dropped once it is promoted.
+    Addr(u32),
+    Load(u32, u32),
+    Store(u32, u32),
+    /// Slot and first lane on each side a slot is addressed.
+    Memcpy(Option<(u32, u32)>, Option<(u32, u32)>),
+}
+
+struct Candidate {
+    ok: bool,
+    addressed: bool,
+    lanes: Vec<Option<IrType>>,
+}
+
+impl Candidate {
+    fn unify(&mut self, lane: u32, ty: IrType) {
+        match self.lanes.get_mut(lane as usize) {
+            _ if ty == IrType::Pointer => self.ok = false,
+            Some(slot @ None) => *slot = Some(ty),
+            Some(Some(have)) if *have == ty => {}
+            _ => self.ok = false,
+        }
+    }
+
+    /// The lane `len` bytes at `offset` cover, when they are inside the slot
+    /// and aligned.
+    fn lanes_at(&self, offset: i64, len: u32) -> Option<u32> {
+        let size = self.lanes.len() as i64 * 4;
+        (offset >= 0 && offset % 4 == 0 && offset + i64::from(len) <= size)
+            .then_some((offset / 4) as u32)
+    }
+}
+
+/// Promote each slot of at most `max_bytes` whose accesses all resolve to
+/// fixed lanes into one vreg per lane. Returns the number of slots promoted.
+pub fn scalarize_slots(func: &mut IrFunction, max_bytes: u32) -> usize {
+    let mut slots: Vec<Candidate> = func
+        .slots
+        .iter()
+        .map(|decl| Candidate {
+            ok: decl.size > 0 && decl.size % 4 == 0 && decl.size <= max_bytes,
+            addressed: false,
+            lanes: vec![None; (decl.size / 4) as usize],
+        })
+        .collect();
+    if !slots.iter().any(|s| s.ok) {
+        return 0;
+    }
+    let taint = address_taint(func, &mut slots);
+    let accesses = resolve_accesses(func, &taint, &mut slots);
+    unify_memcpy_lanes(func, &accesses, &mut slots);
+
+    let promoted: Vec<bool> = slots.iter().map(|s| s.ok && s.addressed).collect();
+    let count = promoted.iter().filter(|&&p| p).count();
+    if count == 0 {
+        return 0;
+    }
+
+    let mut lane_vregs: Vec<Vec<VReg>> = Vec::with_capacity(slots.len());
+    let mut inits = Vec::new();
+    for (slot, promote) in slots.iter().zip(&promoted) {
+        let mut vregs = Vec::new();
+        if *promote {
+            for ty in &slot.lanes {
+                let ty = ty.unwrap_or(IrType::I32);
+                let dst = VReg(func.vreg_types.len() as u32);
+                func.vreg_types.push(ty);
+                inits.push(match ty {
+                    IrType::F32 => LpirOp::FconstF32 { dst, value: 0.0 },
+                    _ => LpirOp::IconstI32 { dst, value: 0 },
+                });
+                vregs.push(dst);
+            }
+        }
+        lane_vregs.push(vregs);
+    }
+    let mut renumber = Vec::with_capacity(slots.len());
+    let mut next = 0u32;
+    for promote in &promoted {
+        renumber.push(next);
+        if !*promote {
+            next += 1;
+        }
+    }
+
+    let is_promoted = |slot: u32| promoted[slot as usize];
+    let lane = |slot: u32, lane: u32| lane_vregs[slot as usize][lane as usize];
+    rebuild_body(func, |i, op, out| {
+        if i == 0 {
+            inits.iter().cloned().for_each(|init| out.push(init));
+        }
+        match (accesses[i], op) {
+            (Some(Access::Addr(slot)), _) if is_promoted(slot) => {}
+            (Some(Access::Load(slot, l)), LpirOp::Load { dst, .. }) if is_promoted(slot) => {
+                out.push(LpirOp::Copy {
+                    dst: *dst,
+                    src: lane(slot, l),
+                });
+            }
+            (Some(Access::Store(slot, l)), LpirOp::Store { value, .. }) if is_promoted(slot) => {
+                out.push(LpirOp::Copy {
+                    dst: lane(slot, l),
+                    src: *value,
+                });
+            }
+            (
+                Some(Access::Memcpy(to, from)),
+                LpirOp::Memcpy {
+                    dst_addr,
+                    src_addr,
+                    size,
+                },
+            ) if to.is_some_and(|(s, _)| is_promoted(s))
+                || from.is_some_and(|(s, _)| is_promoted(s)) =>
+            {
+                let to = to.filter(|&(s, _)| is_promoted(s));
+                let from = from.filter(|&(s, _)| is_promoted(s));
+                for k in 0..size / 4 {
+                    out.push(match (to, from) {
+                        (Some((ds, dl)), Some((ss, sl))) => LpirOp::Copy {
+                            dst: lane(ds, dl + k),
+                            src: lane(ss, sl + k),
+                        },
+                        (Some((ds, dl)), None) => LpirOp::Load {
+                            dst: lane(ds, dl + k),
+                            base: *src_addr,
+                            offset: k * 4,
+                        },
+                        (None, Some((ss, sl))) => LpirOp::Store {
+                            base: *dst_addr,
+                            offset: k * 4,
+                            value: lane(ss, sl + k),
+                        },
+                        (None, None) => unreachable!("memcpy touches a promoted slot"),
+                    });
+                }
+            }
+            (_, LpirOp::SlotAddr { dst, slot }) => out.push(LpirOp::SlotAddr {
+                dst: *dst,
+                slot: SlotId(renumber[slot.0 as usize]),
+            }),
+            _ => out.push(op.clone()),
+        }
+    });
+    let mut index = 0;
+    func.slots.retain(|_| {
+        index += 1;
+        !promoted[index - 1]
+    });
+    count
+}
+
+/// Which slot each vreg may hold the address of. A slot whose address mixes
+/// with another's, or reaches any op but address arithmetic, a word `load` /
+/// `store` base or a `memcpy`, is no longer a candidate.
+fn address_taint(func: &IrFunction, slots: &mut [Candidate]) -> Vec<Option<Taint>> {
+    let mut taint: Vec<Option<Taint>> = vec![None; func.vreg_types.len()];
+    let get = |taint: &[Option<Taint>], v: VReg| taint.get(v.0 as usize).copied().flatten();
+    let reject = |slots: &mut [Candidate], t: Option<Taint>| {
+        if let Some(Taint::Slot(s)) = t {
+            if let Some(slot) = slots.get_mut(s as usize) {
+                slot.ok = false;
+            }
+        }
+    };
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for op in func.body.iter() {
+            let from = match op {
+                LpirOp::SlotAddr { slot, .. } => Some(Taint::Slot(slot.0)),
+                LpirOp::Copy { src, .. } | LpirOp::IaddImm { src, .. } => get(&taint, *src),
+                LpirOp::Iadd { lhs, rhs, .. } => match (get(&taint, *lhs), get(&taint, *rhs)) {
+                    (Some(t), None) | (None, Some(t)) => Some(t),
+                    (Some(a), Some(b)) => {
+                        reject(slots, Some(a));
+                        reject(slots, Some(b));
+                        Some(Taint::Many)
+                    }
+                    (None, None) => None,
+                },
+                _ => None,
+            };
+            let (Some(dst), Some(from)) = (op.def_vreg(), from) else {
+                continue;
+            };
+            let Some(to) = taint.get_mut(dst.0 as usize) else {
+                continue;
+            };
+            let merged = match *to {
+                None => from,
+                Some(have) if have == from => continue,
+                Some(have) => {
+                    reject(slots, Some(have));
+                    reject(slots, Some(from));
+                    Taint::Many
+                }
+            };
+            if *to != Some(merged) {
+                *to = Some(merged);
+                changed = true;
+            }
+        }
+    }
+
+    for op in func.body.iter() {
+        let addr_uses: [Option<VReg>; 2] = match op {
+            LpirOp::Load { base, .. } | LpirOp::Store { base, .. } => [Some(*base), None],
+            LpirOp::Memcpy {
+                dst_addr,
+                src_addr,
+                size,
+            } if size % 4 == 0 => [Some(*dst_addr), Some(*src_addr)],
+            LpirOp::Copy { src, .. } | LpirOp::IaddImm { src, .. } => [Some(*src), None],
+            LpirOp::Iadd { lhs, rhs, .. } => [Some(*lhs), Some(*rhs)],
+            _ => [None, None],
+        };
+        for_each_use(func, op, |u| {
+            if !addr_uses.contains(&Some(u)) {
+                reject(slots, get(&taint, u));
+            }
+        });
+        if let LpirOp::Store { value, .. } = op {
+            reject(slots, get(&taint, *value));
+        }
+    }
+    taint
+}
+
+/// Resolve every use of a candidate's address to a lane, per op index.
+fn resolve_accesses(
+    func: &IrFunction,
+    taint: &[Option<Taint>],
+    slots: &mut [Candidate],
+) -> Vec<Option<Access>> {
+    let slot_of = |v: VReg| match taint.get(v.0 as usize).copied().flatten() {
+        Some(Taint::Slot(s)) => Some(s),
+        _ => None,
+    };
+    let mut facts: FlowFacts<Fact> = FlowFacts::new(func);
+    let mut accesses = Vec::with_capacity(func.body.len());
+    for i in 0..func.body.len() {
+        facts.enter(func, i);
+        let op = &func.body[i];
+        let addr = |v: VReg| match facts.get(v) {
+            Some(Fact::Addr(s, off)) => Some((*s, *off)),
+            _ => None,
+        };
+        // The lane `len` bytes at `v + offset` start, when `v` holds an
+        // address in its slot; otherwise that slot is not promoted.
+        let lane_at = |slots: &mut [Candidate], v: VReg, offset: u32, len: u32| {
+            let s = slot_of(v)?;
+            let lane = addr(v)
+                .filter(|&(at, _)| at == s)
+                .and_then(|(_, off)| slots[s as usize].lanes_at(off + i64::from(offset), len));
+            if lane.is_none() {
+                slots[s as usize].ok = false;
+            }
+            lane.map(|l| (s, l))
+        };
+        let access = match op {
+            LpirOp::Load { dst, base, offset } => {
+                lane_at(slots, *base, *offset, 4).map(|(s, l)| {
+                    slots[s as usize].unify(l, func.vreg_types[dst.0 as usize]);
+                    Access::Load(s, l)
+                })
+            }
+            LpirOp::Store {
+                base,
+                offset,
+                value,
+            } => lane_at(slots, *base, *offset, 4).map(|(s, l)| {
+                slots[s as usize].unify(l, func.vreg_types[value.0 as usize]);
+                Access::Store(s, l)
+            }),
+            LpirOp::Memcpy {
+                dst_addr,
+                src_addr,
+                size,
+            } => {
+                let to = lane_at(slots, *dst_addr, 0, *size);
+                let from = lane_at(slots, *src_addr, 0, *size);
+                (to.is_some() || from.is_some()).then_some(Access::Memcpy(to, from))
+            }
+            _ => None,
+        };
+
+        let fact = match op {
+            LpirOp::SlotAddr { slot, .. } => Some(Fact::Addr(slot.0, 0)),
+            LpirOp::IconstI32 { value, .. } => Some(Fact::Const(*value)),
+            LpirOp::Copy { src, .. } => facts.get(*src).copied(),
+            LpirOp::IaddImm { src, imm, .. } => match facts.get(*src) {
+                Some(Fact::Addr(s, off)) => Some(Fact::Addr(*s, off + i64::from(*imm))),
+                Some(Fact::Const(c)) => Some(Fact::Const(c.wrapping_add(*imm))),
+                None => None,
+            },
+            LpirOp::Iadd { lhs, rhs, .. } => match (facts.get(*lhs), facts.get(*rhs)) {
+                (Some(Fact::Addr(s, off)), Some(Fact::Const(c)))
+                | (Some(Fact::Const(c)), Some(Fact::Addr(s, off))) => {
+                    Some(Fact::Addr(*s, off + i64::from(*c)))
+                }
+                (Some(Fact::Const(a)), Some(Fact::Const(b))) => {
+                    Some(Fact::Const(a.wrapping_add(*b)))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        // Address arithmetic on a candidate must stay an address in it, or
+        // it would read the vregs promotion removes.
+        let access = access.or_else(|| {
+            let dst = op.def_vreg()?;
+            let s = slot_of(dst)?;
+            match fact {
+                Some(Fact::Addr(at, _)) if at == s => Some(Access::Addr(s)),
+                _ => {
+                    let mut reads_candidate = false;
+                    for_each_use(func, op, |u| reads_candidate |= slot_of(u).is_some());
+                    if reads_candidate || matches!(op, LpirOp::SlotAddr { .. }) {
+                        slots[s as usize].ok = false;
+                    }
+                    None
+                }
+            }
+        });
+        if let Some(Access::Addr(s)) = access {
+            slots[s as usize].addressed = true;
+        }
+        for_each_def(func, op, |v| facts.set(v, None));
+        if let Some(dst) = op.def_vreg() {
+            facts.set(dst, fact);
+        }
+        accesses.push(access);
+    }
+    accesses
+}
+
+/// Give lanes copied between two candidates one type; a lane only ever
+/// moved by `memcpy` ends up `i32`.
+fn unify_memcpy_lanes(func: &IrFunction, accesses: &[Option<Access>], slots: &mut [Candidate]) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (i, access) in accesses.iter().enumerate() {
+            let (Some(Access::Memcpy(Some((ds, dl)), Some((ss, sl)))), LpirOp::Memcpy { size, .. }) =
+                (access, &func.body[i])
+            else {
+                continue;
+            };
+            for k in 0..size / 4 {
+                let to = slots[*ds as usize].lanes[(dl + k) as usize];
+                let from = slots[*ss as usize].lanes[(sl + k) as usize];
+                match (to, from) {
+                    (Some(a), Some(b)) if a != b => {
+                        slots[*ds as usize].ok = false;
+                        slots[*ss as usize].ok = false;
+                    }
+                    (None, Some(ty)) => {
+                        slots[*ds as usize].lanes[(dl + k) as usize] = Some(ty);
+                        changed = true;
+                    }
+                    (Some(ty), None) => {
+                        slots[*ss as usize].lanes[(sl + k) as usize] = Some(ty);
+                        changed = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
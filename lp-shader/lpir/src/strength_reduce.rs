@@ -0,0 +1,191 @@
+//! LPIR strength reduction.
+//!
+//! Rewrites ops against a constant operand into cheaper ones: integer
+//! multiplies and unsigned divides by a power of two become shifts, shifts by
+//! a constant amount take the immediate form, and identity arithmetic
+//! (`x * 1`, `x + 0`, `x << 0`, …) becomes a copy. Each rewrite replaces one
+//! op with one op, so control-flow offsets stay valid.
+//!
+//! A vreg counts as constant only when its single write is an
+//! `iconst`/`fconst`; LPIR vregs can be reassigned, so a constant written in
+//! one place and overwritten in a loop must not be trusted.
+//!
+//! Float rewrites are Q32-aware: each is applied only in the float modes
+//! where it is exact. In Q32 a float is a Q16.16 integer; its arithmetic
+//! wraps on overflow in the backends' inline expansions and saturates in the
+//! `__lp_lpir_*_q32` builtins, and every rewrite here is exact under either.
+//! `x * 0.0 == 0` and `x + 0.0 == x` hold there; in IEEE f32 neither does
+//! (NaN, infinities, signed zero). `x * 2.0 → x + x`, `x / 0.5 → x + x`,
+//! `x * 1.0 → x` and `x - 0.0 → x` are exact in both: the doubling rewrites
+//! overflow exactly where the op they replace does, and wrap or saturate the
+//! same way.
+
+use alloc::vec::Vec;
+
+use crate::lpir_module::IrFunction;
+use crate::lpir_op::LpirOp;
+use crate::rewrite::def_counts;
+use crate::types::{FloatMode, VReg};
+
+#[derive(Clone, Copy)]
+enum Known {
+    Int(i32),
+    Float(f32),
+}
+
+/// Strength-reduce `func.body` in place. Returns the number of ops rewritten.
+pub fn strength_reduce(func: &mut IrFunction, float_mode: FloatMode) -> usize {
+    let known = known_constants(func);
+    let int = |v: VReg| match known.get(v.0 as usize) {
+        Some(Some(Known::Int(c))) => Some(*c),
+        _ => None,
+    };
+    let float = |v: VReg| match known.get(v.0 as usize) {
+        Some(Some(Known::Float(c))) => Some(*c),
+        _ => None,
+    };
+    let q32 = float_mode == FloatMode::Q32;
+
+    let mut rewritten = 0;
+    for op in func.body.iter_mut() {
+        let new_op = match *op {
+            LpirOp::Imul { dst, lhs, rhs } => match (int(lhs), int(rhs)) {
+                (_, Some(c)) => mul_by(dst, lhs, c),
+                (Some(c), None) => mul_by(dst, rhs, c),
+                (None, None) => None,
+            },
+            LpirOp::ImulImm { dst, src, imm } => mul_by(dst, src, imm),
+            LpirOp::IdivU { dst, lhs, rhs } => match int(rhs) {
+                Some(1) => Some(LpirOp::Copy { dst, src: lhs }),
+                Some(c) if c > 1 && (c as u32).is_power_of_two() => Some(LpirOp::IshrUImm {
+                    dst,
+                    src: lhs,
+                    imm: c.trailing_zeros() as i32,
+                }),
+                _ => None,
+            },
+            LpirOp::IdivS { dst, lhs, rhs } => {
+                (int(rhs) == Some(1)).then_some(LpirOp::Copy { dst, src: lhs })
+            }
+            LpirOp::Iadd { dst, lhs, rhs } => match (int(lhs), int(rhs)) {
+                (_, Some(0)) => Some(LpirOp::Copy { dst, src: lhs }),
+                (Some(0), _) => Some(LpirOp::Copy { dst, src: rhs }),
+                _ => None,
+            },
+            LpirOp::Isub { dst, lhs, rhs }
+            | LpirOp::Ior { dst, lhs, rhs }
+            | LpirOp::Ixor { dst, lhs, rhs } => {
+                (int(rhs) == Some(0)).then_some(LpirOp::Copy { dst, src: lhs })
+            }
+            LpirOp::Ishl { dst, lhs, rhs } => int(rhs).map(|c| LpirOp::IshlImm {
+                dst,
+                src: lhs,
+                imm: c & 31,
+            }),
+            LpirOp::IshrS { dst, lhs, rhs } => int(rhs).map(|c| LpirOp::IshrSImm {
+                dst,
+                src: lhs,
+                imm: c & 31,
+            }),
+            LpirOp::IshrU { dst, lhs, rhs } => int(rhs).map(|c| LpirOp::IshrUImm {
+                dst,
+                src: lhs,
+                imm: c & 31,
+            }),
+            LpirOp::IaddImm { dst, src, imm: 0 }
+            | LpirOp::IsubImm { dst, src, imm: 0 }
+            | LpirOp::IshlImm { dst, src, imm: 0 }
+            | LpirOp::IshrSImm { dst, src, imm: 0 }
+            | LpirOp::IshrUImm { dst, src, imm: 0 } => Some(LpirOp::Copy { dst, src }),
+
+            LpirOp::Fmul { dst, lhs, rhs } => match (float(lhs), float(rhs)) {
+                (_, Some(c)) => fmul_by(dst, lhs, c, q32),
+                (Some(c), None) => fmul_by(dst, rhs, c, q32),
+                (None, None) => None,
+            },
+            LpirOp::FdivConstF32 { dst, lhs, rhs } => {
+                if rhs == 1.0 {
+                    Some(LpirOp::Copy { dst, src: lhs })
+                } else if rhs == 0.5 {
+                    Some(LpirOp::Fadd { dst, lhs, rhs: lhs })
+                } else {
+                    None
+                }
+            }
+            LpirOp::Fadd { dst, lhs, rhs } if q32 => match (float(lhs), float(rhs)) {
+                (_, Some(c)) if c == 0.0 => Some(LpirOp::Copy { dst, src: lhs }),
+                (Some(c), _) if c == 0.0 => Some(LpirOp::Copy { dst, src: rhs }),
+                _ => None,
+            },
+            // `x - (+0.0)` is `x` for every f32, `-0.0` and NaN included.
+            LpirOp::Fsub { dst, lhs, rhs } => match float(rhs) {
+                Some(c) if c.to_bits() == 0 || (q32 && c == 0.0) => {
+                    Some(LpirOp::Copy { dst, src: lhs })
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+        // Integer ops also read pointers but always write `i32`; a copy
+        // between the two would not validate.
+        if let Some(LpirOp::Copy { dst, src }) = new_op
+            && func.vreg_types[dst.0 as usize] != func.vreg_types[src.0 as usize]
+        {
+            continue;
+        }
+        if let Some(new_op) = new_op {
+            *op = new_op;
+            rewritten += 1;
+        }
+    }
+    rewritten
+}
+
+/// Per vreg, the value of a single `iconst`/`fconst` write.
+fn known_constants(func: &IrFunction) -> Vec<Option<Known>> {
+    let counts = def_counts(func);
+    let mut known = Vec::new();
+    known.resize(func.vreg_types.len(), None);
+    for op in func.body.iter() {
+        let (dst, value) = match *op {
+            LpirOp::IconstI32 { dst, value } => (dst, Known::Int(value)),
+            LpirOp::FconstF32 { dst, value } => (dst, Known::Float(value)),
+            _ => continue,
+        };
+        let i = dst.0 as usize;
+        if counts.get(i) == Some(&1) {
+            known[i] = Some(value);
+        }
+    }
+    known
+}
+
+fn mul_by(dst: VReg, src: VReg, c: i32) -> Option<LpirOp> {
+    match c {
+        0 => Some(LpirOp::IconstI32 { dst, value: 0 }),
+        1 => Some(LpirOp::Copy { dst, src }),
+        -1 => Some(LpirOp::Ineg { dst, src }),
+        c if c > 0 && (c as u32).is_power_of_two() => Some(LpirOp::IshlImm {
+            dst,
+            src,
+            imm: c.trailing_zeros() as i32,
+        }),
+        _ => None,
+    }
+}
+
+fn fmul_by(dst: VReg, src: VReg, c: f32, q32: bool) -> Option<LpirOp> {
+    if c == 1.0 {
+        Some(LpirOp::Copy { dst, src })
+    } else if c == 2.0 {
+        Some(LpirOp::Fadd {
+            dst,
+            lhs: src,
+            rhs: src,
+        })
+    } else if q32 && c == 0.0 {
+        Some(LpirOp::FconstF32 { dst, value: 0.0 })
+    } else {
+        None
+    }
+}
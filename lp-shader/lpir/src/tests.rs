@@ -6,9 +6,15 @@ mod all_ops_roundtrip;
 #[path = "tests/block_ops.rs"]
 mod block_ops;
 
+#[path = "tests/inline.rs"]
+mod inline;
+
 #[path = "tests/interp.rs"]
 mod interp;
 
+#[path = "tests/pipeline.rs"]
+mod pipeline;
+
 #[path = "tests/validate.rs"]
 mod validate;
 
@@ -184,6 +190,23 @@ func @use(v1:f32) -> f32 {
     );
 }
 
+#[test]
+fn round_trip_rodata() {
+    assert_round_trip(
+        "import @glsl::fsin(f32) -> f32
+
+rodata i32 1, i32 2, i32 3, i32 4, i32 5, i32 6, i32 7, i32 8
+rodata f32 0.5, i32 -9
+
+func @table(v1:i32) -> f32 {
+  v2:ptr = rodata_addr
+  v3:f32 = load v2, 32
+  return v3
+}
+",
+    );
+}
+
 #[test]
 fn op_enum_payload_reasonable_size() {
     // Design note (stage II): ~20 bytes per op was an initial target; on 64-bit the
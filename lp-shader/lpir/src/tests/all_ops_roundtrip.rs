@@ -1,7 +1,7 @@
 //! Module that exercises every [`crate::lpir_op::LpirOp`] variant for print/parse round-trip tests.
 
 use crate::builder::{FunctionBuilder, ModuleBuilder};
-use crate::lpir_module::{LpirModule, VMCTX_VREG};
+use crate::lpir_module::{LpirModule, RodataWord, VMCTX_VREG};
 use crate::lpir_op::LpirOp;
 use crate::types::IrType;
 
@@ -11,6 +11,7 @@ pub(crate) fn module_all_ops() -> LpirModule {
     let id_arg = id_b.add_param(IrType::I32);
     id_b.push_return(&[id_arg]);
     let id_callee = mb.add_function(id_b.finish());
+    let table = mb.add_rodata(&[RodataWord::I32(5), RodataWord::F32(0.25)]);
 
     let mut b = FunctionBuilder::new(
         "all_ops",
@@ -315,6 +316,14 @@ pub(crate) fn module_all_ops() -> LpirModule {
         src_addr: base,
         size: 16,
     });
+    let rodata = b.alloc_vreg(IrType::Pointer);
+    b.push(LpirOp::RodataAddr { dst: rodata });
+    let table_f = b.alloc_vreg(IrType::F32);
+    b.push(LpirOp::Load {
+        dst: table_f,
+        base: rodata,
+        offset: table + 4,
+    });
 
     b.push_if(sel_c);
     let ifv = b.alloc_vreg(IrType::F32);
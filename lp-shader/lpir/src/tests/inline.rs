@@ -0,0 +1,355 @@
+//! [`crate::inline`]: cost model decisions, and results identical to the calls
+//! they replace.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::compiler_config::{InlineConfig, InlineMode};
+use crate::inline::{InlineStats, inline_module};
+use crate::interp::{ImportHandler, InterpError, Value, interpret};
+use crate::lpir_module::LpirModule;
+use crate::lpir_op::LpirOp;
+use crate::parse::parse_module;
+use crate::print::print_module;
+use crate::types::CalleeRef;
+use crate::validate::validate_module;
+
+struct NoImports;
+
+impl ImportHandler for NoImports {
+    fn call(&mut self, _: &str, _: &str, _: &[Value]) -> Result<Vec<Value>, InterpError> {
+        Err(InterpError::Import(String::from("no imports")))
+    }
+}
+
+fn config(mode: InlineMode) -> InlineConfig {
+    InlineConfig {
+        mode,
+        ..InlineConfig::default()
+    }
+}
+
+/// Inline `ir` under `config`, check the result validates, and return it with
+/// the pass statistics.
+fn inlined(ir: &str, config: &InlineConfig) -> (LpirModule, InlineStats) {
+    let mut module = parse_module(ir).unwrap_or_else(|e| panic!("parse: {e:?}"));
+    validate_module(&module).unwrap_or_else(|e| panic!("validate before: {e:?}"));
+    let stats = inline_module(&mut module, config);
+    validate_module(&module)
+        .unwrap_or_else(|e| panic!("validate after: {e:?}\n{}", print_module(&module)));
+    (module, stats)
+}
+
+fn local_calls_in(module: &LpirModule, func: &str) -> usize {
+    let func = module
+        .functions
+        .values()
+        .find(|f| f.name == func)
+        .expect("function exists");
+    func.body
+        .iter()
+        .filter(|op| {
+            matches!(
+                op,
+                LpirOp::Call {
+                    callee: CalleeRef::Local(_),
+                    ..
+                }
+            )
+        })
+        .count()
+}
+
+fn run_i32(module: &LpirModule, func: &str, args: &[Value]) -> i32 {
+    let out = interpret(module, func, args, &mut NoImports).unwrap();
+    assert_eq!(out.len(), 1);
+    out[0].as_i32().expect("i32")
+}
+
+/// `func` returns the same thing for every `inputs` entry with and without
+/// inlining forced on.
+fn assert_same_results(ir: &str, func: &str, inputs: &[i32]) -> LpirModule {
+    let original = parse_module(ir).unwrap_or_else(|e| panic!("parse: {e:?}"));
+    let (module, stats) = inlined(ir, &config(InlineMode::Always));
+    assert!(stats.sites_inlined > 0, "nothing inlined");
+    for &x in inputs {
+        assert_eq!(
+            run_i32(&module, func, &[Value::I32(x)]),
+            run_i32(&original, func, &[Value::I32(x)]),
+            "{func}({x})\n{}",
+            print_module(&module)
+        );
+    }
+    module
+}
+
+const SMALL_HELPER: &str = "func @inc(v1:i32) -> i32 {
+  v2:i32 = iadd_imm v1, 1
+  return v2
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = call @inc(v1)
+  v3:i32 = call @inc(v2)
+  return v3
+}
+";
+
+#[test]
+fn small_helper_is_inlined_at_every_site() {
+    let (module, stats) = inlined(SMALL_HELPER, &InlineConfig::default());
+    assert_eq!(stats.sites_inlined, 2);
+    assert_eq!(local_calls_in(&module, "main"), 0);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(40)]), 42);
+    // The callee is still there to be called by name.
+    assert_eq!(run_i32(&module, "inc", &[Value::I32(1)]), 2);
+}
+
+#[test]
+fn a_callee_returning_only_at_its_end_needs_no_block() {
+    let (module, _) = inlined(SMALL_HELPER, &InlineConfig::default());
+    let main = module
+        .functions
+        .values()
+        .find(|f| f.name == "main")
+        .unwrap();
+    assert!(
+        !main
+            .body
+            .iter()
+            .any(|op| matches!(op, LpirOp::Block { .. }))
+    );
+}
+
+#[test]
+fn never_leaves_the_module_alone() {
+    let (module, stats) = inlined(SMALL_HELPER, &config(InlineMode::Never));
+    assert_eq!(stats, InlineStats::default());
+    assert_eq!(local_calls_in(&module, "main"), 2);
+}
+
+#[test]
+fn early_returns_exit_the_inlined_block() {
+    let ir = "func @clamp_pos(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = ilt_s v1, v2
+  if v3 {
+    return v2
+  }
+  v4:i32 = iconst.i32 100
+  v5:i32 = igt_s v1, v4
+  if v5 {
+    return v4
+  } else {
+    v1 = iadd_imm v1, 1
+  }
+  return v1
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = call @clamp_pos(v1)
+  v3:i32 = iadd v2, v1
+  return v3
+}
+";
+    let module = assert_same_results(ir, "main", &[-5, 0, 7, 100, 101, 1000]);
+    assert_eq!(local_calls_in(&module, "main"), 0);
+}
+
+#[test]
+fn a_return_inside_a_loop_leaves_the_loop_and_the_callee() {
+    let ir = "func @first_multiple(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iconst.i32 1
+  loop {
+    v4:i32 = irem_s v3, v2
+    v5:i32 = iconst.i32 0
+    v6:i32 = ieq v4, v5
+    if v6 {
+      v7:i32 = igt_s v3, v1
+      if v7 {
+        return v3
+      }
+    }
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  v8:i32 = iconst.i32 -1
+  return v8
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = iconst.i32 0
+  loop {
+    v4:i32 = ilt_s v3, v1
+    br_if_not v4
+    v5:i32 = iconst.i32 7
+    v6:i32 = call @first_multiple(v3, v5)
+    v2 = iadd v2, v6
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  return v2
+}
+";
+    assert_same_results(ir, "main", &[0, 1, 5, 20]);
+}
+
+#[test]
+fn a_callee_that_writes_its_parameter_leaves_the_argument_alone() {
+    let ir = "func @double(v1:i32) -> i32 {
+  v1 = iadd v1, v1
+  return v1
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = call @double(v1)
+  v3:i32 = iadd v1, v2
+  return v3
+}
+";
+    let module = assert_same_results(ir, "main", &[3, -4]);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(3)]), 9);
+}
+
+#[test]
+fn callee_slots_become_caller_slots() {
+    let ir = "func @roundtrip(v1:i32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:i32 = load v2, 0
+  return v3
+}
+func @main(v1:i32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  v3:i32 = iconst.i32 11
+  store v2, 0, v3
+  v4:i32 = call @roundtrip(v1)
+  v5:i32 = load v2, 0
+  v6:i32 = iadd v4, v5
+  return v6
+}
+";
+    let module = assert_same_results(ir, "main", &[5]);
+    let main = module
+        .functions
+        .values()
+        .find(|f| f.name == "main")
+        .unwrap();
+    assert_eq!(main.slots.len(), 2);
+}
+
+#[test]
+fn call_chains_inline_callees_first() {
+    let ir = "func @leaf(v1:i32) -> i32 {
+  v2:i32 = imul_imm v1, 3
+  return v2
+}
+func @mid(v1:i32) -> i32 {
+  v2:i32 = call @leaf(v1)
+  v3:i32 = iadd_imm v2, 1
+  return v3
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = call @mid(v1)
+  v3:i32 = call @leaf(v2)
+  return v3
+}
+";
+    let module = assert_same_results(ir, "main", &[2, -9]);
+    assert_eq!(local_calls_in(&module, "main"), 0);
+    assert_eq!(local_calls_in(&module, "mid"), 0);
+}
+
+#[test]
+fn recursive_functions_are_not_inlined() {
+    let ir = "func @fact(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 1
+  v3:i32 = ile_s v1, v2
+  if v3 {
+    return v2
+  }
+  v4:i32 = isub v1, v2
+  v5:i32 = call @fact(v4)
+  v6:i32 = imul v1, v5
+  return v6
+}
+func @main(v1:i32) -> i32 {
+  v2:i32 = call @fact(v1)
+  return v2
+}
+";
+    let (module, stats) = inlined(ir, &config(InlineMode::Always));
+    assert_eq!(stats.sites_inlined, 0);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(5)]), 120);
+}
+
+/// 25 ops: over the default small-function threshold of 20.
+fn big_helper() -> String {
+    let mut ir = String::from("func @big(v1:i32) -> i32 {\n");
+    for _ in 0..24 {
+        ir.push_str("  v1 = iadd_imm v1, 1\n");
+    }
+    ir.push_str("  return v1\n}\n");
+    ir
+}
+
+#[test]
+fn auto_inlines_a_big_callee_only_at_a_single_site() {
+    let one_site = big_helper()
+        + "func @main(v1:i32) -> i32 {
+  v2:i32 = call @big(v1)
+  return v2
+}
+";
+    let (module, stats) = inlined(&one_site, &InlineConfig::default());
+    assert_eq!(stats.sites_inlined, 1);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(1)]), 25);
+
+    let no_single_site = InlineConfig {
+        always_inline_single_site: false,
+        ..InlineConfig::default()
+    };
+    let (_, stats) = inlined(&one_site, &no_single_site);
+    assert_eq!(stats.sites_inlined, 0);
+
+    let two_sites = big_helper()
+        + "func @main(v1:i32) -> i32 {
+  v2:i32 = call @big(v1)
+  v3:i32 = call @big(v2)
+  return v3
+}
+";
+    let (_, stats) = inlined(&two_sites, &InlineConfig::default());
+    assert_eq!(stats.sites_inlined, 0);
+    let (module, stats) = inlined(&two_sites, &config(InlineMode::Always));
+    assert_eq!(stats.sites_inlined, 2);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(0)]), 48);
+}
+
+#[test]
+fn budgets_keep_calls_that_would_overgrow() {
+    // The module starts at 5 ops; each inlined `inc` adds 1.
+    let growth_cap = InlineConfig {
+        max_growth_budget: Some(1),
+        ..InlineConfig::default()
+    };
+    let (module, stats) = inlined(SMALL_HELPER, &growth_cap);
+    assert_eq!((stats.sites_inlined, stats.sites_over_budget), (1, 1));
+    assert_eq!(stats.ops_added, 1);
+    assert_eq!(local_calls_in(&module, "main"), 1);
+    assert_eq!(run_i32(&module, "main", &[Value::I32(40)]), 42);
+
+    let module_cap = InlineConfig {
+        module_op_budget: Some(5),
+        ..InlineConfig::default()
+    };
+    let (_, stats) = inlined(SMALL_HELPER, &module_cap);
+    assert_eq!((stats.sites_inlined, stats.sites_over_budget), (0, 2));
+
+    // Always means always.
+    let always = InlineConfig {
+        module_op_budget: Some(5),
+        ..config(InlineMode::Always)
+    };
+    let (_, stats) = inlined(SMALL_HELPER, &always);
+    assert_eq!(stats.sites_inlined, 2);
+}
@@ -1429,6 +1429,29 @@ fn interp_slot_store_load() {
     assert!((r - 42.5).abs() < 1e-6);
 }
 
+#[test]
+fn interp_rodata_load() {
+    let ir = "rodata i32 7, f32 0.25, i32 -3
+
+func @f(v1:i32) -> i32 {
+  v2:ptr = rodata_addr
+  v3:i32 = imul_imm v1, 4
+  v4:ptr = iadd v2, v3
+  v5:i32 = load v4, 0
+  return v5
+}
+
+func @g() -> f32 {
+  v1:ptr = rodata_addr
+  v2:f32 = load v1, 4
+  return v2
+}
+";
+    assert_eq!(run_i32(ir, "f", &[Value::I32(0)]), 7);
+    assert_eq!(run_i32(ir, "f", &[Value::I32(2)]), -3);
+    assert!((run_f32(ir, "g", &[]) - 0.25).abs() < 1e-6);
+}
+
 #[test]
 fn interp_slot_store_load_i32() {
     let ir = "func @f(v1:i32) -> i32 {
@@ -0,0 +1,719 @@
+//! Middle-end passes ([`crate::pipeline`]): what each pass rewrites, what it
+//! must leave alone, and results unchanged by the whole pipeline.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::compiler_config::OptConfig;
+use crate::const_fold::fold_constants;
+use crate::cse::eliminate_common_subexpressions;
+use crate::dce::eliminate_dead_code;
+use crate::interp::{ImportHandler, InterpError, Value, interpret};
+use crate::licm::hoist_loop_invariants;
+use crate::lpir_module::{IrFunction, LpirModule};
+use crate::parse::parse_module;
+use crate::pipeline::{Pass, PassPipeline};
+use crate::print::print_module;
+use crate::scalarize::scalarize_slots;
+use crate::strength_reduce::strength_reduce;
+use crate::types::FloatMode;
+use crate::unroll::unroll_loops;
+use crate::validate::validate_module;
+
+struct NoImports;
+
+impl ImportHandler for NoImports {
+    fn call(&mut self, _: &str, _: &str, _: &[Value]) -> Result<Vec<Value>, InterpError> {
+        Err(InterpError::Import(String::from("no imports")))
+    }
+}
+
+/// Run `pass` over the single function in `ir`, check the result validates,
+/// and return it printed with the pass's count.
+fn apply(ir: &str, pass: impl FnOnce(&mut IrFunction) -> usize) -> (String, usize) {
+    let mut module = parse_module(ir).unwrap_or_else(|e| panic!("parse: {e:?}"));
+    let func = module.functions.values_mut().next().expect("one function");
+    let n = pass(func);
+    validate_module(&module)
+        .unwrap_or_else(|e| panic!("validate after: {e:?}\n{}", print_module(&module)));
+    (print_module(&module), n)
+}
+
+fn run_f32(module: &LpirModule, func: &str, args: &[Value]) -> f32 {
+    let out = interpret(module, func, args, &mut NoImports).unwrap();
+    assert_eq!(out.len(), 1);
+    out[0].as_f32().expect("f32")
+}
+
+fn run_i32(module: &LpirModule, func: &str, args: &[Value]) -> i32 {
+    let out = interpret(module, func, args, &mut NoImports).unwrap();
+    assert_eq!(out.len(), 1);
+    out[0].as_i32().expect("i32")
+}
+
+// --- Strength reduction ---
+
+#[test]
+fn strength_reduce_int_powers_of_two() {
+    let (out, n) = apply(
+        "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 8
+  v3:i32 = imul v1, v2
+  v4:i32 = imul_imm v3, 1
+  v5:i32 = iconst.i32 4
+  v6:i32 = idiv_u v4, v5
+  v7:i32 = imul_imm v6, 12
+  return v7
+}
+",
+        |f| strength_reduce(f, FloatMode::Q32),
+    );
+    assert_eq!(n, 3);
+    assert_eq!(
+        out,
+        "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 8
+  v3:i32 = ishl_imm v1, 3
+  v4:i32 = copy v3
+  v5:i32 = iconst.i32 4
+  v6:i32 = ishr_u_imm v4, 2
+  v7:i32 = imul_imm v6, 12
+  return v7
+}
+"
+    );
+}
+
+#[test]
+fn strength_reduce_ignores_reassigned_constant() {
+    let ir = "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 8
+  if v1 {
+    v2 = iconst.i32 3
+  }
+  v3:i32 = imul v1, v2
+  return v3
+}
+";
+    let (out, n) = apply(ir, |f| strength_reduce(f, FloatMode::Q32));
+    assert_eq!(n, 0);
+    assert_eq!(out, ir);
+}
+
+#[test]
+fn strength_reduce_float_rules_follow_float_mode() {
+    let ir = "func @f(v1:f32) -> f32 {
+  v2:f32 = fconst.f32 0.0
+  v3:f32 = fmul v1, v2
+  v4:f32 = fadd v3, v2
+  v5:f32 = fconst.f32 2.0
+  v6:f32 = fmul v4, v5
+  return v6
+}
+";
+    let (q32, n) = apply(ir, |f| strength_reduce(f, FloatMode::Q32));
+    assert_eq!(n, 3);
+    assert_eq!(
+        q32,
+        "func @f(v1:f32) -> f32 {
+  v2:f32 = fconst.f32 0.0
+  v3:f32 = fconst.f32 0.0
+  v4:f32 = copy v3
+  v5:f32 = fconst.f32 2.0
+  v6:f32 = fadd v4, v4
+  return v6
+}
+"
+    );
+    // `x * 0.0` and `x + 0.0` are not identities for NaN or `-0.0`.
+    let (f32, n) = apply(ir, |f| strength_reduce(f, FloatMode::F32));
+    assert_eq!(n, 1);
+    assert!(f32.contains("v3:f32 = fmul v1, v2"), "{f32}");
+    assert!(f32.contains("v4:f32 = fadd v3, v2"), "{f32}");
+    assert!(f32.contains("v6:f32 = fadd v4, v4"), "{f32}");
+}
+
+// --- CSE ---
+
+#[test]
+fn cse_reuses_identical_and_commuted_ops() {
+    let (out, n) = apply(
+        "func @f(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iadd v1, v2
+  v4:i32 = iadd v2, v1
+  v5:i32 = isub v1, v2
+  v6:i32 = isub v2, v1
+  v7:i32 = iadd v3, v4
+  v8:i32 = iadd v5, v6
+  v9:i32 = iadd v7, v8
+  return v9
+}
+",
+        eliminate_common_subexpressions,
+    );
+    assert_eq!(n, 1);
+    assert!(out.contains("v4:i32 = copy v3"), "{out}");
+    assert!(out.contains("v6:i32 = isub v2, v1"), "{out}");
+}
+
+#[test]
+fn cse_forgets_reassigned_operands_and_closed_arms() {
+    let ir = "func @f(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iadd v1, v2
+  v1 = iadd_imm v1, 1
+  v4:i32 = iadd v1, v2
+  if v2 {
+    v5:i32 = imul v1, v2
+  } else {
+    v6:i32 = imul v1, v2
+  }
+  v7:i32 = imul v1, v2
+  v8:i32 = iadd v3, v4
+  v9:i32 = iadd v8, v7
+  return v9
+}
+";
+    let (out, n) = apply(ir, eliminate_common_subexpressions);
+    assert_eq!(n, 0, "{out}");
+}
+
+#[test]
+fn cse_does_not_reuse_across_loop_iterations() {
+    let ir = "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = iconst.i32 0
+  v4:i32 = iadd v2, v1
+  loop {
+    v5:i32 = ilt_s v3, v1
+    br_if_not v5
+    v6:i32 = iadd v2, v1
+    v2 = iadd v2, v6
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  v7:i32 = iadd v4, v2
+  return v7
+}
+";
+    let (out, n) = apply(ir, eliminate_common_subexpressions);
+    assert_eq!(n, 0, "{out}");
+    let module = parse_module(ir).unwrap();
+    assert_eq!(run_i32(&module, "f", &[Value::I32(3)]), 3 + 21);
+}
+
+// --- LICM ---
+
+const NESTED_INVARIANT: &str = "func @f(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iconst.i32 0
+  v4:i32 = iconst.i32 0
+  loop {
+    v5:i32 = ilt_s v4, v1
+    br_if_not v5
+    v6:i32 = iconst.i32 0
+    loop {
+      v7:i32 = ilt_s v6, v2
+      br_if_not v7
+      v8:i32 = imul v1, v2
+      v9:i32 = iadd_imm v8, 3
+      v10:i32 = idiv_s v9, v2
+      v11:i32 = iadd v6, v9
+      v3 = iadd v3, v11
+      v3 = iadd v3, v10
+      v6 = iadd_imm v6, 1
+      continue
+    }
+    v4 = iadd_imm v4, 1
+    continue
+  }
+  return v3
+}
+";
+
+#[test]
+fn licm_hoists_to_outermost_invariant_loop() {
+    let (out, n) = apply(NESTED_INVARIANT, hoist_loop_invariants);
+    assert_eq!(n, 2, "{out}");
+    assert_eq!(
+        out,
+        "func @f(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iconst.i32 0
+  v4:i32 = iconst.i32 0
+  v8:i32 = imul v1, v2
+  v9:i32 = iadd_imm v8, 3
+  loop {
+    v5:i32 = ilt_s v4, v1
+    br_if_not v5
+    v6:i32 = iconst.i32 0
+    loop {
+      v7:i32 = ilt_s v6, v2
+      br_if_not v7
+      v10:i32 = idiv_s v9, v2
+      v11:i32 = iadd v6, v9
+      v3 = iadd v3, v11
+      v3 = iadd v3, v10
+      v6 = iadd_imm v6, 1
+      continue
+    }
+    v4 = iadd_imm v4, 1
+    continue
+  }
+  return v3
+}
+"
+    );
+}
+
+#[test]
+fn licm_keeps_ops_reading_loop_carried_vregs() {
+    let ir = "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = iconst.i32 0
+  loop {
+    v4:i32 = ilt_s v3, v1
+    br_if_not v4
+    v5:i32 = imul_imm v2, 2
+    v2 = iadd_imm v5, 1
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  return v2
+}
+";
+    let (out, n) = apply(ir, hoist_loop_invariants);
+    assert_eq!(n, 0, "{out}");
+}
+
+#[test]
+fn licm_leaves_constants_unless_a_hoisted_op_reads_them() {
+    let ir = "func @f(v1:i32, v2:f32) -> f32 {
+  v3:i32 = iconst.i32 0
+  v4:f32 = fconst.f32 0.0
+  loop {
+    v5:i32 = ilt_s v3, v1
+    br_if_not v5
+    v6:f32 = fconst.f32 1.5
+    v7:f32 = fconst.f32 2.0
+    v8:f32 = fmul v2, v7
+    v4 = fadd v4, v6
+    v4 = fadd v4, v8
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  return v4
+}
+";
+    let (out, n) = apply(ir, hoist_loop_invariants);
+    assert_eq!(n, 2, "{out}");
+    assert_eq!(
+        out,
+        "func @f(v1:i32, v2:f32) -> f32 {
+  v3:i32 = iconst.i32 0
+  v4:f32 = fconst.f32 0.0
+  v7:f32 = fconst.f32 2.0
+  v8:f32 = fmul v2, v7
+  loop {
+    v5:i32 = ilt_s v3, v1
+    br_if_not v5
+    v6:f32 = fconst.f32 1.5
+    v4 = fadd v4, v6
+    v4 = fadd v4, v8
+    v3 = iadd_imm v3, 1
+    continue
+  }
+  return v4
+}
+"
+    );
+}
+
+// --- DCE ---
+
+#[test]
+fn dce_removes_unread_chains_but_keeps_trapping_ops() {
+    let (out, n) = apply(
+        "func @f(v1:i32, v2:i32) -> i32 {
+  v3:i32 = iadd v1, v2
+  v4:i32 = imul_imm v3, 4
+  v5:i32 = idiv_s v1, v2
+  v1 = copy v1
+  if v1 {
+    v6:i32 = isub v1, v2
+  }
+  return v2
+}
+",
+        eliminate_dead_code,
+    );
+    assert_eq!(n, 4);
+    assert_eq!(
+        out,
+        "func @f(v1:i32, v2:i32) -> i32 {
+  v5:i32 = idiv_s v1, v2
+  if v1 {
+  }
+  return v2
+}
+"
+    );
+}
+
+// --- Unrolling ---
+
+const COUNTED_LOOP: &str = "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = copy v2
+  loop {
+    v4:i32 = iconst.i32 3
+    v5:i32 = ilt_s v3, v4
+    br_if_not v5
+    v1 = iadd v1, v3
+    continuing:
+    v3 = iadd_imm v3, 1
+  }
+  return v1
+}
+";
+
+#[test]
+fn unroll_replaces_counted_loop_with_copies() {
+    let (out, n) = apply(COUNTED_LOOP, |f| unroll_loops(f, 8, 256));
+    assert_eq!(n, 1);
+    assert_eq!(
+        out,
+        "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  v3:i32 = copy v2
+  v4:i32 = iconst.i32 3
+  v5:i32 = ilt_s v3, v4
+  v1 = iadd v1, v3
+  v3 = iadd_imm v3, 1
+  v4 = iconst.i32 3
+  v5 = ilt_s v3, v4
+  v1 = iadd v1, v3
+  v3 = iadd_imm v3, 1
+  v4 = iconst.i32 3
+  v5 = ilt_s v3, v4
+  v1 = iadd v1, v3
+  v3 = iadd_imm v3, 1
+  v4 = iconst.i32 3
+  v5 = ilt_s v3, v4
+  return v1
+}
+"
+    );
+    let module = parse_module(&out).unwrap();
+    assert_eq!(run_i32(&module, "f", &[Value::I32(10)]), 13);
+}
+
+#[test]
+fn unroll_leaves_loops_it_cannot_count_or_afford() {
+    // Bound not a constant.
+    let unknown = COUNTED_LOOP.replace("v5:i32 = ilt_s v3, v4", "v5:i32 = ilt_s v3, v1");
+    // Counter only advanced on one path.
+    let conditional = COUNTED_LOOP.replace(
+        "    v3 = iadd_imm v3, 1\n",
+        "    if v1 {\n      v3 = iadd_imm v3, 1\n    }\n",
+    );
+    // Leaves early.
+    let breaks = COUNTED_LOOP.replace(
+        "    v1 = iadd v1, v3\n",
+        "    v1 = iadd v1, v3\n    if v1 {\n      break\n    }\n",
+    );
+    for ir in [&unknown, &conditional, &breaks] {
+        let (out, n) = apply(ir, |f| unroll_loops(f, 8, 256));
+        assert_eq!(n, 0, "{out}");
+        assert_eq!(&out, ir);
+    }
+    // Three trips, but more than two allowed or more than 8 ops.
+    for (max_trip, max_ops) in [(2, 256), (8, 8)] {
+        let (out, n) = apply(COUNTED_LOOP, |f| unroll_loops(f, max_trip, max_ops));
+        assert_eq!(n, 0, "{out}");
+    }
+}
+
+const NESTED_COUNTED: &str = "func @f(v1:i32) -> i32 {
+  v2:i32 = iconst.i32 0
+  loop {
+    v3:i32 = ilt_s v2, v1
+    br_if_not v3
+    v4:i32 = iconst.i32 0
+    loop {
+      v5:i32 = iconst.i32 2
+      v6:i32 = ile_s v4, v5
+      br_if_not v6
+      if v4 {
+        v7:i32 = imul_imm v4, 10
+        v2 = iadd v2, v7
+      }
+      v4 = iadd_imm v4, 1
+      continue
+    }
+    v2 = iadd_imm v2, 1
+    continue
+  }
+  return v2
+}
+";
+
+#[test]
+fn unroll_takes_inner_counted_loop_and_folds_its_counter() {
+    let mut module = parse_module(NESTED_COUNTED).unwrap();
+    let func = module.functions.values_mut().next().unwrap();
+    assert_eq!(unroll_loops(func, 8, 256), 1);
+    assert!(fold_constants(func) > 0);
+    validate_module(&module).unwrap_or_else(|e| panic!("{e:?}\n{}", print_module(&module)));
+    let out = print_module(&module);
+    assert_eq!(out.matches("loop {").count(), 1, "{out}");
+    // Every copy's `imul_imm` saw its counter as a constant.
+    assert!(!out.contains("imul_imm"), "{out}");
+    let original = parse_module(NESTED_COUNTED).unwrap();
+    for n in [0, 1, 5, 40] {
+        assert_eq!(
+            run_i32(&module, "f", &[Value::I32(n)]),
+            run_i32(&original, "f", &[Value::I32(n)]),
+            "f({n})\n{out}"
+        );
+    }
+}
+
+// --- Scalarization ---
+
+#[test]
+fn scalarize_promotes_slot_with_constant_offsets() {
+    let (out, n) = apply(
+        "func @f(v1:f32, v2:f32, v3:i32) -> f32 {
+  slot ss0, 8
+  slot ss1, 8
+  v4:i32 = slot_addr ss0
+  store v4, 0, v1
+  v5:i32 = iconst.i32 4
+  v6:i32 = iadd v4, v5
+  store v6, 0, v2
+  v7:f32 = load v4, 4
+  v8:i32 = slot_addr ss1
+  v9:i32 = iadd v8, v3
+  store v9, 0, v7
+  v10:f32 = load v8, 0
+  return v10
+}
+",
+        |f| scalarize_slots(f, 64),
+    );
+    assert_eq!(n, 1);
+    assert_eq!(
+        out,
+        "func @f(v1:f32, v2:f32, v3:i32) -> f32 {
+  slot ss0, 8
+  v11:f32 = fconst.f32 0.0
+  v12:f32 = fconst.f32 0.0
+  v11 = copy v1
+  v5:i32 = iconst.i32 4
+  v12 = copy v2
+  v7:f32 = copy v12
+  v8:i32 = slot_addr ss0
+  v9:i32 = iadd v8, v3
+  store v9, 0, v7
+  v10:f32 = load v8, 0
+  return v10
+}
+"
+    );
+}
+
+#[test]
+fn scalarize_keeps_slots_it_cannot_resolve() {
+    let cases = [
+        // Narrow access.
+        "func @f(v1:i32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:i32 = load8u v2, 0
+  return v3
+}
+",
+        // Past the end.
+        "func @f(v1:i32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:i32 = load v2, 4
+  return v3
+}
+",
+        // One lane, two types.
+        "func @f(v1:f32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:i32 = load v2, 0
+  return v3
+}
+",
+        // The address escapes.
+        "func @f(v1:i32) -> i32 {
+  slot ss0, 4
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  return v2
+}
+",
+        // Too large.
+        "func @f(v1:i32) -> i32 {
+  slot ss0, 128
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:i32 = load v2, 0
+  return v3
+}
+",
+    ];
+    for ir in cases {
+        let (out, n) = apply(ir, |f| scalarize_slots(f, 64));
+        assert_eq!(n, 0, "{out}");
+        assert_eq!(out, ir);
+    }
+}
+
+#[test]
+fn scalarize_splits_memcpy_into_lanes() {
+    let ir = "func @f(v1:i32, v2:i32) -> i32 {
+  slot ss0, 8
+  slot ss1, 8
+  v3:i32 = slot_addr ss0
+  v4:i32 = slot_addr ss1
+  store v3, 0, v1
+  store v3, 4, v2
+  memcpy v4, v3, 8
+  v5:i32 = load v4, 4
+  return v5
+}
+";
+    let mut module = parse_module(ir).unwrap();
+    let func = module.functions.values_mut().next().unwrap();
+    assert_eq!(scalarize_slots(func, 64), 2);
+    validate_module(&module).unwrap_or_else(|e| panic!("{e:?}\n{}", print_module(&module)));
+    let out = print_module(&module);
+    assert!(!out.contains("memcpy") && !out.contains("slot"), "{out}");
+    assert_eq!(run_i32(&module, "f", &[Value::I32(1), Value::I32(7)]), 7);
+}
+
+// --- Pipeline ---
+
+#[test]
+fn pipeline_runs_enabled_passes_in_order() {
+    let all = PassPipeline::new(&OptConfig::default(), FloatMode::Q32);
+    assert_eq!(all.passes(), &Pass::ALL);
+
+    let config = OptConfig {
+        cse: false,
+        licm: false,
+        ..OptConfig::default()
+    };
+    let some = PassPipeline::new(&config, FloatMode::Q32);
+    assert_eq!(
+        some.passes(),
+        &[
+            Pass::Unroll,
+            Pass::ConstFold,
+            Pass::Scalarize,
+            Pass::StrengthReduce,
+            Pass::Dce
+        ]
+    );
+
+    let none = OptConfig {
+        unroll: false,
+        scalarize: false,
+        const_fold: false,
+        strength_reduce: false,
+        cse: false,
+        licm: false,
+        dce: false,
+        ..OptConfig::default()
+    };
+    let none = PassPipeline::new(&none, FloatMode::Q32);
+    assert!(none.is_empty());
+    let mut module = parse_module(NESTED_INVARIANT).unwrap();
+    let before = print_module(&module);
+    assert_eq!(none.run_module(&mut module), 0);
+    assert_eq!(print_module(&module), before);
+}
+
+#[test]
+fn pipeline_preserves_results() {
+    let original = parse_module(NESTED_INVARIANT).unwrap();
+    let mut module = original.clone();
+    let changed = PassPipeline::new(&OptConfig::default(), FloatMode::F32).run_module(&mut module);
+    assert!(changed > 0);
+    validate_module(&module).unwrap_or_else(|e| panic!("{e:?}\n{}", print_module(&module)));
+    for (x, y) in [(0, 0), (1, 1), (3, 4), (5, 2), (-2, 7)] {
+        let args = [Value::I32(x), Value::I32(y)];
+        assert_eq!(
+            run_i32(&module, "f", &args),
+            run_i32(&original, "f", &args),
+            "f({x}, {y})\n{}",
+            print_module(&module)
+        );
+    }
+}
+
+const ARRAY_LOOP: &str = "func @f(v1:f32) -> f32 {
+  slot ss0, 12
+  v2:i32 = slot_addr ss0
+  store v2, 0, v1
+  v3:f32 = fadd v1, v1
+  store v2, 4, v3
+  v4:f32 = fmul v3, v3
+  store v2, 8, v4
+  v5:f32 = fconst.f32 0.0
+  v6:i32 = iconst.i32 0
+  v7:i32 = copy v6
+  loop {
+    v8:i32 = iconst.i32 3
+    v9:i32 = ilt_s v7, v8
+    br_if_not v9
+    v10:i32 = iconst.i32 0
+    v11:i32 = ilt_s v7, v10
+    v12:i32 = select v11, v10, v7
+    v13:i32 = imul_imm v12, 4
+    v14:i32 = iadd v2, v13
+    v15:f32 = load v14, 0
+    v5 = fadd v5, v15
+    continuing:
+    v16:i32 = iconst.i32 1
+    v17:i32 = iadd v7, v16
+    v7 = copy v17
+  }
+  return v5
+}
+";
+
+#[test]
+fn pipeline_unrolls_and_scalarizes_indexed_array() {
+    let original = parse_module(ARRAY_LOOP).unwrap();
+    let mut module = original.clone();
+    PassPipeline::new(&OptConfig::default(), FloatMode::F32).run_module(&mut module);
+    validate_module(&module).unwrap_or_else(|e| panic!("{e:?}\n{}", print_module(&module)));
+    let out = print_module(&module);
+    for gone in ["loop", "slot", "load", "store"] {
+        assert!(!out.contains(gone), "{gone}\n{out}");
+    }
+    for x in [0.0, 1.5, -3.0] {
+        let args = [Value::F32(x)];
+        assert_eq!(run_f32(&module, "f", &args), run_f32(&original, "f", &args));
+    }
+
+    // Without unrolling the index stays dynamic and the array in memory.
+    let config = OptConfig {
+        unroll: false,
+        ..OptConfig::default()
+    };
+    let mut module = original.clone();
+    PassPipeline::new(&config, FloatMode::F32).run_module(&mut module);
+    let out = print_module(&module);
+    assert!(
+        out.contains("loop") && out.contains("slot ss0, 12"),
+        "{out}"
+    );
+}
@@ -99,6 +99,7 @@ fn validate_err_break_outside_loop() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("expected validation errors");
     assert!(errs.iter().any(|e| e.message.contains("loop")));
@@ -154,6 +155,7 @@ fn validate_err_undefined_vreg() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs =
         validate_function(m.functions.values().next().unwrap(), &m).expect_err("undefined v0");
@@ -181,6 +183,7 @@ fn validate_err_copy_type_mismatch() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("copy types");
     assert!(errs.iter().any(|e| e.message.contains("copy")));
@@ -207,6 +210,7 @@ fn validate_err_call_arity() {
             sret: false,
         }],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("call arity");
     assert!(errs.iter().any(|e| e.message.contains("arg count")));
@@ -234,6 +238,7 @@ fn validate_err_callee_oob() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("callee");
     assert!(errs.iter().any(|e| e.message.contains("callee")));
@@ -256,6 +261,7 @@ fn validate_err_continue_outside_loop() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("continue");
     assert!(errs.iter().any(|e| e.message.contains("loop")));
@@ -295,6 +301,7 @@ fn validate_err_duplicate_switch_case() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("dup case");
     assert!(
@@ -329,6 +336,7 @@ fn validate_err_return_value_type() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("return type");
     assert!(errs.iter().any(|e| e.message.contains("return value")));
@@ -354,6 +362,7 @@ fn validate_err_vreg_pool_oob() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("pool");
     assert!(errs.iter().any(|e| e.message.contains("pool")));
@@ -456,11 +465,35 @@ fn validate_err_slot_addr_oob() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_function(m.functions.values().next().unwrap(), &m).expect_err("bad slot");
     assert!(errs.iter().any(|e| e.message.contains("slot")));
 }
 
+#[test]
+fn validate_err_rodata_addr_without_rodata() {
+    let f = IrFunction {
+        name: String::from("bad"),
+        is_entry: false,
+        vmctx_vreg: VMCTX_VREG,
+        param_count: 0,
+        return_types: Vec::new(),
+        sret_arg: None,
+        vreg_types: vec![IrType::Pointer, IrType::Pointer],
+        slots: Vec::new(),
+        body: vec![LpirOp::RodataAddr { dst: VReg(1) }].into(),
+        vreg_pool: Vec::new(),
+    };
+    let m = LpirModule {
+        imports: Vec::new(),
+        functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
+    };
+    let errs = validate_function(m.functions.values().next().unwrap(), &m).expect_err("no rodata");
+    assert!(errs.iter().any(|e| e.message.contains("rodata")));
+}
+
 #[test]
 fn validate_err_sret_with_return_types() {
     let f = IrFunction {
@@ -481,6 +514,7 @@ fn validate_err_sret_with_return_types() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("sret return_types");
     assert!(
@@ -509,6 +543,7 @@ fn validate_err_sret_wrong_vreg_index() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("sret index");
     assert!(errs.iter().any(|e| e.message.contains("vmctx+1")));
@@ -540,6 +575,7 @@ fn validate_err_sret_return_values() {
     let m = LpirModule {
         imports: Vec::new(),
         functions: VecMap::from([(FuncId(0), f)]),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("sret return values");
     assert!(
@@ -561,6 +597,7 @@ fn validate_err_import_sret_nonempty_returns() {
             sret: true,
         }],
         functions: VecMap::new(),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("import sret rets");
     assert!(
@@ -582,6 +619,7 @@ fn validate_err_import_sret_first_not_ptr() {
             sret: true,
         }],
         functions: VecMap::new(),
+        rodata: Vec::new(),
     };
     let errs = validate_module(&m).expect_err("import sret ptr");
     assert!(errs.iter().any(|e| e.message.contains("first param_types")));
@@ -0,0 +1,296 @@
+//! LPIR loop unrolling.
+//!
+//! Replaces a loop whose trip count is known at compile time with that many
+//! copies of its body. Each copy then sees its induction variable as a
+//! constant: const folding resolves the index arithmetic, a small array
+//! indexed by the counter can be scalarized ([`crate::scalarize`]), and the
+//! backend drops the compare and branch per iteration. Octave loops in noise
+//! and fbm shaders are the case this is for.
+//!
+//! A loop qualifies when:
+//! - it opens with straight-line ops ending in a `br_if_not`, the header the
+//!   frontends emit for `for` and `while`;
+//! - nothing else leaves it or starts its next iteration early: no `break`,
+//!   `continue` or `br_if_not` of its own past the header, other than one
+//!   `continue` closing the body;
+//! - evaluating the straight-line ops of successive iterations from the
+//!   constants known on entry decides the header condition every time, and
+//!   it turns false within `max_trip` iterations.
+//!
+//! Ops inside nested ifs, loops and switches count as unknown for that
+//! evaluation, so a counter that only advances conditionally keeps its loop.
+//! The header runs once more after the last copy, as it did when the loop
+//! exited. Inner loops are tried before the loops around them, and a loop
+//! stays when unrolling it would emit more than `max_ops` ops.
+
+use alloc::vec::Vec;
+
+use crate::lpir_module::{IrFunction, LpirBody};
+use crate::lpir_op::LpirOp;
+use crate::rewrite::{FlowFacts, end_index, for_each_def, has_offsets, relocate_with};
+use crate::types::VReg;
+
+/// A loop to unroll, by body index.
+#[derive(Clone, Copy)]
+struct Shape {
+    /// Its `LoopStart`.
+    start: usize,
+    /// The header's `br_if_not`.
+    exit: usize,
+    /// Where one iteration ends: the closing `continue`, or `end`.
+    tail: usize,
+    /// Its `End`.
+    end: usize,
+    trip: u32,
+}
+
+/// Unroll each loop in `func.body` with a known trip count of at most
+/// `max_trip` whose unrolled form is at most `max_ops` ops. Returns the
+/// number of loops unrolled.
+pub fn unroll_loops(func: &mut IrFunction, max_trip: u32, max_ops: usize) -> usize {
+    let mut unrolled = 0;
+    'search: loop {
+        let starts: Vec<usize> = (0..func.body.len())
+            .filter(|&i| matches!(func.body[i], LpirOp::LoopStart { .. }))
+            .collect();
+        // Later starts first: an inner loop before the loop around it.
+        for &start in starts.iter().rev() {
+            if let Some(shape) = plan(func, start, max_trip, max_ops) {
+                unroll(func, shape);
+                unrolled += 1;
+                continue 'search;
+            }
+        }
+        return unrolled;
+    }
+}
+
+fn plan(func: &IrFunction, start: usize, max_trip: u32, max_ops: usize) -> Option<Shape> {
+    let LpirOp::LoopStart {
+        continuing_offset, ..
+    } = func.body[start]
+    else {
+        return None;
+    };
+    let end = end_index(&func.body[start])?;
+    let exit = (start + 1..end).find(|&i| is_control(&func.body[i]))?;
+    let LpirOp::BrIfNot { cond } = func.body[exit] else {
+        return None;
+    };
+    let continuing = continuing_offset as usize;
+    if continuing != start + 1 && continuing <= exit {
+        return None;
+    }
+    let tail = if end > exit + 1 && matches!(func.body[end - 1], LpirOp::Continue) {
+        end - 1
+    } else {
+        end
+    };
+    if !stays_inside(func, exit + 1, tail) {
+        return None;
+    }
+    let trip = trip_count(func, start, exit, tail, cond, max_trip)?;
+    let size = (tail - start - 2)
+        .checked_mul(trip as usize)?
+        .checked_add(exit - start - 1)?;
+    (size <= max_ops).then_some(Shape {
+        start,
+        exit,
+        tail,
+        end,
+        trip,
+    })
+}
+
+fn is_control(op: &LpirOp) -> bool {
+    has_offsets(op)
+        || matches!(
+            op,
+            LpirOp::Else
+                | LpirOp::End
+                | LpirOp::Break
+                | LpirOp::Continue
+                | LpirOp::BrIfNot { .. }
+                | LpirOp::ExitBlock
+                | LpirOp::Return { .. }
+        )
+}
+
+/// A construct open inside the range [`stays_inside`] checks.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Open {
+    Loop,
+    Block,
+    Other,
+}
+
+/// Whether control in `from..to` leaves it only by reaching `to` or
+/// returning: every `break`, `continue`, `br_if_not` and `exit_block` in it
+/// targets a loop or block that is itself inside the range.
+fn stays_inside(func: &IrFunction, from: usize, to: usize) -> bool {
+    let mut open: Vec<Open> = Vec::new();
+    for i in from..to {
+        match &func.body[i] {
+            LpirOp::LoopStart { .. } => open.push(Open::Loop),
+            LpirOp::Block { .. } => open.push(Open::Block),
+            LpirOp::IfStart { .. }
+            | LpirOp::SwitchStart { .. }
+            | LpirOp::CaseStart { .. }
+            | LpirOp::DefaultStart { .. } => open.push(Open::Other),
+            LpirOp::End => {
+                if open.pop().is_none() {
+                    return false;
+                }
+            }
+            LpirOp::Break | LpirOp::Continue | LpirOp::BrIfNot { .. } => {
+                if !open.contains(&Open::Loop) {
+                    return false;
+                }
+            }
+            LpirOp::ExitBlock => {
+                if !open.contains(&Open::Block) {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    open.is_empty()
+}
+
+/// How many times the loop at `start` runs its body, evaluating iterations
+/// from the constants known on entry; `None` past `max_trip` or once the
+/// condition is unknown.
+fn trip_count(
+    func: &IrFunction,
+    start: usize,
+    exit: usize,
+    tail: usize,
+    cond: VReg,
+    max_trip: u32,
+) -> Option<u32> {
+    let mut facts = FlowFacts::new(func);
+    for i in 0..start {
+        facts.enter(func, i);
+        step(func, &mut facts, &func.body[i]);
+    }
+    for trip in 0..=max_trip {
+        let mut i = start + 1;
+        while i < tail {
+            let op = &func.body[i];
+            if i == exit {
+                if *facts.get(cond)? == 0 {
+                    return Some(trip);
+                }
+            } else if let Some(end) = end_index(op) {
+                for j in i..end {
+                    for_each_def(func, &func.body[j], |v| facts.set(v, None));
+                }
+                i = end;
+            } else {
+                step(func, &mut facts, op);
+            }
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Record what `op` leaves in the vregs it writes.
+fn step(func: &IrFunction, facts: &mut FlowFacts<i32>, op: &LpirOp) {
+    let value = eval(op, |v| facts.get(v).copied());
+    for_each_def(func, op, |v| facts.set(v, None));
+    if let (Some(dst), Some(value)) = (op.def_vreg(), value) {
+        facts.set(dst, Some(value));
+    }
+}
+
+/// The integer `op` computes, when `get` knows its operands.
+fn eval(op: &LpirOp, get: impl Fn(VReg) -> Option<i32>) -> Option<i32> {
+    let ucmp = |lhs: VReg, rhs: VReg, f: fn(u32, u32) -> bool| {
+        Some(i32::from(f(get(lhs)? as u32, get(rhs)? as u32)))
+    };
+    Some(match *op {
+        LpirOp::IconstI32 { value, .. } => value,
+        LpirOp::Copy { src, .. } => get(src)?,
+        LpirOp::Iadd { lhs, rhs, .. } => get(lhs)?.wrapping_add(get(rhs)?),
+        LpirOp::Isub { lhs, rhs, .. } => get(lhs)?.wrapping_sub(get(rhs)?),
+        LpirOp::Imul { lhs, rhs, .. } => get(lhs)?.wrapping_mul(get(rhs)?),
+        LpirOp::IaddImm { src, imm, .. } => get(src)?.wrapping_add(imm),
+        LpirOp::IsubImm { src, imm, .. } => get(src)?.wrapping_sub(imm),
+        LpirOp::ImulImm { src, imm, .. } => get(src)?.wrapping_mul(imm),
+        LpirOp::IshlImm { src, imm, .. } => get(src)?.wrapping_shl(imm as u32),
+        LpirOp::IeqImm { src, imm, .. } => i32::from(get(src)? == imm),
+        LpirOp::Ieq { lhs, rhs, .. } => i32::from(get(lhs)? == get(rhs)?),
+        LpirOp::Ine { lhs, rhs, .. } => i32::from(get(lhs)? != get(rhs)?),
+        LpirOp::IltS { lhs, rhs, .. } => i32::from(get(lhs)? < get(rhs)?),
+        LpirOp::IleS { lhs, rhs, .. } => i32::from(get(lhs)? <= get(rhs)?),
+        LpirOp::IgtS { lhs, rhs, .. } => i32::from(get(lhs)? > get(rhs)?),
+        LpirOp::IgeS { lhs, rhs, .. } => i32::from(get(lhs)? >= get(rhs)?),
+        LpirOp::IltU { lhs, rhs, .. } => return ucmp(lhs, rhs, |l, r| l < r),
+        LpirOp::IleU { lhs, rhs, .. } => return ucmp(lhs, rhs, |l, r| l <= r),
+        LpirOp::IgtU { lhs, rhs, .. } => return ucmp(lhs, rhs, |l, r| l > r),
+        LpirOp::IgeU { lhs, rhs, .. } => return ucmp(lhs, rhs, |l, r| l >= r),
+        _ => return None,
+    })
+}
+
+/// Replace the loop with `trip` copies of one iteration, minus the header's
+/// `br_if_not`, and a last copy of the header.
+fn unroll(func: &mut IrFunction, shape: Shape) {
+    let Shape {
+        start,
+        exit,
+        tail,
+        end,
+        trip,
+    } = shape;
+    let per_iter = tail - start - 2;
+    let new_len = per_iter * trip as usize + (exit - start - 1);
+    let removed = end - start + 1;
+    let outside = |offset: u32| {
+        let t = offset as usize;
+        if t > end {
+            (t + new_len - removed) as u32
+        } else {
+            offset
+        }
+    };
+
+    let old = core::mem::take(&mut func.body);
+    let mut body = LpirBody::new();
+    let mut push = |op: &LpirOp, f: &dyn Fn(u32) -> u32| {
+        let mut op = op.clone();
+        if has_offsets(&op) {
+            relocate_with(&mut op, f);
+        }
+        body.push(op);
+    };
+    for i in 0..start {
+        push(&old[i], &outside);
+    }
+    for k in 0..trip as usize {
+        let base = start + k * per_iter;
+        // Offsets inside the loop land in this copy; its end is the start of
+        // the next one.
+        let inside = |offset: u32| {
+            let t = offset as usize;
+            let at = if t >= tail {
+                per_iter
+            } else {
+                t - (start + 1) - usize::from(t > exit)
+            };
+            (base + at) as u32
+        };
+        for i in (start + 1..tail).filter(|&i| i != exit) {
+            push(&old[i], &inside);
+        }
+    }
+    for i in start + 1..exit {
+        push(&old[i], &outside);
+    }
+    for i in end + 1..old.len() {
+        push(&old[i], &outside);
+    }
+    func.body = body;
+}
@@ -273,6 +273,13 @@ fn validate_function_inner(
 
         check_op_operands_defined(func, fname, op_i, op, &defined, errs);
         check_slot_memory_ops(func, fname, op_i, op, errs);
+        if matches!(op, LpirOp::RodataAddr { .. }) && module.rodata.is_empty() {
+            errs.push(err_in_func(
+                fname,
+                op_i,
+                "rodata_addr in a module without rodata",
+            ));
+        }
         check_opcode_dst_types(func, fname, op_i, op, errs);
 
         match op {
@@ -708,6 +715,7 @@ fn check_op_operands_defined(
             }
         }
         LpirOp::SlotAddr { .. }
+        | LpirOp::RodataAddr { .. }
         | LpirOp::FconstF32 { .. }
         | LpirOp::IconstI32 { .. }
         | LpirOp::Else
@@ -883,7 +891,12 @@ fn check_opcode_dst_types(
                 ));
             }
         }
-        LpirOp::SlotAddr { dst, .. } => {
+        LpirOp::SlotAddr { dst, .. } | LpirOp::RodataAddr { dst } => {
+            let name = if matches!(op, LpirOp::SlotAddr { .. }) {
+                "slot_addr"
+            } else {
+                "rodata_addr"
+            };
             let j = dst.0 as usize;
             if j < func.vreg_types.len() {
                 let ty = func.vreg_types[j];
@@ -892,7 +905,7 @@ fn check_opcode_dst_types(
                         fname,
                         op_i,
                         format!(
-                            "slot_addr: v{} has type {:?}, expected I32 or Pointer",
+                            "{name}: v{} has type {:?}, expected I32 or Pointer",
                             dst.0, ty
                         ),
                     ));
@@ -999,6 +1012,7 @@ fn mark_op_defs(func: &IrFunction, op: &LpirOp, defined: &mut [bool]) {
         | LpirOp::Unorm8toF { dst, .. } => mark(*dst, defined),
         LpirOp::Select { dst, .. } | LpirOp::Copy { dst, .. } => mark(*dst, defined),
         LpirOp::SlotAddr { dst, .. }
+        | LpirOp::RodataAddr { dst }
         | LpirOp::Load { dst, .. }
         | LpirOp::Load8U { dst, .. }
         | LpirOp::Load8S { dst, .. }
@@ -760,6 +760,20 @@ pub const COMPLETIONS: &[CompletionEntry] = &[
         snippet: "texture1d_r16_unorm(${out}, ${ptr}, ${width}, ${row_stride}, ${u}, ${filter_abi}, ${wrap_x_abi})",
         description: "",
     },
+    CompletionEntry {
+        name: "texture1d_r8_unorm",
+        module: "texture",
+        detail: "texture1d_r8_unorm(out, ptr, width, row_stride, u, filter_abi, wrap_x_abi)",
+        snippet: "texture1d_r8_unorm(${out}, ${ptr}, ${width}, ${row_stride}, ${u}, ${filter_abi}, ${wrap_x_abi})",
+        description: "",
+    },
+    CompletionEntry {
+        name: "texture1d_rgb8_unorm",
+        module: "texture",
+        detail: "texture1d_rgb8_unorm(out, ptr, width, row_stride, u, filter_abi, wrap_x_abi)",
+        snippet: "texture1d_rgb8_unorm(${out}, ${ptr}, ${width}, ${row_stride}, ${u}, ${filter_abi}, ${wrap_x_abi})",
+        description: "",
+    },
     CompletionEntry {
         name: "texture1d_rgba16_unorm",
         module: "texture",
@@ -774,6 +788,20 @@ pub const COMPLETIONS: &[CompletionEntry] = &[
         snippet: "texture2d_r16_unorm(${out}, ${ptr}, ${width}, ${height}, ${row_stride}, ${u}, ${v}, ${filter_abi}, ${wrap_x_abi}, ${wrap_y_abi})",
         description: "",
     },
+    CompletionEntry {
+        name: "texture2d_r8_unorm",
+        module: "texture",
+        detail: "texture2d_r8_unorm(out, ptr, width, height, row_stride, u, v, filter_abi, wrap_x_abi, wrap_y_abi)",
+        snippet: "texture2d_r8_unorm(${out}, ${ptr}, ${width}, ${height}, ${row_stride}, ${u}, ${v}, ${filter_abi}, ${wrap_x_abi}, ${wrap_y_abi})",
+        description: "",
+    },
+    CompletionEntry {
+        name: "texture2d_rgb8_unorm",
+        module: "texture",
+        detail: "texture2d_rgb8_unorm(out, ptr, width, height, row_stride, u, v, filter_abi, wrap_x_abi, wrap_y_abi)",
+        snippet: "texture2d_rgb8_unorm(${out}, ${ptr}, ${width}, ${height}, ${row_stride}, ${u}, ${v}, ${filter_abi}, ${wrap_x_abi}, ${wrap_y_abi})",
+        description: "",
+    },
     CompletionEntry {
         name: "texture2d_rgba16_unorm",
         module: "texture",
@@ -117,8 +117,12 @@ pub fn vm_q32_builtin_id(name: &str, arg_count: usize) -> Option<BuiltinId> {
 pub fn texture_q32_builtin_id(name: &str, arg_count: usize) -> Option<BuiltinId> {
     match (name, arg_count) {
         ("texture1d_r16_unorm", 7) => Some(BuiltinId::LpTexTexture1dR16UnormQ32),
+        ("texture1d_r8_unorm", 7) => Some(BuiltinId::LpTexTexture1dR8UnormQ32),
+        ("texture1d_rgb8_unorm", 7) => Some(BuiltinId::LpTexTexture1dRgb8UnormQ32),
         ("texture1d_rgba16_unorm", 7) => Some(BuiltinId::LpTexTexture1dRgba16UnormQ32),
         ("texture2d_r16_unorm", 10) => Some(BuiltinId::LpTexTexture2dR16UnormQ32),
+        ("texture2d_r8_unorm", 10) => Some(BuiltinId::LpTexTexture2dR8UnormQ32),
+        ("texture2d_rgb8_unorm", 10) => Some(BuiltinId::LpTexTexture2dRgb8UnormQ32),
         ("texture2d_rgba16_unorm", 10) => Some(BuiltinId::LpTexTexture2dRgba16UnormQ32),
         _ => None,
     }
@@ -318,8 +322,12 @@ pub fn vm_f32_builtin_id(name: &str, arg_count: usize) -> Option<BuiltinId> {
 pub fn texture_f32_builtin_id(name: &str, arg_count: usize) -> Option<BuiltinId> {
     match (name, arg_count) {
         ("texture1d_r16_unorm", 7) => Some(BuiltinId::LpTexTexture1dR16UnormF32),
+        ("texture1d_r8_unorm", 7) => Some(BuiltinId::LpTexTexture1dR8UnormF32),
+        ("texture1d_rgb8_unorm", 7) => Some(BuiltinId::LpTexTexture1dRgb8UnormF32),
         ("texture1d_rgba16_unorm", 7) => Some(BuiltinId::LpTexTexture1dRgba16UnormF32),
         ("texture2d_r16_unorm", 10) => Some(BuiltinId::LpTexTexture2dR16UnormF32),
+        ("texture2d_r8_unorm", 10) => Some(BuiltinId::LpTexTexture2dR8UnormF32),
+        ("texture2d_rgb8_unorm", 10) => Some(BuiltinId::LpTexTexture2dRgb8UnormF32),
         ("texture2d_rgba16_unorm", 10) => Some(BuiltinId::LpTexTexture2dRgba16UnormF32),
         _ => None,
     }
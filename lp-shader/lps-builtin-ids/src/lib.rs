@@ -169,10 +169,18 @@ pub enum BuiltinId {
     LpVmGetFuel,
     LpTexTexture1dR16UnormF32,
     LpTexTexture1dR16UnormQ32,
+    LpTexTexture1dR8UnormF32,
+    LpTexTexture1dR8UnormQ32,
+    LpTexTexture1dRgb8UnormF32,
+    LpTexTexture1dRgb8UnormQ32,
     LpTexTexture1dRgba16UnormF32,
     LpTexTexture1dRgba16UnormQ32,
     LpTexTexture2dR16UnormF32,
     LpTexTexture2dR16UnormQ32,
+    LpTexTexture2dR8UnormF32,
+    LpTexTexture2dR8UnormQ32,
+    LpTexTexture2dRgb8UnormF32,
+    LpTexTexture2dRgb8UnormQ32,
     LpTexTexture2dRgba16UnormF32,
     LpTexTexture2dRgba16UnormQ32,
 }
@@ -340,10 +348,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel => "__lp_vm_get_fuel",
             BuiltinId::LpTexTexture1dR16UnormF32 => "__lp_texture1d_r16_unorm_f32",
             BuiltinId::LpTexTexture1dR16UnormQ32 => "__lp_texture1d_r16_unorm_q32",
+            BuiltinId::LpTexTexture1dR8UnormF32 => "__lp_texture1d_r8_unorm_f32",
+            BuiltinId::LpTexTexture1dR8UnormQ32 => "__lp_texture1d_r8_unorm_q32",
+            BuiltinId::LpTexTexture1dRgb8UnormF32 => "__lp_texture1d_rgb8_unorm_f32",
+            BuiltinId::LpTexTexture1dRgb8UnormQ32 => "__lp_texture1d_rgb8_unorm_q32",
             BuiltinId::LpTexTexture1dRgba16UnormF32 => "__lp_texture1d_rgba16_unorm_f32",
             BuiltinId::LpTexTexture1dRgba16UnormQ32 => "__lp_texture1d_rgba16_unorm_q32",
             BuiltinId::LpTexTexture2dR16UnormF32 => "__lp_texture2d_r16_unorm_f32",
             BuiltinId::LpTexTexture2dR16UnormQ32 => "__lp_texture2d_r16_unorm_q32",
+            BuiltinId::LpTexTexture2dR8UnormF32 => "__lp_texture2d_r8_unorm_f32",
+            BuiltinId::LpTexTexture2dR8UnormQ32 => "__lp_texture2d_r8_unorm_q32",
+            BuiltinId::LpTexTexture2dRgb8UnormF32 => "__lp_texture2d_rgb8_unorm_f32",
+            BuiltinId::LpTexTexture2dRgb8UnormQ32 => "__lp_texture2d_rgb8_unorm_q32",
             BuiltinId::LpTexTexture2dRgba16UnormF32 => "__lp_texture2d_rgba16_unorm_f32",
             BuiltinId::LpTexTexture2dRgba16UnormQ32 => "__lp_texture2d_rgba16_unorm_q32",
         }
@@ -511,10 +527,18 @@ impl BuiltinId {
             "__lp_vm_get_fuel" => Some(BuiltinId::LpVmGetFuel),
             "__lp_texture1d_r16_unorm_f32" => Some(BuiltinId::LpTexTexture1dR16UnormF32),
             "__lp_texture1d_r16_unorm_q32" => Some(BuiltinId::LpTexTexture1dR16UnormQ32),
+            "__lp_texture1d_r8_unorm_f32" => Some(BuiltinId::LpTexTexture1dR8UnormF32),
+            "__lp_texture1d_r8_unorm_q32" => Some(BuiltinId::LpTexTexture1dR8UnormQ32),
+            "__lp_texture1d_rgb8_unorm_f32" => Some(BuiltinId::LpTexTexture1dRgb8UnormF32),
+            "__lp_texture1d_rgb8_unorm_q32" => Some(BuiltinId::LpTexTexture1dRgb8UnormQ32),
             "__lp_texture1d_rgba16_unorm_f32" => Some(BuiltinId::LpTexTexture1dRgba16UnormF32),
             "__lp_texture1d_rgba16_unorm_q32" => Some(BuiltinId::LpTexTexture1dRgba16UnormQ32),
             "__lp_texture2d_r16_unorm_f32" => Some(BuiltinId::LpTexTexture2dR16UnormF32),
             "__lp_texture2d_r16_unorm_q32" => Some(BuiltinId::LpTexTexture2dR16UnormQ32),
+            "__lp_texture2d_r8_unorm_f32" => Some(BuiltinId::LpTexTexture2dR8UnormF32),
+            "__lp_texture2d_r8_unorm_q32" => Some(BuiltinId::LpTexTexture2dR8UnormQ32),
+            "__lp_texture2d_rgb8_unorm_f32" => Some(BuiltinId::LpTexTexture2dRgb8UnormF32),
+            "__lp_texture2d_rgb8_unorm_q32" => Some(BuiltinId::LpTexTexture2dRgb8UnormQ32),
             "__lp_texture2d_rgba16_unorm_f32" => Some(BuiltinId::LpTexTexture2dRgba16UnormF32),
             "__lp_texture2d_rgba16_unorm_q32" => Some(BuiltinId::LpTexTexture2dRgba16UnormQ32),
             _ => None,
@@ -683,10 +707,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel,
             BuiltinId::LpTexTexture1dR16UnormF32,
             BuiltinId::LpTexTexture1dR16UnormQ32,
+            BuiltinId::LpTexTexture1dR8UnormF32,
+            BuiltinId::LpTexTexture1dR8UnormQ32,
+            BuiltinId::LpTexTexture1dRgb8UnormF32,
+            BuiltinId::LpTexTexture1dRgb8UnormQ32,
             BuiltinId::LpTexTexture1dRgba16UnormF32,
             BuiltinId::LpTexTexture1dRgba16UnormQ32,
             BuiltinId::LpTexTexture2dR16UnormF32,
             BuiltinId::LpTexTexture2dR16UnormQ32,
+            BuiltinId::LpTexTexture2dR8UnormF32,
+            BuiltinId::LpTexTexture2dR8UnormQ32,
+            BuiltinId::LpTexTexture2dRgb8UnormF32,
+            BuiltinId::LpTexTexture2dRgb8UnormQ32,
             BuiltinId::LpTexTexture2dRgba16UnormF32,
             BuiltinId::LpTexTexture2dRgba16UnormQ32,
         ]
@@ -854,10 +886,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel => Module::Vm,
             BuiltinId::LpTexTexture1dR16UnormF32 => Module::Texture,
             BuiltinId::LpTexTexture1dR16UnormQ32 => Module::Texture,
+            BuiltinId::LpTexTexture1dR8UnormF32 => Module::Texture,
+            BuiltinId::LpTexTexture1dR8UnormQ32 => Module::Texture,
+            BuiltinId::LpTexTexture1dRgb8UnormF32 => Module::Texture,
+            BuiltinId::LpTexTexture1dRgb8UnormQ32 => Module::Texture,
             BuiltinId::LpTexTexture1dRgba16UnormF32 => Module::Texture,
             BuiltinId::LpTexTexture1dRgba16UnormQ32 => Module::Texture,
             BuiltinId::LpTexTexture2dR16UnormF32 => Module::Texture,
             BuiltinId::LpTexTexture2dR16UnormQ32 => Module::Texture,
+            BuiltinId::LpTexTexture2dR8UnormF32 => Module::Texture,
+            BuiltinId::LpTexTexture2dR8UnormQ32 => Module::Texture,
+            BuiltinId::LpTexTexture2dRgb8UnormF32 => Module::Texture,
+            BuiltinId::LpTexTexture2dRgb8UnormQ32 => Module::Texture,
             BuiltinId::LpTexTexture2dRgba16UnormF32 => Module::Texture,
             BuiltinId::LpTexTexture2dRgba16UnormQ32 => Module::Texture,
         }
@@ -1025,10 +1065,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel => "get_fuel",
             BuiltinId::LpTexTexture1dR16UnormF32 => "texture1d_r16_unorm",
             BuiltinId::LpTexTexture1dR16UnormQ32 => "texture1d_r16_unorm",
+            BuiltinId::LpTexTexture1dR8UnormF32 => "texture1d_r8_unorm",
+            BuiltinId::LpTexTexture1dR8UnormQ32 => "texture1d_r8_unorm",
+            BuiltinId::LpTexTexture1dRgb8UnormF32 => "texture1d_rgb8_unorm",
+            BuiltinId::LpTexTexture1dRgb8UnormQ32 => "texture1d_rgb8_unorm",
             BuiltinId::LpTexTexture1dRgba16UnormF32 => "texture1d_rgba16_unorm",
             BuiltinId::LpTexTexture1dRgba16UnormQ32 => "texture1d_rgba16_unorm",
             BuiltinId::LpTexTexture2dR16UnormF32 => "texture2d_r16_unorm",
             BuiltinId::LpTexTexture2dR16UnormQ32 => "texture2d_r16_unorm",
+            BuiltinId::LpTexTexture2dR8UnormF32 => "texture2d_r8_unorm",
+            BuiltinId::LpTexTexture2dR8UnormQ32 => "texture2d_r8_unorm",
+            BuiltinId::LpTexTexture2dRgb8UnormF32 => "texture2d_rgb8_unorm",
+            BuiltinId::LpTexTexture2dRgb8UnormQ32 => "texture2d_rgb8_unorm",
             BuiltinId::LpTexTexture2dRgba16UnormF32 => "texture2d_rgba16_unorm",
             BuiltinId::LpTexTexture2dRgba16UnormQ32 => "texture2d_rgba16_unorm",
         }
@@ -1196,10 +1244,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel => None,
             BuiltinId::LpTexTexture1dR16UnormF32 => Some(Mode::F32),
             BuiltinId::LpTexTexture1dR16UnormQ32 => Some(Mode::Q32),
+            BuiltinId::LpTexTexture1dR8UnormF32 => Some(Mode::F32),
+            BuiltinId::LpTexTexture1dR8UnormQ32 => Some(Mode::Q32),
+            BuiltinId::LpTexTexture1dRgb8UnormF32 => Some(Mode::F32),
+            BuiltinId::LpTexTexture1dRgb8UnormQ32 => Some(Mode::Q32),
             BuiltinId::LpTexTexture1dRgba16UnormF32 => Some(Mode::F32),
             BuiltinId::LpTexTexture1dRgba16UnormQ32 => Some(Mode::Q32),
             BuiltinId::LpTexTexture2dR16UnormF32 => Some(Mode::F32),
             BuiltinId::LpTexTexture2dR16UnormQ32 => Some(Mode::Q32),
+            BuiltinId::LpTexTexture2dR8UnormF32 => Some(Mode::F32),
+            BuiltinId::LpTexTexture2dR8UnormQ32 => Some(Mode::Q32),
+            BuiltinId::LpTexTexture2dRgb8UnormF32 => Some(Mode::F32),
+            BuiltinId::LpTexTexture2dRgb8UnormQ32 => Some(Mode::Q32),
             BuiltinId::LpTexTexture2dRgba16UnormF32 => Some(Mode::F32),
             BuiltinId::LpTexTexture2dRgba16UnormQ32 => Some(Mode::Q32),
         }
@@ -1367,10 +1423,18 @@ impl BuiltinId {
             BuiltinId::LpVmGetFuel => true,
             BuiltinId::LpTexTexture1dR16UnormF32 => false,
             BuiltinId::LpTexTexture1dR16UnormQ32 => false,
+            BuiltinId::LpTexTexture1dR8UnormF32 => false,
+            BuiltinId::LpTexTexture1dR8UnormQ32 => false,
+            BuiltinId::LpTexTexture1dRgb8UnormF32 => false,
+            BuiltinId::LpTexTexture1dRgb8UnormQ32 => false,
             BuiltinId::LpTexTexture1dRgba16UnormF32 => false,
             BuiltinId::LpTexTexture1dRgba16UnormQ32 => false,
             BuiltinId::LpTexTexture2dR16UnormF32 => false,
             BuiltinId::LpTexTexture2dR16UnormQ32 => false,
+            BuiltinId::LpTexTexture2dR8UnormF32 => false,
+            BuiltinId::LpTexTexture2dR8UnormQ32 => false,
+            BuiltinId::LpTexTexture2dRgb8UnormF32 => false,
+            BuiltinId::LpTexTexture2dRgb8UnormQ32 => false,
             BuiltinId::LpTexTexture2dRgba16UnormF32 => false,
             BuiltinId::LpTexTexture2dRgba16UnormQ32 => false,
         }
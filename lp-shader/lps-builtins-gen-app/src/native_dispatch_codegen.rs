@@ -31,8 +31,12 @@ fn guest_pointer_out_plan(fn_name: &str) -> Option<Vec<(usize, usize, &'static s
         "__lp_lpfn_psrdnoise3_f32" => Some(vec![(7, 3, "f32")]),
         "__lp_lpfn_psrdnoise3_q32" => Some(vec![(7, 3, "i32")]),
         "__lp_texture1d_r16_unorm_q32" => Some(vec![(0, 4, "i32")]),
+        "__lp_texture1d_r8_unorm_q32" => Some(vec![(0, 4, "i32")]),
+        "__lp_texture1d_rgb8_unorm_q32" => Some(vec![(0, 4, "i32")]),
         "__lp_texture1d_rgba16_unorm_q32" => Some(vec![(0, 4, "i32")]),
         "__lp_texture2d_r16_unorm_q32" => Some(vec![(0, 4, "i32")]),
+        "__lp_texture2d_r8_unorm_q32" => Some(vec![(0, 4, "i32")]),
+        "__lp_texture2d_rgb8_unorm_q32" => Some(vec![(0, 4, "i32")]),
         "__lp_texture2d_rgba16_unorm_q32" => Some(vec![(0, 4, "i32")]),
         "__lps_sincos_f32" => Some(vec![(1, 1, "f32"), (2, 1, "f32")]),
         "__lps_sincos_q32" => Some(vec![(1, 1, "i32"), (2, 1, "i32")]),
@@ -205,21 +209,24 @@ fn is_texture_guest_offset_builtin(symbol_name: &str) -> bool {
     parse_texture_symbol(symbol_name).is_some()
 }
 
-/// `__lp_texture{1,2}d_{r16,rgba16}_unorm_{q32,f32}` -> (dims, format, mode).
+/// `__lp_texture{1,2}d_{r16,r8,rgb8,rgba16}_unorm_{q32,f32}` -> (dims, format, mode).
 ///
-/// Parsed rather than enumerated: the family is eight symbols across two float
-/// modes and every one of them wants the same twenty lines with three tokens
-/// swapped. It was already four near-identical copies before f32 doubled it.
+/// Parsed rather than enumerated: the family is sixteen symbols across two
+/// float modes and every one of them wants the same twenty lines with three
+/// tokens swapped. It was already four near-identical copies before f32
+/// doubled it.
 fn parse_texture_symbol(symbol_name: &str) -> Option<(u32, &'static str, &'static str)> {
     let rest = symbol_name.strip_prefix("__lp_texture")?;
     let (dims, rest) = match rest.strip_prefix("1d_") {
         Some(r) => (1u32, r),
         None => (2u32, rest.strip_prefix("2d_")?),
     };
-    let (format, rest) = match rest.strip_prefix("rgba16_unorm") {
-        Some(r) => ("rgba16", r),
-        None => ("r16", rest.strip_prefix("r16_unorm")?),
-    };
+    let (format, rest) = ["rgba16", "rgb8", "r16", "r8"]
+        .into_iter()
+        .find_map(|format| {
+            let rest = rest.strip_prefix(format)?.strip_prefix("_unorm")?;
+            Some((format, rest))
+        })?;
     let mode = match rest {
         "_q32" => "q32",
         "_f32" => "f32",
@@ -228,6 +228,13 @@ use crate::builtins::lpir::unorm_conv_q32::{
     __lp_lpir_unorm16_to_f_q32,
 };
 #[cfg(feature = "float-f32")]
+use crate::builtins::texture::{
+    r8_unorm_f32::__lp_texture1d_r8_unorm_f32, r8_unorm_f32::__lp_texture2d_r8_unorm_f32,
+};
+use crate::builtins::texture::{
+    r8_unorm_q32::__lp_texture1d_r8_unorm_q32, r8_unorm_q32::__lp_texture2d_r8_unorm_q32,
+};
+#[cfg(feature = "float-f32")]
 use crate::builtins::texture::{
     r16_unorm_f32::__lp_texture1d_r16_unorm_f32, r16_unorm_f32::__lp_texture2d_r16_unorm_f32,
 };
@@ -235,6 +242,13 @@ use crate::builtins::texture::{
     r16_unorm_q32::__lp_texture1d_r16_unorm_q32, r16_unorm_q32::__lp_texture2d_r16_unorm_q32,
 };
 #[cfg(feature = "float-f32")]
+use crate::builtins::texture::{
+    rgb8_unorm_f32::__lp_texture1d_rgb8_unorm_f32, rgb8_unorm_f32::__lp_texture2d_rgb8_unorm_f32,
+};
+use crate::builtins::texture::{
+    rgb8_unorm_q32::__lp_texture1d_rgb8_unorm_q32, rgb8_unorm_q32::__lp_texture2d_rgb8_unorm_q32,
+};
+#[cfg(feature = "float-f32")]
 use crate::builtins::texture::{
     rgba16_unorm_f32::__lp_texture1d_rgba16_unorm_f32,
     rgba16_unorm_f32::__lp_texture2d_rgba16_unorm_f32,
@@ -567,6 +581,44 @@ pub fn ensure_builtins_referenced() {
             u32,
         ) -> () = __lp_texture1d_r16_unorm_q32;
         #[cfg(feature = "float-f32")]
+        let _texture1d_r8_unorm_f32_fn: unsafe extern "C" fn(
+            *mut f32,
+            u32,
+            u32,
+            u32,
+            f32,
+            u32,
+            u32,
+        ) -> () = __lp_texture1d_r8_unorm_f32;
+        let _texture1d_r8_unorm_q32_fn: unsafe extern "C" fn(
+            *mut i32,
+            u32,
+            u32,
+            u32,
+            i32,
+            u32,
+            u32,
+        ) -> () = __lp_texture1d_r8_unorm_q32;
+        #[cfg(feature = "float-f32")]
+        let _texture1d_rgb8_unorm_f32_fn: unsafe extern "C" fn(
+            *mut f32,
+            u32,
+            u32,
+            u32,
+            f32,
+            u32,
+            u32,
+        ) -> () = __lp_texture1d_rgb8_unorm_f32;
+        let _texture1d_rgb8_unorm_q32_fn: unsafe extern "C" fn(
+            *mut i32,
+            u32,
+            u32,
+            u32,
+            i32,
+            u32,
+            u32,
+        ) -> () = __lp_texture1d_rgb8_unorm_q32;
+        #[cfg(feature = "float-f32")]
         let _texture1d_rgba16_unorm_f32_fn: unsafe extern "C" fn(
             *mut f32,
             u32,
@@ -611,6 +663,56 @@ pub fn ensure_builtins_referenced() {
             u32,
         ) -> () = __lp_texture2d_r16_unorm_q32;
         #[cfg(feature = "float-f32")]
+        let _texture2d_r8_unorm_f32_fn: unsafe extern "C" fn(
+            *mut f32,
+            u32,
+            u32,
+            u32,
+            u32,
+            f32,
+            f32,
+            u32,
+            u32,
+            u32,
+        ) -> () = __lp_texture2d_r8_unorm_f32;
+        let _texture2d_r8_unorm_q32_fn: unsafe extern "C" fn(
+            *mut i32,
+            u32,
+            u32,
+            u32,
+            u32,
+            i32,
+            i32,
+            u32,
+            u32,
+            u32,
+        ) -> () = __lp_texture2d_r8_unorm_q32;
+        #[cfg(feature = "float-f32")]
+        let _texture2d_rgb8_unorm_f32_fn: unsafe extern "C" fn(
+            *mut f32,
+            u32,
+            u32,
+            u32,
+            u32,
+            f32,
+            f32,
+            u32,
+            u32,
+            u32,
+        ) -> () = __lp_texture2d_rgb8_unorm_f32;
+        let _texture2d_rgb8_unorm_q32_fn: unsafe extern "C" fn(
+            *mut i32,
+            u32,
+            u32,
+            u32,
+            u32,
+            i32,
+            i32,
+            u32,
+            u32,
+            u32,
+        ) -> () = __lp_texture2d_rgb8_unorm_q32;
+        #[cfg(feature = "float-f32")]
         let _texture2d_rgba16_unorm_f32_fn: unsafe extern "C" fn(
             *mut f32,
             u32,
@@ -877,12 +979,24 @@ pub fn ensure_builtins_referenced() {
         let _ = core::ptr::read_volatile(&_texture1d_r16_unorm_f32_fn as *const _);
         let _ = core::ptr::read_volatile(&_texture1d_r16_unorm_q32_fn as *const _);
         #[cfg(feature = "float-f32")]
+        let _ = core::ptr::read_volatile(&_texture1d_r8_unorm_f32_fn as *const _);
+        let _ = core::ptr::read_volatile(&_texture1d_r8_unorm_q32_fn as *const _);
+        #[cfg(feature = "float-f32")]
+        let _ = core::ptr::read_volatile(&_texture1d_rgb8_unorm_f32_fn as *const _);
+        let _ = core::ptr::read_volatile(&_texture1d_rgb8_unorm_q32_fn as *const _);
+        #[cfg(feature = "float-f32")]
         let _ = core::ptr::read_volatile(&_texture1d_rgba16_unorm_f32_fn as *const _);
         let _ = core::ptr::read_volatile(&_texture1d_rgba16_unorm_q32_fn as *const _);
         #[cfg(feature = "float-f32")]
         let _ = core::ptr::read_volatile(&_texture2d_r16_unorm_f32_fn as *const _);
         let _ = core::ptr::read_volatile(&_texture2d_r16_unorm_q32_fn as *const _);
         #[cfg(feature = "float-f32")]
+        let _ = core::ptr::read_volatile(&_texture2d_r8_unorm_f32_fn as *const _);
+        let _ = core::ptr::read_volatile(&_texture2d_r8_unorm_q32_fn as *const _);
+        #[cfg(feature = "float-f32")]
+        let _ = core::ptr::read_volatile(&_texture2d_rgb8_unorm_f32_fn as *const _);
+        let _ = core::ptr::read_volatile(&_texture2d_rgb8_unorm_q32_fn as *const _);
+        #[cfg(feature = "float-f32")]
         let _ = core::ptr::read_volatile(&_texture2d_rgba16_unorm_f32_fn as *const _);
         let _ = core::ptr::read_volatile(&_texture2d_rgba16_unorm_q32_fn as *const _);
     }
@@ -7,12 +7,18 @@ pub use sampler_helpers::{Texture1dUnormSampleArgs, Texture2dUnormSampleArgs};
 pub use sampler_helpers::{Texture1dUnormSampleArgsF32, Texture2dUnormSampleArgsF32};
 
 pub mod r16_unorm_q32;
+pub mod r8_unorm_q32;
+pub mod rgb8_unorm_q32;
 pub mod rgba16_unorm_q32;
 pub mod sample_ref;
 
 #[cfg(feature = "float-f32")]
 pub mod r16_unorm_f32;
 #[cfg(feature = "float-f32")]
+pub mod r8_unorm_f32;
+#[cfg(feature = "float-f32")]
+pub mod rgb8_unorm_f32;
+#[cfg(feature = "float-f32")]
 pub mod rgba16_unorm_f32;
 #[cfg(feature = "float-f32")]
 pub mod sample_ref_f32;
@@ -0,0 +1,238 @@
+//! R8 UNORM texture sampling entry points (native-f32 ABI) — the f32 sibling
+//! of [`super::r8_unorm_q32`].
+//!
+//! See [`super::r8_unorm_f32`] for what is shared with the Q32 path and what
+//! is not; the only difference from R8 is the byte load.
+//!
+//! **Tolerance:** exact against the canonical sampling contract.
+
+use lps_shared::texture_format::TextureFilter;
+
+use super::sample_ref_f32::{LinearAxisF32, linear_indices_f32, nearest_index_f32};
+use super::sampler_helpers::{
+    Texture1dUnormSampleArgsF32, Texture2dUnormSampleArgsF32, decode_filter_abi, decode_wrap_abi,
+    f32_lerp, load_r8_texel_lane_f32, texel_rel_byte_offset,
+};
+
+/// # Safety
+/// `out` must be valid for four consecutive `f32` writes. `ptr` and following lanes must describe a
+/// texture whose bytes are readable through `ptr` interpreted as a guest offset / host pointer per target.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture2d_r8_unorm_f32(
+    out: *mut f32,
+    ptr: u32,
+    width: u32,
+    height: u32,
+    row_stride: u32,
+    u: f32,
+    v: f32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+    wrap_y_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture2dUnormSampleArgsF32 {
+        width,
+        height,
+        row_stride,
+        u,
+        v,
+        filter_abi,
+        wrap_x_abi,
+        wrap_y_abi,
+    };
+    let lanes = unsafe { texture2d_r8_unorm_sample_f32(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// # Safety
+/// `out` must be valid for four consecutive `f32` writes. `ptr` and following lanes must describe a
+/// readable height-one / 1D texture row as above.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture1d_r8_unorm_f32(
+    out: *mut f32,
+    ptr: u32,
+    width: u32,
+    row_stride: u32,
+    u: f32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture1dUnormSampleArgsF32 {
+        width,
+        row_stride,
+        u,
+        filter_abi,
+        wrap_x_abi,
+    };
+    let lanes = unsafe { texture1d_r8_unorm_sample_f32(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// R8 expands to `vec4(r, 0, 0, 1)` — the `texelFetch` contract, matching the
+/// Q32 sibling.
+#[inline]
+fn vec4_fill_r8(r_lane: f32) -> [f32; 4] {
+    [r_lane, 0.0, 0.0, 1.0]
+}
+
+/// Sample R8 UNORM as vec4 in 2D using packed ABI arguments.
+///
+/// # Safety
+/// `base` must point to readable texture storage covering every texel byte addressed using `args.width`,
+/// `args.height`, and `args.row_stride` under the implemented wrap/filter logic.
+pub unsafe fn texture2d_r8_unorm_sample_f32(
+    base: *const u8,
+    args: Texture2dUnormSampleArgsF32,
+) -> [f32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let wy = decode_wrap_abi(args.wrap_y_abi);
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_f32(args.u, args.width, wx);
+            let iy = nearest_index_f32(args.v, args.height, wy);
+            let r = unsafe {
+                load_r8_texel_lane_f32(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, R8Layout::BPP),
+                )
+            };
+            vec4_fill_r8(r)
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_f32(args.u, args.width, wx);
+            let ay = linear_indices_f32(args.v, args.height, wy);
+            unsafe { bilinear_r8(base, args.row_stride, ax, ay) }
+        }
+    }
+}
+
+/// Sample R8 UNORM along X for a single row (`iy == 0`).
+///
+/// # Safety
+/// `base` must point to readable storage for row 0 with extent `args.width` and stride `args.row_stride`.
+pub unsafe fn texture1d_r8_unorm_sample_f32(
+    base: *const u8,
+    args: Texture1dUnormSampleArgsF32,
+) -> [f32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let iy = 0u32;
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_f32(args.u, args.width, wx);
+            let r = unsafe {
+                load_r8_texel_lane_f32(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, R8Layout::BPP),
+                )
+            };
+            vec4_fill_r8(r)
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_f32(args.u, args.width, wx);
+            unsafe { linear_rows_r8(base, args.row_stride, iy, ax) }
+        }
+    }
+}
+
+struct R8Layout;
+
+impl R8Layout {
+    const BPP: u32 = 1;
+}
+
+/// # Safety
+/// Every index in `ax`/`ay` must address readable storage.
+unsafe fn bilinear_r8(
+    base: *const u8,
+    row_stride: u32,
+    ax: LinearAxisF32,
+    ay: LinearAxisF32,
+) -> [f32; 4] {
+    let at = |ix: u32, iy: u32| unsafe {
+        load_r8_texel_lane_f32(
+            base,
+            texel_rel_byte_offset(ix, iy, row_stride, R8Layout::BPP),
+        )
+    };
+    let v00 = vec4_fill_r8(at(ax.i0, ay.i0));
+    let v10 = vec4_fill_r8(at(ax.i1, ay.i0));
+    let v01 = vec4_fill_r8(at(ax.i0, ay.i1));
+    let v11 = vec4_fill_r8(at(ax.i1, ay.i1));
+
+    let mut out = [0f32; 4];
+    for i in 0..4 {
+        let s0 = f32_lerp(v00[i], v10[i], ax.frac);
+        let s1 = f32_lerp(v01[i], v11[i], ax.frac);
+        out[i] = f32_lerp(s0, s1, ay.frac);
+    }
+    out
+}
+
+/// # Safety
+/// Every index in `ax` must address readable storage on row `iy`.
+unsafe fn linear_rows_r8(base: *const u8, row_stride: u32, iy: u32, ax: LinearAxisF32) -> [f32; 4] {
+    let at = |ix: u32| unsafe {
+        load_r8_texel_lane_f32(
+            base,
+            texel_rel_byte_offset(ix, iy, row_stride, R8Layout::BPP),
+        )
+    };
+    let v0 = vec4_fill_r8(at(ax.i0));
+    let v1 = vec4_fill_r8(at(ax.i1));
+    let mut out = [0f32; 4];
+    for i in 0..4 {
+        out[i] = f32_lerp(v0[i], v1[i], ax.frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use lps_shared::texture_format::{TextureFilter, TextureWrap};
+
+    use super::*;
+    use crate::builtins::texture::r16_unorm_f32::texture2d_r16_unorm_sample_f32;
+
+    /// A 3x2 R8 grid; no row padding.
+    const TEXELS: [u8; 6] = [0, 51, 255, 128, 7, 200];
+
+    #[test]
+    fn r8_samples_like_the_widened_r16_texture() {
+        let wide: Vec<u8> = TEXELS
+            .iter()
+            .flat_map(|&raw| (u16::from(raw) * 257).to_le_bytes())
+            .collect();
+        for filter in [TextureFilter::Nearest, TextureFilter::Linear] {
+            for i in 0..=12 {
+                let (u, v) = (i as f32 / 12.0, 1.0 - i as f32 / 9.0);
+                let args = |bpp: u32| Texture2dUnormSampleArgsF32 {
+                    width: 3,
+                    height: 2,
+                    row_stride: 3 * bpp,
+                    u,
+                    v,
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::Repeat.to_builtin_abi(),
+                    wrap_y_abi: TextureWrap::ClampToEdge.to_builtin_abi(),
+                };
+                let got = unsafe { texture2d_r8_unorm_sample_f32(TEXELS.as_ptr(), args(1)) };
+                let want = unsafe { texture2d_r16_unorm_sample_f32(wide.as_ptr(), args(2)) };
+                assert_eq!(got, want, "{filter:?} ({u},{v})");
+            }
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! R8 UNORM texture sampling entry points (Q32 ABI): single channel expanded to vec4 like `texelFetch`.
+//!
+//! Each byte widens to unorm16 (`raw * 257`) on load, so an R8 texture samples exactly like the
+//! [`super::r8_unorm_q32`] texture holding the widened values.
+
+use lps_q32::Q32;
+use lps_shared::texture_format::TextureFilter;
+
+use super::sample_ref::{LinearAxis, linear_indices_q32, nearest_index_q32};
+use super::sampler_helpers::{
+    Texture1dUnormSampleArgs, Texture2dUnormSampleArgs, decode_filter_abi, decode_wrap_abi,
+    load_r8_texel_lane, q32_lerp, texel_rel_byte_offset,
+};
+
+/// # Safety
+/// `out` must be valid for four consecutive `i32` writes. `ptr` and following lanes must describe a
+/// texture whose bytes are readable through `ptr` interpreted as a guest offset / host pointer per target.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture2d_r8_unorm_q32(
+    out: *mut i32,
+    ptr: u32,
+    width: u32,
+    height: u32,
+    row_stride: u32,
+    u: i32,
+    v: i32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+    wrap_y_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture2dUnormSampleArgs {
+        width,
+        height,
+        row_stride,
+        u,
+        v,
+        filter_abi,
+        wrap_x_abi,
+        wrap_y_abi,
+    };
+    let lanes = unsafe { texture2d_r8_unorm_sample(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// # Safety
+/// `out` must be valid for four consecutive `i32` writes. `ptr` and following lanes must describe a
+/// readable height-one / 1D texture row as above.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture1d_r8_unorm_q32(
+    out: *mut i32,
+    ptr: u32,
+    width: u32,
+    row_stride: u32,
+    u: i32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture1dUnormSampleArgs {
+        width,
+        row_stride,
+        u,
+        filter_abi,
+        wrap_x_abi,
+    };
+    let lanes = unsafe { texture1d_r8_unorm_sample(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+#[inline]
+fn vec4_fill_r8(r_lane: i32) -> [i32; 4] {
+    [
+        r_lane,
+        Q32::ZERO.to_fixed(),
+        Q32::ZERO.to_fixed(),
+        Q32::ONE.to_fixed(),
+    ]
+}
+
+/// Sample R8 UNORM as vec4 in 2D using packed ABI arguments.
+///
+/// # Safety
+/// `base` must point to readable texture storage covering every texel byte addressed using `args.width`,
+/// `args.height`, and `args.row_stride` under the implemented wrap/filter logic.
+pub unsafe fn texture2d_r8_unorm_sample(
+    base: *const u8,
+    args: Texture2dUnormSampleArgs,
+) -> [i32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let wy = decode_wrap_abi(args.wrap_y_abi);
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_q32(args.u, args.width, wx);
+            let iy = nearest_index_q32(args.v, args.height, wy);
+            let r = unsafe {
+                load_r8_texel_lane(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, R8Layout::BPP),
+                )
+            };
+            vec4_fill_r8(r)
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_q32(args.u, args.width, wx);
+            let ay = linear_indices_q32(args.v, args.height, wy);
+            bilinear_r8(base, args.row_stride, ax, ay)
+        }
+    }
+}
+
+/// Sample R8 UNORM along X for a single row (`iy == 0`).
+///
+/// # Safety
+/// `base` must point to readable storage for row 0 with extent `args.width` and stride `args.row_stride`.
+pub unsafe fn texture1d_r8_unorm_sample(
+    base: *const u8,
+    args: Texture1dUnormSampleArgs,
+) -> [i32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let iy = 0u32;
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_q32(args.u, args.width, wx);
+            let r = unsafe {
+                load_r8_texel_lane(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, R8Layout::BPP),
+                )
+            };
+            vec4_fill_r8(r)
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_q32(args.u, args.width, wx);
+            linear_rows_r8(base, args.row_stride, iy, ax)
+        }
+    }
+}
+
+struct R8Layout;
+
+impl R8Layout {
+    const BPP: u32 = 1;
+}
+
+fn bilinear_r8(base: *const u8, row_stride: u32, ax: LinearAxis, ay: LinearAxis) -> [i32; 4] {
+    let r00 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i0, ay.i0, row_stride, R8Layout::BPP),
+        )
+    };
+    let r10 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i1, ay.i0, row_stride, R8Layout::BPP),
+        )
+    };
+    let r01 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i0, ay.i1, row_stride, R8Layout::BPP),
+        )
+    };
+    let r11 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i1, ay.i1, row_stride, R8Layout::BPP),
+        )
+    };
+
+    let v00 = vec4_fill_r8(r00);
+    let v10 = vec4_fill_r8(r10);
+    let v01 = vec4_fill_r8(r01);
+    let v11 = vec4_fill_r8(r11);
+
+    let mut out = [0i32; 4];
+    for i in 0..4 {
+        let s0 = q32_lerp(v00[i], v10[i], ax.frac);
+        let s1 = q32_lerp(v01[i], v11[i], ax.frac);
+        out[i] = q32_lerp(s0, s1, ay.frac);
+    }
+    out
+}
+
+fn linear_rows_r8(base: *const u8, row_stride: u32, iy: u32, ax: LinearAxis) -> [i32; 4] {
+    let r0 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i0, iy, row_stride, R8Layout::BPP),
+        )
+    };
+    let r1 = unsafe {
+        load_r8_texel_lane(
+            base,
+            texel_rel_byte_offset(ax.i1, iy, row_stride, R8Layout::BPP),
+        )
+    };
+    let v0 = vec4_fill_r8(r0);
+    let v1 = vec4_fill_r8(r1);
+    let mut out = [0i32; 4];
+    for i in 0..4 {
+        out[i] = q32_lerp(v0[i], v1[i], ax.frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use lps_q32::Q32;
+    use lps_shared::texture_format::{TextureFilter, TextureWrap};
+
+    use super::*;
+    use crate::builtins::texture::r16_unorm_q32::{
+        texture1d_r16_unorm_sample, texture2d_r16_unorm_sample,
+    };
+
+    /// A 3x2 R8 grid; no row padding.
+    const TEXELS: [u8; 6] = [0, 51, 255, 128, 7, 200];
+
+    fn widened() -> Vec<u8> {
+        TEXELS
+            .iter()
+            .flat_map(|&raw| (u16::from(raw) * 257).to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn r8_samples_like_the_widened_r16_texture() {
+        let wide = widened();
+        for filter in [TextureFilter::Nearest, TextureFilter::Linear] {
+            for i in 0..=12 {
+                let (u, v) = (i as f32 / 12.0, 1.0 - i as f32 / 9.0);
+                let args = |bpp: u32| Texture2dUnormSampleArgs {
+                    width: 3,
+                    height: 2,
+                    row_stride: 3 * bpp,
+                    u: Q32::from_f32_wrapping(u).to_fixed(),
+                    v: Q32::from_f32_wrapping(v).to_fixed(),
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::Repeat.to_builtin_abi(),
+                    wrap_y_abi: TextureWrap::MirrorRepeat.to_builtin_abi(),
+                };
+                let got = unsafe { texture2d_r8_unorm_sample(TEXELS.as_ptr(), args(1)) };
+                let want = unsafe { texture2d_r16_unorm_sample(wide.as_ptr(), args(2)) };
+                assert_eq!(got, want, "{filter:?} ({u},{v})");
+
+                let args = |bpp: u32| Texture1dUnormSampleArgs {
+                    width: 3,
+                    row_stride: 3 * bpp,
+                    u: Q32::from_f32_wrapping(u).to_fixed(),
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::ClampToEdge.to_builtin_abi(),
+                };
+                let got = unsafe { texture1d_r8_unorm_sample(TEXELS.as_ptr(), args(1)) };
+                let want = unsafe { texture1d_r16_unorm_sample(wide.as_ptr(), args(2)) };
+                assert_eq!(got, want, "1d {filter:?} ({u})");
+            }
+        }
+    }
+
+    #[test]
+    fn full_scale_byte_reads_as_full_scale_unorm16() {
+        let got = unsafe {
+            texture1d_r8_unorm_sample(
+                [255u8].as_ptr(),
+                Texture1dUnormSampleArgs {
+                    width: 1,
+                    row_stride: 1,
+                    u: Q32::from_f32_wrapping(0.5).to_fixed(),
+                    filter_abi: TextureFilter::Nearest.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::ClampToEdge.to_builtin_abi(),
+                },
+            )
+        };
+        let r16_max =
+            crate::builtins::lpir::unorm_conv_q32::__lp_lpir_unorm16_to_f_q32(i32::from(u16::MAX));
+        assert_eq!(got, [r16_max, 0, 0, Q32::ONE.to_fixed()]);
+    }
+}
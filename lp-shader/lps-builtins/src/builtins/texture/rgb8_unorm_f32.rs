@@ -0,0 +1,243 @@
+//! RGB8 UNORM texture sampling entry points (native-f32 ABI) — the f32
+//! sibling of [`super::rgb8_unorm_q32`].
+//!
+//! See [`super::r16_unorm_f32`] for what is shared with the Q32 path and what
+//! is not.
+//!
+//! **Tolerance:** exact against the canonical sampling contract.
+
+use lps_shared::texture_format::TextureFilter;
+
+use super::sample_ref_f32::{LinearAxisF32, linear_indices_f32, nearest_index_f32};
+use super::sampler_helpers::{
+    Texture1dUnormSampleArgsF32, Texture2dUnormSampleArgsF32, decode_filter_abi, decode_wrap_abi,
+    f32_lerp, load_rgb8_texel_f32, texel_rel_byte_offset,
+};
+
+/// 2D normalized sampling for RGB8 textures. Writes vec4/f32 lanes through `out`.
+///
+/// # Safety
+/// `out` must be valid for four consecutive `f32` writes. `ptr` and descriptor lanes must describe a
+/// readable 2D RGB8 texture.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture2d_rgb8_unorm_f32(
+    out: *mut f32,
+    ptr: u32,
+    width: u32,
+    height: u32,
+    row_stride: u32,
+    u: f32,
+    v: f32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+    wrap_y_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture2dUnormSampleArgsF32 {
+        width,
+        height,
+        row_stride,
+        u,
+        v,
+        filter_abi,
+        wrap_x_abi,
+        wrap_y_abi,
+    };
+    let lanes = unsafe { texture2d_rgb8_unorm_sample_f32(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// 1D sampling for height-one RGB8 textures. Writes vec4/f32 lanes through `out`.
+///
+/// # Safety
+/// Same as [`__lp_texture2d_rgb8_unorm_f32`], for a single-row height-one layout.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture1d_rgb8_unorm_f32(
+    out: *mut f32,
+    ptr: u32,
+    width: u32,
+    row_stride: u32,
+    u: f32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture1dUnormSampleArgsF32 {
+        width,
+        row_stride,
+        u,
+        filter_abi,
+        wrap_x_abi,
+    };
+    let lanes = unsafe { texture1d_rgb8_unorm_sample_f32(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// Core 2D sampler (`base` points at texel (0,0)).
+///
+/// # Safety
+/// `base` must point to readable RGB8 texel storage covering all addressing implied by `args`.
+pub unsafe fn texture2d_rgb8_unorm_sample_f32(
+    base: *const u8,
+    args: Texture2dUnormSampleArgsF32,
+) -> [f32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let wy = decode_wrap_abi(args.wrap_y_abi);
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_f32(args.u, args.width, wx);
+            let iy = nearest_index_f32(args.v, args.height, wy);
+            unsafe {
+                load_rgb8_texel_f32(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, Rgb8UnormLayout::BPP),
+                )
+            }
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_f32(args.u, args.width, wx);
+            let ay = linear_indices_f32(args.v, args.height, wy);
+            unsafe { bilinear_rgb8(base, args.row_stride, ax, ay) }
+        }
+    }
+}
+
+/// Height-one strip: sample row `iy == 0` only; ignores normalized `v` (no `wrap_y`).
+///
+/// # Safety
+/// `base` must point to readable RGB8 storage for row 0 with extent `args.width`.
+pub unsafe fn texture1d_rgb8_unorm_sample_f32(
+    base: *const u8,
+    args: Texture1dUnormSampleArgsF32,
+) -> [f32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let iy = 0u32;
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_f32(args.u, args.width, wx);
+            unsafe {
+                load_rgb8_texel_f32(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, Rgb8UnormLayout::BPP),
+                )
+            }
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_f32(args.u, args.width, wx);
+            unsafe { linear_rows_rgb8(base, args.row_stride, iy, ax) }
+        }
+    }
+}
+
+struct Rgb8UnormLayout;
+
+impl Rgb8UnormLayout {
+    const BPP: u32 = 3;
+}
+
+/// # Safety
+/// Every index in `ax`/`ay` must address readable storage.
+unsafe fn bilinear_rgb8(
+    base: *const u8,
+    row_stride: u32,
+    ax: LinearAxisF32,
+    ay: LinearAxisF32,
+) -> [f32; 4] {
+    let at = |ix: u32, iy: u32| unsafe {
+        load_rgb8_texel_f32(
+            base,
+            texel_rel_byte_offset(ix, iy, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c00 = at(ax.i0, ay.i0);
+    let c10 = at(ax.i1, ay.i0);
+    let c01 = at(ax.i0, ay.i1);
+    let c11 = at(ax.i1, ay.i1);
+
+    let mut out = [0f32; 4];
+    for i in 0..4 {
+        let r0 = f32_lerp(c00[i], c10[i], ax.frac);
+        let r1 = f32_lerp(c01[i], c11[i], ax.frac);
+        out[i] = f32_lerp(r0, r1, ay.frac);
+    }
+    out
+}
+
+/// # Safety
+/// Every index in `ax` must address readable storage on row `iy`.
+unsafe fn linear_rows_rgb8(
+    base: *const u8,
+    row_stride: u32,
+    iy: u32,
+    ax: LinearAxisF32,
+) -> [f32; 4] {
+    let at = |ix: u32| unsafe {
+        load_rgb8_texel_f32(
+            base,
+            texel_rel_byte_offset(ix, iy, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c0 = at(ax.i0);
+    let c1 = at(ax.i1);
+    let mut out = [0f32; 4];
+    for i in 0..4 {
+        out[i] = f32_lerp(c0[i], c1[i], ax.frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use lps_shared::texture_format::{TextureFilter, TextureWrap};
+
+    use super::*;
+    use crate::builtins::texture::rgba16_unorm_f32::texture2d_rgba16_unorm_sample_f32;
+
+    /// A 2x2 RGB8 grid; no row padding.
+    const TEXELS: [u8; 12] = [0, 85, 170, 255, 170, 85, 51, 102, 153, 204, 153, 1];
+
+    #[test]
+    fn rgb8_samples_like_the_widened_rgba16_texture() {
+        let wide: Vec<u8> = TEXELS
+            .chunks_exact(3)
+            .flat_map(|rgb| {
+                let lane = |raw: u8| u16::from(raw) * 257;
+                [lane(rgb[0]), lane(rgb[1]), lane(rgb[2]), u16::MAX]
+            })
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        for filter in [TextureFilter::Nearest, TextureFilter::Linear] {
+            for i in 0..=12 {
+                let (u, v) = (i as f32 / 12.0, 1.0 - i as f32 / 9.0);
+                let args = |bpp: u32| Texture2dUnormSampleArgsF32 {
+                    width: 2,
+                    height: 2,
+                    row_stride: 2 * bpp,
+                    u,
+                    v,
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::MirrorRepeat.to_builtin_abi(),
+                    wrap_y_abi: TextureWrap::Repeat.to_builtin_abi(),
+                };
+                let got = unsafe { texture2d_rgb8_unorm_sample_f32(TEXELS.as_ptr(), args(3)) };
+                let want = unsafe { texture2d_rgba16_unorm_sample_f32(wide.as_ptr(), args(8)) };
+                // A missing alpha is exactly 1.0, as for R16; the wide
+                // texture's stored 65535 decodes a hair below it.
+                assert_eq!(got[..3], want[..3], "{filter:?} ({u},{v})");
+                assert_eq!(got[3], 1.0);
+            }
+        }
+    }
+}
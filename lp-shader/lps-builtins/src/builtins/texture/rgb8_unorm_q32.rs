@@ -0,0 +1,260 @@
+//! RGB8 UNORM texture sampling entry points (Q32 ABI): alpha reads as `1.0`, like `texelFetch`.
+//!
+//! Each byte widens to unorm16 (`raw * 257`) on load, so an RGB8 texture samples exactly like
+//! the RGB8 texture holding the widened values and an opaque alpha.
+
+use lps_shared::texture_format::TextureFilter;
+
+use super::sample_ref::{LinearAxis, linear_indices_q32, nearest_index_q32};
+use super::sampler_helpers::{
+    Texture1dUnormSampleArgs, Texture2dUnormSampleArgs, decode_filter_abi, decode_wrap_abi,
+    load_rgb8_texel, q32_lerp, texel_rel_byte_offset,
+};
+
+/// 2D normalized sampling for RGB8 textures. Writes vec4/Q32 lanes through `out`.
+///
+/// # Safety
+/// `out` must be valid for four consecutive `i32` writes. `ptr` and descriptor lanes must describe a
+/// readable 2D RGB8 texture.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture2d_rgb8_unorm_q32(
+    out: *mut i32,
+    ptr: u32,
+    width: u32,
+    height: u32,
+    row_stride: u32,
+    u: i32,
+    v: i32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+    wrap_y_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture2dUnormSampleArgs {
+        width,
+        height,
+        row_stride,
+        u,
+        v,
+        filter_abi,
+        wrap_x_abi,
+        wrap_y_abi,
+    };
+    let lanes = unsafe { texture2d_rgb8_unorm_sample(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// 1D sampling for height-one RGB8 textures. Writes vec4/Q32 lanes through `out`.
+///
+/// # Safety
+/// Same as [`__lp_texture2d_rgb8_unorm_q32`], for a single-row height-one layout.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn __lp_texture1d_rgb8_unorm_q32(
+    out: *mut i32,
+    ptr: u32,
+    width: u32,
+    row_stride: u32,
+    u: i32,
+    filter_abi: u32,
+    wrap_x_abi: u32,
+) {
+    let base = ptr as *const u8;
+    let args = Texture1dUnormSampleArgs {
+        width,
+        row_stride,
+        u,
+        filter_abi,
+        wrap_x_abi,
+    };
+    let lanes = unsafe { texture1d_rgb8_unorm_sample(base, args) };
+    unsafe {
+        core::ptr::copy_nonoverlapping(lanes.as_ptr(), out, 4);
+    }
+}
+
+/// Core 2D sampler (`base` points at texel (0,0); used by Wasmtime dispatch with a host linear-memory base).
+///
+/// # Safety
+/// `base` must point to readable RGB8 texel storage covering all addressing implied by `args`.
+pub unsafe fn texture2d_rgb8_unorm_sample(
+    base: *const u8,
+    args: Texture2dUnormSampleArgs,
+) -> [i32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let wy = decode_wrap_abi(args.wrap_y_abi);
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_q32(args.u, args.width, wx);
+            let iy = nearest_index_q32(args.v, args.height, wy);
+            unsafe {
+                load_rgb8_texel(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, Rgb8UnormLayout::BPP),
+                )
+            }
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_q32(args.u, args.width, wx);
+            let ay = linear_indices_q32(args.v, args.height, wy);
+            bilinear_rgb8(base, args.row_stride, ax, ay)
+        }
+    }
+}
+
+/// Height-one strip: sample row `iy == 0` only; ignores normalized `v` (no `wrap_y`).
+///
+/// # Safety
+/// `base` must point to readable RGB8 storage for row 0 with extent `args.width`.
+pub unsafe fn texture1d_rgb8_unorm_sample(
+    base: *const u8,
+    args: Texture1dUnormSampleArgs,
+) -> [i32; 4] {
+    let filter = decode_filter_abi(args.filter_abi);
+    let wx = decode_wrap_abi(args.wrap_x_abi);
+    let iy = 0u32;
+
+    match filter {
+        TextureFilter::Nearest => {
+            let ix = nearest_index_q32(args.u, args.width, wx);
+            unsafe {
+                load_rgb8_texel(
+                    base,
+                    texel_rel_byte_offset(ix, iy, args.row_stride, Rgb8UnormLayout::BPP),
+                )
+            }
+        }
+        TextureFilter::Linear => {
+            let ax = linear_indices_q32(args.u, args.width, wx);
+            linear_rows_rgb8(base, args.row_stride, iy, ax)
+        }
+    }
+}
+
+struct Rgb8UnormLayout;
+
+impl Rgb8UnormLayout {
+    const BPP: u32 = 3;
+}
+
+fn bilinear_rgb8(base: *const u8, row_stride: u32, ax: LinearAxis, ay: LinearAxis) -> [i32; 4] {
+    let c00 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i0, ay.i0, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c10 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i1, ay.i0, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c01 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i0, ay.i1, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c11 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i1, ay.i1, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+
+    let mut out = [0i32; 4];
+    for i in 0..4 {
+        let r0 = q32_lerp(c00[i], c10[i], ax.frac);
+        let r1 = q32_lerp(c01[i], c11[i], ax.frac);
+        out[i] = q32_lerp(r0, r1, ay.frac);
+    }
+    out
+}
+
+fn linear_rows_rgb8(base: *const u8, row_stride: u32, iy: u32, ax: LinearAxis) -> [i32; 4] {
+    let c0 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i0, iy, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let c1 = unsafe {
+        load_rgb8_texel(
+            base,
+            texel_rel_byte_offset(ax.i1, iy, row_stride, Rgb8UnormLayout::BPP),
+        )
+    };
+    let mut out = [0i32; 4];
+    for i in 0..4 {
+        out[i] = q32_lerp(c0[i], c1[i], ax.frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::vec::Vec;
+
+    use lps_q32::Q32;
+    use lps_shared::texture_format::{TextureFilter, TextureWrap};
+
+    use super::*;
+    use crate::builtins::texture::rgba16_unorm_q32::{
+        texture1d_rgba16_unorm_sample, texture2d_rgba16_unorm_sample,
+    };
+
+    /// A 2x2 RGB8 grid; no row padding.
+    const TEXELS: [u8; 12] = [0, 85, 170, 255, 170, 85, 51, 102, 153, 204, 153, 1];
+
+    fn widened() -> Vec<u8> {
+        TEXELS
+            .chunks_exact(3)
+            .flat_map(|rgb| {
+                let lane = |raw: u8| u16::from(raw) * 257;
+                [lane(rgb[0]), lane(rgb[1]), lane(rgb[2]), u16::MAX]
+            })
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
+
+    #[test]
+    fn rgb8_samples_like_the_widened_opaque_rgba16_texture() {
+        let wide = widened();
+        for filter in [TextureFilter::Nearest, TextureFilter::Linear] {
+            for i in 0..=12 {
+                let (u, v) = (i as f32 / 12.0, 1.0 - i as f32 / 9.0);
+                let args = |bpp: u32| Texture2dUnormSampleArgs {
+                    width: 2,
+                    height: 2,
+                    row_stride: 2 * bpp,
+                    u: Q32::from_f32_wrapping(u).to_fixed(),
+                    v: Q32::from_f32_wrapping(v).to_fixed(),
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::MirrorRepeat.to_builtin_abi(),
+                    wrap_y_abi: TextureWrap::Repeat.to_builtin_abi(),
+                };
+                let got = unsafe { texture2d_rgb8_unorm_sample(TEXELS.as_ptr(), args(3)) };
+                let want = unsafe { texture2d_rgba16_unorm_sample(wide.as_ptr(), args(8)) };
+                assert_eq!(got[..3], want[..3], "{filter:?} ({u},{v})");
+                assert_eq!(got[3], Q32::ONE.to_fixed());
+
+                let args = |bpp: u32| Texture1dUnormSampleArgs {
+                    width: 2,
+                    row_stride: 2 * bpp,
+                    u: Q32::from_f32_wrapping(u).to_fixed(),
+                    filter_abi: filter.to_builtin_abi(),
+                    wrap_x_abi: TextureWrap::ClampToEdge.to_builtin_abi(),
+                };
+                let got = unsafe { texture1d_rgb8_unorm_sample(TEXELS.as_ptr(), args(3)) };
+                let want = unsafe { texture1d_rgba16_unorm_sample(wide.as_ptr(), args(8)) };
+                assert_eq!(got[..3], want[..3], "1d {filter:?} ({u})");
+            }
+        }
+    }
+}
@@ -95,6 +95,39 @@ pub(crate) unsafe fn load_r16_texel_lane(base: *const u8, texel_byte_off: usize)
     load_u16_unorm_q32_lane(base, texel_byte_off)
 }
 
+/// One unorm8 byte widened to unorm16 (`raw * 257`), so `255` decodes like `65535`.
+#[inline]
+fn load_u8_widened(base: *const u8, byte_off: usize) -> i32 {
+    unsafe { i32::from(*base.add(byte_off)) * 257 }
+}
+
+/// Single R8 channel at `texel_byte_off`; caller fills GB + A per format rules.
+///
+/// # Safety
+/// `base.add(texel_byte_off)` must be valid for a 1-byte read.
+#[inline]
+pub(crate) unsafe fn load_r8_texel_lane(base: *const u8, texel_byte_off: usize) -> i32 {
+    crate::builtins::lpir::unorm_conv_q32::__lp_lpir_unorm16_to_f_q32(load_u8_widened(
+        base,
+        texel_byte_off,
+    ))
+}
+
+/// Three RGB8 channels starting at `texel_byte_off`, with alpha filled to `1.0`.
+///
+/// # Safety
+/// `base.add(texel_byte_off)` must be valid for a 3-byte read.
+pub(crate) unsafe fn load_rgb8_texel(base: *const u8, texel_byte_off: usize) -> [i32; 4] {
+    unsafe {
+        [
+            load_r8_texel_lane(base, texel_byte_off),
+            load_r8_texel_lane(base, texel_byte_off + 1),
+            load_r8_texel_lane(base, texel_byte_off + 2),
+            Q32::ONE.to_fixed(),
+        ]
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Native-f32 variants.
 //
@@ -171,3 +204,32 @@ pub(crate) unsafe fn load_rgba16_texel_f32(base: *const u8, texel_byte_off: usiz
 pub(crate) unsafe fn load_r16_texel_lane_f32(base: *const u8, texel_byte_off: usize) -> f32 {
     load_u16_unorm_f32_lane(base, texel_byte_off)
 }
+
+/// Single R8 channel at `texel_byte_off`, as f32.
+///
+/// # Safety
+/// `base.add(texel_byte_off)` must be valid for a 1-byte read.
+#[cfg(feature = "float-f32")]
+#[inline]
+pub(crate) unsafe fn load_r8_texel_lane_f32(base: *const u8, texel_byte_off: usize) -> f32 {
+    crate::builtins::lpir::unorm_conv_f32::__lp_lpir_unorm16_to_f_f32(load_u8_widened(
+        base,
+        texel_byte_off,
+    ))
+}
+
+/// Three RGB8 channels starting at `texel_byte_off`, as f32, with alpha `1.0`.
+///
+/// # Safety
+/// `base.add(texel_byte_off)` must be valid for a 3-byte read.
+#[cfg(feature = "float-f32")]
+pub(crate) unsafe fn load_rgb8_texel_f32(base: *const u8, texel_byte_off: usize) -> [f32; 4] {
+    unsafe {
+        [
+            load_r8_texel_lane_f32(base, texel_byte_off),
+            load_r8_texel_lane_f32(base, texel_byte_off + 1),
+            load_r8_texel_lane_f32(base, texel_byte_off + 2),
+            1.0,
+        ]
+    }
+}
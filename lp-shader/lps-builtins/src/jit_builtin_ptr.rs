@@ -422,6 +422,16 @@ pub fn jit_builtin_code_ptr(builtin: BuiltinId) -> Option<*const u8> {
         BuiltinId::LpTexTexture1dR16UnormF32 => None,
         BuiltinId::LpTexTexture1dR16UnormQ32 => Some(crate::builtins::texture::r16_unorm_q32::__lp_texture1d_r16_unorm_q32 as *const u8),
         #[cfg(feature = "float-f32")]
+        BuiltinId::LpTexTexture1dR8UnormF32 => Some(crate::builtins::texture::r8_unorm_f32::__lp_texture1d_r8_unorm_f32 as *const u8),
+        #[cfg(not(feature = "float-f32"))]
+        BuiltinId::LpTexTexture1dR8UnormF32 => None,
+        BuiltinId::LpTexTexture1dR8UnormQ32 => Some(crate::builtins::texture::r8_unorm_q32::__lp_texture1d_r8_unorm_q32 as *const u8),
+        #[cfg(feature = "float-f32")]
+        BuiltinId::LpTexTexture1dRgb8UnormF32 => Some(crate::builtins::texture::rgb8_unorm_f32::__lp_texture1d_rgb8_unorm_f32 as *const u8),
+        #[cfg(not(feature = "float-f32"))]
+        BuiltinId::LpTexTexture1dRgb8UnormF32 => None,
+        BuiltinId::LpTexTexture1dRgb8UnormQ32 => Some(crate::builtins::texture::rgb8_unorm_q32::__lp_texture1d_rgb8_unorm_q32 as *const u8),
+        #[cfg(feature = "float-f32")]
         BuiltinId::LpTexTexture1dRgba16UnormF32 => Some(crate::builtins::texture::rgba16_unorm_f32::__lp_texture1d_rgba16_unorm_f32 as *const u8),
         #[cfg(not(feature = "float-f32"))]
         BuiltinId::LpTexTexture1dRgba16UnormF32 => None,
@@ -432,6 +442,16 @@ pub fn jit_builtin_code_ptr(builtin: BuiltinId) -> Option<*const u8> {
         BuiltinId::LpTexTexture2dR16UnormF32 => None,
         BuiltinId::LpTexTexture2dR16UnormQ32 => Some(crate::builtins::texture::r16_unorm_q32::__lp_texture2d_r16_unorm_q32 as *const u8),
         #[cfg(feature = "float-f32")]
+        BuiltinId::LpTexTexture2dR8UnormF32 => Some(crate::builtins::texture::r8_unorm_f32::__lp_texture2d_r8_unorm_f32 as *const u8),
+        #[cfg(not(feature = "float-f32"))]
+        BuiltinId::LpTexTexture2dR8UnormF32 => None,
+        BuiltinId::LpTexTexture2dR8UnormQ32 => Some(crate::builtins::texture::r8_unorm_q32::__lp_texture2d_r8_unorm_q32 as *const u8),
+        #[cfg(feature = "float-f32")]
+        BuiltinId::LpTexTexture2dRgb8UnormF32 => Some(crate::builtins::texture::rgb8_unorm_f32::__lp_texture2d_rgb8_unorm_f32 as *const u8),
+        #[cfg(not(feature = "float-f32"))]
+        BuiltinId::LpTexTexture2dRgb8UnormF32 => None,
+        BuiltinId::LpTexTexture2dRgb8UnormQ32 => Some(crate::builtins::texture::rgb8_unorm_q32::__lp_texture2d_rgb8_unorm_q32 as *const u8),
+        #[cfg(feature = "float-f32")]
         BuiltinId::LpTexTexture2dRgba16UnormF32 => Some(crate::builtins::texture::rgba16_unorm_f32::__lp_texture2d_rgba16_unorm_f32 as *const u8),
         #[cfg(not(feature = "float-f32"))]
         BuiltinId::LpTexTexture2dRgba16UnormF32 => None,
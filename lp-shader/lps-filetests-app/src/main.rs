@@ -16,6 +16,9 @@ enum Commands {
     /// Sweep the corpus on one target and emit per-directive JSONL results
     /// (P2 triage input; never mutates corpus files)
     Sweep(SweepOptions),
+    /// Compile every filetest under both native register allocators and
+    /// compare spill counts and guest cycle estimates
+    RegallocCompare(RegallocCompareOptions),
 }
 
 /// Sweep the corpus on a target, emitting JSONL to stdout.
@@ -28,6 +31,19 @@ struct SweepOptions {
     roots: Vec<String>,
 }
 
+/// Compare the walk and split register allocators on one native target.
+#[derive(Parser)]
+struct RegallocCompareOptions {
+    /// Native target to compile for (full canonical name)
+    #[arg(short, long, default_value = "rv32n.q32")]
+    target: String,
+    /// List every file, not just those where the strategies differ
+    #[arg(long)]
+    all: bool,
+    /// Optional corpus-relative roots (dirs/files); default = whole corpus
+    roots: Vec<String>,
+}
+
 #[derive(Args)]
 #[group(id = "output_mode", multiple = false)]
 struct OutputModeCli {
@@ -138,6 +154,76 @@ fn resolve_force_opts(cli: &[String], env: Option<&str>) -> anyhow::Result<Vec<(
     Ok(map.into_iter().collect())
 }
 
+fn print_regalloc_report(report: &lps_filetests::regalloc_compare::CompareReport, all: bool) {
+    use lps_filetests::regalloc_compare::StrategyRun;
+
+    fn cols(r: &StrategyRun) -> String {
+        format!(
+            "{:>4} {:>5} {:>5} {:>10}",
+            r.stats.spill_slots, r.stats.spill_stores, r.stats.spill_reloads, r.cycles
+        )
+    }
+    fn delta(walk: u64, split: u64) -> String {
+        if walk == 0 {
+            return String::from("-");
+        }
+        format!(
+            "{:+.1}%",
+            (split as f64 - walk as f64) * 100.0 / walk as f64
+        )
+    }
+
+    println!(
+        "{:<56} {:>4} {:>5} {:>5} {:>10} | {:>4} {:>5} {:>5} {:>10} | {:>8}",
+        "file (walk | split)",
+        "slot",
+        "store",
+        "reld",
+        "cycles",
+        "slot",
+        "store",
+        "reld",
+        "cycles",
+        "Δcycles"
+    );
+    for f in &report.files {
+        if !all && f.walk == f.split && f.divergent_lines.is_empty() {
+            continue;
+        }
+        println!(
+            "{:<56} {} | {} | {:>8}",
+            f.file,
+            cols(&f.walk),
+            cols(&f.split),
+            delta(f.walk.cycles, f.split.cycles)
+        );
+        for line in &f.divergent_lines {
+            println!("  DIVERGENT {}:{line}", f.file);
+        }
+    }
+    for e in &report.errors {
+        match e.strategy {
+            Some(s) => println!("  ERROR {} ({s:?}): {}", e.file, e.error),
+            None => println!("  ERROR {}: {}", e.file, e.error),
+        }
+    }
+    let (walk, split) = report.totals();
+    println!(
+        "{:<56} {} | {} | {:>8}",
+        format!("TOTAL ({} files)", report.files.len()),
+        cols(&walk),
+        cols(&split),
+        delta(walk.cycles, split.cycles)
+    );
+    println!(
+        "passed: walk {}/{}, split {}/{}",
+        walk.passed,
+        walk.passed + walk.failed,
+        split.passed,
+        split.passed + split.failed
+    );
+}
+
 fn main() -> anyhow::Result<()> {
     env_logger::init();
 
@@ -191,6 +277,15 @@ fn main() -> anyhow::Result<()> {
                 failed
             );
         }
+        Commands::RegallocCompare(c) => {
+            let target =
+                lps_filetests::targets::Target::from_name(&c.target).map_err(anyhow::Error::msg)?;
+            let report = lps_filetests::regalloc_compare::compare_corpus(target, &c.roots)?;
+            print_regalloc_report(&report, c.all);
+            if !report.is_clean() {
+                anyhow::bail!("split allocator diverged from walk on {}", target.name());
+            }
+        }
     }
 
     Ok(())
@@ -0,0 +1,125 @@
+// test run
+
+// ============================================================================
+// Counted loops over small arrays: unrolled, with the array scalarized, under
+// the default pipeline. unroll-off.glsl is the twin with both passes pinned
+// off (keep the two in sync). Same functions, same expected values.
+// ============================================================================
+
+float hash1(float n) {
+    return fract((n * 0.1031 + 0.37) * (n + 7.0));
+}
+
+float value_noise(float x) {
+    float i = floor(x);
+    float f = fract(x);
+    float u = f * f * (3.0 - 2.0 * f);
+    return mix(hash1(i), hash1(i + 1.0), u);
+}
+
+float test_unroll_fbm_octaves() {
+    float amp[4] = float[4](0.5, 0.25, 0.125, 0.0625);
+    float x = 1.37;
+    float sum = 0.0;
+    for (int i = 0; i < 4; i++) {
+        sum += amp[i] * value_noise(x);
+        x *= 2.0;
+    }
+    return sum;
+}
+
+// run: test_unroll_fbm_octaves() ~= 0.5689
+
+int test_unroll_array_by_counter() {
+    int a[4] = int[4](3, 5, 7, 11);
+    int sum = 0;
+    for (int i = 0; i < 4; i++) {
+        sum = sum * 10 + a[i];
+    }
+    return sum;
+}
+
+// run: test_unroll_array_by_counter() == 3581
+
+int test_unroll_write_then_read() {
+    int a[3];
+    for (int i = 0; i < 3; i++) {
+        a[i] = (i + 1) * (i + 1);
+    }
+    return a[0] * 100 + a[1] * 10 + a[2];
+}
+
+// run: test_unroll_write_then_read() == 149
+
+vec3 test_unroll_vec_temporaries() {
+    vec3 acc = vec3(0.0);
+    vec3 weights[3] = vec3[3](vec3(1.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0), vec3(0.0, 0.0, 3.0));
+    for (int i = 0; i < 3; i++) {
+        acc += weights[i] * float(i + 1);
+    }
+    return acc;
+}
+
+// run: test_unroll_vec_temporaries() ~= vec3(1.0, 4.0, 9.0)
+
+int test_unroll_nested() {
+    int sum = 0;
+    for (int i = 0; i < 3; i++) {
+        for (int j = 0; j <= i; j++) {
+            if (j == 1) {
+                sum += 100;
+            }
+            sum += i * 10 + j;
+        }
+    }
+    return sum;
+}
+
+// run: test_unroll_nested() == 284
+
+int test_unroll_counts_down() {
+    int a[4] = int[4](1, 2, 3, 4);
+    int sum = 0;
+    for (int i = 3; i >= 0; i--) {
+        sum = sum * 10 + a[i];
+    }
+    return sum;
+}
+
+// run: test_unroll_counts_down() == 4321
+
+int test_unroll_over_trip_limit() {
+    int sum = 0;
+    for (int i = 0; i < 40; i++) {
+        sum += i;
+    }
+    return sum;
+}
+
+// run: test_unroll_over_trip_limit() == 780
+
+int test_unroll_early_break() {
+    int a[4] = int[4](2, 4, -1, 8);
+    int sum = 0;
+    for (int i = 0; i < 4; i++) {
+        if (a[i] < 0) {
+            break;
+        }
+        sum += a[i];
+    }
+    return sum;
+}
+
+// run: test_unroll_early_break() == 6
+
+int test_unroll_dynamic_index(int k) {
+    int a[4] = int[4](10, 20, 30, 40);
+    int sum = 0;
+    for (int i = 0; i < 2; i++) {
+        sum += a[k - i];
+    }
+    return sum;
+}
+
+// run: test_unroll_dynamic_index(3) == 70
+// run: test_unroll_dynamic_index(1) == 30
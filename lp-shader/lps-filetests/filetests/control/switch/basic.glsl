@@ -0,0 +1,207 @@
+// test run
+
+// ============================================================================
+// Switch statements: case selection, default, and cases that return
+// Spec: statements.adoc "Selection" — the selector is a scalar integer and
+//       case labels are constant integer expressions
+// ============================================================================
+
+int pick(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r = 10;
+        break;
+    case 1:
+        r = 20;
+        break;
+    default:
+        r = -1;
+        break;
+    }
+    return r;
+}
+
+// run: pick(0) == 10
+// run: pick(1) == 20
+// run: pick(2) == -1
+// run: pick(-7) == -1
+
+// default need not be last; it still runs only when no case matches
+int pick_default_first(int x) {
+    int r = 0;
+    switch (x) {
+    default:
+        r = -1;
+        break;
+    case 3:
+        r = 30;
+        break;
+    case 4:
+        r = 40;
+        break;
+    }
+    return r;
+}
+
+// run: pick_default_first(3) == 30
+// run: pick_default_first(4) == 40
+// run: pick_default_first(5) == -1
+
+int pick_no_default(int x) {
+    int r = 5;
+    switch (x) {
+    case 1:
+        r = 100;
+        break;
+    case 2:
+        r = 200;
+        break;
+    }
+    return r;
+}
+
+// run: pick_no_default(1) == 100
+// run: pick_no_default(2) == 200
+// run: pick_no_default(3) == 5
+
+// The selector and a value assigned in one case both stay live after a
+// switch without a default
+int assigned_in_one_case(int x) {
+    int r = 0;
+    switch (x) {
+    case 3:
+        r = 4;
+    }
+    return r + x;
+}
+
+// run: assigned_in_one_case(0) == 0
+// run: assigned_in_one_case(3) == 7
+// run: assigned_in_one_case(5) == 5
+
+int assigned_in_two_cases(int x) {
+    int r = 1;
+    int s = 2;
+    switch (x) {
+    case 0:
+        r = 10;
+        break;
+    case 1:
+        s = 20;
+        break;
+    }
+    return r * 100 + s + x;
+}
+
+// run: assigned_in_two_cases(0) == 1002
+// run: assigned_in_two_cases(1) == 121
+// run: assigned_in_two_cases(2) == 104
+
+int pick_return(int x) {
+    switch (x) {
+    case 0:
+        return 7;
+    case 1:
+        return 8;
+    default:
+        return 9;
+    }
+}
+
+// run: pick_return(0) == 7
+// run: pick_return(1) == 8
+// run: pick_return(2) == 9
+
+// The last case needs no break
+int pick_last_without_break(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r = 1;
+        break;
+    case 1:
+        r = 2;
+    }
+    return r;
+}
+
+// run: pick_last_without_break(0) == 1
+// run: pick_last_without_break(1) == 2
+// run: pick_last_without_break(2) == 0
+
+const int LABEL = 6;
+
+int pick_const_labels(int x) {
+    switch (x) {
+    case LABEL:
+        return 1;
+    case LABEL + 1:
+        return 2;
+    case -LABEL:
+        return 3;
+    }
+    return 0;
+}
+
+// run: pick_const_labels(6) == 1
+// run: pick_const_labels(7) == 2
+// run: pick_const_labels(-6) == 3
+// run: pick_const_labels(0) == 0
+
+int pick_large_labels(int x) {
+    switch (x) {
+    case 100000:
+        return 1;
+    case -2147483648:
+        return 2;
+    case 2147483647:
+        return 3;
+    }
+    return 0;
+}
+
+// run: pick_large_labels(100000) == 1
+// run: pick_large_labels(-2147483648) == 2
+// run: pick_large_labels(2147483647) == 3
+// run: pick_large_labels(99999) == 0
+
+uint pick_uint(uint x) {
+    switch (x) {
+    case 0u:
+        return 10u;
+    case 4000000000u:
+        return 20u;
+    default:
+        return 30u;
+    }
+}
+
+// run: pick_uint(0u) == 10u
+// run: pick_uint(4000000000u) == 20u
+// run: pick_uint(1u) == 30u
+
+int empty_switch(int x) {
+    switch (x) {
+    }
+    return x;
+}
+
+// run: empty_switch(4) == 4
+
+vec2 pick_vec(int x) {
+    vec2 v = vec2(0.0);
+    switch (x) {
+    case 0:
+        v = vec2(1.0, 2.0);
+        break;
+    case 1:
+        v = vec2(3.0, 4.0);
+        break;
+    }
+    return v;
+}
+
+// run: pick_vec(0) ~= vec2(1.0, 2.0)
+// run: pick_vec(1) ~= vec2(3.0, 4.0)
+// run: pick_vec(2) ~= vec2(0.0, 0.0)
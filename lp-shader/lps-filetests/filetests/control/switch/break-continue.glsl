@@ -0,0 +1,148 @@
+// test run
+
+// ============================================================================
+// Switch statements inside and around loops
+// Spec: statements.adoc "Jumps" — break leaves the innermost switch or loop;
+//       continue always targets the innermost loop
+// ============================================================================
+
+// break inside a switch leaves the switch, not the enclosing loop
+int break_leaves_switch(int n) {
+    int sum = 0;
+    for (int i = 0; i < n; i++) {
+        switch (i % 3) {
+        case 0:
+            sum += 1;
+            break;
+        case 1:
+            sum += 10;
+            break;
+        default:
+            sum += 100;
+            break;
+        }
+    }
+    return sum;
+}
+
+// run: break_leaves_switch(0) == 0
+// run: break_leaves_switch(3) == 111
+// run: break_leaves_switch(7) == 223
+
+int continue_in_switch(int n) {
+    int sum = 0;
+    for (int i = 0; i < n; i++) {
+        switch (i % 2) {
+        case 0:
+            continue;
+        default:
+            break;
+        }
+        sum += i;
+    }
+    return sum;
+}
+
+// run: continue_in_switch(6) == 9
+// run: continue_in_switch(1) == 0
+
+// break inside a loop inside a switch leaves only the loop
+int loop_in_switch(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        for (int i = 0; i < 10; i++) {
+            if (i == 4) {
+                break;
+            }
+            r += 1;
+        }
+        r += 100;
+        break;
+    default:
+        r = -1;
+        break;
+    }
+    return r;
+}
+
+// run: loop_in_switch(0) == 104
+// run: loop_in_switch(1) == -1
+
+int while_with_switch_exit(int start) {
+    int x = start;
+    int steps = 0;
+    bool done = false;
+    while (!done) {
+        switch (x) {
+        case 1:
+            done = true;
+            break;
+        default:
+            if (x % 2 == 0) {
+                x = x / 2;
+            } else {
+                x = 3 * x + 1;
+            }
+            steps++;
+            break;
+        }
+    }
+    return steps;
+}
+
+// run: while_with_switch_exit(1) == 0
+// run: while_with_switch_exit(6) == 8
+// run: while_with_switch_exit(27) == 111
+
+int nested_switch(int a, int b) {
+    int r = 0;
+    switch (a) {
+    case 0:
+        switch (b) {
+        case 0:
+            r = 1;
+            break;
+        default:
+            r = 2;
+            break;
+        }
+        r += 10;
+        break;
+    case 1:
+        r = 3;
+        break;
+    }
+    return r;
+}
+
+// run: nested_switch(0, 0) == 11
+// run: nested_switch(0, 5) == 12
+// run: nested_switch(1, 0) == 3
+// run: nested_switch(2, 0) == 0
+
+// A break inside a fallthrough case leaves the switch from the middle
+int fall_through_conditional_break(int x, int limit) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r += 1;
+        if (limit == 1) {
+            break;
+        }
+    case 1:
+        r += 10;
+        if (limit == 2) {
+            break;
+        }
+    default:
+        r += 100;
+    }
+    return r;
+}
+
+// run: fall_through_conditional_break(0, 1) == 1
+// run: fall_through_conditional_break(0, 2) == 11
+// run: fall_through_conditional_break(0, 0) == 111
+// run: fall_through_conditional_break(1, 2) == 10
+// run: fall_through_conditional_break(4, 1) == 100
@@ -0,0 +1,129 @@
+// test run
+
+// ============================================================================
+// Switch statements: fallthrough between case labels
+// Spec: statements.adoc "Selection" — without a break, control flows into the
+//       statements of the next case
+// ============================================================================
+
+int grouped_labels(int x) {
+    switch (x) {
+    case 1:
+    case 2:
+    case 3:
+        return 10;
+    case 4:
+    case 5:
+        return 20;
+    default:
+        return 0;
+    }
+}
+
+// run: grouped_labels(1) == 10
+// run: grouped_labels(2) == 10
+// run: grouped_labels(3) == 10
+// run: grouped_labels(4) == 20
+// run: grouped_labels(5) == 20
+// run: grouped_labels(6) == 0
+
+int fall_through_bodies(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r += 1;
+    case 1:
+        r += 10;
+    case 2:
+        r += 100;
+        break;
+    case 3:
+        r += 1000;
+    }
+    return r;
+}
+
+// run: fall_through_bodies(0) == 111
+// run: fall_through_bodies(1) == 110
+// run: fall_through_bodies(2) == 100
+// run: fall_through_bodies(3) == 1000
+// run: fall_through_bodies(4) == 0
+
+// default in the middle falls into the case after it
+int fall_through_default(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r += 1;
+    default:
+        r += 10;
+    case 5:
+        r += 100;
+        break;
+    case 6:
+        r += 1000;
+    }
+    return r;
+}
+
+// run: fall_through_default(0) == 111
+// run: fall_through_default(3) == 110
+// run: fall_through_default(5) == 100
+// run: fall_through_default(6) == 1000
+
+int fall_through_with_early_return(int x) {
+    int r = 0;
+    switch (x) {
+    case 0:
+        r = 5;
+    case 1:
+        if (r == 0) {
+            return -1;
+        }
+        r *= 2;
+    case 2:
+        r += 1;
+    }
+    return r;
+}
+
+// run: fall_through_with_early_return(0) == 11
+// run: fall_through_with_early_return(1) == -1
+// run: fall_through_with_early_return(2) == 1
+// run: fall_through_with_early_return(3) == 0
+
+// Each case is entered once, no matter how many labels it falls through
+int fall_through_counts(int x) {
+    int hits = 0;
+    switch (x) {
+    case 0:
+        hits += 1;
+    case 1:
+        hits += 1;
+    case 2:
+        hits += 1;
+    case 3:
+        hits += 1;
+    }
+    return hits;
+}
+
+// run: fall_through_counts(0) == 4
+// run: fall_through_counts(2) == 2
+// run: fall_through_counts(3) == 1
+// run: fall_through_counts(9) == 0
+
+int fall_through_live_after(int x) {
+    int r = 0;
+    switch (x) {
+    case 1:
+        r += 2;
+    case 2:
+        r += 3;
+    }
+    return r * 10 + x;
+}
+
+// run: fall_through_live_after(1) == 51
+// run: fall_through_live_after(2) == 32
+// run: fall_through_live_after(7) == 7
@@ -0,0 +1,82 @@
+// test run
+
+// ============================================================================
+// Ternary operator selecting whole arrays and structs
+// Spec: operators.adoc "Selection" — the second and third operands may be
+//       arrays or structures of the same type; only one of them is evaluated
+// ============================================================================
+
+int test_ternary_array_select(int b) {
+    int a[3] = int[3](1, 2, 3);
+    int c[3] = int[3](7, 8, 9);
+    int r[3] = b != 0 ? a : c;
+    return r[0] * 100 + r[1] * 10 + r[2];
+}
+
+// run: test_ternary_array_select(1) == 123
+// run: test_ternary_array_select(0) == 789
+
+int test_ternary_array_constructors(int x) {
+    float r[2] = x > 0 ? float[2](1.5, 2.5) : float[2](-1.0, -2.0);
+    return int(r[0] * 10.0 + r[1]);
+}
+
+// run: test_ternary_array_constructors(1) == 17
+// run: test_ternary_array_constructors(0) == -12
+
+int test_ternary_array_nested(int x) {
+    ivec2 a[2] = ivec2[2](ivec2(1, 2), ivec2(3, 4));
+    ivec2 b[2] = ivec2[2](ivec2(5, 6), ivec2(7, 8));
+    ivec2 c[2] = ivec2[2](ivec2(0), ivec2(9));
+    ivec2 r[2] = x == 0 ? a : (x == 1 ? b : c);
+    return r[0].y * 10 + r[1].x;
+}
+
+// run: test_ternary_array_nested(0) == 23
+// run: test_ternary_array_nested(1) == 67
+// run: test_ternary_array_nested(2) == 9
+
+int sum3(int v[3]) {
+    return v[0] + v[1] + v[2];
+}
+
+int test_ternary_array_argument(int b) {
+    int a[3] = int[3](1, 2, 3);
+    int c[3] = int[3](10, 20, 30);
+    return sum3(b != 0 ? a : c);
+}
+
+// run: test_ternary_array_argument(1) == 6
+// run: test_ternary_array_argument(0) == 60
+
+struct Span {
+    int lo;
+    int hi;
+};
+
+int test_ternary_struct_select(int x) {
+    Span a = Span(1, 3);
+    Span b = Span(4, 6);
+    Span r = x < 5 ? a : b;
+    return r.lo * 10 + r.hi;
+}
+
+// run: test_ternary_struct_select(0) == 13
+// run: test_ternary_struct_select(9) == 46
+
+// Only the selected operand is evaluated
+int calls = 0;
+
+int[2] make_pair(int v) {
+    calls += v;
+    return int[2](v, v + 1);
+}
+
+int test_ternary_array_evaluates_one_side(int b) {
+    calls = 0;
+    int r[2] = b != 0 ? make_pair(1) : make_pair(100);
+    return calls * 1000 + r[1];
+}
+
+// run: test_ternary_array_evaluates_one_side(1) == 1002
+// run: test_ternary_array_evaluates_one_side(0) == 100101
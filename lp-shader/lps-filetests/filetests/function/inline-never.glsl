@@ -0,0 +1,106 @@
+// test run
+// compile-opt(inline.mode, never)
+
+// ============================================================================
+// Inlining pinned off: the call-based twin of inline-always.glsl (keep the
+// two in sync). Same functions, same exact expected values.
+// ============================================================================
+
+int clamp_pos(int x) {
+    if (x < 0) {
+        return 0;
+    }
+    if (x > 100) {
+        return 100;
+    }
+    return x + 1;
+}
+
+int test_inline_multi_return() {
+    return clamp_pos(-5) * 10000 + clamp_pos(7) * 100 + clamp_pos(1000) / 10;
+}
+
+// run: test_inline_multi_return() == 810
+
+int first_multiple_above(int lo, int step) {
+    for (int i = 1; i < 1000; i++) {
+        if (i % step == 0 && i > lo) {
+            return i;
+        }
+    }
+    return -1;
+}
+
+int test_inline_return_in_loop() {
+    int sum = 0;
+    for (int n = 0; n < 4; n++) {
+        sum += first_multiple_above(n * 5, 7);
+    }
+    return sum;
+}
+
+// run: test_inline_return_in_loop() == 49
+
+void accumulate(inout int total, out int previous, int amount) {
+    previous = total;
+    total = total + amount;
+}
+
+int test_inline_out_inout() {
+    int total = 3;
+    int previous = 0;
+    accumulate(total, previous, 4);
+    accumulate(total, previous, 5);
+    return total * 100 + previous;
+}
+
+// run: test_inline_out_inout() == 1207
+
+int double_in_place(int v) {
+    v = v + v;
+    return v;
+}
+
+int test_inline_param_written_by_callee() {
+    int a = 21;
+    int b = double_in_place(a);
+    return a * 1000 + b;
+}
+
+// run: test_inline_param_written_by_callee() == 21042
+
+struct Span {
+    int lo;
+    int hi;
+};
+
+Span widen(Span s, int by) {
+    return Span(s.lo - by, s.hi + by);
+}
+
+Span test_inline_struct_return() {
+    Span s = Span(10, 20);
+    return widen(s, 5);
+}
+
+// run: test_inline_struct_return() == Span(5, 25)
+
+float[3] ramp(float start) {
+    return float[3](start, start + 0.5, start + 1.0);
+}
+
+float[3] test_inline_array_return() {
+    return ramp(0.25);
+}
+
+// run: test_inline_array_return() == float[3](0.25, 0.75, 1.25)
+
+float level(float x) {
+    return lpfn_saturate(x * 2.0);
+}
+
+vec3 test_inline_lpfn_helper() {
+    return vec3(level(-0.5), level(0.125), level(0.75));
+}
+
+// run: test_inline_lpfn_helper() == vec3(0.0, 0.25, 1.0)
@@ -0,0 +1,90 @@
+// test run
+
+// ============================================================================
+// Const arrays: global lookup tables placed in read-only data
+// Spec: variables.adoc "Constant Qualifier" — arrays may be const when their
+//       initializer is a constant expression
+// ============================================================================
+
+const int SQUARES[5] = int[5](0, 1, 4, 9, 16);
+const float WEIGHTS[4] = float[4](0.125, 0.25, 0.375, 0.25);
+const vec3 PALETTE[3] = vec3[3](vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0));
+const uint MASKS[2] = uint[](255u, 4294967295u);
+const int FIB[] = int[](1, 1, 2, 3, 5, 8, 13);
+const vec2 OFFSETS[] = vec2[](vec2(-1.0, 0.5), vec2(0.25));
+
+int test_const_array_constant_index() {
+    return SQUARES[3];
+}
+
+// run: test_const_array_constant_index() == 9
+
+int test_const_array_dynamic_index(int i) {
+    return SQUARES[i];
+}
+
+// run: test_const_array_dynamic_index(0) == 0
+// run: test_const_array_dynamic_index(2) == 4
+// run: test_const_array_dynamic_index(4) == 16
+
+float test_const_array_sum() {
+    float sum = 0.0;
+    for (int i = 0; i < 4; i++) {
+        sum += WEIGHTS[i];
+    }
+    return sum;
+}
+
+// run: test_const_array_sum() ~= 1.0
+
+vec3 test_const_array_vec3(int i) {
+    return PALETTE[i];
+}
+
+// run: test_const_array_vec3(0) ~= vec3(1.0, 0.0, 0.0)
+// run: test_const_array_vec3(1) ~= vec3(0.0, 1.0, 0.0)
+// run: test_const_array_vec3(2) ~= vec3(0.0, 0.0, 1.0)
+
+float test_const_array_vec3_component(int i) {
+    return PALETTE[i].y + PALETTE[2 - i].z * 2.0;
+}
+
+// run: test_const_array_vec3_component(0) ~= 2.0
+// run: test_const_array_vec3_component(1) ~= 1.0
+
+uint test_const_array_uint(int i) {
+    return MASKS[i];
+}
+
+// run: test_const_array_uint(0) == 255u
+// run: test_const_array_uint(1) == 4294967295u
+
+// A local copy of a const array is an ordinary, writable array
+int test_const_array_local_copy() {
+    int a[5] = SQUARES;
+    a[0] = 100;
+    return a[0] + SQUARES[0] + a[4];
+}
+
+// run: test_const_array_local_copy() == 116
+
+int test_const_array_length() {
+    return SQUARES.length() + WEIGHTS.length();
+}
+
+// run: test_const_array_length() == 9
+
+// Unsized const arrays take their length from the initializer
+int test_const_array_unsized(int i) {
+    return FIB[i] + FIB.length();
+}
+
+// run: test_const_array_unsized(0) == 8
+// run: test_const_array_unsized(6) == 20
+
+vec2 test_const_array_unsized_vec2(int i) {
+    return OFFSETS[i] * float(OFFSETS.length());
+}
+
+// run: test_const_array_unsized_vec2(0) ~= vec2(-2.0, 1.0)
+// run: test_const_array_unsized_vec2(1) ~= vec2(0.5, 0.5)
@@ -0,0 +1,57 @@
+// test run
+
+// ============================================================================
+// Preprocessor: object-like and function-like #define, #undef, and
+// conditional groups selected by #if/#ifdef/#ifndef/#elif/#else
+// Spec: preprocessor.adoc "Macro Definition", "Conditional Compilation"
+// ============================================================================
+
+#define SCALE 3
+#define OFFSET (SCALE + 1)
+#define MUL_ADD(a, b, c) ((a) * (b) + (c))
+
+int object_macro(int x) {
+    return x * SCALE + OFFSET;
+}
+
+// run: object_macro(0) == 4
+// run: object_macro(5) == 19
+
+// Arguments are parenthesized by the macro body, so `x + 1` binds as a unit
+int function_macro(int x) {
+    return MUL_ADD(x + 1, SCALE, -2);
+}
+
+// run: function_macro(0) == 1
+// run: function_macro(4) == 13
+
+#define MODE 2
+
+int pick_mode() {
+#if MODE == 1
+    return 10;
+#elif MODE == 2 && defined(SCALE)
+    return 20;
+#else
+    return 30;
+#endif
+}
+
+// run: pick_mode() == 20
+
+#undef SCALE
+#define SCALE 7
+
+int redefined(int x) {
+#ifdef SCALE
+    int r = x * SCALE;
+#else
+    int r = -1;
+#endif
+#ifndef MISSING
+    r += 1;
+#endif
+    return r;
+}
+
+// run: redefined(2) == 15
@@ -0,0 +1,25 @@
+// test run
+
+// ============================================================================
+// Preprocessor: #include resolves next to the including file, like a project
+// shader's includes; #pragma once keeps a second include from redefining
+// ============================================================================
+
+#define WAVE_STEP 5
+#include "lib/wave.glsl"
+#include "lib/wave.glsl"
+
+int stepped(int x) {
+    return wave_step(x);
+}
+
+// run: stepped(0) == 0
+// run: stepped(3) == 15
+
+int triangle_pair(int x) {
+    return triangle(x, 8) * 10 + triangle(x + 1, 8);
+}
+
+// run: triangle_pair(0) == 1
+// run: triangle_pair(2) == 23
+// run: triangle_pair(5) == 32
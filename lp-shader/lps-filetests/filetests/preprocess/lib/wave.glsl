@@ -0,0 +1,19 @@
+// test compile
+//
+// Shared helpers #included by ../include.glsl. Also compiled on its own, so a
+// library that stops compiling fails here before it fails every includer.
+
+#pragma once
+
+#ifndef WAVE_STEP
+#define WAVE_STEP 4
+#endif
+
+int wave_step(int x) {
+    return x * WAVE_STEP;
+}
+
+int triangle(int x, int period) {
+    int t = x % period;
+    return t < period / 2 ? t : period - t;
+}
@@ -0,0 +1,37 @@
+// test run
+
+// Default filetest targets (see lps-filetests `DEFAULT_TARGETS`): rv32n.q32, rv32c.q32, wasm.q32.
+// Exercises LPIR Load8U on 8-bit texture channels widened to the unorm16 range.
+
+// R8Unorm: single stored byte; G/B must fill 0 and A fills 1.0.
+
+// texture-spec: t format=r8unorm filter=nearest wrap=clamp shape=2d
+
+// texture-data: t 2x1 r8unorm
+//   0.6  1.0
+
+uniform sampler2D t;
+
+vec4 fetch_left() {
+    return texelFetch(t, ivec2(0, 0), 0);
+}
+
+float fetch_right_r() {
+    return texelFetch(t, ivec2(1, 0), 0).r;
+}
+
+float fetch_a() {
+    return texelFetch(t, ivec2(0, 0), 0).a;
+}
+
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_right_r() ~= 1.0 (tolerance: 0.0002)
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_a() ~= 1.0 (tolerance: 0.0002)
+// interp.f32: no guest memory to bind texture fixtures into
+// wgpu.f32: texture fixtures are not bound through the GPU registry yet
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_left() ~= vec4(0.6, 0.0, 0.0, 1.0) (tolerance: 0.0003)
@@ -0,0 +1,37 @@
+// test run
+
+// Default targets: rv32n.q32, rv32c.q32, wasm.q32 - Load8U per channel via texelFetch.
+
+// Rgb8Unorm loads three stored bytes (3 bytes per pixel, unaligned); alpha must widen to 1.0.
+// Hex channels must fit a byte (00CC = 204 = 0.8).
+
+// texture-spec: t format=rgb8unorm filter=nearest wrap=clamp shape=2d
+
+// texture-data: t 2x1 rgb8unorm
+//   0.2,0.4,0.6  0.8,00CC,1.0
+
+uniform sampler2D t;
+
+vec4 fetch_a() {
+    return texelFetch(t, ivec2(0, 0), 0);
+}
+
+float fetch_alpha_a() {
+    return texelFetch(t, ivec2(0, 0), 0).a;
+}
+
+vec4 fetch_b() {
+    return texelFetch(t, ivec2(1, 0), 0);
+}
+
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_a() ~= vec4(0.2, 0.4, 0.6, 1.0) (tolerance: 0.0003)
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_alpha_a() ~= 1.0 (tolerance: 0.0002)
+// interp.f32: no guest memory to bind texture fixtures into
+// wgpu.f32: texture fixtures are not bound through the GPU registry yet
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: fetch_b() ~= vec4(0.8, 0.8, 1.0, 1.0) (tolerance: 0.0003)
@@ -0,0 +1,27 @@
+// test run
+
+// R8 UNORM sampling expands to vec4 (R, 0, 0, 1).
+
+// texture-spec: inputColor format=r8unorm filter=nearest wrap=clamp shape=2d
+
+// texture-data: inputColor 2x1 r8unorm
+//   0.8  0.2
+
+uniform sampler2D inputColor;
+
+vec4 sample_left_texel() {
+    return texture(inputColor, vec2(0.125, 0.5));
+}
+
+vec4 sample_right_texel() {
+    return texture(inputColor, vec2(0.875, 0.5));
+}
+
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: sample_left_texel() ~= vec4(0.8, 0.0, 0.0, 1.0) (tolerance: 0.0004)
+// interp.f32: no guest memory to bind texture fixtures into
+// wgpu.f32: texture fixtures are not bound through the GPU registry yet
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: sample_right_texel() ~= vec4(0.2, 0.0, 0.0, 1.0) (tolerance: 0.0004)
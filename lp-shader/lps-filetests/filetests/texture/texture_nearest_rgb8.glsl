@@ -0,0 +1,28 @@
+// test run
+
+// RGB8 UNORM sampling through texture(): three bytes per pixel, alpha widens to 1.0.
+
+// texture-spec: inputColor format=rgb8unorm filter=nearest wrap=clamp shape=2d
+
+// texture-data: inputColor 2x2 rgb8unorm
+//   1.0,0.0,0.0  0.0,1.0,0.0
+//   0.0,0.0,1.0  0.2,0.4,0.6
+
+uniform sampler2D inputColor;
+
+vec4 sample_top_right() {
+    return texture(inputColor, vec2(0.75, 0.25));
+}
+
+vec4 sample_bottom_right() {
+    return texture(inputColor, vec2(0.75, 0.75));
+}
+
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: sample_top_right() ~= vec4(0.0, 1.0, 0.0, 1.0) (tolerance: 0.0004)
+// interp.f32: no guest memory to bind texture fixtures into
+// wgpu.f32: texture fixtures are not bound through the GPU registry yet
+// @unsupported(interp.f32)
+// @unsupported(wgpu.f32)
+// run: sample_bottom_right() ~= vec4(0.2, 0.4, 0.6, 1.0) (tolerance: 0.0004)
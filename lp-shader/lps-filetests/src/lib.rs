@@ -12,6 +12,7 @@ pub mod mutation;
 pub mod output_mode;
 pub mod parse;
 pub mod perf_model;
+pub mod regalloc_compare;
 pub mod runner;
 pub mod sweep;
 pub mod targets;
@@ -34,7 +35,7 @@ use crate::mutation::{MutationAction, MutationPlan};
 use crate::parse::RunDirective;
 use crate::perf_model::PerfModel;
 use crate::targets::{
-    AnnotationKind, DEFAULT_TARGETS, Disposition, Isa, Target, directive_disposition,
+    AnnotationKind, Backend, DEFAULT_TARGETS, Disposition, Isa, Target, directive_disposition,
     parse_target_filters,
 };
 
@@ -460,13 +461,15 @@ struct FileSpec {
 ///
 /// The Xtensa targets execute against the Xtensa builtins image, a gitignored
 /// cross-target artifact that needs the esp toolchain. A fresh clone does not have
-/// it, and `just test-filetests` must still work there.
+/// it, and `just test-filetests` must still work there. The `rv32fn` targets are
+/// in the same position with the `riscv32imafc` builtins image.
 ///
 /// The note goes to stderr and the dropped targets are absent from the summary
 /// table, so a run that tested nothing cannot look like a run that tested
 /// everything — the failure mode this is written against is silent green.
 fn drop_unavailable_targets(requested: Vec<&'static Target>) -> Vec<&'static Target> {
     let xt_image = lps_builtins_xt_image::is_available();
+    let rv32f_image = lpvm_cranelift::rv32f_builtins_available();
     requested
         .into_iter()
         .filter(|t| {
@@ -480,6 +483,14 @@ fn drop_unavailable_targets(requested: Vec<&'static Target>) -> Vec<&'static Tar
                 );
                 return false;
             }
+            if t.backend == Backend::Rv32ffa && !rv32f_image {
+                eprintln!(
+                    "SKIPPING TARGET {}: the riscv32imafc builtins image is not built. \
+                     Run `just build-rv32f-builtins` to enable it.",
+                    t.name()
+                );
+                return false;
+            }
             true
         })
         .collect()
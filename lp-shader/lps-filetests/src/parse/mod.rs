@@ -107,6 +107,28 @@ fn has_reason_above(lines: &[String], idx: usize) -> bool {
 }
 
 /// Parse a test file and extract all directives and source code.
+/// Run a test's GLSL through the preprocessor the way the engine runs a
+/// project shader: `#include "x.glsl"` resolves through
+/// [`lps_glsl::LpFsIncludes`], with the test file's directory as the project
+/// root. Sources without a directive line are returned untouched.
+fn preprocess_source(path: &Path, source: String) -> Result<String> {
+    if !source
+        .lines()
+        .any(|line| line.trim_start().starts_with('#'))
+    {
+        return Ok(source);
+    }
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let name = format!(
+        "/{}",
+        path.file_name().unwrap_or_default().to_string_lossy()
+    );
+    let fs = lpfs::LpFsStd::new(dir.to_path_buf());
+    lps_glsl::preprocess(&source, &name, &mut lps_glsl::LpFsIncludes::new(&fs))
+        .map(|preprocessed| preprocessed.source)
+        .map_err(|err| anyhow::anyhow!("{}: {}", path.display(), err.render()))
+}
+
 pub fn parse_test_file(path: &Path) -> Result<TestFile> {
     let contents = std::fs::read_to_string(path)
         .with_context(|| format!("failed to read {}", path.display()))?;
@@ -293,6 +315,7 @@ pub fn parse_test_file(path: &Path) -> Result<TestFile> {
 
     let (glsl_source, clif_expectations) =
         parse_source::extract_source_and_expectations(&lines, &test_types)?;
+    let glsl_source = preprocess_source(path, glsl_source)?;
 
     Ok(TestFile {
         glsl_source,
@@ -417,11 +417,13 @@ fn parse_width_height(w_h: &str, line_number: usize) -> Result<(u32, u32)> {
 
 fn parse_format(s: &str, line_number: usize) -> Result<TextureStorageFormat> {
     match s {
+        "r8unorm" => Ok(TextureStorageFormat::R8Unorm),
         "r16unorm" => Ok(TextureStorageFormat::R16Unorm),
+        "rgb8unorm" => Ok(TextureStorageFormat::Rgb8Unorm),
         "rgb16unorm" => Ok(TextureStorageFormat::Rgb16Unorm),
         "rgba16unorm" => Ok(TextureStorageFormat::Rgba16Unorm),
         _ => anyhow::bail!(
-            "line {line_number}: unknown texture format {s:?} (expected r16unorm, rgb16unorm, rgba16unorm)"
+            "line {line_number}: unknown texture format {s:?} (expected r8unorm, r16unorm, rgb8unorm, rgb16unorm, rgba16unorm)"
         ),
     }
 }
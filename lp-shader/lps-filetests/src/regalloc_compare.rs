@@ -0,0 +1,206 @@
+//! Register-allocator comparison: compile each corpus file under both
+//! [`RegAllocStrategy`] values on one native target, run its `// run:`
+//! directives, and report spill traffic and guest cycles side by side.
+//!
+//! The cycle figures are the default [`PerfModel`](crate::perf_model::PerfModel)
+//! estimate, summed over the directives that ran under both strategies. A
+//! directive whose result differs between the two is a divergence — an
+//! allocator bug until shown otherwise, whatever the expectation says. Never
+//! mutates corpus files.
+
+use lp_emu_core::LogLevel;
+use lpvm_native::{AllocStats, RegAllocStrategy};
+
+use crate::parse::{RunDirective, TestFile};
+use crate::perf_model::PerfModel;
+use crate::sweep::{self, SweepRecord};
+use crate::targets::{Backend, Target};
+use crate::test_run::compile;
+use crate::test_run::filetest_lpvm::CompiledShader;
+
+/// One strategy's result for one file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StrategyRun {
+    /// Spill traffic in the compiled module.
+    pub stats: AllocStats,
+    /// Guest cycles summed over the directives that ran under both strategies.
+    pub cycles: u64,
+    /// Directives that passed.
+    pub passed: usize,
+    /// Directives that did not.
+    pub failed: usize,
+}
+
+/// Both strategies' results for one file.
+#[derive(Debug)]
+pub struct FileComparison {
+    /// Corpus-relative path.
+    pub file: String,
+    /// The walk allocator (the default).
+    pub walk: StrategyRun,
+    /// The split allocator.
+    pub split: StrategyRun,
+    /// `// run:` lines whose result differs between the two.
+    pub divergent_lines: Vec<usize>,
+}
+
+/// A file that could not be compared: unparsable, or failing to compile
+/// under a strategy.
+#[derive(Debug)]
+pub struct CompareError {
+    /// Corpus-relative path.
+    pub file: String,
+    /// The strategy that failed, when compilation was the problem.
+    pub strategy: Option<RegAllocStrategy>,
+    /// Error text.
+    pub error: String,
+}
+
+/// Every comparison over the selected files.
+#[derive(Debug, Default)]
+pub struct CompareReport {
+    /// Files with both strategies compiled and run.
+    pub files: Vec<FileComparison>,
+    /// Files that could not be.
+    pub errors: Vec<CompareError>,
+}
+
+impl CompareReport {
+    /// Sums over every compared file, as (walk, split).
+    pub fn totals(&self) -> (StrategyRun, StrategyRun) {
+        let mut walk = StrategyRun::default();
+        let mut split = StrategyRun::default();
+        for f in &self.files {
+            walk.accumulate(&f.walk);
+            split.accumulate(&f.split);
+        }
+        (walk, split)
+    }
+
+    /// Whether the split allocator is safe on this corpus: no divergence, and
+    /// no file that compiles under the walk but not under split.
+    pub fn is_clean(&self) -> bool {
+        self.files.iter().all(|f| f.divergent_lines.is_empty())
+            && self
+                .errors
+                .iter()
+                .all(|e| e.strategy != Some(RegAllocStrategy::Split))
+    }
+}
+
+impl StrategyRun {
+    fn accumulate(&mut self, other: &StrategyRun) {
+        self.stats.add(other.stats);
+        self.cycles += other.cycles;
+        self.passed += other.passed;
+        self.failed += other.failed;
+    }
+}
+
+/// Compare the strategies on `target` over the files under `roots`
+/// (corpus-relative; empty = whole corpus).
+pub fn compare_corpus(target: &Target, roots: &[String]) -> anyhow::Result<CompareReport> {
+    if !matches!(
+        target.backend,
+        Backend::Rv32fa | Backend::Xtfa | Backend::Rv32ffa
+    ) {
+        anyhow::bail!(
+            "regalloc-compare needs an lpvm-native target (rv32n, rv32lpn, xtn, ...), not {}",
+            target.name()
+        );
+    }
+    let mut report = CompareReport::default();
+    for (path, rel) in sweep::corpus_files(roots) {
+        let tf = match sweep::parse_run_file(&path) {
+            None => continue,
+            Some(Ok(tf)) => tf,
+            Some(Err(e)) => {
+                report.errors.push(CompareError {
+                    file: rel,
+                    strategy: None,
+                    error: format!("parse: {e:#}"),
+                });
+                continue;
+            }
+        };
+        let applicable: Vec<&RunDirective> = tf
+            .run_directives
+            .iter()
+            .filter(|d| d.mode_filter.applies_to(target))
+            .collect();
+        if applicable.is_empty() {
+            continue;
+        }
+        match compare_file(&rel, &tf, &applicable, target) {
+            Ok(f) => report.files.push(f),
+            Err(e) => report.errors.push(e),
+        }
+    }
+    Ok(report)
+}
+
+fn compare_file(
+    rel: &str,
+    tf: &TestFile,
+    applicable: &[&RunDirective],
+    target: &Target,
+) -> Result<FileComparison, CompareError> {
+    let error = |strategy, error: String| CompareError {
+        file: rel.to_string(),
+        strategy,
+        error,
+    };
+    let config = compile::build_compiler_config(&tf.config_overrides)
+        .map_err(|e| error(None, format!("{e:#}")))?;
+    let cycle_model = PerfModel::default().cycle_model();
+
+    let run = |strategy| -> Result<(AllocStats, Vec<SweepRecord>), CompareError> {
+        let compiled = CompiledShader::compile_glsl_with_regalloc(
+            &tf.glsl_source,
+            target,
+            LogLevel::None,
+            &config,
+            &tf.texture_specs,
+            strategy,
+        )
+        .map_err(|e| error(Some(strategy), format!("{e:#}")))?;
+        let records = applicable
+            .iter()
+            .map(|d| sweep::sweep_directive(rel, d, tf, &compiled, target, cycle_model))
+            .collect();
+        Ok((compiled.alloc_stats().unwrap_or_default(), records))
+    };
+    let (walk_stats, walk) = run(RegAllocStrategy::Walk)?;
+    let (split_stats, split) = run(RegAllocStrategy::Split)?;
+
+    let mut out = FileComparison {
+        file: rel.to_string(),
+        walk: StrategyRun {
+            stats: walk_stats,
+            ..StrategyRun::default()
+        },
+        split: StrategyRun {
+            stats: split_stats,
+            ..StrategyRun::default()
+        },
+        divergent_lines: Vec::new(),
+    };
+    for (w, s) in walk.iter().zip(&split) {
+        for (side, r) in [(&mut out.walk, w), (&mut out.split, s)] {
+            if r.status == "pass" {
+                side.passed += 1;
+            } else {
+                side.failed += 1;
+            }
+        }
+        if (w.status, &w.actual) != (s.status, &s.actual) {
+            out.divergent_lines.push(w.line);
+        }
+        // Only calls both strategies completed are comparable.
+        if let (Some(wc), Some(sc)) = (w.cycles, s.cycles) {
+            out.walk.cycles += wc;
+            out.split.cycles += sc;
+        }
+    }
+    Ok(out)
+}
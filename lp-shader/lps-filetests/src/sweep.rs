@@ -41,6 +41,9 @@ pub struct SweepRecord {
     pub status: &'static str,
     /// Error text for non-pass statuses without a comparable value.
     pub error: Option<String>,
+    /// Guest cycle estimate of the call under the default [`PerfModel`], on
+    /// targets that count them.
+    pub cycles: Option<u64>,
 }
 
 impl SweepRecord {
@@ -76,6 +79,10 @@ impl SweepRecord {
             s.push_str(&format!("\"tolerance\":{t}"));
             s.push(',');
         }
+        if let Some(c) = self.cycles {
+            s.push_str(&format!("\"cycles\":{c}"));
+            s.push(',');
+        }
         push_kv(&mut s, "status", self.status);
         if let Some(e) = &self.error {
             s.push(',');
@@ -215,6 +222,16 @@ pub fn max_abs_delta(a: &LpsValueF32, b: &LpsValueF32) -> Option<f64> {
 /// `target`, returning one record per applicable `// run:` directive.
 /// Never mutates any corpus file.
 pub fn sweep_corpus(target: &Target, roots: &[String]) -> anyhow::Result<Vec<SweepRecord>> {
+    let mut out = Vec::new();
+    for (path, rel) in corpus_files(roots) {
+        sweep_file(&path, &rel, target, &mut out);
+    }
+    Ok(out)
+}
+
+/// Every `.glsl` file under `roots` (corpus-relative; empty = whole corpus),
+/// sorted, with its corpus-relative path.
+pub(crate) fn corpus_files(roots: &[String]) -> Vec<(PathBuf, String)> {
     let filetests_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("filetests");
     let walk_roots: Vec<PathBuf> = if roots.is_empty() {
         vec![filetests_dir.clone()]
@@ -228,17 +245,37 @@ pub fn sweep_corpus(target: &Target, roots: &[String]) -> anyhow::Result<Vec<Swe
         .filter(|p| p.is_file() && p.extension().and_then(|s| s.to_str()) == Some("glsl"))
         .collect();
     files.sort();
+    files
+        .into_iter()
+        .map(|path| {
+            let rel = path
+                .strip_prefix(&filetests_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .to_string();
+            (path, rel)
+        })
+        .collect()
+}
 
-    let mut out = Vec::new();
-    for path in &files {
-        let rel = path
-            .strip_prefix(&filetests_dir)
-            .unwrap_or(path)
-            .to_string_lossy()
-            .to_string();
-        sweep_file(path, &rel, target, &mut out);
+/// Parse `path` as a runnable filetest: `None` for `// test parse-error`
+/// files and files with nothing to run (no `test run`, or `test error`).
+pub(crate) fn parse_run_file(path: &std::path::Path) -> Option<anyhow::Result<parse::TestFile>> {
+    // `// test parse-error` files intentionally fail to parse; skip.
+    if std::fs::read_to_string(path)
+        .map(|s| s.lines().any(|l| l.trim() == "// test parse-error"))
+        .unwrap_or(false)
+    {
+        return None;
     }
-    Ok(out)
+    let tf = match parse::parse_test_file(path) {
+        Ok(tf) => tf,
+        Err(e) => return Some(Err(e)),
+    };
+    if !tf.test_types.contains(&TestType::Run) || tf.test_types.contains(&TestType::Error) {
+        return None;
+    }
+    Some(Ok(tf))
 }
 
 fn op_str(op: ComparisonOp) -> &'static str {
@@ -249,16 +286,10 @@ fn op_str(op: ComparisonOp) -> &'static str {
 }
 
 fn sweep_file(path: &std::path::Path, rel: &str, target: &Target, out: &mut Vec<SweepRecord>) {
-    // `// test parse-error` files intentionally fail to parse; skip.
-    if std::fs::read_to_string(path)
-        .map(|s| s.lines().any(|l| l.trim() == "// test parse-error"))
-        .unwrap_or(false)
-    {
-        return;
-    }
-    let tf = match parse::parse_test_file(path) {
-        Ok(tf) => tf,
-        Err(e) => {
+    let tf = match parse_run_file(path) {
+        None => return,
+        Some(Ok(tf)) => tf,
+        Some(Err(e)) => {
             out.push(SweepRecord {
                 file: rel.to_string(),
                 line: 0,
@@ -270,13 +301,11 @@ fn sweep_file(path: &std::path::Path, rel: &str, target: &Target, out: &mut Vec<
                 tolerance: None,
                 status: "harness-error",
                 error: Some(format!("parse: {e:#}")),
+                cycles: None,
             });
             return;
         }
     };
-    if !tf.test_types.contains(&TestType::Run) || tf.test_types.contains(&TestType::Error) {
-        return;
-    }
 
     let applicable: Vec<_> = tf
         .run_directives
@@ -337,10 +366,12 @@ fn record_error(
         tolerance: d.tolerance,
         status,
         error: Some(error),
+        cycles: None,
     }
 }
 
-fn sweep_directive(
+/// Run one directive against `compiled` on a fresh instance.
+pub(crate) fn sweep_directive(
     rel: &str,
     d: &parse::RunDirective,
     tf: &parse::TestFile,
@@ -401,6 +432,7 @@ fn sweep_directive(
                         tolerance: d.tolerance,
                         status: "pass",
                         error: None,
+                        cycles: None,
                     };
                 }
             }
@@ -419,6 +451,7 @@ fn sweep_directive(
             Ok(v) => v,
             Err(e) => return record_error(rel, d, "exec-error", format!("{e:#}")),
         };
+    let cycles = inst.last_guest_cycle_count();
 
     let expected = match parse_assert::parse_glsl_value(&d.expected_str) {
         Ok(v) => v,
@@ -442,5 +475,6 @@ fn sweep_directive(
             "value-mismatch"
         },
         error: cmp.err(),
+        cycles,
     }
 }
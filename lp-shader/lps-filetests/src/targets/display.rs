@@ -19,6 +19,7 @@ impl Backend {
         Backend::Wasm,
         Backend::Interp,
         Backend::Wgpu,
+        Backend::Rv32ffa,
     ];
 }
 
@@ -49,6 +50,7 @@ impl fmt::Display for Backend {
             Backend::Rv32 => write!(f, "rv32c"),
             Backend::Rv32fa => write!(f, "rv32n"),
             Backend::Xtfa => write!(f, "xtn"),
+            Backend::Rv32ffa => write!(f, "rv32fn"),
             Backend::Wasm => write!(f, "wasm"),
             Backend::Interp => write!(f, "interp"),
             Backend::Wgpu => write!(f, "wgpu"),
@@ -105,9 +107,11 @@ impl Target {
         match (self.frontend, self.backend) {
             (Frontend::Lp, Backend::Rv32fa) => "rv32lpn",
             (Frontend::Lp, Backend::Xtfa) => "xtlpn",
+            (Frontend::Lp, Backend::Rv32ffa) => "rv32lpfn",
             (_, Backend::Rv32) => "rv32c",
             (_, Backend::Rv32fa) => "rv32n",
             (_, Backend::Xtfa) => "xtn",
+            (_, Backend::Rv32ffa) => "rv32fn",
             (_, Backend::Wasm) => "wasm",
             (_, Backend::Interp) => "interp",
             (_, Backend::Wgpu) => "wgpu",
@@ -280,6 +284,24 @@ mod tests {
         assert_eq!(v.len(), 2);
     }
 
+    /// The hardware-FPU rv32 pair exists only in F32, so its shorthands select
+    /// one target each, and it is a different backend from `rv32n` on the same
+    /// ISA.
+    #[test]
+    fn test_rv32_hardware_fpu_pair() {
+        let v = parse_target_filters("rv32fn,rv32lpfn").expect("parse");
+        assert_eq!(
+            v.iter().map(|t| t.name()).collect::<Vec<_>>(),
+            ["rv32fn.f32", "rv32lpfn.f32"]
+        );
+        for t in &v {
+            assert_eq!(t.backend, Backend::Rv32ffa);
+            assert_eq!(t.isa, Isa::Riscv32);
+        }
+        assert_eq!(v[1].frontend, Frontend::Lp);
+        assert!(Target::from_name("rv32fn.q32").is_err());
+    }
+
     /// The f32 pair round-trips by name, and does not collide with the Q32
     /// pair — `xtlpn` is the device pipeline (lps-glsl frontend), `xtn` is
     /// Naga, and the float mode is a separate axis from both.
@@ -22,6 +22,14 @@ pub enum Backend {
     /// parameter — so this variant only picks `IsaTarget::Xtensa` at engine
     /// construction. See `docs/adr/2026-07-30-isa-parameterized-host-emu-engine.md`.
     Xtfa,
+    /// LPIR → RV32IMAFC via `lpvm-native` + the `ilp32f` builtins image +
+    /// emulator: float arithmetic, compares and conversions as native F
+    /// instructions.
+    ///
+    /// Same engine as [`Backend::Rv32fa`] on `IsaTarget::Rv32imafc`. F32 only —
+    /// Q32 never reaches the float file, so an `rv32fn.q32` would be `rv32n.q32`
+    /// linked against a different builtins build.
+    Rv32ffa,
     /// WebAssembly via wasmtime.
     Wasm,
     /// Host-side LPIR interpreter (`lpir::interpret`), f32 semantics; no codegen.
@@ -89,7 +97,7 @@ pub struct Target {
 }
 
 /// All supported targets (`Target::from_name` searches this list).
-/// Order: wasm, rv32c, rv32n, rv32lpn, interp, wgpu, xtn, xtlpn, wasm.f32 — used
+/// Order: wasm, rv32c, rv32n, rv32lpn, interp, wgpu, xtn, xtlpn, wasm.f32, …, rv32fn — used
 /// for error messages and CLI.
 ///
 /// Everything after `rv32lpn.q32` is **appended**, deliberately: [`DEFAULT_TARGETS`]
@@ -138,6 +146,14 @@ pub struct Target {
 /// emulator — and they are not the shipping numeric mode for any rv32 board, so
 /// their cost belongs to a deliberate run, not to every `cargo test`. Select them
 /// explicitly: `-t rv32lpn.f32`.
+///
+/// `rv32fn.f32` / `rv32lpfn.f32` are the **hardware-FPU** rv32 targets: the same
+/// backend on `IsaTarget::Rv32imafc`, emitting `fadd.s`/`feq.s`/`fcvt.*` and
+/// executing them in `lp-riscv-emu`'s RV32F implementation, with builtins
+/// called under `ilp32f`. They need the `riscv32imafc` builtins image
+/// (`just build-rv32f-builtins`), which a fresh clone does not have, so they
+/// are on request like the `xtn.*` targets and are dropped with a note when
+/// the image is missing.
 pub const ALL_TARGETS: &[Target] = &[
     Target {
         frontend: Frontend::Naga,
@@ -230,6 +246,20 @@ pub const ALL_TARGETS: &[Target] = &[
         isa: Isa::Xtensa,
         exec_mode: ExecMode::Emulator,
     },
+    Target {
+        frontend: Frontend::Naga,
+        backend: Backend::Rv32ffa,
+        float_mode: FloatMode::F32,
+        isa: Isa::Riscv32,
+        exec_mode: ExecMode::Emulator,
+    },
+    Target {
+        frontend: Frontend::Lp,
+        backend: Backend::Rv32ffa,
+        float_mode: FloatMode::F32,
+        isa: Isa::Riscv32,
+        exec_mode: ExecMode::Emulator,
+    },
 ];
 
 /// Default targets for local `cargo test` / app runs: rv32n, rv32lpn (lps-glsl
@@ -11,8 +11,9 @@ use lpvm::{
 use lpvm_cranelift::CompileOptions;
 use lpvm_emu::{EmuEngine, EmuInstance, EmuModule};
 use lpvm_native::{
-    IsaTarget, NativeCompileOptions as FaCompileOptions, NativeEmuEngine as FaEmuEngine,
-    NativeEmuInstance as FaEmuInstance, NativeEmuModule as FaEmuModule,
+    AllocStats, IsaTarget, NativeCompileOptions as FaCompileOptions,
+    NativeEmuEngine as FaEmuEngine, NativeEmuInstance as FaEmuInstance,
+    NativeEmuModule as FaEmuModule, RegAllocStrategy,
 };
 use lpvm_wasm::{
     WasmOptions as LpvmWasmOptions,
@@ -240,6 +241,26 @@ impl CompiledShader {
         emu_log_level: LogLevel,
         compiler_config: &CompilerConfig,
         texture_specs: &VecMap<String, TextureBindingSpec>,
+    ) -> anyhow::Result<Self> {
+        Self::compile_glsl_with_regalloc(
+            source,
+            target,
+            emu_log_level,
+            compiler_config,
+            texture_specs,
+            RegAllocStrategy::default(),
+        )
+    }
+
+    /// [`Self::compile_glsl`] with the native backends' register allocator
+    /// chosen explicitly (`regalloc-compare`); other backends ignore it.
+    pub(crate) fn compile_glsl_with_regalloc(
+        source: &str,
+        target: &Target,
+        emu_log_level: LogLevel,
+        compiler_config: &CompilerConfig,
+        texture_specs: &VecMap<String, TextureBindingSpec>,
+        regalloc: RegAllocStrategy,
     ) -> anyhow::Result<Self> {
         // interp.f32 lowers through the oracle-style path (canonical lpfn
         // sources inlined when referenced) and never reaches codegen.
@@ -310,9 +331,10 @@ impl CompiledShader {
             // `docs/adr/2026-07-30-isa-parameterized-host-emu-engine.md`. That is
             // why they share `Self::NativeFa` and why adding Xtensa needed no
             // other match arm in this file.
-            Backend::Rv32fa | Backend::Xtfa => {
+            Backend::Rv32fa | Backend::Xtfa | Backend::Rv32ffa => {
                 let isa = match target.backend {
                     Backend::Xtfa => IsaTarget::Xtensa,
+                    Backend::Rv32ffa => IsaTarget::Rv32imafc,
                     _ => IsaTarget::Rv32imac,
                 };
                 let alloc_trace = std::env::var("LPVM_ALLOC_TRACE").unwrap_or_default() == "1";
@@ -321,6 +343,7 @@ impl CompiledShader {
                     emu_trace_instructions: opts.emu_trace_instructions,
                     alloc_trace,
                     config: compiler_config.clone(),
+                    regalloc,
                     ..Default::default()
                 };
                 let engine = FaEmuEngine::new_for_isa(native_opts, isa);
@@ -361,6 +384,15 @@ impl CompiledShader {
         }
     }
 
+    /// Register-allocator spill traffic, summed over the module's functions
+    /// (native backends only).
+    pub(crate) fn alloc_stats(&self) -> Option<AllocStats> {
+        match self {
+            Self::NativeFa(_, m) => Some(m.alloc_stats()),
+            Self::Emu(_, _) | Self::Wasm(_, _) | Self::Interp(_) | Self::WgpuProbe(_) => None,
+        }
+    }
+
     pub(crate) fn wasm_bytes(&self) -> Option<&[u8]> {
         match self {
             Self::Wasm(_, m) => Some(m.wasm_bytes()),
@@ -88,8 +88,12 @@ pub fn lower_for_interp(
         texture_specs: texture_specs.clone(),
         texel_fetch_bounds: compiler_config.texture.texel_fetch_bounds,
     };
-    let (ir, meta) = lps_frontend::lower_with_options(&naga, &options)
+    let (mut ir, meta) = lps_frontend::lower_with_options(&naga, &options)
         .map_err(|e| anyhow::anyhow!("interp lower: {e}"))?;
+    // Same middle end as the compiled backends; here the canonical `lpfn_*`
+    // bodies are local functions, so builtin helpers inline too.
+    lpir::inline_module(&mut ir, &compiler_config.inline);
+    lpir::PassPipeline::new(&compiler_config.opt, lpir::FloatMode::F32).run_module(&mut ir);
     Ok((ir, meta))
 }
 
@@ -126,7 +126,8 @@ pub fn bind_texture_fixtures_for_run(
 }
 
 /// Validate dimensions, pixel and channel counts, and normalized float ranges; then
-/// encode channels as little-endian `u16` in pixel / row order.
+/// encode channels as little-endian `u16` (or one byte for the unorm8 formats) in
+/// pixel / row order.
 pub fn encode_texture_fixture(fixture: &TextureFixture) -> anyhow::Result<EncodedTextureFixture> {
     if fixture.width == 0 || fixture.height == 0 {
         anyhow::bail!(
@@ -154,6 +155,7 @@ pub fn encode_texture_fixture(fixture: &TextureFixture) -> anyhow::Result<Encode
     let format = fixture.format;
     let channels_per_pixel = format.channel_count();
     let bpp = format.bytes_per_pixel();
+    let unorm8 = format.bytes_per_channel() == 1;
     let row_stride = fixture.width.checked_mul(bpp as u32).ok_or_else(|| {
         anyhow::anyhow!(
             "texture fixture {:?}: row stride overflow (width={} bpp={})",
@@ -198,7 +200,21 @@ pub fn encode_texture_fixture(fixture: &TextureFixture) -> anyhow::Result<Encode
                             fixture.name
                         );
                     }
-                    bytes.extend_from_slice(&unorm16_le_from_normalized_f32(v));
+                    if unorm8 {
+                        bytes.push(unorm8_from_normalized_f32(v));
+                    } else {
+                        bytes.extend_from_slice(&unorm16_le_from_normalized_f32(v));
+                    }
+                }
+                TextureFixtureChannel::ExactHex(u) if unorm8 => {
+                    let byte = u8::try_from(u).map_err(|_| {
+                        anyhow::anyhow!(
+                            "texture fixture {:?}: pixel {i} has hex {u:#x}, which does not fit a {:?} channel",
+                            fixture.name,
+                            format
+                        )
+                    })?;
+                    bytes.push(byte);
                 }
                 TextureFixtureChannel::ExactHex(u) => {
                     bytes.extend_from_slice(&u.to_le_bytes());
@@ -234,6 +250,11 @@ fn unorm16_le_from_normalized_f32(v: f32) -> [u8; 2] {
     q32_word_to_unorm16_le(q)
 }
 
+/// Nearest unorm8 code: `round(v * 255)`.
+fn unorm8_from_normalized_f32(v: f32) -> u8 {
+    (v * 255.0).round() as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,6 +353,40 @@ mod tests {
         assert_eq!(out_rgb.row_stride, 6);
     }
 
+    #[test]
+    fn texture_fixture_encodes_unorm8_one_byte_per_channel() {
+        let rgb = TextureFixture {
+            name: "rgb8".into(),
+            width: 1,
+            height: 1,
+            format: TextureStorageFormat::Rgb8Unorm,
+            pixels: vec![TextureFixturePixel {
+                channels: vec![
+                    TextureFixtureChannel::NormalizedFloat(1.0),
+                    TextureFixtureChannel::NormalizedFloat(0.6),
+                    TextureFixtureChannel::ExactHex(0x12),
+                ],
+            }],
+            line_number: 1,
+        };
+        let out = encode_texture_fixture(&rgb).expect("rgb8");
+        assert_eq!(out.bytes, vec![255, 153, 0x12]);
+        assert_eq!(out.row_stride, 3);
+
+        let wide = TextureFixture {
+            name: "r8".into(),
+            width: 1,
+            height: 1,
+            format: TextureStorageFormat::R8Unorm,
+            pixels: vec![TextureFixturePixel {
+                channels: vec![TextureFixtureChannel::ExactHex(0x100)],
+            }],
+            line_number: 1,
+        };
+        let e = encode_texture_fixture(&wide).expect_err("hex wider than a byte");
+        assert!(e.to_string().contains("0x100"));
+    }
+
     #[test]
     fn texture_fixture_rejects_pixel_count_mismatch() {
         let f = TextureFixture {
@@ -384,7 +439,9 @@ mod tests {
     fn texture_fixture_row_stride_by_format() {
         let w = 7u32;
         for (fmt, stride) in [
+            (TextureStorageFormat::R8Unorm, 7),
             (TextureStorageFormat::R16Unorm, 7 * 2),
+            (TextureStorageFormat::Rgb8Unorm, 7 * 3),
             (TextureStorageFormat::Rgb16Unorm, 7 * 6),
             (TextureStorageFormat::Rgba16Unorm, 7 * 8),
         ] {
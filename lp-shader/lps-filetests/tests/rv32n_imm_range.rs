@@ -205,6 +205,7 @@ where
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
 
     let sig = LpsModuleSig {
@@ -45,6 +45,7 @@ fn build_iadd_module() -> (LpirModule, LpsModuleSig) {
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
 
     let sig = LpsModuleSig {
@@ -569,6 +569,10 @@ fn register_texture_imports(mb: &mut ModuleBuilder) -> VecMap<String, CalleeRef>
     reg("texture1d_rgba16_unorm", 7, &[4]);
     reg("texture2d_r16_unorm", 10, &[5, 6]);
     reg("texture1d_r16_unorm", 7, &[4]);
+    reg("texture2d_r8_unorm", 10, &[5, 6]);
+    reg("texture1d_r8_unorm", 7, &[4]);
+    reg("texture2d_rgb8_unorm", 10, &[5, 6]);
+    reg("texture1d_rgb8_unorm", 7, &[4]);
     m
 }
 
@@ -663,6 +663,31 @@ pub(crate) fn lower_array_initializer(
             }
             Ok(())
         }
+        Expression::Constant(_) if leaf_struct_layout.is_none() => {
+            // A `const` array: its global initializer flattens to leaf lanes.
+            let lanes = ctx.ensure_expr_vec(init_h)?;
+            let per_leaf = naga_type_to_ir_types(ctx.module, &ctx.module.types[leaf_naga].inner)?
+                .len()
+                .max(1);
+            if lanes.len() != per_leaf * info.element_count() as usize {
+                return Err(LowerError::UnsupportedExpression(String::from(
+                    "array initializer: constant size mismatch",
+                )));
+            }
+            for (i, leaf) in lanes.chunks(per_leaf).enumerate() {
+                let byte_off = (i as u32)
+                    .checked_mul(info.leaf_stride())
+                    .ok_or_else(|| LowerError::Internal(String::from("init: byte_off overflow")))?;
+                for (j, &value) in leaf.iter().enumerate() {
+                    ctx.fb.push(LpirOp::Store {
+                        base,
+                        offset: byte_off + (j as u32) * 4,
+                        value,
+                    });
+                }
+            }
+            Ok(())
+        }
         _ => Err(LowerError::UnsupportedExpression(format!(
             "unsupported array initializer: {:?}",
             ctx.func.expressions[init_h]
@@ -173,7 +173,15 @@ pub(crate) fn flatten_local_array_shape(
                                 "unsized local array requires an initializer",
                             )));
                         };
-                        match &func.expressions[init_h] {
+                        // A `const` array initializer is the constant itself;
+                        // its length lives in the module-scope composite.
+                        let init = match &func.expressions[init_h] {
+                            Expression::Constant(c) => {
+                                &module.global_expressions[module.constants[*c].init]
+                            }
+                            init => init,
+                        };
+                        match init {
                             Expression::Compose { components, .. } => {
                                 u32::try_from(components.len()).map_err(|_| {
                                     LowerError::Internal(String::from(
@@ -236,6 +236,14 @@ pub(crate) fn debug_assert_not_param_readonly_aggregate_store(
     );
 }
 
+/// Innermost statement a Naga `break` leaves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BreakTarget {
+    Loop,
+    /// The LPIR block wrapping a switch; `break` lowers to `ExitBlock`.
+    Switch,
+}
+
 /// sret return buffer: hidden pointer at [`IrFunction::sret_arg`], `memcpy` the aggregate here, then
 /// return no values in LPIR.
 #[derive(Clone, Debug)]
@@ -295,6 +303,8 @@ pub(crate) struct LowerCtx<'a> {
     pub(crate) texel_fetch_bounds: lpir::TexelFetchBoundsMode,
     /// Uniform block metadata for canonical paths and std430 offsets (same as [`LpsModuleSig::uniforms_type`]).
     pub(crate) uniforms_type: Option<&'a LpsType>,
+    /// Enclosing loops and switches, innermost last.
+    pub(crate) break_targets: Vec<BreakTarget>,
 }
 
 impl<'a> LowerCtx<'a> {
@@ -583,6 +593,7 @@ impl<'a> LowerCtx<'a> {
             texture_specs,
             texel_fetch_bounds,
             uniforms_type,
+            break_targets: Vec::new(),
         };
 
         for (lv_handle, var) in func.local_variables.iter() {
@@ -1339,6 +1339,14 @@ fn lower_global_expr_vec(
             }
             Ok(result)
         }
+        Expression::Splat { size, value } => {
+            let lanes = lower_global_expr_vec(ctx, *value)?;
+            let mut result = VRegVec::new();
+            for _ in 0..vector_size_usize(*size) {
+                result.extend_from_slice(&lanes);
+            }
+            Ok(result)
+        }
         _ => Err(LowerError::UnsupportedExpression(format!(
             "unsupported global expression init {expr:?}"
         ))),
@@ -6,13 +6,13 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use lpir::{LpirOp, VMCTX_VREG};
-use naga::{Block, Expression, Statement, SwitchValue, TypeInner};
+use naga::{Block, Expression, Handle, Statement, SwitchCase, SwitchValue, TypeInner};
 
 use crate::lower_access;
 use crate::lower_array::aggregate_storage_base_vreg;
 use crate::lower_call;
 use crate::lower_ctx::{
-    LowerCtx, debug_assert_not_param_readonly_aggregate_store, naga_type_to_ir_types,
+    BreakTarget, LowerCtx, debug_assert_not_param_readonly_aggregate_store, naga_type_to_ir_types,
 };
 use crate::lower_error::LowerError;
 use crate::lower_expr::coerce_assignment_vregs;
@@ -50,6 +50,7 @@ fn lower_statement(ctx: &mut LowerCtx<'_>, stmt: &Statement) -> Result<(), Lower
             break_if,
         } => {
             ctx.fb.push_loop();
+            ctx.break_targets.push(BreakTarget::Loop);
 
             // Naga's GLSL frontend for do-while emits `if (!cond) { break; }` as
             // the last body statement with empty continuing and no break_if. Move
@@ -84,11 +85,15 @@ fn lower_statement(ctx: &mut LowerCtx<'_>, stmt: &Statement) -> Result<(), Lower
                 });
                 ctx.fb.push(LpirOp::BrIfNot { cond: neg });
             }
+            ctx.break_targets.pop();
             ctx.fb.end_loop();
             Ok(())
         }
         Statement::Break => {
-            ctx.fb.push(LpirOp::Break);
+            match ctx.break_targets.last() {
+                Some(BreakTarget::Switch) => ctx.fb.push_exit_block(),
+                _ => ctx.fb.push(LpirOp::Break),
+            }
             Ok(())
         }
         Statement::Continue => {
@@ -512,11 +517,39 @@ fn lower_statement(ctx: &mut LowerCtx<'_>, stmt: &Statement) -> Result<(), Lower
                         );
                     }
                     match &ctx.func.expressions[*value] {
-                        Expression::Compose { .. } | Expression::ZeroValue(_) => {
+                        Expression::Compose { .. }
+                        | Expression::ZeroValue(_)
+                        | Expression::Constant(_) => {
                             return crate::lower_array::lower_array_initializer(
                                 ctx, &dst_info, *value,
                             );
                         }
+                        Expression::Select {
+                            condition,
+                            accept,
+                            reject,
+                        } => {
+                            // `c ? a : b` on arrays: store whichever side is taken.
+                            let cond = ctx.ensure_expr(*condition)?;
+                            ctx.fb.push_if(cond);
+                            lower_statement(
+                                ctx,
+                                &Statement::Store {
+                                    pointer: *pointer,
+                                    value: *accept,
+                                },
+                            )?;
+                            ctx.fb.push_else();
+                            lower_statement(
+                                ctx,
+                                &Statement::Store {
+                                    pointer: *pointer,
+                                    value: *reject,
+                                },
+                            )?;
+                            ctx.fb.end_if();
+                            return Ok(());
+                        }
                         Expression::FunctionArgument(arg_i) => {
                             let param_ptr = ctx.arg_vregs_for(*arg_i)?[0];
                             let dst = aggregate_storage_base_vreg(ctx, &dst_info.slot)?;
@@ -663,23 +696,7 @@ fn lower_statement(ctx: &mut LowerCtx<'_>, stmt: &Statement) -> Result<(), Lower
                 "store to non-local pointer",
             ))),
         },
-        Statement::Switch { selector, cases } => {
-            let sel = ctx.ensure_expr(*selector)?;
-            ctx.fb.push_switch(sel);
-            for case in cases {
-                match case.value {
-                    SwitchValue::Default => ctx.fb.push_default(),
-                    SwitchValue::I32(v) => ctx.fb.push_case(v),
-                    SwitchValue::U32(v) => ctx.fb.push_case(v as i32),
-                }
-                lower_block(ctx, &case.body)?;
-                if !case.fall_through {
-                    ctx.fb.end_switch_arm();
-                }
-            }
-            ctx.fb.end_switch();
-            Ok(())
-        }
+        Statement::Switch { selector, cases } => lower_switch(ctx, *selector, cases),
         Statement::Call {
             function,
             arguments,
@@ -703,6 +720,121 @@ fn lower_statement(ctx: &mut LowerCtx<'_>, stmt: &Statement) -> Result<(), Lower
     }
 }
 
+/// Lower a switch inside an LPIR block, so a `break` nested in its cases
+/// leaves it.
+///
+/// LPIR switch arms neither fall through nor allow `default` anywhere but
+/// last. When no case falls through, each case becomes one arm with
+/// `default` moved last. Otherwise the LPIR switch only records the index of
+/// the matching case, and the case bodies follow in order, each guarded by
+/// `matched <= index`: every body is lowered once, which the expression
+/// cache requires.
+fn lower_switch(
+    ctx: &mut LowerCtx<'_>,
+    selector: Handle<Expression>,
+    cases: &[SwitchCase],
+) -> Result<(), LowerError> {
+    let sel = ctx.ensure_expr(selector)?;
+    ctx.fb.push_block();
+    ctx.break_targets.push(BreakTarget::Switch);
+    let direct = cases
+        .iter()
+        .all(|case| !case.fall_through || block_ends_in_jump(&case.body));
+    let result = if direct {
+        lower_switch_arms(ctx, sel, cases)
+    } else {
+        lower_switch_fallthrough(ctx, sel, cases)
+    };
+    ctx.break_targets.pop();
+    result?;
+    ctx.fb.end_block();
+    Ok(())
+}
+
+fn lower_switch_arms(
+    ctx: &mut LowerCtx<'_>,
+    sel: lpir::VReg,
+    cases: &[SwitchCase],
+) -> Result<(), LowerError> {
+    ctx.fb.push_switch(sel);
+    let labelled = cases.iter().filter(|c| c.value != SwitchValue::Default);
+    let default = cases.iter().filter(|c| c.value == SwitchValue::Default);
+    for case in labelled.chain(default) {
+        match case.value {
+            SwitchValue::Default => ctx.fb.push_default(),
+            SwitchValue::I32(v) => ctx.fb.push_case(v),
+            SwitchValue::U32(v) => ctx.fb.push_case(v as i32),
+        }
+        lower_block(ctx, &case.body)?;
+        ctx.fb.end_switch_arm();
+    }
+    ctx.fb.end_switch();
+    Ok(())
+}
+
+fn lower_switch_fallthrough(
+    ctx: &mut LowerCtx<'_>,
+    sel: lpir::VReg,
+    cases: &[SwitchCase],
+) -> Result<(), LowerError> {
+    let no_match = cases
+        .iter()
+        .position(|c| c.value == SwitchValue::Default)
+        .unwrap_or(cases.len());
+    let matched = ctx.fb.alloc_vreg(lpir::IrType::I32);
+    ctx.fb.push(LpirOp::IconstI32 {
+        dst: matched,
+        value: no_match as i32,
+    });
+    ctx.fb.push_switch(sel);
+    for (index, case) in cases.iter().enumerate() {
+        let value = match case.value {
+            SwitchValue::Default => continue,
+            SwitchValue::I32(v) => v,
+            SwitchValue::U32(v) => v as i32,
+        };
+        ctx.fb.push_case(value);
+        ctx.fb.push(LpirOp::IconstI32 {
+            dst: matched,
+            value: index as i32,
+        });
+        ctx.fb.end_switch_arm();
+    }
+    ctx.fb.end_switch();
+    for (index, case) in cases.iter().enumerate() {
+        if case.body.is_empty() && case.fall_through {
+            continue;
+        }
+        let bound = ctx.fb.alloc_vreg(lpir::IrType::I32);
+        ctx.fb.push(LpirOp::IconstI32 {
+            dst: bound,
+            value: index as i32,
+        });
+        let cond = ctx.fb.alloc_vreg(lpir::IrType::I32);
+        ctx.fb.push(LpirOp::IleS {
+            dst: cond,
+            lhs: matched,
+            rhs: bound,
+        });
+        ctx.fb.push_if(cond);
+        lower_block(ctx, &case.body)?;
+        if !case.fall_through && !block_ends_in_jump(&case.body) {
+            // Naga drops the `break` that ended this case.
+            ctx.fb.push_exit_block();
+        }
+        ctx.fb.end_if();
+    }
+    Ok(())
+}
+
+/// `true` when `block` ends in a statement control does not fall out of.
+fn block_ends_in_jump(block: &Block) -> bool {
+    matches!(
+        block.last(),
+        Some(Statement::Break | Statement::Continue | Statement::Return { .. } | Statement::Kill)
+    )
+}
+
 /// `true` if this block does not end with an explicit `return`.
 pub(crate) fn void_block_missing_return(block: &Block) -> bool {
     !matches!(block.last(), Some(Statement::Return { .. }))
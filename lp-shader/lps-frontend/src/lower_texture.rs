@@ -152,6 +152,10 @@ pub(crate) fn lower_image_sample_texture(
         }
         (TextureStorageFormat::R16Unorm, TextureShapeHint::General2D) => "texture2d_r16_unorm",
         (TextureStorageFormat::R16Unorm, TextureShapeHint::HeightOne) => "texture1d_r16_unorm",
+        (TextureStorageFormat::R8Unorm, TextureShapeHint::General2D) => "texture2d_r8_unorm",
+        (TextureStorageFormat::R8Unorm, TextureShapeHint::HeightOne) => "texture1d_r8_unorm",
+        (TextureStorageFormat::Rgb8Unorm, TextureShapeHint::General2D) => "texture2d_rgb8_unorm",
+        (TextureStorageFormat::Rgb8Unorm, TextureShapeHint::HeightOne) => "texture1d_rgb8_unorm",
         (TextureStorageFormat::Rgb16Unorm, _) => {
             return Err(LowerError::UnsupportedExpression(format!(
                 "texture `{}`: unsupported format Rgb16Unorm for filtered sampling",
@@ -677,6 +681,18 @@ fn emit_texel_fetch_vec4_unorm(
             out.push(emit_unorm16_channel_load(ctx, texel_addr, 2)?);
             out.push(emit_unorm16_channel_load(ctx, texel_addr, 3)?);
         }
+        TextureStorageFormat::R8Unorm => {
+            out.push(emit_unorm8_channel_load(ctx, texel_addr, 0)?);
+            out.push(f32_const(ctx, 0.0)?);
+            out.push(f32_const(ctx, 0.0)?);
+            out.push(f32_const(ctx, 1.0)?);
+        }
+        TextureStorageFormat::Rgb8Unorm => {
+            out.push(emit_unorm8_channel_load(ctx, texel_addr, 0)?);
+            out.push(emit_unorm8_channel_load(ctx, texel_addr, 1)?);
+            out.push(emit_unorm8_channel_load(ctx, texel_addr, 2)?);
+            out.push(f32_const(ctx, 1.0)?);
+        }
     }
     Ok(out)
 }
@@ -701,6 +717,33 @@ fn emit_unorm16_channel_load(
     Ok(converted)
 }
 
+/// One unorm8 channel, widened to unorm16 (`raw * 257`) before conversion so
+/// a texel reads exactly as the filtered samplers see it.
+fn emit_unorm8_channel_load(
+    ctx: &mut LowerCtx<'_>,
+    texel_addr: VReg,
+    channel_index: u32,
+) -> Result<VReg, LowerError> {
+    let raw = ctx.fb.alloc_vreg(IrType::I32);
+    ctx.fb.push(LpirOp::Load8U {
+        dst: raw,
+        base: texel_addr,
+        offset: channel_index,
+    });
+    let widened = ctx.fb.alloc_vreg(IrType::I32);
+    ctx.fb.push(LpirOp::ImulImm {
+        dst: widened,
+        src: raw,
+        imm: 257,
+    });
+    let converted = ctx.fb.alloc_vreg(IrType::F32);
+    ctx.fb.push(LpirOp::Unorm16toF {
+        dst: converted,
+        src: widened,
+    });
+    Ok(converted)
+}
+
 fn f32_const(ctx: &mut LowerCtx<'_>, value: f32) -> Result<VReg, LowerError> {
     let dst = ctx.fb.alloc_vreg(IrType::F32);
     ctx.fb.push(LpirOp::FconstF32 { dst, value });
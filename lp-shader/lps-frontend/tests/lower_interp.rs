@@ -95,6 +95,88 @@ fn interp_for_loop_sum() {
     assert_eq!(run_i32(glsl, "f", &[Value::I32(4)]), 6);
 }
 
+#[test]
+fn interp_switch_default_in_the_middle() {
+    let glsl = "int f(int x) {
+        int r = 0;
+        switch (x) {
+        case 0: r = 10; break;
+        default: r = -1; break;
+        case 2: return 20;
+        case 3: r = 30;
+        }
+        return r;
+    }";
+    let got: Vec<i32> = (0..5)
+        .map(|x| run_i32(glsl, "f", &[Value::I32(x)]))
+        .collect();
+    assert_eq!(got, [10, -1, 20, 30, -1]);
+}
+
+#[test]
+fn interp_switch_fallthrough() {
+    let glsl = "int f(int x) {
+        int r = 0;
+        switch (x) {
+        case 1:
+        case 2: r += 1;
+        case 3: r += 10; break;
+        default: r += 100;
+        case 5: r += 1000;
+        }
+        return r;
+    }";
+    let got: Vec<i32> = (0..6)
+        .map(|x| run_i32(glsl, "f", &[Value::I32(x)]))
+        .collect();
+    assert_eq!(got, [1100, 11, 11, 10, 1100, 1000]);
+}
+
+#[test]
+fn interp_switch_break_leaves_the_switch_not_the_loop() {
+    let glsl = "int f(int n) {
+        int total = 0;
+        for (int i = 0; i < n; i++) {
+            switch (i % 3) {
+            case 0: continue;
+            case 1:
+                for (int j = 0; j < 10; j++) { if (j == 2) break; total += 1; }
+                break;
+            default:
+                total += 100;
+                if (i > 4) break;
+                total += 1000;
+            }
+            total += 10000;
+        }
+        return total;
+    }";
+    assert_eq!(run_i32(glsl, "f", &[Value::I32(7)]), 41204);
+}
+
+#[test]
+fn interp_const_array_lookup_and_array_ternary() {
+    let glsl = "const int A[3] = int[3](1, 2, 3);
+    const vec3 V[2] = vec3[2](vec3(1.0, 2.0, 3.0), vec3(4.0, 5.0, 6.0));
+    int f(int c) {
+        int b[3] = int[3](7, 8, 9);
+        int r[3] = c > 0 ? A : b;
+        return r[0] * 100 + r[2] + int(V[c].z) * 1000;
+    }";
+    assert_eq!(run_i32(glsl, "f", &[Value::I32(0)]), 3709);
+    assert_eq!(run_i32(glsl, "f", &[Value::I32(1)]), 6103);
+}
+
+#[test]
+fn interp_unsized_const_array() {
+    let glsl = "const int K[] = int[](5, 6, 7);
+    const vec2 S[] = vec2[](vec2(0.5));
+    int f(int i) {
+        return K[i] + K.length() * 10 + int(S[0].y * 100.0);
+    }";
+    assert_eq!(run_i32(glsl, "f", &[Value::I32(2)]), 87);
+}
+
 #[test]
 fn interp_float_to_int() {
     let glsl = "int f(float x) { return int(x); }";
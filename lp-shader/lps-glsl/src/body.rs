@@ -1,6 +1,6 @@
 pub use crate::syntax::{
     AssignOp, BinaryOp, IncDecOp, ParsedExpr, ParsedExprKind, ParsedFunctionBody, ParsedStmt,
-    UnaryOp,
+    ParsedSwitchCase, UnaryOp,
 };
 
 pub use crate::syntax::parser::{parse_expr_tokens, parse_function_body};
@@ -30,12 +30,20 @@ impl Diagnostic {
     }
 
     pub fn render(&self, source: &str) -> String {
-        let map = SourceMap::new(source);
-        let Some((line, col)) = map.line_col(self.span.start) else {
+        self.render_mapped(source, &SourceMap::new(source))
+    }
+
+    /// Render against `source` with locations taken from `map`, which names
+    /// the authored file and line when `source` is preprocessor output.
+    pub fn render_mapped(&self, source: &str, map: &SourceMap) -> String {
+        let Some((out_line, _)) = map.line_col(self.span.start) else {
+            return format!("{self}");
+        };
+        let Some(location) = map.location(self.span.start) else {
             return format!("{self}");
         };
-        let (line_start, line_end) = map.line_bounds(line).unwrap_or((0, source.len()));
-        let line_text = &source[line_start..line_end];
+        let (line_start, line_end) = map.line_bounds(out_line).unwrap_or((0, source.len()));
+        let line_text = source.get(line_start..line_end).unwrap_or("");
         let underline_start = self.span.start.saturating_sub(line_start);
         let underline_end = self
             .span
@@ -52,8 +60,10 @@ impl Diagnostic {
             marker.push('^');
         }
 
+        let file = location.file.unwrap_or("<shader>");
+        let (line, col) = (location.line, location.col);
         format!(
-            "error: {}\n --> <shader>:{line}:{col}\n  |\n{line:>2} | {line_text}\n  | {marker}",
+            "error: {}\n --> {file}:{line}:{col}\n  |\n{line:>2} | {line_text}\n  | {marker}",
             self.message
         )
     }
@@ -42,8 +42,9 @@ pub use symbols::{
 use typeck::TypeCtx;
 use types::StructTypes;
 pub use types::{
-    BuiltinKind, GlobalInfo, HirExpr, HirExprKind, HirFunction, HirFunctionBody, HirModule,
-    HirOutArg, HirParam, HirStmt, HirTextureOperand, HirUserCallWriteback, ImportKey, UniformInfo,
+    BuiltinKind, ConstArray, GlobalInfo, HirExpr, HirExprKind, HirFunction, HirFunctionBody,
+    HirModule, HirOutArg, HirParam, HirStmt, HirSwitchCase, HirTextureOperand,
+    HirUserCallWriteback, ImportKey, UniformInfo,
 };
 pub use typing::{scalar_base_type, scalar_ir_types, scalar_lane_count};
 
@@ -75,6 +76,7 @@ struct HirBuildFunctionState {
     global_vars: VecMap<String, GlobalInfo>,
     globals_type: Option<LpsType>,
     global_inits: Vec<GlobalInit>,
+    const_arrays: Vec<ConstArray>,
     functions_sigs: Vec<FunctionSig>,
     imports: ImportRegistry,
     globals: VecMap<String, GlobalConst>,
@@ -115,7 +117,7 @@ impl<'src> HirBuildJob<'src> {
                 let structs = build_struct_types(&self.index, &array_size_consts)?;
                 let (uniforms, uniforms_type, uniforms_size) =
                     build_uniforms(&self.index, &structs, &array_size_consts)?;
-                let (global_vars, globals_type, global_inits) = build_global_vars(
+                let (mut global_vars, globals_type, global_inits) = build_global_vars(
                     self.source,
                     &self.tokens,
                     &self.index,
@@ -126,12 +128,12 @@ impl<'src> HirBuildJob<'src> {
                 let functions_sigs =
                     build_function_sigs(&self.index, &structs, &array_size_consts)?;
                 let mut imports = ImportRegistry::default();
-                let globals = build_global_consts(
+                let (globals, const_arrays) = build_global_consts(
                     self.source,
                     &self.tokens,
                     &self.index,
                     &uniforms,
-                    &global_vars,
+                    &mut global_vars,
                     &functions_sigs,
                     &structs,
                     &array_size_consts,
@@ -146,6 +148,7 @@ impl<'src> HirBuildJob<'src> {
                     global_vars,
                     globals_type,
                     global_inits,
+                    const_arrays,
                     functions_sigs,
                     imports,
                     globals,
@@ -256,6 +259,7 @@ impl HirBuildFunctionState {
             },
             uniforms: self.uniforms,
             globals: self.global_vars,
+            const_arrays: self.const_arrays,
             imports: self.imports.into_vec(),
             texture_specs: options.texture_specs.clone(),
             texel_fetch_bounds: options.texel_fetch_bounds,
@@ -278,7 +282,7 @@ pub fn build_hir(
     let structs = build_struct_types(index, &array_size_consts)?;
     let (uniforms, uniforms_type, uniforms_size) =
         build_uniforms(index, &structs, &array_size_consts)?;
-    let (global_vars, globals_type, global_inits) = build_global_vars(
+    let (mut global_vars, globals_type, global_inits) = build_global_vars(
         source,
         tokens,
         index,
@@ -288,12 +292,12 @@ pub fn build_hir(
     )?;
     let functions_sigs = build_function_sigs(index, &structs, &array_size_consts)?;
     let mut imports = ImportRegistry::default();
-    let globals = build_global_consts(
+    let (globals, const_arrays) = build_global_consts(
         source,
         tokens,
         index,
         &uniforms,
-        &global_vars,
+        &mut global_vars,
         &functions_sigs,
         &structs,
         &array_size_consts,
@@ -377,6 +381,7 @@ pub fn build_hir(
         },
         uniforms,
         globals: global_vars,
+        const_arrays,
         imports: imports.into_vec(),
         texture_specs: options.texture_specs.clone(),
         texel_fetch_bounds: options.texel_fetch_bounds,
@@ -494,6 +499,11 @@ fn parse_array_type_name<'a>(
     }
 }
 
+fn is_unsized_array_name(name: &str, array_size_consts: &ArraySizeConsts) -> bool {
+    parse_array_type_name(name, array_size_consts)
+        .is_some_and(|(_, lens)| lens.iter().any(Option::is_none))
+}
+
 fn infer_array_decl_type(
     span: Span,
     base: &LpsType,
@@ -834,7 +844,14 @@ fn build_global_vars(
                 init_span,
             });
         }
-        globals.insert(name, GlobalInfo { ty, byte_offset });
+        globals.insert(
+            name,
+            GlobalInfo {
+                ty,
+                byte_offset,
+                constant: false,
+            },
+        );
     }
 
     let globals_type = if members.is_empty() {
@@ -848,21 +865,26 @@ fn build_global_vars(
     Ok((globals, globals_type, inits))
 }
 
+#[allow(
+    clippy::too_many_arguments,
+    reason = "const initializers need the same typing context as functions"
+)]
 fn build_global_consts(
     source: &str,
     tokens: &[Token],
     index: &TopLevelIndex,
     uniforms: &VecMap<String, UniformInfo>,
-    global_vars: &VecMap<String, GlobalInfo>,
+    global_vars: &mut VecMap<String, GlobalInfo>,
     functions: &[FunctionSig],
     structs: &StructTypes,
     array_size_consts: &ArraySizeConsts,
     imports: &mut ImportRegistry,
     texture_specs: &VecMap<String, lps_shared::TextureBindingSpec>,
-) -> Result<VecMap<String, GlobalConst>, Diagnostic> {
+) -> Result<(VecMap<String, GlobalConst>, Vec<ConstArray>), Diagnostic> {
     let mut globals = VecMap::new();
+    let mut const_arrays = Vec::new();
+    let mut rodata_size = 0usize;
     for konst in &index.consts {
-        let ty = type_ref_to_lps_with_structs(&konst.ty, structs, array_size_consts)?;
         let Some(init_span) = konst.init_span else {
             return Err(Diagnostic::error(
                 konst.span,
@@ -880,6 +902,46 @@ fn build_global_consts(
             imports,
             texture_specs,
         );
+        if parse_array_type_name(&konst.ty.name, array_size_consts).is_some() {
+            // Const arrays are lookup tables: evaluate them once into the
+            // module rodata instead of rebuilding them at each use. The
+            // initializer also fixes the length of an unsized declaration.
+            let expr = ctx.type_decl_init(&konst.ty.name, konst.ty.span, &parsed)?;
+            let ty = ctx.arena.expr_ty(expr).clone();
+            let lanes = const_fold::const_lanes(&ctx.arena, expr).ok_or_else(|| {
+                Diagnostic::error(
+                    init_span,
+                    "const array initializer must be a constant expression",
+                )
+            })?;
+            if global_vars.get(&konst.name).is_some() {
+                return Err(Diagnostic::error(
+                    konst.span,
+                    format!("conflicting global declaration `{}`", konst.name),
+                ));
+            }
+            rodata_size = lps_shared::layout::round_up(
+                rodata_size,
+                lps_shared::type_alignment(&ty, LayoutRules::Std430),
+            );
+            let byte_offset = rodata_size as u32;
+            rodata_size += lps_shared::type_size(&ty, LayoutRules::Std430);
+            global_vars.insert(
+                konst.name.clone(),
+                GlobalInfo {
+                    ty: ty.clone(),
+                    byte_offset,
+                    constant: true,
+                },
+            );
+            const_arrays.push(ConstArray {
+                ty,
+                byte_offset,
+                lanes,
+            });
+            continue;
+        }
+        let ty = type_ref_to_lps_with_structs(&konst.ty, structs, array_size_consts)?;
         let expr = ctx.type_expr(&parsed)?;
         let expr = ctx.coerce_expr(expr, &ty)?;
         globals.insert(
@@ -890,7 +952,7 @@ fn build_global_consts(
             },
         );
     }
-    Ok(globals)
+    Ok((globals, const_arrays))
 }
 
 #[allow(
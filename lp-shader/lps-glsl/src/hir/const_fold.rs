@@ -1,9 +1,13 @@
+use alloc::vec::Vec;
+use lpir::RodataWord;
 use lps_shared::LpsType;
 
 use crate::Span;
 use crate::body::{BinaryOp, UnaryOp};
 
+use super::arena::{ExprId, HirArena};
 use super::types::{BuiltinKind, HirExpr, HirExprKind};
+use super::typing::scalar_lane_count;
 
 pub(super) fn fold_unary(span: Span, op: UnaryOp, expr: &HirExpr) -> Option<HirExpr> {
     match (op, &expr.kind) {
@@ -139,6 +143,50 @@ pub(super) fn fold_cast(span: Span, target: &LpsType, expr: &HirExpr) -> Option<
     }
 }
 
+/// Scalar lanes of a folded constant in flattened value order, mirroring how
+/// lowering flattens constructors. `None` when any lane is not a literal.
+pub(super) fn const_lanes(arena: &HirArena, expr: ExprId) -> Option<Vec<RodataWord>> {
+    let expr = arena.expr(expr);
+    let lane = match expr.kind {
+        HirExprKind::BoolLiteral(value) => RodataWord::I32(i32::from(value)),
+        HirExprKind::IntLiteral(value) => RodataWord::I32(value),
+        HirExprKind::UIntLiteral(value) => RodataWord::I32(value as i32),
+        HirExprKind::FloatLiteral(value) => RodataWord::F32(value),
+        HirExprKind::Constructor { args } => {
+            let args = arena.expr_list(args);
+            let lane_count = scalar_lane_count(&expr.ty);
+            if let [arg] = args
+                && scalar_lane_count(arena.expr_ty(*arg)) == 1
+                && lane_count > 1
+            {
+                let value = *const_lanes(arena, *arg)?.first()?;
+                let Some((cols, rows)) = expr.ty.matrix_dims() else {
+                    return Some(alloc::vec![value; lane_count]);
+                };
+                let zero = RodataWord::F32(0.0);
+                return Some(
+                    (0..cols)
+                        .flat_map(|col| {
+                            (0..rows).map(move |row| if col == row { value } else { zero })
+                        })
+                        .collect(),
+                );
+            }
+            if expr.ty.is_matrix() && args.iter().any(|arg| arena.expr_ty(*arg).is_matrix()) {
+                return None;
+            }
+            let mut lanes = Vec::new();
+            for arg in args {
+                lanes.extend(const_lanes(arena, *arg)?);
+            }
+            lanes.truncate(lane_count);
+            return Some(lanes);
+        }
+        _ => return None,
+    };
+    Some(alloc::vec![lane])
+}
+
 fn fold_float_binary(span: Span, op: BinaryOp, lhs: &HirExpr, rhs: &HirExpr) -> Option<HirExpr> {
     let (HirExprKind::FloatLiteral(lhs), HirExprKind::FloatLiteral(rhs)) = (&lhs.kind, &rhs.kind)
     else {
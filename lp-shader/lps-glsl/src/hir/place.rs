@@ -47,6 +47,11 @@ pub(crate) enum PlaceRoot {
         byte_offset: u32,
         ty: LpsType,
     },
+    Rodata {
+        name: String,
+        byte_offset: u32,
+        ty: LpsType,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -120,6 +125,18 @@ impl HirPlace {
         }
     }
 
+    pub(super) fn rodata(name: String, byte_offset: u32, ty: LpsType) -> Self {
+        Self {
+            root: PlaceRoot::Rodata {
+                name,
+                byte_offset,
+                ty: ty.clone(),
+            },
+            segments: Vec::new(),
+            ty,
+        }
+    }
+
     pub(super) fn push_field(&mut self, span: Span, name: &str) -> Result<(), Diagnostic> {
         let shape = TypeShape::new(&self.ty);
         if let Some(field) = shape.field(name) {
@@ -173,7 +190,7 @@ impl HirPlace {
 
 impl PlaceRoot {
     pub(crate) fn is_writable(&self) -> bool {
-        !matches!(self, PlaceRoot::Uniform { .. })
+        !matches!(self, PlaceRoot::Uniform { .. } | PlaceRoot::Rodata { .. })
     }
 }
 
@@ -26,7 +26,7 @@ use super::function::FunctionSig;
 use super::types::StructTypes;
 use super::{
     build_array_size_consts, build_function_sigs, build_global_vars, build_struct_types,
-    build_uniforms, type_ref_to_lps_with_structs,
+    build_uniforms, is_unsized_array_name, type_ref_to_lps_with_structs,
 };
 
 /// User-declared symbols extracted from GLSL source text.
@@ -109,10 +109,20 @@ pub fn analyze_symbols(source: &str) -> Result<SymbolAnalysis, Diagnostic> {
 
     let mut globals = Vec::new();
     for konst in &index.consts {
-        let ty = type_ref_to_lps_with_structs(&konst.ty, &structs, &array_size_consts)?;
+        // An unsized const array takes its length from the initializer, which
+        // symbol analysis does not type; keep the declared spelling.
+        let type_name = if is_unsized_array_name(&konst.ty.name, &array_size_consts) {
+            konst.ty.name.clone()
+        } else {
+            glsl_type_name(&type_ref_to_lps_with_structs(
+                &konst.ty,
+                &structs,
+                &array_size_consts,
+            )?)
+        };
         globals.push(VarSymbol {
             name: konst.name.clone(),
-            type_name: glsl_type_name(&ty),
+            type_name,
         });
     }
     globals.extend(global_vars.iter().map(|(name, info)| VarSymbol {
@@ -275,6 +285,18 @@ Light pick(Light lights[2], int i) { return lights[0]; }
         assert_eq!(symbols.functions[0].params[0].type_name, "Light[2]");
     }
 
+    #[test]
+    fn const_array_symbols_keep_their_declared_spelling() {
+        let source = "\
+const float LUT[2] = float[2](0.0, 1.0);
+const int K[] = int[](1, 2, 3);
+";
+        let symbols = analyze_symbols(source).expect("const array shader analyzes");
+        assert_eq!(symbols.globals[0].type_name, "float[2]");
+        assert_eq!(symbols.globals[1].name, "K");
+        assert_eq!(symbols.globals[1].type_name, "int[]");
+    }
+
     #[test]
     fn out_qualifier_is_reported() {
         let symbols = analyze_symbols("void split(vec2 v, out float x) { x = v.x; }")
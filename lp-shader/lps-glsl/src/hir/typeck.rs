@@ -7,7 +7,9 @@ use lps_shared::{
     LpsType, ParamQualifier, TextureBindingSpec, TextureShapeHint, TextureStorageFormat,
 };
 
-use crate::body::{AssignOp, BinaryOp, ParsedExpr, ParsedExprKind, ParsedStmt, UnaryOp};
+use crate::body::{
+    AssignOp, BinaryOp, ParsedExpr, ParsedExprKind, ParsedStmt, ParsedSwitchCase, UnaryOp,
+};
 use crate::{Diagnostic, Span};
 
 use super::arena::{ExprId, ExprList, HirArena, PlaceId};
@@ -18,7 +20,7 @@ use super::place::{AccessMode, HirPlace, PlaceRoot, PlaceSegment};
 use super::types::StructTypes;
 use super::types::{
     GlobalInfo, HirExpr, HirExprKind, HirFunctionBody, HirLocal, HirOutArg, HirParam, HirStmt,
-    HirTextureOperand, HirUserCallWriteback, UniformInfo,
+    HirSwitchCase, HirTextureOperand, HirUserCallWriteback, UniformInfo,
 };
 use super::typing::builtin_has_out_args;
 use super::typing::{
@@ -47,6 +49,7 @@ pub(super) struct TypeCtx<'a> {
     pub(super) arena: HirArena,
     scopes: Vec<VecMap<String, usize>>,
     loop_depth: usize,
+    switch_depth: usize,
 }
 
 impl<'a> TypeCtx<'a> {
@@ -75,6 +78,7 @@ impl<'a> TypeCtx<'a> {
             arena: HirArena::default(),
             scopes: alloc::vec![VecMap::new()],
             loop_depth: 0,
+            switch_depth: 0,
         }
     }
 
@@ -102,6 +106,7 @@ impl<'a> TypeCtx<'a> {
             arena: HirArena::default(),
             scopes: alloc::vec![VecMap::new()],
             loop_depth: 0,
+            switch_depth: 0,
         }
     }
 
@@ -277,9 +282,14 @@ impl<'a> TypeCtx<'a> {
                 let condition = self.coerce_expr(condition, &LpsType::Bool)?;
                 Ok(alloc::vec![HirStmt::DoWhile { body, condition }])
             }
+            ParsedStmt::Switch {
+                selector,
+                cases,
+                span,
+            } => self.type_switch(*span, selector, cases, return_ty),
             ParsedStmt::Break { span } => {
-                if self.loop_depth == 0 {
-                    return Err(Diagnostic::error(*span, "break outside loop"));
+                if self.loop_depth == 0 && self.switch_depth == 0 {
+                    return Err(Diagnostic::error(*span, "break outside loop or switch"));
                 }
                 Ok(alloc::vec![HirStmt::Break])
             }
@@ -325,6 +335,80 @@ impl<'a> TypeCtx<'a> {
         }
     }
 
+    fn type_switch(
+        &mut self,
+        span: Span,
+        selector: &ParsedExpr,
+        cases: &[ParsedSwitchCase],
+        return_ty: &LpsType,
+    ) -> Result<Vec<HirStmt>, Diagnostic> {
+        let selector = self.type_expr(selector)?;
+        let selector_ty = self.arena.expr_ty(selector).clone();
+        if !matches!(selector_ty, LpsType::Int | LpsType::UInt) {
+            return Err(Diagnostic::error(
+                span,
+                "switch selector must be a scalar int or uint",
+            ));
+        }
+        if cases.last().is_some_and(|case| case.body.is_empty()) {
+            return Err(Diagnostic::error(
+                cases.last().expect("switch case").span,
+                "last switch label must be followed by a statement",
+            ));
+        }
+        self.switch_depth += 1;
+        self.scopes.push(VecMap::new());
+        let mut typed: Vec<HirSwitchCase> = Vec::with_capacity(cases.len());
+        for case in cases {
+            let value = match &case.label {
+                Some(label) => Some(self.type_case_label(label, &selector_ty)?),
+                None => None,
+            };
+            if typed.iter().any(|seen| seen.value == value) {
+                let message = match value {
+                    Some(v) if selector_ty == LpsType::UInt => {
+                        format!("duplicate case label `{}`", v as u32)
+                    }
+                    Some(v) => format!("duplicate case label `{v}`"),
+                    None => String::from("multiple default labels in switch"),
+                };
+                return Err(Diagnostic::error(case.span, message));
+            }
+            let body = self.type_statements(&case.body, return_ty)?;
+            typed.push(HirSwitchCase { value, body });
+        }
+        self.scopes.pop();
+        self.switch_depth -= 1;
+        Ok(alloc::vec![HirStmt::Switch {
+            selector,
+            cases: typed,
+        }])
+    }
+
+    /// Value of a `case` label as i32 bits, converted to the selector type.
+    fn type_case_label(
+        &mut self,
+        label: &ParsedExpr,
+        selector_ty: &LpsType,
+    ) -> Result<i32, Diagnostic> {
+        let expr = self.type_expr(label)?;
+        if !matches!(self.arena.expr_ty(expr), LpsType::Int | LpsType::UInt) {
+            return Err(Diagnostic::error(
+                label.span,
+                "case label must be a scalar int or uint",
+            ));
+        }
+        let expr = self.coerce_expr(expr, selector_ty)?;
+        match self.arena.expr(expr).kind {
+            HirExprKind::IntLiteral(v) => Ok(v),
+            HirExprKind::UIntLiteral(v) => Ok(v as i32),
+            _ => Err(Diagnostic::error(
+                label.span,
+                "case label must be a constant expression",
+            )),
+        }
+    }
+
     pub(super) fn type_expr(&mut self, expr: &ParsedExpr) -> Result<ExprId, Diagnostic> {
         match &expr.kind {
             ParsedExprKind::BoolLiteral(v) => {
@@ -518,13 +602,13 @@ impl<'a> TypeCtx<'a> {
             return Ok(self.clone_expr_from(&global.arena, global.expr));
         }
         if let Some(global) = self.global_vars.get(name) {
-            return Ok(self.arena.push_expr(
-                span,
-                global.ty.clone(),
-                HirExprKind::Global {
-                    byte_offset: global.byte_offset,
-                },
-            ));
+            let byte_offset = global.byte_offset;
+            let kind = if global.constant {
+                HirExprKind::Rodata { byte_offset }
+            } else {
+                HirExprKind::Global { byte_offset }
+            };
+            return Ok(self.arena.push_expr(span, global.ty.clone(), kind));
         }
         if let Some(uniform) = self.uniforms.get(name) {
             return Ok(self.arena.push_expr(
@@ -773,6 +857,18 @@ impl<'a> TypeCtx<'a> {
             (TextureStorageFormat::R16Unorm, TextureShapeHint::HeightOne) => {
                 ("texture1d_r16_unorm", 7)
             }
+            (TextureStorageFormat::R8Unorm, TextureShapeHint::General2D) => {
+                ("texture2d_r8_unorm", 10)
+            }
+            (TextureStorageFormat::R8Unorm, TextureShapeHint::HeightOne) => {
+                ("texture1d_r8_unorm", 7)
+            }
+            (TextureStorageFormat::Rgb8Unorm, TextureShapeHint::General2D) => {
+                ("texture2d_rgb8_unorm", 10)
+            }
+            (TextureStorageFormat::Rgb8Unorm, TextureShapeHint::HeightOne) => {
+                ("texture1d_rgb8_unorm", 7)
+            }
             (TextureStorageFormat::Rgb16Unorm, _) => {
                 return Err(Diagnostic::error(
                     span,
@@ -1084,6 +1180,14 @@ impl<'a> TypeCtx<'a> {
             }
             _ => return Err(Diagnostic::error(expr.span, "invalid place expression")),
         };
+        if mode != AccessMode::Read
+            && let PlaceRoot::Rodata { name, .. } = &self.arena.place(place).root
+        {
+            return Err(Diagnostic::error(
+                expr.span,
+                format!("cannot write to const variable `{name}`"),
+            ));
+        }
         if mode != AccessMode::Read && !self.arena.place(place).root.is_writable() {
             return Err(Diagnostic::error(
                 expr.span,
@@ -1117,6 +1221,9 @@ impl<'a> TypeCtx<'a> {
         }
         if let Some(global) = self.global_vars.get(name) {
             let ty = global.ty.clone();
+            if global.constant {
+                return Ok(HirPlace::rodata(String::from(name), global.byte_offset, ty));
+            }
             return Ok(HirPlace::global(String::from(name), global.byte_offset, ty));
         }
         Err(Diagnostic::error(span, format!("unknown local `{name}`")))
@@ -1138,7 +1245,7 @@ impl<'a> TypeCtx<'a> {
         type_name_to_lps_with_structs(name, span, self.structs, self.array_size_consts)
     }
 
-    fn type_decl_init(
+    pub(super) fn type_decl_init(
         &mut self,
         name: &str,
         span: Span,
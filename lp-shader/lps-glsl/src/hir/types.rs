@@ -14,6 +14,8 @@ pub struct HirModule {
     pub meta: LpsModuleSig,
     pub uniforms: VecMap<String, UniformInfo>,
     pub globals: VecMap<String, GlobalInfo>,
+    /// `const` arrays, placed in the module rodata in declaration order.
+    pub const_arrays: Vec<ConstArray>,
     pub imports: Vec<ImportInfo>,
     pub texture_specs: VecMap<String, TextureBindingSpec>,
     pub texel_fetch_bounds: lpir::TexelFetchBoundsMode,
@@ -29,6 +31,18 @@ pub struct UniformInfo {
 pub struct GlobalInfo {
     pub ty: LpsType,
     pub byte_offset: u32,
+    /// A `const` array: `byte_offset` is into the module rodata rather than
+    /// the globals region.
+    pub constant: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstArray {
+    pub ty: LpsType,
+    /// Byte offset from [`lpir::LpirOp::RodataAddr`].
+    pub byte_offset: u32,
+    /// Initializer lanes in flattened value order.
+    pub lanes: Vec<lpir::RodataWord>,
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +123,10 @@ pub enum HirStmt {
         body: Vec<HirStmt>,
         condition: ExprId,
     },
+    Switch {
+        selector: ExprId,
+        cases: Vec<HirSwitchCase>,
+    },
     Break,
     Continue,
     Expr(ExprId),
@@ -118,6 +136,15 @@ pub enum HirStmt {
     },
 }
 
+/// One `case` or `default` label of a switch, in source order. Control falls
+/// through from `body` into the next case unless it ends in a jump.
+#[derive(Debug, Clone)]
+pub struct HirSwitchCase {
+    /// Label value as i32 bits; `None` for `default`.
+    pub value: Option<i32>,
+    pub body: Vec<HirStmt>,
+}
+
 #[derive(Debug, Clone)]
 pub struct HirExpr {
     pub span: Span,
@@ -143,6 +170,9 @@ pub enum HirExprKind {
     Global {
         byte_offset: u32,
     },
+    Rodata {
+        byte_offset: u32,
+    },
     Constructor {
         args: ExprList,
     },
@@ -228,8 +228,10 @@ impl<'src, 'tok> Parser<'src, 'tok> {
 
     fn parse_const(&mut self) -> Result<ConstDecl, Diagnostic> {
         let start = self.expect_keyword(Keyword::Const)?.span.start;
-        let ty = self.expect_type_ref()?;
+        let mut ty = self.expect_type_ref()?;
+        self.append_array_suffixes(&mut ty)?;
         let name = self.expect_identifier_like()?.to_string();
+        self.append_array_suffixes(&mut ty)?;
         let init_span = if self.at_punct("=") {
             self.bump();
             Some(self.span_until_semicolon()?)
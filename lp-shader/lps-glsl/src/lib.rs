@@ -5,6 +5,10 @@
 //! designed for on-device ESP32-C6 runtime compilation: `no_std + alloc`,
 //! budgeted/resumable compilation, source-spanned diagnostics, and a small
 //! dependency surface.
+//!
+//! Sources that use `#define`, `#if` or `#include` go through [`preprocess`]
+//! first; its [`Preprocessed::render`] maps diagnostics back to the authored
+//! file and line.
 
 #![no_std]
 
@@ -20,6 +24,7 @@ mod job;
 mod lexer;
 mod lower;
 mod lvalue;
+mod preprocess;
 mod source;
 mod syntax;
 mod token;
@@ -36,7 +41,12 @@ pub use job::{CompileBudget, CompileJob, CompileStage, CompileStepResult};
 pub use lexer::lex;
 pub use lps_shared::ParamQualifier;
 pub use lvalue::{LvalueBase, LvaluePath, LvalueProjection, SwizzleComponent};
-pub use source::{SourceMap, Span};
+#[cfg(feature = "lpfs")]
+pub use preprocess::LpFsIncludes;
+pub use preprocess::{
+    IncludeResolver, IncludedSource, NoIncludes, PreprocessError, Preprocessed, preprocess,
+};
+pub use source::{LineOrigin, SourceLocation, SourceMap, Span};
 pub use token::{Keyword, Token, TokenKind};
 
 #[cfg(test)]
@@ -741,6 +751,165 @@ vec4 render_2d(vec2 pos) {
         lpir::validate_module(&output.ir).expect("valid LPIR");
     }
 
+    #[test]
+    fn switch_moves_default_last_and_breaks_exit_the_wrapping_block() {
+        let source = r#"
+int pick(int x) {
+    int r = 0;
+    switch (x) {
+    case 0: r = 1; break;
+    default: r = 2; break;
+    case 3:
+        for (int i = 0; i < 4; i++) {
+            if (i == x) break;
+            r += i;
+        }
+    }
+    return r;
+}
+
+vec4 render_2d(vec2 pos) {
+    return vec4(float(pick(int(pos.x))));
+}
+"#;
+        let output = compile(source, &CompileOptions::default()).expect("compile switch");
+        lpir::validate_module(&output.ir).expect("valid LPIR");
+        let pick = function(&output, "pick");
+        let arms: alloc::vec::Vec<_> = pick
+            .body
+            .iter()
+            .filter(|op| {
+                matches!(
+                    op,
+                    lpir::LpirOp::CaseStart { .. } | lpir::LpirOp::DefaultStart { .. }
+                )
+            })
+            .collect();
+        assert_eq!(arms.len(), 3);
+        assert!(matches!(arms[2], lpir::LpirOp::DefaultStart { .. }));
+        assert_eq!(
+            op_count(pick, |op| matches!(op, lpir::LpirOp::Block { .. })),
+            1
+        );
+        // The loop's `break` stays a loop break; the cases' trailing ones go.
+        assert_eq!(op_count(pick, |op| matches!(op, lpir::LpirOp::Break)), 1);
+    }
+
+    #[test]
+    fn switch_errors() {
+        for (body, expected) in [
+            (
+                "    switch (pos.x) { default: break; }",
+                "switch selector must be a scalar int or uint",
+            ),
+            (
+                "    int x = 1;\n    switch (x) { case 1: break; case 1: break; }",
+                "duplicate case label `1`",
+            ),
+            (
+                "    int x = 1;\n    switch (x) { case x: break; }",
+                "case label must be a constant expression",
+            ),
+            (
+                "    int x = 1;\n    switch (x) { default: break; default: break; }",
+                "multiple default labels in switch",
+            ),
+            (
+                "    int x = 1;\n    switch (x) { case 1: break; default: }",
+                "last switch label must be followed by a statement",
+            ),
+            ("    break;", "break outside loop or switch"),
+            (
+                "    int x = 1;\n    switch (x) { case 1: continue; }",
+                "continue outside loop",
+            ),
+        ] {
+            assert_eq!(compile_body_error(body), expected);
+        }
+    }
+
+    #[test]
+    fn const_arrays_are_placed_in_rodata() {
+        let source = r#"
+const float LUT[4] = float[4](0.0, 0.25, 0.5, 1.0);
+const ivec2 STEPS[] = ivec2[](ivec2(1, -1), ivec2(2));
+
+vec4 render_2d(vec2 pos) {
+    int i = int(pos.x) % 4;
+    return vec4(LUT[i], LUT[3 - i], float(STEPS[i % STEPS.length()].y), 1.0);
+}
+"#;
+        let output = compile(source, &CompileOptions::default()).expect("compile const array");
+        lpir::validate_module(&output.ir).expect("valid LPIR");
+        assert!(
+            output
+                .ir
+                .functions
+                .values()
+                .all(|f| f.name != "__shader_init")
+        );
+        use lpir::RodataWord::{F32, I32};
+        assert_eq!(
+            output.ir.rodata,
+            [
+                F32(0.0),
+                F32(0.25),
+                F32(0.5),
+                F32(1.0),
+                I32(1),
+                I32(-1),
+                I32(2),
+                I32(2)
+            ]
+        );
+        let render = function(&output, "render_2d");
+        assert_eq!(op_count(render, is_store), 0);
+        assert_eq!(op_count(render, is_load), 3);
+        assert_eq!(
+            op_count(render, |op| matches!(op, lpir::LpirOp::RodataAddr { .. })),
+            3
+        );
+
+        let source = alloc::format!("{}\nvoid poke() {{ LUT[1] = 2.0; }}\n", source.trim());
+        let err = compile(&source, &CompileOptions::default()).expect_err("write to const");
+        assert_eq!(err.message, "cannot write to const variable `LUT`");
+
+        let source = "float t = 1.0;\nconst float K[2] = float[2](t, 2.0);\n";
+        let err = compile(source, &CompileOptions::default()).expect_err("non-constant init");
+        assert_eq!(
+            err.message,
+            "const array initializer must be a constant expression"
+        );
+    }
+
+    #[test]
+    fn const_array_rodata_follows_std430_padding() {
+        let source = r#"
+struct Tap { float weight; vec2 offset; };
+const Tap TAPS[2] = { Tap(0.5, vec2(1.0, -1.0)), Tap(0.25, vec2(2.0)) };
+
+vec2 tap(int i) {
+    return TAPS[i].offset * TAPS[i].weight;
+}
+"#;
+        let output = compile(source, &CompileOptions::default()).expect("compile struct table");
+        lpir::validate_module(&output.ir).expect("valid LPIR");
+        use lpir::RodataWord::{F32, I32};
+        assert_eq!(
+            output.ir.rodata,
+            [
+                F32(0.5),
+                I32(0),
+                F32(1.0),
+                F32(-1.0),
+                F32(0.25),
+                I32(0),
+                F32(2.0),
+                F32(2.0)
+            ]
+        );
+    }
+
     fn compile_with_single_steps(source: &str) -> CompileOutput {
         let mut job = CompileJob::new(source, CompileOptions::default());
         loop {
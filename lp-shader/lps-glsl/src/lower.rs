@@ -12,8 +12,8 @@ use lps_shared::{LpsModuleSig, LpsType, ParamQualifier, TextureBindingSpec};
 
 use crate::body::{BinaryOp, UnaryOp};
 use crate::hir::{
-    ExprId, ExprList, HirArena, HirExprKind, HirFunction, HirModule, HirStmt, ImportKey, PlaceId,
-    scalar_base_type, scalar_ir_types, scalar_lane_count,
+    ExprId, ExprList, HirArena, HirExprKind, HirFunction, HirModule, HirStmt, HirSwitchCase,
+    ImportKey, PlaceId, scalar_base_type, scalar_ir_types, scalar_lane_count,
 };
 use crate::{Diagnostic, Span};
 
@@ -26,9 +26,9 @@ use ops::{
     lower_select, lower_texel_fetch, lower_texture_sample, read_assign_target, single_lane,
 };
 use storage::{
-    LocalStorage, alloc_slot_addr, flat_value_byte_size, is_pointer_param, load_value_from_addr,
-    local_storage, local_value, lower_global_load, lower_uniform_load, param_pointer, store_local,
-    store_value_to_addr,
+    LocalStorage, alloc_slot_addr, const_array_rodata, flat_value_byte_size, is_pointer_param,
+    load_value_from_addr, local_storage, local_value, lower_global_load, lower_rodata_load,
+    lower_uniform_load, param_pointer, store_local, store_value_to_addr,
 };
 
 #[derive(Debug, Clone)]
@@ -39,6 +39,7 @@ pub struct LoweredModule {
 
 pub fn lower_hir(module: HirModule) -> Result<LoweredModule, Diagnostic> {
     let mut mb = ModuleBuilder::new();
+    mb.add_rodata(&const_array_rodata(&module.const_arrays));
     let mut import_map = VecMap::new();
     for import in &module.imports {
         let callee = mb.add_import(ImportDecl {
@@ -121,6 +122,7 @@ fn lower_function(
         param_qualifiers: function.params.iter().map(|p| p.qualifier).collect(),
         texture_specs: &module.texture_specs,
         texel_fetch_bounds: module.texel_fetch_bounds,
+        break_targets: Vec::new(),
     };
     lower_statements(&mut ctx, &function.body.statements)?;
     if function.return_ty == LpsType::Void {
@@ -139,6 +141,15 @@ struct LowerCtx<'a> {
     param_qualifiers: Vec<ParamQualifier>,
     texture_specs: &'a VecMap<String, TextureBindingSpec>,
     texel_fetch_bounds: lpir::TexelFetchBoundsMode,
+    /// Innermost construct a `break` leaves, loops and switches only.
+    break_targets: Vec<BreakTarget>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BreakTarget {
+    Loop,
+    /// The LPIR block wrapping a switch; `break` is an `ExitBlock`.
+    Switch,
 }
 
 #[derive(Debug, Clone)]
@@ -212,7 +223,7 @@ fn lower_stmt(ctx: &mut LowerCtx<'_>, stmt: &HirStmt) -> Result<(), Diagnostic>
             let cond = lower_expr(ctx, *condition)?;
             let cond = single_lane(ctx.arena.expr_span(*condition), &cond)?;
             ctx.fb.push(LpirOp::BrIfNot { cond });
-            lower_statements(ctx, body)?;
+            lower_loop_body(ctx, body)?;
             ctx.fb.push_continuing();
             lower_statements(ctx, continuing)?;
             ctx.fb.end_loop();
@@ -223,14 +234,14 @@ fn lower_stmt(ctx: &mut LowerCtx<'_>, stmt: &HirStmt) -> Result<(), Diagnostic>
             let cond = lower_expr(ctx, *condition)?;
             let cond = single_lane(ctx.arena.expr_span(*condition), &cond)?;
             ctx.fb.push(LpirOp::BrIfNot { cond });
-            lower_statements(ctx, body)?;
+            lower_loop_body(ctx, body)?;
             ctx.fb.push_continuing();
             ctx.fb.end_loop();
             Ok(())
         }
         HirStmt::DoWhile { body, condition } => {
             ctx.fb.push_loop();
-            lower_statements(ctx, body)?;
+            lower_loop_body(ctx, body)?;
             ctx.fb.push_continuing();
             let cond = lower_expr(ctx, *condition)?;
             let cond = single_lane(ctx.arena.expr_span(*condition), &cond)?;
@@ -238,8 +249,12 @@ fn lower_stmt(ctx: &mut LowerCtx<'_>, stmt: &HirStmt) -> Result<(), Diagnostic>
             ctx.fb.end_loop();
             Ok(())
         }
+        HirStmt::Switch { selector, cases } => lower_switch(ctx, *selector, cases),
         HirStmt::Break => {
-            ctx.fb.push(LpirOp::Break);
+            match ctx.break_targets.last() {
+                Some(BreakTarget::Switch) => ctx.fb.push_exit_block(),
+                _ => ctx.fb.push(LpirOp::Break),
+            }
             Ok(())
         }
         HirStmt::Continue => {
@@ -258,6 +273,117 @@ fn lower_stmt(ctx: &mut LowerCtx<'_>, stmt: &HirStmt) -> Result<(), Diagnostic>
     }
 }
 
+fn lower_loop_body(ctx: &mut LowerCtx<'_>, body: &[HirStmt]) -> Result<(), Diagnostic> {
+    ctx.break_targets.push(BreakTarget::Loop);
+    let result = lower_statements(ctx, body);
+    ctx.break_targets.pop();
+    result
+}
+
+/// Lower a switch inside an LPIR block, so `break` can leave it from any depth.
+///
+/// LPIR switch arms do not fall through. When every case but the last ends in
+/// a jump, each case becomes an arm of its own, with `default` moved last as
+/// the backends require. Otherwise an LPIR switch only records which case
+/// matched, and the case bodies follow in source order, each guarded by
+/// `matched <= index`, so control runs on from the matching case until a
+/// `break`.
+fn lower_switch(
+    ctx: &mut LowerCtx<'_>,
+    selector: ExprId,
+    cases: &[HirSwitchCase],
+) -> Result<(), Diagnostic> {
+    let sel = lower_expr(ctx, selector)?;
+    let sel = single_lane(ctx.arena.expr_span(selector), &sel)?;
+    ctx.fb.push_block();
+    ctx.break_targets.push(BreakTarget::Switch);
+    let direct = cases.iter().enumerate().all(|(i, case)| {
+        !case.body.is_empty() && (i + 1 == cases.len() || statements_terminate_block(&case.body))
+    });
+    let result = if direct {
+        lower_switch_arms(ctx, sel, cases)
+    } else {
+        lower_switch_fallthrough(ctx, sel, cases)
+    };
+    ctx.break_targets.pop();
+    result?;
+    ctx.fb.end_block();
+    Ok(())
+}
+
+fn lower_switch_arms(
+    ctx: &mut LowerCtx<'_>,
+    sel: VReg,
+    cases: &[HirSwitchCase],
+) -> Result<(), Diagnostic> {
+    ctx.fb.push_switch(sel);
+    let labelled = cases.iter().filter(|case| case.value.is_some());
+    let default = cases.iter().filter(|case| case.value.is_none());
+    for case in labelled.chain(default) {
+        match case.value {
+            Some(value) => ctx.fb.push_case(value),
+            None => ctx.fb.push_default(),
+        }
+        // The arm ends at the end of the switch anyway.
+        let body = match case.body.split_last() {
+            Some((HirStmt::Break, rest)) => rest,
+            _ => &case.body[..],
+        };
+        lower_statements(ctx, body)?;
+        ctx.fb.end_switch_arm();
+    }
+    ctx.fb.end_switch();
+    Ok(())
+}
+
+fn lower_switch_fallthrough(
+    ctx: &mut LowerCtx<'_>,
+    sel: VReg,
+    cases: &[HirSwitchCase],
+) -> Result<(), Diagnostic> {
+    let no_match = cases
+        .iter()
+        .position(|case| case.value.is_none())
+        .unwrap_or(cases.len());
+    let matched = ctx.fb.alloc_vreg(IrType::I32);
+    ctx.fb.push(LpirOp::IconstI32 {
+        dst: matched,
+        value: no_match as i32,
+    });
+    ctx.fb.push_switch(sel);
+    for (index, case) in cases.iter().enumerate() {
+        if let Some(value) = case.value {
+            ctx.fb.push_case(value);
+            ctx.fb.push(LpirOp::IconstI32 {
+                dst: matched,
+                value: index as i32,
+            });
+            ctx.fb.end_switch_arm();
+        }
+    }
+    ctx.fb.end_switch();
+    for (index, case) in cases.iter().enumerate() {
+        if case.body.is_empty() {
+            continue;
+        }
+        let bound = ctx.fb.alloc_vreg(IrType::I32);
+        ctx.fb.push(LpirOp::IconstI32 {
+            dst: bound,
+            value: index as i32,
+        });
+        let cond = ctx.fb.alloc_vreg(IrType::I32);
+        ctx.fb.push(LpirOp::IleS {
+            dst: cond,
+            lhs: matched,
+            rhs: bound,
+        });
+        ctx.fb.push_if(cond);
+        lower_statements(ctx, &case.body)?;
+        ctx.fb.end_if();
+    }
+    Ok(())
+}
+
 fn return_lanes(
     ctx: &mut LowerCtx<'_>,
     span: Span,
@@ -334,6 +460,9 @@ fn lower_expr(ctx: &mut LowerCtx<'_>, expr: ExprId) -> Result<LowerValue, Diagno
         HirExprKind::Global { byte_offset } => {
             lower_global_load(ctx, expr.span, *byte_offset, &expr.ty)
         }
+        HirExprKind::Rodata { byte_offset } => {
+            lower_rodata_load(ctx, expr.span, *byte_offset, &expr.ty)
+        }
         HirExprKind::Constructor { args } => {
             let mut lanes = Vec::new();
             let args = ctx.arena.expr_list(*args).to_vec();
@@ -637,7 +766,8 @@ fn expr_needs_lazy_eval(arena: &HirArena, expr: ExprId) -> bool {
         | HirExprKind::Param { .. }
         | HirExprKind::Local { .. }
         | HirExprKind::Uniform { .. }
-        | HirExprKind::Global { .. } => false,
+        | HirExprKind::Global { .. }
+        | HirExprKind::Rodata { .. } => false,
         HirExprKind::Constructor { args } => expr_list_needs_lazy_eval(arena, *args),
         HirExprKind::Cast { expr } | HirExprKind::Swizzle { base: expr, .. } => {
             expr_needs_lazy_eval(arena, *expr)
@@ -5,7 +5,8 @@ use crate::{Diagnostic, Span};
 
 use super::super::place::try_read_place_direct;
 use super::super::storage::{
-    is_pointer_param, load_value_from_addr, local_value, lower_global_load, param_pointer,
+    is_pointer_param, load_value_from_addr, local_value, lower_global_load, lower_rodata_load,
+    param_pointer,
 };
 use super::super::{LowerCtx, LowerValue};
 use super::place_project::read_segments;
@@ -35,6 +36,9 @@ pub(super) fn root_value(
         PlaceRoot::Global {
             byte_offset, ty, ..
         } => lower_global_load(ctx, span, *byte_offset, ty),
+        PlaceRoot::Rodata {
+            byte_offset, ty, ..
+        } => lower_rodata_load(ctx, span, *byte_offset, ty),
     }
 }
 
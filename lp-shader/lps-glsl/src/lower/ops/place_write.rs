@@ -54,6 +54,10 @@ fn assign_root(
             "assignment target cannot be a uniform",
         )),
         PlaceRoot::Global { byte_offset, .. } => store_global(ctx, span, *byte_offset, &value),
+        PlaceRoot::Rodata { .. } => Err(Diagnostic::error(
+            span,
+            "assignment target cannot be a const array",
+        )),
     }
 }
 
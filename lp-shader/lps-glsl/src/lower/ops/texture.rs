@@ -309,6 +309,18 @@ fn emit_texel_fetch_vec4_unorm(
             emit_unorm16_channel_load(ctx, texel_addr, 2),
             emit_unorm16_channel_load(ctx, texel_addr, 3),
         ],
+        TextureStorageFormat::R8Unorm => alloc::vec![
+            emit_unorm8_channel_load(ctx, texel_addr, 0),
+            f32_const(ctx, 0.0),
+            f32_const(ctx, 0.0),
+            f32_const(ctx, 1.0),
+        ],
+        TextureStorageFormat::Rgb8Unorm => alloc::vec![
+            emit_unorm8_channel_load(ctx, texel_addr, 0),
+            emit_unorm8_channel_load(ctx, texel_addr, 1),
+            emit_unorm8_channel_load(ctx, texel_addr, 2),
+            f32_const(ctx, 1.0),
+        ],
     }
 }
 
@@ -327,6 +339,29 @@ fn emit_unorm16_channel_load(ctx: &mut LowerCtx<'_>, texel_addr: VReg, channel_i
     converted
 }
 
+/// One unorm8 channel, widened to unorm16 (`raw * 257`) before conversion so
+/// a texel reads exactly as the filtered samplers see it.
+fn emit_unorm8_channel_load(ctx: &mut LowerCtx<'_>, texel_addr: VReg, channel_index: u32) -> VReg {
+    let raw = ctx.fb.alloc_vreg(IrType::I32);
+    ctx.fb.push(LpirOp::Load8U {
+        dst: raw,
+        base: texel_addr,
+        offset: channel_index,
+    });
+    let widened = ctx.fb.alloc_vreg(IrType::I32);
+    ctx.fb.push(LpirOp::ImulImm {
+        dst: widened,
+        src: raw,
+        imm: 257,
+    });
+    let converted = ctx.fb.alloc_vreg(IrType::F32);
+    ctx.fb.push(LpirOp::Unorm16toF {
+        dst: converted,
+        src: widened,
+    });
+    converted
+}
+
 fn spill_f32_q32_lane_as_i32_vreg(ctx: &mut LowerCtx<'_>, f: VReg) -> VReg {
     let slot = ctx.fb.alloc_slot(4);
     let addr = ctx.fb.alloc_vreg(IrType::Pointer);
@@ -10,7 +10,7 @@ pub(super) fn constant_index(expr: &HirExpr) -> Option<usize> {
     }
 }
 
-pub(super) fn scalar_lane_offsets(ty: &lps_shared::LpsType) -> alloc::vec::Vec<u32> {
+pub(in crate::lower) fn scalar_lane_offsets(ty: &lps_shared::LpsType) -> alloc::vec::Vec<u32> {
     use alloc::vec::Vec;
     use lps_shared::LpsType;
     use lps_shared::layout::{array_stride, round_up, type_alignment, type_size};
@@ -4,5 +4,6 @@ mod path;
 mod read;
 mod write;
 
+pub(super) use layout::scalar_lane_offsets;
 pub(super) use read::try_read_place_direct;
 pub(super) use write::try_assign_place_direct;
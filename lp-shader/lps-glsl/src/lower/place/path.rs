@@ -6,7 +6,7 @@ use lps_shared::LpsType;
 use crate::hir::{PlaceRoot, PlaceSegment, TypeShape};
 use crate::{Diagnostic, Span};
 
-use super::super::storage::{LocalStorage, is_pointer_param, param_pointer};
+use super::super::storage::{LocalStorage, is_pointer_param, param_pointer, rodata_addr};
 use super::super::{LowerCtx, lower_expr};
 use super::dynamic;
 use super::layout::{constant_index, scalar_lane_offsets};
@@ -107,6 +107,15 @@ fn root_place(
             static_offset: *byte_offset,
             dynamic_offset: None,
         })),
+        PlaceRoot::Rodata {
+            byte_offset, ty, ..
+        } => Some(LoweredPlace::Memory(MemoryPlace {
+            lane_offsets: scalar_lane_offsets(ty),
+            ty: ty.clone(),
+            base: rodata_addr(ctx),
+            static_offset: *byte_offset,
+            dynamic_offset: None,
+        })),
     })
 }
 
@@ -1,12 +1,13 @@
 use alloc::format;
 use alloc::vec::Vec;
 
-use lpir::{FunctionBuilder, IrType, LpirOp, SlotId, VReg};
+use lpir::{FunctionBuilder, IrType, LpirOp, RodataWord, SlotId, VReg};
 use lps_shared::{LpsType, ParamQualifier};
 
-use crate::hir::{scalar_ir_types, scalar_lane_count};
+use crate::hir::{ConstArray, scalar_ir_types, scalar_lane_count};
 use crate::{Diagnostic, Span};
 
+use super::place::scalar_lane_offsets;
 use super::{LowerCtx, LowerValue};
 
 #[derive(Debug, Clone)]
@@ -124,6 +125,55 @@ pub(super) fn lower_global_load(
     lower_uniform_load(ctx, span, byte_offset, ty)
 }
 
+/// Module rodata image: each `const` array's lanes at their std430 offsets.
+pub(super) fn const_array_rodata(arrays: &[ConstArray]) -> Vec<RodataWord> {
+    let mut words = Vec::new();
+    for array in arrays {
+        for (lane, offset) in array.lanes.iter().zip(scalar_lane_offsets(&array.ty)) {
+            let word = (array.byte_offset + offset) as usize / 4;
+            if words.len() <= word {
+                words.resize(word + 1, RodataWord::I32(0));
+            }
+            words[word] = *lane;
+        }
+    }
+    words
+}
+
+pub(super) fn rodata_addr(ctx: &mut LowerCtx<'_>) -> VReg {
+    let dst = ctx.fb.alloc_vreg(IrType::Pointer);
+    ctx.fb.push(LpirOp::RodataAddr { dst });
+    dst
+}
+
+pub(super) fn lower_rodata_load(
+    ctx: &mut LowerCtx<'_>,
+    span: Span,
+    byte_offset: u32,
+    ty: &LpsType,
+) -> Result<LowerValue, Diagnostic> {
+    let ir_types = scalar_ir_types(ty)?;
+    let offsets = scalar_lane_offsets(ty);
+    if ir_types.len() != offsets.len() {
+        return Err(Diagnostic::error(span, "const array lane count mismatch"));
+    }
+    let base = rodata_addr(ctx);
+    let mut lanes = Vec::new();
+    for (ir_ty, offset) in ir_types.iter().zip(offsets) {
+        let dst = ctx.fb.alloc_vreg(*ir_ty);
+        ctx.fb.push(LpirOp::Load {
+            dst,
+            base,
+            offset: byte_offset.saturating_add(offset),
+        });
+        lanes.push(dst);
+    }
+    Ok(LowerValue {
+        ty: ty.clone(),
+        lanes,
+    })
+}
+
 pub(super) fn store_global(
     ctx: &mut LowerCtx<'_>,
     span: Span,
@@ -0,0 +1,900 @@
+//! GLSL preprocessing: macros, conditional groups and `#include`.
+//!
+//! [`preprocess`] runs ahead of [`compile`](crate::compile) and produces the
+//! expanded source the rest of the pipeline sees, plus a [`SourceMap`] that
+//! points each expanded line back at the file and line it was authored on.
+//! Sources without directives come out unchanged, line for line.
+//!
+//! Supported:
+//!
+//! - object-like and function-like `#define`, and `#undef`
+//! - `#if`, `#ifdef`, `#ifndef`, `#elif`, `#else`, `#endif`, with `defined`
+//!   and the C integer operators in `#if`/`#elif` expressions
+//! - `#include "path"`, loaded through an [`IncludeResolver`], and
+//!   `#pragma once`
+//! - `#error`
+//! - `__LINE__` and `__FILE__` (the file's index in the map)
+//!
+//! `#version`, `#extension` and other `#pragma`s are accepted and dropped.
+//! Not supported: `#line` and token pasting (`##`).
+//!
+//! Every input line yields exactly one output line, except an `#include`,
+//! which yields the included file's lines instead. Directive lines and lines
+//! in skipped groups become empty lines, so spans in the expanded source stay
+//! on their authored line; only columns on lines a macro expanded can move.
+//! A macro invocation whose argument list spans lines expands onto its first
+//! line, and the lines it consumed become empty.
+
+mod expand;
+mod expr;
+#[cfg(feature = "lpfs")]
+mod lpfs_include;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use lp_collection::{VecMap, VecSet};
+
+use crate::source::LineOrigin;
+use crate::{Diagnostic, SourceMap, Span};
+
+use expand::{Macro, TokKind, tokenize};
+
+#[cfg(feature = "lpfs")]
+pub use lpfs_include::LpFsIncludes;
+
+/// Deepest `#include` nesting accepted.
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Loads the files named by `#include`.
+pub trait IncludeResolver {
+    /// Load `path`, as written in an `#include` in the file named `includer`.
+    ///
+    /// The returned name identifies the file for diagnostics, `#pragma once`
+    /// and cycle detection, so two spellings of one file must resolve to the
+    /// same name. The error is reported against the `#include` line.
+    fn resolve(&mut self, includer: &str, path: &str) -> Result<IncludedSource, String>;
+}
+
+/// A file loaded for an `#include`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncludedSource {
+    pub name: String,
+    pub text: String,
+}
+
+/// Resolver for contexts without a filesystem: every `#include` fails.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoIncludes;
+
+impl IncludeResolver for NoIncludes {
+    fn resolve(&mut self, _includer: &str, _path: &str) -> Result<IncludedSource, String> {
+        Err(String::from("#include is not available here"))
+    }
+}
+
+/// Preprocessor output: the source to compile and where its lines came from.
+#[derive(Debug, Clone)]
+pub struct Preprocessed {
+    pub source: String,
+    pub map: SourceMap,
+}
+
+impl Preprocessed {
+    /// Render a diagnostic from compiling [`Self::source`] at its authored
+    /// file and line.
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        diagnostic.render_mapped(&self.source, &self.map)
+    }
+
+    /// Every file pulled in by `#include`, in first-include order.
+    pub fn included_files(&self) -> &[String] {
+        self.map.files().get(1..).unwrap_or(&[])
+    }
+}
+
+/// A preprocessing failure, located in the file that caused it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreprocessError {
+    pub file: String,
+    pub diagnostic: Diagnostic,
+    /// Text of `file`, which `diagnostic`'s span indexes.
+    file_text: String,
+}
+
+impl PreprocessError {
+    pub fn render(&self) -> String {
+        self.diagnostic.render_mapped(
+            &self.file_text,
+            &SourceMap::named(&self.file_text, &self.file),
+        )
+    }
+}
+
+impl fmt::Display for PreprocessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.file, self.diagnostic)
+    }
+}
+
+/// Preprocess `source`, the file named `name`, loading includes through
+/// `includes`.
+pub fn preprocess(
+    source: &str,
+    name: &str,
+    includes: &mut dyn IncludeResolver,
+) -> Result<Preprocessed, PreprocessError> {
+    let mut pp = Preprocessor {
+        includes,
+        macros: VecMap::new(),
+        once: VecSet::new(),
+        stack: Vec::new(),
+        files: Vec::new(),
+        origins: Vec::new(),
+        out: String::with_capacity(source.len()),
+    };
+    pp.run_file(name, source)?;
+    let map = SourceMap::with_origins(&pp.out, pp.files, pp.origins);
+    Ok(Preprocessed {
+        source: pp.out,
+        map,
+    })
+}
+
+struct Preprocessor<'r> {
+    includes: &'r mut dyn IncludeResolver,
+    macros: VecMap<String, Macro>,
+    /// Files that said `#pragma once`.
+    once: VecSet<String>,
+    /// The include chain, root first.
+    stack: Vec<String>,
+    files: Vec<String>,
+    origins: Vec<LineOrigin>,
+    out: String,
+}
+
+/// The file being processed.
+struct FileCtx<'t> {
+    name: &'t str,
+    text: &'t str,
+    index: u16,
+}
+
+impl FileCtx<'_> {
+    fn error(&self, span: Span, message: impl Into<String>) -> PreprocessError {
+        PreprocessError {
+            file: String::from(self.name),
+            diagnostic: Diagnostic::error(span, message),
+            file_text: String::from(self.text),
+        }
+    }
+}
+
+/// One open `#if` group.
+struct Cond {
+    span: Span,
+    /// Whether the enclosing group is active.
+    parent_active: bool,
+    /// Whether some branch of this group has been taken.
+    taken: bool,
+    seen_else: bool,
+    active: bool,
+}
+
+impl Preprocessor<'_> {
+    fn run_file(&mut self, name: &str, text: &str) -> Result<(), PreprocessError> {
+        let index = match self.files.iter().position(|f| f == name) {
+            Some(i) => i,
+            None => {
+                self.files.push(String::from(name));
+                self.files.len() - 1
+            }
+        };
+        let ctx = FileCtx {
+            name,
+            text,
+            index: u16::try_from(index).map_err(|_| {
+                FileCtx {
+                    name,
+                    text,
+                    index: 0,
+                }
+                .error(Span::new(0, 0), "too many included files")
+            })?,
+        };
+        self.stack.push(String::from(name));
+
+        let lines = line_spans(text);
+        let mut conds: Vec<Cond> = Vec::new();
+        let mut in_comment = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let (start, end) = lines[i];
+            let line = &text[start..end];
+            let active = conds.last().is_none_or(|c| c.active);
+            if !in_comment && line.trim_start().starts_with('#') {
+                // Splice `\`-continued lines into one directive.
+                let mut last = i;
+                let mut joined = String::from(line);
+                while joined.ends_with('\\') && last + 1 < lines.len() {
+                    joined.pop();
+                    last += 1;
+                    joined.push_str(&text[lines[last].0..lines[last].1]);
+                }
+                let span = Span::new(
+                    start + (line.len() - line.trim_start().len()),
+                    lines[last].1,
+                );
+                let directive = strip_comments(&joined, &mut in_comment);
+                let included = self.directive(&ctx, &directive, span, i + 1, active, &mut conds)?;
+                if !included {
+                    for line_idx in i..=last {
+                        self.push_line(&ctx, line_idx + 1, "");
+                    }
+                }
+                i = last + 1;
+                continue;
+            }
+            if active {
+                i = self.expand_invocation_lines(&ctx, &lines, i, &mut in_comment)?;
+            } else {
+                strip_comments(line, &mut in_comment);
+                self.push_line(&ctx, i + 1, "");
+            }
+            i += 1;
+        }
+        if let Some(open) = conds.last() {
+            return Err(ctx.error(open.span, "unterminated conditional: missing #endif"));
+        }
+        self.stack.pop();
+        Ok(())
+    }
+
+    /// Expand the text line at `first`, joining the lines after it while a
+    /// macro invocation's argument list is still open. The expansion lands on
+    /// `first`; the joined lines stay empty. Returns the last line consumed.
+    fn expand_invocation_lines(
+        &mut self,
+        ctx: &FileCtx<'_>,
+        lines: &[(usize, usize)],
+        first: usize,
+        in_comment: &mut bool,
+    ) -> Result<usize, PreprocessError> {
+        let (start, end) = lines[first];
+        let line = &ctx.text[start..end];
+        let mut comment = *in_comment;
+        let mut result = self.expand_text_line(line, &mut comment, first + 1, ctx.index);
+        let mut last = first;
+        while let Err(err) = &result
+            && err.unterminated
+            && let Some(&(next_start, next_end)) = lines.get(last + 1)
+            && !ctx.text[next_start..next_end].trim_start().starts_with('#')
+        {
+            // Comments go, so a `//` on one line cannot swallow the next.
+            last += 1;
+            comment = *in_comment;
+            let mut joined = strip_comments(line, &mut comment);
+            for &(s, e) in &lines[first + 1..=last] {
+                joined.push(' ');
+                joined.push_str(&strip_comments(&ctx.text[s..e], &mut comment));
+            }
+            let mut joined_comment = false;
+            result = self.expand_text_line(&joined, &mut joined_comment, first + 1, ctx.index);
+        }
+        let expanded = result.map_err(|err| {
+            // Offsets past the first line point into joined text.
+            let at = start + err.at.min(line.len());
+            ctx.error(Span::new(at, at + 1), err.message)
+        })?;
+        *in_comment = comment;
+        self.push_line(ctx, first + 1, &expanded);
+        for line_idx in first + 1..=last {
+            self.push_line(ctx, line_idx + 1, "");
+        }
+        Ok(last)
+    }
+
+    fn push_line(&mut self, ctx: &FileCtx<'_>, line: usize, text: &str) {
+        self.out.push_str(text);
+        self.out.push('\n');
+        self.origins.push(LineOrigin {
+            file: ctx.index,
+            line: line as u32,
+        });
+    }
+
+    /// Handle one directive. Returns whether it was an `#include` whose
+    /// lines replaced it in the output.
+    fn directive(
+        &mut self,
+        ctx: &FileCtx<'_>,
+        directive: &str,
+        span: Span,
+        line: usize,
+        active: bool,
+        conds: &mut Vec<Cond>,
+    ) -> Result<bool, PreprocessError> {
+        let body = directive.trim_start().trim_start_matches('#').trim_start();
+        let name_len = body
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+        let (name, rest) = (&body[..name_len], body[name_len..].trim());
+
+        match name {
+            "if" | "ifdef" | "ifndef" => {
+                let taken = active && self.condition(ctx, name, rest, span, line)?;
+                conds.push(Cond {
+                    span,
+                    parent_active: active,
+                    taken,
+                    seen_else: false,
+                    active: taken,
+                });
+                return Ok(false);
+            }
+            "elif" | "else" => {
+                let Some(cond) = conds.last_mut() else {
+                    return Err(ctx.error(span, format!("#{name} without #if")));
+                };
+                if cond.seen_else {
+                    return Err(ctx.error(span, format!("#{name} after #else")));
+                }
+                let take = if name == "else" {
+                    cond.seen_else = true;
+                    cond.parent_active && !cond.taken
+                } else {
+                    let (parent_active, taken) = (cond.parent_active, cond.taken);
+                    parent_active && !taken && self.condition(ctx, "if", rest, span, line)?
+                };
+                let cond = conds.last_mut().expect("checked above");
+                cond.active = take;
+                cond.taken |= take;
+                return Ok(false);
+            }
+            "endif" => {
+                if conds.pop().is_none() {
+                    return Err(ctx.error(span, "#endif without #if"));
+                }
+                return Ok(false);
+            }
+            _ => {}
+        }
+        if !active {
+            return Ok(false);
+        }
+
+        match name {
+            "" if rest.is_empty() => {}
+            "define" => self.define(ctx, rest, span)?,
+            "undef" => {
+                let Some(macro_name) = single_ident(rest) else {
+                    return Err(ctx.error(span, "expected a macro name after #undef"));
+                };
+                self.macros.remove(macro_name);
+            }
+            "include" => return self.include(ctx, rest, span).map(|()| true),
+            "pragma" => {
+                if rest == "once" {
+                    self.once.insert(String::from(ctx.name));
+                }
+            }
+            "version" | "extension" => {}
+            "error" => return Err(ctx.error(span, format!("#error {rest}"))),
+            "line" => return Err(ctx.error(span, "#line is not supported")),
+            _ => {
+                return Err(ctx.error(
+                    span,
+                    format!(
+                        "unknown preprocessor directive `#{}`",
+                        body.split_whitespace().next().unwrap_or("")
+                    ),
+                ));
+            }
+        }
+        Ok(false)
+    }
+
+    fn condition(
+        &self,
+        ctx: &FileCtx<'_>,
+        kind: &str,
+        rest: &str,
+        span: Span,
+        line: usize,
+    ) -> Result<bool, PreprocessError> {
+        if kind != "if" {
+            let Some(name) = single_ident(rest) else {
+                return Err(ctx.error(span, format!("expected a macro name after #{kind}")));
+            };
+            return Ok(self.macros.contains_key(name) == (kind == "ifdef"));
+        }
+        if rest.is_empty() {
+            return Err(ctx.error(span, "expected an expression after #if"));
+        }
+        let tokens = self
+            .resolve_defined(rest)
+            .map_err(|message| ctx.error(span, message))?;
+        let expanded = self
+            .expand_tokens(tokens, line, ctx.index)
+            .map_err(|err| ctx.error(span, err.message))?;
+        let value = expr::evaluate(&expanded).map_err(|message| ctx.error(span, message))?;
+        Ok(value != 0)
+    }
+
+    fn define(&mut self, ctx: &FileCtx<'_>, rest: &str, span: Span) -> Result<(), PreprocessError> {
+        let name_len = rest
+            .bytes()
+            .take_while(|b| b.is_ascii_alphanumeric() || *b == b'_')
+            .count();
+        let name = &rest[..name_len];
+        if name.is_empty() || name.as_bytes()[0].is_ascii_digit() {
+            return Err(ctx.error(span, "expected a macro name after #define"));
+        }
+        if matches!(name, "defined" | "__LINE__" | "__FILE__") {
+            return Err(ctx.error(span, format!("`{name}` cannot be redefined")));
+        }
+        let mut after = &rest[name_len..];
+        let params = if let Some(list) = after.strip_prefix('(') {
+            let Some(close) = list.find(')') else {
+                return Err(ctx.error(span, format!("missing `)` in parameter list of `{name}`")));
+            };
+            let mut params: Vec<String> = Vec::new();
+            if !list[..close].trim().is_empty() {
+                for param in list[..close].split(',') {
+                    let Some(param) = single_ident(param) else {
+                        return Err(
+                            ctx.error(span, format!("malformed parameter list of `{name}`"))
+                        );
+                    };
+                    if params.iter().any(|p| p == param) {
+                        return Err(ctx.error(span, format!("duplicate macro parameter `{param}`")));
+                    }
+                    params.push(String::from(param));
+                }
+            }
+            after = &list[close + 1..];
+            Some(params)
+        } else {
+            if after.starts_with(|c: char| !c.is_ascii_whitespace()) {
+                return Err(ctx.error(span, "expected whitespace after the macro name"));
+            }
+            None
+        };
+        let body = after.trim();
+        if body.contains("##") {
+            return Err(ctx.error(span, "token pasting (`##`) is not supported"));
+        }
+        let mac = Macro {
+            params,
+            body: String::from(body),
+        };
+        if let Some(existing) = self.macros.get(name)
+            && !existing.same_definition(&mac)
+        {
+            return Err(ctx.error(span, format!("macro `{name}` redefined differently")));
+        }
+        self.macros.insert(String::from(name), mac);
+        Ok(())
+    }
+
+    fn include(
+        &mut self,
+        ctx: &FileCtx<'_>,
+        rest: &str,
+        span: Span,
+    ) -> Result<(), PreprocessError> {
+        let path = match rest.strip_prefix('"').and_then(|r| r.split_once('"')) {
+            Some((path, tail)) if !path.is_empty() && tail.trim().is_empty() => path,
+            _ if rest.starts_with('<') => {
+                return Err(ctx.error(span, "only quoted #include paths are supported"));
+            }
+            _ => return Err(ctx.error(span, "expected #include \"path\"")),
+        };
+        if self.stack.len() >= MAX_INCLUDE_DEPTH {
+            return Err(ctx.error(span, "#include nested too deeply"));
+        }
+        let included = self
+            .includes
+            .resolve(ctx.name, path)
+            .map_err(|message| ctx.error(span, format!("cannot include \"{path}\": {message}")))?;
+        if self.once.contains(included.name.as_str()) {
+            return Ok(());
+        }
+        if self.stack.iter().any(|f| *f == included.name) {
+            let mut chain = self.stack.join(" -> ");
+            chain.push_str(" -> ");
+            chain.push_str(&included.name);
+            return Err(ctx.error(span, format!("#include cycle: {chain}")));
+        }
+        self.run_file(&included.name, &included.text)
+    }
+
+    /// Replace `defined NAME` and `defined(NAME)` with `1` or `0`.
+    fn resolve_defined<'a>(&self, text: &'a str) -> Result<Vec<expand::Tok<'a>>, String> {
+        let mut tokens = tokenize(text);
+        let mut out = Vec::with_capacity(tokens.len());
+        let mut iter = tokens.drain(..);
+        while let Some(tok) = iter.next() {
+            if tok.kind != TokKind::Ident || tok.text != "defined" {
+                out.push(tok);
+                continue;
+            }
+            let mut significant = iter.by_ref().filter(|t| t.kind != TokKind::Space);
+            let name = match significant.next() {
+                Some(t) if t.kind == TokKind::Ident => t,
+                Some(t) if t.text == "(" => {
+                    let name = significant.next().filter(|t| t.kind == TokKind::Ident);
+                    let close = significant.next();
+                    match (name, close) {
+                        (Some(name), Some(close)) if close.text == ")" => name,
+                        _ => return Err(String::from("expected `defined(NAME)`")),
+                    }
+                }
+                _ => return Err(String::from("expected a macro name after `defined`")),
+            };
+            let value = if self.macros.contains_key(&*name.text) {
+                "1"
+            } else {
+                "0"
+            };
+            out.push(expand::Tok::number(value));
+        }
+        Ok(out)
+    }
+}
+
+/// Byte bounds of each line of `text`, without the line terminator.
+fn line_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (i, b) in text.bytes().enumerate() {
+        if b == b'\n' {
+            let end = if i > start && text.as_bytes()[i - 1] == b'\r' {
+                i - 1
+            } else {
+                i
+            };
+            lines.push((start, end));
+            start = i + 1;
+        }
+    }
+    if start < text.len() {
+        lines.push((start, text.len()));
+    }
+    lines
+}
+
+/// `line` with each comment replaced by one space. `in_comment` carries an
+/// open block comment from one line to the next.
+fn strip_comments(line: &str, in_comment: &mut bool) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    loop {
+        if *in_comment {
+            match rest.find("*/") {
+                Some(end) => {
+                    rest = &rest[end + 2..];
+                    *in_comment = false;
+                    out.push(' ');
+                }
+                None => return out,
+            }
+        }
+        match comment_start(rest) {
+            Some((at, true)) => {
+                out.push_str(&rest[..at]);
+                out.push(' ');
+                return out;
+            }
+            Some((at, false)) => {
+                out.push_str(&rest[..at]);
+                rest = &rest[at + 2..];
+                *in_comment = true;
+            }
+            None => {
+                out.push_str(rest);
+                return out;
+            }
+        }
+    }
+}
+
+/// Offset of the first `//` or `/*` in `text`, and whether it is `//`.
+fn comment_start(text: &str) -> Option<(usize, bool)> {
+    let bytes = text.as_bytes();
+    (0..bytes.len().saturating_sub(1)).find_map(|i| match (bytes[i], bytes[i + 1]) {
+        (b'/', b'/') => Some((i, true)),
+        (b'/', b'*') => Some((i, false)),
+        _ => None,
+    })
+}
+
+/// `text` as a single identifier, trimmed.
+fn single_ident(text: &str) -> Option<&str> {
+    let text = text.trim();
+    let mut bytes = text.bytes();
+    let first = bytes.next()?;
+    ((first.is_ascii_alphabetic() || first == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'_'))
+    .then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CompileOptions, compile};
+
+    /// Resolver over a fixed set of `(name, text)` files, by exact name.
+    struct Files(&'static [(&'static str, &'static str)]);
+
+    impl IncludeResolver for Files {
+        fn resolve(&mut self, _includer: &str, path: &str) -> Result<IncludedSource, String> {
+            let (name, text) = self
+                .0
+                .iter()
+                .find(|(name, _)| *name == path)
+                .ok_or_else(|| String::from("file not found"))?;
+            Ok(IncludedSource {
+                name: String::from(*name),
+                text: String::from(*text),
+            })
+        }
+    }
+
+    fn expand(source: &str) -> String {
+        preprocess(source, "main.glsl", &mut NoIncludes)
+            .unwrap_or_else(|e| panic!("{}", e.render()))
+            .source
+    }
+
+    fn error(source: &str, files: &'static [(&'static str, &'static str)]) -> PreprocessError {
+        preprocess(source, "main.glsl", &mut Files(files)).expect_err("expected an error")
+    }
+
+    #[test]
+    fn source_without_directives_is_unchanged() {
+        let source = "float f(float x) {\n    return x; // done\n}\n";
+        let out = preprocess(source, "main.glsl", &mut NoIncludes).expect("preprocess");
+        assert_eq!(out.source, source);
+        assert_eq!(out.map.files(), ["main.glsl"]);
+        assert!(out.included_files().is_empty());
+    }
+
+    #[test]
+    fn directives_become_empty_lines() {
+        let out = expand("#version 300 es\n#define A 1\nint a = A;\n");
+        assert_eq!(out, "\n\nint a = 1;\n");
+    }
+
+    #[test]
+    fn expands_object_and_function_like_macros() {
+        let out = expand(
+            "#define SCALE 2.0\n\
+             #define MUL(a, b) ((a) * (b))\n\
+             #define TWICE(x) MUL(x, SCALE)\n\
+             float y = TWICE(v.x + 1.0) + MUL(f(1, 2), 3);\n",
+        );
+        assert_eq!(
+            out.lines().nth(3),
+            Some("float y = ((v.x + 1.0) * (2.0)) + ((f(1, 2)) * (3));")
+        );
+    }
+
+    #[test]
+    fn self_reference_and_bare_function_names_stay_put() {
+        let out = expand(
+            "#define x x + 1\n\
+             #define f(a) a\n\
+             int y = x; int (*g) = f;\n",
+        );
+        assert_eq!(out.lines().nth(2), Some("int y = x + 1; int (*g) = f;"));
+    }
+
+    #[test]
+    fn expansion_does_not_paste_tokens() {
+        let out = expand("#define NEG -1\nint a = -NEG;\n");
+        assert_eq!(out.lines().nth(1), Some("int a = - -1;"));
+    }
+
+    #[test]
+    fn comments_are_not_expanded() {
+        let out = expand("#define A 1\nint a = A; /* A\nA */ // A\n");
+        assert_eq!(out, "\nint a = 1; /* A\nA */ // A\n");
+    }
+
+    #[test]
+    fn conditionals_select_one_branch() {
+        let out = expand(
+            "#define LEVEL 2\n\
+             #if LEVEL > 2 || defined(MISSING)\n\
+             high\n\
+             #elif LEVEL == 2 && defined LEVEL\n\
+             two\n\
+             #else\n\
+             low\n\
+             #endif\n\
+             #ifndef LEVEL\n\
+             #unknown directives are skipped here\n\
+             #endif\n",
+        );
+        assert_eq!(
+            out.lines().filter(|l| !l.is_empty()).collect::<Vec<_>>(),
+            ["two"]
+        );
+    }
+
+    #[test]
+    fn continued_define_spans_lines() {
+        let out = expand("#define SUM(a, b) \\\n    ((a) + (b))\nint c = SUM(1, 2);\n");
+        assert_eq!(out, "\n\nint c = ((1) + (2));\n");
+    }
+
+    #[test]
+    fn if_rejects_undefined_identifiers() {
+        let err = error("#if UNDEFINED\n#endif\n", &[]);
+        assert!(
+            err.diagnostic
+                .message
+                .contains("undefined identifier `UNDEFINED`")
+        );
+    }
+
+    #[test]
+    fn unterminated_conditional_points_at_its_if() {
+        let err = error("int a;\n#ifdef A\nint b;\n", &[]);
+        assert!(err.render().contains("--> main.glsl:2:1"));
+    }
+
+    #[test]
+    fn conflicting_redefinition_is_an_error() {
+        let err = error("#define A 1\n#define A  1\n#define A 2\n", &[]);
+        assert!(err.diagnostic.message.contains("redefined"));
+        assert!(err.render().contains("--> main.glsl:3:1"));
+    }
+
+    #[test]
+    fn wrong_argument_count_is_an_error() {
+        let err = error("#define F(a, b) a\nint x = F(1);\n", &[]);
+        assert!(err.render().contains("--> main.glsl:2:9"));
+        assert!(
+            err.diagnostic
+                .message
+                .contains("takes 2 argument(s), got 1")
+        );
+    }
+
+    const LIB: &[(&str, &str)] = &[
+        (
+            "lib/noise.glsl",
+            "#pragma once\n#include \"lib/hash.glsl\"\nfloat noise(float x) {\n    return hash(x);\n}\n",
+        ),
+        (
+            "lib/hash.glsl",
+            "#ifndef HASH_GLSL\n#define HASH_GLSL\nfloat hash(float x) {\n    return fract(x * 7.0);\n}\n#endif\n",
+        ),
+        (
+            "lib/broken.glsl",
+            "float broken() {\n    return undefined_name;\n}\n",
+        ),
+        ("lib/error.glsl", "\n#error library misconfigured\n"),
+        ("lib/a.glsl", "#include \"lib/b.glsl\"\n"),
+        ("lib/b.glsl", "#include \"lib/a.glsl\"\n"),
+    ];
+
+    #[test]
+    fn includes_expand_once_and_compile() {
+        let source = "#include \"lib/noise.glsl\"\n\
+                      #include \"lib/noise.glsl\"\n\
+                      #include \"lib/hash.glsl\"\n\
+                      vec4 render_2d(vec2 pos) {\n    return vec4(noise(pos.x));\n}\n";
+        let out = preprocess(source, "main.glsl", &mut Files(LIB)).expect("preprocess");
+        assert_eq!(out.included_files(), ["lib/noise.glsl", "lib/hash.glsl"]);
+        assert_eq!(out.source.matches("float hash(").count(), 1);
+        compile(&out.source, &CompileOptions::default())
+            .unwrap_or_else(|e| panic!("{}", out.render(&e)));
+    }
+
+    #[test]
+    fn compile_errors_map_to_the_included_file() {
+        let source = "#include \"lib/broken.glsl\"\n\
+                      vec4 render_2d(vec2 pos) {\n    return vec4(broken());\n}\n";
+        let out = preprocess(source, "main.glsl", &mut Files(LIB)).expect("preprocess");
+        let err = compile(&out.source, &CompileOptions::default()).expect_err("compile error");
+        let rendered = out.render(&err);
+        assert!(rendered.contains("--> lib/broken.glsl:2:12"), "{rendered}");
+        assert!(
+            rendered.contains(" 2 |     return undefined_name;"),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn compile_errors_after_an_include_keep_their_line() {
+        let source = "#include \"lib/hash.glsl\"\n\
+                      vec4 render_2d(vec2 pos) {\n    return vec4(missing);\n}\n";
+        let out = preprocess(source, "main.glsl", &mut Files(LIB)).expect("preprocess");
+        let err = compile(&out.source, &CompileOptions::default()).expect_err("compile error");
+        assert!(out.render(&err).contains("--> main.glsl:3:17"));
+    }
+
+    #[test]
+    fn include_errors_name_the_failing_file() {
+        let err = error("#include \"lib/error.glsl\"\n", LIB);
+        assert_eq!(err.file, "lib/error.glsl");
+        assert!(err.render().contains("--> lib/error.glsl:2:1"));
+        assert!(err.diagnostic.message.contains("library misconfigured"));
+
+        let err = error("\n#include \"lib/missing.glsl\"\n", LIB);
+        assert!(err.render().contains("--> main.glsl:2:1"));
+        assert!(err.diagnostic.message.contains("file not found"));
+
+        let err = error("#include \"lib/a.glsl\"\n", LIB);
+        assert!(
+            err.diagnostic
+                .message
+                .contains("main.glsl -> lib/a.glsl -> lib/b.glsl -> lib/a.glsl")
+        );
+    }
+
+    #[test]
+    fn macros_reach_the_compiled_shader() {
+        let source = "#define STEPS 4\n\
+                      #define BRIGHT(c) ((c) * 2.0)\n\
+                      vec4 render_2d(vec2 pos) {\n\
+                      \x20   float acc = 0.0;\n\
+                      #if STEPS > 2\n\
+                      \x20   for (int i = 0; i < STEPS; i++) { acc += BRIGHT(pos.x); }\n\
+                      #endif\n\
+                      \x20   return vec4(acc);\n\
+                      }\n";
+        let out = expand(source);
+        compile(&out, &CompileOptions::default()).expect("compile");
+    }
+
+    #[cfg(feature = "lpfs")]
+    #[test]
+    fn lpfs_includes_resolve_next_to_the_includer_then_from_the_root() {
+        use lpfs::{LpFsMemory, LpPath};
+
+        let mut fs = LpFsMemory::new();
+        for (path, text) in [
+            ("/lib/noise.glsl", "float noise(float x) { return x; }\n"),
+            (
+                "/shaders/local.glsl",
+                "float local(float x) { return x; }\n",
+            ),
+            (
+                "/shaders/main.glsl",
+                "#include \"local.glsl\"\n#include \"lib/noise.glsl\"\n#include \"../lib/noise.glsl\"\n",
+            ),
+        ] {
+            fs.write_file_mut(LpPath::new(path), text.as_bytes())
+                .expect("write");
+        }
+        let out = preprocess(
+            "#pragma once\n#include \"/shaders/main.glsl\"\n",
+            "/root.glsl",
+            &mut LpFsIncludes::new(&fs),
+        )
+        .expect("preprocess");
+        assert_eq!(
+            out.included_files(),
+            [
+                "/shaders/main.glsl",
+                "/shaders/local.glsl",
+                "/lib/noise.glsl"
+            ]
+        );
+
+        let err = preprocess(
+            "#include \"../../outside.glsl\"\n",
+            "/shaders/main.glsl",
+            &mut LpFsIncludes::new(&fs),
+        )
+        .expect_err("outside the root");
+        assert!(err.diagnostic.message.contains("leaves the project root"));
+    }
+}
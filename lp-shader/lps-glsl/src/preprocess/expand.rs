@@ -0,0 +1,574 @@
+//! Macro expansion over preprocessing tokens.
+//!
+//! Expansion follows the C rescanning rules, minus `#` and `##`: arguments are
+//! fully expanded before substitution, the replacement is rescanned together
+//! with the tokens after it, and each token carries the set of macros whose
+//! expansion produced it, which it may not expand again.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::{Preprocessor, comment_start};
+
+/// Most tokens one line's expansion may produce.
+const MAX_EXPANSION_TOKENS: usize = 16 * 1024;
+
+/// Why a line failed to expand.
+#[derive(Debug)]
+pub(super) struct ExpandError {
+    /// Byte offset in the line of the invocation at fault.
+    pub at: usize,
+    pub message: String,
+    /// The text ended inside a macro's argument list, so more lines may
+    /// complete the invocation.
+    pub unterminated: bool,
+}
+
+impl ExpandError {
+    fn new(at: usize, message: String) -> Self {
+        Self {
+            at,
+            message,
+            unterminated: false,
+        }
+    }
+}
+
+pub(super) struct Macro {
+    /// Parameter names; `None` for an object-like macro.
+    pub params: Option<Vec<String>>,
+    pub body: String,
+}
+
+impl Macro {
+    /// Whether redefining `self` as `other` is benign: same parameters and
+    /// the same body, up to the amount of whitespace.
+    pub fn same_definition(&self, other: &Macro) -> bool {
+        self.params == other.params
+            && self
+                .body
+                .split_whitespace()
+                .eq(other.body.split_whitespace())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TokKind {
+    Space,
+    Ident,
+    Number,
+    Punct,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Tok<'a> {
+    pub kind: TokKind,
+    pub text: Cow<'a, str>,
+    /// Byte offset in the line of the text this token came from; tokens of a
+    /// replacement take the offset of the invocation.
+    pub at: usize,
+    /// Macros this token may no longer expand.
+    hide: Vec<&'a str>,
+    /// First or last token of an expansion or a substituted argument, so a
+    /// neighbour on one side was not adjacent to it in the source.
+    edge: bool,
+}
+
+impl<'a> Tok<'a> {
+    pub fn number(text: &'a str) -> Self {
+        Self {
+            kind: TokKind::Number,
+            text: Cow::Borrowed(text),
+            at: 0,
+            hide: Vec::new(),
+            edge: true,
+        }
+    }
+}
+
+/// Split `text` into preprocessing tokens, keeping whitespace.
+pub(super) fn tokenize(text: &str) -> Vec<Tok<'_>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let b = bytes[pos];
+        let kind = if b.is_ascii_whitespace() {
+            while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            TokKind::Space
+        } else if b.is_ascii_alphabetic() || b == b'_' {
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            TokKind::Ident
+        } else if b.is_ascii_digit()
+            || (b == b'.' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit))
+        {
+            // A pp-number: digits, letters, `.`, and a sign after an exponent.
+            pos += 1;
+            while pos < bytes.len() {
+                let c = bytes[pos];
+                if matches!(c, b'+' | b'-') && matches!(bytes[pos - 1], b'e' | b'E') {
+                    pos += 1;
+                } else if c.is_ascii_alphanumeric() || c == b'_' || c == b'.' {
+                    pos += 1;
+                } else {
+                    break;
+                }
+            }
+            TokKind::Number
+        } else {
+            let two = text.get(pos..pos + 2).unwrap_or("");
+            pos += if matches!(two, "<<" | ">>" | "<=" | ">=" | "==" | "!=" | "&&" | "||") {
+                2
+            } else {
+                text[pos..].chars().next().map_or(1, char::len_utf8)
+            };
+            TokKind::Punct
+        };
+        tokens.push(Tok {
+            kind,
+            text: Cow::Borrowed(&text[start..pos]),
+            at: start,
+            hide: Vec::new(),
+            edge: false,
+        });
+    }
+    tokens
+}
+
+impl Preprocessor<'_> {
+    /// Expand the macros in one line of shader text, leaving comments as
+    /// written. Errors carry the byte offset in `line` of the invocation.
+    ///
+    /// `line` may be several source lines joined by the caller to complete
+    /// an invocation whose arguments span them
+    /// ([`ExpandError::unterminated`]).
+    pub(super) fn expand_text_line(
+        &self,
+        line: &str,
+        in_comment: &mut bool,
+        line_no: usize,
+        file: u16,
+    ) -> Result<String, ExpandError> {
+        let mut out = String::with_capacity(line.len());
+        let mut pos = 0;
+        while pos < line.len() {
+            let rest = &line[pos..];
+            if *in_comment {
+                let end = match rest.find("*/") {
+                    Some(end) => {
+                        *in_comment = false;
+                        end + 2
+                    }
+                    None => rest.len(),
+                };
+                out.push_str(&rest[..end]);
+                pos += end;
+                continue;
+            }
+            let comment = comment_start(rest);
+            let code_end = comment.map_or(rest.len(), |(at, _)| at);
+            self.expand_code(&rest[..code_end], pos, line_no, file, &mut out)?;
+            pos += code_end;
+            match comment {
+                Some((_, true)) => {
+                    out.push_str(&line[pos..]);
+                    break;
+                }
+                Some((_, false)) => {
+                    out.push_str("/*");
+                    pos += 2;
+                    *in_comment = true;
+                }
+                None => {}
+            }
+        }
+        Ok(out)
+    }
+
+    fn expand_code(
+        &self,
+        code: &str,
+        base: usize,
+        line_no: usize,
+        file: u16,
+        out: &mut String,
+    ) -> Result<(), ExpandError> {
+        if self.macros.is_empty() && !code.contains("__") {
+            out.push_str(code);
+            return Ok(());
+        }
+        let mut tokens = tokenize(code);
+        for tok in &mut tokens {
+            tok.at += base;
+        }
+        let expanded = self.expand_tokens(tokens, line_no, file)?;
+        render(&expanded, out);
+        Ok(())
+    }
+
+    /// Fully expand `tokens`.
+    pub(super) fn expand_tokens<'a>(
+        &'a self,
+        tokens: Vec<Tok<'a>>,
+        line_no: usize,
+        file: u16,
+    ) -> Result<Vec<Tok<'a>>, ExpandError> {
+        let mut budget = MAX_EXPANSION_TOKENS;
+        self.expand_with_budget(tokens, line_no, file, &mut budget)
+    }
+
+    fn expand_with_budget<'a>(
+        &'a self,
+        mut pending: Vec<Tok<'a>>,
+        line_no: usize,
+        file: u16,
+        budget: &mut usize,
+    ) -> Result<Vec<Tok<'a>>, ExpandError> {
+        // `pending` is a stack: the next token is at the end.
+        pending.reverse();
+        let mut out = Vec::with_capacity(pending.len());
+        while let Some(tok) = pending.pop() {
+            if tok.kind != TokKind::Ident {
+                out.push(tok);
+                continue;
+            }
+            let builtin = match &*tok.text {
+                "__LINE__" => Some(line_no as u32),
+                "__FILE__" => Some(u32::from(file)),
+                _ => None,
+            };
+            if let Some(value) = builtin {
+                out.push(Tok {
+                    kind: TokKind::Number,
+                    text: Cow::Owned(format!("{value}")),
+                    edge: true,
+                    ..tok
+                });
+                continue;
+            }
+            let Some((name, mac)) = self.macros.get_key_value(&*tok.text) else {
+                out.push(tok);
+                continue;
+            };
+            let name = name.as_str();
+            if tok.hide.contains(&name) {
+                out.push(tok);
+                continue;
+            }
+
+            let mut replacement = match &mac.params {
+                None => tokenize(&mac.body),
+                Some(params) => {
+                    // Not followed by `(`: the bare name, left alone.
+                    let mut next = pending.len();
+                    while next > 0 && pending[next - 1].kind == TokKind::Space {
+                        next -= 1;
+                    }
+                    if next == 0 || pending[next - 1].text != "(" {
+                        out.push(tok);
+                        continue;
+                    }
+                    pending.truncate(next - 1);
+                    let Some(mut args) = collect_args(&mut pending) else {
+                        return Err(ExpandError {
+                            at: tok.at,
+                            message: format!("unterminated argument list for macro `{name}`"),
+                            unterminated: true,
+                        });
+                    };
+                    if params.is_empty() && args.len() == 1 && args[0].is_empty() {
+                        args.clear();
+                    }
+                    if args.len() != params.len() {
+                        return Err(ExpandError::new(
+                            tok.at,
+                            format!(
+                                "macro `{name}` takes {} argument(s), got {}",
+                                params.len(),
+                                args.len()
+                            ),
+                        ));
+                    }
+                    // An argument is complete on its own: an unclosed list
+                    // inside it is an error, not a reason to read more lines.
+                    let args = args
+                        .into_iter()
+                        .map(|arg| self.expand_with_budget(arg, line_no, file, budget))
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|err| ExpandError::new(tok.at, err.message))?;
+                    substitute(tokenize(&mac.body), params, &args)
+                }
+            };
+
+            *budget = budget.checked_sub(replacement.len()).ok_or_else(|| {
+                ExpandError::new(tok.at, format!("expansion of macro `{name}` is too large"))
+            })?;
+            for r in &mut replacement {
+                r.at = tok.at;
+                r.hide.extend(tok.hide.iter().copied());
+                r.hide.push(name);
+            }
+            mark_edges(&mut replacement);
+            pending.extend(replacement.into_iter().rev());
+        }
+        Ok(out)
+    }
+}
+
+/// Take the arguments of an invocation whose `(` was just consumed, up to and
+/// including its `)`. `None` when the list is not closed.
+fn collect_args<'a>(pending: &mut Vec<Tok<'a>>) -> Option<Vec<Vec<Tok<'a>>>> {
+    let mut args = Vec::new();
+    let mut current = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        let tok = pending.pop()?;
+        match &*tok.text {
+            "(" => depth += 1,
+            ")" if depth == 0 => {
+                args.push(trim_spaces(current));
+                return Some(args);
+            }
+            ")" => depth -= 1,
+            "," if depth == 0 => {
+                args.push(trim_spaces(core::mem::take(&mut current)));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(tok);
+    }
+}
+
+fn trim_spaces(mut tokens: Vec<Tok<'_>>) -> Vec<Tok<'_>> {
+    while tokens.last().is_some_and(|t| t.kind == TokKind::Space) {
+        tokens.pop();
+    }
+    let lead = tokens
+        .iter()
+        .take_while(|t| t.kind == TokKind::Space)
+        .count();
+    tokens.drain(..lead);
+    tokens
+}
+
+fn substitute<'a>(body: Vec<Tok<'a>>, params: &[String], args: &[Vec<Tok<'a>>]) -> Vec<Tok<'a>> {
+    let mut out = Vec::with_capacity(body.len());
+    for tok in body {
+        match params
+            .iter()
+            .position(|p| tok.kind == TokKind::Ident && *p == *tok.text)
+        {
+            Some(i) => {
+                let start = out.len();
+                out.extend(args[i].iter().cloned());
+                mark_edges(&mut out[start..]);
+            }
+            None => out.push(tok),
+        }
+    }
+    out
+}
+
+fn mark_edges(tokens: &mut [Tok<'_>]) {
+    if let Some(first) = tokens.first_mut() {
+        first.edge = true;
+    }
+    if let Some(last) = tokens.last_mut() {
+        last.edge = true;
+    }
+}
+
+/// Append `tokens` as text, separating an expanded token from its neighbour
+/// wherever writing them together would lex differently (`-` then `-1`
+/// would become `--1`).
+fn render(tokens: &[Tok<'_>], out: &mut String) {
+    let mut prev: Option<&Tok<'_>> = None;
+    for tok in tokens {
+        if let Some(p) = prev
+            && (p.edge || tok.edge)
+            && would_join(&p.text, &tok.text)
+        {
+            out.push(' ');
+        }
+        out.push_str(&tok.text);
+        prev = Some(tok);
+    }
+}
+
+fn would_join(left: &str, right: &str) -> bool {
+    let (Some(a), Some(b)) = (left.bytes().last(), right.bytes().next()) else {
+        return false;
+    };
+    let word = |c: u8| c.is_ascii_alphanumeric() || c == b'_';
+    let op = |c: u8| b"+-*/%<>=!&|^".contains(&c);
+    let numeric = |c: u8| c.is_ascii_digit() || c == b'.';
+    word(a) && word(b) || numeric(a) && numeric(b) || op(a) && op(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use lp_collection::{VecMap, VecSet};
+
+    use super::*;
+    use crate::preprocess::{NoIncludes, preprocess};
+
+    /// Split the argument list that follows an already consumed `(`.
+    fn split_args(text: &str) -> Option<Vec<Vec<String>>> {
+        let mut pending = tokenize(text);
+        pending.reverse();
+        let args = collect_args(&mut pending)?;
+        Some(
+            args.iter()
+                .map(|arg| arg.iter().map(|t| String::from(&*t.text)).collect())
+                .collect(),
+        )
+    }
+
+    /// Expand `text` with `macros` defined, as one line.
+    fn expand_line(
+        macros: &[(&str, Option<&[&str]>, &str)],
+        text: &str,
+    ) -> Result<String, ExpandError> {
+        let mut includes = NoIncludes;
+        let mut pp = Preprocessor {
+            includes: &mut includes,
+            macros: VecMap::new(),
+            once: VecSet::new(),
+            stack: Vec::new(),
+            files: Vec::new(),
+            origins: Vec::new(),
+            out: String::new(),
+        };
+        for (name, params, body) in macros {
+            let params = params.map(|p| p.iter().map(|s| String::from(*s)).collect());
+            pp.macros.insert(
+                String::from(*name),
+                Macro {
+                    params,
+                    body: String::from(*body),
+                },
+            );
+        }
+        pp.expand_text_line(text, &mut false, 1, 0)
+    }
+
+    #[test]
+    fn arguments_split_at_top_level_commas() {
+        assert_eq!(
+            split_args("a, (b, c),  f(d, e) ) tail"),
+            Some(vec![
+                vec![String::from("a")],
+                ["(", "b", ",", " ", "c", ")"].map(String::from).to_vec(),
+                ["f", "(", "d", ",", " ", "e", ")"]
+                    .map(String::from)
+                    .to_vec(),
+            ])
+        );
+    }
+
+    #[test]
+    fn empty_list_is_one_empty_argument() {
+        assert_eq!(split_args(" )"), Some(vec![Vec::new()]));
+        assert_eq!(split_args(",)"), Some(vec![Vec::new(), Vec::new()]));
+    }
+
+    #[test]
+    fn unclosed_list_has_no_arguments() {
+        assert_eq!(split_args("a, (b)"), None);
+        assert_eq!(split_args("f(a)"), None);
+    }
+
+    #[test]
+    fn open_argument_list_is_reported_as_unterminated() {
+        let err = expand_line(&[("F", Some(&["x"]), "x")], "int a = F(1,").expect_err("open list");
+        assert!(err.unterminated);
+        assert_eq!(err.at, 8);
+
+        // Rescanning an expansion can open a list the line never closes.
+        let err = expand_line(
+            &[("F", Some(&["x"]), "x"), ("CALL", None, "F(")],
+            "int a = CALL 1",
+        )
+        .expect_err("open list");
+        assert!(err.unterminated);
+    }
+
+    #[test]
+    fn list_opened_inside_an_argument_is_not_unterminated() {
+        // `OPEN` expands to `G(` while F's argument is pre-expanded. The
+        // argument is complete on its own, so more lines cannot close G's list.
+        let err = expand_line(
+            &[
+                ("OPEN", None, "G("),
+                ("G", Some(&["x"]), "x"),
+                ("F", Some(&["a"]), "a"),
+            ],
+            "int a = F(OPEN);",
+        )
+        .expect_err("unclosed G");
+        assert!(!err.unterminated);
+        assert!(err.message.contains("`G`"), "{}", err.message);
+    }
+
+    #[test]
+    fn argument_list_may_span_lines() {
+        let out = preprocess(
+            "#define ADD(a, b) ((a) + (b))\n\
+             int x = ADD(1, // first\n\
+                         /* second */ 2\n\
+                         );\n\
+             int y = x;\n",
+            "main.glsl",
+            &mut NoIncludes,
+        )
+        .unwrap_or_else(|e| panic!("{}", e.render()));
+        let lines: Vec<&str> = out.source.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1].split_whitespace().collect::<String>(),
+            "intx=((1)+(2));"
+        );
+        assert_eq!(&lines[2..], ["", "", "int y = x;"]);
+        assert_eq!(
+            out.map
+                .location(out.source.find("int y").unwrap())
+                .unwrap()
+                .line,
+            5
+        );
+    }
+
+    #[test]
+    fn argument_list_does_not_run_into_a_directive() {
+        let err = preprocess(
+            "#define F(a) a\nint x = F(1,\n#define G 2\n2);\n",
+            "main.glsl",
+            &mut NoIncludes,
+        )
+        .expect_err("unterminated");
+        assert!(
+            err.diagnostic
+                .message
+                .contains("unterminated argument list")
+        );
+        assert!(err.render().contains("main.glsl:2:"), "{}", err.render());
+    }
+
+    #[test]
+    fn expansion_keeps_adjacent_operators_apart() {
+        let out = expand_line(&[("NEG", None, "-1")], "int a = 2-NEG;").expect("expand");
+        assert_eq!(out, "int a = 2- -1;");
+        assert!(would_join("a", "b") && would_join("1", ".5") && would_join("-", "="));
+        assert!(!would_join("a", "(") && !would_join(")", "-"));
+    }
+}
@@ -0,0 +1,216 @@
+//! `#if` / `#elif` expression evaluation.
+//!
+//! Operates on fully expanded tokens with `defined` already resolved. Values
+//! are 64-bit signed integers with the C precedence table. An identifier left
+//! after expansion is an error rather than `0`, as GLSL requires.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::expand::{Tok, TokKind};
+
+pub(super) fn evaluate(tokens: &[Tok<'_>]) -> Result<i64, String> {
+    let tokens: Vec<&str> = tokens
+        .iter()
+        .filter(|t| t.kind != TokKind::Space)
+        .map(|t| &*t.text)
+        .collect();
+    if let Some(tok) = tokens.iter().find(|t| classify(t) == TokKind::Ident) {
+        return Err(format!("undefined identifier `{tok}` in #if expression"));
+    }
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+    };
+    let value = parser.binary(0, true)?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(value),
+        Some(tok) => Err(format!("unexpected `{tok}` in #if expression")),
+    }
+}
+
+fn classify(tok: &str) -> TokKind {
+    match tok.as_bytes().first() {
+        Some(b) if b.is_ascii_alphabetic() || *b == b'_' => TokKind::Ident,
+        Some(b) if b.is_ascii_digit() => TokKind::Number,
+        _ => TokKind::Punct,
+    }
+}
+
+struct Parser<'t> {
+    tokens: &'t [&'t str],
+    pos: usize,
+}
+
+/// Binary operators by precedence level, loosest first.
+const LEVELS: &[&[&str]] = &[
+    &["||"],
+    &["&&"],
+    &["|"],
+    &["^"],
+    &["&"],
+    &["==", "!="],
+    &["<", ">", "<=", ">="],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+impl<'t> Parser<'t> {
+    fn peek(&self) -> Option<&'t str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    /// Parse operators of `level` and tighter. `live` is false inside the
+    /// unevaluated side of `&&` / `||`, where division by zero is no error.
+    fn binary(&mut self, level: usize, live: bool) -> Result<i64, String> {
+        if level == LEVELS.len() {
+            return self.unary(live);
+        }
+        let mut lhs = self.binary(level + 1, live)?;
+        while let Some(op) = self.peek().filter(|t| LEVELS[level].contains(t)) {
+            self.pos += 1;
+            let rhs_live = live
+                && match op {
+                    "&&" => lhs != 0,
+                    "||" => lhs == 0,
+                    _ => true,
+                };
+            let rhs = self.binary(level + 1, rhs_live)?;
+            lhs = match op {
+                "||" => i64::from(lhs != 0 || rhs != 0),
+                "&&" => i64::from(lhs != 0 && rhs != 0),
+                "|" => lhs | rhs,
+                "^" => lhs ^ rhs,
+                "&" => lhs & rhs,
+                "==" => i64::from(lhs == rhs),
+                "!=" => i64::from(lhs != rhs),
+                "<" => i64::from(lhs < rhs),
+                ">" => i64::from(lhs > rhs),
+                "<=" => i64::from(lhs <= rhs),
+                ">=" => i64::from(lhs >= rhs),
+                "<<" => lhs.wrapping_shl(rhs as u32),
+                ">>" => lhs.wrapping_shr(rhs as u32),
+                "+" => lhs.wrapping_add(rhs),
+                "-" => lhs.wrapping_sub(rhs),
+                "*" => lhs.wrapping_mul(rhs),
+                "/" | "%" if rhs == 0 => {
+                    if live {
+                        return Err(String::from("division by zero in #if expression"));
+                    }
+                    0
+                }
+                "/" => lhs.wrapping_div(rhs),
+                _ => lhs.wrapping_rem(rhs),
+            };
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self, live: bool) -> Result<i64, String> {
+        let Some(tok) = self.peek() else {
+            return Err(String::from("unexpected end of #if expression"));
+        };
+        self.pos += 1;
+        match tok {
+            "+" => self.unary(live),
+            "-" => Ok(self.unary(live)?.wrapping_neg()),
+            "~" => Ok(!self.unary(live)?),
+            "!" => Ok(i64::from(self.unary(live)? == 0)),
+            "(" => {
+                let value = self.binary(0, live)?;
+                if self.peek() != Some(")") {
+                    return Err(String::from("expected `)` in #if expression"));
+                }
+                self.pos += 1;
+                Ok(value)
+            }
+            _ if classify(tok) == TokKind::Number => parse_int(tok),
+            _ => Err(format!("unexpected `{tok}` in #if expression")),
+        }
+    }
+}
+
+fn parse_int(tok: &str) -> Result<i64, String> {
+    let digits = tok.trim_end_matches(['u', 'U']);
+    let parsed = if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        i64::from_str_radix(hex, 16)
+    } else if digits.len() > 1 && digits.starts_with('0') {
+        i64::from_str_radix(&digits[1..], 8)
+    } else {
+        digits.parse()
+    };
+    parsed.map_err(|_| format!("expected an integer in #if expression, found `{tok}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preprocess::expand::tokenize;
+
+    fn eval(text: &str) -> Result<i64, String> {
+        evaluate(&tokenize(text))
+    }
+
+    #[test]
+    fn operators_follow_c_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), Ok(7));
+        assert_eq!(eval("(1 + 2) * 3"), Ok(9));
+        assert_eq!(eval("1 << 2 + 1"), Ok(8));
+        assert_eq!(eval("1 | 2 ^ 3 & 6"), Ok(1 | (2 ^ (3 & 6))));
+        assert_eq!(eval("1 < 2 == 1"), Ok(1));
+        assert_eq!(eval("7 - 2 - 1"), Ok(4));
+        assert_eq!(eval("-7 / 2 + -7 % 2"), Ok(-4));
+    }
+
+    #[test]
+    fn unary_operators_nest() {
+        assert_eq!(eval("-1 < 0"), Ok(1));
+        assert_eq!(eval("~0"), Ok(-1));
+        assert_eq!(eval("!5 || !!0"), Ok(0));
+        assert_eq!(eval("- - +3"), Ok(3));
+    }
+
+    #[test]
+    fn integer_literals_take_c_bases_and_suffixes() {
+        assert_eq!(eval("0x10 + 010 + 3u"), Ok(27));
+        assert_eq!(eval("0XfF"), Ok(255));
+        assert_eq!(eval("0"), Ok(0));
+        assert!(eval("1.5").is_err());
+        assert!(eval("09").is_err());
+    }
+
+    #[test]
+    fn division_by_zero_is_an_error_only_when_evaluated() {
+        assert_eq!(eval("0 && 1 / 0"), Ok(0));
+        assert_eq!(eval("1 || 1 % 0"), Ok(1));
+        assert_eq!(
+            eval("1 && 1 / 0"),
+            Err(String::from("division by zero in #if expression"))
+        );
+    }
+
+    #[test]
+    fn malformed_expressions_are_errors() {
+        assert_eq!(
+            eval("FOO + 1"),
+            Err(String::from("undefined identifier `FOO` in #if expression"))
+        );
+        assert_eq!(
+            eval("1 2"),
+            Err(String::from("unexpected `2` in #if expression"))
+        );
+        assert_eq!(
+            eval("(1 + 2"),
+            Err(String::from("expected `)` in #if expression"))
+        );
+        assert_eq!(
+            eval("1 +"),
+            Err(String::from("unexpected end of #if expression"))
+        );
+    }
+}
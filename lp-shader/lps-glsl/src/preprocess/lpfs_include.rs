@@ -0,0 +1,62 @@
+//! `#include` resolution against a project filesystem.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use lpfs::{FsError, LpFs, LpPath, LpPathBuf};
+
+use super::{IncludeResolver, IncludedSource};
+
+/// Resolves `#include "path"` through a project's [`LpFs`].
+///
+/// A path starting with `/` is project-absolute. Any other path is looked up
+/// next to the including file first and then from the project root, so a
+/// shared `lib/noise.glsl` is reachable from shaders in any directory. Names
+/// are the normalized project paths, so the root file should be preprocessed
+/// under its project path too (e.g. `/shaders/main.glsl`).
+pub struct LpFsIncludes<'fs> {
+    fs: &'fs dyn LpFs,
+}
+
+impl<'fs> LpFsIncludes<'fs> {
+    pub fn new(fs: &'fs dyn LpFs) -> Self {
+        Self { fs }
+    }
+}
+
+impl IncludeResolver for LpFsIncludes<'_> {
+    fn resolve(&mut self, includer: &str, path: &str) -> Result<IncludedSource, String> {
+        let root = LpPathBuf::from("/");
+        let mut candidates: Vec<LpPathBuf> = Vec::new();
+        if !path.starts_with('/') {
+            let includer = root.join_relative(includer).unwrap_or_else(|| root.clone());
+            let dir = includer
+                .as_path()
+                .parent()
+                .map_or_else(|| root.clone(), LpPath::to_path_buf);
+            candidates.extend(dir.join_relative(path));
+        }
+        candidates.extend(root.join_relative(path));
+        if candidates.is_empty() {
+            return Err(String::from("path leaves the project root"));
+        }
+        candidates.dedup();
+
+        for candidate in candidates {
+            match self.fs.read_file(candidate.as_path()) {
+                Ok(bytes) => {
+                    let text = String::from_utf8(bytes)
+                        .map_err(|err| format!("{}: {err}", candidate.as_str()))?;
+                    return Ok(IncludedSource {
+                        name: candidate.as_str().to_string(),
+                        text,
+                    });
+                }
+                Err(FsError::NotFound(_)) => {}
+                Err(err) => return Err(err.to_string()),
+            }
+        }
+        Err(String::from("file not found"))
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::string::String;
 use alloc::vec::Vec;
 
 /// Byte span in the original source.
@@ -14,10 +15,38 @@ impl Span {
 }
 
 /// Source line index for diagnostics.
+///
+/// A map built by [`preprocess`](crate::preprocess) also records where each
+/// line of the expanded source was authored, so diagnostics against the
+/// expanded text can name the original file and line.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SourceMap {
     line_starts: Vec<usize>,
     source_len: usize,
+    /// Per-line origins; empty when every line is its own origin in file 0.
+    origins: Vec<LineOrigin>,
+    /// Names of the files `origins` index, when known.
+    files: Vec<String>,
+}
+
+/// Where one line of a preprocessed source was authored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineOrigin {
+    /// Index into [`SourceMap::files`].
+    pub file: u16,
+    /// 1-based line in that file.
+    pub line: u32,
+}
+
+/// A byte offset resolved to its authored location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation<'a> {
+    /// File name, when the map knows it.
+    pub file: Option<&'a str>,
+    /// 1-based line in `file`.
+    pub line: usize,
+    /// 1-based byte column in the mapped source's line.
+    pub col: usize,
 }
 
 impl SourceMap {
@@ -32,9 +61,25 @@ impl SourceMap {
         Self {
             line_starts,
             source_len: source.len(),
+            origins: Vec::new(),
+            files: Vec::new(),
         }
     }
 
+    /// Map for `source` authored as the single file `name`.
+    pub fn named(source: &str, name: &str) -> Self {
+        let mut map = Self::new(source);
+        map.files.push(String::from(name));
+        map
+    }
+
+    pub(crate) fn with_origins(source: &str, files: Vec<String>, origins: Vec<LineOrigin>) -> Self {
+        let mut map = Self::new(source);
+        map.files = files;
+        map.origins = origins;
+        map
+    }
+
     /// Return 1-based `(line, column)` for a byte offset.
     pub fn line_col(&self, offset: usize) -> Option<(usize, usize)> {
         let line_idx = match self.line_starts.binary_search(&offset) {
@@ -60,4 +105,23 @@ impl SourceMap {
         }
         Some((start, end))
     }
+
+    /// Return the authored file and line for a byte offset.
+    pub fn location(&self, offset: usize) -> Option<SourceLocation<'_>> {
+        let (line, col) = self.line_col(offset)?;
+        let origin = self.origins.get(line - 1).copied().unwrap_or(LineOrigin {
+            file: 0,
+            line: line as u32,
+        });
+        Some(SourceLocation {
+            file: self.files.get(origin.file as usize).map(String::as_str),
+            line: origin.line as usize,
+            col,
+        })
+    }
+
+    /// Names of the files this map's lines come from; the first is the root.
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
 }
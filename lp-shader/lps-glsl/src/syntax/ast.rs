@@ -52,6 +52,11 @@ pub enum ParsedStmt {
         condition: ParsedExpr,
         span: Span,
     },
+    Switch {
+        selector: ParsedExpr,
+        cases: Vec<ParsedSwitchCase>,
+        span: Span,
+    },
     Break {
         span: Span,
     },
@@ -75,6 +80,16 @@ pub enum ParsedStmt {
     },
 }
 
+/// One `case` or `default` label and the statements after it, up to the next
+/// label. Labels that share a body have an empty `body` and fall through.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSwitchCase {
+    /// `None` for `default`.
+    pub label: Option<ParsedExpr>,
+    pub body: Vec<ParsedStmt>,
+    pub span: Span,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedLetDecl {
     pub ty: String,
@@ -96,6 +96,7 @@ pub(super) fn stmt_end(stmt: &ParsedStmt) -> usize {
         | ParsedStmt::For { span, .. }
         | ParsedStmt::While { span, .. }
         | ParsedStmt::DoWhile { span, .. }
+        | ParsedStmt::Switch { span, .. }
         | ParsedStmt::Break { span }
         | ParsedStmt::Continue { span }
         | ParsedStmt::Block { span, .. }
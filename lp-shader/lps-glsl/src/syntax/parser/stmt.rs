@@ -1,7 +1,7 @@
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use crate::syntax::ParsedStmt;
+use crate::syntax::{ParsedStmt, ParsedSwitchCase};
 use crate::{Diagnostic, Keyword, Span};
 
 use super::BodyParser;
@@ -61,6 +61,9 @@ impl<'src, 'tok> BodyParser<'src, 'tok> {
         if self.at_keyword(Keyword::Do) {
             return self.parse_do_while();
         }
+        if self.at_keyword(Keyword::Switch) {
+            return self.parse_switch();
+        }
         if self.at_keyword(Keyword::Break) {
             let start = self.bump().span.start;
             let end = self.expect_punct(";")?.span.end;
@@ -202,6 +205,56 @@ impl<'src, 'tok> BodyParser<'src, 'tok> {
         })
     }
 
+    pub(super) fn parse_switch(&mut self) -> Result<ParsedStmt, Diagnostic> {
+        let start = self.expect_keyword(Keyword::Switch)?.span.start;
+        self.expect_punct("(")?;
+        let selector = self.parse_expr(0)?;
+        self.expect_punct(")")?;
+        self.expect_punct("{")?;
+        let mut cases: Vec<ParsedSwitchCase> = Vec::new();
+        while !self.at_punct("}") {
+            if self.at_end() {
+                return Err(Diagnostic::error(
+                    self.current_span(),
+                    "unterminated switch body",
+                ));
+            }
+            if self.at_keyword(Keyword::Case) || self.at_keyword(Keyword::Default) {
+                let label_start = self.current_span().start;
+                let label = if self.at_keyword(Keyword::Case) {
+                    self.bump();
+                    Some(self.parse_expr(0)?)
+                } else {
+                    self.bump();
+                    None
+                };
+                let end = self.expect_punct(":")?.span.end;
+                cases.push(ParsedSwitchCase {
+                    label,
+                    body: Vec::new(),
+                    span: Span::new(label_start, end),
+                });
+                continue;
+            }
+            if cases.is_empty() {
+                return Err(Diagnostic::error(
+                    self.current_span(),
+                    "statement before the first case label",
+                ));
+            }
+            let stmt = self.parse_statement()?;
+            let case = cases.last_mut().expect("switch case");
+            case.span = Span::new(case.span.start, stmt_end(&stmt));
+            case.body.push(stmt);
+        }
+        let end = self.expect_punct("}")?.span.end;
+        Ok(ParsedStmt::Switch {
+            selector,
+            cases,
+            span: Span::new(start, end),
+        })
+    }
+
     pub(super) fn parse_statement_or_block(&mut self) -> Result<Vec<ParsedStmt>, Diagnostic> {
         if self.at_punct("{") {
             self.bump();
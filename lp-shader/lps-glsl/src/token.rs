@@ -4,8 +4,10 @@ use crate::Span;
 pub enum Keyword {
     Bool,
     Break,
+    Case,
     Const,
     Continue,
+    Default,
     Do,
     Else,
     Float,
@@ -14,6 +16,7 @@ pub enum Keyword {
     Int,
     Layout,
     Return,
+    Switch,
     Uint,
     Uniform,
     Vec2,
@@ -28,8 +31,10 @@ impl Keyword {
         Some(match s {
             "bool" => Self::Bool,
             "break" => Self::Break,
+            "case" => Self::Case,
             "const" => Self::Const,
             "continue" => Self::Continue,
+            "default" => Self::Default,
             "do" => Self::Do,
             "else" => Self::Else,
             "float" => Self::Float,
@@ -38,6 +43,7 @@ impl Keyword {
             "int" => Self::Int,
             "layout" => Self::Layout,
             "return" => Self::Return,
+            "switch" => Self::Switch,
             "uint" => Self::Uint,
             "uniform" => Self::Uniform,
             "vec2" => Self::Vec2,
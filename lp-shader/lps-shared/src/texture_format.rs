@@ -17,6 +17,14 @@ pub enum TextureStorageFormat {
     Rgb16Unorm,
     /// Single-channel 16-bit unsigned normalized, 2 bytes/pixel.
     R16Unorm,
+    /// RGB 8-bit unsigned normalized, 3 bytes/pixel (no alpha).
+    ///
+    /// Tightly packed: 3 × u8 per pixel. Loads widen each channel to unorm16
+    /// as `raw * 257`, so `255` samples exactly like a unorm16 `65535`.
+    Rgb8Unorm,
+    /// Single-channel 8-bit unsigned normalized, 1 byte/pixel — masks and
+    /// heightmaps that do not need 16-bit precision.
+    R8Unorm,
 }
 
 impl TextureStorageFormat {
@@ -27,6 +35,8 @@ impl TextureStorageFormat {
             Self::Rgba16Unorm => 8,
             Self::Rgb16Unorm => 6,
             Self::R16Unorm => 2,
+            Self::Rgb8Unorm => 3,
+            Self::R8Unorm => 1,
         }
     }
 
@@ -37,23 +47,32 @@ impl TextureStorageFormat {
             Self::Rgba16Unorm => 4,
             Self::Rgb16Unorm => 3,
             Self::R16Unorm => 1,
+            Self::Rgb8Unorm => 3,
+            Self::R8Unorm => 1,
         }
     }
 
     /// Minimum alignment (bytes) required for loads on this GPU texture path (base pointer, row stride,
     /// and channel column offsets relative to row starts).
     ///
-    /// Current 16-bit unorm formats use halfword loads, so they require 2-byte alignment.
-    ///
-    /// Future `R8`/`u8`-backed formats should be able to return 1. Future fully 32-bit channel
-    /// layouts can require 4 without changing the guest [`LpsTexture2DDescriptor`] ABI.
+    /// 16-bit unorm formats use halfword loads, so they require 2-byte alignment; 8-bit unorm
+    /// formats use byte loads and accept any address. Future fully 32-bit channel layouts can
+    /// require 4 without changing the guest [`LpsTexture2DDescriptor`] ABI.
     #[inline]
     #[must_use]
     pub fn required_load_alignment(self) -> usize {
         match self {
             Self::Rgba16Unorm | Self::Rgb16Unorm | Self::R16Unorm => 2,
+            Self::Rgb8Unorm | Self::R8Unorm => 1,
         }
     }
+
+    /// Bytes per channel: `2` for the unorm16 formats, `1` for unorm8.
+    #[inline]
+    #[must_use]
+    pub fn bytes_per_channel(self) -> usize {
+        self.bytes_per_pixel() / self.channel_count()
+    }
 }
 
 /// Compile-time filter mode for a texture binding.
@@ -261,6 +280,22 @@ mod tests {
         assert_eq!(TextureStorageFormat::R16Unorm.required_load_alignment(), 2);
     }
 
+    #[test]
+    fn unorm8_formats_are_byte_packed_and_byte_aligned() {
+        assert_eq!(TextureStorageFormat::Rgb8Unorm.bytes_per_pixel(), 3);
+        assert_eq!(TextureStorageFormat::Rgb8Unorm.channel_count(), 3);
+        assert_eq!(TextureStorageFormat::R8Unorm.bytes_per_pixel(), 1);
+        assert_eq!(TextureStorageFormat::R8Unorm.channel_count(), 1);
+        for format in [
+            TextureStorageFormat::Rgb8Unorm,
+            TextureStorageFormat::R8Unorm,
+        ] {
+            assert_eq!(format.required_load_alignment(), 1);
+            assert_eq!(format.bytes_per_channel(), 1);
+        }
+        assert_eq!(TextureStorageFormat::Rgb16Unorm.bytes_per_channel(), 2);
+    }
+
     #[test]
     fn required_footprint_accepts_padded_rows() {
         let d = LpsTexture2DDescriptor {
@@ -1,13 +1,19 @@
 //! When `riscv32-object` is enabled, embeds `lps-builtins-emu-app` for linking tests.
 //! Build the executable with `scripts/build-builtins.sh` from the workspace root.
+//! The `riscv32imafc` (`ilp32f`) build of the same app is embedded alongside it
+//! when present: `scripts/build-builtins.sh riscv32imafc-unknown-none-elf`.
 
 fn main() {
     let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR");
     let out_path = std::path::Path::new(&out_dir).join("lp_builtins_lib.rs");
 
     if std::env::var("CARGO_FEATURE_RISCV32_OBJECT").is_err() {
-        std::fs::write(&out_path, "pub const LP_BUILTINS_EXE_BYTES: &[u8] = &[];\n")
-            .expect("write lp_builtins_lib.rs");
+        std::fs::write(
+            &out_path,
+            "pub const LP_BUILTINS_EXE_BYTES: &[u8] = &[];\n\
+             pub const LP_BUILTINS_F_EXE_BYTES: &[u8] = &[];\n",
+        )
+        .expect("write lp_builtins_lib.rs");
         return;
     }
 
@@ -16,7 +22,41 @@ fn main() {
     // entirely, while the manifest dir is always inside it.
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR");
     let workspace_root = find_workspace_root(&manifest_dir).expect("workspace root");
-    let target = "riscv32imac-unknown-none-elf";
+
+    let mut lib = embed_builtins_exe(
+        &workspace_root,
+        &out_dir,
+        "riscv32imac-unknown-none-elf",
+        "LP_BUILTINS_EXE_BYTES",
+        "scripts/build-builtins.sh",
+        true,
+    );
+    // The `ilp32f` image is optional: only `lpvm-native`'s `Rv32imafc` target
+    // links against it, and everything that does checks for it first.
+    lib.push_str(&embed_builtins_exe(
+        &workspace_root,
+        &out_dir,
+        "riscv32imafc-unknown-none-elf",
+        "LP_BUILTINS_F_EXE_BYTES",
+        "scripts/build-builtins.sh riscv32imafc-unknown-none-elf",
+        false,
+    ));
+    std::fs::write(&out_path, lib).expect("write lp_builtins_lib.rs");
+}
+
+/// One `pub const <const_name>: &[u8]` line for the `target` builds of
+/// `lps-builtins-emu-app`: an `include_bytes!` of a copy in `OUT_DIR`, or an
+/// empty slice when the exe is unusable. The empty case warns with `build_cmd`
+/// if the image is `required`, or if a build for `target` was evidently
+/// attempted; an optional image nobody built stays quiet.
+fn embed_builtins_exe(
+    workspace_root: &std::path::Path,
+    out_dir: &str,
+    target: &str,
+    const_name: &str,
+    build_cmd: &str,
+    required: bool,
+) -> String {
     let profile = std::env::var("PROFILE").unwrap_or_else(|_| "debug".to_string());
 
     let exe_path_release = workspace_root
@@ -40,26 +80,27 @@ fn main() {
         exe_path_release
     };
 
-    let copied = std::path::Path::new(&out_dir).join("lps-builtins-emu-app");
+    let copied = std::path::Path::new(out_dir)
+        .join(target)
+        .join("lps-builtins-emu-app");
+    std::fs::create_dir_all(copied.parent().expect("copy has a parent"))
+        .expect("create OUT_DIR target dir");
     if let Err(reason) = copy_builtins_exe(&exe_path, &copied) {
-        println!(
-            "cargo:warning=lps-builtins-emu-app unusable at {} ({reason}) — run scripts/build-builtins.sh",
-            exe_path.display()
-        );
-        std::fs::write(&out_path, "pub const LP_BUILTINS_EXE_BYTES: &[u8] = &[];\n")
-            .expect("write empty lp_builtins_lib.rs");
-        return;
+        let attempted = exe_path.parent().is_some_and(|d| d.is_dir());
+        if required || attempted {
+            println!(
+                "cargo:warning=lps-builtins-emu-app ({target}) unusable at {} ({reason}) — run {build_cmd}",
+                exe_path.display()
+            );
+        }
+        return format!("pub const {const_name}: &[u8] = &[];\n");
     }
     let rel = copied
-        .strip_prefix(&out_dir)
+        .strip_prefix(out_dir)
         .expect("relative to OUT_DIR")
         .to_string_lossy()
         .replace('\\', "/");
-    std::fs::write(
-        &out_path,
-        format!("pub const LP_BUILTINS_EXE_BYTES: &[u8] = include_bytes!(\"{rel}\");\n"),
-    )
-    .expect("write lp_builtins_lib.rs");
+    format!("pub const {const_name}: &[u8] = include_bytes!(\"{rel}\");\n")
 }
 
 /// Copy the builtins ELF into `OUT_DIR`, retrying past a concurrent rewrite.
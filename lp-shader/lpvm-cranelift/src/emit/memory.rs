@@ -23,6 +23,13 @@ pub(crate) fn emit_memory(
             let addr = builder.ins().stack_addr(ctx.pointer_type, ss, 0);
             def_v(builder, vars, *dst, addr);
         }
+        LpirOp::RodataAddr { dst } => {
+            let gv = ctx
+                .rodata
+                .ok_or_else(|| CompileError::unsupported("rodata_addr without module rodata"))?;
+            let addr = builder.ins().symbol_value(ctx.pointer_type, gv);
+            def_v(builder, vars, *dst, addr);
+        }
         LpirOp::Load { dst, base, offset } => {
             let ptr = operand_as_ptr(builder, vars, ctx, *base);
             let ty = ir_type_for_mode(
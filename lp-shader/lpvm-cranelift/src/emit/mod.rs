@@ -3,7 +3,7 @@
 use alloc::vec::Vec;
 
 use cranelift_codegen::ir::{AbiParam, ArgumentPurpose, Signature, types};
-use cranelift_codegen::ir::{Block, FuncRef, InstBuilder, StackSlot, TrapCode, Value};
+use cranelift_codegen::ir::{Block, FuncRef, GlobalValue, InstBuilder, StackSlot, TrapCode, Value};
 use cranelift_codegen::isa::{CallConv, TargetIsa};
 use cranelift_frontend::{FunctionBuilder, Variable};
 use lp_collection::VecMap;
@@ -36,11 +36,13 @@ pub(crate) struct EmitCtx<'a> {
     pub func_refs: &'a [FuncRef],
     pub import_func_refs: &'a [FuncRef],
     pub slots: &'a [StackSlot],
+    /// The module rodata object, when the module has rodata.
+    pub rodata: Option<GlobalValue>,
     pub ir: &'a LpirModule,
     /// Rank `0..functions.len()-1` for each [`LpirFuncId`] (VecMap key order).
     pub func_id_to_ir_rank: &'a VecMap<LpirFuncId, usize>,
     pub pointer_type: types::Type,
-    /// `SlotAddr` definition and transitive `Iadd` results use native pointer SSA type (see `vreg_wide_addr_chain`).
+    /// `SlotAddr`/`RodataAddr` definitions and transitive `Iadd` results use native pointer SSA type (see `vreg_wide_addr_chain`).
     pub vreg_wide_addr: Vec<bool>,
     pub float_mode: FloatMode,
     pub lpir_builtins: Option<LpirBuiltinRefs>,
@@ -202,7 +204,9 @@ pub(crate) fn vreg_wide_addr_chain(func: &IrFunction) -> Vec<bool> {
     }
     for op in &func.body {
         match op {
-            LpirOp::SlotAddr { dst, .. } => wide[dst.0 as usize] = true,
+            LpirOp::SlotAddr { dst, .. } | LpirOp::RodataAddr { dst } => {
+                wide[dst.0 as usize] = true
+            }
             LpirOp::Iadd { dst, lhs, rhs } | LpirOp::Isub { dst, lhs, rhs } => {
                 if wide[lhs.0 as usize] || wide[rhs.0 as usize] {
                     wide[dst.0 as usize] = true;
@@ -52,7 +52,9 @@ pub use lpvm::{
     CallError, CallResult, GlslReturn, LpsValueQ32, decode_q32_return, flatten_q32_arg,
 };
 #[cfg(feature = "riscv32-object")]
-pub use object_link::link_object_with_builtins;
+pub use object_link::{
+    link_object_with_builtins, link_object_with_rv32f_builtins, rv32f_builtins_available,
+};
 
 /// Options-only tests: run under `--no-default-features`.
 #[cfg(test)]
@@ -5,16 +5,48 @@ use lp_collection::VecMap;
 
 use cranelift_codegen::ir::{FuncRef, StackSlot, StackSlotData, StackSlotKind};
 use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext};
-use cranelift_module::{FuncId, Linkage, Module};
-use lpir::FloatMode;
+use cranelift_module::{DataDescription, DataId, FuncId, Linkage, Module};
 use lpir::lpir_module::LpirModule;
 use lpir::types::FuncId as LpirFuncId;
+use lpir::{FloatMode, PassPipeline};
 
 use crate::builtins::{self, LpirBuiltinFuncIds};
 use crate::compile_options::{CompileOptions, MemoryStrategy};
 use crate::emit::{self, LpirBuiltinRefs, translate_function};
 use crate::error::{CompileError, CompilerError};
 
+/// Object symbol for the module rodata (same name `lpvm-native` links it under).
+const RODATA_SYMBOL: &str = "__lpir_rodata";
+
+/// Define the module rodata as a local read-only data object, with float words
+/// encoded the way [`lpir::LpirOp::FconstF32`] is for `mode`.
+fn define_rodata<M: Module>(
+    module: &mut M,
+    ir: &LpirModule,
+    mode: FloatMode,
+) -> Result<DataId, CompilerError> {
+    let bytes = match mode {
+        FloatMode::Q32 => ir.rodata_bytes(|f| lps_q32::q32_encode::q32_encode(f) as u32),
+        FloatMode::F32 => ir.rodata_bytes(f32::to_bits),
+    };
+    let id = module
+        .declare_data(RODATA_SYMBOL, Linkage::Local, false, false)
+        .map_err(|e| {
+            CompilerError::Codegen(CompileError::cranelift(alloc::format!(
+                "declare rodata: {e}"
+            )))
+        })?;
+    let mut desc = DataDescription::new();
+    desc.define(bytes.into_boxed_slice());
+    desc.set_align(4);
+    module.define_data(id, &desc).map_err(|e| {
+        CompilerError::Codegen(CompileError::cranelift(alloc::format!(
+            "define rodata: {e}"
+        )))
+    })?;
+    Ok(id)
+}
+
 /// Declare imports, declare user functions, and define bodies. Caller runs `finalize_definitions` or `finish`.
 pub(crate) fn lower_lpir_into_module<M: Module>(
     module: &mut M,
@@ -28,6 +60,18 @@ pub(crate) fn lower_lpir_into_module<M: Module>(
         )));
     }
 
+    let pipeline = PassPipeline::new(&options.config.opt, mode);
+    let optimized;
+    let ir = if lpir::wants_inlining(ir, &options.config.inline) || !pipeline.is_empty() {
+        let mut copy = ir.clone();
+        lpir::inline_module(&mut copy, &options.config.inline);
+        pipeline.run_module(&mut copy);
+        optimized = copy;
+        &optimized
+    } else {
+        ir
+    };
+
     let call_conv = module.isa().default_call_conv();
     let pointer_type = module.isa().pointer_type();
 
@@ -47,6 +91,12 @@ pub(crate) fn lower_lpir_into_module<M: Module>(
         None
     };
 
+    let rodata_id = if ir.rodata.is_empty() {
+        None
+    } else {
+        Some(define_rodata(module, ir, mode)?)
+    };
+
     let func_id_to_ir_rank: VecMap<LpirFuncId, usize> = ir
         .functions
         .keys()
@@ -149,11 +199,14 @@ pub(crate) fn lower_lpir_into_module<M: Module>(
                 fnearest: module.declare_func_in_func(ids.fnearest, builder.func),
             });
 
+            let rodata = rodata_id.map(|id| module.declare_data_in_func(id, builder.func));
+
             let vreg_wide_addr = emit::vreg_wide_addr_chain(f);
             let emit_ctx = emit::EmitCtx {
                 func_refs: &func_refs,
                 import_func_refs: &import_func_refs,
                 slots: &slots,
+                rodata,
                 ir,
                 func_id_to_ir_rank: &func_id_to_ir_rank,
                 pointer_type,
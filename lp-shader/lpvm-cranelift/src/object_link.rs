@@ -17,6 +17,24 @@ pub fn builtins_executable_bytes() -> &'static [u8] {
     builtins_exe::LP_BUILTINS_EXE_BYTES
 }
 
+/// Embedded `riscv32imafc` build of `lps-builtins-emu-app` (empty if missing at
+/// build time).
+///
+/// Its f32 builtins take and return floats in `fa*` (`ilp32f`), which is what
+/// `lpvm-native`'s `Rv32imafc` target calls. Soft-float code must not link
+/// against it, nor hard-float code against [`builtins_executable_bytes`].
+pub fn rv32f_builtins_executable_bytes() -> &'static [u8] {
+    builtins_exe::LP_BUILTINS_F_EXE_BYTES
+}
+
+/// Whether [`link_object_with_rv32f_builtins`] has an image to link against.
+///
+/// It is an optional cross-target artifact, so callers that can do without it
+/// (the filetest matrix) check this and skip instead of failing.
+pub fn rv32f_builtins_available() -> bool {
+    !rv32f_builtins_executable_bytes().is_empty()
+}
+
 /// Load builtins ELF, merge relocatable `object_elf`, verify `BuiltinId` symbols.
 pub fn link_object_with_builtins(object_elf: &[u8]) -> Result<ElfLoadInfo, CompilerError> {
     let builtins_exe_bytes = builtins_executable_bytes();
@@ -25,7 +43,22 @@ pub fn link_object_with_builtins(object_elf: &[u8]) -> Result<ElfLoadInfo, Compi
             "lps-builtins-emu-app is empty or was not found at build time; run scripts/build-builtins.sh from the workspace root",
         )));
     }
+    link_into(builtins_exe_bytes, object_elf)
+}
+
+/// [`link_object_with_builtins`] against the `ilp32f` image
+/// ([`rv32f_builtins_executable_bytes`]).
+pub fn link_object_with_rv32f_builtins(object_elf: &[u8]) -> Result<ElfLoadInfo, CompilerError> {
+    let builtins_exe_bytes = rv32f_builtins_executable_bytes();
+    if builtins_exe_bytes.is_empty() {
+        return Err(CompilerError::Codegen(CompileError::unsupported(
+            "the riscv32imafc lps-builtins-emu-app is empty or was not found at build time; run scripts/build-builtins.sh riscv32imafc-unknown-none-elf from the workspace root",
+        )));
+    }
+    link_into(builtins_exe_bytes, object_elf)
+}
 
+fn link_into(builtins_exe_bytes: &[u8], object_elf: &[u8]) -> Result<ElfLoadInfo, CompilerError> {
     let mut load_info = lp_riscv_elf::load_elf(builtins_exe_bytes).map_err(|e| {
         CompilerError::Codegen(CompileError::cranelift(format!(
             "load builtins executable: {e}"
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Assemble the RV32F encoder fixture with LLVM and write its disassembly to
+# f_subset.dump, which the encoder tests read (src/isa/rv32/encode.rs).
+#
+# Golden rule (AGENTS.md): instruction bytes are assembler-derived, never
+# hand-written — that is the entire point of this script. Unlike the Xtensa
+# fixtures the dump is checked in: the unit tests need it, and a stock
+# llvm-mc reproduces it, so a diff after ./build.sh is the review signal.
+#
+#   ./build.sh          assemble, regenerate f_subset.dump
+set -euo pipefail
+cd "$(dirname "$0")"
+
+MC="${LLVM_MC:-llvm-mc}"
+OBJDUMP="${LLVM_OBJDUMP:-llvm-objdump}"
+if ! command -v "$MC" >/dev/null; then
+  echo "error: $MC not found (set LLVM_MC)" >&2
+  exit 1
+fi
+
+mkdir -p obj
+"$MC" -triple=riscv32 -mattr=+f -filetype=obj -o obj/f_subset.o f_subset.S
+# numeric,no-aliases: x/f register numbers and the base mnemonic (fsgnj.s,
+# not fmv.s), so each line names exactly one encoder and its operands.
+"$OBJDUMP" -d -M numeric,no-aliases --mattr=+f obj/f_subset.o \
+  | sed -n '/<.text>:/,$p' | tail -n +2 >f_subset.dump
+
+echo "build.sh: OK ($(wc -l <f_subset.dump | tr -d ' ') instructions)"
@@ -57,7 +57,7 @@ impl FuncAbi {
     pub fn arg_regs(&self) -> &[PReg] {
         match self.isa {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => &crate::isa::rv32::abi::ARG_REGS,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => &crate::isa::rv32::abi::ARG_REGS,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => &crate::isa::xt::abi::ARG_REGS,
         }
@@ -161,6 +161,8 @@ impl ModuleAbi {
                         None => func_abi_rv32(fn_sig, None),
                     }
                 }
+                #[cfg(feature = "isa-rv32")]
+                IsaTarget::Rv32imafc => crate::isa::rv32::abi::func_abi_rv32f(fn_sig, ir_func),
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::abi::func_abi_xt(fn_sig, ir_func),
             };
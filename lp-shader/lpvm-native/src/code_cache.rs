@@ -0,0 +1,723 @@
+//! Persistent JIT code cache on an [`LpFs`].
+//!
+//! Compiling a shader on device costs seconds and the largest heap transient
+//! the firmware has (the reason compiles wait for a memory-pressure window —
+//! `docs/adr/2026-08-03-memory-pressure-at-compile-safe-points.md`). A module
+//! whose inputs have not changed compiles to the same bytes, so the emitted
+//! code is kept on flash and reused on the next boot or project load.
+//!
+//! What is stored is the module **before** linking: each function's machine
+//! code, its call relocations and its name (the symbol the other functions'
+//! relocations resolve against), plus the module's encoded read-only data. A linked image has addresses baked in that
+//! differ between boots — the builtin table, the heap address the image runs
+//! from — so a hit is relinked, which is cheap next to register allocation
+//! and emission.
+//!
+//! Entries are keyed by [`CodeCacheKey`], a digest of the GLSL source (via
+//! [`lpvm::LpvmCompileParams::source_hash`]), the uniforms and globals layout,
+//! backend options (float mode, middle-end config, fuel, …), target ISA and
+//! compiler build. A load validates the entry (magic, format, key, checksum,
+//! relocation bounds and types); one that fails is deleted and reported as a
+//! miss, so the caller recompiles. The directory is capped at a byte budget
+//! and evicts least recently used entries first, tracked in a small `index`
+//! file.
+//!
+//! A hit skips only the backend. The GLSL frontend still runs, because the
+//! module signature and entry metadata come from its output; caching those
+//! too is what would let a hit skip it.
+//!
+//! The ESP32 firmwares build one over the flash filesystem under
+//! `/.lp/jit-cache` (`lp_gfx_lpvm::TargetLpvmGraphics::with_code_cache`),
+//! with `LP_BUILD_COMMIT` as the `compiler_id`. A dirty build, whose commit
+//! does not identify its compiler, and a safe-mode boot run without one.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use lpfs::{FsError, LpFs, LpPath, LpPathBuf};
+use lps_shared::LpsModuleSig;
+
+use crate::compile::{CompiledFunction, CompiledModule, NativeReloc};
+use crate::isa::IsaTarget;
+use crate::native_options::NativeCompileOptions;
+use crate::vinst::ModuleSymbols;
+
+/// Layout version of a cache entry. Bump on any change to the encoding below;
+/// older entries then fail validation and are recompiled.
+pub const CODE_CACHE_FORMAT: u32 = 2;
+
+/// This crate's version, always part of the key. Builds of the same version
+/// can still emit different code, so embedders also pass a build identity
+/// ([`CodeCache::new`]'s `compiler_id`).
+pub const COMPILER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const MAGIC: &[u8; 4] = b"LPJC";
+const INDEX_FILE: &str = "index";
+const ENTRY_EXT: &str = "bin";
+
+/// Digest of everything that decides a module's machine code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CodeCacheKey(pub u64);
+
+/// The inputs a [`CodeCacheKey`] is derived from.
+pub struct CodeCacheInputs<'a> {
+    /// Build identity of the compiler ([`JitCodeCache::compiler_id`]).
+    pub compiler_id: &'a str,
+    /// Hash of the source the LPIR was lowered from
+    /// ([`lpvm::LpvmCompileParams::source_hash`]).
+    pub source_hash: u64,
+    /// Module signature; its uniforms and globals layout are keyed.
+    pub meta: &'a LpsModuleSig,
+    /// Backend options the module is compiled with, float mode and
+    /// middle-end config included.
+    pub options: &'a NativeCompileOptions,
+    pub isa: IsaTarget,
+}
+
+impl CodeCacheKey {
+    #[must_use]
+    pub fn new(inputs: &CodeCacheInputs<'_>) -> Self {
+        let mut h = Fnv64::new();
+        h.field(&CODE_CACHE_FORMAT.to_le_bytes());
+        h.field(COMPILER_VERSION.as_bytes());
+        h.field(inputs.compiler_id.as_bytes());
+        h.field(&inputs.source_hash.to_le_bytes());
+        // `Debug` of the layout types is a complete, deterministic rendering,
+        // and any compiler change that could alter it is already keyed above.
+        h.debug_field(&inputs.meta.uniforms_type);
+        h.debug_field(&inputs.meta.globals_type);
+        h.debug_field(inputs.options);
+        h.debug_field(&inputs.isa);
+        Self(h.finish())
+    }
+
+    fn file_name(self) -> String {
+        format!("{:016x}.{ENTRY_EXT}", self.0)
+    }
+}
+
+/// A code cache the JIT engine can consult
+/// (`NativeJitEngine::with_code_cache`, on JIT targets). Best effort: a
+/// failed store is logged and forgotten, never a compile error.
+pub trait JitCodeCache: Send + Sync {
+    /// Build identity of the compiler whose output this cache holds.
+    fn compiler_id(&self) -> &str;
+
+    /// The validated module stored under `key`, if any.
+    fn load(&self, key: CodeCacheKey, isa: IsaTarget) -> Option<CompiledModule>;
+
+    /// Store `module` under `key`, evicting older entries to stay in budget.
+    fn store(&self, key: CodeCacheKey, module: &CompiledModule);
+}
+
+/// [`JitCodeCache`] kept in one directory of an [`LpFs`].
+pub struct CodeCache<F: LpFs> {
+    fs: F,
+    dir: LpPathBuf,
+    max_bytes: u64,
+    compiler_id: String,
+}
+
+/// One line of the index: an entry and its size, oldest use first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct IndexEntry {
+    key: CodeCacheKey,
+    bytes: u64,
+}
+
+impl<F: LpFs> CodeCache<F> {
+    /// A cache in `dir` (absolute) holding at most `max_bytes` of entries.
+    ///
+    /// `compiler_id` names the compiler build — on firmware, the build commit.
+    /// Entries from any other build never match, so it must change whenever
+    /// the emitted code can; a build from a dirty tree should not cache.
+    pub fn new(fs: F, dir: LpPathBuf, max_bytes: u64, compiler_id: impl Into<String>) -> Self {
+        Self {
+            fs,
+            dir,
+            max_bytes,
+            compiler_id: compiler_id.into(),
+        }
+    }
+
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Total size of the entries currently indexed.
+    pub fn used_bytes(&self) -> u64 {
+        self.read_index().iter().map(|e| e.bytes).sum()
+    }
+
+    /// The module stored under `key`, validated for `isa`. An invalid entry
+    /// is deleted and reported as a miss.
+    pub fn load_module(&self, key: CodeCacheKey, isa: IsaTarget) -> Option<CompiledModule> {
+        let path = self.entry_path(key);
+        let bytes = self.fs.read_file(path.as_path()).ok()?;
+        match decode(&bytes, key, isa) {
+            Ok(module) => {
+                let mut index = self.read_index();
+                if index.last().map(|e| e.key) != Some(key) {
+                    let bytes = bytes.len() as u64;
+                    index.retain(|e| e.key != key);
+                    index.push(IndexEntry { key, bytes });
+                    if let Err(e) = self.write_index(&index) {
+                        log::warn!("[code-cache] index update failed: {e}");
+                    }
+                }
+                Some(module)
+            }
+            Err(reason) => {
+                log::warn!("[code-cache] dropping {}: {reason}", path.as_str());
+                let _ = self.fs.delete_file(path.as_path());
+                let mut index = self.read_index();
+                index.retain(|e| e.key != key);
+                let _ = self.write_index(&index);
+                None
+            }
+        }
+    }
+
+    /// Store `module` under `key`. Least recently used entries are evicted
+    /// first to make room; a module larger than the whole budget is not
+    /// stored.
+    pub fn store_module(&self, key: CodeCacheKey, module: &CompiledModule) -> Result<(), FsError> {
+        let bytes = encode(key, module);
+        let size = bytes.len() as u64;
+        if size > self.max_bytes {
+            return Ok(());
+        }
+        let mut index = self.read_index();
+        index.retain(|e| e.key != key);
+        let mut used: u64 = index.iter().map(|e| e.bytes).sum();
+        // Evict before writing, so flash use never exceeds the budget.
+        while used + size > self.max_bytes && !index.is_empty() {
+            let evicted = index.remove(0);
+            let _ = self.fs.delete_file(self.entry_path(evicted.key).as_path());
+            used -= evicted.bytes;
+        }
+        self.fs.write_file(self.entry_path(key).as_path(), &bytes)?;
+        index.push(IndexEntry { key, bytes: size });
+        self.write_index(&index)?;
+        self.remove_unindexed(&index);
+        Ok(())
+    }
+
+    fn entry_path(&self, key: CodeCacheKey) -> LpPathBuf {
+        self.dir.join(key.file_name())
+    }
+
+    fn index_path(&self) -> LpPathBuf {
+        self.dir.join(INDEX_FILE)
+    }
+
+    /// The index, oldest use first. A missing index is empty; an unreadable
+    /// one is treated the same, and [`Self::remove_unindexed`] reclaims the
+    /// entries it listed on the next store.
+    fn read_index(&self) -> Vec<IndexEntry> {
+        let Ok(bytes) = self.fs.read_file(self.index_path().as_path()) else {
+            return Vec::new();
+        };
+        let parsed = core::str::from_utf8(&bytes).ok().and_then(|text| {
+            text.lines()
+                .map(|line| {
+                    let (key, size) = line.split_once(' ')?;
+                    Some(IndexEntry {
+                        key: CodeCacheKey(u64::from_str_radix(key, 16).ok()?),
+                        bytes: size.parse().ok()?,
+                    })
+                })
+                .collect::<Option<Vec<_>>>()
+        });
+        parsed.unwrap_or_else(|| {
+            log::warn!("[code-cache] unreadable index, starting empty");
+            Vec::new()
+        })
+    }
+
+    fn write_index(&self, index: &[IndexEntry]) -> Result<(), FsError> {
+        let mut text = String::new();
+        for e in index {
+            let _ = writeln!(text, "{:016x} {}", e.key.0, e.bytes);
+        }
+        self.fs
+            .write_file(self.index_path().as_path(), text.as_bytes())
+    }
+
+    /// Delete entry files the index does not list — left by an interrupted
+    /// store or a discarded index — so they cannot hold flash outside the
+    /// budget.
+    fn remove_unindexed(&self, index: &[IndexEntry]) {
+        let Ok(paths) = self.fs.list_dir(self.dir.as_path(), false) else {
+            return;
+        };
+        for path in paths {
+            let path: &LpPath = path.as_path();
+            if path.extension() != Some(ENTRY_EXT) {
+                continue;
+            }
+            let indexed = path
+                .file_stem()
+                .and_then(|stem| u64::from_str_radix(stem, 16).ok())
+                .is_some_and(|k| index.iter().any(|e| e.key.0 == k));
+            if !indexed {
+                let _ = self.fs.delete_file(path);
+            }
+        }
+    }
+}
+
+impl<F: LpFs + Send + Sync> JitCodeCache for CodeCache<F> {
+    fn compiler_id(&self) -> &str {
+        &self.compiler_id
+    }
+
+    fn load(&self, key: CodeCacheKey, isa: IsaTarget) -> Option<CompiledModule> {
+        self.load_module(key, isa)
+    }
+
+    fn store(&self, key: CodeCacheKey, module: &CompiledModule) {
+        if let Err(e) = self.store_module(key, module) {
+            log::warn!("[code-cache] store failed: {e}");
+        }
+    }
+}
+
+// --- Entry encoding ---
+//
+// Little-endian throughout:
+//
+//   magic "LPJC" | format u32 | key u64 | function count u32
+//   per function: name | code | reloc count u32
+//     per reloc: offset u32 | r_type u32 | symbol
+//   rodata word count u32 | that many u32 words
+//   FNV-1a 32 of every preceding byte
+//
+// where name, code and symbol are a u32 length followed by that many bytes.
+
+fn encode(key: CodeCacheKey, module: &CompiledModule) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(MAGIC);
+    put_u32(&mut out, CODE_CACHE_FORMAT);
+    out.extend_from_slice(&key.0.to_le_bytes());
+    put_u32(&mut out, module.functions.len() as u32);
+    for f in &module.functions {
+        put_bytes(&mut out, f.name.as_bytes());
+        put_bytes(&mut out, &f.code);
+        put_u32(&mut out, f.relocs.len() as u32);
+        for r in &f.relocs {
+            put_u32(&mut out, r.offset as u32);
+            put_u32(&mut out, r.r_type);
+            put_bytes(&mut out, r.symbol.as_bytes());
+        }
+    }
+    put_u32(&mut out, module.rodata.len() as u32);
+    for &word in &module.rodata {
+        put_u32(&mut out, word);
+    }
+    let checksum = fnv1a_32(&out);
+    put_u32(&mut out, checksum);
+    out
+}
+
+fn decode(bytes: &[u8], key: CodeCacheKey, isa: IsaTarget) -> Result<CompiledModule, &'static str> {
+    let body_len = bytes.len().checked_sub(4).ok_or("truncated")?;
+    let (body, checksum) = bytes.split_at(body_len);
+    if fnv1a_32(body) != u32::from_le_bytes(checksum.try_into().unwrap()) {
+        return Err("checksum mismatch");
+    }
+    let mut r = Reader {
+        bytes: body,
+        pos: 0,
+    };
+    if r.take(4) != Some(MAGIC.as_slice()) {
+        return Err("bad magic");
+    }
+    if r.u32() != Some(CODE_CACHE_FORMAT) {
+        return Err("format version mismatch");
+    }
+    if r.u64() != Some(key.0) {
+        return Err("key mismatch");
+    }
+    let count = r.u32().ok_or("truncated")?;
+    let mut functions = Vec::new();
+    for _ in 0..count {
+        let name = r.string().ok_or("bad function name")?;
+        let code = r.bytes().ok_or("truncated")?.to_vec();
+        let reloc_count = r.u32().ok_or("truncated")?;
+        let mut relocs = Vec::new();
+        for _ in 0..reloc_count {
+            let offset = r.u32().ok_or("truncated")? as usize;
+            let r_type = r.u32().ok_or("truncated")?;
+            let symbol = r.string().ok_or("bad relocation symbol")?;
+            if r_type != isa.call_reloc_type() || symbol.is_empty() {
+                return Err("relocation does not match the target");
+            }
+            if offset
+                .checked_add(reloc_len(isa))
+                .is_none_or(|end| end > code.len())
+            {
+                return Err("relocation out of bounds");
+            }
+            relocs.push(NativeReloc {
+                offset,
+                symbol,
+                r_type,
+            });
+        }
+        functions.push(CompiledFunction {
+            name,
+            code,
+            relocs,
+            debug_lines: None,
+            debug_info: None,
+            alloc_stats: Default::default(),
+        });
+    }
+    let rodata_len = r.u32().ok_or("truncated")?;
+    let mut rodata = Vec::new();
+    for _ in 0..rodata_len {
+        rodata.push(r.u32().ok_or("truncated")?);
+    }
+    if r.pos != body.len() {
+        return Err("trailing bytes");
+    }
+    if functions.is_empty() {
+        return Err("no functions");
+    }
+    Ok(CompiledModule {
+        functions,
+        symbols: ModuleSymbols::default(),
+        rodata,
+    })
+}
+
+/// Bytes of code a call relocation patches.
+fn reloc_len(isa: IsaTarget) -> usize {
+    match isa {
+        // auipc + jalr
+        #[cfg(feature = "isa-rv32")]
+        IsaTarget::Rv32imac | IsaTarget::Rv32imafc => 8,
+        // literal-pool word
+        #[cfg(feature = "isa-xt")]
+        IsaTarget::Xtensa => 4,
+    }
+}
+
+fn put_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn put_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    put_u32(out, bytes.len() as u32);
+    out.extend_from_slice(bytes);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(n)?;
+        let out = self.bytes.get(self.pos..end)?;
+        self.pos = end;
+        Some(out)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let s = core::str::from_utf8(self.bytes()?).ok()?;
+        Some(String::from(s))
+    }
+}
+
+fn fnv1a_32(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for &b in bytes {
+        hash ^= u32::from(b);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash
+}
+
+/// FNV-1a 64 over a sequence of fields, each followed by a separator byte so
+/// adjacent fields cannot run together.
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn field(&mut self, bytes: &[u8]) {
+        self.bytes(bytes);
+        self.bytes(&[0xff]);
+    }
+
+    fn debug_field(&mut self, value: &impl fmt::Debug) {
+        let _ = write!(self, "{value:?}");
+        self.bytes(&[0xff]);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Write for Fnv64 {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bytes(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use lpfs::LpFsMemory;
+    use lpir::FloatMode;
+
+    const ISA: IsaTarget = IsaTarget::Rv32imac;
+
+    fn module(name: &str, code_len: usize) -> CompiledModule {
+        CompiledModule {
+            functions: vec![
+                CompiledFunction {
+                    name: String::from(name),
+                    code: vec![0x13; code_len],
+                    relocs: vec![NativeReloc {
+                        offset: 0,
+                        symbol: String::from("__lp_lpir_fdiv_q32"),
+                        r_type: ISA.call_reloc_type(),
+                    }],
+                    debug_lines: None,
+                    debug_info: None,
+                    alloc_stats: Default::default(),
+                },
+                CompiledFunction {
+                    name: String::from("helper"),
+                    code: vec![0x67, 0x80, 0x00, 0x00],
+                    relocs: vec![],
+                    debug_lines: None,
+                    debug_info: None,
+                    alloc_stats: Default::default(),
+                },
+            ],
+            symbols: ModuleSymbols::default(),
+            rodata: vec![7, 0x8000],
+        }
+    }
+
+    fn cache(max_bytes: u64) -> CodeCache<LpFsMemory> {
+        CodeCache::new(
+            LpFsMemory::new(),
+            LpPathBuf::from("/.jit-cache"),
+            max_bytes,
+            "test-build",
+        )
+    }
+
+    fn key_for(source_hash: u64, float_mode: FloatMode) -> CodeCacheKey {
+        CodeCacheKey::new(&CodeCacheInputs {
+            compiler_id: "test-build",
+            source_hash,
+            meta: &LpsModuleSig::default(),
+            options: &NativeCompileOptions {
+                float_mode,
+                ..NativeCompileOptions::default()
+            },
+            isa: ISA,
+        })
+    }
+
+    fn same_code(a: &CompiledModule, b: &CompiledModule) -> bool {
+        a.rodata == b.rodata
+            && a.functions.len() == b.functions.len()
+            && a.functions.iter().zip(&b.functions).all(|(x, y)| {
+                x.name == y.name
+                    && x.code == y.code
+                    && x.relocs.len() == y.relocs.len()
+                    && x.relocs.iter().zip(&y.relocs).all(|(p, q)| {
+                        p.offset == q.offset && p.symbol == q.symbol && p.r_type == q.r_type
+                    })
+            })
+    }
+
+    #[test]
+    fn key_covers_every_input() {
+        let base = key_for(1, FloatMode::Q32);
+        assert_eq!(base, key_for(1, FloatMode::Q32));
+        assert_ne!(base, key_for(2, FloatMode::Q32));
+        assert_ne!(base, key_for(1, FloatMode::F32));
+
+        let meta = LpsModuleSig {
+            uniforms_type: Some(lps_shared::LpsType::Float),
+            ..LpsModuleSig::default()
+        };
+        let q32 = NativeCompileOptions {
+            float_mode: FloatMode::Q32,
+            ..NativeCompileOptions::default()
+        };
+        let no_fuel = NativeCompileOptions {
+            fuel: false,
+            ..q32.clone()
+        };
+        let with = |compiler_id, meta, options, isa| {
+            CodeCacheKey::new(&CodeCacheInputs {
+                compiler_id,
+                source_hash: 1,
+                meta,
+                options,
+                isa,
+            })
+        };
+        let default_meta = LpsModuleSig::default();
+        assert_eq!(base, with("test-build", &default_meta, &q32, ISA));
+        assert_ne!(base, with("other-build", &default_meta, &q32, ISA));
+        assert_ne!(base, with("test-build", &meta, &q32, ISA));
+        assert_ne!(base, with("test-build", &default_meta, &no_fuel, ISA));
+        let fdiv_call = NativeCompileOptions {
+            inline_q32_fdiv: !q32.inline_q32_fdiv,
+            ..q32.clone()
+        };
+        assert_ne!(base, with("test-build", &default_meta, &fdiv_call, ISA));
+        assert_ne!(
+            base,
+            with("test-build", &default_meta, &q32, IsaTarget::Xtensa)
+        );
+        #[cfg(feature = "regalloc-split")]
+        {
+            let split = NativeCompileOptions {
+                regalloc: crate::regalloc::RegAllocStrategy::Split,
+                ..q32.clone()
+            };
+            assert_ne!(base, with("test-build", &default_meta, &split, ISA));
+        }
+    }
+
+    #[test]
+    fn store_then_load_round_trips() {
+        let cache = cache(4096);
+        let key = key_for(1, FloatMode::Q32);
+        assert!(cache.load_module(key, ISA).is_none());
+        let m = module("main", 16);
+        cache.store_module(key, &m).unwrap();
+        let loaded = cache.load_module(key, ISA).expect("hit");
+        assert!(same_code(&loaded, &m));
+        assert!(cache.used_bytes() > 0);
+    }
+
+    #[test]
+    fn invalid_entries_are_deleted_and_miss() {
+        let cache = cache(4096);
+        let key = key_for(1, FloatMode::Q32);
+        cache.store_module(key, &module("main", 16)).unwrap();
+        let path = cache.entry_path(key);
+
+        // One flipped code byte fails the checksum.
+        let mut bytes = cache.fs.read_file(path.as_path()).unwrap();
+        bytes[30] ^= 1;
+        cache.fs.write_file(path.as_path(), &bytes).unwrap();
+        assert!(cache.load_module(key, ISA).is_none());
+        assert!(!cache.fs.file_exists(path.as_path()).unwrap());
+        assert_eq!(cache.used_bytes(), 0);
+
+        // An entry filed under the wrong key.
+        let other = key_for(2, FloatMode::Q32);
+        cache.store_module(other, &module("main", 16)).unwrap();
+        let moved = cache
+            .fs
+            .read_file(cache.entry_path(other).as_path())
+            .unwrap();
+        cache.fs.write_file(path.as_path(), &moved).unwrap();
+        assert!(cache.load_module(key, ISA).is_none());
+
+        // Relocations for another ISA.
+        cache.store_module(key, &module("main", 16)).unwrap();
+        assert!(cache.load_module(key, IsaTarget::Xtensa).is_none());
+    }
+
+    #[test]
+    fn relocation_past_the_code_is_rejected() {
+        let key = key_for(1, FloatMode::Q32);
+        let mut m = module("main", 16);
+        m.functions[0].relocs[0].offset = 12;
+        assert_eq!(
+            decode(&encode(key, &m), key, ISA).err(),
+            Some("relocation out of bounds")
+        );
+        m.functions[0].relocs[0].offset = 8;
+        assert!(decode(&encode(key, &m), key, ISA).is_ok());
+    }
+
+    #[test]
+    fn evicts_least_recently_used_to_stay_in_budget() {
+        let entry = encode(key_for(0, FloatMode::Q32), &module("main", 64)).len() as u64;
+        let cache = cache(entry * 2);
+        let (a, b, c) = (
+            key_for(1, FloatMode::Q32),
+            key_for(2, FloatMode::Q32),
+            key_for(3, FloatMode::Q32),
+        );
+        cache.store_module(a, &module("main", 64)).unwrap();
+        cache.store_module(b, &module("main", 64)).unwrap();
+        // Using `a` makes `b` the oldest.
+        assert!(cache.load_module(a, ISA).is_some());
+        cache.store_module(c, &module("main", 64)).unwrap();
+
+        assert!(cache.used_bytes() <= cache.max_bytes());
+        assert!(cache.load_module(a, ISA).is_some());
+        assert!(cache.load_module(b, ISA).is_none());
+        assert!(cache.load_module(c, ISA).is_some());
+
+        // Larger than the whole budget: not stored, nothing evicted.
+        let d = key_for(4, FloatMode::Q32);
+        cache.store_module(d, &module("main", 1024)).unwrap();
+        assert!(cache.load_module(d, ISA).is_none());
+        assert!(cache.load_module(a, ISA).is_some());
+    }
+
+    #[test]
+    fn unindexed_entries_are_reclaimed() {
+        let cache = cache(4096);
+        let orphan = key_for(9, FloatMode::Q32);
+        let orphan_path = cache.entry_path(orphan);
+        cache
+            .fs
+            .write_file(orphan_path.as_path(), &encode(orphan, &module("main", 16)))
+            .unwrap();
+        cache
+            .fs
+            .write_file(cache.index_path().as_path(), b"not an index")
+            .unwrap();
+        cache
+            .store_module(key_for(1, FloatMode::Q32), &module("main", 16))
+            .unwrap();
+        assert!(!cache.fs.file_exists(orphan_path.as_path()).unwrap());
+        assert!(cache.load_module(key_for(1, FloatMode::Q32), ISA).is_some());
+    }
+}
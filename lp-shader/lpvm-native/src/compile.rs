@@ -7,7 +7,7 @@ mod stages;
 use alloc::string::String;
 use alloc::vec::Vec;
 
-use lpir::{FloatMode, IrFunction, LpirModule};
+use lpir::{FloatMode, IrFunction, LpirModule, PassPipeline};
 use lps_shared::LpsFnSig;
 use lpvm::FunctionDebugInfo;
 
@@ -16,11 +16,17 @@ use crate::abi::FuncAbi;
 use crate::abi::ModuleAbi;
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
+use crate::regalloc::AllocStats;
 use crate::vinst::ModuleSymbols;
 
 pub use module_job::NativeCompileJob;
 pub use stages::{NativeCompileBudget, NativeCompileStage, NativeCompileStepResult};
 
+/// Symbol a module's read-only data is linked under. [`lpir::LpirOp::RodataAddr`]
+/// lowers to its address, relocated like a call target; linkers answer it
+/// with wherever they keep [`CompiledModule::rodata`].
+pub const RODATA_SYMBOL: &str = "__lpir_rodata";
+
 /// Relocation entry for a call site.
 #[derive(Clone, Debug)]
 pub struct NativeReloc {
@@ -45,6 +51,9 @@ pub struct CompiledFunction {
     pub debug_lines: Option<Vec<(u32, Option<u32>)>>,
     /// Structured debug info with sections.
     pub debug_info: Option<FunctionDebugInfo>,
+    /// Spill traffic of the register allocation (all zero for a function
+    /// loaded from the code cache, which does not store it).
+    pub alloc_stats: AllocStats,
 }
 
 /// Output of a full module compilation.
@@ -54,6 +63,9 @@ pub struct CompiledModule {
     pub functions: Vec<CompiledFunction>,
     /// Module-level symbol table (for interned strings).
     pub symbols: ModuleSymbols,
+    /// The module's read-only data, float words encoded for the float mode.
+    /// Kept as words so every buffer holding it is word-aligned.
+    pub rodata: Vec<u32>,
 }
 
 /// Module-level state shared across function compilations.
@@ -68,6 +80,9 @@ pub struct CompileSession {
     pub float_mode: FloatMode,
     /// Compilation options.
     pub options: crate::native_options::NativeCompileOptions,
+    /// LPIR passes run on each function before lowering, from
+    /// `options.config.opt`.
+    pub pipeline: PassPipeline,
 }
 
 impl CompileSession {
@@ -78,12 +93,14 @@ impl CompileSession {
         float_mode: FloatMode,
         options: crate::native_options::NativeCompileOptions,
     ) -> Self {
+        let pipeline = PassPipeline::new(&options.config.opt, float_mode);
         Self {
             symbols: ModuleSymbols::default(),
             abi,
             isa,
             float_mode,
             options,
+            pipeline,
         }
     }
 }
@@ -96,23 +113,35 @@ pub(crate) fn compile_function_func_abi(
     match session.isa {
         #[cfg(feature = "isa-rv32")]
         IsaTarget::Rv32imac => crate::isa::rv32::abi::func_abi_rv32(fn_sig, Some(func)),
+        #[cfg(feature = "isa-rv32")]
+        IsaTarget::Rv32imafc => crate::isa::rv32::abi::func_abi_rv32f(fn_sig, Some(func)),
         #[cfg(feature = "isa-xt")]
         IsaTarget::Xtensa => crate::isa::xt::abi::func_abi_xt(fn_sig, Some(func)),
     }
 }
 
-/// Const-fold a function in place. In the job path `func` is the function
-/// inside the job's own `LpirModule`; folding never changes signatures, so
-/// later lowering of other functions (which reads callee names only) is
+/// Run step `step` of `pipeline` over `func` in place. Returns `false` once
+/// every pass has run. In the job path `func` is the function inside the
+/// job's own `LpirModule`; the passes never change signatures, so later
+/// lowering of other functions (which reads callee names only) is
 /// unaffected.
-pub(crate) fn compile_function_fold_constants(func: &mut IrFunction) {
-    let n_folded = lpir::const_fold::fold_constants(func);
-    if n_folded > 0 {
+pub(crate) fn compile_function_optimize_step(
+    func: &mut IrFunction,
+    pipeline: &PassPipeline,
+    step: usize,
+) -> bool {
+    let Some(&pass) = pipeline.passes().get(step) else {
+        return false;
+    };
+    let changed = pipeline.run_pass(pass, func);
+    if changed > 0 {
         log::debug!(
-            "[native-fa] compile_function: folded {n_folded} LPIR constants for {}",
+            "[native-fa] compile_function: {} changed {changed} LPIR ops in {}",
+            pass.name(),
             func.name
         );
     }
+    true
 }
 
 pub(crate) fn compile_function_lower_stage(
@@ -124,6 +153,7 @@ pub(crate) fn compile_function_lower_stage(
     let lower_opts = LowerOpts {
         float_mode: session.float_mode,
         fuel: session.options.fuel,
+        inline_q32_fdiv: session.options.inline_q32_fdiv,
     };
     let lowered =
         crate::lower::lower_ops(func, ir, &session.abi, &lower_opts).map_err(NativeError::Lower)?;
@@ -152,7 +182,7 @@ pub(crate) fn compile_function_peephole(
 
 pub(crate) fn compile_function_regalloc_stage(
     state: &mut function_job::FunctionCompileState,
-    _session: &CompileSession,
+    session: &CompileSession,
 ) -> Result<(), NativeError> {
     let Some(lowered) = state.lowered.as_ref() else {
         return Err(NativeError::Internal(format!(
@@ -161,7 +191,9 @@ pub(crate) fn compile_function_regalloc_stage(
         )));
     };
     let alloc_result =
-        crate::regalloc::allocate(lowered, &state.func_abi).map_err(NativeError::RegAlloc)?;
+        crate::regalloc::allocate_with(lowered, &state.func_abi, session.options.regalloc)
+            .map_err(NativeError::RegAlloc)?;
+    state.alloc_stats = AllocStats::of(&alloc_result.output);
     state.alloc_result = Some(alloc_result);
     Ok(())
 }
@@ -242,6 +274,7 @@ pub(crate) fn compile_function_debug_sections(
         relocs: emitted.relocs,
         debug_lines,
         debug_info,
+        alloc_stats: state.alloc_stats,
     });
     Ok(())
 }
@@ -257,7 +290,7 @@ pub(crate) fn compile_function_finalize(
     })
 }
 
-/// Compile one function: LPIR → (const fold) → VInst → (imm fold) → AllocOutput → bytes.
+/// Compile one function: LPIR → (pass pipeline) → VInst → (imm fold) → AllocOutput → bytes.
 pub fn compile_function(
     session: &mut CompileSession,
     func: &IrFunction,
@@ -271,10 +304,14 @@ pub fn compile_function(
     );
 
     let func_abi = compile_function_func_abi(session, func, fn_sig);
-    // Standalone path (host/tests): the caller's module is borrowed, so fold
-    // a local copy. The job path folds in place inside its owned module.
+    // Standalone path (host/tests): the caller's module is borrowed, so
+    // optimize a local copy. The job path optimizes in place inside its
+    // owned module.
     let mut folded = func.clone();
-    compile_function_fold_constants(&mut folded);
+    let mut step = 0;
+    while compile_function_optimize_step(&mut folded, &session.pipeline, step) {
+        step += 1;
+    }
     let mut state =
         function_job::FunctionCompileState::new(0, lpir::FuncId(0), folded.name.clone(), func_abi);
     compile_function_lower_stage(&mut state, &folded, ir, session)?;
@@ -320,6 +357,7 @@ mod tests {
             &LpirModule {
                 imports: vec![],
                 functions: Default::default(),
+                rodata: Vec::new(),
             },
             &LpsModuleSig::default(),
         );
@@ -337,6 +375,7 @@ mod tests {
         let ir = LpirModule {
             imports: vec![],
             functions: VecMap::new(),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig::default();
         let result = compile_module(
@@ -379,6 +418,7 @@ mod tests {
                     vreg_pool: vec![VReg(0)],
                 },
             )]),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig {
             functions: vec![LpsFnSig {
@@ -485,6 +525,7 @@ mod tests {
                     vreg_pool: vec![VReg(0)],
                 },
             )]),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig {
             functions: vec![LpsFnSig {
@@ -498,6 +539,45 @@ mod tests {
         (ir, sig)
     }
 
+    #[test]
+    fn rodata_is_encoded_for_the_float_mode_and_relocated() {
+        let ir = lpir::parse_module(
+            "rodata i32 3, f32 0.25
+
+func @test() -> i32 {
+  v1:ptr = rodata_addr
+  v2:i32 = load v1, 0
+  return v2
+}
+",
+        )
+        .expect("parse");
+        let (_, sig) = simple_iconst_module();
+        for (float_mode, isa, quarter) in [
+            (lpir::FloatMode::Q32, IsaTarget::Rv32imac, 0x4000),
+            (
+                lpir::FloatMode::F32,
+                IsaTarget::Rv32imafc,
+                0.25f32.to_bits(),
+            ),
+            (lpir::FloatMode::Q32, IsaTarget::Xtensa, 0x4000),
+        ] {
+            let options = crate::native_options::NativeCompileOptions {
+                float_mode,
+                ..Default::default()
+            };
+            let module = compile_module(&ir, &sig, float_mode, options, isa).expect("compile");
+            assert_eq!(module.rodata, vec![3, quarter], "{isa:?}");
+            assert!(
+                module.functions[0]
+                    .relocs
+                    .iter()
+                    .any(|r| r.symbol == RODATA_SYMBOL),
+                "{isa:?}: rodata_addr must be relocated against {RODATA_SYMBOL}"
+            );
+        }
+    }
+
     #[test]
     fn native_compile_job_single_step_reaches_finished_module() {
         let (ir, sig) = simple_iconst_module();
@@ -522,19 +602,33 @@ mod tests {
                 }
             }
         }
-        assert_eq!(
-            seen,
-            vec![
-                NativeCompileStage::SetupModule,
-                NativeCompileStage::CompileFunctionConstFold,
-                NativeCompileStage::CompileFunctionLower,
-                NativeCompileStage::CompileFunctionPeephole,
-                NativeCompileStage::CompileFunctionRegalloc,
-                NativeCompileStage::CompileFunctionEmit,
-                NativeCompileStage::CompileFunctionDebug,
-                NativeCompileStage::AssembleModule,
-            ]
-        );
+        // One optimize step per pass the default config enables: each LPIR
+        // pass is its own budgeted step, so a device compile yields between
+        // passes rather than running the whole pipeline in one slice. The
+        // count follows `OptConfig`'s defaults, not a number of its own.
+        let passes = PassPipeline::new(
+            &crate::native_options::NativeCompileOptions::default()
+                .config
+                .opt,
+            lpir::FloatMode::Q32,
+        )
+        .passes()
+        .len();
+        assert!(passes > 0, "the default config runs LPIR passes");
+        let mut expected = vec![NativeCompileStage::SetupModule];
+        expected.extend(core::iter::repeat_n(
+            NativeCompileStage::CompileFunctionOptimize,
+            passes,
+        ));
+        expected.extend([
+            NativeCompileStage::CompileFunctionLower,
+            NativeCompileStage::CompileFunctionPeephole,
+            NativeCompileStage::CompileFunctionRegalloc,
+            NativeCompileStage::CompileFunctionEmit,
+            NativeCompileStage::CompileFunctionDebug,
+            NativeCompileStage::AssembleModule,
+        ]);
+        assert_eq!(seen, expected);
     }
 
     #[test]
@@ -581,4 +675,104 @@ mod tests {
             (lhs, rhs) => panic!("debug_info mismatch: left={lhs:?} right={rhs:?}"),
         }
     }
+
+    /// The whole RV32F pipeline on a function that exercises each of its seams:
+    /// a mixed int/float parameter list (the entry ABI stays word-based), an
+    /// inline `fdiv.s`, and a builtin (`fmin`) that crosses the `ilp32f`
+    /// boundary. Compile-only; execution is the filetest matrix's job.
+    #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+    #[test]
+    fn rv32f_compiles_inline_division_and_a_float_bank_builtin_call() {
+        use lpir::builder::FunctionBuilder;
+        use lps_shared::{FnParam, ParamQualifier};
+
+        let mut fb = FunctionBuilder::new("f", &[IrType::F32]);
+        let a = fb.add_param(IrType::F32);
+        let n = fb.add_param(IrType::I32);
+        let b = fb.add_param(IrType::F32);
+        let q = fb.alloc_vreg(IrType::F32);
+        let m = fb.alloc_vreg(IrType::F32);
+        let nf = fb.alloc_vreg(IrType::F32);
+        let out = fb.alloc_vreg(IrType::F32);
+        fb.push(LpirOp::Fdiv {
+            dst: q,
+            lhs: a,
+            rhs: b,
+        });
+        fb.push(LpirOp::Fmin {
+            dst: m,
+            lhs: q,
+            rhs: a,
+        });
+        fb.push(LpirOp::ItofS { dst: nf, src: n });
+        fb.push(LpirOp::Fadd {
+            dst: out,
+            lhs: m,
+            rhs: nf,
+        });
+        fb.push_return(&[out]);
+        let ir = LpirModule {
+            imports: vec![],
+            functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
+        };
+        let param = |name: &str, ty| FnParam {
+            name: String::from(name),
+            ty,
+            qualifier: ParamQualifier::In,
+        };
+        let sig = LpsModuleSig {
+            functions: vec![LpsFnSig {
+                name: String::from("f"),
+                return_type: LpsType::Float,
+                parameters: vec![
+                    param("a", LpsType::Float),
+                    param("n", LpsType::Int),
+                    param("b", LpsType::Float),
+                ],
+                kind: LpsFnKind::UserDefined,
+            }],
+            ..Default::default()
+        };
+
+        let module = compile_module(
+            &ir,
+            &sig,
+            lpir::FloatMode::F32,
+            Default::default(),
+            IsaTarget::Rv32imafc,
+        )
+        .expect("rv32f compile");
+        let f = &module.functions[0];
+
+        assert!(
+            f.relocs.iter().any(|r| r.symbol == "__lp_lpir_fmin_f32"),
+            "fmin is a builtin call: {:?}",
+            f.relocs,
+        );
+        assert!(
+            !f.relocs.iter().any(|r| r.symbol.contains("fdiv")),
+            "fdiv.s is inline on RV32F, not a call: {:?}",
+            f.relocs,
+        );
+
+        // Walk the stream by instruction length and look for `fdiv.s`
+        // (OP-FP, funct7 0b0001100).
+        let mut words = Vec::new();
+        let mut at = 0;
+        while at < f.code.len() {
+            if f.code[at] & 0b11 == 0b11 {
+                words.push(u32::from_le_bytes(f.code[at..at + 4].try_into().unwrap()));
+                at += 4;
+            } else {
+                at += 2;
+            }
+        }
+        assert!(
+            words
+                .iter()
+                .any(|w| w & 0x7f == 0b1010011 && w >> 25 == 0b0001100),
+            "expected an inline fdiv.s",
+        );
+    }
 }
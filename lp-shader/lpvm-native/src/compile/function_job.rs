@@ -2,13 +2,13 @@ use crate::CompiledFunction;
 use crate::abi::FuncAbi;
 use crate::emit::EmittedCode;
 use crate::lower::LoweredFunction;
-use crate::regalloc::AllocResult;
+use crate::regalloc::{AllocResult, AllocStats};
 use lpir::FuncId;
 
 /// Per-function bookkeeping for [`super::module_job::NativeCompileJob`].
 ///
 /// Holds only stage artifacts and the function's identity. The LPIR body is
-/// NOT copied here: stages read (and const-fold mutates) the function inside
+/// NOT copied here: stages read (and the LPIR passes mutate) the function inside
 /// the job's own `LpirModule`, keyed by `func_id`. Earlier versions kept
 /// `original`/`optimized` clones per function, which tripled IR residency on
 /// the 320 KB device heap.
@@ -17,8 +17,11 @@ pub(crate) struct FunctionCompileState {
     pub(crate) func_id: FuncId,
     pub(crate) name: alloc::string::String,
     pub(crate) func_abi: FuncAbi,
+    /// Next step of the session's pass pipeline to run.
+    pub(crate) next_pass: usize,
     pub(crate) lowered: Option<LoweredFunction>,
     pub(crate) alloc_result: Option<AllocResult>,
+    pub(crate) alloc_stats: AllocStats,
     pub(crate) emitted: Option<EmittedCode>,
     pub(crate) compiled: Option<CompiledFunction>,
     pub(crate) finished: bool,
@@ -36,8 +39,10 @@ impl FunctionCompileState {
             func_id,
             name,
             func_abi,
+            next_pass: 0,
             lowered: None,
             alloc_result: None,
+            alloc_stats: AllocStats::default(),
             emitted: None,
             compiled: None,
             finished: false,
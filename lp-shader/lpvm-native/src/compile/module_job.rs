@@ -10,16 +10,16 @@ use crate::CompiledModule;
 use crate::abi::ModuleAbi;
 use crate::compile::{
     CompileSession, compile_function_debug_sections, compile_function_emit_stage,
-    compile_function_finalize, compile_function_fold_constants, compile_function_func_abi,
-    compile_function_lower_stage, compile_function_peephole, compile_function_regalloc_stage,
+    compile_function_finalize, compile_function_func_abi, compile_function_lower_stage,
+    compile_function_optimize_step, compile_function_peephole, compile_function_regalloc_stage,
 };
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 use crate::native_options::NativeCompileOptions;
 
 pub struct NativeCompileJob {
-    /// The job's own module. Stages borrow from it directly — const fold
-    /// mutates functions in place — so no per-stage or per-function IR
+    /// The job's own module. Stages borrow from it directly — the LPIR passes
+    /// mutate functions in place — so no per-stage or per-function IR
     /// copies are made (they used to triple IR residency during compile).
     ir: LpirModule,
     stage: NativeCompileStage,
@@ -85,7 +85,7 @@ impl NativeCompileJob {
                     self.stage = if self.functions.is_empty() {
                         NativeCompileStage::AssembleModule
                     } else {
-                        NativeCompileStage::CompileFunctionConstFold
+                        NativeCompileStage::CompileFunctionOptimize
                     };
                     NativeCompileStepResult::Pending
                 }
@@ -94,15 +94,17 @@ impl NativeCompileJob {
                     NativeCompileStepResult::Failed(err)
                 }
             },
-            NativeCompileStage::CompileFunctionConstFold => {
-                self.run_function_stage(|ir, func, _session| {
+            NativeCompileStage::CompileFunctionOptimize => {
+                self.run_function_stage(|ir, func, session| {
                     let body = ir.functions.get_mut(&func.func_id).ok_or_else(|| {
                         NativeError::Internal(alloc::format!(
-                            "const fold stage missing function {} in module",
+                            "optimize stage missing function {} in module",
                             func.name
                         ))
                     })?;
-                    compile_function_fold_constants(body);
+                    if compile_function_optimize_step(body, &session.pipeline, func.next_pass) {
+                        func.next_pass += 1;
+                    }
                     Ok(())
                 })
             }
@@ -178,6 +180,21 @@ impl NativeCompileJob {
                 self.ir.functions.len()
             );
         }
+        // Inline before anything reads function bodies; signatures are
+        // unchanged, so the ABI below and `self.sig` stay valid.
+        let mut inline = self.options.config.inline.clone();
+        inline
+            .module_op_budget
+            .get_or_insert(self.isa.inline_module_op_budget());
+        let inlined = lpir::inline_module(&mut self.ir, &inline);
+        if inlined.sites_inlined > 0 || inlined.sites_over_budget > 0 {
+            log::debug!(
+                "[native-fa] NativeCompileJob: inlined {} call sites (+{} ops), {} over budget",
+                inlined.sites_inlined,
+                inlined.ops_added,
+                inlined.sites_over_budget
+            );
+        }
         let module_abi = ModuleAbi::from_ir_and_sig(self.isa, &self.ir, &self.sig);
         let session =
             CompileSession::new(module_abi, self.isa, self.float_mode, self.options.clone());
@@ -220,9 +237,14 @@ impl NativeCompileJob {
             .take()
             .map(|session| session.symbols)
             .unwrap_or_default();
+        let rodata = match self.float_mode {
+            FloatMode::Q32 => self.ir.rodata_words(|f| lps_q32::q32_encode(f) as u32),
+            FloatMode::F32 => self.ir.rodata_words(f32::to_bits),
+        };
         Ok(CompiledModule {
             functions: core::mem::take(&mut self.completed_functions),
             symbols,
+            rodata,
         })
     }
 
@@ -277,8 +299,14 @@ impl NativeCompileJob {
             );
         }
         match self.stage {
-            NativeCompileStage::CompileFunctionConstFold => {
-                self.stage = NativeCompileStage::CompileFunctionLower;
+            NativeCompileStage::CompileFunctionOptimize => {
+                let passes = self
+                    .session
+                    .as_ref()
+                    .map_or(0, |session| session.pipeline.passes().len());
+                if self.functions[index].next_pass >= passes {
+                    self.stage = NativeCompileStage::CompileFunctionLower;
+                }
             }
             NativeCompileStage::CompileFunctionLower => {
                 self.stage = NativeCompileStage::CompileFunctionPeephole;
@@ -302,7 +330,7 @@ impl NativeCompileJob {
                 func.finished = true;
                 self.completed_functions.push(compiled);
                 if self.next_incomplete_function_slot().is_some() {
-                    self.stage = NativeCompileStage::CompileFunctionConstFold;
+                    self.stage = NativeCompileStage::CompileFunctionOptimize;
                 } else {
                     self.stage = NativeCompileStage::AssembleModule;
                 }
@@ -42,7 +42,8 @@ pub enum NativeCompileStepResult {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NativeCompileStage {
     SetupModule,
-    CompileFunctionConstFold,
+    /// One LPIR pass per step; repeats until the pipeline is done.
+    CompileFunctionOptimize,
     CompileFunctionLower,
     CompileFunctionPeephole,
     CompileFunctionRegalloc,
@@ -18,8 +18,8 @@
 //!   @0:                        // label definition
 //!
 //! Hardware float (the `F*` family):
-//!   i2 = FAdd i0, i1           // FAluRRR: FAdd / FSub / FMul
-//!   i2 = FAbs i0               // FAluRR:  FMov / FAbs / FNeg
+//!   i2 = FAdd i0, i1           // FAluRRR: FAdd / FSub / FMul / FDiv
+//!   i2 = FAbs i0               // FAluRR:  FMov / FAbs / FNeg / FSqrt
 //!   i3 = Fcmp Lt, i0, i1       // IEEE compare, integer 0/1 result
 //!   i3 = FSelect i0, i1, i2    // cond, if_true, if_false
 //!   i1 = FLoad32 i0, 4         // base, offset (optional)
@@ -27,6 +27,7 @@
 //!   i1 = Wfr i0                // AR -> FR, bit-for-bit
 //!   i1 = Rfr i0                // FR -> AR, bit-for-bit
 //!   i1 = IToFS i0              // int -> float conversion (IToFS / IToFU)
+//!   i1 = FToIS i0              // float -> int, saturating (FToIS / FToIU)
 //!
 //! Every vreg is spelled `iN` regardless of register class. The class is not a
 //! property of the vreg's *name* — it is read off the instruction (see
@@ -448,6 +449,20 @@ fn parse_def_instruction(
             })
         }
 
+        "SymbolAddr" => {
+            if dsts.len() != 1 {
+                return Err(ParseError {
+                    line: line_num,
+                    message: "SymbolAddr needs 1 dst".into(),
+                });
+            }
+            Ok(VInst::SymbolAddr {
+                dst: dsts[0],
+                target: symbols.intern(args_str.trim()),
+                src_op: SRC_OP_NONE,
+            })
+        }
+
         "Call" => {
             // Format: mod (i0, i1) or just mod for no args
             let open_paren = args_str.find('(').unwrap_or(args_str.len());
@@ -464,10 +479,13 @@ fn parse_def_instruction(
                 target,
                 args: args_slice,
                 rets: rets_slice,
-                // Textual VInst snapshot format does not encode sret; tests use defaults.
+                // Textual VInst snapshot format encodes neither sret nor the float
+                // argument banks; tests use defaults.
                 callee_uses_sret: false,
                 caller_passes_sret_ptr: false,
                 caller_sret_vm_abi_swap: false,
+                float_args: 0,
+                float_rets: 0,
                 src_op: SRC_OP_NONE,
             })
         }
@@ -578,6 +596,16 @@ fn parse_def_instruction(
             })
         }
 
+        "FToIS" | "FToIU" => {
+            expect_one_dst(&dsts, op, line_num)?;
+            Ok(VInst::FToI {
+                dst: dsts[0],
+                src: parse_ireg(args_str.trim())?,
+                signed: op == "FToIS",
+                src_op: SRC_OP_NONE,
+            })
+        }
+
         _ => Err(ParseError {
             line: line_num,
             message: format!("Unknown instruction: {op}"),
@@ -849,6 +877,8 @@ fn parse_nodef_instruction(
             callee_uses_sret: false,
             caller_passes_sret_ptr: false,
             caller_sret_vm_abi_swap: false,
+            float_args: 0,
+            float_rets: 0,
             src_op: SRC_OP_NONE,
         });
     }
@@ -1047,6 +1077,9 @@ fn format_vinst(inst: &VInst, pool: &[VReg], symbols: &ModuleSymbols) -> String
         VInst::SlotAddr { dst, slot, .. } => {
             format!("{} = SlotAddr {}", ireg(dst), slot)
         }
+        VInst::SymbolAddr { dst, target, .. } => {
+            format!("{} = SymbolAddr {}", ireg(dst), symbols.name(*target))
+        }
         VInst::MemcpyWords {
             dst_base,
             src_base,
@@ -1166,6 +1199,14 @@ fn format_vinst(inst: &VInst, pool: &[VReg], symbols: &ModuleSymbols) -> String
             if *signed { "IToFS" } else { "IToFU" },
             ireg(src)
         ),
+        VInst::FToI {
+            dst, src, signed, ..
+        } => format!(
+            "{} = {} {}",
+            ireg(dst),
+            if *signed { "FToIS" } else { "FToIU" },
+            ireg(src)
+        ),
     }
 }
 
@@ -1385,9 +1426,11 @@ Ret i2
             "i0 = FAdd i1, i2",
             "i0 = FSub i1, i2",
             "i0 = FMul i1, i2",
+            "i0 = FDiv i1, i2",
             "i0 = FMov i1",
             "i0 = FAbs i1",
             "i0 = FNeg i1",
+            "i0 = FSqrt i1",
             "i0 = Fcmp Eq, i1, i2",
             "i0 = Fcmp Ne, i1, i2",
             "i0 = Fcmp Lt, i1, i2",
@@ -1401,6 +1444,8 @@ Ret i2
             "i0 = Rfr i1",
             "i0 = IToFS i1",
             "i0 = IToFU i1",
+            "i0 = FToIS i1",
+            "i0 = FToIU i1",
         ];
         for line in lines {
             let (vinsts, syms, pool) = parse(line).unwrap_or_else(|e| panic!("{line}: {e:?}"));
@@ -68,6 +68,7 @@ pub fn compile_module_asm_text(
 mod tests {
     use alloc::string::String;
     use alloc::vec;
+    use alloc::vec::Vec;
 
     use lp_collection::VecMap;
 
@@ -106,6 +107,7 @@ mod tests {
                     vreg_pool: vec![VReg(3)],
                 },
             )]),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig {
             functions: vec![LpsFnSig {
@@ -127,13 +127,15 @@ pub fn max_outgoing_stack_bytes(vinsts: &[VInst], func_abi: &FuncAbi) -> u32 {
             args,
             callee_uses_sret,
             caller_passes_sret_ptr,
+            float_args,
             ..
         } = inst
         {
             let cap = func_abi
                 .isa()
                 .lpir_call_stack_args_start(*callee_uses_sret, *caller_passes_sret_ptr);
-            let n = args.len();
+            // Float-bank arguments never reach the stack (see `plan_call`).
+            let n = args.len() - float_args.count_ones() as usize;
             if n > cap {
                 let stack_words = (n - cap) as u32;
                 max_bytes = max_bytes.max(stack_words * 4);
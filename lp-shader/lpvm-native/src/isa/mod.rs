@@ -42,6 +42,12 @@ pub(crate) use shared::IsaEmitOutput;
 pub enum IsaTarget {
     #[cfg(feature = "isa-rv32")]
     Rv32imac,
+    /// RV32IMAFC (`riscv32imafc-unknown-none-elf`, `ilp32f`): the same integer
+    /// backend as [`IsaTarget::Rv32imac`], plus the F extension's register file
+    /// and instructions for [`lpir::FloatMode::F32`]. Every integer hook
+    /// answers exactly as `Rv32imac` does; only the float hooks differ.
+    #[cfg(feature = "isa-rv32")]
+    Rv32imafc,
     /// Xtensa windowed-ABI target (ESP32-S3 / LX7 and classic ESP32 / LX6 —
     /// the two are ISA-identical for the emitted integer subset; only the JIT
     /// buffer placement differs per chip, and that lives in `rt_jit`).
@@ -60,11 +66,9 @@ pub enum IsaTarget {
 /// the choice has to be a *named property of the target*, checked in one place,
 /// rather than an assumption baked into shared lowering.
 ///
-/// Note what makes this safe today: **no variant of [`IsaTarget`] answers
-/// [`F32Lowering::HardwareFpu`]**, so no code path in this crate can emit an
-/// FP-register instruction. The variant is not speculative padding — it is the
-/// thing a new `Rv32imafc` (or the Xtensa FPU backend, roadmap M7) flips, and
-/// having it here means that change is one arm of one match instead of a search
+/// Two variants of [`IsaTarget`] answer [`F32Lowering::HardwareFpu`], and only
+/// in a build with `float-f32`: `Xtensa` (the S3's coprocessor, roadmap M7)
+/// and `Rv32imafc`. Each was one arm of one match here rather than a search
 /// for every place float lowering assumed soft calls.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum F32Lowering {
@@ -90,10 +94,11 @@ impl IsaTarget {
     /// never satisfy a float constraint, and the same hardware encoding names
     /// two different registers in the two classes.
     ///
-    /// Only one backend has a float pool: Xtensa, and only when `float-f32` is
-    /// enabled. rv32's f32 path is soft float, which keeps every value in
-    /// integer registers (see [`F32Lowering::SoftFloatCalls`]), so an empty
-    /// float pool is the *correct* answer there rather than a missing feature.
+    /// Two backends have a float pool, and only when `float-f32` is enabled:
+    /// Xtensa and `Rv32imafc`. `Rv32imac`'s f32 path is soft float, which keeps
+    /// every value in integer registers (see [`F32Lowering::SoftFloatCalls`]),
+    /// so an empty float pool is the *correct* answer there rather than a
+    /// missing feature.
     ///
     /// An empty pool is not a silent fallback: a float vreg reaching the
     /// allocator on such a target fails with [`AllocError::OutOfRegisters`]
@@ -106,13 +111,17 @@ impl IsaTarget {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::ALLOC_POOL,
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::gpr::ALLOC_POOL,
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::ALLOC_POOL,
             },
             RegClass::Float => match self {
                 #[cfg(feature = "isa-rv32")]
                 IsaTarget::Rv32imac => &[],
+                #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+                IsaTarget::Rv32imafc => crate::isa::rv32::fpr::ALLOC_POOL,
+                #[cfg(all(feature = "isa-rv32", not(feature = "float-f32")))]
+                IsaTarget::Rv32imafc => &[],
                 #[cfg(all(feature = "isa-xt", feature = "float-f32"))]
                 IsaTarget::Xtensa => crate::isa::xt::fpr::ALLOC_POOL,
                 #[cfg(all(feature = "isa-xt", not(feature = "float-f32")))]
@@ -126,13 +135,19 @@ impl IsaTarget {
         match p.class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::pool_contains(p.hw),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                    crate::isa::rv32::gpr::pool_contains(p.hw)
+                }
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::pool_contains(p.hw),
             },
             RegClass::Float => match self {
                 #[cfg(feature = "isa-rv32")]
                 IsaTarget::Rv32imac => false,
+                #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+                IsaTarget::Rv32imafc => crate::isa::rv32::fpr::pool_contains(p.hw),
+                #[cfg(all(feature = "isa-rv32", not(feature = "float-f32")))]
+                IsaTarget::Rv32imafc => false,
                 #[cfg(all(feature = "isa-xt", feature = "float-f32"))]
                 IsaTarget::Xtensa => crate::isa::xt::fpr::pool_contains(p.hw),
                 #[cfg(all(feature = "isa-xt", not(feature = "float-f32")))]
@@ -146,7 +161,7 @@ impl IsaTarget {
         match p.class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::reg_name(p.hw),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::gpr::reg_name(p.hw),
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::reg_name(p.hw),
             },
@@ -156,6 +171,10 @@ impl IsaTarget {
                 // legible placeholder rather than an `unreachable!`.
                 #[cfg(feature = "isa-rv32")]
                 IsaTarget::Rv32imac => "f?",
+                #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+                IsaTarget::Rv32imafc => crate::isa::rv32::fpr::reg_name(p.hw),
+                #[cfg(all(feature = "isa-rv32", not(feature = "float-f32")))]
+                IsaTarget::Rv32imafc => "f?",
                 #[cfg(all(feature = "isa-xt", feature = "float-f32"))]
                 IsaTarget::Xtensa => crate::isa::xt::fpr::reg_name(p.hw),
                 #[cfg(all(feature = "isa-xt", not(feature = "float-f32")))]
@@ -169,7 +188,7 @@ impl IsaTarget {
     pub fn sret_uses_buffer_for(self, scalar_count: u32) -> bool {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 (scalar_count as usize) > crate::isa::rv32::abi::SRET_SCALAR_THRESHOLD
             }
             #[cfg(feature = "isa-xt")]
@@ -183,7 +202,7 @@ impl IsaTarget {
     pub fn stack_alignment(self) -> u32 {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => crate::isa::rv32::abi::STACK_ALIGNMENT,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::abi::STACK_ALIGNMENT,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => crate::isa::xt::abi::STACK_ALIGNMENT,
         }
@@ -209,7 +228,9 @@ impl IsaTarget {
     pub fn frame_top_reserved_bytes(self) -> u32 {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => crate::isa::rv32::abi::FRAME_TOP_RESERVED_BYTES,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                crate::isa::rv32::abi::FRAME_TOP_RESERVED_BYTES
+            }
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => crate::isa::xt::abi::FRAME_TOP_RESERVED_BYTES,
         }
@@ -246,7 +267,7 @@ impl IsaTarget {
     pub fn alu_imm_fits(self, op: AluImmOp, val: i32) -> bool {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 let _ = op;
                 crate::isa::rv32::abi::fits_imm12(val)
             }
@@ -291,12 +312,40 @@ impl IsaTarget {
     pub fn integer_div_traps_on_zero(self) -> bool {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => false,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => false,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => true,
         }
     }
 
+    /// Default [`lpir::InlineConfig::module_op_budget`] for this target: the
+    /// most LPIR ops a module may hold once the inliner is done with it. A
+    /// config that sets a budget wins.
+    ///
+    /// This is the JIT memory budget seen from the middle end. Inlining never
+    /// shrinks a module here — every function stays an export, so an inlined
+    /// callee's body exists twice — and the device pays for that in code
+    /// memory, not the host. The figure is the one the code memory is already
+    /// sized for: the largest shader in `examples/` (`basic`) lowers to 576
+    /// LPIR ops and 6,516 B of Xtensa code, and the classic ESP32's fixed
+    /// 24 KiB region (`codemem_esp32::CodeRegion::ESP32_DEFAULT`) is sized
+    /// against exactly that shader. Capping an inlined module at the same op
+    /// count keeps every module inside the largest one the region already
+    /// budgets for, so small shaders get their helpers inlined and the big
+    /// ones are left as they were.
+    ///
+    /// `Rv32imac` JITs into the ESP32-C6's heap, with no fixed region to
+    /// overflow, so it gets twice the room; the heap is still the C6's
+    /// binding constraint, which is why it is a budget at all.
+    pub fn inline_module_op_budget(self) -> usize {
+        match self {
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => 1152,
+            #[cfg(feature = "isa-xt")]
+            IsaTarget::Xtensa => 576,
+        }
+    }
+
     /// How this target implements `float` when the shader is compiled in
     /// [`lpir::FloatMode::F32`] — the float-capability seam.
     ///
@@ -323,6 +372,11 @@ impl IsaTarget {
     /// quietly giving it the slow path would hide a misconfigured image behind
     /// working output.
     ///
+    /// `Rv32imafc` names an rv32 part **with** the F extension, so it answers
+    /// [`F32Lowering::HardwareFpu`] and lowers to RV32F instructions on the
+    /// 32-entry `f` file. It shares the `float-f32` gate with Xtensa and
+    /// answers [`F32Lowering::Unsupported`] without it, for the same reason.
+    ///
     /// The asymmetry with `Rv32imac` is the whole point of this hook. "rv32"
     /// and "Xtensa" are not two dialects of one float story — one has no FPU at
     /// all and one has a full coprocessor, and the answer has to be a named
@@ -331,6 +385,10 @@ impl IsaTarget {
         match self {
             #[cfg(feature = "isa-rv32")]
             IsaTarget::Rv32imac => F32Lowering::SoftFloatCalls,
+            #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+            IsaTarget::Rv32imafc => F32Lowering::HardwareFpu,
+            #[cfg(all(feature = "isa-rv32", not(feature = "float-f32")))]
+            IsaTarget::Rv32imafc => F32Lowering::Unsupported,
             #[cfg(all(feature = "isa-xt", feature = "float-f32"))]
             IsaTarget::Xtensa => F32Lowering::HardwareFpu,
             #[cfg(all(feature = "isa-xt", not(feature = "float-f32")))]
@@ -364,11 +422,51 @@ impl IsaTarget {
         }
     }
 
+    /// Whether `Fdiv` and `Fsqrt` lower to one FP instruction
+    /// ([`crate::vinst::FAluOp::Div`], [`crate::vinst::FAluRROp::Sqrt`]) rather
+    /// than a builtin call. Only meaningful where [`Self::f32_lowering`] is
+    /// [`F32Lowering::HardwareFpu`].
+    ///
+    /// RV32F's `fdiv.s` and `fsqrt.s` are correctly rounded by the
+    /// specification, which is exactly what `docs/design/float.md` §3 asks of
+    /// `/` and `sqrt`. Xtensa's FPU has no such instructions — only the
+    /// `div0.s`/`divn.s` estimate sequence — so it keeps the builtin (M7 D4).
+    pub fn inlines_f32_div_sqrt(self) -> bool {
+        match self {
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imac => false,
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imafc => true,
+            #[cfg(feature = "isa-xt")]
+            IsaTarget::Xtensa => false,
+        }
+    }
+
+    /// Whether `FtoiSatS`/`FtoiSatU` lower to [`crate::vinst::VInst::FToI`]
+    /// rather than a builtin call. Only meaningful where [`Self::f32_lowering`]
+    /// is [`F32Lowering::HardwareFpu`].
+    ///
+    /// RV32F's `fcvt.w.s`/`fcvt.wu.s` saturate finite and infinite
+    /// out-of-range inputs by specification, which is half of what
+    /// `docs/design/float.md` §3 asks; the emitter masks the other half, NaN
+    /// → 0, with one `feq.s`. Xtensa's `trunc.s` has no such guarantee
+    /// measured (M6-P6), so it keeps the builtin.
+    pub fn inlines_f32_ftoi_sat(self) -> bool {
+        match self {
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imac => false,
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imafc => true,
+            #[cfg(feature = "isa-xt")]
+            IsaTarget::Xtensa => false,
+        }
+    }
+
     /// `object` crate Architecture for ELF emission.
     pub fn elf_architecture(self) -> Architecture {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => Architecture::Riscv32,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => Architecture::Riscv32,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => Architecture::Xtensa,
         }
@@ -379,6 +477,8 @@ impl IsaTarget {
         match self {
             #[cfg(feature = "isa-rv32")]
             IsaTarget::Rv32imac => crate::isa::rv32::link::EF_RISCV_FLOAT_ABI_SOFT,
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imafc => crate::isa::rv32::link::EF_RISCV_FLOAT_ABI_SINGLE,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => crate::isa::xt::link::EF_XTENSA_NONE,
         }
@@ -399,19 +499,28 @@ impl IsaTarget {
     /// overwrites, so the value is wrong only for inputs that happen to
     /// straddle a call.
     ///
-    /// Empty for [`RegClass::Float`] on rv32, where soft float keeps every
+    /// `Rv32imafc`'s float answer is also its whole pool: the pool is the
+    /// `ft` temporaries, which `ilp32f` makes caller-saved.
+    ///
+    /// Empty for [`RegClass::Float`] on `Rv32imac`, where soft float keeps every
     /// value in the integer bank and the integer answer already covers it.
     pub fn caller_saved_pool_hw(self, class: RegClass) -> &'static [u8] {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::CALLER_SAVED_POOL,
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                    crate::isa::rv32::gpr::CALLER_SAVED_POOL
+                }
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::CALLER_SAVED_POOL,
             },
             RegClass::Float => match self {
                 #[cfg(feature = "isa-rv32")]
                 IsaTarget::Rv32imac => &[],
+                #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+                IsaTarget::Rv32imafc => crate::isa::rv32::fpr::CALLER_SAVED_POOL,
+                #[cfg(all(feature = "isa-rv32", not(feature = "float-f32")))]
+                IsaTarget::Rv32imafc => &[],
                 #[cfg(all(feature = "isa-xt", feature = "float-f32"))]
                 IsaTarget::Xtensa => crate::isa::xt::fpr::CALLER_SAVED_POOL,
                 #[cfg(all(feature = "isa-xt", not(feature = "float-f32")))]
@@ -445,13 +554,18 @@ impl IsaTarget {
     /// costs a register for the life of the backend (M7 D8 declines to reserve
     /// any).
     ///
-    /// This changes only if a target arrives whose ABI passes floats in the
-    /// float file — RV32F's `fa0..fa7`, say. That target adds the parameter
-    /// *and* a scratch FR in the same change.
+    /// `Rv32imafc` is the target whose ABI passes floats in the float file
+    /// (`fa0..fa7`, builtin calls only), and it still needs no float scratch:
+    /// its float pool is the `ft` temporaries, disjoint from `fa0..fa7` the
+    /// way rv32's integer pool is disjoint from `a0..a7`. A staging move reads
+    /// a pool register or a spill slot and writes an argument register, so no
+    /// float move is ever another one's source and no cycle can form. A
+    /// target whose float argument bank overlaps its float pool adds the
+    /// parameter *and* a scratch FR in the same change.
     pub fn move_cycle_scratch(self) -> PReg {
         PReg::int(match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => crate::isa::rv32::gpr::SCRATCH,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::gpr::SCRATCH,
             // a9, not a8: `CALL8` writes the mangled return address into a8, so
             // keeping the swap temp clear of it leaves no overlap to reason about.
             #[cfg(feature = "isa-xt")]
@@ -467,20 +581,25 @@ impl IsaTarget {
     /// call constrains its def to a different register file than an
     /// i32-returning one.
     ///
-    /// **`None` for [`RegClass::Float`] on every target here, and that is
-    /// settled rather than pending** (M7 D3). Neither float ABI in this crate
-    /// returns a value in the float file: rv32's soft float returns a `float`
-    /// in `a0` by construction, and Xtensa returns the raw IEEE bit pattern in
-    /// an address register because the esp toolchain that compiles our float
-    /// builtins does (M6-P4's measured probe). Lowering therefore emits an
-    /// explicit [`crate::vinst::VInst::Wfr`] after a float-returning call, so
-    /// the call's own def really is integer-class and this hook is asked the
-    /// integer question.
+    /// **`None` for [`RegClass::Float`] on `Rv32imac` and Xtensa, and that is
+    /// settled rather than pending** (M7 D3). rv32's soft float returns a
+    /// `float` in `a0` by construction, and Xtensa returns the raw IEEE bit
+    /// pattern in an address register because the esp toolchain that compiles
+    /// our float builtins does (M6-P4's measured probe). Lowering therefore
+    /// emits an explicit [`crate::vinst::VInst::Wfr`] after a float-returning
+    /// call, so the call's own def really is integer-class and this hook is
+    /// asked the integer question.
+    ///
+    /// `Rv32imafc` answers `fa0`/`fa1`: its builtins are compiled for `ilp32f`,
+    /// which returns a `float` there. `idx` is the index within the float bank
+    /// ([`VInst::call_ret_bank`]), not the raw return position.
     pub fn direct_ret_reg(self, class: RegClass, idx: usize) -> Option<PReg> {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::RET_REGS.get(idx).copied(),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                    crate::isa::rv32::gpr::RET_REGS.get(idx).copied()
+                }
                 // CALLER view: this hook names where a call's result lands
                 // (regalloc/walk.rs allocates call-def vregs here). Under the
                 // CALL8 rotation that is a10/a11, NOT the callee-view a2/a3 —
@@ -489,24 +608,24 @@ impl IsaTarget {
                 IsaTarget::Xtensa => crate::isa::xt::gpr::CALL_RET_REGS.get(idx).copied(),
             }
             .map(PReg::int),
-            RegClass::Float => None,
+            RegClass::Float => self.float_ret_regs().get(idx).copied().map(PReg::float),
         }
     }
 
     /// Count of direct return registers of `class` in the hardware ABI
     /// (e.g. 2 for RV32 a0–a1).
     ///
-    /// Zero for [`RegClass::Float`] for the reason spelled out on
-    /// [`Self::direct_ret_reg`]: no float return bank exists on either target.
+    /// Zero for [`RegClass::Float`] except on `Rv32imafc`, for the reason
+    /// spelled out on [`Self::direct_ret_reg`].
     pub fn direct_ret_reg_count(self, class: RegClass) -> usize {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::RET_REGS.len(),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::gpr::RET_REGS.len(),
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::CALL_RET_REGS.len(),
             },
-            RegClass::Float => 0,
+            RegClass::Float => self.float_ret_regs().len(),
         }
     }
 
@@ -516,37 +635,62 @@ impl IsaTarget {
     /// ABI passes float arguments in the float file, and the two banks are
     /// indexed independently.
     ///
-    /// **`None` for [`RegClass::Float`] by decision** (M7 D3) — the mirror of
-    /// [`Self::direct_ret_reg`]'s note. Float parameters arrive in address
-    /// registers and lowering emits a [`crate::vinst::VInst::Wfr`] at function
-    /// entry to move each into the float file, so the parameter vreg this hook
-    /// precolors is integer-class.
+    /// **`None` for [`RegClass::Float`] on `Rv32imac` and Xtensa by decision**
+    /// (M7 D3) — the mirror of [`Self::direct_ret_reg`]'s note. Float
+    /// parameters arrive in address registers and lowering emits a
+    /// [`crate::vinst::VInst::Wfr`] at function entry to move each into the
+    /// float file, so the parameter vreg this hook precolors is integer-class.
+    ///
+    /// `Rv32imafc` answers `fa0..fa7`, the `ilp32f` float argument bank. Only
+    /// calls into builtins use it; LPIR functions keep taking float
+    /// parameters as words on every target.
     pub fn call_arg_reg(self, class: RegClass, idx: usize) -> Option<PReg> {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::ARG_REGS.get(idx).copied(),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                    crate::isa::rv32::gpr::ARG_REGS.get(idx).copied()
+                }
                 // CALLEE view (incoming parameters precolor here).
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::ARG_REGS.get(idx).copied(),
             }
             .map(PReg::int),
-            RegClass::Float => None,
+            RegClass::Float => self.float_arg_regs().get(idx).copied().map(PReg::float),
         }
     }
 
     /// Number of argument registers of `class` in the hardware calling convention.
     ///
-    /// Zero for [`RegClass::Float`]; see [`Self::call_arg_reg`].
+    /// Zero for [`RegClass::Float`] except on `Rv32imafc`; see
+    /// [`Self::call_arg_reg`].
     pub fn call_arg_reg_count(self, class: RegClass) -> usize {
         match class {
             RegClass::Int => match self {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => crate::isa::rv32::gpr::ARG_REGS.len(),
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::gpr::ARG_REGS.len(),
                 #[cfg(feature = "isa-xt")]
                 IsaTarget::Xtensa => crate::isa::xt::gpr::ARG_REGS.len(),
             },
-            RegClass::Float => 0,
+            RegClass::Float => self.float_arg_regs().len(),
+        }
+    }
+
+    /// The float argument bank, `fa0..fa7` on `Rv32imafc` and empty elsewhere.
+    fn float_arg_regs(self) -> &'static [u8] {
+        match self {
+            #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+            IsaTarget::Rv32imafc => crate::isa::rv32::fpr::ARG_REGS,
+            _ => &[],
+        }
+    }
+
+    /// The float return bank, `fa0`/`fa1` on `Rv32imafc` and empty elsewhere.
+    fn float_ret_regs(self) -> &'static [u8] {
+        match self {
+            #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+            IsaTarget::Rv32imafc => crate::isa::rv32::fpr::RET_REGS,
+            _ => &[],
         }
     }
 
@@ -563,7 +707,7 @@ impl IsaTarget {
     ) -> usize {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 if callee_uses_sret && !caller_passes_sret_ptr {
                     crate::isa::rv32::abi::ARG_REGS.len() - 1
                 } else {
@@ -588,16 +732,21 @@ impl IsaTarget {
     ///
     /// `class` is the class of the *operand*, and it selects the register bank:
     /// a hard-float ABI stages a float argument in the float file.
+    /// `arg_index` is the operand's index **within that bank**
+    /// ([`VInst::call_arg_bank`]), which equals its position on every call
+    /// without a float argument.
     ///
-    /// **`None` for [`RegClass::Float`] permanently on both targets** (M7 D3),
+    /// **`None` for [`RegClass::Float`] on `Rv32imac` and Xtensa** (M7 D3),
     /// not as a stub. Lowering emits a [`crate::vinst::VInst::Rfr`] before each
-    /// float call argument, so a `Call`'s `args` slice is entirely
-    /// integer-class by the time the allocator reads it and this early return
-    /// is unreachable in well-formed output. It stays as a hard floor: a float
-    /// vreg that somehow reached an argument slot must fail the stack-pass
-    /// path loudly rather than be staged into a GPR and passed as an integer.
-    /// The slot arithmetic below (the sret/vmctx shuffles) is class-independent
-    /// and would be reused as-is if a float argument bank ever landed.
+    /// float call argument there, so a `Call`'s `args` slice is entirely
+    /// integer-class by the time the allocator reads it. It stays as a hard
+    /// floor: a float vreg that somehow reached an argument slot must fail the
+    /// stack-pass path loudly rather than be staged into a GPR and passed as an
+    /// integer.
+    ///
+    /// On `Rv32imafc` a float argument goes to `fa[arg_index]`. The sret/vmctx
+    /// shuffles below do not apply to it: the hidden pointers are integers and
+    /// only move within the integer bank.
     pub fn lpir_call_arg_target(
         self,
         class: RegClass,
@@ -607,11 +756,11 @@ impl IsaTarget {
         arg_index: usize,
     ) -> Option<PReg> {
         if class == RegClass::Float {
-            return None;
+            return self.call_arg_reg(RegClass::Float, arg_index);
         }
         let hw = match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 let slot = if !callee_uses_sret {
                     arg_index
                 } else if !caller_passes_sret_ptr {
@@ -664,11 +813,18 @@ impl IsaTarget {
     /// Only defined for architectures the on-device JIT supports — host builds
     /// always name their target explicitly. The `xtensa` arm lands with the
     /// ESP32-S3 backport.
-    #[cfg(target_arch = "riscv32")]
+    #[cfg(all(target_arch = "riscv32", not(target_feature = "f")))]
     pub fn native() -> IsaTarget {
         IsaTarget::Rv32imac
     }
 
+    /// An rv32 image built with the F extension JITs for it: the firmware and
+    /// its builtins are `ilp32f`, which is the convention `Rv32imafc` calls.
+    #[cfg(all(target_arch = "riscv32", target_feature = "f"))]
+    pub fn native() -> IsaTarget {
+        IsaTarget::Rv32imafc
+    }
+
     /// See the sibling arm above — one `fn native` per JIT-capable CPU.
     #[cfg(target_arch = "xtensa")]
     pub fn native() -> IsaTarget {
@@ -679,7 +835,7 @@ impl IsaTarget {
     pub fn call_reloc_type(self) -> u32 {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => crate::isa::rv32::link::R_RISCV_CALL_PLT,
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::link::R_RISCV_CALL_PLT,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => crate::isa::xt::link::R_XTENSA_32,
         }
@@ -698,7 +854,7 @@ impl IsaTarget {
     ) -> Result<IsaEmitOutput, AllocError> {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => crate::isa::rv32::emit::emit_function(
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => crate::isa::rv32::emit::emit_function(
                 vinsts,
                 vreg_pool,
                 output,
@@ -723,8 +879,10 @@ impl IsaTarget {
     /// Render one instruction word as assembly text (debug output only).
     pub fn format_instruction(self, word: u32) -> String {
         match self {
+            // `lp_riscv_inst` decodes no F-extension instruction, so an
+            // `Rv32imafc` FP word renders as `.word 0x…`.
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => lp_riscv_inst::format_instruction(word),
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => lp_riscv_inst::format_instruction(word),
             // Xtensa instructions are 2 or 3 bytes; render the word's low
             // three little-endian bytes at pc 0 (callers with real buffers
             // use `format_instruction_at`).
@@ -747,7 +905,7 @@ impl IsaTarget {
     pub fn format_instruction_at(self, bytes: &[u8]) -> Option<(String, usize)> {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 let word = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?);
                 Some((lp_riscv_inst::format_instruction(word), 4))
             }
@@ -783,7 +941,7 @@ impl IsaTarget {
     ) -> String {
         match self {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 let table = crate::isa::rv32::debug::LineTable::from_debug_lines(debug_lines);
                 crate::isa::rv32::debug::disasm::disassemble_function(code, &table, func, opts)
             }
@@ -828,27 +986,30 @@ mod tests {
         alloc::vec![
             #[cfg(feature = "isa-rv32")]
             IsaTarget::Rv32imac,
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imafc,
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa,
         ]
     }
 
-    /// The float-capability seam's whole job: **exactly one** target may claim
-    /// a hardware FPU, and only in a build that links the FP tables.
+    /// The float-capability seam's whole job: only the targets that name an
+    /// FPU may claim one, and only in a build that links the FP tables.
     ///
     /// M9 asserted that *nothing* answered `HardwareFpu`, because nothing could
-    /// encode an FP instruction. M7 makes Xtensa the one that does — the S3 has
-    /// the Floating-Point Coprocessor Option and M6-P1 confirmed all 26
-    /// instructions present on silicon. The assertion is kept, not deleted,
-    /// with the claim narrowed: any *other* target answering `HardwareFpu` is a
-    /// part that would take an illegal-instruction trap on the first `fadd.s`.
+    /// encode an FP instruction. M7 makes Xtensa the first that does — the S3
+    /// has the Floating-Point Coprocessor Option and M6-P1 confirmed all 26
+    /// instructions present on silicon — and `Rv32imafc` the second. The
+    /// assertion is kept, not deleted, with the claim narrowed: any *other*
+    /// target answering `HardwareFpu` is a part that would take an
+    /// illegal-instruction trap on the first `fadd.s`.
     #[test]
-    fn only_xtensa_with_float_f32_claims_a_hardware_fpu() {
+    fn only_fpu_targets_with_float_f32_claim_a_hardware_fpu() {
         for isa in every_isa() {
             let claims_hardware = isa.f32_lowering() == F32Lowering::HardwareFpu;
-            let may_claim_hardware = cfg!(feature = "isa-xt")
-                && cfg!(feature = "float-f32")
-                && alloc::format!("{isa:?}") == "Xtensa";
+            let name = alloc::format!("{isa:?}");
+            let may_claim_hardware =
+                cfg!(feature = "float-f32") && (name == "Xtensa" || name == "Rv32imafc");
             assert_eq!(
                 claims_hardware, may_claim_hardware,
                 "{isa:?}: hardware-FPU claim does not match what this build can encode"
@@ -878,7 +1039,7 @@ mod tests {
         );
     }
 
-    /// Soft float keeps every value integer-class, so rv32's float pool stays
+    /// Soft float keeps every value integer-class, so the C6's float pool stays
     /// empty. A float vreg reaching the allocator there fails loudly with
     /// `OutOfRegisters` rather than quietly landing an f32 bit pattern in a GPR
     /// that some later pass treats as an integer.
@@ -918,6 +1079,35 @@ mod tests {
         }
     }
 
+    /// RV32F's pool is the `ft` temporaries minus the emitter scratch: all of
+    /// them call-clobbered, none of them an argument register, so the
+    /// prologue saves nothing new and a staging move never reads a register
+    /// another one writes.
+    #[cfg(all(feature = "isa-rv32", feature = "float-f32"))]
+    #[test]
+    fn rv32imafc_float_pool_is_the_ft_temporaries() {
+        let isa = IsaTarget::Rv32imafc;
+        assert_eq!(isa.f32_lowering(), F32Lowering::HardwareFpu);
+        let pool = isa.allocatable_pool_order(RegClass::Float);
+        assert_eq!(pool.len(), 11, "ft0..ft10; ft11 is the scratch");
+        assert_eq!(isa.caller_saved_pool_hw(RegClass::Float), pool);
+        for k in 0..isa.call_arg_reg_count(RegClass::Float) {
+            let fa = isa.call_arg_reg(RegClass::Float, k).unwrap();
+            assert!(!isa.is_in_allocatable_pool(fa), "fa{k} is in the pool");
+        }
+        assert!(!isa.is_in_allocatable_pool(PReg::float(31)));
+        assert_eq!(isa.reg_name(PReg::float(10)), "fa0");
+        assert_eq!(isa.reg_name(PReg::int(10)), "a0");
+        // Every integer answer is the C6's.
+        let c6 = IsaTarget::Rv32imac;
+        assert_eq!(
+            isa.allocatable_pool_order(RegClass::Int),
+            c6.allocatable_pool_order(RegClass::Int)
+        );
+        assert_eq!(isa.move_cycle_scratch(), c6.move_cycle_scratch());
+        assert_ne!(isa.elf_e_flags(), c6.elf_e_flags());
+    }
+
     /// The same hardware index in the two classes must not be confused: `f3`
     /// and `a3` are different registers, and `reg_name` is what a spill trace
     /// or an alloc dump shows a human debugging a wrong pixel.
@@ -930,15 +1120,26 @@ mod tests {
         }
     }
 
-    /// No target has a float **ABI** bank, and unlike the pool this is not
-    /// waiting on a feature — it is M7 D3. Float values cross every call
-    /// boundary in address registers on both float targets, so a float vreg
-    /// never occupies an argument or return slot. If one of these ever answers
-    /// non-zero, the `Rfr`/`Wfr` transfers lowering inserts have become
-    /// redundant and the calling convention changed.
+    /// Only `Rv32imafc` has a float **ABI** bank, and on the others this is
+    /// not waiting on a feature — it is M7 D3. Float values cross every call
+    /// boundary in address registers on Xtensa and the C6, so a float vreg
+    /// never occupies an argument or return slot there. If one of these ever
+    /// answers non-zero, the `Rfr`/`Wfr` transfers lowering inserts have
+    /// become redundant and the calling convention changed.
     #[test]
-    fn no_target_has_a_float_abi_bank() {
+    fn only_rv32imafc_has_a_float_abi_bank() {
         for isa in every_isa() {
+            assert_eq!(isa.move_cycle_scratch().class, RegClass::Int);
+            if alloc::format!("{isa:?}") == "Rv32imafc" && cfg!(feature = "float-f32") {
+                assert_eq!(isa.direct_ret_reg_count(RegClass::Float), 2);
+                assert_eq!(isa.call_arg_reg_count(RegClass::Float), 8);
+                assert_eq!(
+                    isa.lpir_call_arg_target(RegClass::Float, true, true, true, 1),
+                    Some(PReg::float(11)),
+                    "the sret/vmctx swap is an integer-bank shuffle"
+                );
+                continue;
+            }
             assert_eq!(isa.direct_ret_reg_count(RegClass::Float), 0);
             assert_eq!(isa.call_arg_reg_count(RegClass::Float), 0);
             assert_eq!(isa.direct_ret_reg(RegClass::Float, 0), None);
@@ -947,7 +1148,6 @@ mod tests {
                 isa.lpir_call_arg_target(RegClass::Float, false, false, false, 0),
                 None
             );
-            assert_eq!(isa.move_cycle_scratch().class, RegClass::Int);
         }
     }
 }
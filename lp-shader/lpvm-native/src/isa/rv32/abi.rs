@@ -165,6 +165,29 @@ pub fn callee_saved_int() -> PregSet {
     ]))
 }
 
+/// Caller-saved float registers for `Rv32imafc`'s clobber set: the whole
+/// float pool ([`super::fpr::CALLER_SAVED_POOL`]).
+#[cfg(feature = "float-f32")]
+pub fn caller_saved_float() -> PregSet {
+    float_set(super::fpr::CALLER_SAVED_POOL)
+}
+
+/// Base allocatable float set for `Rv32imafc`: `ft0`–`ft10`
+/// ([`super::fpr::ALLOC_POOL`]). `ft11` stays out as the emitter's scratch.
+#[cfg(feature = "float-f32")]
+pub fn alloca_base_float() -> PregSet {
+    float_set(super::fpr::ALLOC_POOL)
+}
+
+#[cfg(feature = "float-f32")]
+fn float_set(regs: &[u8]) -> PregSet {
+    let mut s = PregSet::EMPTY;
+    for &r in regs {
+        s.insert(PReg::float(r));
+    }
+    s
+}
+
 /// Always reserved for special roles (not allocatable as general values).
 /// t0–t2 are emitter scratch registers (TEMP0–TEMP2).
 pub fn reserved_always_int() -> PregSet {
@@ -311,6 +334,26 @@ pub fn classify_params_for_compile(sig: &LpsFnSig, func: &IrFunction) -> Vec<Arg
 /// When `func` is `None`, falls back to [`entry_param_scalar_count`] and flattened
 /// [`LpsFnSig`] parameters (tests and signature-only metadata).
 pub fn func_abi_rv32(sig: &LpsFnSig, func: Option<&IrFunction>) -> crate::abi::FuncAbi {
+    func_abi_for(sig, func, crate::isa::IsaTarget::Rv32imac)
+}
+
+/// [`func_abi_rv32`] for [`crate::isa::IsaTarget::Rv32imafc`].
+///
+/// The parameter and return convention is the C6's unchanged — shader
+/// functions take and return floats as words on both targets. What differs is
+/// the float file: its pool joins the allocatable set, and the whole pool joins
+/// the clobber set, because `ilp32f` saves none of `ft0`–`ft10` across a call.
+/// Leaving the float lanes out of either would make the allocator either find
+/// no float register at all or keep a live float in one a builtin overwrites.
+pub fn func_abi_rv32f(sig: &LpsFnSig, func: Option<&IrFunction>) -> crate::abi::FuncAbi {
+    func_abi_for(sig, func, crate::isa::IsaTarget::Rv32imafc)
+}
+
+fn func_abi_for(
+    sig: &LpsFnSig,
+    func: Option<&IrFunction>,
+    isa: crate::isa::IsaTarget,
+) -> crate::abi::FuncAbi {
     use crate::abi::FuncAbi;
     use crate::abi::classify::entry_param_scalar_count;
 
@@ -335,6 +378,17 @@ pub fn func_abi_rv32(sig: &LpsFnSig, func: Option<&IrFunction>) -> crate::abi::F
     if is_sret {
         allocatable.remove(S1);
     }
+    // No callee-saved float lane on either target: the pool holds no `fs`
+    // register, so the frame has no float save area.
+    #[cfg(feature = "float-f32")]
+    let caller_saved = if isa == crate::isa::IsaTarget::Rv32imafc {
+        allocatable = allocatable.union(alloca_base_float());
+        caller_saved_int().union(caller_saved_float())
+    } else {
+        caller_saved_int()
+    };
+    #[cfg(not(feature = "float-f32"))]
+    let caller_saved = caller_saved_int();
 
     let total_param_slots = match func {
         Some(f) => f.total_param_slots() as usize,
@@ -347,9 +401,9 @@ pub fn func_abi_rv32(sig: &LpsFnSig, func: Option<&IrFunction>) -> crate::abi::F
         return_method,
         allocatable,
         precolors,
-        caller_saved_int(),
+        caller_saved,
         callee_saved_int(),
-        crate::isa::IsaTarget::Rv32imac,
+        isa,
     )
 }
 
@@ -543,4 +597,23 @@ mod tests {
         assert_eq!(ARG_REGS[0], A0);
         assert_eq!(ARG_REGS[7], A7);
     }
+
+    #[cfg(feature = "float-f32")]
+    #[test]
+    fn only_rv32f_allocates_and_clobbers_the_float_pool() {
+        let sig = sig_with_params("f", LpsType::Float, &[param("a", LpsType::Float)]);
+        let soft = func_abi_rv32(&sig, None);
+        let hard = func_abi_rv32f(&sig, None);
+        let ft0 = PReg::float(0);
+        let ft11 = PReg::float(super::super::fpr::SCRATCH);
+        let fs0 = PReg::float(8);
+
+        assert!(!soft.allocatable().contains(ft0));
+        assert!(!soft.call_clobbers().contains(ft0));
+        assert!(hard.allocatable().contains(ft0));
+        assert!(hard.call_clobbers().contains(ft0));
+        assert!(!hard.allocatable().contains(ft11), "the scratch stays out");
+        assert!(!hard.allocatable().contains(fs0), "no callee-saved float");
+        assert_eq!(soft.param_locs(), hard.param_locs());
+    }
 }
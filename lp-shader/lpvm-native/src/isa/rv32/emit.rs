@@ -30,7 +30,7 @@ pub struct EmitContext<'a> {
     code: Vec<u8>,
     relocs: Vec<NativeReloc>,
     debug_lines: Vec<(u32, Option<u32>)>,
-    frame: FrameLayout,
+    pub(super) frame: FrameLayout,
     symbols: &'a ModuleSymbols,
     /// Kept for API parity with [`emit_function`] (e.g. future pool-indexed lowering).
     #[allow(
@@ -95,7 +95,7 @@ impl<'a> EmitContext<'a> {
     }
 
     /// Push a 32-bit instruction word.
-    fn push_u32(&mut self, w: u32, src_op: Option<u32>) {
+    pub(super) fn push_u32(&mut self, w: u32, src_op: Option<u32>) {
         let offset = self.code.len() as u32;
         self.code.extend_from_slice(&w.to_le_bytes());
         if self.collect_debug_lines
@@ -106,16 +106,20 @@ impl<'a> EmitContext<'a> {
     }
 
     /// Temporary registers for spill handling.
-    const TEMP0: PReg = 5; // t0
-    const TEMP1: PReg = 6; // t1
+    pub(super) const TEMP0: PReg = 5; // t0
+    pub(super) const TEMP1: PReg = 6; // t1
     const TEMP2: PReg = 7; // t2
 
     /// Get allocation for a specific operand.
-    fn operand_alloc(output: &AllocOutput, inst_idx: usize, operand_idx: usize) -> Alloc {
+    pub(super) fn operand_alloc(
+        output: &AllocOutput,
+        inst_idx: usize,
+        operand_idx: usize,
+    ) -> Alloc {
         output.operand_alloc(inst_idx as u16, operand_idx as u16)
     }
 
-    fn is_dead_def(output: &AllocOutput, inst_idx: usize, def_op_idx: usize) -> bool {
+    pub(super) fn is_dead_def(output: &AllocOutput, inst_idx: usize, def_op_idx: usize) -> bool {
         matches!(
             Self::operand_alloc(output, inst_idx, def_op_idx),
             Alloc::None
@@ -126,21 +130,22 @@ impl<'a> EmitContext<'a> {
     ///
     /// The single gate between the allocator's class-aware `Alloc` and this
     /// emitter's bare GPR indices. A float-class allocation is rejected rather
-    /// than unwrapped: RV32F codegen is a later milestone, and emitting an
-    /// integer instruction against a hardware index that names a float register
-    /// would be silently wrong rather than merely unimplemented.
-    fn hw(preg: crate::abi::PackedPReg) -> Result<PReg, AllocError> {
+    /// than unwrapped: `x3` and `f3` share a hardware index but not a register
+    /// file, so emitting an integer instruction against a float allocation
+    /// would be silently wrong. Float operands go through `emit_fp`'s `fhw`,
+    /// which rejects the other class the same way.
+    pub(super) fn hw(preg: crate::abi::PackedPReg) -> Result<PReg, AllocError> {
         match preg.class() {
             RegClass::Int => Ok(preg.hw()),
             RegClass::Float => Err(crate::emit_err!(
-                "allocation names float register f{} — rv32 has no float backend",
+                "allocation names float register f{} where a GPR is required",
                 preg.hw()
             )),
         }
     }
 
     /// Use a vreg: return its physical register, loading from spill if needed.
-    fn use_vreg(
+    pub(super) fn use_vreg(
         &mut self,
         output: &AllocOutput,
         inst_idx: usize,
@@ -167,7 +172,7 @@ impl<'a> EmitContext<'a> {
     }
 
     /// Def a vreg: return the physical register to write to.
-    fn def_vreg(
+    pub(super) fn def_vreg(
         &mut self,
         output: &AllocOutput,
         inst_idx: usize,
@@ -184,7 +189,7 @@ impl<'a> EmitContext<'a> {
     }
 
     /// Store a spilled vreg after it was written to a temp.
-    fn store_def_vreg(
+    pub(super) fn store_def_vreg(
         &mut self,
         output: &AllocOutput,
         inst_idx: usize,
@@ -208,6 +213,12 @@ impl<'a> EmitContext<'a> {
 
     /// Emit an allocator edit (reload/spill/reg move) as concrete instructions.
     fn emit_edit(&mut self, edit: &Edit, src_op: Option<u32>) -> Result<(), AllocError> {
+        // Float-class edits use `flw`/`fsw`/`fsgnj.s`; see `emit_fp` for why
+        // stack-to-stack stays here even for floats (a class-free word copy).
+        #[cfg(feature = "float-f32")]
+        if self.emit_float_edit(edit, src_op)? {
+            return Ok(());
+        }
         match edit {
             Edit::Move { from, to } => match (*from, *to) {
                 (Alloc::None, _) | (_, Alloc::None) => return Err(crate::emit_err!()),
@@ -504,6 +515,7 @@ impl<'a> EmitContext<'a> {
                     AluOp::Sub => encode_sub(rd, rs1, rs2),
                     AluOp::Mul => encode_mul(rd, rs1, rs2),
                     AluOp::MulH => encode_mulh(rd, rs1, rs2),
+                    AluOp::MulHU => encode_mulhu(rd, rs1, rs2),
                     AluOp::And => encode_and(rd, rs1, rs2),
                     AluOp::Or => encode_or(rd, rs1, rs2),
                     AluOp::Xor => encode_xor(rd, rs1, rs2),
@@ -732,6 +744,22 @@ impl<'a> EmitContext<'a> {
                 }
                 self.store_def_vreg(output, inst_idx, 0, Self::TEMP0, src_op)?;
             }
+            VInst::SymbolAddr { target, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                // auipc+addi: the call relocation patches the I-type immediate
+                // of the second word whether it is a jalr or an addi.
+                let rd = self.def_vreg(output, inst_idx, 0, Self::TEMP0)? as u32;
+                let auipc_off = self.code.len();
+                self.push_u32(encode_auipc(rd, 0), src_op);
+                self.push_u32(encode_addi(rd, rd, 0), src_op);
+                self.relocs.push(NativeReloc {
+                    offset: auipc_off,
+                    symbol: String::from(self.symbols.name(*target)),
+                });
+                self.store_def_vreg(output, inst_idx, 0, Self::TEMP0, src_op)?;
+            }
             VInst::MemcpyWords { size, .. } => {
                 let t_data = Self::TEMP0 as u32;
                 let p_src = Self::TEMP1 as u32;
@@ -774,10 +802,17 @@ impl<'a> EmitContext<'a> {
                     .lpir_call_stack_args_start(*callee_uses_sret, *caller_passes_sret_ptr);
 
                 // Store overflow args to the outgoing stack area at [SP + offset].
-                for i in cap..args.len() {
+                // Positions count within the integer bank: float arguments
+                // (`Rv32imafc` builtins) always travel in `fa0`–`fa7`, which
+                // lowering guarantees by falling back to words past the eighth.
+                for i in 0..args.len() {
+                    let (class, k) = vinst.call_arg_bank(i);
+                    if class != RegClass::Int || k < cap {
+                        continue;
+                    }
                     let operand_idx = rets.len() + i;
                     let alloc = Self::operand_alloc(output, inst_idx, operand_idx);
-                    let stack_off = ((i - cap) * 4) as i32;
+                    let stack_off = ((k - cap) * 4) as i32;
                     match alloc {
                         Alloc::Reg(src) => {
                             self.push_u32(
@@ -924,14 +959,26 @@ impl<'a> EmitContext<'a> {
                     self.push_u32(encode_sw(t0, rv, fuel_off), src_op);
                 }
             }
-            // Hardware float. `Rv32imac` names a part with **no F extension**,
-            // so these are not "not implemented yet" here — they are
-            // unreachable by construction: `IsaTarget::f32_lowering` answers
-            // `SoftFloatCalls` for this target, and that lowering emits only
-            // integer instructions. Reaching this arm means the float-capability
-            // seam was bypassed, and the hardware would take an
-            // illegal-instruction trap on the first frame if we guessed an
-            // encoding. An RV32F variant of `IsaTarget` supplies real arms here.
+            // Hardware float — the float half lives in `super::emit_fp`. Only
+            // `Rv32imafc` lowers to these; for `Rv32imac`, which has no F
+            // extension, `IsaTarget::f32_lowering` answers `SoftFloatCalls` and
+            // that lowering emits only integer instructions.
+            #[cfg(feature = "float-f32")]
+            VInst::FAluRRR { .. }
+            | VInst::FAluRR { .. }
+            | VInst::Fcmp { .. }
+            | VInst::FSelect { .. }
+            | VInst::FLoad32 { .. }
+            | VInst::FStore32 { .. }
+            | VInst::Wfr { .. }
+            | VInst::Rfr { .. }
+            | VInst::IToF { .. }
+            | VInst::FToI { .. } => {
+                self.emit_float_vinst(vinst, output, inst_idx, src_op)?;
+            }
+            // Without `float-f32` no FP emitter is linked and reaching here
+            // means the float-capability seam was bypassed.
+            #[cfg(not(feature = "float-f32"))]
             VInst::FAluRRR { .. }
             | VInst::FAluRR { .. }
             | VInst::Fcmp { .. }
@@ -940,7 +987,8 @@ impl<'a> EmitContext<'a> {
             | VInst::FStore32 { .. }
             | VInst::Wfr { .. }
             | VInst::Rfr { .. }
-            | VInst::IToF { .. } => {
+            | VInst::IToF { .. }
+            | VInst::FToI { .. } => {
                 // The message-less form on purpose. `emit_err!` with any
                 // message allocates a `String` through `format!` (**+512 B
                 // measured on the C6 image**), and adding `vinst.mnemonic()` to
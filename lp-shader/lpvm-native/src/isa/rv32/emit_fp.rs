@@ -0,0 +1,780 @@
+//! The float half of the RV32 emitter: every float `VInst`, and the float
+//! spill/reload edits, for [`IsaTarget::Rv32imafc`](crate::isa::IsaTarget::Rv32imafc).
+//!
+//! Gated on `float-f32` like Xtensa's `emit_fp`, whose shape this follows: the
+//! integer half ([`super::emit`]) owns the frame, branch fixups and the call
+//! sequence, and this module adds no machinery of its own.
+//!
+//! ## The FP register model
+//!
+//! Values live in the `ft` temporaries ([`super::fpr`]); `ft11` is the
+//! emitter's scratch, needed because the allocator can place a float
+//! *destination* on the stack and an F instruction still has to write a
+//! register before it can be stored. GPR scratch stays `t0` throughout, so
+//! the two files' scratch reservations never interact.
+//!
+//! Unlike Xtensa, floats do not cross every boundary as words: calls to the
+//! `lps-builtins` f32 family pass them in `fa0`–`fa7` (`ilp32f`), and the
+//! allocator moves them there with ordinary float-class edits. Calls between
+//! shader functions, and the shader entry ABI, still carry words; lowering
+//! inserts the [`VInst::Wfr`]/[`VInst::Rfr`] transfers for those.
+//!
+//! ## Semantics
+//!
+//! Every rounding F instruction names its mode — RNE for arithmetic and
+//! int→float, RTZ for float→int — so nothing depends on `frm`. Compares are the F extension's
+//! quiet `feq`/`flt`/`fle`, which give exactly the `docs/design/float.md` §3
+//! table: ordered compares are false on NaN, and `Ne` (`!feq`) is true.
+//!
+//! Spill offsets are used directly, as the integer half does for `lw`/`sw`:
+//! the frame keeps spill slots within `flw`/`fsw`'s signed 12-bit reach.
+
+use super::emit::EmitContext;
+use super::encode::*;
+use super::fpr::{self, FReg};
+use super::gpr::FP_REG;
+use crate::abi::{PackedPReg, RegClass};
+use crate::regalloc::{Alloc, AllocError, AllocOutput, Edit};
+use crate::vinst::{FAluOp, FAluRROp, FcmpCond, VInst};
+
+impl EmitContext<'_> {
+    // --- the class gate, float side ---------------------------------------
+
+    /// The `f`-register named by a register allocation — the mirror of
+    /// [`EmitContext::hw`], rejecting an integer-class allocation.
+    fn fhw(preg: PackedPReg) -> Result<FReg, AllocError> {
+        match preg.class() {
+            RegClass::Int => Err(crate::emit_err!(
+                "allocation names GPR x{} where a float register is required",
+                preg.hw()
+            )),
+            RegClass::Float if preg.hw() < fpr::FR_COUNT => Ok(preg.hw()),
+            RegClass::Float => Err(crate::emit_err!("allocation names non-FPR f{}", preg.hw())),
+        }
+    }
+
+    /// Use a float vreg: return the FPR holding it.
+    ///
+    /// There is no reload path, for the reason Xtensa's `fuse_vreg` gives: the
+    /// allocator reloads spilled float uses with edits, so a `Stack` use means
+    /// its contract changed — and reloading into the single scratch would be
+    /// wrong for an instruction with two spilled float uses.
+    fn fuse_vreg(
+        &mut self,
+        output: &AllocOutput,
+        inst_idx: usize,
+        operand_idx: usize,
+    ) -> Result<FReg, AllocError> {
+        match Self::operand_alloc(output, inst_idx, operand_idx) {
+            Alloc::Reg(preg) => Self::fhw(preg),
+            Alloc::Stack(slot) => Err(crate::emit_err!(
+                "float use operand {operand_idx} allocated to spill slot {slot}; \
+                 the allocator is expected to reload float uses into registers via edits"
+            )),
+            Alloc::None => Err(crate::emit_err!()),
+        }
+    }
+
+    /// Def a float vreg: return the FPR to write to. A stack-allocated def
+    /// computes into [`fpr::SCRATCH`] and is stored by
+    /// [`Self::fstore_def_vreg`].
+    fn fdef_vreg(
+        &mut self,
+        output: &AllocOutput,
+        inst_idx: usize,
+        operand_idx: usize,
+    ) -> Result<FReg, AllocError> {
+        match Self::operand_alloc(output, inst_idx, operand_idx) {
+            Alloc::Reg(preg) => Self::fhw(preg),
+            Alloc::Stack(_) => Ok(fpr::SCRATCH),
+            Alloc::None => Err(crate::emit_err!()),
+        }
+    }
+
+    /// Store a spilled float def after it was written to [`fpr::SCRATCH`].
+    fn fstore_def_vreg(
+        &mut self,
+        output: &AllocOutput,
+        inst_idx: usize,
+        operand_idx: usize,
+        src_op: Option<u32>,
+    ) -> Result<(), AllocError> {
+        if let Alloc::Stack(slot) = Self::operand_alloc(output, inst_idx, operand_idx) {
+            self.fspill_store(fpr::SCRATCH, slot, src_op)?;
+        }
+        Ok(())
+    }
+
+    // --- float spill slots -------------------------------------------------
+
+    /// `flw dst, <spill slot>(s0)`.
+    fn fspill_load(&mut self, dst: FReg, slot: u8, src_op: Option<u32>) -> Result<(), AllocError> {
+        let off = self
+            .frame
+            .spill_offset_from_fp(slot as u32)
+            .ok_or(crate::emit_err!())?;
+        self.push_u32(encode_flw(dst as u32, FP_REG as u32, off), src_op);
+        Ok(())
+    }
+
+    /// `fsw src, <spill slot>(s0)`.
+    fn fspill_store(&mut self, src: FReg, slot: u8, src_op: Option<u32>) -> Result<(), AllocError> {
+        let off = self
+            .frame
+            .spill_offset_from_fp(slot as u32)
+            .ok_or(crate::emit_err!())?;
+        self.push_u32(encode_fsw(src as u32, FP_REG as u32, off), src_op);
+        Ok(())
+    }
+
+    /// `fmv.s` (`fsgnj.s rd, rs, rs`), elided when the registers coincide.
+    fn fmov(&mut self, rd: FReg, rs: FReg, src_op: Option<u32>) {
+        if rd != rs {
+            self.push_u32(encode_fsgnj_s(rd as u32, rs as u32, rs as u32), src_op);
+        }
+    }
+
+    // --- allocator edits ---------------------------------------------------
+
+    /// Handle an allocator edit that moves a **float** value, returning
+    /// `false` when the edit is not float-class and the integer path should
+    /// take it.
+    ///
+    /// Stack-to-stack is left to the integer path even for floats: it is a
+    /// bit-for-bit copy of one 4-byte slot through `t0`, and neither endpoint
+    /// carries a class.
+    pub(super) fn emit_float_edit(
+        &mut self,
+        edit: &Edit,
+        src_op: Option<u32>,
+    ) -> Result<bool, AllocError> {
+        let Edit::Move { from, to } = edit else {
+            // Stack-passed parameters are shader-entry words; only an ABI
+            // change underneath lowering could send one to a float register.
+            if let Edit::LoadIncomingArg { to, .. } = edit
+                && matches!(to, Alloc::Reg(p) if p.class() == RegClass::Float)
+            {
+                return Err(crate::emit_err!(
+                    "incoming argument loaded straight into a float register; \
+                     shader parameters travel in GPRs"
+                ));
+            }
+            return Ok(false);
+        };
+        match (*from, *to) {
+            (Alloc::Reg(src), Alloc::Reg(dst))
+                if src.class() == RegClass::Float && dst.class() == RegClass::Float =>
+            {
+                let (d, s) = (Self::fhw(dst)?, Self::fhw(src)?);
+                self.fmov(d, s, src_op);
+                Ok(true)
+            }
+            // GPR↔FPR transfers are explicit `Wfr`/`Rfr` VInsts, so the
+            // allocator only ever sees same-class copies.
+            (Alloc::Reg(src), Alloc::Reg(dst)) if src.class() != dst.class() => {
+                Err(crate::emit_err!(
+                    "allocator edit moves between register classes ({:?} -> {:?}); \
+                     GPR/FPR transfers are Wfr/Rfr VInsts, not moves",
+                    src.class(),
+                    dst.class()
+                ))
+            }
+            (Alloc::Stack(slot), Alloc::Reg(dst)) if dst.class() == RegClass::Float => {
+                let d = Self::fhw(dst)?;
+                self.fspill_load(d, slot, src_op)?;
+                Ok(true)
+            }
+            (Alloc::Reg(src), Alloc::Stack(slot)) if src.class() == RegClass::Float => {
+                let s = Self::fhw(src)?;
+                self.fspill_store(s, slot, src_op)?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    // --- float VInst emission ----------------------------------------------
+
+    /// Emit one float `VInst`. Operand indices follow the allocator's layout:
+    /// defs in `for_each_def` order, then uses in `for_each_use` order.
+    pub(super) fn emit_float_vinst(
+        &mut self,
+        vinst: &VInst,
+        output: &AllocOutput,
+        inst_idx: usize,
+        src_op: Option<u32>,
+    ) -> Result<(), AllocError> {
+        let t0 = Self::TEMP0;
+        match vinst {
+            VInst::FAluRRR { op, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s1 = self.fuse_vreg(output, inst_idx, 1)? as u32;
+                let s2 = self.fuse_vreg(output, inst_idx, 2)? as u32;
+                let d = self.fdef_vreg(output, inst_idx, 0)? as u32;
+                let w = match op {
+                    FAluOp::Add => encode_fadd_s(d, s1, s2),
+                    FAluOp::Sub => encode_fsub_s(d, s1, s2),
+                    FAluOp::Mul => encode_fmul_s(d, s1, s2),
+                    FAluOp::Div => encode_fdiv_s(d, s1, s2),
+                };
+                self.push_u32(w, src_op);
+                self.fstore_def_vreg(output, inst_idx, 0, src_op)
+            }
+            VInst::FAluRR { op, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s = self.fuse_vreg(output, inst_idx, 1)?;
+                let d = self.fdef_vreg(output, inst_idx, 0)?;
+                let (du, su) = (d as u32, s as u32);
+                match op {
+                    FAluRROp::Mov => self.fmov(d, s, src_op),
+                    // Sign injection: neither touches a NaN payload.
+                    FAluRROp::Neg => self.push_u32(encode_fsgnjn_s(du, su, su), src_op),
+                    FAluRROp::Abs => self.push_u32(encode_fsgnjx_s(du, su, su), src_op),
+                    FAluRROp::Sqrt => self.push_u32(encode_fsqrt_s(du, su), src_op),
+                }
+                self.fstore_def_vreg(output, inst_idx, 0, src_op)
+            }
+            VInst::Fcmp { cond, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let lhs = self.fuse_vreg(output, inst_idx, 1)? as u32;
+                let rhs = self.fuse_vreg(output, inst_idx, 2)? as u32;
+                let d = self.def_vreg(output, inst_idx, 0, t0)? as u32;
+                // `Gt`/`Ge` swap the operands rather than inventing predicates
+                // the ISA does not have; `Ne` is `!feq`, true on NaN.
+                let w = match cond {
+                    FcmpCond::Eq | FcmpCond::Ne => encode_feq_s(d, lhs, rhs),
+                    FcmpCond::Lt => encode_flt_s(d, lhs, rhs),
+                    FcmpCond::Le => encode_fle_s(d, lhs, rhs),
+                    FcmpCond::Gt => encode_flt_s(d, rhs, lhs),
+                    FcmpCond::Ge => encode_fle_s(d, rhs, lhs),
+                };
+                self.push_u32(w, src_op);
+                if *cond == FcmpCond::Ne {
+                    self.push_u32(encode_xori(d, d, 1), src_op);
+                }
+                self.store_def_vreg(output, inst_idx, 0, t0, src_op)
+            }
+            VInst::FSelect { .. } => self.emit_fselect(output, inst_idx, src_op),
+            VInst::FLoad32 { offset, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let b = self.use_vreg(output, inst_idx, 1, t0, src_op)? as u32;
+                let d = self.fdef_vreg(output, inst_idx, 0)? as u32;
+                self.push_u32(encode_flw(d, b, *offset), src_op);
+                self.fstore_def_vreg(output, inst_idx, 0, src_op)
+            }
+            VInst::FStore32 { offset, .. } => {
+                let s = self.fuse_vreg(output, inst_idx, 0)? as u32;
+                let b = self.use_vreg(output, inst_idx, 1, t0, src_op)? as u32;
+                self.push_u32(encode_fsw(s, b, *offset), src_op);
+                Ok(())
+            }
+            VInst::Wfr { .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s = self.use_vreg(output, inst_idx, 1, t0, src_op)? as u32;
+                let d = self.fdef_vreg(output, inst_idx, 0)? as u32;
+                self.push_u32(encode_fmv_w_x(d, s), src_op);
+                self.fstore_def_vreg(output, inst_idx, 0, src_op)
+            }
+            VInst::Rfr { .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s = self.fuse_vreg(output, inst_idx, 1)? as u32;
+                let d = self.def_vreg(output, inst_idx, 0, t0)? as u32;
+                self.push_u32(encode_fmv_x_w(d, s), src_op);
+                self.store_def_vreg(output, inst_idx, 0, t0, src_op)
+            }
+            VInst::IToF { signed, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s = self.use_vreg(output, inst_idx, 1, t0, src_op)? as u32;
+                let d = self.fdef_vreg(output, inst_idx, 0)? as u32;
+                let w = if *signed {
+                    encode_fcvt_s_w(d, s)
+                } else {
+                    encode_fcvt_s_wu(d, s)
+                };
+                self.push_u32(w, src_op);
+                self.fstore_def_vreg(output, inst_idx, 0, src_op)
+            }
+            VInst::FToI { signed, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                let s = self.fuse_vreg(output, inst_idx, 1)? as u32;
+                let d = self.def_vreg(output, inst_idx, 0, t0)? as u32;
+                let mask = Self::TEMP1 as u32;
+                // RTZ conversion already saturates ±∞ and finite out-of-range
+                // inputs; only NaN comes out wrong (as the positive bound).
+                // `feq.s s, s` is 0 exactly for NaN, so its negation is an
+                // all-ones / all-zeros mask that sends NaN to 0.
+                let w = if *signed {
+                    encode_fcvt_w_s(d, s, RoundingMode::Rtz)
+                } else {
+                    encode_fcvt_wu_s(d, s, RoundingMode::Rtz)
+                };
+                self.push_u32(w, src_op);
+                self.push_u32(encode_feq_s(mask, s, s), src_op);
+                self.push_u32(encode_sub(mask, 0, mask), src_op);
+                self.push_u32(encode_and(d, d, mask), src_op);
+                self.store_def_vreg(output, inst_idx, 0, t0, src_op)
+            }
+            _ => Err(crate::emit_err!(
+                "emit_float_vinst called with a non-float VInst: {}",
+                vinst.mnemonic()
+            )),
+        }
+    }
+
+    /// `FSelect` — RV32F has no conditional move, so a short forward branch
+    /// skips the one `fmv.s` that should not happen.
+    ///
+    /// ```text
+    ///   fmv.s d, if_false          # omitted when d already is one operand
+    ///   beqz  cond, 1f             # bnez when d holds if_true
+    ///   fmv.s d, if_true
+    /// 1:
+    /// ```
+    ///
+    /// Writing `d` first is wrong when `d` aliases `if_true` — the value the
+    /// branch then selects is gone — so that case inverts the sense and moves
+    /// `if_false` under `bnez` instead, with no scratch copy.
+    fn emit_fselect(
+        &mut self,
+        output: &AllocOutput,
+        inst_idx: usize,
+        src_op: Option<u32>,
+    ) -> Result<(), AllocError> {
+        if Self::is_dead_def(output, inst_idx, 0) {
+            return Ok(());
+        }
+        let cond = self.use_vreg(output, inst_idx, 1, Self::TEMP0, src_op)? as u32;
+        let if_true = self.fuse_vreg(output, inst_idx, 2)?;
+        let if_false = self.fuse_vreg(output, inst_idx, 3)?;
+        let d = self.fdef_vreg(output, inst_idx, 0)?;
+
+        let (skip_if_set, moved) = if d == if_true {
+            (true, if_false)
+        } else {
+            self.fmov(d, if_false, src_op);
+            (false, if_true)
+        };
+        if d != moved {
+            // +8: over the branch itself and the one move after it.
+            let w = if skip_if_set {
+                encode_bne(cond, 0, 8)
+            } else {
+                encode_beq(cond, 0, 8)
+            };
+            self.push_u32(w, src_op);
+            self.fmov(d, moved, src_op);
+        }
+        self.fstore_def_vreg(output, inst_idx, 0, src_op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use lps_shared::{LpsFnKind, LpsFnSig, LpsType};
+
+    use super::*;
+    use crate::abi::{FrameLayout, PReg, PregSet};
+    use crate::isa::rv32::emit::emit_function;
+    use crate::isa::shared::IsaEmitOutput;
+    use crate::regalloc::EditPoint;
+    use crate::regalloc::walk::build_operand_layout;
+    use crate::vinst::{ModuleSymbols, SRC_OP_NONE, VReg, VRegSlice};
+
+    const NONE: u16 = SRC_OP_NONE;
+
+    fn v(n: u16) -> VReg {
+        VReg(n)
+    }
+
+    fn ireg(hw: u8) -> Alloc {
+        Alloc::int_reg(hw)
+    }
+
+    fn freg(hw: u8) -> Alloc {
+        Alloc::reg(PReg::float(hw))
+    }
+
+    fn frame(spills: u32) -> FrameLayout {
+        let sig = LpsFnSig {
+            name: "t".into(),
+            return_type: LpsType::Int,
+            parameters: vec![],
+            kind: LpsFnKind::UserDefined,
+        };
+        let abi = crate::isa::rv32::abi::func_abi_rv32(&sig, None);
+        FrameLayout::compute(&abi, spills, PregSet::EMPTY, &[], false, 0, 0)
+    }
+
+    /// `AllocOutput` from a per-vreg allocation map, in the allocator's operand
+    /// layout — the helper Xtensa's `emit_fp` tests use.
+    fn alloc_output(
+        vinsts: &[VInst],
+        pool: &[VReg],
+        map: &[(u16, Alloc)],
+        edits: Vec<(EditPoint, Edit)>,
+        spills: u32,
+    ) -> AllocOutput {
+        let (inst_alloc_offsets, total, _classes) = build_operand_layout(vinsts, pool);
+        let mut allocs = vec![Alloc::None; total];
+        for (idx, inst) in vinsts.iter().enumerate() {
+            let mut ops: Vec<VReg> = Vec::new();
+            inst.for_each_def(pool, |r| ops.push(r));
+            inst.for_each_use(pool, |r| ops.push(r));
+            for (k, r) in ops.iter().enumerate() {
+                let a = map
+                    .iter()
+                    .find(|(vr, _)| *vr == r.0)
+                    .map(|(_, a)| *a)
+                    .unwrap_or_else(|| panic!("test map missing v{}", r.0));
+                allocs[inst_alloc_offsets[idx] as usize + k] = a;
+            }
+        }
+        AllocOutput {
+            allocs,
+            inst_alloc_offsets,
+            edits,
+            num_spill_slots: spills,
+            trace: crate::regalloc::trace_sink_new(),
+        }
+    }
+
+    fn words(
+        vinsts: &[VInst],
+        pool: &[VReg],
+        output: &AllocOutput,
+        frame: FrameLayout,
+    ) -> Vec<u32> {
+        let symbols = ModuleSymbols::default();
+        let out: IsaEmitOutput =
+            emit_function(vinsts, pool, output, frame, &symbols, false, false).expect("emit");
+        out.code
+            .chunks_exact(4)
+            .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect()
+    }
+
+    /// True when `needle` occurs in `hay` as a contiguous run.
+    fn has_run(hay: &[u32], needle: &[u32]) -> bool {
+        hay.windows(needle.len()).any(|w| w == needle)
+    }
+
+    fn ret0() -> VInst {
+        VInst::Ret {
+            vals: VRegSlice { start: 0, count: 1 },
+            src_op: NONE,
+        }
+    }
+
+    /// `a0 = bits(f(a0) OP f(a1))`: the boundary transfers and the op, in order.
+    #[test]
+    fn binops_encode_between_the_transfers() {
+        for (op, want) in [
+            (FAluOp::Add, encode_fadd_s(2, 0, 1)),
+            (FAluOp::Sub, encode_fsub_s(2, 0, 1)),
+            (FAluOp::Mul, encode_fmul_s(2, 0, 1)),
+            (FAluOp::Div, encode_fdiv_s(2, 0, 1)),
+        ] {
+            let pool = [v(0), v(1), v(2), v(3), v(4)];
+            let vinsts = [
+                VInst::Wfr {
+                    dst: v(2),
+                    src: v(0),
+                    src_op: NONE,
+                },
+                VInst::Wfr {
+                    dst: v(3),
+                    src: v(1),
+                    src_op: NONE,
+                },
+                VInst::FAluRRR {
+                    op,
+                    dst: v(4),
+                    src1: v(2),
+                    src2: v(3),
+                    src_op: NONE,
+                },
+                VInst::Rfr {
+                    dst: v(0),
+                    src: v(4),
+                    src_op: NONE,
+                },
+                ret0(),
+            ];
+            let out = alloc_output(
+                &vinsts,
+                &pool,
+                &[
+                    (0, ireg(10)),
+                    (1, ireg(11)),
+                    (2, freg(0)),
+                    (3, freg(1)),
+                    (4, freg(2)),
+                ],
+                vec![],
+                0,
+            );
+            let w = words(&vinsts, &pool, &out, frame(0));
+            let seq = [
+                encode_fmv_w_x(0, 10),
+                encode_fmv_w_x(1, 11),
+                want,
+                encode_fmv_x_w(10, 2),
+            ];
+            assert!(has_run(&w, &seq), "{op:?}: {w:08x?}");
+        }
+    }
+
+    /// `FToI` is the RTZ conversion followed by the NaN mask, built in `t1`
+    /// so it cannot collide with a spilled destination's `t0`.
+    #[test]
+    fn float_to_int_is_rtz_conversion_then_nan_mask() {
+        for (signed, conv) in [
+            (true, encode_fcvt_w_s(10, 0, RoundingMode::Rtz)),
+            (false, encode_fcvt_wu_s(10, 0, RoundingMode::Rtz)),
+        ] {
+            let pool = [v(0), v(1)];
+            let vinsts = [
+                VInst::Wfr {
+                    dst: v(1),
+                    src: v(0),
+                    src_op: NONE,
+                },
+                VInst::FToI {
+                    dst: v(0),
+                    src: v(1),
+                    signed,
+                    src_op: NONE,
+                },
+                ret0(),
+            ];
+            let out = alloc_output(&vinsts, &pool, &[(0, ireg(10)), (1, freg(0))], vec![], 0);
+            let w = words(&vinsts, &pool, &out, frame(0));
+            let t1 = EmitContext::TEMP1 as u32;
+            let seq = [
+                conv,
+                encode_feq_s(t1, 0, 0),
+                encode_sub(t1, 0, t1),
+                encode_and(10, 10, t1),
+            ];
+            assert!(has_run(&w, &seq), "signed={signed}: {w:08x?}");
+        }
+    }
+
+    #[test]
+    fn unary_ops_are_sign_injection_or_sqrt_and_mov_elides() {
+        for (op, want) in [
+            (FAluRROp::Neg, vec![encode_fsgnjn_s(1, 0, 0)]),
+            (FAluRROp::Abs, vec![encode_fsgnjx_s(1, 0, 0)]),
+            (FAluRROp::Sqrt, vec![encode_fsqrt_s(1, 0)]),
+            (FAluRROp::Mov, vec![encode_fsgnj_s(1, 0, 0)]),
+        ] {
+            let pool = [v(0), v(1), v(2)];
+            let vinsts = [
+                VInst::FAluRR {
+                    op,
+                    dst: v(2),
+                    src: v(1),
+                    src_op: NONE,
+                },
+                VInst::Rfr {
+                    dst: v(0),
+                    src: v(2),
+                    src_op: NONE,
+                },
+                ret0(),
+            ];
+            let out = alloc_output(
+                &vinsts,
+                &pool,
+                &[(0, ireg(10)), (1, freg(0)), (2, freg(1))],
+                vec![],
+                0,
+            );
+            let w = words(&vinsts, &pool, &out, frame(0));
+            assert!(has_run(&w, &want), "{op:?}: {w:08x?}");
+        }
+    }
+
+    #[test]
+    fn fcmp_swaps_for_gt_ge_and_negates_ne() {
+        for (cond, want) in [
+            (FcmpCond::Eq, vec![encode_feq_s(10, 0, 1)]),
+            (
+                FcmpCond::Ne,
+                vec![encode_feq_s(10, 0, 1), encode_xori(10, 10, 1)],
+            ),
+            (FcmpCond::Lt, vec![encode_flt_s(10, 0, 1)]),
+            (FcmpCond::Le, vec![encode_fle_s(10, 0, 1)]),
+            (FcmpCond::Gt, vec![encode_flt_s(10, 1, 0)]),
+            (FcmpCond::Ge, vec![encode_fle_s(10, 1, 0)]),
+        ] {
+            let pool = [v(0), v(1), v(2)];
+            let vinsts = [
+                VInst::Fcmp {
+                    cond,
+                    dst: v(0),
+                    lhs: v(1),
+                    rhs: v(2),
+                    src_op: NONE,
+                },
+                ret0(),
+            ];
+            let out = alloc_output(
+                &vinsts,
+                &pool,
+                &[(0, ireg(10)), (1, freg(0)), (2, freg(1))],
+                vec![],
+                0,
+            );
+            let w = words(&vinsts, &pool, &out, frame(0));
+            assert!(has_run(&w, &want), "{cond:?}: {w:08x?}");
+        }
+    }
+
+    /// All three alias shapes: the branch always skips exactly the move that
+    /// must not happen, and `d == if_true` never overwrites `if_true` first.
+    #[test]
+    fn fselect_is_correct_for_every_alias_shape() {
+        // (d, if_true, if_false) → expected sequence.
+        let cases: [(u8, &[u32]); 3] = [
+            (
+                2,
+                &[
+                    encode_fsgnj_s(2, 1, 1),
+                    encode_beq(10, 0, 8),
+                    encode_fsgnj_s(2, 0, 0),
+                ],
+            ),
+            (0, &[encode_bne(10, 0, 8), encode_fsgnj_s(0, 1, 1)]),
+            (1, &[encode_beq(10, 0, 8), encode_fsgnj_s(1, 0, 0)]),
+        ];
+        for (d, want) in cases {
+            let pool = [v(0), v(1), v(2), v(3), v(4)];
+            let vinsts = [
+                VInst::FSelect {
+                    dst: v(3),
+                    cond: v(0),
+                    if_true: v(1),
+                    if_false: v(2),
+                    src_op: NONE,
+                },
+                VInst::Rfr {
+                    dst: v(4),
+                    src: v(3),
+                    src_op: NONE,
+                },
+                VInst::Ret {
+                    vals: VRegSlice { start: 4, count: 1 },
+                    src_op: NONE,
+                },
+            ];
+            let out = alloc_output(
+                &vinsts,
+                &pool,
+                &[
+                    (0, ireg(10)),
+                    (1, freg(0)),
+                    (2, freg(1)),
+                    (3, freg(d)),
+                    (4, ireg(10)),
+                ],
+                vec![],
+                0,
+            );
+            let w = words(&vinsts, &pool, &out, frame(0));
+            assert!(has_run(&w, want), "d=f{d}: {w:08x?}");
+        }
+    }
+
+    /// A spilled float def computes into the scratch and is stored with `fsw`;
+    /// a float reload edit is an `flw`, both relative to the frame pointer.
+    #[test]
+    fn spilled_float_def_and_reload_go_through_the_scratch() {
+        let pool = [v(0), v(1), v(2)];
+        let vinsts = [
+            VInst::Wfr {
+                dst: v(1),
+                src: v(0),
+                src_op: NONE,
+            },
+            VInst::Rfr {
+                dst: v(2),
+                src: v(1),
+                src_op: NONE,
+            },
+            VInst::Ret {
+                vals: VRegSlice { start: 2, count: 1 },
+                src_op: NONE,
+            },
+        ];
+        let mut out = alloc_output(
+            &vinsts,
+            &pool,
+            &[(0, ireg(10)), (1, Alloc::Stack(0)), (2, ireg(10))],
+            vec![(
+                EditPoint::Before(1),
+                Edit::Move {
+                    from: Alloc::Stack(0),
+                    to: freg(0),
+                },
+            )],
+            1,
+        );
+        // The Rfr use sees the reloaded register, not the slot.
+        let (offsets, _, _) = build_operand_layout(&vinsts, &pool);
+        out.allocs[offsets[1] as usize + 1] = freg(0);
+        let f = frame(1);
+        let off = f.spill_offset_from_fp(0).unwrap();
+        let w = words(&vinsts, &pool, &out, f);
+        let seq = [
+            encode_fmv_w_x(fpr::SCRATCH as u32, 10),
+            encode_fsw(fpr::SCRATCH as u32, FP_REG as u32, off),
+            encode_flw(0, FP_REG as u32, off),
+            encode_fmv_x_w(10, 0),
+        ];
+        assert!(has_run(&w, &seq), "{w:08x?}");
+    }
+
+    #[test]
+    fn cross_class_edit_is_an_error() {
+        let pool = [v(0)];
+        let vinsts = [VInst::Ret {
+            vals: VRegSlice { start: 0, count: 0 },
+            src_op: NONE,
+        }];
+        let out = alloc_output(
+            &vinsts,
+            &pool,
+            &[],
+            vec![(
+                EditPoint::Before(0),
+                Edit::Move {
+                    from: ireg(10),
+                    to: freg(0),
+                },
+            )],
+            0,
+        );
+        let symbols = ModuleSymbols::default();
+        assert!(emit_function(&vinsts, &pool, &out, frame(0), &symbols, false, false).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! FPR index helpers for RV32F emission (`f0`–`f31`) — the register model for
+//! [`IsaTarget::Rv32imafc`](crate::isa::IsaTarget::Rv32imafc).
+//!
+//! Sibling of [`gpr`](super::gpr) and of Xtensa's `fpr.rs`. Register roles are
+//! the `ilp32f` calling convention's (RISC-V ELF psABI, "Floating-point
+//! Register Convention"), because the `lps-builtins` f32 family this backend
+//! calls is compiled for `riscv32imafc-unknown-none-elf`, whose ABI that is:
+//!
+//! | Registers | ABI name | Role | Saved by |
+//! |---|---|---|---|
+//! | `f0`–`f7` | `ft0`–`ft7` | temporaries | caller |
+//! | `f8`–`f9` | `fs0`–`fs1` | saved | callee |
+//! | `f10`–`f11` | `fa0`–`fa1` | arguments / return values | caller |
+//! | `f12`–`f17` | `fa2`–`fa7` | arguments | caller |
+//! | `f18`–`f27` | `fs2`–`fs11` | saved | callee |
+//! | `f28`–`f31` | `ft8`–`ft11` | temporaries | caller |
+//!
+//! # The pool is the temporaries
+//!
+//! [`ALLOC_POOL`] is `ft0`–`ft10`, and nothing else, for two reasons:
+//!
+//! - It leaves out every `fs` register, so the prologue and epilogue save no
+//!   float register and the frame layout is the C6's unchanged.
+//! - It leaves out `fa0`–`fa7`, so the float pool and the float argument bank
+//!   are disjoint the same way the integer pool and `a0`–`a7` are. A call's
+//!   staging moves then read pool registers and write argument registers, and
+//!   no cycle can form among them — which is why
+//!   [`IsaTarget::move_cycle_scratch`](crate::isa::IsaTarget::move_cycle_scratch)
+//!   needs no float scratch.
+//!
+//! Eleven registers is more than the S3's whole FR file gives its allocator,
+//! and every one of them is call-clobbered, so [`CALLER_SAVED_POOL`] is the
+//! whole pool.
+//!
+//! # One FPR is reserved as scratch
+//!
+//! `ft11` ([`SCRATCH`]), for the spilled def: an FP instruction whose
+//! destination the allocator put on the stack computes into it and is then
+//! stored with `fsw`. The argument for why one suffices is Xtensa's — see
+//! `isa/xt/fpr.rs` — and applies unchanged: only defs are ever
+//! stack-allocated, and no float `VInst` has two float defs.
+
+/// Physical FPR index (`f0`–`f31`).
+pub type FReg = u8;
+
+/// Number of floating-point registers the F extension provides.
+pub const FR_COUNT: u8 = 32;
+
+/// The emitter's float scratch (`ft11` = `f31`) — **not allocatable**.
+pub const SCRATCH: FReg = 31;
+
+/// Registers available to the allocator: `ft0`–`ft10`.
+///
+/// Order is the LRU initialization order. No pool member carries an ABI role,
+/// so the order carries no meaning beyond determinism.
+pub const ALLOC_POOL: &[FReg] = &[30, 29, 28, 7, 6, 5, 4, 3, 2, 1, 0];
+
+/// Pool members clobbered by a call — all of them, by `ilp32f`.
+pub const CALLER_SAVED_POOL: &[FReg] = ALLOC_POOL;
+
+/// Float argument registers (`fa0`–`fa7`).
+pub const ARG_REGS: &[FReg] = &[10, 11, 12, 13, 14, 15, 16, 17];
+
+/// Float return registers (`fa0`–`fa1`).
+pub const RET_REGS: &[FReg] = &[10, 11];
+
+/// ABI name for debugging / text format.
+pub fn reg_name(reg: FReg) -> &'static str {
+    match reg {
+        0 => "ft0",
+        1 => "ft1",
+        2 => "ft2",
+        3 => "ft3",
+        4 => "ft4",
+        5 => "ft5",
+        6 => "ft6",
+        7 => "ft7",
+        8 => "fs0",
+        9 => "fs1",
+        10 => "fa0",
+        11 => "fa1",
+        12 => "fa2",
+        13 => "fa3",
+        14 => "fa4",
+        15 => "fa5",
+        16 => "fa6",
+        17 => "fa7",
+        18 => "fs2",
+        19 => "fs3",
+        20 => "fs4",
+        21 => "fs5",
+        22 => "fs6",
+        23 => "fs7",
+        24 => "fs8",
+        25 => "fs9",
+        26 => "fs10",
+        27 => "fs11",
+        28 => "ft8",
+        29 => "ft9",
+        30 => "ft10",
+        31 => "ft11",
+        _ => "???",
+    }
+}
+
+/// Parse a register name — ABI (`ft0`, `fa3`, …) or numeric (`f0`–`f31`) —
+/// to its physical index.
+#[allow(
+    clippy::result_unit_err,
+    reason = "gpr.rs shape parity: same signature as isa/rv32/gpr.rs::parse_reg"
+)]
+pub fn parse_reg(name: &str) -> Result<FReg, ()> {
+    if let Some(r) = (0..FR_COUNT).find(|&r| reg_name(r) == name) {
+        return Ok(r);
+    }
+    let digits = name.strip_prefix('f').ok_or(())?;
+    if digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return Err(());
+    }
+    match digits.parse::<u8>() {
+        Ok(n) if n < FR_COUNT => Ok(n),
+        _ => Err(()),
+    }
+}
+
+#[inline]
+pub fn pool_contains(r: FReg) -> bool {
+    ALLOC_POOL.contains(&r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reg_name_round_trips() {
+        for i in 0..FR_COUNT {
+            assert_eq!(parse_reg(reg_name(i)), Ok(i), "round trip failed for f{i}");
+            assert_eq!(parse_reg(&alloc::format!("f{i}")), Ok(i));
+        }
+    }
+
+    #[test]
+    fn parse_reg_rejects_non_registers() {
+        for bad in ["f32", "f-1", "f", "a0", "", "f00", "ft12", "F0"] {
+            assert_eq!(parse_reg(bad), Err(()), "{bad} parsed as a register");
+        }
+    }
+
+    /// The pool is exactly the temporaries minus the scratch: no `fs` register
+    /// (the prologue would have to save it) and no `fa` register (a staging
+    /// move could then read another one's destination).
+    #[test]
+    fn the_pool_is_the_temporaries_but_the_scratch() {
+        for r in 0..FR_COUNT {
+            let temporary = r <= 7 || r >= 28;
+            assert_eq!(
+                pool_contains(r),
+                temporary && r != SCRATCH,
+                "{} is on the wrong side of the pool",
+                reg_name(r)
+            );
+        }
+        for (i, &f) in ALLOC_POOL.iter().enumerate() {
+            assert!(!ALLOC_POOL[i + 1..].contains(&f), "duplicate f{f}");
+        }
+        assert_eq!(CALLER_SAVED_POOL, ALLOC_POOL);
+        assert!(RET_REGS.iter().all(|r| ARG_REGS.contains(r)));
+    }
+}
@@ -14,8 +14,16 @@ pub(crate) const R_RISCV_CALL_PLT: u32 = 17;
 /// `e_flags` value for the soft-float ABI used by ESP32-C6.
 pub const EF_RISCV_FLOAT_ABI_SOFT: u32 = elf::EF_RISCV_FLOAT_ABI_SOFT;
 
+/// `e_flags` value for the `ilp32f` hard-float ABI (`Rv32imafc`). The linker
+/// refuses to merge objects whose float ABIs differ, so this must match the
+/// builtins image the object is linked against.
+pub const EF_RISCV_FLOAT_ABI_SINGLE: u32 = elf::EF_RISCV_FLOAT_ABI_SINGLE;
+
 /// Patch an RV32 `auipc + jalr` call sequence at `code[reloc.offset..]` so the
 /// call resolves to `target_addr` (absolute runtime address).
+///
+/// An `auipc + addi` pair (the address of a data symbol) takes the same
+/// patch: both second words carry the low 12 bits in the I-type immediate.
 pub fn patch_call_plt(
     code: &mut [u8],
     reloc: &NativeReloc,
@@ -42,10 +50,10 @@ pub fn patch_call_plt(
             .map_err(|_| NativeError::Internal(String::from("jalr read")))?,
     );
 
-    // Verify auipc+jalr encoding
-    if (auipc_word & 0x7f) != 0x17 || (jalr_word & 0x7f) != 0x67 {
+    // Verify auipc+jalr (or auipc+addi) encoding
+    if (auipc_word & 0x7f) != 0x17 || !matches!(jalr_word & 0x7f, 0x67 | 0x13) {
         return Err(NativeError::Internal(alloc::format!(
-            "expected auipc+jalr at offset {off}, got 0x{auipc_word:08x} 0x{jalr_word:08x}"
+            "expected auipc+jalr or auipc+addi at offset {off}, got 0x{auipc_word:08x} 0x{jalr_word:08x}"
         )));
     }
 
@@ -1,8 +1,14 @@
-//! RV32 ISA-specific code: encoding, GPR, ABI, emission.
+//! RV32 ISA-specific code: encoding, GPR/FPR, ABI, emission.
 
 pub mod abi;
 pub mod debug;
 pub mod emit;
+// The F-extension half of the emitter and its register model, gated like
+// Xtensa's so a Fixed-only image links no float tables.
+#[cfg(feature = "float-f32")]
+pub mod emit_fp;
 pub mod encode;
+#[cfg(feature = "float-f32")]
+pub mod fpr;
 pub mod gpr;
 pub mod link;
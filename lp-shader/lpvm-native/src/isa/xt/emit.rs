@@ -109,8 +109,8 @@ const CALLX: CallxOp = CallxOp::Callx8;
 enum Literal {
     /// A known 32-bit constant (deduplicated by value).
     Const(u32),
-    /// The absolute address of a call target, patched by the linker/JIT
-    /// (deduplicated by symbol; reported as a call relocation).
+    /// The absolute address of a call target or data symbol, patched by the
+    /// linker/JIT (deduplicated by symbol; reported as a call relocation).
     Sym(crate::vinst::SymbolId),
 }
 
@@ -723,6 +723,7 @@ impl<'a> EmitContext<'a> {
             AluOp::Sub => Some(AluRrr::Sub),
             AluOp::Mul => Some(AluRrr::Mull),
             AluOp::MulH => Some(AluRrr::Mulsh),
+            AluOp::MulHU => Some(AluRrr::Muluh),
             AluOp::And => Some(AluRrr::And),
             AluOp::Or => Some(AluRrr::Or),
             AluOp::Xor => Some(AluRrr::Xor),
@@ -1347,6 +1348,16 @@ impl<'a> EmitContext<'a> {
                 self.add_imm(rd, SP, off, S1, src_op)?;
                 self.store_def_vreg(output, inst_idx, 0, S0, src_op)?;
             }
+            VInst::SymbolAddr { target, .. } => {
+                if Self::is_dead_def(output, inst_idx, 0) {
+                    return Ok(());
+                }
+                // Pooled like a call target: the literal slot is the relocation.
+                let rd = self.def_vreg(output, inst_idx, 0, S0)?;
+                let lit = self.lit(Literal::Sym(*target));
+                self.push_item(Item::L32r { rt: rd, lit }, src_op);
+                self.store_def_vreg(output, inst_idx, 0, S0, src_op)?;
+            }
             VInst::MemcpyWords { size, .. } => {
                 self.emit_memcpy(output, inst_idx, *size, src_op)?;
             }
@@ -1392,7 +1403,8 @@ impl<'a> EmitContext<'a> {
             | VInst::FStore32 { .. }
             | VInst::Wfr { .. }
             | VInst::Rfr { .. }
-            | VInst::IToF { .. } => {
+            | VInst::IToF { .. }
+            | VInst::FToI { .. } => {
                 self.emit_float_vinst(vinst, output, inst_idx, src_op)?;
             }
             // Without `float-f32` there is no FP emitter linked, so a float
@@ -1410,7 +1422,8 @@ impl<'a> EmitContext<'a> {
             | VInst::FStore32 { .. }
             | VInst::Wfr { .. }
             | VInst::Rfr { .. }
-            | VInst::IToF { .. } => {
+            | VInst::IToF { .. }
+            | VInst::FToI { .. } => {
                 return Err(crate::emit_err!(
                     "xt emitter: {} needs the `float-f32` feature (M7 D9)",
                     vinst.mnemonic()
@@ -2113,6 +2126,8 @@ mod tests {
                 callee_uses_sret: false,
                 caller_passes_sret_ptr: false,
                 caller_sret_vm_abi_swap: false,
+                float_args: 0,
+                float_rets: 0,
                 src_op: NONE,
             },
             VInst::AluRRI {
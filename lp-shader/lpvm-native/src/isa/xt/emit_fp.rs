@@ -305,6 +305,11 @@ impl EmitContext<'_> {
                     FAluOp::Add => FpRrrOp::AddS,
                     FAluOp::Sub => FpRrrOp::SubS,
                     FAluOp::Mul => FpRrrOp::MulS,
+                    // The LX7 FPU has no single-instruction divide; lowering
+                    // calls the builtin unless `IsaTarget::inlines_f32_div_sqrt`.
+                    FAluOp::Div => {
+                        return Err(crate::emit_err!("xt emitter: the LX7 FPU has no FDiv"));
+                    }
                 };
                 self.inst(Inst::FpRrr(fop, d, s1, s2), src_op);
                 self.fstore_def_vreg(output, inst_idx, 0, src_op)
@@ -319,6 +324,9 @@ impl EmitContext<'_> {
                     FAluRROp::Mov => FpRrOp::MovS,
                     FAluRROp::Abs => FpRrOp::AbsS,
                     FAluRROp::Neg => FpRrOp::NegS,
+                    FAluRROp::Sqrt => {
+                        return Err(crate::emit_err!("xt emitter: the LX7 FPU has no FSqrt"));
+                    }
                 };
                 // `mov.s fr, fr` is the identity; every other form is not, so
                 // only the move may be elided.
@@ -383,6 +391,11 @@ impl EmitContext<'_> {
                 self.inst(Inst::IntToFp(op, d, s, 0), src_op);
                 self.fstore_def_vreg(output, inst_idx, 0, src_op)
             }
+            // `trunc.s`'s out-of-range behaviour is unmeasured (M6-P6), so
+            // lowering calls the builtin unless `IsaTarget::inlines_f32_ftoi_sat`.
+            VInst::FToI { .. } => Err(crate::emit_err!(
+                "xt emitter: FToI is not lowered inline on the LX7"
+            )),
             _ => Err(crate::emit_err!(
                 "emit_float_vinst called with a non-float VInst: {}",
                 vinst.mnemonic()
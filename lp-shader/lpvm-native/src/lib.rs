@@ -36,6 +36,7 @@ extern crate alloc;
 pub use log;
 
 pub mod abi;
+pub mod code_cache;
 pub mod codemem_esp32;
 pub mod compile;
 pub mod config;
@@ -93,6 +94,7 @@ pub use link::{LinkedJitImage, link_elf, link_jit};
 pub use lower::{LoopRegion, LoweredFunction, lower_lpir_op, lower_ops};
 pub use lower_opts::LowerOpts;
 pub use native_options::NativeCompileOptions;
+pub use regalloc::{AllocStats, RegAllocStrategy};
 pub use types::NativeType;
 pub use vinst::{
     IcmpCond, IrVReg, LabelId, ModuleSymbols, SRC_OP_NONE, SymbolId, TempVRegs, VInst, VReg,
@@ -6,9 +6,9 @@ use lp_collection::VecMap;
 use object::write::{Object, Relocation, StandardSection, Symbol, SymbolId, SymbolSection};
 use object::{BinaryFormat, Endianness, FileFlags, SymbolFlags, SymbolKind, SymbolScope, elf};
 
-use crate::compile::CompiledModule;
 #[cfg(feature = "isa-rv32")]
 use crate::compile::NativeReloc;
+use crate::compile::{CompiledModule, RODATA_SYMBOL};
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 
@@ -27,7 +27,9 @@ pub struct LinkedJitImage {
 ///
 /// # Arguments
 /// * `module` - Compiled module with functions and relocations
-/// * `resolve_symbol` - Callback to resolve symbol names to addresses
+/// * `resolve_symbol` - Callback to resolve symbol names to addresses: the
+///   builtins, and [`RODATA_SYMBOL`] for a module with read-only data — the
+///   image holds code only, so the caller keeps [`CompiledModule::rodata`]
 ///
 /// # Returns
 /// Linked JIT image with all call sites patched.
@@ -135,7 +137,7 @@ where
             let absolute_offset = func_base + reloc.offset;
             match isa {
                 #[cfg(feature = "isa-rv32")]
-                IsaTarget::Rv32imac => {
+                IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                     if reloc.r_type == isa.call_reloc_type() {
                         let abs_reloc = NativeReloc {
                             offset: absolute_offset,
@@ -235,6 +237,22 @@ pub fn link_elf(module: &CompiledModule, isa: IsaTarget) -> Result<Vec<u8>, Nati
         });
         symbol_ids.insert(func.name.clone(), sym_id);
     }
+    // Read-only data follows the code in `.text`, so the loaders that place
+    // `.text` place it too.
+    let rodata_sym = (!module.rodata.is_empty()).then(|| {
+        let sym_id = obj.add_symbol(Symbol {
+            name: RODATA_SYMBOL.as_bytes().to_vec(),
+            value: 0,
+            size: (module.rodata.len() * 4) as u64,
+            kind: SymbolKind::Data,
+            scope: SymbolScope::Compilation,
+            weak: false,
+            section: SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+        symbol_ids.insert(String::from(RODATA_SYMBOL), sym_id);
+        sym_id
+    });
 
     // Append code for each function and update symbol values
     for func in &module.functions {
@@ -283,6 +301,11 @@ pub fn link_elf(module: &CompiledModule, isa: IsaTarget) -> Result<Vec<u8>, Nati
         }
     }
 
+    if let Some(sym_id) = rodata_sym {
+        let bytes: Vec<u8> = module.rodata.iter().flat_map(|w| w.to_le_bytes()).collect();
+        obj.symbol_mut(sym_id).value = obj.append_section_data(text, &bytes, 4);
+    }
+
     obj.write()
         .map_err(|e| NativeError::Internal(format!("ELF write failed: {e}")))
 }
@@ -303,8 +326,10 @@ mod tests {
                 relocs: vec![],
                 debug_lines: None,
                 debug_info: None,
+                alloc_stats: Default::default(),
             }],
             symbols: crate::vinst::ModuleSymbols::default(),
+            rodata: Vec::new(),
         }
     }
 
@@ -356,6 +381,7 @@ mod tests {
                     }],
                     debug_lines: None,
                     debug_info: None,
+                    alloc_stats: Default::default(),
                 },
                 crate::compile::CompiledFunction {
                     name: String::from("callee"),
@@ -363,9 +389,11 @@ mod tests {
                     relocs: vec![],
                     debug_lines: None,
                     debug_info: None,
+                    alloc_stats: Default::default(),
                 },
             ],
             symbols: crate::vinst::ModuleSymbols::default(),
+            rodata: Vec::new(),
         };
 
         // Custom resolver that returns the offset of "callee"
@@ -385,4 +413,42 @@ mod tests {
         assert_eq!(linked.entries["caller"], 0);
         assert_eq!(linked.entries["callee"], 12);
     }
+
+    fn rodata_module() -> CompiledModule {
+        CompiledModule {
+            functions: vec![crate::compile::CompiledFunction {
+                name: String::from("table"),
+                code: vec![
+                    0x97, 0x02, 0x00, 0x00, // auipc t0, 0
+                    0x93, 0x82, 0x02, 0x00, // addi t0, t0, 0
+                    0x67, 0x80, 0x00, 0x00, // ret
+                ],
+                relocs: vec![NativeReloc {
+                    offset: 0,
+                    symbol: String::from(RODATA_SYMBOL),
+                    r_type: crate::isa::rv32::link::R_RISCV_CALL_PLT,
+                }],
+                debug_lines: None,
+                debug_info: None,
+                alloc_stats: Default::default(),
+            }],
+            symbols: crate::vinst::ModuleSymbols::default(),
+            rodata: vec![7, 0x8000],
+        }
+    }
+
+    #[test]
+    fn test_link_jit_patches_a_rodata_address() {
+        let module = rodata_module();
+        let linked = link_jit_at(&module, IsaTarget::Rv32imac, 0x1000, |sym| {
+            (sym == RODATA_SYMBOL).then_some(0x2800)
+        })
+        .unwrap();
+
+        // 0x1800 past the auipc: the low half is negative, so hi20 rounds up.
+        let word = |i: usize| u32::from_le_bytes(linked.code[i..i + 4].try_into().unwrap());
+        let hi = word(0) & 0xFFFF_F000;
+        let lo = (word(4) as i32) >> 20;
+        assert_eq!(0x1000u32.wrapping_add(hi).wrapping_add(lo as u32), 0x2800);
+    }
 }
@@ -27,9 +27,12 @@
 //!     wrapping.
 //!   * `Fmul`: 5-VInst `mul`/`mulh`/`srli`/`slli`/`or` sequence computing
 //!     `((a * b) >> 16)` mod 2^32, wrapping.
-//!   * `Fdiv`: [`BuiltinId::LpLpirFdivRecipQ32`] sym_call — reciprocal
-//!     multiplication, ~0.01% typical error, with an explicit divisor==0
-//!     saturation guard inside the helper.
+//!   * `Fdiv`: inline reciprocal multiplication (`divu` for the
+//!     reciprocal, `mul`/`mulhu` for the wide product), ~0.01% typical
+//!     error, with a branch-free divisor==0 saturation select. Bit-exact
+//!     with the [`BuiltinId::LpLpirFdivRecipQ32`] helper, which the classic
+//!     ESP32 still calls to fit its code region
+//!     ([`crate::native_options::NativeCompileOptions::inline_q32_fdiv`]).
 //!   * `FdivConstF32`: inline `lhs * q32(1.0 / rhs)`, wrapping.
 //!
 //!   This is the only Q32 arithmetic configuration the product ships. The
@@ -323,6 +326,170 @@ fn emit_q32_fdiv_const_zero(
     });
 }
 
+/// Q32 `Fdiv`: the reciprocal-multiply divide, inline. Bit-exact with
+/// `__lp_lpir_fdiv_recip_q32` and `lpvm-wasm`'s `emit_q32_fdiv_recip`:
+///
+/// ```text
+/// recip = 0x8000_0000 /u |rhs|
+/// quot  = ((|lhs| as u64 * recip) >> 15) as u32     // mul + mulhu
+/// dst   = rhs == 0 ? sat(lhs) : (lhs ^ rhs) < 0 ? -quot : quot
+/// ```
+///
+/// Branch-free: a zero divisor is or-ed up to 1 so the `divu` is defined on
+/// every ISA (Xtensa's `quou` traps on zero), and the final select replaces
+/// that lane with the same sign-based saturation as a constant zero divisor.
+fn emit_q32_fdiv_recip(
+    out: &mut Vec<VInst>,
+    temps: &mut TempVRegs,
+    dst: VReg,
+    lhs: VReg,
+    rhs: VReg,
+    src_op: u16,
+) {
+    let is_zero = temps.mint();
+    out.push(VInst::IcmpImm {
+        dst: is_zero,
+        src: rhs,
+        imm: 0,
+        cond: IcmpCond::Eq,
+        src_op,
+    });
+    let saturated = temps.mint();
+    emit_q32_fdiv_const_zero(out, temps, saturated, lhs, src_op);
+
+    // `sign` is all ones when the operands' signs differ.
+    let sign = temps.mint();
+    out.push(VInst::AluRRR {
+        op: AluOp::Xor,
+        dst: sign,
+        src1: lhs,
+        src2: rhs,
+        src_op,
+    });
+    out.push(VInst::AluRRI {
+        op: AluImmOp::SraiS,
+        dst: sign,
+        src: sign,
+        imm: 31,
+        src_op,
+    });
+    let abs_lhs = emit_abs(out, temps, lhs, src_op);
+    let abs_rhs = emit_abs(out, temps, rhs, src_op);
+    out.push(VInst::AluRRR {
+        op: AluOp::Or,
+        dst: abs_rhs,
+        src1: abs_rhs,
+        src2: is_zero,
+        src_op,
+    });
+
+    let top = temps.mint();
+    let recip = temps.mint();
+    out.push(VInst::IConst32 {
+        dst: top,
+        val: i32::MIN,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::DivU,
+        dst: recip,
+        src1: top,
+        src2: abs_rhs,
+        src_op,
+    });
+
+    let lo = temps.mint();
+    let hi = temps.mint();
+    let quot = temps.mint();
+    out.push(VInst::AluRRR {
+        op: AluOp::Mul,
+        dst: lo,
+        src1: abs_lhs,
+        src2: recip,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::MulHU,
+        dst: hi,
+        src1: abs_lhs,
+        src2: recip,
+        src_op,
+    });
+    out.push(VInst::AluRRI {
+        op: AluImmOp::SrliU,
+        dst: lo,
+        src: lo,
+        imm: 15,
+        src_op,
+    });
+    out.push(VInst::AluRRI {
+        op: AluImmOp::Slli,
+        dst: hi,
+        src: hi,
+        imm: 17,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::Or,
+        dst: quot,
+        src1: lo,
+        src2: hi,
+        src_op,
+    });
+
+    // Conditional negate: `(quot ^ sign) - sign`.
+    out.push(VInst::AluRRR {
+        op: AluOp::Xor,
+        dst: quot,
+        src1: quot,
+        src2: sign,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::Sub,
+        dst: quot,
+        src1: quot,
+        src2: sign,
+        src_op,
+    });
+    out.push(VInst::Select {
+        dst,
+        cond: is_zero,
+        if_true: saturated,
+        if_false: quot,
+        src_op,
+    });
+}
+
+/// `|src|` as a u32 bit pattern (`i32::MIN` stays `0x8000_0000`), into a
+/// fresh temp.
+fn emit_abs(out: &mut Vec<VInst>, temps: &mut TempVRegs, src: VReg, src_op: u16) -> VReg {
+    let mask = temps.mint();
+    let abs = temps.mint();
+    out.push(VInst::AluRRI {
+        op: AluImmOp::SraiS,
+        dst: mask,
+        src,
+        imm: 31,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::Xor,
+        dst: abs,
+        src1: src,
+        src2: mask,
+        src_op,
+    });
+    out.push(VInst::AluRRR {
+        op: AluOp::Sub,
+        dst: abs,
+        src1: abs,
+        src2: mask,
+        src_op,
+    });
+    abs
+}
+
 /// Lower one of the four LPIR integer divide/remainder ops.
 ///
 /// On an ISA whose native divide already implements the LPIR contract this is
@@ -545,6 +712,8 @@ fn sym_call(
         callee_uses_sret: false,
         caller_passes_sret_ptr: false,
         caller_sret_vm_abi_swap: false,
+        float_args: 0,
+        float_rets: 0,
         src_op: pack_src_op(src_op),
     });
     Ok(())
@@ -1130,6 +1299,14 @@ pub fn lower_lpir_op(
             });
             Ok(())
         }
+        LpirOp::RodataAddr { dst } => {
+            out.push(VInst::SymbolAddr {
+                dst: fa_vreg(*dst),
+                target: symbols.intern(crate::compile::RODATA_SYMBOL),
+                src_op: po,
+            });
+            Ok(())
+        }
         LpirOp::Memcpy {
             dst_addr,
             src_addr,
@@ -1201,15 +1378,21 @@ pub fn lower_lpir_op(
             emit_q32_fmul_wrap(out, temps, fa_vreg(*dst), fa_vreg(*lhs), fa_vreg(*rhs), po);
             Ok(())
         }
-        LpirOp::Fdiv { dst, lhs, rhs } if opts.float_mode == FloatMode::Q32 => sym_call(
-            out,
-            symbols,
-            vreg_pool,
-            BuiltinId::LpLpirFdivRecipQ32.name(),
-            &[*lhs, *rhs],
-            &[*dst],
-            src_op,
-        ),
+        LpirOp::Fdiv { dst, lhs, rhs } if opts.float_mode == FloatMode::Q32 => {
+            if !opts.inline_q32_fdiv {
+                return sym_call(
+                    out,
+                    symbols,
+                    vreg_pool,
+                    BuiltinId::LpLpirFdivRecipQ32.name(),
+                    &[*lhs, *rhs],
+                    &[*dst],
+                    src_op,
+                );
+            }
+            emit_q32_fdiv_recip(out, temps, fa_vreg(*dst), fa_vreg(*lhs), fa_vreg(*rhs), po);
+            Ok(())
+        }
         LpirOp::FdivConstF32 { dst, lhs, rhs } if opts.float_mode == FloatMode::Q32 => {
             if *rhs == 0.0 {
                 emit_q32_fdiv_const_zero(out, temps, fa_vreg(*dst), fa_vreg(*lhs), po);
@@ -1663,10 +1846,13 @@ pub fn lower_lpir_op(
         | LpirOp::Else
         | LpirOp::End
         | LpirOp::LoopStart { .. }
+        | LpirOp::SwitchStart { .. }
+        | LpirOp::CaseStart { .. }
+        | LpirOp::DefaultStart { .. }
         | LpirOp::Block { .. }
         | LpirOp::ExitBlock => Err(LowerError::UnsupportedOp {
             description: String::from(
-                "structural control-flow op must be lowered via lower_ops (IfStart/LoopStart/Block/Else/End/ExitBlock)",
+                "structural control-flow op must be lowered via lower_ops (IfStart/LoopStart/SwitchStart/Block/Else/End/ExitBlock)",
             ),
         }),
         LpirOp::Break | LpirOp::Continue | LpirOp::BrIfNot { .. } => {
@@ -1705,35 +1891,39 @@ pub fn lower_lpir_op(
                 caller_passes_sret_ptr && callee_sret_vm_abi_swap(ir, *callee);
             // Boundaries two and three. Float arguments come out of the float
             // file before the call and float results go back in after it, so
-            // the `Call`'s own operands are entirely integer-class — which is
-            // what leaves the sret/vmctx slot machinery and
-            // `lpir_call_arg_target` working unchanged.
+            // the `Call`'s own operands are integer-class — which is what
+            // leaves the sret/vmctx slot machinery and `lpir_call_arg_target`
+            // working unchanged. The exception is an import on a target with a
+            // float argument bank: the builtin is compiled for that ABI, and
+            // `plan_call` hands it floats in float registers directly.
             #[cfg(feature = "float-f32")]
             if hardware_fpu(opts, abi) {
                 let target = symbols.intern(name);
-                let mut arg_words = Vec::with_capacity(args_slice.len());
-                for a in args_slice {
-                    arg_words.push(crate::lower_f32::word_operand(out, func, *a, temps, po));
-                }
-                let mut ret_words = Vec::with_capacity(results_slice.len());
-                let mut ret_transfers = Vec::with_capacity(results_slice.len());
-                for r in results_slice {
-                    let (word, float_dst) = crate::lower_f32::word_result(func, *r, temps);
-                    ret_words.push(word);
-                    ret_transfers.push((word, float_dst));
-                }
+                // Shader functions take words on every target.
+                let builtin_callee = ir.callee_as_import(*callee).is_some();
+                let plan = crate::lower_f32::plan_call(
+                    out,
+                    abi.isa(),
+                    builtin_callee,
+                    func,
+                    args_slice,
+                    results_slice,
+                    callee_uses_sret,
+                    temps,
+                    po,
+                )?;
                 out.push(VInst::Call {
                     target,
-                    args: push_backend_vregs(vreg_pool, &arg_words)?,
-                    rets: push_backend_vregs(vreg_pool, &ret_words)?,
+                    args: push_backend_vregs(vreg_pool, &plan.args)?,
+                    rets: push_backend_vregs(vreg_pool, &plan.rets)?,
                     callee_uses_sret,
                     caller_passes_sret_ptr,
                     caller_sret_vm_abi_swap,
+                    float_args: plan.float_args,
+                    float_rets: plan.float_rets,
                     src_op: po,
                 });
-                for (word, float_dst) in ret_transfers {
-                    crate::lower_f32::push_return_transfer(out, word, float_dst, po);
-                }
+                plan.push_return_transfers(out, po);
                 return Ok(());
             }
             out.push(VInst::Call {
@@ -1743,14 +1933,12 @@ pub fn lower_lpir_op(
                 callee_uses_sret,
                 caller_passes_sret_ptr,
                 caller_sret_vm_abi_swap,
+                float_args: 0,
+                float_rets: 0,
                 src_op: po,
             });
             Ok(())
         }
-
-        other => Err(LowerError::UnsupportedOp {
-            description: format!("{other:?}"),
-        }),
     }
 }
 
@@ -2088,6 +2276,25 @@ impl<'a> LowerCtx<'a> {
                     i = eo;
                     current_linear_start = Some(self.out.len() as u16);
                 }
+                LpirOp::SwitchStart {
+                    selector,
+                    end_offset,
+                } => {
+                    flush_linear(
+                        &mut seq,
+                        &mut self.region_tree,
+                        &mut current_linear_start,
+                        vinst_start,
+                    );
+                    let eo = *end_offset as usize;
+                    let (cases, default) = self.switch_arms(i, eo)?;
+                    let chain = self.lower_switch_chain(fa_vreg(*selector), &cases, default)?;
+                    if chain != REGION_ID_NONE {
+                        seq.push(chain);
+                    }
+                    i = eo;
+                    current_linear_start = Some(self.out.len() as u16);
+                }
                 LpirOp::Block { end_offset } => {
                     flush_linear(
                         &mut seq,
@@ -2244,8 +2451,146 @@ impl<'a> LowerCtx<'a> {
             Ok(self.region_tree.push_seq(&seq))
         }
     }
+
+    /// Split the `SwitchStart` at `start` (closed by the `End` before `end`)
+    /// into its `case` arms, in order, and its `default` arm. Each arm is the
+    /// `(header index, body start, body end)` of its ops, without the `End`.
+    fn switch_arms(&self, start: usize, end: usize) -> Result<SwitchArms, LowerError> {
+        let mut cases = Vec::new();
+        let mut default = None;
+        let mut j = start + 1;
+        let last = end.saturating_sub(1);
+        while j < last {
+            let (arm_end, value) = match &self.func.body[j] {
+                LpirOp::CaseStart { value, end_offset } => (*end_offset as usize, Some(*value)),
+                LpirOp::DefaultStart { end_offset } => (*end_offset as usize, None),
+                _ => {
+                    return Err(LowerError::UnsupportedOp {
+                        description: String::from("switch body op outside a case/default arm"),
+                    });
+                }
+            };
+            if arm_end <= j || arm_end > last {
+                return Err(LowerError::UnsupportedOp {
+                    description: String::from("switch arm end_offset out of range"),
+                });
+            }
+            let arm = SwitchArm {
+                header: j,
+                body: j + 1..arm_end - 1,
+            };
+            match value {
+                Some(value) => cases.push((value, arm)),
+                None => default = Some(arm),
+            }
+            j = arm_end;
+        }
+        Ok((cases, default))
+    }
+
+    /// Lower a switch as an `if`/`else` chain: one `IcmpImm` + `BrIf` per
+    /// case in source order, the default arm (wherever it appeared) in the
+    /// final `else`. Each level has the exact region shape of a lowered
+    /// `IfStart`, so the allocator sees nothing new.
+    fn lower_switch_chain(
+        &mut self,
+        selector: VReg,
+        cases: &[(i32, SwitchArm)],
+        default: Option<SwitchArm>,
+    ) -> Result<RegionId, LowerError> {
+        use crate::region::Region;
+
+        let Some(((value, arm), rest)) = cases.split_first() else {
+            return match default {
+                Some(arm) => self.lower_range(arm.body.start, arm.body.end),
+                None => Ok(REGION_ID_NONE),
+            };
+        };
+        let src_op = pack_src_op(Some(arm.header as u32));
+        let has_else = !rest.is_empty() || default.is_some();
+        let else_label = self.alloc_label();
+        let end_label = if has_else {
+            self.alloc_label()
+        } else {
+            else_label
+        };
+
+        let head_start = self.out.len() as u16;
+        let matches = self.temps.mint();
+        self.out.push(VInst::IcmpImm {
+            dst: matches,
+            src: selector,
+            imm: *value,
+            cond: IcmpCond::Eq,
+            src_op,
+        });
+        self.out.push(VInst::BrIf {
+            cond: matches,
+            target: else_label,
+            invert: true,
+            src_op,
+        });
+        let head = self.region_tree.push(Region::Linear {
+            start: head_start,
+            end: self.out.len() as u16,
+        });
+
+        let then_inner = self.lower_range(arm.body.start, arm.body.end)?;
+        let br_start = self.out.len() as u16;
+        self.out.push(VInst::Br {
+            target: end_label,
+            src_op,
+        });
+        let br_end = self.region_tree.push(Region::Linear {
+            start: br_start,
+            end: self.out.len() as u16,
+        });
+
+        let (then_body, else_body) = if has_else {
+            let then_body = self.region_tree.push_seq(&[then_inner, br_end]);
+            let else_lbl = self.push_label_region(else_label, src_op);
+            let else_inner = self.lower_switch_chain(selector, rest, default)?;
+            let end_lbl = self.push_label_region(end_label, src_op);
+            let else_body = if else_inner == REGION_ID_NONE {
+                self.region_tree.push_seq(&[else_lbl, end_lbl])
+            } else {
+                self.region_tree.push_seq(&[else_lbl, else_inner, end_lbl])
+            };
+            (then_body, else_body)
+        } else {
+            let end_lbl = self.push_label_region(end_label, src_op);
+            let then_body = self.region_tree.push_seq(&[then_inner, br_end, end_lbl]);
+            (then_body, REGION_ID_NONE)
+        };
+        Ok(self.region_tree.push(Region::IfThenElse {
+            head,
+            then_body,
+            else_body,
+            else_label,
+            merge_label: end_label,
+        }))
+    }
+
+    fn push_label_region(&mut self, label: LabelId, src_op: u16) -> RegionId {
+        let start = self.out.len() as u16;
+        self.out.push(VInst::Label(label, src_op));
+        self.region_tree.push(crate::region::Region::Linear {
+            start,
+            end: self.out.len() as u16,
+        })
+    }
+}
+
+/// One `case` / `default` arm of an LPIR switch.
+struct SwitchArm {
+    /// Index of the `CaseStart` / `DefaultStart`.
+    header: usize,
+    /// Body ops, without the closing `End`.
+    body: core::ops::Range<usize>,
 }
 
+type SwitchArms = (Vec<(i32, SwitchArm)>, Option<SwitchArm>);
+
 /// Lower full function body (including if/else and loop control flow).
 pub fn lower_ops(
     func: &IrFunction,
@@ -2564,7 +2909,19 @@ mod tests {
         let opts = LowerOpts {
             float_mode,
             fuel: false,
+            inline_q32_fdiv: true,
         };
+        call_lower_op_opts(op, &opts, src_op, f, ir, abi)
+    }
+
+    fn call_lower_op_opts(
+        op: &LpirOp,
+        opts: &LowerOpts,
+        src_op: Option<u32>,
+        f: &IrFunction,
+        ir: &LpirModule,
+        abi: &ModuleAbi,
+    ) -> Result<(Vec<VInst>, ModuleSymbols, Vec<FaVReg>), LowerError> {
         let mut out = Vec::new();
         let mut symbols = ModuleSymbols::default();
         let mut pool = Vec::new();
@@ -2572,7 +2929,7 @@ mod tests {
         super::lower_lpir_op(
             &mut out,
             op,
-            &opts,
+            opts,
             src_op,
             f,
             ir,
@@ -2824,37 +3181,64 @@ mod tests {
     }
 
     #[test]
-    fn lower_q32_fdiv_to_call() {
+    fn lower_q32_fdiv_inline_without_call() {
         let op = LpirOp::Fdiv {
             dst: v(2),
             lhs: v(0),
             rhs: v(1),
         };
-        let f = empty_func();
+        let f = func_three_q32_vregs();
         let (ir, abi) = empty_ir_abi();
-        let (v, symbols, pool) =
+        let (v, _symbols, _pool) =
             call_lower_op_full(&op, FloatMode::Q32, Some(0), &f, &ir, &abi).expect("ok");
-        assert_eq!(v.len(), 1, "single VInst (sym_call or trivial inline)");
-        match &v[0] {
-            VInst::Call {
-                target,
-                args,
-                rets,
-                callee_uses_sret,
-                caller_passes_sret_ptr,
-                caller_sret_vm_abi_swap,
-                src_op,
-            } => {
-                assert_eq!(symbols.name(*target), "__lp_lpir_fdiv_recip_q32");
-                assert_eq!(args.vregs(&pool), &[FaVReg(0), FaVReg(1)]);
-                assert_eq!(rets.vregs(&pool), &[FaVReg(2)]);
-                assert!(!callee_uses_sret);
-                assert!(!caller_passes_sret_ptr);
-                assert!(!caller_sret_vm_abi_swap);
-                assert_eq!(unpack_src_op(*src_op), Some(0));
-            }
-            other => panic!("expected Call, got {other:?}"),
+        assert!(
+            !v.iter().any(|i| matches!(i, VInst::Call { .. })),
+            "inline, no helper call: {v:?}"
+        );
+        for inst in &v {
+            assert_eq!(inst.src_op(), Some(0), "{inst:?}");
         }
+        // `dst` is written once, last, so it may alias either operand.
+        let mut writes_dst = 0;
+        for inst in &v {
+            inst.for_each_def(&[], |d| writes_dst += usize::from(d == FaVReg(2)));
+        }
+        assert_eq!(writes_dst, 1);
+        assert!(matches!(
+            v.last(),
+            Some(VInst::Select { dst: FaVReg(2), .. })
+        ));
+    }
+
+    /// With inlining off (the classic ESP32's code region,
+    /// `NativeCompileOptions::inline_q32_fdiv`) the divide is one helper call.
+    #[test]
+    fn lower_q32_fdiv_calls_the_recip_helper_when_not_inlined() {
+        let op = LpirOp::Fdiv {
+            dst: v(2),
+            lhs: v(0),
+            rhs: v(1),
+        };
+        let f = func_three_q32_vregs();
+        let (ir, abi) = empty_ir_abi();
+        let opts = LowerOpts {
+            float_mode: FloatMode::Q32,
+            fuel: false,
+            inline_q32_fdiv: false,
+        };
+        let (v, symbols, pool) =
+            call_lower_op_opts(&op, &opts, Some(0), &f, &ir, &abi).expect("ok");
+        let [
+            VInst::Call {
+                target, args, rets, ..
+            },
+        ] = v.as_slice()
+        else {
+            panic!("expected one helper call: {v:?}");
+        };
+        assert_eq!(symbols.name(*target), BuiltinId::LpLpirFdivRecipQ32.name());
+        assert_eq!(args.vregs(&pool), &[FaVReg(0), FaVReg(1)]);
+        assert_eq!(rets.vregs(&pool), &[FaVReg(2)]);
     }
 
     fn func_three_q32_vregs() -> IrFunction {
@@ -2958,7 +3342,7 @@ mod tests {
     }
 
     #[test]
-    fn fdiv_q32_reciprocal_emits_sym_call_to_recip_helper() {
+    fn fdiv_q32_reciprocal_emits_divu_mul_mulhu_sequence() {
         let op = LpirOp::Fdiv {
             dst: v(2),
             lhs: v(0),
@@ -2966,13 +3350,36 @@ mod tests {
         };
         let f = empty_func();
         let (ir, abi) = empty_ir_abi();
-        let (v, symbols, _pool) =
+        let (v, _symbols, _pool) =
             call_lower_op_full(&op, FloatMode::Q32, None, &f, &ir, &abi).expect("ok");
-        assert_eq!(v.len(), 1);
-        let VInst::Call { target, .. } = &v[0] else {
-            panic!("expected sym_call");
-        };
-        assert_eq!(symbols.name(*target), BuiltinId::LpLpirFdivRecipQ32.name());
+        let kinds: Vec<&str> = v
+            .iter()
+            .filter_map(|i| match i {
+                VInst::AluRRR {
+                    op: AluOp::DivU, ..
+                } => Some("divu"),
+                VInst::AluRRR { op: AluOp::Mul, .. } => Some("mul"),
+                VInst::AluRRR {
+                    op: AluOp::MulHU, ..
+                } => Some("mulhu"),
+                VInst::AluRRI {
+                    op: AluImmOp::SrliU,
+                    imm: 15,
+                    ..
+                } => Some("srli15"),
+                VInst::AluRRI {
+                    op: AluImmOp::Slli,
+                    imm: 17,
+                    ..
+                } => Some("slli17"),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(kinds, &["divu", "mul", "mulhu", "srli15", "slli17"]);
+        assert!(
+            v.iter()
+                .any(|i| matches!(i, VInst::IConst32 { val: i32::MIN, .. }))
+        );
     }
 
     #[test]
@@ -3375,6 +3782,7 @@ mod tests {
                 caller_passes_sret_ptr,
                 caller_sret_vm_abi_swap,
                 src_op,
+                ..
             } => {
                 assert_eq!(symbols.name(*target), "__lp_lpir_itof_s_q32");
                 assert_eq!(args.vregs(&pool), &[FaVReg(0)]);
@@ -3408,6 +3816,7 @@ mod tests {
                 caller_passes_sret_ptr,
                 caller_sret_vm_abi_swap,
                 src_op,
+                ..
             } => {
                 assert_eq!(symbols.name(*target), "__lp_lpir_itof_u_q32");
                 assert_eq!(args.vregs(&pool), &[FaVReg(0)]);
@@ -3441,6 +3850,7 @@ mod tests {
                 caller_passes_sret_ptr,
                 caller_sret_vm_abi_swap,
                 src_op,
+                ..
             } => {
                 assert_eq!(symbols.name(*target), "__lp_lpir_fsqrt_q32");
                 assert_eq!(args.vregs(&pool), &[FaVReg(0)]);
@@ -3884,6 +4294,7 @@ mod tests {
         let ir = LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), func.clone())]),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig::default();
         let abi = ModuleAbi::from_ir_and_sig(crate::isa::IsaTarget::Rv32imac, &ir, &sig);
@@ -3893,6 +4304,7 @@ mod tests {
         let lower_opts = LowerOpts {
             float_mode: FloatMode::Q32,
             fuel: false,
+            inline_q32_fdiv: true,
         };
         let lowered = lower_ops(&func, &ir, &abi, &lower_opts).expect("lower ok");
 
@@ -4034,12 +4446,14 @@ mod tests {
         let ir = LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), func.clone())]),
+            rodata: Vec::new(),
         };
         let sig = LpsModuleSig::default();
         let abi = ModuleAbi::from_ir_and_sig(crate::isa::IsaTarget::Rv32imac, &ir, &sig);
         let lower_opts = LowerOpts {
             float_mode: FloatMode::Q32,
             fuel,
+            inline_q32_fdiv: true,
         };
         lower_ops(func, &ir, &abi, &lower_opts).expect("lower ok")
     }
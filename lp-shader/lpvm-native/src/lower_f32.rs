@@ -69,9 +69,10 @@
 //! # Hardware FPU: single instructions, and a call for the rest
 //!
 //! On a part with a real single-precision FPU — the ESP32-S3's Floating-Point
-//! Coprocessor today — the point of native f32 is that a handful of operations
-//! collapse from a call or a five-instruction Q32 sequence to **one
-//! instruction**. That set is exactly what is inlined (M7 D4):
+//! Coprocessor, and an RV32F core ([`IsaTarget::Rv32imafc`]) — the point of
+//! native f32 is that a handful of operations collapse from a call or a
+//! five-instruction Q32 sequence to **one instruction**. That set is exactly
+//! what is inlined (M7 D4):
 //!
 //! | Inlined — one FP instruction | Routed to an M5 builtin |
 //! |---|---|
@@ -86,17 +87,24 @@
 //! pending. **Division** is a builtin call in Q32 as well, so calling one here
 //! is parity; inlining the `div0.s`/`divn.s` estimate sequence buys speed and
 //! costs a hard dependency on an exhaustive extraction of the chip's
-//! implementation-defined estimate tables. **Float→int** is routed because
+//! implementation-defined estimate tables. RV32F has no such cost: `fdiv.s` and
+//! `fsqrt.s` are single correctly-rounded instructions, so on that target —
+//! and only there, per [`IsaTarget::inlines_f32_div_sqrt`] — `Fdiv`,
+//! `FdivConstF32` and `Fsqrt` move to the left-hand column. **Float→int** is
+//! routed because
 //! `trunc.s` alone may not satisfy `float.md` §3: whether this silicon
 //! saturates or wraps for finite out-of-range inputs is an open measurement,
 //! and a builtin that is correct by construction beats an instruction that
-//! might be.
+//! might be. RV32F's `fcvt.w.s` is specified to saturate, so there
+//! ([`IsaTarget::inlines_f32_ftoi_sat`]) `FtoiSatS`/`FtoiSatU` are a
+//! [`VInst::FToI`] too.
 //!
 //! # The hardware calling convention: FR-internally, AR-at-boundaries
 //!
 //! Float values live in float registers inside a function body and travel in
 //! **address registers, as raw IEEE bit patterns**, across every parameter,
-//! call-argument, call-return and function-return boundary (M7 D1).
+//! call-argument, call-return and function-return boundary (M7 D1) — with one
+//! exception, below.
 //!
 //! This is not a free choice. The `lps-builtins` f32 family is compiled by
 //! `xtensa-esp32s3-elf-gcc`, and that toolchain's measured ABI (M6-P4) passes
@@ -120,10 +128,17 @@
 //! that matters for review — **the ABI is visible in the VInst dump**, so a
 //! test can read it rather than infer it from generated code.
 //!
-//! The consequence worth stating explicitly: a `Call`'s arguments and results
-//! are always integer-class by the time the allocator sees them, which is why
-//! [`crate::regalloc::classes`] has no float arms for `Call` or `Ret` and why
-//! `IsaTarget`'s float ABI hooks are permanently empty.
+//! ## The exception: builtins under `ilp32f`
+//!
+//! On RV32F the builtins image is compiled for `riscv32imafc-unknown-none-elf`,
+//! whose ABI passes floats in `fa0`–`fa7` and returns them in `fa0`–`fa1`. A
+//! word there would be read from the wrong register file, so a call to a
+//! builtin on that target hands float operands over in float registers:
+//! [`plan_call`] marks them in the `Call`'s `float_args`/`float_rets` masks,
+//! [`crate::regalloc::classes`] reads the masks, and `IsaTarget`'s float ABI
+//! hooks — empty on every other target — place them. Calls between shader
+//! functions, and `Ret`, keep words everywhere, so the shader entry ABI is the
+//! same on RV32F as on the C6.
 
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -175,7 +190,7 @@ pub fn lower_f32_op(
             ),
         }),
         F32Lowering::HardwareFpu => {
-            lower_hardware_fpu_op(out, op, src_op, func, symbols, vreg_pool, temps)
+            lower_hardware_fpu_op(out, op, isa, src_op, func, symbols, vreg_pool, temps)
         }
     }
 }
@@ -701,6 +716,7 @@ fn f32_builtin_symbol(name: &str, argc: usize) -> Result<&'static str, LowerErro
 fn lower_hardware_fpu_op(
     out: &mut Vec<VInst>,
     op: &LpirOp,
+    isa: IsaTarget,
     src_op: Option<u32>,
     func: &IrFunction,
     symbols: &mut ModuleSymbols,
@@ -728,6 +744,35 @@ fn lower_hardware_fpu_op(
             push_falu(out, FAluOp::Mul, f(*dst), f(*lhs), f(*rhs), po);
             Ok(())
         }
+        // RV32F's `fdiv.s`/`fsqrt.s` are correctly rounded by definition, so
+        // they satisfy `float.md` §3 as written; see the builtin arms below for
+        // why the S3 calls out instead.
+        LpirOp::Fdiv { dst, lhs, rhs } if isa.inlines_f32_div_sqrt() => {
+            push_falu(out, FAluOp::Div, f(*dst), f(*lhs), f(*rhs), po);
+            Ok(())
+        }
+        LpirOp::Fsqrt { dst, src } if isa.inlines_f32_div_sqrt() => {
+            push_falu_rr(out, FAluRROp::Sqrt, f(*dst), f(*src), po);
+            Ok(())
+        }
+        // `x / c` stays a real division — the constant only saves the
+        // divisor's register.
+        LpirOp::FdivConstF32 { dst, lhs, rhs } if isa.inlines_f32_div_sqrt() => {
+            let divisor_word = temps.mint();
+            out.push(VInst::IConst32 {
+                dst: divisor_word,
+                val: rhs.to_bits() as i32,
+                src_op: po,
+            });
+            let divisor = temps.mint();
+            out.push(VInst::Wfr {
+                dst: divisor,
+                src: divisor_word,
+                src_op: po,
+            });
+            push_falu(out, FAluOp::Div, f(*dst), f(*lhs), divisor, po);
+            Ok(())
+        }
         // Sign-bit operations in the float domain. `abs.s`/`neg.s` are defined
         // as bit manipulations, so they stay exact on NaN and `-0.0`
         // (`docs/design/float.md` §3) — the same property the soft-float path
@@ -831,6 +876,7 @@ fn lower_hardware_fpu_op(
         // exhaustive tables.
         LpirOp::Fdiv { dst, lhs, rhs } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -842,6 +888,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Fsqrt { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -853,6 +900,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Ffloor { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -864,6 +912,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Fceil { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -875,6 +924,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Ftrunc { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -886,6 +936,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Fnearest { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -897,6 +948,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Fmin { dst, lhs, rhs } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -908,6 +960,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Fmax { dst, lhs, rhs } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -917,6 +970,26 @@ fn lower_hardware_fpu_op(
             &[*dst],
             po,
         ),
+        // RV32F's RTZ conversion saturates by specification; the emitter adds
+        // the NaN → 0 mask that makes it exactly `float.md` §3.
+        LpirOp::FtoiSatS { dst, src } if isa.inlines_f32_ftoi_sat() => {
+            out.push(VInst::FToI {
+                dst: i(*dst),
+                src: f(*src),
+                signed: true,
+                src_op: po,
+            });
+            Ok(())
+        }
+        LpirOp::FtoiSatU { dst, src } if isa.inlines_f32_ftoi_sat() => {
+            out.push(VInst::FToI {
+                dst: i(*dst),
+                src: f(*src),
+                signed: false,
+                src_op: po,
+            });
+            Ok(())
+        }
         // Saturating float→int. `trunc.s` alone does not satisfy
         // `float.md` §3 — whether the S3's truncation saturates or wraps for
         // finite out-of-range inputs is an unresolved M6-P6 measurement — so
@@ -925,6 +998,7 @@ fn lower_hardware_fpu_op(
         // if the measurement says so) is named follow-up work.
         LpirOp::FtoiSatS { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -936,6 +1010,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::FtoiSatU { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -947,6 +1022,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::FtoUnorm16 { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -958,6 +1034,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::FtoUnorm8 { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -969,6 +1046,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Unorm16toF { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -980,6 +1058,7 @@ fn lower_hardware_fpu_op(
         ),
         LpirOp::Unorm8toF { dst, src } => fp_call(
             out,
+            isa,
             func,
             symbols,
             vreg_pool,
@@ -1050,13 +1129,15 @@ fn push_fcmp(out: &mut Vec<VInst>, cond: FcmpCond, dst: VReg, lhs: VReg, rhs: VR
 }
 
 /// A builtin call with the boundary transfers around it: `Rfr` per float
-/// argument, the `Call`, then `Wfr` per float result.
+/// argument, the `Call`, then `Wfr` per float result — or, where [`plan_call`]
+/// finds a float argument bank, float operands passed as they are.
 #[allow(
     clippy::too_many_arguments,
     reason = "one call site per builtin-routed op; splitting it would only move the arguments"
 )]
 fn fp_call(
     out: &mut Vec<VInst>,
+    isa: IsaTarget,
     func: &IrFunction,
     symbols: &mut ModuleSymbols,
     vreg_pool: &mut Vec<VReg>,
@@ -1067,22 +1148,124 @@ fn fp_call(
     po: u16,
 ) -> Result<(), LowerError> {
     let symbol = f32_builtin_symbol(lpir_name, args.len())?;
-    let mut arg_words = Vec::with_capacity(args.len());
-    for a in args {
-        arg_words.push(word_operand(out, func, *a, temps, po));
+    let plan = plan_call(out, isa, true, func, args, rets, false, temps, po)?;
+    out.push(VInst::Call {
+        target: symbols.intern(symbol),
+        args: push_native_vregs(vreg_pool, &plan.args)?,
+        rets: push_native_vregs(vreg_pool, &plan.rets)?,
+        callee_uses_sret: false,
+        caller_passes_sret_ptr: false,
+        caller_sret_vm_abi_swap: false,
+        float_args: plan.float_args,
+        float_rets: plan.float_rets,
+        src_op: po,
+    });
+    plan.push_return_transfers(out, po);
+    Ok(())
+}
+
+/// A call's operands after [`plan_call`]: the vregs the `Call` takes and
+/// defines, which of them are float-class, and the transfers owed afterwards.
+pub struct CallPlan {
+    pub args: Vec<VReg>,
+    pub rets: Vec<VReg>,
+    /// [`VInst::Call::float_args`].
+    pub float_args: u16,
+    /// [`VInst::Call::float_rets`].
+    pub float_rets: u8,
+    ret_transfers: Vec<(VReg, Option<VReg>)>,
+}
+
+impl CallPlan {
+    /// Push the `Wfr` per word-returned float result — after the `Call`.
+    pub fn push_return_transfers(self, out: &mut Vec<VInst>, po: u16) {
+        for (word, float_dst) in self.ret_transfers {
+            push_return_transfer(out, word, float_dst, po);
+        }
     }
-    let mut ret_words = Vec::with_capacity(rets.len());
-    let mut ret_transfers = Vec::with_capacity(rets.len());
-    for r in rets {
-        let (word, float_dst) = word_result(func, *r, temps);
-        ret_words.push(word);
-        ret_transfers.push((word, float_dst));
+}
+
+/// Decide how each float operand of a builtin call crosses the boundary.
+///
+/// On a target with a float argument bank (`ilp32f`, i.e.
+/// [`IsaTarget::Rv32imafc`]) a float goes straight from the float file into
+/// `fa0`–`fa7` and comes back in `fa0`–`fa1`, with no transfer at all — the
+/// allocator sees a float-class operand and the ABI hooks place it. That holds
+/// only as far as the psABI's own rule does: past the eighth float argument,
+/// past the second float result, or under sret, a float travels exactly like
+/// an integer, so it falls back to the word convention ([`word_operand`] /
+/// [`word_result`]). Every other target has no float bank and gets words
+/// throughout, which is the convention in the module docs.
+///
+/// `builtin_callee` says the callee is compiled for that ABI — the
+/// `lps-builtins` family. Calls between shader functions pass `false` and keep
+/// words on every target.
+#[allow(
+    clippy::too_many_arguments,
+    reason = "the call's operands plus the lowering context fp_call already threads"
+)]
+pub fn plan_call(
+    out: &mut Vec<VInst>,
+    isa: IsaTarget,
+    builtin_callee: bool,
+    func: &IrFunction,
+    args: &[lpir::VReg],
+    rets: &[lpir::VReg],
+    callee_uses_sret: bool,
+    temps: &mut TempVRegs,
+    po: u16,
+) -> Result<CallPlan, LowerError> {
+    let arg_bank = if builtin_callee {
+        isa.call_arg_reg_count(RegClass::Float)
+    } else {
+        0
+    };
+    let ret_bank = if builtin_callee && !callee_uses_sret {
+        isa.direct_ret_reg_count(RegClass::Float)
+    } else {
+        0
+    };
+    let mut plan = CallPlan {
+        args: Vec::with_capacity(args.len()),
+        rets: Vec::with_capacity(rets.len()),
+        float_args: 0,
+        float_rets: 0,
+        ret_transfers: Vec::with_capacity(rets.len()),
+    };
+    for (idx, a) in args.iter().enumerate() {
+        if is_float(func, *a) && (plan.float_args.count_ones() as usize) < arg_bank {
+            plan.float_args |= bank_bit::<u16>(idx)?;
+            plan.args.push(float_vreg(func, *a));
+        } else {
+            plan.args.push(word_operand(out, func, *a, temps, po));
+        }
     }
-    call_native(out, symbols, vreg_pool, symbol, &arg_words, &ret_words, po)?;
-    for (word, float_dst) in ret_transfers {
-        push_return_transfer(out, word, float_dst, po);
+    for (idx, r) in rets.iter().enumerate() {
+        if is_float(func, *r) && (plan.float_rets.count_ones() as usize) < ret_bank {
+            plan.float_rets |= bank_bit::<u8>(idx)?;
+            plan.rets.push(float_vreg(func, *r));
+        } else {
+            let (word, float_dst) = word_result(func, *r, temps);
+            plan.rets.push(word);
+            plan.ret_transfers.push((word, float_dst));
+        }
     }
-    Ok(())
+    Ok(plan)
+}
+
+/// Bit `idx` of a `Call` float mask. A float operand the mask cannot name
+/// would have to be passed in a float register yet described as a word, so it
+/// is refused rather than guessed at.
+fn bank_bit<T: TryFrom<u32>>(idx: usize) -> Result<T, LowerError> {
+    u32::try_from(idx)
+        .ok()
+        .and_then(|i| 1u32.checked_shl(i))
+        .and_then(|b| T::try_from(b).ok())
+        .ok_or_else(|| LowerError::UnsupportedOp {
+            description: alloc::format!(
+                "float call operand at position {idx} is beyond the Call's float mask"
+            ),
+        })
 }
 
 /// Push the `Wfr` a float-returning call owes, if it owes one.
@@ -1206,6 +1389,8 @@ fn soft_call(
         callee_uses_sret: false,
         caller_passes_sret_ptr: false,
         caller_sret_vm_abi_swap: false,
+        float_args: 0,
+        float_rets: 0,
         src_op: po,
     });
     Ok(())
@@ -1229,6 +1414,8 @@ fn call_native(
         callee_uses_sret: false,
         caller_passes_sret_ptr: false,
         caller_sret_vm_abi_swap: false,
+        float_args: 0,
+        float_rets: 0,
         src_op: po,
     });
     Ok(())
@@ -2039,6 +2226,195 @@ mod tests {
         assert!(out.is_empty(), "no transfer for a non-float: {out:?}");
     }
 
+    #[cfg(feature = "isa-rv32")]
+    const RV32F: IsaTarget = IsaTarget::Rv32imafc;
+
+    /// `fcvt.w.s` saturates by specification, so the conversion the other
+    /// hardware target routes to a builtin is one `FToI` here.
+    #[cfg(feature = "isa-rv32")]
+    #[test]
+    fn rv32f_inlines_saturating_float_to_int() {
+        for signed in [true, false] {
+            let op = if signed {
+                LpirOp::FtoiSatS {
+                    dst: v(0),
+                    src: v(1),
+                }
+            } else {
+                LpirOp::FtoiSatU {
+                    dst: v(0),
+                    src: v(1),
+                }
+            };
+            let (insts, _, _) = lower(op, RV32F);
+            assert!(
+                matches!(insts.as_slice(), [VInst::FToI { signed: s, .. }] if *s == signed),
+                "{insts:?}"
+            );
+        }
+    }
+
+    /// RV32F's `fdiv.s`/`fsqrt.s` are correctly rounded, so on that target
+    /// division and square root join the inline family — including division by
+    /// a constant, which stays a real division.
+    #[cfg(feature = "isa-rv32")]
+    #[test]
+    fn rv32f_inlines_division_and_square_root() {
+        let (insts, _, _) = lower(
+            LpirOp::Fdiv {
+                dst: v(0),
+                lhs: v(1),
+                rhs: v(2),
+            },
+            RV32F,
+        );
+        assert!(
+            matches!(
+                insts.as_slice(),
+                [VInst::FAluRRR {
+                    op: FAluOp::Div,
+                    ..
+                }]
+            ),
+            "{insts:?}"
+        );
+        let (insts, _, _) = lower(
+            LpirOp::Fsqrt {
+                dst: v(0),
+                src: v(1),
+            },
+            RV32F,
+        );
+        assert!(
+            matches!(
+                insts.as_slice(),
+                [VInst::FAluRR {
+                    op: FAluRROp::Sqrt,
+                    ..
+                }]
+            ),
+            "{insts:?}"
+        );
+        let (insts, _, _) = lower(
+            LpirOp::FdivConstF32 {
+                dst: v(0),
+                lhs: v(1),
+                rhs: 3.0,
+            },
+            RV32F,
+        );
+        assert!(
+            matches!(
+                insts.as_slice(),
+                [
+                    VInst::IConst32 { val, .. },
+                    VInst::Wfr { .. },
+                    VInst::FAluRRR { op: FAluOp::Div, .. }
+                ] if *val == 3.0f32.to_bits() as i32
+            ),
+            "{insts:?}"
+        );
+    }
+
+    /// Under `ilp32f` a builtin takes and returns floats in float registers:
+    /// the `Call` names the float vregs themselves and marks them in its
+    /// masks, with no transfer on either side.
+    #[cfg(feature = "isa-rv32")]
+    #[test]
+    fn an_rv32f_builtin_call_passes_floats_in_the_float_bank() {
+        let func = float_func(8);
+        let (insts, symbols, pool) = lower_in(
+            LpirOp::Fmin {
+                dst: v(3),
+                lhs: v(1),
+                rhs: v(2),
+            },
+            RV32F,
+            &func,
+        );
+        let [
+            call @ VInst::Call {
+                args,
+                rets,
+                float_args,
+                float_rets,
+                ..
+            },
+        ] = insts.as_slice()
+        else {
+            panic!("expected a lone Call: {insts:?}");
+        };
+        assert_eq!(called_symbols(&insts, &symbols), ["__lp_lpir_fmin_f32"]);
+        assert_eq!((*float_args, *float_rets), (0b11, 0b1));
+        assert_eq!(args.vregs(&pool), [VReg(1), VReg(2)]);
+        assert_eq!(rets.vregs(&pool), [VReg(3)]);
+        assert_eq!(call.call_arg_bank(1), (RegClass::Float, 1));
+    }
+
+    /// The float bank holds as far as the psABI's does: a ninth float argument
+    /// travels as a word, and so does every float operand of a call whose
+    /// callee is not a builtin, or whose results come back through sret.
+    #[cfg(feature = "isa-rv32")]
+    #[test]
+    fn plan_call_falls_back_to_words_where_ilp32f_does() {
+        let func = float_func(16);
+        let args: Vec<lpir::VReg> = (1..=9).map(v).collect();
+        let mut out = Vec::new();
+        let mut temps = TempVRegs::new(64);
+        let plan = plan_call(
+            &mut out,
+            RV32F,
+            true,
+            &func,
+            &args,
+            &[v(0)],
+            false,
+            &mut temps,
+            0,
+        )
+        .unwrap();
+        assert_eq!(plan.float_args, 0xff, "fa0-fa7, then words");
+        assert_eq!(plan.float_rets, 1);
+        assert!(matches!(out.as_slice(), [VInst::Rfr { src, .. }] if *src == VReg(9)));
+
+        for (builtin, sret) in [(false, false), (true, true)] {
+            out.clear();
+            let plan = plan_call(
+                &mut out,
+                RV32F,
+                builtin,
+                &func,
+                &args[..2],
+                &[v(0)],
+                sret,
+                &mut temps,
+                0,
+            )
+            .unwrap();
+            assert_eq!(plan.float_rets, 0, "builtin={builtin} sret={sret}");
+            assert_eq!(plan.float_args, if builtin { 0b11 } else { 0 });
+        }
+
+        // No target but RV32F has a float bank to hand anything to.
+        #[cfg(feature = "isa-xt")]
+        {
+            out.clear();
+            let plan = plan_call(
+                &mut out,
+                XT,
+                true,
+                &func,
+                &args[..2],
+                &[v(0)],
+                false,
+                &mut temps,
+                0,
+            )
+            .unwrap();
+            assert_eq!((plan.float_args, plan.float_rets), (0, 0));
+        }
+    }
+
     /// The resolver never crosses modes. A missing f32 builtin has to name the
     /// op, because the alternative — quietly resolving the Q32 sibling — is the
     /// defect class that produces plausible wrong pixels instead of an error.
@@ -11,4 +11,7 @@ pub struct LowerOpts {
     /// entry and loop back-edges (see
     /// [`crate::native_options::NativeCompileOptions::fuel`]).
     pub fuel: bool,
+    /// Lower Q32 `Fdiv` inline rather than calling the reciprocal helper
+    /// (see [`crate::native_options::NativeCompileOptions::inline_q32_fdiv`]).
+    pub inline_q32_fdiv: bool,
 }
@@ -1,5 +1,7 @@
 //! Backend-specific compile options (not shared with Cranelift / WASM).
 
+use crate::regalloc::RegAllocStrategy;
+
 /// Options for LPIR → native RV32 codegen.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NativeCompileOptions {
@@ -28,6 +30,22 @@ pub struct NativeCompileOptions {
 
     /// Middle-end LPIR pass settings (inline, etc.).
     pub config: lpir::CompilerConfig,
+
+    /// Register allocator. [`RegAllocStrategy::Walk`] by default; the
+    /// alternative is only compiled with the `regalloc-split` feature.
+    pub regalloc: RegAllocStrategy,
+
+    /// When true, Q32 `Fdiv` lowers to the inline reciprocal-multiply
+    /// sequence; when false, to a call to `__lp_lpir_fdiv_recip_q32`. Both
+    /// are bit-exact, so this only trades speed against code size.
+    ///
+    /// On by default, except in an `xt-placed-code` build: the classic ESP32
+    /// places every resident shader in a fixed 24 KiB region
+    /// ([`crate::codemem_esp32::CodeRegion::ESP32_DEFAULT`]), and the inline
+    /// form, about twenty instructions per divide, pushed the corpus's worst
+    /// project past it (`tests/xt_classic_codemem_corpus.rs`). The S3 and the
+    /// RV32 targets have no such region and inline.
+    pub inline_q32_fdiv: bool,
 }
 
 impl Default for NativeCompileOptions {
@@ -40,6 +58,8 @@ impl Default for NativeCompileOptions {
             stage_trace: false,
             fuel: true,
             config: lpir::CompilerConfig::default(),
+            regalloc: RegAllocStrategy::Walk,
+            inline_q32_fdiv: !cfg!(feature = "xt-placed-code"),
         }
     }
 }
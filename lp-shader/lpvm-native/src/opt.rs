@@ -43,6 +43,11 @@ fn is_commutative(op: AluOp) -> bool {
 /// subsequent iteration broken (e.g. `px_off` initialised to 0 outside a
 /// per-pixel loop and `+= 8` inside it would still resolve to `+ 0` at the
 /// in-loop use, causing every pixel to overwrite pixel 0).
+///
+/// The same holds at a merge point: a vreg assigned in both an `if` arm and
+/// before it (`r = 0; if (c) { r = 4; }`) reaches the `Label` after the arm
+/// with either value, so facts about vregs with more than one def are
+/// dropped at every `Label`. Single-def constants stay known across it.
 pub fn fold_immediates(lowered: &mut LoweredFunction, isa: IsaTarget) {
     let max_vreg = max_vreg_index(&lowered.vinsts, &lowered.vreg_pool);
     if max_vreg == 0 {
@@ -52,6 +57,7 @@ pub fn fold_immediates(lowered: &mut LoweredFunction, isa: IsaTarget) {
     vreg_const.resize(max_vreg + 1, None);
 
     let loop_defs = compute_loop_def_sets(lowered, max_vreg);
+    let multi_def = compute_multi_def_set(lowered, max_vreg);
     let inside_loop_with_def = |i: usize, v: VReg| -> bool {
         let vidx = v.0 as usize;
         loop_defs.iter().any(|ld| {
@@ -61,6 +67,13 @@ pub fn fold_immediates(lowered: &mut LoweredFunction, isa: IsaTarget) {
 
     for i in 0..lowered.vinsts.len() {
         match &lowered.vinsts[i] {
+            VInst::Label(..) => {
+                for (fact, multi) in vreg_const.iter_mut().zip(&multi_def) {
+                    if *multi {
+                        *fact = None;
+                    }
+                }
+            }
             VInst::IConst32 { dst, val, .. } => {
                 let idx = dst.0 as usize;
                 if idx < vreg_const.len() {
@@ -181,6 +194,24 @@ fn compute_loop_def_sets(lowered: &LoweredFunction, max_vreg: usize) -> Vec<Loop
     out
 }
 
+/// `out[v.0 as usize]` is true iff `v` is def'd more than once in the
+/// function.
+fn compute_multi_def_set(lowered: &LoweredFunction, max_vreg: usize) -> Vec<bool> {
+    let mut seen = Vec::new();
+    seen.resize(max_vreg + 1, false);
+    let mut multi = seen.clone();
+    for inst in &lowered.vinsts {
+        inst.for_each_def(&lowered.vreg_pool, |v| {
+            let idx = v.0 as usize;
+            if idx < seen.len() {
+                multi[idx] |= seen[idx];
+                seen[idx] = true;
+            }
+        });
+    }
+    multi
+}
+
 fn max_vreg_index(vinsts: &[VInst], pool: &[VReg]) -> usize {
     let mut max = 0usize;
     for inst in vinsts {
@@ -454,6 +485,78 @@ mod tests {
         );
     }
 
+    /// `r = 0; if (c) { r = 4; } use(r)`: the `if` arm's constant must not
+    /// fold into the use after the merge, or every path sees `r == 4`.
+    #[test]
+    fn no_fold_across_merge_when_vreg_is_reassigned_in_an_arm() {
+        let mut lowered = make_lowered(vec![
+            VInst::IConst32 {
+                dst: VReg(1),
+                val: 0,
+                src_op: SRC_OP_NONE,
+            },
+            VInst::BrIf {
+                cond: VReg(4),
+                target: 0,
+                invert: true,
+                src_op: SRC_OP_NONE,
+            },
+            VInst::IConst32 {
+                dst: VReg(1),
+                val: 4,
+                src_op: SRC_OP_NONE,
+            },
+            VInst::Label(0, SRC_OP_NONE),
+            VInst::AluRRR {
+                op: AluOp::Add,
+                dst: VReg(2),
+                src1: VReg(0),
+                src2: VReg(1),
+                src_op: SRC_OP_NONE,
+            },
+        ]);
+        fold_immediates(&mut lowered, IsaTarget::Rv32imac);
+        assert!(
+            matches!(lowered.vinsts[4], VInst::AluRRR { op: AluOp::Add, .. }),
+            "use after the merge must not fold to addi+4; got {:?}",
+            lowered.vinsts[4]
+        );
+    }
+
+    #[test]
+    fn single_def_constant_folds_across_merge() {
+        let mut lowered = make_lowered(vec![
+            VInst::IConst32 {
+                dst: VReg(1),
+                val: 7,
+                src_op: SRC_OP_NONE,
+            },
+            VInst::BrIf {
+                cond: VReg(4),
+                target: 0,
+                invert: true,
+                src_op: SRC_OP_NONE,
+            },
+            VInst::Label(0, SRC_OP_NONE),
+            VInst::AluRRR {
+                op: AluOp::Add,
+                dst: VReg(2),
+                src1: VReg(0),
+                src2: VReg(1),
+                src_op: SRC_OP_NONE,
+            },
+        ]);
+        fold_immediates(&mut lowered, IsaTarget::Rv32imac);
+        assert!(matches!(
+            lowered.vinsts[3],
+            VInst::AluRRI {
+                op: AluImmOp::Addi,
+                imm: 7,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn vreg_redef_clears_constant() {
         let mut lowered = make_lowered(vec![
@@ -25,16 +25,20 @@
 //! of *their* operands: the transfers and the comparison are deliberately
 //! mixed-class, because that is what the calling convention is made of.
 //!
-//! # `Call` is Int-only, on purpose
+//! # `Call` is Int-only unless its masks say otherwise
 //!
-//! There are no float arms for [`VInst::Call`] or [`VInst::Ret`], and that is a
-//! decision rather than an omission (M7 D1/D2). Float values travel across
-//! every call and return boundary in **address registers**, as raw IEEE bit
-//! patterns, because the toolchain that compiles the float builtins we call
+//! [`VInst::Ret`] has no float arm, and [`VInst::Call`] has none of its own,
+//! and that is a decision rather than an omission (M7 D1/D2). Float values
+//! travel across call and return boundaries in **address registers**, as raw
+//! IEEE bit patterns, wherever the toolchain that compiles the float builtins
 //! does exactly that. Lowering inserts explicit [`VInst::Rfr`]/[`VInst::Wfr`]
-//! transfers at those four boundaries, so by the time a `Call` is built its
-//! operands really are integers, and the ABI is legible in the VInst dump
+//! transfers at those boundaries, so the ABI is legible in the VInst dump
 //! rather than implied by a table here.
+//!
+//! The one convention that differs is `ilp32f` (RV32F builtins), where a float
+//! argument or result is float-class; lowering records which in the `Call`'s
+//! `float_args`/`float_rets` masks and [`VInst::call_arg_bank`] answers from
+//! them. The dump shows those masks too.
 
 use alloc::vec::Vec;
 
@@ -48,7 +52,6 @@ use crate::vinst::{VInst, VReg};
 /// values of mixed class once float returns exist.
 #[cfg(feature = "float-f32")]
 pub fn def_class(inst: &VInst, def_idx: usize) -> RegClass {
-    let _ = def_idx;
     match inst {
         // The float file is where the value lands.
         VInst::FAluRRR { .. }
@@ -66,7 +69,11 @@ pub fn def_class(inst: &VInst, def_idx: usize) -> RegClass {
         // materializes the 0/1 into an AR inside the same sequence (M7 D5).
         VInst::Fcmp { .. }
         // `Rfr` is the FR → AR half: its result is the integer bit pattern.
-        | VInst::Rfr { .. } => RegClass::Int,
+        | VInst::Rfr { .. }
+        // Float → integer conversion writes an integer.
+        | VInst::FToI { .. } => RegClass::Int,
+
+        VInst::Call { .. } => inst.call_ret_bank(def_idx).0,
 
         _ => RegClass::Int,
     }
@@ -112,8 +119,11 @@ pub fn use_class(inst: &VInst, use_idx: usize) -> RegClass {
         VInst::Wfr { .. } => RegClass::Int,
         VInst::Rfr { .. } => RegClass::Float,
 
-        // Integer → float conversion reads an integer.
+        // Integer → float conversion reads an integer, and the reverse a float.
         VInst::IToF { .. } => RegClass::Int,
+        VInst::FToI { .. } => RegClass::Float,
+
+        VInst::Call { .. } => inst.call_arg_bank(use_idx).0,
 
         _ => RegClass::Int,
     }
@@ -467,9 +477,8 @@ mod tests {
 
     /// `Call` and `Ret` have no float operands *by design* (M7 D1/D2): float
     /// values cross those boundaries in address registers, and lowering emits
-    /// explicit `Rfr`/`Wfr` transfers to put them there. If this ever answers
-    /// Float, the calling convention changed and `lpir_call_arg_target`'s
-    /// float arm — which returns `None` — starts rejecting real code.
+    /// explicit `Rfr`/`Wfr` transfers to put them there. Only a `Call`'s
+    /// float masks (set for `ilp32f` builtins) make an operand float-class.
     #[test]
     fn calls_and_returns_carry_no_float_operands() {
         use crate::vinst::{SymbolId, VRegSlice};
@@ -481,6 +490,8 @@ mod tests {
             callee_uses_sret: false,
             caller_passes_sret_ptr: false,
             caller_sret_vm_abi_swap: false,
+            float_args: 0,
+            float_rets: 0,
             src_op: SRC_OP_NONE,
         };
         assert_eq!(def_class(&call, 0), I);
@@ -489,6 +500,21 @@ mod tests {
         }
         let _ = &pool;
 
+        let masked = VInst::Call {
+            target: SymbolId(0),
+            args: VRegSlice { start: 0, count: 2 },
+            rets: VRegSlice { start: 2, count: 1 },
+            callee_uses_sret: false,
+            caller_passes_sret_ptr: false,
+            caller_sret_vm_abi_swap: false,
+            float_args: 0b10,
+            float_rets: 0b1,
+            src_op: SRC_OP_NONE,
+        };
+        assert_eq!(def_class(&masked, 0), F);
+        assert_eq!(use_class(&masked, 0), I);
+        assert_eq!(use_class(&masked, 1), F);
+
         let ret = VInst::Ret {
             vals: VRegSlice { start: 0, count: 2 },
             src_op: SRC_OP_NONE,
@@ -1,7 +1,8 @@
 //! Register allocation for the native RV32 backend.
 //!
 //! This module provides a straight-line register allocator using backward walk
-//! with edit-list emission (regalloc2-style approach adapted for LPIR).
+//! with edit-list emission (regalloc2-style approach adapted for LPIR). The
+//! walk has two strategies, chosen per compile by [`RegAllocStrategy`].
 
 use crate::abi::{FuncAbi, PReg, PackedPReg, RegClass};
 use crate::lower::LoweredFunction;
@@ -19,6 +20,8 @@ pub mod liveness;
 pub mod pool;
 pub mod render;
 pub mod spill;
+#[cfg(feature = "regalloc-split")]
+pub mod split;
 pub mod trace;
 pub mod verify;
 pub mod walk;
@@ -290,8 +293,87 @@ fn used_callee_saved_from_output(output: &AllocOutput, func_abi: &FuncAbi) -> cr
     set
 }
 
-/// Allocate registers for a lowered function (full region tree).
+/// Which allocator [`allocate_with`] runs.
+///
+/// Both are the same backward walk over the region tree and share all of its
+/// call, ABI and entry handling; they differ in what happens at region
+/// boundaries and in how an eviction victim is picked. Every allocation is
+/// checked by the same [`verify`] rules whichever one produced it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RegAllocStrategy {
+    /// Flush every register-resident value to its spill slot at each region
+    /// boundary; evict least recently used. Small and fast, and what firmware
+    /// ships.
+    #[default]
+    Walk,
+    /// Live-range splitting and loop-aware spill placement (see [`split`]):
+    /// values stay in their registers across boundaries nothing can branch
+    /// to, loop invariants are pinned to a register for the whole loop, and
+    /// eviction prefers values whose next reload is cheapest.
+    #[cfg(feature = "regalloc-split")]
+    Split,
+}
+
+/// Static spill traffic of one allocation, counted from its edit list.
+///
+/// These are instruction counts, not executions: a reload inside a loop
+/// counts once. Pair with a cycle count to see what the traffic costs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Spill slots in the frame.
+    pub spill_slots: u32,
+    /// Register → slot moves.
+    pub spill_stores: u32,
+    /// Slot → register moves, including incoming stack arguments loaded into
+    /// a register.
+    pub spill_reloads: u32,
+}
+
+impl AllocStats {
+    pub fn of(output: &AllocOutput) -> Self {
+        let mut stats = AllocStats {
+            spill_slots: output.num_spill_slots,
+            ..AllocStats::default()
+        };
+        for (_, edit) in &output.edits {
+            match edit {
+                Edit::Move {
+                    from: Alloc::Reg(_),
+                    to: Alloc::Stack(_),
+                } => stats.spill_stores += 1,
+                Edit::Move {
+                    from: Alloc::Stack(_),
+                    to: Alloc::Reg(_),
+                }
+                | Edit::LoadIncomingArg {
+                    to: Alloc::Reg(_), ..
+                } => stats.spill_reloads += 1,
+                _ => {}
+            }
+        }
+        stats
+    }
+
+    /// Sum over functions.
+    pub fn add(&mut self, other: AllocStats) {
+        self.spill_slots += other.spill_slots;
+        self.spill_stores += other.spill_stores;
+        self.spill_reloads += other.spill_reloads;
+    }
+}
+
+/// Allocate registers for a lowered function (full region tree) with the
+/// default [`RegAllocStrategy::Walk`].
 pub fn allocate(lowered: &LoweredFunction, func_abi: &FuncAbi) -> Result<AllocResult, AllocError> {
+    allocate_with(lowered, func_abi, RegAllocStrategy::Walk)
+}
+
+/// Allocate registers for a lowered function with the given strategy.
+pub fn allocate_with(
+    lowered: &LoweredFunction,
+    func_abi: &FuncAbi,
+    strategy: RegAllocStrategy,
+) -> Result<AllocResult, AllocError> {
     use crate::regalloc::pool::RegPool;
     use crate::region::{REGION_ID_NONE, Region, RegionId, RegionTree};
 
@@ -326,13 +408,22 @@ pub fn allocate(lowered: &LoweredFunction, func_abi: &FuncAbi) -> Result<AllocRe
     };
 
     log::debug!("[native-fa] allocate: calling allocate_from_tree...");
+    let pool = RegPool::for_abi(func_abi);
+    #[cfg(feature = "regalloc-split")]
+    let pool = match strategy {
+        RegAllocStrategy::Split => {
+            pool.with_spill_costs(split::SpillCosts::new(&lowered.vinsts, &lowered.vreg_pool))
+        }
+        RegAllocStrategy::Walk => pool,
+    };
     let output = walk::allocate_from_tree(
         &lowered.vinsts,
         &lowered.vreg_pool,
         tree,
         root,
         func_abi,
-        RegPool::for_abi(func_abi),
+        pool,
+        strategy,
     )?;
     let spill_slots = output.num_spill_slots;
     let used_callee_saved = used_callee_saved_from_output(&output, func_abi);
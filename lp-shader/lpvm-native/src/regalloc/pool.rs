@@ -2,6 +2,8 @@
 
 use crate::abi::{PReg, RegClass};
 use crate::isa::IsaTarget;
+#[cfg(feature = "regalloc-split")]
+use crate::regalloc::split::SpillCosts;
 use crate::vinst::VReg;
 use alloc::vec::Vec;
 
@@ -24,6 +26,20 @@ struct ClassPool {
     /// withholding actually hold: `clear` and `clear_all` reseed the LRU, and
     /// reseeding from the static list would silently hand the register back.
     pool_order: Vec<u8>,
+    /// Hardware registers holding a value pinned for an enclosing loop (bit
+    /// per register). A pinned register is never handed out by [`alloc`]
+    /// and never chosen as its eviction victim. Only the split strategy
+    /// pins, so under the walk strategy this is always zero.
+    ///
+    /// [`alloc`]: ClassPool::alloc
+    pinned: u32,
+    /// Instruction at which each register was last allocated or touched; a
+    /// cost-directed eviction must not pick an operand of the instruction
+    /// it is allocating for. LRU gets that for free (operands are MRU).
+    #[cfg(feature = "regalloc-split")]
+    touched_at: [u16; 32],
+    #[cfg(feature = "regalloc-split")]
+    cursor: u16,
 }
 
 impl ClassPool {
@@ -32,7 +48,45 @@ impl ClassPool {
             preg_vreg: [None; 32],
             lru: pool_order.clone(),
             pool_order,
+            pinned: 0,
+            #[cfg(feature = "regalloc-split")]
+            touched_at: [u16::MAX; 32],
+            #[cfg(feature = "regalloc-split")]
+            cursor: 0,
+        }
+    }
+
+    fn is_pinned(&self, preg: u8) -> bool {
+        self.pinned & (1 << preg) != 0
+    }
+
+    #[cfg(feature = "regalloc-split")]
+    fn mark_touched(&mut self, preg: u8) {
+        self.touched_at[preg as usize] = self.cursor;
+    }
+
+    #[cfg(not(feature = "regalloc-split"))]
+    fn mark_touched(&mut self, _preg: u8) {}
+
+    /// LRU index of the occupied register whose value is cheapest to spill,
+    /// skipping pinned registers and operands of the current instruction.
+    /// Ties keep LRU order.
+    #[cfg(feature = "regalloc-split")]
+    fn cheapest_victim(&self, costs: &SpillCosts) -> Option<usize> {
+        let mut best: Option<(usize, (u32, u32))> = None;
+        for (i, &preg) in self.lru.iter().enumerate() {
+            if self.is_pinned(preg) || self.touched_at[preg as usize] == self.cursor {
+                continue;
+            }
+            let Some(vreg) = self.preg_vreg[preg as usize] else {
+                continue;
+            };
+            let key = costs.victim_key(vreg, self.cursor);
+            if best.is_none_or(|(_, k)| key < k) {
+                best = Some((i, key));
+            }
         }
+        best.map(|(i, _)| i)
     }
 
     fn home(&self, vreg: VReg) -> Option<u8> {
@@ -42,27 +96,36 @@ impl ClassPool {
             .map(|i| i as u8)
     }
 
-    fn alloc(&mut self, vreg: VReg) -> Option<(u8, Option<VReg>)> {
+    fn alloc(
+        &mut self,
+        vreg: VReg,
+        #[cfg(feature = "regalloc-split")] costs: Option<&SpillCosts>,
+    ) -> Option<(u8, Option<VReg>)> {
         // Try to find a free allocatable reg (prefer LRU order)
         for (i, &preg) in self.lru.iter().enumerate() {
-            if self.preg_vreg[preg as usize].is_none() {
+            if self.preg_vreg[preg as usize].is_none() && !self.is_pinned(preg) {
                 self.preg_vreg[preg as usize] = Some(vreg);
                 // Move to end (most recently used)
                 self.lru.remove(i);
                 self.lru.push(preg);
+                self.mark_touched(preg);
                 return Some((preg, None));
             }
         }
-        // Evict LRU (index 0). An empty LRU means this class has no
-        // allocatable registers at all on this ISA — the caller turns that
-        // into `OutOfRegisters` rather than reaching into another class.
-        if self.lru.is_empty() {
-            return None;
-        }
-        let victim_preg = self.lru.remove(0);
+        // Evict LRU (index 0), or the cheapest victim when the pool carries
+        // spill costs. An empty LRU means this class has no allocatable
+        // registers at all on this ISA — the caller turns that into
+        // `OutOfRegisters` rather than reaching into another class.
+        let lru_victim = self.lru.iter().position(|&p| !self.is_pinned(p))?;
+        #[cfg(feature = "regalloc-split")]
+        let lru_victim = costs
+            .and_then(|c| self.cheapest_victim(c))
+            .unwrap_or(lru_victim);
+        let victim_preg = self.lru.remove(lru_victim);
         let victim_vreg = self.preg_vreg[victim_preg as usize];
         self.preg_vreg[victim_preg as usize] = Some(vreg);
         self.lru.push(victim_preg);
+        self.mark_touched(victim_preg);
         Some((victim_preg, victim_vreg))
     }
 
@@ -93,6 +156,7 @@ impl ClassPool {
             self.lru.remove(pos);
             self.lru.push(preg);
         }
+        self.mark_touched(preg);
     }
 
     fn clear(&mut self) {
@@ -120,6 +184,10 @@ impl ClassPool {
 pub struct RegPool {
     int: ClassPool,
     float: ClassPool,
+    /// Per-vreg spill costs; when present, eviction picks the cheapest victim
+    /// rather than the least recently used one.
+    #[cfg(feature = "regalloc-split")]
+    costs: Option<SpillCosts>,
 }
 
 impl RegPool {
@@ -134,9 +202,32 @@ impl RegPool {
         Self {
             int: ClassPool::from_order(int_order),
             float: ClassPool::from_order(float_order),
+            #[cfg(feature = "regalloc-split")]
+            costs: None,
         }
     }
 
+    /// Direct evictions by `costs` instead of LRU order (the split strategy).
+    #[cfg(feature = "regalloc-split")]
+    pub fn with_spill_costs(mut self, costs: SpillCosts) -> Self {
+        self.costs = Some(costs);
+        self
+    }
+
+    /// The spill costs evictions are directed by, if any.
+    #[cfg(feature = "regalloc-split")]
+    pub fn spill_costs(&self) -> Option<&SpillCosts> {
+        self.costs.as_ref()
+    }
+
+    /// Record the instruction the walk is allocating for. Registers allocated
+    /// or touched from here on are its operands until the next call.
+    #[cfg(feature = "regalloc-split")]
+    pub fn set_cursor(&mut self, inst_idx: u16) {
+        self.int.cursor = inst_idx;
+        self.float.cursor = inst_idx;
+    }
+
     /// Pool for a specific function, honouring registers its ABI withholds.
     ///
     /// `FuncAbi::allocatable` removes the sret pointer's register, which must
@@ -209,9 +300,17 @@ impl RegPool {
     /// — the case a float vreg hits on a backend without an FPU. It is a hard
     /// error, never a fall back into the other class.
     pub fn alloc(&mut self, vreg: VReg, class: RegClass) -> Option<(PReg, Option<VReg>)> {
-        self.class_pool(class)
-            .alloc(vreg)
-            .map(|(hw, evicted)| (PReg { hw, class }, evicted))
+        #[cfg(feature = "regalloc-split")]
+        let allocated = {
+            let pool = match class {
+                RegClass::Int => &mut self.int,
+                RegClass::Float => &mut self.float,
+            };
+            pool.alloc(vreg, self.costs.as_ref())
+        };
+        #[cfg(not(feature = "regalloc-split"))]
+        let allocated = self.class_pool(class).alloc(vreg);
+        allocated.map(|(hw, evicted)| (PReg { hw, class }, evicted))
     }
 
     /// Allocate a specific physical register for vreg. Evicts current occupant if any.
@@ -254,6 +353,32 @@ impl RegPool {
         self.class_pool(preg.class).touch(preg.hw);
     }
 
+    /// Pin `preg`: it keeps its current occupant until [`unpin`](Self::unpin),
+    /// whatever else the walk allocates.
+    pub fn pin(&mut self, preg: PReg) {
+        self.class_pool(preg.class).pinned |= 1 << preg.hw;
+    }
+
+    pub fn unpin(&mut self, preg: PReg) {
+        self.class_pool(preg.class).pinned &= !(1 << preg.hw);
+    }
+
+    pub fn is_pinned(&self, preg: PReg) -> bool {
+        let pool = match preg.class {
+            RegClass::Int => &self.int,
+            RegClass::Float => &self.float,
+        };
+        pool.is_pinned(preg.hw)
+    }
+
+    /// The allocatable registers of `class`, in LRU-seed order.
+    pub fn order(&self, class: RegClass) -> &[u8] {
+        match class {
+            RegClass::Int => &self.int.pool_order,
+            RegClass::Float => &self.float.pool_order,
+        }
+    }
+
     /// Count occupied allocatable registers across all classes.
     pub fn occupied_count(&self) -> usize {
         self.class_pools()
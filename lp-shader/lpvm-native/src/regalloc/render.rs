@@ -42,6 +42,7 @@ fn format_func_header_line(func: &IrFunction, module: &LpirModule, func_abi: &Fu
     let m = LpirModule {
         imports: module.imports.clone(),
         functions: VecMap::from([(id, f)]),
+        rodata: Vec::new(),
     };
     let s = print_module(&m);
     s.lines()
@@ -73,6 +74,7 @@ fn format_lpir_op_line(
     let m = LpirModule {
         imports: module.imports.clone(),
         functions,
+        rodata: Vec::new(),
     };
     let s = print_module(&m);
     let mut in_body = false;
@@ -524,6 +526,12 @@ fn format_inst(inst: &VInst, vreg_pool: &[VReg], symbols: Option<&ModuleSymbols>
         VInst::SlotAddr { dst, slot, .. } => {
             format!("i{} = SlotAddr {}", dst.0, slot)
         }
+        VInst::SymbolAddr { dst, target, .. } => {
+            let name = symbols
+                .map(|s| s.name(*target).to_string())
+                .unwrap_or_else(|| format!("sym{}", target.0));
+            format!("i{} = SymbolAddr {}", dst.0, name)
+        }
         VInst::MemcpyWords {
             dst_base,
             src_base,
@@ -678,6 +686,14 @@ fn format_inst(inst: &VInst, vreg_pool: &[VReg], symbols: Option<&ModuleSymbols>
             if *signed { "IToFS" } else { "IToFU" },
             src.0
         ),
+        VInst::FToI {
+            dst, src, signed, ..
+        } => format!(
+            "i{} = {} i{}",
+            dst.0,
+            if *signed { "FToIS" } else { "FToIU" },
+            src.0
+        ),
     }
 }
 
@@ -0,0 +1,315 @@
+//! Support for [`RegAllocStrategy::Split`](super::RegAllocStrategy::Split).
+//!
+//! The split strategy is the backward walk in [`walk`](super::walk) with
+//! three changes, each aimed at the spill traffic the walk strategy leaves in
+//! loop-heavy shaders:
+//!
+//! - **Live-range splitting at non-join boundaries.** The walk flushes every
+//!   register-resident value to its slot at each region boundary, so a value
+//!   computed just before an `if` is stored and reloaded on the way in even
+//!   though nothing can branch there. A boundary is only a join when the
+//!   region after it starts with a label; elsewhere control arrives by falling
+//!   through the preceding region alone, and the value stays where it is. See
+//!   [`falls_through_into`].
+//! - **Loop-invariant pinning.** A value a loop reads but never writes would
+//!   be reloaded at the top of every iteration. The split strategy parks such
+//!   values in a register for the whole loop, loading each at most once before
+//!   the loop is entered. How many it may pin is budgeted so the loop body
+//!   keeps enough registers of its own.
+//! - **Cost-directed eviction.** When the pool is full, the victim is the
+//!   value whose next reload (walking backward: its previous occurrence) is
+//!   cheapest, weighted by loop depth — a value only needed outside the loop
+//!   is evicted before one the loop keeps reading. See [`SpillCosts`].
+//!
+//! Everything else — calls, ABI constraints, entry moves, the write-through
+//! invariant that any vreg with a slot has its latest value there — is the
+//! walk's, and its output goes through the same [`verify`](super::verify).
+//!
+//! Behind the `regalloc-split` feature: it is host-and-measurement code today,
+//! and the firmware does not pay for it.
+
+use crate::region::{REGION_ID_NONE, Region, RegionId, RegionTree};
+use crate::vinst::{LabelId, VInst, VReg};
+use alloc::vec::Vec;
+
+/// Registers of a class that loop-invariant pinning leaves unpinned, for the
+/// loop's own temporaries.
+pub(crate) const UNPINNED_RESERVE: usize = 6;
+
+/// When the loop contains a call, the unpinned registers that survive one
+/// (callee-saved) it must keep. Values live across the call and the call's
+/// own arguments are allocated out of these.
+pub(crate) const CALL_SAFE_RESERVE: usize = 3;
+
+/// Deepest loop nesting the eviction weight distinguishes; deeper counts as
+/// this deep.
+const MAX_WEIGHTED_DEPTH: u8 = 4;
+
+/// Per-vreg occurrence lists and per-instruction loop depth, for choosing
+/// eviction victims.
+pub struct SpillCosts {
+    /// `points[starts[v]..starts[v + 1]]` are vreg `v`'s occurrences.
+    starts: Vec<u32>,
+    /// Occurrences in instruction order, each `inst << 1 | is_use`.
+    points: Vec<u32>,
+    /// Loop nesting depth of each instruction.
+    depth: Vec<u8>,
+}
+
+impl SpillCosts {
+    pub fn new(vinsts: &[VInst], vreg_pool: &[VReg]) -> Self {
+        let mut max_vreg = 0usize;
+        for inst in vinsts {
+            inst.for_each_vreg_touching(vreg_pool, |v| max_vreg = max_vreg.max(v.0 as usize));
+        }
+        let n = max_vreg + 1;
+
+        let mut starts = vec![0u32; n + 1];
+        for inst in vinsts {
+            inst.for_each_def(vreg_pool, |v| starts[v.0 as usize + 1] += 1);
+            inst.for_each_use(vreg_pool, |v| starts[v.0 as usize + 1] += 1);
+        }
+        for i in 1..=n {
+            starts[i] += starts[i - 1];
+        }
+        let mut fill = starts.clone();
+        let mut points = vec![0u32; starts[n] as usize];
+        for (idx, inst) in vinsts.iter().enumerate() {
+            let at = (idx as u32) << 1;
+            // Defs first: at one instruction the use is read before the def
+            // is written, and the walk (backward) meets the def first.
+            inst.for_each_def(vreg_pool, |v| {
+                points[fill[v.0 as usize] as usize] = at;
+                fill[v.0 as usize] += 1;
+            });
+            inst.for_each_use(vreg_pool, |v| {
+                points[fill[v.0 as usize] as usize] = at | 1;
+                fill[v.0 as usize] += 1;
+            });
+        }
+
+        Self {
+            starts,
+            points,
+            depth: loop_depths(vinsts),
+        }
+    }
+
+    /// Loop nesting depth of instruction `inst_idx` (0 outside any loop).
+    pub fn depth(&self, inst_idx: usize) -> u8 {
+        self.depth.get(inst_idx).copied().unwrap_or(0)
+    }
+
+    /// Weight of one execution of instruction `inst_idx`: 8 per loop level.
+    pub fn frequency(&self, inst_idx: usize) -> u32 {
+        1 << (3 * self.depth(inst_idx).min(MAX_WEIGHTED_DEPTH))
+    }
+
+    /// Sort key for evicting `vreg` at instruction `cursor`; smaller is a
+    /// better victim.
+    ///
+    /// Evicting a value in the backward walk means its previous occurrence
+    /// finds it in its slot: a use there becomes a reload, a def becomes a
+    /// def-to-slot (one store). The first key is that cost, weighted by how
+    /// often the occurrence runs; a value with no previous occurrence (an
+    /// entry parameter) costs nothing. The second breaks ties toward the
+    /// value whose previous occurrence is furthest away.
+    pub fn victim_key(&self, vreg: VReg, cursor: u16) -> (u32, u32) {
+        let v = vreg.0 as usize;
+        if v + 1 >= self.starts.len() {
+            return (0, 0);
+        }
+        let occ = &self.points[self.starts[v] as usize..self.starts[v + 1] as usize];
+        let before = occ.partition_point(|&p| (p >> 1) < u32::from(cursor));
+        let Some(&prev) = before.checked_sub(1).and_then(|i| occ.get(i)) else {
+            return (0, 0);
+        };
+        let inst = (prev >> 1) as usize;
+        let cost = self.frequency(inst) << (prev & 1);
+        (cost, inst as u32)
+    }
+}
+
+/// Loop nesting depth of every instruction, from the back-edges in the
+/// stream: a `Br`/`BrIf` to a label placed earlier closes a loop spanning
+/// the two.
+pub fn loop_depths(vinsts: &[VInst]) -> Vec<u8> {
+    let mut label_at: Vec<(LabelId, usize)> = Vec::new();
+    let mut delta = vec![0i32; vinsts.len() + 1];
+    for (idx, inst) in vinsts.iter().enumerate() {
+        match inst {
+            VInst::Label(id, _) => label_at.push((*id, idx)),
+            VInst::Br { target, .. } | VInst::BrIf { target, .. } => {
+                if let Some(&(_, at)) = label_at.iter().find(|(id, _)| id == target) {
+                    delta[at] += 1;
+                    delta[idx + 1] -= 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    let mut depth = 0i32;
+    delta[..vinsts.len()]
+        .iter()
+        .map(|d| {
+            depth += d;
+            depth.clamp(0, i32::from(u8::MAX)) as u8
+        })
+        .collect()
+}
+
+/// Whether control can only reach `anchor` (the first instruction of the
+/// region after `prev`) by falling off the end of `prev`, so that values may
+/// cross the boundary in their registers.
+///
+/// Branches target labels only, so a region that does not start with one is
+/// entered by fallthrough alone. `prev` must also end without a label:
+/// otherwise a branch to that label reaches `anchor` too, and it would arrive
+/// with the values in their slots rather than in the registers the region
+/// after expects.
+pub fn falls_through_into(
+    tree: &RegionTree,
+    vinsts: &[VInst],
+    prev: RegionId,
+    anchor: u16,
+) -> bool {
+    !matches!(vinsts.get(anchor as usize), Some(VInst::Label(..)) | None)
+        && ends_plainly(tree, vinsts, prev)
+}
+
+/// Whether `id` is non-empty and its last stretch of instructions contains no
+/// label. A `Seq` is judged by its last child: the boundary in front of that
+/// child is itself only elided when what precedes it ends plainly. Control
+/// regions end in a label or a branch and are never plain.
+fn ends_plainly(tree: &RegionTree, vinsts: &[VInst], id: RegionId) -> bool {
+    if id == REGION_ID_NONE {
+        return false;
+    }
+    match &tree.nodes[id as usize] {
+        Region::Linear { start, end } => {
+            start < end
+                && !vinsts[*start as usize..*end as usize]
+                    .iter()
+                    .any(|i| matches!(i, VInst::Label(..)))
+        }
+        Region::Seq {
+            children_start,
+            child_count,
+        } => {
+            *child_count > 0
+                && ends_plainly(
+                    tree,
+                    vinsts,
+                    tree.seq_children[*children_start as usize + *child_count as usize - 1],
+                )
+        }
+        Region::IfThenElse { .. } | Region::Loop { .. } | Region::Block { .. } => false,
+    }
+}
+
+/// One past the last instruction of `id`, if it has any.
+pub fn region_end_vinst(tree: &RegionTree, id: RegionId) -> Option<u16> {
+    if id == REGION_ID_NONE {
+        return None;
+    }
+    match &tree.nodes[id as usize] {
+        Region::Linear { start, end } => (start < end).then_some(*end),
+        Region::Seq {
+            children_start,
+            child_count,
+        } => {
+            let s = *children_start as usize;
+            let e = s + *child_count as usize;
+            tree.seq_children[s..e]
+                .iter()
+                .filter_map(|&c| region_end_vinst(tree, c))
+                .max()
+        }
+        Region::IfThenElse {
+            head,
+            then_body,
+            else_body,
+            ..
+        } => [*head, *then_body, *else_body]
+            .into_iter()
+            .filter_map(|c| region_end_vinst(tree, c))
+            .max(),
+        Region::Loop { header, body, .. } => region_end_vinst(tree, *body)
+            .into_iter()
+            .chain(region_end_vinst(tree, *header))
+            .max(),
+        Region::Block { body, .. } => region_end_vinst(tree, *body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vinst::{AluOp, SRC_OP_NONE};
+
+    fn iconst(dst: u16) -> VInst {
+        VInst::IConst32 {
+            dst: VReg(dst),
+            val: 1,
+            src_op: SRC_OP_NONE,
+        }
+    }
+
+    fn add(dst: u16, a: u16, b: u16) -> VInst {
+        VInst::AluRRR {
+            op: AluOp::Add,
+            dst: VReg(dst),
+            src1: VReg(a),
+            src2: VReg(b),
+            src_op: SRC_OP_NONE,
+        }
+    }
+
+    fn br(target: LabelId) -> VInst {
+        VInst::Br {
+            target,
+            src_op: SRC_OP_NONE,
+        }
+    }
+
+    /// Two nested back-edges: the inner body is at depth 2, the stretch of
+    /// the outer loop around it at depth 1, everything else at 0.
+    #[test]
+    fn loop_depths_follow_back_edges() {
+        let vinsts = vec![
+            iconst(0),                    // 0
+            VInst::Label(1, SRC_OP_NONE), // 1  outer header
+            iconst(1),                    // 2
+            VInst::Label(2, SRC_OP_NONE), // 3  inner header
+            add(2, 0, 1),                 // 4
+            br(2),                        // 5  inner back-edge
+            br(1),                        // 6  outer back-edge
+            VInst::Label(3, SRC_OP_NONE), // 7  exit (forward target)
+            iconst(3),                    // 8
+        ];
+        assert_eq!(loop_depths(&vinsts), vec![0, 1, 1, 2, 2, 2, 1, 0, 0]);
+    }
+
+    /// A use inside the loop costs more to evict than one outside it, and a
+    /// use more than a def at the same depth; a value with no earlier
+    /// occurrence is free.
+    #[test]
+    fn victim_key_weights_by_loop_depth_and_kind() {
+        let vinsts = vec![
+            iconst(0),                    // 0  def v0 (depth 0)
+            iconst(1),                    // 1  def v1 (depth 0)
+            VInst::Label(1, SRC_OP_NONE), // 2
+            add(2, 0, 0),                 // 3  use v0 (depth 1)
+            br(1),                        // 4
+            add(3, 1, 1),                 // 5  use v1 (depth 0)
+        ];
+        let costs = SpillCosts::new(&vinsts, &[]);
+        // At 6, v0's previous occurrence is the in-loop use, v1's the
+        // out-of-loop one.
+        assert!(costs.victim_key(VReg(1), 6) < costs.victim_key(VReg(0), 6));
+        assert_eq!(costs.victim_key(VReg(0), 6).0, 16);
+        // At 5 (v1's own use is not "previous"), v1 is back to its def.
+        assert_eq!(costs.victim_key(VReg(1), 5).0, 1);
+        assert_eq!(costs.victim_key(VReg(0), 0), (0, 0));
+    }
+}
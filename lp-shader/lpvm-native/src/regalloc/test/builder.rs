@@ -20,10 +20,10 @@
 use crate::abi::FuncAbi;
 use crate::debug::vinst;
 use crate::isa::IsaTarget;
-use crate::regalloc::AllocOutput;
 use crate::regalloc::pool::RegPool;
 use crate::regalloc::render::render_alloc_output;
-use crate::regalloc::walk::walk_linear_with_pool;
+use crate::regalloc::walk::walk_linear_with_strategy;
+use crate::regalloc::{AllocOutput, RegAllocStrategy};
 
 use crate::isa::rv32::abi;
 use crate::vinst::{ModuleSymbols, VInst, VReg};
@@ -38,6 +38,7 @@ pub struct AllocTestBuilder {
     /// Same spelling as filetests: `void`, `i32`, `f32`, `vec4`, `mat4`, …
     abi_return: String,
     isa: IsaTarget,
+    strategy: RegAllocStrategy,
 }
 
 /// Start building an allocation test.
@@ -52,6 +53,7 @@ pub fn alloc_test() -> AllocTestBuilder {
         abi_params: 0,
         abi_return: String::from("void"),
         isa: IsaTarget::Rv32imac,
+        strategy: RegAllocStrategy::Walk,
     }
 }
 
@@ -98,6 +100,12 @@ impl AllocTestBuilder {
         self
     }
 
+    /// Allocator strategy; the walk by default.
+    pub fn strategy(mut self, strategy: RegAllocStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
     fn build_func_abi(&self) -> FuncAbi {
         let sig = LpsFnSig {
             name: String::from("test"),
@@ -113,6 +121,7 @@ impl AllocTestBuilder {
         };
         match self.isa {
             IsaTarget::Rv32imac => abi::func_abi_rv32(&sig, None),
+            IsaTarget::Rv32imafc => abi::func_abi_rv32f(&sig, None),
             #[cfg(feature = "isa-xt")]
             IsaTarget::Xtensa => crate::isa::xt::abi::func_abi_xt(&sig, None),
         }
@@ -135,7 +144,15 @@ impl AllocTestBuilder {
             None => RegPool::for_abi(&func_abi),
         };
 
-        let output = walk_linear_with_pool(&vinsts, &vreg_pool, &func_abi, pool)
+        #[cfg(feature = "regalloc-split")]
+        let pool = match self.strategy {
+            RegAllocStrategy::Walk => pool,
+            RegAllocStrategy::Split => {
+                pool.with_spill_costs(crate::regalloc::split::SpillCosts::new(&vinsts, &vreg_pool))
+            }
+        };
+
+        let output = walk_linear_with_strategy(&vinsts, &vreg_pool, &func_abi, pool, self.strategy)
             .unwrap_or_else(|e| panic!("Allocation failed: {e:?}"));
 
         crate::regalloc::verify::verify_alloc(&vinsts, &vreg_pool, &output, &func_abi);
@@ -194,6 +211,8 @@ impl AllocTestBuilder {
                 callee_uses_sret: cu,
                 caller_passes_sret_ptr: cp,
                 caller_sret_vm_abi_swap: sw,
+                float_args: 0,
+                float_rets: 0,
                 ..
             } = inst
             {
@@ -253,6 +272,13 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
+    /// Every strategy this build has; the structural tests hold for each.
+    const STRATEGIES: &[RegAllocStrategy] = &[
+        RegAllocStrategy::Walk,
+        #[cfg(feature = "regalloc-split")]
+        RegAllocStrategy::Split,
+    ];
+
     // ── Layer 1: Parameterized structural tests ──────────────────
     //
     // Same program shape, swept across pool sizes. Structural invariants
@@ -280,8 +306,9 @@ mod tests {
     /// Max live = 4 (i0..i3 all live when first Add happens in backward walk).
     #[rstest]
     fn pairwise_chain(#[values(1, 2, 3, 4, 8, 16)] pool: usize) {
-        let r = alloc_test().pool_size(pool).run_vinst(
-            "i0 = IConst32 1
+        for &strategy in STRATEGIES {
+            let r = alloc_test().pool_size(pool).strategy(strategy).run_vinst(
+                "i0 = IConst32 1
                  i1 = IConst32 2
                  i2 = IConst32 3
                  i3 = IConst32 4
@@ -289,11 +316,12 @@ mod tests {
                  i5 = Add i2, i3
                  i6 = Add i4, i5
                  Ret i6",
-        );
-        if pool >= 4 {
-            r.expect_spill_slots(0);
-        } else {
-            r.expect_spill_slots_at_least(1);
+            );
+            if pool >= 4 {
+                r.expect_spill_slots(0);
+            } else {
+                r.expect_spill_slots_at_least(1);
+            }
         }
     }
 
@@ -319,8 +347,9 @@ mod tests {
     /// Tests deeper liveness under pressure.
     #[rstest]
     fn tree_reduction(#[values(1, 2, 3, 4, 6, 8, 16)] pool: usize) {
-        let r = alloc_test().pool_size(pool).run_vinst(
-            "i0 = IConst32 1
+        for &strategy in STRATEGIES {
+            let r = alloc_test().pool_size(pool).strategy(strategy).run_vinst(
+                "i0 = IConst32 1
                  i1 = IConst32 2
                  i2 = IConst32 3
                  i3 = IConst32 4
@@ -332,12 +361,13 @@ mod tests {
                  i9 = Add i6, i7
                  i10 = Add i8, i9
                  Ret i10",
-        );
-        // Tree reduces in pairs, so max live is 6 at the start
-        if pool >= 6 {
-            r.expect_spill_slots(0);
-        } else {
-            r.expect_spill_slots_at_least(1);
+            );
+            // Tree reduces in pairs, so max live is 6 at the start
+            if pool >= 6 {
+                r.expect_spill_slots(0);
+            } else {
+                r.expect_spill_slots_at_least(1);
+            }
         }
     }
 
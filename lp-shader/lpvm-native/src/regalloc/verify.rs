@@ -208,7 +208,7 @@ fn verify_allocs_within_pool(
                     || is_precolored_reg(preg)
                     || (is_call
                         && !callee_uses_sret
-                        && def_idx < isa.direct_ret_reg_count(preg.class));
+                        && inst.call_ret_bank(def_idx).1 < isa.direct_ret_reg_count(preg.class));
                 assert!(
                     allowed,
                     "inst {inst_idx}: def allocated to non-allocatable register x{}",
@@ -229,7 +229,7 @@ fn verify_allocs_within_pool(
                             callee_uses_sret,
                             caller_passes_sret_ptr,
                             caller_sret_vm_abi_swap,
-                            use_idx,
+                            inst.call_arg_bank(use_idx).1,
                         )
                         .is_some();
                 let allowed =
@@ -248,6 +248,7 @@ fn verify_allocs_within_pool(
 
 /// Call-specific ABI checks: ret operands in RET_REGS, arg operands in ARG_REGS.
 /// For sret calls: rets are generic (not constrained to RET_REGS), args shifted by 1.
+/// Positions are per-class bank indices ([`VInst::call_arg_bank`]).
 fn verify_call_abi(vinsts: &[VInst], vreg_pool: &[VReg], output: &AllocOutput, func_abi: &FuncAbi) {
     let isa = func_abi.isa();
     for (inst_idx, inst) in vinsts.iter().enumerate() {
@@ -270,7 +271,8 @@ fn verify_call_abi(vinsts: &[VInst], vreg_pool: &[VReg], output: &AllocOutput, f
         if !callee_uses_sret {
             let mut def_idx: usize = 0;
             inst.for_each_def(vreg_pool, |_def_vreg| {
-                if let Some(expected) = isa.direct_ret_reg(def_class(inst, def_idx), def_idx) {
+                let bank_idx = inst.call_ret_bank(def_idx).1;
+                if let Some(expected) = isa.direct_ret_reg(def_class(inst, def_idx), bank_idx) {
                     let actual = output.allocs[offset + def_idx];
                     assert!(
                         actual == Alloc::reg(expected),
@@ -292,7 +294,7 @@ fn verify_call_abi(vinsts: &[VInst], vreg_pool: &[VReg], output: &AllocOutput, f
                 callee_uses_sret,
                 caller_passes_sret_ptr,
                 caller_sret_vm_abi_swap,
-                use_idx,
+                inst.call_arg_bank(use_idx).1,
             ) {
                 let actual = output.allocs[offset + num_defs + use_idx];
                 assert!(
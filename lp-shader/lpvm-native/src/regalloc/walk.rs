@@ -9,7 +9,8 @@ use crate::regalloc::pool::RegPool;
 use crate::regalloc::spill::SpillAlloc;
 use crate::regalloc::trace::TraceEntry;
 use crate::regalloc::{
-    Alloc, AllocError, AllocOutput, Edit, EditPoint, TracePush, TraceSink, trace_sink_new,
+    Alloc, AllocError, AllocOutput, Edit, EditPoint, RegAllocStrategy, TracePush, TraceSink,
+    trace_sink_new,
 };
 use crate::region::{REGION_ID_NONE, Region, RegionId, RegionTree};
 use crate::regset::RegSet;
@@ -152,7 +153,7 @@ fn build_passthrough_set(
                         *callee_uses_sret,
                         *caller_passes_sret_ptr,
                         *caller_sret_vm_abi_swap,
-                        i,
+                        inst.call_arg_bank(i).1,
                     ) else {
                         disqualified[idx] = true;
                         continue;
@@ -182,6 +183,9 @@ fn build_passthrough_set(
 }
 
 /// Register allocation over the full `vinsts` slice using a region tree root.
+///
+/// Under [`RegAllocStrategy::Split`] the pool should carry spill costs
+/// ([`RegPool::with_spill_costs`]); without them eviction stays LRU.
 pub fn allocate_from_tree(
     vinsts: &[VInst],
     vreg_pool: &[VReg],
@@ -189,6 +193,7 @@ pub fn allocate_from_tree(
     root: RegionId,
     func_abi: &FuncAbi,
     pool: RegPool,
+    strategy: RegAllocStrategy,
 ) -> Result<AllocOutput, AllocError> {
     let (inst_alloc_offsets, total_operands, classes) = build_operand_layout(vinsts, vreg_pool);
     let mut max_vreg_idx = vreg_pool.iter().map(|v| v.0).max().unwrap_or(0) as usize;
@@ -214,6 +219,8 @@ pub fn allocate_from_tree(
         loop_carried: RegSet::new(),
         passthrough,
         call_scratch: CallScratch::default(),
+        split: strategy != RegAllocStrategy::Walk,
+        pins: Vec::new(),
     };
     state.walk_region(root)?;
     state.finish()
@@ -270,6 +277,12 @@ struct WalkState<'a> {
     passthrough: Vec<Option<PReg>>,
     /// Reused by [`process_call`]; see [`CallScratch`].
     call_scratch: CallScratch,
+    /// [`RegAllocStrategy::Split`]: keep values in registers across
+    /// boundaries that are not joins, and pin loop invariants.
+    split: bool,
+    /// Loop invariants pinned to a register for the loops being walked,
+    /// outermost first. Always empty under the walk strategy.
+    pins: Vec<(VReg, PReg)>,
 }
 
 impl<'a> WalkState<'a> {
@@ -293,7 +306,10 @@ impl<'a> WalkState<'a> {
                     self.walk_region(child)?;
                     if idx > 0 {
                         if let Some(anchor) = region_first_vinst(self.tree, child) {
-                            self.boundary_reload_before(anchor)?;
+                            let prev = self.tree.seq_children[s + idx - 1];
+                            if !self.keeps_registers_across(prev, anchor) {
+                                self.boundary_reload_before(anchor)?;
+                            }
                         }
                     }
                 }
@@ -319,7 +335,9 @@ impl<'a> WalkState<'a> {
                 // head's. Placing reloads inside the fallthrough path prevents
                 // them from clobbering the BrIf condition register.
                 if let Some(anchor) = region_first_vinst(self.tree, *then_body) {
-                    self.boundary_reload_before(anchor)?;
+                    if !self.keeps_registers_across(*head, anchor) {
+                        self.boundary_reload_before(anchor)?;
+                    }
                 }
                 self.walk_region(*head)?;
                 Ok(())
@@ -327,13 +345,19 @@ impl<'a> WalkState<'a> {
             Region::Block { body, .. } => {
                 if *body != REGION_ID_NONE {
                     self.walk_region(*body)?;
-                    if let Some(anchor) = region_first_vinst(self.tree, *body) {
-                        self.boundary_reload_before(anchor)?;
+                    // Nothing branches to a block's start. The split strategy
+                    // leaves the decision to the boundary in front of the
+                    // block, which knows what precedes it.
+                    if !self.split {
+                        if let Some(anchor) = region_first_vinst(self.tree, *body) {
+                            self.boundary_reload_before(anchor)?;
+                        }
                     }
                 }
                 Ok(())
             }
             Region::Loop { header, body, .. } => {
+                let outer_pins = self.pins.len();
                 if *body != REGION_ID_NONE {
                     // Pre-assign spill slots for loop-carried values so that
                     // defs inside the body write directly to the slot. The
@@ -374,22 +398,156 @@ impl<'a> WalkState<'a> {
                         }
                     }
 
+                    #[cfg(feature = "regalloc-split")]
+                    if self.split {
+                        self.pin_loop_invariants(*header, *body, body_live.live_in, &defs_in_loop);
+                    }
+
                     self.walk_region(*body)?;
                     if let Some(anchor) = region_first_vinst(self.tree, *body) {
                         self.boundary_reload_before(anchor)?;
                     }
                 }
                 self.walk_region(*header)?;
+                // Leaving the loop upward: the pinned values stay in their
+                // registers as ordinary occupants, and the boundary in front
+                // of the loop loads them (or finds them already there) once.
+                for (_, preg) in self.pins.drain(outer_pins..) {
+                    self.pool.unpin(preg);
+                }
                 Ok(())
             }
         }
     }
 
+    /// Whether the boundary in front of `anchor`, after region `prev`, can be
+    /// crossed with values in their registers instead of flushed — only under
+    /// the split strategy, and only when it is not a join
+    /// ([`split::falls_through_into`](crate::regalloc::split::falls_through_into)).
+    fn keeps_registers_across(&self, prev: RegionId, anchor: u16) -> bool {
+        #[cfg(feature = "regalloc-split")]
+        if self.split {
+            return crate::regalloc::split::falls_through_into(
+                self.tree,
+                self.vinsts,
+                prev,
+                anchor,
+            );
+        }
+        let _ = (prev, anchor);
+        false
+    }
+
+    /// Pin values the loop reads but never writes into registers for the
+    /// whole loop walk, so the per-iteration flush at the top of the body
+    /// leaves them alone and they are loaded once, before the loop.
+    ///
+    /// Candidates are ranked by how often the loop reads them, weighted by
+    /// nesting depth. Each class keeps [`UNPINNED_RESERVE`] registers for the
+    /// loop's own values; a loop that calls pins only into callee-saved
+    /// registers and keeps [`CALL_SAFE_RESERVE`] of those free besides.
+    /// Entry-parameter pass-throughs never enter the pool and are skipped.
+    ///
+    /// [`UNPINNED_RESERVE`]: crate::regalloc::split::UNPINNED_RESERVE
+    /// [`CALL_SAFE_RESERVE`]: crate::regalloc::split::CALL_SAFE_RESERVE
+    #[cfg(feature = "regalloc-split")]
+    fn pin_loop_invariants(
+        &mut self,
+        header: RegionId,
+        body: RegionId,
+        live_in: RegSet,
+        defs_in_loop: &RegSet,
+    ) {
+        use crate::regalloc::split::{CALL_SAFE_RESERVE, UNPINNED_RESERVE, region_end_vinst};
+
+        let Some(start) =
+            region_first_vinst(self.tree, header).or_else(|| region_first_vinst(self.tree, body))
+        else {
+            return;
+        };
+        let Some(end) = region_end_vinst(self.tree, body) else {
+            return;
+        };
+        let span = start as usize..end as usize;
+
+        let mut candidates: Vec<(VReg, u32)> = live_in
+            .iter()
+            .filter(|&v| {
+                !defs_in_loop.contains(v)
+                    && self.pool.home(v).is_none()
+                    && self
+                        .passthrough
+                        .get(v.0 as usize)
+                        .is_none_or(|p| p.is_none())
+            })
+            .map(|v| (v, 0))
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+        let mut has_call = false;
+        for idx in span {
+            let inst = &self.vinsts[idx];
+            has_call |= inst.is_call();
+            let weight = self.pool.spill_costs().map_or(1, |c| c.frequency(idx));
+            inst.for_each_use(self.vreg_pool, |u| {
+                if let Some(c) = candidates.iter_mut().find(|(v, _)| *v == u) {
+                    c.1 += weight;
+                }
+            });
+        }
+        // Most-read first; vreg order among equals keeps the choice stable.
+        candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.0.cmp(&b.0.0)));
+
+        let isa = self.func_abi.isa();
+        for (vreg, uses) in candidates {
+            if uses == 0 {
+                break;
+            }
+            let class = self.classes.of(vreg);
+            let caller_saved = isa.caller_saved_pool_hw(class);
+            let order = self.pool.order(class);
+            let pinned = |hw: u8| self.pool.is_pinned(PReg { hw, class });
+            let pinned_count = order.iter().filter(|&&hw| pinned(hw)).count();
+            if pinned_count + 1 + UNPINNED_RESERVE > order.len() {
+                continue;
+            }
+            if has_call {
+                let call_safe_unpinned = order
+                    .iter()
+                    .filter(|&&hw| !caller_saved.contains(&hw) && !pinned(hw))
+                    .count();
+                if call_safe_unpinned <= CALL_SAFE_RESERVE {
+                    continue;
+                }
+            }
+            let Some(hw) = order.iter().copied().find(|&hw| {
+                let preg = PReg { hw, class };
+                !self.pool.iter_occupied().any(|(p, _)| p == preg)
+                    && !(has_call && caller_saved.contains(&hw))
+            }) else {
+                continue;
+            };
+            let preg = PReg { hw, class };
+            self.pool.alloc_fixed(preg, vreg);
+            self.pool.pin(preg);
+            self.pins.push((vreg, preg));
+            TracePush::push_with(&mut self.trace, || TraceEntry {
+                vinst_idx: start as usize,
+                vinst_mnemonic: String::from("loop_pin"),
+                decision: alloc::format!("v{} -> x{} ({uses} uses)", vreg.0, hw),
+                register_state: String::new(),
+            });
+        }
+    }
+
     fn walk_linear_range(&mut self, start: usize, end: usize) -> Result<(), AllocError> {
         for inst_idx in (start..end).rev() {
             let inst = &self.vinsts[inst_idx];
             let inst_idx_u16 = inst_idx as u16;
             let offset = self.inst_alloc_offsets[inst_idx] as usize;
+            #[cfg(feature = "regalloc-split")]
+            self.pool.set_cursor(inst_idx_u16);
 
             if inst.is_call() {
                 process_call(
@@ -467,8 +625,20 @@ impl<'a> WalkState<'a> {
         let mut occupied = [(PReg::int(0), VReg(0)); 64];
         let mut n = 0;
         for entry in self.pool.iter_occupied() {
-            occupied[n] = entry;
-            n += 1;
+            // Pinned loop invariants stay put: the loop never writes them.
+            if !self.pool.is_pinned(entry.0) {
+                occupied[n] = entry;
+                n += 1;
+            }
+        }
+        for &(vreg, preg) in &self.pins {
+            if self.pool.home(vreg) != Some(preg) {
+                return Err(crate::emit_err!(
+                    "pinned v{} left x{} inside its loop",
+                    vreg.0,
+                    preg.hw
+                ));
+            }
         }
         for &(preg, vreg) in &occupied[..n] {
             // The slot's class is the register's class: this reload writes
@@ -643,6 +813,19 @@ pub fn walk_linear_with_pool(
     vreg_pool: &[VReg],
     func_abi: &FuncAbi,
     pool: RegPool,
+) -> Result<AllocOutput, AllocError> {
+    walk_linear_with_strategy(vinsts, vreg_pool, func_abi, pool, RegAllocStrategy::Walk)
+}
+
+/// [`walk_linear_with_pool`] under `strategy`. The pool is used as given: for
+/// [`RegAllocStrategy::Split`] attach its spill costs first, as
+/// [`allocate_with`](super::allocate_with) does.
+pub fn walk_linear_with_strategy(
+    vinsts: &[VInst],
+    vreg_pool: &[VReg],
+    func_abi: &FuncAbi,
+    pool: RegPool,
+    strategy: RegAllocStrategy,
 ) -> Result<AllocOutput, AllocError> {
     let mut tree = RegionTree::new();
     let root = if vinsts.is_empty() {
@@ -654,7 +837,7 @@ pub fn walk_linear_with_pool(
         })
     };
     tree.root = root;
-    allocate_from_tree(vinsts, vreg_pool, &tree, root, func_abi, pool)
+    allocate_from_tree(vinsts, vreg_pool, &tree, root, func_abi, pool, strategy)
 }
 
 /// Generic (non-call) instruction processing.
@@ -848,7 +1031,9 @@ fn process_generic(
             match spill.get_or_assign(use_vreg, class) {
                 Ok(slot) => {
                     if let Some(preg) = pool.home(use_vreg) {
-                        pool.free(preg);
+                        if !pool.is_pinned(preg) {
+                            pool.free(preg);
+                        }
                     }
                     Alloc::Stack(slot)
                 }
@@ -1091,8 +1276,10 @@ fn process_call(
         let alloc_idx = offset + operand_idx;
         operand_idx += 1;
         let ret_class = classes.of(ret_vreg);
+        // ABI positions count within the value's own class bank.
+        let bank_idx = inst.call_ret_bank(i).1;
 
-        if callee_uses_sret || i >= isa.direct_ret_reg_count(ret_class) {
+        if callee_uses_sret || bank_idx >= isa.direct_ret_reg_count(ret_class) {
             let alloc = if let Some(preg) = pool.home(ret_vreg) {
                 ret_value_pool_regs.push(preg);
                 Alloc::reg(preg)
@@ -1109,7 +1296,7 @@ fn process_call(
         }
 
         let target = isa
-            .direct_ret_reg(ret_class, i)
+            .direct_ret_reg(ret_class, bank_idx)
             .ok_or(AllocError::OutOfRegisters)?;
 
         allocs[alloc_idx] = Alloc::reg(target);
@@ -1230,7 +1417,7 @@ fn process_call(
             callee_uses_sret,
             caller_passes_sret_ptr,
             caller_sret_vm_abi_swap,
-            i,
+            inst.call_arg_bank(i).1,
         );
         let is_reg_pass = target_opt.is_some();
         let trace_target = target_opt.map(|p| p.hw).unwrap_or(0);
@@ -13,6 +13,7 @@ use crate::error::NativeError;
 use crate::isa::IsaTarget;
 use crate::link::link_elf;
 use crate::native_options::NativeCompileOptions;
+use crate::regalloc::AllocStats;
 
 use super::{GuestImage, NativeEmuModule};
 
@@ -45,7 +46,9 @@ impl NativeEmuEngine {
     pub fn new_for_isa(options: NativeCompileOptions, isa: IsaTarget) -> Self {
         let arena = match isa {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => EmuSharedArena::new(lpvm_emu::DEFAULT_SHARED_CAPACITY),
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
+                EmuSharedArena::new(lpvm_emu::DEFAULT_SHARED_CAPACITY)
+            }
             #[cfg(feature = "emu-xt")]
             IsaTarget::Xtensa => EmuSharedArena::with_start(
                 lpvm_emu::DEFAULT_SHARED_CAPACITY,
@@ -92,10 +95,12 @@ impl LpvmEngine for NativeEmuEngine {
 
         // 2. Build ModuleDebugInfo from compiled functions
         let mut debug_info = ModuleDebugInfo::new();
+        let mut alloc_stats = AllocStats::default();
         for func in &compiled.functions {
             if let Some(info) = &func.debug_info {
                 debug_info.add_function(info.clone());
             }
+            alloc_stats.add(func.alloc_stats);
         }
 
         // 3. Link into a runnable guest image. Both arms end at the same neutral
@@ -109,6 +114,16 @@ impl LpvmEngine for NativeEmuEngine {
                 let load = GuestImage::from(lpvm_cranelift::link_object_with_builtins(&elf)?);
                 (elf, load)
             }
+            #[cfg(feature = "isa-rv32")]
+            IsaTarget::Rv32imafc => {
+                // Same route, into the `ilp32f` build of the builtins: the
+                // calls lowering emits pass floats in `fa*`.
+                let elf = link_elf(&compiled, IsaTarget::Rv32imafc)
+                    .map_err(|e| NativeError::Internal(format!("ELF link failed: {e}")))?;
+                let load =
+                    GuestImage::from(lpvm_cranelift::link_object_with_rv32f_builtins(&elf)?);
+                (elf, load)
+            }
             #[cfg(feature = "emu-xt")]
             IsaTarget::Xtensa => {
                 // Xtensa loads the *linked* builtins executable as a base image
@@ -133,6 +148,7 @@ impl LpvmEngine for NativeEmuEngine {
             arena: self.arena.clone(),
             options: self.options.clone(),
             debug_info,
+            alloc_stats,
         })
     }
 
@@ -310,7 +310,7 @@ impl NativeEmuInstance {
         // --- the ISA branch ---
         let run = match self.module.isa {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => self.run_rv32(
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => self.run_rv32(
                 ir_func,
                 entry,
                 full,
@@ -384,7 +384,7 @@ impl NativeEmuInstance {
     fn uses_struct_return(&self, ir_func: &IrFunction) -> Result<bool, NativeError> {
         match self.module.isa {
             #[cfg(feature = "isa-rv32")]
-            IsaTarget::Rv32imac => {
+            IsaTarget::Rv32imac | IsaTarget::Rv32imafc => {
                 let isa = riscv32_lpvm_reference_isa()
                     .map_err(|e| NativeError::Call(CallError::Unsupported(format!("{e}"))))?;
                 Ok(signature_uses_struct_return(&*isa, ir_func))
@@ -443,9 +443,10 @@ impl NativeEmuInstance {
         // fails with "Unsupported return type: types::F32", and if it ever
         // stopped failing it would be reading the wrong register.
         //
-        // When a hardware-FPU backend lands ([`crate::isa::F32Lowering::
-        // HardwareFpu`]), the entry ABI changes with it and this becomes a
-        // query on the target rather than a constant.
+        // `Rv32imafc` is a hardware-FPU target ([`crate::isa::F32Lowering::
+        // HardwareFpu`]) and this still holds for it: only its calls into
+        // builtins use the float registers, and shader entry points keep taking
+        // and returning floats as words.
         let sig = signature_for_ir_func(
             ir_func,
             CallConv::SystemV,
@@ -10,6 +10,7 @@ use lpvm_emu::{EmuSharedArena, GUEST_VMCTX_BYTES, write_guest_vmctx_header};
 
 use crate::error::NativeError;
 use crate::native_options::NativeCompileOptions;
+use crate::regalloc::AllocStats;
 
 use super::NativeEmuInstance;
 
@@ -28,6 +29,17 @@ pub struct NativeEmuModule {
     pub(crate) options: NativeCompileOptions,
     /// Debug info with sections per function.
     pub(crate) debug_info: ModuleDebugInfo,
+    /// Spill traffic summed over every compiled function.
+    pub(crate) alloc_stats: AllocStats,
+}
+
+impl NativeEmuModule {
+    /// Spill slots, stores and reloads the register allocator left in this
+    /// module, summed over its functions — how strategies are compared
+    /// (`NativeCompileOptions::regalloc`).
+    pub fn alloc_stats(&self) -> AllocStats {
+        self.alloc_stats
+    }
 }
 
 impl LpvmModule for NativeEmuModule {
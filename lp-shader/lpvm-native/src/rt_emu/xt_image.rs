@@ -46,7 +46,7 @@ use lp_xt_elf::XtensaElf;
 use lp_xt_emu::board::BoardProfile;
 use lp_xt_emu::memory::AliasRule;
 
-use crate::compile::CompiledModule;
+use crate::compile::{CompiledModule, RODATA_SYMBOL};
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 
@@ -147,6 +147,13 @@ pub fn build_xt_image(
         cursor += f.code.len();
         cursor = cursor.next_multiple_of(4);
     }
+    // Read-only data after the code, named by its D-bus address: it is read
+    // through the data bus, never fetched.
+    let rodata_at = cursor;
+    if !compiled.rodata.is_empty() {
+        symbol_map.insert(RODATA_SYMBOL.to_string(), region_base + rodata_at as u32);
+        cursor += compiled.rodata.len() * 4;
+    }
     if cursor > region_len {
         return Err(NativeError::Internal(format!(
             "compiled shader code does not fit the Xtensa code region: {} bytes \
@@ -167,6 +174,10 @@ pub fn build_xt_image(
     for (f, &at) in compiled.functions.iter().zip(&offsets) {
         code[at..at + f.code.len()].copy_from_slice(&f.code);
     }
+    for (i, word) in compiled.rodata.iter().enumerate() {
+        let at = rodata_at + 4 * i;
+        code[at..at + 4].copy_from_slice(&word.to_le_bytes());
+    }
     for (f, &at) in compiled.functions.iter().zip(&offsets) {
         for reloc in &f.relocs {
             if reloc.r_type != IsaTarget::Xtensa.call_reloc_type() {
@@ -14,8 +14,10 @@
 //!   consulted. The span belongs to the [`crate::codemem_esp32::CodeArena`]
 //!   it came from — dropping the buffer does NOT free it; the engine that
 //!   allocated the span owns that lifetime.
+//!
+//! Either way the module's read-only data stays in a heap buffer owned here:
+//! code loads it through the data bus, which every target can read.
 
-#[cfg(not(all(feature = "xt-placed-code", target_arch = "xtensa")))]
 use alloc::vec::Vec;
 
 enum Inner {
@@ -55,6 +57,9 @@ impl Drop for JitBuffer {
 /// Holds emitted machine code for one module.
 pub struct JitBuffer {
     inner: Inner,
+    /// [`crate::CompiledModule::rodata`], at the address the code was linked
+    /// against.
+    _rodata: Vec<u32>,
 }
 
 impl JitBuffer {
@@ -62,6 +67,7 @@ impl JitBuffer {
     pub(crate) fn from_code(code: Vec<u8>) -> Self {
         Self {
             inner: Inner::Heap { code },
+            _rodata: Vec::new(),
         }
     }
 
@@ -72,6 +78,7 @@ impl JitBuffer {
         debug_assert!(exec_base % 4 == 0);
         Self {
             inner: Inner::Placed { exec_base, len },
+            _rodata: Vec::new(),
         }
     }
 
@@ -82,9 +89,17 @@ impl JitBuffer {
         debug_assert!(exec_base % 4 == 0);
         Self {
             inner: Inner::PlacedGlobal { exec_base, len },
+            _rodata: Vec::new(),
         }
     }
 
+    /// Keep the module's read-only data alive with the code linked against
+    /// its address. Moving the `Vec` in does not move its heap storage.
+    pub(crate) fn with_rodata(mut self, rodata: Vec<u32>) -> Self {
+        self._rodata = rodata;
+        self
+    }
+
     /// Byte length of emitted code.
     #[must_use]
     pub fn len(&self) -> usize {
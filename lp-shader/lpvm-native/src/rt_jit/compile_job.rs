@@ -1,19 +1,29 @@
+use alloc::string::String;
 use alloc::sync::Arc;
 
+use lp_collection::VecMap;
+
 use lpir::LpirModule;
 use lps_shared::LpsModuleSig;
 use lpvm::{LpvmCompileBudget, LpvmCompileJob, LpvmCompileParams, LpvmCompileStepResult};
 
-use crate::compile::{NativeCompileBudget, NativeCompileJob, NativeCompileStepResult};
+use crate::code_cache::{CodeCacheKey, JitCodeCache};
+use crate::compile::{
+    CompiledModule, NativeCompileBudget, NativeCompileJob, NativeCompileStepResult,
+};
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 use crate::native_options::NativeCompileOptions;
 
+use super::buffer::JitBuffer;
 use super::builtins::BuiltinTable;
-use super::compiler::link_compiled_module_jit;
+use super::compiler::{code_cache_entry, link_compiled_module_jit};
 use super::module::{NativeJitModule, NativeJitModuleInner, build_entry_info};
 
 enum NativeJitCompileStage {
+    /// Look the module up in the code cache on the first step, so the flash
+    /// read happens inside a budgeted step rather than at job creation.
+    CacheLookup(LpirModule),
     Backend(NativeCompileJob),
     Done,
 }
@@ -24,7 +34,9 @@ pub struct NativeJitCompileJob {
     options: NativeCompileOptions,
     isa: IsaTarget,
     stage: NativeJitCompileStage,
-    entry_info: lp_collection::VecMap<alloc::string::String, super::module::NativeJitEntryInfo>,
+    /// Where the compiled module is looked up and stored, if caching applies.
+    code_cache: Option<(Arc<dyn JitCodeCache>, CodeCacheKey)>,
+    entry_info: VecMap<String, super::module::NativeJitEntryInfo>,
 }
 
 impl NativeJitCompileJob {
@@ -35,6 +47,7 @@ impl NativeJitCompileJob {
         mut options: NativeCompileOptions,
         params: LpvmCompileParams,
         isa: IsaTarget,
+        code_cache: Option<&Arc<dyn JitCodeCache>>,
     ) -> Self {
         options.config = params.config;
         options.float_mode = params.float_mode;
@@ -42,17 +55,60 @@ impl NativeJitCompileJob {
         // is moved, not cloned — the backend job owns the only IR copy.
         let entry_info = build_entry_info(&ir, &meta, isa)
             .expect("native jit compile job requires matching IR and module signatures");
-        let backend =
-            NativeCompileJob::new(ir, meta.clone(), options.float_mode, options.clone(), isa);
+        let code_cache = code_cache_entry(code_cache, params.source_hash, &meta, &options, isa);
+        let stage = if code_cache.is_some() {
+            NativeJitCompileStage::CacheLookup(ir)
+        } else {
+            NativeJitCompileStage::Backend(NativeCompileJob::new(
+                ir,
+                meta.clone(),
+                options.float_mode,
+                options.clone(),
+                isa,
+            ))
+        };
         Self {
             meta,
             builtin_table,
             options,
             isa,
-            stage: NativeJitCompileStage::Backend(backend),
+            stage,
+            code_cache,
             entry_info,
         }
     }
+
+    fn backend(&self, ir: LpirModule) -> NativeJitCompileStage {
+        NativeJitCompileStage::Backend(NativeCompileJob::new(
+            ir,
+            self.meta.clone(),
+            self.options.float_mode,
+            self.options.clone(),
+            self.isa,
+        ))
+    }
+
+    fn link(
+        &self,
+        compiled: CompiledModule,
+    ) -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
+        link_compiled_module_jit(compiled, &self.builtin_table, self.isa)
+    }
+
+    fn finish(
+        &self,
+        (buffer, entry_offsets): (JitBuffer, VecMap<String, usize>),
+    ) -> LpvmCompileStepResult<NativeJitModule, NativeError> {
+        LpvmCompileStepResult::Finished(NativeJitModule {
+            inner: Arc::new(NativeJitModuleInner {
+                meta: self.meta.clone(),
+                buffer,
+                entry_offsets,
+                entry_info: self.entry_info.clone(),
+                options: self.options.clone(),
+            }),
+        })
+    }
 }
 
 impl LpvmCompileJob for NativeJitCompileJob {
@@ -64,6 +120,27 @@ impl LpvmCompileJob for NativeJitCompileJob {
         budget: LpvmCompileBudget,
     ) -> LpvmCompileStepResult<Self::Module, Self::Error> {
         match &mut self.stage {
+            NativeJitCompileStage::CacheLookup(_) => {
+                let NativeJitCompileStage::CacheLookup(ir) =
+                    core::mem::replace(&mut self.stage, NativeJitCompileStage::Done)
+                else {
+                    unreachable!()
+                };
+                let (cache, key) = self
+                    .code_cache
+                    .as_ref()
+                    .expect("cache lookup without a cache");
+                if let Some(compiled) = cache.load(*key, self.isa) {
+                    match self.link(compiled) {
+                        Ok(linked) => return self.finish(linked),
+                        Err(e) => log::warn!(
+                            "[code-cache] cached module failed to link, recompiling: {e}"
+                        ),
+                    }
+                }
+                self.stage = self.backend(ir);
+                LpvmCompileStepResult::Pending
+            }
             NativeJitCompileStage::Backend(job) => {
                 match job.step(NativeCompileBudget::steps(budget.max_steps)) {
                     NativeCompileStepResult::Pending => LpvmCompileStepResult::Pending,
@@ -73,18 +150,11 @@ impl LpvmCompileJob for NativeJitCompileJob {
                     }
                     NativeCompileStepResult::Finished(compiled) => {
                         self.stage = NativeJitCompileStage::Done;
-                        match link_compiled_module_jit(compiled, &self.builtin_table, self.isa) {
-                            Ok((buffer, entry_offsets)) => {
-                                LpvmCompileStepResult::Finished(NativeJitModule {
-                                    inner: Arc::new(NativeJitModuleInner {
-                                        meta: self.meta.clone(),
-                                        buffer,
-                                        entry_offsets,
-                                        entry_info: self.entry_info.clone(),
-                                        options: self.options.clone(),
-                                    }),
-                                })
-                            }
+                        if let Some((cache, key)) = &self.code_cache {
+                            cache.store(*key, &compiled);
+                        }
+                        match self.link(compiled) {
+                            Ok(linked) => self.finish(linked),
                             Err(err) => LpvmCompileStepResult::Failed(err),
                         }
                     }
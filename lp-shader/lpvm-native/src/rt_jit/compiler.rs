@@ -1,13 +1,15 @@
 //! Concatenate emitted functions, record relocations, patch auipc+jalr at finalize.
 
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use lp_collection::VecMap;
 
 use lpir::LpirModule;
 use lps_shared::LpsModuleSig;
 
-use crate::compile::{CompiledModule, compile_module};
+use crate::code_cache::{CodeCacheInputs, CodeCacheKey, JitCodeCache};
+use crate::compile::{CompiledModule, RODATA_SYMBOL, compile_module};
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 use crate::jit_symbol_sizes::{derive_sizes, sort_by_offset};
@@ -57,8 +59,56 @@ pub fn compile_module_jit(
     link_compiled_module_jit(compiled, builtin_table, isa)
 }
 
+/// [`compile_module_jit`] through a persistent [`JitCodeCache`]: relink the
+/// module stored under `key` if there is one, otherwise compile it and store
+/// the unlinked result. A stored module that no longer links (a builtin it
+/// calls is gone) is recompiled, and the fresh result replaces it.
+pub(crate) fn compile_module_jit_cached(
+    ir: &LpirModule,
+    sig: &LpsModuleSig,
+    builtin_table: &BuiltinTable,
+    options: &NativeCompileOptions,
+    isa: IsaTarget,
+    cache: &dyn JitCodeCache,
+    key: CodeCacheKey,
+) -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
+    if let Some(compiled) = cache.load(key, isa) {
+        match link_compiled_module_jit(compiled, builtin_table, isa) {
+            Ok(linked) => return Ok(linked),
+            Err(e) => log::warn!("[code-cache] cached module failed to link, recompiling: {e}"),
+        }
+    }
+    let compiled = compile_module(ir, sig, options.float_mode, options.clone(), isa)?;
+    cache.store(key, &compiled);
+    link_compiled_module_jit(compiled, builtin_table, isa)
+}
+
+/// The [`JitCodeCache`] entry a compile with `options` may use, if any: the
+/// engine needs a cache and the caller a source hash. Debug-info builds
+/// bypass the cache, which keeps no per-instruction debug lines.
+pub(crate) fn code_cache_entry(
+    cache: Option<&Arc<dyn JitCodeCache>>,
+    source_hash: Option<u64>,
+    meta: &LpsModuleSig,
+    options: &NativeCompileOptions,
+    isa: IsaTarget,
+) -> Option<(Arc<dyn JitCodeCache>, CodeCacheKey)> {
+    let cache = cache?;
+    if options.debug_info {
+        return None;
+    }
+    let key = CodeCacheKey::new(&CodeCacheInputs {
+        compiler_id: cache.compiler_id(),
+        source_hash: source_hash?,
+        meta,
+        options,
+        isa,
+    });
+    Some((Arc::clone(cache), key))
+}
+
 pub(crate) fn link_compiled_module_jit(
-    compiled: CompiledModule,
+    mut compiled: CompiledModule,
     builtin_table: &BuiltinTable,
     isa: IsaTarget,
 ) -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
@@ -75,21 +125,18 @@ pub(crate) fn link_compiled_module_jit(
     #[cfg(not(all(feature = "xt-placed-code", target_arch = "xtensa")))]
     {
         // 2. Link JIT image with builtin resolution
+        let rodata = core::mem::take(&mut compiled.rodata);
         lp_perf::emit_begin!(EVENT_SHADER_LINK);
         let link_result = link_jit(&compiled, isa, |sym| {
-            // First check builtins
-            if let Some(addr) = builtin_table.lookup(sym) {
-                return Some(addr as u32);
-            }
-            // Functions are resolved during link phase
-            None
+            // Builtins and rodata; functions are resolved during link phase
+            resolve_external(builtin_table, &rodata, sym)
         });
         lp_perf::emit_end!(EVENT_SHADER_LINK);
         let linked =
             link_result.map_err(|e| NativeError::Internal(format!("JIT link failed: {e}")))?;
 
         // 3. Create JitBuffer from linked code
-        let buffer = JitBuffer::from_code(linked.code);
+        let buffer = JitBuffer::from_code(linked.code).with_rodata(rodata);
 
         let buffer_len = u32::try_from(buffer.len())
             .map_err(|_| NativeError::Internal("JIT buffer length does not fit u32".into()))?;
@@ -110,12 +157,13 @@ pub(crate) fn link_compiled_module_jit(
 /// as a compile error, never a wild write.
 #[cfg(all(feature = "xt-placed-code", target_arch = "xtensa"))]
 fn link_compiled_module_jit_placed_global(
-    compiled: CompiledModule,
+    mut compiled: CompiledModule,
     builtin_table: &BuiltinTable,
     isa: IsaTarget,
 ) -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
     use crate::codemem_esp32::{self, DeviceCodeSink, global};
 
+    let rodata = core::mem::take(&mut compiled.rodata);
     let total: usize = compiled.functions.iter().map(|f| f.code.len()).sum();
     let total_u32 = u32::try_from(total)
         .map_err(|_| NativeError::Internal("JIT image length does not fit u32".into()))?;
@@ -129,7 +177,7 @@ fn link_compiled_module_jit_placed_global(
             let place = |arena: &mut codemem_esp32::CodeArena| {
                 lp_perf::emit_begin!(EVENT_SHADER_LINK);
                 let link_result = crate::link::link_jit_at(&compiled, isa, exec_base, |sym| {
-                    builtin_table.lookup(sym).map(|addr| addr as u32)
+                    resolve_external(builtin_table, &rodata, sym)
                 });
                 lp_perf::emit_end!(EVENT_SHADER_LINK);
                 let linked = link_result
@@ -157,7 +205,7 @@ fn link_compiled_module_jit_placed_global(
         },
     )
     .map_err(|e| NativeError::Internal(format!("JIT code placement failed: {e}")))?;
-    placed
+    placed.map(|(buffer, entries)| (buffer.with_rodata(rodata), entries))
 }
 
 /// [`compile_module_jit`] for the classic-ESP32 **fixed-region** placement:
@@ -178,7 +226,8 @@ pub fn compile_module_jit_placed(
     arena: &mut crate::codemem_esp32::CodeArena,
     sink: &mut impl crate::codemem_esp32::CodeSink,
 ) -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
-    let compiled = compile_module(ir, sig, options.float_mode, options.clone(), isa)?;
+    let mut compiled = compile_module(ir, sig, options.float_mode, options.clone(), isa)?;
+    let rodata = core::mem::take(&mut compiled.rodata);
 
     let total: usize = compiled.functions.iter().map(|f| f.code.len()).sum();
     let total_u32 = u32::try_from(total)
@@ -190,7 +239,7 @@ pub fn compile_module_jit_placed(
     let mut place = || -> Result<(JitBuffer, VecMap<String, usize>), NativeError> {
         lp_perf::emit_begin!(EVENT_SHADER_LINK);
         let link_result = crate::link::link_jit_at(&compiled, isa, exec_base, |sym| {
-            builtin_table.lookup(sym).map(|addr| addr as u32)
+            resolve_external(builtin_table, &rodata, sym)
         });
         lp_perf::emit_end!(EVENT_SHADER_LINK);
         let linked =
@@ -207,7 +256,7 @@ pub fn compile_module_jit_placed(
         Ok((buffer, linked.entries))
     };
     match place() {
-        Ok(ok) => Ok(ok),
+        Ok((buffer, entries)) => Ok((buffer.with_rodata(rodata), entries)),
         Err(e) => {
             arena.free(exec_base, total_u32);
             Err(e)
@@ -215,6 +264,16 @@ pub fn compile_module_jit_placed(
     }
 }
 
+/// Address of a symbol the JIT image does not define: a builtin, or the
+/// module's `rodata` (kept in its own heap buffer — the code region of the
+/// placed path is not data-readable everywhere).
+fn resolve_external(builtin_table: &BuiltinTable, rodata: &[u32], sym: &str) -> Option<u32> {
+    if sym == RODATA_SYMBOL {
+        return Some(rodata.as_ptr() as usize as u32);
+    }
+    builtin_table.lookup(sym).map(|addr| addr as u32)
+}
+
 /// Builds [`JitSymbolEntry`] records (names in `name_buf`) and notifies the profiler sink.
 fn emit_jit_symbols(buffer_base: u32, buffer_len: u32, entry_offsets: &VecMap<String, usize>) {
     if entry_offsets.is_empty() {
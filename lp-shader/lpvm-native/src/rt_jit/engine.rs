@@ -7,13 +7,14 @@ use lpir::LpirModule;
 use lps_shared::LpsModuleSig;
 use lpvm::{BoxedLpvmCompileJob, LpvmEngine, LpvmMemory};
 
+use crate::code_cache::JitCodeCache;
 use crate::error::NativeError;
 use crate::isa::IsaTarget;
 use crate::native_options::NativeCompileOptions;
 
 use super::builtins::BuiltinTable;
 use super::compile_job::NativeJitCompileJob;
-use super::compiler::compile_module_jit;
+use super::compiler::{code_cache_entry, compile_module_jit, compile_module_jit_cached};
 use super::host_memory::NativeHostMemory;
 use super::module::{NativeJitModule, NativeJitModuleInner, build_entry_info};
 
@@ -22,6 +23,9 @@ pub struct NativeJitEngine {
     builtin_table: Arc<BuiltinTable>,
     memory: NativeHostMemory,
     options: NativeCompileOptions,
+    /// Persistent code cache for compiles whose params carry a
+    /// [`lpvm::LpvmCompileParams::source_hash`].
+    code_cache: Option<Arc<dyn JitCodeCache>>,
 }
 
 impl NativeJitEngine {
@@ -31,9 +35,20 @@ impl NativeJitEngine {
             builtin_table,
             memory: NativeHostMemory::new(),
             options,
+            code_cache: None,
         }
     }
 
+    /// Reuse machine code across boots: [`LpvmEngine::compile_with_params`]
+    /// and compile jobs load from and store to `cache` (see
+    /// [`crate::code_cache`]). [`LpvmEngine::compile`] has no source hash to
+    /// key by and always compiles.
+    #[must_use]
+    pub fn with_code_cache(mut self, cache: Arc<dyn JitCodeCache>) -> Self {
+        self.code_cache = Some(cache);
+        self
+    }
+
     #[must_use]
     pub fn builtin_table(&self) -> &BuiltinTable {
         &self.builtin_table
@@ -91,9 +106,21 @@ impl LpvmEngine for NativeJitEngine {
         let mut opts = self.options.clone();
         opts.config = params.config.clone();
         opts.float_mode = params.float_mode;
-        let entry_info = build_entry_info(ir, meta, IsaTarget::native())?;
-        let (buffer, entry_offsets) =
-            compile_module_jit(ir, meta, &self.builtin_table, &opts, IsaTarget::native())?;
+        let isa = IsaTarget::native();
+        let entry_info = build_entry_info(ir, meta, isa)?;
+        let cache = code_cache_entry(
+            self.code_cache.as_ref(),
+            params.source_hash,
+            meta,
+            &opts,
+            isa,
+        );
+        let (buffer, entry_offsets) = match cache {
+            Some((cache, key)) => {
+                compile_module_jit_cached(ir, meta, &self.builtin_table, &opts, isa, &*cache, key)?
+            }
+            None => compile_module_jit(ir, meta, &self.builtin_table, &opts, isa)?,
+        };
         Ok(NativeJitModule {
             inner: Arc::new(NativeJitModuleInner {
                 meta: meta.clone(),
@@ -118,6 +145,7 @@ impl LpvmEngine for NativeJitEngine {
             self.options.clone(),
             params,
             IsaTarget::native(),
+            self.code_cache.as_ref(),
         )))
     }
 
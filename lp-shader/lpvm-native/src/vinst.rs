@@ -109,6 +109,8 @@ pub enum AluOp {
     Mul,
     /// High half of signed `rs1 * rs2` (RISC-V `mulh`).
     MulH,
+    /// High half of unsigned `rs1 * rs2` (RISC-V `mulhu`).
+    MulHU,
     And,
     Or,
     Xor,
@@ -128,6 +130,7 @@ impl AluOp {
             AluOp::Sub => "Sub",
             AluOp::Mul => "Mul",
             AluOp::MulH => "MulH",
+            AluOp::MulHU => "MulHU",
             AluOp::And => "And",
             AluOp::Or => "Or",
             AluOp::Xor => "Xor",
@@ -147,6 +150,7 @@ impl AluOp {
             AluOp::Sub => "-",
             AluOp::Mul => "*",
             AluOp::MulH => "*h",
+            AluOp::MulHU => "*hu",
             AluOp::And => "&",
             AluOp::Or => "|",
             AluOp::Xor => "^",
@@ -166,6 +170,7 @@ impl AluOp {
             "Sub" => Some(AluOp::Sub),
             "Mul" => Some(AluOp::Mul),
             "MulH" => Some(AluOp::MulH),
+            "MulHU" => Some(AluOp::MulHU),
             "And" => Some(AluOp::And),
             "Or" => Some(AluOp::Or),
             "Xor" => Some(AluOp::Xor),
@@ -236,15 +241,17 @@ impl AluImmOp {
 
 /// Three-operand float arithmetic: `dst = src1 OP src2`.
 ///
-/// Only the operations that are **one instruction everywhere** are here.
-/// Division is not: it is a builtin call on both float targets (M7 D4), so
-/// giving it a VInst would create a variant no backend could emit.
+/// `Add`, `Sub` and `Mul` are one instruction on every FPU target. `Div` is
+/// one instruction on RV32F (`fdiv.s`) and a builtin call on Xtensa (M7 D4);
+/// lowering emits it only where [`crate::isa::IsaTarget::inlines_f32_div_sqrt`]
+/// says so, and the Xtensa emitter rejects it.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum FAluOp {
     Add,
     Sub,
     Mul,
+    Div,
 }
 
 impl FAluOp {
@@ -253,6 +260,7 @@ impl FAluOp {
             FAluOp::Add => "FAdd",
             FAluOp::Sub => "FSub",
             FAluOp::Mul => "FMul",
+            FAluOp::Div => "FDiv",
         }
     }
 
@@ -261,6 +269,7 @@ impl FAluOp {
             FAluOp::Add => "+.f",
             FAluOp::Sub => "-.f",
             FAluOp::Mul => "*.f",
+            FAluOp::Div => "/.f",
         }
     }
 
@@ -269,6 +278,7 @@ impl FAluOp {
             "FAdd" => Some(FAluOp::Add),
             "FSub" => Some(FAluOp::Sub),
             "FMul" => Some(FAluOp::Mul),
+            "FDiv" => Some(FAluOp::Div),
             _ => None,
         }
     }
@@ -280,12 +290,16 @@ impl FAluOp {
 /// — they must stay exact on NaN and `-0.0` (`docs/design/float.md` §3). The
 /// hardware `abs.s`/`neg.s` are defined that way; the soft-float path spells
 /// the same thing as an integer mask.
+///
+/// `Sqrt` follows `FAluOp::Div`: inline on RV32F (`fsqrt.s`), a builtin call
+/// on Xtensa.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
 pub enum FAluRROp {
     Mov,
     Abs,
     Neg,
+    Sqrt,
 }
 
 impl FAluRROp {
@@ -294,6 +308,7 @@ impl FAluRROp {
             FAluRROp::Mov => "FMov",
             FAluRROp::Abs => "FAbs",
             FAluRROp::Neg => "FNeg",
+            FAluRROp::Sqrt => "FSqrt",
         }
     }
 
@@ -302,6 +317,7 @@ impl FAluRROp {
             "FMov" => Some(FAluRROp::Mov),
             "FAbs" => Some(FAluRROp::Abs),
             "FNeg" => Some(FAluRROp::Neg),
+            "FSqrt" => Some(FAluRROp::Sqrt),
             _ => None,
         }
     }
@@ -393,6 +409,24 @@ fn icmp_cond_op(cond: IcmpCond) -> &'static str {
 
 // ─── Helpers ─────────────────────────────────────────────────────────────────
 
+/// Class of operand `i` under a call's float mask, and how many operands of
+/// the same class precede it.
+#[cfg(feature = "float-f32")]
+fn bank_of(mask: u32, i: usize) -> (crate::abi::RegClass, usize) {
+    let is_float = i < 32 && mask & (1 << i) != 0;
+    let below = if i >= 32 {
+        mask
+    } else {
+        mask & ((1u32 << i) - 1)
+    };
+    let floats_before = below.count_ones() as usize;
+    if is_float {
+        (crate::abi::RegClass::Float, floats_before)
+    } else {
+        (crate::abi::RegClass::Int, i - floats_before)
+    }
+}
+
 fn vregs_csv_pool(pool: &[VReg], slice: VRegSlice) -> String {
     slice
         .vregs(pool)
@@ -546,6 +580,13 @@ pub enum VInst {
     },
     /// Compute address of LPIR stack slot.
     SlotAddr { dst: VReg, slot: u32, src_op: u16 },
+    /// Absolute address of a linked data symbol (module rodata), patched
+    /// through the same relocation as a call target.
+    SymbolAddr {
+        dst: VReg,
+        target: SymbolId,
+        src_op: u16,
+    },
     /// Word-aligned memcpy.
     MemcpyWords {
         dst_base: VReg,
@@ -569,6 +610,14 @@ pub enum VInst {
         /// shader calls (`vmctx → a1`, `sret → a0`). If false (`@texture::*`-style `[sret, …]` with
         /// no [`ImportDecl::needs_vmctx`]), arguments map sequentially from `a0`.
         caller_sret_vm_abi_swap: bool,
+        /// Bit `i` set: argument `i` is float-class and is passed in the
+        /// callee's float argument bank (RV32F `fa0`–`fa7`). Zero on every
+        /// call that crosses the boundary as words; see
+        /// [`VInst::call_arg_bank`].
+        float_args: u16,
+        /// Bit `i` set: return value `i` is float-class and arrives in the
+        /// float return bank (RV32F `fa0`/`fa1`).
+        float_rets: u8,
         src_op: u16,
     },
     /// Return from function.
@@ -691,6 +740,19 @@ pub enum VInst {
         signed: bool,
         src_op: u16,
     },
+    /// Float → integer **saturating** conversion, truncating toward zero.
+    /// **dst Int, src Float.**
+    ///
+    /// `LpirOp::FtoiSatS`/`FtoiSatU` exactly: out-of-range inputs clamp to the
+    /// destination range and NaN gives 0. Lowering emits it only where
+    /// [`crate::isa::IsaTarget::inlines_f32_ftoi_sat`] says one instruction
+    /// (plus a NaN mask) provides that; elsewhere it is a builtin call.
+    FToI {
+        dst: VReg,
+        src: VReg,
+        signed: bool,
+        src_op: u16,
+    },
 }
 
 impl VInst {
@@ -716,6 +778,7 @@ impl VInst {
             | VInst::Load16U { src_op, .. }
             | VInst::Load16S { src_op, .. }
             | VInst::SlotAddr { src_op, .. }
+            | VInst::SymbolAddr { src_op, .. }
             | VInst::MemcpyWords { src_op, .. }
             | VInst::IConst32 { src_op, .. }
             | VInst::Call { src_op, .. }
@@ -729,7 +792,8 @@ impl VInst {
             | VInst::FStore32 { src_op, .. }
             | VInst::Wfr { src_op, .. }
             | VInst::Rfr { src_op, .. }
-            | VInst::IToF { src_op, .. } => *src_op,
+            | VInst::IToF { src_op, .. }
+            | VInst::FToI { src_op, .. } => *src_op,
             VInst::Label(_, src_op) => *src_op,
         };
         unpack_src_op(raw)
@@ -751,6 +815,7 @@ impl VInst {
             | VInst::Load16U { dst, .. }
             | VInst::Load16S { dst, .. }
             | VInst::SlotAddr { dst, .. }
+            | VInst::SymbolAddr { dst, .. }
             | VInst::IConst32 { dst, .. }
             | VInst::FAluRRR { dst, .. }
             | VInst::FAluRR { dst, .. }
@@ -759,7 +824,8 @@ impl VInst {
             | VInst::FLoad32 { dst, .. }
             | VInst::Wfr { dst, .. }
             | VInst::Rfr { dst, .. }
-            | VInst::IToF { dst, .. } => f(*dst),
+            | VInst::IToF { dst, .. }
+            | VInst::FToI { dst, .. } => f(*dst),
             VInst::Store32 { .. }
             | VInst::Store8 { .. }
             | VInst::Store16 { .. }
@@ -820,7 +886,7 @@ impl VInst {
                 f(*src);
                 f(*base);
             }
-            VInst::SlotAddr { .. } => {}
+            VInst::SlotAddr { .. } | VInst::SymbolAddr { .. } => {}
             VInst::MemcpyWords {
                 dst_base, src_base, ..
             } => {
@@ -866,7 +932,8 @@ impl VInst {
             VInst::FAluRR { src, .. }
             | VInst::Wfr { src, .. }
             | VInst::Rfr { src, .. }
-            | VInst::IToF { src, .. } => f(*src),
+            | VInst::IToF { src, .. }
+            | VInst::FToI { src, .. } => f(*src),
             VInst::FLoad32 { base, .. } => f(*base),
             VInst::FStore32 { src, base, .. } => {
                 f(*src);
@@ -879,6 +946,46 @@ impl VInst {
         matches!(self, VInst::Call { .. })
     }
 
+    /// Register class of a [`VInst::Call`]'s `i`-th argument, and its index
+    /// within that class's argument bank.
+    ///
+    /// A hard-float ABI numbers the two banks independently: in
+    /// `f(int a, float b, int c)` `c` is the second integer argument (`a1`),
+    /// not the third. Every ABI query about a call operand (`direct_ret_reg`,
+    /// `lpir_call_arg_target`, the emitter's outgoing stack offsets) takes the
+    /// bank index this returns, never the raw position. With no float bits
+    /// set the answer is `(Int, i)`, which is every call on every target but
+    /// RV32F.
+    #[cfg(feature = "float-f32")]
+    pub fn call_arg_bank(&self, i: usize) -> (crate::abi::RegClass, usize) {
+        match self {
+            VInst::Call { float_args, .. } => bank_of(u32::from(*float_args), i),
+            _ => (crate::abi::RegClass::Int, i),
+        }
+    }
+
+    /// [`Self::call_arg_bank`] for the `i`-th return value.
+    #[cfg(feature = "float-f32")]
+    pub fn call_ret_bank(&self, i: usize) -> (crate::abi::RegClass, usize) {
+        match self {
+            VInst::Call { float_rets, .. } => bank_of(u32::from(*float_rets), i),
+            _ => (crate::abi::RegClass::Int, i),
+        }
+    }
+
+    /// Without `float-f32` no call has a float operand, and the answer is a
+    /// constant for the reason `regalloc::classes::def_class` gives.
+    #[cfg(not(feature = "float-f32"))]
+    pub fn call_arg_bank(&self, i: usize) -> (crate::abi::RegClass, usize) {
+        (crate::abi::RegClass::Int, i)
+    }
+
+    /// See the `float-f32` sibling.
+    #[cfg(not(feature = "float-f32"))]
+    pub fn call_ret_bank(&self, i: usize) -> (crate::abi::RegClass, usize) {
+        (crate::abi::RegClass::Int, i)
+    }
+
     pub fn mnemonic(&self) -> &'static str {
         match self {
             VInst::AluRRR { op, .. } => op.mnemonic(),
@@ -900,6 +1007,7 @@ impl VInst {
             VInst::Load16U { .. } => "Load16U",
             VInst::Load16S { .. } => "Load16S",
             VInst::SlotAddr { .. } => "SlotAddr",
+            VInst::SymbolAddr { .. } => "SymbolAddr",
             VInst::MemcpyWords { .. } => "MemcpyWords",
             VInst::IConst32 { .. } => "IConst32",
             VInst::Call { .. } => "Call",
@@ -916,6 +1024,8 @@ impl VInst {
             VInst::Rfr { .. } => "Rfr",
             VInst::IToF { signed: true, .. } => "IToFS",
             VInst::IToF { signed: false, .. } => "IToFU",
+            VInst::FToI { signed: true, .. } => "FToIS",
+            VInst::FToI { signed: false, .. } => "FToIU",
         }
     }
 
@@ -996,6 +1106,9 @@ impl VInst {
                 src, base, offset, ..
             } => format!("[v{}{:+}] = v{} (16)", base.0, offset, src.0),
             VInst::SlotAddr { dst, slot, .. } => format!("v{} = &slot({})", dst.0, slot),
+            VInst::SymbolAddr { dst, target, .. } => {
+                format!("v{} = &{}", dst.0, symbols.name(*target))
+            }
             VInst::MemcpyWords {
                 dst_base,
                 src_base,
@@ -1084,6 +1197,12 @@ impl VInst {
                 let kind = if *signed { "i32" } else { "u32" };
                 format!("v{} = f32(v{} as {})", dst.0, src.0, kind)
             }
+            VInst::FToI {
+                dst, src, signed, ..
+            } => {
+                let kind = if *signed { "i32" } else { "u32" };
+                format!("v{} = {}_sat(v{})", dst.0, kind, src.0)
+            }
         }
     }
 }
@@ -1096,4 +1215,19 @@ mod tests {
     fn vinst_size() {
         assert!(core::mem::size_of::<VInst>() <= 32);
     }
+
+    #[cfg(feature = "float-f32")]
+    #[test]
+    fn call_banks_number_each_class_independently() {
+        use crate::abi::RegClass::{Float, Int};
+        // f(int, float, int, float, float)
+        let mask = 0b11010;
+        let banks: Vec<_> = (0..5).map(|i| bank_of(mask, i)).collect();
+        assert_eq!(
+            banks,
+            [(Int, 0), (Float, 0), (Int, 1), (Float, 1), (Float, 2)]
+        );
+        // No float bits: the raw position, so every word-ABI call is unchanged.
+        assert_eq!(bank_of(0, 7), (Int, 7));
+    }
 }
@@ -150,6 +150,7 @@ fn build_intra_module_call() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+            rodata: Vec::new(),
         },
         sig_of(vec![int_sig("g"), int_sig("f")]),
     )
@@ -203,6 +204,7 @@ fn build_deep_call_chain() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions,
+            rodata: Vec::new(),
         },
         sig_of(sigs),
     )
@@ -251,6 +253,7 @@ fn build_branch_both_directions() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![int_sig("f")]),
     )
@@ -287,6 +290,7 @@ fn build_imm_materialize() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![int_sig("f")]),
     )
@@ -342,6 +346,7 @@ fn build_sret_vec4() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![vec4_ret_sig("f")]),
     )
@@ -383,6 +388,7 @@ fn build_q32_square() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![int_sig("f")]),
     )
@@ -422,6 +428,7 @@ fn build_builtin_sin() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![import],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![int_sig("f")]),
     )
@@ -724,6 +731,7 @@ fn build_f32_arith() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![float2_sig("f", LpsType::Float)]),
     )
@@ -790,6 +798,7 @@ fn build_f32_compare_mask() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![float2_sig("f", LpsType::Int)]),
     )
@@ -844,6 +853,7 @@ fn build_f32_call_boundary() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+            rodata: Vec::new(),
         },
         sig_of(vec![
             float2_sig("g", LpsType::Float),
@@ -886,6 +896,7 @@ fn build_f32_builtin_floor() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![float1_sig("f")]),
     )
@@ -950,6 +961,7 @@ fn build_f32_spill_pressure() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), fb.finish())]),
+            rodata: Vec::new(),
         },
         sig_of(vec![float1_sig("f")]),
     )
@@ -1073,6 +1085,7 @@ fn build_f32_recursion() -> (LpirModule, LpsModuleSig) {
         LpirModule {
             imports: vec![],
             functions: VecMap::from([(FuncId(0), rec), (FuncId(1), f)]),
+            rodata: Vec::new(),
         },
         sig_of(vec![recursion_sig("rec"), recursion_sig("f")]),
     )
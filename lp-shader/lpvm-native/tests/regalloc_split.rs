@@ -0,0 +1,397 @@
+//! Differential tests for `RegAllocStrategy::Split`: the same LPIR compiled
+//! with the walk and split allocators, run on `lp-xt-emu`, must return the
+//! same (reference-checked) value — and on loop-heavy code the split
+//! allocator must actually spill less.
+//!
+//! `verify` already checks the split output structurally in debug builds;
+//! this is the semantic check, through the real emitter and the
+//! silicon-verified emulator. Xtensa is the target because its 12-register
+//! pool is the tighter one, so these functions spill under both strategies.
+
+#![cfg(feature = "regalloc-split")]
+
+use lp_collection::VecMap;
+use lpir::builder::FunctionBuilder;
+use lpir::{FloatMode, FuncId, IrType, LpirModule, LpirOp, VReg};
+use lps_shared::{FnParam, LpsFnKind, LpsFnSig, LpsModuleSig, LpsType, ParamQualifier};
+use lpvm_native::compile::{CompiledModule, compile_module};
+use lpvm_native::isa::IsaTarget;
+use lpvm_native::native_options::NativeCompileOptions;
+use lpvm_native::regalloc::{AllocStats, RegAllocStrategy};
+
+use lp_xt_emu::{Emulator, RunOutcome};
+
+/// Invariants the loop functions keep live across their loops — more than the
+/// Xtensa pool holds alongside the loop's own temporaries.
+const INVARIANTS: i32 = 10;
+
+/// What one strategy produced for one function.
+struct Run {
+    result: u32,
+    stats: AllocStats,
+    instructions: u64,
+}
+
+/// Compile `ir` for Xtensa with `strategy`, link every function at the
+/// emulator's I-bus base, and run `entry` with `args` (arg 0 is vmctx).
+fn compile_link_run(
+    ir: &LpirModule,
+    sig: &LpsModuleSig,
+    strategy: RegAllocStrategy,
+    entry: &str,
+    args: &[u32],
+) -> Run {
+    let opts = NativeCompileOptions {
+        float_mode: FloatMode::Q32,
+        fuel: false,
+        regalloc: strategy,
+        ..Default::default()
+    };
+    let module = compile_module(ir, sig, FloatMode::Q32, opts, IsaTarget::Xtensa)
+        .expect("xt compile should succeed");
+    let stats = module_stats(&module);
+
+    let mut code = Vec::new();
+    let mut entries = VecMap::<String, usize>::new();
+    let mut func_offsets = Vec::new();
+    for f in &module.functions {
+        func_offsets.push(code.len());
+        entries.insert(f.name.clone(), code.len());
+        code.extend_from_slice(&f.code);
+    }
+    let mut emu = Emulator::new();
+    let ibus_base = emu.profile.code_ibus_base();
+    for (fi, f) in module.functions.iter().enumerate() {
+        for reloc in &f.relocs {
+            let target_off = *entries
+                .get(&reloc.symbol)
+                .unwrap_or_else(|| panic!("unresolved symbol {}", reloc.symbol));
+            let slot = func_offsets[fi] + reloc.offset;
+            code[slot..slot + 4].copy_from_slice(&(ibus_base + target_off as u32).to_le_bytes());
+        }
+    }
+
+    let entry_off = *entries.get(entry).expect("entry function exists") as u32;
+    let result = match emu.run_with_args(&code, entry_off, args) {
+        RunOutcome::Ok(v) => v,
+        RunOutcome::Trap(t) => panic!("{strategy:?}: unexpected trap: {t:?}"),
+    };
+    Run {
+        result,
+        stats,
+        instructions: emu.get_instruction_count(),
+    }
+}
+
+fn module_stats(module: &CompiledModule) -> AllocStats {
+    let mut total = AllocStats::default();
+    for f in &module.functions {
+        total.add(f.alloc_stats);
+    }
+    total
+}
+
+/// Run `ir` under both strategies; assert they agree with each other and with
+/// `want`, and return (walk, split).
+fn run_both(ir: &LpirModule, sig: &LpsModuleSig, args: &[u32], want: i32) -> (Run, Run) {
+    let walk = compile_link_run(ir, sig, RegAllocStrategy::Walk, "f", args);
+    let split = compile_link_run(ir, sig, RegAllocStrategy::Split, "f", args);
+    assert_eq!(walk.result as i32, want, "walk result, args={args:?}");
+    assert_eq!(split.result as i32, want, "split result, args={args:?}");
+    (walk, split)
+}
+
+fn int_sig(name: &str, params: usize) -> LpsFnSig {
+    LpsFnSig {
+        name: name.to_string(),
+        parameters: (0..params)
+            .map(|i| FnParam {
+                name: format!("p{i}"),
+                ty: LpsType::Int,
+                qualifier: ParamQualifier::In,
+            })
+            .collect(),
+        return_type: LpsType::Int,
+        kind: LpsFnKind::UserDefined,
+    }
+}
+
+fn module_of(funcs: Vec<(lpir::IrFunction, LpsFnSig)>) -> (LpirModule, LpsModuleSig) {
+    let mut functions = VecMap::new();
+    let mut sigs = Vec::new();
+    for (i, (f, s)) in funcs.into_iter().enumerate() {
+        functions.insert(FuncId(i as u16), f);
+        sigs.push(s);
+    }
+    let module = LpirModule {
+        imports: vec![],
+        functions,
+        rodata: Vec::new(),
+    };
+    let sig = LpsModuleSig {
+        functions: sigs,
+        uniforms_type: None,
+        globals_type: None,
+        ..Default::default()
+    };
+    (module, sig)
+}
+
+/// `k_j = x + 7j` for each invariant, defined ahead of any loop.
+fn push_invariants(fb: &mut FunctionBuilder, x: VReg) -> Vec<VReg> {
+    (0..INVARIANTS)
+        .map(|j| {
+            let k = fb.alloc_vreg(IrType::I32);
+            fb.push(LpirOp::IaddImm {
+                dst: k,
+                src: x,
+                imm: 7 * j,
+            });
+            k
+        })
+        .collect()
+}
+
+/// `loop { if i >= n break; body; i += 1 }`.
+fn push_counted_loop(
+    fb: &mut FunctionBuilder,
+    n: VReg,
+    body: impl FnOnce(&mut FunctionBuilder, VReg),
+) {
+    let i = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IconstI32 { dst: i, value: 0 });
+    fb.push_loop();
+    let done = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IgeS {
+        dst: done,
+        lhs: i,
+        rhs: n,
+    });
+    fb.push_if(done);
+    fb.push(LpirOp::Break);
+    fb.end_if();
+    body(fb, i);
+    fb.push(LpirOp::IaddImm {
+        dst: i,
+        src: i,
+        imm: 1,
+    });
+    fb.end_loop();
+}
+
+/// `acc += k_j ^ i` for every invariant, then a data-dependent if/else on
+/// `i & 1` that reads two more of them.
+fn push_mix(fb: &mut FunctionBuilder, acc: VReg, ks: &[VReg], i: VReg) {
+    for &k in ks {
+        let t = fb.alloc_vreg(IrType::I32);
+        fb.push(LpirOp::Ixor {
+            dst: t,
+            lhs: k,
+            rhs: i,
+        });
+        fb.push(LpirOp::Iadd {
+            dst: acc,
+            lhs: acc,
+            rhs: t,
+        });
+    }
+    let one = fb.alloc_vreg(IrType::I32);
+    let odd = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IconstI32 { dst: one, value: 1 });
+    fb.push(LpirOp::Iand {
+        dst: odd,
+        lhs: i,
+        rhs: one,
+    });
+    fb.push_if(odd);
+    fb.push(LpirOp::Iadd {
+        dst: acc,
+        lhs: acc,
+        rhs: ks[0],
+    });
+    fb.push_else();
+    fb.push(LpirOp::Isub {
+        dst: acc,
+        lhs: acc,
+        rhs: ks[1],
+    });
+    fb.end_if();
+}
+
+fn mix_reference(acc: &mut i32, x: i32, i: i32) {
+    for j in 0..INVARIANTS {
+        *acc = acc.wrapping_add((x + 7 * j) ^ i);
+    }
+    if i & 1 != 0 {
+        *acc = acc.wrapping_add(x);
+    } else {
+        *acc = acc.wrapping_sub(x + 7);
+    }
+}
+
+/// `f(x, n)`: one counted loop of `push_mix` over ten invariants.
+fn single_loop_module() -> (LpirModule, LpsModuleSig) {
+    let mut fb = FunctionBuilder::new("f", &[IrType::I32]);
+    let x = fb.add_param(IrType::I32);
+    let n = fb.add_param(IrType::I32);
+    let ks = push_invariants(&mut fb, x);
+    let acc = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IconstI32 { dst: acc, value: 0 });
+    push_counted_loop(&mut fb, n, |fb, i| push_mix(fb, acc, &ks, i));
+    fb.push_return(&[acc]);
+    module_of(vec![(fb.finish(), int_sig("f", 2))])
+}
+
+/// `f(x, n)`: `push_mix` inside two nested counted loops of `n` each, the
+/// inner one indexed by `i * n + j`.
+fn nested_loop_module() -> (LpirModule, LpsModuleSig) {
+    let mut fb = FunctionBuilder::new("f", &[IrType::I32]);
+    let x = fb.add_param(IrType::I32);
+    let n = fb.add_param(IrType::I32);
+    let ks = push_invariants(&mut fb, x);
+    let acc = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IconstI32 { dst: acc, value: 0 });
+    push_counted_loop(&mut fb, n, |fb, i| {
+        let base = fb.alloc_vreg(IrType::I32);
+        fb.push(LpirOp::Imul {
+            dst: base,
+            lhs: i,
+            rhs: n,
+        });
+        push_counted_loop(fb, n, |fb, j| {
+            let ij = fb.alloc_vreg(IrType::I32);
+            fb.push(LpirOp::Iadd {
+                dst: ij,
+                lhs: base,
+                rhs: j,
+            });
+            push_mix(fb, acc, &ks, ij);
+        });
+    });
+    fb.push_return(&[acc]);
+    module_of(vec![(fb.finish(), int_sig("f", 2))])
+}
+
+/// `g(a) = 3a + 1`; `f(x, n)` calls it once per iteration of the
+/// `push_mix` loop, so invariants must survive a call inside the loop.
+fn loop_with_call_module() -> (LpirModule, LpsModuleSig) {
+    let mut gb = FunctionBuilder::new("g", &[IrType::I32]);
+    let a = gb.add_param(IrType::I32);
+    let three = gb.alloc_vreg(IrType::I32);
+    gb.push(LpirOp::IconstI32 {
+        dst: three,
+        value: 3,
+    });
+    gb.push(LpirOp::Imul {
+        dst: a,
+        lhs: a,
+        rhs: three,
+    });
+    gb.push(LpirOp::IaddImm {
+        dst: a,
+        src: a,
+        imm: 1,
+    });
+    gb.push_return(&[a]);
+    let g = gb.finish();
+
+    let mut fb = FunctionBuilder::new("f", &[IrType::I32]);
+    let x = fb.add_param(IrType::I32);
+    let n = fb.add_param(IrType::I32);
+    let ks = push_invariants(&mut fb, x);
+    let acc = fb.alloc_vreg(IrType::I32);
+    fb.push(LpirOp::IconstI32 { dst: acc, value: 0 });
+    push_counted_loop(&mut fb, n, |fb, i| {
+        push_mix(fb, acc, &ks, i);
+        let r = fb.alloc_vreg(IrType::I32);
+        fb.push_call(
+            lpir::CalleeRef::Local(FuncId(0)),
+            &[lpir::VMCTX_VREG, i],
+            &[r],
+        );
+        fb.push(LpirOp::Iadd {
+            dst: acc,
+            lhs: acc,
+            rhs: r,
+        });
+    });
+    fb.push_return(&[acc]);
+    module_of(vec![(g, int_sig("g", 1)), (fb.finish(), int_sig("f", 2))])
+}
+
+#[rstest::rstest]
+#[case(3, 0)]
+#[case(3, 1)]
+#[case(-5, 7)]
+#[case(1000, 16)]
+fn single_loop_agrees(#[case] x: i32, #[case] n: i32) {
+    let (ir, sig) = single_loop_module();
+    let mut want = 0i32;
+    for i in 0..n {
+        mix_reference(&mut want, x, i);
+    }
+    run_both(&ir, &sig, &[0, x as u32, n as u32], want);
+}
+
+#[rstest::rstest]
+#[case(3, 0)]
+#[case(3, 1)]
+#[case(11, 5)]
+fn nested_loops_agree(#[case] x: i32, #[case] n: i32) {
+    let (ir, sig) = nested_loop_module();
+    let mut want = 0i32;
+    for i in 0..n {
+        for j in 0..n {
+            mix_reference(&mut want, x, i * n + j);
+        }
+    }
+    run_both(&ir, &sig, &[0, x as u32, n as u32], want);
+}
+
+#[rstest::rstest]
+#[case(3, 0)]
+#[case(9, 6)]
+fn loop_with_call_agrees(#[case] x: i32, #[case] n: i32) {
+    let (ir, sig) = loop_with_call_module();
+    let mut want = 0i32;
+    for i in 0..n {
+        mix_reference(&mut want, x, i);
+        want = want.wrapping_add(3 * i + 1);
+    }
+    run_both(&ir, &sig, &[0, x as u32, n as u32], want);
+}
+
+/// The point of the strategy: with more invariants than the pool holds,
+/// the walk reloads them every iteration; split pins what fits and evicts
+/// the out-of-loop values first. Fewer executed instructions on a long run,
+/// and never more reloads in the code.
+#[test]
+fn split_executes_fewer_instructions_on_loops() {
+    for (name, (ir, sig)) in [
+        ("single", single_loop_module()),
+        ("nested", nested_loop_module()),
+        ("call", loop_with_call_module()),
+    ] {
+        let (walk, split) = run_both_unchecked(&ir, &sig, &[0, 5, 12]);
+        assert_eq!(walk.result, split.result, "{name}: results differ");
+        assert!(
+            split.instructions < walk.instructions,
+            "{name}: split ran {} instructions, walk {}",
+            split.instructions,
+            walk.instructions
+        );
+        assert!(
+            split.stats.spill_reloads <= walk.stats.spill_reloads,
+            "{name}: split {:?}, walk {:?}",
+            split.stats,
+            walk.stats
+        );
+    }
+}
+
+fn run_both_unchecked(ir: &LpirModule, sig: &LpsModuleSig, args: &[u32]) -> (Run, Run) {
+    (
+        compile_link_run(ir, sig, RegAllocStrategy::Walk, "f", args),
+        compile_link_run(ir, sig, RegAllocStrategy::Split, "f", args),
+    )
+}
@@ -128,8 +128,13 @@ fn measure(glsl: &str) -> Result<(u32, usize), String> {
     // emitted 2,244 B fuel-off but the classic reported 2,444 B for the same
     // shader). Sizing a region from a fuel-off number would build that error
     // straight into the safety factor.
+    //
+    // `inline_q32_fdiv: false` is the classic's own default (its firmware
+    // builds this crate with `xt-placed-code`); a host build inlines the
+    // divide and would measure the S3's code, not the classic's.
     let opts = NativeCompileOptions {
         float_mode: FloatMode::Q32,
+        inline_q32_fdiv: false,
         ..Default::default()
     };
     let compiled = compile_module(&ir, &meta, FloatMode::Q32, opts, IsaTarget::Xtensa)
@@ -98,6 +98,7 @@ fn call_module() -> (LpirModule, LpsModuleSig) {
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let int_sig = |name: &str| LpsFnSig {
         name: name.to_string(),
@@ -90,6 +90,7 @@ fn unary_float_module(
     let module = LpirModule {
         imports,
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![LpsFnSig {
@@ -195,6 +196,7 @@ fn arithmetic_without_builtins_matches_rv32() {
     let ir = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![LpsFnSig {
@@ -7,16 +7,17 @@
 
 use lp_collection::VecMap;
 use lpir::builder::FunctionBuilder;
-use lpir::{FloatMode, FuncId, IrType, LpirModule, LpirOp};
+use lpir::{FloatMode, FuncId, IrType, LpirModule, LpirOp, RodataWord};
 use lps_shared::{FnParam, LpsFnKind, LpsFnSig, LpsModuleSig, LpsType, ParamQualifier};
-use lpvm_native::compile::compile_module;
+use lpvm_native::compile::{RODATA_SYMBOL, compile_module};
 use lpvm_native::isa::IsaTarget;
 use lpvm_native::native_options::NativeCompileOptions;
 
 use lp_xt_emu::{Emulator, RunOutcome};
 
 /// Compile `(ir, sig)` for Xtensa, link all functions at the emulator's
-/// I-bus code base (patching literal-slot call relocations), and run
+/// I-bus code base (patching literal-slot call relocations) with the module's
+/// rodata after them, read through the region's D-bus view, and run
 /// `entry_name` with `args` (arg 0 is the vmctx word — pass 0 when the
 /// module needs none). Returns the emulator outcome.
 fn compile_link_run(
@@ -44,6 +45,11 @@ fn compile_link_run(
         entries.insert(f.name.clone(), code.len());
         code.extend_from_slice(&f.code);
     }
+    code.resize(code.len().next_multiple_of(4), 0);
+    let rodata_off = code.len();
+    for word in &module.rodata {
+        code.extend_from_slice(&word.to_le_bytes());
+    }
     let mut emu = Emulator::new();
     let ibus_base = emu.profile.code_ibus_base();
     for (fi, f) in module.functions.iter().enumerate() {
@@ -53,10 +59,14 @@ fn compile_link_run(
                 IsaTarget::Xtensa.call_reloc_type(),
                 "unexpected reloc type"
             );
-            let target_off = *entries
-                .get(&reloc.symbol)
-                .unwrap_or_else(|| panic!("unresolved symbol {}", reloc.symbol));
-            let target = ibus_base + target_off as u32;
+            let target = if reloc.symbol == RODATA_SYMBOL {
+                emu.profile.code_dbus_base + rodata_off as u32
+            } else {
+                let target_off = *entries
+                    .get(&reloc.symbol)
+                    .unwrap_or_else(|| panic!("unresolved symbol {}", reloc.symbol));
+                ibus_base + target_off as u32
+            };
             let slot = func_offsets[fi] + reloc.offset;
             code[slot..slot + 4].copy_from_slice(&target.to_le_bytes());
         }
@@ -84,6 +94,7 @@ fn unary_module(
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![LpsFnSig {
@@ -125,6 +136,7 @@ fn binary_module(
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![LpsFnSig {
@@ -208,6 +220,42 @@ fn returns_a_constant_through_entry_retw() {
     assert_eq!(r, 42);
 }
 
+#[test]
+fn rodata_addr_loads_the_module_rodata() {
+    let (mut ir, sig) = unary_module(|fb, x| {
+        let base = fb.alloc_vreg(IrType::Pointer);
+        fb.push(LpirOp::RodataAddr { dst: base });
+        let off = fb.alloc_vreg(IrType::I32);
+        fb.push(LpirOp::IshlImm {
+            dst: off,
+            src: x,
+            imm: 2,
+        });
+        let addr = fb.alloc_vreg(IrType::Pointer);
+        fb.push(LpirOp::Iadd {
+            dst: addr,
+            lhs: base,
+            rhs: off,
+        });
+        let v = fb.alloc_vreg(IrType::I32);
+        fb.push(LpirOp::Load {
+            dst: v,
+            base: addr,
+            offset: 0,
+        });
+        fb.push_return(&[v]);
+    });
+    ir.rodata = vec![
+        RodataWord::I32(11),
+        RodataWord::I32(-22),
+        RodataWord::I32(33),
+    ];
+    for (x, want) in [(0, 11), (1, -22), (2, 33)] {
+        let got = expect_ok(compile_link_run(&ir, &sig, "f", &[0, x as u32], false)) as i32;
+        assert_eq!(got, want, "rodata word {x}");
+    }
+}
+
 #[test]
 fn addi_in_range_uses_the_imm_form_correctly() {
     let r = run_unary(
@@ -571,6 +619,108 @@ fn redefined_divisor_keeps_the_guard() {
     );
 }
 
+/// `a / b` as a Q32 `Fdiv` with both operands opaque, compiled once and run
+/// for every `(a, b)` pair.
+fn run_q32_fdiv(pairs: &[(i32, i32)]) -> Vec<i32> {
+    let mut fb = FunctionBuilder::new("f", &[IrType::F32]);
+    let a = fb.add_param(IrType::F32);
+    let b = fb.add_param(IrType::F32);
+    let d = fb.alloc_vreg(IrType::F32);
+    fb.push(LpirOp::Fdiv {
+        dst: d,
+        lhs: a,
+        rhs: b,
+    });
+    fb.push_return(&[d]);
+    let ir = LpirModule {
+        imports: vec![],
+        functions: VecMap::from([(FuncId(0), fb.finish())]),
+        rodata: Vec::new(),
+    };
+    let param = |name: &str| FnParam {
+        name: name.to_string(),
+        ty: LpsType::Float,
+        qualifier: ParamQualifier::In,
+    };
+    let sig = LpsModuleSig {
+        functions: vec![LpsFnSig {
+            name: "f".to_string(),
+            parameters: vec![param("a"), param("b")],
+            return_type: LpsType::Float,
+            kind: LpsFnKind::UserDefined,
+        }],
+        uniforms_type: None,
+        globals_type: None,
+        ..Default::default()
+    };
+    pairs
+        .iter()
+        .map(|&(a, b)| {
+            expect_ok(compile_link_run(
+                &ir,
+                &sig,
+                "f",
+                &[0, a as u32, b as u32],
+                false,
+            )) as i32
+        })
+        .collect()
+}
+
+/// Q32 `Fdiv` is lowered inline (`quou` reciprocal, `mull`/`muluh` product)
+/// instead of calling `__lp_lpir_fdiv_recip_q32`; it must stay bit-exact with
+/// that helper, including the divisor == 0 saturation and `i32::MIN`, whose
+/// magnitude only fits unsigned.
+#[test]
+fn q32_fdiv_inline_is_bit_exact_with_the_recip_builtin() {
+    use lps_builtins::builtins::lpir::fdiv_recip_q32::__lp_lpir_fdiv_recip_q32;
+
+    const EDGES: [i32; 16] = [
+        0,
+        1,
+        -1,
+        2,
+        0x8000,
+        0x1_0000,
+        -0x1_0000,
+        0x1_8000,
+        0x7F_FFFF,
+        0x100_0000,
+        -0x123_4567,
+        0x7FFF_0000,
+        i32::MAX,
+        i32::MIN,
+        i32::MIN + 1,
+        -0x5555_5555,
+    ];
+    let mut pairs: Vec<(i32, i32)> = EDGES
+        .iter()
+        .flat_map(|&a| EDGES.iter().map(move |&b| (a, b)))
+        .collect();
+    // Plus a deterministic spread of ordinary magnitudes.
+    let mut state = 0x2545_F491u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as i32
+    };
+    for _ in 0..256 {
+        let a = next() >> (next() as u32 % 24);
+        let b = next() >> (next() as u32 % 24);
+        pairs.push((a, b));
+    }
+
+    let got = run_q32_fdiv(&pairs);
+    for (&(a, b), &g) in pairs.iter().zip(&got) {
+        assert_eq!(
+            g,
+            __lp_lpir_fdiv_recip_q32(a, b),
+            "{a:#x} / {b:#x}: inline {g:#x}"
+        );
+    }
+}
+
 #[test]
 fn shifts_signed_and_unsigned() {
     let r = run_unary(
@@ -680,6 +830,68 @@ fn if_else_branches_both_directions() {
     }
 }
 
+#[test]
+fn switch_picks_the_matching_case_or_the_default() {
+    // switch x { case 1: 10; default: 99; case -7: 70; case 300000: 3 }.
+    // The default is not the last arm, and one label needs a materialized
+    // immediate.
+    for (x, want) in [(1, 10), (-7, 70), (300_000, 3), (0, 99), (2, 99)] {
+        let r = run_unary(
+            |fb, x| {
+                let out = fb.alloc_vreg(IrType::I32);
+                fb.push(LpirOp::IconstI32 { dst: out, value: 0 });
+                fb.push_switch(x);
+                for (label, value) in [
+                    (Some(1), 10),
+                    (None, 99),
+                    (Some(-7), 70),
+                    (Some(300_000), 3),
+                ] {
+                    match label {
+                        Some(label) => fb.push_case(label),
+                        None => fb.push_default(),
+                    }
+                    fb.push(LpirOp::IconstI32 { dst: out, value });
+                    fb.end_switch_arm();
+                }
+                fb.end_switch();
+                fb.push_return(&[out]);
+            },
+            x,
+        );
+        assert_eq!(r, want, "x={x}");
+    }
+}
+
+#[test]
+fn switch_without_default_falls_out_and_arms_may_return() {
+    // out = 5; switch x { case 0: return 40; case 1: out = 6 } return out;
+    for (x, want) in [(0, 40), (1, 6), (2, 5)] {
+        let r = run_unary(
+            |fb, x| {
+                let out = fb.alloc_vreg(IrType::I32);
+                let early = fb.alloc_vreg(IrType::I32);
+                fb.push(LpirOp::IconstI32 { dst: out, value: 5 });
+                fb.push_switch(x);
+                fb.push_case(0);
+                fb.push(LpirOp::IconstI32 {
+                    dst: early,
+                    value: 40,
+                });
+                fb.push_return(&[early]);
+                fb.end_switch_arm();
+                fb.push_case(1);
+                fb.push(LpirOp::IconstI32 { dst: out, value: 6 });
+                fb.end_switch_arm();
+                fb.end_switch();
+                fb.push_return(&[out]);
+            },
+            x,
+        );
+        assert_eq!(r, want, "x={x}");
+    }
+}
+
 #[test]
 fn spill_pressure_beyond_the_12_reg_pool() {
     // 20 live values forces spills (pool = 12): v_i = x + i, all kept live,
@@ -755,6 +967,7 @@ fn cross_function_call_via_literal_slot_callx8() {
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let int_sig = |name: &str| LpsFnSig {
         name: name.to_string(),
@@ -865,6 +1078,7 @@ fn multi_arg_call_passes_every_argument(#[case] n: usize) {
     let module = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let sig_of = |name: &str, np: usize| LpsFnSig {
         name: name.to_string(),
@@ -896,3 +1110,44 @@ fn multi_arg_call_passes_every_argument(#[case] n: usize) {
 fn alloc_name(i: usize) -> String {
     format!("p{i}")
 }
+
+/// Compile GLSL through the real frontend and the default opt pipeline, then
+/// run the int function `name` with `args`.
+fn run_glsl(src: &str, name: &str, args: &[i32]) -> i32 {
+    let out = lps_glsl::compile(src, &Default::default())
+        .unwrap_or_else(|e| panic!("frontend: {}", e.render(src)));
+    let mut call_args = vec![0u32];
+    call_args.extend(args.iter().map(|&a| a as u32));
+    expect_ok(compile_link_run(
+        &out.ir, &out.meta, name, &call_args, false,
+    )) as i32
+}
+
+#[test]
+fn int_reassigned_in_an_if_arm_is_not_folded_after_the_merge() {
+    // `r` is a copy of `0` before the `if` and of `4` inside it; after
+    // constant folding both are `iconst` defs of one vreg, and only the
+    // path taken may decide which reaches `r + x`.
+    let src = "int f(int x) { int r = 0; if (x == 3) { r = 4; } return r + x; }";
+    for (x, want) in [(0, 0), (3, 7), (5, 5)] {
+        assert_eq!(run_glsl(src, "f", &[x]), want, "f({x})");
+    }
+}
+
+#[test]
+fn switch_assigned_int_and_selector_stay_live_after_a_no_default_switch() {
+    let src = "
+        int direct(int x) { int r = 0; switch (x) { case 3: r = 4; } return r + x; }
+        int fall(int x) {
+            int r = 0;
+            switch (x) { case 1: r += 2; case 2: r += 3; }
+            return r * 10 + x;
+        }
+    ";
+    for (x, want) in [(0, 0), (3, 7), (5, 5)] {
+        assert_eq!(run_glsl(src, "direct", &[x]), want, "direct({x})");
+    }
+    for (x, want) in [(1, 51), (2, 32), (7, 7)] {
+        assert_eq!(run_glsl(src, "fall", &[x]), want, "fall({x})");
+    }
+}
@@ -376,6 +376,7 @@ fn one_function_module(func: lpir::IrFunction) -> LpirModule {
     LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), func)]),
+        rodata: Vec::new(),
     }
 }
 
@@ -669,6 +670,7 @@ fn floats_cross_a_guest_call_in_address_registers() {
     let ir = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), g), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![
@@ -755,6 +757,7 @@ fn a_reassigned_float_parameter_is_read_back_from_the_float_file() {
     let ir = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), bump), (FuncId(1), forward)]),
+        rodata: Vec::new(),
     };
     let sig = LpsModuleSig {
         functions: vec![
@@ -1053,6 +1056,7 @@ fn float_recursion_at_depth_100_preserves_every_live_value() {
     let ir = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), rec), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let params = || vec![int_param("n"), float_param("a"), float_param("b")];
     let sig = LpsModuleSig {
@@ -1196,6 +1200,7 @@ fn integer_recursion_at_depth_100_is_the_control() {
     let ir = LpirModule {
         imports: vec![],
         functions: VecMap::from([(FuncId(0), rec), (FuncId(1), f)]),
+        rodata: Vec::new(),
     };
     let params = || vec![int_param("n"), int_param("a"), int_param("b")];
     let sig = LpsModuleSig {
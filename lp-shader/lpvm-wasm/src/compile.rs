@@ -2,7 +2,7 @@
 
 use alloc::{format, string::String, vec::Vec};
 
-use lpir::LpirModule;
+use lpir::{FloatMode, LpirModule, PassPipeline};
 use lps_shared::LpsModuleSig;
 
 use crate::emit;
@@ -43,6 +43,20 @@ pub fn compile_lpir(
     options: &WasmOptions,
 ) -> Result<WasmArtifact, WasmError> {
     validate_metadata(ir, meta)?;
+    // Inlining and the pass pipeline leave every function (and its
+    // signature) in place, so the metadata check above holds for the
+    // optimized copy too.
+    let pipeline = PassPipeline::new(&options.config.opt, options.float_mode);
+    let optimized;
+    let ir = if lpir::wants_inlining(ir, &options.config.inline) || !pipeline.is_empty() {
+        let mut module = ir.clone();
+        lpir::inline_module(&mut module, &options.config.inline);
+        pipeline.run_module(&mut module);
+        optimized = module;
+        &optimized
+    } else {
+        ir
+    };
     let export_names = export_fn_names(meta);
     let (wasm_bytes, shadow_stack_base, env_memory) =
         emit::emit_module(ir, &export_names, options).map_err(WasmError::emit)?;
@@ -55,11 +69,20 @@ pub fn compile_lpir(
             exports,
             shadow_stack_base,
             env_memory,
+            rodata: rodata_bytes(ir, options.float_mode),
         },
         signatures: meta.clone(),
     })
 }
 
+/// Encode float rodata words the same way [`lpir::LpirOp::FconstF32`] is emitted.
+fn rodata_bytes(ir: &LpirModule, float_mode: FloatMode) -> Vec<u8> {
+    match float_mode {
+        FloatMode::Q32 => ir.rodata_bytes(|f| emit::q32::f32_to_q16_16(f) as u32),
+        FloatMode::F32 => ir.rodata_bytes(f32::to_bits),
+    }
+}
+
 fn count_wasm_insts(wasm_bytes: &[u8]) -> Result<usize, WasmError> {
     let mut inst_count = 0usize;
     for payload in wasmparser::Parser::new(0).parse_all(wasm_bytes) {
@@ -500,6 +500,54 @@ pub(super) fn wasm_import_val_types(builtin: BuiltinId) -> (Vec<ValType>, Vec<Va
             ],
             Vec::new(),
         ),
+        BuiltinId::LpTexTexture1dR8UnormF32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::F32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture1dR8UnormQ32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture1dRgb8UnormF32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::F32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture1dRgb8UnormQ32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
         BuiltinId::LpTexTexture1dRgba16UnormF32 => (
             vec![
                 ValType::I32,
@@ -554,6 +602,66 @@ pub(super) fn wasm_import_val_types(builtin: BuiltinId) -> (Vec<ValType>, Vec<Va
             ],
             Vec::new(),
         ),
+        BuiltinId::LpTexTexture2dR8UnormF32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::F32,
+                ValType::F32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture2dR8UnormQ32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture2dRgb8UnormF32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::F32,
+                ValType::F32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
+        BuiltinId::LpTexTexture2dRgb8UnormQ32 => (
+            vec![
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+                ValType::I32,
+            ],
+            Vec::new(),
+        ),
         BuiltinId::LpTexTexture2dRgba16UnormF32 => (
             vec![
                 ValType::I32,
@@ -7,7 +7,7 @@ pub(crate) mod func;
 mod imports;
 mod memory;
 mod ops;
-mod q32;
+pub(crate) mod q32;
 
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -48,6 +48,8 @@ pub(crate) struct FuncEmitCtx<'a> {
     pub vmctx_local: Option<u32>,
     pub i64_scratch: Option<u32>,
     pub sp_global: Option<u32>,
+    /// Global holding the guest address of this instance's copy of the module rodata.
+    pub rodata_global: Option<u32>,
     pub frame_size: u32,
     /// Slot offsets for memory operations. Stored as Vec for ownership.
     pub slot_offsets: alloc::vec::Vec<u32>,
@@ -107,6 +109,7 @@ pub(crate) fn emit_module(
     let any_slots = ir.functions.values().any(|f| !f.slots.is_empty());
     let needs_result_ptr_calls = imports::module_needs_result_ptr_calls(ir, options.float_mode);
     let needs_shadow_stack = any_slots || needs_result_ptr_calls;
+    let needs_rodata = !ir.rodata.is_empty();
     let mut import_section = ImportSection::new();
     // Fuel checks load/store the vmctx header in linear memory at every
     // function entry, so a fuel-enabled module always needs `env.memory`.
@@ -155,6 +158,11 @@ pub(crate) fn emit_module(
             0,
         );
     }
+    // Globals are numbered in definition order: `$sp` first when present.
+    let rodata_global = needs_rodata.then_some(u32::from(needs_shadow_stack));
+    if let Some(idx) = rodata_global {
+        exports.export(crate::module::RODATA_GLOBAL_EXPORT, ExportKind::Global, idx);
+    }
     for (i, name) in export_names.iter().enumerate() {
         let wasm_fn_index = filtered_fn_count + i as u32;
         exports.export(name.as_str(), ExportKind::Func, wasm_fn_index);
@@ -170,7 +178,7 @@ pub(crate) fn emit_module(
         filtered_import_count: filtered_fn_count,
     };
 
-    // $sp is global index 0 — only valid while it's the sole WASM global.
+    // $sp is global index 0 — only valid while no global is defined before it.
     let sp_global = if needs_shadow_stack { Some(0u32) } else { None };
 
     // VMContext local index - always 0 (first local in every function)
@@ -186,6 +194,18 @@ pub(crate) fn emit_module(
             &ConstExpr::i32_const(memory::SHADOW_STACK_BASE),
         );
     }
+    if needs_rodata {
+        // Shader modules share one linear memory, so the rodata has no fixed
+        // home: the host copies it per instance and stores the address here.
+        globals.global(
+            GlobalType {
+                val_type: ValType::I32,
+                mutable: true,
+                shared: false,
+            },
+            &ConstExpr::i32_const(0),
+        );
+    }
 
     let mut code = CodeSection::new();
     for f in ir.functions.values() {
@@ -194,6 +214,7 @@ pub(crate) fn emit_module(
             vmctx_local,
             i64_scratch: None, // Will be calculated inside encode_ir_function
             sp_global,
+            rodata_global,
             frame_size: 0, // Will be calculated inside encode_ir_function
             slot_offsets: alloc::vec::Vec::new(),
             result_buffer_base_offset: 0,
@@ -344,10 +344,13 @@ pub(crate) fn emit_op(
                 sink.br(d);
                 sink.end();
                 *wasm_open = wasm_open.saturating_sub(1);
+                // A `return` in this arm does not make the next arm unreachable.
+                fctx.unreachable_mode = false;
                 return Ok(());
             }
             if matches!(ctrl.last(), Some(CtrlEntry::SwitchDefaultArm)) {
                 ctrl.pop();
+                fctx.unreachable_mode = false;
                 return Ok(());
             }
 
@@ -373,13 +376,11 @@ pub(crate) fn emit_op(
                     sink.end();
                     *wasm_open = wasm_open.saturating_sub(2);
                 }
-                Some(CtrlEntry::Switch { .. }) => {
-                    sink.end();
-                    *wasm_open = wasm_open.saturating_sub(1);
-                }
-                Some(CtrlEntry::FwdBlock { .. }) => {
+                Some(CtrlEntry::Switch { .. }) | Some(CtrlEntry::FwdBlock { .. }) => {
                     sink.end();
                     *wasm_open = wasm_open.saturating_sub(1);
+                    // Reachable again through an arm or an `ExitBlock`.
+                    fctx.unreachable_mode = false;
                 }
                 Some(other) => {
                     ctrl.push(other);
@@ -964,6 +965,12 @@ pub(crate) fn emit_op(
                 .i32_add()
                 .local_set(dst.0);
         }
+        LpirOp::RodataAddr { dst } => {
+            let rodata = fctx
+                .rodata_global
+                .ok_or_else(|| String::from("RodataAddr without rodata global"))?;
+            sink.global_get(rodata).local_set(dst.0);
+        }
         LpirOp::Load { dst, base, offset } => {
             let m = memory::mem_arg0(*offset, 2);
             match vreg_val_ty(func, *dst, fm)? {
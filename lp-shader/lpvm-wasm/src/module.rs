@@ -11,6 +11,11 @@ pub use wasm_encoder::ValType as WasmValType;
 /// Export name for the shadow stack pointer global when the module uses slot memory.
 pub const SHADOW_STACK_GLOBAL_EXPORT: &str = "__lp_shadow_sp";
 
+/// Export name for the global holding the guest address of the module rodata.
+/// The host copies [`WasmModule::rodata`] into memory it owns per instance and
+/// sets this global before the first call.
+pub const RODATA_GLOBAL_EXPORT: &str = "__lp_rodata";
+
 /// Map a GLSL type to the sequence of WASM locals/results used in the ABI.
 pub fn glsl_type_to_wasm_components(ty: &LpsType, float_mode: FloatMode) -> Vec<WasmValType> {
     match ty {
@@ -109,6 +114,9 @@ pub struct WasmModule {
     pub shadow_stack_base: Option<i32>,
     /// When set, the module imports `env.memory`; browsers/wasmtime must supply matching limits.
     pub env_memory: Option<EnvMemorySpec>,
+    /// Module rodata encoded for the float mode; non-empty iff the module
+    /// exports [`RODATA_GLOBAL_EXPORT`].
+    pub rodata: Vec<u8>,
 }
 
 /// Metadata for an exported WASM function.
@@ -86,6 +86,7 @@ impl BrowserLpvmEngine {
             signatures: artifact.signatures().clone(),
             exports,
             shadow_stack_base: wm.shadow_stack_base,
+            rodata: wm.rodata.clone(),
             opts,
             lpir: ir.clone(),
         })
@@ -101,6 +102,7 @@ pub struct BrowserLpvmModule {
     pub(crate) signatures: LpsModuleSig,
     pub(crate) exports: HashMap<String, WasmExport>,
     pub(crate) shadow_stack_base: Option<i32>,
+    pub(crate) rodata: Vec<u8>,
     pub(crate) opts: WasmOptions,
     pub(crate) lpir: LpirModule,
 }
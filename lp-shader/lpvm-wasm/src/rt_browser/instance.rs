@@ -15,7 +15,7 @@ use lpvm::{
 use wasm_bindgen::{JsCast, JsValue};
 
 use crate::error::WasmError;
-use crate::module::{SHADOW_STACK_GLOBAL_EXPORT, WasmExport};
+use crate::module::{RODATA_GLOBAL_EXPORT, SHADOW_STACK_GLOBAL_EXPORT, WasmExport};
 
 use super::BrowserLpvmModule;
 use super::link;
@@ -51,6 +51,10 @@ pub struct BrowserLpvmInstance {
     /// and the app itself — share low memory, so a second live shader
     /// silently clobbered the first one's uniforms and persistent globals.
     vmctx_base: usize,
+    /// This instance's copy of the module rodata, addressed through the
+    /// rodata global the same way `vmctx_buf` is through param 0. Empty when
+    /// the module has no rodata.
+    _rodata: Vec<u32>,
     /// Byte offset from vmctx base to globals region
     globals_offset: usize,
     /// Byte offset from vmctx base to snapshot region
@@ -77,6 +81,22 @@ impl BrowserLpvmInstance {
         i32::try_from(vmctx_base)
             .map_err(|_| WasmError::runtime("vmctx guest base exceeds i32 range"))?;
 
+        let rodata: Vec<u32> = module
+            .rodata
+            .chunks_exact(4)
+            .map(|w| u32::from_le_bytes([w[0], w[1], w[2], w[3]]))
+            .collect();
+        if !rodata.is_empty() {
+            let global = Reflect::get(&exports_obj, &JsValue::from_str(RODATA_GLOBAL_EXPORT))
+                .map_err(|e| WasmError::runtime(format!("get rodata global: {e:?}")))?;
+            Reflect::set(
+                &global,
+                &JsValue::from_str("value"),
+                &JsValue::from_f64(rodata.as_ptr() as usize as f64),
+            )
+            .map_err(|e| WasmError::runtime(format!("set rodata global: {e:?}")))?;
+        }
+
         let sigs = &module.signatures;
         let globals_offset = sigs.globals_offset();
         let snapshot_offset = sigs.snapshot_offset();
@@ -92,6 +112,7 @@ impl BrowserLpvmInstance {
             float_mode: module.opts.float_mode,
             vmctx_buf,
             vmctx_base,
+            _rodata: rodata,
             globals_offset,
             snapshot_offset,
             globals_size,
@@ -64,6 +64,7 @@ pub struct WasmLpvmModule {
     pub(crate) signatures: LpsModuleSig,
     pub(crate) exports: HashMap<String, crate::module::WasmExport>,
     pub(crate) shadow_stack_base: Option<i32>,
+    pub(crate) rodata: Vec<u8>,
     pub(crate) opts: WasmOptions,
     pub(crate) lpir: LpirModule,
 }
@@ -128,6 +129,7 @@ impl LpvmEngine for WasmLpvmEngine {
             signatures: artifact.signatures().clone(),
             exports,
             shadow_stack_base: artifact.wasm_module().shadow_stack_base,
+            rodata: artifact.wasm_module().rodata.clone(),
             opts: self.compile_options.clone(),
             lpir: ir.clone(),
         })
@@ -172,6 +174,7 @@ impl LpvmEngine for WasmLpvmEngine {
             signatures: artifact.signatures().clone(),
             exports,
             shadow_stack_base: artifact.wasm_module().shadow_stack_base,
+            rodata: artifact.wasm_module().rodata.clone(),
             opts,
             lpir: ir.clone(),
         })
@@ -23,7 +23,7 @@ use super::marshal::{
 };
 use crate::aggregate_abi::{decode_aggregate_std430_bytes, export_needs_shadow_marshal};
 use crate::error::WasmError;
-use crate::module::{SHADOW_STACK_GLOBAL_EXPORT, WasmExport};
+use crate::module::{RODATA_GLOBAL_EXPORT, SHADOW_STACK_GLOBAL_EXPORT, WasmExport};
 
 use super::WasmLpvmModule;
 use lpir::LpirModule;
@@ -60,6 +60,27 @@ pub struct WasmLpvmInstance {
     render_samples_cache: Option<RenderTextureEntry>,
 }
 
+/// Copy the module rodata into a fresh bump allocation and point the
+/// instance's rodata global at it. The bump region is never reused, so the
+/// copy outlives the instance like its vmctx block does.
+fn place_rodata(module: &WasmLpvmModule, instance: &Instance) -> Result<(), WasmError> {
+    let buf = super::shared_runtime::WasmtimeLpvmMemory::new(Arc::clone(&module.runtime))
+        .alloc(module.rodata.len(), 4)
+        .map_err(|e| WasmError::runtime(format!("rodata alloc: {e:?}")))?;
+    let base = i32::try_from(buf.guest_base())
+        .map_err(|_| WasmError::runtime("rodata guest base exceeds i32 range"))?;
+    let mut guard = module.runtime.lock();
+    let mem = guard.memory;
+    let store = &mut guard.store;
+    mem.write(&mut *store, base as usize, &module.rodata)
+        .map_err(|e| WasmError::runtime(format!("rodata write: {e}")))?;
+    let g = instance
+        .get_global(&mut *store, RODATA_GLOBAL_EXPORT)
+        .ok_or_else(|| WasmError::runtime("missing rodata global export"))?;
+    g.set(&mut *store, Val::I32(base))
+        .map_err(|e| WasmError::runtime(format!("failed to set rodata global: {e}")))
+}
+
 impl WasmLpvmInstance {
     pub(crate) fn new(module: &WasmLpvmModule) -> Result<Self, WasmError> {
         let instance = link::instantiate_wasm_module(
@@ -91,6 +112,9 @@ impl WasmLpvmInstance {
             let store = &mut guard.store;
             mem.data_mut(store)[vmctx_base..vmctx_base + total_size].fill(0);
         }
+        if !module.rodata.is_empty() {
+            place_rodata(module, &instance)?;
+        }
 
         let mut inst = Self {
             runtime: Arc::clone(&module.runtime),
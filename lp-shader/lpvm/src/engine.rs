@@ -32,6 +32,11 @@ pub struct LpvmCompileParams {
     pub config: CompilerConfig,
     /// Numeric mode this module is emitted in.
     pub float_mode: FloatMode,
+    /// Hash of the source this module was lowered from, plus every option
+    /// that shaped its LPIR (texture specs, output format, …). Engines with a
+    /// persistent code cache key compiled code by it together with `config`,
+    /// `float_mode` and the module signature; `None` never hits a cache.
+    pub source_hash: Option<u64>,
 }
 
 impl LpvmCompileParams {
@@ -42,6 +47,7 @@ impl LpvmCompileParams {
         Self {
             config,
             float_mode: FloatMode::Q32,
+            source_hash: None,
         }
     }
 }
@@ -1,12 +1,18 @@
 #!/bin/bash
 # Build lps-builtins-emu-app executable with aggressive optimizations
+#
+# Usage: build-builtins.sh [TARGET]
+#
+# TARGET defaults to riscv32imac-unknown-none-elf, the soft-float image every
+# rv32 engine links against. riscv32imafc-unknown-none-elf builds the `ilp32f`
+# image `lpvm-native`'s `Rv32imafc` target calls into; see the Zcf note below.
 
 set -e
 
 SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
 WORKSPACE_ROOT="$(cd "$SCRIPT_DIR/.." && pwd)"
 LIGHTPLAYER_DIR="$WORKSPACE_ROOT/lp-shader"
-TARGET="riscv32imac-unknown-none-elf"
+TARGET="${1:-riscv32imac-unknown-none-elf}"
 OUTPUT_DIR="$WORKSPACE_ROOT/target/$TARGET/release"
 BINARY="$OUTPUT_DIR/lps-builtins-emu-app"
 BUILTINS_SRC_DIR="$LIGHTPLAYER_DIR/lps-builtins/src/builtins"
@@ -62,6 +68,18 @@ fi
 # Create output directory
 mkdir -p "$OUTPUT_DIR"
 
+# The F image must not contain Zcf (`C.FLW`/`C.FSW`): `lp-riscv-emu` does not
+# decode it, and neither does the RV32F part it models. rustc's riscv32imafc
+# target turns Zcf on implicitly with C+F, and `-zcf` alone is not honoured, so
+# C is swapped for Zca (the integer-only compressed subset). The prebuilt `core`
+# for the target was compiled with Zcf too, hence build-std.
+EXTRA_RUSTFLAGS=""
+BUILD_STD_ARGS=()
+if [ "$TARGET" = "riscv32imafc-unknown-none-elf" ]; then
+  EXTRA_RUSTFLAGS="-C target-feature=-c,+zca"
+  BUILD_STD_ARGS=(-Z build-std=core,alloc -Z build-std-features=compiler-builtins-mem)
+fi
+
 # Build using cargo but with RUSTFLAGS for optimization
 # Cargo will handle its own caching
 cd "$LIGHTPLAYER_DIR"
@@ -71,8 +89,10 @@ RUSTFLAGS="-C opt-level=1 \
            -C overflow-checks=off \
            -C debuginfo=0 \
            -C link-dead-code=off \
-           -C codegen-units=1" \
+           -C codegen-units=1 \
+           $EXTRA_RUSTFLAGS" \
   cargo build \
+  "${BUILD_STD_ARGS[@]}" \
   --target $TARGET \
   --package lps-builtins-emu-app \
   --release \
@@ -97,7 +117,21 @@ if [ "$F32_SYMBOLS" -eq 0 ]; then
   exit 1
 fi
 
+# Check the Zcf claim above rather than trust it, when there is a disassembler
+# to check it with.
+if [ "$TARGET" = "riscv32imafc-unknown-none-elf" ]; then
+  OBJDUMP="${OBJDUMP:-llvm-objdump}"
+  if command -v "$OBJDUMP" >/dev/null 2>&1; then
+    if "$OBJDUMP" -d -M no-aliases "$BINARY" | grep -qE "\bc\.f(l|s)w(sp)?\b"; then
+      echo "error: $BINARY contains Zcf instructions (C.FLW/C.FSW), which the emulator does not decode" >&2
+      exit 1
+    fi
+  else
+    echo "warning: $OBJDUMP not found; the Zcf check on $BINARY was skipped" >&2
+  fi
+fi
+
 # Output formatted results
 GREEN='\033[0;32m'
 NC='\033[0m' # No Color
-echo -e "${GREEN}lps-builtins-emu-app:${NC} built with $LP_SYMBOLS built-ins ($F32_SYMBOLS native-f32)"
+echo -e "${GREEN}lps-builtins-emu-app ($TARGET):${NC} built with $LP_SYMBOLS built-ins ($F32_SYMBOLS native-f32)"